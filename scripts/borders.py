@@ -0,0 +1,484 @@
+#!/usr/bin/env python
+# -*- coding: utf-8 -*-
+#
+# Generates src/borders.rs, the land borders between countries. Each border
+# is listed once below; the adjacency lists are made symmetric here.
+#
+# Only borders on land count: bridges and causeways (Singapore, Bahrain) do
+# not, nor do maritime boundaries. Parts of a country with their own alpha-2
+# code border in their own name, so French Guiana borders Brazil and
+# Suriname, not France, and Saint Martin borders Sint Maarten. Kosovo is part
+# of RS, Gaza and the West Bank are PS, and the Spanish enclaves of Ceuta
+# and Melilla make ES border MA. Landlocked countries follow the UN list of
+# landlocked developing countries plus the landlocked European states; the
+# Caspian Sea counts as a lake.
+#
+# Usage: cd scripts && python3 borders.py > ../src/borders.rs
+import json
+
+ISO_CODES = "/usr/share/iso-codes/json"
+
+# One land border per row, alpha-2 codes
+b = """
+AD FR
+AD ES
+AE OM
+AE SA
+AF CN
+AF IR
+AF PK
+AF TJ
+AF TM
+AF UZ
+AL GR
+AL ME
+AL MK
+AL RS
+AM AZ
+AM GE
+AM IR
+AM TR
+AO CD
+AO CG
+AO NA
+AO ZM
+AR BO
+AR BR
+AR CL
+AR PY
+AR UY
+AT CH
+AT CZ
+AT DE
+AT HU
+AT IT
+AT LI
+AT SI
+AT SK
+AZ GE
+AZ IR
+AZ RU
+AZ TR
+BA HR
+BA ME
+BA RS
+BD IN
+BD MM
+BE DE
+BE FR
+BE LU
+BE NL
+BF BJ
+BF CI
+BF GH
+BF ML
+BF NE
+BF TG
+BG GR
+BG MK
+BG RO
+BG RS
+BG TR
+BI CD
+BI RW
+BI TZ
+BJ NE
+BJ NG
+BJ TG
+BN MY
+BO BR
+BO CL
+BO PE
+BO PY
+BR CO
+BR GF
+BR GY
+BR PE
+BR PY
+BR SR
+BR UY
+BR VE
+BT CN
+BT IN
+BW NA
+BW ZA
+BW ZM
+BW ZW
+BY LT
+BY LV
+BY PL
+BY RU
+BY UA
+CA US
+CD CF
+CD CG
+CD RW
+CD SS
+CD TZ
+CD UG
+CD ZM
+CF CG
+CF CM
+CF SD
+CF SS
+CF TD
+CG CM
+CG GA
+CH DE
+CH FR
+CH IT
+CH LI
+CI GH
+CI GN
+CI LR
+CI ML
+CL PE
+CM GA
+CM GQ
+CM NG
+CM TD
+CN HK
+CN IN
+CN KG
+CN KP
+CN KZ
+CN LA
+CN MM
+CN MN
+CN MO
+CN NP
+CN PK
+CN RU
+CN TJ
+CN VN
+CO EC
+CO PA
+CO PE
+CO VE
+CR NI
+CR PA
+CZ DE
+CZ PL
+CZ SK
+DE DK
+DE FR
+DE LU
+DE NL
+DE PL
+DJ ER
+DJ ET
+DJ SO
+DO HT
+DZ EH
+DZ LY
+DZ MA
+DZ ML
+DZ MR
+DZ NE
+DZ TN
+EC PE
+EE LV
+EE RU
+EG IL
+EG LY
+EG PS
+EG SD
+EH MA
+EH MR
+ER ET
+ER SD
+ES FR
+ES GI
+ES MA
+ES PT
+ET KE
+ET SD
+ET SO
+ET SS
+FI NO
+FI RU
+FI SE
+FR IT
+FR LU
+FR MC
+GA GQ
+GB IE
+GE RU
+GE TR
+GF SR
+GH TG
+GM SN
+GN GW
+GN LR
+GN ML
+GN SL
+GN SN
+GR MK
+GR TR
+GT BZ
+GT HN
+GT MX
+GT SV
+GW SN
+GY SR
+GY VE
+HN NI
+HN SV
+HR HU
+HR ME
+HR RS
+HR SI
+HU RO
+HU RS
+HU SI
+HU SK
+HU UA
+ID MY
+ID PG
+ID TL
+IL JO
+IL LB
+IL PS
+IL SY
+IN MM
+IN NP
+IN PK
+IQ IR
+IQ JO
+IQ KW
+IQ SA
+IQ SY
+IQ TR
+IR PK
+IR TM
+IR TR
+IT SI
+IT SM
+IT VA
+JO PS
+JO SA
+JO SY
+KE SO
+KE SS
+KE TZ
+KE UG
+KG KZ
+KG TJ
+KG UZ
+KH LA
+KH TH
+KH VN
+KP KR
+KP RU
+KW SA
+KZ RU
+KZ TM
+KZ UZ
+LA MM
+LA TH
+LA VN
+LB SY
+LR SL
+LT LV
+LT PL
+LT RU
+LV RU
+LY NE
+LY SD
+LY TD
+LY TN
+MD RO
+MD UA
+ME RS
+MF SX
+MK RS
+ML MR
+ML NE
+ML SN
+MM TH
+MN RU
+MW MZ
+MW TZ
+MW ZM
+MX BZ
+MX US
+MY TH
+MZ SZ
+MZ TZ
+MZ ZA
+MZ ZM
+MZ ZW
+NA ZA
+NA ZM
+NE NG
+NE TD
+NG TD
+NO RU
+NO SE
+OM SA
+OM YE
+PL RU
+PL SK
+PL UA
+QA SA
+RO RS
+RO UA
+RU UA
+RW TZ
+RW UG
+SA YE
+SD SS
+SD TD
+SK UA
+SS UG
+SY TR
+SZ ZA
+TJ UZ
+TM UZ
+TZ UG
+TZ ZM
+ZA LS
+ZA ZW
+ZM ZW
+"""
+
+LANDLOCKED = """
+AD AF AM AT AZ BF BI BO BT BW BY CF CH CZ ET HU KG KZ LA LI LS LU MD MK ML
+MN MW NE NP PY RS RW SK SM SS TD TJ TM UG UZ VA ZM ZW
+"""
+
+pre_code = """use crate::{CountryCode, ALL};
+use phf::phf_map;
+use phf::Map;
+use std::collections::{HashSet, VecDeque};
+
+impl CountryCode {
+    ///Return the countries this one shares a land border with, in alpha-2
+    ///order. Borders across bridges or the sea do not count, so Singapore
+    ///and Bahrain have none.
+    pub fn neighbors(&self) -> &'static [CountryCode] {
+        NEIGHBOR_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+
+    ///Return true if this country shares a land border with `other`
+    pub fn is_neighbor(&self, other: &CountryCode) -> bool {
+        self.neighbors().contains(other)
+    }
+
+    ///Return true if the country has no coastline on the open sea; the
+    ///Caspian Sea counts as a lake
+    pub fn is_landlocked(&self) -> bool {
+        LANDLOCKED.contains(self)
+    }
+
+    ///Return true if the country is landlocked and so are all its
+    ///neighbors, which is the case for Liechtenstein and Uzbekistan
+    pub fn is_double_landlocked(&self) -> bool {
+        self.is_landlocked() && self.neighbors().iter().all(CountryCode::is_landlocked)
+    }
+
+    ///Return every country reachable from this one over land borders, this
+    ///one included, in `ALL` order. A country without land borders is its
+    ///own landmass.
+    pub fn landmass(&self) -> Vec<CountryCode> {
+        let mut seen = HashSet::from([self.alpha2]);
+        let mut queue = VecDeque::from([*self]);
+        while let Some(country) = queue.pop_front() {
+            for neighbor in country.neighbors() {
+                if seen.insert(neighbor.alpha2) {
+                    queue.push_back(*neighbor);
+                }
+            }
+        }
+        ALL.iter().filter(|c| seen.contains(c.alpha2)).copied().collect()
+    }
+}
+
+/// Returns the minimum number of land borders to cross to get from `from`
+/// to `to`, if one can reach the other over land. `Some(0)` if both are the
+/// same country.
+/// #Sample
+/// ```
+/// use rust_iso3166::border_crossings;
+/// assert_eq!(Some(1), border_crossings(rust_iso3166::FR, rust_iso3166::DE));
+/// assert_eq!(Some(2), border_crossings(rust_iso3166::PT, rust_iso3166::FR));
+/// assert_eq!(None, border_crossings(rust_iso3166::FR, rust_iso3166::GB));
+/// assert!(rust_iso3166::UZ.is_double_landlocked());
+/// ```
+pub fn border_crossings(from: CountryCode, to: CountryCode) -> Option<usize> {
+    let mut seen = HashSet::from([from.alpha2]);
+    let mut queue = VecDeque::from([(from, 0)]);
+    while let Some((country, crossings)) = queue.pop_front() {
+        if country == to {
+            return Some(crossings);
+        }
+        for neighbor in country.neighbors() {
+            if seen.insert(neighbor.alpha2) {
+                queue.push_back((*neighbor, crossings + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the groups of countries connected by land borders, largest
+/// first. Countries without land borders are left out.
+///
+/// Borders on islands shared by several countries tie the island to the
+/// mainland group of one of them, so Papua New Guinea and Timor-Leste join
+/// Afro-Eurasia through Indonesia and Malaysia.
+/// #Sample
+/// ```
+/// let landmasses = rust_iso3166::landmasses();
+/// assert!(landmasses[0].contains(&rust_iso3166::ZA));
+/// assert!(landmasses[0].contains(&rust_iso3166::CN));
+/// assert!(landmasses[1].contains(&rust_iso3166::AR));
+/// ```
+pub fn landmasses() -> Vec<Vec<CountryCode>> {
+    let mut seen = HashSet::new();
+    let mut landmasses = Vec::new();
+    for country in ALL {
+        if country.neighbors().is_empty() || seen.contains(country.alpha2) {
+            continue;
+        }
+        let landmass = country.landmass();
+        seen.extend(landmass.iter().map(|c| c.alpha2));
+        landmasses.push(landmass);
+    }
+    landmasses.sort_by_key(|landmass| std::cmp::Reverse(landmass.len()));
+    landmasses
+}
+"""
+
+
+def main():
+    with open("%s/iso_3166-1.json" % ISO_CODES) as f:
+        countries = set(x["alpha_2"] for x in json.load(f)["3166-1"])
+    neighbors = {}
+    for row in b.split("\n"):
+        if row:
+            x, y = row.split(" ")
+            assert x in countries and y in countries and x != y, row
+            assert y not in neighbors.get(x, []), row
+            neighbors.setdefault(x, []).append(y)
+            neighbors.setdefault(y, []).append(x)
+    landlocked = sorted(LANDLOCKED.split())
+    assert set(landlocked) <= countries
+
+    print(pre_code)
+    print("/// Land neighbors by alpha-2 code.")
+    print("pub const NEIGHBOR_MAP: Map<&str, &[CountryCode]> = phf_map! {")
+    for alpha2 in sorted(neighbors):
+        codes = ", ".join("crate::%s" % n for n in sorted(neighbors[alpha2]))
+        print("    \"%s\" => &[%s]," % (alpha2, codes))
+    print("};")
+    print()
+    print("/// Landlocked countries.")
+    print("pub const LANDLOCKED: &[CountryCode] = &[")
+    for alpha2 in landlocked:
+        print("    crate::%s," % alpha2)
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,178 @@
+#!/usr/bin/env python
+# -*- coding: utf-8 -*-
+#
+# Generates src/cctld.rs. ASCII ccTLDs follow the alpha-2 code except where
+# the IANA root zone database says otherwise (EXCEPTIONS, NOT_DELEGATED).
+# The punycode form of each IDN ccTLD is computed here, so only the Unicode
+# label needs to be listed.
+#
+# Usage: cd scripts && python3 cctld.py > ../src/cctld.rs
+import json
+
+ISO_CODES = "/usr/share/iso-codes/json"
+
+# ccTLDs that differ from the alpha-2 code; the first one is the country's
+EXCEPTIONS = {
+    "GB": ["uk", "gb"],
+    "SH": ["sh", "ac"],
+}
+
+# Alpha-2 codes without a delegated ccTLD
+NOT_DELEGATED = ["BL", "BQ", "EH", "MF", "UM"]
+
+# alpha-2, Unicode IDN ccTLDs
+IDN = """
+AE	امارات
+AM	հայ
+BD	বাংলা
+BG	бг
+BH	البحرين
+BY	бел
+CN	中国 中國
+DZ	الجزائر
+EG	مصر
+GE	გე
+GR	ελ
+HK	香港
+IN	भारत ভারত ভাৰত ਭਾਰਤ ભારત ଭାରତ இந்தியா భారత్ ಭಾರತ ഭാരതം भारतम् भारोत بارت ڀارت بھارت
+IQ	عراق
+IR	ایران
+JO	الاردن
+KR	한국
+KZ	қаз
+LK	ලංකා இலங்கை
+LY	ليبيا
+MA	المغرب
+MK	мкд
+MN	мон
+MO	澳門
+MR	موريتانيا
+MY	مليسيا
+OM	عمان
+PK	پاکستان
+PS	فلسطين
+QA	قطر
+RS	срб
+RU	рф
+SA	السعودية
+SD	سودان
+SG	新加坡 சிங்கப்பூர்
+SY	سورية
+TH	ไทย
+TN	تونس
+TW	台湾 台灣
+UA	укр
+YE	اليمن
+"""
+
+pre_code = """use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+
+/// An internationalized country-code top-level domain.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IdnCcTld {
+    ///The label in Unicode, such as `рф`
+    pub unicode: &'static str,
+    ///The label in punycode, such as `xn--p1ai`
+    pub punycode: &'static str,
+}
+
+impl CountryCode {
+    ///Return the ASCII ccTLD with its leading dot, such as `.uk` for the
+    ///United Kingdom. `None` for codes without a delegated ccTLD: BL, BQ,
+    ///EH, MF and UM.
+    pub fn cctld(&self) -> Option<&'static str> {
+        COUNTRY_CCTLD_MAP.get(self.alpha2).copied()
+    }
+
+    ///Return the internationalized ccTLDs, such as `.中国` for China
+    pub fn idn_cctlds(&self) -> &'static [IdnCcTld] {
+        COUNTRY_IDN_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+}
+
+/// Returns the country of the ccTLD a hostname or email address ends in, if
+/// exists.
+///
+/// Only the top-level label counts, so multi-level public suffixes such as
+/// `.co.uk` or `.com.au` resolve through their ccTLD, while `.uk.com` and
+/// other look-alike second-level domains under generic TLDs return `None`.
+/// Besides the alpha-2 codes, `.uk` and the unused `.gb` resolve to the
+/// United Kingdom and `.ac` to Saint Helena, Ascension and Tristan da Cunha.
+/// `.eu` and the legacy `.su` do not belong to one country and return
+/// `None`. IDN ccTLDs match in Unicode and punycode form; case and a
+/// trailing dot are ignored.
+/// #Sample
+/// ```
+/// use rust_iso3166::from_hostname;
+/// assert_eq!(Some(rust_iso3166::GB), from_hostname("shop.example.co.uk"));
+/// assert_eq!(Some(rust_iso3166::AU), from_hostname("jane@example.com.au"));
+/// assert_eq!(Some(rust_iso3166::RU), from_hostname("пример.рф"));
+/// assert_eq!(Some(rust_iso3166::RU), from_hostname("xn--e1afmkfd.xn--p1ai"));
+/// assert_eq!(None, from_hostname("example.eu"));
+/// assert_eq!(None, from_hostname("example.uk.com"));
+/// ```
+pub fn from_hostname(host: &str) -> Option<CountryCode> {
+    let host = host.trim();
+    let host = host.rsplit('@').next().unwrap_or(host);
+    let host = host.strip_suffix('.').unwrap_or(host);
+    let tld = host.rsplit('.').next()?;
+    if tld.is_ascii() {
+        CCTLD_MAP.get(tld.to_ascii_lowercase().as_str()).copied()
+    } else {
+        CCTLD_MAP.get(tld.to_lowercase().as_str()).copied()
+    }
+}
+"""
+
+
+def punycode(label):
+    return "xn--" + label.encode("punycode").decode("ascii")
+
+
+def main():
+    with open("%s/iso_3166-1.json" % ISO_CODES) as f:
+        countries = sorted(x["alpha_2"] for x in json.load(f)["3166-1"])
+    ascii_tlds = {}
+    for alpha2 in countries:
+        if alpha2 in NOT_DELEGATED:
+            continue
+        ascii_tlds[alpha2] = EXCEPTIONS.get(alpha2, [alpha2.lower()])
+    idn = {}
+    for row in IDN.split("\n"):
+        if row:
+            alpha2, labels = row.split("\t")
+            idn[alpha2] = labels.split(" ")
+
+    print(pre_code)
+    print("/// ccTLD of each country by alpha-2 code.")
+    print("pub const COUNTRY_CCTLD_MAP: Map<&str, &str> = phf_map! {")
+    for alpha2, tlds in ascii_tlds.items():
+        print("    \"%s\" => \".%s\"," % (alpha2, tlds[0]))
+    print("};")
+    print()
+    print("/// IDN ccTLDs by alpha-2 code.")
+    print("pub const COUNTRY_IDN_MAP: Map<&str, &[IdnCcTld]> = phf_map! {")
+    for alpha2, labels in idn.items():
+        literals = [
+            "IdnCcTld { unicode: \"%s\", punycode: \"%s\" }" % (label, punycode(label))
+            for label in labels
+        ]
+        print("    \"%s\" => &[%s]," % (alpha2, ", ".join(literals)))
+    print("};")
+    print()
+    print("/// Countries by lower-case ccTLD label, ASCII, Unicode IDN and punycode.")
+    print("pub const CCTLD_MAP: Map<&str, CountryCode> = phf_map! {")
+    for alpha2, tlds in ascii_tlds.items():
+        for tld in tlds:
+            print("    \"%s\" => crate::%s," % (tld, alpha2))
+    for alpha2, labels in idn.items():
+        for label in labels:
+            print("    \"%s\" => crate::%s," % (label, alpha2))
+            print("    \"%s\" => crate::%s," % (punycode(label), alpha2))
+    print("};")
+
+
+if __name__ == "__main__":
+    main()
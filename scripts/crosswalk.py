@@ -0,0 +1,440 @@
+#!/usr/bin/env python
+# -*- coding: utf-8 -*-
+#
+# Generates src/crosswalk.rs, the codes US government, NATO and ITU sources
+# use for countries. FIPS 10-4 codes are the Geopolitical Entities and Codes
+# (GEC) of the World Factbook. STANAG 1059 trigraphs are the ISO 3166-1
+# alpha-3 codes since edition 9 and come from the iso-codes JSON data. ITU
+# codes are the geographical area symbols of the ITU Radiocommunication
+# Bureau, one to three letters.
+#
+# Usage: cd scripts && python3 crosswalk.py > ../src/crosswalk.rs
+import json
+
+ISO_CODES = "/usr/share/iso-codes/json"
+
+# alpha-2, FIPS 10-4, ITU
+a = """
+AD	AN	AND
+AE	AE	UAE
+AF	AF	AFG
+AG	AC	ATG
+AI	AV	-
+AL	AL	ALB
+AM	AM	ARM
+AO	AO	AGL
+AQ	AY	-
+AR	AR	ARG
+AS	AQ	-
+AT	AU	AUT
+AU	AS	AUS
+AW	AA	ABW
+AX	-	-
+AZ	AJ	AZE
+BA	BK	BIH
+BB	BB	BRB
+BD	BG	BGD
+BE	BE	BEL
+BF	UV	BFA
+BG	BU	BUL
+BH	BA	BHR
+BI	BY	BDI
+BJ	BN	BEN
+BL	TB	-
+BM	BD	BER
+BN	BX	BRM
+BO	BL	BOL
+BQ	-	-
+BR	BR	B
+BS	BF	BAH
+BT	BT	BTN
+BV	BV	-
+BW	BC	BOT
+BY	BO	BLR
+BZ	BH	BLZ
+CA	CA	CAN
+CC	CK	-
+CD	CG	COD
+CF	CT	CAF
+CG	CF	COG
+CH	SZ	SUI
+CI	IV	CTI
+CK	CW	-
+CL	CI	CHL
+CM	CM	CME
+CN	CH	CHN
+CO	CO	CLM
+CR	CS	CTR
+CU	CU	CUB
+CV	CV	CPV
+CW	UC	CUW
+CX	KT	-
+CY	CY	CYP
+CZ	EZ	CZE
+DE	GM	D
+DJ	DJ	DJI
+DK	DA	DNK
+DM	DO	DMA
+DO	DR	DOM
+DZ	AG	ALG
+EC	EC	EQA
+EE	EN	EST
+EG	EG	EGY
+EH	WI	-
+ER	ER	ERI
+ES	SP	E
+ET	ET	ETH
+FI	FI	FIN
+FJ	FJ	FJI
+FK	FK	-
+FM	FM	FSM
+FO	FO	FRO
+FR	FR	F
+GA	GB	GAB
+GB	UK	G
+GD	GJ	GRD
+GE	GG	GEO
+GF	FG	GUF
+GG	GK	-
+GH	GH	GHA
+GI	GI	GIB
+GL	GL	GRL
+GM	GA	GMB
+GN	GV	GUI
+GP	GP	GDL
+GQ	EK	GNE
+GR	GR	GRC
+GS	SX	-
+GT	GT	GTM
+GU	GQ	GUM
+GW	PU	GNB
+GY	GY	GUY
+HK	HK	HKG
+HM	HM	-
+HN	HO	HND
+HR	HR	HRV
+HT	HA	HTI
+HU	HU	HNG
+ID	ID	INS
+IE	EI	IRL
+IL	IS	ISR
+IM	IM	-
+IN	IN	IND
+IO	IO	-
+IQ	IZ	IRQ
+IR	IR	IRN
+IS	IC	ISL
+IT	IT	I
+JE	JE	-
+JM	JM	JMC
+JO	JO	JOR
+JP	JA	J
+KE	KE	KEN
+KG	KG	KGZ
+KH	CB	CBG
+KI	KR	KIR
+KM	CN	COM
+KN	SC	KNA
+KP	KN	KRE
+KR	KS	KOR
+KW	KU	KWT
+KY	CJ	CYM
+KZ	KZ	KAZ
+LA	LA	LAO
+LB	LE	LBN
+LC	ST	LCA
+LI	LS	LIE
+LK	CE	CLN
+LR	LI	LBR
+LS	LT	LSO
+LT	LH	LTU
+LU	LU	LUX
+LV	LG	LVA
+LY	LY	LBY
+MA	MO	MRC
+MC	MN	MCO
+MD	MD	MDA
+ME	MJ	MNE
+MF	RN	-
+MG	MA	MDG
+MH	RM	MHL
+MK	MK	MKD
+ML	ML	MLI
+MM	BM	MYA
+MN	MG	MNG
+MO	MC	MAC
+MP	CQ	-
+MQ	MB	MRT
+MR	MR	MTN
+MS	MH	-
+MT	MT	MLT
+MU	MP	MAU
+MV	MV	MLD
+MW	MI	MWI
+MX	MX	MEX
+MY	MY	MLA
+MZ	MZ	MOZ
+NA	WA	NMB
+NC	NC	NCL
+NE	NG	NGR
+NF	NF	-
+NG	NI	NIG
+NI	NU	NCG
+NL	NL	HOL
+NO	NO	NOR
+NP	NP	NPL
+NR	NR	NRU
+NU	NE	-
+NZ	NZ	NZL
+OM	MU	OMA
+PA	PM	PNR
+PE	PE	PRU
+PF	FP	OCE
+PG	PP	PNG
+PH	RP	PHL
+PK	PK	PAK
+PL	PL	POL
+PM	SB	-
+PN	PC	-
+PR	RQ	PTR
+PS	-	-
+PT	PO	POR
+PW	PS	PLW
+PY	PA	PRG
+QA	QA	QAT
+RE	RE	REU
+RO	RO	ROU
+RS	RI	SRB
+RU	RS	RUS
+RW	RW	RRW
+SA	SA	ARS
+SB	BP	SLM
+SC	SE	SEY
+SD	SU	SDN
+SE	SW	S
+SG	SN	SNG
+SH	SH	-
+SI	SI	SVN
+SJ	SV	-
+SK	LO	SVK
+SL	SL	SRL
+SM	SM	SMR
+SN	SG	SEN
+SO	SO	SOM
+SR	NS	SUR
+SS	OD	SSD
+ST	TP	STP
+SV	ES	SLV
+SX	NN	-
+SY	SY	SYR
+SZ	WZ	SWZ
+TC	TK	-
+TD	CD	TCD
+TF	FS	-
+TG	TO	TGO
+TH	TH	THA
+TJ	TI	TJK
+TK	TL	-
+TL	TT	TLS
+TM	TX	TKM
+TN	TS	TUN
+TO	TN	TON
+TR	TU	TUR
+TT	TD	TRD
+TV	TV	TUV
+TW	TW	-
+TZ	TZ	TZA
+UA	UP	UKR
+UG	UG	UGA
+UM	-	-
+US	US	USA
+UY	UY	URG
+UZ	UZ	UZB
+VA	VT	CVA
+VC	VC	VCT
+VE	VE	VEN
+VG	VI	-
+VI	VQ	VIR
+VN	VM	VTN
+VU	NH	VUT
+WF	WF	-
+WS	WS	SMO
+YE	YM	YEM
+YT	MF	-
+ZA	SF	AFS
+ZM	ZA	ZMB
+ZW	ZI	ZWE
+"""
+
+# FIPS 10-4 codes for parts of a country that have no ISO 3166-1 code
+FIPS_PARTS = [
+    ("BQ", "UM", "Navassa Island"),
+    ("BS", "TF", "Bassas da India"),
+    ("DQ", "UM", "Jarvis Island"),
+    ("EU", "TF", "Europa Island"),
+    ("FQ", "UM", "Baker Island"),
+    ("GO", "TF", "Glorioso Islands"),
+    ("GZ", "PS", "Gaza Strip"),
+    ("HQ", "UM", "Howland Island"),
+    ("JN", "SJ", "Jan Mayen"),
+    ("JQ", "UM", "Johnston Atoll"),
+    ("JU", "TF", "Juan de Nova Island"),
+    ("KQ", "UM", "Kingman Reef"),
+    ("LQ", "UM", "Palmyra Atoll"),
+    ("MQ", "UM", "Midway Islands"),
+    ("TE", "TF", "Tromelin Island"),
+    ("WE", "PS", "West Bank"),
+    ("WQ", "UM", "Wake Island"),
+]
+
+pre_code = """//! Crosswalks between ISO 3166-1 and the country codes of other standards:
+//! FIPS 10-4 (the GEC codes of US government sources), NATO STANAG 1059 and
+//! the ITU.
+//!
+//! Many FIPS codes are another country's ISO alpha-2 code: FIPS `AU` is
+//! Austria and FIPS `AS` is Australia, while ISO `AU` is Australia and ISO
+//! `AS` is American Samoa. Every lookup therefore names its `Scheme`, and
+//! codes from these schemes must never go through `from_alpha2`.
+//!
+//! # Sample code
+//! ```
+//! use rust_iso3166::crosswalk::{self, Scheme};
+//! assert_eq!(Some(rust_iso3166::AT), crosswalk::from_code(Scheme::FIPS, "AU"));
+//! assert_eq!(Some(rust_iso3166::AU), crosswalk::from_code(Scheme::FIPS, "AS"));
+//! assert_eq!(Some("AS"), rust_iso3166::AU.crosswalk_code(Scheme::FIPS));
+//! assert_eq!(Some("G"), rust_iso3166::GB.crosswalk_code(Scheme::ITU));
+//! assert_eq!(Some(rust_iso3166::DE), crosswalk::from_code(Scheme::STANAG, "DEU"));
+//! ```
+
+use crate::parse::{as_str, to_upper};
+use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+
+/// A country coding scheme other than ISO 3166.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Scheme {
+    ///FIPS 10-4 country codes, maintained as the Geopolitical Entities and
+    ///Codes (GEC) of the US government. Some parts of a country have their
+    ///own code, such as `JN` for Jan Mayen; they resolve to the country.
+    FIPS,
+    ///NATO STANAG 1059 trigraphs, the same as ISO 3166-1 alpha-3 since
+    ///edition 9
+    STANAG,
+    ///ITU geographical area symbols, such as `G` for the United Kingdom or
+    ///`HOL` for the Netherlands. Most territories have none.
+    ITU,
+}
+
+/// The codes of one country in each `Scheme`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Crosswalk {
+    ///FIPS 10-4 code
+    pub fips: Option<&'static str>,
+    ///STANAG 1059 trigraph
+    pub stanag: Option<&'static str>,
+    ///ITU geographical area symbol
+    pub itu: Option<&'static str>,
+}
+
+impl Scheme {
+    ///All schemes
+    pub const ALL: &'static [Scheme] = &[Scheme::FIPS, Scheme::STANAG, Scheme::ITU];
+
+    ///Return the name of the standard
+    pub fn name(&self) -> &'static str {
+        match self {
+            Scheme::FIPS => "FIPS 10-4",
+            Scheme::STANAG => "STANAG 1059",
+            Scheme::ITU => "ITU",
+        }
+    }
+
+    ///Return every code of this scheme and the country it stands for
+    pub fn codes(&self) -> &'static Map<&'static str, CountryCode> {
+        match self {
+            Scheme::FIPS => &FIPS_MAP,
+            Scheme::STANAG => &STANAG_MAP,
+            Scheme::ITU => &ITU_MAP,
+        }
+    }
+}
+
+impl CountryCode {
+    ///Return the codes of this country in every scheme
+    pub fn crosswalk(&self) -> Crosswalk {
+        CROSSWALK_MAP.get(self.alpha2).copied().unwrap_or(Crosswalk {
+            fips: None,
+            stanag: None,
+            itu: None,
+        })
+    }
+
+    ///Return the code of this country in the given scheme
+    pub fn crosswalk_code(&self, scheme: Scheme) -> Option<&'static str> {
+        let crosswalk = self.crosswalk();
+        match scheme {
+            Scheme::FIPS => crosswalk.fips,
+            Scheme::STANAG => crosswalk.stanag,
+            Scheme::ITU => crosswalk.itu,
+        }
+    }
+}
+
+/// Returns the country with the given code in the given scheme, if exists.
+/// ASCII case and surrounding whitespace are ignored.
+/// #Sample
+/// ```
+/// use rust_iso3166::crosswalk::{self, Scheme};
+/// assert_eq!(Some(rust_iso3166::DE), crosswalk::from_code(Scheme::FIPS, "gm"));
+/// assert_eq!(Some(rust_iso3166::SJ), crosswalk::from_code(Scheme::FIPS, "JN"));
+/// assert_eq!(Some(rust_iso3166::NL), crosswalk::from_code(Scheme::ITU, "HOL"));
+/// assert_eq!(None, crosswalk::from_code(Scheme::FIPS, "AUT"));
+/// ```
+pub fn from_code(scheme: Scheme, code: &str) -> Option<CountryCode> {
+    let (buf, len) = to_upper::<3>(code.trim(), 1..=3, |_, c| c.is_ascii_alphabetic()).ok()?;
+    scheme.codes().get(as_str(&buf[..len])).copied()
+}
+"""
+
+
+def option(code):
+    return "Some(\"%s\")" % code if code else "None"
+
+
+def main():
+    with open("%s/iso_3166-1.json" % ISO_CODES) as f:
+        alpha3 = {x["alpha_2"]: x["alpha_3"] for x in json.load(f)["3166-1"]}
+    rows = []
+    for x in a.split("\n"):
+        if x:
+            alpha2, fips, itu = [None if c == "-" else c for c in x.split("\t")]
+            rows.append((alpha2, fips, alpha3[alpha2], itu))
+
+    print(pre_code)
+    print("/// Codes in every scheme by alpha-2 code.")
+    print("pub const CROSSWALK_MAP: Map<&str, Crosswalk> = phf_map! {")
+    for alpha2, fips, stanag, itu in rows:
+        print(
+            "    \"%s\" => Crosswalk { fips: %s, stanag: %s, itu: %s },"
+            % (alpha2, option(fips), option(stanag), option(itu))
+        )
+    print("};")
+    reverse = [
+        ("FIPS_MAP", "FIPS 10-4", [(r[1], r[0]) for r in rows if r[1]] + [(c, a2) for c, a2, _ in FIPS_PARTS]),
+        ("STANAG_MAP", "STANAG 1059", [(r[2], r[0]) for r in rows]),
+        ("ITU_MAP", "ITU", [(r[3], r[0]) for r in rows if r[3]]),
+    ]
+    for const, name, codes in reverse:
+        print()
+        print("/// Countries by %s code." % name)
+        print("pub const %s: Map<&str, CountryCode> = phf_map! {" % const)
+        for code, alpha2 in sorted(codes):
+            print("    \"%s\" => crate::%s," % (code, alpha2))
+        print("};")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,422 @@
+#!/usr/bin/env python
+# -*- coding: utf-8 -*-
+#
+# Generates src/currency.rs. The currencies in legal use per country come
+# from CLDR's currency data (via ICU), updated by hand since: Bulgaria joined
+# the euro area in 2026, Curaçao and Sint Maarten replaced ANG with XCG in
+# 2025 and Zimbabwe introduced ZWG in 2024. Fund codes (BOV, CHE, USN, ...)
+# are left out. Names and numeric codes come from the iso-codes JSON data.
+#
+# Usage: cd scripts && python3 currency.py > ../src/currency.rs
+import json
+
+ISO_CODES = "/usr/share/iso-codes/json"
+
+# Newer than the iso-codes release the names are read from
+EXTRA = {
+    "XCG": ("Caribbean Guilder", "532"),
+    "ZWG": ("Zimbabwe Gold", "924"),
+}
+
+# ISO 4217 minor units that differ from 2
+MINOR_UNITS = {
+    "BIF": 0, "CLP": 0, "DJF": 0, "GNF": 0, "ISK": 0, "JPY": 0, "KMF": 0,
+    "KRW": 0, "PYG": 0, "RWF": 0, "UGX": 0, "VND": 0, "VUV": 0, "XAF": 0,
+    "XOF": 0, "XPF": 0,
+    "BHD": 3, "IQD": 3, "JOD": 3, "KWD": 3, "LYD": 3, "OMR": 3, "TND": 3,
+}
+
+# alpha-2, currencies in legal use (main currency first)
+a = """
+AD	EUR
+AE	AED
+AF	AFN
+AG	XCD
+AI	XCD
+AL	ALL
+AM	AMD
+AO	AOA
+AQ	
+AR	ARS
+AS	USD
+AT	EUR
+AU	AUD
+AW	AWG
+AX	EUR
+AZ	AZN
+BA	BAM
+BB	BBD
+BD	BDT
+BE	EUR
+BF	XOF
+BG	EUR
+BH	BHD
+BI	BIF
+BJ	XOF
+BL	EUR
+BM	BMD
+BN	BND
+BO	BOB
+BQ	USD
+BR	BRL
+BS	BSD
+BT	BTN INR
+BV	NOK
+BW	BWP
+BY	BYN
+BZ	BZD
+CA	CAD
+CC	AUD
+CD	CDF
+CF	XAF
+CG	XAF
+CH	CHF
+CI	XOF
+CK	NZD
+CL	CLP
+CM	XAF
+CN	CNY
+CO	COP
+CR	CRC
+CU	CUP
+CV	CVE
+CW	XCG
+CX	AUD
+CY	EUR
+CZ	CZK
+DE	EUR
+DJ	DJF
+DK	DKK
+DM	XCD
+DO	DOP
+DZ	DZD
+EC	USD
+EE	EUR
+EG	EGP
+EH	MAD
+ER	ERN
+ES	EUR
+ET	ETB
+FI	EUR
+FJ	FJD
+FK	FKP
+FM	USD
+FO	DKK
+FR	EUR
+GA	XAF
+GB	GBP
+GD	XCD
+GE	GEL
+GF	EUR
+GG	GBP
+GH	GHS
+GI	GIP
+GL	DKK
+GM	GMD
+GN	GNF
+GP	EUR
+GQ	XAF
+GR	EUR
+GS	GBP
+GT	GTQ
+GU	USD
+GW	XOF
+GY	GYD
+HK	HKD
+HM	AUD
+HN	HNL
+HR	EUR
+HT	HTG USD
+HU	HUF
+ID	IDR
+IE	EUR
+IL	ILS
+IM	GBP
+IN	INR
+IO	USD
+IQ	IQD
+IR	IRR
+IS	ISK
+IT	EUR
+JE	GBP
+JM	JMD
+JO	JOD
+JP	JPY
+KE	KES
+KG	KGS
+KH	KHR
+KI	AUD
+KM	KMF
+KN	XCD
+KP	KPW
+KR	KRW
+KW	KWD
+KY	KYD
+KZ	KZT
+LA	LAK
+LB	LBP
+LC	XCD
+LI	CHF
+LK	LKR
+LR	LRD
+LS	LSL ZAR
+LT	EUR
+LU	EUR
+LV	EUR
+LY	LYD
+MA	MAD
+MC	EUR
+MD	MDL
+ME	EUR
+MF	EUR
+MG	MGA
+MH	USD
+MK	MKD
+ML	XOF
+MM	MMK
+MN	MNT
+MO	MOP
+MP	USD
+MQ	EUR
+MR	MRU
+MS	XCD
+MT	EUR
+MU	MUR
+MV	MVR
+MW	MWK
+MX	MXN
+MY	MYR
+MZ	MZN
+NA	NAD ZAR
+NC	XPF
+NE	XOF
+NF	AUD
+NG	NGN
+NI	NIO
+NL	EUR
+NO	NOK
+NP	NPR
+NR	AUD
+NU	NZD
+NZ	NZD
+OM	OMR
+PA	PAB USD
+PE	PEN
+PF	XPF
+PG	PGK
+PH	PHP
+PK	PKR
+PL	PLN
+PM	EUR
+PN	NZD
+PR	USD
+PS	ILS JOD
+PT	EUR
+PW	USD
+PY	PYG
+QA	QAR
+RE	EUR
+RO	RON
+RS	RSD
+RU	RUB
+RW	RWF
+SA	SAR
+SB	SBD
+SC	SCR
+SD	SDG
+SE	SEK
+SG	SGD
+SH	SHP
+SI	EUR
+SJ	NOK
+SK	EUR
+SL	SLE
+SM	EUR
+SN	XOF
+SO	SOS
+SR	SRD
+SS	SSP
+ST	STN
+SV	USD SVC
+SX	XCG
+SY	SYP
+SZ	SZL
+TC	USD
+TD	XAF
+TF	EUR
+TG	XOF
+TH	THB
+TJ	TJS
+TK	NZD
+TL	USD
+TM	TMT
+TN	TND
+TO	TOP
+TR	TRY
+TT	TTD
+TV	AUD
+TW	TWD
+TZ	TZS
+UA	UAH
+UG	UGX
+UM	USD
+US	USD
+UY	UYU
+UZ	UZS
+VA	EUR
+VC	XCD
+VE	VES VED
+VG	USD
+VI	USD
+VN	VND
+VU	VUV
+WF	XPF
+WS	WST
+YE	YER
+YT	EUR
+ZA	ZAR
+ZM	ZMW
+ZW	ZWG USD"""
+
+pre_code = """//! ISO 4217 currencies in legal use in each country, behind the `currency`
+//! feature.
+//!
+//! # Sample code
+//! ```
+//! use rust_iso3166::currency;
+//! let panama = rust_iso3166::from_alpha2("PA").unwrap();
+//! let codes: Vec<_> = panama.currencies().iter().map(|c| c.code).collect();
+//! assert_eq!(vec!["PAB", "USD"], codes);
+//!
+//! let eur = currency::from_code("EUR").unwrap();
+//! assert_eq!((978, 2), (eur.numeric, eur.minor_units));
+//! assert!(currency::countries_using("XOF").contains(&rust_iso3166::SN));
+//! ```
+
+use crate::parse::{as_str, to_upper};
+use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+
+/// Data for each ISO 4217 currency.
+#[derive(Debug, Ord, PartialOrd, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Currency {
+    ///Alphabetic code
+    pub code: &'static str,
+    ///Numeric code
+    pub numeric: u16,
+    ///Number of digits after the decimal separator
+    pub minor_units: u8,
+    ///English name
+    pub name: &'static str,
+}
+
+impl Currency {
+    ///Return len 3 String for the numeric code
+    pub fn numeric_str(&self) -> String {
+        format!("{:03}", self.numeric)
+    }
+
+    ///Return the countries using this currency
+    pub fn countries(&self) -> Vec<CountryCode> {
+        countries_using(self.code)
+    }
+}
+
+impl CountryCode {
+    ///Return the ISO 4217 currencies in legal use, main currency first
+    pub fn currencies(&self) -> &'static [Currency] {
+        COUNTRY_CURRENCY_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+}
+
+/// Returns the Currency with the given alphabetic code, if exists. ASCII
+/// case is ignored.
+/// #Sample
+/// ```
+/// let currency = rust_iso3166::currency::from_code("JPY");
+/// assert_eq!(0, currency.unwrap().minor_units);
+/// assert_eq!(currency, rust_iso3166::currency::from_code("jpy"));
+/// ```
+pub fn from_code(code: &str) -> Option<Currency> {
+    let (buf, len) = to_upper::<3>(code.trim(), 3..=3, |_, c| c.is_ascii_alphabetic()).ok()?;
+    CURRENCY_MAP.get(as_str(&buf[..len])).copied()
+}
+
+/// Returns the Currency with the given numeric code, if exists.
+/// #Sample
+/// ```
+/// let currency = rust_iso3166::currency::from_numeric(840);
+/// assert_eq!("USD", currency.unwrap().code);
+/// ```
+pub fn from_numeric(numeric: u16) -> Option<Currency> {
+    CURRENCY_MAP.values().find(|c| c.numeric == numeric).copied()
+}
+
+/// Returns the countries where the currency with the given alphabetic code
+/// is in legal use, in `ALL` order. ASCII case is ignored, as in
+/// `countries_speaking`.
+/// #Sample
+/// ```
+/// let countries = rust_iso3166::currency::countries_using("XCD");
+/// assert!(countries.contains(&rust_iso3166::AG));
+/// assert_eq!(countries, rust_iso3166::currency::countries_using("xcd"));
+/// ```
+pub fn countries_using(code: &str) -> Vec<CountryCode> {
+    let code = code.trim();
+    crate::ALL
+        .iter()
+        .filter(|country| {
+            country
+                .currencies()
+                .iter()
+                .any(|c| c.code.eq_ignore_ascii_case(code))
+        })
+        .copied()
+        .collect()
+}
+"""
+
+
+def currencies():
+    with open("%s/iso_4217.json" % ISO_CODES) as f:
+        data = {x["alpha_3"]: (x["name"], x["numeric"]) for x in json.load(f)["4217"]}
+    data.update(EXTRA)
+    return data
+
+
+def literal(code, data):
+    name, numeric = data[code]
+    return "Currency { code: \"%s\", numeric: %d, minor_units: %d, name: \"%s\" }" % (
+        code,
+        int(numeric),
+        MINOR_UNITS.get(code, 2),
+        name,
+    )
+
+
+def main():
+    data = currencies()
+    rows = [x.split("\t") for x in a.split("\n") if x]
+    used = sorted(set(c for _, codes in rows for c in codes.split()))
+
+    print(pre_code)
+    for code in used:
+        print("pub const %s: Currency = %s;" % (code, literal(code, data)))
+    print()
+    print("/// Currencies in legal use by alpha-3 code.")
+    print("pub const CURRENCY_MAP: Map<&str, Currency> = phf_map! {")
+    for code in used:
+        print("    \"%s\" => %s," % (code, code))
+    print("};")
+    print()
+    print("/// Currencies in legal use by country alpha-2 code.")
+    print("pub const COUNTRY_CURRENCY_MAP: Map<&str, &[Currency]> = phf_map! {")
+    for alpha2, codes in rows:
+        items = ", ".join(codes.split())
+        print("    \"%s\" => &[%s]," % (alpha2, items))
+    print("};")
+
+
+if __name__ == "__main__":
+    main()
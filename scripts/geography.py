@@ -0,0 +1,697 @@
+#!/usr/bin/env python
+# -*- coding: utf-8 -*-
+#
+# Generates src/geography.rs, the capitals, representative points and
+# bounding boxes of countries.
+#
+# Centroids are a representative point near the geographic center of the
+# main territory, following the Google DSPL countries table, so the United
+# States centers on the contiguous states and France on the metropole.
+# Bounding boxes cover the mainland and nearby islands; outlying island
+# groups such as Hawaii, the Canary Islands, the Galapagos or the Azores are
+# left out. A box with `west` greater than `east` crosses the antimeridian.
+# Capitals list the seat named by the constitution first, then any other
+# seat of government; where the capital is disputed the seat of government
+# is listed. Coordinates are in degrees (WGS 84); capitals and boxes are
+# rounded to two decimals.
+#
+# Usage: cd scripts && python3 geography.py > ../src/geography.rs
+import json
+
+ISO_CODES = "/usr/share/iso-codes/json"
+
+# alpha-2, centroid latitude, longitude, south, west, north, east
+a = """
+AD	42.546245	1.601554	42.43	1.41	42.66	1.79
+AE	23.424076	53.847818	22.63	51.50	26.08	56.38
+AF	33.93911	67.709953	29.38	60.50	38.49	74.89
+AG	17.060816	-61.796428	16.93	-62.35	17.73	-61.66
+AI	18.220554	-63.068615	18.16	-63.43	18.60	-62.92
+AL	41.153332	20.168331	39.64	19.26	42.66	21.06
+AM	40.069099	45.038189	38.84	43.45	41.30	46.63
+AO	-11.202692	17.873887	-18.04	11.64	-4.37	24.08
+AQ	-75.250973	-0.071389	-90.00	-180.00	-60.00	180.00
+AR	-38.416097	-63.616672	-55.06	-73.58	-21.78	-53.64
+AS	-14.270972	-170.132217	-14.60	-171.09	-11.05	-168.14
+AT	47.516231	14.550072	46.37	9.53	49.02	17.16
+AU	-25.274398	133.775136	-43.66	113.16	-10.05	153.64
+AW	12.52111	-69.968338	12.41	-70.06	12.63	-69.87
+AX	60.1785	19.9156	59.74	19.25	60.49	21.35
+AZ	40.143105	47.576927	38.39	44.77	41.91	50.63
+BA	43.915886	17.679076	42.56	15.72	45.28	19.62
+BB	13.193887	-59.543198	13.04	-59.65	13.34	-59.42
+BD	23.684994	90.356331	20.59	88.01	26.63	92.68
+BE	50.503887	4.469936	49.50	2.54	51.51	6.41
+BF	12.238333	-1.561593	9.40	-5.52	15.08	2.41
+BG	42.733883	25.48583	41.24	22.36	44.22	28.61
+BH	25.930414	50.637772	25.56	50.38	26.33	50.82
+BI	-3.373056	29.918886	-4.47	29.00	-2.31	30.85
+BJ	9.30769	2.315834	6.14	0.77	12.42	3.85
+BL	17.9	-62.83	17.87	-62.95	17.97	-62.79
+BM	32.321384	-64.75737	32.25	-64.89	32.39	-64.64
+BN	4.535277	114.727669	4.00	114.08	5.05	115.36
+BO	-16.290154	-63.588653	-22.90	-69.64	-9.68	-57.45
+BQ	12.18	-68.24	12.02	-68.42	17.65	-62.94
+BR	-14.235004	-51.92528	-33.75	-73.99	5.27	-34.79
+BS	25.03428	-77.39628	20.91	-80.48	27.26	-72.71
+BT	27.514162	90.433601	26.70	88.75	28.36	92.13
+BV	-54.423199	3.413194	-54.46	3.28	-54.38	3.48
+BW	-22.328474	24.684866	-26.91	19.99	-17.78	29.38
+BY	53.709807	27.953389	51.26	23.18	56.17	32.78
+BZ	17.189877	-88.49765	15.89	-89.23	18.50	-87.47
+CA	56.130366	-106.346771	41.68	-141.00	83.11	-52.62
+CC	-12.164165	96.870956	-12.21	96.81	-11.82	96.94
+CD	-4.038333	21.758664	-13.46	12.20	5.39	31.31
+CF	6.611111	20.939444	2.22	14.42	11.00	27.46
+CG	-0.228021	15.827659	-5.03	11.20	3.71	18.65
+CH	46.818188	8.227512	45.82	5.96	47.81	10.49
+CI	7.539989	-5.54708	4.36	-8.60	10.74	-2.49
+CK	-21.236736	-159.777671	-21.96	-165.85	-8.95	-157.31
+CL	-35.675147	-71.542969	-55.98	-75.64	-17.50	-66.42
+CM	7.369722	12.354722	1.65	8.49	13.08	16.19
+CN	35.86166	104.195397	18.16	73.50	53.56	134.77
+CO	4.570868	-74.297333	-4.23	-79.00	12.46	-66.87
+CR	9.748917	-83.753428	8.03	-85.95	11.22	-82.55
+CU	21.521757	-77.781167	19.83	-84.95	23.27	-74.13
+CV	16.002082	-24.013197	14.80	-25.36	17.21	-22.66
+CW	12.16957	-68.990021	12.03	-69.16	12.39	-68.74
+CX	-10.447525	105.690449	-10.57	105.53	-10.41	105.72
+CY	35.126413	33.429859	34.56	32.27	35.71	34.60
+CZ	49.817492	15.472962	48.55	12.09	51.06	18.86
+DE	51.165691	10.451526	47.27	5.87	55.06	15.04
+DJ	11.825138	42.590275	10.91	41.77	12.71	43.42
+DK	56.26392	9.501785	54.56	8.08	57.75	15.20
+DM	15.414999	-61.370976	15.20	-61.48	15.64	-61.24
+DO	18.735693	-70.162651	17.47	-72.00	19.93	-68.32
+DZ	28.033886	1.659626	18.96	-8.67	37.09	11.98
+EC	-1.831239	-78.183406	-5.01	-81.08	1.68	-75.19
+EE	58.595272	25.013607	57.52	21.76	59.68	28.21
+EG	26.820553	30.802498	22.00	24.70	31.67	36.90
+EH	24.215527	-12.885834	20.77	-17.10	27.67	-8.67
+ER	15.179384	39.782334	12.36	36.44	18.00	43.13
+ES	40.463667	-3.74922	35.27	-9.30	43.79	4.33
+ET	9.145	40.489673	3.40	32.99	14.89	47.99
+FI	61.92411	25.748151	59.81	20.55	70.09	31.59
+FJ	-16.578193	179.414413	-20.68	177.13	-15.70	-178.23
+FK	-51.796253	-59.523613	-52.40	-61.35	-51.04	-57.71
+FM	7.425554	150.550812	1.03	137.33	10.09	163.03
+FO	61.892635	-6.911806	61.39	-7.68	62.40	-6.26
+FR	46.227638	2.213749	41.33	-5.14	51.09	9.56
+GA	-0.803689	11.609444	-3.98	8.70	2.32	14.50
+GB	55.378051	-3.435973	49.86	-8.65	60.86	1.76
+GD	12.262776	-61.604171	11.98	-61.80	12.54	-61.38
+GE	42.315407	43.356892	41.05	40.01	43.59	46.74
+GF	3.933889	-53.125782	2.11	-54.60	5.78	-51.61
+GG	49.465691	-2.585278	49.40	-2.68	49.73	-2.16
+GH	7.946527	-1.023194	4.74	-3.26	11.17	1.19
+GI	36.137741	-5.345374	36.11	-5.37	36.16	-5.34
+GL	71.706936	-42.604303	59.78	-73.04	83.63	-11.31
+GM	13.443182	-15.310139	13.06	-16.82	13.83	-13.80
+GN	9.945587	-9.696645	7.19	-15.08	12.68	-7.64
+GP	16.25	-61.55	15.83	-61.81	16.52	-61.00
+GQ	1.650801	10.267895	-1.47	5.61	3.79	11.34
+GR	39.074208	21.824312	34.80	19.37	41.75	29.65
+GS	-54.429579	-36.587909	-59.48	-38.03	-53.97	-26.23
+GT	15.783471	-90.230759	13.74	-92.24	17.82	-88.22
+GU	13.444304	144.793731	13.24	144.62	13.65	145.01
+GW	11.803749	-15.180413	10.92	-16.71	12.69	-13.64
+GY	4.860416	-58.93018	1.18	-61.40	8.56	-56.48
+HK	22.396428	114.109497	22.15	113.84	22.56	114.44
+HM	-53.08181	73.504158	-53.19	72.58	-52.91	73.86
+HN	15.199999	-86.241905	12.98	-89.35	16.52	-83.13
+HR	45.1	15.2	42.39	13.49	46.55	19.45
+HT	18.971187	-72.285215	18.02	-74.48	20.09	-71.62
+HU	47.162494	19.503304	45.74	16.11	48.59	22.90
+ID	-0.789275	113.921327	-11.01	95.01	6.08	141.02
+IE	53.41291	-8.24389	51.42	-10.66	55.39	-5.99
+IL	31.046051	34.851612	29.49	34.27	33.28	35.68
+IM	54.236107	-4.548056	54.04	-4.83	54.42	-4.31
+IN	20.593684	78.96288	6.75	68.11	35.50	97.40
+IO	-6.343194	71.876519	-7.44	71.26	-5.23	72.49
+IQ	33.223191	43.679291	29.06	38.79	37.38	48.57
+IR	32.427908	53.688046	25.06	44.03	39.78	63.32
+IS	64.963051	-19.020835	63.29	-24.55	66.57	-13.50
+IT	41.87194	12.56738	35.49	6.63	47.09	18.52
+JE	49.214439	-2.13125	49.16	-2.26	49.27	-2.01
+JM	18.109581	-77.297508	17.70	-78.37	18.53	-76.18
+JO	30.585164	36.238414	29.19	34.96	33.37	39.30
+JP	36.204824	138.252924	24.04	122.93	45.52	145.82
+KE	-0.023559	37.906193	-4.68	33.91	5.03	41.91
+KG	41.20438	74.766098	39.17	69.25	43.24	80.28
+KH	12.565679	104.990963	9.91	102.34	14.69	107.63
+KI	-3.370417	-168.734039	-11.45	169.53	4.72	-150.22
+KM	-11.875001	43.872219	-12.42	43.22	-11.36	44.54
+KN	17.357822	-62.782998	17.09	-62.87	17.42	-62.54
+KP	40.339852	127.510093	37.67	124.18	43.01	130.68
+KR	35.907757	127.766922	33.11	124.61	38.61	131.87
+KW	29.31166	47.481766	28.52	46.55	30.10	48.43
+KY	19.513469	-80.566956	19.26	-81.43	19.76	-79.72
+KZ	48.019573	66.923684	40.57	46.49	55.44	87.31
+LA	19.85627	102.495496	13.91	100.08	22.50	107.64
+LB	33.854721	35.862285	33.05	35.10	34.69	36.62
+LC	13.909444	-60.978893	13.71	-61.08	14.11	-60.87
+LI	47.166	9.555373	47.05	9.47	47.27	9.64
+LK	7.873054	80.771797	5.92	79.52	9.83	81.88
+LR	6.428055	-9.429499	4.36	-11.49	8.55	-7.37
+LS	-29.609988	28.233608	-30.67	27.01	-28.57	29.46
+LT	55.169438	23.881275	53.90	20.93	56.45	26.84
+LU	49.815273	6.129583	49.45	5.73	50.18	6.53
+LV	56.879635	24.603189	55.67	20.97	58.08	28.24
+LY	26.3351	17.228331	19.50	9.39	33.17	25.15
+MA	31.791702	-7.09262	27.67	-13.17	35.92	-0.99
+MC	43.7384	7.4246	43.72	7.41	43.75	7.44
+MD	47.411631	28.369885	45.47	26.62	48.49	30.16
+ME	42.708678	19.37439	41.85	18.43	43.56	20.36
+MF	18.0708	-63.0501	18.05	-63.15	18.13	-62.97
+MG	-18.766947	46.869107	-25.61	43.22	-11.95	50.48
+MH	7.131474	171.184478	4.57	160.80	14.62	172.17
+MK	41.608635	21.745275	40.85	20.45	42.37	23.03
+ML	17.570692	-3.996166	10.16	-12.24	25.00	4.27
+MM	21.913965	95.956223	9.78	92.17	28.55	101.17
+MN	46.862496	103.846656	41.58	87.75	52.15	119.93
+MO	22.198745	113.543873	22.11	113.53	22.22	113.60
+MP	17.33083	145.38469	14.11	144.90	20.55	145.87
+MQ	14.641528	-61.024174	14.39	-61.23	14.88	-60.81
+MR	21.00789	-10.940835	14.72	-17.07	27.30	-4.83
+MS	16.742498	-62.187366	16.67	-62.24	16.82	-62.14
+MT	35.937496	14.375416	35.79	14.18	36.08	14.58
+MU	-20.348404	57.552152	-20.53	57.31	-19.98	57.81
+MV	3.202778	73.22068	-0.70	72.64	7.11	73.76
+MW	-13.254308	34.301525	-17.13	32.67	-9.37	35.92
+MX	23.634501	-102.552784	14.53	-117.13	32.72	-86.71
+MY	4.210484	101.975766	0.85	99.64	7.36	119.27
+MZ	-18.665695	35.529562	-26.87	30.22	-10.47	40.84
+NA	-22.95764	18.49041	-28.97	11.72	-16.96	25.26
+NC	-20.904305	165.618042	-22.70	163.57	-19.55	168.14
+NE	17.607789	8.081666	11.69	0.17	23.52	15.99
+NF	-29.040835	167.954712	-29.14	167.91	-28.99	168.00
+NG	9.081999	8.675277	4.27	2.67	13.89	14.68
+NI	12.865416	-85.207229	10.71	-87.69	15.03	-82.73
+NL	52.132633	5.291266	50.75	3.36	53.56	7.23
+NO	60.472024	8.468946	57.98	4.65	71.19	31.08
+NP	28.394857	84.124008	26.35	80.06	30.45	88.20
+NR	-0.522778	166.931503	-0.55	166.90	-0.50	166.96
+NU	-19.054445	-169.867233	-19.15	-169.95	-18.95	-169.77
+NZ	-40.900557	174.885971	-47.29	166.43	-34.39	178.57
+OM	21.512583	55.923255	16.65	52.00	26.40	59.84
+PA	8.537981	-80.782127	7.20	-83.05	9.65	-77.16
+PE	-9.189967	-75.015152	-18.35	-81.33	-0.04	-68.65
+PF	-17.679742	-149.406843	-27.65	-154.71	-7.90	-134.93
+PG	-6.314993	143.95555	-11.66	140.84	-0.87	159.49
+PH	12.879721	121.774017	4.59	116.93	21.12	126.60
+PK	30.375321	69.345116	23.69	60.87	37.08	77.84
+PL	51.919438	19.145136	49.00	14.12	54.84	24.15
+PM	46.941936	-56.27111	46.75	-56.42	47.14	-56.12
+PN	-24.703615	-127.439308	-25.08	-130.74	-23.92	-124.77
+PR	18.220833	-66.590149	17.88	-67.95	18.52	-65.22
+PS	31.952162	35.233154	31.22	34.22	32.55	35.57
+PT	39.399872	-8.224454	36.96	-9.50	42.15	-6.19
+PW	7.51498	134.58252	2.80	131.12	8.10	134.73
+PY	-23.442503	-58.443832	-27.61	-62.65	-19.29	-54.26
+QA	25.354826	51.183884	24.47	50.75	26.18	51.64
+RE	-21.115141	55.536384	-21.39	55.22	-20.87	55.84
+RO	45.943161	24.96676	43.62	20.26	48.27	29.72
+RS	44.016521	21.005859	41.86	18.82	46.19	23.01
+RU	61.52401	105.318756	41.19	19.64	81.86	-169.05
+RW	-1.940278	29.873888	-2.84	28.86	-1.05	30.90
+SA	23.885942	45.079162	16.38	34.50	32.16	55.67
+SB	-9.64571	160.156194	-11.85	155.51	-6.59	167.29
+SC	-4.679574	55.491977	-10.23	46.20	-3.71	56.29
+SD	15.6	30.2	8.68	21.81	22.23	38.61
+SE	60.128161	18.643501	55.34	11.03	69.06	24.17
+SG	1.352083	103.819836	1.16	103.60	1.47	104.09
+SH	-15.96	-5.71	-16.02	-5.79	-15.90	-5.64
+SI	46.151241	14.995463	45.42	13.38	46.88	16.61
+SJ	77.553604	23.670272	74.34	10.49	80.83	33.64
+SK	48.669026	19.699024	47.73	16.83	49.61	22.57
+SL	8.460555	-11.779889	6.92	-13.31	10.00	-10.27
+SM	43.94236	12.457777	43.89	12.40	43.99	12.52
+SN	14.497401	-14.452362	12.31	-17.54	16.69	-11.35
+SO	5.152149	46.199616	-1.66	40.98	11.99	51.41
+SR	3.919305	-56.027783	1.83	-58.07	6.01	-53.98
+SS	7.86	29.69	3.49	23.44	12.24	35.95
+ST	0.18636	6.613081	-0.01	6.46	1.70	7.47
+SV	13.794185	-88.89653	13.15	-90.13	14.45	-87.69
+SX	18.04248	-63.05483	18.01	-63.14	18.06	-63.01
+SY	34.802075	38.996815	32.31	35.71	37.32	42.38
+SZ	-26.522503	31.465866	-27.32	30.79	-25.72	32.14
+TC	21.694025	-71.797928	21.18	-72.48	21.96	-71.07
+TD	15.454166	18.732207	7.44	13.47	23.45	24.00
+TF	-49.280366	69.348557	-49.73	68.72	-48.46	70.57
+TG	8.619543	0.824782	6.10	-0.15	11.14	1.81
+TH	15.870032	100.992541	5.61	97.34	20.46	105.64
+TJ	38.861034	71.276093	36.67	67.34	41.04	75.15
+TK	-8.967363	-171.855881	-9.44	-172.52	-8.53	-171.18
+TL	-8.874217	125.727539	-9.50	124.04	-8.13	127.34
+TM	38.969719	59.556278	35.13	52.44	42.80	66.71
+TN	33.886917	9.537499	30.24	7.52	37.54	11.60
+TO	-21.178986	-175.198242	-22.35	-176.22	-15.56	-173.70
+TR	38.963745	35.243322	35.82	25.66	42.11	44.82
+TT	10.691803	-61.222503	10.04	-61.93	11.36	-60.49
+TV	-7.109535	177.64933	-10.80	176.06	-5.64	179.91
+TW	23.69781	120.960515	21.90	119.31	25.30	122.01
+TZ	-6.369028	34.888822	-11.75	29.33	-0.99	40.44
+UA	48.379433	31.16558	44.39	22.14	52.38	40.23
+UG	1.373333	32.290275	-1.48	29.57	4.23	35.04
+UM	19.28	166.65	-0.39	166.61	28.42	-160.02
+US	37.09024	-95.712891	24.52	172.44	71.39	-66.95
+UY	-32.522779	-55.765835	-34.97	-58.44	-30.09	-53.09
+UZ	41.377491	64.585262	37.18	55.99	45.59	73.13
+VA	41.902916	12.453389	41.90	12.45	41.91	12.46
+VC	12.984305	-61.287228	12.58	-61.46	13.38	-61.11
+VE	6.42375	-66.58973	0.65	-73.35	12.20	-59.80
+VG	18.420695	-64.639968	18.31	-64.85	18.75	-64.27
+VI	18.335765	-64.896335	17.68	-65.09	18.42	-64.56
+VN	14.058324	108.277199	8.41	102.14	23.39	109.47
+VU	-15.376706	166.959158	-20.25	166.52	-13.07	170.24
+WF	-13.768752	-177.156097	-14.36	-178.21	-13.18	-176.12
+WS	-13.759029	-172.104629	-14.08	-172.80	-13.43	-171.40
+YE	15.552727	48.516388	12.11	42.55	19.00	53.11
+YT	-12.8275	45.166244	-13.00	45.01	-12.64	45.30
+ZA	-30.559482	22.937506	-34.84	16.46	-22.13	32.89
+ZM	-13.133897	27.849332	-18.08	21.99	-8.22	33.71
+ZW	-19.015438	29.154857	-22.42	25.24	-15.61	33.06
+"""
+
+# alpha-2, capital, latitude, longitude; one row per capital, constitutional
+# capital first
+c = """
+AD	Andorra la Vella	42.51	1.52
+AE	Abu Dhabi	24.45	54.38
+AF	Kabul	34.53	69.17
+AG	Saint John's	17.12	-61.85
+AI	The Valley	18.22	-63.06
+AL	Tirana	41.33	19.82
+AM	Yerevan	40.18	44.51
+AO	Luanda	-8.84	13.23
+AR	Buenos Aires	-34.60	-58.38
+AS	Pago Pago	-14.28	-170.70
+AT	Vienna	48.21	16.37
+AU	Canberra	-35.28	149.13
+AW	Oranjestad	12.52	-70.03
+AX	Mariehamn	60.10	19.94
+AZ	Baku	40.41	49.87
+BA	Sarajevo	43.86	18.41
+BB	Bridgetown	13.10	-59.62
+BD	Dhaka	23.81	90.41
+BE	Brussels	50.85	4.35
+BF	Ouagadougou	12.37	-1.52
+BG	Sofia	42.70	23.32
+BH	Manama	26.23	50.59
+BI	Gitega	-3.43	29.93
+BI	Bujumbura	-3.38	29.36
+BJ	Porto-Novo	6.50	2.60
+BJ	Cotonou	6.37	2.39
+BL	Gustavia	17.90	-62.85
+BM	Hamilton	32.29	-64.78
+BN	Bandar Seri Begawan	4.90	114.94
+BO	Sucre	-19.05	-65.26
+BO	La Paz	-16.50	-68.15
+BQ	Kralendijk	12.15	-68.27
+BR	Brasília	-15.79	-47.88
+BS	Nassau	25.05	-77.35
+BT	Thimphu	27.47	89.64
+BW	Gaborone	-24.65	25.91
+BY	Minsk	53.90	27.56
+BZ	Belmopan	17.25	-88.77
+CA	Ottawa	45.42	-75.70
+CC	West Island	-12.19	96.83
+CD	Kinshasa	-4.32	15.31
+CF	Bangui	4.39	18.56
+CG	Brazzaville	-4.27	15.28
+CH	Bern	46.95	7.45
+CI	Yamoussoukro	6.82	-5.28
+CI	Abidjan	5.36	-4.01
+CK	Avarua	-21.21	-159.78
+CL	Santiago	-33.45	-70.67
+CM	Yaoundé	3.85	11.50
+CN	Beijing	39.90	116.41
+CO	Bogotá	4.71	-74.07
+CR	San José	9.93	-84.09
+CU	Havana	23.11	-82.37
+CV	Praia	14.93	-23.51
+CW	Willemstad	12.11	-68.93
+CX	Flying Fish Cove	-10.42	105.68
+CY	Nicosia	35.19	33.38
+CZ	Prague	50.08	14.44
+DE	Berlin	52.52	13.40
+DJ	Djibouti	11.59	43.15
+DK	Copenhagen	55.68	12.57
+DM	Roseau	15.30	-61.39
+DO	Santo Domingo	18.49	-69.93
+DZ	Algiers	36.75	3.06
+EC	Quito	-0.18	-78.47
+EE	Tallinn	59.44	24.75
+EG	Cairo	30.04	31.24
+EH	Laayoune	27.15	-13.20
+ER	Asmara	15.32	38.93
+ES	Madrid	40.42	-3.70
+ET	Addis Ababa	9.03	38.74
+FI	Helsinki	60.17	24.94
+FJ	Suva	-18.14	178.44
+FK	Stanley	-51.69	-57.86
+FM	Palikir	6.92	158.16
+FO	Tórshavn	62.01	-6.77
+FR	Paris	48.86	2.35
+GA	Libreville	0.42	9.47
+GB	London	51.51	-0.13
+GD	Saint George's	12.06	-61.75
+GE	Tbilisi	41.72	44.78
+GF	Cayenne	4.94	-52.33
+GG	Saint Peter Port	49.46	-2.54
+GH	Accra	5.60	-0.19
+GI	Gibraltar	36.14	-5.35
+GL	Nuuk	64.18	-51.72
+GM	Banjul	13.45	-16.58
+GN	Conakry	9.64	-13.58
+GP	Basse-Terre	16.00	-61.73
+GQ	Malabo	3.75	8.78
+GR	Athens	37.98	23.73
+GS	King Edward Point	-54.28	-36.49
+GT	Guatemala City	14.63	-90.51
+GU	Hagåtña	13.48	144.75
+GW	Bissau	11.86	-15.60
+GY	Georgetown	6.80	-58.16
+HN	Tegucigalpa	14.07	-87.19
+HR	Zagreb	45.81	15.98
+HT	Port-au-Prince	18.54	-72.34
+HU	Budapest	47.50	19.04
+ID	Jakarta	-6.21	106.85
+IE	Dublin	53.35	-6.26
+IL	Jerusalem	31.77	35.21
+IM	Douglas	54.15	-4.48
+IN	New Delhi	28.61	77.21
+IQ	Baghdad	33.31	44.36
+IR	Tehran	35.69	51.39
+IS	Reykjavík	64.15	-21.94
+IT	Rome	41.90	12.50
+JE	Saint Helier	49.19	-2.11
+JM	Kingston	18.02	-76.80
+JO	Amman	31.95	35.93
+JP	Tokyo	35.68	139.69
+KE	Nairobi	-1.29	36.82
+KG	Bishkek	42.87	74.59
+KH	Phnom Penh	11.56	104.92
+KI	South Tarawa	1.33	172.98
+KM	Moroni	-11.70	43.26
+KN	Basseterre	17.30	-62.72
+KP	Pyongyang	39.04	125.76
+KR	Seoul	37.57	126.98
+KW	Kuwait City	29.38	47.99
+KY	George Town	19.29	-81.38
+KZ	Astana	51.17	71.45
+LA	Vientiane	17.98	102.63
+LB	Beirut	33.89	35.50
+LC	Castries	14.01	-60.99
+LI	Vaduz	47.14	9.52
+LK	Sri Jayawardenepura Kotte	6.89	79.90
+LK	Colombo	6.93	79.86
+LR	Monrovia	6.30	-10.80
+LS	Maseru	-29.31	27.48
+LT	Vilnius	54.69	25.28
+LU	Luxembourg	49.61	6.13
+LV	Riga	56.95	24.11
+LY	Tripoli	32.89	13.19
+MA	Rabat	34.02	-6.83
+MC	Monaco	43.73	7.42
+MD	Chișinău	47.01	28.86
+ME	Podgorica	42.44	19.26
+MF	Marigot	18.07	-63.08
+MG	Antananarivo	-18.88	47.51
+MH	Majuro	7.09	171.38
+MK	Skopje	42.00	21.43
+ML	Bamako	12.64	-8.00
+MM	Naypyidaw	19.76	96.08
+MN	Ulaanbaatar	47.89	106.91
+MP	Saipan	15.19	145.75
+MQ	Fort-de-France	14.60	-61.07
+MR	Nouakchott	18.09	-15.98
+MS	Plymouth	16.71	-62.22
+MS	Brades	16.79	-62.21
+MT	Valletta	35.90	14.51
+MU	Port Louis	-20.16	57.50
+MV	Malé	4.18	73.51
+MW	Lilongwe	-13.96	33.79
+MX	Mexico City	19.43	-99.13
+MY	Kuala Lumpur	3.15	101.69
+MY	Putrajaya	2.93	101.69
+MZ	Maputo	-25.97	32.57
+NA	Windhoek	-22.56	17.08
+NC	Nouméa	-22.28	166.46
+NE	Niamey	13.51	2.11
+NF	Kingston	-29.05	167.96
+NG	Abuja	9.08	7.40
+NI	Managua	12.11	-86.24
+NL	Amsterdam	52.37	4.90
+NL	The Hague	52.08	4.30
+NO	Oslo	59.91	10.75
+NP	Kathmandu	27.72	85.32
+NR	Yaren	-0.55	166.92
+NU	Alofi	-19.06	-169.92
+NZ	Wellington	-41.29	174.78
+OM	Muscat	23.59	58.41
+PA	Panama City	8.98	-79.52
+PE	Lima	-12.05	-77.04
+PF	Papeete	-17.54	-149.57
+PG	Port Moresby	-9.44	147.18
+PH	Manila	14.60	120.98
+PK	Islamabad	33.68	73.05
+PL	Warsaw	52.23	21.01
+PM	Saint-Pierre	46.78	-56.18
+PN	Adamstown	-25.07	-130.10
+PR	San Juan	18.47	-66.11
+PS	Ramallah	31.90	35.20
+PT	Lisbon	38.72	-9.14
+PW	Ngerulmud	7.50	134.62
+PY	Asunción	-25.26	-57.58
+QA	Doha	25.29	51.53
+RE	Saint-Denis	-20.88	55.45
+RO	Bucharest	44.43	26.10
+RS	Belgrade	44.79	20.45
+RU	Moscow	55.76	37.62
+RW	Kigali	-1.94	30.06
+SA	Riyadh	24.71	46.68
+SB	Honiara	-9.43	159.96
+SC	Victoria	-4.62	55.45
+SD	Khartoum	15.50	32.56
+SE	Stockholm	59.33	18.07
+SG	Singapore	1.29	103.85
+SH	Jamestown	-15.93	-5.72
+SI	Ljubljana	46.06	14.51
+SJ	Longyearbyen	78.22	15.65
+SK	Bratislava	48.15	17.11
+SL	Freetown	8.48	-13.23
+SM	San Marino	43.94	12.45
+SN	Dakar	14.72	-17.47
+SO	Mogadishu	2.05	45.32
+SR	Paramaribo	5.85	-55.20
+SS	Juba	4.85	31.58
+ST	São Tomé	0.34	6.73
+SV	San Salvador	13.69	-89.22
+SX	Philipsburg	18.03	-63.05
+SY	Damascus	33.51	36.29
+SZ	Mbabane	-26.31	31.14
+SZ	Lobamba	-26.45	31.21
+TC	Cockburn Town	21.46	-71.14
+TD	N'Djamena	12.13	15.06
+TF	Port-aux-Français	-49.35	70.22
+TG	Lomé	6.13	1.22
+TH	Bangkok	13.76	100.50
+TJ	Dushanbe	38.56	68.79
+TL	Dili	-8.56	125.57
+TM	Ashgabat	37.95	58.38
+TN	Tunis	36.81	10.18
+TO	Nukuʻalofa	-21.14	-175.20
+TR	Ankara	39.93	32.86
+TT	Port of Spain	10.65	-61.51
+TV	Funafuti	-8.52	179.20
+TW	Taipei	25.03	121.57
+TZ	Dodoma	-6.16	35.75
+TZ	Dar es Salaam	-6.79	39.21
+UA	Kyiv	50.45	30.52
+UG	Kampala	0.35	32.58
+US	Washington	38.90	-77.04
+UY	Montevideo	-34.90	-56.16
+UZ	Tashkent	41.30	69.24
+VA	Vatican City	41.90	12.45
+VC	Kingstown	13.16	-61.22
+VE	Caracas	10.48	-66.90
+VG	Road Town	18.43	-64.62
+VI	Charlotte Amalie	18.34	-64.93
+VN	Hanoi	21.03	105.85
+VU	Port Vila	-17.73	168.32
+WF	Mata-Utu	-13.28	-176.17
+WS	Apia	-13.83	-171.76
+YE	Sanaa	15.37	44.19
+YT	Mamoudzou	-12.78	45.23
+ZA	Pretoria	-25.75	28.19
+ZA	Cape Town	-33.92	18.42
+ZA	Bloemfontein	-29.12	26.21
+ZM	Lusaka	-15.39	28.32
+ZW	Harare	-17.83	31.05
+"""
+
+pre_code = """use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+
+/// Mean radius of the Earth in kilometres (IUGG).
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// A point on the Earth, in degrees (WGS 84).
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Coordinates {
+    ///Latitude, positive north of the equator
+    pub latitude: f64,
+    ///Longitude, positive east of Greenwich
+    pub longitude: f64,
+}
+
+/// A capital city.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Capital {
+    ///English name, such as `Pretoria`
+    pub name: &'static str,
+    ///Location of the city centre
+    pub coordinates: Coordinates,
+}
+
+/// The extent of a country, in degrees. `west` is greater than `east` when
+/// the box crosses the antimeridian, as for Fiji, Kiribati, Russia, the
+/// United States Minor Outlying Islands and the United States.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BoundingBox {
+    ///Southernmost latitude
+    pub south: f64,
+    ///Westernmost longitude
+    pub west: f64,
+    ///Northernmost latitude
+    pub north: f64,
+    ///Easternmost longitude
+    pub east: f64,
+}
+
+impl Coordinates {
+    ///Return the great-circle distance to `other` in kilometres, using the
+    ///haversine formula on a spherical Earth
+    pub fn distance_km(&self, other: &Coordinates) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let dlat = lat2 - lat1;
+        let dlon = (other.longitude - self.longitude).to_radians();
+        let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+    }
+}
+
+impl BoundingBox {
+    ///Return true if the box crosses the antimeridian (180° longitude)
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+
+    ///Return true if the point lies inside the box, edges included
+    pub fn contains(&self, point: &Coordinates) -> bool {
+        let longitude = if self.crosses_antimeridian() {
+            point.longitude >= self.west || point.longitude <= self.east
+        } else {
+            (self.west..=self.east).contains(&point.longitude)
+        };
+        longitude && (self.south..=self.north).contains(&point.latitude)
+    }
+}
+
+impl CountryCode {
+    ///Return the capitals, the constitutional capital first. Most countries
+    ///have one; South Africa lists Pretoria, Cape Town and Bloemfontein and
+    ///Bolivia lists Sucre and La Paz. Empty for territories without a
+    ///capital, such as Antarctica, Hong Kong or Tokelau.
+    pub fn capital(&self) -> &'static [Capital] {
+        CAPITAL_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+
+    ///Return a representative point near the geographic centre of the main
+    ///territory, suitable for centering a map. For the United States it is
+    ///in the contiguous states, for France in the metropole.
+    pub fn centroid(&self) -> Option<Coordinates> {
+        CENTROID_MAP.get(self.alpha2).copied()
+    }
+
+    ///Return the bounding box of the mainland and nearby islands, suitable
+    ///for zooming a map. Outlying island groups such as Hawaii or the Canary
+    ///Islands are left out.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        BOUNDING_BOX_MAP.get(self.alpha2).copied()
+    }
+}
+
+/// Returns the great-circle distance between the centroids of two countries
+/// in kilometres, if both have one. The distance between neighbours can
+/// exceed the one between their capitals or borders by far, so it only
+/// suits rough estimates.
+/// #Sample
+/// ```
+/// let km = rust_iso3166::distance_km(rust_iso3166::FR, rust_iso3166::DE).unwrap();
+/// assert!((800.0..900.0).contains(&km));
+/// assert_eq!(Some(0.0), rust_iso3166::distance_km(rust_iso3166::FR, rust_iso3166::FR));
+/// assert_eq!("Sucre", rust_iso3166::BO.capital()[0].name);
+/// ```
+pub fn distance_km(a: CountryCode, b: CountryCode) -> Option<f64> {
+    Some(a.centroid()?.distance_km(&b.centroid()?))
+}
+"""
+
+
+def coordinates(latitude, longitude):
+    return "Coordinates { latitude: %s, longitude: %s }" % (number(latitude), number(longitude))
+
+
+def number(value):
+    return value if "." in value else value + ".0"
+
+
+def main():
+    with open("%s/iso_3166-1.json" % ISO_CODES) as f:
+        countries = sorted(x["alpha_2"] for x in json.load(f)["3166-1"])
+    rows = {}
+    for x in a.split("\n"):
+        if x:
+            alpha2, *values = x.split("\t")
+            rows[alpha2] = values
+    capitals = {}
+    for x in c.split("\n"):
+        if x:
+            alpha2, name, latitude, longitude = x.split("\t")
+            capitals.setdefault(alpha2, []).append((name, latitude, longitude))
+    assert sorted(rows) == countries, set(countries) ^ set(rows)
+    assert set(capitals) <= set(countries), set(capitals) - set(countries)
+
+    print(pre_code)
+    print("/// Capitals by alpha-2 code.")
+    print("pub const CAPITAL_MAP: Map<&str, &[Capital]> = phf_map! {")
+    for alpha2, cities in capitals.items():
+        literals = [
+            "Capital { name: \"%s\", coordinates: %s }" % (name, coordinates(lat, lon))
+            for name, lat, lon in cities
+        ]
+        print("    \"%s\" => &[%s]," % (alpha2, ", ".join(literals)))
+    print("};")
+    print()
+    print("/// Representative points by alpha-2 code.")
+    print("pub const CENTROID_MAP: Map<&str, Coordinates> = phf_map! {")
+    for alpha2, values in rows.items():
+        print("    \"%s\" => %s," % (alpha2, coordinates(values[0], values[1])))
+    print("};")
+    print()
+    print("/// Bounding boxes by alpha-2 code.")
+    print("pub const BOUNDING_BOX_MAP: Map<&str, BoundingBox> = phf_map! {")
+    for alpha2, values in rows.items():
+        south, west, north, east = [number(v) for v in values[2:]]
+        print(
+            "    \"%s\" => BoundingBox { south: %s, west: %s, north: %s, east: %s },"
+            % (alpha2, south, west, north, east)
+        )
+    print("};")
+
+
+if __name__ == "__main__":
+    main()
@@ -102,6 +102,19 @@ pub fn from_code(code: &str) -> Option<Subdivision> {
     SUBDIVISION_MAP.get(code).cloned()
 }
 
+/// Returns the Subdivision with the given code, if exists, ignoring case and
+/// surrounding whitespace and accepting `_` or a space in place of the hyphen.
+/// Does not allocate.
+/// #Sample
+/// ```
+/// let sub = rust_iso3166::iso3166_2::from_code_ignore_case(" se_o ");
+/// assert_eq!("SE-O", sub.unwrap().code);
+/// ```
+#[cfg_attr(all(direct_wasm,target_arch = "wasm32"), wasm_bindgen(js_name = from_code_ignore_case_iso_3166_2))]
+pub fn from_code_ignore_case(code: &str) -> Option<Subdivision> {
+    crate::parse::with_lenient::<6, _>(code, |code| SUBDIVISION_MAP.get(code).cloned())
+}
+
 #[cfg(feature = "serde")]
 impl Serialize for Subdivision {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -120,8 +133,7 @@ impl<'de> Deserialize<'de> for Subdivision {
     {
         use serde::de::Error;
         let s = String::deserialize(deserializer)?;
-        let s_upper = s.to_uppercase();
-        from_code(&s_upper)
+        crate::parse::with_upper::<6, _>(&s, from_code)
             .ok_or_else(|| D::Error::custom(format!("Invalid ISO 3166-2 code: {}", s)))
     }
 }
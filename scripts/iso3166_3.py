@@ -3,6 +3,44 @@
 import csv
 import re
 
+from locales import catalog, clean, iso_names
+
+
+def country_names():
+    # alpha-2 => (English full name, French short name), from lib.py's table
+    names = {}
+    for line in open("lib.py", encoding="utf-8"):
+        ts = line.rstrip("\n").split("\t")
+        if len(ts) == 8 and ts[4].startswith("ISO 3166-2:"):
+            names[ts[1]] = (ts[6], ts[7])
+    return names
+
+
+CURRENT = country_names()
+
+# English full names of the former states. ISO 3166-3 gives only the
+# short, sometimes inverted, name ("Zaire, Republic of").
+FORMER_OFFICIAL = {
+    "BUMM": "Socialist Republic of the Union of Burma",
+    "BYAA": "Byelorussian Soviet Socialist Republic",
+    "CSHH": "Czechoslovak Socialist Republic",
+    "DDDE": "German Democratic Republic",
+    "DYBJ": "Republic of Dahomey",
+    "HVBF": "Republic of Upper Volta",
+    "SUHH": "Union of Soviet Socialist Republics",
+    "VDVN": "Democratic Republic of Viet-Nam",
+    "YDYE": "People's Democratic Republic of Yemen",
+    "YUCS": "Socialist Federal Republic of Yugoslavia",
+    "ZRCD": "Republic of Zaire",
+}
+
+
+def option(s):
+    return 'Some("%s")' % s if s else "None"
+
+FORMER_FR = catalog("fr", "iso_3166-3")
+FORMER_EN = iso_names("3166-3", "alpha_4")
+
 pre_code = """
 use phf::phf_map;
 use phf::Map;
@@ -135,6 +173,8 @@ pub const %s: CountryCode3 = CountryCode3 {
         alpha2: "%s",
         alpha3: "%s",
         numeric: %s,
+        official_name: %s,
+        name_fr: "%s",
     },
     validity: &[%s,%s],
     desc: "%s",
@@ -148,6 +188,8 @@ pub const %s: CountryCode3 = CountryCode3 {
             former_alpha2,
             former_alpha3,
             former_numeric,
+            option(FORMER_OFFICIAL.get(code)),
+            clean(FORMER_FR.get(FORMER_EN.get(code)) or name),
             validity_from,
             validity_to,
             desc,
@@ -166,14 +208,19 @@ pub const %s: CountryCode3 = CountryCode3 {
             .replace("Divided into:", "")
             .strip()
         )
+        # Successors that were themselves withdrawn, such as Serbia and
+        # Montenegro, are only in the ISO 3166-3 catalog.
+        full, fr = CURRENT.get(c_alpha2) or ("", clean(FORMER_FR.get(c_name) or c_name))
         print(
             """ CountryCode {
         name: "%s",
         alpha2: "%s",
         alpha3: "%s",
         numeric: %s,
+        official_name: %s,
+        name_fr: "%s",
 },"""
-            % (c_name, c_alpha2, c_alpha3, c_numeric)
+            % (c_name, c_alpha2, c_alpha3, c_numeric, option(full), fr)
         )
     print(
         """
@@ -195,6 +242,18 @@ pub fn from_code(alpha4: &str) -> Option<CountryCode3> {
     ALPHA4_MAP.get(alpha4).cloned()
 }
 
+/// Returns the CountryCode3 with the given Alpha4 code, if exists, ignoring
+/// case and surrounding whitespace. Does not allocate.
+/// #Sample
+/// ```
+/// let sub = rust_iso3166::iso3166_3::from_code_ignore_case("pzpa ");
+/// assert_eq!("Panama Canal Zone", sub.unwrap().name);
+/// ```
+#[cfg_attr(all(direct_wasm,target_arch = "wasm32"), wasm_bindgen(js_name = from_code_ignore_case_iso_3166_3))]
+pub fn from_code_ignore_case(alpha4: &str) -> Option<CountryCode3> {
+    crate::parse::with_lenient::<4, _>(alpha4, |code| ALPHA4_MAP.get(code).cloned())
+}
+
 #[cfg(feature = "serde")]
 impl Serialize for CountryCode3 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -213,8 +272,7 @@ impl<'de> Deserialize<'de> for CountryCode3 {
     {
         use serde::de::Error;
         let s = String::deserialize(deserializer)?;
-        let s_upper = s.to_uppercase();
-        from_code(&s_upper)
+        crate::parse::with_upper::<4, _>(&s, from_code)
             .ok_or_else(|| D::Error::custom(format!("Invalid ISO 3166-3 code: {}", s)))
     }
 }
@@ -0,0 +1,1891 @@
+#!/usr/bin/env python
+# -*- coding: utf-8 -*-
+#
+# Generates src/language.rs. Territory populations and the share of each
+# territory's population speaking a language (as a first or second language)
+# come from CLDR's territoryInfo (via ICU), together with the official status
+# of the language. Script variants such as zh_Hant or sr_Latn are merged into
+# their language. Unofficial languages are kept when at least
+# WIDELY_SPOKEN percent of the population speaks them. Language names and
+# ISO 639-3 codes come from the iso-codes JSON data.
+#
+# Usage: cd scripts && python3 language.py > ../src/language.rs
+import json
+import re
+
+ISO_CODES = "/usr/share/iso-codes/json"
+
+# Minimum share of the population, in percent, for an unofficial language
+WIDELY_SPOKEN = 10
+
+STATUS = {
+    "official": "Official",
+    "de_facto_official": "DeFactoOfficial",
+    "official_regional": "OfficialRegional",
+    "": "Unofficial",
+}
+
+# alpha-2, population
+p = """
+AD	77000
+AE	9992080
+AF	36643800
+AG	98179
+AI	18090
+AL	3074580
+AM	3021320
+AO	32522300
+AQ	300
+AR	45479100
+AS	49437
+AT	8859450
+AU	25466500
+AW	119428
+AX	26200
+AZ	10205800
+BA	3835590
+BB	294560
+BD	162651000
+BE	11720700
+BF	20835400
+BG	6966900
+BH	1505000
+BI	11865800
+BJ	12864600
+BL	7122
+BM	71750
+BN	464478
+BO	11639900
+BQ	20000
+BR	211716000
+BS	337721
+BT	782318
+BV	1
+BW	2317230
+BY	9477920
+BZ	399598
+CA	37694100
+CC	596
+CD	101780000
+CF	5990860
+CG	5293070
+CH	8403990
+CI	27481100
+CK	8574
+CL	18186800
+CM	27745000
+CN	1394020000
+CO	49084800
+CR	5097990
+CU	11059100
+CV	583255
+CW	151345
+CX	2205
+CY	1266680
+CZ	10702500
+DE	80159700
+DJ	921804
+DK	5869410
+DM	74243
+DO	10499700
+DZ	42972900
+EC	16904900
+EE	1228620
+EG	104124000
+EH	652271
+ER	6081200
+ES	50015800
+ET	108113000
+FI	5571670
+FJ	935974
+FK	3198
+FM	102436
+FO	51628
+FR	67848200
+GA	2230910
+GB	65761100
+GD	113094
+GE	3997000
+GF	199509
+GG	67052
+GH	29340200
+GI	29581
+GL	57616
+GM	2174000
+GN	12527400
+GP	452776
+GQ	836178
+GR	10607100
+GS	20
+GT	17153300
+GU	168485
+GW	1927100
+GY	750204
+HK	7249910
+HM	1
+HN	9235340
+HR	4227750
+HT	11067800
+HU	9771830
+ID	267026000
+IE	5176570
+IL	8675480
+IM	90499
+IN	1326090000
+IO	3500
+IQ	38872700
+IR	84923300
+IS	350734
+IT	62402700
+JE	101073
+JM	2808570
+JO	10820600
+JP	125507000
+KE	53527900
+KG	5964900
+KH	16927000
+KI	111796
+KM	846281
+KN	53821
+KP	25643500
+KR	51835100
+KW	2993710
+KY	61944
+KZ	19091900
+LA	7447400
+LB	5469610
+LC	166487
+LI	39137
+LK	22889200
+LR	5073300
+LS	1969330
+LT	2731460
+LU	628381
+LV	1881230
+LY	6890540
+MA	35561700
+MC	39000
+MD	3364500
+ME	609859
+MF	32556
+MG	26955700
+MH	77917
+MK	2125970
+ML	19553400
+MM	56590100
+MN	3168030
+MO	614458
+MP	51433
+MQ	436131
+MR	4005480
+MS	5373
+MT	457267
+MU	1379370
+MV	391904
+MW	21196600
+MX	128650000
+MY	32652100
+MZ	30098200
+NA	2630070
+NC	290009
+NE	22772400
+NF	1748
+NG	214028000
+NI	6203440
+NL	17280400
+NO	5467440
+NP	30327900
+NR	11000
+NU	2000
+NZ	4925480
+OM	4664840
+PA	3894080
+PE	31915000
+PF	295121
+PG	7259460
+PH	109181000
+PK	233501000
+PL	38282300
+PM	5347
+PN	50
+PR	3189070
+PS	4818260
+PT	10302700
+PW	21685
+PY	7191690
+QA	2444170
+RE	787584
+RO	21302900
+RS	7012170
+RU	141722000
+RW	12712400
+SA	34173500
+SB	685097
+SC	95981
+SD	45561600
+SE	10202500
+SG	6209660
+SH	7862
+SI	2102680
+SJ	2926
+SK	5440600
+SL	6624930
+SM	34232
+SN	15736400
+SO	11757100
+SR	609569
+SS	10561200
+ST	211122
+SV	6481100
+SX	43847
+SY	19398400
+SZ	1104480
+TC	55926
+TD	16877400
+TF	140
+TG	8608440
+TH	68977400
+TJ	8873670
+TK	1647
+TL	1383720
+TM	5528630
+TN	11721200
+TO	106095
+TR	82017500
+TT	1208790
+TV	11342
+TW	23603000
+TZ	58552800
+UA	43922900
+UG	43253000
+UM	316
+US	332639000
+UY	3387610
+UZ	30565400
+VA	1000
+VC	101390
+VE	28644600
+VG	37381
+VI	106235
+VN	98721300
+VU	298333
+WF	15854
+WS	203774
+YE	29884400
+YT	194000
+ZA	56463600
+ZM	17426600
+ZW	14546300
+"""
+
+# alpha-2, CLDR language, percent of the population speaking it, official status
+l = """
+AD	ca	51	official
+AD	es	43
+AD	fr	7.5
+AE	ar	78	official
+AE	bal	2.3
+AE	en	50
+AE	fa	1.9
+AE	ml	7
+AE	ps	2.9
+AF	bgn	0.63
+AF	fa	50	official
+AF	haz	5.9
+AF	kk_Arab	0.0055
+AF	prd	1.2
+AF	ps	43	official
+AF	tk	1.7	official_regional
+AF	ug	0.0082
+AF	uz_Arab	4.7	official_regional
+AG	en	86	official
+AG	pt	1.6
+AI	en	95	official
+AL	el	1.9
+AL	mk	0.47
+AL	sq	100	official
+AM	az	0
+AM	hy	98	official
+AM	ku	3.3
+AO	kmb	25
+AO	ln	0.67
+AO	pt	67	official
+AO	umb	29
+AR	cy	0.066
+AR	en	7
+AR	es	100	official
+AR	gn	0.047
+AS	en	97	de_facto_official
+AS	sm	99	official
+AT	bar	95
+AT	de	97	official
+AT	en	73
+AT	fr	11
+AT	hr	1.2	official_regional
+AT	hu	0.26	official_regional
+AT	it	9
+AT	sl	0.37	official_regional
+AU	en	96	de_facto_official
+AU	hnj	0.0086
+AU	it	1.9
+AU	wbp	0.0098
+AU	zh_Hant	2.1
+AW	en	2.5
+AW	nl	97	official
+AW	pap	61	official
+AX	sv	99	official
+AZ	az	89	official
+AZ	az_Cyrl	9.9	official
+AZ	ku	0.24
+AZ	tkr	0.16
+AZ	tly	9.8
+AZ	ttt	0.22
+BA	bs	99	official
+BA	bs_Cyrl	99	official
+BA	en	45
+BA	hr	12	official
+BA	sr	10	official
+BA	sr_Latn	10	official
+BB	en	100	official
+BD	bn	98	official
+BD	ccp	0.22
+BD	en	18
+BD	grt	0.073
+BD	mni	0.011
+BD	mro	0.018
+BD	my	0.21
+BD	rhg	0.53
+BD	rkt	6.5
+BD	syl	5
+BE	de	22	official
+BE	en	59
+BE	fr	38	official
+BE	nl	55	official
+BE	vls	10
+BE	wa	5.8
+BF	dyu	32
+BF	ff	0
+BF	ff_Adlm	0
+BF	fr	22	official
+BF	mos	40
+BG	bg	100	official
+BG	de	8
+BG	en	25
+BG	ru	23
+BG	tr	11
+BH	ar	87	official
+BH	ml	3.3
+BI	en	0.053	official
+BI	fr	59	official
+BI	rn	63	official
+BI	sw	0.054
+BJ	fon	25
+BJ	fr	35	official
+BJ	yo	6.7
+BL	fr	96	official
+BM	en	92	official
+BN	en	1.7
+BN	ms	93	official
+BN	ms_Arab	5	official
+BN	zh_Hant	11
+BO	aro	0.0009
+BO	ay	20	official
+BO	es	61	official
+BO	gn	0.45
+BO	qu	32	official
+BQ	nl	8	official
+BQ	pap	81
+BR	de	0.84
+BR	en	8
+BR	es	0.036
+BR	gub	0.0084
+BR	it	0.28
+BR	ja	0.21
+BR	kgp	0.024
+BR	ko	0.021
+BR	pt	91	official
+BR	vec	0.24	official_regional
+BR	xav	0.0047
+BR	yrl	0.01
+BS	en	100	official
+BT	dz	47	official
+BT	en	11
+BT	lep	3.9
+BT	ne	17
+BT	tsj	15
+BW	af	0.26
+BW	en	81	official
+BW	tn	62	official
+BY	be	100	official
+BY	ru	12	official
+BZ	en	100	official
+BZ	es	28
+CA	ar	1.5
+CA	atj	0.017
+CA	bla	0.013
+CA	bn	0.24
+CA	chp	0.034	official_regional
+CA	clc	0.0023
+CA	cr	0.024	official_regional
+CA	crg	0.0018
+CA	crk	0.011
+CA	crl	0.001
+CA	csw	0.0048
+CA	dak	0.0032
+CA	de	0.78
+CA	den	0.0061	official_regional
+CA	dgr	0.0056	official_regional
+CA	el	0.33
+CA	en	86	official
+CA	es	1.6
+CA	fa	0.65
+CA	fil	1.5
+CA	fr	30	official
+CA	gu	0.36
+CA	gwi	0.0008	official_regional
+CA	hi	0.5
+CA	hr	0.12
+CA	hu	0.13
+CA	hur	0.0019
+CA	it	0.91
+CA	iu	0.12	official_regional
+CA	iu_Latn	0.12	official_regional
+CA	ja	0.14
+CA	ko	0.45
+CA	kwk	0.001
+CA	lil	0.0014
+CA	mic	0.021
+CA	moe	0.032
+CA	moh	0.0047
+CA	nl	0.15
+CA	nsk	0.0037
+CA	oj	0.063
+CA	ojs	0.04
+CA	oka	0.0013
+CA	pa	1.6
+CA	pdt	0.24
+CA	pl	0.46
+CA	pqm	0.0013
+CA	pt	0.61
+CA	ro	0.27
+CA	ru	0.56
+CA	so	0.13
+CA	sr	0.17
+CA	ta	0.49
+CA	tr	0.1
+CA	uk	0.2
+CA	ur	0.76
+CA	vi	0.49
+CA	war	0.0018
+CA	yue	1.7
+CA	zh	1.8
+CC	en	17	de_facto_official
+CC	ms_Arab	83
+CD	fr	3.8	official
+CD	kg	1.5	official_regional
+CD	ln	3.1	official_regional
+CD	lol	0.61
+CD	lu	2.3
+CD	lua	9.6	official_regional
+CD	rw	0.38
+CD	sw	50	official_regional
+CF	fr	49	official
+CF	ln	0.24
+CF	sg	49	official
+CG	fr	84	official
+CG	ln	2.4
+CH	de	73	official
+CH	en	61
+CH	fr	21	official
+CH	gsw	65	de_facto_official
+CH	it	4.3	official
+CH	lmo	4.1
+CH	pt	3.4
+CH	rm	0.5	official_regional
+CH	rmo	0.29
+CH	wae	0.12
+CI	bci	11
+CI	bqv	0.17
+CI	dnj	4
+CI	fr	49	official
+CI	kfo	0.23
+CI	sef	4.3
+CK	en	100	official
+CL	arn	1.5
+CL	en	9.5
+CL	es	98	official
+CM	agq	0.14
+CM	ar	0.39
+CM	bas	1.2
+CM	bax	1.2
+CM	bbj	1.4
+CM	bfd	0.57
+CM	bkm	1.3
+CM	bss	0.54
+CM	bum	4.6
+CM	byv	1.1
+CM	dua	0.48
+CM	en	38	official
+CM	ewo	3.1
+CM	ff	3.6
+CM	ff_Adlm	0
+CM	fr	68	official
+CM	ha_Arab	0.14
+CM	jgo	0.34
+CM	ken	0.25
+CM	kkj	0.54
+CM	ksf	0.32
+CM	maf	0.74
+CM	mgo	0.47
+CM	mua	1
+CM	nmg	0.032
+CM	nnh	1.4
+CM	yav	0.0083
+CM	ybb	1.6
+CN	bo	0.2	official_regional
+CN	en	0.0045
+CN	gan	1.7
+CN	hak	2.3
+CN	hnj	0.004
+CN	hsn	2.9
+CN	ii	0.6
+CN	khb	0.019
+CN	kk_Arab	0.085
+CN	ko	0.15	official_regional
+CN	ky_Arab	0.034
+CN	lcp	0.0058
+CN	lis	0.045
+CN	lzh	0
+CN	mn_Mong	0.26	official_regional
+CN	nan	1.9
+CN	nxq	0.024
+CN	ru	0.001
+CN	tdd	0.019
+CN	ug	0.55	official_regional
+CN	uz_Cyrl	0.0004
+CN	vi	0.0005
+CN	wuu	6
+CN	yue_Hans	5.2
+CN	za	0.31	official_regional
+CN	zh	90	official
+CO	es	93	official
+CO	guc	0.27
+CO	yrl	0.0061
+CR	es	95	official
+CU	es	100	official
+CV	kea	91
+CV	pt	76	official
+CW	es	3.8
+CW	nl	8	official
+CW	pap	81	de_facto_official
+CX	en	63	official
+CY	ar	0.1
+CY	el	95	official
+CY	en	73
+CY	fr	7
+CY	hy	0.22
+CY	tr	23	official
+CZ	cs	98	official
+CZ	de	15
+CZ	en	27
+CZ	pl	0.49
+CZ	sk	16
+DE	bar	17
+DE	da	2
+DE	de	91	official
+DE	dsb	0.0087
+DE	el	0.38
+DE	en	64
+DE	es	6
+DE	fr	18
+DE	frr	0.012	official_regional
+DE	frs	0.0025
+DE	gsw	2.3
+DE	hr	0.79
+DE	hsb	0.016
+DE	it	7
+DE	ksh	0.3
+DE	ku	0.66
+DE	nds	12
+DE	nl	9
+DE	pfl	0
+DE	pl	0.29
+DE	ru	6
+DE	stq	0.0012
+DE	swg	1
+DE	tr	2.5
+DE	vmf	6
+DJ	aa	42
+DJ	ar	7.3	official
+DJ	fr	2.1	official
+DJ	so	41
+DK	da	93	official
+DK	de	47	official_regional
+DK	en	86
+DK	fo	0.38
+DK	jut	0
+DK	kl	0.12	official_regional
+DK	sv	13
+DM	en	94	official
+DO	en	0.076
+DO	es	78	official
+DZ	ar	74	official
+DZ	arq	83
+DZ	en	7
+DZ	fr	20	official
+DZ	kab	7.8
+EC	es	96	official
+EC	qu	17	official
+EC	qug	5.7
+EE	en	50
+EE	et	71	official
+EE	fi	21
+EE	ru	56
+EE	vro	5.7
+EG	ar	94	official
+EG	arz	64
+EG	el	0.061
+EG	en	35
+EH	ar	100	official
+ER	aa	3.6
+ER	ar	4.9	official
+ER	byn	1.3
+ER	en	59	official
+ER	ssy	3.6
+ER	ti	60	de_facto_official
+ER	tig	18
+ES	an	0.052
+ES	ast	1.3	official_regional
+ES	ca	17	official_regional
+ES	en	24
+ES	es	99	official
+ES	eu	2	official_regional
+ES	ext	0.49
+ES	gl	7	official_regional
+ES	oc	0.0099	official_regional
+ET	aa	1.4
+ET	am	33	official
+ET	en	43
+ET	gez	0
+ET	om	32
+ET	sid	3.5
+ET	so	6
+ET	ti	6
+ET	wal	1.8
+FI	de	18
+FI	en	70
+FI	et	0.11
+FI	fi	94	official
+FI	rmf	0.09
+FI	ru	0.81
+FI	se	0.036
+FI	smn	0.011
+FI	sms	0.011	official_regional
+FI	sv	44	official
+FJ	en	94	official
+FJ	fj	39	official
+FJ	hi	44
+FJ	hif	41	official
+FJ	rtm	0.27
+FK	en	88	official
+FM	chk	30
+FM	en	57	official
+FM	kos	7.8
+FM	pon	23
+FM	uli	2.9
+FM	yap	6.4
+FO	fo	95	official
+FR	br	0.83
+FR	ca	0.17
+FR	co	0.24
+FR	de	5
+FR	en	39
+FR	es	13
+FR	eu	0.13
+FR	fr	99	official
+FR	frp	0.094
+FR	gsw	0.91
+FR	hnj	0.19
+FR	ia	0.0002
+FR	it	1.7
+FR	nl	0.13
+FR	oc	3
+FR	pcd	1.1
+FR	pt	1.3
+GA	fr	63	official
+GA	puu	9
+GB	ar	0.3
+GB	bn	0.4
+GB	cy	1.3	official_regional
+GB	de	9
+GB	en	98	official
+GB	en_Shaw	0
+GB	es	8
+GB	fr	23
+GB	ga	0.15	official_regional
+GB	gd	0.11	official_regional
+GB	gu	2.9
+GB	it	0.2
+GB	kw	0.003
+GB	lt	0.2
+GB	pa	3.6
+GB	pl	4
+GB	pt	0.2
+GB	sco	2.5
+GB	so	0.2
+GB	ta	3.2
+GB	tr	0.2
+GB	ur	3.5
+GB	zh_Hant	0.3
+GD	en	96	official
+GE	ab	2.2	official_regional
+GE	hy	7
+GE	ka	86	official
+GE	ku	0.89
+GE	os	2.2	official_regional
+GE	ru	9
+GE	xmf	11
+GF	fr	77	official
+GF	gcr	26
+GF	hnj	2.4
+GF	zh_Hant	2.5
+GG	en	100	official
+GH	abr	5
+GH	ada	3
+GH	ak	39	official_regional
+GH	ee	11	official_regional
+GH	en	21	official
+GH	ff	0
+GH	ff_Adlm	0
+GH	gaa	2.8	official_regional
+GH	gur	3.5
+GH	ha	0.86
+GH	nzi	1
+GH	saf	0.014
+GI	en	80	official
+GI	es	50
+GL	da	14
+GL	kl	84	official
+GM	en	40	official
+GM	ff	0
+GM	ff_Adlm	0
+GM	man	29
+GN	ff	26
+GN	ff_Adlm	0
+GN	fr	29	official
+GN	kpe	3.8
+GN	man_Nkoo	23
+GN	nqo	5
+GN	sus	11
+GP	fr	90	official
+GQ	bvb	7.9
+GQ	es	87	official
+GQ	fan	51
+GQ	fr	8.8	official
+GQ	pt	0.0001	official
+GR	bg	0.27
+GR	de	5
+GR	el	99	official
+GR	en	51
+GR	fr	9
+GR	mk	1.6
+GR	pnt	3.7
+GR	sq	0.094
+GR	tr	1.2
+GR	tsd	0.0019
+GT	es	93	official
+GT	quc	7	official_regional
+GU	ch	22	official
+GU	en	91	de_facto_official
+GW	ff	0.0001
+GW	ff_Adlm	0.0001
+GW	knf	2.6
+GW	pt	100	official
+GY	en	100	official
+HK	en	51	official
+HK	yue	90
+HK	zh	5
+HK	zh_Hant	95	official
+HN	en	0.44
+HN	es	78	official
+HR	en	49
+HR	hr	99	official
+HR	it	1.6	official_regional
+HR	vec	0.7	official_regional
+HT	fr	4.7	official
+HT	ht	81	official
+HU	de	18
+HU	en	20
+HU	fr	3
+HU	hr	0.32
+HU	hu	100	official
+HU	ro	0.99
+HU	sk	0.12
+HU	sl	0.051
+ID	ace	1.4
+ID	aoz	0.27
+ID	ban	1.8
+ID	bbc	0.92
+ID	bew	2.1
+ID	bjn	1.5
+ID	bug	1.6
+ID	gay	0.12
+ID	gor	0.41
+ID	id	64	official
+ID	jv	34
+ID	kge	0.32
+ID	kvr	0.14
+ID	lbw	0.13
+ID	ljp	0.69
+ID	mad	6.3
+ID	mak	0.73
+ID	mdr	0.092
+ID	min	3
+ID	ms	3.4
+ID	ms_Arab	1.2
+ID	mwv	0.024
+ID	nij	0.37
+ID	rej	0.46
+ID	rob	0.11
+ID	sas	0.97
+ID	sly	0.054
+ID	su	12
+ID	sxn	0.092
+ID	zh_Hant	0.92
+IE	en	98	official
+IE	fr	17
+IE	ga	22	official
+IL	am	0.59
+IL	ar	20	official
+IL	en	85
+IL	he	100	official
+IL	hu	1
+IL	lad	1.3
+IL	ml	0.092
+IL	pl	1.5
+IL	ro	3.7
+IL	ru	11
+IL	ti	0.12
+IL	yi	3
+IM	en	100	official
+IM	gv	1.9	official
+IN	as	1.3	official_regional
+IN	awa	1.9
+IN	bfq	0.023
+IN	bft	0.0062
+IN	bfy	0.037
+IN	bgc	1.2
+IN	bhb	0.12
+IN	bhi	0.092
+IN	bho	2.3
+IN	bjj	0.56
+IN	bn	8.1	official_regional
+IN	bo	0.011
+IN	bpy	0.0068
+IN	bra	0.0041
+IN	brx	0.14
+IN	btv	0.0026
+IN	ccp	0.028
+IN	dcc	0.99
+IN	doi	0.2
+IN	dv	0.0003
+IN	dz	0.0002
+IN	en	19	official
+IN	gbm	0.27
+IN	gom	0.32
+IN	gon	0.24
+IN	grt	0.053
+IN	gu	4.5	official_regional
+IN	hi	41	official
+IN	hi_Latn	0.1
+IN	hne	1.1
+IN	hoc	0.099
+IN	hoj	0.082
+IN	kfr	0.075
+IN	kfy	0.22
+IN	kha	0.08	official_regional
+IN	khn	0.15
+IN	kht	0.0007
+IN	kn	3.7	official_regional
+IN	kok	0.37	official_regional
+IN	kru	0.19
+IN	ks	0.41	official_regional
+IN	lah	0.0025
+IN	lep	0.0035
+IN	lif	0.0026
+IN	lmn	0.27
+IN	mag	1.2
+IN	mai	1.2	official_regional
+IN	ml	3.2	official_regional
+IN	mni	0.11
+IN	mr	7	official_regional
+IN	mtr	0.097
+IN	mwr	1.2
+IN	ne	0.56	official_regional
+IN	njo	0.023
+IN	noe	0.13
+IN	or	3.2	official_regional
+IN	pa	2.8	official_regional
+IN	raj	0.1
+IN	ria	0.013
+IN	rkt	0.43
+IN	sa	0.0012	official_regional
+IN	sat	0.55	official_regional
+IN	saz	0.029
+IN	sck	0.18
+IN	sd	0.26	official_regional
+IN	sd_Deva	0.14	official_regional
+IN	srx	0.035
+IN	swv	0.28
+IN	ta	5.9	official_regional
+IN	tcy	0.15
+IN	te	7.2	official_regional
+IN	unr	0.094
+IN	unx	0.048
+IN	ur	5	official_regional
+IN	wbq	0.18
+IN	wbr	0.15
+IN	wtm	0.46
+IN	xnr	0.16
+IO	en	100	official
+IQ	ar	68	official
+IQ	az_Arab	1.8	official_regional
+IQ	ckb	20	official_regional
+IQ	en	35
+IQ	fa	0.87
+IQ	lrc	0.61
+IQ	syr	0.5
+IR	ar	2
+IR	az_Arab	24
+IR	bal	2
+IR	bgn	0.56
+IR	bqi	1.4
+IR	ckb	3.9
+IR	fa	75	official
+IR	gbz	0.0094
+IR	glk	4.6
+IR	hy	0.24
+IR	ka	0.071
+IR	kk_Arab	0.0035
+IR	lki	0.76
+IR	lrc	2.1
+IR	luz	1.2
+IR	mzn	5
+IR	prd	0.5
+IR	ps	0.16
+IR	rmt	1.9
+IR	sdh	3.7
+IR	tk	2.8
+IS	da	0.64
+IS	is	100	official
+IT	ca	0.035
+IT	de	1.6
+IT	egl	0.05
+IT	el	0.035
+IT	en	34
+IT	fr	6.3	official_regional
+IT	fur	0.06
+IT	hr	0.0056
+IT	it	95	official
+IT	lij	0.86
+IT	lmo	0.03
+IT	nap	0.97
+IT	pms	0.0099
+IT	rgn	0
+IT	sc	1.7
+IT	scn	0.82
+IT	sdc	0.17
+IT	sl	0.17
+IT	vec	1.3	official_regional
+JE	en	95	official
+JM	en	98	official
+JM	jam	95
+JO	ar	100	official
+JO	en	45
+JP	ja	95	official
+JP	ko	0.52
+JP	ryu	0.77
+KE	ar	0.046
+KE	dav	0.82
+KE	ebu	1.5
+KE	en	19	official
+KE	gu	0.0093
+KE	guz	4.9
+KE	kam	7.6
+KE	ki	17
+KE	kln	7.6
+KE	luo	9.8
+KE	luy	11
+KE	mas	1.6
+KE	mer	4
+KE	om	0.47
+KE	pa	0.019
+KE	pko	0.69
+KE	saq	0.46
+KE	so	1.3
+KE	sw	66	official
+KE	teo	0.74
+KG	ky	48	official
+KG	ru	36	official
+KH	cja	1.6
+KH	kdt	0.11
+KH	km	89	official
+KI	en	100	official
+KI	gil	60	official
+KM	ar	66	official
+KM	fr	56	official
+KM	wni	34	official
+KM	zdj	37	official
+KN	en	98	official
+KP	ko	88	official
+KR	ko	100	official
+KW	ar	100	official
+KY	en	98	official
+KZ	de	6.4
+KZ	en	15
+KZ	kk	64	official
+KZ	ru	72	official
+KZ	ug_Cyrl	2
+LA	hnj	3
+LA	kdt	0.96
+LA	kjg	5.8
+LA	lo	69	official
+LB	ar	86	official
+LB	en	40
+LB	fr	0.37
+LB	hy	5.2
+LB	ku_Arab	1.7
+LC	en	90	official
+LI	de	100	official
+LI	gsw	85	de_facto_official
+LI	wae	3.3
+LK	en	10
+LK	si	68	official
+LK	ta	15	official
+LR	en	83	official
+LR	ff	0
+LR	ff_Adlm	0
+LR	kpe	14
+LR	men	0.48
+LR	vai	2.6
+LR	vai_Latn	0
+LS	en	27	official
+LS	ss	2.4
+LS	st	98	official
+LS	xh	0.99
+LS	zu	14
+LT	de	14
+LT	en	38
+LT	lt	86	official
+LT	ru	80
+LT	sgs	0
+LU	de	63	official
+LU	en	56
+LU	fr	87	official
+LU	lb	67	official
+LU	pt	16
+LV	en	46
+LV	ltg	8.9
+LV	lv	61	official
+LV	ru	38
+LY	ar	74	official
+MA	ar	62	official
+MA	ary	87
+MA	en	14
+MA	es	0.065
+MA	fr	20	de_facto_official
+MA	rif	4.9
+MA	rif_Latn	4.9
+MA	shi	8.7
+MA	shi_Latn	8.7
+MA	tzm	9.8	official
+MA	zgh	22
+MC	fr	99	official
+MD	bg	9.4
+MD	gag	3.3
+MD	ro	63	official
+MD	ru	3
+MD	uk	14
+ME	sq	7.9
+ME	sr	5
+ME	sr_Latn	100	official
+MF	fr	100	official
+MG	en	18	official
+MG	fr	69	official
+MG	mg	90	official
+MH	en	93	official
+MH	mh	73	official
+MK	mk	67	official
+MK	sq	25	official_regional
+MK	tr	3.5
+ML	ar	0.9
+ML	bm	46
+ML	bm_Nkoo	2
+ML	bmq	0.86
+ML	bze	0.85
+ML	dtm	1.1
+ML	ffm	7.7
+ML	fr	46	official
+ML	kao	1
+ML	khq	1.7
+ML	mwk	5
+ML	ses	3.4
+ML	snk	5.9
+ML	tmh	2.1
+MM	hnj	0.022
+MM	kac	1.7
+MM	kht	0.0075
+MM	mnw	1.5
+MM	my	64	official
+MM	rhg	1.7
+MM	shn	6.4
+MN	kk_Arab	7.2
+MN	mn	93	official
+MN	ru	0.13
+MN	ug_Cyrl	0.032
+MN	zh	1.4
+MO	en	2.3
+MO	pt	5	official
+MO	zh	5
+MO	zh_Hant	98	official
+MP	ch	18
+MP	en	97	de_facto_official
+MQ	fr	98	official
+MR	ar	85	official
+MR	ff	5.7
+MR	ff_Adlm	0
+MR	fr	17
+MR	wo	0.25
+MS	en	65	official
+MT	en	88	official
+MT	fr	11
+MT	it	56
+MT	mt	100	official
+MU	bho	27
+MU	en	72	official
+MU	fr	3	official
+MU	mfe	90
+MU	ta	2.5
+MU	ur	5.2
+MV	dv	98	official
+MV	en	75
+MW	en	63	official
+MW	ny	63	official
+MW	tog	0.98
+MW	tum	8.4
+MW	zu	0.33
+MX	en	13
+MX	es	83	de_facto_official
+MX	maz	0.34
+MX	nch	0.19
+MX	nhe	0.39
+MX	nhw	0.39
+MX	sei	0.0007
+MX	vec	0.0019	official_regional
+MX	yua	0.67
+MY	bjn	0.015
+MY	bug	0.079
+MY	dtp	0.56
+MY	en	21
+MY	iba	2.5
+MY	jv	1.2
+MY	ml	0.15
+MY	ms	75	official
+MY	ta	4.2
+MY	zh	17
+MY	zmi	1.2
+MZ	mgh	4.5
+MZ	ndc	9.9
+MZ	ngl	6.8
+MZ	ny	2.6
+MZ	pt	27	official
+MZ	rng	3.4
+MZ	seh	4.6
+MZ	sw	0.031
+MZ	ts	7.9
+MZ	vmw	13
+MZ	yao	2.4
+MZ	zu	0.006
+NA	af	75
+NA	de	0.9
+NA	en	7	official
+NA	hz	9.1
+NA	kj	35
+NA	naq	11
+NA	ng	21
+NA	tn	0.56
+NC	fr	96	official
+NE	ar	0.21
+NE	dje	17
+NE	ff	0
+NE	ff_Adlm	0
+NE	fr	29	official
+NE	fuq	7
+NE	ha	41
+NE	tmh	6
+NE	twq	0.035
+NF	en	96	official
+NG	amo	0.0087
+NG	ann	0
+NG	ar	0.071
+NG	bin	0.71
+NG	cch	0.021
+NG	efi	1.4
+NG	en	53	official
+NG	ff	0
+NG	ff_Adlm	0
+NG	fuv	6.7
+NG	ha	13
+NG	ha_Arab	1
+NG	ibb	1.4
+NG	ig	13
+NG	kaj	0.21
+NG	kcg	0.093
+NG	pcm	21
+NG	tiv	1.6
+NG	yo	13	official
+NI	es	78	official
+NL	de	71
+NL	en	90
+NL	fr	29
+NL	fy	4.3	official_regional
+NL	gos	3.6
+NL	id	1.8
+NL	li	5.5
+NL	nds	11
+NL	nl	100	official
+NL	rif_Latn	1.2
+NL	tr	1.2
+NL	zea	1.4
+NO	nb	100	official
+NO	nn	25	official
+NO	no	100	official
+NO	se	0.29	official_regional
+NP	awa	2.2
+NP	bap	1.5
+NP	bfy	0.54
+NP	bho	6.8
+NP	bn	0.094
+NP	bo	0.24
+NP	dty	2.5
+NP	en	3
+NP	gvr	0.29
+NP	hi	0.42
+NP	jml	3.2
+NP	lep	0.0093
+NP	lif	1.1
+NP	mai	11
+NP	mgp	1.1
+NP	mrd	0.83
+NP	ne	44	official
+NP	new	3.3
+NP	rjs	0.44
+NP	taj	0.43
+NP	tdg	1.3
+NP	tdh	0.12
+NP	thl	2
+NP	thq	1
+NP	thr	1.2
+NP	tkt	0.24
+NP	unr_Deva	0.019
+NP	xsr	0.52
+NR	en	85	official
+NR	na	63	official
+NU	en	56	official
+NU	niu	56	official
+NZ	en	98	de_facto_official
+NZ	mi	2.8	official
+OM	ar	81	official
+OM	bal	4.9
+OM	fa	0.94
+PA	en	14
+PA	es	69	official
+PA	zh_Hant	0.15
+PE	ay	1.6
+PE	es	73	official
+PE	qu	15	official
+PF	fr	61	official
+PF	ty	31	official
+PF	zh_Hant	7.8
+PG	en	50	official
+PG	ho	2.1	official
+PG	tpi	71	official
+PH	bik	3
+PH	bku	0.0073
+PH	bto	0.28
+PH	ceb	24	official_regional
+PH	cps	0.66
+PH	en	64	official
+PH	es	31
+PH	fbl	2.3
+PH	fil	60	official
+PH	hil	8.4	official_regional
+PH	hnn	0.016
+PH	ilo	9.6	official_regional
+PH	krj	0.39
+PH	mdh	1.2	official_regional
+PH	pag	1.4	official_regional
+PH	pam	2.3
+PH	tbw	0.0092
+PH	tsg	1.1	official_regional
+PH	war	2.9	official_regional
+PH	zh_Hant	0.73
+PK	bal	2.6
+PK	bft	0.18
+PK	bgn	0.57
+PK	brh	1.3
+PK	btv	0.019
+PK	en	50	official
+PK	fa	0.66
+PK	gjk	0.11
+PK	gju	0.2
+PK	hnd	0.41
+PK	hno	1.7
+PK	khw	0.15
+PK	ks	0.069
+PK	kvx	0.16
+PK	kxp	0.11
+PK	lah	40
+PK	mvy	0.14
+PK	pa_Arab	70
+PK	ps	16
+PK	sd	15
+PK	skr	12
+PK	tg_Arab	0.33
+PK	trw	0.053
+PK	ur	95	official
+PL	be	0.58
+PL	csb	0.13	official_regional
+PL	de	19	official_regional
+PL	en	33
+PL	lt	0.021	official_regional
+PL	pl	96	official
+PL	prg	0.0001
+PL	ru	18
+PL	sli	0.031
+PL	szl	1.3
+PL	uk	0.39
+PM	en	3.5
+PM	fr	96	official
+PN	en	92	official
+PR	en	49	de_facto_official
+PR	es	87	official
+PS	ar	100	official
+PT	en	27
+PT	es	10
+PT	fr	15
+PT	gl	0.14
+PT	pt	96	official
+PW	en	8.7	official
+PW	pau	74	official
+PY	de	2.9
+PY	es	3.2	official
+PY	gn	80	official
+QA	ar	89	official
+QA	fa	11
+QA	ml	0.27
+RE	fr	89	official
+RE	rcf	71
+RE	ta	15
+RO	bg	0.032
+RO	de	0.21
+RO	el	0.019
+RO	en	31
+RO	es	10
+RO	fr	17
+RO	hu	6.6
+RO	pl	0.013
+RO	ro	90	official
+RO	sr_Latn	0.12
+RO	tr	0.13
+RS	hr	0.93	official_regional
+RS	hu	4.8	official_regional
+RS	ro	2.1	official_regional
+RS	sk	0.85	official_regional
+RS	sq	19
+RS	sr	99	official
+RS	sr_Latn	99	official
+RS	uk	0	official_regional
+RU	ady	0.088	official_regional
+RU	alt	0.014
+RU	av	0.39	official_regional
+RU	az_Cyrl	0.093	official_regional
+RU	ba	1.3	official_regional
+RU	bua	0.22
+RU	ce	0.66	official_regional
+RU	chm	0.37
+RU	cu	0
+RU	cv	1.3
+RU	dar	0.26
+RU	fi	0.012
+RU	hy	0.84
+RU	inh	0.16	official_regional
+RU	izh	0.0001
+RU	kbd	0.31	official_regional
+RU	koi	0.045	official_regional
+RU	krc	0.17	official_regional
+RU	krl	0.082
+RU	kum	0.2	official_regional
+RU	kv	0.18	official_regional
+RU	lbe	0.078	official_regional
+RU	lez	0.18	official_regional
+RU	mdf	0.21	official_regional
+RU	mn	0.0015
+RU	mrj	0.021
+RU	myv	0.31	official_regional
+RU	os	0.32
+RU	ru	94	official
+RU	sah	0.32	official_regional
+RU	sr_Latn	0.0035
+RU	tt	1.4	official_regional
+RU	tyv	0.13	official_regional
+RU	udm	0.38	official_regional
+RU	vep	0.0025
+RU	vot	0
+RW	en	15	official
+RW	fr	0.018	official
+RW	rw	77	official
+SA	ar	100	official
+SA	ars	3
+SB	en	100	official
+SB	pis	82
+SB	rug	1.4
+SC	crs	98
+SC	en	38	official
+SC	fr	60	official
+SD	ar	61	official
+SD	bej	5.4
+SD	en	61	official
+SD	fia	0.83
+SD	fvr	2.7
+SD	ha_Arab	1.8
+SD	mls	0.99
+SD	zag	0.51
+SE	en	86
+SE	fi	2.2	official_regional
+SE	fit	0.55
+SE	ia	0
+SE	rmu	0.093
+SE	se	0.33
+SE	sma	0.0029
+SE	smj	0.015
+SE	sv	95	official
+SE	yi	0.029
+SG	en	93	official
+SG	ml	0.16
+SG	ms	14	official
+SG	pa	0.15
+SG	ta	2.1	official
+SG	zh	77	official
+SH	en	69	official
+SI	de	42
+SI	en	59
+SI	hr	61
+SI	hu	0.44
+SI	it	0.19
+SI	sl	87	official
+SI	vec	1.4	official_regional
+SJ	nb	51	official
+SJ	ru	41
+SK	cs	47
+SK	de	22
+SK	en	26
+SK	hu	11
+SK	pl	0.93
+SK	sk	90	official
+SK	uk	1.9
+SL	en	35	official
+SL	ff	0
+SL	ff_Adlm	0
+SL	kri	95
+SL	men	27
+SL	tem	26
+SM	eo	0.88
+SM	it	89	official
+SN	bjt	0.61	official_regional
+SN	bsc	0.097	official_regional
+SN	dyo	2.6	official_regional
+SN	ff	21	official_regional
+SN	ff_Adlm	0
+SN	fr	39	official
+SN	knf	0.21	official_regional
+SN	mey	0.046	official_regional
+SN	mfv	0.77	official_regional
+SN	sav	1.5	official_regional
+SN	snf	0.24	official_regional
+SN	srr	11	official_regional
+SN	tnr	0.021	official_regional
+SN	wo	70	de_facto_official
+SO	ar	34	official
+SO	om	0.42
+SO	so	78	official
+SO	sw	2
+SR	hnj	0.38
+SR	nl	90	official
+SR	srn	68
+SR	zh_Hant	1.1
+SS	ar	27
+SS	en	27	official
+SS	nus	5.6
+ST	pt	85	official
+SV	es	89	official
+SX	en	68	official
+SX	es	11
+SX	nl	3.6	official
+SX	vic	7.1
+SY	ar	80	official
+SY	fr	5.9	official
+SY	hy	1.8
+SY	ku	8
+SY	syr	0.084
+SZ	en	80	official
+SZ	ss	58	official
+SZ	ts	1.7
+SZ	zu	6.8
+TC	en	98	official
+TD	ar	17	official
+TD	fr	26	official
+TF	fr	100
+TG	ee	17
+TG	fr	61	official
+TG	ife	1.3
+TH	en	27
+TH	hnj	0.098
+TH	kdt	0.48
+TH	kxm	1.7
+TH	lcp	0.01
+TH	lwl	0.01
+TH	mfa	5
+TH	mnw	0.17
+TH	nod	9.6
+TH	shn	0.096
+TH	sou	8
+TH	th	80	official
+TH	tts	24
+TH	zh_Hant	1.8
+TJ	ar	0.011
+TJ	fa	0.78
+TJ	ru	12
+TJ	tg	100	official
+TK	en	78	official
+TK	tkl	78	official
+TL	pt	59	official
+TL	tet	59	official
+TM	ku	0.4
+TM	ru	12
+TM	tk	70	official
+TM	uz	9
+TN	aeb	90
+TN	ar	90	official
+TN	fr	74	official
+TO	en	28	official
+TO	to	95	official
+TR	ab	0.0049
+TR	ady	0.39
+TR	ar	0.56
+TR	az	0.74
+TR	az_Arab	0.65
+TR	bg	0.42
+TR	bgx	0.46
+TR	el	0.0049
+TR	en	17
+TR	hy	0.056
+TR	ka	0.056
+TR	kbd	0.77
+TR	kiu	0.19
+TR	kk	0.0007
+TR	ku	5.5
+TR	ky_Latn	0.0014
+TR	lzz	0.028
+TR	sq	0.021
+TR	sr_Latn	0.028
+TR	tr	93	official
+TR	tru	0.0037
+TR	uz	0.0024
+TR	zza	1.4
+TT	en	88	official
+TT	es	0.34
+TV	en	9.4	official
+TV	tvl	87	official
+TW	trv	0.02
+TW	zh_Hant	95	official
+TZ	asa	1.2
+TZ	bez	1.7
+TZ	en	69	official
+TZ	jmc	0.74
+TZ	kde	2.5
+TZ	ksb	1.7
+TZ	lag	0.87
+TZ	mas	1.5
+TZ	mgy	1.4
+TZ	nym	3.3
+TZ	rof	0.74
+TZ	rwk	0.22
+TZ	sbp	0.2
+TZ	suk	8.7
+TZ	sw	90	official
+TZ	vun	0.74
+UA	be	0.83
+UA	bg	0.49
+UA	crh	0.56
+UA	el	0.016
+UA	hu	0.37
+UA	pl	2.4
+UA	ro	0.52
+UA	ru	46	de_facto_official
+UA	rue	1.2
+UA	tr	0.42
+UA	uk	65	official
+UA	yi	1.3
+UG	ach	3.7
+UG	cgg	5.4
+UG	en	3.9	official
+UG	hi	0.0051
+UG	laj	3.8
+UG	lg	13
+UG	myx	2.9
+UG	nyn	6.3
+UG	rw	2.1
+UG	sw	75	official
+UG	teo	3.9
+UG	ttj	1.9
+UG	xog	5.3
+UM	en	100	de_facto_official
+US	cad	0
+US	cho	0.0033
+US	chr	0.0077
+US	cic	0
+US	dak	0.0059
+US	de	0.47
+US	en	96	de_facto_official
+US	es	9.6	official_regional
+US	esu	0.0063
+US	fil	0.42
+US	fr	0.56
+US	frc	0.0084
+US	haw	0.0089	official_regional
+US	hnj	0.035
+US	ik	0.0024
+US	io	0
+US	it	0.34
+US	jbo	0
+US	ko	0.3
+US	lkt	0.0025
+US	mus	0.0012
+US	nv	0.05
+US	osa	0
+US	pdc	0.039
+US	ru	0.24
+US	vi	0.34
+US	yi	0.049
+US	zh_Hant	0.69
+UY	es	88	official
+UZ	kaa	1.6
+UZ	ru	14
+UZ	tr	0.76
+UZ	uz	85	official
+UZ	uz_Cyrl	15	official
+VA	it	82	de_facto_official
+VA	la	82
+VC	en	96	official
+VE	es	82	official
+VE	yrl	0.007
+VG	en	98	official
+VI	en	75	de_facto_official
+VN	blt	0.69
+VN	cjm	0.089
+VN	hnj	0.17
+VN	vi	86	official
+VN	zh_Hant	1.1
+VU	bi	90	official
+VU	en	83	official
+VU	fr	50	official
+WF	fr	48	official
+WF	fud	30
+WF	wls	60
+WS	en	2.1	official
+WS	sm	100	official
+YE	ar	74	official
+YE	en	9
+YT	buc	23
+YT	fr	57	official
+YT	sw	1.4
+YT	swb	88
+ZA	af	13	official_regional
+ZA	en	31	official
+ZA	hi	2
+ZA	nr	1.6	official_regional
+ZA	nso	9.4	official_regional
+ZA	ss	2.7	official_regional
+ZA	st	7.9	official_regional
+ZA	sw	0.0018
+ZA	tn	8.2	official_regional
+ZA	ts	4.4	official_regional
+ZA	ve	2.3	official_regional
+ZA	xh	18	official_regional
+ZA	zu	24	official_regional
+ZM	bem	31
+ZM	en	16	official
+ZM	loz	6
+ZM	ny	15
+ZW	en	42	official
+ZW	kck	5.3
+ZW	mxc	6.5
+ZW	nd	12	official
+ZW	ndc	6.1
+ZW	ny	1.9
+ZW	sn	81	official
+ZW	tn	0.22
+ZW	ve	0.64
+"""
+
+pre_code = """use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+use std::cmp::Reverse;
+
+/// Official status of a language in a country, as recorded by CLDR.
+#[derive(Debug, Ord, PartialOrd, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LanguageStatus {
+    ///Official language of the country
+    Official,
+    ///Used as the official language without being declared one, such as
+    ///English in the United States
+    DeFactoOfficial,
+    ///Official in part of the country, such as Catalan in Spain
+    OfficialRegional,
+    ///Without official status, but spoken by at least a tenth of the
+    ///population
+    Unofficial,
+}
+
+impl LanguageStatus {
+    ///Return true for every status but `Unofficial`
+    pub fn is_official(&self) -> bool {
+        !matches!(self, LanguageStatus::Unofficial)
+    }
+}
+
+/// A language spoken in a country.
+///
+/// Speaker counts are CLDR estimates and include second-language speakers,
+/// so they add up to more than the population.
+/// #Sample
+/// ```
+/// use rust_iso3166::LanguageStatus;
+/// let switzerland = rust_iso3166::from_alpha2("CH").unwrap();
+/// let languages = switzerland.languages();
+/// assert_eq!(("de", LanguageStatus::Official), (languages[0].code, languages[0].status));
+/// let official: Vec<_> = languages
+///     .iter()
+///     .filter(|l| l.status == LanguageStatus::Official)
+///     .map(|l| l.code)
+///     .collect();
+/// assert_eq!(vec!["de", "fr", "it"], official);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Language {
+    ///ISO 639-1 code, or the ISO 639-3 code for languages without one
+    pub code: &'static str,
+    ///ISO 639-3 code
+    pub alpha3: &'static str,
+    ///English name
+    pub name: &'static str,
+    ///Official status in the country
+    pub status: LanguageStatus,
+    ///Estimated number of speakers in the country
+    pub speakers: u32,
+}
+
+impl CountryCode {
+    ///Return the official and widely spoken languages, most speakers first
+    pub fn languages(&self) -> &'static [Language] {
+        COUNTRY_LANGUAGE_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+}
+
+/// Returns the countries where the language with the given ISO 639-1 or
+/// ISO 639-3 code is official or widely spoken, most speakers first. ASCII
+/// case is ignored.
+/// #Sample
+/// ```
+/// let countries = rust_iso3166::countries_speaking("pt");
+/// assert_eq!(rust_iso3166::BR, countries[0]);
+/// assert!(countries.contains(&rust_iso3166::AO));
+/// assert_eq!(countries, rust_iso3166::countries_speaking("POR"));
+/// ```
+pub fn countries_speaking(code: &str) -> Vec<CountryCode> {
+    let code = code.trim();
+    let mut found: Vec<(CountryCode, u32)> = crate::ALL
+        .iter()
+        .filter_map(|country| {
+            country
+                .languages()
+                .iter()
+                .find(|l| l.code.eq_ignore_ascii_case(code) || l.alpha3.eq_ignore_ascii_case(code))
+                .map(|l| (*country, l.speakers))
+        })
+        .collect();
+    found.sort_by_key(|&(_, speakers)| Reverse(speakers));
+    found.into_iter().map(|(country, _)| country).collect()
+}
+"""
+
+# Names clearer than the ISO 639-3 reference names
+NAMES = {
+    "el": "Greek",
+}
+
+
+def iso_639():
+    with open("%s/iso_639-3.json" % ISO_CODES) as f:
+        data = json.load(f)["639-3"]
+    found = {}
+    for x in data:
+        name = re.sub(r"\s*\([^)]*\)", "", x["name"])
+        found[x["alpha_3"]] = (x["alpha_3"], name)
+        if "alpha_2" in x:
+            found[x["alpha_2"]] = (x["alpha_3"], NAMES.get(x["alpha_2"], name))
+    return found
+
+
+def main():
+    names = iso_639()
+    population = dict(x.split("\t") for x in p.split("\n") if x)
+    languages = {alpha2: {} for alpha2 in population}
+    for row in l.split("\n"):
+        if not row:
+            continue
+        alpha2, code, percent, status = (row.split("\t") + [""])[:4]
+        code = code.split("_")[0]
+        status = list(STATUS).index(status)
+        if status == list(STATUS).index("") and float(percent) < WIDELY_SPOKEN:
+            continue
+        speakers = round(int(population[alpha2]) * float(percent) / 100)
+        # Script variants of a language are the same speakers written down
+        # differently, so keep the largest share rather than adding them.
+        old = languages[alpha2].get(code)
+        if old:
+            status = min(status, old[0])
+            speakers = max(speakers, old[1])
+        languages[alpha2][code] = (status, speakers)
+
+    print(pre_code)
+    print("/// Official and widely spoken languages by country alpha-2 code.")
+    print("pub const COUNTRY_LANGUAGE_MAP: Map<&str, &[Language]> = phf_map! {")
+    for alpha2 in sorted(languages):
+        found = sorted(languages[alpha2].items(), key=lambda x: (-x[1][1], x[1][0], x[0]))
+        literals = []
+        for code, (status, speakers) in found:
+            alpha3, name = names[code]
+            literals.append(
+                "Language { code: \"%s\", alpha3: \"%s\", name: \"%s\", status: LanguageStatus::%s, speakers: %d }"
+                % (code, alpha3, name, list(STATUS.values())[status], speakers)
+            )
+        print("    \"%s\" => &[%s]," % (alpha2, ", ".join(literals)))
+    print("};")
+
+
+if __name__ == "__main__":
+    main()
@@ -6,6 +6,48 @@ use phf::phf_map;
 use phf::Map;
 pub mod iso3166_2;
 pub mod iso3166_3;
+mod alias;
+mod bcp47;
+mod borders;
+mod cctld;
+pub mod crosswalk;
+#[cfg(feature = "currency")]
+pub mod currency;
+mod flag;
+mod geography;
+pub mod groups;
+mod language;
+pub mod locale;
+mod lookup;
+mod normalize;
+mod parse;
+mod phone;
+mod region;
+pub mod search;
+mod sovereignty;
+pub mod sports;
+mod status;
+mod timezone;
+mod vehicle;
+pub use alias::{from_alias, from_name, ALIAS_MAP};
+pub use bcp47::{from_accept_language, locale_region, LocaleRegion, DEPRECATED_REGION_MAP};
+pub use borders::{border_crossings, landmasses, LANDLOCKED, NEIGHBOR_MAP};
+pub use cctld::{from_hostname, IdnCcTld, CCTLD_MAP, COUNTRY_CCTLD_MAP, COUNTRY_IDN_MAP};
+pub use flag::{find_flags, flag_status};
+pub use geography::{
+    distance_km, BoundingBox, Capital, Coordinates, BOUNDING_BOX_MAP, CAPITAL_MAP, CENTROID_MAP,
+};
+pub use language::{countries_speaking, Language, LanguageStatus, COUNTRY_LANGUAGE_MAP};
+pub use lookup::{lookup, lookup_all, Code};
+pub use parse::ParseError;
+pub use phone::{from_phone_prefix, CALLING_CODE_MAP};
+pub use region::{Region, RegionLevel, COUNTRY_REGION_MAP, REGION_MAP};
+pub use sovereignty::{Dependency, Relationship, DEPENDENCY_MAP};
+pub use status::{code_status, CodeStatus, Reservation, RESERVED_MAP};
+pub use timezone::{
+    countries_for_zone, COUNTRY_TIME_ZONE_MAP, SUBDIVISION_TIME_ZONE_MAP, ZONE_COUNTRY_MAP,
+};
+pub use vehicle::{from_vehicle_sign, VehicleSign, COUNTRY_VEHICLE_SIGN_MAP, VEHICLE_SIGN_MAP};
 use std::hash::Hash;
 #[cfg(all(direct_wasm,target_arch = "wasm32"))]
 use wasm_bindgen::prelude::*;
@@ -52,6 +94,10 @@ pub struct CountryCode {
     alpha3: &'static str,
     ///Numeric code
     numeric: u16,
+    ///English full name, if ISO 3166-1 gives one
+    official_name: Option<&'static str>,
+    ///French short name
+    name_fr: &'static str,
 }
 
 #[cfg(any(not(direct_wasm),not(target_arch = "wasm32")))]
@@ -65,6 +111,12 @@ pub struct CountryCode {
     pub alpha3: &'static str,
     ///Numeric code
     pub numeric: u16,
+    ///English full name from ISO 3166-1 without the leading "the", such as
+    ///"Commonwealth of Australia". `None` for territories that have none.
+    pub official_name: Option<&'static str>,
+    ///French short name from ISO 3166-1, such as "Bolivie (État plurinational
+    ///de)", without the article ISO adds in parentheses ("Allemagne (l')")
+    pub name_fr: &'static str,
 }
 
 #[cfg_attr(all(direct_wasm,target_arch = "wasm32"), wasm_bindgen)]
@@ -94,6 +146,18 @@ impl CountryCode {
         self.numeric
     }
 
+    #[cfg(all(direct_wasm,target_arch = "wasm32"))]
+    #[wasm_bindgen(getter)]
+    pub fn official_name(&self) -> Option<String> {
+        self.official_name.map(Into::into)
+    }
+
+    #[cfg(all(direct_wasm,target_arch = "wasm32"))]
+    #[wasm_bindgen(getter)]
+    pub fn name_fr(&self) -> String {
+        self.name_fr.into()
+    }
+
     ///Return len 3 String for CountryCode numeric
     pub fn numeric_str (&self) -> String {
         format!("{:03}", self.numeric)
@@ -142,9 +206,7 @@ impl<'de> Deserialize<'de> for CountryCode {
     {
         use serde::de::Error;
         let s = String::deserialize(deserializer)?;
-        let s_upper = s.to_uppercase();
-        from_alpha2(&s_upper)
-            .or_else(|| from_alpha3(&s_upper))
+        parse::with_upper::<3, _>(&s, |code| from_alpha2(code).or_else(|| from_alpha3(code)))
             .ok_or_else(|| D::Error::custom(format!("Invalid country code: {}", s)))
     }
 }
@@ -171,6 +233,30 @@ pub fn from_alpha3(alpha3: &str) -> Option<CountryCode> {
     ALPHA3_MAP.get(alpha3).cloned()
 }
 
+/// Returns the CountryCode with the given Alpha2 code, if exists, ignoring
+/// case and surrounding whitespace. Does not allocate.
+/// #Sample
+/// ```
+/// let country = rust_iso3166::from_alpha2_ignore_case(" au ");
+/// assert_eq!("AUS", country.unwrap().alpha3);
+/// ```
+#[cfg_attr(all(direct_wasm,target_arch = "wasm32"), wasm_bindgen)]
+pub fn from_alpha2_ignore_case(alpha2: &str) -> Option<CountryCode> {
+    parse::with_lenient::<2, _>(alpha2, |code| ALPHA2_MAP.get(code).cloned())
+}
+
+/// Returns the CountryCode with the given Alpha3 code, if exists, ignoring
+/// case and surrounding whitespace. Does not allocate.
+/// #Sample
+/// ```
+/// let country = rust_iso3166::from_alpha3_ignore_case("Aus ");
+/// assert_eq!("AU", country.unwrap().alpha2);
+/// ```
+#[cfg_attr(all(direct_wasm,target_arch = "wasm32"), wasm_bindgen)]
+pub fn from_alpha3_ignore_case(alpha3: &str) -> Option<CountryCode> {
+    parse::with_lenient::<3, _>(alpha3, |code| ALPHA3_MAP.get(code).cloned())
+}
+
 /// Returns the CountryCode with the given numeric , if exists.
 // #Sample
 /// ```
@@ -196,256 +282,262 @@ pub fn from_numeric_str(numeric: &str) -> Option<CountryCode> {
 """
 
 a = """
-Afghanistan	AF	AFG	004	ISO 3166-2:AF	Yes
-Åland Islands	AX	ALA	248	ISO 3166-2:AX	No
-Albania	AL	ALB	008	ISO 3166-2:AL	Yes
-Algeria	DZ	DZA	012	ISO 3166-2:DZ	Yes
-American Samoa	AS	ASM	016	ISO 3166-2:AS	No
-Andorra	AD	AND	020	ISO 3166-2:AD	Yes
-Angola	AO	AGO	024	ISO 3166-2:AO	Yes
-Anguilla	AI	AIA	660	ISO 3166-2:AI	No
-Antarctica	AQ	ATA	010	ISO 3166-2:AQ	No
-Antigua and Barbuda	AG	ATG	028	ISO 3166-2:AG	Yes
-Argentina	AR	ARG	032	ISO 3166-2:AR	Yes
-Armenia	AM	ARM	051	ISO 3166-2:AM	Yes
-Aruba	AW	ABW	533	ISO 3166-2:AW	No
-Australia	AU	AUS	036	ISO 3166-2:AU	Yes
-Austria	AT	AUT	040	ISO 3166-2:AT	Yes
-Azerbaijan	AZ	AZE	031	ISO 3166-2:AZ	Yes
-Bahamas	BS	BHS	044	ISO 3166-2:BS	Yes
-Bahrain	BH	BHR	048	ISO 3166-2:BH	Yes
-Bangladesh	BD	BGD	050	ISO 3166-2:BD	Yes
-Barbados	BB	BRB	052	ISO 3166-2:BB	Yes
-Belarus	BY	BLR	112	ISO 3166-2:BY	Yes
-Belgium	BE	BEL	056	ISO 3166-2:BE	Yes
-Belize	BZ	BLZ	084	ISO 3166-2:BZ	Yes
-Benin	BJ	BEN	204	ISO 3166-2:BJ	Yes
-Bermuda	BM	BMU	060	ISO 3166-2:BM	No
-Bhutan	BT	BTN	064	ISO 3166-2:BT	Yes
-Bolivia (Plurinational State of)	BO	BOL	068	ISO 3166-2:BO	Yes
-Bonaire, Sint Eustatius and Saba	BQ	BES	535	ISO 3166-2:BQ	No
-Bosnia and Herzegovina	BA	BIH	070	ISO 3166-2:BA	Yes
-Botswana	BW	BWA	072	ISO 3166-2:BW	Yes
-Bouvet Island	BV	BVT	074	ISO 3166-2:BV	No
-Brazil	BR	BRA	076	ISO 3166-2:BR	Yes
-British Indian Ocean Territory	IO	IOT	086	ISO 3166-2:IO	No
-Brunei Darussalam	BN	BRN	096	ISO 3166-2:BN	Yes
-Bulgaria	BG	BGR	100	ISO 3166-2:BG	Yes
-Burkina Faso	BF	BFA	854	ISO 3166-2:BF	Yes
-Burundi	BI	BDI	108	ISO 3166-2:BI	Yes
-Cabo Verde	CV	CPV	132	ISO 3166-2:CV	Yes
-Cambodia	KH	KHM	116	ISO 3166-2:KH	Yes
-Cameroon	CM	CMR	120	ISO 3166-2:CM	Yes
-Canada	CA	CAN	124	ISO 3166-2:CA	Yes
-Cayman Islands	KY	CYM	136	ISO 3166-2:KY	No
-Central African Republic	CF	CAF	140	ISO 3166-2:CF	Yes
-Chad	TD	TCD	148	ISO 3166-2:TD	Yes
-Chile	CL	CHL	152	ISO 3166-2:CL	Yes
-China	CN	CHN	156	ISO 3166-2:CN	Yes
-Christmas Island	CX	CXR	162	ISO 3166-2:CX	No
-Cocos (Keeling) Islands	CC	CCK	166	ISO 3166-2:CC	No
-Colombia	CO	COL	170	ISO 3166-2:CO	Yes
-Comoros	KM	COM	174	ISO 3166-2:KM	Yes
-Congo	CG	COG	178	ISO 3166-2:CG	Yes
-Congo (Democratic Republic of the)	CD	COD	180	ISO 3166-2:CD	Yes
-Cook Islands	CK	COK	184	ISO 3166-2:CK	No
-Costa Rica	CR	CRI	188	ISO 3166-2:CR	Yes
-Côte d'Ivoire	CI	CIV	384	ISO 3166-2:CI	Yes
-Croatia	HR	HRV	191	ISO 3166-2:HR	Yes
-Cuba	CU	CUB	192	ISO 3166-2:CU	Yes
-Curaçao	CW	CUW	531	ISO 3166-2:CW	No
-Cyprus	CY	CYP	196	ISO 3166-2:CY	Yes
-Czechia	CZ	CZE	203	ISO 3166-2:CZ	Yes
-Denmark	DK	DNK	208	ISO 3166-2:DK	Yes
-Djibouti	DJ	DJI	262	ISO 3166-2:DJ	Yes
-Dominica	DM	DMA	212	ISO 3166-2:DM	Yes
-Dominican Republic	DO	DOM	214	ISO 3166-2:DO	Yes
-Ecuador	EC	ECU	218	ISO 3166-2:EC	Yes
-Egypt	EG	EGY	818	ISO 3166-2:EG	Yes
-El Salvador	SV	SLV	222	ISO 3166-2:SV	Yes
-Equatorial Guinea	GQ	GNQ	226	ISO 3166-2:GQ	Yes
-Eritrea	ER	ERI	232	ISO 3166-2:ER	Yes
-Estonia	EE	EST	233	ISO 3166-2:EE	Yes
-Eswatini	SZ	SWZ	748	ISO 3166-2:SZ	Yes
-Ethiopia	ET	ETH	231	ISO 3166-2:ET	Yes
-Falkland Islands (Malvinas)	FK	FLK	238	ISO 3166-2:FK	No
-Faroe Islands	FO	FRO	234	ISO 3166-2:FO	No
-Fiji	FJ	FJI	242	ISO 3166-2:FJ	Yes
-Finland	FI	FIN	246	ISO 3166-2:FI	Yes
-France	FR	FRA	250	ISO 3166-2:FR	Yes
-French Guiana	GF	GUF	254	ISO 3166-2:GF	No
-French Polynesia	PF	PYF	258	ISO 3166-2:PF	No
-French Southern Territories	TF	ATF	260	ISO 3166-2:TF	No
-Gabon	GA	GAB	266	ISO 3166-2:GA	Yes
-Gambia	GM	GMB	270	ISO 3166-2:GM	Yes
-Georgia	GE	GEO	268	ISO 3166-2:GE	Yes
-Germany	DE	DEU	276	ISO 3166-2:DE	Yes
-Ghana	GH	GHA	288	ISO 3166-2:GH	Yes
-Gibraltar	GI	GIB	292	ISO 3166-2:GI	No
-Greece	GR	GRC	300	ISO 3166-2:GR	Yes
-Greenland	GL	GRL	304	ISO 3166-2:GL	No
-Grenada	GD	GRD	308	ISO 3166-2:GD	Yes
-Guadeloupe	GP	GLP	312	ISO 3166-2:GP	No
-Guam	GU	GUM	316	ISO 3166-2:GU	No
-Guatemala	GT	GTM	320	ISO 3166-2:GT	Yes
-Guernsey	GG	GGY	831	ISO 3166-2:GG	No
-Guinea	GN	GIN	324	ISO 3166-2:GN	Yes
-Guinea-Bissau	GW	GNB	624	ISO 3166-2:GW	Yes
-Guyana	GY	GUY	328	ISO 3166-2:GY	Yes
-Haiti	HT	HTI	332	ISO 3166-2:HT	Yes
-Heard Island and McDonald Islands	HM	HMD	334	ISO 3166-2:HM	No
-Holy See	VA	VAT	336	ISO 3166-2:VA	Yes
-Honduras	HN	HND	340	ISO 3166-2:HN	Yes
-Hong Kong	HK	HKG	344	ISO 3166-2:HK	No
-Hungary	HU	HUN	348	ISO 3166-2:HU	Yes
-Iceland	IS	ISL	352	ISO 3166-2:IS	Yes
-India	IN	IND	356	ISO 3166-2:IN	Yes
-Indonesia	ID	IDN	360	ISO 3166-2:ID	Yes
-Iran (Islamic Republic of)	IR	IRN	364	ISO 3166-2:IR	Yes
-Iraq	IQ	IRQ	368	ISO 3166-2:IQ	Yes
-Ireland	IE	IRL	372	ISO 3166-2:IE	Yes
-Isle of Man	IM	IMN	833	ISO 3166-2:IM	No
-Israel	IL	ISR	376	ISO 3166-2:IL	Yes
-Italy	IT	ITA	380	ISO 3166-2:IT	Yes
-Jamaica	JM	JAM	388	ISO 3166-2:JM	Yes
-Japan	JP	JPN	392	ISO 3166-2:JP	Yes
-Jersey	JE	JEY	832	ISO 3166-2:JE	No
-Jordan	JO	JOR	400	ISO 3166-2:JO	Yes
-Kazakhstan	KZ	KAZ	398	ISO 3166-2:KZ	Yes
-Kenya	KE	KEN	404	ISO 3166-2:KE	Yes
-Kiribati	KI	KIR	296	ISO 3166-2:KI	Yes
-Korea (Democratic People's Republic of)	KP	PRK	408	ISO 3166-2:KP	Yes
-Korea (Republic of)	KR	KOR	410	ISO 3166-2:KR	Yes
-Kuwait	KW	KWT	414	ISO 3166-2:KW	Yes
-Kyrgyzstan	KG	KGZ	417	ISO 3166-2:KG	Yes
-Lao People's Democratic Republic	LA	LAO	418	ISO 3166-2:LA	Yes
-Latvia	LV	LVA	428	ISO 3166-2:LV	Yes
-Lebanon	LB	LBN	422	ISO 3166-2:LB	Yes
-Lesotho	LS	LSO	426	ISO 3166-2:LS	Yes
-Liberia	LR	LBR	430	ISO 3166-2:LR	Yes
-Libya	LY	LBY	434	ISO 3166-2:LY	Yes
-Liechtenstein	LI	LIE	438	ISO 3166-2:LI	Yes
-Lithuania	LT	LTU	440	ISO 3166-2:LT	Yes
-Luxembourg	LU	LUX	442	ISO 3166-2:LU	Yes
-Macao	MO	MAC	446	ISO 3166-2:MO	No
-Madagascar	MG	MDG	450	ISO 3166-2:MG	Yes
-Malawi	MW	MWI	454	ISO 3166-2:MW	Yes
-Malaysia	MY	MYS	458	ISO 3166-2:MY	Yes
-Maldives	MV	MDV	462	ISO 3166-2:MV	Yes
-Mali	ML	MLI	466	ISO 3166-2:ML	Yes
-Malta	MT	MLT	470	ISO 3166-2:MT	Yes
-Marshall Islands	MH	MHL	584	ISO 3166-2:MH	Yes
-Martinique	MQ	MTQ	474	ISO 3166-2:MQ	No
-Mauritania	MR	MRT	478	ISO 3166-2:MR	Yes
-Mauritius	MU	MUS	480	ISO 3166-2:MU	Yes
-Mayotte	YT	MYT	175	ISO 3166-2:YT	No
-Mexico	MX	MEX	484	ISO 3166-2:MX	Yes
-Micronesia (Federated States of)	FM	FSM	583	ISO 3166-2:FM	Yes
-Moldova (Republic of)	MD	MDA	498	ISO 3166-2:MD	Yes
-Monaco	MC	MCO	492	ISO 3166-2:MC	Yes
-Mongolia	MN	MNG	496	ISO 3166-2:MN	Yes
-Montenegro	ME	MNE	499	ISO 3166-2:ME	Yes
-Montserrat	MS	MSR	500	ISO 3166-2:MS	No
-Morocco	MA	MAR	504	ISO 3166-2:MA	Yes
-Mozambique	MZ	MOZ	508	ISO 3166-2:MZ	Yes
-Myanmar	MM	MMR	104	ISO 3166-2:MM	Yes
-Namibia	NA	NAM	516	ISO 3166-2:NA	Yes
-Nauru	NR	NRU	520	ISO 3166-2:NR	Yes
-Nepal	NP	NPL	524	ISO 3166-2:NP	Yes
-Netherlands (Kingdom of the)	NL	NLD	528	ISO 3166-2:NL	Yes
-New Caledonia	NC	NCL	540	ISO 3166-2:NC	No
-New Zealand	NZ	NZL	554	ISO 3166-2:NZ	Yes
-Nicaragua	NI	NIC	558	ISO 3166-2:NI	Yes
-Niger	NE	NER	562	ISO 3166-2:NE	Yes
-Nigeria	NG	NGA	566	ISO 3166-2:NG	Yes
-Niue	NU	NIU	570	ISO 3166-2:NU	No
-Norfolk Island	NF	NFK	574	ISO 3166-2:NF	No
-North Macedonia	MK	MKD	807	ISO 3166-2:MK	Yes
-Northern Mariana Islands	MP	MNP	580	ISO 3166-2:MP	No
-Norway	NO	NOR	578	ISO 3166-2:NO	Yes
-Oman	OM	OMN	512	ISO 3166-2:OM	Yes
-Pakistan	PK	PAK	586	ISO 3166-2:PK	Yes
-Palau	PW	PLW	585	ISO 3166-2:PW	Yes
-Palestine (State of)	PS	PSE	275	ISO 3166-2:PS	No
-Panama	PA	PAN	591	ISO 3166-2:PA	Yes
-Papua New Guinea	PG	PNG	598	ISO 3166-2:PG	Yes
-Paraguay	PY	PRY	600	ISO 3166-2:PY	Yes
-Peru	PE	PER	604	ISO 3166-2:PE	Yes
-Philippines	PH	PHL	608	ISO 3166-2:PH	Yes
-Pitcairn	PN	PCN	612	ISO 3166-2:PN	No
-Poland	PL	POL	616	ISO 3166-2:PL	Yes
-Portugal	PT	PRT	620	ISO 3166-2:PT	Yes
-Puerto Rico	PR	PRI	630	ISO 3166-2:PR	No
-Qatar	QA	QAT	634	ISO 3166-2:QA	Yes
-Réunion	RE	REU	638	ISO 3166-2:RE	No
-Romania	RO	ROU	642	ISO 3166-2:RO	Yes
-Russian Federation	RU	RUS	643	ISO 3166-2:RU	Yes
-Rwanda	RW	RWA	646	ISO 3166-2:RW	Yes
-Saint Barthélemy	BL	BLM	652	ISO 3166-2:BL	No
-Saint Helena, Ascension and Tristan da Cunha	SH	SHN	654	ISO 3166-2:SH	No
-Saint Kitts and Nevis	KN	KNA	659	ISO 3166-2:KN	Yes
-Saint Lucia	LC	LCA	662	ISO 3166-2:LC	Yes
-Saint Martin (French part)	MF	MAF	663	ISO 3166-2:MF	No
-Saint Pierre and Miquelon	PM	SPM	666	ISO 3166-2:PM	No
-Saint Vincent and the Grenadines	VC	VCT	670	ISO 3166-2:VC	Yes
-Samoa	WS	WSM	882	ISO 3166-2:WS	Yes
-San Marino	SM	SMR	674	ISO 3166-2:SM	Yes
-Sao Tome and Principe	ST	STP	678	ISO 3166-2:ST	Yes
-Saudi Arabia	SA	SAU	682	ISO 3166-2:SA	Yes
-Senegal	SN	SEN	686	ISO 3166-2:SN	Yes
-Serbia	RS	SRB	688	ISO 3166-2:RS	Yes
-Seychelles	SC	SYC	690	ISO 3166-2:SC	Yes
-Sierra Leone	SL	SLE	694	ISO 3166-2:SL	Yes
-Singapore	SG	SGP	702	ISO 3166-2:SG	Yes
-Sint Maarten (Dutch part)	SX	SXM	534	ISO 3166-2:SX	No
-Slovakia	SK	SVK	703	ISO 3166-2:SK	Yes
-Slovenia	SI	SVN	705	ISO 3166-2:SI	Yes
-Solomon Islands	SB	SLB	090	ISO 3166-2:SB	Yes
-Somalia	SO	SOM	706	ISO 3166-2:SO	Yes
-South Africa	ZA	ZAF	710	ISO 3166-2:ZA	Yes
-South Georgia and the South Sandwich Islands	GS	SGS	239	ISO 3166-2:GS	No
-South Sudan	SS	SSD	728	ISO 3166-2:SS	Yes
-Spain	ES	ESP	724	ISO 3166-2:ES	Yes
-Sri Lanka	LK	LKA	144	ISO 3166-2:LK	Yes
-Sudan	SD	SDN	729	ISO 3166-2:SD	Yes
-Suriname	SR	SUR	740	ISO 3166-2:SR	Yes
-Svalbard and Jan Mayen	SJ	SJM	744	ISO 3166-2:SJ	No
-Sweden	SE	SWE	752	ISO 3166-2:SE	Yes
-Switzerland	CH	CHE	756	ISO 3166-2:CH	Yes
-Syrian Arab Republic	SY	SYR	760	ISO 3166-2:SY	Yes
-Taiwan (Province of China)	TW	TWN	158	ISO 3166-2:TW	No
-Tajikistan	TJ	TJK	762	ISO 3166-2:TJ	Yes
-Tanzania (United Republic of)	TZ	TZA	834	ISO 3166-2:TZ	Yes
-Thailand	TH	THA	764	ISO 3166-2:TH	Yes
-Timor-Leste	TL	TLS	626	ISO 3166-2:TL	Yes
-Togo	TG	TGO	768	ISO 3166-2:TG	Yes
-Tokelau	TK	TKL	772	ISO 3166-2:TK	No
-Tonga	TO	TON	776	ISO 3166-2:TO	Yes
-Trinidad and Tobago	TT	TTO	780	ISO 3166-2:TT	Yes
-Tunisia	TN	TUN	788	ISO 3166-2:TN	Yes
-Türkiye	TR	TUR	792	ISO 3166-2:TR	Yes
-Turkmenistan	TM	TKM	795	ISO 3166-2:TM	Yes
-Turks and Caicos Islands	TC	TCA	796	ISO 3166-2:TC	No
-Tuvalu	TV	TUV	798	ISO 3166-2:TV	Yes
-Uganda	UG	UGA	800	ISO 3166-2:UG	Yes
-Ukraine	UA	UKR	804	ISO 3166-2:UA	Yes
-United Arab Emirates	AE	ARE	784	ISO 3166-2:AE	Yes
-United Kingdom of Great Britain and Northern Ireland	GB	GBR	826	ISO 3166-2:GB	Yes
-United States of America	US	USA	840	ISO 3166-2:US	Yes
-United States Minor Outlying Islands	UM	UMI	581	ISO 3166-2:UM	No
-Uruguay	UY	URY	858	ISO 3166-2:UY	Yes
-Uzbekistan	UZ	UZB	860	ISO 3166-2:UZ	Yes
-Vanuatu	VU	VUT	548	ISO 3166-2:VU	Yes
-Venezuela (Bolivarian Republic of)	VE	VEN	862	ISO 3166-2:VE	Yes
-Viet Nam	VN	VNM	704	ISO 3166-2:VN	Yes
-Virgin Islands (British)	VG	VGB	092	ISO 3166-2:VG	No
-Virgin Islands (U.S.)	VI	VIR	850	ISO 3166-2:VI	No
-Wallis and Futuna	WF	WLF	876	ISO 3166-2:WF	No
-Western Sahara	EH	ESH	732	ISO 3166-2:EH	No
-Yemen	YE	YEM	887	ISO 3166-2:YE	Yes
-Zambia	ZM	ZMB	894	ISO 3166-2:ZM	Yes
-Zimbabwe	ZW	ZWE	716	ISO 3166-2:ZW	Yes
+Afghanistan	AF	AFG	004	ISO 3166-2:AF	Yes	Islamic Republic of Afghanistan	Afghanistan
+Åland Islands	AX	ALA	248	ISO 3166-2:AX	No		Åland (les Îles)
+Albania	AL	ALB	008	ISO 3166-2:AL	Yes	Republic of Albania	Albanie
+Algeria	DZ	DZA	012	ISO 3166-2:DZ	Yes	People's Democratic Republic of Algeria	Algérie
+American Samoa	AS	ASM	016	ISO 3166-2:AS	No		Samoa américaines
+Andorra	AD	AND	020	ISO 3166-2:AD	Yes	Principality of Andorra	Andorre
+Angola	AO	AGO	024	ISO 3166-2:AO	Yes	Republic of Angola	Angola
+Anguilla	AI	AIA	660	ISO 3166-2:AI	No		Anguilla
+Antarctica	AQ	ATA	010	ISO 3166-2:AQ	No		Antarctique
+Antigua and Barbuda	AG	ATG	028	ISO 3166-2:AG	Yes	Antigua and Barbuda	Antigua-et-Barbuda
+Argentina	AR	ARG	032	ISO 3166-2:AR	Yes	Argentine Republic	Argentine
+Armenia	AM	ARM	051	ISO 3166-2:AM	Yes	Republic of Armenia	Arménie
+Aruba	AW	ABW	533	ISO 3166-2:AW	No		Aruba
+Australia	AU	AUS	036	ISO 3166-2:AU	Yes	Commonwealth of Australia	Australie
+Austria	AT	AUT	040	ISO 3166-2:AT	Yes	Republic of Austria	Autriche
+Azerbaijan	AZ	AZE	031	ISO 3166-2:AZ	Yes	Republic of Azerbaijan	Azerbaïdjan
+Bahamas	BS	BHS	044	ISO 3166-2:BS	Yes	Commonwealth of the Bahamas	Bahamas
+Bahrain	BH	BHR	048	ISO 3166-2:BH	Yes	Kingdom of Bahrain	Bahreïn
+Bangladesh	BD	BGD	050	ISO 3166-2:BD	Yes	People's Republic of Bangladesh	Bangladesh
+Barbados	BB	BRB	052	ISO 3166-2:BB	Yes	Barbados	Barbade
+Belarus	BY	BLR	112	ISO 3166-2:BY	Yes	Republic of Belarus	Bélarus
+Belgium	BE	BEL	056	ISO 3166-2:BE	Yes	Kingdom of Belgium	Belgique
+Belize	BZ	BLZ	084	ISO 3166-2:BZ	Yes	Belize	Belize
+Benin	BJ	BEN	204	ISO 3166-2:BJ	Yes	Republic of Benin	Bénin
+Bermuda	BM	BMU	060	ISO 3166-2:BM	No		Bermudes
+Bhutan	BT	BTN	064	ISO 3166-2:BT	Yes	Kingdom of Bhutan	Bhoutan
+Bolivia (Plurinational State of)	BO	BOL	068	ISO 3166-2:BO	Yes	Plurinational State of Bolivia	Bolivie (État plurinational de)
+Bonaire, Sint Eustatius and Saba	BQ	BES	535	ISO 3166-2:BQ	No	Bonaire, Sint Eustatius and Saba	Bonaire, Saint-Eustache et Saba
+Bosnia and Herzegovina	BA	BIH	070	ISO 3166-2:BA	Yes	Republic of Bosnia and Herzegovina	Bosnie-Herzégovine
+Botswana	BW	BWA	072	ISO 3166-2:BW	Yes	Republic of Botswana	Botswana
+Bouvet Island	BV	BVT	074	ISO 3166-2:BV	No		Bouvet (l'Île)
+Brazil	BR	BRA	076	ISO 3166-2:BR	Yes	Federative Republic of Brazil	Brésil
+British Indian Ocean Territory	IO	IOT	086	ISO 3166-2:IO	No		Indien (le Territoire britannique de l'océan)
+Brunei Darussalam	BN	BRN	096	ISO 3166-2:BN	Yes	Brunei Darussalam	Brunéi Darussalam
+Bulgaria	BG	BGR	100	ISO 3166-2:BG	Yes	Republic of Bulgaria	Bulgarie
+Burkina Faso	BF	BFA	854	ISO 3166-2:BF	Yes	Burkina Faso	Burkina Faso
+Burundi	BI	BDI	108	ISO 3166-2:BI	Yes	Republic of Burundi	Burundi
+Cabo Verde	CV	CPV	132	ISO 3166-2:CV	Yes	Republic of Cabo Verde	Cabo Verde
+Cambodia	KH	KHM	116	ISO 3166-2:KH	Yes	Kingdom of Cambodia	Cambodge
+Cameroon	CM	CMR	120	ISO 3166-2:CM	Yes	Republic of Cameroon	Cameroun
+Canada	CA	CAN	124	ISO 3166-2:CA	Yes	Canada	Canada
+Cayman Islands	KY	CYM	136	ISO 3166-2:KY	No		Caïmans (les Îles)
+Central African Republic	CF	CAF	140	ISO 3166-2:CF	Yes	Central African Republic	République centrafricaine
+Chad	TD	TCD	148	ISO 3166-2:TD	Yes	Republic of Chad	Tchad
+Chile	CL	CHL	152	ISO 3166-2:CL	Yes	Republic of Chile	Chili
+China	CN	CHN	156	ISO 3166-2:CN	Yes	People's Republic of China	Chine
+Christmas Island	CX	CXR	162	ISO 3166-2:CX	No		Christmas (l'Île)
+Cocos (Keeling) Islands	CC	CCK	166	ISO 3166-2:CC	No		Cocos (les Îles)/ Keeling (les Îles)
+Colombia	CO	COL	170	ISO 3166-2:CO	Yes	Republic of Colombia	Colombie
+Comoros	KM	COM	174	ISO 3166-2:KM	Yes	Union of the Comoros	Comores
+Congo	CG	COG	178	ISO 3166-2:CG	Yes	Republic of the Congo	Congo
+Congo (Democratic Republic of the)	CD	COD	180	ISO 3166-2:CD	Yes	Democratic Republic of the Congo	Congo (la République démocratique du)
+Cook Islands	CK	COK	184	ISO 3166-2:CK	No		Cook (les Îles)
+Costa Rica	CR	CRI	188	ISO 3166-2:CR	Yes	Republic of Costa Rica	Costa Rica
+Côte d'Ivoire	CI	CIV	384	ISO 3166-2:CI	Yes	Republic of Côte d'Ivoire	Côte d'Ivoire
+Croatia	HR	HRV	191	ISO 3166-2:HR	Yes	Republic of Croatia	Croatie
+Cuba	CU	CUB	192	ISO 3166-2:CU	Yes	Republic of Cuba	Cuba
+Curaçao	CW	CUW	531	ISO 3166-2:CW	No	Curaçao	Curaçao
+Cyprus	CY	CYP	196	ISO 3166-2:CY	Yes	Republic of Cyprus	Chypre
+Czechia	CZ	CZE	203	ISO 3166-2:CZ	Yes	Czech Republic	Tchéquie
+Denmark	DK	DNK	208	ISO 3166-2:DK	Yes	Kingdom of Denmark	Danemark
+Djibouti	DJ	DJI	262	ISO 3166-2:DJ	Yes	Republic of Djibouti	Djibouti
+Dominica	DM	DMA	212	ISO 3166-2:DM	Yes	Commonwealth of Dominica	Dominique
+Dominican Republic	DO	DOM	214	ISO 3166-2:DO	Yes	Dominican Republic	dominicaine (la République)
+Ecuador	EC	ECU	218	ISO 3166-2:EC	Yes	Republic of Ecuador	Équateur
+Egypt	EG	EGY	818	ISO 3166-2:EG	Yes	Arab Republic of Egypt	Égypte
+El Salvador	SV	SLV	222	ISO 3166-2:SV	Yes	Republic of El Salvador	El Salvador
+Equatorial Guinea	GQ	GNQ	226	ISO 3166-2:GQ	Yes	Republic of Equatorial Guinea	Guinée équatoriale
+Eritrea	ER	ERI	232	ISO 3166-2:ER	Yes	State of Eritrea	Érythrée
+Estonia	EE	EST	233	ISO 3166-2:EE	Yes	Republic of Estonia	Estonie
+Eswatini	SZ	SWZ	748	ISO 3166-2:SZ	Yes	Kingdom of Eswatini	Eswatini
+Ethiopia	ET	ETH	231	ISO 3166-2:ET	Yes	Federal Democratic Republic of Ethiopia	Éthiopie
+Falkland Islands (Malvinas)	FK	FLK	238	ISO 3166-2:FK	No		Falkland (les Îles)/Malouines (les Îles)
+Faroe Islands	FO	FRO	234	ISO 3166-2:FO	No		Féroé (les Îles)
+Fiji	FJ	FJI	242	ISO 3166-2:FJ	Yes	Republic of Fiji	Fidji
+Finland	FI	FIN	246	ISO 3166-2:FI	Yes	Republic of Finland	Finlande
+France	FR	FRA	250	ISO 3166-2:FR	Yes	French Republic	France
+French Guiana	GF	GUF	254	ISO 3166-2:GF	No		Guyane française
+French Polynesia	PF	PYF	258	ISO 3166-2:PF	No		Polynésie française
+French Southern Territories	TF	ATF	260	ISO 3166-2:TF	No		Terres australes françaises
+Gabon	GA	GAB	266	ISO 3166-2:GA	Yes	Gabonese Republic	Gabon
+Gambia	GM	GMB	270	ISO 3166-2:GM	Yes	Republic of the Gambia	Gambie
+Georgia	GE	GEO	268	ISO 3166-2:GE	Yes	Georgia	Géorgie
+Germany	DE	DEU	276	ISO 3166-2:DE	Yes	Federal Republic of Germany	Allemagne
+Ghana	GH	GHA	288	ISO 3166-2:GH	Yes	Republic of Ghana	Ghana
+Gibraltar	GI	GIB	292	ISO 3166-2:GI	No		Gibraltar
+Greece	GR	GRC	300	ISO 3166-2:GR	Yes	Hellenic Republic	Grèce
+Greenland	GL	GRL	304	ISO 3166-2:GL	No		Groenland
+Grenada	GD	GRD	308	ISO 3166-2:GD	Yes	Grenada	Grenade
+Guadeloupe	GP	GLP	312	ISO 3166-2:GP	No		Guadeloupe
+Guam	GU	GUM	316	ISO 3166-2:GU	No		Guam
+Guatemala	GT	GTM	320	ISO 3166-2:GT	Yes	Republic of Guatemala	Guatemala
+Guernsey	GG	GGY	831	ISO 3166-2:GG	No		Guernesey
+Guinea	GN	GIN	324	ISO 3166-2:GN	Yes	Republic of Guinea	Guinée
+Guinea-Bissau	GW	GNB	624	ISO 3166-2:GW	Yes	Republic of Guinea-Bissau	Guinée-Bissau
+Guyana	GY	GUY	328	ISO 3166-2:GY	Yes	Republic of Guyana	Guyana
+Haiti	HT	HTI	332	ISO 3166-2:HT	Yes	Republic of Haiti	Haïti
+Heard Island and McDonald Islands	HM	HMD	334	ISO 3166-2:HM	No		Heard-et-Îles MacDonald (l'Île)
+Holy See	VA	VAT	336	ISO 3166-2:VA	Yes	Holy See	Saint-Siège
+Honduras	HN	HND	340	ISO 3166-2:HN	Yes	Republic of Honduras	Honduras
+Hong Kong	HK	HKG	344	ISO 3166-2:HK	No	Hong Kong Special Administrative Region of China	Hong Kong
+Hungary	HU	HUN	348	ISO 3166-2:HU	Yes	Hungary	Hongrie
+Iceland	IS	ISL	352	ISO 3166-2:IS	Yes	Republic of Iceland	Islande
+India	IN	IND	356	ISO 3166-2:IN	Yes	Republic of India	Inde
+Indonesia	ID	IDN	360	ISO 3166-2:ID	Yes	Republic of Indonesia	Indonésie
+Iran (Islamic Republic of)	IR	IRN	364	ISO 3166-2:IR	Yes	Islamic Republic of Iran	Iran (République Islamique d')
+Iraq	IQ	IRQ	368	ISO 3166-2:IQ	Yes	Republic of Iraq	Iraq
+Ireland	IE	IRL	372	ISO 3166-2:IE	Yes	Ireland	Irlande
+Isle of Man	IM	IMN	833	ISO 3166-2:IM	No		Île de Man
+Israel	IL	ISR	376	ISO 3166-2:IL	Yes	State of Israel	Israël
+Italy	IT	ITA	380	ISO 3166-2:IT	Yes	Italian Republic	Italie
+Jamaica	JM	JAM	388	ISO 3166-2:JM	Yes	Jamaica	Jamaïque
+Japan	JP	JPN	392	ISO 3166-2:JP	Yes	Japan	Japon
+Jersey	JE	JEY	832	ISO 3166-2:JE	No		Jersey
+Jordan	JO	JOR	400	ISO 3166-2:JO	Yes	Hashemite Kingdom of Jordan	Jordanie
+Kazakhstan	KZ	KAZ	398	ISO 3166-2:KZ	Yes	Republic of Kazakhstan	Kazakhstan
+Kenya	KE	KEN	404	ISO 3166-2:KE	Yes	Republic of Kenya	Kenya
+Kiribati	KI	KIR	296	ISO 3166-2:KI	Yes	Republic of Kiribati	Kiribati
+Korea (Democratic People's Republic of)	KP	PRK	408	ISO 3166-2:KP	Yes	Democratic People's Republic of Korea	Corée (la République populaire démocratique de)
+Korea (Republic of)	KR	KOR	410	ISO 3166-2:KR	Yes	Republic of Korea	Corée (la République de)
+Kuwait	KW	KWT	414	ISO 3166-2:KW	Yes	State of Kuwait	Koweït
+Kyrgyzstan	KG	KGZ	417	ISO 3166-2:KG	Yes	Kyrgyz Republic	Kirghizistan
+Lao People's Democratic Republic	LA	LAO	418	ISO 3166-2:LA	Yes	Lao People's Democratic Republic	Lao (la République démocratique populaire)
+Latvia	LV	LVA	428	ISO 3166-2:LV	Yes	Republic of Latvia	Lettonie
+Lebanon	LB	LBN	422	ISO 3166-2:LB	Yes	Lebanese Republic	Liban
+Lesotho	LS	LSO	426	ISO 3166-2:LS	Yes	Kingdom of Lesotho	Lesotho
+Liberia	LR	LBR	430	ISO 3166-2:LR	Yes	Republic of Liberia	Libéria
+Libya	LY	LBY	434	ISO 3166-2:LY	Yes	State of Libya	Libye
+Liechtenstein	LI	LIE	438	ISO 3166-2:LI	Yes	Principality of Liechtenstein	Liechtenstein
+Lithuania	LT	LTU	440	ISO 3166-2:LT	Yes	Republic of Lithuania	Lituanie
+Luxembourg	LU	LUX	442	ISO 3166-2:LU	Yes	Grand Duchy of Luxembourg	Luxembourg
+Macao	MO	MAC	446	ISO 3166-2:MO	No	Macao Special Administrative Region of China	Macao
+Madagascar	MG	MDG	450	ISO 3166-2:MG	Yes	Republic of Madagascar	Madagascar
+Malawi	MW	MWI	454	ISO 3166-2:MW	Yes	Republic of Malawi	Malawi
+Malaysia	MY	MYS	458	ISO 3166-2:MY	Yes	Malaysia	Malaisie
+Maldives	MV	MDV	462	ISO 3166-2:MV	Yes	Republic of Maldives	Maldives
+Mali	ML	MLI	466	ISO 3166-2:ML	Yes	Republic of Mali	Mali
+Malta	MT	MLT	470	ISO 3166-2:MT	Yes	Republic of Malta	Malte
+Marshall Islands	MH	MHL	584	ISO 3166-2:MH	Yes	Republic of the Marshall Islands	Marshall (les Îles)
+Martinique	MQ	MTQ	474	ISO 3166-2:MQ	No		Martinique
+Mauritania	MR	MRT	478	ISO 3166-2:MR	Yes	Islamic Republic of Mauritania	Mauritanie
+Mauritius	MU	MUS	480	ISO 3166-2:MU	Yes	Republic of Mauritius	Maurice
+Mayotte	YT	MYT	175	ISO 3166-2:YT	No		Mayotte
+Mexico	MX	MEX	484	ISO 3166-2:MX	Yes	United Mexican States	Mexique
+Micronesia (Federated States of)	FM	FSM	583	ISO 3166-2:FM	Yes	Federated States of Micronesia	Micronésie (États fédérés de)
+Moldova (Republic of)	MD	MDA	498	ISO 3166-2:MD	Yes	Republic of Moldova	Moldova (la République de)
+Monaco	MC	MCO	492	ISO 3166-2:MC	Yes	Principality of Monaco	Monaco
+Mongolia	MN	MNG	496	ISO 3166-2:MN	Yes	Mongolia	Mongolie
+Montenegro	ME	MNE	499	ISO 3166-2:ME	Yes	Montenegro	Monténégro
+Montserrat	MS	MSR	500	ISO 3166-2:MS	No		Montserrat
+Morocco	MA	MAR	504	ISO 3166-2:MA	Yes	Kingdom of Morocco	Maroc
+Mozambique	MZ	MOZ	508	ISO 3166-2:MZ	Yes	Republic of Mozambique	Mozambique
+Myanmar	MM	MMR	104	ISO 3166-2:MM	Yes	Republic of the Union of Myanmar	Myanmar
+Namibia	NA	NAM	516	ISO 3166-2:NA	Yes	Republic of Namibia	Namibie
+Nauru	NR	NRU	520	ISO 3166-2:NR	Yes	Republic of Nauru	Nauru
+Nepal	NP	NPL	524	ISO 3166-2:NP	Yes	Federal Democratic Republic of Nepal	Népal
+Netherlands (Kingdom of the)	NL	NLD	528	ISO 3166-2:NL	Yes	Kingdom of the Netherlands	Pays-Bas (Royaume des)
+New Caledonia	NC	NCL	540	ISO 3166-2:NC	No		Nouvelle-Calédonie
+New Zealand	NZ	NZL	554	ISO 3166-2:NZ	Yes	New Zealand	Nouvelle-Zélande
+Nicaragua	NI	NIC	558	ISO 3166-2:NI	Yes	Republic of Nicaragua	Nicaragua
+Niger	NE	NER	562	ISO 3166-2:NE	Yes	Republic of the Niger	Niger
+Nigeria	NG	NGA	566	ISO 3166-2:NG	Yes	Federal Republic of Nigeria	Nigéria
+Niue	NU	NIU	570	ISO 3166-2:NU	No	Niue	Niue
+Norfolk Island	NF	NFK	574	ISO 3166-2:NF	No		Norfolk (l'Île)
+North Macedonia	MK	MKD	807	ISO 3166-2:MK	Yes	Republic of North Macedonia	Macédoine du Nord
+Northern Mariana Islands	MP	MNP	580	ISO 3166-2:MP	No	Commonwealth of the Northern Mariana Islands	Mariannes du Nord (les Îles)
+Norway	NO	NOR	578	ISO 3166-2:NO	Yes	Kingdom of Norway	Norvège
+Oman	OM	OMN	512	ISO 3166-2:OM	Yes	Sultanate of Oman	Oman
+Pakistan	PK	PAK	586	ISO 3166-2:PK	Yes	Islamic Republic of Pakistan	Pakistan
+Palau	PW	PLW	585	ISO 3166-2:PW	Yes	Republic of Palau	Palaos
+Palestine (State of)	PS	PSE	275	ISO 3166-2:PS	No	State of Palestine	Palestine, État de
+Panama	PA	PAN	591	ISO 3166-2:PA	Yes	Republic of Panama	Panama
+Papua New Guinea	PG	PNG	598	ISO 3166-2:PG	Yes	Independent State of Papua New Guinea	Papouasie-Nouvelle-Guinée
+Paraguay	PY	PRY	600	ISO 3166-2:PY	Yes	Republic of Paraguay	Paraguay
+Peru	PE	PER	604	ISO 3166-2:PE	Yes	Republic of Peru	Pérou
+Philippines	PH	PHL	608	ISO 3166-2:PH	Yes	Republic of the Philippines	Philippines
+Pitcairn	PN	PCN	612	ISO 3166-2:PN	No		Pitcairn
+Poland	PL	POL	616	ISO 3166-2:PL	Yes	Republic of Poland	Pologne
+Portugal	PT	PRT	620	ISO 3166-2:PT	Yes	Portuguese Republic	Portugal
+Puerto Rico	PR	PRI	630	ISO 3166-2:PR	No		Porto Rico
+Qatar	QA	QAT	634	ISO 3166-2:QA	Yes	State of Qatar	Qatar
+Réunion	RE	REU	638	ISO 3166-2:RE	No		Réunion
+Romania	RO	ROU	642	ISO 3166-2:RO	Yes	Romania	Roumanie
+Russian Federation	RU	RUS	643	ISO 3166-2:RU	Yes	Russian Federation	Russie (la Fédération de)
+Rwanda	RW	RWA	646	ISO 3166-2:RW	Yes	Rwandese Republic	Rwanda
+Saint Barthélemy	BL	BLM	652	ISO 3166-2:BL	No		Saint-Barthélemy
+Saint Helena, Ascension and Tristan da Cunha	SH	SHN	654	ISO 3166-2:SH	No		Sainte-Hélène, Ascension et Tristan da Cunha
+Saint Kitts and Nevis	KN	KNA	659	ISO 3166-2:KN	Yes	Saint Kitts and Nevis	Saint-Kitts-et-Nevis
+Saint Lucia	LC	LCA	662	ISO 3166-2:LC	Yes	Saint Lucia	Sainte-Lucie
+Saint Martin (French part)	MF	MAF	663	ISO 3166-2:MF	No		Saint-Martin (partie française)
+Saint Pierre and Miquelon	PM	SPM	666	ISO 3166-2:PM	No		Saint-Pierre-et-Miquelon
+Saint Vincent and the Grenadines	VC	VCT	670	ISO 3166-2:VC	Yes	Saint Vincent and the Grenadines	Saint-Vincent-et-les Grenadines
+Samoa	WS	WSM	882	ISO 3166-2:WS	Yes	Independent State of Samoa	Samoa
+San Marino	SM	SMR	674	ISO 3166-2:SM	Yes	Republic of San Marino	Saint-Marin
+Sao Tome and Principe	ST	STP	678	ISO 3166-2:ST	Yes	Democratic Republic of Sao Tome and Principe	Sao Tomé-et-Principe
+Saudi Arabia	SA	SAU	682	ISO 3166-2:SA	Yes	Kingdom of Saudi Arabia	Arabie saoudite
+Senegal	SN	SEN	686	ISO 3166-2:SN	Yes	Republic of Senegal	Sénégal
+Serbia	RS	SRB	688	ISO 3166-2:RS	Yes	Republic of Serbia	Serbie
+Seychelles	SC	SYC	690	ISO 3166-2:SC	Yes	Republic of Seychelles	Seychelles
+Sierra Leone	SL	SLE	694	ISO 3166-2:SL	Yes	Republic of Sierra Leone	Sierra Leone
+Singapore	SG	SGP	702	ISO 3166-2:SG	Yes	Republic of Singapore	Singapour
+Sint Maarten (Dutch part)	SX	SXM	534	ISO 3166-2:SX	No	Sint Maarten (Dutch part)	Saint-Martin (partie néerlandaise)
+Slovakia	SK	SVK	703	ISO 3166-2:SK	Yes	Slovak Republic	Slovaquie
+Slovenia	SI	SVN	705	ISO 3166-2:SI	Yes	Republic of Slovenia	Slovénie
+Solomon Islands	SB	SLB	090	ISO 3166-2:SB	Yes	Solomon Islands	Salomon (les Îles)
+Somalia	SO	SOM	706	ISO 3166-2:SO	Yes	Federal Republic of Somalia	Somalie
+South Africa	ZA	ZAF	710	ISO 3166-2:ZA	Yes	Republic of South Africa	Afrique du Sud
+South Georgia and the South Sandwich Islands	GS	SGS	239	ISO 3166-2:GS	No		Géorgie du Sud-et-les Îles Sandwich du Sud
+South Sudan	SS	SSD	728	ISO 3166-2:SS	Yes	Republic of South Sudan	Soudan du Sud
+Spain	ES	ESP	724	ISO 3166-2:ES	Yes	Kingdom of Spain	Espagne
+Sri Lanka	LK	LKA	144	ISO 3166-2:LK	Yes	Democratic Socialist Republic of Sri Lanka	Sri Lanka
+Sudan	SD	SDN	729	ISO 3166-2:SD	Yes	Republic of the Sudan	Soudan
+Suriname	SR	SUR	740	ISO 3166-2:SR	Yes	Republic of Suriname	Suriname
+Svalbard and Jan Mayen	SJ	SJM	744	ISO 3166-2:SJ	No		Svalbard et l'Île Jan Mayen
+Sweden	SE	SWE	752	ISO 3166-2:SE	Yes	Kingdom of Sweden	Suède
+Switzerland	CH	CHE	756	ISO 3166-2:CH	Yes	Swiss Confederation	Suisse
+Syrian Arab Republic	SY	SYR	760	ISO 3166-2:SY	Yes	Syrian Arab Republic	République arabe syrienne
+Taiwan (Province of China)	TW	TWN	158	ISO 3166-2:TW	No	Taiwan, Province of China	Taïwan (Province de Chine)
+Tajikistan	TJ	TJK	762	ISO 3166-2:TJ	Yes	Republic of Tajikistan	Tadjikistan
+Tanzania (United Republic of)	TZ	TZA	834	ISO 3166-2:TZ	Yes	United Republic of Tanzania	Tanzanie (la République-Unie de)
+Thailand	TH	THA	764	ISO 3166-2:TH	Yes	Kingdom of Thailand	Thaïlande
+Timor-Leste	TL	TLS	626	ISO 3166-2:TL	Yes	Democratic Republic of Timor-Leste	Timor-Leste
+Togo	TG	TGO	768	ISO 3166-2:TG	Yes	Togolese Republic	Togo
+Tokelau	TK	TKL	772	ISO 3166-2:TK	No		Tokelau
+Tonga	TO	TON	776	ISO 3166-2:TO	Yes	Kingdom of Tonga	Tonga
+Trinidad and Tobago	TT	TTO	780	ISO 3166-2:TT	Yes	Republic of Trinidad and Tobago	Trinité-et-Tobago
+Tunisia	TN	TUN	788	ISO 3166-2:TN	Yes	Republic of Tunisia	Tunisie
+Türkiye	TR	TUR	792	ISO 3166-2:TR	Yes	Republic of Türkiye	Türkiye
+Turkmenistan	TM	TKM	795	ISO 3166-2:TM	Yes	Turkmenistan	Turkménistan
+Turks and Caicos Islands	TC	TCA	796	ISO 3166-2:TC	No		Turks-et-Caïcos (les Îles)
+Tuvalu	TV	TUV	798	ISO 3166-2:TV	Yes	Tuvalu	Tuvalu
+Uganda	UG	UGA	800	ISO 3166-2:UG	Yes	Republic of Uganda	Ouganda
+Ukraine	UA	UKR	804	ISO 3166-2:UA	Yes	Ukraine	Ukraine
+United Arab Emirates	AE	ARE	784	ISO 3166-2:AE	Yes	United Arab Emirates	Émirats arabes unis
+United Kingdom of Great Britain and Northern Ireland	GB	GBR	826	ISO 3166-2:GB	Yes	United Kingdom of Great Britain and Northern Ireland	Royaume-Uni de Grande-Bretagne et d'Irlande du Nord
+United States of America	US	USA	840	ISO 3166-2:US	Yes	United States of America	États-Unis d'Amérique
+United States Minor Outlying Islands	UM	UMI	581	ISO 3166-2:UM	No		Îles mineures éloignées des États-Unis
+Uruguay	UY	URY	858	ISO 3166-2:UY	Yes	Eastern Republic of Uruguay	Uruguay
+Uzbekistan	UZ	UZB	860	ISO 3166-2:UZ	Yes	Republic of Uzbekistan	Ouzbékistan
+Vanuatu	VU	VUT	548	ISO 3166-2:VU	Yes	Republic of Vanuatu	Vanuatu
+Venezuela (Bolivarian Republic of)	VE	VEN	862	ISO 3166-2:VE	Yes	Bolivarian Republic of Venezuela	Venezuela (République bolivarienne du)
+Viet Nam	VN	VNM	704	ISO 3166-2:VN	Yes	Socialist Republic of Viet Nam	Viet Nam
+Virgin Islands (British)	VG	VGB	092	ISO 3166-2:VG	No	British Virgin Islands	Vierges britanniques (les Îles)
+Virgin Islands (U.S.)	VI	VIR	850	ISO 3166-2:VI	No	Virgin Islands of the United States	Vierges des États-Unis (les Îles)
+Wallis and Futuna	WF	WLF	876	ISO 3166-2:WF	No		Wallis-et-Futuna
+Western Sahara	EH	ESH	732	ISO 3166-2:EH	No		Sahara occidental
+Yemen	YE	YEM	887	ISO 3166-2:YE	Yes	Republic of Yemen	Yémen
+Zambia	ZM	ZMB	894	ISO 3166-2:ZM	Yes	Republic of Zambia	Zambie
+Zimbabwe	ZW	ZWE	716	ISO 3166-2:ZW	Yes	Republic of Zimbabwe	Zimbabwe
 """
+
+
+def option(s):
+    return 'Some("%s")' % s if s else "None"
+
+
 print(pre_code)
 
 for x in a.split("\n"):
@@ -460,9 +552,11 @@ pub const %s: CountryCode = CountryCode {
     alpha2: "%s",
     alpha3: "%s",
     numeric: %s,
+    official_name: %s,
+    name_fr: "%s",
 };
 """
-        % (ts[1], ts[0], ts[1], ts[2], int(ts[3]))
+        % (ts[1], ts[0], ts[1], ts[2], int(ts[3]), option(ts[6]), ts[7])
     )
 
 
@@ -626,3 +720,107 @@ print(
 ];
 """
 )
+
+print(
+    """
+/// Every Country defined by ISO 3166-1, one variant per entry in `ALL`.
+///
+/// The discriminant is the ISO 3166-1 numeric code. Unlike comparing `alpha2`
+/// strings, a misspelt variant does not compile. The enum is `#[non_exhaustive]`
+/// so that new ISO assignments are not a breaking change; a `match` outside
+/// this crate needs a wildcard arm.
+/// #Sample
+/// ```
+/// use rust_iso3166::{Country, CountryCode};
+/// let country = Country::AU;
+/// assert_eq!(36, country as u16);
+/// assert_eq!("AUS", country.country_code().alpha3);
+/// let code: CountryCode = Country::US.into();
+/// assert_eq!(Ok(Country::US), Country::try_from(code));
+/// ```
+#[non_exhaustive]
+#[repr(u16)]
+#[derive(Debug, Ord, PartialOrd, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Country {
+"""
+)
+for x in a.split("\n"):
+    ts = x.split("\t")
+    if len(ts) < 2:
+        print(x)
+        continue
+    print("    ///%s\n    %s = %s," % (ts[0], ts[1], int(ts[3])))
+print(
+    """
+}
+
+impl Country {
+    ///Return the CountryCode data for this Country
+    pub const fn country_code(self) -> CountryCode {
+        match self {
+"""
+)
+for x in a.split("\n"):
+    ts = x.split("\t")
+    if len(ts) < 2:
+        print(x)
+        continue
+    print("            Country::%s => %s," % (ts[1], ts[1]))
+print(
+    """
+        }
+    }
+
+    ///Return the Country with the given numeric code, if exists.
+    pub const fn from_numeric(numeric: u16) -> Option<Country> {
+        match numeric {
+"""
+)
+for x in a.split("\n"):
+    ts = x.split("\t")
+    if len(ts) < 2:
+        print(x)
+        continue
+    print("            %s => Some(Country::%s)," % (int(ts[3]), ts[1]))
+print(
+    """
+            _ => None,
+        }
+    }
+}
+
+impl From<Country> for CountryCode {
+    fn from(country: Country) -> Self {
+        country.country_code()
+    }
+}
+
+/// Fails with the given CountryCode if it is not one of the entries in `ALL`.
+impl TryFrom<CountryCode> for Country {
+    type Error = CountryCode;
+
+    fn try_from(code: CountryCode) -> Result<Self, Self::Error> {
+        match Country::from_numeric(code.numeric) {
+            Some(country) if country.country_code() == code => Ok(country),
+            _ => Err(code),
+        }
+    }
+}
+"""
+)
+print(
+    """
+impl CountryCode {
+    ///Return true for sovereign states, the "Independent" column of ISO 3166-1
+    pub fn is_independent(&self) -> bool {
+        !matches!(
+            self.alpha2,"""
+)
+dependent = [x.split("\t")[1] for x in a.split("\n") if len(x.split("\t")) > 5 and x.split("\t")[5] == "No"]
+print("            " + "\n                | ".join('"%s"' % d for d in dependent))
+print(
+    """        )
+    }
+}
+"""
+)
@@ -0,0 +1,217 @@
+#!/usr/bin/env python
+# -*- coding: utf-8 -*-
+#
+# Generates src/locale/ from the iso-codes JSON data and gettext catalogs
+# (Debian/Ubuntu package `iso-codes`). The catalogs are keyed by the iso-codes
+# English name, which is looked up here by code so that the generated tables
+# can be keyed by alpha-2 / subdivision / alpha-4 code.
+#
+# Usage: cd scripts && python3 locales.py
+import csv
+import gettext
+import json
+import os
+import re
+
+ISO_CODES = "/usr/share/iso-codes/json"
+LOCALE_DIR = "/usr/share/locale"
+OUT_DIR = "../src/locale"
+
+# (tag, feature, English language name, catalog per ISO 3166 part)
+LOCALES = [
+    ("ar", "locale-ar", "Arabic", ["ar", "ar", "ar"]),
+    ("de", "locale-de", "German", ["de", "de", "de"]),
+    ("es", "locale-es", "Spanish", ["es", "es", "es"]),
+    ("fr", "locale-fr", "French", ["fr", "fr", "fr"]),
+    ("it", "locale-it", "Italian", ["it", "it", "it"]),
+    ("ja", "locale-ja", "Japanese", ["ja", "ja", "ja"]),
+    ("ko", "locale-ko", "Korean", ["ko", "ko", "ko"]),
+    ("nl", "locale-nl", "Dutch", ["nl", "nl", "nl"]),
+    ("pl", "locale-pl", "Polish", ["pl", "pl", "pl"]),
+    # iso-codes has no European Portuguese subdivision catalog
+    ("pt", "locale-pt", "Portuguese", ["pt", "pt_BR", "pt"]),
+    ("ru", "locale-ru", "Russian", ["ru", "ru", "ru"]),
+    ("sv", "locale-sv", "Swedish", ["sv", "sv", "sv"]),
+    ("tr", "locale-tr", "Turkish", ["tr", "tr", "tr"]),
+    ("zh", "locale-zh", "Simplified Chinese", ["zh_CN", "zh_CN", "zh_CN"]),
+    ("zh-hant", "locale-zh-hant", "Traditional Chinese", ["zh_TW", "zh_TW", "zh_TW"]),
+]
+
+
+def rust_str(s):
+    return '"%s"' % s.replace("\\", "\\\\").replace('"', '\\"')
+
+
+def catalog(lang, domain):
+    path = os.path.join(LOCALE_DIR, lang, "LC_MESSAGES", domain + ".mo")
+    if not os.path.exists(path):
+        return {}
+    with open(path, "rb") as f:
+        return gettext.GNUTranslations(f)._catalog
+
+
+def iso_names(part, key):
+    with open(os.path.join(ISO_CODES, "iso_%s.json" % part)) as f:
+        return {x[key]: x["name"] for x in json.load(f)[part]}
+
+
+def our_codes():
+    alpha2 = []
+    for line in open("lib.py", encoding="utf-8"):
+        ts = line.rstrip("\n").split("\t")
+        if len(ts) == 8 and ts[4].startswith("ISO 3166-2:"):
+            alpha2.append(ts[1])
+    subdivisions = [x[1] for x in csv.reader(open("iso3166_2.data"), delimiter=",", quotechar='"')]
+    former = [x[3].strip() for x in csv.reader(open("iso3166_3.data"), delimiter=",", quotechar='"')][1:]
+    return alpha2, subdivisions, former
+
+
+def clean(name):
+    # Drop language-variant and code-transition brackets, e.g.
+    # "Lleida [Lérida]" or "Västra Götalands län [SE-14]".
+    return re.sub(r"\s*\[[^\]]*\]", "", name).strip()
+
+
+def table(name, doc, codes, names, translations, coverage=False):
+    rows = []
+    for code in codes:
+        msgid = names.get(code)
+        if msgid and translations.get(msgid):
+            rows.append("    %s => %s," % (rust_str(code), rust_str(clean(translations[msgid]))))
+    if coverage:
+        # iso-codes translates few subdivision names for some languages
+        doc += ", %d of %d" % (len(rows), len(codes))
+    return "\n".join(["", "///%s" % doc, "pub const %s: Map<&str, &str> = phf_map! {" % name] + rows + ["};"])
+
+
+def main():
+    os.makedirs(OUT_DIR, exist_ok=True)
+    alpha2, subdivisions, former = our_codes()
+    country_names = iso_names("3166-1", "alpha_2")
+    subdivision_names = iso_names("3166-2", "code")
+    former_names = iso_names("3166-3", "alpha_4")
+
+    for tag, feature, language, (c1, c2, c3) in LOCALES:
+        module = tag.replace("-", "_")
+        parts = [
+            "use phf::phf_map;",
+            "use phf::Map;",
+            table("COUNTRY_NAME_MAP", "%s country names by alpha-2 code" % language,
+                  alpha2, country_names, catalog(c1, "iso_3166-1")),
+            table("SUBDIVISION_NAME_MAP", "%s subdivision names by ISO 3166-2 code" % language,
+                  subdivisions, subdivision_names, catalog(c2, "iso_3166-2"), True),
+            table("FORMER_NAME_MAP", "%s former country names by alpha-4 code" % language,
+                  former, former_names, catalog(c3, "iso_3166-3")),
+        ]
+        with open(os.path.join(OUT_DIR, module + ".rs"), "w", encoding="utf-8") as f:
+            f.write("\n".join(parts) + "\n")
+
+    mods = []
+    arms = []
+    tags = []
+    for tag, feature, language, _ in LOCALES:
+        module = tag.replace("-", "_")
+        mods.append('#[cfg(feature = "%s")]\npub mod %s;' % (feature, module))
+        arms.append(
+            '        #[cfg(feature = "%s")]\n        "%s" => Some(Tables {\n'
+            "            countries: &%s::COUNTRY_NAME_MAP,\n"
+            "            subdivisions: &%s::SUBDIVISION_NAME_MAP,\n"
+            "            former: &%s::FORMER_NAME_MAP,\n"
+            "        })," % (feature, tag, module, module, module)
+        )
+        tags.append('    #[cfg(feature = "%s")]\n    "%s",' % (feature, tag))
+    with open(os.path.join(OUT_DIR, "mod.rs"), "w", encoding="utf-8") as f:
+        f.write(MOD_TEMPLATE % ("\n".join(mods), "\n".join(tags), "\n".join(arms)))
+
+
+MOD_TEMPLATE = """//! Country, subdivision and former country names in other languages.
+//!
+//! Each language is behind its own cargo feature (`locale-de`, `locale-ja`,
+//! ...) so binaries only embed the tables they use; `all-locales` enables
+//! every one. The translations come from the
+//! [iso-codes](https://salsa.debian.org/iso-codes-team/iso-codes) gettext
+//! catalogs and are regenerated by `scripts/locales.py`.
+//!
+//! # Sample code
+//! ```
+//! # #[cfg(feature = "locale-de")] {
+//! let country = rust_iso3166::from_alpha2("DE").unwrap();
+//! assert_eq!(Some("Deutschland"), country.name_in("de-AT"));
+//! # }
+//! ```
+
+use crate::iso3166_2::Subdivision;
+use crate::iso3166_3::CountryCode3;
+use crate::CountryCode;
+use phf::Map;
+
+%s
+
+///The language tags of the locales compiled in
+pub const LOCALES: &[&str] = &[
+%s
+];
+
+struct Tables {
+    countries: &'static Map<&'static str, &'static str>,
+    subdivisions: &'static Map<&'static str, &'static str>,
+    former: &'static Map<&'static str, &'static str>,
+}
+
+/// Picks the tables for a BCP 47 style tag such as `de`, `pt-BR`, `zh_Hant`
+/// or `zh-Hant-TW-u-ca-roc`. Only the language, and for Chinese the script or
+/// region right after it, is used; later subtags and extensions are ignored.
+fn tables(locale: &str) -> Option<Tables> {
+    let mut subtags = locale.trim().split(['-', '_']);
+    let language = subtags.next()?;
+    let hant = |s: &str| {
+        ["hant", "tw", "hk", "mo"]
+            .iter()
+            .any(|t| s.eq_ignore_ascii_case(t))
+    };
+    let tag = if language.eq_ignore_ascii_case("zh") && subtags.take(2).any(hant) {
+        "zh-hant"
+    } else {
+        LOCALES
+            .iter()
+            .copied()
+            .find(|l| l.eq_ignore_ascii_case(language))?
+    };
+    match tag {
+%s
+        _ => None,
+    }
+}
+
+impl CountryCode {
+    ///Return the name in the given language, if its `locale-*` feature is
+    ///enabled and a translation exists
+    pub fn name_in(&self, locale: &str) -> Option<&'static str> {
+        tables(locale)?.countries.get(self.alpha2).copied()
+    }
+}
+
+impl Subdivision {
+    ///Return the name in the given language, if its `locale-*` feature is
+    ///enabled and a translation exists. iso-codes translates far fewer
+    ///subdivisions than countries: German, Dutch, Polish, French, Swedish and
+    ///Italian cover three quarters or more, Simplified Chinese, Japanese,
+    ///Turkish and Russian 40 to 55 percent, Traditional Chinese and Spanish
+    ///under a tenth, Korean and Portuguese a handful and Arabic none. Each
+    ///`SUBDIVISION_NAME_MAP` doc gives the count.
+    pub fn name_in(&self, locale: &str) -> Option<&'static str> {
+        tables(locale)?.subdivisions.get(self.code).copied()
+    }
+}
+
+impl CountryCode3 {
+    ///Return the name in the given language, if its `locale-*` feature is
+    ///enabled and a translation exists
+    pub fn name_in(&self, locale: &str) -> Option<&'static str> {
+        tables(locale)?.former.get(self.code).copied()
+    }
+}
+"""
+
+if __name__ == "__main__":
+    main()
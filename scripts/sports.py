@@ -0,0 +1,439 @@
+#!/usr/bin/env python
+# -*- coding: utf-8 -*-
+#
+# Generates src/sports.rs, the team codes of international sports
+# federations. World Athletics and the IPC use the IOC code wherever the
+# country has a National Olympic Committee; their other members have their
+# own codes in the table. FIFA's home nations are ISO 3166-2 subdivisions
+# of the United Kingdom. Kosovo (IOC KOS, FIFA KVX) has no ISO 3166 code and
+# is left out.
+#
+# Usage: cd scripts && python3 sports.py > ../src/sports.rs
+
+# ISO 3166-1 alpha-2 or ISO 3166-2 code, IOC, FIFA, World Athletics, IPC
+a = """
+AD	AND	AND	AND	AND
+AE	UAE	UAE	UAE	UAE
+AF	AFG	AFG	AFG	AFG
+AG	ANT	ATG	ANT	ANT
+AI	-	AIA	AIA	-
+AL	ALB	ALB	ALB	ALB
+AM	ARM	ARM	ARM	ARM
+AO	ANG	ANG	ANG	ANG
+AR	ARG	ARG	ARG	ARG
+AS	ASA	ASA	ASA	ASA
+AT	AUT	AUT	AUT	AUT
+AU	AUS	AUS	AUS	AUS
+AW	ARU	ARU	ARU	ARU
+AZ	AZE	AZE	AZE	AZE
+BA	BIH	BIH	BIH	BIH
+BB	BAR	BRB	BAR	BAR
+BD	BAN	BAN	BAN	BAN
+BE	BEL	BEL	BEL	BEL
+BF	BUR	BFA	BUR	BUR
+BG	BUL	BUL	BUL	BUL
+BH	BRN	BHR	BRN	BRN
+BI	BDI	BDI	BDI	BDI
+BJ	BEN	BEN	BEN	BEN
+BM	BER	BER	BER	BER
+BN	BRU	BRU	BRU	BRU
+BO	BOL	BOL	BOL	BOL
+BR	BRA	BRA	BRA	BRA
+BS	BAH	BAH	BAH	BAH
+BT	BHU	BHU	BHU	BHU
+BW	BOT	BOT	BOT	BOT
+BY	BLR	BLR	BLR	BLR
+BZ	BIZ	BLZ	BIZ	BIZ
+CA	CAN	CAN	CAN	CAN
+CD	COD	COD	COD	COD
+CF	CAF	CTA	CAF	CAF
+CG	CGO	CGO	CGO	CGO
+CH	SUI	SUI	SUI	SUI
+CI	CIV	CIV	CIV	CIV
+CK	COK	COK	COK	COK
+CL	CHI	CHI	CHI	CHI
+CM	CMR	CMR	CMR	CMR
+CN	CHN	CHN	CHN	CHN
+CO	COL	COL	COL	COL
+CR	CRC	CRC	CRC	CRC
+CU	CUB	CUB	CUB	CUB
+CV	CPV	CPV	CPV	CPV
+CW	-	CUW	-	-
+CY	CYP	CYP	CYP	CYP
+CZ	CZE	CZE	CZE	CZE
+DE	GER	GER	GER	GER
+DJ	DJI	DJI	DJI	DJI
+DK	DEN	DEN	DEN	DEN
+DM	DMA	DMA	DMA	DMA
+DO	DOM	DOM	DOM	DOM
+DZ	ALG	ALG	ALG	ALG
+EC	ECU	ECU	ECU	ECU
+EE	EST	EST	EST	EST
+EG	EGY	EGY	EGY	EGY
+ER	ERI	ERI	ERI	ERI
+ES	ESP	ESP	ESP	ESP
+ET	ETH	ETH	ETH	ETH
+FI	FIN	FIN	FIN	FIN
+FJ	FIJ	FIJ	FIJ	FIJ
+FM	FSM	-	FSM	FSM
+FO	-	FRO	-	FRO
+FR	FRA	FRA	FRA	FRA
+GA	GAB	GAB	GAB	GAB
+GB	GBR	-	GBR	GBR
+GD	GRN	GRN	GRN	GRN
+GE	GEO	GEO	GEO	GEO
+GH	GHA	GHA	GHA	GHA
+GI	-	GIB	GIB	-
+GM	GAM	GAM	GAM	GAM
+GN	GUI	GUI	GUI	GUI
+GQ	GEQ	EQG	GEQ	GEQ
+GR	GRE	GRE	GRE	GRE
+GT	GUA	GUA	GUA	GUA
+GU	GUM	GUM	GUM	GUM
+GW	GBS	GNB	GBS	GBS
+GY	GUY	GUY	GUY	GUY
+HK	HKG	HKG	HKG	HKG
+HN	HON	HON	HON	HON
+HR	CRO	CRO	CRO	CRO
+HT	HAI	HAI	HAI	HAI
+HU	HUN	HUN	HUN	HUN
+ID	INA	IDN	INA	INA
+IE	IRL	IRL	IRL	IRL
+IL	ISR	ISR	ISR	ISR
+IN	IND	IND	IND	IND
+IQ	IRQ	IRQ	IRQ	IRQ
+IR	IRI	IRN	IRI	IRI
+IS	ISL	ISL	ISL	ISL
+IT	ITA	ITA	ITA	ITA
+JM	JAM	JAM	JAM	JAM
+JO	JOR	JOR	JOR	JOR
+JP	JPN	JPN	JPN	JPN
+KE	KEN	KEN	KEN	KEN
+KG	KGZ	KGZ	KGZ	KGZ
+KH	CAM	CAM	CAM	CAM
+KI	KIR	-	KIR	KIR
+KM	COM	COM	COM	COM
+KN	SKN	SKN	SKN	SKN
+KP	PRK	PRK	PRK	PRK
+KR	KOR	KOR	KOR	KOR
+KW	KUW	KUW	KUW	KUW
+KY	CAY	CAY	CAY	CAY
+KZ	KAZ	KAZ	KAZ	KAZ
+LA	LAO	LAO	LAO	LAO
+LB	LBN	LBN	LBN	LBN
+LC	LCA	LCA	LCA	LCA
+LI	LIE	LIE	LIE	LIE
+LK	SRI	SRI	SRI	SRI
+LR	LBR	LBR	LBR	LBR
+LS	LES	LES	LES	LES
+LT	LTU	LTU	LTU	LTU
+LU	LUX	LUX	LUX	LUX
+LV	LAT	LVA	LAT	LAT
+LY	LBA	LBY	LBA	LBA
+MA	MAR	MAR	MAR	MAR
+MC	MON	-	MON	MON
+MD	MDA	MDA	MDA	MDA
+ME	MNE	MNE	MNE	MNE
+MG	MAD	MAD	MAD	MAD
+MH	MHL	-	MHL	MHL
+MK	MKD	MKD	MKD	MKD
+ML	MLI	MLI	MLI	MLI
+MM	MYA	MYA	MYA	MYA
+MN	MGL	MNG	MGL	MGL
+MO	-	MAC	MAC	MAC
+MP	-	-	NMI	-
+MR	MTN	MTN	MTN	MTN
+MS	-	MSR	MSR	-
+MT	MLT	MLT	MLT	MLT
+MU	MRI	MRI	MRI	MRI
+MV	MDV	MDV	MDV	MDV
+MW	MAW	MWI	MAW	MAW
+MX	MEX	MEX	MEX	MEX
+MY	MAS	MAS	MAS	MAS
+MZ	MOZ	MOZ	MOZ	MOZ
+NA	NAM	NAM	NAM	NAM
+NC	-	NCL	-	-
+NE	NIG	NIG	NIG	NIG
+NF	-	-	NFI	-
+NG	NGR	NGA	NGR	NGR
+NI	NCA	NCA	NCA	NCA
+NL	NED	NED	NED	NED
+NO	NOR	NOR	NOR	NOR
+NP	NEP	NEP	NEP	NEP
+NR	NRU	-	NRU	NRU
+NZ	NZL	NZL	NZL	NZL
+OM	OMA	OMA	OMA	OMA
+PA	PAN	PAN	PAN	PAN
+PE	PER	PER	PER	PER
+PF	-	TAH	-	-
+PG	PNG	PNG	PNG	PNG
+PH	PHI	PHI	PHI	PHI
+PK	PAK	PAK	PAK	PAK
+PL	POL	POL	POL	POL
+PR	PUR	PUR	PUR	PUR
+PS	PLE	PLE	PLE	PLE
+PT	POR	POR	POR	POR
+PW	PLW	-	PLW	PLW
+PY	PAR	PAR	PAR	PAR
+QA	QAT	QAT	QAT	QAT
+RO	ROU	ROU	ROU	ROU
+RS	SRB	SRB	SRB	SRB
+RU	RUS	RUS	RUS	RUS
+RW	RWA	RWA	RWA	RWA
+SA	KSA	KSA	KSA	KSA
+SB	SOL	SOL	SOL	SOL
+SC	SEY	SEY	SEY	SEY
+SD	SUD	SDN	SUD	SUD
+SE	SWE	SWE	SWE	SWE
+SG	SGP	SIN	SGP	SGP
+SI	SLO	SVN	SLO	SLO
+SK	SVK	SVK	SVK	SVK
+SL	SLE	SLE	SLE	SLE
+SM	SMR	SMR	SMR	SMR
+SN	SEN	SEN	SEN	SEN
+SO	SOM	SOM	SOM	SOM
+SR	SUR	SUR	SUR	SUR
+SS	SSD	SSD	SSD	SSD
+ST	STP	STP	STP	STP
+SV	ESA	SLV	ESA	ESA
+SY	SYR	SYR	SYR	SYR
+SZ	SWZ	SWZ	SWZ	SWZ
+TC	-	TCA	TKS	-
+TD	CHA	CHA	CHA	CHA
+TG	TOG	TOG	TOG	TOG
+TH	THA	THA	THA	THA
+TJ	TJK	TJK	TJK	TJK
+TL	TLS	TLS	TLS	TLS
+TM	TKM	TKM	TKM	TKM
+TN	TUN	TUN	TUN	TUN
+TO	TGA	TGA	TGA	TGA
+TR	TUR	TUR	TUR	TUR
+TT	TTO	TRI	TTO	TTO
+TV	TUV	-	TUV	TUV
+TW	TPE	TPE	TPE	TPE
+TZ	TAN	TAN	TAN	TAN
+UA	UKR	UKR	UKR	UKR
+UG	UGA	UGA	UGA	UGA
+US	USA	USA	USA	USA
+UY	URU	URU	URU	URU
+UZ	UZB	UZB	UZB	UZB
+VC	VIN	VIN	VIN	VIN
+VE	VEN	VEN	VEN	VEN
+VG	IVB	VGB	IVB	IVB
+VI	ISV	VIR	ISV	ISV
+VN	VIE	VIE	VIE	VIE
+VU	VAN	VAN	VAN	VAN
+WS	SAM	SAM	SAM	SAM
+YE	YEM	YEM	YEM	YEM
+ZA	RSA	RSA	RSA	RSA
+ZM	ZAM	ZAM	ZAM	ZAM
+ZW	ZIM	ZIM	ZIM	ZIM
+GB-ENG	-	ENG	-	-
+GB-NIR	-	NIR	-	-
+GB-SCT	-	SCO	-	-
+GB-WLS	-	WAL	-	-
+"""
+
+# Codes of teams that no longer compete, by federation. The ISO code is an
+# ISO 3166-3 alpha-4 code, or an alpha-2 code when the country lives on.
+HISTORICAL = {
+    "IOC": [
+        ("AHO", "ANHH"),
+        ("BIR", "BUMM"),
+        ("DAH", "DYBJ"),
+        ("EUN", "SUHH"),
+        ("FRG", "DE"),
+        ("GDR", "DDDE"),
+        ("RHO", "RHZW"),
+        ("SCG", "CSXX"),
+        ("TCH", "CSHH"),
+        ("URS", "SUHH"),
+        ("VOL", "HVBF"),
+        ("YAR", "YE"),
+        ("YMD", "YDYE"),
+        ("YUG", "YUCS"),
+        ("ZAI", "ZRCD"),
+    ],
+    "FIFA": [
+        ("ANT", "ANHH"),
+        ("FRG", "DE"),
+        ("GDR", "DDDE"),
+        ("SCG", "CSXX"),
+        ("TCH", "CSHH"),
+        ("URS", "SUHH"),
+        ("YUG", "YUCS"),
+        ("ZAI", "ZRCD"),
+    ],
+}
+
+FEDERATIONS = [
+    ("IOC", "IOC_MAP", "IOC_TEAM_MAP", "International Olympic Committee"),
+    ("FIFA", "FIFA_MAP", "FIFA_TEAM_MAP", "FIFA"),
+    ("WorldAthletics", "WORLD_ATHLETICS_MAP", "WORLD_ATHLETICS_TEAM_MAP", "World Athletics"),
+    ("IPC", "IPC_MAP", "IPC_TEAM_MAP", "International Paralympic Committee"),
+]
+
+pre_code = """//! Team codes of international sports federations: the IOC, FIFA, World
+//! Athletics and the International Paralympic Committee.
+//!
+//! Codes stand for a `Code`, which is usually a country. FIFA's England,
+//! Scotland, Wales and Northern Ireland teams are subdivisions of the United
+//! Kingdom, and codes of teams that no longer compete, such as the IOC's
+//! `URS`, point to the former country in ISO 3166-3.
+//!
+//! # Sample code
+//! ```
+//! use rust_iso3166::sports::{self, Federation};
+//! use rust_iso3166::Code;
+//! let de = rust_iso3166::from_alpha2("DE").unwrap();
+//! assert_eq!(Some("GER"), de.sports_code(Federation::IOC));
+//! assert_eq!(None, rust_iso3166::GB.sports_code(Federation::FIFA));
+//!
+//! let netherlands = sports::from_code(Federation::FIFA, "NED").unwrap();
+//! assert_eq!(Code::Country(rust_iso3166::NL), netherlands.team);
+//! assert_eq!("GB-SCT", sports::from_code(Federation::FIFA, "SCO").unwrap().team.code());
+//! let soviet_union = sports::from_code(Federation::IOC, "urs").unwrap();
+//! assert_eq!("SUHH", soviet_union.team.code());
+//! assert!(soviet_union.historical);
+//! ```
+
+use crate::parse::{as_str, to_upper};
+use crate::{iso3166_2, iso3166_3, Code, CountryCode};
+use phf::phf_map;
+use phf::Map;
+
+/// A sports federation with its own team codes.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Federation {
+    ///International Olympic Committee
+    IOC,
+    ///Fédération Internationale de Football Association
+    FIFA,
+    ///World Athletics, whose members outside the Olympic movement include
+    ///Anguilla, Gibraltar and Macao
+    WorldAthletics,
+    ///International Paralympic Committee, whose members outside the Olympic
+    ///movement include the Faroe Islands and Macao
+    IPC,
+}
+
+/// A federation's team code and the team it stands for.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SportsCode {
+    ///The federation's code, such as `GER`
+    pub code: &'static str,
+    ///The country, subdivision or former country of the team
+    pub team: Code,
+    ///True for codes of teams that no longer compete, such as `URS`
+    pub historical: bool,
+}
+
+impl Federation {
+    ///All federations
+    pub const ALL: &'static [Federation] = &[
+        Federation::IOC,
+        Federation::FIFA,
+        Federation::WorldAthletics,
+        Federation::IPC,
+    ];
+
+    ///Return the English name
+    pub fn name(&self) -> &'static str {
+        match self {
+%s
+        }
+    }
+
+    ///Return every code of this federation, current and historical
+    pub fn codes(&self) -> &'static Map<&'static str, SportsCode> {
+        match self {
+%s
+        }
+    }
+
+    ///Return the current code of every team, by alpha-2 or ISO 3166-2 code
+    pub fn team_codes(&self) -> &'static Map<&'static str, &'static str> {
+        match self {
+%s
+        }
+    }
+}
+
+impl Code {
+    ///Return the current team code in the given federation
+    pub fn sports_code(&self, federation: Federation) -> Option<&'static str> {
+        federation.team_codes().get(self.code()).copied()
+    }
+}
+
+impl CountryCode {
+    ///Return the current team code in the given federation. The United
+    ///Kingdom has no FIFA code, see `Code::sports_code` for its home nations.
+    pub fn sports_code(&self, federation: Federation) -> Option<&'static str> {
+        Code::Country(*self).sports_code(federation)
+    }
+}
+
+/// Returns the team with the given federation code, if exists. Historical
+/// codes are included and ASCII case is ignored.
+/// #Sample
+/// ```
+/// use rust_iso3166::sports::{self, Federation};
+/// let gdr = sports::from_code(Federation::IOC, "GDR").unwrap();
+/// assert_eq!("DDDE", gdr.team.code());
+/// let wales = sports::from_code(Federation::FIFA, "WAL").unwrap();
+/// assert_eq!("GB-WLS", wales.team.code());
+/// ```
+pub fn from_code(federation: Federation, code: &str) -> Option<SportsCode> {
+    let (buf, len) = to_upper::<3>(code.trim(), 3..=3, |_, c| c.is_ascii_alphabetic()).ok()?;
+    federation.codes().get(as_str(&buf[..len])).copied()
+}
+"""
+
+
+def team(iso):
+    if len(iso) == 2:
+        return "Code::Country(crate::%s)" % iso
+    if len(iso) == 4:
+        return "Code::Former(iso3166_3::%s)" % iso
+    return "Code::Subdivision(iso3166_2::%s)" % iso.replace("-", "_")
+
+
+def main():
+    rows = [x.split("\t") for x in a.split("\n") if x]
+    names = "\n".join(
+        "            Federation::%s => \"%s\"," % (variant, name) for variant, _, _, name in FEDERATIONS
+    )
+    maps = "\n".join(
+        "            Federation::%s => &%s," % (variant, const) for variant, const, _, _ in FEDERATIONS
+    )
+    team_maps = "\n".join(
+        "            Federation::%s => &%s," % (variant, const) for variant, _, const, _ in FEDERATIONS
+    )
+    print(pre_code % (names, maps, team_maps))
+    for column, (variant, const, team_const, name) in enumerate(FEDERATIONS, start=1):
+        codes = [(row[column], row[0], False) for row in rows if row[column] != "-"]
+        codes += [(code, iso, True) for code, iso in HISTORICAL.get(variant, [])]
+        print("/// %s codes." % name)
+        print("pub const %s: Map<&str, SportsCode> = phf_map! {" % const)
+        for code, iso, historical in sorted(codes):
+            print(
+                "    \"%s\" => SportsCode { code: \"%s\", team: %s, historical: %s },"
+                % (code, code, team(iso), "true" if historical else "false")
+            )
+        print("};")
+        print()
+        print("/// Current %s codes by alpha-2 or ISO 3166-2 code." % name)
+        print("pub const %s: Map<&str, &str> = phf_map! {" % team_const)
+        for row in rows:
+            if row[column] != "-":
+                print("    \"%s\" => \"%s\"," % (row[0], row[column]))
+        print("};")
+        if column < len(FEDERATIONS):
+            print()
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,240 @@
+#!/usr/bin/env python
+# -*- coding: utf-8 -*-
+#
+# Generates src/timezone.rs, the IANA time zones of countries and of the
+# subdivisions of the United States, Canada and Australia.
+#
+# Country zones come from zone.tab, which names every country's own zones
+# (Europe/Vaduz for Liechtenstein), in file order except that PRIMARY moves
+# the zone of the capital or most people first. The reverse index adds the
+# countries zone1970.tab groups under a zone (Europe/Zurich also covers DE
+# and LI) and the legacy names linked to it in tzdata.zi (US/Pacific).
+# Subdivision zones are curated below, main zone first, and must be zones of
+# the country in zone.tab.
+#
+# Usage: cd scripts && python3 timezone.py > ../src/timezone.rs
+import json
+
+ISO_CODES = "/usr/share/iso-codes/json"
+ZONEINFO = "/usr/share/zoneinfo"
+
+# The main zone of countries whose first zone.tab row is not
+PRIMARY = {
+    "AU": "Australia/Sydney",
+    "BR": "America/Sao_Paulo",
+    "CA": "America/Toronto",
+    "FM": "Pacific/Pohnpei",
+    "PS": "Asia/Hebron",
+    "RU": "Europe/Moscow",
+    "UA": "Europe/Kyiv",
+    "UZ": "Asia/Tashkent",
+}
+
+# ISO 3166-2 code, IANA zones
+s = """
+AU-ACT	Australia/Sydney
+AU-NSW	Australia/Sydney Australia/Broken_Hill Australia/Lord_Howe
+AU-NT	Australia/Darwin
+AU-QLD	Australia/Brisbane Australia/Lindeman
+AU-SA	Australia/Adelaide
+AU-TAS	Australia/Hobart Antarctica/Macquarie
+AU-VIC	Australia/Melbourne
+AU-WA	Australia/Perth Australia/Eucla
+CA-AB	America/Edmonton
+CA-BC	America/Vancouver America/Edmonton America/Creston America/Dawson_Creek America/Fort_Nelson
+CA-MB	America/Winnipeg
+CA-NB	America/Moncton
+CA-NL	America/St_Johns America/Goose_Bay
+CA-NS	America/Halifax America/Glace_Bay
+CA-NT	America/Edmonton America/Inuvik
+CA-NU	America/Iqaluit America/Rankin_Inlet America/Cambridge_Bay America/Resolute America/Atikokan
+CA-ON	America/Toronto America/Winnipeg America/Atikokan
+CA-PE	America/Halifax
+CA-QC	America/Toronto America/Blanc-Sablon
+CA-SK	America/Regina America/Swift_Current America/Edmonton
+CA-YT	America/Whitehorse America/Dawson
+US-AK	America/Anchorage America/Juneau America/Sitka America/Metlakatla America/Yakutat America/Nome America/Adak
+US-AL	America/Chicago
+US-AR	America/Chicago
+US-AS	Pacific/Pago_Pago
+US-AZ	America/Phoenix America/Denver
+US-CA	America/Los_Angeles
+US-CO	America/Denver
+US-CT	America/New_York
+US-DC	America/New_York
+US-DE	America/New_York
+US-FL	America/New_York America/Chicago
+US-GA	America/New_York
+US-GU	Pacific/Guam
+US-HI	Pacific/Honolulu
+US-IA	America/Chicago
+US-ID	America/Boise America/Los_Angeles
+US-IL	America/Chicago
+US-IN	America/Indiana/Indianapolis America/Indiana/Vincennes America/Indiana/Winamac America/Indiana/Marengo America/Indiana/Petersburg America/Indiana/Vevay America/Indiana/Tell_City America/Indiana/Knox America/Chicago
+US-KS	America/Chicago America/Denver
+US-KY	America/New_York America/Kentucky/Louisville America/Kentucky/Monticello America/Chicago
+US-LA	America/Chicago
+US-MA	America/New_York
+US-MD	America/New_York
+US-ME	America/New_York
+US-MI	America/Detroit America/Menominee
+US-MN	America/Chicago
+US-MO	America/Chicago
+US-MP	Pacific/Saipan
+US-MS	America/Chicago
+US-MT	America/Denver
+US-NC	America/New_York
+US-ND	America/Chicago America/North_Dakota/Center America/North_Dakota/New_Salem America/North_Dakota/Beulah America/Denver
+US-NE	America/Chicago America/Denver
+US-NH	America/New_York
+US-NJ	America/New_York
+US-NM	America/Denver
+US-NV	America/Los_Angeles America/Denver
+US-NY	America/New_York
+US-OH	America/New_York
+US-OK	America/Chicago
+US-OR	America/Los_Angeles America/Boise
+US-PA	America/New_York
+US-PR	America/Puerto_Rico
+US-RI	America/New_York
+US-SC	America/New_York
+US-SD	America/Chicago America/Denver
+US-TN	America/Chicago America/New_York
+US-TX	America/Chicago America/Denver
+US-UM	Pacific/Midway Pacific/Wake
+US-UT	America/Denver
+US-VA	America/New_York
+US-VI	America/St_Thomas
+US-VT	America/New_York
+US-WA	America/Los_Angeles
+US-WI	America/Chicago
+US-WV	America/New_York
+US-WY	America/Denver
+"""
+
+# The zones of US territories with their own alpha-2 code are listed under
+# that code in zone.tab
+TERRITORIES = {"US-AS": "AS", "US-GU": "GU", "US-MP": "MP", "US-PR": "PR", "US-UM": "UM", "US-VI": "VI"}
+
+pre_code = """use crate::iso3166_2::Subdivision;
+use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+
+impl CountryCode {
+    ///Return the IANA time zones of the country, such as `Europe/Berlin` and
+    ///`Europe/Busingen` for Germany, from the tz database's zone.tab. The
+    ///zone of the capital or of most people comes first, so it makes a
+    ///sensible default. Empty for uninhabited places such as Bouvet Island.
+    pub fn time_zones(&self) -> &'static [&'static str] {
+        COUNTRY_TIME_ZONE_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+
+    ///Return `time_zones` as `chrono_tz::Tz` values
+    #[cfg(feature = "chrono-tz")]
+    pub fn time_zones_tz(&self) -> Vec<chrono_tz::Tz> {
+        self.time_zones().iter().filter_map(|z| z.parse().ok()).collect()
+    }
+}
+
+impl Subdivision {
+    ///Return the IANA time zones of the subdivision, main zone first, such
+    ///as `America/Los_Angeles` for `US-CA` or `Australia/Perth` and
+    ///`Australia/Eucla` for `AU-WA`. Only states, provinces and territories
+    ///of the United States, Canada and Australia are covered; elsewhere this
+    ///is empty and the country's `time_zones` apply.
+    pub fn time_zones(&self) -> &'static [&'static str] {
+        SUBDIVISION_TIME_ZONE_MAP.get(self.code).copied().unwrap_or(&[])
+    }
+
+    ///Return `time_zones` as `chrono_tz::Tz` values
+    #[cfg(feature = "chrono-tz")]
+    pub fn time_zones_tz(&self) -> Vec<chrono_tz::Tz> {
+        self.time_zones().iter().filter_map(|z| z.parse().ok()).collect()
+    }
+}
+
+/// Returns the countries whose clocks follow an IANA time zone, the country
+/// listing it in zone.tab first.
+///
+/// Countries that zone1970.tab groups under the zone follow, so
+/// `Europe/Zurich` also returns Germany (for Büsingen) and Liechtenstein.
+/// Legacy names linked to a zone, such as `US/Pacific` or `Asia/Calcutta`,
+/// are accepted. Names are case-sensitive, as in the tz database.
+/// #Sample
+/// ```
+/// use rust_iso3166::countries_for_zone;
+/// assert_eq!(&[rust_iso3166::CH, rust_iso3166::DE, rust_iso3166::LI], countries_for_zone("Europe/Zurich"));
+/// assert_eq!(&[rust_iso3166::IN], countries_for_zone("Asia/Calcutta"));
+/// assert!(countries_for_zone("Etc/UTC").is_empty());
+/// assert_eq!(&["America/Los_Angeles"], rust_iso3166::iso3166_2::from_code("US-CA").unwrap().time_zones());
+/// ```
+pub fn countries_for_zone(zone: &str) -> &'static [CountryCode] {
+    ZONE_COUNTRY_MAP.get(zone.trim()).copied().unwrap_or(&[])
+}
+"""
+
+
+def read_tab(name):
+    with open("%s/%s" % (ZONEINFO, name)) as f:
+        for line in f:
+            if line.strip() and not line.startswith("#"):
+                yield line.rstrip("\n").split("\t")
+
+
+def main():
+    with open("%s/iso_3166-1.json" % ISO_CODES) as f:
+        countries = set(x["alpha_2"] for x in json.load(f)["3166-1"])
+    country_zones = {}
+    zone_countries = {}
+    for row in read_tab("zone.tab"):
+        country_zones.setdefault(row[0], []).append(row[2])
+        zone_countries.setdefault(row[2], []).append(row[0])
+    for alpha2, zone in PRIMARY.items():
+        zones = country_zones[alpha2]
+        zones.remove(zone)
+        zones.insert(0, zone)
+    for row in read_tab("zone1970.tab"):
+        listed = zone_countries.setdefault(row[2], [])
+        for alpha2 in row[0].split(","):
+            if alpha2 not in listed:
+                listed.append(alpha2)
+    with open("%s/tzdata.zi" % ZONEINFO) as f:
+        links = [line.split()[1:3] for line in f if line.startswith("L ")]
+    for target, name in links:
+        if target in zone_countries and name not in zone_countries:
+            zone_countries[name] = zone_countries[target]
+    assert set(country_zones) <= countries, set(country_zones) - countries
+
+    subdivision_zones = {}
+    for row in s.split("\n"):
+        if row:
+            code, zones = row.split("\t")
+            subdivision_zones[code] = zones.split(" ")
+            alpha2 = TERRITORIES.get(code, code[:2])
+            assert set(subdivision_zones[code]) <= set(country_zones[alpha2]), code
+
+    print(pre_code)
+    print("/// IANA time zones by alpha-2 code.")
+    print("pub const COUNTRY_TIME_ZONE_MAP: Map<&str, &[&str]> = phf_map! {")
+    for alpha2 in sorted(country_zones):
+        zones = ", ".join("\"%s\"" % z for z in country_zones[alpha2])
+        print("    \"%s\" => &[%s]," % (alpha2, zones))
+    print("};")
+    print()
+    print("/// IANA time zones by ISO 3166-2 code.")
+    print("pub const SUBDIVISION_TIME_ZONE_MAP: Map<&str, &[&str]> = phf_map! {")
+    for code, zones in subdivision_zones.items():
+        print("    \"%s\" => &[%s]," % (code, ", ".join("\"%s\"" % z for z in zones)))
+    print("};")
+    print()
+    print("/// Countries by IANA time zone, including legacy linked names.")
+    print("pub const ZONE_COUNTRY_MAP: Map<&str, &[CountryCode]> = phf_map! {")
+    for zone in sorted(zone_countries):
+        codes = ", ".join("crate::%s" % c for c in zone_countries[zone] if c in countries)
+        print("    \"%s\" => &[%s]," % (zone, codes))
+    print("};")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,139 @@
+use crate::normalize::normalize;
+use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Common, colloquial and former official English names, by alpha-2 code.
+///
+/// Former ISO short names are kept here when a data refresh renames a
+/// country (`Turkey` → `Türkiye`), so stored free text keeps resolving.
+pub const ALIAS_MAP: Map<&str, &[&str]> = phf_map! {
+    "AE" => &["UAE", "Emirates"],
+    "BL" => &["Saint Barthelemy", "St Barts", "St Barths"],
+    "BN" => &["Brunei"],
+    "BO" => &["Bolivia", "Bolivia, Plurinational State of"],
+    "BQ" => &["Caribbean Netherlands"],
+    "BS" => &["The Bahamas"],
+    "BY" => &["Byelorussia", "Belorussia"],
+    "CC" => &["Cocos Islands", "Keeling Islands"],
+    "CD" => &[
+        "DR Congo",
+        "DRC",
+        "Democratic Republic of the Congo",
+        "Congo-Kinshasa",
+        "Congo, The Democratic Republic of the",
+        "Zaire",
+    ],
+    "CG" => &["Republic of the Congo", "Congo-Brazzaville", "Congo Republic"],
+    "CI" => &["Ivory Coast"],
+    "CN" => &["People's Republic of China", "PRC", "Mainland China"],
+    "CV" => &["Cape Verde"],
+    "CZ" => &["Czech Republic"],
+    "FK" => &["Falkland Islands", "Falklands", "Malvinas"],
+    "FM" => &["Micronesia", "Micronesia, Federated States of"],
+    "FO" => &["Faeroe Islands"],
+    "GB" => &[
+        "UK",
+        "U.K.",
+        "United Kingdom",
+        "Great Britain",
+        "Britain",
+    ],
+    "GM" => &["The Gambia"],
+    "GS" => &["South Georgia"],
+    "HK" => &["Hong Kong SAR"],
+    "IE" => &["Éire", "Republic of Ireland"],
+    "IO" => &["Chagos Islands"],
+    "IR" => &["Iran", "Persia", "Iran, Islamic Republic of"],
+    "KG" => &["Kyrgyz Republic"],
+    "KN" => &["St Kitts and Nevis", "Saint Kitts"],
+    "KP" => &["North Korea", "DPRK", "Korea, Democratic People's Republic of"],
+    "KR" => &["South Korea", "Republic of Korea", "Korea, Republic of"],
+    "LA" => &["Laos", "Lao PDR"],
+    "LC" => &["St Lucia"],
+    "MD" => &["Moldova", "Moldova, Republic of"],
+    "MF" => &["Saint Martin", "St Martin"],
+    "MK" => &["Macedonia", "FYROM", "Macedonia, the former Yugoslav Republic of"],
+    "MM" => &["Burma"],
+    "MO" => &["Macau", "Macao SAR"],
+    "NL" => &["Netherlands", "The Netherlands", "Holland"],
+    "PM" => &["St Pierre and Miquelon"],
+    "PN" => &["Pitcairn Islands"],
+    "PS" => &["Palestine", "State of Palestine", "Palestine, State of"],
+    "RU" => &["Russia"],
+    "SH" => &["Saint Helena", "St Helena"],
+    "SJ" => &["Svalbard"],
+    "SK" => &["Slovak Republic"],
+    "SX" => &["Sint Maarten"],
+    "SY" => &["Syria"],
+    "SZ" => &["Swaziland"],
+    "TC" => &["Turks and Caicos"],
+    "TL" => &["East Timor"],
+    "TR" => &["Turkey"],
+    "TW" => &["Taiwan", "Republic of China", "Taiwan, Province of China"],
+    "TZ" => &["Tanzania", "Tanzania, United Republic of"],
+    "UM" => &["US Minor Outlying Islands"],
+    "US" => &["USA", "U.S.A.", "U.S.", "United States", "America"],
+    "VA" => &["Vatican", "Vatican City", "Holy See (Vatican City State)"],
+    "VC" => &["St Vincent and the Grenadines", "Saint Vincent"],
+    "VE" => &["Venezuela", "Venezuela, Bolivarian Republic of"],
+    "VG" => &["British Virgin Islands", "Virgin Islands, British"],
+    "VI" => &["US Virgin Islands", "U.S. Virgin Islands", "Virgin Islands, U.S."],
+    "VN" => &["Vietnam"],
+};
+
+impl CountryCode {
+    ///Return the common, colloquial and former names of this country
+    pub fn aliases(&self) -> &'static [&'static str] {
+        ALIAS_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+}
+
+/// Returns the CountryCode with the given alias, if exists. Case, diacritics
+/// and punctuation are ignored.
+/// #Sample
+/// ```
+/// let country = rust_iso3166::from_alias("Holland");
+/// assert_eq!("NL", country.unwrap().alpha2);
+/// let country = rust_iso3166::from_alias("u.k.");
+/// assert_eq!("GB", country.unwrap().alpha2);
+/// ```
+pub fn from_alias(alias: &str) -> Option<CountryCode> {
+    aliases().get(&normalize(alias)).copied()
+}
+
+/// Returns the CountryCode with the given ISO short name or alias, if exists.
+/// Case, diacritics and punctuation are ignored.
+/// #Sample
+/// ```
+/// let country = rust_iso3166::from_name("Türkiye");
+/// assert_eq!("TR", country.unwrap().alpha2);
+/// let country = rust_iso3166::from_name("turkey");
+/// assert_eq!("TR", country.unwrap().alpha2);
+/// ```
+pub fn from_name(name: &str) -> Option<CountryCode> {
+    names()
+        .get(&normalize(name))
+        .copied()
+        .or_else(|| from_alias(name))
+}
+
+fn aliases() -> &'static HashMap<String, CountryCode> {
+    static ALIASES: OnceLock<HashMap<String, CountryCode>> = OnceLock::new();
+    ALIASES.get_or_init(|| {
+        ALIAS_MAP
+            .entries()
+            .flat_map(|(alpha2, aliases)| {
+                let country = crate::ALPHA2_MAP[*alpha2];
+                aliases.iter().map(move |alias| (normalize(alias), country))
+            })
+            .collect()
+    })
+}
+
+fn names() -> &'static HashMap<String, CountryCode> {
+    static NAMES: OnceLock<HashMap<String, CountryCode>> = OnceLock::new();
+    NAMES.get_or_init(|| crate::ALL.iter().map(|c| (normalize(c.name), *c)).collect())
+}
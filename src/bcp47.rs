@@ -0,0 +1,157 @@
+use crate::parse::{as_str, to_upper};
+use crate::{CountryCode, Region, ALPHA2_MAP, CD, DE, FR, MM, NUMERIC_MAP, TL, YE};
+use phf::phf_map;
+use phf::Map;
+
+/// What the region subtag of a BCP 47 language tag stands for.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LocaleRegion {
+    ///An ISO 3166-1 country, such as `BR` in `pt-BR`
+    Country(CountryCode),
+    ///A UN M49 macro-region, such as `419` (Latin America and the Caribbean)
+    ///in `es-419`
+    Region(Region),
+}
+
+impl LocaleRegion {
+    ///Return the countries this region subtag covers, in `ALL` order for
+    ///macro-regions
+    pub fn countries(&self) -> Vec<CountryCode> {
+        match self {
+            LocaleRegion::Country(country) => vec![*country],
+            LocaleRegion::Region(region) => region.countries(),
+        }
+    }
+}
+
+impl CountryCode {
+    /// Returns the country of a BCP 47 language tag such as `pt-BR` or a
+    /// POSIX-style locale such as `zh_Hant_TW` or `de_DE.UTF-8`, if the tag
+    /// names one. Tags without a region subtag and tags naming a
+    /// macro-region (see `locale_region`) return `None`.
+    /// #Sample
+    /// ```
+    /// use rust_iso3166::CountryCode;
+    /// assert_eq!(Some(rust_iso3166::BR), CountryCode::from_locale("pt-BR"));
+    /// assert_eq!(Some(rust_iso3166::TW), CountryCode::from_locale("zh_Hant_TW"));
+    /// assert_eq!(Some(rust_iso3166::MM), CountryCode::from_locale("my-BU"));
+    /// assert_eq!(None, CountryCode::from_locale("es-419"));
+    /// assert_eq!(None, CountryCode::from_locale("en"));
+    /// ```
+    pub fn from_locale(tag: &str) -> Option<CountryCode> {
+        match locale_region(tag)? {
+            LocaleRegion::Country(country) => Some(country),
+            LocaleRegion::Region(_) => None,
+        }
+    }
+}
+
+/// Returns what the region subtag of a BCP 47 language tag stands for, if
+/// the tag has one.
+///
+/// Region subtags are ISO 3166-1 alpha-2 codes or UN M49 numeric codes,
+/// matched ignoring ASCII case. Deprecated subtags with a preferred value in
+/// the IANA language subtag registry, such as `BU` or `ZR`, resolve to the
+/// current country (see `DEPRECATED_REGION_MAP`). `_` is accepted in place
+/// of `-` and a POSIX `.charset` or `@modifier` suffix is ignored. Private
+/// use and grandfathered tags, and subtags after the first singleton (such
+/// as the `u` of `de-u-rg-chzzzz`), are not looked at.
+/// #Sample
+/// ```
+/// use rust_iso3166::{locale_region, LocaleRegion};
+/// assert_eq!(Some(LocaleRegion::Country(rust_iso3166::DE)), locale_region("de-DE"));
+/// assert_eq!(Some(LocaleRegion::Country(rust_iso3166::ES)), locale_region("es-724"));
+/// let latin_america = locale_region("es-419").unwrap();
+/// assert!(latin_america.countries().contains(&rust_iso3166::MX));
+/// assert_eq!(None, locale_region("sr-Latn"));
+/// ```
+pub fn locale_region(tag: &str) -> Option<LocaleRegion> {
+    let tag = tag.trim();
+    let tag = tag.split(['.', '@']).next().unwrap_or(tag);
+    let mut subtags = tag.split(['-', '_']);
+    let language = subtags.next()?;
+    if !(2..=8).contains(&language.len()) || !language.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+    for subtag in subtags.take_while(|s| s.len() > 1) {
+        let bytes = subtag.as_bytes();
+        if bytes.len() == 3 && bytes.iter().all(u8::is_ascii_digit) {
+            if let Some(country) = NUMERIC_MAP.get(subtag) {
+                return Some(LocaleRegion::Country(*country));
+            }
+            return subtag
+                .parse()
+                .ok()
+                .and_then(Region::from_code)
+                .map(LocaleRegion::Region);
+        }
+        if bytes.len() == 2 {
+            let (buf, len) = to_upper::<2>(subtag, 2..=2, |_, c| c.is_ascii_alphabetic()).ok()?;
+            let code = as_str(&buf[..len]);
+            return ALPHA2_MAP
+                .get(code)
+                .or_else(|| DEPRECATED_REGION_MAP.get(code))
+                .map(|country| LocaleRegion::Country(*country));
+        }
+    }
+    None
+}
+
+/// Returns the countries implied by an HTTP `Accept-Language` header,
+/// highest quality value first.
+///
+/// Each language range contributes the countries of its region subtag (see
+/// `locale_region`), so `es-419` adds every country of Latin America and the
+/// Caribbean. Ranges without a region, `*` and ranges with `q=0` add
+/// nothing. Ranges with equal quality keep their order in the header, and a
+/// country is only listed at its first, highest-ranked, occurrence.
+/// #Sample
+/// ```
+/// let found = rust_iso3166::from_accept_language("fr-CH, fr;q=0.9, en-GB;q=0.8, de;q=0.7");
+/// assert_eq!(vec!["CH", "GB"], found.iter().map(|c| c.alpha2).collect::<Vec<_>>());
+/// let found = rust_iso3166::from_accept_language("en-US;q=0.5, pt-BR");
+/// assert_eq!(vec!["BR", "US"], found.iter().map(|c| c.alpha2).collect::<Vec<_>>());
+/// ```
+pub fn from_accept_language(header: &str) -> Vec<CountryCode> {
+    let mut ranges: Vec<(&str, f32)> = header
+        .split(',')
+        .filter_map(|range| {
+            let mut params = range.split(';');
+            let tag = params.next()?.trim();
+            let mut quality = 1.0;
+            for param in params {
+                let (name, value) = param.split_once('=')?;
+                if name.trim().eq_ignore_ascii_case("q") {
+                    quality = value
+                        .trim()
+                        .parse()
+                        .ok()
+                        .filter(|q| (0.0..=1.0).contains(q))?;
+                }
+            }
+            (quality > 0.0).then_some((tag, quality))
+        })
+        .collect();
+    ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let mut found: Vec<CountryCode> = Vec::new();
+    for region in ranges.iter().filter_map(|(tag, _)| locale_region(tag)) {
+        for country in region.countries() {
+            if !found.contains(&country) {
+                found.push(country);
+            }
+        }
+    }
+    found
+}
+
+/// Deprecated BCP 47 region subtags and the country of their preferred
+/// value in the IANA language subtag registry.
+pub const DEPRECATED_REGION_MAP: Map<&str, CountryCode> = phf_map! {
+    "BU" => MM,
+    "DD" => DE,
+    "FX" => FR,
+    "TP" => TL,
+    "YD" => YE,
+    "ZR" => CD,
+};
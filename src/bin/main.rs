@@ -10,7 +10,14 @@ fn main() {
     eprintln!("Usage: {} [query]", script_name);
     let mut found = false;
     let mut table = Table::new();
-    table.add_row(row!["Name", "Alpha2", "Alpha3", "Numeric"]);
+    table.add_row(row![
+        "Name",
+        "Alpha2",
+        "Alpha3",
+        "Numeric",
+        "Official name",
+        "French name"
+    ]);
 
     for country in rust_iso3166::ALL {
         if country.alpha2.to_lowercase().contains(query)
@@ -21,20 +28,29 @@ fn main() {
                 country.name,
                 country.alpha2,
                 country.alpha3,
-                country.numeric_str()
+                country.numeric_str(),
+                country.official_name.unwrap_or(""),
+                country.name_fr
             ]);
             found = true;
         }
     }
 
     if !found {
-        for country in rust_iso3166::ALL {
-            if country.name.to_lowercase().contains(query) {
+        let options = rust_iso3166::search::SearchOptions {
+            subdivisions: false,
+            former: false,
+            ..Default::default()
+        };
+        for hit in rust_iso3166::search::search(query, &options) {
+            if let rust_iso3166::Code::Country(country) = hit.code {
                 table.add_row(row![
                     country.name,
                     country.alpha2,
                     country.alpha3,
-                    country.numeric_str()
+                    country.numeric_str(),
+                    country.official_name.unwrap_or(""),
+                    country.name_fr
                 ]);
             }
         }
@@ -0,0 +1,315 @@
+use crate::{CountryCode, ALL};
+use phf::phf_map;
+use phf::Map;
+use std::collections::{HashSet, VecDeque};
+
+impl CountryCode {
+    ///Return the countries this one shares a land border with, in alpha-2
+    ///order. Borders across bridges or the sea do not count, so Singapore
+    ///and Bahrain have none.
+    pub fn neighbors(&self) -> &'static [CountryCode] {
+        NEIGHBOR_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+
+    ///Return true if this country shares a land border with `other`
+    pub fn is_neighbor(&self, other: &CountryCode) -> bool {
+        self.neighbors().contains(other)
+    }
+
+    ///Return true if the country has no coastline on the open sea; the
+    ///Caspian Sea counts as a lake
+    pub fn is_landlocked(&self) -> bool {
+        LANDLOCKED.contains(self)
+    }
+
+    ///Return true if the country is landlocked and so are all its
+    ///neighbors, which is the case for Liechtenstein and Uzbekistan
+    pub fn is_double_landlocked(&self) -> bool {
+        self.is_landlocked() && self.neighbors().iter().all(CountryCode::is_landlocked)
+    }
+
+    ///Return every country reachable from this one over land borders, this
+    ///one included, in `ALL` order. A country without land borders is its
+    ///own landmass.
+    pub fn landmass(&self) -> Vec<CountryCode> {
+        let mut seen = HashSet::from([self.alpha2]);
+        let mut queue = VecDeque::from([*self]);
+        while let Some(country) = queue.pop_front() {
+            for neighbor in country.neighbors() {
+                if seen.insert(neighbor.alpha2) {
+                    queue.push_back(*neighbor);
+                }
+            }
+        }
+        ALL.iter().filter(|c| seen.contains(c.alpha2)).copied().collect()
+    }
+}
+
+/// Returns the minimum number of land borders to cross to get from `from`
+/// to `to`, if one can reach the other over land. `Some(0)` if both are the
+/// same country.
+/// #Sample
+/// ```
+/// use rust_iso3166::border_crossings;
+/// assert_eq!(Some(1), border_crossings(rust_iso3166::FR, rust_iso3166::DE));
+/// assert_eq!(Some(2), border_crossings(rust_iso3166::PT, rust_iso3166::FR));
+/// assert_eq!(None, border_crossings(rust_iso3166::FR, rust_iso3166::GB));
+/// assert!(rust_iso3166::UZ.is_double_landlocked());
+/// ```
+pub fn border_crossings(from: CountryCode, to: CountryCode) -> Option<usize> {
+    let mut seen = HashSet::from([from.alpha2]);
+    let mut queue = VecDeque::from([(from, 0)]);
+    while let Some((country, crossings)) = queue.pop_front() {
+        if country == to {
+            return Some(crossings);
+        }
+        for neighbor in country.neighbors() {
+            if seen.insert(neighbor.alpha2) {
+                queue.push_back((*neighbor, crossings + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the groups of countries connected by land borders, largest
+/// first. Countries without land borders are left out.
+///
+/// Borders on islands shared by several countries tie the island to the
+/// mainland group of one of them, so Papua New Guinea and Timor-Leste join
+/// Afro-Eurasia through Indonesia and Malaysia.
+/// #Sample
+/// ```
+/// let landmasses = rust_iso3166::landmasses();
+/// assert!(landmasses[0].contains(&rust_iso3166::ZA));
+/// assert!(landmasses[0].contains(&rust_iso3166::CN));
+/// assert!(landmasses[1].contains(&rust_iso3166::AR));
+/// ```
+pub fn landmasses() -> Vec<Vec<CountryCode>> {
+    let mut seen = HashSet::new();
+    let mut landmasses = Vec::new();
+    for country in ALL {
+        if country.neighbors().is_empty() || seen.contains(country.alpha2) {
+            continue;
+        }
+        let landmass = country.landmass();
+        seen.extend(landmass.iter().map(|c| c.alpha2));
+        landmasses.push(landmass);
+    }
+    landmasses.sort_by_key(|landmass| std::cmp::Reverse(landmass.len()));
+    landmasses
+}
+
+/// Land neighbors by alpha-2 code.
+pub const NEIGHBOR_MAP: Map<&str, &[CountryCode]> = phf_map! {
+    "AD" => &[crate::ES, crate::FR],
+    "AE" => &[crate::OM, crate::SA],
+    "AF" => &[crate::CN, crate::IR, crate::PK, crate::TJ, crate::TM, crate::UZ],
+    "AL" => &[crate::GR, crate::ME, crate::MK, crate::RS],
+    "AM" => &[crate::AZ, crate::GE, crate::IR, crate::TR],
+    "AO" => &[crate::CD, crate::CG, crate::NA, crate::ZM],
+    "AR" => &[crate::BO, crate::BR, crate::CL, crate::PY, crate::UY],
+    "AT" => &[crate::CH, crate::CZ, crate::DE, crate::HU, crate::IT, crate::LI, crate::SI, crate::SK],
+    "AZ" => &[crate::AM, crate::GE, crate::IR, crate::RU, crate::TR],
+    "BA" => &[crate::HR, crate::ME, crate::RS],
+    "BD" => &[crate::IN, crate::MM],
+    "BE" => &[crate::DE, crate::FR, crate::LU, crate::NL],
+    "BF" => &[crate::BJ, crate::CI, crate::GH, crate::ML, crate::NE, crate::TG],
+    "BG" => &[crate::GR, crate::MK, crate::RO, crate::RS, crate::TR],
+    "BI" => &[crate::CD, crate::RW, crate::TZ],
+    "BJ" => &[crate::BF, crate::NE, crate::NG, crate::TG],
+    "BN" => &[crate::MY],
+    "BO" => &[crate::AR, crate::BR, crate::CL, crate::PE, crate::PY],
+    "BR" => &[crate::AR, crate::BO, crate::CO, crate::GF, crate::GY, crate::PE, crate::PY, crate::SR, crate::UY, crate::VE],
+    "BT" => &[crate::CN, crate::IN],
+    "BW" => &[crate::NA, crate::ZA, crate::ZM, crate::ZW],
+    "BY" => &[crate::LT, crate::LV, crate::PL, crate::RU, crate::UA],
+    "BZ" => &[crate::GT, crate::MX],
+    "CA" => &[crate::US],
+    "CD" => &[crate::AO, crate::BI, crate::CF, crate::CG, crate::RW, crate::SS, crate::TZ, crate::UG, crate::ZM],
+    "CF" => &[crate::CD, crate::CG, crate::CM, crate::SD, crate::SS, crate::TD],
+    "CG" => &[crate::AO, crate::CD, crate::CF, crate::CM, crate::GA],
+    "CH" => &[crate::AT, crate::DE, crate::FR, crate::IT, crate::LI],
+    "CI" => &[crate::BF, crate::GH, crate::GN, crate::LR, crate::ML],
+    "CL" => &[crate::AR, crate::BO, crate::PE],
+    "CM" => &[crate::CF, crate::CG, crate::GA, crate::GQ, crate::NG, crate::TD],
+    "CN" => &[crate::AF, crate::BT, crate::HK, crate::IN, crate::KG, crate::KP, crate::KZ, crate::LA, crate::MM, crate::MN, crate::MO, crate::NP, crate::PK, crate::RU, crate::TJ, crate::VN],
+    "CO" => &[crate::BR, crate::EC, crate::PA, crate::PE, crate::VE],
+    "CR" => &[crate::NI, crate::PA],
+    "CZ" => &[crate::AT, crate::DE, crate::PL, crate::SK],
+    "DE" => &[crate::AT, crate::BE, crate::CH, crate::CZ, crate::DK, crate::FR, crate::LU, crate::NL, crate::PL],
+    "DJ" => &[crate::ER, crate::ET, crate::SO],
+    "DK" => &[crate::DE],
+    "DO" => &[crate::HT],
+    "DZ" => &[crate::EH, crate::LY, crate::MA, crate::ML, crate::MR, crate::NE, crate::TN],
+    "EC" => &[crate::CO, crate::PE],
+    "EE" => &[crate::LV, crate::RU],
+    "EG" => &[crate::IL, crate::LY, crate::PS, crate::SD],
+    "EH" => &[crate::DZ, crate::MA, crate::MR],
+    "ER" => &[crate::DJ, crate::ET, crate::SD],
+    "ES" => &[crate::AD, crate::FR, crate::GI, crate::MA, crate::PT],
+    "ET" => &[crate::DJ, crate::ER, crate::KE, crate::SD, crate::SO, crate::SS],
+    "FI" => &[crate::NO, crate::RU, crate::SE],
+    "FR" => &[crate::AD, crate::BE, crate::CH, crate::DE, crate::ES, crate::IT, crate::LU, crate::MC],
+    "GA" => &[crate::CG, crate::CM, crate::GQ],
+    "GB" => &[crate::IE],
+    "GE" => &[crate::AM, crate::AZ, crate::RU, crate::TR],
+    "GF" => &[crate::BR, crate::SR],
+    "GH" => &[crate::BF, crate::CI, crate::TG],
+    "GI" => &[crate::ES],
+    "GM" => &[crate::SN],
+    "GN" => &[crate::CI, crate::GW, crate::LR, crate::ML, crate::SL, crate::SN],
+    "GQ" => &[crate::CM, crate::GA],
+    "GR" => &[crate::AL, crate::BG, crate::MK, crate::TR],
+    "GT" => &[crate::BZ, crate::HN, crate::MX, crate::SV],
+    "GW" => &[crate::GN, crate::SN],
+    "GY" => &[crate::BR, crate::SR, crate::VE],
+    "HK" => &[crate::CN],
+    "HN" => &[crate::GT, crate::NI, crate::SV],
+    "HR" => &[crate::BA, crate::HU, crate::ME, crate::RS, crate::SI],
+    "HT" => &[crate::DO],
+    "HU" => &[crate::AT, crate::HR, crate::RO, crate::RS, crate::SI, crate::SK, crate::UA],
+    "ID" => &[crate::MY, crate::PG, crate::TL],
+    "IE" => &[crate::GB],
+    "IL" => &[crate::EG, crate::JO, crate::LB, crate::PS, crate::SY],
+    "IN" => &[crate::BD, crate::BT, crate::CN, crate::MM, crate::NP, crate::PK],
+    "IQ" => &[crate::IR, crate::JO, crate::KW, crate::SA, crate::SY, crate::TR],
+    "IR" => &[crate::AF, crate::AM, crate::AZ, crate::IQ, crate::PK, crate::TM, crate::TR],
+    "IT" => &[crate::AT, crate::CH, crate::FR, crate::SI, crate::SM, crate::VA],
+    "JO" => &[crate::IL, crate::IQ, crate::PS, crate::SA, crate::SY],
+    "KE" => &[crate::ET, crate::SO, crate::SS, crate::TZ, crate::UG],
+    "KG" => &[crate::CN, crate::KZ, crate::TJ, crate::UZ],
+    "KH" => &[crate::LA, crate::TH, crate::VN],
+    "KP" => &[crate::CN, crate::KR, crate::RU],
+    "KR" => &[crate::KP],
+    "KW" => &[crate::IQ, crate::SA],
+    "KZ" => &[crate::CN, crate::KG, crate::RU, crate::TM, crate::UZ],
+    "LA" => &[crate::CN, crate::KH, crate::MM, crate::TH, crate::VN],
+    "LB" => &[crate::IL, crate::SY],
+    "LI" => &[crate::AT, crate::CH],
+    "LR" => &[crate::CI, crate::GN, crate::SL],
+    "LS" => &[crate::ZA],
+    "LT" => &[crate::BY, crate::LV, crate::PL, crate::RU],
+    "LU" => &[crate::BE, crate::DE, crate::FR],
+    "LV" => &[crate::BY, crate::EE, crate::LT, crate::RU],
+    "LY" => &[crate::DZ, crate::EG, crate::NE, crate::SD, crate::TD, crate::TN],
+    "MA" => &[crate::DZ, crate::EH, crate::ES],
+    "MC" => &[crate::FR],
+    "MD" => &[crate::RO, crate::UA],
+    "ME" => &[crate::AL, crate::BA, crate::HR, crate::RS],
+    "MF" => &[crate::SX],
+    "MK" => &[crate::AL, crate::BG, crate::GR, crate::RS],
+    "ML" => &[crate::BF, crate::CI, crate::DZ, crate::GN, crate::MR, crate::NE, crate::SN],
+    "MM" => &[crate::BD, crate::CN, crate::IN, crate::LA, crate::TH],
+    "MN" => &[crate::CN, crate::RU],
+    "MO" => &[crate::CN],
+    "MR" => &[crate::DZ, crate::EH, crate::ML],
+    "MW" => &[crate::MZ, crate::TZ, crate::ZM],
+    "MX" => &[crate::BZ, crate::GT, crate::US],
+    "MY" => &[crate::BN, crate::ID, crate::TH],
+    "MZ" => &[crate::MW, crate::SZ, crate::TZ, crate::ZA, crate::ZM, crate::ZW],
+    "NA" => &[crate::AO, crate::BW, crate::ZA, crate::ZM],
+    "NE" => &[crate::BF, crate::BJ, crate::DZ, crate::LY, crate::ML, crate::NG, crate::TD],
+    "NG" => &[crate::BJ, crate::CM, crate::NE, crate::TD],
+    "NI" => &[crate::CR, crate::HN],
+    "NL" => &[crate::BE, crate::DE],
+    "NO" => &[crate::FI, crate::RU, crate::SE],
+    "NP" => &[crate::CN, crate::IN],
+    "OM" => &[crate::AE, crate::SA, crate::YE],
+    "PA" => &[crate::CO, crate::CR],
+    "PE" => &[crate::BO, crate::BR, crate::CL, crate::CO, crate::EC],
+    "PG" => &[crate::ID],
+    "PK" => &[crate::AF, crate::CN, crate::IN, crate::IR],
+    "PL" => &[crate::BY, crate::CZ, crate::DE, crate::LT, crate::RU, crate::SK, crate::UA],
+    "PS" => &[crate::EG, crate::IL, crate::JO],
+    "PT" => &[crate::ES],
+    "PY" => &[crate::AR, crate::BO, crate::BR],
+    "QA" => &[crate::SA],
+    "RO" => &[crate::BG, crate::HU, crate::MD, crate::RS, crate::UA],
+    "RS" => &[crate::AL, crate::BA, crate::BG, crate::HR, crate::HU, crate::ME, crate::MK, crate::RO],
+    "RU" => &[crate::AZ, crate::BY, crate::CN, crate::EE, crate::FI, crate::GE, crate::KP, crate::KZ, crate::LT, crate::LV, crate::MN, crate::NO, crate::PL, crate::UA],
+    "RW" => &[crate::BI, crate::CD, crate::TZ, crate::UG],
+    "SA" => &[crate::AE, crate::IQ, crate::JO, crate::KW, crate::OM, crate::QA, crate::YE],
+    "SD" => &[crate::CF, crate::EG, crate::ER, crate::ET, crate::LY, crate::SS, crate::TD],
+    "SE" => &[crate::FI, crate::NO],
+    "SI" => &[crate::AT, crate::HR, crate::HU, crate::IT],
+    "SK" => &[crate::AT, crate::CZ, crate::HU, crate::PL, crate::UA],
+    "SL" => &[crate::GN, crate::LR],
+    "SM" => &[crate::IT],
+    "SN" => &[crate::GM, crate::GN, crate::GW, crate::ML],
+    "SO" => &[crate::DJ, crate::ET, crate::KE],
+    "SR" => &[crate::BR, crate::GF, crate::GY],
+    "SS" => &[crate::CD, crate::CF, crate::ET, crate::KE, crate::SD, crate::UG],
+    "SV" => &[crate::GT, crate::HN],
+    "SX" => &[crate::MF],
+    "SY" => &[crate::IL, crate::IQ, crate::JO, crate::LB, crate::TR],
+    "SZ" => &[crate::MZ, crate::ZA],
+    "TD" => &[crate::CF, crate::CM, crate::LY, crate::NE, crate::NG, crate::SD],
+    "TG" => &[crate::BF, crate::BJ, crate::GH],
+    "TH" => &[crate::KH, crate::LA, crate::MM, crate::MY],
+    "TJ" => &[crate::AF, crate::CN, crate::KG, crate::UZ],
+    "TL" => &[crate::ID],
+    "TM" => &[crate::AF, crate::IR, crate::KZ, crate::UZ],
+    "TN" => &[crate::DZ, crate::LY],
+    "TR" => &[crate::AM, crate::AZ, crate::BG, crate::GE, crate::GR, crate::IQ, crate::IR, crate::SY],
+    "TZ" => &[crate::BI, crate::CD, crate::KE, crate::MW, crate::MZ, crate::RW, crate::UG, crate::ZM],
+    "UA" => &[crate::BY, crate::HU, crate::MD, crate::PL, crate::RO, crate::RU, crate::SK],
+    "UG" => &[crate::CD, crate::KE, crate::RW, crate::SS, crate::TZ],
+    "US" => &[crate::CA, crate::MX],
+    "UY" => &[crate::AR, crate::BR],
+    "UZ" => &[crate::AF, crate::KG, crate::KZ, crate::TJ, crate::TM],
+    "VA" => &[crate::IT],
+    "VE" => &[crate::BR, crate::CO, crate::GY],
+    "VN" => &[crate::CN, crate::KH, crate::LA],
+    "YE" => &[crate::OM, crate::SA],
+    "ZA" => &[crate::BW, crate::LS, crate::MZ, crate::NA, crate::SZ, crate::ZW],
+    "ZM" => &[crate::AO, crate::BW, crate::CD, crate::MW, crate::MZ, crate::NA, crate::TZ, crate::ZW],
+    "ZW" => &[crate::BW, crate::MZ, crate::ZA, crate::ZM],
+};
+
+/// Landlocked countries.
+pub const LANDLOCKED: &[CountryCode] = &[
+    crate::AD,
+    crate::AF,
+    crate::AM,
+    crate::AT,
+    crate::AZ,
+    crate::BF,
+    crate::BI,
+    crate::BO,
+    crate::BT,
+    crate::BW,
+    crate::BY,
+    crate::CF,
+    crate::CH,
+    crate::CZ,
+    crate::ET,
+    crate::HU,
+    crate::KG,
+    crate::KZ,
+    crate::LA,
+    crate::LI,
+    crate::LS,
+    crate::LU,
+    crate::MD,
+    crate::MK,
+    crate::ML,
+    crate::MN,
+    crate::MW,
+    crate::NE,
+    crate::NP,
+    crate::PY,
+    crate::RS,
+    crate::RW,
+    crate::SK,
+    crate::SM,
+    crate::SS,
+    crate::TD,
+    crate::TJ,
+    crate::TM,
+    crate::UG,
+    crate::UZ,
+    crate::VA,
+    crate::ZM,
+    crate::ZW,
+];
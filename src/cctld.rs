@@ -0,0 +1,720 @@
+use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+
+/// An internationalized country-code top-level domain.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IdnCcTld {
+    ///The label in Unicode, such as `рф`
+    pub unicode: &'static str,
+    ///The label in punycode, such as `xn--p1ai`
+    pub punycode: &'static str,
+}
+
+impl CountryCode {
+    ///Return the ASCII ccTLD with its leading dot, such as `.uk` for the
+    ///United Kingdom. `None` for codes without a delegated ccTLD: BL, BQ,
+    ///EH, MF and UM.
+    pub fn cctld(&self) -> Option<&'static str> {
+        COUNTRY_CCTLD_MAP.get(self.alpha2).copied()
+    }
+
+    ///Return the internationalized ccTLDs, such as `.中国` for China
+    pub fn idn_cctlds(&self) -> &'static [IdnCcTld] {
+        COUNTRY_IDN_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+}
+
+/// Returns the country of the ccTLD a hostname or email address ends in, if
+/// exists.
+///
+/// Only the top-level label counts, so multi-level public suffixes such as
+/// `.co.uk` or `.com.au` resolve through their ccTLD, while `.uk.com` and
+/// other look-alike second-level domains under generic TLDs return `None`.
+/// Besides the alpha-2 codes, `.uk` and the unused `.gb` resolve to the
+/// United Kingdom and `.ac` to Saint Helena, Ascension and Tristan da Cunha.
+/// `.eu` and the legacy `.su` do not belong to one country and return
+/// `None`. IDN ccTLDs match in Unicode and punycode form; case and a
+/// trailing dot are ignored.
+/// #Sample
+/// ```
+/// use rust_iso3166::from_hostname;
+/// assert_eq!(Some(rust_iso3166::GB), from_hostname("shop.example.co.uk"));
+/// assert_eq!(Some(rust_iso3166::AU), from_hostname("jane@example.com.au"));
+/// assert_eq!(Some(rust_iso3166::RU), from_hostname("пример.рф"));
+/// assert_eq!(Some(rust_iso3166::RU), from_hostname("xn--e1afmkfd.xn--p1ai"));
+/// assert_eq!(None, from_hostname("example.eu"));
+/// assert_eq!(None, from_hostname("example.uk.com"));
+/// ```
+pub fn from_hostname(host: &str) -> Option<CountryCode> {
+    let host = host.trim();
+    let host = host.rsplit('@').next().unwrap_or(host);
+    let host = host.strip_suffix('.').unwrap_or(host);
+    let tld = host.rsplit('.').next()?;
+    if tld.is_ascii() {
+        CCTLD_MAP.get(tld.to_ascii_lowercase().as_str()).copied()
+    } else {
+        CCTLD_MAP.get(tld.to_lowercase().as_str()).copied()
+    }
+}
+
+/// ccTLD of each country by alpha-2 code.
+pub const COUNTRY_CCTLD_MAP: Map<&str, &str> = phf_map! {
+    "AD" => ".ad",
+    "AE" => ".ae",
+    "AF" => ".af",
+    "AG" => ".ag",
+    "AI" => ".ai",
+    "AL" => ".al",
+    "AM" => ".am",
+    "AO" => ".ao",
+    "AQ" => ".aq",
+    "AR" => ".ar",
+    "AS" => ".as",
+    "AT" => ".at",
+    "AU" => ".au",
+    "AW" => ".aw",
+    "AX" => ".ax",
+    "AZ" => ".az",
+    "BA" => ".ba",
+    "BB" => ".bb",
+    "BD" => ".bd",
+    "BE" => ".be",
+    "BF" => ".bf",
+    "BG" => ".bg",
+    "BH" => ".bh",
+    "BI" => ".bi",
+    "BJ" => ".bj",
+    "BM" => ".bm",
+    "BN" => ".bn",
+    "BO" => ".bo",
+    "BR" => ".br",
+    "BS" => ".bs",
+    "BT" => ".bt",
+    "BV" => ".bv",
+    "BW" => ".bw",
+    "BY" => ".by",
+    "BZ" => ".bz",
+    "CA" => ".ca",
+    "CC" => ".cc",
+    "CD" => ".cd",
+    "CF" => ".cf",
+    "CG" => ".cg",
+    "CH" => ".ch",
+    "CI" => ".ci",
+    "CK" => ".ck",
+    "CL" => ".cl",
+    "CM" => ".cm",
+    "CN" => ".cn",
+    "CO" => ".co",
+    "CR" => ".cr",
+    "CU" => ".cu",
+    "CV" => ".cv",
+    "CW" => ".cw",
+    "CX" => ".cx",
+    "CY" => ".cy",
+    "CZ" => ".cz",
+    "DE" => ".de",
+    "DJ" => ".dj",
+    "DK" => ".dk",
+    "DM" => ".dm",
+    "DO" => ".do",
+    "DZ" => ".dz",
+    "EC" => ".ec",
+    "EE" => ".ee",
+    "EG" => ".eg",
+    "ER" => ".er",
+    "ES" => ".es",
+    "ET" => ".et",
+    "FI" => ".fi",
+    "FJ" => ".fj",
+    "FK" => ".fk",
+    "FM" => ".fm",
+    "FO" => ".fo",
+    "FR" => ".fr",
+    "GA" => ".ga",
+    "GB" => ".uk",
+    "GD" => ".gd",
+    "GE" => ".ge",
+    "GF" => ".gf",
+    "GG" => ".gg",
+    "GH" => ".gh",
+    "GI" => ".gi",
+    "GL" => ".gl",
+    "GM" => ".gm",
+    "GN" => ".gn",
+    "GP" => ".gp",
+    "GQ" => ".gq",
+    "GR" => ".gr",
+    "GS" => ".gs",
+    "GT" => ".gt",
+    "GU" => ".gu",
+    "GW" => ".gw",
+    "GY" => ".gy",
+    "HK" => ".hk",
+    "HM" => ".hm",
+    "HN" => ".hn",
+    "HR" => ".hr",
+    "HT" => ".ht",
+    "HU" => ".hu",
+    "ID" => ".id",
+    "IE" => ".ie",
+    "IL" => ".il",
+    "IM" => ".im",
+    "IN" => ".in",
+    "IO" => ".io",
+    "IQ" => ".iq",
+    "IR" => ".ir",
+    "IS" => ".is",
+    "IT" => ".it",
+    "JE" => ".je",
+    "JM" => ".jm",
+    "JO" => ".jo",
+    "JP" => ".jp",
+    "KE" => ".ke",
+    "KG" => ".kg",
+    "KH" => ".kh",
+    "KI" => ".ki",
+    "KM" => ".km",
+    "KN" => ".kn",
+    "KP" => ".kp",
+    "KR" => ".kr",
+    "KW" => ".kw",
+    "KY" => ".ky",
+    "KZ" => ".kz",
+    "LA" => ".la",
+    "LB" => ".lb",
+    "LC" => ".lc",
+    "LI" => ".li",
+    "LK" => ".lk",
+    "LR" => ".lr",
+    "LS" => ".ls",
+    "LT" => ".lt",
+    "LU" => ".lu",
+    "LV" => ".lv",
+    "LY" => ".ly",
+    "MA" => ".ma",
+    "MC" => ".mc",
+    "MD" => ".md",
+    "ME" => ".me",
+    "MG" => ".mg",
+    "MH" => ".mh",
+    "MK" => ".mk",
+    "ML" => ".ml",
+    "MM" => ".mm",
+    "MN" => ".mn",
+    "MO" => ".mo",
+    "MP" => ".mp",
+    "MQ" => ".mq",
+    "MR" => ".mr",
+    "MS" => ".ms",
+    "MT" => ".mt",
+    "MU" => ".mu",
+    "MV" => ".mv",
+    "MW" => ".mw",
+    "MX" => ".mx",
+    "MY" => ".my",
+    "MZ" => ".mz",
+    "NA" => ".na",
+    "NC" => ".nc",
+    "NE" => ".ne",
+    "NF" => ".nf",
+    "NG" => ".ng",
+    "NI" => ".ni",
+    "NL" => ".nl",
+    "NO" => ".no",
+    "NP" => ".np",
+    "NR" => ".nr",
+    "NU" => ".nu",
+    "NZ" => ".nz",
+    "OM" => ".om",
+    "PA" => ".pa",
+    "PE" => ".pe",
+    "PF" => ".pf",
+    "PG" => ".pg",
+    "PH" => ".ph",
+    "PK" => ".pk",
+    "PL" => ".pl",
+    "PM" => ".pm",
+    "PN" => ".pn",
+    "PR" => ".pr",
+    "PS" => ".ps",
+    "PT" => ".pt",
+    "PW" => ".pw",
+    "PY" => ".py",
+    "QA" => ".qa",
+    "RE" => ".re",
+    "RO" => ".ro",
+    "RS" => ".rs",
+    "RU" => ".ru",
+    "RW" => ".rw",
+    "SA" => ".sa",
+    "SB" => ".sb",
+    "SC" => ".sc",
+    "SD" => ".sd",
+    "SE" => ".se",
+    "SG" => ".sg",
+    "SH" => ".sh",
+    "SI" => ".si",
+    "SJ" => ".sj",
+    "SK" => ".sk",
+    "SL" => ".sl",
+    "SM" => ".sm",
+    "SN" => ".sn",
+    "SO" => ".so",
+    "SR" => ".sr",
+    "SS" => ".ss",
+    "ST" => ".st",
+    "SV" => ".sv",
+    "SX" => ".sx",
+    "SY" => ".sy",
+    "SZ" => ".sz",
+    "TC" => ".tc",
+    "TD" => ".td",
+    "TF" => ".tf",
+    "TG" => ".tg",
+    "TH" => ".th",
+    "TJ" => ".tj",
+    "TK" => ".tk",
+    "TL" => ".tl",
+    "TM" => ".tm",
+    "TN" => ".tn",
+    "TO" => ".to",
+    "TR" => ".tr",
+    "TT" => ".tt",
+    "TV" => ".tv",
+    "TW" => ".tw",
+    "TZ" => ".tz",
+    "UA" => ".ua",
+    "UG" => ".ug",
+    "US" => ".us",
+    "UY" => ".uy",
+    "UZ" => ".uz",
+    "VA" => ".va",
+    "VC" => ".vc",
+    "VE" => ".ve",
+    "VG" => ".vg",
+    "VI" => ".vi",
+    "VN" => ".vn",
+    "VU" => ".vu",
+    "WF" => ".wf",
+    "WS" => ".ws",
+    "YE" => ".ye",
+    "YT" => ".yt",
+    "ZA" => ".za",
+    "ZM" => ".zm",
+    "ZW" => ".zw",
+};
+
+/// IDN ccTLDs by alpha-2 code.
+pub const COUNTRY_IDN_MAP: Map<&str, &[IdnCcTld]> = phf_map! {
+    "AE" => &[IdnCcTld { unicode: "امارات", punycode: "xn--mgbaam7a8h" }],
+    "AM" => &[IdnCcTld { unicode: "հայ", punycode: "xn--y9a3aq" }],
+    "BD" => &[IdnCcTld { unicode: "বাংলা", punycode: "xn--54b7fta0cc" }],
+    "BG" => &[IdnCcTld { unicode: "бг", punycode: "xn--90ae" }],
+    "BH" => &[IdnCcTld { unicode: "البحرين", punycode: "xn--mgbcpq6gpa1a" }],
+    "BY" => &[IdnCcTld { unicode: "бел", punycode: "xn--90ais" }],
+    "CN" => &[IdnCcTld { unicode: "中国", punycode: "xn--fiqs8s" }, IdnCcTld { unicode: "中國", punycode: "xn--fiqz9s" }],
+    "DZ" => &[IdnCcTld { unicode: "الجزائر", punycode: "xn--lgbbat1ad8j" }],
+    "EG" => &[IdnCcTld { unicode: "مصر", punycode: "xn--wgbh1c" }],
+    "GE" => &[IdnCcTld { unicode: "გე", punycode: "xn--node" }],
+    "GR" => &[IdnCcTld { unicode: "ελ", punycode: "xn--qxam" }],
+    "HK" => &[IdnCcTld { unicode: "香港", punycode: "xn--j6w193g" }],
+    "IN" => &[IdnCcTld { unicode: "भारत", punycode: "xn--h2brj9c" }, IdnCcTld { unicode: "ভারত", punycode: "xn--45brj9c" }, IdnCcTld { unicode: "ভাৰত", punycode: "xn--45br5cyl" }, IdnCcTld { unicode: "ਭਾਰਤ", punycode: "xn--s9brj9c" }, IdnCcTld { unicode: "ભારત", punycode: "xn--gecrj9c" }, IdnCcTld { unicode: "ଭାରତ", punycode: "xn--3hcrj9c" }, IdnCcTld { unicode: "இந்தியா", punycode: "xn--xkc2dl3a5ee0h" }, IdnCcTld { unicode: "భారత్", punycode: "xn--fpcrj9c3d" }, IdnCcTld { unicode: "ಭಾರತ", punycode: "xn--2scrj9c" }, IdnCcTld { unicode: "ഭാരതം", punycode: "xn--rvc1e0am3e" }, IdnCcTld { unicode: "भारतम्", punycode: "xn--h2breg3eve" }, IdnCcTld { unicode: "भारोत", punycode: "xn--h2brj9c8c" }, IdnCcTld { unicode: "بارت", punycode: "xn--mgbbh1a" }, IdnCcTld { unicode: "ڀارت", punycode: "xn--mgbgu82a" }, IdnCcTld { unicode: "بھارت", punycode: "xn--mgbbh1a71e" }],
+    "IQ" => &[IdnCcTld { unicode: "عراق", punycode: "xn--mgbtx2b" }],
+    "IR" => &[IdnCcTld { unicode: "ایران", punycode: "xn--mgba3a4f16a" }],
+    "JO" => &[IdnCcTld { unicode: "الاردن", punycode: "xn--mgbayh7gpa" }],
+    "KR" => &[IdnCcTld { unicode: "한국", punycode: "xn--3e0b707e" }],
+    "KZ" => &[IdnCcTld { unicode: "қаз", punycode: "xn--80ao21a" }],
+    "LK" => &[IdnCcTld { unicode: "ලංකා", punycode: "xn--fzc2c9e2c" }, IdnCcTld { unicode: "இலங்கை", punycode: "xn--xkc2al3hye2a" }],
+    "LY" => &[IdnCcTld { unicode: "ليبيا", punycode: "xn--mgbb7fyab" }],
+    "MA" => &[IdnCcTld { unicode: "المغرب", punycode: "xn--mgbc0a9azcg" }],
+    "MK" => &[IdnCcTld { unicode: "мкд", punycode: "xn--d1alf" }],
+    "MN" => &[IdnCcTld { unicode: "мон", punycode: "xn--l1acc" }],
+    "MO" => &[IdnCcTld { unicode: "澳門", punycode: "xn--mix891f" }],
+    "MR" => &[IdnCcTld { unicode: "موريتانيا", punycode: "xn--mgbah1a3hjkrd" }],
+    "MY" => &[IdnCcTld { unicode: "مليسيا", punycode: "xn--mgbx4cd0ab" }],
+    "OM" => &[IdnCcTld { unicode: "عمان", punycode: "xn--mgb9awbf" }],
+    "PK" => &[IdnCcTld { unicode: "پاکستان", punycode: "xn--mgbai9azgqp6j" }],
+    "PS" => &[IdnCcTld { unicode: "فلسطين", punycode: "xn--ygbi2ammx" }],
+    "QA" => &[IdnCcTld { unicode: "قطر", punycode: "xn--wgbl6a" }],
+    "RS" => &[IdnCcTld { unicode: "срб", punycode: "xn--90a3ac" }],
+    "RU" => &[IdnCcTld { unicode: "рф", punycode: "xn--p1ai" }],
+    "SA" => &[IdnCcTld { unicode: "السعودية", punycode: "xn--mgberp4a5d4ar" }],
+    "SD" => &[IdnCcTld { unicode: "سودان", punycode: "xn--mgbpl2fh" }],
+    "SG" => &[IdnCcTld { unicode: "新加坡", punycode: "xn--yfro4i67o" }, IdnCcTld { unicode: "சிங்கப்பூர்", punycode: "xn--clchc0ea0b2g2a9gcd" }],
+    "SY" => &[IdnCcTld { unicode: "سورية", punycode: "xn--ogbpf8fl" }],
+    "TH" => &[IdnCcTld { unicode: "ไทย", punycode: "xn--o3cw4h" }],
+    "TN" => &[IdnCcTld { unicode: "تونس", punycode: "xn--pgbs0dh" }],
+    "TW" => &[IdnCcTld { unicode: "台湾", punycode: "xn--kprw13d" }, IdnCcTld { unicode: "台灣", punycode: "xn--kpry57d" }],
+    "UA" => &[IdnCcTld { unicode: "укр", punycode: "xn--j1amh" }],
+    "YE" => &[IdnCcTld { unicode: "اليمن", punycode: "xn--mgb2ddes" }],
+};
+
+/// Countries by lower-case ccTLD label, ASCII, Unicode IDN and punycode.
+pub const CCTLD_MAP: Map<&str, CountryCode> = phf_map! {
+    "ad" => crate::AD,
+    "ae" => crate::AE,
+    "af" => crate::AF,
+    "ag" => crate::AG,
+    "ai" => crate::AI,
+    "al" => crate::AL,
+    "am" => crate::AM,
+    "ao" => crate::AO,
+    "aq" => crate::AQ,
+    "ar" => crate::AR,
+    "as" => crate::AS,
+    "at" => crate::AT,
+    "au" => crate::AU,
+    "aw" => crate::AW,
+    "ax" => crate::AX,
+    "az" => crate::AZ,
+    "ba" => crate::BA,
+    "bb" => crate::BB,
+    "bd" => crate::BD,
+    "be" => crate::BE,
+    "bf" => crate::BF,
+    "bg" => crate::BG,
+    "bh" => crate::BH,
+    "bi" => crate::BI,
+    "bj" => crate::BJ,
+    "bm" => crate::BM,
+    "bn" => crate::BN,
+    "bo" => crate::BO,
+    "br" => crate::BR,
+    "bs" => crate::BS,
+    "bt" => crate::BT,
+    "bv" => crate::BV,
+    "bw" => crate::BW,
+    "by" => crate::BY,
+    "bz" => crate::BZ,
+    "ca" => crate::CA,
+    "cc" => crate::CC,
+    "cd" => crate::CD,
+    "cf" => crate::CF,
+    "cg" => crate::CG,
+    "ch" => crate::CH,
+    "ci" => crate::CI,
+    "ck" => crate::CK,
+    "cl" => crate::CL,
+    "cm" => crate::CM,
+    "cn" => crate::CN,
+    "co" => crate::CO,
+    "cr" => crate::CR,
+    "cu" => crate::CU,
+    "cv" => crate::CV,
+    "cw" => crate::CW,
+    "cx" => crate::CX,
+    "cy" => crate::CY,
+    "cz" => crate::CZ,
+    "de" => crate::DE,
+    "dj" => crate::DJ,
+    "dk" => crate::DK,
+    "dm" => crate::DM,
+    "do" => crate::DO,
+    "dz" => crate::DZ,
+    "ec" => crate::EC,
+    "ee" => crate::EE,
+    "eg" => crate::EG,
+    "er" => crate::ER,
+    "es" => crate::ES,
+    "et" => crate::ET,
+    "fi" => crate::FI,
+    "fj" => crate::FJ,
+    "fk" => crate::FK,
+    "fm" => crate::FM,
+    "fo" => crate::FO,
+    "fr" => crate::FR,
+    "ga" => crate::GA,
+    "uk" => crate::GB,
+    "gb" => crate::GB,
+    "gd" => crate::GD,
+    "ge" => crate::GE,
+    "gf" => crate::GF,
+    "gg" => crate::GG,
+    "gh" => crate::GH,
+    "gi" => crate::GI,
+    "gl" => crate::GL,
+    "gm" => crate::GM,
+    "gn" => crate::GN,
+    "gp" => crate::GP,
+    "gq" => crate::GQ,
+    "gr" => crate::GR,
+    "gs" => crate::GS,
+    "gt" => crate::GT,
+    "gu" => crate::GU,
+    "gw" => crate::GW,
+    "gy" => crate::GY,
+    "hk" => crate::HK,
+    "hm" => crate::HM,
+    "hn" => crate::HN,
+    "hr" => crate::HR,
+    "ht" => crate::HT,
+    "hu" => crate::HU,
+    "id" => crate::ID,
+    "ie" => crate::IE,
+    "il" => crate::IL,
+    "im" => crate::IM,
+    "in" => crate::IN,
+    "io" => crate::IO,
+    "iq" => crate::IQ,
+    "ir" => crate::IR,
+    "is" => crate::IS,
+    "it" => crate::IT,
+    "je" => crate::JE,
+    "jm" => crate::JM,
+    "jo" => crate::JO,
+    "jp" => crate::JP,
+    "ke" => crate::KE,
+    "kg" => crate::KG,
+    "kh" => crate::KH,
+    "ki" => crate::KI,
+    "km" => crate::KM,
+    "kn" => crate::KN,
+    "kp" => crate::KP,
+    "kr" => crate::KR,
+    "kw" => crate::KW,
+    "ky" => crate::KY,
+    "kz" => crate::KZ,
+    "la" => crate::LA,
+    "lb" => crate::LB,
+    "lc" => crate::LC,
+    "li" => crate::LI,
+    "lk" => crate::LK,
+    "lr" => crate::LR,
+    "ls" => crate::LS,
+    "lt" => crate::LT,
+    "lu" => crate::LU,
+    "lv" => crate::LV,
+    "ly" => crate::LY,
+    "ma" => crate::MA,
+    "mc" => crate::MC,
+    "md" => crate::MD,
+    "me" => crate::ME,
+    "mg" => crate::MG,
+    "mh" => crate::MH,
+    "mk" => crate::MK,
+    "ml" => crate::ML,
+    "mm" => crate::MM,
+    "mn" => crate::MN,
+    "mo" => crate::MO,
+    "mp" => crate::MP,
+    "mq" => crate::MQ,
+    "mr" => crate::MR,
+    "ms" => crate::MS,
+    "mt" => crate::MT,
+    "mu" => crate::MU,
+    "mv" => crate::MV,
+    "mw" => crate::MW,
+    "mx" => crate::MX,
+    "my" => crate::MY,
+    "mz" => crate::MZ,
+    "na" => crate::NA,
+    "nc" => crate::NC,
+    "ne" => crate::NE,
+    "nf" => crate::NF,
+    "ng" => crate::NG,
+    "ni" => crate::NI,
+    "nl" => crate::NL,
+    "no" => crate::NO,
+    "np" => crate::NP,
+    "nr" => crate::NR,
+    "nu" => crate::NU,
+    "nz" => crate::NZ,
+    "om" => crate::OM,
+    "pa" => crate::PA,
+    "pe" => crate::PE,
+    "pf" => crate::PF,
+    "pg" => crate::PG,
+    "ph" => crate::PH,
+    "pk" => crate::PK,
+    "pl" => crate::PL,
+    "pm" => crate::PM,
+    "pn" => crate::PN,
+    "pr" => crate::PR,
+    "ps" => crate::PS,
+    "pt" => crate::PT,
+    "pw" => crate::PW,
+    "py" => crate::PY,
+    "qa" => crate::QA,
+    "re" => crate::RE,
+    "ro" => crate::RO,
+    "rs" => crate::RS,
+    "ru" => crate::RU,
+    "rw" => crate::RW,
+    "sa" => crate::SA,
+    "sb" => crate::SB,
+    "sc" => crate::SC,
+    "sd" => crate::SD,
+    "se" => crate::SE,
+    "sg" => crate::SG,
+    "sh" => crate::SH,
+    "ac" => crate::SH,
+    "si" => crate::SI,
+    "sj" => crate::SJ,
+    "sk" => crate::SK,
+    "sl" => crate::SL,
+    "sm" => crate::SM,
+    "sn" => crate::SN,
+    "so" => crate::SO,
+    "sr" => crate::SR,
+    "ss" => crate::SS,
+    "st" => crate::ST,
+    "sv" => crate::SV,
+    "sx" => crate::SX,
+    "sy" => crate::SY,
+    "sz" => crate::SZ,
+    "tc" => crate::TC,
+    "td" => crate::TD,
+    "tf" => crate::TF,
+    "tg" => crate::TG,
+    "th" => crate::TH,
+    "tj" => crate::TJ,
+    "tk" => crate::TK,
+    "tl" => crate::TL,
+    "tm" => crate::TM,
+    "tn" => crate::TN,
+    "to" => crate::TO,
+    "tr" => crate::TR,
+    "tt" => crate::TT,
+    "tv" => crate::TV,
+    "tw" => crate::TW,
+    "tz" => crate::TZ,
+    "ua" => crate::UA,
+    "ug" => crate::UG,
+    "us" => crate::US,
+    "uy" => crate::UY,
+    "uz" => crate::UZ,
+    "va" => crate::VA,
+    "vc" => crate::VC,
+    "ve" => crate::VE,
+    "vg" => crate::VG,
+    "vi" => crate::VI,
+    "vn" => crate::VN,
+    "vu" => crate::VU,
+    "wf" => crate::WF,
+    "ws" => crate::WS,
+    "ye" => crate::YE,
+    "yt" => crate::YT,
+    "za" => crate::ZA,
+    "zm" => crate::ZM,
+    "zw" => crate::ZW,
+    "امارات" => crate::AE,
+    "xn--mgbaam7a8h" => crate::AE,
+    "հայ" => crate::AM,
+    "xn--y9a3aq" => crate::AM,
+    "বাংলা" => crate::BD,
+    "xn--54b7fta0cc" => crate::BD,
+    "бг" => crate::BG,
+    "xn--90ae" => crate::BG,
+    "البحرين" => crate::BH,
+    "xn--mgbcpq6gpa1a" => crate::BH,
+    "бел" => crate::BY,
+    "xn--90ais" => crate::BY,
+    "中国" => crate::CN,
+    "xn--fiqs8s" => crate::CN,
+    "中國" => crate::CN,
+    "xn--fiqz9s" => crate::CN,
+    "الجزائر" => crate::DZ,
+    "xn--lgbbat1ad8j" => crate::DZ,
+    "مصر" => crate::EG,
+    "xn--wgbh1c" => crate::EG,
+    "გე" => crate::GE,
+    "xn--node" => crate::GE,
+    "ελ" => crate::GR,
+    "xn--qxam" => crate::GR,
+    "香港" => crate::HK,
+    "xn--j6w193g" => crate::HK,
+    "भारत" => crate::IN,
+    "xn--h2brj9c" => crate::IN,
+    "ভারত" => crate::IN,
+    "xn--45brj9c" => crate::IN,
+    "ভাৰত" => crate::IN,
+    "xn--45br5cyl" => crate::IN,
+    "ਭਾਰਤ" => crate::IN,
+    "xn--s9brj9c" => crate::IN,
+    "ભારત" => crate::IN,
+    "xn--gecrj9c" => crate::IN,
+    "ଭାରତ" => crate::IN,
+    "xn--3hcrj9c" => crate::IN,
+    "இந்தியா" => crate::IN,
+    "xn--xkc2dl3a5ee0h" => crate::IN,
+    "భారత్" => crate::IN,
+    "xn--fpcrj9c3d" => crate::IN,
+    "ಭಾರತ" => crate::IN,
+    "xn--2scrj9c" => crate::IN,
+    "ഭാരതം" => crate::IN,
+    "xn--rvc1e0am3e" => crate::IN,
+    "भारतम्" => crate::IN,
+    "xn--h2breg3eve" => crate::IN,
+    "भारोत" => crate::IN,
+    "xn--h2brj9c8c" => crate::IN,
+    "بارت" => crate::IN,
+    "xn--mgbbh1a" => crate::IN,
+    "ڀارت" => crate::IN,
+    "xn--mgbgu82a" => crate::IN,
+    "بھارت" => crate::IN,
+    "xn--mgbbh1a71e" => crate::IN,
+    "عراق" => crate::IQ,
+    "xn--mgbtx2b" => crate::IQ,
+    "ایران" => crate::IR,
+    "xn--mgba3a4f16a" => crate::IR,
+    "الاردن" => crate::JO,
+    "xn--mgbayh7gpa" => crate::JO,
+    "한국" => crate::KR,
+    "xn--3e0b707e" => crate::KR,
+    "қаз" => crate::KZ,
+    "xn--80ao21a" => crate::KZ,
+    "ලංකා" => crate::LK,
+    "xn--fzc2c9e2c" => crate::LK,
+    "இலங்கை" => crate::LK,
+    "xn--xkc2al3hye2a" => crate::LK,
+    "ليبيا" => crate::LY,
+    "xn--mgbb7fyab" => crate::LY,
+    "المغرب" => crate::MA,
+    "xn--mgbc0a9azcg" => crate::MA,
+    "мкд" => crate::MK,
+    "xn--d1alf" => crate::MK,
+    "мон" => crate::MN,
+    "xn--l1acc" => crate::MN,
+    "澳門" => crate::MO,
+    "xn--mix891f" => crate::MO,
+    "موريتانيا" => crate::MR,
+    "xn--mgbah1a3hjkrd" => crate::MR,
+    "مليسيا" => crate::MY,
+    "xn--mgbx4cd0ab" => crate::MY,
+    "عمان" => crate::OM,
+    "xn--mgb9awbf" => crate::OM,
+    "پاکستان" => crate::PK,
+    "xn--mgbai9azgqp6j" => crate::PK,
+    "فلسطين" => crate::PS,
+    "xn--ygbi2ammx" => crate::PS,
+    "قطر" => crate::QA,
+    "xn--wgbl6a" => crate::QA,
+    "срб" => crate::RS,
+    "xn--90a3ac" => crate::RS,
+    "рф" => crate::RU,
+    "xn--p1ai" => crate::RU,
+    "السعودية" => crate::SA,
+    "xn--mgberp4a5d4ar" => crate::SA,
+    "سودان" => crate::SD,
+    "xn--mgbpl2fh" => crate::SD,
+    "新加坡" => crate::SG,
+    "xn--yfro4i67o" => crate::SG,
+    "சிங்கப்பூர்" => crate::SG,
+    "xn--clchc0ea0b2g2a9gcd" => crate::SG,
+    "سورية" => crate::SY,
+    "xn--ogbpf8fl" => crate::SY,
+    "ไทย" => crate::TH,
+    "xn--o3cw4h" => crate::TH,
+    "تونس" => crate::TN,
+    "xn--pgbs0dh" => crate::TN,
+    "台湾" => crate::TW,
+    "xn--kprw13d" => crate::TW,
+    "台灣" => crate::TW,
+    "xn--kpry57d" => crate::TW,
+    "укр" => crate::UA,
+    "xn--j1amh" => crate::UA,
+    "اليمن" => crate::YE,
+    "xn--mgb2ddes" => crate::YE,
+};
@@ -0,0 +1,1098 @@
+//! Crosswalks between ISO 3166-1 and the country codes of other standards:
+//! FIPS 10-4 (the GEC codes of US government sources), NATO STANAG 1059 and
+//! the ITU.
+//!
+//! Many FIPS codes are another country's ISO alpha-2 code: FIPS `AU` is
+//! Austria and FIPS `AS` is Australia, while ISO `AU` is Australia and ISO
+//! `AS` is American Samoa. Every lookup therefore names its `Scheme`, and
+//! codes from these schemes must never go through `from_alpha2`.
+//!
+//! # Sample code
+//! ```
+//! use rust_iso3166::crosswalk::{self, Scheme};
+//! assert_eq!(Some(rust_iso3166::AT), crosswalk::from_code(Scheme::FIPS, "AU"));
+//! assert_eq!(Some(rust_iso3166::AU), crosswalk::from_code(Scheme::FIPS, "AS"));
+//! assert_eq!(Some("AS"), rust_iso3166::AU.crosswalk_code(Scheme::FIPS));
+//! assert_eq!(Some("G"), rust_iso3166::GB.crosswalk_code(Scheme::ITU));
+//! assert_eq!(Some(rust_iso3166::DE), crosswalk::from_code(Scheme::STANAG, "DEU"));
+//! ```
+
+use crate::parse::{as_str, to_upper};
+use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+
+/// A country coding scheme other than ISO 3166.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Scheme {
+    ///FIPS 10-4 country codes, maintained as the Geopolitical Entities and
+    ///Codes (GEC) of the US government. Some parts of a country have their
+    ///own code, such as `JN` for Jan Mayen; they resolve to the country.
+    FIPS,
+    ///NATO STANAG 1059 trigraphs, the same as ISO 3166-1 alpha-3 since
+    ///edition 9
+    STANAG,
+    ///ITU geographical area symbols, such as `G` for the United Kingdom or
+    ///`HOL` for the Netherlands. Most territories have none.
+    ITU,
+}
+
+/// The codes of one country in each `Scheme`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Crosswalk {
+    ///FIPS 10-4 code
+    pub fips: Option<&'static str>,
+    ///STANAG 1059 trigraph
+    pub stanag: Option<&'static str>,
+    ///ITU geographical area symbol
+    pub itu: Option<&'static str>,
+}
+
+impl Scheme {
+    ///All schemes
+    pub const ALL: &'static [Scheme] = &[Scheme::FIPS, Scheme::STANAG, Scheme::ITU];
+
+    ///Return the name of the standard
+    pub fn name(&self) -> &'static str {
+        match self {
+            Scheme::FIPS => "FIPS 10-4",
+            Scheme::STANAG => "STANAG 1059",
+            Scheme::ITU => "ITU",
+        }
+    }
+
+    ///Return every code of this scheme and the country it stands for
+    pub fn codes(&self) -> &'static Map<&'static str, CountryCode> {
+        match self {
+            Scheme::FIPS => &FIPS_MAP,
+            Scheme::STANAG => &STANAG_MAP,
+            Scheme::ITU => &ITU_MAP,
+        }
+    }
+}
+
+impl CountryCode {
+    ///Return the codes of this country in every scheme
+    pub fn crosswalk(&self) -> Crosswalk {
+        CROSSWALK_MAP.get(self.alpha2).copied().unwrap_or(Crosswalk {
+            fips: None,
+            stanag: None,
+            itu: None,
+        })
+    }
+
+    ///Return the code of this country in the given scheme
+    pub fn crosswalk_code(&self, scheme: Scheme) -> Option<&'static str> {
+        let crosswalk = self.crosswalk();
+        match scheme {
+            Scheme::FIPS => crosswalk.fips,
+            Scheme::STANAG => crosswalk.stanag,
+            Scheme::ITU => crosswalk.itu,
+        }
+    }
+}
+
+/// Returns the country with the given code in the given scheme, if exists.
+/// ASCII case and surrounding whitespace are ignored.
+/// #Sample
+/// ```
+/// use rust_iso3166::crosswalk::{self, Scheme};
+/// assert_eq!(Some(rust_iso3166::DE), crosswalk::from_code(Scheme::FIPS, "gm"));
+/// assert_eq!(Some(rust_iso3166::SJ), crosswalk::from_code(Scheme::FIPS, "JN"));
+/// assert_eq!(Some(rust_iso3166::NL), crosswalk::from_code(Scheme::ITU, "HOL"));
+/// assert_eq!(None, crosswalk::from_code(Scheme::FIPS, "AUT"));
+/// ```
+pub fn from_code(scheme: Scheme, code: &str) -> Option<CountryCode> {
+    let (buf, len) = to_upper::<3>(code.trim(), 1..=3, |_, c| c.is_ascii_alphabetic()).ok()?;
+    scheme.codes().get(as_str(&buf[..len])).copied()
+}
+
+/// Codes in every scheme by alpha-2 code.
+pub const CROSSWALK_MAP: Map<&str, Crosswalk> = phf_map! {
+    "AD" => Crosswalk { fips: Some("AN"), stanag: Some("AND"), itu: Some("AND") },
+    "AE" => Crosswalk { fips: Some("AE"), stanag: Some("ARE"), itu: Some("UAE") },
+    "AF" => Crosswalk { fips: Some("AF"), stanag: Some("AFG"), itu: Some("AFG") },
+    "AG" => Crosswalk { fips: Some("AC"), stanag: Some("ATG"), itu: Some("ATG") },
+    "AI" => Crosswalk { fips: Some("AV"), stanag: Some("AIA"), itu: None },
+    "AL" => Crosswalk { fips: Some("AL"), stanag: Some("ALB"), itu: Some("ALB") },
+    "AM" => Crosswalk { fips: Some("AM"), stanag: Some("ARM"), itu: Some("ARM") },
+    "AO" => Crosswalk { fips: Some("AO"), stanag: Some("AGO"), itu: Some("AGL") },
+    "AQ" => Crosswalk { fips: Some("AY"), stanag: Some("ATA"), itu: None },
+    "AR" => Crosswalk { fips: Some("AR"), stanag: Some("ARG"), itu: Some("ARG") },
+    "AS" => Crosswalk { fips: Some("AQ"), stanag: Some("ASM"), itu: None },
+    "AT" => Crosswalk { fips: Some("AU"), stanag: Some("AUT"), itu: Some("AUT") },
+    "AU" => Crosswalk { fips: Some("AS"), stanag: Some("AUS"), itu: Some("AUS") },
+    "AW" => Crosswalk { fips: Some("AA"), stanag: Some("ABW"), itu: Some("ABW") },
+    "AX" => Crosswalk { fips: None, stanag: Some("ALA"), itu: None },
+    "AZ" => Crosswalk { fips: Some("AJ"), stanag: Some("AZE"), itu: Some("AZE") },
+    "BA" => Crosswalk { fips: Some("BK"), stanag: Some("BIH"), itu: Some("BIH") },
+    "BB" => Crosswalk { fips: Some("BB"), stanag: Some("BRB"), itu: Some("BRB") },
+    "BD" => Crosswalk { fips: Some("BG"), stanag: Some("BGD"), itu: Some("BGD") },
+    "BE" => Crosswalk { fips: Some("BE"), stanag: Some("BEL"), itu: Some("BEL") },
+    "BF" => Crosswalk { fips: Some("UV"), stanag: Some("BFA"), itu: Some("BFA") },
+    "BG" => Crosswalk { fips: Some("BU"), stanag: Some("BGR"), itu: Some("BUL") },
+    "BH" => Crosswalk { fips: Some("BA"), stanag: Some("BHR"), itu: Some("BHR") },
+    "BI" => Crosswalk { fips: Some("BY"), stanag: Some("BDI"), itu: Some("BDI") },
+    "BJ" => Crosswalk { fips: Some("BN"), stanag: Some("BEN"), itu: Some("BEN") },
+    "BL" => Crosswalk { fips: Some("TB"), stanag: Some("BLM"), itu: None },
+    "BM" => Crosswalk { fips: Some("BD"), stanag: Some("BMU"), itu: Some("BER") },
+    "BN" => Crosswalk { fips: Some("BX"), stanag: Some("BRN"), itu: Some("BRM") },
+    "BO" => Crosswalk { fips: Some("BL"), stanag: Some("BOL"), itu: Some("BOL") },
+    "BQ" => Crosswalk { fips: None, stanag: Some("BES"), itu: None },
+    "BR" => Crosswalk { fips: Some("BR"), stanag: Some("BRA"), itu: Some("B") },
+    "BS" => Crosswalk { fips: Some("BF"), stanag: Some("BHS"), itu: Some("BAH") },
+    "BT" => Crosswalk { fips: Some("BT"), stanag: Some("BTN"), itu: Some("BTN") },
+    "BV" => Crosswalk { fips: Some("BV"), stanag: Some("BVT"), itu: None },
+    "BW" => Crosswalk { fips: Some("BC"), stanag: Some("BWA"), itu: Some("BOT") },
+    "BY" => Crosswalk { fips: Some("BO"), stanag: Some("BLR"), itu: Some("BLR") },
+    "BZ" => Crosswalk { fips: Some("BH"), stanag: Some("BLZ"), itu: Some("BLZ") },
+    "CA" => Crosswalk { fips: Some("CA"), stanag: Some("CAN"), itu: Some("CAN") },
+    "CC" => Crosswalk { fips: Some("CK"), stanag: Some("CCK"), itu: None },
+    "CD" => Crosswalk { fips: Some("CG"), stanag: Some("COD"), itu: Some("COD") },
+    "CF" => Crosswalk { fips: Some("CT"), stanag: Some("CAF"), itu: Some("CAF") },
+    "CG" => Crosswalk { fips: Some("CF"), stanag: Some("COG"), itu: Some("COG") },
+    "CH" => Crosswalk { fips: Some("SZ"), stanag: Some("CHE"), itu: Some("SUI") },
+    "CI" => Crosswalk { fips: Some("IV"), stanag: Some("CIV"), itu: Some("CTI") },
+    "CK" => Crosswalk { fips: Some("CW"), stanag: Some("COK"), itu: None },
+    "CL" => Crosswalk { fips: Some("CI"), stanag: Some("CHL"), itu: Some("CHL") },
+    "CM" => Crosswalk { fips: Some("CM"), stanag: Some("CMR"), itu: Some("CME") },
+    "CN" => Crosswalk { fips: Some("CH"), stanag: Some("CHN"), itu: Some("CHN") },
+    "CO" => Crosswalk { fips: Some("CO"), stanag: Some("COL"), itu: Some("CLM") },
+    "CR" => Crosswalk { fips: Some("CS"), stanag: Some("CRI"), itu: Some("CTR") },
+    "CU" => Crosswalk { fips: Some("CU"), stanag: Some("CUB"), itu: Some("CUB") },
+    "CV" => Crosswalk { fips: Some("CV"), stanag: Some("CPV"), itu: Some("CPV") },
+    "CW" => Crosswalk { fips: Some("UC"), stanag: Some("CUW"), itu: Some("CUW") },
+    "CX" => Crosswalk { fips: Some("KT"), stanag: Some("CXR"), itu: None },
+    "CY" => Crosswalk { fips: Some("CY"), stanag: Some("CYP"), itu: Some("CYP") },
+    "CZ" => Crosswalk { fips: Some("EZ"), stanag: Some("CZE"), itu: Some("CZE") },
+    "DE" => Crosswalk { fips: Some("GM"), stanag: Some("DEU"), itu: Some("D") },
+    "DJ" => Crosswalk { fips: Some("DJ"), stanag: Some("DJI"), itu: Some("DJI") },
+    "DK" => Crosswalk { fips: Some("DA"), stanag: Some("DNK"), itu: Some("DNK") },
+    "DM" => Crosswalk { fips: Some("DO"), stanag: Some("DMA"), itu: Some("DMA") },
+    "DO" => Crosswalk { fips: Some("DR"), stanag: Some("DOM"), itu: Some("DOM") },
+    "DZ" => Crosswalk { fips: Some("AG"), stanag: Some("DZA"), itu: Some("ALG") },
+    "EC" => Crosswalk { fips: Some("EC"), stanag: Some("ECU"), itu: Some("EQA") },
+    "EE" => Crosswalk { fips: Some("EN"), stanag: Some("EST"), itu: Some("EST") },
+    "EG" => Crosswalk { fips: Some("EG"), stanag: Some("EGY"), itu: Some("EGY") },
+    "EH" => Crosswalk { fips: Some("WI"), stanag: Some("ESH"), itu: None },
+    "ER" => Crosswalk { fips: Some("ER"), stanag: Some("ERI"), itu: Some("ERI") },
+    "ES" => Crosswalk { fips: Some("SP"), stanag: Some("ESP"), itu: Some("E") },
+    "ET" => Crosswalk { fips: Some("ET"), stanag: Some("ETH"), itu: Some("ETH") },
+    "FI" => Crosswalk { fips: Some("FI"), stanag: Some("FIN"), itu: Some("FIN") },
+    "FJ" => Crosswalk { fips: Some("FJ"), stanag: Some("FJI"), itu: Some("FJI") },
+    "FK" => Crosswalk { fips: Some("FK"), stanag: Some("FLK"), itu: None },
+    "FM" => Crosswalk { fips: Some("FM"), stanag: Some("FSM"), itu: Some("FSM") },
+    "FO" => Crosswalk { fips: Some("FO"), stanag: Some("FRO"), itu: Some("FRO") },
+    "FR" => Crosswalk { fips: Some("FR"), stanag: Some("FRA"), itu: Some("F") },
+    "GA" => Crosswalk { fips: Some("GB"), stanag: Some("GAB"), itu: Some("GAB") },
+    "GB" => Crosswalk { fips: Some("UK"), stanag: Some("GBR"), itu: Some("G") },
+    "GD" => Crosswalk { fips: Some("GJ"), stanag: Some("GRD"), itu: Some("GRD") },
+    "GE" => Crosswalk { fips: Some("GG"), stanag: Some("GEO"), itu: Some("GEO") },
+    "GF" => Crosswalk { fips: Some("FG"), stanag: Some("GUF"), itu: Some("GUF") },
+    "GG" => Crosswalk { fips: Some("GK"), stanag: Some("GGY"), itu: None },
+    "GH" => Crosswalk { fips: Some("GH"), stanag: Some("GHA"), itu: Some("GHA") },
+    "GI" => Crosswalk { fips: Some("GI"), stanag: Some("GIB"), itu: Some("GIB") },
+    "GL" => Crosswalk { fips: Some("GL"), stanag: Some("GRL"), itu: Some("GRL") },
+    "GM" => Crosswalk { fips: Some("GA"), stanag: Some("GMB"), itu: Some("GMB") },
+    "GN" => Crosswalk { fips: Some("GV"), stanag: Some("GIN"), itu: Some("GUI") },
+    "GP" => Crosswalk { fips: Some("GP"), stanag: Some("GLP"), itu: Some("GDL") },
+    "GQ" => Crosswalk { fips: Some("EK"), stanag: Some("GNQ"), itu: Some("GNE") },
+    "GR" => Crosswalk { fips: Some("GR"), stanag: Some("GRC"), itu: Some("GRC") },
+    "GS" => Crosswalk { fips: Some("SX"), stanag: Some("SGS"), itu: None },
+    "GT" => Crosswalk { fips: Some("GT"), stanag: Some("GTM"), itu: Some("GTM") },
+    "GU" => Crosswalk { fips: Some("GQ"), stanag: Some("GUM"), itu: Some("GUM") },
+    "GW" => Crosswalk { fips: Some("PU"), stanag: Some("GNB"), itu: Some("GNB") },
+    "GY" => Crosswalk { fips: Some("GY"), stanag: Some("GUY"), itu: Some("GUY") },
+    "HK" => Crosswalk { fips: Some("HK"), stanag: Some("HKG"), itu: Some("HKG") },
+    "HM" => Crosswalk { fips: Some("HM"), stanag: Some("HMD"), itu: None },
+    "HN" => Crosswalk { fips: Some("HO"), stanag: Some("HND"), itu: Some("HND") },
+    "HR" => Crosswalk { fips: Some("HR"), stanag: Some("HRV"), itu: Some("HRV") },
+    "HT" => Crosswalk { fips: Some("HA"), stanag: Some("HTI"), itu: Some("HTI") },
+    "HU" => Crosswalk { fips: Some("HU"), stanag: Some("HUN"), itu: Some("HNG") },
+    "ID" => Crosswalk { fips: Some("ID"), stanag: Some("IDN"), itu: Some("INS") },
+    "IE" => Crosswalk { fips: Some("EI"), stanag: Some("IRL"), itu: Some("IRL") },
+    "IL" => Crosswalk { fips: Some("IS"), stanag: Some("ISR"), itu: Some("ISR") },
+    "IM" => Crosswalk { fips: Some("IM"), stanag: Some("IMN"), itu: None },
+    "IN" => Crosswalk { fips: Some("IN"), stanag: Some("IND"), itu: Some("IND") },
+    "IO" => Crosswalk { fips: Some("IO"), stanag: Some("IOT"), itu: None },
+    "IQ" => Crosswalk { fips: Some("IZ"), stanag: Some("IRQ"), itu: Some("IRQ") },
+    "IR" => Crosswalk { fips: Some("IR"), stanag: Some("IRN"), itu: Some("IRN") },
+    "IS" => Crosswalk { fips: Some("IC"), stanag: Some("ISL"), itu: Some("ISL") },
+    "IT" => Crosswalk { fips: Some("IT"), stanag: Some("ITA"), itu: Some("I") },
+    "JE" => Crosswalk { fips: Some("JE"), stanag: Some("JEY"), itu: None },
+    "JM" => Crosswalk { fips: Some("JM"), stanag: Some("JAM"), itu: Some("JMC") },
+    "JO" => Crosswalk { fips: Some("JO"), stanag: Some("JOR"), itu: Some("JOR") },
+    "JP" => Crosswalk { fips: Some("JA"), stanag: Some("JPN"), itu: Some("J") },
+    "KE" => Crosswalk { fips: Some("KE"), stanag: Some("KEN"), itu: Some("KEN") },
+    "KG" => Crosswalk { fips: Some("KG"), stanag: Some("KGZ"), itu: Some("KGZ") },
+    "KH" => Crosswalk { fips: Some("CB"), stanag: Some("KHM"), itu: Some("CBG") },
+    "KI" => Crosswalk { fips: Some("KR"), stanag: Some("KIR"), itu: Some("KIR") },
+    "KM" => Crosswalk { fips: Some("CN"), stanag: Some("COM"), itu: Some("COM") },
+    "KN" => Crosswalk { fips: Some("SC"), stanag: Some("KNA"), itu: Some("KNA") },
+    "KP" => Crosswalk { fips: Some("KN"), stanag: Some("PRK"), itu: Some("KRE") },
+    "KR" => Crosswalk { fips: Some("KS"), stanag: Some("KOR"), itu: Some("KOR") },
+    "KW" => Crosswalk { fips: Some("KU"), stanag: Some("KWT"), itu: Some("KWT") },
+    "KY" => Crosswalk { fips: Some("CJ"), stanag: Some("CYM"), itu: Some("CYM") },
+    "KZ" => Crosswalk { fips: Some("KZ"), stanag: Some("KAZ"), itu: Some("KAZ") },
+    "LA" => Crosswalk { fips: Some("LA"), stanag: Some("LAO"), itu: Some("LAO") },
+    "LB" => Crosswalk { fips: Some("LE"), stanag: Some("LBN"), itu: Some("LBN") },
+    "LC" => Crosswalk { fips: Some("ST"), stanag: Some("LCA"), itu: Some("LCA") },
+    "LI" => Crosswalk { fips: Some("LS"), stanag: Some("LIE"), itu: Some("LIE") },
+    "LK" => Crosswalk { fips: Some("CE"), stanag: Some("LKA"), itu: Some("CLN") },
+    "LR" => Crosswalk { fips: Some("LI"), stanag: Some("LBR"), itu: Some("LBR") },
+    "LS" => Crosswalk { fips: Some("LT"), stanag: Some("LSO"), itu: Some("LSO") },
+    "LT" => Crosswalk { fips: Some("LH"), stanag: Some("LTU"), itu: Some("LTU") },
+    "LU" => Crosswalk { fips: Some("LU"), stanag: Some("LUX"), itu: Some("LUX") },
+    "LV" => Crosswalk { fips: Some("LG"), stanag: Some("LVA"), itu: Some("LVA") },
+    "LY" => Crosswalk { fips: Some("LY"), stanag: Some("LBY"), itu: Some("LBY") },
+    "MA" => Crosswalk { fips: Some("MO"), stanag: Some("MAR"), itu: Some("MRC") },
+    "MC" => Crosswalk { fips: Some("MN"), stanag: Some("MCO"), itu: Some("MCO") },
+    "MD" => Crosswalk { fips: Some("MD"), stanag: Some("MDA"), itu: Some("MDA") },
+    "ME" => Crosswalk { fips: Some("MJ"), stanag: Some("MNE"), itu: Some("MNE") },
+    "MF" => Crosswalk { fips: Some("RN"), stanag: Some("MAF"), itu: None },
+    "MG" => Crosswalk { fips: Some("MA"), stanag: Some("MDG"), itu: Some("MDG") },
+    "MH" => Crosswalk { fips: Some("RM"), stanag: Some("MHL"), itu: Some("MHL") },
+    "MK" => Crosswalk { fips: Some("MK"), stanag: Some("MKD"), itu: Some("MKD") },
+    "ML" => Crosswalk { fips: Some("ML"), stanag: Some("MLI"), itu: Some("MLI") },
+    "MM" => Crosswalk { fips: Some("BM"), stanag: Some("MMR"), itu: Some("MYA") },
+    "MN" => Crosswalk { fips: Some("MG"), stanag: Some("MNG"), itu: Some("MNG") },
+    "MO" => Crosswalk { fips: Some("MC"), stanag: Some("MAC"), itu: Some("MAC") },
+    "MP" => Crosswalk { fips: Some("CQ"), stanag: Some("MNP"), itu: None },
+    "MQ" => Crosswalk { fips: Some("MB"), stanag: Some("MTQ"), itu: Some("MRT") },
+    "MR" => Crosswalk { fips: Some("MR"), stanag: Some("MRT"), itu: Some("MTN") },
+    "MS" => Crosswalk { fips: Some("MH"), stanag: Some("MSR"), itu: None },
+    "MT" => Crosswalk { fips: Some("MT"), stanag: Some("MLT"), itu: Some("MLT") },
+    "MU" => Crosswalk { fips: Some("MP"), stanag: Some("MUS"), itu: Some("MAU") },
+    "MV" => Crosswalk { fips: Some("MV"), stanag: Some("MDV"), itu: Some("MLD") },
+    "MW" => Crosswalk { fips: Some("MI"), stanag: Some("MWI"), itu: Some("MWI") },
+    "MX" => Crosswalk { fips: Some("MX"), stanag: Some("MEX"), itu: Some("MEX") },
+    "MY" => Crosswalk { fips: Some("MY"), stanag: Some("MYS"), itu: Some("MLA") },
+    "MZ" => Crosswalk { fips: Some("MZ"), stanag: Some("MOZ"), itu: Some("MOZ") },
+    "NA" => Crosswalk { fips: Some("WA"), stanag: Some("NAM"), itu: Some("NMB") },
+    "NC" => Crosswalk { fips: Some("NC"), stanag: Some("NCL"), itu: Some("NCL") },
+    "NE" => Crosswalk { fips: Some("NG"), stanag: Some("NER"), itu: Some("NGR") },
+    "NF" => Crosswalk { fips: Some("NF"), stanag: Some("NFK"), itu: None },
+    "NG" => Crosswalk { fips: Some("NI"), stanag: Some("NGA"), itu: Some("NIG") },
+    "NI" => Crosswalk { fips: Some("NU"), stanag: Some("NIC"), itu: Some("NCG") },
+    "NL" => Crosswalk { fips: Some("NL"), stanag: Some("NLD"), itu: Some("HOL") },
+    "NO" => Crosswalk { fips: Some("NO"), stanag: Some("NOR"), itu: Some("NOR") },
+    "NP" => Crosswalk { fips: Some("NP"), stanag: Some("NPL"), itu: Some("NPL") },
+    "NR" => Crosswalk { fips: Some("NR"), stanag: Some("NRU"), itu: Some("NRU") },
+    "NU" => Crosswalk { fips: Some("NE"), stanag: Some("NIU"), itu: None },
+    "NZ" => Crosswalk { fips: Some("NZ"), stanag: Some("NZL"), itu: Some("NZL") },
+    "OM" => Crosswalk { fips: Some("MU"), stanag: Some("OMN"), itu: Some("OMA") },
+    "PA" => Crosswalk { fips: Some("PM"), stanag: Some("PAN"), itu: Some("PNR") },
+    "PE" => Crosswalk { fips: Some("PE"), stanag: Some("PER"), itu: Some("PRU") },
+    "PF" => Crosswalk { fips: Some("FP"), stanag: Some("PYF"), itu: Some("OCE") },
+    "PG" => Crosswalk { fips: Some("PP"), stanag: Some("PNG"), itu: Some("PNG") },
+    "PH" => Crosswalk { fips: Some("RP"), stanag: Some("PHL"), itu: Some("PHL") },
+    "PK" => Crosswalk { fips: Some("PK"), stanag: Some("PAK"), itu: Some("PAK") },
+    "PL" => Crosswalk { fips: Some("PL"), stanag: Some("POL"), itu: Some("POL") },
+    "PM" => Crosswalk { fips: Some("SB"), stanag: Some("SPM"), itu: None },
+    "PN" => Crosswalk { fips: Some("PC"), stanag: Some("PCN"), itu: None },
+    "PR" => Crosswalk { fips: Some("RQ"), stanag: Some("PRI"), itu: Some("PTR") },
+    "PS" => Crosswalk { fips: None, stanag: Some("PSE"), itu: None },
+    "PT" => Crosswalk { fips: Some("PO"), stanag: Some("PRT"), itu: Some("POR") },
+    "PW" => Crosswalk { fips: Some("PS"), stanag: Some("PLW"), itu: Some("PLW") },
+    "PY" => Crosswalk { fips: Some("PA"), stanag: Some("PRY"), itu: Some("PRG") },
+    "QA" => Crosswalk { fips: Some("QA"), stanag: Some("QAT"), itu: Some("QAT") },
+    "RE" => Crosswalk { fips: Some("RE"), stanag: Some("REU"), itu: Some("REU") },
+    "RO" => Crosswalk { fips: Some("RO"), stanag: Some("ROU"), itu: Some("ROU") },
+    "RS" => Crosswalk { fips: Some("RI"), stanag: Some("SRB"), itu: Some("SRB") },
+    "RU" => Crosswalk { fips: Some("RS"), stanag: Some("RUS"), itu: Some("RUS") },
+    "RW" => Crosswalk { fips: Some("RW"), stanag: Some("RWA"), itu: Some("RRW") },
+    "SA" => Crosswalk { fips: Some("SA"), stanag: Some("SAU"), itu: Some("ARS") },
+    "SB" => Crosswalk { fips: Some("BP"), stanag: Some("SLB"), itu: Some("SLM") },
+    "SC" => Crosswalk { fips: Some("SE"), stanag: Some("SYC"), itu: Some("SEY") },
+    "SD" => Crosswalk { fips: Some("SU"), stanag: Some("SDN"), itu: Some("SDN") },
+    "SE" => Crosswalk { fips: Some("SW"), stanag: Some("SWE"), itu: Some("S") },
+    "SG" => Crosswalk { fips: Some("SN"), stanag: Some("SGP"), itu: Some("SNG") },
+    "SH" => Crosswalk { fips: Some("SH"), stanag: Some("SHN"), itu: None },
+    "SI" => Crosswalk { fips: Some("SI"), stanag: Some("SVN"), itu: Some("SVN") },
+    "SJ" => Crosswalk { fips: Some("SV"), stanag: Some("SJM"), itu: None },
+    "SK" => Crosswalk { fips: Some("LO"), stanag: Some("SVK"), itu: Some("SVK") },
+    "SL" => Crosswalk { fips: Some("SL"), stanag: Some("SLE"), itu: Some("SRL") },
+    "SM" => Crosswalk { fips: Some("SM"), stanag: Some("SMR"), itu: Some("SMR") },
+    "SN" => Crosswalk { fips: Some("SG"), stanag: Some("SEN"), itu: Some("SEN") },
+    "SO" => Crosswalk { fips: Some("SO"), stanag: Some("SOM"), itu: Some("SOM") },
+    "SR" => Crosswalk { fips: Some("NS"), stanag: Some("SUR"), itu: Some("SUR") },
+    "SS" => Crosswalk { fips: Some("OD"), stanag: Some("SSD"), itu: Some("SSD") },
+    "ST" => Crosswalk { fips: Some("TP"), stanag: Some("STP"), itu: Some("STP") },
+    "SV" => Crosswalk { fips: Some("ES"), stanag: Some("SLV"), itu: Some("SLV") },
+    "SX" => Crosswalk { fips: Some("NN"), stanag: Some("SXM"), itu: None },
+    "SY" => Crosswalk { fips: Some("SY"), stanag: Some("SYR"), itu: Some("SYR") },
+    "SZ" => Crosswalk { fips: Some("WZ"), stanag: Some("SWZ"), itu: Some("SWZ") },
+    "TC" => Crosswalk { fips: Some("TK"), stanag: Some("TCA"), itu: None },
+    "TD" => Crosswalk { fips: Some("CD"), stanag: Some("TCD"), itu: Some("TCD") },
+    "TF" => Crosswalk { fips: Some("FS"), stanag: Some("ATF"), itu: None },
+    "TG" => Crosswalk { fips: Some("TO"), stanag: Some("TGO"), itu: Some("TGO") },
+    "TH" => Crosswalk { fips: Some("TH"), stanag: Some("THA"), itu: Some("THA") },
+    "TJ" => Crosswalk { fips: Some("TI"), stanag: Some("TJK"), itu: Some("TJK") },
+    "TK" => Crosswalk { fips: Some("TL"), stanag: Some("TKL"), itu: None },
+    "TL" => Crosswalk { fips: Some("TT"), stanag: Some("TLS"), itu: Some("TLS") },
+    "TM" => Crosswalk { fips: Some("TX"), stanag: Some("TKM"), itu: Some("TKM") },
+    "TN" => Crosswalk { fips: Some("TS"), stanag: Some("TUN"), itu: Some("TUN") },
+    "TO" => Crosswalk { fips: Some("TN"), stanag: Some("TON"), itu: Some("TON") },
+    "TR" => Crosswalk { fips: Some("TU"), stanag: Some("TUR"), itu: Some("TUR") },
+    "TT" => Crosswalk { fips: Some("TD"), stanag: Some("TTO"), itu: Some("TRD") },
+    "TV" => Crosswalk { fips: Some("TV"), stanag: Some("TUV"), itu: Some("TUV") },
+    "TW" => Crosswalk { fips: Some("TW"), stanag: Some("TWN"), itu: None },
+    "TZ" => Crosswalk { fips: Some("TZ"), stanag: Some("TZA"), itu: Some("TZA") },
+    "UA" => Crosswalk { fips: Some("UP"), stanag: Some("UKR"), itu: Some("UKR") },
+    "UG" => Crosswalk { fips: Some("UG"), stanag: Some("UGA"), itu: Some("UGA") },
+    "UM" => Crosswalk { fips: None, stanag: Some("UMI"), itu: None },
+    "US" => Crosswalk { fips: Some("US"), stanag: Some("USA"), itu: Some("USA") },
+    "UY" => Crosswalk { fips: Some("UY"), stanag: Some("URY"), itu: Some("URG") },
+    "UZ" => Crosswalk { fips: Some("UZ"), stanag: Some("UZB"), itu: Some("UZB") },
+    "VA" => Crosswalk { fips: Some("VT"), stanag: Some("VAT"), itu: Some("CVA") },
+    "VC" => Crosswalk { fips: Some("VC"), stanag: Some("VCT"), itu: Some("VCT") },
+    "VE" => Crosswalk { fips: Some("VE"), stanag: Some("VEN"), itu: Some("VEN") },
+    "VG" => Crosswalk { fips: Some("VI"), stanag: Some("VGB"), itu: None },
+    "VI" => Crosswalk { fips: Some("VQ"), stanag: Some("VIR"), itu: Some("VIR") },
+    "VN" => Crosswalk { fips: Some("VM"), stanag: Some("VNM"), itu: Some("VTN") },
+    "VU" => Crosswalk { fips: Some("NH"), stanag: Some("VUT"), itu: Some("VUT") },
+    "WF" => Crosswalk { fips: Some("WF"), stanag: Some("WLF"), itu: None },
+    "WS" => Crosswalk { fips: Some("WS"), stanag: Some("WSM"), itu: Some("SMO") },
+    "YE" => Crosswalk { fips: Some("YM"), stanag: Some("YEM"), itu: Some("YEM") },
+    "YT" => Crosswalk { fips: Some("MF"), stanag: Some("MYT"), itu: None },
+    "ZA" => Crosswalk { fips: Some("SF"), stanag: Some("ZAF"), itu: Some("AFS") },
+    "ZM" => Crosswalk { fips: Some("ZA"), stanag: Some("ZMB"), itu: Some("ZMB") },
+    "ZW" => Crosswalk { fips: Some("ZI"), stanag: Some("ZWE"), itu: Some("ZWE") },
+};
+
+/// Countries by FIPS 10-4 code.
+pub const FIPS_MAP: Map<&str, CountryCode> = phf_map! {
+    "AA" => crate::AW,
+    "AC" => crate::AG,
+    "AE" => crate::AE,
+    "AF" => crate::AF,
+    "AG" => crate::DZ,
+    "AJ" => crate::AZ,
+    "AL" => crate::AL,
+    "AM" => crate::AM,
+    "AN" => crate::AD,
+    "AO" => crate::AO,
+    "AQ" => crate::AS,
+    "AR" => crate::AR,
+    "AS" => crate::AU,
+    "AU" => crate::AT,
+    "AV" => crate::AI,
+    "AY" => crate::AQ,
+    "BA" => crate::BH,
+    "BB" => crate::BB,
+    "BC" => crate::BW,
+    "BD" => crate::BM,
+    "BE" => crate::BE,
+    "BF" => crate::BS,
+    "BG" => crate::BD,
+    "BH" => crate::BZ,
+    "BK" => crate::BA,
+    "BL" => crate::BO,
+    "BM" => crate::MM,
+    "BN" => crate::BJ,
+    "BO" => crate::BY,
+    "BP" => crate::SB,
+    "BQ" => crate::UM,
+    "BR" => crate::BR,
+    "BS" => crate::TF,
+    "BT" => crate::BT,
+    "BU" => crate::BG,
+    "BV" => crate::BV,
+    "BX" => crate::BN,
+    "BY" => crate::BI,
+    "CA" => crate::CA,
+    "CB" => crate::KH,
+    "CD" => crate::TD,
+    "CE" => crate::LK,
+    "CF" => crate::CG,
+    "CG" => crate::CD,
+    "CH" => crate::CN,
+    "CI" => crate::CL,
+    "CJ" => crate::KY,
+    "CK" => crate::CC,
+    "CM" => crate::CM,
+    "CN" => crate::KM,
+    "CO" => crate::CO,
+    "CQ" => crate::MP,
+    "CS" => crate::CR,
+    "CT" => crate::CF,
+    "CU" => crate::CU,
+    "CV" => crate::CV,
+    "CW" => crate::CK,
+    "CY" => crate::CY,
+    "DA" => crate::DK,
+    "DJ" => crate::DJ,
+    "DO" => crate::DM,
+    "DQ" => crate::UM,
+    "DR" => crate::DO,
+    "EC" => crate::EC,
+    "EG" => crate::EG,
+    "EI" => crate::IE,
+    "EK" => crate::GQ,
+    "EN" => crate::EE,
+    "ER" => crate::ER,
+    "ES" => crate::SV,
+    "ET" => crate::ET,
+    "EU" => crate::TF,
+    "EZ" => crate::CZ,
+    "FG" => crate::GF,
+    "FI" => crate::FI,
+    "FJ" => crate::FJ,
+    "FK" => crate::FK,
+    "FM" => crate::FM,
+    "FO" => crate::FO,
+    "FP" => crate::PF,
+    "FQ" => crate::UM,
+    "FR" => crate::FR,
+    "FS" => crate::TF,
+    "GA" => crate::GM,
+    "GB" => crate::GA,
+    "GG" => crate::GE,
+    "GH" => crate::GH,
+    "GI" => crate::GI,
+    "GJ" => crate::GD,
+    "GK" => crate::GG,
+    "GL" => crate::GL,
+    "GM" => crate::DE,
+    "GO" => crate::TF,
+    "GP" => crate::GP,
+    "GQ" => crate::GU,
+    "GR" => crate::GR,
+    "GT" => crate::GT,
+    "GV" => crate::GN,
+    "GY" => crate::GY,
+    "GZ" => crate::PS,
+    "HA" => crate::HT,
+    "HK" => crate::HK,
+    "HM" => crate::HM,
+    "HO" => crate::HN,
+    "HQ" => crate::UM,
+    "HR" => crate::HR,
+    "HU" => crate::HU,
+    "IC" => crate::IS,
+    "ID" => crate::ID,
+    "IM" => crate::IM,
+    "IN" => crate::IN,
+    "IO" => crate::IO,
+    "IR" => crate::IR,
+    "IS" => crate::IL,
+    "IT" => crate::IT,
+    "IV" => crate::CI,
+    "IZ" => crate::IQ,
+    "JA" => crate::JP,
+    "JE" => crate::JE,
+    "JM" => crate::JM,
+    "JN" => crate::SJ,
+    "JO" => crate::JO,
+    "JQ" => crate::UM,
+    "JU" => crate::TF,
+    "KE" => crate::KE,
+    "KG" => crate::KG,
+    "KN" => crate::KP,
+    "KQ" => crate::UM,
+    "KR" => crate::KI,
+    "KS" => crate::KR,
+    "KT" => crate::CX,
+    "KU" => crate::KW,
+    "KZ" => crate::KZ,
+    "LA" => crate::LA,
+    "LE" => crate::LB,
+    "LG" => crate::LV,
+    "LH" => crate::LT,
+    "LI" => crate::LR,
+    "LO" => crate::SK,
+    "LQ" => crate::UM,
+    "LS" => crate::LI,
+    "LT" => crate::LS,
+    "LU" => crate::LU,
+    "LY" => crate::LY,
+    "MA" => crate::MG,
+    "MB" => crate::MQ,
+    "MC" => crate::MO,
+    "MD" => crate::MD,
+    "MF" => crate::YT,
+    "MG" => crate::MN,
+    "MH" => crate::MS,
+    "MI" => crate::MW,
+    "MJ" => crate::ME,
+    "MK" => crate::MK,
+    "ML" => crate::ML,
+    "MN" => crate::MC,
+    "MO" => crate::MA,
+    "MP" => crate::MU,
+    "MQ" => crate::UM,
+    "MR" => crate::MR,
+    "MT" => crate::MT,
+    "MU" => crate::OM,
+    "MV" => crate::MV,
+    "MX" => crate::MX,
+    "MY" => crate::MY,
+    "MZ" => crate::MZ,
+    "NC" => crate::NC,
+    "NE" => crate::NU,
+    "NF" => crate::NF,
+    "NG" => crate::NE,
+    "NH" => crate::VU,
+    "NI" => crate::NG,
+    "NL" => crate::NL,
+    "NN" => crate::SX,
+    "NO" => crate::NO,
+    "NP" => crate::NP,
+    "NR" => crate::NR,
+    "NS" => crate::SR,
+    "NU" => crate::NI,
+    "NZ" => crate::NZ,
+    "OD" => crate::SS,
+    "PA" => crate::PY,
+    "PC" => crate::PN,
+    "PE" => crate::PE,
+    "PK" => crate::PK,
+    "PL" => crate::PL,
+    "PM" => crate::PA,
+    "PO" => crate::PT,
+    "PP" => crate::PG,
+    "PS" => crate::PW,
+    "PU" => crate::GW,
+    "QA" => crate::QA,
+    "RE" => crate::RE,
+    "RI" => crate::RS,
+    "RM" => crate::MH,
+    "RN" => crate::MF,
+    "RO" => crate::RO,
+    "RP" => crate::PH,
+    "RQ" => crate::PR,
+    "RS" => crate::RU,
+    "RW" => crate::RW,
+    "SA" => crate::SA,
+    "SB" => crate::PM,
+    "SC" => crate::KN,
+    "SE" => crate::SC,
+    "SF" => crate::ZA,
+    "SG" => crate::SN,
+    "SH" => crate::SH,
+    "SI" => crate::SI,
+    "SL" => crate::SL,
+    "SM" => crate::SM,
+    "SN" => crate::SG,
+    "SO" => crate::SO,
+    "SP" => crate::ES,
+    "ST" => crate::LC,
+    "SU" => crate::SD,
+    "SV" => crate::SJ,
+    "SW" => crate::SE,
+    "SX" => crate::GS,
+    "SY" => crate::SY,
+    "SZ" => crate::CH,
+    "TB" => crate::BL,
+    "TD" => crate::TT,
+    "TE" => crate::TF,
+    "TH" => crate::TH,
+    "TI" => crate::TJ,
+    "TK" => crate::TC,
+    "TL" => crate::TK,
+    "TN" => crate::TO,
+    "TO" => crate::TG,
+    "TP" => crate::ST,
+    "TS" => crate::TN,
+    "TT" => crate::TL,
+    "TU" => crate::TR,
+    "TV" => crate::TV,
+    "TW" => crate::TW,
+    "TX" => crate::TM,
+    "TZ" => crate::TZ,
+    "UC" => crate::CW,
+    "UG" => crate::UG,
+    "UK" => crate::GB,
+    "UP" => crate::UA,
+    "US" => crate::US,
+    "UV" => crate::BF,
+    "UY" => crate::UY,
+    "UZ" => crate::UZ,
+    "VC" => crate::VC,
+    "VE" => crate::VE,
+    "VI" => crate::VG,
+    "VM" => crate::VN,
+    "VQ" => crate::VI,
+    "VT" => crate::VA,
+    "WA" => crate::NA,
+    "WE" => crate::PS,
+    "WF" => crate::WF,
+    "WI" => crate::EH,
+    "WQ" => crate::UM,
+    "WS" => crate::WS,
+    "WZ" => crate::SZ,
+    "YM" => crate::YE,
+    "ZA" => crate::ZM,
+    "ZI" => crate::ZW,
+};
+
+/// Countries by STANAG 1059 code.
+pub const STANAG_MAP: Map<&str, CountryCode> = phf_map! {
+    "ABW" => crate::AW,
+    "AFG" => crate::AF,
+    "AGO" => crate::AO,
+    "AIA" => crate::AI,
+    "ALA" => crate::AX,
+    "ALB" => crate::AL,
+    "AND" => crate::AD,
+    "ARE" => crate::AE,
+    "ARG" => crate::AR,
+    "ARM" => crate::AM,
+    "ASM" => crate::AS,
+    "ATA" => crate::AQ,
+    "ATF" => crate::TF,
+    "ATG" => crate::AG,
+    "AUS" => crate::AU,
+    "AUT" => crate::AT,
+    "AZE" => crate::AZ,
+    "BDI" => crate::BI,
+    "BEL" => crate::BE,
+    "BEN" => crate::BJ,
+    "BES" => crate::BQ,
+    "BFA" => crate::BF,
+    "BGD" => crate::BD,
+    "BGR" => crate::BG,
+    "BHR" => crate::BH,
+    "BHS" => crate::BS,
+    "BIH" => crate::BA,
+    "BLM" => crate::BL,
+    "BLR" => crate::BY,
+    "BLZ" => crate::BZ,
+    "BMU" => crate::BM,
+    "BOL" => crate::BO,
+    "BRA" => crate::BR,
+    "BRB" => crate::BB,
+    "BRN" => crate::BN,
+    "BTN" => crate::BT,
+    "BVT" => crate::BV,
+    "BWA" => crate::BW,
+    "CAF" => crate::CF,
+    "CAN" => crate::CA,
+    "CCK" => crate::CC,
+    "CHE" => crate::CH,
+    "CHL" => crate::CL,
+    "CHN" => crate::CN,
+    "CIV" => crate::CI,
+    "CMR" => crate::CM,
+    "COD" => crate::CD,
+    "COG" => crate::CG,
+    "COK" => crate::CK,
+    "COL" => crate::CO,
+    "COM" => crate::KM,
+    "CPV" => crate::CV,
+    "CRI" => crate::CR,
+    "CUB" => crate::CU,
+    "CUW" => crate::CW,
+    "CXR" => crate::CX,
+    "CYM" => crate::KY,
+    "CYP" => crate::CY,
+    "CZE" => crate::CZ,
+    "DEU" => crate::DE,
+    "DJI" => crate::DJ,
+    "DMA" => crate::DM,
+    "DNK" => crate::DK,
+    "DOM" => crate::DO,
+    "DZA" => crate::DZ,
+    "ECU" => crate::EC,
+    "EGY" => crate::EG,
+    "ERI" => crate::ER,
+    "ESH" => crate::EH,
+    "ESP" => crate::ES,
+    "EST" => crate::EE,
+    "ETH" => crate::ET,
+    "FIN" => crate::FI,
+    "FJI" => crate::FJ,
+    "FLK" => crate::FK,
+    "FRA" => crate::FR,
+    "FRO" => crate::FO,
+    "FSM" => crate::FM,
+    "GAB" => crate::GA,
+    "GBR" => crate::GB,
+    "GEO" => crate::GE,
+    "GGY" => crate::GG,
+    "GHA" => crate::GH,
+    "GIB" => crate::GI,
+    "GIN" => crate::GN,
+    "GLP" => crate::GP,
+    "GMB" => crate::GM,
+    "GNB" => crate::GW,
+    "GNQ" => crate::GQ,
+    "GRC" => crate::GR,
+    "GRD" => crate::GD,
+    "GRL" => crate::GL,
+    "GTM" => crate::GT,
+    "GUF" => crate::GF,
+    "GUM" => crate::GU,
+    "GUY" => crate::GY,
+    "HKG" => crate::HK,
+    "HMD" => crate::HM,
+    "HND" => crate::HN,
+    "HRV" => crate::HR,
+    "HTI" => crate::HT,
+    "HUN" => crate::HU,
+    "IDN" => crate::ID,
+    "IMN" => crate::IM,
+    "IND" => crate::IN,
+    "IOT" => crate::IO,
+    "IRL" => crate::IE,
+    "IRN" => crate::IR,
+    "IRQ" => crate::IQ,
+    "ISL" => crate::IS,
+    "ISR" => crate::IL,
+    "ITA" => crate::IT,
+    "JAM" => crate::JM,
+    "JEY" => crate::JE,
+    "JOR" => crate::JO,
+    "JPN" => crate::JP,
+    "KAZ" => crate::KZ,
+    "KEN" => crate::KE,
+    "KGZ" => crate::KG,
+    "KHM" => crate::KH,
+    "KIR" => crate::KI,
+    "KNA" => crate::KN,
+    "KOR" => crate::KR,
+    "KWT" => crate::KW,
+    "LAO" => crate::LA,
+    "LBN" => crate::LB,
+    "LBR" => crate::LR,
+    "LBY" => crate::LY,
+    "LCA" => crate::LC,
+    "LIE" => crate::LI,
+    "LKA" => crate::LK,
+    "LSO" => crate::LS,
+    "LTU" => crate::LT,
+    "LUX" => crate::LU,
+    "LVA" => crate::LV,
+    "MAC" => crate::MO,
+    "MAF" => crate::MF,
+    "MAR" => crate::MA,
+    "MCO" => crate::MC,
+    "MDA" => crate::MD,
+    "MDG" => crate::MG,
+    "MDV" => crate::MV,
+    "MEX" => crate::MX,
+    "MHL" => crate::MH,
+    "MKD" => crate::MK,
+    "MLI" => crate::ML,
+    "MLT" => crate::MT,
+    "MMR" => crate::MM,
+    "MNE" => crate::ME,
+    "MNG" => crate::MN,
+    "MNP" => crate::MP,
+    "MOZ" => crate::MZ,
+    "MRT" => crate::MR,
+    "MSR" => crate::MS,
+    "MTQ" => crate::MQ,
+    "MUS" => crate::MU,
+    "MWI" => crate::MW,
+    "MYS" => crate::MY,
+    "MYT" => crate::YT,
+    "NAM" => crate::NA,
+    "NCL" => crate::NC,
+    "NER" => crate::NE,
+    "NFK" => crate::NF,
+    "NGA" => crate::NG,
+    "NIC" => crate::NI,
+    "NIU" => crate::NU,
+    "NLD" => crate::NL,
+    "NOR" => crate::NO,
+    "NPL" => crate::NP,
+    "NRU" => crate::NR,
+    "NZL" => crate::NZ,
+    "OMN" => crate::OM,
+    "PAK" => crate::PK,
+    "PAN" => crate::PA,
+    "PCN" => crate::PN,
+    "PER" => crate::PE,
+    "PHL" => crate::PH,
+    "PLW" => crate::PW,
+    "PNG" => crate::PG,
+    "POL" => crate::PL,
+    "PRI" => crate::PR,
+    "PRK" => crate::KP,
+    "PRT" => crate::PT,
+    "PRY" => crate::PY,
+    "PSE" => crate::PS,
+    "PYF" => crate::PF,
+    "QAT" => crate::QA,
+    "REU" => crate::RE,
+    "ROU" => crate::RO,
+    "RUS" => crate::RU,
+    "RWA" => crate::RW,
+    "SAU" => crate::SA,
+    "SDN" => crate::SD,
+    "SEN" => crate::SN,
+    "SGP" => crate::SG,
+    "SGS" => crate::GS,
+    "SHN" => crate::SH,
+    "SJM" => crate::SJ,
+    "SLB" => crate::SB,
+    "SLE" => crate::SL,
+    "SLV" => crate::SV,
+    "SMR" => crate::SM,
+    "SOM" => crate::SO,
+    "SPM" => crate::PM,
+    "SRB" => crate::RS,
+    "SSD" => crate::SS,
+    "STP" => crate::ST,
+    "SUR" => crate::SR,
+    "SVK" => crate::SK,
+    "SVN" => crate::SI,
+    "SWE" => crate::SE,
+    "SWZ" => crate::SZ,
+    "SXM" => crate::SX,
+    "SYC" => crate::SC,
+    "SYR" => crate::SY,
+    "TCA" => crate::TC,
+    "TCD" => crate::TD,
+    "TGO" => crate::TG,
+    "THA" => crate::TH,
+    "TJK" => crate::TJ,
+    "TKL" => crate::TK,
+    "TKM" => crate::TM,
+    "TLS" => crate::TL,
+    "TON" => crate::TO,
+    "TTO" => crate::TT,
+    "TUN" => crate::TN,
+    "TUR" => crate::TR,
+    "TUV" => crate::TV,
+    "TWN" => crate::TW,
+    "TZA" => crate::TZ,
+    "UGA" => crate::UG,
+    "UKR" => crate::UA,
+    "UMI" => crate::UM,
+    "URY" => crate::UY,
+    "USA" => crate::US,
+    "UZB" => crate::UZ,
+    "VAT" => crate::VA,
+    "VCT" => crate::VC,
+    "VEN" => crate::VE,
+    "VGB" => crate::VG,
+    "VIR" => crate::VI,
+    "VNM" => crate::VN,
+    "VUT" => crate::VU,
+    "WLF" => crate::WF,
+    "WSM" => crate::WS,
+    "YEM" => crate::YE,
+    "ZAF" => crate::ZA,
+    "ZMB" => crate::ZM,
+    "ZWE" => crate::ZW,
+};
+
+/// Countries by ITU code.
+pub const ITU_MAP: Map<&str, CountryCode> = phf_map! {
+    "ABW" => crate::AW,
+    "AFG" => crate::AF,
+    "AFS" => crate::ZA,
+    "AGL" => crate::AO,
+    "ALB" => crate::AL,
+    "ALG" => crate::DZ,
+    "AND" => crate::AD,
+    "ARG" => crate::AR,
+    "ARM" => crate::AM,
+    "ARS" => crate::SA,
+    "ATG" => crate::AG,
+    "AUS" => crate::AU,
+    "AUT" => crate::AT,
+    "AZE" => crate::AZ,
+    "B" => crate::BR,
+    "BAH" => crate::BS,
+    "BDI" => crate::BI,
+    "BEL" => crate::BE,
+    "BEN" => crate::BJ,
+    "BER" => crate::BM,
+    "BFA" => crate::BF,
+    "BGD" => crate::BD,
+    "BHR" => crate::BH,
+    "BIH" => crate::BA,
+    "BLR" => crate::BY,
+    "BLZ" => crate::BZ,
+    "BOL" => crate::BO,
+    "BOT" => crate::BW,
+    "BRB" => crate::BB,
+    "BRM" => crate::BN,
+    "BTN" => crate::BT,
+    "BUL" => crate::BG,
+    "CAF" => crate::CF,
+    "CAN" => crate::CA,
+    "CBG" => crate::KH,
+    "CHL" => crate::CL,
+    "CHN" => crate::CN,
+    "CLM" => crate::CO,
+    "CLN" => crate::LK,
+    "CME" => crate::CM,
+    "COD" => crate::CD,
+    "COG" => crate::CG,
+    "COM" => crate::KM,
+    "CPV" => crate::CV,
+    "CTI" => crate::CI,
+    "CTR" => crate::CR,
+    "CUB" => crate::CU,
+    "CUW" => crate::CW,
+    "CVA" => crate::VA,
+    "CYM" => crate::KY,
+    "CYP" => crate::CY,
+    "CZE" => crate::CZ,
+    "D" => crate::DE,
+    "DJI" => crate::DJ,
+    "DMA" => crate::DM,
+    "DNK" => crate::DK,
+    "DOM" => crate::DO,
+    "E" => crate::ES,
+    "EGY" => crate::EG,
+    "EQA" => crate::EC,
+    "ERI" => crate::ER,
+    "EST" => crate::EE,
+    "ETH" => crate::ET,
+    "F" => crate::FR,
+    "FIN" => crate::FI,
+    "FJI" => crate::FJ,
+    "FRO" => crate::FO,
+    "FSM" => crate::FM,
+    "G" => crate::GB,
+    "GAB" => crate::GA,
+    "GDL" => crate::GP,
+    "GEO" => crate::GE,
+    "GHA" => crate::GH,
+    "GIB" => crate::GI,
+    "GMB" => crate::GM,
+    "GNB" => crate::GW,
+    "GNE" => crate::GQ,
+    "GRC" => crate::GR,
+    "GRD" => crate::GD,
+    "GRL" => crate::GL,
+    "GTM" => crate::GT,
+    "GUF" => crate::GF,
+    "GUI" => crate::GN,
+    "GUM" => crate::GU,
+    "GUY" => crate::GY,
+    "HKG" => crate::HK,
+    "HND" => crate::HN,
+    "HNG" => crate::HU,
+    "HOL" => crate::NL,
+    "HRV" => crate::HR,
+    "HTI" => crate::HT,
+    "I" => crate::IT,
+    "IND" => crate::IN,
+    "INS" => crate::ID,
+    "IRL" => crate::IE,
+    "IRN" => crate::IR,
+    "IRQ" => crate::IQ,
+    "ISL" => crate::IS,
+    "ISR" => crate::IL,
+    "J" => crate::JP,
+    "JMC" => crate::JM,
+    "JOR" => crate::JO,
+    "KAZ" => crate::KZ,
+    "KEN" => crate::KE,
+    "KGZ" => crate::KG,
+    "KIR" => crate::KI,
+    "KNA" => crate::KN,
+    "KOR" => crate::KR,
+    "KRE" => crate::KP,
+    "KWT" => crate::KW,
+    "LAO" => crate::LA,
+    "LBN" => crate::LB,
+    "LBR" => crate::LR,
+    "LBY" => crate::LY,
+    "LCA" => crate::LC,
+    "LIE" => crate::LI,
+    "LSO" => crate::LS,
+    "LTU" => crate::LT,
+    "LUX" => crate::LU,
+    "LVA" => crate::LV,
+    "MAC" => crate::MO,
+    "MAU" => crate::MU,
+    "MCO" => crate::MC,
+    "MDA" => crate::MD,
+    "MDG" => crate::MG,
+    "MEX" => crate::MX,
+    "MHL" => crate::MH,
+    "MKD" => crate::MK,
+    "MLA" => crate::MY,
+    "MLD" => crate::MV,
+    "MLI" => crate::ML,
+    "MLT" => crate::MT,
+    "MNE" => crate::ME,
+    "MNG" => crate::MN,
+    "MOZ" => crate::MZ,
+    "MRC" => crate::MA,
+    "MRT" => crate::MQ,
+    "MTN" => crate::MR,
+    "MWI" => crate::MW,
+    "MYA" => crate::MM,
+    "NCG" => crate::NI,
+    "NCL" => crate::NC,
+    "NGR" => crate::NE,
+    "NIG" => crate::NG,
+    "NMB" => crate::NA,
+    "NOR" => crate::NO,
+    "NPL" => crate::NP,
+    "NRU" => crate::NR,
+    "NZL" => crate::NZ,
+    "OCE" => crate::PF,
+    "OMA" => crate::OM,
+    "PAK" => crate::PK,
+    "PHL" => crate::PH,
+    "PLW" => crate::PW,
+    "PNG" => crate::PG,
+    "PNR" => crate::PA,
+    "POL" => crate::PL,
+    "POR" => crate::PT,
+    "PRG" => crate::PY,
+    "PRU" => crate::PE,
+    "PTR" => crate::PR,
+    "QAT" => crate::QA,
+    "REU" => crate::RE,
+    "ROU" => crate::RO,
+    "RRW" => crate::RW,
+    "RUS" => crate::RU,
+    "S" => crate::SE,
+    "SDN" => crate::SD,
+    "SEN" => crate::SN,
+    "SEY" => crate::SC,
+    "SLM" => crate::SB,
+    "SLV" => crate::SV,
+    "SMO" => crate::WS,
+    "SMR" => crate::SM,
+    "SNG" => crate::SG,
+    "SOM" => crate::SO,
+    "SRB" => crate::RS,
+    "SRL" => crate::SL,
+    "SSD" => crate::SS,
+    "STP" => crate::ST,
+    "SUI" => crate::CH,
+    "SUR" => crate::SR,
+    "SVK" => crate::SK,
+    "SVN" => crate::SI,
+    "SWZ" => crate::SZ,
+    "SYR" => crate::SY,
+    "TCD" => crate::TD,
+    "TGO" => crate::TG,
+    "THA" => crate::TH,
+    "TJK" => crate::TJ,
+    "TKM" => crate::TM,
+    "TLS" => crate::TL,
+    "TON" => crate::TO,
+    "TRD" => crate::TT,
+    "TUN" => crate::TN,
+    "TUR" => crate::TR,
+    "TUV" => crate::TV,
+    "TZA" => crate::TZ,
+    "UAE" => crate::AE,
+    "UGA" => crate::UG,
+    "UKR" => crate::UA,
+    "URG" => crate::UY,
+    "USA" => crate::US,
+    "UZB" => crate::UZ,
+    "VCT" => crate::VC,
+    "VEN" => crate::VE,
+    "VIR" => crate::VI,
+    "VTN" => crate::VN,
+    "VUT" => crate::VU,
+    "YEM" => crate::YE,
+    "ZMB" => crate::ZM,
+    "ZWE" => crate::ZW,
+};
@@ -0,0 +1,665 @@
+//! ISO 4217 currencies in legal use in each country, behind the `currency`
+//! feature.
+//!
+//! # Sample code
+//! ```
+//! use rust_iso3166::currency;
+//! let panama = rust_iso3166::from_alpha2("PA").unwrap();
+//! let codes: Vec<_> = panama.currencies().iter().map(|c| c.code).collect();
+//! assert_eq!(vec!["PAB", "USD"], codes);
+//!
+//! let eur = currency::from_code("EUR").unwrap();
+//! assert_eq!((978, 2), (eur.numeric, eur.minor_units));
+//! assert!(currency::countries_using("XOF").contains(&rust_iso3166::SN));
+//! ```
+
+use crate::parse::{as_str, to_upper};
+use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+
+/// Data for each ISO 4217 currency.
+#[derive(Debug, Ord, PartialOrd, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Currency {
+    ///Alphabetic code
+    pub code: &'static str,
+    ///Numeric code
+    pub numeric: u16,
+    ///Number of digits after the decimal separator
+    pub minor_units: u8,
+    ///English name
+    pub name: &'static str,
+}
+
+impl Currency {
+    ///Return len 3 String for the numeric code
+    pub fn numeric_str(&self) -> String {
+        format!("{:03}", self.numeric)
+    }
+
+    ///Return the countries using this currency
+    pub fn countries(&self) -> Vec<CountryCode> {
+        countries_using(self.code)
+    }
+}
+
+impl CountryCode {
+    ///Return the ISO 4217 currencies in legal use, main currency first
+    pub fn currencies(&self) -> &'static [Currency] {
+        COUNTRY_CURRENCY_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+}
+
+/// Returns the Currency with the given alphabetic code, if exists. ASCII
+/// case is ignored.
+/// #Sample
+/// ```
+/// let currency = rust_iso3166::currency::from_code("JPY");
+/// assert_eq!(0, currency.unwrap().minor_units);
+/// assert_eq!(currency, rust_iso3166::currency::from_code("jpy"));
+/// ```
+pub fn from_code(code: &str) -> Option<Currency> {
+    let (buf, len) = to_upper::<3>(code.trim(), 3..=3, |_, c| c.is_ascii_alphabetic()).ok()?;
+    CURRENCY_MAP.get(as_str(&buf[..len])).copied()
+}
+
+/// Returns the Currency with the given numeric code, if exists.
+/// #Sample
+/// ```
+/// let currency = rust_iso3166::currency::from_numeric(840);
+/// assert_eq!("USD", currency.unwrap().code);
+/// ```
+pub fn from_numeric(numeric: u16) -> Option<Currency> {
+    CURRENCY_MAP.values().find(|c| c.numeric == numeric).copied()
+}
+
+/// Returns the countries where the currency with the given alphabetic code
+/// is in legal use, in `ALL` order. ASCII case is ignored, as in
+/// `countries_speaking`.
+/// #Sample
+/// ```
+/// let countries = rust_iso3166::currency::countries_using("XCD");
+/// assert!(countries.contains(&rust_iso3166::AG));
+/// assert_eq!(countries, rust_iso3166::currency::countries_using("xcd"));
+/// ```
+pub fn countries_using(code: &str) -> Vec<CountryCode> {
+    let code = code.trim();
+    crate::ALL
+        .iter()
+        .filter(|country| {
+            country
+                .currencies()
+                .iter()
+                .any(|c| c.code.eq_ignore_ascii_case(code))
+        })
+        .copied()
+        .collect()
+}
+
+pub const AED: Currency = Currency { code: "AED", numeric: 784, minor_units: 2, name: "UAE Dirham" };
+pub const AFN: Currency = Currency { code: "AFN", numeric: 971, minor_units: 2, name: "Afghani" };
+pub const ALL: Currency = Currency { code: "ALL", numeric: 8, minor_units: 2, name: "Lek" };
+pub const AMD: Currency = Currency { code: "AMD", numeric: 51, minor_units: 2, name: "Armenian Dram" };
+pub const AOA: Currency = Currency { code: "AOA", numeric: 973, minor_units: 2, name: "Kwanza" };
+pub const ARS: Currency = Currency { code: "ARS", numeric: 32, minor_units: 2, name: "Argentine Peso" };
+pub const AUD: Currency = Currency { code: "AUD", numeric: 36, minor_units: 2, name: "Australian Dollar" };
+pub const AWG: Currency = Currency { code: "AWG", numeric: 533, minor_units: 2, name: "Aruban Florin" };
+pub const AZN: Currency = Currency { code: "AZN", numeric: 944, minor_units: 2, name: "Azerbaijan Manat" };
+pub const BAM: Currency = Currency { code: "BAM", numeric: 977, minor_units: 2, name: "Convertible Mark" };
+pub const BBD: Currency = Currency { code: "BBD", numeric: 52, minor_units: 2, name: "Barbados Dollar" };
+pub const BDT: Currency = Currency { code: "BDT", numeric: 50, minor_units: 2, name: "Taka" };
+pub const BHD: Currency = Currency { code: "BHD", numeric: 48, minor_units: 3, name: "Bahraini Dinar" };
+pub const BIF: Currency = Currency { code: "BIF", numeric: 108, minor_units: 0, name: "Burundi Franc" };
+pub const BMD: Currency = Currency { code: "BMD", numeric: 60, minor_units: 2, name: "Bermudian Dollar" };
+pub const BND: Currency = Currency { code: "BND", numeric: 96, minor_units: 2, name: "Brunei Dollar" };
+pub const BOB: Currency = Currency { code: "BOB", numeric: 68, minor_units: 2, name: "Boliviano" };
+pub const BRL: Currency = Currency { code: "BRL", numeric: 986, minor_units: 2, name: "Brazilian Real" };
+pub const BSD: Currency = Currency { code: "BSD", numeric: 44, minor_units: 2, name: "Bahamian Dollar" };
+pub const BTN: Currency = Currency { code: "BTN", numeric: 64, minor_units: 2, name: "Ngultrum" };
+pub const BWP: Currency = Currency { code: "BWP", numeric: 72, minor_units: 2, name: "Pula" };
+pub const BYN: Currency = Currency { code: "BYN", numeric: 933, minor_units: 2, name: "Belarusian Ruble" };
+pub const BZD: Currency = Currency { code: "BZD", numeric: 84, minor_units: 2, name: "Belize Dollar" };
+pub const CAD: Currency = Currency { code: "CAD", numeric: 124, minor_units: 2, name: "Canadian Dollar" };
+pub const CDF: Currency = Currency { code: "CDF", numeric: 976, minor_units: 2, name: "Congolese Franc" };
+pub const CHF: Currency = Currency { code: "CHF", numeric: 756, minor_units: 2, name: "Swiss Franc" };
+pub const CLP: Currency = Currency { code: "CLP", numeric: 152, minor_units: 0, name: "Chilean Peso" };
+pub const CNY: Currency = Currency { code: "CNY", numeric: 156, minor_units: 2, name: "Yuan Renminbi" };
+pub const COP: Currency = Currency { code: "COP", numeric: 170, minor_units: 2, name: "Colombian Peso" };
+pub const CRC: Currency = Currency { code: "CRC", numeric: 188, minor_units: 2, name: "Costa Rican Colon" };
+pub const CUP: Currency = Currency { code: "CUP", numeric: 192, minor_units: 2, name: "Cuban Peso" };
+pub const CVE: Currency = Currency { code: "CVE", numeric: 132, minor_units: 2, name: "Cabo Verde Escudo" };
+pub const CZK: Currency = Currency { code: "CZK", numeric: 203, minor_units: 2, name: "Czech Koruna" };
+pub const DJF: Currency = Currency { code: "DJF", numeric: 262, minor_units: 0, name: "Djibouti Franc" };
+pub const DKK: Currency = Currency { code: "DKK", numeric: 208, minor_units: 2, name: "Danish Krone" };
+pub const DOP: Currency = Currency { code: "DOP", numeric: 214, minor_units: 2, name: "Dominican Peso" };
+pub const DZD: Currency = Currency { code: "DZD", numeric: 12, minor_units: 2, name: "Algerian Dinar" };
+pub const EGP: Currency = Currency { code: "EGP", numeric: 818, minor_units: 2, name: "Egyptian Pound" };
+pub const ERN: Currency = Currency { code: "ERN", numeric: 232, minor_units: 2, name: "Nakfa" };
+pub const ETB: Currency = Currency { code: "ETB", numeric: 230, minor_units: 2, name: "Ethiopian Birr" };
+pub const EUR: Currency = Currency { code: "EUR", numeric: 978, minor_units: 2, name: "Euro" };
+pub const FJD: Currency = Currency { code: "FJD", numeric: 242, minor_units: 2, name: "Fiji Dollar" };
+pub const FKP: Currency = Currency { code: "FKP", numeric: 238, minor_units: 2, name: "Falkland Islands Pound" };
+pub const GBP: Currency = Currency { code: "GBP", numeric: 826, minor_units: 2, name: "Pound Sterling" };
+pub const GEL: Currency = Currency { code: "GEL", numeric: 981, minor_units: 2, name: "Lari" };
+pub const GHS: Currency = Currency { code: "GHS", numeric: 936, minor_units: 2, name: "Ghana Cedi" };
+pub const GIP: Currency = Currency { code: "GIP", numeric: 292, minor_units: 2, name: "Gibraltar Pound" };
+pub const GMD: Currency = Currency { code: "GMD", numeric: 270, minor_units: 2, name: "Dalasi" };
+pub const GNF: Currency = Currency { code: "GNF", numeric: 324, minor_units: 0, name: "Guinean Franc" };
+pub const GTQ: Currency = Currency { code: "GTQ", numeric: 320, minor_units: 2, name: "Quetzal" };
+pub const GYD: Currency = Currency { code: "GYD", numeric: 328, minor_units: 2, name: "Guyana Dollar" };
+pub const HKD: Currency = Currency { code: "HKD", numeric: 344, minor_units: 2, name: "Hong Kong Dollar" };
+pub const HNL: Currency = Currency { code: "HNL", numeric: 340, minor_units: 2, name: "Lempira" };
+pub const HTG: Currency = Currency { code: "HTG", numeric: 332, minor_units: 2, name: "Gourde" };
+pub const HUF: Currency = Currency { code: "HUF", numeric: 348, minor_units: 2, name: "Forint" };
+pub const IDR: Currency = Currency { code: "IDR", numeric: 360, minor_units: 2, name: "Rupiah" };
+pub const ILS: Currency = Currency { code: "ILS", numeric: 376, minor_units: 2, name: "New Israeli Sheqel" };
+pub const INR: Currency = Currency { code: "INR", numeric: 356, minor_units: 2, name: "Indian Rupee" };
+pub const IQD: Currency = Currency { code: "IQD", numeric: 368, minor_units: 3, name: "Iraqi Dinar" };
+pub const IRR: Currency = Currency { code: "IRR", numeric: 364, minor_units: 2, name: "Iranian Rial" };
+pub const ISK: Currency = Currency { code: "ISK", numeric: 352, minor_units: 0, name: "Iceland Krona" };
+pub const JMD: Currency = Currency { code: "JMD", numeric: 388, minor_units: 2, name: "Jamaican Dollar" };
+pub const JOD: Currency = Currency { code: "JOD", numeric: 400, minor_units: 3, name: "Jordanian Dinar" };
+pub const JPY: Currency = Currency { code: "JPY", numeric: 392, minor_units: 0, name: "Yen" };
+pub const KES: Currency = Currency { code: "KES", numeric: 404, minor_units: 2, name: "Kenyan Shilling" };
+pub const KGS: Currency = Currency { code: "KGS", numeric: 417, minor_units: 2, name: "Som" };
+pub const KHR: Currency = Currency { code: "KHR", numeric: 116, minor_units: 2, name: "Riel" };
+pub const KMF: Currency = Currency { code: "KMF", numeric: 174, minor_units: 0, name: "Comorian Franc" };
+pub const KPW: Currency = Currency { code: "KPW", numeric: 408, minor_units: 2, name: "North Korean Won" };
+pub const KRW: Currency = Currency { code: "KRW", numeric: 410, minor_units: 0, name: "Won" };
+pub const KWD: Currency = Currency { code: "KWD", numeric: 414, minor_units: 3, name: "Kuwaiti Dinar" };
+pub const KYD: Currency = Currency { code: "KYD", numeric: 136, minor_units: 2, name: "Cayman Islands Dollar" };
+pub const KZT: Currency = Currency { code: "KZT", numeric: 398, minor_units: 2, name: "Tenge" };
+pub const LAK: Currency = Currency { code: "LAK", numeric: 418, minor_units: 2, name: "Lao Kip" };
+pub const LBP: Currency = Currency { code: "LBP", numeric: 422, minor_units: 2, name: "Lebanese Pound" };
+pub const LKR: Currency = Currency { code: "LKR", numeric: 144, minor_units: 2, name: "Sri Lanka Rupee" };
+pub const LRD: Currency = Currency { code: "LRD", numeric: 430, minor_units: 2, name: "Liberian Dollar" };
+pub const LSL: Currency = Currency { code: "LSL", numeric: 426, minor_units: 2, name: "Loti" };
+pub const LYD: Currency = Currency { code: "LYD", numeric: 434, minor_units: 3, name: "Libyan Dinar" };
+pub const MAD: Currency = Currency { code: "MAD", numeric: 504, minor_units: 2, name: "Moroccan Dirham" };
+pub const MDL: Currency = Currency { code: "MDL", numeric: 498, minor_units: 2, name: "Moldovan Leu" };
+pub const MGA: Currency = Currency { code: "MGA", numeric: 969, minor_units: 2, name: "Malagasy Ariary" };
+pub const MKD: Currency = Currency { code: "MKD", numeric: 807, minor_units: 2, name: "Denar" };
+pub const MMK: Currency = Currency { code: "MMK", numeric: 104, minor_units: 2, name: "Kyat" };
+pub const MNT: Currency = Currency { code: "MNT", numeric: 496, minor_units: 2, name: "Tugrik" };
+pub const MOP: Currency = Currency { code: "MOP", numeric: 446, minor_units: 2, name: "Pataca" };
+pub const MRU: Currency = Currency { code: "MRU", numeric: 929, minor_units: 2, name: "Ouguiya" };
+pub const MUR: Currency = Currency { code: "MUR", numeric: 480, minor_units: 2, name: "Mauritius Rupee" };
+pub const MVR: Currency = Currency { code: "MVR", numeric: 462, minor_units: 2, name: "Rufiyaa" };
+pub const MWK: Currency = Currency { code: "MWK", numeric: 454, minor_units: 2, name: "Malawi Kwacha" };
+pub const MXN: Currency = Currency { code: "MXN", numeric: 484, minor_units: 2, name: "Mexican Peso" };
+pub const MYR: Currency = Currency { code: "MYR", numeric: 458, minor_units: 2, name: "Malaysian Ringgit" };
+pub const MZN: Currency = Currency { code: "MZN", numeric: 943, minor_units: 2, name: "Mozambique Metical" };
+pub const NAD: Currency = Currency { code: "NAD", numeric: 516, minor_units: 2, name: "Namibia Dollar" };
+pub const NGN: Currency = Currency { code: "NGN", numeric: 566, minor_units: 2, name: "Naira" };
+pub const NIO: Currency = Currency { code: "NIO", numeric: 558, minor_units: 2, name: "Cordoba Oro" };
+pub const NOK: Currency = Currency { code: "NOK", numeric: 578, minor_units: 2, name: "Norwegian Krone" };
+pub const NPR: Currency = Currency { code: "NPR", numeric: 524, minor_units: 2, name: "Nepalese Rupee" };
+pub const NZD: Currency = Currency { code: "NZD", numeric: 554, minor_units: 2, name: "New Zealand Dollar" };
+pub const OMR: Currency = Currency { code: "OMR", numeric: 512, minor_units: 3, name: "Rial Omani" };
+pub const PAB: Currency = Currency { code: "PAB", numeric: 590, minor_units: 2, name: "Balboa" };
+pub const PEN: Currency = Currency { code: "PEN", numeric: 604, minor_units: 2, name: "Sol" };
+pub const PGK: Currency = Currency { code: "PGK", numeric: 598, minor_units: 2, name: "Kina" };
+pub const PHP: Currency = Currency { code: "PHP", numeric: 608, minor_units: 2, name: "Philippine Peso" };
+pub const PKR: Currency = Currency { code: "PKR", numeric: 586, minor_units: 2, name: "Pakistan Rupee" };
+pub const PLN: Currency = Currency { code: "PLN", numeric: 985, minor_units: 2, name: "Zloty" };
+pub const PYG: Currency = Currency { code: "PYG", numeric: 600, minor_units: 0, name: "Guarani" };
+pub const QAR: Currency = Currency { code: "QAR", numeric: 634, minor_units: 2, name: "Qatari Rial" };
+pub const RON: Currency = Currency { code: "RON", numeric: 946, minor_units: 2, name: "Romanian Leu" };
+pub const RSD: Currency = Currency { code: "RSD", numeric: 941, minor_units: 2, name: "Serbian Dinar" };
+pub const RUB: Currency = Currency { code: "RUB", numeric: 643, minor_units: 2, name: "Russian Ruble" };
+pub const RWF: Currency = Currency { code: "RWF", numeric: 646, minor_units: 0, name: "Rwanda Franc" };
+pub const SAR: Currency = Currency { code: "SAR", numeric: 682, minor_units: 2, name: "Saudi Riyal" };
+pub const SBD: Currency = Currency { code: "SBD", numeric: 90, minor_units: 2, name: "Solomon Islands Dollar" };
+pub const SCR: Currency = Currency { code: "SCR", numeric: 690, minor_units: 2, name: "Seychelles Rupee" };
+pub const SDG: Currency = Currency { code: "SDG", numeric: 938, minor_units: 2, name: "Sudanese Pound" };
+pub const SEK: Currency = Currency { code: "SEK", numeric: 752, minor_units: 2, name: "Swedish Krona" };
+pub const SGD: Currency = Currency { code: "SGD", numeric: 702, minor_units: 2, name: "Singapore Dollar" };
+pub const SHP: Currency = Currency { code: "SHP", numeric: 654, minor_units: 2, name: "Saint Helena Pound" };
+pub const SLE: Currency = Currency { code: "SLE", numeric: 925, minor_units: 2, name: "Leone" };
+pub const SOS: Currency = Currency { code: "SOS", numeric: 706, minor_units: 2, name: "Somali Shilling" };
+pub const SRD: Currency = Currency { code: "SRD", numeric: 968, minor_units: 2, name: "Surinam Dollar" };
+pub const SSP: Currency = Currency { code: "SSP", numeric: 728, minor_units: 2, name: "South Sudanese Pound" };
+pub const STN: Currency = Currency { code: "STN", numeric: 930, minor_units: 2, name: "Dobra" };
+pub const SVC: Currency = Currency { code: "SVC", numeric: 222, minor_units: 2, name: "El Salvador Colon" };
+pub const SYP: Currency = Currency { code: "SYP", numeric: 760, minor_units: 2, name: "Syrian Pound" };
+pub const SZL: Currency = Currency { code: "SZL", numeric: 748, minor_units: 2, name: "Lilangeni" };
+pub const THB: Currency = Currency { code: "THB", numeric: 764, minor_units: 2, name: "Baht" };
+pub const TJS: Currency = Currency { code: "TJS", numeric: 972, minor_units: 2, name: "Somoni" };
+pub const TMT: Currency = Currency { code: "TMT", numeric: 934, minor_units: 2, name: "Turkmenistan New Manat" };
+pub const TND: Currency = Currency { code: "TND", numeric: 788, minor_units: 3, name: "Tunisian Dinar" };
+pub const TOP: Currency = Currency { code: "TOP", numeric: 776, minor_units: 2, name: "Pa’anga" };
+pub const TRY: Currency = Currency { code: "TRY", numeric: 949, minor_units: 2, name: "Turkish Lira" };
+pub const TTD: Currency = Currency { code: "TTD", numeric: 780, minor_units: 2, name: "Trinidad and Tobago Dollar" };
+pub const TWD: Currency = Currency { code: "TWD", numeric: 901, minor_units: 2, name: "New Taiwan Dollar" };
+pub const TZS: Currency = Currency { code: "TZS", numeric: 834, minor_units: 2, name: "Tanzanian Shilling" };
+pub const UAH: Currency = Currency { code: "UAH", numeric: 980, minor_units: 2, name: "Hryvnia" };
+pub const UGX: Currency = Currency { code: "UGX", numeric: 800, minor_units: 0, name: "Uganda Shilling" };
+pub const USD: Currency = Currency { code: "USD", numeric: 840, minor_units: 2, name: "US Dollar" };
+pub const UYU: Currency = Currency { code: "UYU", numeric: 858, minor_units: 2, name: "Peso Uruguayo" };
+pub const UZS: Currency = Currency { code: "UZS", numeric: 860, minor_units: 2, name: "Uzbekistan Sum" };
+pub const VED: Currency = Currency { code: "VED", numeric: 926, minor_units: 2, name: "Bolívar Soberano" };
+pub const VES: Currency = Currency { code: "VES", numeric: 928, minor_units: 2, name: "Bolívar Soberano" };
+pub const VND: Currency = Currency { code: "VND", numeric: 704, minor_units: 0, name: "Dong" };
+pub const VUV: Currency = Currency { code: "VUV", numeric: 548, minor_units: 0, name: "Vatu" };
+pub const WST: Currency = Currency { code: "WST", numeric: 882, minor_units: 2, name: "Tala" };
+pub const XAF: Currency = Currency { code: "XAF", numeric: 950, minor_units: 0, name: "CFA Franc BEAC" };
+pub const XCD: Currency = Currency { code: "XCD", numeric: 951, minor_units: 2, name: "East Caribbean Dollar" };
+pub const XCG: Currency = Currency { code: "XCG", numeric: 532, minor_units: 2, name: "Caribbean Guilder" };
+pub const XOF: Currency = Currency { code: "XOF", numeric: 952, minor_units: 0, name: "CFA Franc BCEAO" };
+pub const XPF: Currency = Currency { code: "XPF", numeric: 953, minor_units: 0, name: "CFP Franc" };
+pub const YER: Currency = Currency { code: "YER", numeric: 886, minor_units: 2, name: "Yemeni Rial" };
+pub const ZAR: Currency = Currency { code: "ZAR", numeric: 710, minor_units: 2, name: "Rand" };
+pub const ZMW: Currency = Currency { code: "ZMW", numeric: 967, minor_units: 2, name: "Zambian Kwacha" };
+pub const ZWG: Currency = Currency { code: "ZWG", numeric: 924, minor_units: 2, name: "Zimbabwe Gold" };
+
+/// Currencies in legal use by alpha-3 code.
+pub const CURRENCY_MAP: Map<&str, Currency> = phf_map! {
+    "AED" => AED,
+    "AFN" => AFN,
+    "ALL" => ALL,
+    "AMD" => AMD,
+    "AOA" => AOA,
+    "ARS" => ARS,
+    "AUD" => AUD,
+    "AWG" => AWG,
+    "AZN" => AZN,
+    "BAM" => BAM,
+    "BBD" => BBD,
+    "BDT" => BDT,
+    "BHD" => BHD,
+    "BIF" => BIF,
+    "BMD" => BMD,
+    "BND" => BND,
+    "BOB" => BOB,
+    "BRL" => BRL,
+    "BSD" => BSD,
+    "BTN" => BTN,
+    "BWP" => BWP,
+    "BYN" => BYN,
+    "BZD" => BZD,
+    "CAD" => CAD,
+    "CDF" => CDF,
+    "CHF" => CHF,
+    "CLP" => CLP,
+    "CNY" => CNY,
+    "COP" => COP,
+    "CRC" => CRC,
+    "CUP" => CUP,
+    "CVE" => CVE,
+    "CZK" => CZK,
+    "DJF" => DJF,
+    "DKK" => DKK,
+    "DOP" => DOP,
+    "DZD" => DZD,
+    "EGP" => EGP,
+    "ERN" => ERN,
+    "ETB" => ETB,
+    "EUR" => EUR,
+    "FJD" => FJD,
+    "FKP" => FKP,
+    "GBP" => GBP,
+    "GEL" => GEL,
+    "GHS" => GHS,
+    "GIP" => GIP,
+    "GMD" => GMD,
+    "GNF" => GNF,
+    "GTQ" => GTQ,
+    "GYD" => GYD,
+    "HKD" => HKD,
+    "HNL" => HNL,
+    "HTG" => HTG,
+    "HUF" => HUF,
+    "IDR" => IDR,
+    "ILS" => ILS,
+    "INR" => INR,
+    "IQD" => IQD,
+    "IRR" => IRR,
+    "ISK" => ISK,
+    "JMD" => JMD,
+    "JOD" => JOD,
+    "JPY" => JPY,
+    "KES" => KES,
+    "KGS" => KGS,
+    "KHR" => KHR,
+    "KMF" => KMF,
+    "KPW" => KPW,
+    "KRW" => KRW,
+    "KWD" => KWD,
+    "KYD" => KYD,
+    "KZT" => KZT,
+    "LAK" => LAK,
+    "LBP" => LBP,
+    "LKR" => LKR,
+    "LRD" => LRD,
+    "LSL" => LSL,
+    "LYD" => LYD,
+    "MAD" => MAD,
+    "MDL" => MDL,
+    "MGA" => MGA,
+    "MKD" => MKD,
+    "MMK" => MMK,
+    "MNT" => MNT,
+    "MOP" => MOP,
+    "MRU" => MRU,
+    "MUR" => MUR,
+    "MVR" => MVR,
+    "MWK" => MWK,
+    "MXN" => MXN,
+    "MYR" => MYR,
+    "MZN" => MZN,
+    "NAD" => NAD,
+    "NGN" => NGN,
+    "NIO" => NIO,
+    "NOK" => NOK,
+    "NPR" => NPR,
+    "NZD" => NZD,
+    "OMR" => OMR,
+    "PAB" => PAB,
+    "PEN" => PEN,
+    "PGK" => PGK,
+    "PHP" => PHP,
+    "PKR" => PKR,
+    "PLN" => PLN,
+    "PYG" => PYG,
+    "QAR" => QAR,
+    "RON" => RON,
+    "RSD" => RSD,
+    "RUB" => RUB,
+    "RWF" => RWF,
+    "SAR" => SAR,
+    "SBD" => SBD,
+    "SCR" => SCR,
+    "SDG" => SDG,
+    "SEK" => SEK,
+    "SGD" => SGD,
+    "SHP" => SHP,
+    "SLE" => SLE,
+    "SOS" => SOS,
+    "SRD" => SRD,
+    "SSP" => SSP,
+    "STN" => STN,
+    "SVC" => SVC,
+    "SYP" => SYP,
+    "SZL" => SZL,
+    "THB" => THB,
+    "TJS" => TJS,
+    "TMT" => TMT,
+    "TND" => TND,
+    "TOP" => TOP,
+    "TRY" => TRY,
+    "TTD" => TTD,
+    "TWD" => TWD,
+    "TZS" => TZS,
+    "UAH" => UAH,
+    "UGX" => UGX,
+    "USD" => USD,
+    "UYU" => UYU,
+    "UZS" => UZS,
+    "VED" => VED,
+    "VES" => VES,
+    "VND" => VND,
+    "VUV" => VUV,
+    "WST" => WST,
+    "XAF" => XAF,
+    "XCD" => XCD,
+    "XCG" => XCG,
+    "XOF" => XOF,
+    "XPF" => XPF,
+    "YER" => YER,
+    "ZAR" => ZAR,
+    "ZMW" => ZMW,
+    "ZWG" => ZWG,
+};
+
+/// Currencies in legal use by country alpha-2 code.
+pub const COUNTRY_CURRENCY_MAP: Map<&str, &[Currency]> = phf_map! {
+    "AD" => &[EUR],
+    "AE" => &[AED],
+    "AF" => &[AFN],
+    "AG" => &[XCD],
+    "AI" => &[XCD],
+    "AL" => &[ALL],
+    "AM" => &[AMD],
+    "AO" => &[AOA],
+    "AQ" => &[],
+    "AR" => &[ARS],
+    "AS" => &[USD],
+    "AT" => &[EUR],
+    "AU" => &[AUD],
+    "AW" => &[AWG],
+    "AX" => &[EUR],
+    "AZ" => &[AZN],
+    "BA" => &[BAM],
+    "BB" => &[BBD],
+    "BD" => &[BDT],
+    "BE" => &[EUR],
+    "BF" => &[XOF],
+    "BG" => &[EUR],
+    "BH" => &[BHD],
+    "BI" => &[BIF],
+    "BJ" => &[XOF],
+    "BL" => &[EUR],
+    "BM" => &[BMD],
+    "BN" => &[BND],
+    "BO" => &[BOB],
+    "BQ" => &[USD],
+    "BR" => &[BRL],
+    "BS" => &[BSD],
+    "BT" => &[BTN, INR],
+    "BV" => &[NOK],
+    "BW" => &[BWP],
+    "BY" => &[BYN],
+    "BZ" => &[BZD],
+    "CA" => &[CAD],
+    "CC" => &[AUD],
+    "CD" => &[CDF],
+    "CF" => &[XAF],
+    "CG" => &[XAF],
+    "CH" => &[CHF],
+    "CI" => &[XOF],
+    "CK" => &[NZD],
+    "CL" => &[CLP],
+    "CM" => &[XAF],
+    "CN" => &[CNY],
+    "CO" => &[COP],
+    "CR" => &[CRC],
+    "CU" => &[CUP],
+    "CV" => &[CVE],
+    "CW" => &[XCG],
+    "CX" => &[AUD],
+    "CY" => &[EUR],
+    "CZ" => &[CZK],
+    "DE" => &[EUR],
+    "DJ" => &[DJF],
+    "DK" => &[DKK],
+    "DM" => &[XCD],
+    "DO" => &[DOP],
+    "DZ" => &[DZD],
+    "EC" => &[USD],
+    "EE" => &[EUR],
+    "EG" => &[EGP],
+    "EH" => &[MAD],
+    "ER" => &[ERN],
+    "ES" => &[EUR],
+    "ET" => &[ETB],
+    "FI" => &[EUR],
+    "FJ" => &[FJD],
+    "FK" => &[FKP],
+    "FM" => &[USD],
+    "FO" => &[DKK],
+    "FR" => &[EUR],
+    "GA" => &[XAF],
+    "GB" => &[GBP],
+    "GD" => &[XCD],
+    "GE" => &[GEL],
+    "GF" => &[EUR],
+    "GG" => &[GBP],
+    "GH" => &[GHS],
+    "GI" => &[GIP],
+    "GL" => &[DKK],
+    "GM" => &[GMD],
+    "GN" => &[GNF],
+    "GP" => &[EUR],
+    "GQ" => &[XAF],
+    "GR" => &[EUR],
+    "GS" => &[GBP],
+    "GT" => &[GTQ],
+    "GU" => &[USD],
+    "GW" => &[XOF],
+    "GY" => &[GYD],
+    "HK" => &[HKD],
+    "HM" => &[AUD],
+    "HN" => &[HNL],
+    "HR" => &[EUR],
+    "HT" => &[HTG, USD],
+    "HU" => &[HUF],
+    "ID" => &[IDR],
+    "IE" => &[EUR],
+    "IL" => &[ILS],
+    "IM" => &[GBP],
+    "IN" => &[INR],
+    "IO" => &[USD],
+    "IQ" => &[IQD],
+    "IR" => &[IRR],
+    "IS" => &[ISK],
+    "IT" => &[EUR],
+    "JE" => &[GBP],
+    "JM" => &[JMD],
+    "JO" => &[JOD],
+    "JP" => &[JPY],
+    "KE" => &[KES],
+    "KG" => &[KGS],
+    "KH" => &[KHR],
+    "KI" => &[AUD],
+    "KM" => &[KMF],
+    "KN" => &[XCD],
+    "KP" => &[KPW],
+    "KR" => &[KRW],
+    "KW" => &[KWD],
+    "KY" => &[KYD],
+    "KZ" => &[KZT],
+    "LA" => &[LAK],
+    "LB" => &[LBP],
+    "LC" => &[XCD],
+    "LI" => &[CHF],
+    "LK" => &[LKR],
+    "LR" => &[LRD],
+    "LS" => &[LSL, ZAR],
+    "LT" => &[EUR],
+    "LU" => &[EUR],
+    "LV" => &[EUR],
+    "LY" => &[LYD],
+    "MA" => &[MAD],
+    "MC" => &[EUR],
+    "MD" => &[MDL],
+    "ME" => &[EUR],
+    "MF" => &[EUR],
+    "MG" => &[MGA],
+    "MH" => &[USD],
+    "MK" => &[MKD],
+    "ML" => &[XOF],
+    "MM" => &[MMK],
+    "MN" => &[MNT],
+    "MO" => &[MOP],
+    "MP" => &[USD],
+    "MQ" => &[EUR],
+    "MR" => &[MRU],
+    "MS" => &[XCD],
+    "MT" => &[EUR],
+    "MU" => &[MUR],
+    "MV" => &[MVR],
+    "MW" => &[MWK],
+    "MX" => &[MXN],
+    "MY" => &[MYR],
+    "MZ" => &[MZN],
+    "NA" => &[NAD, ZAR],
+    "NC" => &[XPF],
+    "NE" => &[XOF],
+    "NF" => &[AUD],
+    "NG" => &[NGN],
+    "NI" => &[NIO],
+    "NL" => &[EUR],
+    "NO" => &[NOK],
+    "NP" => &[NPR],
+    "NR" => &[AUD],
+    "NU" => &[NZD],
+    "NZ" => &[NZD],
+    "OM" => &[OMR],
+    "PA" => &[PAB, USD],
+    "PE" => &[PEN],
+    "PF" => &[XPF],
+    "PG" => &[PGK],
+    "PH" => &[PHP],
+    "PK" => &[PKR],
+    "PL" => &[PLN],
+    "PM" => &[EUR],
+    "PN" => &[NZD],
+    "PR" => &[USD],
+    "PS" => &[ILS, JOD],
+    "PT" => &[EUR],
+    "PW" => &[USD],
+    "PY" => &[PYG],
+    "QA" => &[QAR],
+    "RE" => &[EUR],
+    "RO" => &[RON],
+    "RS" => &[RSD],
+    "RU" => &[RUB],
+    "RW" => &[RWF],
+    "SA" => &[SAR],
+    "SB" => &[SBD],
+    "SC" => &[SCR],
+    "SD" => &[SDG],
+    "SE" => &[SEK],
+    "SG" => &[SGD],
+    "SH" => &[SHP],
+    "SI" => &[EUR],
+    "SJ" => &[NOK],
+    "SK" => &[EUR],
+    "SL" => &[SLE],
+    "SM" => &[EUR],
+    "SN" => &[XOF],
+    "SO" => &[SOS],
+    "SR" => &[SRD],
+    "SS" => &[SSP],
+    "ST" => &[STN],
+    "SV" => &[USD, SVC],
+    "SX" => &[XCG],
+    "SY" => &[SYP],
+    "SZ" => &[SZL],
+    "TC" => &[USD],
+    "TD" => &[XAF],
+    "TF" => &[EUR],
+    "TG" => &[XOF],
+    "TH" => &[THB],
+    "TJ" => &[TJS],
+    "TK" => &[NZD],
+    "TL" => &[USD],
+    "TM" => &[TMT],
+    "TN" => &[TND],
+    "TO" => &[TOP],
+    "TR" => &[TRY],
+    "TT" => &[TTD],
+    "TV" => &[AUD],
+    "TW" => &[TWD],
+    "TZ" => &[TZS],
+    "UA" => &[UAH],
+    "UG" => &[UGX],
+    "UM" => &[USD],
+    "US" => &[USD],
+    "UY" => &[UYU],
+    "UZ" => &[UZS],
+    "VA" => &[EUR],
+    "VC" => &[XCD],
+    "VE" => &[VES, VED],
+    "VG" => &[USD],
+    "VI" => &[USD],
+    "VN" => &[VND],
+    "VU" => &[VUV],
+    "WF" => &[XPF],
+    "WS" => &[WST],
+    "YE" => &[YER],
+    "YT" => &[EUR],
+    "ZA" => &[ZAR],
+    "ZM" => &[ZMW],
+    "ZW" => &[ZWG, USD],
+};
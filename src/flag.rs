@@ -0,0 +1,166 @@
+use crate::iso3166_2::{Subdivision, SUBDIVISION_MAP};
+use crate::parse::as_str;
+use crate::{code_status, CodeStatus, CountryCode, ALPHA2_MAP};
+
+/// REGIONAL INDICATOR SYMBOL LETTER A. A flag emoji is the pair of regional
+/// indicators spelling the alpha-2 code.
+const REGIONAL_INDICATOR_A: u32 = 0x1F1E6;
+
+/// WAVING BLACK FLAG, the base of a subdivision flag tag sequence.
+const BLACK_FLAG: char = '\u{1F3F4}';
+
+/// CANCEL TAG, the end of a tag sequence.
+const CANCEL_TAG: char = '\u{E007F}';
+
+/// TAG characters mirror ASCII at this offset.
+const TAG_OFFSET: u32 = 0xE0000;
+
+/// Subdivisions whose flag is recommended for general interchange (RGI) by
+/// Unicode: England, Scotland and Wales.
+const RGI_SUBDIVISION_FLAGS: [&str; 3] = ["GB-ENG", "GB-SCT", "GB-WLS"];
+
+impl CountryCode {
+    ///Return the flag emoji, the pair of regional indicator symbols spelling
+    ///the alpha-2 code
+    pub fn flag_emoji(&self) -> String {
+        self.alpha2
+            .bytes()
+            .filter_map(|b| char::from_u32(REGIONAL_INDICATOR_A + u32::from(b - b'A')))
+            .collect()
+    }
+
+    /// Returns the country of a flag emoji, if exists. Flags of reserved or
+    /// user-assigned codes such as 🇪🇺 or 🇽🇰 return `None`; `flag_status` tells
+    /// them apart.
+    /// #Sample
+    /// ```
+    /// use rust_iso3166::CountryCode;
+    /// assert_eq!(Some(rust_iso3166::AU), CountryCode::from_flag_emoji("🇦🇺"));
+    /// assert_eq!("🇦🇺", rust_iso3166::AU.flag_emoji());
+    /// assert_eq!(None, CountryCode::from_flag_emoji("🇪🇺"));
+    /// ```
+    pub fn from_flag_emoji(emoji: &str) -> Option<CountryCode> {
+        match flag_status(emoji)? {
+            CodeStatus::Assigned(country) => Some(country),
+            _ => None,
+        }
+    }
+}
+
+impl Subdivision {
+    ///Return the Unicode flag tag sequence: a black flag followed by the
+    ///code as lower-case TAG characters without the hyphen (`gbeng` for
+    ///`GB-ENG`) and a CANCEL TAG. Only the sequences for which `is_rgi_flag`
+    ///is true are displayed as flags on most platforms.
+    pub fn flag_tag_sequence(&self) -> String {
+        let tags = self
+            .code
+            .bytes()
+            .filter(|b| *b != b'-')
+            .filter_map(|b| char::from_u32(TAG_OFFSET + u32::from(b.to_ascii_lowercase())));
+        std::iter::once(BLACK_FLAG)
+            .chain(tags)
+            .chain(std::iter::once(CANCEL_TAG))
+            .collect()
+    }
+
+    ///Return true if Unicode recommends the flag tag sequence for general
+    ///interchange (RGI), which is the case for England, Scotland and Wales
+    pub fn is_rgi_flag(&self) -> bool {
+        RGI_SUBDIVISION_FLAGS.contains(&self.code)
+    }
+
+    /// Returns the Subdivision of a Unicode flag tag sequence, if exists.
+    /// Sequences that are well-formed but not RGI, such as the one for
+    /// `US-TX`, are accepted too.
+    /// #Sample
+    /// ```
+    /// use rust_iso3166::iso3166_2::{self, Subdivision};
+    /// let scotland = iso3166_2::from_code("GB-SCT").unwrap();
+    /// assert_eq!("🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}", scotland.flag_tag_sequence());
+    /// assert!(scotland.is_rgi_flag());
+    /// assert_eq!(Some(scotland), Subdivision::from_flag_tag_sequence(&scotland.flag_tag_sequence()));
+    /// ```
+    pub fn from_flag_tag_sequence(sequence: &str) -> Option<Subdivision> {
+        let tags = sequence
+            .trim()
+            .strip_prefix(BLACK_FLAG)?
+            .strip_suffix(CANCEL_TAG)?;
+        let mut code = [0u8; 6];
+        let mut len = 0;
+        for (index, c) in tags.chars().enumerate() {
+            let b = u8::try_from(u32::from(c).checked_sub(TAG_OFFSET)?).ok()?;
+            if !b.is_ascii_alphanumeric() {
+                return None;
+            }
+            if index == 2 {
+                *code.get_mut(len)? = b'-';
+                len += 1;
+            }
+            *code.get_mut(len)? = b.to_ascii_uppercase();
+            len += 1;
+        }
+        SUBDIVISION_MAP.get(as_str(&code[..len])).copied()
+    }
+}
+
+/// Returns how ISO 3166-1 treats the code a flag emoji spells, if the input
+/// is a single pair of regional indicator symbols.
+///
+/// Unicode recommends flags for a few codes that are not assigned to a
+/// country: the exceptionally reserved 🇪🇺 (European Union), 🇺🇳 (United
+/// Nations), 🇦🇨, 🇨🇵, 🇩🇬, 🇪🇦, 🇮🇨 and 🇹🇦, and the user-assigned 🇽🇰
+/// (Kosovo).
+/// #Sample
+/// ```
+/// use rust_iso3166::{flag_status, CodeStatus};
+/// assert_eq!(Some(CodeStatus::Assigned(rust_iso3166::FR)), flag_status("🇫🇷"));
+/// let eu = flag_status("🇪🇺").unwrap();
+/// assert_eq!("European Union", eu.reservation().unwrap().note);
+/// assert_eq!(Some(CodeStatus::UserAssigned), flag_status("🇽🇰"));
+/// assert_eq!(None, flag_status("FR"));
+/// ```
+pub fn flag_status(emoji: &str) -> Option<CodeStatus> {
+    let mut chars = emoji.trim().chars();
+    let code = [
+        regional_indicator(chars.next()?)?,
+        regional_indicator(chars.next()?)?,
+    ];
+    if chars.next().is_some() {
+        return None;
+    }
+    code_status(as_str(&code)).ok()
+}
+
+/// Returns the countries of every flag emoji in `text`, in order of
+/// appearance and with repeats. Flags of codes not assigned to a country,
+/// such as 🇪🇺, are skipped.
+/// #Sample
+/// ```
+/// let found = rust_iso3166::find_flags("Go 🇧🇷! 🇪🇺🇩🇪🇫🇷");
+/// assert_eq!(vec!["BR", "DE", "FR"], found.iter().map(|c| c.alpha2).collect::<Vec<_>>());
+/// ```
+pub fn find_flags(text: &str) -> Vec<CountryCode> {
+    let mut found = Vec::new();
+    let mut first = None;
+    for c in text.chars() {
+        match (first, regional_indicator(c)) {
+            (None, Some(letter)) => first = Some(letter),
+            (Some(previous), Some(letter)) => {
+                first = None;
+                let code = [previous, letter];
+                if let Some(country) = ALPHA2_MAP.get(as_str(&code)) {
+                    found.push(*country);
+                }
+            }
+            (_, None) => first = None,
+        }
+    }
+    found
+}
+
+/// The ASCII upper-case letter a regional indicator symbol stands for.
+fn regional_indicator(c: char) -> Option<u8> {
+    let offset = u32::from(c).checked_sub(REGIONAL_INDICATOR_A)?;
+    (offset < 26).then(|| b'A' + offset as u8)
+}
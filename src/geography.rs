@@ -0,0 +1,858 @@
+use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+
+/// Mean radius of the Earth in kilometres (IUGG).
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// A point on the Earth, in degrees (WGS 84).
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Coordinates {
+    ///Latitude, positive north of the equator
+    pub latitude: f64,
+    ///Longitude, positive east of Greenwich
+    pub longitude: f64,
+}
+
+/// A capital city.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Capital {
+    ///English name, such as `Pretoria`
+    pub name: &'static str,
+    ///Location of the city centre
+    pub coordinates: Coordinates,
+}
+
+/// The extent of a country, in degrees. `west` is greater than `east` when
+/// the box crosses the antimeridian, as for Fiji, Kiribati, Russia, the
+/// United States Minor Outlying Islands and the United States.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BoundingBox {
+    ///Southernmost latitude
+    pub south: f64,
+    ///Westernmost longitude
+    pub west: f64,
+    ///Northernmost latitude
+    pub north: f64,
+    ///Easternmost longitude
+    pub east: f64,
+}
+
+impl Coordinates {
+    ///Return the great-circle distance to `other` in kilometres, using the
+    ///haversine formula on a spherical Earth
+    pub fn distance_km(&self, other: &Coordinates) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let dlat = lat2 - lat1;
+        let dlon = (other.longitude - self.longitude).to_radians();
+        let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+    }
+}
+
+impl BoundingBox {
+    ///Return true if the box crosses the antimeridian (180° longitude)
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+
+    ///Return true if the point lies inside the box, edges included
+    pub fn contains(&self, point: &Coordinates) -> bool {
+        let longitude = if self.crosses_antimeridian() {
+            point.longitude >= self.west || point.longitude <= self.east
+        } else {
+            (self.west..=self.east).contains(&point.longitude)
+        };
+        longitude && (self.south..=self.north).contains(&point.latitude)
+    }
+}
+
+impl CountryCode {
+    ///Return the capitals, the constitutional capital first. Most countries
+    ///have one; South Africa lists Pretoria, Cape Town and Bloemfontein and
+    ///Bolivia lists Sucre and La Paz. Empty for territories without a
+    ///capital, such as Antarctica, Hong Kong or Tokelau.
+    pub fn capital(&self) -> &'static [Capital] {
+        CAPITAL_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+
+    ///Return a representative point near the geographic centre of the main
+    ///territory, suitable for centering a map. For the United States it is
+    ///in the contiguous states, for France in the metropole.
+    pub fn centroid(&self) -> Option<Coordinates> {
+        CENTROID_MAP.get(self.alpha2).copied()
+    }
+
+    ///Return the bounding box of the mainland and nearby islands, suitable
+    ///for zooming a map. Outlying island groups such as Hawaii or the Canary
+    ///Islands are left out.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        BOUNDING_BOX_MAP.get(self.alpha2).copied()
+    }
+}
+
+/// Returns the great-circle distance between the centroids of two countries
+/// in kilometres, if both have one. The distance between neighbours can
+/// exceed the one between their capitals or borders by far, so it only
+/// suits rough estimates.
+/// #Sample
+/// ```
+/// let km = rust_iso3166::distance_km(rust_iso3166::FR, rust_iso3166::DE).unwrap();
+/// assert!((800.0..900.0).contains(&km));
+/// assert_eq!(Some(0.0), rust_iso3166::distance_km(rust_iso3166::FR, rust_iso3166::FR));
+/// assert_eq!("Sucre", rust_iso3166::BO.capital()[0].name);
+/// ```
+pub fn distance_km(a: CountryCode, b: CountryCode) -> Option<f64> {
+    Some(a.centroid()?.distance_km(&b.centroid()?))
+}
+
+/// Capitals by alpha-2 code.
+pub const CAPITAL_MAP: Map<&str, &[Capital]> = phf_map! {
+    "AD" => &[Capital { name: "Andorra la Vella", coordinates: Coordinates { latitude: 42.51, longitude: 1.52 } }],
+    "AE" => &[Capital { name: "Abu Dhabi", coordinates: Coordinates { latitude: 24.45, longitude: 54.38 } }],
+    "AF" => &[Capital { name: "Kabul", coordinates: Coordinates { latitude: 34.53, longitude: 69.17 } }],
+    "AG" => &[Capital { name: "Saint John's", coordinates: Coordinates { latitude: 17.12, longitude: -61.85 } }],
+    "AI" => &[Capital { name: "The Valley", coordinates: Coordinates { latitude: 18.22, longitude: -63.06 } }],
+    "AL" => &[Capital { name: "Tirana", coordinates: Coordinates { latitude: 41.33, longitude: 19.82 } }],
+    "AM" => &[Capital { name: "Yerevan", coordinates: Coordinates { latitude: 40.18, longitude: 44.51 } }],
+    "AO" => &[Capital { name: "Luanda", coordinates: Coordinates { latitude: -8.84, longitude: 13.23 } }],
+    "AR" => &[Capital { name: "Buenos Aires", coordinates: Coordinates { latitude: -34.60, longitude: -58.38 } }],
+    "AS" => &[Capital { name: "Pago Pago", coordinates: Coordinates { latitude: -14.28, longitude: -170.70 } }],
+    "AT" => &[Capital { name: "Vienna", coordinates: Coordinates { latitude: 48.21, longitude: 16.37 } }],
+    "AU" => &[Capital { name: "Canberra", coordinates: Coordinates { latitude: -35.28, longitude: 149.13 } }],
+    "AW" => &[Capital { name: "Oranjestad", coordinates: Coordinates { latitude: 12.52, longitude: -70.03 } }],
+    "AX" => &[Capital { name: "Mariehamn", coordinates: Coordinates { latitude: 60.10, longitude: 19.94 } }],
+    "AZ" => &[Capital { name: "Baku", coordinates: Coordinates { latitude: 40.41, longitude: 49.87 } }],
+    "BA" => &[Capital { name: "Sarajevo", coordinates: Coordinates { latitude: 43.86, longitude: 18.41 } }],
+    "BB" => &[Capital { name: "Bridgetown", coordinates: Coordinates { latitude: 13.10, longitude: -59.62 } }],
+    "BD" => &[Capital { name: "Dhaka", coordinates: Coordinates { latitude: 23.81, longitude: 90.41 } }],
+    "BE" => &[Capital { name: "Brussels", coordinates: Coordinates { latitude: 50.85, longitude: 4.35 } }],
+    "BF" => &[Capital { name: "Ouagadougou", coordinates: Coordinates { latitude: 12.37, longitude: -1.52 } }],
+    "BG" => &[Capital { name: "Sofia", coordinates: Coordinates { latitude: 42.70, longitude: 23.32 } }],
+    "BH" => &[Capital { name: "Manama", coordinates: Coordinates { latitude: 26.23, longitude: 50.59 } }],
+    "BI" => &[Capital { name: "Gitega", coordinates: Coordinates { latitude: -3.43, longitude: 29.93 } }, Capital { name: "Bujumbura", coordinates: Coordinates { latitude: -3.38, longitude: 29.36 } }],
+    "BJ" => &[Capital { name: "Porto-Novo", coordinates: Coordinates { latitude: 6.50, longitude: 2.60 } }, Capital { name: "Cotonou", coordinates: Coordinates { latitude: 6.37, longitude: 2.39 } }],
+    "BL" => &[Capital { name: "Gustavia", coordinates: Coordinates { latitude: 17.90, longitude: -62.85 } }],
+    "BM" => &[Capital { name: "Hamilton", coordinates: Coordinates { latitude: 32.29, longitude: -64.78 } }],
+    "BN" => &[Capital { name: "Bandar Seri Begawan", coordinates: Coordinates { latitude: 4.90, longitude: 114.94 } }],
+    "BO" => &[Capital { name: "Sucre", coordinates: Coordinates { latitude: -19.05, longitude: -65.26 } }, Capital { name: "La Paz", coordinates: Coordinates { latitude: -16.50, longitude: -68.15 } }],
+    "BQ" => &[Capital { name: "Kralendijk", coordinates: Coordinates { latitude: 12.15, longitude: -68.27 } }],
+    "BR" => &[Capital { name: "Brasília", coordinates: Coordinates { latitude: -15.79, longitude: -47.88 } }],
+    "BS" => &[Capital { name: "Nassau", coordinates: Coordinates { latitude: 25.05, longitude: -77.35 } }],
+    "BT" => &[Capital { name: "Thimphu", coordinates: Coordinates { latitude: 27.47, longitude: 89.64 } }],
+    "BW" => &[Capital { name: "Gaborone", coordinates: Coordinates { latitude: -24.65, longitude: 25.91 } }],
+    "BY" => &[Capital { name: "Minsk", coordinates: Coordinates { latitude: 53.90, longitude: 27.56 } }],
+    "BZ" => &[Capital { name: "Belmopan", coordinates: Coordinates { latitude: 17.25, longitude: -88.77 } }],
+    "CA" => &[Capital { name: "Ottawa", coordinates: Coordinates { latitude: 45.42, longitude: -75.70 } }],
+    "CC" => &[Capital { name: "West Island", coordinates: Coordinates { latitude: -12.19, longitude: 96.83 } }],
+    "CD" => &[Capital { name: "Kinshasa", coordinates: Coordinates { latitude: -4.32, longitude: 15.31 } }],
+    "CF" => &[Capital { name: "Bangui", coordinates: Coordinates { latitude: 4.39, longitude: 18.56 } }],
+    "CG" => &[Capital { name: "Brazzaville", coordinates: Coordinates { latitude: -4.27, longitude: 15.28 } }],
+    "CH" => &[Capital { name: "Bern", coordinates: Coordinates { latitude: 46.95, longitude: 7.45 } }],
+    "CI" => &[Capital { name: "Yamoussoukro", coordinates: Coordinates { latitude: 6.82, longitude: -5.28 } }, Capital { name: "Abidjan", coordinates: Coordinates { latitude: 5.36, longitude: -4.01 } }],
+    "CK" => &[Capital { name: "Avarua", coordinates: Coordinates { latitude: -21.21, longitude: -159.78 } }],
+    "CL" => &[Capital { name: "Santiago", coordinates: Coordinates { latitude: -33.45, longitude: -70.67 } }],
+    "CM" => &[Capital { name: "Yaoundé", coordinates: Coordinates { latitude: 3.85, longitude: 11.50 } }],
+    "CN" => &[Capital { name: "Beijing", coordinates: Coordinates { latitude: 39.90, longitude: 116.41 } }],
+    "CO" => &[Capital { name: "Bogotá", coordinates: Coordinates { latitude: 4.71, longitude: -74.07 } }],
+    "CR" => &[Capital { name: "San José", coordinates: Coordinates { latitude: 9.93, longitude: -84.09 } }],
+    "CU" => &[Capital { name: "Havana", coordinates: Coordinates { latitude: 23.11, longitude: -82.37 } }],
+    "CV" => &[Capital { name: "Praia", coordinates: Coordinates { latitude: 14.93, longitude: -23.51 } }],
+    "CW" => &[Capital { name: "Willemstad", coordinates: Coordinates { latitude: 12.11, longitude: -68.93 } }],
+    "CX" => &[Capital { name: "Flying Fish Cove", coordinates: Coordinates { latitude: -10.42, longitude: 105.68 } }],
+    "CY" => &[Capital { name: "Nicosia", coordinates: Coordinates { latitude: 35.19, longitude: 33.38 } }],
+    "CZ" => &[Capital { name: "Prague", coordinates: Coordinates { latitude: 50.08, longitude: 14.44 } }],
+    "DE" => &[Capital { name: "Berlin", coordinates: Coordinates { latitude: 52.52, longitude: 13.40 } }],
+    "DJ" => &[Capital { name: "Djibouti", coordinates: Coordinates { latitude: 11.59, longitude: 43.15 } }],
+    "DK" => &[Capital { name: "Copenhagen", coordinates: Coordinates { latitude: 55.68, longitude: 12.57 } }],
+    "DM" => &[Capital { name: "Roseau", coordinates: Coordinates { latitude: 15.30, longitude: -61.39 } }],
+    "DO" => &[Capital { name: "Santo Domingo", coordinates: Coordinates { latitude: 18.49, longitude: -69.93 } }],
+    "DZ" => &[Capital { name: "Algiers", coordinates: Coordinates { latitude: 36.75, longitude: 3.06 } }],
+    "EC" => &[Capital { name: "Quito", coordinates: Coordinates { latitude: -0.18, longitude: -78.47 } }],
+    "EE" => &[Capital { name: "Tallinn", coordinates: Coordinates { latitude: 59.44, longitude: 24.75 } }],
+    "EG" => &[Capital { name: "Cairo", coordinates: Coordinates { latitude: 30.04, longitude: 31.24 } }],
+    "EH" => &[Capital { name: "Laayoune", coordinates: Coordinates { latitude: 27.15, longitude: -13.20 } }],
+    "ER" => &[Capital { name: "Asmara", coordinates: Coordinates { latitude: 15.32, longitude: 38.93 } }],
+    "ES" => &[Capital { name: "Madrid", coordinates: Coordinates { latitude: 40.42, longitude: -3.70 } }],
+    "ET" => &[Capital { name: "Addis Ababa", coordinates: Coordinates { latitude: 9.03, longitude: 38.74 } }],
+    "FI" => &[Capital { name: "Helsinki", coordinates: Coordinates { latitude: 60.17, longitude: 24.94 } }],
+    "FJ" => &[Capital { name: "Suva", coordinates: Coordinates { latitude: -18.14, longitude: 178.44 } }],
+    "FK" => &[Capital { name: "Stanley", coordinates: Coordinates { latitude: -51.69, longitude: -57.86 } }],
+    "FM" => &[Capital { name: "Palikir", coordinates: Coordinates { latitude: 6.92, longitude: 158.16 } }],
+    "FO" => &[Capital { name: "Tórshavn", coordinates: Coordinates { latitude: 62.01, longitude: -6.77 } }],
+    "FR" => &[Capital { name: "Paris", coordinates: Coordinates { latitude: 48.86, longitude: 2.35 } }],
+    "GA" => &[Capital { name: "Libreville", coordinates: Coordinates { latitude: 0.42, longitude: 9.47 } }],
+    "GB" => &[Capital { name: "London", coordinates: Coordinates { latitude: 51.51, longitude: -0.13 } }],
+    "GD" => &[Capital { name: "Saint George's", coordinates: Coordinates { latitude: 12.06, longitude: -61.75 } }],
+    "GE" => &[Capital { name: "Tbilisi", coordinates: Coordinates { latitude: 41.72, longitude: 44.78 } }],
+    "GF" => &[Capital { name: "Cayenne", coordinates: Coordinates { latitude: 4.94, longitude: -52.33 } }],
+    "GG" => &[Capital { name: "Saint Peter Port", coordinates: Coordinates { latitude: 49.46, longitude: -2.54 } }],
+    "GH" => &[Capital { name: "Accra", coordinates: Coordinates { latitude: 5.60, longitude: -0.19 } }],
+    "GI" => &[Capital { name: "Gibraltar", coordinates: Coordinates { latitude: 36.14, longitude: -5.35 } }],
+    "GL" => &[Capital { name: "Nuuk", coordinates: Coordinates { latitude: 64.18, longitude: -51.72 } }],
+    "GM" => &[Capital { name: "Banjul", coordinates: Coordinates { latitude: 13.45, longitude: -16.58 } }],
+    "GN" => &[Capital { name: "Conakry", coordinates: Coordinates { latitude: 9.64, longitude: -13.58 } }],
+    "GP" => &[Capital { name: "Basse-Terre", coordinates: Coordinates { latitude: 16.00, longitude: -61.73 } }],
+    "GQ" => &[Capital { name: "Malabo", coordinates: Coordinates { latitude: 3.75, longitude: 8.78 } }],
+    "GR" => &[Capital { name: "Athens", coordinates: Coordinates { latitude: 37.98, longitude: 23.73 } }],
+    "GS" => &[Capital { name: "King Edward Point", coordinates: Coordinates { latitude: -54.28, longitude: -36.49 } }],
+    "GT" => &[Capital { name: "Guatemala City", coordinates: Coordinates { latitude: 14.63, longitude: -90.51 } }],
+    "GU" => &[Capital { name: "Hagåtña", coordinates: Coordinates { latitude: 13.48, longitude: 144.75 } }],
+    "GW" => &[Capital { name: "Bissau", coordinates: Coordinates { latitude: 11.86, longitude: -15.60 } }],
+    "GY" => &[Capital { name: "Georgetown", coordinates: Coordinates { latitude: 6.80, longitude: -58.16 } }],
+    "HN" => &[Capital { name: "Tegucigalpa", coordinates: Coordinates { latitude: 14.07, longitude: -87.19 } }],
+    "HR" => &[Capital { name: "Zagreb", coordinates: Coordinates { latitude: 45.81, longitude: 15.98 } }],
+    "HT" => &[Capital { name: "Port-au-Prince", coordinates: Coordinates { latitude: 18.54, longitude: -72.34 } }],
+    "HU" => &[Capital { name: "Budapest", coordinates: Coordinates { latitude: 47.50, longitude: 19.04 } }],
+    "ID" => &[Capital { name: "Jakarta", coordinates: Coordinates { latitude: -6.21, longitude: 106.85 } }],
+    "IE" => &[Capital { name: "Dublin", coordinates: Coordinates { latitude: 53.35, longitude: -6.26 } }],
+    "IL" => &[Capital { name: "Jerusalem", coordinates: Coordinates { latitude: 31.77, longitude: 35.21 } }],
+    "IM" => &[Capital { name: "Douglas", coordinates: Coordinates { latitude: 54.15, longitude: -4.48 } }],
+    "IN" => &[Capital { name: "New Delhi", coordinates: Coordinates { latitude: 28.61, longitude: 77.21 } }],
+    "IQ" => &[Capital { name: "Baghdad", coordinates: Coordinates { latitude: 33.31, longitude: 44.36 } }],
+    "IR" => &[Capital { name: "Tehran", coordinates: Coordinates { latitude: 35.69, longitude: 51.39 } }],
+    "IS" => &[Capital { name: "Reykjavík", coordinates: Coordinates { latitude: 64.15, longitude: -21.94 } }],
+    "IT" => &[Capital { name: "Rome", coordinates: Coordinates { latitude: 41.90, longitude: 12.50 } }],
+    "JE" => &[Capital { name: "Saint Helier", coordinates: Coordinates { latitude: 49.19, longitude: -2.11 } }],
+    "JM" => &[Capital { name: "Kingston", coordinates: Coordinates { latitude: 18.02, longitude: -76.80 } }],
+    "JO" => &[Capital { name: "Amman", coordinates: Coordinates { latitude: 31.95, longitude: 35.93 } }],
+    "JP" => &[Capital { name: "Tokyo", coordinates: Coordinates { latitude: 35.68, longitude: 139.69 } }],
+    "KE" => &[Capital { name: "Nairobi", coordinates: Coordinates { latitude: -1.29, longitude: 36.82 } }],
+    "KG" => &[Capital { name: "Bishkek", coordinates: Coordinates { latitude: 42.87, longitude: 74.59 } }],
+    "KH" => &[Capital { name: "Phnom Penh", coordinates: Coordinates { latitude: 11.56, longitude: 104.92 } }],
+    "KI" => &[Capital { name: "South Tarawa", coordinates: Coordinates { latitude: 1.33, longitude: 172.98 } }],
+    "KM" => &[Capital { name: "Moroni", coordinates: Coordinates { latitude: -11.70, longitude: 43.26 } }],
+    "KN" => &[Capital { name: "Basseterre", coordinates: Coordinates { latitude: 17.30, longitude: -62.72 } }],
+    "KP" => &[Capital { name: "Pyongyang", coordinates: Coordinates { latitude: 39.04, longitude: 125.76 } }],
+    "KR" => &[Capital { name: "Seoul", coordinates: Coordinates { latitude: 37.57, longitude: 126.98 } }],
+    "KW" => &[Capital { name: "Kuwait City", coordinates: Coordinates { latitude: 29.38, longitude: 47.99 } }],
+    "KY" => &[Capital { name: "George Town", coordinates: Coordinates { latitude: 19.29, longitude: -81.38 } }],
+    "KZ" => &[Capital { name: "Astana", coordinates: Coordinates { latitude: 51.17, longitude: 71.45 } }],
+    "LA" => &[Capital { name: "Vientiane", coordinates: Coordinates { latitude: 17.98, longitude: 102.63 } }],
+    "LB" => &[Capital { name: "Beirut", coordinates: Coordinates { latitude: 33.89, longitude: 35.50 } }],
+    "LC" => &[Capital { name: "Castries", coordinates: Coordinates { latitude: 14.01, longitude: -60.99 } }],
+    "LI" => &[Capital { name: "Vaduz", coordinates: Coordinates { latitude: 47.14, longitude: 9.52 } }],
+    "LK" => &[Capital { name: "Sri Jayawardenepura Kotte", coordinates: Coordinates { latitude: 6.89, longitude: 79.90 } }, Capital { name: "Colombo", coordinates: Coordinates { latitude: 6.93, longitude: 79.86 } }],
+    "LR" => &[Capital { name: "Monrovia", coordinates: Coordinates { latitude: 6.30, longitude: -10.80 } }],
+    "LS" => &[Capital { name: "Maseru", coordinates: Coordinates { latitude: -29.31, longitude: 27.48 } }],
+    "LT" => &[Capital { name: "Vilnius", coordinates: Coordinates { latitude: 54.69, longitude: 25.28 } }],
+    "LU" => &[Capital { name: "Luxembourg", coordinates: Coordinates { latitude: 49.61, longitude: 6.13 } }],
+    "LV" => &[Capital { name: "Riga", coordinates: Coordinates { latitude: 56.95, longitude: 24.11 } }],
+    "LY" => &[Capital { name: "Tripoli", coordinates: Coordinates { latitude: 32.89, longitude: 13.19 } }],
+    "MA" => &[Capital { name: "Rabat", coordinates: Coordinates { latitude: 34.02, longitude: -6.83 } }],
+    "MC" => &[Capital { name: "Monaco", coordinates: Coordinates { latitude: 43.73, longitude: 7.42 } }],
+    "MD" => &[Capital { name: "Chișinău", coordinates: Coordinates { latitude: 47.01, longitude: 28.86 } }],
+    "ME" => &[Capital { name: "Podgorica", coordinates: Coordinates { latitude: 42.44, longitude: 19.26 } }],
+    "MF" => &[Capital { name: "Marigot", coordinates: Coordinates { latitude: 18.07, longitude: -63.08 } }],
+    "MG" => &[Capital { name: "Antananarivo", coordinates: Coordinates { latitude: -18.88, longitude: 47.51 } }],
+    "MH" => &[Capital { name: "Majuro", coordinates: Coordinates { latitude: 7.09, longitude: 171.38 } }],
+    "MK" => &[Capital { name: "Skopje", coordinates: Coordinates { latitude: 42.00, longitude: 21.43 } }],
+    "ML" => &[Capital { name: "Bamako", coordinates: Coordinates { latitude: 12.64, longitude: -8.00 } }],
+    "MM" => &[Capital { name: "Naypyidaw", coordinates: Coordinates { latitude: 19.76, longitude: 96.08 } }],
+    "MN" => &[Capital { name: "Ulaanbaatar", coordinates: Coordinates { latitude: 47.89, longitude: 106.91 } }],
+    "MP" => &[Capital { name: "Saipan", coordinates: Coordinates { latitude: 15.19, longitude: 145.75 } }],
+    "MQ" => &[Capital { name: "Fort-de-France", coordinates: Coordinates { latitude: 14.60, longitude: -61.07 } }],
+    "MR" => &[Capital { name: "Nouakchott", coordinates: Coordinates { latitude: 18.09, longitude: -15.98 } }],
+    "MS" => &[Capital { name: "Plymouth", coordinates: Coordinates { latitude: 16.71, longitude: -62.22 } }, Capital { name: "Brades", coordinates: Coordinates { latitude: 16.79, longitude: -62.21 } }],
+    "MT" => &[Capital { name: "Valletta", coordinates: Coordinates { latitude: 35.90, longitude: 14.51 } }],
+    "MU" => &[Capital { name: "Port Louis", coordinates: Coordinates { latitude: -20.16, longitude: 57.50 } }],
+    "MV" => &[Capital { name: "Malé", coordinates: Coordinates { latitude: 4.18, longitude: 73.51 } }],
+    "MW" => &[Capital { name: "Lilongwe", coordinates: Coordinates { latitude: -13.96, longitude: 33.79 } }],
+    "MX" => &[Capital { name: "Mexico City", coordinates: Coordinates { latitude: 19.43, longitude: -99.13 } }],
+    "MY" => &[Capital { name: "Kuala Lumpur", coordinates: Coordinates { latitude: 3.15, longitude: 101.69 } }, Capital { name: "Putrajaya", coordinates: Coordinates { latitude: 2.93, longitude: 101.69 } }],
+    "MZ" => &[Capital { name: "Maputo", coordinates: Coordinates { latitude: -25.97, longitude: 32.57 } }],
+    "NA" => &[Capital { name: "Windhoek", coordinates: Coordinates { latitude: -22.56, longitude: 17.08 } }],
+    "NC" => &[Capital { name: "Nouméa", coordinates: Coordinates { latitude: -22.28, longitude: 166.46 } }],
+    "NE" => &[Capital { name: "Niamey", coordinates: Coordinates { latitude: 13.51, longitude: 2.11 } }],
+    "NF" => &[Capital { name: "Kingston", coordinates: Coordinates { latitude: -29.05, longitude: 167.96 } }],
+    "NG" => &[Capital { name: "Abuja", coordinates: Coordinates { latitude: 9.08, longitude: 7.40 } }],
+    "NI" => &[Capital { name: "Managua", coordinates: Coordinates { latitude: 12.11, longitude: -86.24 } }],
+    "NL" => &[Capital { name: "Amsterdam", coordinates: Coordinates { latitude: 52.37, longitude: 4.90 } }, Capital { name: "The Hague", coordinates: Coordinates { latitude: 52.08, longitude: 4.30 } }],
+    "NO" => &[Capital { name: "Oslo", coordinates: Coordinates { latitude: 59.91, longitude: 10.75 } }],
+    "NP" => &[Capital { name: "Kathmandu", coordinates: Coordinates { latitude: 27.72, longitude: 85.32 } }],
+    "NR" => &[Capital { name: "Yaren", coordinates: Coordinates { latitude: -0.55, longitude: 166.92 } }],
+    "NU" => &[Capital { name: "Alofi", coordinates: Coordinates { latitude: -19.06, longitude: -169.92 } }],
+    "NZ" => &[Capital { name: "Wellington", coordinates: Coordinates { latitude: -41.29, longitude: 174.78 } }],
+    "OM" => &[Capital { name: "Muscat", coordinates: Coordinates { latitude: 23.59, longitude: 58.41 } }],
+    "PA" => &[Capital { name: "Panama City", coordinates: Coordinates { latitude: 8.98, longitude: -79.52 } }],
+    "PE" => &[Capital { name: "Lima", coordinates: Coordinates { latitude: -12.05, longitude: -77.04 } }],
+    "PF" => &[Capital { name: "Papeete", coordinates: Coordinates { latitude: -17.54, longitude: -149.57 } }],
+    "PG" => &[Capital { name: "Port Moresby", coordinates: Coordinates { latitude: -9.44, longitude: 147.18 } }],
+    "PH" => &[Capital { name: "Manila", coordinates: Coordinates { latitude: 14.60, longitude: 120.98 } }],
+    "PK" => &[Capital { name: "Islamabad", coordinates: Coordinates { latitude: 33.68, longitude: 73.05 } }],
+    "PL" => &[Capital { name: "Warsaw", coordinates: Coordinates { latitude: 52.23, longitude: 21.01 } }],
+    "PM" => &[Capital { name: "Saint-Pierre", coordinates: Coordinates { latitude: 46.78, longitude: -56.18 } }],
+    "PN" => &[Capital { name: "Adamstown", coordinates: Coordinates { latitude: -25.07, longitude: -130.10 } }],
+    "PR" => &[Capital { name: "San Juan", coordinates: Coordinates { latitude: 18.47, longitude: -66.11 } }],
+    "PS" => &[Capital { name: "Ramallah", coordinates: Coordinates { latitude: 31.90, longitude: 35.20 } }],
+    "PT" => &[Capital { name: "Lisbon", coordinates: Coordinates { latitude: 38.72, longitude: -9.14 } }],
+    "PW" => &[Capital { name: "Ngerulmud", coordinates: Coordinates { latitude: 7.50, longitude: 134.62 } }],
+    "PY" => &[Capital { name: "Asunción", coordinates: Coordinates { latitude: -25.26, longitude: -57.58 } }],
+    "QA" => &[Capital { name: "Doha", coordinates: Coordinates { latitude: 25.29, longitude: 51.53 } }],
+    "RE" => &[Capital { name: "Saint-Denis", coordinates: Coordinates { latitude: -20.88, longitude: 55.45 } }],
+    "RO" => &[Capital { name: "Bucharest", coordinates: Coordinates { latitude: 44.43, longitude: 26.10 } }],
+    "RS" => &[Capital { name: "Belgrade", coordinates: Coordinates { latitude: 44.79, longitude: 20.45 } }],
+    "RU" => &[Capital { name: "Moscow", coordinates: Coordinates { latitude: 55.76, longitude: 37.62 } }],
+    "RW" => &[Capital { name: "Kigali", coordinates: Coordinates { latitude: -1.94, longitude: 30.06 } }],
+    "SA" => &[Capital { name: "Riyadh", coordinates: Coordinates { latitude: 24.71, longitude: 46.68 } }],
+    "SB" => &[Capital { name: "Honiara", coordinates: Coordinates { latitude: -9.43, longitude: 159.96 } }],
+    "SC" => &[Capital { name: "Victoria", coordinates: Coordinates { latitude: -4.62, longitude: 55.45 } }],
+    "SD" => &[Capital { name: "Khartoum", coordinates: Coordinates { latitude: 15.50, longitude: 32.56 } }],
+    "SE" => &[Capital { name: "Stockholm", coordinates: Coordinates { latitude: 59.33, longitude: 18.07 } }],
+    "SG" => &[Capital { name: "Singapore", coordinates: Coordinates { latitude: 1.29, longitude: 103.85 } }],
+    "SH" => &[Capital { name: "Jamestown", coordinates: Coordinates { latitude: -15.93, longitude: -5.72 } }],
+    "SI" => &[Capital { name: "Ljubljana", coordinates: Coordinates { latitude: 46.06, longitude: 14.51 } }],
+    "SJ" => &[Capital { name: "Longyearbyen", coordinates: Coordinates { latitude: 78.22, longitude: 15.65 } }],
+    "SK" => &[Capital { name: "Bratislava", coordinates: Coordinates { latitude: 48.15, longitude: 17.11 } }],
+    "SL" => &[Capital { name: "Freetown", coordinates: Coordinates { latitude: 8.48, longitude: -13.23 } }],
+    "SM" => &[Capital { name: "San Marino", coordinates: Coordinates { latitude: 43.94, longitude: 12.45 } }],
+    "SN" => &[Capital { name: "Dakar", coordinates: Coordinates { latitude: 14.72, longitude: -17.47 } }],
+    "SO" => &[Capital { name: "Mogadishu", coordinates: Coordinates { latitude: 2.05, longitude: 45.32 } }],
+    "SR" => &[Capital { name: "Paramaribo", coordinates: Coordinates { latitude: 5.85, longitude: -55.20 } }],
+    "SS" => &[Capital { name: "Juba", coordinates: Coordinates { latitude: 4.85, longitude: 31.58 } }],
+    "ST" => &[Capital { name: "São Tomé", coordinates: Coordinates { latitude: 0.34, longitude: 6.73 } }],
+    "SV" => &[Capital { name: "San Salvador", coordinates: Coordinates { latitude: 13.69, longitude: -89.22 } }],
+    "SX" => &[Capital { name: "Philipsburg", coordinates: Coordinates { latitude: 18.03, longitude: -63.05 } }],
+    "SY" => &[Capital { name: "Damascus", coordinates: Coordinates { latitude: 33.51, longitude: 36.29 } }],
+    "SZ" => &[Capital { name: "Mbabane", coordinates: Coordinates { latitude: -26.31, longitude: 31.14 } }, Capital { name: "Lobamba", coordinates: Coordinates { latitude: -26.45, longitude: 31.21 } }],
+    "TC" => &[Capital { name: "Cockburn Town", coordinates: Coordinates { latitude: 21.46, longitude: -71.14 } }],
+    "TD" => &[Capital { name: "N'Djamena", coordinates: Coordinates { latitude: 12.13, longitude: 15.06 } }],
+    "TF" => &[Capital { name: "Port-aux-Français", coordinates: Coordinates { latitude: -49.35, longitude: 70.22 } }],
+    "TG" => &[Capital { name: "Lomé", coordinates: Coordinates { latitude: 6.13, longitude: 1.22 } }],
+    "TH" => &[Capital { name: "Bangkok", coordinates: Coordinates { latitude: 13.76, longitude: 100.50 } }],
+    "TJ" => &[Capital { name: "Dushanbe", coordinates: Coordinates { latitude: 38.56, longitude: 68.79 } }],
+    "TL" => &[Capital { name: "Dili", coordinates: Coordinates { latitude: -8.56, longitude: 125.57 } }],
+    "TM" => &[Capital { name: "Ashgabat", coordinates: Coordinates { latitude: 37.95, longitude: 58.38 } }],
+    "TN" => &[Capital { name: "Tunis", coordinates: Coordinates { latitude: 36.81, longitude: 10.18 } }],
+    "TO" => &[Capital { name: "Nukuʻalofa", coordinates: Coordinates { latitude: -21.14, longitude: -175.20 } }],
+    "TR" => &[Capital { name: "Ankara", coordinates: Coordinates { latitude: 39.93, longitude: 32.86 } }],
+    "TT" => &[Capital { name: "Port of Spain", coordinates: Coordinates { latitude: 10.65, longitude: -61.51 } }],
+    "TV" => &[Capital { name: "Funafuti", coordinates: Coordinates { latitude: -8.52, longitude: 179.20 } }],
+    "TW" => &[Capital { name: "Taipei", coordinates: Coordinates { latitude: 25.03, longitude: 121.57 } }],
+    "TZ" => &[Capital { name: "Dodoma", coordinates: Coordinates { latitude: -6.16, longitude: 35.75 } }, Capital { name: "Dar es Salaam", coordinates: Coordinates { latitude: -6.79, longitude: 39.21 } }],
+    "UA" => &[Capital { name: "Kyiv", coordinates: Coordinates { latitude: 50.45, longitude: 30.52 } }],
+    "UG" => &[Capital { name: "Kampala", coordinates: Coordinates { latitude: 0.35, longitude: 32.58 } }],
+    "US" => &[Capital { name: "Washington", coordinates: Coordinates { latitude: 38.90, longitude: -77.04 } }],
+    "UY" => &[Capital { name: "Montevideo", coordinates: Coordinates { latitude: -34.90, longitude: -56.16 } }],
+    "UZ" => &[Capital { name: "Tashkent", coordinates: Coordinates { latitude: 41.30, longitude: 69.24 } }],
+    "VA" => &[Capital { name: "Vatican City", coordinates: Coordinates { latitude: 41.90, longitude: 12.45 } }],
+    "VC" => &[Capital { name: "Kingstown", coordinates: Coordinates { latitude: 13.16, longitude: -61.22 } }],
+    "VE" => &[Capital { name: "Caracas", coordinates: Coordinates { latitude: 10.48, longitude: -66.90 } }],
+    "VG" => &[Capital { name: "Road Town", coordinates: Coordinates { latitude: 18.43, longitude: -64.62 } }],
+    "VI" => &[Capital { name: "Charlotte Amalie", coordinates: Coordinates { latitude: 18.34, longitude: -64.93 } }],
+    "VN" => &[Capital { name: "Hanoi", coordinates: Coordinates { latitude: 21.03, longitude: 105.85 } }],
+    "VU" => &[Capital { name: "Port Vila", coordinates: Coordinates { latitude: -17.73, longitude: 168.32 } }],
+    "WF" => &[Capital { name: "Mata-Utu", coordinates: Coordinates { latitude: -13.28, longitude: -176.17 } }],
+    "WS" => &[Capital { name: "Apia", coordinates: Coordinates { latitude: -13.83, longitude: -171.76 } }],
+    "YE" => &[Capital { name: "Sanaa", coordinates: Coordinates { latitude: 15.37, longitude: 44.19 } }],
+    "YT" => &[Capital { name: "Mamoudzou", coordinates: Coordinates { latitude: -12.78, longitude: 45.23 } }],
+    "ZA" => &[Capital { name: "Pretoria", coordinates: Coordinates { latitude: -25.75, longitude: 28.19 } }, Capital { name: "Cape Town", coordinates: Coordinates { latitude: -33.92, longitude: 18.42 } }, Capital { name: "Bloemfontein", coordinates: Coordinates { latitude: -29.12, longitude: 26.21 } }],
+    "ZM" => &[Capital { name: "Lusaka", coordinates: Coordinates { latitude: -15.39, longitude: 28.32 } }],
+    "ZW" => &[Capital { name: "Harare", coordinates: Coordinates { latitude: -17.83, longitude: 31.05 } }],
+};
+
+/// Representative points by alpha-2 code.
+pub const CENTROID_MAP: Map<&str, Coordinates> = phf_map! {
+    "AD" => Coordinates { latitude: 42.546245, longitude: 1.601554 },
+    "AE" => Coordinates { latitude: 23.424076, longitude: 53.847818 },
+    "AF" => Coordinates { latitude: 33.93911, longitude: 67.709953 },
+    "AG" => Coordinates { latitude: 17.060816, longitude: -61.796428 },
+    "AI" => Coordinates { latitude: 18.220554, longitude: -63.068615 },
+    "AL" => Coordinates { latitude: 41.153332, longitude: 20.168331 },
+    "AM" => Coordinates { latitude: 40.069099, longitude: 45.038189 },
+    "AO" => Coordinates { latitude: -11.202692, longitude: 17.873887 },
+    "AQ" => Coordinates { latitude: -75.250973, longitude: -0.071389 },
+    "AR" => Coordinates { latitude: -38.416097, longitude: -63.616672 },
+    "AS" => Coordinates { latitude: -14.270972, longitude: -170.132217 },
+    "AT" => Coordinates { latitude: 47.516231, longitude: 14.550072 },
+    "AU" => Coordinates { latitude: -25.274398, longitude: 133.775136 },
+    "AW" => Coordinates { latitude: 12.52111, longitude: -69.968338 },
+    "AX" => Coordinates { latitude: 60.1785, longitude: 19.9156 },
+    "AZ" => Coordinates { latitude: 40.143105, longitude: 47.576927 },
+    "BA" => Coordinates { latitude: 43.915886, longitude: 17.679076 },
+    "BB" => Coordinates { latitude: 13.193887, longitude: -59.543198 },
+    "BD" => Coordinates { latitude: 23.684994, longitude: 90.356331 },
+    "BE" => Coordinates { latitude: 50.503887, longitude: 4.469936 },
+    "BF" => Coordinates { latitude: 12.238333, longitude: -1.561593 },
+    "BG" => Coordinates { latitude: 42.733883, longitude: 25.48583 },
+    "BH" => Coordinates { latitude: 25.930414, longitude: 50.637772 },
+    "BI" => Coordinates { latitude: -3.373056, longitude: 29.918886 },
+    "BJ" => Coordinates { latitude: 9.30769, longitude: 2.315834 },
+    "BL" => Coordinates { latitude: 17.9, longitude: -62.83 },
+    "BM" => Coordinates { latitude: 32.321384, longitude: -64.75737 },
+    "BN" => Coordinates { latitude: 4.535277, longitude: 114.727669 },
+    "BO" => Coordinates { latitude: -16.290154, longitude: -63.588653 },
+    "BQ" => Coordinates { latitude: 12.18, longitude: -68.24 },
+    "BR" => Coordinates { latitude: -14.235004, longitude: -51.92528 },
+    "BS" => Coordinates { latitude: 25.03428, longitude: -77.39628 },
+    "BT" => Coordinates { latitude: 27.514162, longitude: 90.433601 },
+    "BV" => Coordinates { latitude: -54.423199, longitude: 3.413194 },
+    "BW" => Coordinates { latitude: -22.328474, longitude: 24.684866 },
+    "BY" => Coordinates { latitude: 53.709807, longitude: 27.953389 },
+    "BZ" => Coordinates { latitude: 17.189877, longitude: -88.49765 },
+    "CA" => Coordinates { latitude: 56.130366, longitude: -106.346771 },
+    "CC" => Coordinates { latitude: -12.164165, longitude: 96.870956 },
+    "CD" => Coordinates { latitude: -4.038333, longitude: 21.758664 },
+    "CF" => Coordinates { latitude: 6.611111, longitude: 20.939444 },
+    "CG" => Coordinates { latitude: -0.228021, longitude: 15.827659 },
+    "CH" => Coordinates { latitude: 46.818188, longitude: 8.227512 },
+    "CI" => Coordinates { latitude: 7.539989, longitude: -5.54708 },
+    "CK" => Coordinates { latitude: -21.236736, longitude: -159.777671 },
+    "CL" => Coordinates { latitude: -35.675147, longitude: -71.542969 },
+    "CM" => Coordinates { latitude: 7.369722, longitude: 12.354722 },
+    "CN" => Coordinates { latitude: 35.86166, longitude: 104.195397 },
+    "CO" => Coordinates { latitude: 4.570868, longitude: -74.297333 },
+    "CR" => Coordinates { latitude: 9.748917, longitude: -83.753428 },
+    "CU" => Coordinates { latitude: 21.521757, longitude: -77.781167 },
+    "CV" => Coordinates { latitude: 16.002082, longitude: -24.013197 },
+    "CW" => Coordinates { latitude: 12.16957, longitude: -68.990021 },
+    "CX" => Coordinates { latitude: -10.447525, longitude: 105.690449 },
+    "CY" => Coordinates { latitude: 35.126413, longitude: 33.429859 },
+    "CZ" => Coordinates { latitude: 49.817492, longitude: 15.472962 },
+    "DE" => Coordinates { latitude: 51.165691, longitude: 10.451526 },
+    "DJ" => Coordinates { latitude: 11.825138, longitude: 42.590275 },
+    "DK" => Coordinates { latitude: 56.26392, longitude: 9.501785 },
+    "DM" => Coordinates { latitude: 15.414999, longitude: -61.370976 },
+    "DO" => Coordinates { latitude: 18.735693, longitude: -70.162651 },
+    "DZ" => Coordinates { latitude: 28.033886, longitude: 1.659626 },
+    "EC" => Coordinates { latitude: -1.831239, longitude: -78.183406 },
+    "EE" => Coordinates { latitude: 58.595272, longitude: 25.013607 },
+    "EG" => Coordinates { latitude: 26.820553, longitude: 30.802498 },
+    "EH" => Coordinates { latitude: 24.215527, longitude: -12.885834 },
+    "ER" => Coordinates { latitude: 15.179384, longitude: 39.782334 },
+    "ES" => Coordinates { latitude: 40.463667, longitude: -3.74922 },
+    "ET" => Coordinates { latitude: 9.145, longitude: 40.489673 },
+    "FI" => Coordinates { latitude: 61.92411, longitude: 25.748151 },
+    "FJ" => Coordinates { latitude: -16.578193, longitude: 179.414413 },
+    "FK" => Coordinates { latitude: -51.796253, longitude: -59.523613 },
+    "FM" => Coordinates { latitude: 7.425554, longitude: 150.550812 },
+    "FO" => Coordinates { latitude: 61.892635, longitude: -6.911806 },
+    "FR" => Coordinates { latitude: 46.227638, longitude: 2.213749 },
+    "GA" => Coordinates { latitude: -0.803689, longitude: 11.609444 },
+    "GB" => Coordinates { latitude: 55.378051, longitude: -3.435973 },
+    "GD" => Coordinates { latitude: 12.262776, longitude: -61.604171 },
+    "GE" => Coordinates { latitude: 42.315407, longitude: 43.356892 },
+    "GF" => Coordinates { latitude: 3.933889, longitude: -53.125782 },
+    "GG" => Coordinates { latitude: 49.465691, longitude: -2.585278 },
+    "GH" => Coordinates { latitude: 7.946527, longitude: -1.023194 },
+    "GI" => Coordinates { latitude: 36.137741, longitude: -5.345374 },
+    "GL" => Coordinates { latitude: 71.706936, longitude: -42.604303 },
+    "GM" => Coordinates { latitude: 13.443182, longitude: -15.310139 },
+    "GN" => Coordinates { latitude: 9.945587, longitude: -9.696645 },
+    "GP" => Coordinates { latitude: 16.25, longitude: -61.55 },
+    "GQ" => Coordinates { latitude: 1.650801, longitude: 10.267895 },
+    "GR" => Coordinates { latitude: 39.074208, longitude: 21.824312 },
+    "GS" => Coordinates { latitude: -54.429579, longitude: -36.587909 },
+    "GT" => Coordinates { latitude: 15.783471, longitude: -90.230759 },
+    "GU" => Coordinates { latitude: 13.444304, longitude: 144.793731 },
+    "GW" => Coordinates { latitude: 11.803749, longitude: -15.180413 },
+    "GY" => Coordinates { latitude: 4.860416, longitude: -58.93018 },
+    "HK" => Coordinates { latitude: 22.396428, longitude: 114.109497 },
+    "HM" => Coordinates { latitude: -53.08181, longitude: 73.504158 },
+    "HN" => Coordinates { latitude: 15.199999, longitude: -86.241905 },
+    "HR" => Coordinates { latitude: 45.1, longitude: 15.2 },
+    "HT" => Coordinates { latitude: 18.971187, longitude: -72.285215 },
+    "HU" => Coordinates { latitude: 47.162494, longitude: 19.503304 },
+    "ID" => Coordinates { latitude: -0.789275, longitude: 113.921327 },
+    "IE" => Coordinates { latitude: 53.41291, longitude: -8.24389 },
+    "IL" => Coordinates { latitude: 31.046051, longitude: 34.851612 },
+    "IM" => Coordinates { latitude: 54.236107, longitude: -4.548056 },
+    "IN" => Coordinates { latitude: 20.593684, longitude: 78.96288 },
+    "IO" => Coordinates { latitude: -6.343194, longitude: 71.876519 },
+    "IQ" => Coordinates { latitude: 33.223191, longitude: 43.679291 },
+    "IR" => Coordinates { latitude: 32.427908, longitude: 53.688046 },
+    "IS" => Coordinates { latitude: 64.963051, longitude: -19.020835 },
+    "IT" => Coordinates { latitude: 41.87194, longitude: 12.56738 },
+    "JE" => Coordinates { latitude: 49.214439, longitude: -2.13125 },
+    "JM" => Coordinates { latitude: 18.109581, longitude: -77.297508 },
+    "JO" => Coordinates { latitude: 30.585164, longitude: 36.238414 },
+    "JP" => Coordinates { latitude: 36.204824, longitude: 138.252924 },
+    "KE" => Coordinates { latitude: -0.023559, longitude: 37.906193 },
+    "KG" => Coordinates { latitude: 41.20438, longitude: 74.766098 },
+    "KH" => Coordinates { latitude: 12.565679, longitude: 104.990963 },
+    "KI" => Coordinates { latitude: -3.370417, longitude: -168.734039 },
+    "KM" => Coordinates { latitude: -11.875001, longitude: 43.872219 },
+    "KN" => Coordinates { latitude: 17.357822, longitude: -62.782998 },
+    "KP" => Coordinates { latitude: 40.339852, longitude: 127.510093 },
+    "KR" => Coordinates { latitude: 35.907757, longitude: 127.766922 },
+    "KW" => Coordinates { latitude: 29.31166, longitude: 47.481766 },
+    "KY" => Coordinates { latitude: 19.513469, longitude: -80.566956 },
+    "KZ" => Coordinates { latitude: 48.019573, longitude: 66.923684 },
+    "LA" => Coordinates { latitude: 19.85627, longitude: 102.495496 },
+    "LB" => Coordinates { latitude: 33.854721, longitude: 35.862285 },
+    "LC" => Coordinates { latitude: 13.909444, longitude: -60.978893 },
+    "LI" => Coordinates { latitude: 47.166, longitude: 9.555373 },
+    "LK" => Coordinates { latitude: 7.873054, longitude: 80.771797 },
+    "LR" => Coordinates { latitude: 6.428055, longitude: -9.429499 },
+    "LS" => Coordinates { latitude: -29.609988, longitude: 28.233608 },
+    "LT" => Coordinates { latitude: 55.169438, longitude: 23.881275 },
+    "LU" => Coordinates { latitude: 49.815273, longitude: 6.129583 },
+    "LV" => Coordinates { latitude: 56.879635, longitude: 24.603189 },
+    "LY" => Coordinates { latitude: 26.3351, longitude: 17.228331 },
+    "MA" => Coordinates { latitude: 31.791702, longitude: -7.09262 },
+    "MC" => Coordinates { latitude: 43.7384, longitude: 7.4246 },
+    "MD" => Coordinates { latitude: 47.411631, longitude: 28.369885 },
+    "ME" => Coordinates { latitude: 42.708678, longitude: 19.37439 },
+    "MF" => Coordinates { latitude: 18.0708, longitude: -63.0501 },
+    "MG" => Coordinates { latitude: -18.766947, longitude: 46.869107 },
+    "MH" => Coordinates { latitude: 7.131474, longitude: 171.184478 },
+    "MK" => Coordinates { latitude: 41.608635, longitude: 21.745275 },
+    "ML" => Coordinates { latitude: 17.570692, longitude: -3.996166 },
+    "MM" => Coordinates { latitude: 21.913965, longitude: 95.956223 },
+    "MN" => Coordinates { latitude: 46.862496, longitude: 103.846656 },
+    "MO" => Coordinates { latitude: 22.198745, longitude: 113.543873 },
+    "MP" => Coordinates { latitude: 17.33083, longitude: 145.38469 },
+    "MQ" => Coordinates { latitude: 14.641528, longitude: -61.024174 },
+    "MR" => Coordinates { latitude: 21.00789, longitude: -10.940835 },
+    "MS" => Coordinates { latitude: 16.742498, longitude: -62.187366 },
+    "MT" => Coordinates { latitude: 35.937496, longitude: 14.375416 },
+    "MU" => Coordinates { latitude: -20.348404, longitude: 57.552152 },
+    "MV" => Coordinates { latitude: 3.202778, longitude: 73.22068 },
+    "MW" => Coordinates { latitude: -13.254308, longitude: 34.301525 },
+    "MX" => Coordinates { latitude: 23.634501, longitude: -102.552784 },
+    "MY" => Coordinates { latitude: 4.210484, longitude: 101.975766 },
+    "MZ" => Coordinates { latitude: -18.665695, longitude: 35.529562 },
+    "NA" => Coordinates { latitude: -22.95764, longitude: 18.49041 },
+    "NC" => Coordinates { latitude: -20.904305, longitude: 165.618042 },
+    "NE" => Coordinates { latitude: 17.607789, longitude: 8.081666 },
+    "NF" => Coordinates { latitude: -29.040835, longitude: 167.954712 },
+    "NG" => Coordinates { latitude: 9.081999, longitude: 8.675277 },
+    "NI" => Coordinates { latitude: 12.865416, longitude: -85.207229 },
+    "NL" => Coordinates { latitude: 52.132633, longitude: 5.291266 },
+    "NO" => Coordinates { latitude: 60.472024, longitude: 8.468946 },
+    "NP" => Coordinates { latitude: 28.394857, longitude: 84.124008 },
+    "NR" => Coordinates { latitude: -0.522778, longitude: 166.931503 },
+    "NU" => Coordinates { latitude: -19.054445, longitude: -169.867233 },
+    "NZ" => Coordinates { latitude: -40.900557, longitude: 174.885971 },
+    "OM" => Coordinates { latitude: 21.512583, longitude: 55.923255 },
+    "PA" => Coordinates { latitude: 8.537981, longitude: -80.782127 },
+    "PE" => Coordinates { latitude: -9.189967, longitude: -75.015152 },
+    "PF" => Coordinates { latitude: -17.679742, longitude: -149.406843 },
+    "PG" => Coordinates { latitude: -6.314993, longitude: 143.95555 },
+    "PH" => Coordinates { latitude: 12.879721, longitude: 121.774017 },
+    "PK" => Coordinates { latitude: 30.375321, longitude: 69.345116 },
+    "PL" => Coordinates { latitude: 51.919438, longitude: 19.145136 },
+    "PM" => Coordinates { latitude: 46.941936, longitude: -56.27111 },
+    "PN" => Coordinates { latitude: -24.703615, longitude: -127.439308 },
+    "PR" => Coordinates { latitude: 18.220833, longitude: -66.590149 },
+    "PS" => Coordinates { latitude: 31.952162, longitude: 35.233154 },
+    "PT" => Coordinates { latitude: 39.399872, longitude: -8.224454 },
+    "PW" => Coordinates { latitude: 7.51498, longitude: 134.58252 },
+    "PY" => Coordinates { latitude: -23.442503, longitude: -58.443832 },
+    "QA" => Coordinates { latitude: 25.354826, longitude: 51.183884 },
+    "RE" => Coordinates { latitude: -21.115141, longitude: 55.536384 },
+    "RO" => Coordinates { latitude: 45.943161, longitude: 24.96676 },
+    "RS" => Coordinates { latitude: 44.016521, longitude: 21.005859 },
+    "RU" => Coordinates { latitude: 61.52401, longitude: 105.318756 },
+    "RW" => Coordinates { latitude: -1.940278, longitude: 29.873888 },
+    "SA" => Coordinates { latitude: 23.885942, longitude: 45.079162 },
+    "SB" => Coordinates { latitude: -9.64571, longitude: 160.156194 },
+    "SC" => Coordinates { latitude: -4.679574, longitude: 55.491977 },
+    "SD" => Coordinates { latitude: 15.6, longitude: 30.2 },
+    "SE" => Coordinates { latitude: 60.128161, longitude: 18.643501 },
+    "SG" => Coordinates { latitude: 1.352083, longitude: 103.819836 },
+    "SH" => Coordinates { latitude: -15.96, longitude: -5.71 },
+    "SI" => Coordinates { latitude: 46.151241, longitude: 14.995463 },
+    "SJ" => Coordinates { latitude: 77.553604, longitude: 23.670272 },
+    "SK" => Coordinates { latitude: 48.669026, longitude: 19.699024 },
+    "SL" => Coordinates { latitude: 8.460555, longitude: -11.779889 },
+    "SM" => Coordinates { latitude: 43.94236, longitude: 12.457777 },
+    "SN" => Coordinates { latitude: 14.497401, longitude: -14.452362 },
+    "SO" => Coordinates { latitude: 5.152149, longitude: 46.199616 },
+    "SR" => Coordinates { latitude: 3.919305, longitude: -56.027783 },
+    "SS" => Coordinates { latitude: 7.86, longitude: 29.69 },
+    "ST" => Coordinates { latitude: 0.18636, longitude: 6.613081 },
+    "SV" => Coordinates { latitude: 13.794185, longitude: -88.89653 },
+    "SX" => Coordinates { latitude: 18.04248, longitude: -63.05483 },
+    "SY" => Coordinates { latitude: 34.802075, longitude: 38.996815 },
+    "SZ" => Coordinates { latitude: -26.522503, longitude: 31.465866 },
+    "TC" => Coordinates { latitude: 21.694025, longitude: -71.797928 },
+    "TD" => Coordinates { latitude: 15.454166, longitude: 18.732207 },
+    "TF" => Coordinates { latitude: -49.280366, longitude: 69.348557 },
+    "TG" => Coordinates { latitude: 8.619543, longitude: 0.824782 },
+    "TH" => Coordinates { latitude: 15.870032, longitude: 100.992541 },
+    "TJ" => Coordinates { latitude: 38.861034, longitude: 71.276093 },
+    "TK" => Coordinates { latitude: -8.967363, longitude: -171.855881 },
+    "TL" => Coordinates { latitude: -8.874217, longitude: 125.727539 },
+    "TM" => Coordinates { latitude: 38.969719, longitude: 59.556278 },
+    "TN" => Coordinates { latitude: 33.886917, longitude: 9.537499 },
+    "TO" => Coordinates { latitude: -21.178986, longitude: -175.198242 },
+    "TR" => Coordinates { latitude: 38.963745, longitude: 35.243322 },
+    "TT" => Coordinates { latitude: 10.691803, longitude: -61.222503 },
+    "TV" => Coordinates { latitude: -7.109535, longitude: 177.64933 },
+    "TW" => Coordinates { latitude: 23.69781, longitude: 120.960515 },
+    "TZ" => Coordinates { latitude: -6.369028, longitude: 34.888822 },
+    "UA" => Coordinates { latitude: 48.379433, longitude: 31.16558 },
+    "UG" => Coordinates { latitude: 1.373333, longitude: 32.290275 },
+    "UM" => Coordinates { latitude: 19.28, longitude: 166.65 },
+    "US" => Coordinates { latitude: 37.09024, longitude: -95.712891 },
+    "UY" => Coordinates { latitude: -32.522779, longitude: -55.765835 },
+    "UZ" => Coordinates { latitude: 41.377491, longitude: 64.585262 },
+    "VA" => Coordinates { latitude: 41.902916, longitude: 12.453389 },
+    "VC" => Coordinates { latitude: 12.984305, longitude: -61.287228 },
+    "VE" => Coordinates { latitude: 6.42375, longitude: -66.58973 },
+    "VG" => Coordinates { latitude: 18.420695, longitude: -64.639968 },
+    "VI" => Coordinates { latitude: 18.335765, longitude: -64.896335 },
+    "VN" => Coordinates { latitude: 14.058324, longitude: 108.277199 },
+    "VU" => Coordinates { latitude: -15.376706, longitude: 166.959158 },
+    "WF" => Coordinates { latitude: -13.768752, longitude: -177.156097 },
+    "WS" => Coordinates { latitude: -13.759029, longitude: -172.104629 },
+    "YE" => Coordinates { latitude: 15.552727, longitude: 48.516388 },
+    "YT" => Coordinates { latitude: -12.8275, longitude: 45.166244 },
+    "ZA" => Coordinates { latitude: -30.559482, longitude: 22.937506 },
+    "ZM" => Coordinates { latitude: -13.133897, longitude: 27.849332 },
+    "ZW" => Coordinates { latitude: -19.015438, longitude: 29.154857 },
+};
+
+/// Bounding boxes by alpha-2 code.
+pub const BOUNDING_BOX_MAP: Map<&str, BoundingBox> = phf_map! {
+    "AD" => BoundingBox { south: 42.43, west: 1.41, north: 42.66, east: 1.79 },
+    "AE" => BoundingBox { south: 22.63, west: 51.50, north: 26.08, east: 56.38 },
+    "AF" => BoundingBox { south: 29.38, west: 60.50, north: 38.49, east: 74.89 },
+    "AG" => BoundingBox { south: 16.93, west: -62.35, north: 17.73, east: -61.66 },
+    "AI" => BoundingBox { south: 18.16, west: -63.43, north: 18.60, east: -62.92 },
+    "AL" => BoundingBox { south: 39.64, west: 19.26, north: 42.66, east: 21.06 },
+    "AM" => BoundingBox { south: 38.84, west: 43.45, north: 41.30, east: 46.63 },
+    "AO" => BoundingBox { south: -18.04, west: 11.64, north: -4.37, east: 24.08 },
+    "AQ" => BoundingBox { south: -90.00, west: -180.00, north: -60.00, east: 180.00 },
+    "AR" => BoundingBox { south: -55.06, west: -73.58, north: -21.78, east: -53.64 },
+    "AS" => BoundingBox { south: -14.60, west: -171.09, north: -11.05, east: -168.14 },
+    "AT" => BoundingBox { south: 46.37, west: 9.53, north: 49.02, east: 17.16 },
+    "AU" => BoundingBox { south: -43.66, west: 113.16, north: -10.05, east: 153.64 },
+    "AW" => BoundingBox { south: 12.41, west: -70.06, north: 12.63, east: -69.87 },
+    "AX" => BoundingBox { south: 59.74, west: 19.25, north: 60.49, east: 21.35 },
+    "AZ" => BoundingBox { south: 38.39, west: 44.77, north: 41.91, east: 50.63 },
+    "BA" => BoundingBox { south: 42.56, west: 15.72, north: 45.28, east: 19.62 },
+    "BB" => BoundingBox { south: 13.04, west: -59.65, north: 13.34, east: -59.42 },
+    "BD" => BoundingBox { south: 20.59, west: 88.01, north: 26.63, east: 92.68 },
+    "BE" => BoundingBox { south: 49.50, west: 2.54, north: 51.51, east: 6.41 },
+    "BF" => BoundingBox { south: 9.40, west: -5.52, north: 15.08, east: 2.41 },
+    "BG" => BoundingBox { south: 41.24, west: 22.36, north: 44.22, east: 28.61 },
+    "BH" => BoundingBox { south: 25.56, west: 50.38, north: 26.33, east: 50.82 },
+    "BI" => BoundingBox { south: -4.47, west: 29.00, north: -2.31, east: 30.85 },
+    "BJ" => BoundingBox { south: 6.14, west: 0.77, north: 12.42, east: 3.85 },
+    "BL" => BoundingBox { south: 17.87, west: -62.95, north: 17.97, east: -62.79 },
+    "BM" => BoundingBox { south: 32.25, west: -64.89, north: 32.39, east: -64.64 },
+    "BN" => BoundingBox { south: 4.00, west: 114.08, north: 5.05, east: 115.36 },
+    "BO" => BoundingBox { south: -22.90, west: -69.64, north: -9.68, east: -57.45 },
+    "BQ" => BoundingBox { south: 12.02, west: -68.42, north: 17.65, east: -62.94 },
+    "BR" => BoundingBox { south: -33.75, west: -73.99, north: 5.27, east: -34.79 },
+    "BS" => BoundingBox { south: 20.91, west: -80.48, north: 27.26, east: -72.71 },
+    "BT" => BoundingBox { south: 26.70, west: 88.75, north: 28.36, east: 92.13 },
+    "BV" => BoundingBox { south: -54.46, west: 3.28, north: -54.38, east: 3.48 },
+    "BW" => BoundingBox { south: -26.91, west: 19.99, north: -17.78, east: 29.38 },
+    "BY" => BoundingBox { south: 51.26, west: 23.18, north: 56.17, east: 32.78 },
+    "BZ" => BoundingBox { south: 15.89, west: -89.23, north: 18.50, east: -87.47 },
+    "CA" => BoundingBox { south: 41.68, west: -141.00, north: 83.11, east: -52.62 },
+    "CC" => BoundingBox { south: -12.21, west: 96.81, north: -11.82, east: 96.94 },
+    "CD" => BoundingBox { south: -13.46, west: 12.20, north: 5.39, east: 31.31 },
+    "CF" => BoundingBox { south: 2.22, west: 14.42, north: 11.00, east: 27.46 },
+    "CG" => BoundingBox { south: -5.03, west: 11.20, north: 3.71, east: 18.65 },
+    "CH" => BoundingBox { south: 45.82, west: 5.96, north: 47.81, east: 10.49 },
+    "CI" => BoundingBox { south: 4.36, west: -8.60, north: 10.74, east: -2.49 },
+    "CK" => BoundingBox { south: -21.96, west: -165.85, north: -8.95, east: -157.31 },
+    "CL" => BoundingBox { south: -55.98, west: -75.64, north: -17.50, east: -66.42 },
+    "CM" => BoundingBox { south: 1.65, west: 8.49, north: 13.08, east: 16.19 },
+    "CN" => BoundingBox { south: 18.16, west: 73.50, north: 53.56, east: 134.77 },
+    "CO" => BoundingBox { south: -4.23, west: -79.00, north: 12.46, east: -66.87 },
+    "CR" => BoundingBox { south: 8.03, west: -85.95, north: 11.22, east: -82.55 },
+    "CU" => BoundingBox { south: 19.83, west: -84.95, north: 23.27, east: -74.13 },
+    "CV" => BoundingBox { south: 14.80, west: -25.36, north: 17.21, east: -22.66 },
+    "CW" => BoundingBox { south: 12.03, west: -69.16, north: 12.39, east: -68.74 },
+    "CX" => BoundingBox { south: -10.57, west: 105.53, north: -10.41, east: 105.72 },
+    "CY" => BoundingBox { south: 34.56, west: 32.27, north: 35.71, east: 34.60 },
+    "CZ" => BoundingBox { south: 48.55, west: 12.09, north: 51.06, east: 18.86 },
+    "DE" => BoundingBox { south: 47.27, west: 5.87, north: 55.06, east: 15.04 },
+    "DJ" => BoundingBox { south: 10.91, west: 41.77, north: 12.71, east: 43.42 },
+    "DK" => BoundingBox { south: 54.56, west: 8.08, north: 57.75, east: 15.20 },
+    "DM" => BoundingBox { south: 15.20, west: -61.48, north: 15.64, east: -61.24 },
+    "DO" => BoundingBox { south: 17.47, west: -72.00, north: 19.93, east: -68.32 },
+    "DZ" => BoundingBox { south: 18.96, west: -8.67, north: 37.09, east: 11.98 },
+    "EC" => BoundingBox { south: -5.01, west: -81.08, north: 1.68, east: -75.19 },
+    "EE" => BoundingBox { south: 57.52, west: 21.76, north: 59.68, east: 28.21 },
+    "EG" => BoundingBox { south: 22.00, west: 24.70, north: 31.67, east: 36.90 },
+    "EH" => BoundingBox { south: 20.77, west: -17.10, north: 27.67, east: -8.67 },
+    "ER" => BoundingBox { south: 12.36, west: 36.44, north: 18.00, east: 43.13 },
+    "ES" => BoundingBox { south: 35.27, west: -9.30, north: 43.79, east: 4.33 },
+    "ET" => BoundingBox { south: 3.40, west: 32.99, north: 14.89, east: 47.99 },
+    "FI" => BoundingBox { south: 59.81, west: 20.55, north: 70.09, east: 31.59 },
+    "FJ" => BoundingBox { south: -20.68, west: 177.13, north: -15.70, east: -178.23 },
+    "FK" => BoundingBox { south: -52.40, west: -61.35, north: -51.04, east: -57.71 },
+    "FM" => BoundingBox { south: 1.03, west: 137.33, north: 10.09, east: 163.03 },
+    "FO" => BoundingBox { south: 61.39, west: -7.68, north: 62.40, east: -6.26 },
+    "FR" => BoundingBox { south: 41.33, west: -5.14, north: 51.09, east: 9.56 },
+    "GA" => BoundingBox { south: -3.98, west: 8.70, north: 2.32, east: 14.50 },
+    "GB" => BoundingBox { south: 49.86, west: -8.65, north: 60.86, east: 1.76 },
+    "GD" => BoundingBox { south: 11.98, west: -61.80, north: 12.54, east: -61.38 },
+    "GE" => BoundingBox { south: 41.05, west: 40.01, north: 43.59, east: 46.74 },
+    "GF" => BoundingBox { south: 2.11, west: -54.60, north: 5.78, east: -51.61 },
+    "GG" => BoundingBox { south: 49.40, west: -2.68, north: 49.73, east: -2.16 },
+    "GH" => BoundingBox { south: 4.74, west: -3.26, north: 11.17, east: 1.19 },
+    "GI" => BoundingBox { south: 36.11, west: -5.37, north: 36.16, east: -5.34 },
+    "GL" => BoundingBox { south: 59.78, west: -73.04, north: 83.63, east: -11.31 },
+    "GM" => BoundingBox { south: 13.06, west: -16.82, north: 13.83, east: -13.80 },
+    "GN" => BoundingBox { south: 7.19, west: -15.08, north: 12.68, east: -7.64 },
+    "GP" => BoundingBox { south: 15.83, west: -61.81, north: 16.52, east: -61.00 },
+    "GQ" => BoundingBox { south: -1.47, west: 5.61, north: 3.79, east: 11.34 },
+    "GR" => BoundingBox { south: 34.80, west: 19.37, north: 41.75, east: 29.65 },
+    "GS" => BoundingBox { south: -59.48, west: -38.03, north: -53.97, east: -26.23 },
+    "GT" => BoundingBox { south: 13.74, west: -92.24, north: 17.82, east: -88.22 },
+    "GU" => BoundingBox { south: 13.24, west: 144.62, north: 13.65, east: 145.01 },
+    "GW" => BoundingBox { south: 10.92, west: -16.71, north: 12.69, east: -13.64 },
+    "GY" => BoundingBox { south: 1.18, west: -61.40, north: 8.56, east: -56.48 },
+    "HK" => BoundingBox { south: 22.15, west: 113.84, north: 22.56, east: 114.44 },
+    "HM" => BoundingBox { south: -53.19, west: 72.58, north: -52.91, east: 73.86 },
+    "HN" => BoundingBox { south: 12.98, west: -89.35, north: 16.52, east: -83.13 },
+    "HR" => BoundingBox { south: 42.39, west: 13.49, north: 46.55, east: 19.45 },
+    "HT" => BoundingBox { south: 18.02, west: -74.48, north: 20.09, east: -71.62 },
+    "HU" => BoundingBox { south: 45.74, west: 16.11, north: 48.59, east: 22.90 },
+    "ID" => BoundingBox { south: -11.01, west: 95.01, north: 6.08, east: 141.02 },
+    "IE" => BoundingBox { south: 51.42, west: -10.66, north: 55.39, east: -5.99 },
+    "IL" => BoundingBox { south: 29.49, west: 34.27, north: 33.28, east: 35.68 },
+    "IM" => BoundingBox { south: 54.04, west: -4.83, north: 54.42, east: -4.31 },
+    "IN" => BoundingBox { south: 6.75, west: 68.11, north: 35.50, east: 97.40 },
+    "IO" => BoundingBox { south: -7.44, west: 71.26, north: -5.23, east: 72.49 },
+    "IQ" => BoundingBox { south: 29.06, west: 38.79, north: 37.38, east: 48.57 },
+    "IR" => BoundingBox { south: 25.06, west: 44.03, north: 39.78, east: 63.32 },
+    "IS" => BoundingBox { south: 63.29, west: -24.55, north: 66.57, east: -13.50 },
+    "IT" => BoundingBox { south: 35.49, west: 6.63, north: 47.09, east: 18.52 },
+    "JE" => BoundingBox { south: 49.16, west: -2.26, north: 49.27, east: -2.01 },
+    "JM" => BoundingBox { south: 17.70, west: -78.37, north: 18.53, east: -76.18 },
+    "JO" => BoundingBox { south: 29.19, west: 34.96, north: 33.37, east: 39.30 },
+    "JP" => BoundingBox { south: 24.04, west: 122.93, north: 45.52, east: 145.82 },
+    "KE" => BoundingBox { south: -4.68, west: 33.91, north: 5.03, east: 41.91 },
+    "KG" => BoundingBox { south: 39.17, west: 69.25, north: 43.24, east: 80.28 },
+    "KH" => BoundingBox { south: 9.91, west: 102.34, north: 14.69, east: 107.63 },
+    "KI" => BoundingBox { south: -11.45, west: 169.53, north: 4.72, east: -150.22 },
+    "KM" => BoundingBox { south: -12.42, west: 43.22, north: -11.36, east: 44.54 },
+    "KN" => BoundingBox { south: 17.09, west: -62.87, north: 17.42, east: -62.54 },
+    "KP" => BoundingBox { south: 37.67, west: 124.18, north: 43.01, east: 130.68 },
+    "KR" => BoundingBox { south: 33.11, west: 124.61, north: 38.61, east: 131.87 },
+    "KW" => BoundingBox { south: 28.52, west: 46.55, north: 30.10, east: 48.43 },
+    "KY" => BoundingBox { south: 19.26, west: -81.43, north: 19.76, east: -79.72 },
+    "KZ" => BoundingBox { south: 40.57, west: 46.49, north: 55.44, east: 87.31 },
+    "LA" => BoundingBox { south: 13.91, west: 100.08, north: 22.50, east: 107.64 },
+    "LB" => BoundingBox { south: 33.05, west: 35.10, north: 34.69, east: 36.62 },
+    "LC" => BoundingBox { south: 13.71, west: -61.08, north: 14.11, east: -60.87 },
+    "LI" => BoundingBox { south: 47.05, west: 9.47, north: 47.27, east: 9.64 },
+    "LK" => BoundingBox { south: 5.92, west: 79.52, north: 9.83, east: 81.88 },
+    "LR" => BoundingBox { south: 4.36, west: -11.49, north: 8.55, east: -7.37 },
+    "LS" => BoundingBox { south: -30.67, west: 27.01, north: -28.57, east: 29.46 },
+    "LT" => BoundingBox { south: 53.90, west: 20.93, north: 56.45, east: 26.84 },
+    "LU" => BoundingBox { south: 49.45, west: 5.73, north: 50.18, east: 6.53 },
+    "LV" => BoundingBox { south: 55.67, west: 20.97, north: 58.08, east: 28.24 },
+    "LY" => BoundingBox { south: 19.50, west: 9.39, north: 33.17, east: 25.15 },
+    "MA" => BoundingBox { south: 27.67, west: -13.17, north: 35.92, east: -0.99 },
+    "MC" => BoundingBox { south: 43.72, west: 7.41, north: 43.75, east: 7.44 },
+    "MD" => BoundingBox { south: 45.47, west: 26.62, north: 48.49, east: 30.16 },
+    "ME" => BoundingBox { south: 41.85, west: 18.43, north: 43.56, east: 20.36 },
+    "MF" => BoundingBox { south: 18.05, west: -63.15, north: 18.13, east: -62.97 },
+    "MG" => BoundingBox { south: -25.61, west: 43.22, north: -11.95, east: 50.48 },
+    "MH" => BoundingBox { south: 4.57, west: 160.80, north: 14.62, east: 172.17 },
+    "MK" => BoundingBox { south: 40.85, west: 20.45, north: 42.37, east: 23.03 },
+    "ML" => BoundingBox { south: 10.16, west: -12.24, north: 25.00, east: 4.27 },
+    "MM" => BoundingBox { south: 9.78, west: 92.17, north: 28.55, east: 101.17 },
+    "MN" => BoundingBox { south: 41.58, west: 87.75, north: 52.15, east: 119.93 },
+    "MO" => BoundingBox { south: 22.11, west: 113.53, north: 22.22, east: 113.60 },
+    "MP" => BoundingBox { south: 14.11, west: 144.90, north: 20.55, east: 145.87 },
+    "MQ" => BoundingBox { south: 14.39, west: -61.23, north: 14.88, east: -60.81 },
+    "MR" => BoundingBox { south: 14.72, west: -17.07, north: 27.30, east: -4.83 },
+    "MS" => BoundingBox { south: 16.67, west: -62.24, north: 16.82, east: -62.14 },
+    "MT" => BoundingBox { south: 35.79, west: 14.18, north: 36.08, east: 14.58 },
+    "MU" => BoundingBox { south: -20.53, west: 57.31, north: -19.98, east: 57.81 },
+    "MV" => BoundingBox { south: -0.70, west: 72.64, north: 7.11, east: 73.76 },
+    "MW" => BoundingBox { south: -17.13, west: 32.67, north: -9.37, east: 35.92 },
+    "MX" => BoundingBox { south: 14.53, west: -117.13, north: 32.72, east: -86.71 },
+    "MY" => BoundingBox { south: 0.85, west: 99.64, north: 7.36, east: 119.27 },
+    "MZ" => BoundingBox { south: -26.87, west: 30.22, north: -10.47, east: 40.84 },
+    "NA" => BoundingBox { south: -28.97, west: 11.72, north: -16.96, east: 25.26 },
+    "NC" => BoundingBox { south: -22.70, west: 163.57, north: -19.55, east: 168.14 },
+    "NE" => BoundingBox { south: 11.69, west: 0.17, north: 23.52, east: 15.99 },
+    "NF" => BoundingBox { south: -29.14, west: 167.91, north: -28.99, east: 168.00 },
+    "NG" => BoundingBox { south: 4.27, west: 2.67, north: 13.89, east: 14.68 },
+    "NI" => BoundingBox { south: 10.71, west: -87.69, north: 15.03, east: -82.73 },
+    "NL" => BoundingBox { south: 50.75, west: 3.36, north: 53.56, east: 7.23 },
+    "NO" => BoundingBox { south: 57.98, west: 4.65, north: 71.19, east: 31.08 },
+    "NP" => BoundingBox { south: 26.35, west: 80.06, north: 30.45, east: 88.20 },
+    "NR" => BoundingBox { south: -0.55, west: 166.90, north: -0.50, east: 166.96 },
+    "NU" => BoundingBox { south: -19.15, west: -169.95, north: -18.95, east: -169.77 },
+    "NZ" => BoundingBox { south: -47.29, west: 166.43, north: -34.39, east: 178.57 },
+    "OM" => BoundingBox { south: 16.65, west: 52.00, north: 26.40, east: 59.84 },
+    "PA" => BoundingBox { south: 7.20, west: -83.05, north: 9.65, east: -77.16 },
+    "PE" => BoundingBox { south: -18.35, west: -81.33, north: -0.04, east: -68.65 },
+    "PF" => BoundingBox { south: -27.65, west: -154.71, north: -7.90, east: -134.93 },
+    "PG" => BoundingBox { south: -11.66, west: 140.84, north: -0.87, east: 159.49 },
+    "PH" => BoundingBox { south: 4.59, west: 116.93, north: 21.12, east: 126.60 },
+    "PK" => BoundingBox { south: 23.69, west: 60.87, north: 37.08, east: 77.84 },
+    "PL" => BoundingBox { south: 49.00, west: 14.12, north: 54.84, east: 24.15 },
+    "PM" => BoundingBox { south: 46.75, west: -56.42, north: 47.14, east: -56.12 },
+    "PN" => BoundingBox { south: -25.08, west: -130.74, north: -23.92, east: -124.77 },
+    "PR" => BoundingBox { south: 17.88, west: -67.95, north: 18.52, east: -65.22 },
+    "PS" => BoundingBox { south: 31.22, west: 34.22, north: 32.55, east: 35.57 },
+    "PT" => BoundingBox { south: 36.96, west: -9.50, north: 42.15, east: -6.19 },
+    "PW" => BoundingBox { south: 2.80, west: 131.12, north: 8.10, east: 134.73 },
+    "PY" => BoundingBox { south: -27.61, west: -62.65, north: -19.29, east: -54.26 },
+    "QA" => BoundingBox { south: 24.47, west: 50.75, north: 26.18, east: 51.64 },
+    "RE" => BoundingBox { south: -21.39, west: 55.22, north: -20.87, east: 55.84 },
+    "RO" => BoundingBox { south: 43.62, west: 20.26, north: 48.27, east: 29.72 },
+    "RS" => BoundingBox { south: 41.86, west: 18.82, north: 46.19, east: 23.01 },
+    "RU" => BoundingBox { south: 41.19, west: 19.64, north: 81.86, east: -169.05 },
+    "RW" => BoundingBox { south: -2.84, west: 28.86, north: -1.05, east: 30.90 },
+    "SA" => BoundingBox { south: 16.38, west: 34.50, north: 32.16, east: 55.67 },
+    "SB" => BoundingBox { south: -11.85, west: 155.51, north: -6.59, east: 167.29 },
+    "SC" => BoundingBox { south: -10.23, west: 46.20, north: -3.71, east: 56.29 },
+    "SD" => BoundingBox { south: 8.68, west: 21.81, north: 22.23, east: 38.61 },
+    "SE" => BoundingBox { south: 55.34, west: 11.03, north: 69.06, east: 24.17 },
+    "SG" => BoundingBox { south: 1.16, west: 103.60, north: 1.47, east: 104.09 },
+    "SH" => BoundingBox { south: -16.02, west: -5.79, north: -15.90, east: -5.64 },
+    "SI" => BoundingBox { south: 45.42, west: 13.38, north: 46.88, east: 16.61 },
+    "SJ" => BoundingBox { south: 74.34, west: 10.49, north: 80.83, east: 33.64 },
+    "SK" => BoundingBox { south: 47.73, west: 16.83, north: 49.61, east: 22.57 },
+    "SL" => BoundingBox { south: 6.92, west: -13.31, north: 10.00, east: -10.27 },
+    "SM" => BoundingBox { south: 43.89, west: 12.40, north: 43.99, east: 12.52 },
+    "SN" => BoundingBox { south: 12.31, west: -17.54, north: 16.69, east: -11.35 },
+    "SO" => BoundingBox { south: -1.66, west: 40.98, north: 11.99, east: 51.41 },
+    "SR" => BoundingBox { south: 1.83, west: -58.07, north: 6.01, east: -53.98 },
+    "SS" => BoundingBox { south: 3.49, west: 23.44, north: 12.24, east: 35.95 },
+    "ST" => BoundingBox { south: -0.01, west: 6.46, north: 1.70, east: 7.47 },
+    "SV" => BoundingBox { south: 13.15, west: -90.13, north: 14.45, east: -87.69 },
+    "SX" => BoundingBox { south: 18.01, west: -63.14, north: 18.06, east: -63.01 },
+    "SY" => BoundingBox { south: 32.31, west: 35.71, north: 37.32, east: 42.38 },
+    "SZ" => BoundingBox { south: -27.32, west: 30.79, north: -25.72, east: 32.14 },
+    "TC" => BoundingBox { south: 21.18, west: -72.48, north: 21.96, east: -71.07 },
+    "TD" => BoundingBox { south: 7.44, west: 13.47, north: 23.45, east: 24.00 },
+    "TF" => BoundingBox { south: -49.73, west: 68.72, north: -48.46, east: 70.57 },
+    "TG" => BoundingBox { south: 6.10, west: -0.15, north: 11.14, east: 1.81 },
+    "TH" => BoundingBox { south: 5.61, west: 97.34, north: 20.46, east: 105.64 },
+    "TJ" => BoundingBox { south: 36.67, west: 67.34, north: 41.04, east: 75.15 },
+    "TK" => BoundingBox { south: -9.44, west: -172.52, north: -8.53, east: -171.18 },
+    "TL" => BoundingBox { south: -9.50, west: 124.04, north: -8.13, east: 127.34 },
+    "TM" => BoundingBox { south: 35.13, west: 52.44, north: 42.80, east: 66.71 },
+    "TN" => BoundingBox { south: 30.24, west: 7.52, north: 37.54, east: 11.60 },
+    "TO" => BoundingBox { south: -22.35, west: -176.22, north: -15.56, east: -173.70 },
+    "TR" => BoundingBox { south: 35.82, west: 25.66, north: 42.11, east: 44.82 },
+    "TT" => BoundingBox { south: 10.04, west: -61.93, north: 11.36, east: -60.49 },
+    "TV" => BoundingBox { south: -10.80, west: 176.06, north: -5.64, east: 179.91 },
+    "TW" => BoundingBox { south: 21.90, west: 119.31, north: 25.30, east: 122.01 },
+    "TZ" => BoundingBox { south: -11.75, west: 29.33, north: -0.99, east: 40.44 },
+    "UA" => BoundingBox { south: 44.39, west: 22.14, north: 52.38, east: 40.23 },
+    "UG" => BoundingBox { south: -1.48, west: 29.57, north: 4.23, east: 35.04 },
+    "UM" => BoundingBox { south: -0.39, west: 166.61, north: 28.42, east: -160.02 },
+    "US" => BoundingBox { south: 24.52, west: 172.44, north: 71.39, east: -66.95 },
+    "UY" => BoundingBox { south: -34.97, west: -58.44, north: -30.09, east: -53.09 },
+    "UZ" => BoundingBox { south: 37.18, west: 55.99, north: 45.59, east: 73.13 },
+    "VA" => BoundingBox { south: 41.90, west: 12.45, north: 41.91, east: 12.46 },
+    "VC" => BoundingBox { south: 12.58, west: -61.46, north: 13.38, east: -61.11 },
+    "VE" => BoundingBox { south: 0.65, west: -73.35, north: 12.20, east: -59.80 },
+    "VG" => BoundingBox { south: 18.31, west: -64.85, north: 18.75, east: -64.27 },
+    "VI" => BoundingBox { south: 17.68, west: -65.09, north: 18.42, east: -64.56 },
+    "VN" => BoundingBox { south: 8.41, west: 102.14, north: 23.39, east: 109.47 },
+    "VU" => BoundingBox { south: -20.25, west: 166.52, north: -13.07, east: 170.24 },
+    "WF" => BoundingBox { south: -14.36, west: -178.21, north: -13.18, east: -176.12 },
+    "WS" => BoundingBox { south: -14.08, west: -172.80, north: -13.43, east: -171.40 },
+    "YE" => BoundingBox { south: 12.11, west: 42.55, north: 19.00, east: 53.11 },
+    "YT" => BoundingBox { south: -13.00, west: 45.01, north: -12.64, east: 45.30 },
+    "ZA" => BoundingBox { south: -34.84, west: 16.46, north: -22.13, east: 32.89 },
+    "ZM" => BoundingBox { south: -18.08, west: 21.99, north: -8.22, east: 33.71 },
+    "ZW" => BoundingBox { south: -22.42, west: 25.24, north: -15.61, east: 33.06 },
+};
@@ -0,0 +1,370 @@
+//! Supranational groupings of countries, with the dates each member joined
+//! and left, so membership can be asked for any day.
+//!
+//! # Sample code
+//! ```
+//! use rust_iso3166::groups::{Date, Group};
+//! let gb = rust_iso3166::from_alpha2("GB").unwrap();
+//! assert!(gb.is_member_of(Group::EU, Date::new(2020, 1, 31)));
+//! assert!(!gb.is_member_of(Group::EU, Date::new(2020, 2, 1)));
+//!
+//! let euro_2022 = Group::Eurozone.members_at(Date::new(2022, 12, 31));
+//! let euro_2023 = Group::Eurozone.members_at(Date::new(2023, 1, 1));
+//! assert_eq!(euro_2022.len() + 1, euro_2023.len());
+//! ```
+
+use crate::{
+    CountryCode, AR, AT, AU, BE, BG, BN, BR, CA, CH, CL, CN, CO, CR, CY, CZ, DE, DK, EE, ES, FI,
+    FR, GB, GR, HR, HU, ID, IE, IL, IN, IS, IT, JP, KH, KR, LA, LI, LT, LU, LV, MM, MT, MX, MY, NL,
+    NO, NZ, PH, PL, PT, RO, RU, SA, SE, SG, SI, SK, TH, TL, TR, US, VN, ZA,
+};
+use std::fmt;
+
+/// A calendar day, just enough to ask about membership dates.
+#[derive(Debug, Ord, PartialOrd, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Date {
+    ///Year
+    pub year: u16,
+    ///Month, 1 to 12
+    pub month: u8,
+    ///Day of the month, 1 to 31
+    pub day: u8,
+}
+
+impl Date {
+    ///Return the Date for the given year, month and day
+    pub const fn new(year: u16, month: u8, day: u8) -> Date {
+        Date { year, month, day }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A group of countries.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Group {
+    ///European Union. Dates before 1 November 1993 are membership of the
+    ///European Communities.
+    EU,
+    ///European Economic Area: the EU members plus Iceland, Liechtenstein
+    ///and Norway
+    EEA,
+    ///Schengen Area, from the day each country lifted internal border
+    ///controls
+    Schengen,
+    ///Countries using the euro as members of the euro area. Countries using
+    ///it without membership, such as Montenegro, are not included.
+    Eurozone,
+    ///Organisation for Economic Co-operation and Development
+    OECD,
+    ///Group of Twenty. The European Union and the African Union are members
+    ///too but are not countries.
+    G20,
+    ///Association of Southeast Asian Nations
+    ASEAN,
+}
+
+/// One country's time in a group.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Membership {
+    ///The member
+    pub country: CountryCode,
+    ///First day of membership
+    pub joined: Date,
+    ///First day the country was no longer a member, if it left
+    pub left: Option<Date>,
+}
+
+impl Membership {
+    ///Return true if the country was a member on `date`
+    pub fn is_active_at(&self, date: Date) -> bool {
+        self.joined <= date && self.left.is_none_or(|left| date < left)
+    }
+}
+
+impl Group {
+    ///All groups
+    pub const ALL: &'static [Group] = &[
+        Group::EU,
+        Group::EEA,
+        Group::Schengen,
+        Group::Eurozone,
+        Group::OECD,
+        Group::G20,
+        Group::ASEAN,
+    ];
+
+    ///Return the English name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Group::EU => "European Union",
+            Group::EEA => "European Economic Area",
+            Group::Schengen => "Schengen Area",
+            Group::Eurozone => "Euro area",
+            Group::OECD => "Organisation for Economic Co-operation and Development",
+            Group::G20 => "Group of Twenty",
+            Group::ASEAN => "Association of Southeast Asian Nations",
+        }
+    }
+
+    ///Return every membership, past and present
+    pub fn memberships(&self) -> &'static [Membership] {
+        match self {
+            Group::EU => EU,
+            Group::EEA => EEA,
+            Group::Schengen => SCHENGEN,
+            Group::Eurozone => EUROZONE,
+            Group::OECD => OECD,
+            Group::G20 => G20,
+            Group::ASEAN => ASEAN,
+        }
+    }
+
+    ///Return the members on `date`, in the order they joined
+    pub fn members_at(&self, date: Date) -> Vec<CountryCode> {
+        self.memberships()
+            .iter()
+            .filter(|m| m.is_active_at(date))
+            .map(|m| m.country)
+            .collect()
+    }
+}
+
+impl CountryCode {
+    ///Return true if this country was a member of `group` on `date`
+    pub fn is_member_of(&self, group: Group, date: Date) -> bool {
+        group
+            .memberships()
+            .iter()
+            .any(|m| m.country == *self && m.is_active_at(date))
+    }
+
+    ///Return the groups this country was a member of on `date`
+    pub fn groups_at(&self, date: Date) -> Vec<Group> {
+        Group::ALL
+            .iter()
+            .filter(|group| self.is_member_of(**group, date))
+            .copied()
+            .collect()
+    }
+}
+
+const fn member(country: CountryCode, year: u16, month: u8, day: u8) -> Membership {
+    Membership {
+        country,
+        joined: Date::new(year, month, day),
+        left: None,
+    }
+}
+
+const fn former(country: CountryCode, joined: Date, left: Date) -> Membership {
+    Membership {
+        country,
+        joined,
+        left: Some(left),
+    }
+}
+
+const EU: &[Membership] = &[
+    member(BE, 1958, 1, 1),
+    member(DE, 1958, 1, 1),
+    member(FR, 1958, 1, 1),
+    member(IT, 1958, 1, 1),
+    member(LU, 1958, 1, 1),
+    member(NL, 1958, 1, 1),
+    member(DK, 1973, 1, 1),
+    member(IE, 1973, 1, 1),
+    former(GB, Date::new(1973, 1, 1), Date::new(2020, 2, 1)),
+    member(GR, 1981, 1, 1),
+    member(PT, 1986, 1, 1),
+    member(ES, 1986, 1, 1),
+    member(AT, 1995, 1, 1),
+    member(FI, 1995, 1, 1),
+    member(SE, 1995, 1, 1),
+    member(CY, 2004, 5, 1),
+    member(CZ, 2004, 5, 1),
+    member(EE, 2004, 5, 1),
+    member(HU, 2004, 5, 1),
+    member(LV, 2004, 5, 1),
+    member(LT, 2004, 5, 1),
+    member(MT, 2004, 5, 1),
+    member(PL, 2004, 5, 1),
+    member(SK, 2004, 5, 1),
+    member(SI, 2004, 5, 1),
+    member(BG, 2007, 1, 1),
+    member(RO, 2007, 1, 1),
+    member(HR, 2013, 7, 1),
+];
+
+const EEA: &[Membership] = &[
+    member(AT, 1994, 1, 1),
+    member(BE, 1994, 1, 1),
+    member(DK, 1994, 1, 1),
+    member(FI, 1994, 1, 1),
+    member(FR, 1994, 1, 1),
+    member(DE, 1994, 1, 1),
+    member(GR, 1994, 1, 1),
+    member(IS, 1994, 1, 1),
+    member(IE, 1994, 1, 1),
+    member(IT, 1994, 1, 1),
+    member(LU, 1994, 1, 1),
+    member(NL, 1994, 1, 1),
+    member(NO, 1994, 1, 1),
+    member(PT, 1994, 1, 1),
+    member(ES, 1994, 1, 1),
+    member(SE, 1994, 1, 1),
+    former(GB, Date::new(1994, 1, 1), Date::new(2021, 1, 1)),
+    member(LI, 1995, 5, 1),
+    member(CY, 2004, 5, 1),
+    member(CZ, 2004, 5, 1),
+    member(EE, 2004, 5, 1),
+    member(HU, 2004, 5, 1),
+    member(LV, 2004, 5, 1),
+    member(LT, 2004, 5, 1),
+    member(MT, 2004, 5, 1),
+    member(PL, 2004, 5, 1),
+    member(SK, 2004, 5, 1),
+    member(SI, 2004, 5, 1),
+    member(BG, 2007, 8, 1),
+    member(RO, 2007, 8, 1),
+    member(HR, 2014, 4, 12),
+];
+
+const SCHENGEN: &[Membership] = &[
+    member(BE, 1995, 3, 26),
+    member(FR, 1995, 3, 26),
+    member(DE, 1995, 3, 26),
+    member(LU, 1995, 3, 26),
+    member(NL, 1995, 3, 26),
+    member(PT, 1995, 3, 26),
+    member(ES, 1995, 3, 26),
+    member(IT, 1997, 10, 26),
+    member(AT, 1997, 12, 1),
+    member(GR, 2000, 1, 1),
+    member(DK, 2001, 3, 25),
+    member(FI, 2001, 3, 25),
+    member(IS, 2001, 3, 25),
+    member(NO, 2001, 3, 25),
+    member(SE, 2001, 3, 25),
+    member(CZ, 2007, 12, 21),
+    member(EE, 2007, 12, 21),
+    member(HU, 2007, 12, 21),
+    member(LV, 2007, 12, 21),
+    member(LT, 2007, 12, 21),
+    member(MT, 2007, 12, 21),
+    member(PL, 2007, 12, 21),
+    member(SK, 2007, 12, 21),
+    member(SI, 2007, 12, 21),
+    member(CH, 2008, 12, 12),
+    member(LI, 2011, 12, 19),
+    member(HR, 2023, 1, 1),
+    member(BG, 2024, 3, 31),
+    member(RO, 2024, 3, 31),
+];
+
+const EUROZONE: &[Membership] = &[
+    member(AT, 1999, 1, 1),
+    member(BE, 1999, 1, 1),
+    member(FI, 1999, 1, 1),
+    member(FR, 1999, 1, 1),
+    member(DE, 1999, 1, 1),
+    member(IE, 1999, 1, 1),
+    member(IT, 1999, 1, 1),
+    member(LU, 1999, 1, 1),
+    member(NL, 1999, 1, 1),
+    member(PT, 1999, 1, 1),
+    member(ES, 1999, 1, 1),
+    member(GR, 2001, 1, 1),
+    member(SI, 2007, 1, 1),
+    member(CY, 2008, 1, 1),
+    member(MT, 2008, 1, 1),
+    member(SK, 2009, 1, 1),
+    member(EE, 2011, 1, 1),
+    member(LV, 2014, 1, 1),
+    member(LT, 2015, 1, 1),
+    member(HR, 2023, 1, 1),
+    member(BG, 2026, 1, 1),
+];
+
+const OECD: &[Membership] = &[
+    member(CA, 1961, 4, 10),
+    member(US, 1961, 4, 12),
+    member(GB, 1961, 5, 2),
+    member(DK, 1961, 5, 30),
+    member(IS, 1961, 6, 5),
+    member(NO, 1961, 7, 4),
+    member(TR, 1961, 8, 2),
+    member(ES, 1961, 8, 3),
+    member(PT, 1961, 8, 4),
+    member(FR, 1961, 8, 7),
+    member(IE, 1961, 8, 17),
+    member(BE, 1961, 9, 13),
+    member(DE, 1961, 9, 27),
+    member(GR, 1961, 9, 27),
+    member(SE, 1961, 9, 28),
+    member(CH, 1961, 9, 28),
+    member(AT, 1961, 9, 29),
+    member(NL, 1961, 11, 13),
+    member(LU, 1961, 12, 7),
+    member(IT, 1962, 3, 29),
+    member(JP, 1964, 4, 28),
+    member(FI, 1969, 1, 28),
+    member(AU, 1971, 6, 7),
+    member(NZ, 1973, 5, 29),
+    member(MX, 1994, 5, 18),
+    member(CZ, 1995, 12, 21),
+    member(HU, 1996, 5, 7),
+    member(PL, 1996, 11, 22),
+    member(KR, 1996, 12, 12),
+    member(SK, 2000, 12, 14),
+    member(CL, 2010, 5, 7),
+    member(SI, 2010, 7, 21),
+    member(IL, 2010, 9, 7),
+    member(EE, 2010, 12, 9),
+    member(LV, 2016, 7, 1),
+    member(LT, 2018, 7, 5),
+    member(CO, 2020, 4, 28),
+    member(CR, 2021, 5, 25),
+];
+
+const G20: &[Membership] = &[
+    member(AR, 1999, 12, 15),
+    member(AU, 1999, 12, 15),
+    member(BR, 1999, 12, 15),
+    member(CA, 1999, 12, 15),
+    member(CN, 1999, 12, 15),
+    member(FR, 1999, 12, 15),
+    member(DE, 1999, 12, 15),
+    member(IN, 1999, 12, 15),
+    member(ID, 1999, 12, 15),
+    member(IT, 1999, 12, 15),
+    member(JP, 1999, 12, 15),
+    member(KR, 1999, 12, 15),
+    member(MX, 1999, 12, 15),
+    member(RU, 1999, 12, 15),
+    member(SA, 1999, 12, 15),
+    member(ZA, 1999, 12, 15),
+    member(TR, 1999, 12, 15),
+    member(GB, 1999, 12, 15),
+    member(US, 1999, 12, 15),
+];
+
+const ASEAN: &[Membership] = &[
+    member(ID, 1967, 8, 8),
+    member(MY, 1967, 8, 8),
+    member(PH, 1967, 8, 8),
+    member(SG, 1967, 8, 8),
+    member(TH, 1967, 8, 8),
+    member(BN, 1984, 1, 7),
+    member(VN, 1995, 7, 28),
+    member(LA, 1997, 7, 23),
+    member(MM, 1997, 7, 23),
+    member(KH, 1999, 4, 30),
+    member(TL, 2025, 10, 26),
+];
@@ -90,6 +90,8 @@ pub const BQAQ: CountryCode3 = CountryCode3 {
         alpha2: "BQ",
         alpha3: "ATB",
         numeric: 0,
+        official_name: None,
+        name_fr: "Territoire britannique de l'Antarctique",
     },
     validity: &[1974,1979],
     desc: "Merged into Antarctica",
@@ -100,6 +102,8 @@ pub const BQAQ: CountryCode3 = CountryCode3 {
         alpha2: "AQ",
         alpha3: "ATA",
         numeric: 10,
+        official_name: None,
+        name_fr: "Antarctique",
 },
 
     ],
@@ -114,6 +118,8 @@ pub const BUMM: CountryCode3 = CountryCode3 {
         alpha2: "BU",
         alpha3: "BUR",
         numeric: 104,
+        official_name: Some("Socialist Republic of the Union of Burma"),
+        name_fr: "Union de Birmanie",
     },
     validity: &[1974,1989],
     desc: "Name changed to Myanmar",
@@ -124,6 +130,8 @@ pub const BUMM: CountryCode3 = CountryCode3 {
         alpha2: "MM",
         alpha3: "MMR",
         numeric: 104,
+        official_name: Some("Republic of the Union of Myanmar"),
+        name_fr: "Myanmar",
 },
 
     ],
@@ -138,6 +146,8 @@ pub const BYAA: CountryCode3 = CountryCode3 {
         alpha2: "BY",
         alpha3: "BYS",
         numeric: 112,
+        official_name: Some("Byelorussian Soviet Socialist Republic"),
+        name_fr: "République socialiste soviétique de Biélorussie",
     },
     validity: &[1974,1992],
     desc: "Name changed to Belarus",
@@ -148,6 +158,8 @@ pub const BYAA: CountryCode3 = CountryCode3 {
         alpha2: "BY",
         alpha3: "BLR",
         numeric: 112,
+        official_name: Some("Republic of Belarus"),
+        name_fr: "Bélarus",
 },
 
     ],
@@ -162,6 +174,8 @@ pub const CTKI: CountryCode3 = CountryCode3 {
         alpha2: "CT",
         alpha3: "CTE",
         numeric: 128,
+        official_name: None,
+        name_fr: "Îles Canton et Enderbury",
     },
     validity: &[1974,1984],
     desc: "Merged into Kiribati",
@@ -172,6 +186,8 @@ pub const CTKI: CountryCode3 = CountryCode3 {
         alpha2: "KI",
         alpha3: "KIR",
         numeric: 296,
+        official_name: Some("Republic of Kiribati"),
+        name_fr: "Kiribati",
 },
 
     ],
@@ -186,6 +202,8 @@ pub const CSHH: CountryCode3 = CountryCode3 {
         alpha2: "CS",
         alpha3: "CSK",
         numeric: 200,
+        official_name: Some("Czechoslovak Socialist Republic"),
+        name_fr: "Tchécoslovaquie, république socialiste tchécoslovaque",
     },
     validity: &[1974,1993],
     desc: "Divided into: Czechia Slovakia",
@@ -196,12 +214,16 @@ pub const CSHH: CountryCode3 = CountryCode3 {
         alpha2: "CZ",
         alpha3: "CZE",
         numeric: 203,
+        official_name: Some("Czech Republic"),
+        name_fr: "Tchéquie",
 },
  CountryCode {
         name: "Slovakia",
         alpha2: "SK",
         alpha3: "SVK",
         numeric: 703,
+        official_name: Some("Slovak Republic"),
+        name_fr: "Slovaquie",
 },
 
     ],
@@ -216,6 +238,8 @@ pub const DYBJ: CountryCode3 = CountryCode3 {
         alpha2: "DY",
         alpha3: "DHY",
         numeric: 204,
+        official_name: Some("Republic of Dahomey"),
+        name_fr: "Dahomey",
     },
     validity: &[1974,1977],
     desc: "Name changed to Benin",
@@ -226,6 +250,8 @@ pub const DYBJ: CountryCode3 = CountryCode3 {
         alpha2: "BJ",
         alpha3: "BEN",
         numeric: 204,
+        official_name: Some("Republic of Benin"),
+        name_fr: "Bénin",
 },
 
     ],
@@ -240,6 +266,8 @@ pub const NQAQ: CountryCode3 = CountryCode3 {
         alpha2: "NQ",
         alpha3: "ATN",
         numeric: 216,
+        official_name: None,
+        name_fr: "Terre de la Reine Maud",
     },
     validity: &[1974,1983],
     desc: "Merged into Antarctica",
@@ -250,6 +278,8 @@ pub const NQAQ: CountryCode3 = CountryCode3 {
         alpha2: "AQ",
         alpha3: "ATA",
         numeric: 10,
+        official_name: None,
+        name_fr: "Antarctique",
 },
 
     ],
@@ -264,6 +294,8 @@ pub const TPTL: CountryCode3 = CountryCode3 {
         alpha2: "TP",
         alpha3: "TMP",
         numeric: 626,
+        official_name: None,
+        name_fr: "Timor oriental",
     },
     validity: &[1974,2002],
     desc: "Name changed to Timor-Leste",
@@ -274,6 +306,8 @@ pub const TPTL: CountryCode3 = CountryCode3 {
         alpha2: "TL",
         alpha3: "TLS",
         numeric: 626,
+        official_name: Some("Democratic Republic of Timor-Leste"),
+        name_fr: "Timor-Leste",
 },
 
     ],
@@ -288,6 +322,8 @@ pub const FXFR: CountryCode3 = CountryCode3 {
         alpha2: "FX",
         alpha3: "FXX",
         numeric: 249,
+        official_name: None,
+        name_fr: "France métropolitaine",
     },
     validity: &[1993,1997],
     desc: "Merged into France",
@@ -298,6 +334,8 @@ pub const FXFR: CountryCode3 = CountryCode3 {
         alpha2: "FR",
         alpha3: "FRA",
         numeric: 250,
+        official_name: Some("French Republic"),
+        name_fr: "France",
 },
 
     ],
@@ -312,6 +350,8 @@ pub const AIDJ: CountryCode3 = CountryCode3 {
         alpha2: "AI",
         alpha3: "AFI",
         numeric: 262,
+        official_name: None,
+        name_fr: "Territoire français des Afars et des Issas",
     },
     validity: &[1974,1977],
     desc: "Name changed to Djibouti",
@@ -322,6 +362,8 @@ pub const AIDJ: CountryCode3 = CountryCode3 {
         alpha2: "DJ",
         alpha3: "DJI",
         numeric: 262,
+        official_name: Some("Republic of Djibouti"),
+        name_fr: "Djibouti",
 },
 
     ],
@@ -336,6 +378,8 @@ pub const FQHH: CountryCode3 = CountryCode3 {
         alpha2: "FQ",
         alpha3: "ATF",
         numeric: 0,
+        official_name: None,
+        name_fr: "Terres australes et antarctiques françaises",
     },
     validity: &[1974,1979],
     desc: "Divided into: Part of Antarctica (i.e., Adélie Land) French Southern Territories",
@@ -346,12 +390,16 @@ pub const FQHH: CountryCode3 = CountryCode3 {
         alpha2: "AQ",
         alpha3: "ATA",
         numeric: 10,
+        official_name: None,
+        name_fr: "Antarctique",
 },
  CountryCode {
         name: "French Southern Territories",
         alpha2: "TF",
         alpha3: "ATF",
         numeric: 260,
+        official_name: None,
+        name_fr: "Terres australes françaises",
 },
 
     ],
@@ -366,6 +414,8 @@ pub const DDDE: CountryCode3 = CountryCode3 {
         alpha2: "DD",
         alpha3: "DDR",
         numeric: 278,
+        official_name: Some("German Democratic Republic"),
+        name_fr: "République démocratique allemande",
     },
     validity: &[1974,1990],
     desc: "Merged into Germany",
@@ -376,6 +426,8 @@ pub const DDDE: CountryCode3 = CountryCode3 {
         alpha2: "DE",
         alpha3: "DEU",
         numeric: 276,
+        official_name: Some("Federal Republic of Germany"),
+        name_fr: "Allemagne",
 },
 
     ],
@@ -390,6 +442,8 @@ pub const GEHH: CountryCode3 = CountryCode3 {
         alpha2: "GE",
         alpha3: "GEL",
         numeric: 296,
+        official_name: None,
+        name_fr: "Îles Gilbert et Ellice",
     },
     validity: &[1974,1979],
     desc: "Name changed to Kiribati",
@@ -400,6 +454,8 @@ pub const GEHH: CountryCode3 = CountryCode3 {
         alpha2: "KI",
         alpha3: "KIR",
         numeric: 296,
+        official_name: Some("Republic of Kiribati"),
+        name_fr: "Kiribati",
 },
 
     ],
@@ -414,6 +470,8 @@ pub const JTUM: CountryCode3 = CountryCode3 {
         alpha2: "JT",
         alpha3: "JTN",
         numeric: 396,
+        official_name: None,
+        name_fr: "Île Johnston",
     },
     validity: &[1974,1986],
     desc: "Merged into United States Minor Outlying Islands",
@@ -424,6 +482,8 @@ pub const JTUM: CountryCode3 = CountryCode3 {
         alpha2: "UM",
         alpha3: "UMI",
         numeric: 581,
+        official_name: None,
+        name_fr: "Îles mineures éloignées des États-Unis",
 },
 
     ],
@@ -438,6 +498,8 @@ pub const MIUM: CountryCode3 = CountryCode3 {
         alpha2: "MI",
         alpha3: "MID",
         numeric: 488,
+        official_name: None,
+        name_fr: "Îles Midway",
     },
     validity: &[1974,1986],
     desc: "Merged into United States Minor Outlying Islands",
@@ -448,6 +510,8 @@ pub const MIUM: CountryCode3 = CountryCode3 {
         alpha2: "UM",
         alpha3: "UMI",
         numeric: 581,
+        official_name: None,
+        name_fr: "Îles mineures éloignées des États-Unis",
 },
 
     ],
@@ -462,6 +526,8 @@ pub const ANHH: CountryCode3 = CountryCode3 {
         alpha2: "AN",
         alpha3: "ANT",
         numeric: 530,
+        official_name: None,
+        name_fr: "Antilles néerlandaises",
     },
     validity: &[1974,2010],
     desc: "Divided into: Bonaire, Sint Eustatius and Saba Curaçao Sint Maarten (Dutch part)",
@@ -472,18 +538,24 @@ pub const ANHH: CountryCode3 = CountryCode3 {
         alpha2: "BQ",
         alpha3: "BES",
         numeric: 535,
+        official_name: Some("Bonaire, Sint Eustatius and Saba"),
+        name_fr: "Bonaire, Saint-Eustache et Saba",
 },
  CountryCode {
         name: "Curaçao",
         alpha2: "CW",
         alpha3: "CUW",
         numeric: 531,
+        official_name: Some("Curaçao"),
+        name_fr: "Curaçao",
 },
  CountryCode {
         name: "Sint Maarten (Dutch part)",
         alpha2: "SX",
         alpha3: "SXM",
         numeric: 534,
+        official_name: Some("Sint Maarten (Dutch part)"),
+        name_fr: "Saint-Martin (partie néerlandaise)",
 },
 
     ],
@@ -498,6 +570,8 @@ pub const NTHH: CountryCode3 = CountryCode3 {
         alpha2: "NT",
         alpha3: "NTZ",
         numeric: 536,
+        official_name: None,
+        name_fr: "Zone neutre",
     },
     validity: &[1974,1993],
     desc: "Divided into: Part of Iraq Part of Saudi Arabia",
@@ -508,12 +582,16 @@ pub const NTHH: CountryCode3 = CountryCode3 {
         alpha2: "IQ",
         alpha3: "IRQ",
         numeric: 368,
+        official_name: Some("Republic of Iraq"),
+        name_fr: "Iraq",
 },
  CountryCode {
         name: "Part of Saudi Arabia",
         alpha2: "SA",
         alpha3: "SAU",
         numeric: 682,
+        official_name: Some("Kingdom of Saudi Arabia"),
+        name_fr: "Arabie saoudite",
 },
 
     ],
@@ -528,6 +606,8 @@ pub const NHVU: CountryCode3 = CountryCode3 {
         alpha2: "NH",
         alpha3: "NHB",
         numeric: 548,
+        official_name: None,
+        name_fr: "Nouvelles-Hébrides",
     },
     validity: &[1974,1980],
     desc: "Name changed to Vanuatu",
@@ -538,6 +618,8 @@ pub const NHVU: CountryCode3 = CountryCode3 {
         alpha2: "VU",
         alpha3: "VUT",
         numeric: 548,
+        official_name: Some("Republic of Vanuatu"),
+        name_fr: "Vanuatu",
 },
 
     ],
@@ -552,6 +634,8 @@ pub const PCHH: CountryCode3 = CountryCode3 {
         alpha2: "PC",
         alpha3: "PCI",
         numeric: 582,
+        official_name: None,
+        name_fr: "Îles du Pacifique",
     },
     validity: &[1974,1986],
     desc: "Divided into: Marshall Islands Micronesia (Federated States of) Northern Mariana Islands Palau",
@@ -562,24 +646,32 @@ pub const PCHH: CountryCode3 = CountryCode3 {
         alpha2: "MH",
         alpha3: "MHL",
         numeric: 584,
+        official_name: Some("Republic of the Marshall Islands"),
+        name_fr: "Marshall (les Îles)",
 },
  CountryCode {
         name: "Micronesia (Federated States of)",
         alpha2: "FM",
         alpha3: "FSM",
         numeric: 583,
+        official_name: Some("Federated States of Micronesia"),
+        name_fr: "Micronésie (États fédérés de)",
 },
  CountryCode {
         name: "Northern Mariana Islands",
         alpha2: "MP",
         alpha3: "MNP",
         numeric: 580,
+        official_name: Some("Commonwealth of the Northern Mariana Islands"),
+        name_fr: "Mariannes du Nord (les Îles)",
 },
  CountryCode {
         name: "Palau",
         alpha2: "PW",
         alpha3: "PLW",
         numeric: 585,
+        official_name: Some("Republic of Palau"),
+        name_fr: "Palaos",
 },
 
     ],
@@ -594,6 +686,8 @@ pub const PZPA: CountryCode3 = CountryCode3 {
         alpha2: "PZ",
         alpha3: "PCZ",
         numeric: 0,
+        official_name: None,
+        name_fr: "Zone du canal de Panama",
     },
     validity: &[1974,1980],
     desc: "Merged into Panama",
@@ -604,6 +698,8 @@ pub const PZPA: CountryCode3 = CountryCode3 {
         alpha2: "PA",
         alpha3: "PAN",
         numeric: 591,
+        official_name: Some("Republic of Panama"),
+        name_fr: "Panama",
 },
 
     ],
@@ -618,6 +714,8 @@ pub const CSXX: CountryCode3 = CountryCode3 {
         alpha2: "CS",
         alpha3: "SCG",
         numeric: 891,
+        official_name: None,
+        name_fr: "Serbie et Monténégro",
     },
     validity: &[2003,2006],
     desc: "Divided into: Montenegro Serbia",
@@ -628,12 +726,16 @@ pub const CSXX: CountryCode3 = CountryCode3 {
         alpha2: "ME",
         alpha3: "MNE",
         numeric: 499,
+        official_name: Some("Montenegro"),
+        name_fr: "Monténégro",
 },
  CountryCode {
         name: "Serbia",
         alpha2: "RS",
         alpha3: "SRB",
         numeric: 688,
+        official_name: Some("Republic of Serbia"),
+        name_fr: "Serbie",
 },
 
     ],
@@ -648,6 +750,8 @@ pub const SKIN: CountryCode3 = CountryCode3 {
         alpha2: "SK",
         alpha3: "SKM",
         numeric: 0,
+        official_name: None,
+        name_fr: "Sikkim",
     },
     validity: &[1974,1975],
     desc: "Merged into India",
@@ -658,6 +762,8 @@ pub const SKIN: CountryCode3 = CountryCode3 {
         alpha2: "IN",
         alpha3: "IND",
         numeric: 356,
+        official_name: Some("Republic of India"),
+        name_fr: "Inde",
 },
 
     ],
@@ -672,6 +778,8 @@ pub const RHZW: CountryCode3 = CountryCode3 {
         alpha2: "RH",
         alpha3: "RHO",
         numeric: 716,
+        official_name: None,
+        name_fr: "Rhodésie du Sud",
     },
     validity: &[1974,1980],
     desc: "Name changed to Zimbabwe",
@@ -682,6 +790,8 @@ pub const RHZW: CountryCode3 = CountryCode3 {
         alpha2: "ZW",
         alpha3: "ZWE",
         numeric: 716,
+        official_name: Some("Republic of Zimbabwe"),
+        name_fr: "Zimbabwe",
 },
 
     ],
@@ -696,6 +806,8 @@ pub const PUUM: CountryCode3 = CountryCode3 {
         alpha2: "PU",
         alpha3: "PUS",
         numeric: 849,
+        official_name: None,
+        name_fr: "Îles diverses du Pacifique des États-Unis",
     },
     validity: &[1974,1986],
     desc: "Merged into United States Minor Outlying Islands",
@@ -706,6 +818,8 @@ pub const PUUM: CountryCode3 = CountryCode3 {
         alpha2: "UM",
         alpha3: "UMI",
         numeric: 581,
+        official_name: None,
+        name_fr: "Îles mineures éloignées des États-Unis",
 },
 
     ],
@@ -720,6 +834,8 @@ pub const HVBF: CountryCode3 = CountryCode3 {
         alpha2: "HV",
         alpha3: "HVO",
         numeric: 854,
+        official_name: Some("Republic of Upper Volta"),
+        name_fr: "Haute-Volta, République de",
     },
     validity: &[1974,1984],
     desc: "Name changed to Burkina Faso",
@@ -730,6 +846,8 @@ pub const HVBF: CountryCode3 = CountryCode3 {
         alpha2: "BF",
         alpha3: "BFA",
         numeric: 854,
+        official_name: Some("Burkina Faso"),
+        name_fr: "Burkina Faso",
 },
 
     ],
@@ -744,6 +862,8 @@ pub const SUHH: CountryCode3 = CountryCode3 {
         alpha2: "SU",
         alpha3: "SUN",
         numeric: 810,
+        official_name: Some("Union of Soviet Socialist Republics"),
+        name_fr: "URSS, Union des républiques socialistes soviétiques",
     },
     validity: &[1974,1992],
     desc: "Divided into: Armenia Azerbaijan Estonia Georgia Kazakhstan Kyrgyzstan Latvia Lithuania Moldova (Republic of) Russian Federation Tajikistan Turkmenistan Uzbekistan",
@@ -754,78 +874,104 @@ pub const SUHH: CountryCode3 = CountryCode3 {
         alpha2: "AM",
         alpha3: "ARM",
         numeric: 51,
+        official_name: Some("Republic of Armenia"),
+        name_fr: "Arménie",
 },
  CountryCode {
         name: "Azerbaijan",
         alpha2: "AZ",
         alpha3: "AZE",
         numeric: 31,
+        official_name: Some("Republic of Azerbaijan"),
+        name_fr: "Azerbaïdjan",
 },
  CountryCode {
         name: "Estonia",
         alpha2: "EE",
         alpha3: "EST",
         numeric: 233,
+        official_name: Some("Republic of Estonia"),
+        name_fr: "Estonie",
 },
  CountryCode {
         name: "Georgia",
         alpha2: "GE",
         alpha3: "GEO",
         numeric: 268,
+        official_name: Some("Georgia"),
+        name_fr: "Géorgie",
 },
  CountryCode {
         name: "Kazakhstan",
         alpha2: "KZ",
         alpha3: "KAZ",
         numeric: 398,
+        official_name: Some("Republic of Kazakhstan"),
+        name_fr: "Kazakhstan",
 },
  CountryCode {
         name: "Kyrgyzstan",
         alpha2: "KG",
         alpha3: "KGZ",
         numeric: 417,
+        official_name: Some("Kyrgyz Republic"),
+        name_fr: "Kirghizistan",
 },
  CountryCode {
         name: "Latvia",
         alpha2: "LV",
         alpha3: "LVA",
         numeric: 428,
+        official_name: Some("Republic of Latvia"),
+        name_fr: "Lettonie",
 },
  CountryCode {
         name: "Lithuania",
         alpha2: "LT",
         alpha3: "LTU",
         numeric: 440,
+        official_name: Some("Republic of Lithuania"),
+        name_fr: "Lituanie",
 },
  CountryCode {
         name: "Moldova (Republic of)",
         alpha2: "MD",
         alpha3: "MDA",
         numeric: 498,
+        official_name: Some("Republic of Moldova"),
+        name_fr: "Moldova (la République de)",
 },
  CountryCode {
         name: "Russian Federation",
         alpha2: "RU",
         alpha3: "RUS",
         numeric: 643,
+        official_name: Some("Russian Federation"),
+        name_fr: "Russie (la Fédération de)",
 },
  CountryCode {
         name: "Tajikistan",
         alpha2: "TJ",
         alpha3: "TJK",
         numeric: 762,
+        official_name: Some("Republic of Tajikistan"),
+        name_fr: "Tadjikistan",
 },
  CountryCode {
         name: "Turkmenistan",
         alpha2: "TM",
         alpha3: "TKM",
         numeric: 795,
+        official_name: Some("Turkmenistan"),
+        name_fr: "Turkménistan",
 },
  CountryCode {
         name: "Uzbekistan",
         alpha2: "UZ",
         alpha3: "UZB",
         numeric: 860,
+        official_name: Some("Republic of Uzbekistan"),
+        name_fr: "Ouzbékistan",
 },
 
     ],
@@ -840,6 +986,8 @@ pub const VDVN: CountryCode3 = CountryCode3 {
         alpha2: "VD",
         alpha3: "VDR",
         numeric: 0,
+        official_name: Some("Democratic Republic of Viet-Nam"),
+        name_fr: "Viet-Nam, République démocratique du",
     },
     validity: &[1974,1977],
     desc: "Merged into Viet Nam",
@@ -850,6 +998,8 @@ pub const VDVN: CountryCode3 = CountryCode3 {
         alpha2: "VN",
         alpha3: "VNM",
         numeric: 704,
+        official_name: Some("Socialist Republic of Viet Nam"),
+        name_fr: "Viet Nam",
 },
 
     ],
@@ -864,6 +1014,8 @@ pub const WKUM: CountryCode3 = CountryCode3 {
         alpha2: "WK",
         alpha3: "WAK",
         numeric: 872,
+        official_name: None,
+        name_fr: "Wake",
     },
     validity: &[1974,1986],
     desc: "Merged into United States Minor Outlying Islands",
@@ -874,6 +1026,8 @@ pub const WKUM: CountryCode3 = CountryCode3 {
         alpha2: "UM",
         alpha3: "UMI",
         numeric: 581,
+        official_name: None,
+        name_fr: "Îles mineures éloignées des États-Unis",
 },
 
     ],
@@ -888,6 +1042,8 @@ pub const YDYE: CountryCode3 = CountryCode3 {
         alpha2: "YD",
         alpha3: "YMD",
         numeric: 720,
+        official_name: Some("People's Democratic Republic of Yemen"),
+        name_fr: "Yémen, République populaire démocratique du",
     },
     validity: &[1974,1990],
     desc: "Merged into Yemen",
@@ -898,6 +1054,8 @@ pub const YDYE: CountryCode3 = CountryCode3 {
         alpha2: "YE",
         alpha3: "YEM",
         numeric: 887,
+        official_name: Some("Republic of Yemen"),
+        name_fr: "Yémen",
 },
 
     ],
@@ -912,6 +1070,8 @@ pub const YUCS: CountryCode3 = CountryCode3 {
         alpha2: "YU",
         alpha3: "YUG",
         numeric: 891,
+        official_name: Some("Socialist Federal Republic of Yugoslavia"),
+        name_fr: "Yugoslavia, (Socialist) Federal Republic of",
     },
     validity: &[1974,2003],
     desc: "Name changed to Serbia and Montenegro",
@@ -922,6 +1082,8 @@ pub const YUCS: CountryCode3 = CountryCode3 {
         alpha2: "CS",
         alpha3: "SCG",
         numeric: 891,
+        official_name: None,
+        name_fr: "Serbie et Monténégro",
 },
 
     ],
@@ -936,6 +1098,8 @@ pub const ZRCD: CountryCode3 = CountryCode3 {
         alpha2: "ZR",
         alpha3: "ZAR",
         numeric: 180,
+        official_name: Some("Republic of Zaire"),
+        name_fr: "Zaïre, République du",
     },
     validity: &[1974,1997],
     desc: "Name changed to Congo (Democratic Republic of the)",
@@ -946,6 +1110,8 @@ pub const ZRCD: CountryCode3 = CountryCode3 {
         alpha2: "CD",
         alpha3: "COD",
         numeric: 180,
+        official_name: Some("Democratic Republic of the Congo"),
+        name_fr: "Congo (la République démocratique du)",
 },
 
     ],
@@ -963,6 +1129,18 @@ pub fn from_code(alpha4: &str) -> Option<CountryCode3> {
     ALPHA4_MAP.get(alpha4).cloned()
 }
 
+/// Returns the CountryCode3 with the given Alpha4 code, if exists, ignoring
+/// case and surrounding whitespace. Does not allocate.
+/// #Sample
+/// ```
+/// let sub = rust_iso3166::iso3166_3::from_code_ignore_case("pzpa ");
+/// assert_eq!("Panama Canal Zone", sub.unwrap().name);
+/// ```
+#[cfg_attr(all(direct_wasm,target_arch = "wasm32"), wasm_bindgen(js_name = from_code_ignore_case_iso_3166_3))]
+pub fn from_code_ignore_case(alpha4: &str) -> Option<CountryCode3> {
+    crate::parse::with_lenient::<4, _>(alpha4, |code| ALPHA4_MAP.get(code).cloned())
+}
+
 #[cfg(feature = "serde")]
 impl Serialize for CountryCode3 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -981,8 +1159,7 @@ impl<'de> Deserialize<'de> for CountryCode3 {
     {
         use serde::de::Error;
         let s = String::deserialize(deserializer)?;
-        let s_upper = s.to_uppercase();
-        from_code(&s_upper)
+        crate::parse::with_upper::<4, _>(&s, from_code)
             .ok_or_else(|| D::Error::custom(format!("Invalid ISO 3166-3 code: {}", s)))
     }
 }
@@ -0,0 +1,344 @@
+use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+use std::cmp::Reverse;
+
+/// Official status of a language in a country, as recorded by CLDR.
+#[derive(Debug, Ord, PartialOrd, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LanguageStatus {
+    ///Official language of the country
+    Official,
+    ///Used as the official language without being declared one, such as
+    ///English in the United States
+    DeFactoOfficial,
+    ///Official in part of the country, such as Catalan in Spain
+    OfficialRegional,
+    ///Without official status, but spoken by at least a tenth of the
+    ///population
+    Unofficial,
+}
+
+impl LanguageStatus {
+    ///Return true for every status but `Unofficial`
+    pub fn is_official(&self) -> bool {
+        !matches!(self, LanguageStatus::Unofficial)
+    }
+}
+
+/// A language spoken in a country.
+///
+/// Speaker counts are CLDR estimates and include second-language speakers,
+/// so they add up to more than the population.
+/// #Sample
+/// ```
+/// use rust_iso3166::LanguageStatus;
+/// let switzerland = rust_iso3166::from_alpha2("CH").unwrap();
+/// let languages = switzerland.languages();
+/// assert_eq!(("de", LanguageStatus::Official), (languages[0].code, languages[0].status));
+/// let official: Vec<_> = languages
+///     .iter()
+///     .filter(|l| l.status == LanguageStatus::Official)
+///     .map(|l| l.code)
+///     .collect();
+/// assert_eq!(vec!["de", "fr", "it"], official);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Language {
+    ///ISO 639-1 code, or the ISO 639-3 code for languages without one
+    pub code: &'static str,
+    ///ISO 639-3 code
+    pub alpha3: &'static str,
+    ///English name
+    pub name: &'static str,
+    ///Official status in the country
+    pub status: LanguageStatus,
+    ///Estimated number of speakers in the country
+    pub speakers: u32,
+}
+
+impl CountryCode {
+    ///Return the official and widely spoken languages, most speakers first
+    pub fn languages(&self) -> &'static [Language] {
+        COUNTRY_LANGUAGE_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+}
+
+/// Returns the countries where the language with the given ISO 639-1 or
+/// ISO 639-3 code is official or widely spoken, most speakers first. ASCII
+/// case is ignored.
+/// #Sample
+/// ```
+/// let countries = rust_iso3166::countries_speaking("pt");
+/// assert_eq!(rust_iso3166::BR, countries[0]);
+/// assert!(countries.contains(&rust_iso3166::AO));
+/// assert_eq!(countries, rust_iso3166::countries_speaking("POR"));
+/// ```
+pub fn countries_speaking(code: &str) -> Vec<CountryCode> {
+    let code = code.trim();
+    let mut found: Vec<(CountryCode, u32)> = crate::ALL
+        .iter()
+        .filter_map(|country| {
+            country
+                .languages()
+                .iter()
+                .find(|l| l.code.eq_ignore_ascii_case(code) || l.alpha3.eq_ignore_ascii_case(code))
+                .map(|l| (*country, l.speakers))
+        })
+        .collect();
+    found.sort_by_key(|&(_, speakers)| Reverse(speakers));
+    found.into_iter().map(|(country, _)| country).collect()
+}
+
+/// Official and widely spoken languages by country alpha-2 code.
+pub const COUNTRY_LANGUAGE_MAP: Map<&str, &[Language]> = phf_map! {
+    "AD" => &[Language { code: "ca", alpha3: "cat", name: "Catalan", status: LanguageStatus::Official, speakers: 39270 }, Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Unofficial, speakers: 33110 }],
+    "AE" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 7793822 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 4996040 }],
+    "AF" => &[Language { code: "fa", alpha3: "fas", name: "Persian", status: LanguageStatus::Official, speakers: 18321900 }, Language { code: "ps", alpha3: "pus", name: "Pushto", status: LanguageStatus::Official, speakers: 15756834 }, Language { code: "uz", alpha3: "uzb", name: "Uzbek", status: LanguageStatus::OfficialRegional, speakers: 1722259 }, Language { code: "tk", alpha3: "tuk", name: "Turkmen", status: LanguageStatus::OfficialRegional, speakers: 622945 }],
+    "AG" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 84434 }],
+    "AI" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 17186 }],
+    "AL" => &[Language { code: "sq", alpha3: "sqi", name: "Albanian", status: LanguageStatus::Official, speakers: 3074580 }],
+    "AM" => &[Language { code: "hy", alpha3: "hye", name: "Armenian", status: LanguageStatus::Official, speakers: 2960894 }],
+    "AO" => &[Language { code: "pt", alpha3: "por", name: "Portuguese", status: LanguageStatus::Official, speakers: 21789941 }, Language { code: "umb", alpha3: "umb", name: "Umbundu", status: LanguageStatus::Unofficial, speakers: 9431467 }, Language { code: "kmb", alpha3: "kmb", name: "Kimbundu", status: LanguageStatus::Unofficial, speakers: 8130575 }],
+    "AQ" => &[],
+    "AR" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 45479100 }],
+    "AS" => &[Language { code: "sm", alpha3: "smo", name: "Samoan", status: LanguageStatus::Official, speakers: 48943 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::DeFactoOfficial, speakers: 47954 }],
+    "AT" => &[Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::Official, speakers: 8593666 }, Language { code: "bar", alpha3: "bar", name: "Bavarian", status: LanguageStatus::Unofficial, speakers: 8416478 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 6467398 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Unofficial, speakers: 974540 }, Language { code: "hr", alpha3: "hrv", name: "Croatian", status: LanguageStatus::OfficialRegional, speakers: 106313 }, Language { code: "sl", alpha3: "slv", name: "Slovenian", status: LanguageStatus::OfficialRegional, speakers: 32780 }, Language { code: "hu", alpha3: "hun", name: "Hungarian", status: LanguageStatus::OfficialRegional, speakers: 23035 }],
+    "AU" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::DeFactoOfficial, speakers: 24447840 }],
+    "AW" => &[Language { code: "nl", alpha3: "nld", name: "Dutch", status: LanguageStatus::Official, speakers: 115845 }, Language { code: "pap", alpha3: "pap", name: "Papiamento", status: LanguageStatus::Official, speakers: 72851 }],
+    "AX" => &[Language { code: "sv", alpha3: "swe", name: "Swedish", status: LanguageStatus::Official, speakers: 25938 }],
+    "AZ" => &[Language { code: "az", alpha3: "aze", name: "Azerbaijani", status: LanguageStatus::Official, speakers: 9083162 }],
+    "BA" => &[Language { code: "bs", alpha3: "bos", name: "Bosnian", status: LanguageStatus::Official, speakers: 3797234 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 1726016 }, Language { code: "hr", alpha3: "hrv", name: "Croatian", status: LanguageStatus::Official, speakers: 460271 }, Language { code: "sr", alpha3: "srp", name: "Serbian", status: LanguageStatus::Official, speakers: 383559 }],
+    "BB" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 294560 }],
+    "BD" => &[Language { code: "bn", alpha3: "ben", name: "Bengali", status: LanguageStatus::Official, speakers: 159397980 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 29277180 }],
+    "BE" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 6915213 }, Language { code: "nl", alpha3: "nld", name: "Dutch", status: LanguageStatus::Official, speakers: 6446385 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 4453866 }, Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::Official, speakers: 2578554 }, Language { code: "vls", alpha3: "vls", name: "Vlaams", status: LanguageStatus::Unofficial, speakers: 1172070 }],
+    "BF" => &[Language { code: "mos", alpha3: "mos", name: "Mossi", status: LanguageStatus::Unofficial, speakers: 8334160 }, Language { code: "dyu", alpha3: "dyu", name: "Dyula", status: LanguageStatus::Unofficial, speakers: 6667328 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 4583788 }],
+    "BG" => &[Language { code: "bg", alpha3: "bul", name: "Bulgarian", status: LanguageStatus::Official, speakers: 6966900 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 1741725 }, Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Unofficial, speakers: 1602387 }, Language { code: "tr", alpha3: "tur", name: "Turkish", status: LanguageStatus::Unofficial, speakers: 766359 }],
+    "BH" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 1309350 }],
+    "BI" => &[Language { code: "rn", alpha3: "run", name: "Rundi", status: LanguageStatus::Official, speakers: 7475454 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 7000822 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 6289 }],
+    "BJ" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 4502610 }, Language { code: "fon", alpha3: "fon", name: "Fon", status: LanguageStatus::Unofficial, speakers: 3216150 }],
+    "BL" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 6837 }],
+    "BM" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 66010 }],
+    "BN" => &[Language { code: "ms", alpha3: "msa", name: "Malay", status: LanguageStatus::Official, speakers: 431965 }, Language { code: "zh", alpha3: "zho", name: "Chinese", status: LanguageStatus::Unofficial, speakers: 51093 }],
+    "BO" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 7100339 }, Language { code: "qu", alpha3: "que", name: "Quechua", status: LanguageStatus::Official, speakers: 3724768 }, Language { code: "ay", alpha3: "aym", name: "Aymara", status: LanguageStatus::Official, speakers: 2327980 }],
+    "BQ" => &[Language { code: "pap", alpha3: "pap", name: "Papiamento", status: LanguageStatus::Unofficial, speakers: 16200 }, Language { code: "nl", alpha3: "nld", name: "Dutch", status: LanguageStatus::Official, speakers: 1600 }],
+    "BR" => &[Language { code: "pt", alpha3: "por", name: "Portuguese", status: LanguageStatus::Official, speakers: 192661560 }, Language { code: "vec", alpha3: "vec", name: "Venetian", status: LanguageStatus::OfficialRegional, speakers: 508118 }],
+    "BS" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 337721 }],
+    "BT" => &[Language { code: "dz", alpha3: "dzo", name: "Dzongkha", status: LanguageStatus::Official, speakers: 367689 }, Language { code: "ne", alpha3: "nep", name: "Nepali", status: LanguageStatus::Unofficial, speakers: 132994 }, Language { code: "tsj", alpha3: "tsj", name: "Tshangla", status: LanguageStatus::Unofficial, speakers: 117348 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 86055 }],
+    "BV" => &[],
+    "BW" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 1876956 }, Language { code: "tn", alpha3: "tsn", name: "Tswana", status: LanguageStatus::Official, speakers: 1436683 }],
+    "BY" => &[Language { code: "be", alpha3: "bel", name: "Belarusian", status: LanguageStatus::Official, speakers: 9477920 }, Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Official, speakers: 1137350 }],
+    "BZ" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 399598 }, Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Unofficial, speakers: 111887 }],
+    "CA" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 32416926 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 11308230 }, Language { code: "iu", alpha3: "iku", name: "Inuktitut", status: LanguageStatus::OfficialRegional, speakers: 45233 }, Language { code: "chp", alpha3: "chp", name: "Chipewyan", status: LanguageStatus::OfficialRegional, speakers: 12816 }, Language { code: "cr", alpha3: "cre", name: "Cree", status: LanguageStatus::OfficialRegional, speakers: 9047 }, Language { code: "den", alpha3: "den", name: "Slave", status: LanguageStatus::OfficialRegional, speakers: 2299 }, Language { code: "dgr", alpha3: "dgr", name: "Dogrib", status: LanguageStatus::OfficialRegional, speakers: 2111 }, Language { code: "gwi", alpha3: "gwi", name: "Gwichʼin", status: LanguageStatus::OfficialRegional, speakers: 302 }],
+    "CC" => &[Language { code: "ms", alpha3: "msa", name: "Malay", status: LanguageStatus::Unofficial, speakers: 495 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::DeFactoOfficial, speakers: 101 }],
+    "CD" => &[Language { code: "sw", alpha3: "swa", name: "Swahili", status: LanguageStatus::OfficialRegional, speakers: 50890000 }, Language { code: "lua", alpha3: "lua", name: "Luba-Lulua", status: LanguageStatus::OfficialRegional, speakers: 9770880 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 3867640 }, Language { code: "ln", alpha3: "lin", name: "Lingala", status: LanguageStatus::OfficialRegional, speakers: 3155180 }, Language { code: "kg", alpha3: "kon", name: "Kongo", status: LanguageStatus::OfficialRegional, speakers: 1526700 }],
+    "CF" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 2935521 }, Language { code: "sg", alpha3: "sag", name: "Sango", status: LanguageStatus::Official, speakers: 2935521 }],
+    "CG" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 4446179 }],
+    "CH" => &[Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::Official, speakers: 6134913 }, Language { code: "gsw", alpha3: "gsw", name: "Swiss German", status: LanguageStatus::DeFactoOfficial, speakers: 5462594 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 5126434 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 1764838 }, Language { code: "it", alpha3: "ita", name: "Italian", status: LanguageStatus::Official, speakers: 361372 }, Language { code: "rm", alpha3: "roh", name: "Romansh", status: LanguageStatus::OfficialRegional, speakers: 42020 }],
+    "CI" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 13465739 }, Language { code: "bci", alpha3: "bci", name: "Baoulé", status: LanguageStatus::Unofficial, speakers: 3022921 }],
+    "CK" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 8574 }],
+    "CL" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 17823064 }],
+    "CM" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 18866600 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 10543100 }],
+    "CN" => &[Language { code: "zh", alpha3: "zho", name: "Chinese", status: LanguageStatus::Official, speakers: 1254618000 }, Language { code: "ug", alpha3: "uig", name: "Uighur", status: LanguageStatus::OfficialRegional, speakers: 7667110 }, Language { code: "za", alpha3: "zha", name: "Zhuang", status: LanguageStatus::OfficialRegional, speakers: 4321462 }, Language { code: "mn", alpha3: "mon", name: "Mongolian", status: LanguageStatus::OfficialRegional, speakers: 3624452 }, Language { code: "bo", alpha3: "bod", name: "Tibetan", status: LanguageStatus::OfficialRegional, speakers: 2788040 }, Language { code: "ko", alpha3: "kor", name: "Korean", status: LanguageStatus::OfficialRegional, speakers: 2091030 }],
+    "CO" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 45648864 }],
+    "CR" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 4843090 }],
+    "CU" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 11059100 }],
+    "CV" => &[Language { code: "kea", alpha3: "kea", name: "Kabuverdianu", status: LanguageStatus::Unofficial, speakers: 530762 }, Language { code: "pt", alpha3: "por", name: "Portuguese", status: LanguageStatus::Official, speakers: 443274 }],
+    "CW" => &[Language { code: "pap", alpha3: "pap", name: "Papiamento", status: LanguageStatus::DeFactoOfficial, speakers: 122589 }, Language { code: "nl", alpha3: "nld", name: "Dutch", status: LanguageStatus::Official, speakers: 12108 }],
+    "CX" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 1389 }],
+    "CY" => &[Language { code: "el", alpha3: "ell", name: "Greek", status: LanguageStatus::Official, speakers: 1203346 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 924676 }, Language { code: "tr", alpha3: "tur", name: "Turkish", status: LanguageStatus::Official, speakers: 291336 }],
+    "CZ" => &[Language { code: "cs", alpha3: "ces", name: "Czech", status: LanguageStatus::Official, speakers: 10488450 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 2889675 }, Language { code: "sk", alpha3: "slk", name: "Slovak", status: LanguageStatus::Unofficial, speakers: 1712400 }, Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::Unofficial, speakers: 1605375 }],
+    "DE" => &[Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::Official, speakers: 72945327 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 51302208 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Unofficial, speakers: 14428746 }, Language { code: "bar", alpha3: "bar", name: "Bavarian", status: LanguageStatus::Unofficial, speakers: 13627149 }, Language { code: "nds", alpha3: "nds", name: "Low German", status: LanguageStatus::Unofficial, speakers: 9619164 }, Language { code: "frr", alpha3: "frr", name: "Northern Frisian", status: LanguageStatus::OfficialRegional, speakers: 9619 }],
+    "DJ" => &[Language { code: "aa", alpha3: "aar", name: "Afar", status: LanguageStatus::Unofficial, speakers: 387158 }, Language { code: "so", alpha3: "som", name: "Somali", status: LanguageStatus::Unofficial, speakers: 377940 }, Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 67292 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 19358 }],
+    "DK" => &[Language { code: "da", alpha3: "dan", name: "Danish", status: LanguageStatus::Official, speakers: 5458551 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 5047693 }, Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::OfficialRegional, speakers: 2758623 }, Language { code: "sv", alpha3: "swe", name: "Swedish", status: LanguageStatus::Unofficial, speakers: 763023 }, Language { code: "kl", alpha3: "kal", name: "Kalaallisut", status: LanguageStatus::OfficialRegional, speakers: 7043 }],
+    "DM" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 69788 }],
+    "DO" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 8189766 }],
+    "DZ" => &[Language { code: "arq", alpha3: "arq", name: "Algerian Arabic", status: LanguageStatus::Unofficial, speakers: 35667507 }, Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 31799946 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 8594580 }],
+    "EC" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 16228704 }, Language { code: "qu", alpha3: "que", name: "Quechua", status: LanguageStatus::Official, speakers: 2873833 }],
+    "EE" => &[Language { code: "et", alpha3: "est", name: "Estonian", status: LanguageStatus::Official, speakers: 872320 }, Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Unofficial, speakers: 688027 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 614310 }, Language { code: "fi", alpha3: "fin", name: "Finnish", status: LanguageStatus::Unofficial, speakers: 258010 }],
+    "EG" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 97876560 }, Language { code: "arz", alpha3: "arz", name: "Egyptian Arabic", status: LanguageStatus::Unofficial, speakers: 66639360 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 36443400 }],
+    "EH" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 652271 }],
+    "ER" => &[Language { code: "ti", alpha3: "tir", name: "Tigrinya", status: LanguageStatus::DeFactoOfficial, speakers: 3648720 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 3587908 }, Language { code: "tig", alpha3: "tig", name: "Tigre", status: LanguageStatus::Unofficial, speakers: 1094616 }, Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 297979 }],
+    "ES" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 49515642 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 12003792 }, Language { code: "ca", alpha3: "cat", name: "Catalan", status: LanguageStatus::OfficialRegional, speakers: 8502686 }, Language { code: "gl", alpha3: "glg", name: "Galician", status: LanguageStatus::OfficialRegional, speakers: 3501106 }, Language { code: "eu", alpha3: "eus", name: "Basque", status: LanguageStatus::OfficialRegional, speakers: 1000316 }, Language { code: "ast", alpha3: "ast", name: "Asturian", status: LanguageStatus::OfficialRegional, speakers: 650205 }, Language { code: "oc", alpha3: "oci", name: "Occitan", status: LanguageStatus::OfficialRegional, speakers: 4952 }],
+    "ET" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 46488590 }, Language { code: "am", alpha3: "amh", name: "Amharic", status: LanguageStatus::Official, speakers: 35677290 }, Language { code: "om", alpha3: "orm", name: "Oromo", status: LanguageStatus::Unofficial, speakers: 34596160 }],
+    "FI" => &[Language { code: "fi", alpha3: "fin", name: "Finnish", status: LanguageStatus::Official, speakers: 5237370 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 3900169 }, Language { code: "sv", alpha3: "swe", name: "Swedish", status: LanguageStatus::Official, speakers: 2451535 }, Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::Unofficial, speakers: 1002901 }, Language { code: "sms", alpha3: "sms", name: "Skolt Sami", status: LanguageStatus::OfficialRegional, speakers: 613 }],
+    "FJ" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 879816 }, Language { code: "hi", alpha3: "hin", name: "Hindi", status: LanguageStatus::Unofficial, speakers: 411829 }, Language { code: "hif", alpha3: "hif", name: "Fiji Hindi", status: LanguageStatus::Official, speakers: 383749 }, Language { code: "fj", alpha3: "fij", name: "Fijian", status: LanguageStatus::Official, speakers: 365030 }],
+    "FK" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 2814 }],
+    "FM" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 58389 }, Language { code: "chk", alpha3: "chk", name: "Chuukese", status: LanguageStatus::Unofficial, speakers: 30731 }, Language { code: "pon", alpha3: "pon", name: "Pohnpeian", status: LanguageStatus::Unofficial, speakers: 23560 }],
+    "FO" => &[Language { code: "fo", alpha3: "fao", name: "Faroese", status: LanguageStatus::Official, speakers: 49047 }],
+    "FR" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 67169718 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 26460798 }, Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Unofficial, speakers: 8820266 }],
+    "GA" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 1405473 }],
+    "GB" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 64445878 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Unofficial, speakers: 15125053 }, Language { code: "cy", alpha3: "cym", name: "Welsh", status: LanguageStatus::OfficialRegional, speakers: 854894 }, Language { code: "ga", alpha3: "gle", name: "Irish", status: LanguageStatus::OfficialRegional, speakers: 98642 }, Language { code: "gd", alpha3: "gla", name: "Scottish Gaelic", status: LanguageStatus::OfficialRegional, speakers: 72337 }],
+    "GD" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 108570 }],
+    "GE" => &[Language { code: "ka", alpha3: "kat", name: "Georgian", status: LanguageStatus::Official, speakers: 3437420 }, Language { code: "xmf", alpha3: "xmf", name: "Mingrelian", status: LanguageStatus::Unofficial, speakers: 439670 }, Language { code: "ab", alpha3: "abk", name: "Abkhazian", status: LanguageStatus::OfficialRegional, speakers: 87934 }, Language { code: "os", alpha3: "oss", name: "Ossetian", status: LanguageStatus::OfficialRegional, speakers: 87934 }],
+    "GF" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 153622 }, Language { code: "gcr", alpha3: "gcr", name: "Guianese Creole French", status: LanguageStatus::Unofficial, speakers: 51872 }],
+    "GG" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 67052 }],
+    "GH" => &[Language { code: "ak", alpha3: "aka", name: "Akan", status: LanguageStatus::OfficialRegional, speakers: 11442678 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 6161442 }, Language { code: "ee", alpha3: "ewe", name: "Ewe", status: LanguageStatus::OfficialRegional, speakers: 3227422 }, Language { code: "gaa", alpha3: "gaa", name: "Ga", status: LanguageStatus::OfficialRegional, speakers: 821526 }],
+    "GI" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 23665 }, Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Unofficial, speakers: 14790 }],
+    "GL" => &[Language { code: "kl", alpha3: "kal", name: "Kalaallisut", status: LanguageStatus::Official, speakers: 48397 }, Language { code: "da", alpha3: "dan", name: "Danish", status: LanguageStatus::Unofficial, speakers: 8066 }],
+    "GM" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 869600 }, Language { code: "man", alpha3: "man", name: "Mandingo", status: LanguageStatus::Unofficial, speakers: 630460 }],
+    "GN" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 3632946 }, Language { code: "ff", alpha3: "ful", name: "Fulah", status: LanguageStatus::Unofficial, speakers: 3257124 }, Language { code: "man", alpha3: "man", name: "Mandingo", status: LanguageStatus::Unofficial, speakers: 2881302 }, Language { code: "sus", alpha3: "sus", name: "Susu", status: LanguageStatus::Unofficial, speakers: 1378014 }],
+    "GP" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 407498 }],
+    "GQ" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 727475 }, Language { code: "fan", alpha3: "fan", name: "Fang", status: LanguageStatus::Unofficial, speakers: 426451 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 73584 }, Language { code: "pt", alpha3: "por", name: "Portuguese", status: LanguageStatus::Official, speakers: 1 }],
+    "GR" => &[Language { code: "el", alpha3: "ell", name: "Greek", status: LanguageStatus::Official, speakers: 10501029 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 5409621 }],
+    "GS" => &[],
+    "GT" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 15952569 }, Language { code: "quc", alpha3: "quc", name: "K'iche'", status: LanguageStatus::OfficialRegional, speakers: 1200731 }],
+    "GU" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::DeFactoOfficial, speakers: 153321 }, Language { code: "ch", alpha3: "cha", name: "Chamorro", status: LanguageStatus::Official, speakers: 37067 }],
+    "GW" => &[Language { code: "pt", alpha3: "por", name: "Portuguese", status: LanguageStatus::Official, speakers: 1927100 }],
+    "GY" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 750204 }],
+    "HK" => &[Language { code: "zh", alpha3: "zho", name: "Chinese", status: LanguageStatus::Official, speakers: 6887414 }, Language { code: "yue", alpha3: "yue", name: "Yue Chinese", status: LanguageStatus::Unofficial, speakers: 6524919 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 3697454 }],
+    "HM" => &[],
+    "HN" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 7203565 }],
+    "HR" => &[Language { code: "hr", alpha3: "hrv", name: "Croatian", status: LanguageStatus::Official, speakers: 4185472 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 2071598 }, Language { code: "it", alpha3: "ita", name: "Italian", status: LanguageStatus::OfficialRegional, speakers: 67644 }, Language { code: "vec", alpha3: "vec", name: "Venetian", status: LanguageStatus::OfficialRegional, speakers: 29594 }],
+    "HT" => &[Language { code: "ht", alpha3: "hat", name: "Haitian", status: LanguageStatus::Official, speakers: 8964918 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 520187 }],
+    "HU" => &[Language { code: "hu", alpha3: "hun", name: "Hungarian", status: LanguageStatus::Official, speakers: 9771830 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 1954366 }, Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::Unofficial, speakers: 1758929 }],
+    "ID" => &[Language { code: "id", alpha3: "ind", name: "Indonesian", status: LanguageStatus::Official, speakers: 170896640 }, Language { code: "jv", alpha3: "jav", name: "Javanese", status: LanguageStatus::Unofficial, speakers: 90788840 }, Language { code: "su", alpha3: "sun", name: "Sundanese", status: LanguageStatus::Unofficial, speakers: 32043120 }],
+    "IE" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 5073039 }, Language { code: "ga", alpha3: "gle", name: "Irish", status: LanguageStatus::Official, speakers: 1138845 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Unofficial, speakers: 880017 }],
+    "IL" => &[Language { code: "he", alpha3: "heb", name: "Hebrew", status: LanguageStatus::Official, speakers: 8675480 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 7374158 }, Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 1735096 }, Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Unofficial, speakers: 954303 }],
+    "IM" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 90499 }, Language { code: "gv", alpha3: "glv", name: "Manx", status: LanguageStatus::Official, speakers: 1719 }],
+    "IN" => &[Language { code: "hi", alpha3: "hin", name: "Hindi", status: LanguageStatus::Official, speakers: 543696900 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 251957100 }, Language { code: "bn", alpha3: "ben", name: "Bengali", status: LanguageStatus::OfficialRegional, speakers: 107413290 }, Language { code: "te", alpha3: "tel", name: "Telugu", status: LanguageStatus::OfficialRegional, speakers: 95478480 }, Language { code: "mr", alpha3: "mar", name: "Marathi", status: LanguageStatus::OfficialRegional, speakers: 92826300 }, Language { code: "ta", alpha3: "tam", name: "Tamil", status: LanguageStatus::OfficialRegional, speakers: 78239310 }, Language { code: "ur", alpha3: "urd", name: "Urdu", status: LanguageStatus::OfficialRegional, speakers: 66304500 }, Language { code: "gu", alpha3: "guj", name: "Gujarati", status: LanguageStatus::OfficialRegional, speakers: 59674050 }, Language { code: "kn", alpha3: "kan", name: "Kannada", status: LanguageStatus::OfficialRegional, speakers: 49065330 }, Language { code: "ml", alpha3: "mal", name: "Malayalam", status: LanguageStatus::OfficialRegional, speakers: 42434880 }, Language { code: "or", alpha3: "ori", name: "Oriya", status: LanguageStatus::OfficialRegional, speakers: 42434880 }, Language { code: "pa", alpha3: "pan", name: "Panjabi", status: LanguageStatus::OfficialRegional, speakers: 37130520 }, Language { code: "as", alpha3: "asm", name: "Assamese", status: LanguageStatus::OfficialRegional, speakers: 17239170 }, Language { code: "mai", alpha3: "mai", name: "Maithili", status: LanguageStatus::OfficialRegional, speakers: 15913080 }, Language { code: "ne", alpha3: "nep", name: "Nepali", status: LanguageStatus::OfficialRegional, speakers: 7426104 }, Language { code: "sat", alpha3: "sat", name: "Santali", status: LanguageStatus::OfficialRegional, speakers: 7293495 }, Language { code: "ks", alpha3: "kas", name: "Kashmiri", status: LanguageStatus::OfficialRegional, speakers: 5436969 }, Language { code: "kok", alpha3: "kok", name: "Konkani", status: LanguageStatus::OfficialRegional, speakers: 4906533 }, Language { code: "sd", alpha3: "snd", name: "Sindhi", status: LanguageStatus::OfficialRegional, speakers: 3447834 }, Language { code: "kha", alpha3: "kha", name: "Khasi", status: LanguageStatus::OfficialRegional, speakers: 1060872 }, Language { code: "sa", alpha3: "san", name: "Sanskrit", status: LanguageStatus::OfficialRegional, speakers: 15913 }],
+    "IO" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 3500 }],
+    "IQ" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 26433436 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 13605445 }, Language { code: "ckb", alpha3: "ckb", name: "Central Kurdish", status: LanguageStatus::OfficialRegional, speakers: 7774540 }, Language { code: "az", alpha3: "aze", name: "Azerbaijani", status: LanguageStatus::OfficialRegional, speakers: 699709 }],
+    "IR" => &[Language { code: "fa", alpha3: "fas", name: "Persian", status: LanguageStatus::Official, speakers: 63692475 }, Language { code: "az", alpha3: "aze", name: "Azerbaijani", status: LanguageStatus::Unofficial, speakers: 20381592 }],
+    "IS" => &[Language { code: "is", alpha3: "isl", name: "Icelandic", status: LanguageStatus::Official, speakers: 350734 }],
+    "IT" => &[Language { code: "it", alpha3: "ita", name: "Italian", status: LanguageStatus::Official, speakers: 59282565 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 21216918 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::OfficialRegional, speakers: 3931370 }, Language { code: "vec", alpha3: "vec", name: "Venetian", status: LanguageStatus::OfficialRegional, speakers: 811235 }],
+    "JE" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 96019 }],
+    "JM" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 2752399 }, Language { code: "jam", alpha3: "jam", name: "Jamaican Creole English", status: LanguageStatus::Unofficial, speakers: 2668142 }],
+    "JO" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 10820600 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 4869270 }],
+    "JP" => &[Language { code: "ja", alpha3: "jpn", name: "Japanese", status: LanguageStatus::Official, speakers: 119231650 }],
+    "KE" => &[Language { code: "sw", alpha3: "swa", name: "Swahili", status: LanguageStatus::Official, speakers: 35328414 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 10170301 }, Language { code: "ki", alpha3: "kik", name: "Kikuyu", status: LanguageStatus::Unofficial, speakers: 9099743 }, Language { code: "luy", alpha3: "luy", name: "Luyia", status: LanguageStatus::Unofficial, speakers: 5888069 }],
+    "KG" => &[Language { code: "ky", alpha3: "kir", name: "Kirghiz", status: LanguageStatus::Official, speakers: 2863152 }, Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Official, speakers: 2147364 }],
+    "KH" => &[Language { code: "km", alpha3: "khm", name: "Khmer", status: LanguageStatus::Official, speakers: 15065030 }],
+    "KI" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 111796 }, Language { code: "gil", alpha3: "gil", name: "Gilbertese", status: LanguageStatus::Official, speakers: 67078 }],
+    "KM" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 558545 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 473917 }, Language { code: "zdj", alpha3: "zdj", name: "Ngazidja Comorian", status: LanguageStatus::Official, speakers: 313124 }, Language { code: "wni", alpha3: "wni", name: "Ndzwani Comorian", status: LanguageStatus::Official, speakers: 287736 }],
+    "KN" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 52745 }],
+    "KP" => &[Language { code: "ko", alpha3: "kor", name: "Korean", status: LanguageStatus::Official, speakers: 22566280 }],
+    "KR" => &[Language { code: "ko", alpha3: "kor", name: "Korean", status: LanguageStatus::Official, speakers: 51835100 }],
+    "KW" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 2993710 }],
+    "KY" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 60705 }],
+    "KZ" => &[Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Official, speakers: 13746168 }, Language { code: "kk", alpha3: "kaz", name: "Kazakh", status: LanguageStatus::Official, speakers: 12218816 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 2863785 }],
+    "LA" => &[Language { code: "lo", alpha3: "lao", name: "Lao", status: LanguageStatus::Official, speakers: 5138706 }],
+    "LB" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 4703865 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 2187844 }],
+    "LC" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 149838 }],
+    "LI" => &[Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::Official, speakers: 39137 }, Language { code: "gsw", alpha3: "gsw", name: "Swiss German", status: LanguageStatus::DeFactoOfficial, speakers: 33266 }],
+    "LK" => &[Language { code: "si", alpha3: "sin", name: "Sinhala", status: LanguageStatus::Official, speakers: 15564656 }, Language { code: "ta", alpha3: "tam", name: "Tamil", status: LanguageStatus::Official, speakers: 3433380 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 2288920 }],
+    "LR" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 4210839 }, Language { code: "kpe", alpha3: "kpe", name: "Kpelle", status: LanguageStatus::Unofficial, speakers: 710262 }],
+    "LS" => &[Language { code: "st", alpha3: "sot", name: "Southern Sotho", status: LanguageStatus::Official, speakers: 1929943 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 531719 }, Language { code: "zu", alpha3: "zul", name: "Zulu", status: LanguageStatus::Unofficial, speakers: 275706 }],
+    "LT" => &[Language { code: "lt", alpha3: "lit", name: "Lithuanian", status: LanguageStatus::Official, speakers: 2349056 }, Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Unofficial, speakers: 2185168 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 1037955 }, Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::Unofficial, speakers: 382404 }],
+    "LU" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 546691 }, Language { code: "lb", alpha3: "ltz", name: "Luxembourgish", status: LanguageStatus::Official, speakers: 421015 }, Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::Official, speakers: 395880 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 351893 }, Language { code: "pt", alpha3: "por", name: "Portuguese", status: LanguageStatus::Unofficial, speakers: 100541 }],
+    "LV" => &[Language { code: "lv", alpha3: "lav", name: "Latvian", status: LanguageStatus::Official, speakers: 1147550 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 865366 }, Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Unofficial, speakers: 714867 }],
+    "LY" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 5099000 }],
+    "MA" => &[Language { code: "ary", alpha3: "ary", name: "Moroccan Arabic", status: LanguageStatus::Unofficial, speakers: 30938679 }, Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 22048254 }, Language { code: "zgh", alpha3: "zgh", name: "Standard Moroccan Tamazight", status: LanguageStatus::Unofficial, speakers: 7823574 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::DeFactoOfficial, speakers: 7112340 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 4978638 }, Language { code: "tzm", alpha3: "tzm", name: "Central Atlas Tamazight", status: LanguageStatus::Official, speakers: 3485047 }],
+    "MC" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 38610 }],
+    "MD" => &[Language { code: "ro", alpha3: "ron", name: "Romanian", status: LanguageStatus::Official, speakers: 2119635 }, Language { code: "uk", alpha3: "ukr", name: "Ukrainian", status: LanguageStatus::Unofficial, speakers: 471030 }],
+    "ME" => &[Language { code: "sr", alpha3: "srp", name: "Serbian", status: LanguageStatus::Official, speakers: 609859 }],
+    "MF" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 32556 }],
+    "MG" => &[Language { code: "mg", alpha3: "mlg", name: "Malagasy", status: LanguageStatus::Official, speakers: 24260130 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 18599433 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 4852026 }],
+    "MH" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 72463 }, Language { code: "mh", alpha3: "mah", name: "Marshallese", status: LanguageStatus::Official, speakers: 56879 }],
+    "MK" => &[Language { code: "mk", alpha3: "mkd", name: "Macedonian", status: LanguageStatus::Official, speakers: 1424400 }, Language { code: "sq", alpha3: "sqi", name: "Albanian", status: LanguageStatus::OfficialRegional, speakers: 531492 }],
+    "ML" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 8994564 }, Language { code: "bm", alpha3: "bam", name: "Bambara", status: LanguageStatus::Unofficial, speakers: 8994564 }],
+    "MM" => &[Language { code: "my", alpha3: "mya", name: "Burmese", status: LanguageStatus::Official, speakers: 36217664 }],
+    "MN" => &[Language { code: "mn", alpha3: "mon", name: "Mongolian", status: LanguageStatus::Official, speakers: 2946268 }],
+    "MO" => &[Language { code: "zh", alpha3: "zho", name: "Chinese", status: LanguageStatus::Official, speakers: 602169 }, Language { code: "pt", alpha3: "por", name: "Portuguese", status: LanguageStatus::Official, speakers: 30723 }],
+    "MP" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::DeFactoOfficial, speakers: 49890 }, Language { code: "ch", alpha3: "cha", name: "Chamorro", status: LanguageStatus::Unofficial, speakers: 9258 }],
+    "MQ" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 427408 }],
+    "MR" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 3404658 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Unofficial, speakers: 680932 }],
+    "MS" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 3492 }],
+    "MT" => &[Language { code: "mt", alpha3: "mlt", name: "Maltese", status: LanguageStatus::Official, speakers: 457267 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 402395 }, Language { code: "it", alpha3: "ita", name: "Italian", status: LanguageStatus::Unofficial, speakers: 256070 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Unofficial, speakers: 50299 }],
+    "MU" => &[Language { code: "mfe", alpha3: "mfe", name: "Morisyen", status: LanguageStatus::Unofficial, speakers: 1241433 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 993146 }, Language { code: "bho", alpha3: "bho", name: "Bhojpuri", status: LanguageStatus::Unofficial, speakers: 372430 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 41381 }],
+    "MV" => &[Language { code: "dv", alpha3: "div", name: "Dhivehi", status: LanguageStatus::Official, speakers: 384066 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 293928 }],
+    "MW" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 13353858 }, Language { code: "ny", alpha3: "nya", name: "Nyanja", status: LanguageStatus::Official, speakers: 13353858 }],
+    "MX" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::DeFactoOfficial, speakers: 106779500 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 16724500 }, Language { code: "vec", alpha3: "vec", name: "Venetian", status: LanguageStatus::OfficialRegional, speakers: 2444 }],
+    "MY" => &[Language { code: "ms", alpha3: "msa", name: "Malay", status: LanguageStatus::Official, speakers: 24489075 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 6856941 }, Language { code: "zh", alpha3: "zho", name: "Chinese", status: LanguageStatus::Unofficial, speakers: 5550857 }],
+    "MZ" => &[Language { code: "pt", alpha3: "por", name: "Portuguese", status: LanguageStatus::Official, speakers: 8126514 }, Language { code: "vmw", alpha3: "vmw", name: "Makhuwa", status: LanguageStatus::Unofficial, speakers: 3912766 }],
+    "NA" => &[Language { code: "af", alpha3: "afr", name: "Afrikaans", status: LanguageStatus::Unofficial, speakers: 1972552 }, Language { code: "kj", alpha3: "kua", name: "Kuanyama", status: LanguageStatus::Unofficial, speakers: 920524 }, Language { code: "ng", alpha3: "ndo", name: "Ndonga", status: LanguageStatus::Unofficial, speakers: 552315 }, Language { code: "naq", alpha3: "naq", name: "Khoekhoe", status: LanguageStatus::Unofficial, speakers: 289308 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 184105 }],
+    "NC" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 278409 }],
+    "NE" => &[Language { code: "ha", alpha3: "hau", name: "Hausa", status: LanguageStatus::Unofficial, speakers: 9336684 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 6603996 }, Language { code: "dje", alpha3: "dje", name: "Zarma", status: LanguageStatus::Unofficial, speakers: 3871308 }],
+    "NF" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 1678 }],
+    "NG" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 113434840 }, Language { code: "pcm", alpha3: "pcm", name: "Nigerian Pidgin", status: LanguageStatus::Unofficial, speakers: 44945880 }, Language { code: "yo", alpha3: "yor", name: "Yoruba", status: LanguageStatus::Official, speakers: 27823640 }, Language { code: "ha", alpha3: "hau", name: "Hausa", status: LanguageStatus::Unofficial, speakers: 27823640 }, Language { code: "ig", alpha3: "ibo", name: "Igbo", status: LanguageStatus::Unofficial, speakers: 27823640 }],
+    "NI" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 4838683 }],
+    "NL" => &[Language { code: "nl", alpha3: "nld", name: "Dutch", status: LanguageStatus::Official, speakers: 17280400 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 15552360 }, Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::Unofficial, speakers: 12269084 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Unofficial, speakers: 5011316 }, Language { code: "nds", alpha3: "nds", name: "Low German", status: LanguageStatus::Unofficial, speakers: 1900844 }, Language { code: "fy", alpha3: "fry", name: "Western Frisian", status: LanguageStatus::OfficialRegional, speakers: 743057 }],
+    "NO" => &[Language { code: "nb", alpha3: "nob", name: "Norwegian Bokmål", status: LanguageStatus::Official, speakers: 5467440 }, Language { code: "no", alpha3: "nor", name: "Norwegian", status: LanguageStatus::Official, speakers: 5467440 }, Language { code: "nn", alpha3: "nno", name: "Norwegian Nynorsk", status: LanguageStatus::Official, speakers: 1366860 }, Language { code: "se", alpha3: "sme", name: "Northern Sami", status: LanguageStatus::OfficialRegional, speakers: 15856 }],
+    "NP" => &[Language { code: "ne", alpha3: "nep", name: "Nepali", status: LanguageStatus::Official, speakers: 13344276 }, Language { code: "mai", alpha3: "mai", name: "Maithili", status: LanguageStatus::Unofficial, speakers: 3336069 }],
+    "NR" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 9350 }, Language { code: "na", alpha3: "nau", name: "Nauru", status: LanguageStatus::Official, speakers: 6930 }],
+    "NU" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 1120 }, Language { code: "niu", alpha3: "niu", name: "Niuean", status: LanguageStatus::Official, speakers: 1120 }],
+    "NZ" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::DeFactoOfficial, speakers: 4826970 }, Language { code: "mi", alpha3: "mri", name: "Maori", status: LanguageStatus::Official, speakers: 137913 }],
+    "OM" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 3778520 }],
+    "PA" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 2686915 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 545171 }],
+    "PE" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 23297950 }, Language { code: "qu", alpha3: "que", name: "Quechua", status: LanguageStatus::Official, speakers: 4787250 }],
+    "PF" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 180024 }, Language { code: "ty", alpha3: "tah", name: "Tahitian", status: LanguageStatus::Official, speakers: 91488 }],
+    "PG" => &[Language { code: "tpi", alpha3: "tpi", name: "Tok Pisin", status: LanguageStatus::Official, speakers: 5154217 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 3629730 }, Language { code: "ho", alpha3: "hmo", name: "Hiri Motu", status: LanguageStatus::Official, speakers: 152449 }],
+    "PH" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 69875840 }, Language { code: "fil", alpha3: "fil", name: "Filipino", status: LanguageStatus::Official, speakers: 65508600 }, Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Unofficial, speakers: 33846110 }, Language { code: "ceb", alpha3: "ceb", name: "Cebuano", status: LanguageStatus::OfficialRegional, speakers: 26203440 }, Language { code: "ilo", alpha3: "ilo", name: "Iloko", status: LanguageStatus::OfficialRegional, speakers: 10481376 }, Language { code: "hil", alpha3: "hil", name: "Hiligaynon", status: LanguageStatus::OfficialRegional, speakers: 9171204 }, Language { code: "war", alpha3: "war", name: "Waray", status: LanguageStatus::OfficialRegional, speakers: 3166249 }, Language { code: "pag", alpha3: "pag", name: "Pangasinan", status: LanguageStatus::OfficialRegional, speakers: 1528534 }, Language { code: "mdh", alpha3: "mdh", name: "Maguindanaon", status: LanguageStatus::OfficialRegional, speakers: 1310172 }, Language { code: "tsg", alpha3: "tsg", name: "Tausug", status: LanguageStatus::OfficialRegional, speakers: 1200991 }],
+    "PK" => &[Language { code: "ur", alpha3: "urd", name: "Urdu", status: LanguageStatus::Official, speakers: 221825950 }, Language { code: "pa", alpha3: "pan", name: "Panjabi", status: LanguageStatus::Unofficial, speakers: 163450700 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 116750500 }, Language { code: "lah", alpha3: "lah", name: "Lahnda", status: LanguageStatus::Unofficial, speakers: 93400400 }, Language { code: "ps", alpha3: "pus", name: "Pushto", status: LanguageStatus::Unofficial, speakers: 37360160 }, Language { code: "sd", alpha3: "snd", name: "Sindhi", status: LanguageStatus::Unofficial, speakers: 35025150 }, Language { code: "skr", alpha3: "skr", name: "Saraiki", status: LanguageStatus::Unofficial, speakers: 28020120 }],
+    "PL" => &[Language { code: "pl", alpha3: "pol", name: "Polish", status: LanguageStatus::Official, speakers: 36751008 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 12633159 }, Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::OfficialRegional, speakers: 7273637 }, Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Unofficial, speakers: 6890814 }, Language { code: "csb", alpha3: "csb", name: "Kashubian", status: LanguageStatus::OfficialRegional, speakers: 49767 }, Language { code: "lt", alpha3: "lit", name: "Lithuanian", status: LanguageStatus::OfficialRegional, speakers: 8039 }],
+    "PM" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 5133 }],
+    "PN" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 46 }],
+    "PR" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 2774491 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::DeFactoOfficial, speakers: 1562644 }],
+    "PS" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 4818260 }],
+    "PT" => &[Language { code: "pt", alpha3: "por", name: "Portuguese", status: LanguageStatus::Official, speakers: 9890592 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 2781729 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Unofficial, speakers: 1545405 }, Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Unofficial, speakers: 1030270 }],
+    "PW" => &[Language { code: "pau", alpha3: "pau", name: "Palauan", status: LanguageStatus::Official, speakers: 16047 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 1887 }],
+    "PY" => &[Language { code: "gn", alpha3: "grn", name: "Guarani", status: LanguageStatus::Official, speakers: 5753352 }, Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 230134 }],
+    "QA" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 2175311 }, Language { code: "fa", alpha3: "fas", name: "Persian", status: LanguageStatus::Unofficial, speakers: 268859 }],
+    "RE" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 700950 }, Language { code: "rcf", alpha3: "rcf", name: "Réunion Creole French", status: LanguageStatus::Unofficial, speakers: 559185 }, Language { code: "ta", alpha3: "tam", name: "Tamil", status: LanguageStatus::Unofficial, speakers: 118138 }],
+    "RO" => &[Language { code: "ro", alpha3: "ron", name: "Romanian", status: LanguageStatus::Official, speakers: 19172610 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 6603899 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Unofficial, speakers: 3621493 }, Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Unofficial, speakers: 2130290 }],
+    "RS" => &[Language { code: "sr", alpha3: "srp", name: "Serbian", status: LanguageStatus::Official, speakers: 6942048 }, Language { code: "sq", alpha3: "sqi", name: "Albanian", status: LanguageStatus::Unofficial, speakers: 1332312 }, Language { code: "hu", alpha3: "hun", name: "Hungarian", status: LanguageStatus::OfficialRegional, speakers: 336584 }, Language { code: "ro", alpha3: "ron", name: "Romanian", status: LanguageStatus::OfficialRegional, speakers: 147256 }, Language { code: "hr", alpha3: "hrv", name: "Croatian", status: LanguageStatus::OfficialRegional, speakers: 65213 }, Language { code: "sk", alpha3: "slk", name: "Slovak", status: LanguageStatus::OfficialRegional, speakers: 59603 }, Language { code: "uk", alpha3: "ukr", name: "Ukrainian", status: LanguageStatus::OfficialRegional, speakers: 0 }],
+    "RU" => &[Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Official, speakers: 133218680 }, Language { code: "tt", alpha3: "tat", name: "Tatar", status: LanguageStatus::OfficialRegional, speakers: 1984108 }, Language { code: "ba", alpha3: "bak", name: "Bashkir", status: LanguageStatus::OfficialRegional, speakers: 1842386 }, Language { code: "ce", alpha3: "che", name: "Chechen", status: LanguageStatus::OfficialRegional, speakers: 935365 }, Language { code: "av", alpha3: "ava", name: "Avaric", status: LanguageStatus::OfficialRegional, speakers: 552716 }, Language { code: "udm", alpha3: "udm", name: "Udmurt", status: LanguageStatus::OfficialRegional, speakers: 538544 }, Language { code: "sah", alpha3: "sah", name: "Yakut", status: LanguageStatus::OfficialRegional, speakers: 453510 }, Language { code: "kbd", alpha3: "kbd", name: "Kabardian", status: LanguageStatus::OfficialRegional, speakers: 439338 }, Language { code: "myv", alpha3: "myv", name: "Erzya", status: LanguageStatus::OfficialRegional, speakers: 439338 }, Language { code: "mdf", alpha3: "mdf", name: "Moksha", status: LanguageStatus::OfficialRegional, speakers: 297616 }, Language { code: "kum", alpha3: "kum", name: "Kumyk", status: LanguageStatus::OfficialRegional, speakers: 283444 }, Language { code: "kv", alpha3: "kom", name: "Komi", status: LanguageStatus::OfficialRegional, speakers: 255100 }, Language { code: "lez", alpha3: "lez", name: "Lezghian", status: LanguageStatus::OfficialRegional, speakers: 255100 }, Language { code: "krc", alpha3: "krc", name: "Karachay-Balkar", status: LanguageStatus::OfficialRegional, speakers: 240927 }, Language { code: "inh", alpha3: "inh", name: "Ingush", status: LanguageStatus::OfficialRegional, speakers: 226755 }, Language { code: "tyv", alpha3: "tyv", name: "Tuvinian", status: LanguageStatus::OfficialRegional, speakers: 184239 }, Language { code: "az", alpha3: "aze", name: "Azerbaijani", status: LanguageStatus::OfficialRegional, speakers: 131801 }, Language { code: "ady", alpha3: "ady", name: "Adyghe", status: LanguageStatus::OfficialRegional, speakers: 124715 }, Language { code: "lbe", alpha3: "lbe", name: "Lak", status: LanguageStatus::OfficialRegional, speakers: 110543 }, Language { code: "koi", alpha3: "koi", name: "Komi-Permyak", status: LanguageStatus::OfficialRegional, speakers: 63775 }],
+    "RW" => &[Language { code: "rw", alpha3: "kin", name: "Kinyarwanda", status: LanguageStatus::Official, speakers: 9788548 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 1906860 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 2288 }],
+    "SA" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 34173500 }],
+    "SB" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 685097 }, Language { code: "pis", alpha3: "pis", name: "Pijin", status: LanguageStatus::Unofficial, speakers: 561780 }],
+    "SC" => &[Language { code: "crs", alpha3: "crs", name: "Seselwa Creole French", status: LanguageStatus::Unofficial, speakers: 94061 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 57589 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 36473 }],
+    "SD" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 27792576 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 27792576 }],
+    "SE" => &[Language { code: "sv", alpha3: "swe", name: "Swedish", status: LanguageStatus::Official, speakers: 9692375 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 8774150 }, Language { code: "fi", alpha3: "fin", name: "Finnish", status: LanguageStatus::OfficialRegional, speakers: 224455 }],
+    "SG" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 5774984 }, Language { code: "zh", alpha3: "zho", name: "Chinese", status: LanguageStatus::Official, speakers: 4781438 }, Language { code: "ms", alpha3: "msa", name: "Malay", status: LanguageStatus::Official, speakers: 869352 }, Language { code: "ta", alpha3: "tam", name: "Tamil", status: LanguageStatus::Official, speakers: 130403 }],
+    "SH" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 5425 }],
+    "SI" => &[Language { code: "sl", alpha3: "slv", name: "Slovenian", status: LanguageStatus::Official, speakers: 1829332 }, Language { code: "hr", alpha3: "hrv", name: "Croatian", status: LanguageStatus::Unofficial, speakers: 1282635 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 1240581 }, Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::Unofficial, speakers: 883126 }, Language { code: "vec", alpha3: "vec", name: "Venetian", status: LanguageStatus::OfficialRegional, speakers: 29438 }],
+    "SJ" => &[Language { code: "nb", alpha3: "nob", name: "Norwegian Bokmål", status: LanguageStatus::Official, speakers: 1492 }, Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Unofficial, speakers: 1200 }],
+    "SK" => &[Language { code: "sk", alpha3: "slk", name: "Slovak", status: LanguageStatus::Official, speakers: 4896540 }, Language { code: "cs", alpha3: "ces", name: "Czech", status: LanguageStatus::Unofficial, speakers: 2557082 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 1414556 }, Language { code: "de", alpha3: "deu", name: "German", status: LanguageStatus::Unofficial, speakers: 1196932 }, Language { code: "hu", alpha3: "hun", name: "Hungarian", status: LanguageStatus::Unofficial, speakers: 598466 }],
+    "SL" => &[Language { code: "kri", alpha3: "kri", name: "Krio", status: LanguageStatus::Unofficial, speakers: 6293684 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 2318726 }, Language { code: "men", alpha3: "men", name: "Mende", status: LanguageStatus::Unofficial, speakers: 1788731 }, Language { code: "tem", alpha3: "tem", name: "Timne", status: LanguageStatus::Unofficial, speakers: 1722482 }],
+    "SM" => &[Language { code: "it", alpha3: "ita", name: "Italian", status: LanguageStatus::Official, speakers: 30466 }],
+    "SN" => &[Language { code: "wo", alpha3: "wol", name: "Wolof", status: LanguageStatus::DeFactoOfficial, speakers: 11015480 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 6137196 }, Language { code: "ff", alpha3: "ful", name: "Fulah", status: LanguageStatus::OfficialRegional, speakers: 3304644 }, Language { code: "srr", alpha3: "srr", name: "Serer", status: LanguageStatus::OfficialRegional, speakers: 1731004 }, Language { code: "dyo", alpha3: "dyo", name: "Jola-Fonyi", status: LanguageStatus::OfficialRegional, speakers: 409146 }, Language { code: "sav", alpha3: "sav", name: "Saafi-Saafi", status: LanguageStatus::OfficialRegional, speakers: 236046 }, Language { code: "mfv", alpha3: "mfv", name: "Mandjak", status: LanguageStatus::OfficialRegional, speakers: 121170 }, Language { code: "bjt", alpha3: "bjt", name: "Balanta-Ganja", status: LanguageStatus::OfficialRegional, speakers: 95992 }, Language { code: "snf", alpha3: "snf", name: "Noon", status: LanguageStatus::OfficialRegional, speakers: 37767 }, Language { code: "knf", alpha3: "knf", name: "Mankanya", status: LanguageStatus::OfficialRegional, speakers: 33046 }, Language { code: "bsc", alpha3: "bsc", name: "Bassari", status: LanguageStatus::OfficialRegional, speakers: 15264 }, Language { code: "mey", alpha3: "mey", name: "Hassaniyya", status: LanguageStatus::OfficialRegional, speakers: 7239 }, Language { code: "tnr", alpha3: "tnr", name: "Ménik", status: LanguageStatus::OfficialRegional, speakers: 3305 }],
+    "SO" => &[Language { code: "so", alpha3: "som", name: "Somali", status: LanguageStatus::Official, speakers: 9170538 }, Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 3997414 }],
+    "SR" => &[Language { code: "nl", alpha3: "nld", name: "Dutch", status: LanguageStatus::Official, speakers: 548612 }, Language { code: "srn", alpha3: "srn", name: "Sranan Tongo", status: LanguageStatus::Unofficial, speakers: 414507 }],
+    "SS" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 2851524 }, Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Unofficial, speakers: 2851524 }],
+    "ST" => &[Language { code: "pt", alpha3: "por", name: "Portuguese", status: LanguageStatus::Official, speakers: 179454 }],
+    "SV" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 5768179 }],
+    "SX" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 29816 }, Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Unofficial, speakers: 4823 }, Language { code: "nl", alpha3: "nld", name: "Dutch", status: LanguageStatus::Official, speakers: 1578 }],
+    "SY" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 15518720 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 1144506 }],
+    "SZ" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 883584 }, Language { code: "ss", alpha3: "ssw", name: "Swati", status: LanguageStatus::Official, speakers: 640598 }],
+    "TC" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 54807 }],
+    "TD" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 4388124 }, Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 2869158 }],
+    "TF" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Unofficial, speakers: 140 }],
+    "TG" => &[Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 5251148 }, Language { code: "ee", alpha3: "ewe", name: "Ewe", status: LanguageStatus::Unofficial, speakers: 1463435 }],
+    "TH" => &[Language { code: "th", alpha3: "tha", name: "Thai", status: LanguageStatus::Official, speakers: 55181920 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 18623898 }, Language { code: "tts", alpha3: "tts", name: "Northeastern Thai", status: LanguageStatus::Unofficial, speakers: 16554576 }],
+    "TJ" => &[Language { code: "tg", alpha3: "tgk", name: "Tajik", status: LanguageStatus::Official, speakers: 8873670 }, Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Unofficial, speakers: 1064840 }],
+    "TK" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 1285 }, Language { code: "tkl", alpha3: "tkl", name: "Tokelau", status: LanguageStatus::Official, speakers: 1285 }],
+    "TL" => &[Language { code: "pt", alpha3: "por", name: "Portuguese", status: LanguageStatus::Official, speakers: 816395 }, Language { code: "tet", alpha3: "tet", name: "Tetum", status: LanguageStatus::Official, speakers: 816395 }],
+    "TM" => &[Language { code: "tk", alpha3: "tuk", name: "Turkmen", status: LanguageStatus::Official, speakers: 3870041 }, Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Unofficial, speakers: 663436 }],
+    "TN" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 10549080 }, Language { code: "aeb", alpha3: "aeb", name: "Tunisian Arabic", status: LanguageStatus::Unofficial, speakers: 10549080 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 8673688 }],
+    "TO" => &[Language { code: "to", alpha3: "ton", name: "Tonga", status: LanguageStatus::Official, speakers: 100790 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 29707 }],
+    "TR" => &[Language { code: "tr", alpha3: "tur", name: "Turkish", status: LanguageStatus::Official, speakers: 76276275 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Unofficial, speakers: 13942975 }],
+    "TT" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 1063735 }],
+    "TV" => &[Language { code: "tvl", alpha3: "tvl", name: "Tuvalu", status: LanguageStatus::Official, speakers: 9868 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 1066 }],
+    "TW" => &[Language { code: "zh", alpha3: "zho", name: "Chinese", status: LanguageStatus::Official, speakers: 22422850 }],
+    "TZ" => &[Language { code: "sw", alpha3: "swa", name: "Swahili", status: LanguageStatus::Official, speakers: 52697520 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 40401432 }],
+    "UA" => &[Language { code: "uk", alpha3: "ukr", name: "Ukrainian", status: LanguageStatus::Official, speakers: 28549885 }, Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::DeFactoOfficial, speakers: 20204534 }],
+    "UG" => &[Language { code: "sw", alpha3: "swa", name: "Swahili", status: LanguageStatus::Official, speakers: 32439750 }, Language { code: "lg", alpha3: "lug", name: "Ganda", status: LanguageStatus::Unofficial, speakers: 5622890 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 1686867 }],
+    "UM" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::DeFactoOfficial, speakers: 316 }],
+    "US" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::DeFactoOfficial, speakers: 319333440 }, Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::OfficialRegional, speakers: 31933344 }, Language { code: "haw", alpha3: "haw", name: "Hawaiian", status: LanguageStatus::OfficialRegional, speakers: 29605 }],
+    "UY" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 2981097 }],
+    "UZ" => &[Language { code: "uz", alpha3: "uzb", name: "Uzbek", status: LanguageStatus::Official, speakers: 25980590 }, Language { code: "ru", alpha3: "rus", name: "Russian", status: LanguageStatus::Unofficial, speakers: 4279156 }],
+    "VA" => &[Language { code: "it", alpha3: "ita", name: "Italian", status: LanguageStatus::DeFactoOfficial, speakers: 820 }, Language { code: "la", alpha3: "lat", name: "Latin", status: LanguageStatus::Unofficial, speakers: 820 }],
+    "VC" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 97334 }],
+    "VE" => &[Language { code: "es", alpha3: "spa", name: "Spanish", status: LanguageStatus::Official, speakers: 23488572 }],
+    "VG" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 36633 }],
+    "VI" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::DeFactoOfficial, speakers: 79676 }],
+    "VN" => &[Language { code: "vi", alpha3: "vie", name: "Vietnamese", status: LanguageStatus::Official, speakers: 84900318 }],
+    "VU" => &[Language { code: "bi", alpha3: "bis", name: "Bislama", status: LanguageStatus::Official, speakers: 268500 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 247616 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 149166 }],
+    "WF" => &[Language { code: "wls", alpha3: "wls", name: "Wallisian", status: LanguageStatus::Unofficial, speakers: 9512 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 7610 }, Language { code: "fud", alpha3: "fud", name: "East Futuna", status: LanguageStatus::Unofficial, speakers: 4756 }],
+    "WS" => &[Language { code: "sm", alpha3: "smo", name: "Samoan", status: LanguageStatus::Official, speakers: 203774 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 4279 }],
+    "YE" => &[Language { code: "ar", alpha3: "ara", name: "Arabic", status: LanguageStatus::Official, speakers: 22114456 }],
+    "YT" => &[Language { code: "swb", alpha3: "swb", name: "Maore Comorian", status: LanguageStatus::Unofficial, speakers: 170720 }, Language { code: "fr", alpha3: "fra", name: "French", status: LanguageStatus::Official, speakers: 110580 }, Language { code: "buc", alpha3: "buc", name: "Bushi", status: LanguageStatus::Unofficial, speakers: 44620 }],
+    "ZA" => &[Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 17503716 }, Language { code: "zu", alpha3: "zul", name: "Zulu", status: LanguageStatus::OfficialRegional, speakers: 13551264 }, Language { code: "xh", alpha3: "xho", name: "Xhosa", status: LanguageStatus::OfficialRegional, speakers: 10163448 }, Language { code: "af", alpha3: "afr", name: "Afrikaans", status: LanguageStatus::OfficialRegional, speakers: 7340268 }, Language { code: "nso", alpha3: "nso", name: "Pedi", status: LanguageStatus::OfficialRegional, speakers: 5307578 }, Language { code: "tn", alpha3: "tsn", name: "Tswana", status: LanguageStatus::OfficialRegional, speakers: 4630015 }, Language { code: "st", alpha3: "sot", name: "Southern Sotho", status: LanguageStatus::OfficialRegional, speakers: 4460624 }, Language { code: "ts", alpha3: "tso", name: "Tsonga", status: LanguageStatus::OfficialRegional, speakers: 2484398 }, Language { code: "ss", alpha3: "ssw", name: "Swati", status: LanguageStatus::OfficialRegional, speakers: 1524517 }, Language { code: "ve", alpha3: "ven", name: "Venda", status: LanguageStatus::OfficialRegional, speakers: 1298663 }, Language { code: "nr", alpha3: "nbl", name: "South Ndebele", status: LanguageStatus::OfficialRegional, speakers: 903418 }],
+    "ZM" => &[Language { code: "bem", alpha3: "bem", name: "Bemba", status: LanguageStatus::Unofficial, speakers: 5402246 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 2788256 }, Language { code: "ny", alpha3: "nya", name: "Nyanja", status: LanguageStatus::Unofficial, speakers: 2613990 }],
+    "ZW" => &[Language { code: "sn", alpha3: "sna", name: "Shona", status: LanguageStatus::Official, speakers: 11782503 }, Language { code: "en", alpha3: "eng", name: "English", status: LanguageStatus::Official, speakers: 6109446 }, Language { code: "nd", alpha3: "nde", name: "North Ndebele", status: LanguageStatus::Official, speakers: 1745556 }],
+};
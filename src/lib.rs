@@ -3,6 +3,48 @@ use phf::phf_map;
 use phf::Map;
 pub mod iso3166_2;
 pub mod iso3166_3;
+mod alias;
+mod bcp47;
+mod borders;
+mod cctld;
+pub mod crosswalk;
+#[cfg(feature = "currency")]
+pub mod currency;
+mod flag;
+mod geography;
+pub mod groups;
+mod language;
+pub mod locale;
+mod lookup;
+mod normalize;
+mod parse;
+mod phone;
+mod region;
+pub mod search;
+mod sovereignty;
+pub mod sports;
+mod status;
+mod timezone;
+mod vehicle;
+pub use alias::{from_alias, from_name, ALIAS_MAP};
+pub use bcp47::{from_accept_language, locale_region, LocaleRegion, DEPRECATED_REGION_MAP};
+pub use borders::{border_crossings, landmasses, LANDLOCKED, NEIGHBOR_MAP};
+pub use cctld::{from_hostname, IdnCcTld, CCTLD_MAP, COUNTRY_CCTLD_MAP, COUNTRY_IDN_MAP};
+pub use flag::{find_flags, flag_status};
+pub use geography::{
+    distance_km, BoundingBox, Capital, Coordinates, BOUNDING_BOX_MAP, CAPITAL_MAP, CENTROID_MAP,
+};
+pub use language::{countries_speaking, Language, LanguageStatus, COUNTRY_LANGUAGE_MAP};
+pub use lookup::{lookup, lookup_all, Code};
+pub use parse::ParseError;
+pub use phone::{from_phone_prefix, CALLING_CODE_MAP};
+pub use region::{Region, RegionLevel, COUNTRY_REGION_MAP, REGION_MAP};
+pub use sovereignty::{Dependency, Relationship, DEPENDENCY_MAP};
+pub use status::{code_status, CodeStatus, Reservation, RESERVED_MAP};
+pub use timezone::{
+    countries_for_zone, COUNTRY_TIME_ZONE_MAP, SUBDIVISION_TIME_ZONE_MAP, ZONE_COUNTRY_MAP,
+};
+pub use vehicle::{from_vehicle_sign, VehicleSign, COUNTRY_VEHICLE_SIGN_MAP, VEHICLE_SIGN_MAP};
 use std::hash::Hash;
 #[cfg(all(direct_wasm,target_arch = "wasm32"))]
 use wasm_bindgen::prelude::*;
@@ -49,6 +91,10 @@ pub struct CountryCode {
     alpha3: &'static str,
     ///Numeric code
     numeric: u16,
+    ///English full name, if ISO 3166-1 gives one
+    official_name: Option<&'static str>,
+    ///French short name
+    name_fr: &'static str,
 }
 
 #[cfg(any(not(direct_wasm),not(target_arch = "wasm32")))]
@@ -62,6 +108,12 @@ pub struct CountryCode {
     pub alpha3: &'static str,
     ///Numeric code
     pub numeric: u16,
+    ///English full name from ISO 3166-1 without the leading "the", such as
+    ///"Commonwealth of Australia". `None` for territories that have none.
+    pub official_name: Option<&'static str>,
+    ///French short name from ISO 3166-1, such as "Bolivie (État plurinational
+    ///de)", without the article ISO adds in parentheses ("Allemagne (l')")
+    pub name_fr: &'static str,
 }
 
 #[cfg_attr(all(direct_wasm,target_arch = "wasm32"), wasm_bindgen)]
@@ -91,6 +143,18 @@ impl CountryCode {
         self.numeric
     }
 
+    #[cfg(all(direct_wasm,target_arch = "wasm32"))]
+    #[wasm_bindgen(getter)]
+    pub fn official_name(&self) -> Option<String> {
+        self.official_name.map(Into::into)
+    }
+
+    #[cfg(all(direct_wasm,target_arch = "wasm32"))]
+    #[wasm_bindgen(getter)]
+    pub fn name_fr(&self) -> String {
+        self.name_fr.into()
+    }
+
     ///Return len 3 String for CountryCode numeric
     pub fn numeric_str (&self) -> String {
         format!("{:03}", self.numeric)
@@ -139,9 +203,7 @@ impl<'de> Deserialize<'de> for CountryCode {
     {
         use serde::de::Error;
         let s = String::deserialize(deserializer)?;
-        let s_upper = s.to_uppercase();
-        from_alpha2(&s_upper)
-            .or_else(|| from_alpha3(&s_upper))
+        parse::with_upper::<3, _>(&s, |code| from_alpha2(code).or_else(|| from_alpha3(code)))
             .ok_or_else(|| D::Error::custom(format!("Invalid country code: {}", s)))
     }
 }
@@ -168,6 +230,30 @@ pub fn from_alpha3(alpha3: &str) -> Option<CountryCode> {
     ALPHA3_MAP.get(alpha3).cloned()
 }
 
+/// Returns the CountryCode with the given Alpha2 code, if exists, ignoring
+/// case and surrounding whitespace. Does not allocate.
+/// #Sample
+/// ```
+/// let country = rust_iso3166::from_alpha2_ignore_case(" au ");
+/// assert_eq!("AUS", country.unwrap().alpha3);
+/// ```
+#[cfg_attr(all(direct_wasm,target_arch = "wasm32"), wasm_bindgen)]
+pub fn from_alpha2_ignore_case(alpha2: &str) -> Option<CountryCode> {
+    parse::with_lenient::<2, _>(alpha2, |code| ALPHA2_MAP.get(code).cloned())
+}
+
+/// Returns the CountryCode with the given Alpha3 code, if exists, ignoring
+/// case and surrounding whitespace. Does not allocate.
+/// #Sample
+/// ```
+/// let country = rust_iso3166::from_alpha3_ignore_case("Aus ");
+/// assert_eq!("AU", country.unwrap().alpha2);
+/// ```
+#[cfg_attr(all(direct_wasm,target_arch = "wasm32"), wasm_bindgen)]
+pub fn from_alpha3_ignore_case(alpha3: &str) -> Option<CountryCode> {
+    parse::with_lenient::<3, _>(alpha3, |code| ALPHA3_MAP.get(code).cloned())
+}
+
 /// Returns the CountryCode with the given numeric , if exists.
 // #Sample
 /// ```
@@ -198,6 +284,8 @@ pub const AF: CountryCode = CountryCode {
     alpha2: "AF",
     alpha3: "AFG",
     numeric: 4,
+    official_name: Some("Islamic Republic of Afghanistan"),
+    name_fr: "Afghanistan",
 };
 
 
@@ -206,6 +294,8 @@ pub const AX: CountryCode = CountryCode {
     alpha2: "AX",
     alpha3: "ALA",
     numeric: 248,
+    official_name: None,
+    name_fr: "Åland (les Îles)",
 };
 
 
@@ -214,6 +304,8 @@ pub const AL: CountryCode = CountryCode {
     alpha2: "AL",
     alpha3: "ALB",
     numeric: 8,
+    official_name: Some("Republic of Albania"),
+    name_fr: "Albanie",
 };
 
 
@@ -222,6 +314,8 @@ pub const DZ: CountryCode = CountryCode {
     alpha2: "DZ",
     alpha3: "DZA",
     numeric: 12,
+    official_name: Some("People's Democratic Republic of Algeria"),
+    name_fr: "Algérie",
 };
 
 
@@ -230,6 +324,8 @@ pub const AS: CountryCode = CountryCode {
     alpha2: "AS",
     alpha3: "ASM",
     numeric: 16,
+    official_name: None,
+    name_fr: "Samoa américaines",
 };
 
 
@@ -238,6 +334,8 @@ pub const AD: CountryCode = CountryCode {
     alpha2: "AD",
     alpha3: "AND",
     numeric: 20,
+    official_name: Some("Principality of Andorra"),
+    name_fr: "Andorre",
 };
 
 
@@ -246,6 +344,8 @@ pub const AO: CountryCode = CountryCode {
     alpha2: "AO",
     alpha3: "AGO",
     numeric: 24,
+    official_name: Some("Republic of Angola"),
+    name_fr: "Angola",
 };
 
 
@@ -254,6 +354,8 @@ pub const AI: CountryCode = CountryCode {
     alpha2: "AI",
     alpha3: "AIA",
     numeric: 660,
+    official_name: None,
+    name_fr: "Anguilla",
 };
 
 
@@ -262,6 +364,8 @@ pub const AQ: CountryCode = CountryCode {
     alpha2: "AQ",
     alpha3: "ATA",
     numeric: 10,
+    official_name: None,
+    name_fr: "Antarctique",
 };
 
 
@@ -270,6 +374,8 @@ pub const AG: CountryCode = CountryCode {
     alpha2: "AG",
     alpha3: "ATG",
     numeric: 28,
+    official_name: Some("Antigua and Barbuda"),
+    name_fr: "Antigua-et-Barbuda",
 };
 
 
@@ -278,6 +384,8 @@ pub const AR: CountryCode = CountryCode {
     alpha2: "AR",
     alpha3: "ARG",
     numeric: 32,
+    official_name: Some("Argentine Republic"),
+    name_fr: "Argentine",
 };
 
 
@@ -286,6 +394,8 @@ pub const AM: CountryCode = CountryCode {
     alpha2: "AM",
     alpha3: "ARM",
     numeric: 51,
+    official_name: Some("Republic of Armenia"),
+    name_fr: "Arménie",
 };
 
 
@@ -294,6 +404,8 @@ pub const AW: CountryCode = CountryCode {
     alpha2: "AW",
     alpha3: "ABW",
     numeric: 533,
+    official_name: None,
+    name_fr: "Aruba",
 };
 
 
@@ -302,6 +414,8 @@ pub const AU: CountryCode = CountryCode {
     alpha2: "AU",
     alpha3: "AUS",
     numeric: 36,
+    official_name: Some("Commonwealth of Australia"),
+    name_fr: "Australie",
 };
 
 
@@ -310,6 +424,8 @@ pub const AT: CountryCode = CountryCode {
     alpha2: "AT",
     alpha3: "AUT",
     numeric: 40,
+    official_name: Some("Republic of Austria"),
+    name_fr: "Autriche",
 };
 
 
@@ -318,6 +434,8 @@ pub const AZ: CountryCode = CountryCode {
     alpha2: "AZ",
     alpha3: "AZE",
     numeric: 31,
+    official_name: Some("Republic of Azerbaijan"),
+    name_fr: "Azerbaïdjan",
 };
 
 
@@ -326,6 +444,8 @@ pub const BS: CountryCode = CountryCode {
     alpha2: "BS",
     alpha3: "BHS",
     numeric: 44,
+    official_name: Some("Commonwealth of the Bahamas"),
+    name_fr: "Bahamas",
 };
 
 
@@ -334,6 +454,8 @@ pub const BH: CountryCode = CountryCode {
     alpha2: "BH",
     alpha3: "BHR",
     numeric: 48,
+    official_name: Some("Kingdom of Bahrain"),
+    name_fr: "Bahreïn",
 };
 
 
@@ -342,6 +464,8 @@ pub const BD: CountryCode = CountryCode {
     alpha2: "BD",
     alpha3: "BGD",
     numeric: 50,
+    official_name: Some("People's Republic of Bangladesh"),
+    name_fr: "Bangladesh",
 };
 
 
@@ -350,6 +474,8 @@ pub const BB: CountryCode = CountryCode {
     alpha2: "BB",
     alpha3: "BRB",
     numeric: 52,
+    official_name: Some("Barbados"),
+    name_fr: "Barbade",
 };
 
 
@@ -358,6 +484,8 @@ pub const BY: CountryCode = CountryCode {
     alpha2: "BY",
     alpha3: "BLR",
     numeric: 112,
+    official_name: Some("Republic of Belarus"),
+    name_fr: "Bélarus",
 };
 
 
@@ -366,6 +494,8 @@ pub const BE: CountryCode = CountryCode {
     alpha2: "BE",
     alpha3: "BEL",
     numeric: 56,
+    official_name: Some("Kingdom of Belgium"),
+    name_fr: "Belgique",
 };
 
 
@@ -374,6 +504,8 @@ pub const BZ: CountryCode = CountryCode {
     alpha2: "BZ",
     alpha3: "BLZ",
     numeric: 84,
+    official_name: Some("Belize"),
+    name_fr: "Belize",
 };
 
 
@@ -382,6 +514,8 @@ pub const BJ: CountryCode = CountryCode {
     alpha2: "BJ",
     alpha3: "BEN",
     numeric: 204,
+    official_name: Some("Republic of Benin"),
+    name_fr: "Bénin",
 };
 
 
@@ -390,6 +524,8 @@ pub const BM: CountryCode = CountryCode {
     alpha2: "BM",
     alpha3: "BMU",
     numeric: 60,
+    official_name: None,
+    name_fr: "Bermudes",
 };
 
 
@@ -398,6 +534,8 @@ pub const BT: CountryCode = CountryCode {
     alpha2: "BT",
     alpha3: "BTN",
     numeric: 64,
+    official_name: Some("Kingdom of Bhutan"),
+    name_fr: "Bhoutan",
 };
 
 
@@ -406,6 +544,8 @@ pub const BO: CountryCode = CountryCode {
     alpha2: "BO",
     alpha3: "BOL",
     numeric: 68,
+    official_name: Some("Plurinational State of Bolivia"),
+    name_fr: "Bolivie (État plurinational de)",
 };
 
 
@@ -414,6 +554,8 @@ pub const BQ: CountryCode = CountryCode {
     alpha2: "BQ",
     alpha3: "BES",
     numeric: 535,
+    official_name: Some("Bonaire, Sint Eustatius and Saba"),
+    name_fr: "Bonaire, Saint-Eustache et Saba",
 };
 
 
@@ -422,6 +564,8 @@ pub const BA: CountryCode = CountryCode {
     alpha2: "BA",
     alpha3: "BIH",
     numeric: 70,
+    official_name: Some("Republic of Bosnia and Herzegovina"),
+    name_fr: "Bosnie-Herzégovine",
 };
 
 
@@ -430,6 +574,8 @@ pub const BW: CountryCode = CountryCode {
     alpha2: "BW",
     alpha3: "BWA",
     numeric: 72,
+    official_name: Some("Republic of Botswana"),
+    name_fr: "Botswana",
 };
 
 
@@ -438,6 +584,8 @@ pub const BV: CountryCode = CountryCode {
     alpha2: "BV",
     alpha3: "BVT",
     numeric: 74,
+    official_name: None,
+    name_fr: "Bouvet (l'Île)",
 };
 
 
@@ -446,6 +594,8 @@ pub const BR: CountryCode = CountryCode {
     alpha2: "BR",
     alpha3: "BRA",
     numeric: 76,
+    official_name: Some("Federative Republic of Brazil"),
+    name_fr: "Brésil",
 };
 
 
@@ -454,6 +604,8 @@ pub const IO: CountryCode = CountryCode {
     alpha2: "IO",
     alpha3: "IOT",
     numeric: 86,
+    official_name: None,
+    name_fr: "Indien (le Territoire britannique de l'océan)",
 };
 
 
@@ -462,6 +614,8 @@ pub const BN: CountryCode = CountryCode {
     alpha2: "BN",
     alpha3: "BRN",
     numeric: 96,
+    official_name: Some("Brunei Darussalam"),
+    name_fr: "Brunéi Darussalam",
 };
 
 
@@ -470,6 +624,8 @@ pub const BG: CountryCode = CountryCode {
     alpha2: "BG",
     alpha3: "BGR",
     numeric: 100,
+    official_name: Some("Republic of Bulgaria"),
+    name_fr: "Bulgarie",
 };
 
 
@@ -478,6 +634,8 @@ pub const BF: CountryCode = CountryCode {
     alpha2: "BF",
     alpha3: "BFA",
     numeric: 854,
+    official_name: Some("Burkina Faso"),
+    name_fr: "Burkina Faso",
 };
 
 
@@ -486,6 +644,8 @@ pub const BI: CountryCode = CountryCode {
     alpha2: "BI",
     alpha3: "BDI",
     numeric: 108,
+    official_name: Some("Republic of Burundi"),
+    name_fr: "Burundi",
 };
 
 
@@ -494,6 +654,8 @@ pub const CV: CountryCode = CountryCode {
     alpha2: "CV",
     alpha3: "CPV",
     numeric: 132,
+    official_name: Some("Republic of Cabo Verde"),
+    name_fr: "Cabo Verde",
 };
 
 
@@ -502,6 +664,8 @@ pub const KH: CountryCode = CountryCode {
     alpha2: "KH",
     alpha3: "KHM",
     numeric: 116,
+    official_name: Some("Kingdom of Cambodia"),
+    name_fr: "Cambodge",
 };
 
 
@@ -510,6 +674,8 @@ pub const CM: CountryCode = CountryCode {
     alpha2: "CM",
     alpha3: "CMR",
     numeric: 120,
+    official_name: Some("Republic of Cameroon"),
+    name_fr: "Cameroun",
 };
 
 
@@ -518,6 +684,8 @@ pub const CA: CountryCode = CountryCode {
     alpha2: "CA",
     alpha3: "CAN",
     numeric: 124,
+    official_name: Some("Canada"),
+    name_fr: "Canada",
 };
 
 
@@ -526,6 +694,8 @@ pub const KY: CountryCode = CountryCode {
     alpha2: "KY",
     alpha3: "CYM",
     numeric: 136,
+    official_name: None,
+    name_fr: "Caïmans (les Îles)",
 };
 
 
@@ -534,6 +704,8 @@ pub const CF: CountryCode = CountryCode {
     alpha2: "CF",
     alpha3: "CAF",
     numeric: 140,
+    official_name: Some("Central African Republic"),
+    name_fr: "République centrafricaine",
 };
 
 
@@ -542,6 +714,8 @@ pub const TD: CountryCode = CountryCode {
     alpha2: "TD",
     alpha3: "TCD",
     numeric: 148,
+    official_name: Some("Republic of Chad"),
+    name_fr: "Tchad",
 };
 
 
@@ -550,6 +724,8 @@ pub const CL: CountryCode = CountryCode {
     alpha2: "CL",
     alpha3: "CHL",
     numeric: 152,
+    official_name: Some("Republic of Chile"),
+    name_fr: "Chili",
 };
 
 
@@ -558,6 +734,8 @@ pub const CN: CountryCode = CountryCode {
     alpha2: "CN",
     alpha3: "CHN",
     numeric: 156,
+    official_name: Some("People's Republic of China"),
+    name_fr: "Chine",
 };
 
 
@@ -566,6 +744,8 @@ pub const CX: CountryCode = CountryCode {
     alpha2: "CX",
     alpha3: "CXR",
     numeric: 162,
+    official_name: None,
+    name_fr: "Christmas (l'Île)",
 };
 
 
@@ -574,6 +754,8 @@ pub const CC: CountryCode = CountryCode {
     alpha2: "CC",
     alpha3: "CCK",
     numeric: 166,
+    official_name: None,
+    name_fr: "Cocos (les Îles)/ Keeling (les Îles)",
 };
 
 
@@ -582,6 +764,8 @@ pub const CO: CountryCode = CountryCode {
     alpha2: "CO",
     alpha3: "COL",
     numeric: 170,
+    official_name: Some("Republic of Colombia"),
+    name_fr: "Colombie",
 };
 
 
@@ -590,6 +774,8 @@ pub const KM: CountryCode = CountryCode {
     alpha2: "KM",
     alpha3: "COM",
     numeric: 174,
+    official_name: Some("Union of the Comoros"),
+    name_fr: "Comores",
 };
 
 
@@ -598,6 +784,8 @@ pub const CG: CountryCode = CountryCode {
     alpha2: "CG",
     alpha3: "COG",
     numeric: 178,
+    official_name: Some("Republic of the Congo"),
+    name_fr: "Congo",
 };
 
 
@@ -606,6 +794,8 @@ pub const CD: CountryCode = CountryCode {
     alpha2: "CD",
     alpha3: "COD",
     numeric: 180,
+    official_name: Some("Democratic Republic of the Congo"),
+    name_fr: "Congo (la République démocratique du)",
 };
 
 
@@ -614,6 +804,8 @@ pub const CK: CountryCode = CountryCode {
     alpha2: "CK",
     alpha3: "COK",
     numeric: 184,
+    official_name: None,
+    name_fr: "Cook (les Îles)",
 };
 
 
@@ -622,6 +814,8 @@ pub const CR: CountryCode = CountryCode {
     alpha2: "CR",
     alpha3: "CRI",
     numeric: 188,
+    official_name: Some("Republic of Costa Rica"),
+    name_fr: "Costa Rica",
 };
 
 
@@ -630,6 +824,8 @@ pub const CI: CountryCode = CountryCode {
     alpha2: "CI",
     alpha3: "CIV",
     numeric: 384,
+    official_name: Some("Republic of Côte d'Ivoire"),
+    name_fr: "Côte d'Ivoire",
 };
 
 
@@ -638,6 +834,8 @@ pub const HR: CountryCode = CountryCode {
     alpha2: "HR",
     alpha3: "HRV",
     numeric: 191,
+    official_name: Some("Republic of Croatia"),
+    name_fr: "Croatie",
 };
 
 
@@ -646,6 +844,8 @@ pub const CU: CountryCode = CountryCode {
     alpha2: "CU",
     alpha3: "CUB",
     numeric: 192,
+    official_name: Some("Republic of Cuba"),
+    name_fr: "Cuba",
 };
 
 
@@ -654,6 +854,8 @@ pub const CW: CountryCode = CountryCode {
     alpha2: "CW",
     alpha3: "CUW",
     numeric: 531,
+    official_name: Some("Curaçao"),
+    name_fr: "Curaçao",
 };
 
 
@@ -662,6 +864,8 @@ pub const CY: CountryCode = CountryCode {
     alpha2: "CY",
     alpha3: "CYP",
     numeric: 196,
+    official_name: Some("Republic of Cyprus"),
+    name_fr: "Chypre",
 };
 
 
@@ -670,6 +874,8 @@ pub const CZ: CountryCode = CountryCode {
     alpha2: "CZ",
     alpha3: "CZE",
     numeric: 203,
+    official_name: Some("Czech Republic"),
+    name_fr: "Tchéquie",
 };
 
 
@@ -678,6 +884,8 @@ pub const DK: CountryCode = CountryCode {
     alpha2: "DK",
     alpha3: "DNK",
     numeric: 208,
+    official_name: Some("Kingdom of Denmark"),
+    name_fr: "Danemark",
 };
 
 
@@ -686,6 +894,8 @@ pub const DJ: CountryCode = CountryCode {
     alpha2: "DJ",
     alpha3: "DJI",
     numeric: 262,
+    official_name: Some("Republic of Djibouti"),
+    name_fr: "Djibouti",
 };
 
 
@@ -694,6 +904,8 @@ pub const DM: CountryCode = CountryCode {
     alpha2: "DM",
     alpha3: "DMA",
     numeric: 212,
+    official_name: Some("Commonwealth of Dominica"),
+    name_fr: "Dominique",
 };
 
 
@@ -702,6 +914,8 @@ pub const DO: CountryCode = CountryCode {
     alpha2: "DO",
     alpha3: "DOM",
     numeric: 214,
+    official_name: Some("Dominican Republic"),
+    name_fr: "dominicaine (la République)",
 };
 
 
@@ -710,6 +924,8 @@ pub const EC: CountryCode = CountryCode {
     alpha2: "EC",
     alpha3: "ECU",
     numeric: 218,
+    official_name: Some("Republic of Ecuador"),
+    name_fr: "Équateur",
 };
 
 
@@ -718,6 +934,8 @@ pub const EG: CountryCode = CountryCode {
     alpha2: "EG",
     alpha3: "EGY",
     numeric: 818,
+    official_name: Some("Arab Republic of Egypt"),
+    name_fr: "Égypte",
 };
 
 
@@ -726,6 +944,8 @@ pub const SV: CountryCode = CountryCode {
     alpha2: "SV",
     alpha3: "SLV",
     numeric: 222,
+    official_name: Some("Republic of El Salvador"),
+    name_fr: "El Salvador",
 };
 
 
@@ -734,6 +954,8 @@ pub const GQ: CountryCode = CountryCode {
     alpha2: "GQ",
     alpha3: "GNQ",
     numeric: 226,
+    official_name: Some("Republic of Equatorial Guinea"),
+    name_fr: "Guinée équatoriale",
 };
 
 
@@ -742,6 +964,8 @@ pub const ER: CountryCode = CountryCode {
     alpha2: "ER",
     alpha3: "ERI",
     numeric: 232,
+    official_name: Some("State of Eritrea"),
+    name_fr: "Érythrée",
 };
 
 
@@ -750,6 +974,8 @@ pub const EE: CountryCode = CountryCode {
     alpha2: "EE",
     alpha3: "EST",
     numeric: 233,
+    official_name: Some("Republic of Estonia"),
+    name_fr: "Estonie",
 };
 
 
@@ -758,6 +984,8 @@ pub const SZ: CountryCode = CountryCode {
     alpha2: "SZ",
     alpha3: "SWZ",
     numeric: 748,
+    official_name: Some("Kingdom of Eswatini"),
+    name_fr: "Eswatini",
 };
 
 
@@ -766,6 +994,8 @@ pub const ET: CountryCode = CountryCode {
     alpha2: "ET",
     alpha3: "ETH",
     numeric: 231,
+    official_name: Some("Federal Democratic Republic of Ethiopia"),
+    name_fr: "Éthiopie",
 };
 
 
@@ -774,6 +1004,8 @@ pub const FK: CountryCode = CountryCode {
     alpha2: "FK",
     alpha3: "FLK",
     numeric: 238,
+    official_name: None,
+    name_fr: "Falkland (les Îles)/Malouines (les Îles)",
 };
 
 
@@ -782,6 +1014,8 @@ pub const FO: CountryCode = CountryCode {
     alpha2: "FO",
     alpha3: "FRO",
     numeric: 234,
+    official_name: None,
+    name_fr: "Féroé (les Îles)",
 };
 
 
@@ -790,6 +1024,8 @@ pub const FJ: CountryCode = CountryCode {
     alpha2: "FJ",
     alpha3: "FJI",
     numeric: 242,
+    official_name: Some("Republic of Fiji"),
+    name_fr: "Fidji",
 };
 
 
@@ -798,6 +1034,8 @@ pub const FI: CountryCode = CountryCode {
     alpha2: "FI",
     alpha3: "FIN",
     numeric: 246,
+    official_name: Some("Republic of Finland"),
+    name_fr: "Finlande",
 };
 
 
@@ -806,6 +1044,8 @@ pub const FR: CountryCode = CountryCode {
     alpha2: "FR",
     alpha3: "FRA",
     numeric: 250,
+    official_name: Some("French Republic"),
+    name_fr: "France",
 };
 
 
@@ -814,6 +1054,8 @@ pub const GF: CountryCode = CountryCode {
     alpha2: "GF",
     alpha3: "GUF",
     numeric: 254,
+    official_name: None,
+    name_fr: "Guyane française",
 };
 
 
@@ -822,6 +1064,8 @@ pub const PF: CountryCode = CountryCode {
     alpha2: "PF",
     alpha3: "PYF",
     numeric: 258,
+    official_name: None,
+    name_fr: "Polynésie française",
 };
 
 
@@ -830,6 +1074,8 @@ pub const TF: CountryCode = CountryCode {
     alpha2: "TF",
     alpha3: "ATF",
     numeric: 260,
+    official_name: None,
+    name_fr: "Terres australes françaises",
 };
 
 
@@ -838,6 +1084,8 @@ pub const GA: CountryCode = CountryCode {
     alpha2: "GA",
     alpha3: "GAB",
     numeric: 266,
+    official_name: Some("Gabonese Republic"),
+    name_fr: "Gabon",
 };
 
 
@@ -846,6 +1094,8 @@ pub const GM: CountryCode = CountryCode {
     alpha2: "GM",
     alpha3: "GMB",
     numeric: 270,
+    official_name: Some("Republic of the Gambia"),
+    name_fr: "Gambie",
 };
 
 
@@ -854,6 +1104,8 @@ pub const GE: CountryCode = CountryCode {
     alpha2: "GE",
     alpha3: "GEO",
     numeric: 268,
+    official_name: Some("Georgia"),
+    name_fr: "Géorgie",
 };
 
 
@@ -862,6 +1114,8 @@ pub const DE: CountryCode = CountryCode {
     alpha2: "DE",
     alpha3: "DEU",
     numeric: 276,
+    official_name: Some("Federal Republic of Germany"),
+    name_fr: "Allemagne",
 };
 
 
@@ -870,6 +1124,8 @@ pub const GH: CountryCode = CountryCode {
     alpha2: "GH",
     alpha3: "GHA",
     numeric: 288,
+    official_name: Some("Republic of Ghana"),
+    name_fr: "Ghana",
 };
 
 
@@ -878,6 +1134,8 @@ pub const GI: CountryCode = CountryCode {
     alpha2: "GI",
     alpha3: "GIB",
     numeric: 292,
+    official_name: None,
+    name_fr: "Gibraltar",
 };
 
 
@@ -886,6 +1144,8 @@ pub const GR: CountryCode = CountryCode {
     alpha2: "GR",
     alpha3: "GRC",
     numeric: 300,
+    official_name: Some("Hellenic Republic"),
+    name_fr: "Grèce",
 };
 
 
@@ -894,6 +1154,8 @@ pub const GL: CountryCode = CountryCode {
     alpha2: "GL",
     alpha3: "GRL",
     numeric: 304,
+    official_name: None,
+    name_fr: "Groenland",
 };
 
 
@@ -902,6 +1164,8 @@ pub const GD: CountryCode = CountryCode {
     alpha2: "GD",
     alpha3: "GRD",
     numeric: 308,
+    official_name: Some("Grenada"),
+    name_fr: "Grenade",
 };
 
 
@@ -910,6 +1174,8 @@ pub const GP: CountryCode = CountryCode {
     alpha2: "GP",
     alpha3: "GLP",
     numeric: 312,
+    official_name: None,
+    name_fr: "Guadeloupe",
 };
 
 
@@ -918,6 +1184,8 @@ pub const GU: CountryCode = CountryCode {
     alpha2: "GU",
     alpha3: "GUM",
     numeric: 316,
+    official_name: None,
+    name_fr: "Guam",
 };
 
 
@@ -926,6 +1194,8 @@ pub const GT: CountryCode = CountryCode {
     alpha2: "GT",
     alpha3: "GTM",
     numeric: 320,
+    official_name: Some("Republic of Guatemala"),
+    name_fr: "Guatemala",
 };
 
 
@@ -934,6 +1204,8 @@ pub const GG: CountryCode = CountryCode {
     alpha2: "GG",
     alpha3: "GGY",
     numeric: 831,
+    official_name: None,
+    name_fr: "Guernesey",
 };
 
 
@@ -942,6 +1214,8 @@ pub const GN: CountryCode = CountryCode {
     alpha2: "GN",
     alpha3: "GIN",
     numeric: 324,
+    official_name: Some("Republic of Guinea"),
+    name_fr: "Guinée",
 };
 
 
@@ -950,6 +1224,8 @@ pub const GW: CountryCode = CountryCode {
     alpha2: "GW",
     alpha3: "GNB",
     numeric: 624,
+    official_name: Some("Republic of Guinea-Bissau"),
+    name_fr: "Guinée-Bissau",
 };
 
 
@@ -958,6 +1234,8 @@ pub const GY: CountryCode = CountryCode {
     alpha2: "GY",
     alpha3: "GUY",
     numeric: 328,
+    official_name: Some("Republic of Guyana"),
+    name_fr: "Guyana",
 };
 
 
@@ -966,6 +1244,8 @@ pub const HT: CountryCode = CountryCode {
     alpha2: "HT",
     alpha3: "HTI",
     numeric: 332,
+    official_name: Some("Republic of Haiti"),
+    name_fr: "Haïti",
 };
 
 
@@ -974,6 +1254,8 @@ pub const HM: CountryCode = CountryCode {
     alpha2: "HM",
     alpha3: "HMD",
     numeric: 334,
+    official_name: None,
+    name_fr: "Heard-et-Îles MacDonald (l'Île)",
 };
 
 
@@ -982,6 +1264,8 @@ pub const VA: CountryCode = CountryCode {
     alpha2: "VA",
     alpha3: "VAT",
     numeric: 336,
+    official_name: Some("Holy See"),
+    name_fr: "Saint-Siège",
 };
 
 
@@ -990,6 +1274,8 @@ pub const HN: CountryCode = CountryCode {
     alpha2: "HN",
     alpha3: "HND",
     numeric: 340,
+    official_name: Some("Republic of Honduras"),
+    name_fr: "Honduras",
 };
 
 
@@ -998,6 +1284,8 @@ pub const HK: CountryCode = CountryCode {
     alpha2: "HK",
     alpha3: "HKG",
     numeric: 344,
+    official_name: Some("Hong Kong Special Administrative Region of China"),
+    name_fr: "Hong Kong",
 };
 
 
@@ -1006,6 +1294,8 @@ pub const HU: CountryCode = CountryCode {
     alpha2: "HU",
     alpha3: "HUN",
     numeric: 348,
+    official_name: Some("Hungary"),
+    name_fr: "Hongrie",
 };
 
 
@@ -1014,6 +1304,8 @@ pub const IS: CountryCode = CountryCode {
     alpha2: "IS",
     alpha3: "ISL",
     numeric: 352,
+    official_name: Some("Republic of Iceland"),
+    name_fr: "Islande",
 };
 
 
@@ -1022,6 +1314,8 @@ pub const IN: CountryCode = CountryCode {
     alpha2: "IN",
     alpha3: "IND",
     numeric: 356,
+    official_name: Some("Republic of India"),
+    name_fr: "Inde",
 };
 
 
@@ -1030,6 +1324,8 @@ pub const ID: CountryCode = CountryCode {
     alpha2: "ID",
     alpha3: "IDN",
     numeric: 360,
+    official_name: Some("Republic of Indonesia"),
+    name_fr: "Indonésie",
 };
 
 
@@ -1038,6 +1334,8 @@ pub const IR: CountryCode = CountryCode {
     alpha2: "IR",
     alpha3: "IRN",
     numeric: 364,
+    official_name: Some("Islamic Republic of Iran"),
+    name_fr: "Iran (République Islamique d')",
 };
 
 
@@ -1046,6 +1344,8 @@ pub const IQ: CountryCode = CountryCode {
     alpha2: "IQ",
     alpha3: "IRQ",
     numeric: 368,
+    official_name: Some("Republic of Iraq"),
+    name_fr: "Iraq",
 };
 
 
@@ -1054,6 +1354,8 @@ pub const IE: CountryCode = CountryCode {
     alpha2: "IE",
     alpha3: "IRL",
     numeric: 372,
+    official_name: Some("Ireland"),
+    name_fr: "Irlande",
 };
 
 
@@ -1062,6 +1364,8 @@ pub const IM: CountryCode = CountryCode {
     alpha2: "IM",
     alpha3: "IMN",
     numeric: 833,
+    official_name: None,
+    name_fr: "Île de Man",
 };
 
 
@@ -1070,6 +1374,8 @@ pub const IL: CountryCode = CountryCode {
     alpha2: "IL",
     alpha3: "ISR",
     numeric: 376,
+    official_name: Some("State of Israel"),
+    name_fr: "Israël",
 };
 
 
@@ -1078,6 +1384,8 @@ pub const IT: CountryCode = CountryCode {
     alpha2: "IT",
     alpha3: "ITA",
     numeric: 380,
+    official_name: Some("Italian Republic"),
+    name_fr: "Italie",
 };
 
 
@@ -1086,6 +1394,8 @@ pub const JM: CountryCode = CountryCode {
     alpha2: "JM",
     alpha3: "JAM",
     numeric: 388,
+    official_name: Some("Jamaica"),
+    name_fr: "Jamaïque",
 };
 
 
@@ -1094,6 +1404,8 @@ pub const JP: CountryCode = CountryCode {
     alpha2: "JP",
     alpha3: "JPN",
     numeric: 392,
+    official_name: Some("Japan"),
+    name_fr: "Japon",
 };
 
 
@@ -1102,6 +1414,8 @@ pub const JE: CountryCode = CountryCode {
     alpha2: "JE",
     alpha3: "JEY",
     numeric: 832,
+    official_name: None,
+    name_fr: "Jersey",
 };
 
 
@@ -1110,6 +1424,8 @@ pub const JO: CountryCode = CountryCode {
     alpha2: "JO",
     alpha3: "JOR",
     numeric: 400,
+    official_name: Some("Hashemite Kingdom of Jordan"),
+    name_fr: "Jordanie",
 };
 
 
@@ -1118,6 +1434,8 @@ pub const KZ: CountryCode = CountryCode {
     alpha2: "KZ",
     alpha3: "KAZ",
     numeric: 398,
+    official_name: Some("Republic of Kazakhstan"),
+    name_fr: "Kazakhstan",
 };
 
 
@@ -1126,6 +1444,8 @@ pub const KE: CountryCode = CountryCode {
     alpha2: "KE",
     alpha3: "KEN",
     numeric: 404,
+    official_name: Some("Republic of Kenya"),
+    name_fr: "Kenya",
 };
 
 
@@ -1134,6 +1454,8 @@ pub const KI: CountryCode = CountryCode {
     alpha2: "KI",
     alpha3: "KIR",
     numeric: 296,
+    official_name: Some("Republic of Kiribati"),
+    name_fr: "Kiribati",
 };
 
 
@@ -1142,6 +1464,8 @@ pub const KP: CountryCode = CountryCode {
     alpha2: "KP",
     alpha3: "PRK",
     numeric: 408,
+    official_name: Some("Democratic People's Republic of Korea"),
+    name_fr: "Corée (la République populaire démocratique de)",
 };
 
 
@@ -1150,6 +1474,8 @@ pub const KR: CountryCode = CountryCode {
     alpha2: "KR",
     alpha3: "KOR",
     numeric: 410,
+    official_name: Some("Republic of Korea"),
+    name_fr: "Corée (la République de)",
 };
 
 
@@ -1158,6 +1484,8 @@ pub const KW: CountryCode = CountryCode {
     alpha2: "KW",
     alpha3: "KWT",
     numeric: 414,
+    official_name: Some("State of Kuwait"),
+    name_fr: "Koweït",
 };
 
 
@@ -1166,6 +1494,8 @@ pub const KG: CountryCode = CountryCode {
     alpha2: "KG",
     alpha3: "KGZ",
     numeric: 417,
+    official_name: Some("Kyrgyz Republic"),
+    name_fr: "Kirghizistan",
 };
 
 
@@ -1174,6 +1504,8 @@ pub const LA: CountryCode = CountryCode {
     alpha2: "LA",
     alpha3: "LAO",
     numeric: 418,
+    official_name: Some("Lao People's Democratic Republic"),
+    name_fr: "Lao (la République démocratique populaire)",
 };
 
 
@@ -1182,6 +1514,8 @@ pub const LV: CountryCode = CountryCode {
     alpha2: "LV",
     alpha3: "LVA",
     numeric: 428,
+    official_name: Some("Republic of Latvia"),
+    name_fr: "Lettonie",
 };
 
 
@@ -1190,6 +1524,8 @@ pub const LB: CountryCode = CountryCode {
     alpha2: "LB",
     alpha3: "LBN",
     numeric: 422,
+    official_name: Some("Lebanese Republic"),
+    name_fr: "Liban",
 };
 
 
@@ -1198,6 +1534,8 @@ pub const LS: CountryCode = CountryCode {
     alpha2: "LS",
     alpha3: "LSO",
     numeric: 426,
+    official_name: Some("Kingdom of Lesotho"),
+    name_fr: "Lesotho",
 };
 
 
@@ -1206,6 +1544,8 @@ pub const LR: CountryCode = CountryCode {
     alpha2: "LR",
     alpha3: "LBR",
     numeric: 430,
+    official_name: Some("Republic of Liberia"),
+    name_fr: "Libéria",
 };
 
 
@@ -1214,6 +1554,8 @@ pub const LY: CountryCode = CountryCode {
     alpha2: "LY",
     alpha3: "LBY",
     numeric: 434,
+    official_name: Some("State of Libya"),
+    name_fr: "Libye",
 };
 
 
@@ -1222,6 +1564,8 @@ pub const LI: CountryCode = CountryCode {
     alpha2: "LI",
     alpha3: "LIE",
     numeric: 438,
+    official_name: Some("Principality of Liechtenstein"),
+    name_fr: "Liechtenstein",
 };
 
 
@@ -1230,6 +1574,8 @@ pub const LT: CountryCode = CountryCode {
     alpha2: "LT",
     alpha3: "LTU",
     numeric: 440,
+    official_name: Some("Republic of Lithuania"),
+    name_fr: "Lituanie",
 };
 
 
@@ -1238,6 +1584,8 @@ pub const LU: CountryCode = CountryCode {
     alpha2: "LU",
     alpha3: "LUX",
     numeric: 442,
+    official_name: Some("Grand Duchy of Luxembourg"),
+    name_fr: "Luxembourg",
 };
 
 
@@ -1246,6 +1594,8 @@ pub const MO: CountryCode = CountryCode {
     alpha2: "MO",
     alpha3: "MAC",
     numeric: 446,
+    official_name: Some("Macao Special Administrative Region of China"),
+    name_fr: "Macao",
 };
 
 
@@ -1254,6 +1604,8 @@ pub const MG: CountryCode = CountryCode {
     alpha2: "MG",
     alpha3: "MDG",
     numeric: 450,
+    official_name: Some("Republic of Madagascar"),
+    name_fr: "Madagascar",
 };
 
 
@@ -1262,6 +1614,8 @@ pub const MW: CountryCode = CountryCode {
     alpha2: "MW",
     alpha3: "MWI",
     numeric: 454,
+    official_name: Some("Republic of Malawi"),
+    name_fr: "Malawi",
 };
 
 
@@ -1270,6 +1624,8 @@ pub const MY: CountryCode = CountryCode {
     alpha2: "MY",
     alpha3: "MYS",
     numeric: 458,
+    official_name: Some("Malaysia"),
+    name_fr: "Malaisie",
 };
 
 
@@ -1278,6 +1634,8 @@ pub const MV: CountryCode = CountryCode {
     alpha2: "MV",
     alpha3: "MDV",
     numeric: 462,
+    official_name: Some("Republic of Maldives"),
+    name_fr: "Maldives",
 };
 
 
@@ -1286,6 +1644,8 @@ pub const ML: CountryCode = CountryCode {
     alpha2: "ML",
     alpha3: "MLI",
     numeric: 466,
+    official_name: Some("Republic of Mali"),
+    name_fr: "Mali",
 };
 
 
@@ -1294,6 +1654,8 @@ pub const MT: CountryCode = CountryCode {
     alpha2: "MT",
     alpha3: "MLT",
     numeric: 470,
+    official_name: Some("Republic of Malta"),
+    name_fr: "Malte",
 };
 
 
@@ -1302,6 +1664,8 @@ pub const MH: CountryCode = CountryCode {
     alpha2: "MH",
     alpha3: "MHL",
     numeric: 584,
+    official_name: Some("Republic of the Marshall Islands"),
+    name_fr: "Marshall (les Îles)",
 };
 
 
@@ -1310,6 +1674,8 @@ pub const MQ: CountryCode = CountryCode {
     alpha2: "MQ",
     alpha3: "MTQ",
     numeric: 474,
+    official_name: None,
+    name_fr: "Martinique",
 };
 
 
@@ -1318,6 +1684,8 @@ pub const MR: CountryCode = CountryCode {
     alpha2: "MR",
     alpha3: "MRT",
     numeric: 478,
+    official_name: Some("Islamic Republic of Mauritania"),
+    name_fr: "Mauritanie",
 };
 
 
@@ -1326,6 +1694,8 @@ pub const MU: CountryCode = CountryCode {
     alpha2: "MU",
     alpha3: "MUS",
     numeric: 480,
+    official_name: Some("Republic of Mauritius"),
+    name_fr: "Maurice",
 };
 
 
@@ -1334,6 +1704,8 @@ pub const YT: CountryCode = CountryCode {
     alpha2: "YT",
     alpha3: "MYT",
     numeric: 175,
+    official_name: None,
+    name_fr: "Mayotte",
 };
 
 
@@ -1342,6 +1714,8 @@ pub const MX: CountryCode = CountryCode {
     alpha2: "MX",
     alpha3: "MEX",
     numeric: 484,
+    official_name: Some("United Mexican States"),
+    name_fr: "Mexique",
 };
 
 
@@ -1350,6 +1724,8 @@ pub const FM: CountryCode = CountryCode {
     alpha2: "FM",
     alpha3: "FSM",
     numeric: 583,
+    official_name: Some("Federated States of Micronesia"),
+    name_fr: "Micronésie (États fédérés de)",
 };
 
 
@@ -1358,6 +1734,8 @@ pub const MD: CountryCode = CountryCode {
     alpha2: "MD",
     alpha3: "MDA",
     numeric: 498,
+    official_name: Some("Republic of Moldova"),
+    name_fr: "Moldova (la République de)",
 };
 
 
@@ -1366,6 +1744,8 @@ pub const MC: CountryCode = CountryCode {
     alpha2: "MC",
     alpha3: "MCO",
     numeric: 492,
+    official_name: Some("Principality of Monaco"),
+    name_fr: "Monaco",
 };
 
 
@@ -1374,6 +1754,8 @@ pub const MN: CountryCode = CountryCode {
     alpha2: "MN",
     alpha3: "MNG",
     numeric: 496,
+    official_name: Some("Mongolia"),
+    name_fr: "Mongolie",
 };
 
 
@@ -1382,6 +1764,8 @@ pub const ME: CountryCode = CountryCode {
     alpha2: "ME",
     alpha3: "MNE",
     numeric: 499,
+    official_name: Some("Montenegro"),
+    name_fr: "Monténégro",
 };
 
 
@@ -1390,6 +1774,8 @@ pub const MS: CountryCode = CountryCode {
     alpha2: "MS",
     alpha3: "MSR",
     numeric: 500,
+    official_name: None,
+    name_fr: "Montserrat",
 };
 
 
@@ -1398,6 +1784,8 @@ pub const MA: CountryCode = CountryCode {
     alpha2: "MA",
     alpha3: "MAR",
     numeric: 504,
+    official_name: Some("Kingdom of Morocco"),
+    name_fr: "Maroc",
 };
 
 
@@ -1406,6 +1794,8 @@ pub const MZ: CountryCode = CountryCode {
     alpha2: "MZ",
     alpha3: "MOZ",
     numeric: 508,
+    official_name: Some("Republic of Mozambique"),
+    name_fr: "Mozambique",
 };
 
 
@@ -1414,6 +1804,8 @@ pub const MM: CountryCode = CountryCode {
     alpha2: "MM",
     alpha3: "MMR",
     numeric: 104,
+    official_name: Some("Republic of the Union of Myanmar"),
+    name_fr: "Myanmar",
 };
 
 
@@ -1422,6 +1814,8 @@ pub const NA: CountryCode = CountryCode {
     alpha2: "NA",
     alpha3: "NAM",
     numeric: 516,
+    official_name: Some("Republic of Namibia"),
+    name_fr: "Namibie",
 };
 
 
@@ -1430,6 +1824,8 @@ pub const NR: CountryCode = CountryCode {
     alpha2: "NR",
     alpha3: "NRU",
     numeric: 520,
+    official_name: Some("Republic of Nauru"),
+    name_fr: "Nauru",
 };
 
 
@@ -1438,6 +1834,8 @@ pub const NP: CountryCode = CountryCode {
     alpha2: "NP",
     alpha3: "NPL",
     numeric: 524,
+    official_name: Some("Federal Democratic Republic of Nepal"),
+    name_fr: "Népal",
 };
 
 
@@ -1446,6 +1844,8 @@ pub const NL: CountryCode = CountryCode {
     alpha2: "NL",
     alpha3: "NLD",
     numeric: 528,
+    official_name: Some("Kingdom of the Netherlands"),
+    name_fr: "Pays-Bas (Royaume des)",
 };
 
 
@@ -1454,6 +1854,8 @@ pub const NC: CountryCode = CountryCode {
     alpha2: "NC",
     alpha3: "NCL",
     numeric: 540,
+    official_name: None,
+    name_fr: "Nouvelle-Calédonie",
 };
 
 
@@ -1462,6 +1864,8 @@ pub const NZ: CountryCode = CountryCode {
     alpha2: "NZ",
     alpha3: "NZL",
     numeric: 554,
+    official_name: Some("New Zealand"),
+    name_fr: "Nouvelle-Zélande",
 };
 
 
@@ -1470,6 +1874,8 @@ pub const NI: CountryCode = CountryCode {
     alpha2: "NI",
     alpha3: "NIC",
     numeric: 558,
+    official_name: Some("Republic of Nicaragua"),
+    name_fr: "Nicaragua",
 };
 
 
@@ -1478,6 +1884,8 @@ pub const NE: CountryCode = CountryCode {
     alpha2: "NE",
     alpha3: "NER",
     numeric: 562,
+    official_name: Some("Republic of the Niger"),
+    name_fr: "Niger",
 };
 
 
@@ -1486,6 +1894,8 @@ pub const NG: CountryCode = CountryCode {
     alpha2: "NG",
     alpha3: "NGA",
     numeric: 566,
+    official_name: Some("Federal Republic of Nigeria"),
+    name_fr: "Nigéria",
 };
 
 
@@ -1494,6 +1904,8 @@ pub const NU: CountryCode = CountryCode {
     alpha2: "NU",
     alpha3: "NIU",
     numeric: 570,
+    official_name: Some("Niue"),
+    name_fr: "Niue",
 };
 
 
@@ -1502,6 +1914,8 @@ pub const NF: CountryCode = CountryCode {
     alpha2: "NF",
     alpha3: "NFK",
     numeric: 574,
+    official_name: None,
+    name_fr: "Norfolk (l'Île)",
 };
 
 
@@ -1510,6 +1924,8 @@ pub const MK: CountryCode = CountryCode {
     alpha2: "MK",
     alpha3: "MKD",
     numeric: 807,
+    official_name: Some("Republic of North Macedonia"),
+    name_fr: "Macédoine du Nord",
 };
 
 
@@ -1518,6 +1934,8 @@ pub const MP: CountryCode = CountryCode {
     alpha2: "MP",
     alpha3: "MNP",
     numeric: 580,
+    official_name: Some("Commonwealth of the Northern Mariana Islands"),
+    name_fr: "Mariannes du Nord (les Îles)",
 };
 
 
@@ -1526,6 +1944,8 @@ pub const NO: CountryCode = CountryCode {
     alpha2: "NO",
     alpha3: "NOR",
     numeric: 578,
+    official_name: Some("Kingdom of Norway"),
+    name_fr: "Norvège",
 };
 
 
@@ -1534,6 +1954,8 @@ pub const OM: CountryCode = CountryCode {
     alpha2: "OM",
     alpha3: "OMN",
     numeric: 512,
+    official_name: Some("Sultanate of Oman"),
+    name_fr: "Oman",
 };
 
 
@@ -1542,6 +1964,8 @@ pub const PK: CountryCode = CountryCode {
     alpha2: "PK",
     alpha3: "PAK",
     numeric: 586,
+    official_name: Some("Islamic Republic of Pakistan"),
+    name_fr: "Pakistan",
 };
 
 
@@ -1550,6 +1974,8 @@ pub const PW: CountryCode = CountryCode {
     alpha2: "PW",
     alpha3: "PLW",
     numeric: 585,
+    official_name: Some("Republic of Palau"),
+    name_fr: "Palaos",
 };
 
 
@@ -1558,6 +1984,8 @@ pub const PS: CountryCode = CountryCode {
     alpha2: "PS",
     alpha3: "PSE",
     numeric: 275,
+    official_name: Some("State of Palestine"),
+    name_fr: "Palestine, État de",
 };
 
 
@@ -1566,6 +1994,8 @@ pub const PA: CountryCode = CountryCode {
     alpha2: "PA",
     alpha3: "PAN",
     numeric: 591,
+    official_name: Some("Republic of Panama"),
+    name_fr: "Panama",
 };
 
 
@@ -1574,6 +2004,8 @@ pub const PG: CountryCode = CountryCode {
     alpha2: "PG",
     alpha3: "PNG",
     numeric: 598,
+    official_name: Some("Independent State of Papua New Guinea"),
+    name_fr: "Papouasie-Nouvelle-Guinée",
 };
 
 
@@ -1582,6 +2014,8 @@ pub const PY: CountryCode = CountryCode {
     alpha2: "PY",
     alpha3: "PRY",
     numeric: 600,
+    official_name: Some("Republic of Paraguay"),
+    name_fr: "Paraguay",
 };
 
 
@@ -1590,6 +2024,8 @@ pub const PE: CountryCode = CountryCode {
     alpha2: "PE",
     alpha3: "PER",
     numeric: 604,
+    official_name: Some("Republic of Peru"),
+    name_fr: "Pérou",
 };
 
 
@@ -1598,6 +2034,8 @@ pub const PH: CountryCode = CountryCode {
     alpha2: "PH",
     alpha3: "PHL",
     numeric: 608,
+    official_name: Some("Republic of the Philippines"),
+    name_fr: "Philippines",
 };
 
 
@@ -1606,6 +2044,8 @@ pub const PN: CountryCode = CountryCode {
     alpha2: "PN",
     alpha3: "PCN",
     numeric: 612,
+    official_name: None,
+    name_fr: "Pitcairn",
 };
 
 
@@ -1614,6 +2054,8 @@ pub const PL: CountryCode = CountryCode {
     alpha2: "PL",
     alpha3: "POL",
     numeric: 616,
+    official_name: Some("Republic of Poland"),
+    name_fr: "Pologne",
 };
 
 
@@ -1622,6 +2064,8 @@ pub const PT: CountryCode = CountryCode {
     alpha2: "PT",
     alpha3: "PRT",
     numeric: 620,
+    official_name: Some("Portuguese Republic"),
+    name_fr: "Portugal",
 };
 
 
@@ -1630,6 +2074,8 @@ pub const PR: CountryCode = CountryCode {
     alpha2: "PR",
     alpha3: "PRI",
     numeric: 630,
+    official_name: None,
+    name_fr: "Porto Rico",
 };
 
 
@@ -1638,6 +2084,8 @@ pub const QA: CountryCode = CountryCode {
     alpha2: "QA",
     alpha3: "QAT",
     numeric: 634,
+    official_name: Some("State of Qatar"),
+    name_fr: "Qatar",
 };
 
 
@@ -1646,6 +2094,8 @@ pub const RE: CountryCode = CountryCode {
     alpha2: "RE",
     alpha3: "REU",
     numeric: 638,
+    official_name: None,
+    name_fr: "Réunion",
 };
 
 
@@ -1654,6 +2104,8 @@ pub const RO: CountryCode = CountryCode {
     alpha2: "RO",
     alpha3: "ROU",
     numeric: 642,
+    official_name: Some("Romania"),
+    name_fr: "Roumanie",
 };
 
 
@@ -1662,6 +2114,8 @@ pub const RU: CountryCode = CountryCode {
     alpha2: "RU",
     alpha3: "RUS",
     numeric: 643,
+    official_name: Some("Russian Federation"),
+    name_fr: "Russie (la Fédération de)",
 };
 
 
@@ -1670,6 +2124,8 @@ pub const RW: CountryCode = CountryCode {
     alpha2: "RW",
     alpha3: "RWA",
     numeric: 646,
+    official_name: Some("Rwandese Republic"),
+    name_fr: "Rwanda",
 };
 
 
@@ -1678,6 +2134,8 @@ pub const BL: CountryCode = CountryCode {
     alpha2: "BL",
     alpha3: "BLM",
     numeric: 652,
+    official_name: None,
+    name_fr: "Saint-Barthélemy",
 };
 
 
@@ -1686,6 +2144,8 @@ pub const SH: CountryCode = CountryCode {
     alpha2: "SH",
     alpha3: "SHN",
     numeric: 654,
+    official_name: None,
+    name_fr: "Sainte-Hélène, Ascension et Tristan da Cunha",
 };
 
 
@@ -1694,6 +2154,8 @@ pub const KN: CountryCode = CountryCode {
     alpha2: "KN",
     alpha3: "KNA",
     numeric: 659,
+    official_name: Some("Saint Kitts and Nevis"),
+    name_fr: "Saint-Kitts-et-Nevis",
 };
 
 
@@ -1702,6 +2164,8 @@ pub const LC: CountryCode = CountryCode {
     alpha2: "LC",
     alpha3: "LCA",
     numeric: 662,
+    official_name: Some("Saint Lucia"),
+    name_fr: "Sainte-Lucie",
 };
 
 
@@ -1710,6 +2174,8 @@ pub const MF: CountryCode = CountryCode {
     alpha2: "MF",
     alpha3: "MAF",
     numeric: 663,
+    official_name: None,
+    name_fr: "Saint-Martin (partie française)",
 };
 
 
@@ -1718,6 +2184,8 @@ pub const PM: CountryCode = CountryCode {
     alpha2: "PM",
     alpha3: "SPM",
     numeric: 666,
+    official_name: None,
+    name_fr: "Saint-Pierre-et-Miquelon",
 };
 
 
@@ -1726,6 +2194,8 @@ pub const VC: CountryCode = CountryCode {
     alpha2: "VC",
     alpha3: "VCT",
     numeric: 670,
+    official_name: Some("Saint Vincent and the Grenadines"),
+    name_fr: "Saint-Vincent-et-les Grenadines",
 };
 
 
@@ -1734,6 +2204,8 @@ pub const WS: CountryCode = CountryCode {
     alpha2: "WS",
     alpha3: "WSM",
     numeric: 882,
+    official_name: Some("Independent State of Samoa"),
+    name_fr: "Samoa",
 };
 
 
@@ -1742,6 +2214,8 @@ pub const SM: CountryCode = CountryCode {
     alpha2: "SM",
     alpha3: "SMR",
     numeric: 674,
+    official_name: Some("Republic of San Marino"),
+    name_fr: "Saint-Marin",
 };
 
 
@@ -1750,6 +2224,8 @@ pub const ST: CountryCode = CountryCode {
     alpha2: "ST",
     alpha3: "STP",
     numeric: 678,
+    official_name: Some("Democratic Republic of Sao Tome and Principe"),
+    name_fr: "Sao Tomé-et-Principe",
 };
 
 
@@ -1758,6 +2234,8 @@ pub const SA: CountryCode = CountryCode {
     alpha2: "SA",
     alpha3: "SAU",
     numeric: 682,
+    official_name: Some("Kingdom of Saudi Arabia"),
+    name_fr: "Arabie saoudite",
 };
 
 
@@ -1766,6 +2244,8 @@ pub const SN: CountryCode = CountryCode {
     alpha2: "SN",
     alpha3: "SEN",
     numeric: 686,
+    official_name: Some("Republic of Senegal"),
+    name_fr: "Sénégal",
 };
 
 
@@ -1774,6 +2254,8 @@ pub const RS: CountryCode = CountryCode {
     alpha2: "RS",
     alpha3: "SRB",
     numeric: 688,
+    official_name: Some("Republic of Serbia"),
+    name_fr: "Serbie",
 };
 
 
@@ -1782,6 +2264,8 @@ pub const SC: CountryCode = CountryCode {
     alpha2: "SC",
     alpha3: "SYC",
     numeric: 690,
+    official_name: Some("Republic of Seychelles"),
+    name_fr: "Seychelles",
 };
 
 
@@ -1790,6 +2274,8 @@ pub const SL: CountryCode = CountryCode {
     alpha2: "SL",
     alpha3: "SLE",
     numeric: 694,
+    official_name: Some("Republic of Sierra Leone"),
+    name_fr: "Sierra Leone",
 };
 
 
@@ -1798,6 +2284,8 @@ pub const SG: CountryCode = CountryCode {
     alpha2: "SG",
     alpha3: "SGP",
     numeric: 702,
+    official_name: Some("Republic of Singapore"),
+    name_fr: "Singapour",
 };
 
 
@@ -1806,6 +2294,8 @@ pub const SX: CountryCode = CountryCode {
     alpha2: "SX",
     alpha3: "SXM",
     numeric: 534,
+    official_name: Some("Sint Maarten (Dutch part)"),
+    name_fr: "Saint-Martin (partie néerlandaise)",
 };
 
 
@@ -1814,6 +2304,8 @@ pub const SK: CountryCode = CountryCode {
     alpha2: "SK",
     alpha3: "SVK",
     numeric: 703,
+    official_name: Some("Slovak Republic"),
+    name_fr: "Slovaquie",
 };
 
 
@@ -1822,6 +2314,8 @@ pub const SI: CountryCode = CountryCode {
     alpha2: "SI",
     alpha3: "SVN",
     numeric: 705,
+    official_name: Some("Republic of Slovenia"),
+    name_fr: "Slovénie",
 };
 
 
@@ -1830,6 +2324,8 @@ pub const SB: CountryCode = CountryCode {
     alpha2: "SB",
     alpha3: "SLB",
     numeric: 90,
+    official_name: Some("Solomon Islands"),
+    name_fr: "Salomon (les Îles)",
 };
 
 
@@ -1838,6 +2334,8 @@ pub const SO: CountryCode = CountryCode {
     alpha2: "SO",
     alpha3: "SOM",
     numeric: 706,
+    official_name: Some("Federal Republic of Somalia"),
+    name_fr: "Somalie",
 };
 
 
@@ -1846,6 +2344,8 @@ pub const ZA: CountryCode = CountryCode {
     alpha2: "ZA",
     alpha3: "ZAF",
     numeric: 710,
+    official_name: Some("Republic of South Africa"),
+    name_fr: "Afrique du Sud",
 };
 
 
@@ -1854,6 +2354,8 @@ pub const GS: CountryCode = CountryCode {
     alpha2: "GS",
     alpha3: "SGS",
     numeric: 239,
+    official_name: None,
+    name_fr: "Géorgie du Sud-et-les Îles Sandwich du Sud",
 };
 
 
@@ -1862,6 +2364,8 @@ pub const SS: CountryCode = CountryCode {
     alpha2: "SS",
     alpha3: "SSD",
     numeric: 728,
+    official_name: Some("Republic of South Sudan"),
+    name_fr: "Soudan du Sud",
 };
 
 
@@ -1870,6 +2374,8 @@ pub const ES: CountryCode = CountryCode {
     alpha2: "ES",
     alpha3: "ESP",
     numeric: 724,
+    official_name: Some("Kingdom of Spain"),
+    name_fr: "Espagne",
 };
 
 
@@ -1878,6 +2384,8 @@ pub const LK: CountryCode = CountryCode {
     alpha2: "LK",
     alpha3: "LKA",
     numeric: 144,
+    official_name: Some("Democratic Socialist Republic of Sri Lanka"),
+    name_fr: "Sri Lanka",
 };
 
 
@@ -1886,6 +2394,8 @@ pub const SD: CountryCode = CountryCode {
     alpha2: "SD",
     alpha3: "SDN",
     numeric: 729,
+    official_name: Some("Republic of the Sudan"),
+    name_fr: "Soudan",
 };
 
 
@@ -1894,6 +2404,8 @@ pub const SR: CountryCode = CountryCode {
     alpha2: "SR",
     alpha3: "SUR",
     numeric: 740,
+    official_name: Some("Republic of Suriname"),
+    name_fr: "Suriname",
 };
 
 
@@ -1902,6 +2414,8 @@ pub const SJ: CountryCode = CountryCode {
     alpha2: "SJ",
     alpha3: "SJM",
     numeric: 744,
+    official_name: None,
+    name_fr: "Svalbard et l'Île Jan Mayen",
 };
 
 
@@ -1910,6 +2424,8 @@ pub const SE: CountryCode = CountryCode {
     alpha2: "SE",
     alpha3: "SWE",
     numeric: 752,
+    official_name: Some("Kingdom of Sweden"),
+    name_fr: "Suède",
 };
 
 
@@ -1918,6 +2434,8 @@ pub const CH: CountryCode = CountryCode {
     alpha2: "CH",
     alpha3: "CHE",
     numeric: 756,
+    official_name: Some("Swiss Confederation"),
+    name_fr: "Suisse",
 };
 
 
@@ -1926,6 +2444,8 @@ pub const SY: CountryCode = CountryCode {
     alpha2: "SY",
     alpha3: "SYR",
     numeric: 760,
+    official_name: Some("Syrian Arab Republic"),
+    name_fr: "République arabe syrienne",
 };
 
 
@@ -1934,6 +2454,8 @@ pub const TW: CountryCode = CountryCode {
     alpha2: "TW",
     alpha3: "TWN",
     numeric: 158,
+    official_name: Some("Taiwan, Province of China"),
+    name_fr: "Taïwan (Province de Chine)",
 };
 
 
@@ -1942,6 +2464,8 @@ pub const TJ: CountryCode = CountryCode {
     alpha2: "TJ",
     alpha3: "TJK",
     numeric: 762,
+    official_name: Some("Republic of Tajikistan"),
+    name_fr: "Tadjikistan",
 };
 
 
@@ -1950,6 +2474,8 @@ pub const TZ: CountryCode = CountryCode {
     alpha2: "TZ",
     alpha3: "TZA",
     numeric: 834,
+    official_name: Some("United Republic of Tanzania"),
+    name_fr: "Tanzanie (la République-Unie de)",
 };
 
 
@@ -1958,6 +2484,8 @@ pub const TH: CountryCode = CountryCode {
     alpha2: "TH",
     alpha3: "THA",
     numeric: 764,
+    official_name: Some("Kingdom of Thailand"),
+    name_fr: "Thaïlande",
 };
 
 
@@ -1966,6 +2494,8 @@ pub const TL: CountryCode = CountryCode {
     alpha2: "TL",
     alpha3: "TLS",
     numeric: 626,
+    official_name: Some("Democratic Republic of Timor-Leste"),
+    name_fr: "Timor-Leste",
 };
 
 
@@ -1974,6 +2504,8 @@ pub const TG: CountryCode = CountryCode {
     alpha2: "TG",
     alpha3: "TGO",
     numeric: 768,
+    official_name: Some("Togolese Republic"),
+    name_fr: "Togo",
 };
 
 
@@ -1982,6 +2514,8 @@ pub const TK: CountryCode = CountryCode {
     alpha2: "TK",
     alpha3: "TKL",
     numeric: 772,
+    official_name: None,
+    name_fr: "Tokelau",
 };
 
 
@@ -1990,6 +2524,8 @@ pub const TO: CountryCode = CountryCode {
     alpha2: "TO",
     alpha3: "TON",
     numeric: 776,
+    official_name: Some("Kingdom of Tonga"),
+    name_fr: "Tonga",
 };
 
 
@@ -1998,6 +2534,8 @@ pub const TT: CountryCode = CountryCode {
     alpha2: "TT",
     alpha3: "TTO",
     numeric: 780,
+    official_name: Some("Republic of Trinidad and Tobago"),
+    name_fr: "Trinité-et-Tobago",
 };
 
 
@@ -2006,6 +2544,8 @@ pub const TN: CountryCode = CountryCode {
     alpha2: "TN",
     alpha3: "TUN",
     numeric: 788,
+    official_name: Some("Republic of Tunisia"),
+    name_fr: "Tunisie",
 };
 
 
@@ -2014,6 +2554,8 @@ pub const TR: CountryCode = CountryCode {
     alpha2: "TR",
     alpha3: "TUR",
     numeric: 792,
+    official_name: Some("Republic of Türkiye"),
+    name_fr: "Türkiye",
 };
 
 
@@ -2022,6 +2564,8 @@ pub const TM: CountryCode = CountryCode {
     alpha2: "TM",
     alpha3: "TKM",
     numeric: 795,
+    official_name: Some("Turkmenistan"),
+    name_fr: "Turkménistan",
 };
 
 
@@ -2030,6 +2574,8 @@ pub const TC: CountryCode = CountryCode {
     alpha2: "TC",
     alpha3: "TCA",
     numeric: 796,
+    official_name: None,
+    name_fr: "Turks-et-Caïcos (les Îles)",
 };
 
 
@@ -2038,6 +2584,8 @@ pub const TV: CountryCode = CountryCode {
     alpha2: "TV",
     alpha3: "TUV",
     numeric: 798,
+    official_name: Some("Tuvalu"),
+    name_fr: "Tuvalu",
 };
 
 
@@ -2046,6 +2594,8 @@ pub const UG: CountryCode = CountryCode {
     alpha2: "UG",
     alpha3: "UGA",
     numeric: 800,
+    official_name: Some("Republic of Uganda"),
+    name_fr: "Ouganda",
 };
 
 
@@ -2054,6 +2604,8 @@ pub const UA: CountryCode = CountryCode {
     alpha2: "UA",
     alpha3: "UKR",
     numeric: 804,
+    official_name: Some("Ukraine"),
+    name_fr: "Ukraine",
 };
 
 
@@ -2062,6 +2614,8 @@ pub const AE: CountryCode = CountryCode {
     alpha2: "AE",
     alpha3: "ARE",
     numeric: 784,
+    official_name: Some("United Arab Emirates"),
+    name_fr: "Émirats arabes unis",
 };
 
 
@@ -2070,6 +2624,8 @@ pub const GB: CountryCode = CountryCode {
     alpha2: "GB",
     alpha3: "GBR",
     numeric: 826,
+    official_name: Some("United Kingdom of Great Britain and Northern Ireland"),
+    name_fr: "Royaume-Uni de Grande-Bretagne et d'Irlande du Nord",
 };
 
 
@@ -2078,6 +2634,8 @@ pub const US: CountryCode = CountryCode {
     alpha2: "US",
     alpha3: "USA",
     numeric: 840,
+    official_name: Some("United States of America"),
+    name_fr: "États-Unis d'Amérique",
 };
 
 
@@ -2086,6 +2644,8 @@ pub const UM: CountryCode = CountryCode {
     alpha2: "UM",
     alpha3: "UMI",
     numeric: 581,
+    official_name: None,
+    name_fr: "Îles mineures éloignées des États-Unis",
 };
 
 
@@ -2094,6 +2654,8 @@ pub const UY: CountryCode = CountryCode {
     alpha2: "UY",
     alpha3: "URY",
     numeric: 858,
+    official_name: Some("Eastern Republic of Uruguay"),
+    name_fr: "Uruguay",
 };
 
 
@@ -2102,6 +2664,8 @@ pub const UZ: CountryCode = CountryCode {
     alpha2: "UZ",
     alpha3: "UZB",
     numeric: 860,
+    official_name: Some("Republic of Uzbekistan"),
+    name_fr: "Ouzbékistan",
 };
 
 
@@ -2110,6 +2674,8 @@ pub const VU: CountryCode = CountryCode {
     alpha2: "VU",
     alpha3: "VUT",
     numeric: 548,
+    official_name: Some("Republic of Vanuatu"),
+    name_fr: "Vanuatu",
 };
 
 
@@ -2118,6 +2684,8 @@ pub const VE: CountryCode = CountryCode {
     alpha2: "VE",
     alpha3: "VEN",
     numeric: 862,
+    official_name: Some("Bolivarian Republic of Venezuela"),
+    name_fr: "Venezuela (République bolivarienne du)",
 };
 
 
@@ -2126,6 +2694,8 @@ pub const VN: CountryCode = CountryCode {
     alpha2: "VN",
     alpha3: "VNM",
     numeric: 704,
+    official_name: Some("Socialist Republic of Viet Nam"),
+    name_fr: "Viet Nam",
 };
 
 
@@ -2134,6 +2704,8 @@ pub const VG: CountryCode = CountryCode {
     alpha2: "VG",
     alpha3: "VGB",
     numeric: 92,
+    official_name: Some("British Virgin Islands"),
+    name_fr: "Vierges britanniques (les Îles)",
 };
 
 
@@ -2142,6 +2714,8 @@ pub const VI: CountryCode = CountryCode {
     alpha2: "VI",
     alpha3: "VIR",
     numeric: 850,
+    official_name: Some("Virgin Islands of the United States"),
+    name_fr: "Vierges des États-Unis (les Îles)",
 };
 
 
@@ -2150,6 +2724,8 @@ pub const WF: CountryCode = CountryCode {
     alpha2: "WF",
     alpha3: "WLF",
     numeric: 876,
+    official_name: None,
+    name_fr: "Wallis-et-Futuna",
 };
 
 
@@ -2158,6 +2734,8 @@ pub const EH: CountryCode = CountryCode {
     alpha2: "EH",
     alpha3: "ESH",
     numeric: 732,
+    official_name: None,
+    name_fr: "Sahara occidental",
 };
 
 
@@ -2166,6 +2744,8 @@ pub const YE: CountryCode = CountryCode {
     alpha2: "YE",
     alpha3: "YEM",
     numeric: 887,
+    official_name: Some("Republic of Yemen"),
+    name_fr: "Yémen",
 };
 
 
@@ -2174,6 +2754,8 @@ pub const ZM: CountryCode = CountryCode {
     alpha2: "ZM",
     alpha3: "ZMB",
     numeric: 894,
+    official_name: Some("Republic of Zambia"),
+    name_fr: "Zambie",
 };
 
 
@@ -2182,6 +2764,8 @@ pub const ZW: CountryCode = CountryCode {
     alpha2: "ZW",
     alpha3: "ZWE",
     numeric: 716,
+    official_name: Some("Republic of Zimbabwe"),
+    name_fr: "Zimbabwe",
 };
 
 
@@ -4507,3 +5091,1131 @@ ZW,
 
 ];
 
+
+/// Every Country defined by ISO 3166-1, one variant per entry in `ALL`.
+///
+/// The discriminant is the ISO 3166-1 numeric code. Unlike comparing `alpha2`
+/// strings, a misspelt variant does not compile. The enum is `#[non_exhaustive]`
+/// so that new ISO assignments are not a breaking change; a `match` outside
+/// this crate needs a wildcard arm.
+/// #Sample
+/// ```
+/// use rust_iso3166::{Country, CountryCode};
+/// let country = Country::AU;
+/// assert_eq!(36, country as u16);
+/// assert_eq!("AUS", country.country_code().alpha3);
+/// let code: CountryCode = Country::US.into();
+/// assert_eq!(Ok(Country::US), Country::try_from(code));
+/// ```
+#[non_exhaustive]
+#[repr(u16)]
+#[derive(Debug, Ord, PartialOrd, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Country {
+
+
+    ///Afghanistan
+    AF = 4,
+    ///Åland Islands
+    AX = 248,
+    ///Albania
+    AL = 8,
+    ///Algeria
+    DZ = 12,
+    ///American Samoa
+    AS = 16,
+    ///Andorra
+    AD = 20,
+    ///Angola
+    AO = 24,
+    ///Anguilla
+    AI = 660,
+    ///Antarctica
+    AQ = 10,
+    ///Antigua and Barbuda
+    AG = 28,
+    ///Argentina
+    AR = 32,
+    ///Armenia
+    AM = 51,
+    ///Aruba
+    AW = 533,
+    ///Australia
+    AU = 36,
+    ///Austria
+    AT = 40,
+    ///Azerbaijan
+    AZ = 31,
+    ///Bahamas
+    BS = 44,
+    ///Bahrain
+    BH = 48,
+    ///Bangladesh
+    BD = 50,
+    ///Barbados
+    BB = 52,
+    ///Belarus
+    BY = 112,
+    ///Belgium
+    BE = 56,
+    ///Belize
+    BZ = 84,
+    ///Benin
+    BJ = 204,
+    ///Bermuda
+    BM = 60,
+    ///Bhutan
+    BT = 64,
+    ///Bolivia (Plurinational State of)
+    BO = 68,
+    ///Bonaire, Sint Eustatius and Saba
+    BQ = 535,
+    ///Bosnia and Herzegovina
+    BA = 70,
+    ///Botswana
+    BW = 72,
+    ///Bouvet Island
+    BV = 74,
+    ///Brazil
+    BR = 76,
+    ///British Indian Ocean Territory
+    IO = 86,
+    ///Brunei Darussalam
+    BN = 96,
+    ///Bulgaria
+    BG = 100,
+    ///Burkina Faso
+    BF = 854,
+    ///Burundi
+    BI = 108,
+    ///Cabo Verde
+    CV = 132,
+    ///Cambodia
+    KH = 116,
+    ///Cameroon
+    CM = 120,
+    ///Canada
+    CA = 124,
+    ///Cayman Islands
+    KY = 136,
+    ///Central African Republic
+    CF = 140,
+    ///Chad
+    TD = 148,
+    ///Chile
+    CL = 152,
+    ///China
+    CN = 156,
+    ///Christmas Island
+    CX = 162,
+    ///Cocos (Keeling) Islands
+    CC = 166,
+    ///Colombia
+    CO = 170,
+    ///Comoros
+    KM = 174,
+    ///Congo
+    CG = 178,
+    ///Congo (Democratic Republic of the)
+    CD = 180,
+    ///Cook Islands
+    CK = 184,
+    ///Costa Rica
+    CR = 188,
+    ///Côte d'Ivoire
+    CI = 384,
+    ///Croatia
+    HR = 191,
+    ///Cuba
+    CU = 192,
+    ///Curaçao
+    CW = 531,
+    ///Cyprus
+    CY = 196,
+    ///Czechia
+    CZ = 203,
+    ///Denmark
+    DK = 208,
+    ///Djibouti
+    DJ = 262,
+    ///Dominica
+    DM = 212,
+    ///Dominican Republic
+    DO = 214,
+    ///Ecuador
+    EC = 218,
+    ///Egypt
+    EG = 818,
+    ///El Salvador
+    SV = 222,
+    ///Equatorial Guinea
+    GQ = 226,
+    ///Eritrea
+    ER = 232,
+    ///Estonia
+    EE = 233,
+    ///Eswatini
+    SZ = 748,
+    ///Ethiopia
+    ET = 231,
+    ///Falkland Islands (Malvinas)
+    FK = 238,
+    ///Faroe Islands
+    FO = 234,
+    ///Fiji
+    FJ = 242,
+    ///Finland
+    FI = 246,
+    ///France
+    FR = 250,
+    ///French Guiana
+    GF = 254,
+    ///French Polynesia
+    PF = 258,
+    ///French Southern Territories
+    TF = 260,
+    ///Gabon
+    GA = 266,
+    ///Gambia
+    GM = 270,
+    ///Georgia
+    GE = 268,
+    ///Germany
+    DE = 276,
+    ///Ghana
+    GH = 288,
+    ///Gibraltar
+    GI = 292,
+    ///Greece
+    GR = 300,
+    ///Greenland
+    GL = 304,
+    ///Grenada
+    GD = 308,
+    ///Guadeloupe
+    GP = 312,
+    ///Guam
+    GU = 316,
+    ///Guatemala
+    GT = 320,
+    ///Guernsey
+    GG = 831,
+    ///Guinea
+    GN = 324,
+    ///Guinea-Bissau
+    GW = 624,
+    ///Guyana
+    GY = 328,
+    ///Haiti
+    HT = 332,
+    ///Heard Island and McDonald Islands
+    HM = 334,
+    ///Holy See
+    VA = 336,
+    ///Honduras
+    HN = 340,
+    ///Hong Kong
+    HK = 344,
+    ///Hungary
+    HU = 348,
+    ///Iceland
+    IS = 352,
+    ///India
+    IN = 356,
+    ///Indonesia
+    ID = 360,
+    ///Iran (Islamic Republic of)
+    IR = 364,
+    ///Iraq
+    IQ = 368,
+    ///Ireland
+    IE = 372,
+    ///Isle of Man
+    IM = 833,
+    ///Israel
+    IL = 376,
+    ///Italy
+    IT = 380,
+    ///Jamaica
+    JM = 388,
+    ///Japan
+    JP = 392,
+    ///Jersey
+    JE = 832,
+    ///Jordan
+    JO = 400,
+    ///Kazakhstan
+    KZ = 398,
+    ///Kenya
+    KE = 404,
+    ///Kiribati
+    KI = 296,
+    ///Korea (Democratic People's Republic of)
+    KP = 408,
+    ///Korea (Republic of)
+    KR = 410,
+    ///Kuwait
+    KW = 414,
+    ///Kyrgyzstan
+    KG = 417,
+    ///Lao People's Democratic Republic
+    LA = 418,
+    ///Latvia
+    LV = 428,
+    ///Lebanon
+    LB = 422,
+    ///Lesotho
+    LS = 426,
+    ///Liberia
+    LR = 430,
+    ///Libya
+    LY = 434,
+    ///Liechtenstein
+    LI = 438,
+    ///Lithuania
+    LT = 440,
+    ///Luxembourg
+    LU = 442,
+    ///Macao
+    MO = 446,
+    ///Madagascar
+    MG = 450,
+    ///Malawi
+    MW = 454,
+    ///Malaysia
+    MY = 458,
+    ///Maldives
+    MV = 462,
+    ///Mali
+    ML = 466,
+    ///Malta
+    MT = 470,
+    ///Marshall Islands
+    MH = 584,
+    ///Martinique
+    MQ = 474,
+    ///Mauritania
+    MR = 478,
+    ///Mauritius
+    MU = 480,
+    ///Mayotte
+    YT = 175,
+    ///Mexico
+    MX = 484,
+    ///Micronesia (Federated States of)
+    FM = 583,
+    ///Moldova (Republic of)
+    MD = 498,
+    ///Monaco
+    MC = 492,
+    ///Mongolia
+    MN = 496,
+    ///Montenegro
+    ME = 499,
+    ///Montserrat
+    MS = 500,
+    ///Morocco
+    MA = 504,
+    ///Mozambique
+    MZ = 508,
+    ///Myanmar
+    MM = 104,
+    ///Namibia
+    NA = 516,
+    ///Nauru
+    NR = 520,
+    ///Nepal
+    NP = 524,
+    ///Netherlands (Kingdom of the)
+    NL = 528,
+    ///New Caledonia
+    NC = 540,
+    ///New Zealand
+    NZ = 554,
+    ///Nicaragua
+    NI = 558,
+    ///Niger
+    NE = 562,
+    ///Nigeria
+    NG = 566,
+    ///Niue
+    NU = 570,
+    ///Norfolk Island
+    NF = 574,
+    ///North Macedonia
+    MK = 807,
+    ///Northern Mariana Islands
+    MP = 580,
+    ///Norway
+    NO = 578,
+    ///Oman
+    OM = 512,
+    ///Pakistan
+    PK = 586,
+    ///Palau
+    PW = 585,
+    ///Palestine (State of)
+    PS = 275,
+    ///Panama
+    PA = 591,
+    ///Papua New Guinea
+    PG = 598,
+    ///Paraguay
+    PY = 600,
+    ///Peru
+    PE = 604,
+    ///Philippines
+    PH = 608,
+    ///Pitcairn
+    PN = 612,
+    ///Poland
+    PL = 616,
+    ///Portugal
+    PT = 620,
+    ///Puerto Rico
+    PR = 630,
+    ///Qatar
+    QA = 634,
+    ///Réunion
+    RE = 638,
+    ///Romania
+    RO = 642,
+    ///Russian Federation
+    RU = 643,
+    ///Rwanda
+    RW = 646,
+    ///Saint Barthélemy
+    BL = 652,
+    ///Saint Helena, Ascension and Tristan da Cunha
+    SH = 654,
+    ///Saint Kitts and Nevis
+    KN = 659,
+    ///Saint Lucia
+    LC = 662,
+    ///Saint Martin (French part)
+    MF = 663,
+    ///Saint Pierre and Miquelon
+    PM = 666,
+    ///Saint Vincent and the Grenadines
+    VC = 670,
+    ///Samoa
+    WS = 882,
+    ///San Marino
+    SM = 674,
+    ///Sao Tome and Principe
+    ST = 678,
+    ///Saudi Arabia
+    SA = 682,
+    ///Senegal
+    SN = 686,
+    ///Serbia
+    RS = 688,
+    ///Seychelles
+    SC = 690,
+    ///Sierra Leone
+    SL = 694,
+    ///Singapore
+    SG = 702,
+    ///Sint Maarten (Dutch part)
+    SX = 534,
+    ///Slovakia
+    SK = 703,
+    ///Slovenia
+    SI = 705,
+    ///Solomon Islands
+    SB = 90,
+    ///Somalia
+    SO = 706,
+    ///South Africa
+    ZA = 710,
+    ///South Georgia and the South Sandwich Islands
+    GS = 239,
+    ///South Sudan
+    SS = 728,
+    ///Spain
+    ES = 724,
+    ///Sri Lanka
+    LK = 144,
+    ///Sudan
+    SD = 729,
+    ///Suriname
+    SR = 740,
+    ///Svalbard and Jan Mayen
+    SJ = 744,
+    ///Sweden
+    SE = 752,
+    ///Switzerland
+    CH = 756,
+    ///Syrian Arab Republic
+    SY = 760,
+    ///Taiwan (Province of China)
+    TW = 158,
+    ///Tajikistan
+    TJ = 762,
+    ///Tanzania (United Republic of)
+    TZ = 834,
+    ///Thailand
+    TH = 764,
+    ///Timor-Leste
+    TL = 626,
+    ///Togo
+    TG = 768,
+    ///Tokelau
+    TK = 772,
+    ///Tonga
+    TO = 776,
+    ///Trinidad and Tobago
+    TT = 780,
+    ///Tunisia
+    TN = 788,
+    ///Türkiye
+    TR = 792,
+    ///Turkmenistan
+    TM = 795,
+    ///Turks and Caicos Islands
+    TC = 796,
+    ///Tuvalu
+    TV = 798,
+    ///Uganda
+    UG = 800,
+    ///Ukraine
+    UA = 804,
+    ///United Arab Emirates
+    AE = 784,
+    ///United Kingdom of Great Britain and Northern Ireland
+    GB = 826,
+    ///United States of America
+    US = 840,
+    ///United States Minor Outlying Islands
+    UM = 581,
+    ///Uruguay
+    UY = 858,
+    ///Uzbekistan
+    UZ = 860,
+    ///Vanuatu
+    VU = 548,
+    ///Venezuela (Bolivarian Republic of)
+    VE = 862,
+    ///Viet Nam
+    VN = 704,
+    ///Virgin Islands (British)
+    VG = 92,
+    ///Virgin Islands (U.S.)
+    VI = 850,
+    ///Wallis and Futuna
+    WF = 876,
+    ///Western Sahara
+    EH = 732,
+    ///Yemen
+    YE = 887,
+    ///Zambia
+    ZM = 894,
+    ///Zimbabwe
+    ZW = 716,
+
+
+}
+
+impl Country {
+    ///Return the CountryCode data for this Country
+    pub const fn country_code(self) -> CountryCode {
+        match self {
+
+
+            Country::AF => AF,
+            Country::AX => AX,
+            Country::AL => AL,
+            Country::DZ => DZ,
+            Country::AS => AS,
+            Country::AD => AD,
+            Country::AO => AO,
+            Country::AI => AI,
+            Country::AQ => AQ,
+            Country::AG => AG,
+            Country::AR => AR,
+            Country::AM => AM,
+            Country::AW => AW,
+            Country::AU => AU,
+            Country::AT => AT,
+            Country::AZ => AZ,
+            Country::BS => BS,
+            Country::BH => BH,
+            Country::BD => BD,
+            Country::BB => BB,
+            Country::BY => BY,
+            Country::BE => BE,
+            Country::BZ => BZ,
+            Country::BJ => BJ,
+            Country::BM => BM,
+            Country::BT => BT,
+            Country::BO => BO,
+            Country::BQ => BQ,
+            Country::BA => BA,
+            Country::BW => BW,
+            Country::BV => BV,
+            Country::BR => BR,
+            Country::IO => IO,
+            Country::BN => BN,
+            Country::BG => BG,
+            Country::BF => BF,
+            Country::BI => BI,
+            Country::CV => CV,
+            Country::KH => KH,
+            Country::CM => CM,
+            Country::CA => CA,
+            Country::KY => KY,
+            Country::CF => CF,
+            Country::TD => TD,
+            Country::CL => CL,
+            Country::CN => CN,
+            Country::CX => CX,
+            Country::CC => CC,
+            Country::CO => CO,
+            Country::KM => KM,
+            Country::CG => CG,
+            Country::CD => CD,
+            Country::CK => CK,
+            Country::CR => CR,
+            Country::CI => CI,
+            Country::HR => HR,
+            Country::CU => CU,
+            Country::CW => CW,
+            Country::CY => CY,
+            Country::CZ => CZ,
+            Country::DK => DK,
+            Country::DJ => DJ,
+            Country::DM => DM,
+            Country::DO => DO,
+            Country::EC => EC,
+            Country::EG => EG,
+            Country::SV => SV,
+            Country::GQ => GQ,
+            Country::ER => ER,
+            Country::EE => EE,
+            Country::SZ => SZ,
+            Country::ET => ET,
+            Country::FK => FK,
+            Country::FO => FO,
+            Country::FJ => FJ,
+            Country::FI => FI,
+            Country::FR => FR,
+            Country::GF => GF,
+            Country::PF => PF,
+            Country::TF => TF,
+            Country::GA => GA,
+            Country::GM => GM,
+            Country::GE => GE,
+            Country::DE => DE,
+            Country::GH => GH,
+            Country::GI => GI,
+            Country::GR => GR,
+            Country::GL => GL,
+            Country::GD => GD,
+            Country::GP => GP,
+            Country::GU => GU,
+            Country::GT => GT,
+            Country::GG => GG,
+            Country::GN => GN,
+            Country::GW => GW,
+            Country::GY => GY,
+            Country::HT => HT,
+            Country::HM => HM,
+            Country::VA => VA,
+            Country::HN => HN,
+            Country::HK => HK,
+            Country::HU => HU,
+            Country::IS => IS,
+            Country::IN => IN,
+            Country::ID => ID,
+            Country::IR => IR,
+            Country::IQ => IQ,
+            Country::IE => IE,
+            Country::IM => IM,
+            Country::IL => IL,
+            Country::IT => IT,
+            Country::JM => JM,
+            Country::JP => JP,
+            Country::JE => JE,
+            Country::JO => JO,
+            Country::KZ => KZ,
+            Country::KE => KE,
+            Country::KI => KI,
+            Country::KP => KP,
+            Country::KR => KR,
+            Country::KW => KW,
+            Country::KG => KG,
+            Country::LA => LA,
+            Country::LV => LV,
+            Country::LB => LB,
+            Country::LS => LS,
+            Country::LR => LR,
+            Country::LY => LY,
+            Country::LI => LI,
+            Country::LT => LT,
+            Country::LU => LU,
+            Country::MO => MO,
+            Country::MG => MG,
+            Country::MW => MW,
+            Country::MY => MY,
+            Country::MV => MV,
+            Country::ML => ML,
+            Country::MT => MT,
+            Country::MH => MH,
+            Country::MQ => MQ,
+            Country::MR => MR,
+            Country::MU => MU,
+            Country::YT => YT,
+            Country::MX => MX,
+            Country::FM => FM,
+            Country::MD => MD,
+            Country::MC => MC,
+            Country::MN => MN,
+            Country::ME => ME,
+            Country::MS => MS,
+            Country::MA => MA,
+            Country::MZ => MZ,
+            Country::MM => MM,
+            Country::NA => NA,
+            Country::NR => NR,
+            Country::NP => NP,
+            Country::NL => NL,
+            Country::NC => NC,
+            Country::NZ => NZ,
+            Country::NI => NI,
+            Country::NE => NE,
+            Country::NG => NG,
+            Country::NU => NU,
+            Country::NF => NF,
+            Country::MK => MK,
+            Country::MP => MP,
+            Country::NO => NO,
+            Country::OM => OM,
+            Country::PK => PK,
+            Country::PW => PW,
+            Country::PS => PS,
+            Country::PA => PA,
+            Country::PG => PG,
+            Country::PY => PY,
+            Country::PE => PE,
+            Country::PH => PH,
+            Country::PN => PN,
+            Country::PL => PL,
+            Country::PT => PT,
+            Country::PR => PR,
+            Country::QA => QA,
+            Country::RE => RE,
+            Country::RO => RO,
+            Country::RU => RU,
+            Country::RW => RW,
+            Country::BL => BL,
+            Country::SH => SH,
+            Country::KN => KN,
+            Country::LC => LC,
+            Country::MF => MF,
+            Country::PM => PM,
+            Country::VC => VC,
+            Country::WS => WS,
+            Country::SM => SM,
+            Country::ST => ST,
+            Country::SA => SA,
+            Country::SN => SN,
+            Country::RS => RS,
+            Country::SC => SC,
+            Country::SL => SL,
+            Country::SG => SG,
+            Country::SX => SX,
+            Country::SK => SK,
+            Country::SI => SI,
+            Country::SB => SB,
+            Country::SO => SO,
+            Country::ZA => ZA,
+            Country::GS => GS,
+            Country::SS => SS,
+            Country::ES => ES,
+            Country::LK => LK,
+            Country::SD => SD,
+            Country::SR => SR,
+            Country::SJ => SJ,
+            Country::SE => SE,
+            Country::CH => CH,
+            Country::SY => SY,
+            Country::TW => TW,
+            Country::TJ => TJ,
+            Country::TZ => TZ,
+            Country::TH => TH,
+            Country::TL => TL,
+            Country::TG => TG,
+            Country::TK => TK,
+            Country::TO => TO,
+            Country::TT => TT,
+            Country::TN => TN,
+            Country::TR => TR,
+            Country::TM => TM,
+            Country::TC => TC,
+            Country::TV => TV,
+            Country::UG => UG,
+            Country::UA => UA,
+            Country::AE => AE,
+            Country::GB => GB,
+            Country::US => US,
+            Country::UM => UM,
+            Country::UY => UY,
+            Country::UZ => UZ,
+            Country::VU => VU,
+            Country::VE => VE,
+            Country::VN => VN,
+            Country::VG => VG,
+            Country::VI => VI,
+            Country::WF => WF,
+            Country::EH => EH,
+            Country::YE => YE,
+            Country::ZM => ZM,
+            Country::ZW => ZW,
+
+
+        }
+    }
+
+    ///Return the Country with the given numeric code, if exists.
+    pub const fn from_numeric(numeric: u16) -> Option<Country> {
+        match numeric {
+
+
+            4 => Some(Country::AF),
+            248 => Some(Country::AX),
+            8 => Some(Country::AL),
+            12 => Some(Country::DZ),
+            16 => Some(Country::AS),
+            20 => Some(Country::AD),
+            24 => Some(Country::AO),
+            660 => Some(Country::AI),
+            10 => Some(Country::AQ),
+            28 => Some(Country::AG),
+            32 => Some(Country::AR),
+            51 => Some(Country::AM),
+            533 => Some(Country::AW),
+            36 => Some(Country::AU),
+            40 => Some(Country::AT),
+            31 => Some(Country::AZ),
+            44 => Some(Country::BS),
+            48 => Some(Country::BH),
+            50 => Some(Country::BD),
+            52 => Some(Country::BB),
+            112 => Some(Country::BY),
+            56 => Some(Country::BE),
+            84 => Some(Country::BZ),
+            204 => Some(Country::BJ),
+            60 => Some(Country::BM),
+            64 => Some(Country::BT),
+            68 => Some(Country::BO),
+            535 => Some(Country::BQ),
+            70 => Some(Country::BA),
+            72 => Some(Country::BW),
+            74 => Some(Country::BV),
+            76 => Some(Country::BR),
+            86 => Some(Country::IO),
+            96 => Some(Country::BN),
+            100 => Some(Country::BG),
+            854 => Some(Country::BF),
+            108 => Some(Country::BI),
+            132 => Some(Country::CV),
+            116 => Some(Country::KH),
+            120 => Some(Country::CM),
+            124 => Some(Country::CA),
+            136 => Some(Country::KY),
+            140 => Some(Country::CF),
+            148 => Some(Country::TD),
+            152 => Some(Country::CL),
+            156 => Some(Country::CN),
+            162 => Some(Country::CX),
+            166 => Some(Country::CC),
+            170 => Some(Country::CO),
+            174 => Some(Country::KM),
+            178 => Some(Country::CG),
+            180 => Some(Country::CD),
+            184 => Some(Country::CK),
+            188 => Some(Country::CR),
+            384 => Some(Country::CI),
+            191 => Some(Country::HR),
+            192 => Some(Country::CU),
+            531 => Some(Country::CW),
+            196 => Some(Country::CY),
+            203 => Some(Country::CZ),
+            208 => Some(Country::DK),
+            262 => Some(Country::DJ),
+            212 => Some(Country::DM),
+            214 => Some(Country::DO),
+            218 => Some(Country::EC),
+            818 => Some(Country::EG),
+            222 => Some(Country::SV),
+            226 => Some(Country::GQ),
+            232 => Some(Country::ER),
+            233 => Some(Country::EE),
+            748 => Some(Country::SZ),
+            231 => Some(Country::ET),
+            238 => Some(Country::FK),
+            234 => Some(Country::FO),
+            242 => Some(Country::FJ),
+            246 => Some(Country::FI),
+            250 => Some(Country::FR),
+            254 => Some(Country::GF),
+            258 => Some(Country::PF),
+            260 => Some(Country::TF),
+            266 => Some(Country::GA),
+            270 => Some(Country::GM),
+            268 => Some(Country::GE),
+            276 => Some(Country::DE),
+            288 => Some(Country::GH),
+            292 => Some(Country::GI),
+            300 => Some(Country::GR),
+            304 => Some(Country::GL),
+            308 => Some(Country::GD),
+            312 => Some(Country::GP),
+            316 => Some(Country::GU),
+            320 => Some(Country::GT),
+            831 => Some(Country::GG),
+            324 => Some(Country::GN),
+            624 => Some(Country::GW),
+            328 => Some(Country::GY),
+            332 => Some(Country::HT),
+            334 => Some(Country::HM),
+            336 => Some(Country::VA),
+            340 => Some(Country::HN),
+            344 => Some(Country::HK),
+            348 => Some(Country::HU),
+            352 => Some(Country::IS),
+            356 => Some(Country::IN),
+            360 => Some(Country::ID),
+            364 => Some(Country::IR),
+            368 => Some(Country::IQ),
+            372 => Some(Country::IE),
+            833 => Some(Country::IM),
+            376 => Some(Country::IL),
+            380 => Some(Country::IT),
+            388 => Some(Country::JM),
+            392 => Some(Country::JP),
+            832 => Some(Country::JE),
+            400 => Some(Country::JO),
+            398 => Some(Country::KZ),
+            404 => Some(Country::KE),
+            296 => Some(Country::KI),
+            408 => Some(Country::KP),
+            410 => Some(Country::KR),
+            414 => Some(Country::KW),
+            417 => Some(Country::KG),
+            418 => Some(Country::LA),
+            428 => Some(Country::LV),
+            422 => Some(Country::LB),
+            426 => Some(Country::LS),
+            430 => Some(Country::LR),
+            434 => Some(Country::LY),
+            438 => Some(Country::LI),
+            440 => Some(Country::LT),
+            442 => Some(Country::LU),
+            446 => Some(Country::MO),
+            450 => Some(Country::MG),
+            454 => Some(Country::MW),
+            458 => Some(Country::MY),
+            462 => Some(Country::MV),
+            466 => Some(Country::ML),
+            470 => Some(Country::MT),
+            584 => Some(Country::MH),
+            474 => Some(Country::MQ),
+            478 => Some(Country::MR),
+            480 => Some(Country::MU),
+            175 => Some(Country::YT),
+            484 => Some(Country::MX),
+            583 => Some(Country::FM),
+            498 => Some(Country::MD),
+            492 => Some(Country::MC),
+            496 => Some(Country::MN),
+            499 => Some(Country::ME),
+            500 => Some(Country::MS),
+            504 => Some(Country::MA),
+            508 => Some(Country::MZ),
+            104 => Some(Country::MM),
+            516 => Some(Country::NA),
+            520 => Some(Country::NR),
+            524 => Some(Country::NP),
+            528 => Some(Country::NL),
+            540 => Some(Country::NC),
+            554 => Some(Country::NZ),
+            558 => Some(Country::NI),
+            562 => Some(Country::NE),
+            566 => Some(Country::NG),
+            570 => Some(Country::NU),
+            574 => Some(Country::NF),
+            807 => Some(Country::MK),
+            580 => Some(Country::MP),
+            578 => Some(Country::NO),
+            512 => Some(Country::OM),
+            586 => Some(Country::PK),
+            585 => Some(Country::PW),
+            275 => Some(Country::PS),
+            591 => Some(Country::PA),
+            598 => Some(Country::PG),
+            600 => Some(Country::PY),
+            604 => Some(Country::PE),
+            608 => Some(Country::PH),
+            612 => Some(Country::PN),
+            616 => Some(Country::PL),
+            620 => Some(Country::PT),
+            630 => Some(Country::PR),
+            634 => Some(Country::QA),
+            638 => Some(Country::RE),
+            642 => Some(Country::RO),
+            643 => Some(Country::RU),
+            646 => Some(Country::RW),
+            652 => Some(Country::BL),
+            654 => Some(Country::SH),
+            659 => Some(Country::KN),
+            662 => Some(Country::LC),
+            663 => Some(Country::MF),
+            666 => Some(Country::PM),
+            670 => Some(Country::VC),
+            882 => Some(Country::WS),
+            674 => Some(Country::SM),
+            678 => Some(Country::ST),
+            682 => Some(Country::SA),
+            686 => Some(Country::SN),
+            688 => Some(Country::RS),
+            690 => Some(Country::SC),
+            694 => Some(Country::SL),
+            702 => Some(Country::SG),
+            534 => Some(Country::SX),
+            703 => Some(Country::SK),
+            705 => Some(Country::SI),
+            90 => Some(Country::SB),
+            706 => Some(Country::SO),
+            710 => Some(Country::ZA),
+            239 => Some(Country::GS),
+            728 => Some(Country::SS),
+            724 => Some(Country::ES),
+            144 => Some(Country::LK),
+            729 => Some(Country::SD),
+            740 => Some(Country::SR),
+            744 => Some(Country::SJ),
+            752 => Some(Country::SE),
+            756 => Some(Country::CH),
+            760 => Some(Country::SY),
+            158 => Some(Country::TW),
+            762 => Some(Country::TJ),
+            834 => Some(Country::TZ),
+            764 => Some(Country::TH),
+            626 => Some(Country::TL),
+            768 => Some(Country::TG),
+            772 => Some(Country::TK),
+            776 => Some(Country::TO),
+            780 => Some(Country::TT),
+            788 => Some(Country::TN),
+            792 => Some(Country::TR),
+            795 => Some(Country::TM),
+            796 => Some(Country::TC),
+            798 => Some(Country::TV),
+            800 => Some(Country::UG),
+            804 => Some(Country::UA),
+            784 => Some(Country::AE),
+            826 => Some(Country::GB),
+            840 => Some(Country::US),
+            581 => Some(Country::UM),
+            858 => Some(Country::UY),
+            860 => Some(Country::UZ),
+            548 => Some(Country::VU),
+            862 => Some(Country::VE),
+            704 => Some(Country::VN),
+            92 => Some(Country::VG),
+            850 => Some(Country::VI),
+            876 => Some(Country::WF),
+            732 => Some(Country::EH),
+            887 => Some(Country::YE),
+            894 => Some(Country::ZM),
+            716 => Some(Country::ZW),
+
+
+            _ => None,
+        }
+    }
+}
+
+impl From<Country> for CountryCode {
+    fn from(country: Country) -> Self {
+        country.country_code()
+    }
+}
+
+/// Fails with the given CountryCode if it is not one of the entries in `ALL`.
+impl TryFrom<CountryCode> for Country {
+    type Error = CountryCode;
+
+    fn try_from(code: CountryCode) -> Result<Self, Self::Error> {
+        match Country::from_numeric(code.numeric) {
+            Some(country) if country.country_code() == code => Ok(country),
+            _ => Err(code),
+        }
+    }
+}
+
+
+impl CountryCode {
+    ///Return true for sovereign states, the "Independent" column of ISO 3166-1
+    pub fn is_independent(&self) -> bool {
+        !matches!(
+            self.alpha2,
+            "AX"
+                | "AS"
+                | "AI"
+                | "AQ"
+                | "AW"
+                | "BM"
+                | "BQ"
+                | "BV"
+                | "IO"
+                | "KY"
+                | "CX"
+                | "CC"
+                | "CK"
+                | "CW"
+                | "FK"
+                | "FO"
+                | "GF"
+                | "PF"
+                | "TF"
+                | "GI"
+                | "GL"
+                | "GP"
+                | "GU"
+                | "GG"
+                | "HM"
+                | "HK"
+                | "IM"
+                | "JE"
+                | "MO"
+                | "MQ"
+                | "YT"
+                | "MS"
+                | "NC"
+                | "NU"
+                | "NF"
+                | "MP"
+                | "PS"
+                | "PN"
+                | "PR"
+                | "RE"
+                | "BL"
+                | "SH"
+                | "MF"
+                | "PM"
+                | "SX"
+                | "GS"
+                | "SJ"
+                | "TW"
+                | "TK"
+                | "TC"
+                | "UM"
+                | "VG"
+                | "VI"
+                | "WF"
+                | "EH"
+        )
+    }
+}
+
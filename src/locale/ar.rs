@@ -0,0 +1,292 @@
+use phf::phf_map;
+use phf::Map;
+
+///Arabic country names by alpha-2 code
+pub const COUNTRY_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF" => "أفغانستان",
+    "AX" => "جزر آلاند",
+    "AL" => "ألبانيا",
+    "DZ" => "الجزائر",
+    "AS" => "صاموا الأمريكيّة",
+    "AD" => "أندورا",
+    "AO" => "أنغولا",
+    "AI" => "أنغويلا",
+    "AQ" => "القطب الجنوبي",
+    "AG" => "أنتيغوا و باربودا",
+    "AR" => "الأرجنتين",
+    "AM" => "أرمينيا",
+    "AW" => "أروبا",
+    "AU" => "أستراليا",
+    "AT" => "النّمسا",
+    "AZ" => "أذربيجان",
+    "BS" => "جزر البهاما",
+    "BH" => "البحرين",
+    "BD" => "بنغلادش",
+    "BB" => "بربادوس",
+    "BY" => "روسيا البيضاء",
+    "BE" => "بلجيكا",
+    "BZ" => "بيليز",
+    "BJ" => "بنين",
+    "BM" => "برمودا",
+    "BT" => "بوتان",
+    "BO" => "جمهورية بوليفيا",
+    "BQ" => "بونير وسانت يوستاتيوس وسابا",
+    "BA" => "البوسنة و الهرسك",
+    "BW" => "بوتسوانا",
+    "BV" => "جزيرة بوفي",
+    "BR" => "البرازيل",
+    "IO" => "مقاطعة المحيط الهندي البريطانيّة",
+    "BN" => "بروناي دار السّلام",
+    "BG" => "بلغاريا",
+    "BF" => "بوركينا فاصو",
+    "BI" => "بوروندي",
+    "CV" => "الرأس الأخضر",
+    "KH" => "كمبوديا",
+    "CM" => "الكاميرون",
+    "CA" => "كندا",
+    "KY" => "جزر الكيمان",
+    "CF" => "جمهورية إفريقيّا الوسطى",
+    "TD" => "تشاد",
+    "CL" => "تشيلي",
+    "CN" => "الصّين",
+    "CX" => "جزر الكريسماس",
+    "CC" => "جزر الكوكوس",
+    "CO" => "كولومبيا",
+    "KM" => "جزر القمر",
+    "CG" => "الكونغو",
+    "CD" => "الكونغو، جمهوريّة الكونغو الدّيموقراطيّة",
+    "CK" => "جزر كوك",
+    "CR" => "كوستاريكا",
+    "CI" => "ساحل العاج",
+    "HR" => "كرواتيا",
+    "CU" => "كوبا",
+    "CW" => "جزر كوراكاو",
+    "CY" => "قبرص",
+    "CZ" => "التشيك",
+    "DK" => "الدّنمارك",
+    "DJ" => "جيبوتي",
+    "DM" => "دومينيكا",
+    "DO" => "جمهوريّة الدّومينيكان",
+    "EC" => "الإكوادور",
+    "EG" => "مصر",
+    "SV" => "السّلفادور",
+    "GQ" => "غينيا الاستوائيّة",
+    "ER" => "إريتريا",
+    "EE" => "إستونيا",
+    "SZ" => "إسواتيني",
+    "ET" => "إثيوبيا",
+    "FK" => "جزر فولكلاند (مالفيناس)",
+    "FO" => "جزر الفارو",
+    "FJ" => "فيجي",
+    "FI" => "فنلندا",
+    "FR" => "فرنسا",
+    "GF" => "غيانا الفرنسيّة",
+    "PF" => "بولينيسيا الفرنسيّة",
+    "TF" => "المقاطعات الفرنسيّة الجنوبيّة",
+    "GA" => "الغابون",
+    "GM" => "غامبيا",
+    "GE" => "جورجيا",
+    "DE" => "ألمانيا",
+    "GH" => "غانا",
+    "GI" => "جبل طارق",
+    "GR" => "اليونان",
+    "GL" => "غرينلاند",
+    "GD" => "غرينادا",
+    "GP" => "جوادالوبّي",
+    "GU" => "جوام",
+    "GT" => "غواتيمالا",
+    "GG" => "جزيرة جويرزني",
+    "GN" => "غينيا",
+    "GW" => "غينيا بيساو",
+    "GY" => "غويانا",
+    "HT" => "هايتي",
+    "HM" => "جزيرة هيرد وجزر مَكْدونالد",
+    "VA" => "المقعد المقدّس (ولاية مدينة الفاتيكان)",
+    "HN" => "هندوراس",
+    "HK" => "هونغ كونغ",
+    "HU" => "المجر (هنغاريا)",
+    "IS" => "آيسلندا",
+    "IN" => "الهند",
+    "ID" => "إندونيسيا",
+    "IR" => "إيران، الجمهوريّة الإسلاميّة الإيرانيّة",
+    "IQ" => "العراق",
+    "IE" => "أيرلندا",
+    "IM" => "آيزل أف مان",
+    "IL" => "إسرائيل",
+    "IT" => "إيطاليا",
+    "JM" => "جامايكا",
+    "JP" => "اليابان",
+    "JE" => "جيرسي",
+    "JO" => "الأردن",
+    "KZ" => "كازاخستان",
+    "KE" => "كينيا",
+    "KI" => "كيريباتي",
+    "KP" => "كوريا، جمهورية كوريا الشّعبيّة الدّيموقراطيّة",
+    "KR" => "كوريا، جمهوريّة كوريا",
+    "KW" => "الكويت",
+    "KG" => "قيرغزستان",
+    "LA" => "جمهوريّة لاو الدّيموقراطيّة الشّعبيّة",
+    "LV" => "لاتفيا",
+    "LB" => "لبنان",
+    "LS" => "ليسوتو",
+    "LR" => "ليبيريا",
+    "LY" => "ليبيا",
+    "LI" => "ليشتنشتاين",
+    "LT" => "لثوانيا",
+    "LU" => "لوكسمبورغ",
+    "MO" => "مكّاو",
+    "MG" => "مدغشقر",
+    "MW" => "ملاوي",
+    "MY" => "ماليزيا",
+    "MV" => "جزر المالديف",
+    "ML" => "مالي",
+    "MT" => "مالطة",
+    "MH" => "جزر المارشال",
+    "MQ" => "مارتينيك",
+    "MR" => "موريتانيا",
+    "MU" => "موريشيوس",
+    "YT" => "مايوت",
+    "MX" => "المكسيك",
+    "FM" => "ميكرونيزيا، ولايات ميكرونيزيا الموحّدة",
+    "MD" => "جمهورية مولدوفا",
+    "MC" => "موناكو",
+    "MN" => "منغوليا",
+    "ME" => "المنتنيغرو",
+    "MS" => "مونتسيرات",
+    "MA" => "المغرب",
+    "MZ" => "موزمبيق",
+    "MM" => "ميانمار",
+    "NA" => "ناميبيا",
+    "NR" => "ناورو",
+    "NP" => "نيبال",
+    "NL" => "هولندا",
+    "NC" => "نيو قلدونيا",
+    "NZ" => "نيوزيلاندا",
+    "NI" => "نيكاراجوا",
+    "NE" => "النّيجر",
+    "NG" => "نيجيريا",
+    "NU" => "نيوي",
+    "NF" => "جزيرة نورفولك",
+    "MK" => "مقدونيا الشمالية",
+    "MP" => "جزر ماريانا الشّماليّة",
+    "NO" => "النّرويج",
+    "OM" => "عمان",
+    "PK" => "باكستان",
+    "PW" => "بالاو",
+    "PS" => "دولة فلسطين",
+    "PA" => "بنما",
+    "PG" => "بابوا غينيا الجديدة",
+    "PY" => "الباراغواي",
+    "PE" => "البيرو",
+    "PH" => "الفلبّين",
+    "PN" => "بتكيرن",
+    "PL" => "بولندا",
+    "PT" => "البرتغال",
+    "PR" => "بورتوريكو",
+    "QA" => "قطر",
+    "RE" => "ريونيون",
+    "RO" => "رومانيا",
+    "RU" => "الاتّحاد الرّوسي",
+    "RW" => "رواندا",
+    "BL" => "سان بارتليمي",
+    "SH" => "ساينت هيلينا، تريستان دا كونا",
+    "KN" => "سانت كيتس و نيفس",
+    "LC" => "سانت لوسيا",
+    "MF" => "سانت مارتين (القطاع الفرنسي)",
+    "PM" => "سانت بيير و ميكيلون",
+    "VC" => "سانت فنسنت و جزر الغرينادين",
+    "WS" => "صاموا",
+    "SM" => "سان مارينو",
+    "ST" => "ساو تومي و برنسبي",
+    "SA" => "السّعوديّة",
+    "SN" => "السّنغال",
+    "RS" => "صربية",
+    "SC" => "السّيشل",
+    "SL" => "سيراليون",
+    "SG" => "سنغافورة",
+    "SX" => "سانت مارتن (الجزء الهولندي)",
+    "SK" => "سلوفاكيا",
+    "SI" => "سلوفينيا",
+    "SB" => "جزر سولومن",
+    "SO" => "الصّومال",
+    "ZA" => "جنوب إفريقيا",
+    "GS" => "جورجيا الجنوبيّة و جزر ساندويتش الجنوبيّة",
+    "SS" => "جنوب السّودان",
+    "ES" => "إسبانيا",
+    "LK" => "سريلانكا",
+    "SD" => "السّودان",
+    "SR" => "سورينام",
+    "SJ" => "سفالبارد و جان ماين",
+    "SE" => "السّويد",
+    "CH" => "سويسرا",
+    "SY" => "الجمهوريّة العربيّة السّوريّة",
+    "TW" => "تايوان، محافظة صينيّة",
+    "TJ" => "طاجيكستان",
+    "TZ" => "تنزانيا، جمهوريّة تنزانيا المتّحدة",
+    "TH" => "تايلاند",
+    "TL" => "تيمور-ليستي",
+    "TG" => "توغو",
+    "TK" => "جزر توكيلو",
+    "TO" => "تونغا",
+    "TT" => "ترينيداد و توباغو",
+    "TN" => "تونس",
+    "TM" => "تركمانستان",
+    "TC" => "جزر التّرك و الكايكوس",
+    "TV" => "توفالو",
+    "UG" => "أوغندا",
+    "UA" => "أوكرانيا",
+    "AE" => "الإمارات العربيّة المتحدّة",
+    "GB" => "المملكة المتّحدة",
+    "US" => "الولايات المتّحدة",
+    "UM" => "جزر الولايات المتّحدة الصّغرى النّائية",
+    "UY" => "الأوروغواي",
+    "UZ" => "أوزبكستان",
+    "VU" => "فانواتو",
+    "VE" => "جمهورية فنزويلا البوليفارية",
+    "VN" => "الفييتنام",
+    "VG" => "فيرجن، جزر فيرجن البريطانيّة",
+    "VI" => "فيرجن، جزر فيرجن الأميركيّة",
+    "WF" => "واليس و فوتونا",
+    "EH" => "الصّحراء الغربيّة",
+    "YE" => "اليمن",
+    "ZM" => "زامبيا",
+    "ZW" => "زمبابوي",
+};
+
+///Arabic subdivision names by ISO 3166-2 code, 0 of 5046
+pub const SUBDIVISION_NAME_MAP: Map<&str, &str> = phf_map! {
+};
+
+///Arabic former country names by alpha-4 code
+pub const FORMER_NAME_MAP: Map<&str, &str> = phf_map! {
+    "BQAQ" => "مقاطعة المحيط الهندي البريطانيّة",
+    "BUMM" => "جمهورية اتحاد بورما الاشتراكية",
+    "BYAA" => "جمهورية بلاروسية السوفييتية الاشتراكية",
+    "CTKI" => "جزر كنتون وإندربري",
+    "CSHH" => "تشيكوسلوفاكيا، جمهورية سلوفاكيا الاشتراكية",
+    "DYBJ" => "داهومي",
+    "NQAQ" => "أرض الملكة مود",
+    "TPTL" => "تيمور الشرقية",
+    "FXFR" => "فرنسا، العاصمة",
+    "AIDJ" => "العفر والأوسا الفرنسية",
+    "FQHH" => "المناطق الجنوبية والأنتركتيكية الفرنسية",
+    "DDDE" => "جمهوريّة ألمانيا الديموقراطيّة",
+    "GEHH" => "جزر غيلبرت وإيليس",
+    "JTUM" => "جزيرة جونستون",
+    "MIUM" => "جزر ميدواي",
+    "ANHH" => "أنتيل هولندا",
+    "NTHH" => "المنطقة المحايدة",
+    "NHVU" => "هبريدز الجديدة",
+    "PCHH" => "جزر المحيط الهادي (منطقة ثقة)",
+    "PZPA" => "منطقة قناة بنما",
+    "CSXX" => "صربيا والمنتنيغرو",
+    "SKIN" => "سكم",
+    "RHZW" => "روديسا الجنوبية",
+    "PUUM" => "جزر المحيط الهادي الأمريكية",
+    "HVBF" => "جمهورية فولتا العليا",
+    "SUHH" => "اتحاد الجمهوريات السوفيتية الاشتراكية",
+    "VDVN" => "جمهورية فيتنام الدّيموقراطيّة",
+    "WKUM" => "جزيرة وايك",
+    "YDYE" => "جمهورية اليمن الشعبية الديموقراطية",
+    "ZRCD" => "جمهوريّة زائير",
+};
@@ -0,0 +1,664 @@
+use phf::phf_map;
+use phf::Map;
+
+///Spanish country names by alpha-2 code
+pub const COUNTRY_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF" => "Afganistán",
+    "AX" => "Islas Äland",
+    "AL" => "Albania",
+    "DZ" => "Algeria",
+    "AS" => "Samoa Estadounidense",
+    "AD" => "Andorra",
+    "AO" => "Angola",
+    "AI" => "Anguila",
+    "AQ" => "Antártida",
+    "AG" => "Antigua y Barbuda",
+    "AR" => "Argentina",
+    "AM" => "Armenia",
+    "AW" => "Aruba",
+    "AU" => "Australia",
+    "AT" => "Austria",
+    "AZ" => "Azerbaiyán",
+    "BS" => "Bahamas",
+    "BH" => "Baréin",
+    "BD" => "Bangladés",
+    "BB" => "Barbados",
+    "BY" => "Bielorrusia",
+    "BE" => "Bélgica",
+    "BZ" => "Belice",
+    "BJ" => "Benín",
+    "BM" => "Islas Bermudas",
+    "BT" => "Bután",
+    "BO" => "Bolivia, Estado plurinacional de",
+    "BQ" => "Islas BES (Caribe Neerlandés)",
+    "BA" => "Bosnia y Herzegovina",
+    "BW" => "Botsuana",
+    "BV" => "Isla Bouvet",
+    "BR" => "Brasil",
+    "IO" => "Territorio Británico del Océano Índico",
+    "BN" => "Brunei Darussalam",
+    "BG" => "Bulgaria",
+    "BF" => "Burquina Faso",
+    "BI" => "Burundi",
+    "CV" => "Cabo Verde",
+    "KH" => "Camboya",
+    "CM" => "Camerún",
+    "CA" => "Canadá",
+    "KY" => "Islas Caimán",
+    "CF" => "República Centroafricana",
+    "TD" => "Chad",
+    "CL" => "Chile",
+    "CN" => "China",
+    "CX" => "Isla de Navidad",
+    "CC" => "Islas Cocos (Keeling)",
+    "CO" => "Colombia",
+    "KM" => "Comores, Islas",
+    "CG" => "Congo",
+    "CD" => "Congo, República Democrática del",
+    "CK" => "Islas Cook",
+    "CR" => "Costa Rica",
+    "CI" => "Costa de Marfíl",
+    "HR" => "Croacia",
+    "CU" => "Cuba",
+    "CW" => "Curazao",
+    "CY" => "Chipre",
+    "CZ" => "Chequia",
+    "DK" => "Dinamarca",
+    "DJ" => "Yibuti",
+    "DM" => "Dominica",
+    "DO" => "República Dominicana",
+    "EC" => "Ecuador",
+    "EG" => "Egipto",
+    "SV" => "El Salvador",
+    "GQ" => "Guinea Ecuatorial",
+    "ER" => "Eritrea",
+    "EE" => "Estonia",
+    "SZ" => "Esuatini",
+    "ET" => "Etiopía",
+    "FK" => "Islas Falkland (Malvinas)",
+    "FO" => "Islas Feroe",
+    "FJ" => "Fiyi",
+    "FI" => "Finlandia",
+    "FR" => "Francia",
+    "GF" => "Guayana Francesa",
+    "PF" => "Polinesia Francesa",
+    "TF" => "Territorios Franceses del Sur",
+    "GA" => "Gabón",
+    "GM" => "Gambia",
+    "GE" => "Georgia",
+    "DE" => "Alemania",
+    "GH" => "Ghana",
+    "GI" => "Gibraltar",
+    "GR" => "Grecia",
+    "GL" => "Groenlandia",
+    "GD" => "Granada",
+    "GP" => "Guadalupe",
+    "GU" => "Guam",
+    "GT" => "Guatemala",
+    "GG" => "Guernsey",
+    "GN" => "Guinea",
+    "GW" => "Guinea-Bisáu",
+    "GY" => "Guyana",
+    "HT" => "Haití",
+    "HM" => "Islas Heard y McDonald",
+    "VA" => "Santa Sede (Ciudad Estado del Vaticano)",
+    "HN" => "Honduras",
+    "HK" => "Hong Kong",
+    "HU" => "Hungría",
+    "IS" => "Islandia",
+    "IN" => "India",
+    "ID" => "Indonesia",
+    "IR" => "Irán, República islámica de",
+    "IQ" => "Irak",
+    "IE" => "Irlanda",
+    "IM" => "Isla de Man",
+    "IL" => "Israel",
+    "IT" => "Italia",
+    "JM" => "Jamaica",
+    "JP" => "Japón",
+    "JE" => "Jersey",
+    "JO" => "Jordania",
+    "KZ" => "Kazajistán",
+    "KE" => "Kenia",
+    "KI" => "Kiribati",
+    "KP" => "Corea, República Democrática Popular de",
+    "KR" => "Corea, República de",
+    "KW" => "Kuwait",
+    "KG" => "Kirguistán",
+    "LA" => "República Democrática Popular de Lao",
+    "LV" => "Letonia",
+    "LB" => "Líbano",
+    "LS" => "Lesoto",
+    "LR" => "Liberia",
+    "LY" => "Libia",
+    "LI" => "Liechtenstein",
+    "LT" => "Lituania",
+    "LU" => "Luxemburgo",
+    "MO" => "Macao",
+    "MG" => "Madagascar",
+    "MW" => "Malaui",
+    "MY" => "Malasia",
+    "MV" => "Islas Maldivas",
+    "ML" => "Malí",
+    "MT" => "Malta",
+    "MH" => "Islas Marshall",
+    "MQ" => "Martinica",
+    "MR" => "Mauritania",
+    "MU" => "Mauricio",
+    "YT" => "Mayotte",
+    "MX" => "México",
+    "FM" => "Micronesia, Estados Federados de",
+    "MD" => "Moldavia, República de",
+    "MC" => "Mónaco",
+    "MN" => "Mongolia",
+    "ME" => "Montenegro",
+    "MS" => "Montserrat",
+    "MA" => "Marruecos",
+    "MZ" => "Mozambique",
+    "MM" => "Birmania",
+    "NA" => "Namibia",
+    "NR" => "Nauru",
+    "NP" => "Nepal",
+    "NL" => "Países Bajos",
+    "NC" => "Nueva Caledonia",
+    "NZ" => "Nueva Zelanda",
+    "NI" => "Nicaragua",
+    "NE" => "Niger",
+    "NG" => "Nigeria",
+    "NU" => "Niue",
+    "NF" => "Isla Norfolk",
+    "MK" => "Macedonia del Norte",
+    "MP" => "Islas Marianas del Norte",
+    "NO" => "Noruega",
+    "OM" => "Omán",
+    "PK" => "Pakistán",
+    "PW" => "Palaos",
+    "PS" => "Palestina, Estado de",
+    "PA" => "Panamá",
+    "PG" => "Papúa Nueva Guinea",
+    "PY" => "Paraguay",
+    "PE" => "Perú",
+    "PH" => "Filipinas",
+    "PN" => "Pitcairn",
+    "PL" => "Polonia",
+    "PT" => "Portugal",
+    "PR" => "Puerto Rico",
+    "QA" => "Catar",
+    "RE" => "Reunión",
+    "RO" => "Rumanía",
+    "RU" => "Federación Rusa",
+    "RW" => "Ruanda",
+    "BL" => "San Bartolomé",
+    "SH" => "Santa Elena, Ascensión y Tristán de Acuña",
+    "KN" => "San Cristóbal y Nieves",
+    "LC" => "Santa Lucía",
+    "MF" => "San Martín (zona francesa)",
+    "PM" => "San Pedro y Miquelon",
+    "VC" => "San Vicente y las Granadinas",
+    "WS" => "Samoa",
+    "SM" => "San Marino",
+    "ST" => "Santo Tomé y Príncipe",
+    "SA" => "Arabia Saudí",
+    "SN" => "Senegal",
+    "RS" => "Serbia",
+    "SC" => "Seychelles",
+    "SL" => "Sierra Leona",
+    "SG" => "Singapur",
+    "SX" => "Isla de San Martín (zona holandsea)",
+    "SK" => "Eslovaquia",
+    "SI" => "Eslovenia",
+    "SB" => "Islas Salomón",
+    "SO" => "Somalia",
+    "ZA" => "Sudáfrica",
+    "GS" => "Islas Georgias del Sur y Sándwich del Sur",
+    "SS" => "Sudán del Sur",
+    "ES" => "España",
+    "LK" => "Sri Lanka",
+    "SD" => "Sudán",
+    "SR" => "Surinám",
+    "SJ" => "Svalbard y Jan Mayen",
+    "SE" => "Suecia",
+    "CH" => "Suiza",
+    "SY" => "República árabe de Siria",
+    "TW" => "Taiwán, Provincia de China",
+    "TJ" => "Tayikistán",
+    "TZ" => "Tanzania, República unida de",
+    "TH" => "Tailandia",
+    "TL" => "Timor Oriental",
+    "TG" => "Togo",
+    "TK" => "Tokelau",
+    "TO" => "Tonga",
+    "TT" => "Trinidad y Tobago",
+    "TN" => "Tunez",
+    "TM" => "Turkmenistán",
+    "TC" => "Islas Turcas y Caicos",
+    "TV" => "Tuvalu",
+    "UG" => "Uganda",
+    "UA" => "Ucrania",
+    "AE" => "Emiratos Árabes Unidos",
+    "GB" => "Reino Unido",
+    "US" => "Estados Unidos",
+    "UM" => "Islas Ultramarinas Menores de Estados Unidos",
+    "UY" => "Uruguay",
+    "UZ" => "Uzbekistán",
+    "VU" => "Vanuatu",
+    "VE" => "Venezuela, República Bolivariana de",
+    "VN" => "Vietnam",
+    "VG" => "Islas Vírgenes, Británicas",
+    "VI" => "Islas Vírgenes, de EEUU",
+    "WF" => "Wallis y Futuna",
+    "EH" => "Sahara Occidental",
+    "YE" => "Yemen",
+    "ZM" => "Zambia",
+    "ZW" => "Zimbabue",
+};
+
+///Spanish subdivision names by ISO 3166-2 code, 372 of 5046
+pub const SUBDIVISION_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF-BAL" => "Balkh",
+    "AF-KAB" => "Kabul",
+    "AF-KAN" => "Kandahar",
+    "AF-NAN" => "Nangarjar",
+    "AF-NIM" => "Nimruz",
+    "AF-NUR" => "Nurestán",
+    "AF-PAN" => "Panjshir",
+    "AF-PAR" => "Paruán",
+    "AF-PIA" => "Paktia",
+    "AF-PKA" => "Paktiká",
+    "AF-SAM" => "Samangan",
+    "AF-SAR" => "Sar-e Pul",
+    "AF-TAK" => "Tajar",
+    "AF-URU" => "Uruzgán",
+    "AF-WAR" => "Vardak",
+    "AL-03" => "Elbasan",
+    "AD-02" => "Canillo",
+    "AD-03" => "Encamp",
+    "AD-04" => "La Masaña",
+    "AD-05" => "Ordino",
+    "AD-06" => "San Julián de Loria",
+    "AD-07" => "Andorra la Vieja",
+    "AD-08" => "Las Escaldas-Engordany",
+    "AO-ZAI" => "Zaire",
+    "AR-F" => "La Rioja",
+    "AR-R" => "Río Negro",
+    "AR-X" => "Córdoba",
+    "AZ-QAZ" => "Gazaj",
+    "BZ-BZ" => "Belice",
+    "BZ-TOL" => "Toledo",
+    "BO-B" => "El Beni",
+    "BO-C" => "Cochabamba",
+    "BO-H" => "Chuquisaca",
+    "BO-L" => "La Paz",
+    "BO-N" => "Pando",
+    "BO-O" => "Oruro",
+    "BO-P" => "Potosí",
+    "BR-AC" => "Acre",
+    "BR-AM" => "Amazonas",
+    "BR-CE" => "Ceará",
+    "BR-DF" => "Distrito Federal",
+    "BR-ES" => "Espíritu Santo",
+    "BR-MA" => "Marañón",
+    "CV-B" => "Islas de Barlovento",
+    "CA-AB" => "Alberta",
+    "CA-BC" => "Columbia Británica",
+    "CA-MB" => "Manitoba",
+    "CA-NB" => "Nuevo Brunswick",
+    "CA-NL" => "Terranova y Labrador",
+    "CA-NS" => "Nueva Escocia",
+    "CA-NT" => "Territorios del Noroeste",
+    "CA-NU" => "Nunavut",
+    "CA-ON" => "Ontario",
+    "CA-PE" => "Isla del Príncipe Eduardo",
+    "CA-QC" => "Quebec",
+    "CA-SK" => "Saskatchewan",
+    "CO-AMA" => "Amazonas",
+    "CO-ANT" => "Antioquia",
+    "CO-ARA" => "Arauca",
+    "CO-ATL" => "Atlántico",
+    "CO-BOL" => "Bolívar",
+    "CO-BOY" => "Boyacá",
+    "CO-CAL" => "Caldas",
+    "CO-CAQ" => "Caquetá",
+    "CO-CAS" => "Casanare",
+    "CO-CAU" => "Cauca",
+    "CO-CES" => "Cesar",
+    "CO-CHO" => "Chocó",
+    "CO-COR" => "Córdoba",
+    "CO-CUN" => "Cundinamarca",
+    "CO-DC" => "Distrito Capital de Bogotá",
+    "CO-GUA" => "Guainía",
+    "CO-GUV" => "Guaviare",
+    "CO-HUI" => "Huila",
+    "CO-LAG" => "La Guajira",
+    "CO-MAG" => "Magdalena",
+    "CO-MET" => "Meta",
+    "CO-NAR" => "Nariño",
+    "CO-NSA" => "Norte de Santander",
+    "CO-PUT" => "Putumayo",
+    "CO-QUI" => "Quindío",
+    "CO-RIS" => "Risaralda",
+    "CO-SAN" => "Santander",
+    "CO-SAP" => "San Andrés, Providencia y Santa Catalina",
+    "CO-SUC" => "Sucre",
+    "CO-TOL" => "Tolima",
+    "CO-VAC" => "Valle del Cauca",
+    "CO-VAU" => "Vaupés",
+    "CO-VID" => "Vichada",
+    "CR-A" => "Alajuela",
+    "CR-C" => "Cartago",
+    "CR-G" => "Guanacaste",
+    "CR-H" => "Heredia",
+    "CR-L" => "Limón",
+    "CR-P" => "Puntarenas",
+    "CR-SJ" => "San José",
+    "CU-03" => "La Habana",
+    "CU-04" => "Matanzas",
+    "CU-05" => "Villa Clara",
+    "CU-06" => "Cienfuegos",
+    "CU-07" => "Sancti Spíritus",
+    "CU-08" => "Ciego de Ávila",
+    "CU-09" => "Camagüey",
+    "CU-10" => "Las Tunas",
+    "CU-11" => "Holguín",
+    "CU-12" => "Granma",
+    "CU-13" => "Santiago de Cuba",
+    "CU-14" => "Guantánamo",
+    "CU-99" => "Isla de la Juventud",
+    "DJ-DJ" => "Yibuti",
+    "EC-B" => "Bolívar",
+    "SV-LI" => "La Libertad",
+    "SV-PA" => "La Paz",
+    "FR-90" => "Territorio de Belfort",
+    "FR-971" => "Guadalupe",
+    "FR-OCC" => "Occitania",
+    "GN-ML" => "Malí",
+    "HN-LE" => "Lempira",
+    "HN-LP" => "La Paz",
+    "IT-21" => "Piamonte",
+    "IT-25" => "Lombardía",
+    "IT-32" => "Trentino-Alto Adigio",
+    "IT-34" => "Véneto",
+    "IT-42" => "Liguria",
+    "IT-45" => "Emilia-Romaña",
+    "IT-52" => "Toscana",
+    "IT-55" => "Umbría",
+    "IT-57" => "Marcas",
+    "IT-62" => "Lacio",
+    "IT-65" => "Abruzos",
+    "IT-67" => "Molise",
+    "IT-72" => "Campania",
+    "IT-75" => "Apulia",
+    "IT-77" => "Basilicata",
+    "IT-78" => "Calabria",
+    "IT-82" => "Sicilia",
+    "IT-88" => "Cerdeña",
+    "IT-BO" => "Bolonia",
+    "IT-BR" => "Brindisi",
+    "IT-FI" => "Florencia",
+    "IT-GE" => "Génova",
+    "MX-AGU" => "Aguascalientes",
+    "MX-BCN" => "Baja California",
+    "MX-BCS" => "Baja California Sur",
+    "MX-CAM" => "Campeche",
+    "MX-CHH" => "Chihuahua",
+    "MX-CHP" => "Chiapas",
+    "MX-CMX" => "Ciudad de México",
+    "MX-COA" => "Coahuila de Zaragoza",
+    "MX-COL" => "Colima",
+    "MX-DUR" => "Durango",
+    "MX-GRO" => "Guerrero",
+    "MX-GUA" => "Guanajuato",
+    "MX-HID" => "Hidalgo",
+    "MX-JAL" => "Jalisco",
+    "MX-MEX" => "México",
+    "MX-MIC" => "Michoacán de Ocampo",
+    "MX-MOR" => "Morelos",
+    "MX-NAY" => "Nayarit",
+    "MX-NLE" => "Nuevo León",
+    "MX-OAX" => "Oaxaca",
+    "MX-PUE" => "Puebla",
+    "MX-QUE" => "Querétaro",
+    "MX-ROO" => "Quintana Roo",
+    "MX-SIN" => "Sinaloa",
+    "MX-SLP" => "San Luis Potosí",
+    "MX-SON" => "Sonora",
+    "MX-TAB" => "Tabasco",
+    "MX-TAM" => "Tamaulipas",
+    "MX-TLA" => "Tlaxcala",
+    "MX-VER" => "Veracruz de Ignacio de la Llave",
+    "MX-YUC" => "Yucatán",
+    "MX-ZAC" => "Zacatecas",
+    "MZ-G" => "Gaza",
+    "NL-AW" => "Aruba",
+    "NL-CW" => "Curazao",
+    "NI-BO" => "Boaco",
+    "NI-CA" => "Carazo",
+    "NI-CI" => "Chinandega",
+    "NI-CO" => "Chontales",
+    "NI-ES" => "Estelí",
+    "NI-GR" => "Granada",
+    "NI-JI" => "Jinotega",
+    "NI-MD" => "Madriz",
+    "NI-MN" => "Managua",
+    "NI-MS" => "Masaya",
+    "NI-MT" => "Matagalpa",
+    "NI-NS" => "Nueva Segovia",
+    "NI-RI" => "Rivas",
+    "NI-SJ" => "Río San Juan",
+    "NG-NI" => "Níger",
+    "PS-GZA" => "Gaza",
+    "PA-1" => "Bocas del Toro",
+    "PA-2" => "Coclé",
+    "PA-4" => "Chiriquí",
+    "PA-5" => "Darién",
+    "PA-6" => "Herrera",
+    "PA-7" => "Los Santos",
+    "PA-8" => "Panamá",
+    "PA-9" => "Veraguas",
+    "PA-EM" => "Emberá",
+    "PA-NB" => "Ngöbe-Buglé",
+    "PE-ANC" => "Áncash",
+    "PE-ARE" => "Arequipa",
+    "PE-AYA" => "Ayacucho",
+    "PE-CAJ" => "Cajamarca",
+    "PE-CAL" => "El Callao",
+    "PE-HUC" => "Huánuco",
+    "PE-HUV" => "Huancavelica",
+    "PE-ICA" => "Ica",
+    "PE-LAL" => "La Libertad",
+    "PE-LAM" => "Lambayeque",
+    "PE-LIM" => "Lima",
+    "PE-LOR" => "Loreto",
+    "PE-MDD" => "Madre de Dios",
+    "PE-MOQ" => "Moquegua",
+    "PE-PAS" => "Pasco",
+    "PE-PIU" => "Piura",
+    "PE-PUN" => "Puno",
+    "PE-TAC" => "Tacna",
+    "PE-TUM" => "Tumbes",
+    "PE-UCA" => "Ucayali",
+    "PH-00" => "Región de la Capital Nacional",
+    "PH-01" => "Ilocos (Región I)",
+    "PH-02" => "Valle del Cagayán (Región II)",
+    "PH-03" => "Luzón Central (Región III)",
+    "PH-05" => "Bícol (Región V)",
+    "PH-06" => "Visayas Occidentales (Región VI)",
+    "PH-07" => "Visayas Centrales (Región VII)",
+    "PH-08" => "Visayas Orientales (Región VIII)",
+    "PH-09" => "Península de Zamboanga (Región IX)",
+    "PH-10" => "Mindanao del Norte (Región X)",
+    "PH-AGN" => "Agusan del Norte",
+    "PH-AGS" => "Agusan del Sur",
+    "ES-AB" => "Albacete",
+    "ES-BA" => "Badajoz",
+    "ES-BI" => "Vizcaya",
+    "ES-BU" => "Burgos",
+    "ES-CB" => "Cantabria",
+    "ES-CE" => "Ceuta",
+    "ES-CO" => "Córdoba",
+    "ES-CR" => "Ciudad Real",
+    "ES-CU" => "Cuenca",
+    "ES-GC" => "Las Palmas",
+    "ES-GR" => "Granada",
+    "ES-GU" => "Guadalajara",
+    "ES-H" => "Huelva",
+    "ES-HU" => "Huesca",
+    "ES-LO" => "La Rioja",
+    "ES-M" => "Madrid",
+    "ES-ML" => "Melilla",
+    "ES-MU" => "Murcia",
+    "ES-O" => "Asturias",
+    "ES-P" => "Palencia",
+    "ES-RI" => "La Rioja",
+    "ES-S" => "Cantabria",
+    "ES-SA" => "Salamanca",
+    "ES-SE" => "Sevilla",
+    "ES-SO" => "Soria",
+    "ES-SS" => "Guipúzcoa",
+    "ES-TE" => "Teruel",
+    "ES-TF" => "Santa Cruz de Tenerife",
+    "ES-TO" => "Toledo",
+    "ES-VA" => "Valladolid",
+    "ES-Z" => "Zaragoza",
+    "ES-ZA" => "Zamora",
+    "TZ-09" => "Kilimanyaro",
+    "TT-POS" => "Puerto España",
+    "TT-SFO" => "San Fernando",
+    "TT-SGE" => "Sangre Grande",
+    "UA-40" => "Sebastopol",
+    "AE-DU" => "Dubái",
+    "AE-FU" => "Fuyaira",
+    "AE-RK" => "Ras al-Khaimah",
+    "US-AK" => "Alaska",
+    "US-AL" => "Alabama",
+    "US-AR" => "Arkansas",
+    "US-AS" => "Samoa Estadounidense",
+    "US-AZ" => "Arizona",
+    "US-CA" => "California",
+    "US-CO" => "Colorado",
+    "US-CT" => "Connecticut",
+    "US-DC" => "Distrito de Columbia",
+    "US-DE" => "Delaware",
+    "US-FL" => "Florida",
+    "US-GA" => "Georgia",
+    "US-GU" => "Guam",
+    "US-HI" => "Hawái",
+    "US-IA" => "Iowa",
+    "US-ID" => "Idaho",
+    "US-IL" => "Illinois",
+    "US-IN" => "Indiana",
+    "US-KS" => "Kansas",
+    "US-KY" => "Kentucky",
+    "US-LA" => "Luisiana",
+    "US-MA" => "Massachusetts",
+    "US-ME" => "Maine",
+    "US-MI" => "Míchigan",
+    "US-MN" => "Minnesota",
+    "US-MO" => "Missouri",
+    "US-MP" => "Islas Marianas del Norte",
+    "US-MS" => "Misisipi",
+    "US-NC" => "Carolina del Norte",
+    "US-ND" => "Dakota del Norte",
+    "US-NE" => "Nebraska",
+    "US-NH" => "Nuevo Hampshire",
+    "US-NJ" => "Nueva Jersey",
+    "US-NM" => "Nuevo México",
+    "US-NV" => "Nevada",
+    "US-NY" => "Nueva York",
+    "US-OH" => "Ohio",
+    "US-OK" => "Oklahoma",
+    "US-OR" => "Oregón",
+    "US-PA" => "Pensilvania",
+    "US-PR" => "Puerto Rico",
+    "US-RI" => "Rhode Island",
+    "US-SC" => "Carolina del Sur",
+    "US-SD" => "Dakota del Sur",
+    "US-TN" => "Tennessee",
+    "US-TX" => "Texas",
+    "US-UM" => "Islas Ultramarinas Menores de Estados Unidos",
+    "US-UT" => "Utah",
+    "US-VA" => "Virginia",
+    "US-VT" => "Vermont",
+    "US-WA" => "Washington",
+    "US-WI" => "Wisconsin",
+    "US-WV" => "Virginia Occidental",
+    "US-WY" => "Wyoming",
+    "UM-71" => "Midway, islas",
+    "UM-79" => "Wake, isla",
+    "UY-AR" => "Artigas",
+    "UY-CA" => "Canelones",
+    "UY-CL" => "Cerro Largo",
+    "UY-CO" => "Colonia",
+    "UY-DU" => "Durazno",
+    "UY-FD" => "Florida",
+    "UY-FS" => "Flores",
+    "UY-LA" => "Lavalleja",
+    "UY-MA" => "Maldonado",
+    "UY-MO" => "Montevideo",
+    "UY-PA" => "Paysandú",
+    "UY-RN" => "Río Negro",
+    "UY-RO" => "Rocha",
+    "UY-RV" => "Rivera",
+    "UY-SA" => "Salto",
+    "UY-SJ" => "San José",
+    "UY-SO" => "Soriano",
+    "UY-TA" => "Tacuarembó",
+    "UY-TT" => "Treinta y Tres",
+    "VE-B" => "Anzoátegui",
+    "VE-C" => "Apure",
+    "VE-D" => "Aragua",
+    "VE-E" => "Barinas",
+    "VE-F" => "Bolívar",
+    "VE-G" => "Carabobo",
+    "VE-H" => "Cojedes",
+    "VE-I" => "Falcón",
+    "VE-J" => "Guárico",
+    "VE-K" => "Lara",
+    "VE-L" => "Mérida",
+    "VE-M" => "Miranda",
+    "VE-N" => "Monagas",
+    "VE-O" => "Nueva Esparta",
+    "VE-P" => "Portuguesa",
+    "VE-R" => "Sucre",
+    "VE-S" => "Táchira",
+    "VE-T" => "Trujillo",
+    "VE-U" => "Yaracuy",
+    "VE-V" => "Zulia",
+    "VE-W" => "Dependencias Federales",
+    "VE-Y" => "Delta Amacuro",
+    "VE-Z" => "Amazonas",
+};
+
+///Spanish former country names by alpha-4 code
+pub const FORMER_NAME_MAP: Map<&str, &str> = phf_map! {
+    "BQAQ" => "Territorio Antártico Británico",
+    "BUMM" => "Birmania, República Socialista de la Unión de",
+    "BYAA" => "Bielorrusia, República Socialista Soviética de",
+    "CTKI" => "Cantón y Enderbury, islas",
+    "CSHH" => "Checoslovaquia, República Socialista Checoslovaca",
+    "DYBJ" => "Dahomey",
+    "NQAQ" => "Tierra de la Reina Maud",
+    "TPTL" => "Timor oriental",
+    "FXFR" => "Francia, zona metropolitana",
+    "AIDJ" => "Territorio Francés de los Afares y de los Issas",
+    "FQHH" => "Francia, Tierras Australes y Antárticas de",
+    "DDDE" => "Alemania, República Democrática de",
+    "GEHH" => "Gilber y Ellice, islas",
+    "JTUM" => "Johnston, atolón",
+    "MIUM" => "Midway, islas",
+    "ANHH" => "Antillas Neerlandesas",
+    "NTHH" => "Zona neutral",
+    "NHVU" => "Nuevas Hébridas",
+    "PCHH" => "Islas del Pacífico (Territorio en Fideicomiso)",
+    "PZPA" => "Zona del Canal de Panamá",
+    "CSXX" => "Serbia y Montenegro",
+    "SKIN" => "Sikkim",
+    "RHZW" => "Rodesia del sur",
+    "PUUM" => "Diversas Islas del Pacífico de Estados Unidos",
+    "HVBF" => "Alto Volta, República del",
+    "SUHH" => "URSS, Unión de Repúblicas Socialistas Soviéticas",
+    "VDVN" => "Vietnam, República Democrática de",
+    "WKUM" => "Wake, isla",
+    "YDYE" => "Yemén, República Democrática Popular de",
+    "ZRCD" => "Zaire, República del",
+};
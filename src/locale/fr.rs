@@ -0,0 +1,4186 @@
+use phf::phf_map;
+use phf::Map;
+
+///French country names by alpha-2 code
+pub const COUNTRY_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF" => "Afghanistan",
+    "AX" => "Åland, Îles",
+    "AL" => "Albanie",
+    "DZ" => "Algérie",
+    "AS" => "Samoa américaines",
+    "AD" => "Andorre",
+    "AO" => "Angola",
+    "AI" => "Anguilla",
+    "AQ" => "Antarctique",
+    "AG" => "Antigua-et-Barbuda",
+    "AR" => "Argentine",
+    "AM" => "Arménie",
+    "AW" => "Aruba",
+    "AU" => "Australie",
+    "AT" => "Autriche",
+    "AZ" => "Azerbaïdjan",
+    "BS" => "Bahamas",
+    "BH" => "Bahreïn",
+    "BD" => "Bangladesh",
+    "BB" => "Barbade",
+    "BY" => "Bélarus",
+    "BE" => "Belgique",
+    "BZ" => "Belize",
+    "BJ" => "Bénin",
+    "BM" => "Bermudes",
+    "BT" => "Bhoutan",
+    "BO" => "Bolivie, état plurinational de",
+    "BQ" => "Bonaire, Saint-Eustache et Saba",
+    "BA" => "Bosnie-Herzégovine",
+    "BW" => "Botswana",
+    "BV" => "île Bouvet",
+    "BR" => "Brésil",
+    "IO" => "Territoire britannique de l'océan Indien",
+    "BN" => "Brunéi Darussalam",
+    "BG" => "Bulgarie",
+    "BF" => "Burkina Faso",
+    "BI" => "Burundi",
+    "CV" => "Cap-Vert",
+    "KH" => "Cambodge",
+    "CM" => "Cameroun",
+    "CA" => "Canada",
+    "KY" => "îles Caïmans",
+    "CF" => "République centrafricaine",
+    "TD" => "Tchad",
+    "CL" => "Chili",
+    "CN" => "Chine",
+    "CX" => "Christmas, Île",
+    "CC" => "Cocos (Keeling), Îles",
+    "CO" => "Colombie",
+    "KM" => "Comores",
+    "CG" => "République du Congo",
+    "CD" => "République démocratique du Congo",
+    "CK" => "îles Cook",
+    "CR" => "Costa Rica",
+    "CI" => "Côte d'Ivoire",
+    "HR" => "Croatie",
+    "CU" => "Cuba",
+    "CW" => "Curaçao",
+    "CY" => "Chypre",
+    "CZ" => "Tchéquie",
+    "DK" => "Danemark",
+    "DJ" => "Djibouti",
+    "DM" => "Dominique",
+    "DO" => "République dominicaine",
+    "EC" => "Équateur",
+    "EG" => "Égypte",
+    "SV" => "Salvador",
+    "GQ" => "Guinée Équatoriale",
+    "ER" => "Érythrée",
+    "EE" => "Estonie",
+    "SZ" => "Eswatini",
+    "ET" => "Éthiopie",
+    "FK" => "Malouines, Îles (Falkland)",
+    "FO" => "îles Féroé",
+    "FJ" => "Fidji",
+    "FI" => "Finlande",
+    "FR" => "France",
+    "GF" => "Guyane française",
+    "PF" => "Polynésie française",
+    "TF" => "Terres australes françaises",
+    "GA" => "Gabon",
+    "GM" => "Gambie",
+    "GE" => "Géorgie",
+    "DE" => "Allemagne",
+    "GH" => "Ghana",
+    "GI" => "Gibraltar",
+    "GR" => "Grèce",
+    "GL" => "Groënland",
+    "GD" => "Grenade",
+    "GP" => "Guadeloupe",
+    "GU" => "Guam",
+    "GT" => "Guatemala",
+    "GG" => "Guernesey",
+    "GN" => "Guinée",
+    "GW" => "Guinée-Bissau",
+    "GY" => "Guyana",
+    "HT" => "Haïti",
+    "HM" => "îles Heard-et-MacDonald",
+    "VA" => "Saint-Siège (état de la cité du Vatican)",
+    "HN" => "Honduras",
+    "HK" => "Hong Kong",
+    "HU" => "Hongrie",
+    "IS" => "Islande",
+    "IN" => "Inde",
+    "ID" => "Indonésie",
+    "IR" => "Iran, République islamique d'",
+    "IQ" => "Irak",
+    "IE" => "Irlande",
+    "IM" => "Île de Man",
+    "IL" => "Israël",
+    "IT" => "Italie",
+    "JM" => "Jamaïque",
+    "JP" => "Japon",
+    "JE" => "Jersey",
+    "JO" => "Jordanie",
+    "KZ" => "Kazakhstan",
+    "KE" => "Kenya",
+    "KI" => "Kiribati",
+    "KP" => "Corée, République populaire démocratique de",
+    "KR" => "Corée, République de",
+    "KW" => "Koweït",
+    "KG" => "Kirghizistan",
+    "LA" => "Lao, République démocratique populaire",
+    "LV" => "Lettonie",
+    "LB" => "Liban",
+    "LS" => "Lesotho",
+    "LR" => "Libéria",
+    "LY" => "Libye",
+    "LI" => "Liechtenstein",
+    "LT" => "Lituanie",
+    "LU" => "Luxembourg",
+    "MO" => "Macau",
+    "MG" => "Madagascar",
+    "MW" => "Malawi",
+    "MY" => "Malaisie",
+    "MV" => "Maldives",
+    "ML" => "Mali",
+    "MT" => "Malte",
+    "MH" => "Îles Marshall",
+    "MQ" => "Martinique",
+    "MR" => "Mauritanie",
+    "MU" => "Maurice",
+    "YT" => "Mayotte",
+    "MX" => "Mexique",
+    "FM" => "Micronésie, États fédérés de",
+    "MD" => "Moldova, République de",
+    "MC" => "Monaco",
+    "MN" => "Mongolie",
+    "ME" => "Monténégro",
+    "MS" => "Montserrat",
+    "MA" => "Maroc",
+    "MZ" => "Mozambique",
+    "MM" => "Birmanie",
+    "NA" => "Namibie",
+    "NR" => "Nauru",
+    "NP" => "Népal",
+    "NL" => "Pays-Bas",
+    "NC" => "Nouvelle-Calédonie",
+    "NZ" => "Nouvelle-Zélande",
+    "NI" => "Nicaragua",
+    "NE" => "Niger",
+    "NG" => "Nigeria",
+    "NU" => "Nioue",
+    "NF" => "île Norfolk",
+    "MK" => "Macédoine du Nord",
+    "MP" => "Îles Mariannes du Nord",
+    "NO" => "Norvège",
+    "OM" => "Oman",
+    "PK" => "Pakistan",
+    "PW" => "Palaos",
+    "PS" => "Palestine, État de",
+    "PA" => "Panama",
+    "PG" => "Papouasie-Nouvelle-Guinée",
+    "PY" => "Paraguay",
+    "PE" => "Pérou",
+    "PH" => "Philippines",
+    "PN" => "Îles Pitcairn",
+    "PL" => "Pologne",
+    "PT" => "Portugal",
+    "PR" => "Porto Rico",
+    "QA" => "Qatar",
+    "RE" => "Réunion, Île de la",
+    "RO" => "Roumanie",
+    "RU" => "Russie, Fédération de",
+    "RW" => "Rwanda",
+    "BL" => "Saint-Barthélemy",
+    "SH" => "Sainte-Hélène, Ascension et Tristan da Cunha",
+    "KN" => "Saint-Christophe-et-Niévès",
+    "LC" => "Sainte-Lucie",
+    "MF" => "Saint-Martin (partie française)",
+    "PM" => "Saint-Pierre-et-Miquelon",
+    "VC" => "Saint-Vincent-et-les-Grenadines",
+    "WS" => "Samoa",
+    "SM" => "Saint-Marin",
+    "ST" => "Sao Tomé-et-Principe",
+    "SA" => "Arabie saoudite",
+    "SN" => "Sénégal",
+    "RS" => "Serbie",
+    "SC" => "Seychelles",
+    "SL" => "Sierra Leone",
+    "SG" => "Singapour",
+    "SX" => "Saint-Martin (partie néerlandaise)",
+    "SK" => "Slovaquie",
+    "SI" => "Slovénie",
+    "SB" => "Salomon, Îles",
+    "SO" => "Somalie",
+    "ZA" => "Afrique du Sud",
+    "GS" => "Géorgie du Sud et les îles Sandwich du Sud",
+    "SS" => "Soudan du Sud",
+    "ES" => "Espagne",
+    "LK" => "Sri Lanka",
+    "SD" => "Soudan",
+    "SR" => "Surinam",
+    "SJ" => "Svalbard et île Jan Mayen",
+    "SE" => "Suède",
+    "CH" => "Suisse",
+    "SY" => "Syrienne, République arabe",
+    "TW" => "Taïwan, province de Chine",
+    "TJ" => "Tadjikistan",
+    "TZ" => "Tanzanie, République unie de",
+    "TH" => "Thaïlande",
+    "TL" => "Timor oriental",
+    "TG" => "Togo",
+    "TK" => "Tokelau",
+    "TO" => "Tonga",
+    "TT" => "Trinité-et-Tobago",
+    "TN" => "Tunisie",
+    "TM" => "Turkménistan",
+    "TC" => "îles Turques-et-Caïques",
+    "TV" => "Tuvalu",
+    "UG" => "Ouganda",
+    "UA" => "Ukraine",
+    "AE" => "Émirats arabes unis",
+    "GB" => "Royaume-Uni",
+    "US" => "États-Unis",
+    "UM" => "Îles mineures éloignées des États-Unis",
+    "UY" => "Uruguay",
+    "UZ" => "Ouzbékistan",
+    "VU" => "Vanuatu",
+    "VE" => "Vénézuela, république bolivarienne du",
+    "VN" => "Viêt Nam",
+    "VG" => "Îles Vierges britanniques",
+    "VI" => "Îles Vierges, États-Unis",
+    "WF" => "Wallis et Futuna",
+    "EH" => "Sahara occidental",
+    "YE" => "Yémen",
+    "ZM" => "Zambie",
+    "ZW" => "Zimbabwe",
+};
+
+///French subdivision names by ISO 3166-2 code, 3894 of 5046
+pub const SUBDIVISION_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF-BAL" => "Balkh",
+    "AF-BAM" => "Bamyan",
+    "AF-BDG" => "Badghis",
+    "AF-BDS" => "Badakhchan",
+    "AF-BGL" => "Baghlan",
+    "AF-DAY" => "Daykundi",
+    "AF-FRA" => "Farah",
+    "AF-FYB" => "Faryab",
+    "AF-GHA" => "Ghazni",
+    "AF-GHO" => "Ghor",
+    "AF-HEL" => "Helmand",
+    "AF-HER" => "Hérat",
+    "AF-JOW" => "Jowzjan",
+    "AF-KAB" => "Kaboul",
+    "AF-KAN" => "Kandahar",
+    "AF-KAP" => "Kapisa",
+    "AF-KDZ" => "Kunduz",
+    "AF-KHO" => "Khōst",
+    "AF-LAG" => "Laghman",
+    "AF-LOG" => "Logar",
+    "AF-NAN" => "Nangarhar",
+    "AF-NIM" => "Nimruz",
+    "AF-NUR" => "Nourestan",
+    "AF-PAN" => "Panchir",
+    "AF-PAR" => "Parwan",
+    "AF-PIA" => "Paktia",
+    "AF-PKA" => "Paktika",
+    "AF-SAM" => "Samangan",
+    "AF-SAR" => "Sar-é Pol",
+    "AF-TAK" => "Takhar",
+    "AF-URU" => "Ourouzgan",
+    "AF-WAR" => "Wardag",
+    "AF-ZAB" => "Zaboul",
+    "AL-01" => "Berat",
+    "AL-02" => "Durrës",
+    "AL-03" => "elbasan",
+    "AL-04" => "Fier",
+    "AL-05" => "Gjirokastër",
+    "AL-06" => "Korçë",
+    "AL-07" => "Kukës",
+    "AL-08" => "Lezhë",
+    "AL-09" => "Dibër",
+    "AL-10" => "Shkodër",
+    "AL-11" => "Tirana",
+    "AL-12" => "Vlorë",
+    "DZ-01" => "Adrar",
+    "DZ-02" => "Chlef",
+    "DZ-03" => "Laghouat",
+    "DZ-04" => "Oum El-Bouaghi",
+    "DZ-05" => "Batna",
+    "DZ-06" => "Béjaïa",
+    "DZ-07" => "Biskra",
+    "DZ-08" => "Béchar",
+    "DZ-09" => "Blida",
+    "DZ-10" => "Bouira",
+    "DZ-12" => "Tébessa",
+    "DZ-13" => "Tlemcen",
+    "DZ-14" => "Tiaret",
+    "DZ-15" => "Tizi Ouzou",
+    "DZ-16" => "Alger",
+    "DZ-17" => "Djelfa",
+    "DZ-18" => "Jijel",
+    "DZ-19" => "Sétif",
+    "DZ-20" => "Saïda",
+    "DZ-21" => "Skikda",
+    "DZ-22" => "Sidi Bel Abbès",
+    "DZ-23" => "Annaba",
+    "DZ-24" => "Guelma",
+    "DZ-25" => "Constantine",
+    "DZ-26" => "Médéa",
+    "DZ-27" => "Mostaganem",
+    "DZ-29" => "Mascara",
+    "DZ-30" => "Ouargla",
+    "DZ-31" => "Oran",
+    "DZ-32" => "El-Bayadh",
+    "DZ-33" => "Illizi",
+    "DZ-34" => "Bordj Bou Arréridj",
+    "DZ-35" => "Boumerdès",
+    "DZ-36" => "El-Taref",
+    "DZ-37" => "Tindouf",
+    "DZ-38" => "Tissemsilt",
+    "DZ-39" => "El Oued",
+    "DZ-40" => "Khenchela",
+    "DZ-41" => "Souk Ahras",
+    "DZ-42" => "Tipaza",
+    "DZ-43" => "Mila",
+    "DZ-44" => "Aïn Defla",
+    "DZ-45" => "Naâma",
+    "DZ-46" => "Aïn Témouchent",
+    "DZ-47" => "Ghardaïa",
+    "DZ-48" => "Relizane",
+    "AD-02" => "Canillo",
+    "AD-03" => "Encamp",
+    "AD-04" => "La Massana",
+    "AD-05" => "Ordino",
+    "AD-06" => "Saint Julià de Lòria",
+    "AD-07" => "Andorre la Vieille",
+    "AD-08" => "Escaldes-Engordany",
+    "AO-BGO" => "Bengo",
+    "AO-BGU" => "Benguela",
+    "AO-BIE" => "Bié",
+    "AO-CAB" => "Cabinda",
+    "AO-CNN" => "Cunene",
+    "AO-CNO" => "Cuanza-Nord",
+    "AO-CUS" => "Cuanza-Sud",
+    "AO-HUA" => "Huambo",
+    "AO-HUI" => "Huila",
+    "AO-LNO" => "Lunda-Nord",
+    "AO-LSU" => "Lunda-Sud",
+    "AO-LUA" => "Luanda",
+    "AO-MAL" => "Malanje",
+    "AO-MOX" => "Moxico",
+    "AO-NAM" => "Namibe",
+    "AO-UIG" => "Uige",
+    "AO-ZAI" => "Zaire",
+    "AG-03" => "Saint-George",
+    "AG-04" => "Saint-John",
+    "AG-05" => "Saint-Mary",
+    "AG-06" => "Saint-Paul",
+    "AG-07" => "Saint-Peter",
+    "AG-08" => "Saint-Philip",
+    "AG-10" => "Barbade",
+    "AG-11" => "Redonda",
+    "AR-A" => "Salta",
+    "AR-B" => "Buenos Aires",
+    "AR-C" => "Ville autonome de Buenos Aires",
+    "AR-D" => "San Luis",
+    "AR-E" => "Entre-Ríos",
+    "AR-F" => "La Rioja",
+    "AR-G" => "Santiago del Estero",
+    "AR-H" => "Chaco",
+    "AR-J" => "San Juan",
+    "AR-K" => "Catamarca",
+    "AR-L" => "La Pampa",
+    "AR-M" => "Mendoza",
+    "AR-N" => "Misiones",
+    "AR-P" => "Formosa",
+    "AR-Q" => "Neuquén",
+    "AR-R" => "Río Negro",
+    "AR-S" => "Santa Fe",
+    "AR-U" => "Chubut",
+    "AR-V" => "Tierra del Fuego, Antártida, e Islas del Atlántico Sur",
+    "AR-W" => "Corrientes",
+    "AR-X" => "Cordoba",
+    "AR-Y" => "Jujuy",
+    "AR-Z" => "Santa Cruz",
+    "AM-AR" => "Ararat",
+    "AM-AV" => "Armavir",
+    "AM-ER" => "Yerevan",
+    "AM-KT" => "Kotayk",
+    "AM-LO" => "Lori",
+    "AM-SU" => "Syunik",
+    "AM-VD" => "Vayots’ Dzor",
+    "AU-ACT" => "Territoire de la capitale australienne",
+    "AU-NSW" => "Nouvelle-Galles-du-Sud",
+    "AU-NT" => "Territoire du Nord",
+    "AU-QLD" => "Queensland",
+    "AU-SA" => "Australie-Méridionale",
+    "AU-TAS" => "Tasmanie",
+    "AU-VIC" => "Victoria",
+    "AU-WA" => "Australie-Occidentale",
+    "AT-1" => "Burgenland",
+    "AT-2" => "Carinthie",
+    "AT-3" => "Basse-Autriche",
+    "AT-4" => "Haute-Autriche",
+    "AT-5" => "Salzbourg",
+    "AT-6" => "Styrie",
+    "AT-7" => "Tyrol",
+    "AT-8" => "Vorarlberg",
+    "AT-9" => "Vienne",
+    "AZ-ABS" => "Absheron",
+    "AZ-AGA" => "Agstafa",
+    "AZ-AGC" => "Agjabadi",
+    "AZ-AGM" => "Agdam",
+    "AZ-AGS" => "Agdas",
+    "AZ-AGU" => "Agsu",
+    "AZ-AST" => "Astara",
+    "AZ-BA" => "Bakou",
+    "AZ-BAB" => "Babak",
+    "AZ-BAL" => "Balakan",
+    "AZ-BAR" => "Barda",
+    "AZ-BEY" => "Beylaqan",
+    "AZ-BIL" => "Bilasuvar",
+    "AZ-CAB" => "Jabrayil",
+    "AZ-CUL" => "Julfa",
+    "AZ-DAS" => "Daskasan",
+    "AZ-FUZ" => "Füzuli",
+    "AZ-GA" => "Gandja",
+    "AZ-GAD" => "Gadabay",
+    "AZ-GOR" => "Goranboy",
+    "AZ-GOY" => "Göycay",
+    "AZ-GYG" => "Göygöl",
+    "AZ-HAC" => "Hajigabul",
+    "AZ-IMI" => "Imisli",
+    "AZ-ISM" => "Ismayilli",
+    "AZ-KAL" => "Kalbacar",
+    "AZ-KAN" => "Kangarli",
+    "AZ-KUR" => "Kürdamir",
+    "AZ-LA" => "Lankaran",
+    "AZ-LAC" => "Lacın",
+    "AZ-LAN" => "Lankaran",
+    "AZ-LER" => "Lerik",
+    "AZ-MAS" => "Masalli",
+    "AZ-MI" => "Mingachevir",
+    "AZ-NA" => "Naftalan",
+    "AZ-NEF" => "Neftchala",
+    "AZ-NV" => "Nakhitchevan",
+    "AZ-NX" => "Nakhitchevan",
+    "AZ-OGU" => "Oguz",
+    "AZ-ORD" => "Ordubad",
+    "AZ-QAB" => "Qabala",
+    "AZ-QAX" => "Qakh",
+    "AZ-QAZ" => "Qazakh",
+    "AZ-QBA" => "Quba",
+    "AZ-QBI" => "Qubadli",
+    "AZ-QOB" => "Qobustan",
+    "AZ-QUS" => "Qusar",
+    "AZ-SA" => "Chaki",
+    "AZ-SAB" => "Sabirabad",
+    "AZ-SAD" => "Sadarak",
+    "AZ-SAH" => "Shakhbuz",
+    "AZ-SAK" => "Chaki",
+    "AZ-SAL" => "Salyan",
+    "AZ-SAR" => "Sharur",
+    "AZ-SAT" => "Saatli",
+    "AZ-SBN" => "Chabran",
+    "AZ-SIY" => "Siyazan",
+    "AZ-SKR" => "Shemkir",
+    "AZ-SM" => "Sumqayit",
+    "AZ-SMI" => "Shamaxi",
+    "AZ-SMX" => "Samukh",
+    "AZ-SR" => "Chirvan",
+    "AZ-SUS" => "Susa",
+    "AZ-TAR" => "Tartar",
+    "AZ-TOV" => "Tovuz",
+    "AZ-UCA" => "Ujar",
+    "AZ-XA" => "Xankandi",
+    "AZ-XAC" => "Khachmaz",
+    "AZ-XCI" => "Khojali",
+    "AZ-XIZ" => "Xizi",
+    "AZ-XVD" => "Khojavend",
+    "AZ-YAR" => "Yardymli",
+    "AZ-YE" => "Yevlakh",
+    "AZ-YEV" => "Yevlakh",
+    "AZ-ZAN" => "Zangilan",
+    "AZ-ZAQ" => "Zaqatala",
+    "AZ-ZAR" => "Zardab",
+    "BS-AK" => "Acklins",
+    "BS-BI" => "Bimini",
+    "BS-BP" => "Black Point",
+    "BS-BY" => "Îles Berry",
+    "BS-CE" => "Eleuthera Central",
+    "BS-CI" => "Cat Island",
+    "BS-CK" => "Île Crooked et Long Cay",
+    "BS-CO" => "Abaco Central",
+    "BS-CS" => "Andros central",
+    "BS-EG" => "Grand Bahama orientale",
+    "BS-EX" => "Exuma",
+    "BS-FP" => "Ville de Freeport",
+    "BS-GC" => "Grand Cay",
+    "BS-HI" => "Harbour Island",
+    "BS-HT" => "Hope Town",
+    "BS-IN" => "Inagua",
+    "BS-LI" => "Long Island",
+    "BS-MC" => "Mangrove Cay",
+    "BS-MG" => "Mayaguana",
+    "BS-MI" => "Île de Moore",
+    "BS-NE" => "Eleuthera septentrional",
+    "BS-NO" => "Abaco septentrional",
+    "BS-NS" => "Andros septentrional",
+    "BS-RC" => "Rum Cay",
+    "BS-RI" => "Ragged Island",
+    "BS-SA" => "Andros méridional",
+    "BS-SE" => "Eleuthera méridional",
+    "BS-SO" => "Abaco méridional",
+    "BS-SS" => "San Salvador",
+    "BS-SW" => "Spanish Wells",
+    "BS-WG" => "Grand Bahama occidentale",
+    "BH-14" => "Al Janubiyah",
+    "BH-15" => "Al Muharraq",
+    "BH-17" => "Nord",
+    "BD-01" => "Bandarban",
+    "BD-02" => "Laguna",
+    "BD-03" => "Bogura",
+    "BD-04" => "Brahmanbaria",
+    "BD-05" => "Bagerhat",
+    "BD-06" => "Barisal",
+    "BD-07" => "Bhola",
+    "BD-08" => "Comilla",
+    "BD-09" => "Chandpur",
+    "BD-10" => "Chittagong",
+    "BD-11" => "Cox's Bazar",
+    "BD-12" => "Chuadanga",
+    "BD-13" => "Dacca",
+    "BD-14" => "Dinajpur",
+    "BD-15" => "Faridpur",
+    "BD-16" => "Feni",
+    "BD-17" => "Gopalganj",
+    "BD-18" => "Gazipur",
+    "BD-19" => "Gaibandha",
+    "BD-20" => "Habiganj",
+    "BD-21" => "Jamalpur",
+    "BD-22" => "Jessore",
+    "BD-23" => "Jhenaidah",
+    "BD-24" => "Jaipurhat",
+    "BD-25" => "Jhalakati",
+    "BD-26" => "Kishoreganj",
+    "BD-27" => "Khulna",
+    "BD-28" => "Kurigram",
+    "BD-29" => "Khagrachari",
+    "BD-30" => "Kushtia",
+    "BD-31" => "Lakshmipur",
+    "BD-32" => "Lalmonirhat",
+    "BD-33" => "Manikganj",
+    "BD-34" => "Mymensingh",
+    "BD-35" => "Munshiganj",
+    "BD-36" => "Madaripur",
+    "BD-37" => "Fgura",
+    "BD-38" => "Moulvibazar",
+    "BD-39" => "Meherpur",
+    "BD-40" => "Narayanganj",
+    "BD-41" => "Netrakona",
+    "BD-42" => "Narsingdi",
+    "BD-43" => "Nara",
+    "BD-44" => "Natore",
+    "BD-45" => "Nawabganj",
+    "BD-46" => "Nilphamari",
+    "BD-47" => "Noakhali",
+    "BD-48" => "Naogaon",
+    "BD-49" => "Pabna",
+    "BD-50" => "Pirojpur",
+    "BD-51" => "Patuakhali",
+    "BD-52" => "Panchagarh",
+    "BD-53" => "Rajbari",
+    "BD-54" => "Rajshahi",
+    "BD-55" => "Rangpur",
+    "BD-56" => "Rangamati",
+    "BD-57" => "Sherpur",
+    "BD-58" => "Satkhira",
+    "BD-59" => "Sirajganj",
+    "BD-60" => "Sylhet",
+    "BD-61" => "Sunamganj",
+    "BD-62" => "Shariatpur",
+    "BD-63" => "Tanga",
+    "BD-64" => "Thakurgaon",
+    "BD-A" => "Barisal",
+    "BD-B" => "Chittagong",
+    "BD-C" => "Dacca",
+    "BD-D" => "Khulna",
+    "BD-E" => "Rajshahi",
+    "BD-F" => "Rangpur",
+    "BD-G" => "Sylhet",
+    "BD-H" => "Mymensingh",
+    "BB-01" => "Christ Church",
+    "BB-02" => "Saint-Andrew",
+    "BB-03" => "Saint-George",
+    "BB-04" => "Saint-James",
+    "BB-05" => "Saint-John",
+    "BB-06" => "Saint-Joseph",
+    "BB-07" => "Saint-Lucy",
+    "BB-08" => "Saint-Michael",
+    "BB-09" => "Saint-Peter",
+    "BB-10" => "Saint-Philip",
+    "BB-11" => "Saint-Thomas",
+    "BY-BR" => "Voblast de Brest",
+    "BY-MA" => "Voblast de Mahiliow",
+    "BY-VI" => "Voblast de Vitebsk",
+    "BE-BRU" => "Région de Bruxelles-Capitale",
+    "BE-VAN" => "Anvers",
+    "BE-VBR" => "Brabant-Flamand",
+    "BE-VLG" => "Flamande, Région",
+    "BE-VLI" => "Limourg",
+    "BE-VOV" => "Flandre-Orientale",
+    "BE-VWV" => "Flandre-Occidentale",
+    "BE-WAL" => "wallonne, Région",
+    "BE-WBR" => "Brabant wallon",
+    "BE-WHT" => "Hainaut",
+    "BE-WLG" => "Liège",
+    "BE-WLX" => "Luxembourg",
+    "BE-WNA" => "Namur",
+    "BZ-BZ" => "Belize",
+    "BZ-CY" => "Cayo",
+    "BZ-CZL" => "Corozal",
+    "BZ-OW" => "Orange Walk",
+    "BZ-SC" => "Stann Creek",
+    "BZ-TOL" => "Toledo",
+    "BJ-AL" => "Alibori",
+    "BJ-AQ" => "Atlantique",
+    "BJ-BO" => "Borgou",
+    "BJ-CO" => "Collines",
+    "BJ-DO" => "Donga",
+    "BJ-LI" => "Litoral",
+    "BJ-MO" => "Mono",
+    "BJ-OU" => "Ouémé",
+    "BJ-PL" => "Plateau",
+    "BJ-ZO" => "Zou",
+    "BT-11" => "Paro",
+    "BT-12" => "Chukha",
+    "BT-15" => "Thimphu",
+    "BT-21" => "Tsirang",
+    "BT-22" => "Dagana",
+    "BT-23" => "Punakha",
+    "BT-24" => "Wangdue Phodrang",
+    "BT-31" => "Sarpang",
+    "BT-32" => "Trongsa",
+    "BT-33" => "Bumthang",
+    "BT-34" => "Zhemgang",
+    "BT-41" => "Trashigang",
+    "BT-42" => "Mongar",
+    "BT-44" => "Lhuntse",
+    "BT-GA" => "Gasa",
+    "BT-TY" => "Trashiyangtse",
+    "BO-B" => "Beni",
+    "BO-C" => "Cochabamba",
+    "BO-H" => "Chuquisaca",
+    "BO-L" => "La Paz",
+    "BO-N" => "Pando",
+    "BO-O" => "Oruro",
+    "BO-P" => "Potosi",
+    "BO-S" => "Santa Cruz",
+    "BO-T" => "Tarija",
+    "BQ-BO" => "Bonaire",
+    "BQ-SA" => "Saba",
+    "BQ-SE" => "Saint Eustache",
+    "BA-BIH" => "Fédération de Bosnie et Herzégovine",
+    "BA-BRC" => "District de Brčko",
+    "BA-SRP" => "République serbe de Bosnie",
+    "BW-CE" => "Central",
+    "BW-GH" => "Ghanzi",
+    "BW-KG" => "Kgalagadi",
+    "BW-KL" => "Kgatleng",
+    "BW-KW" => "Kweneng",
+    "BW-NE" => "Nord-Est",
+    "BW-NW" => "Nord-Ouest",
+    "BW-SE" => "Sud-Est",
+    "BW-SO" => "Sud",
+    "BR-AC" => "Acre",
+    "BR-AL" => "Alagoas",
+    "BR-AM" => "Amazone",
+    "BR-AP" => "Amapa",
+    "BR-BA" => "Bahia",
+    "BR-CE" => "Ceara",
+    "BR-DF" => "District fédéral",
+    "BR-ES" => "Espirito Santo",
+    "BR-GO" => "Goias",
+    "BR-MA" => "Maranhao",
+    "BR-MG" => "Minas Gerais",
+    "BR-MS" => "Mato Grosso do Sul",
+    "BR-MT" => "Mato Grosso",
+    "BR-PA" => "Para",
+    "BR-PB" => "Paraiba",
+    "BR-PE" => "Pernambouc",
+    "BR-PI" => "Piaui",
+    "BR-PR" => "Parana",
+    "BR-RJ" => "Rio de Janeiro",
+    "BR-RN" => "Rio Grande do Norte",
+    "BR-RO" => "Rondonia",
+    "BR-RR" => "Roraima",
+    "BR-RS" => "Rio Grande do Sul",
+    "BR-SC" => "Santa-Catarina",
+    "BR-SE" => "Sergipe",
+    "BR-SP" => "Sôo Paulo",
+    "BR-TO" => "Tocantins",
+    "BN-BE" => "Belait",
+    "BN-BM" => "Brunei-Muara",
+    "BN-TE" => "Temburong",
+    "BN-TU" => "Tutong",
+    "BG-01" => "Blagoevgrad",
+    "BG-02" => "Bourgas",
+    "BG-03" => "Varna",
+    "BG-04" => "Veliko Tarnovo",
+    "BG-05" => "Vidin",
+    "BG-06" => "Vratsa",
+    "BG-07" => "Gabrovo",
+    "BG-08" => "Dobritch",
+    "BG-09" => "Kardjali",
+    "BG-10" => "Kyoustendil",
+    "BG-11" => "Lovetch",
+    "BG-12" => "Montana",
+    "BG-13" => "Pazardjik",
+    "BG-14" => "Pernik",
+    "BG-15" => "Pleven",
+    "BG-16" => "Plovdiv",
+    "BG-17" => "Razgrad",
+    "BG-18" => "Roussé",
+    "BG-19" => "Silistra",
+    "BG-20" => "Sliven",
+    "BG-21" => "Smolyan",
+    "BG-22" => "Sofia-ville",
+    "BG-23" => "Sofia",
+    "BG-24" => "Stara Zagora",
+    "BG-25" => "Targovichte",
+    "BG-26" => "Khaskovo",
+    "BG-27" => "Choumen",
+    "BG-28" => "Yambol",
+    "BF-01" => "Boucle du Mouhoun",
+    "BF-02" => "Cascades",
+    "BF-03" => "Centre",
+    "BF-04" => "Centre-Est",
+    "BF-05" => "Centre-Nord",
+    "BF-06" => "Centre-Ouest",
+    "BF-07" => "Centre-Sud",
+    "BF-08" => "Est",
+    "BF-09" => "Hauts-Bassins",
+    "BF-10" => "Nord",
+    "BF-11" => "Plateau-Central",
+    "BF-12" => "Sahel",
+    "BF-13" => "Sud-Ouest",
+    "BF-BAL" => "Balé",
+    "BF-BAM" => "Bam",
+    "BF-BAN" => "Banwa",
+    "BF-BAZ" => "Bazèga",
+    "BF-BGR" => "Bougouriba",
+    "BF-BLG" => "Boulgou",
+    "BF-BLK" => "Boulkiemdé",
+    "BF-COM" => "Comoé",
+    "BF-GAN" => "Ganzourgou",
+    "BF-GNA" => "Gnagna",
+    "BF-GOU" => "Gourma",
+    "BF-HOU" => "Houet",
+    "BF-IOB" => "Ioba",
+    "BF-KAD" => "Kadiogo",
+    "BF-KEN" => "Kénédougou",
+    "BF-KMD" => "Komandjoari",
+    "BF-KMP" => "Kompienga",
+    "BF-KOP" => "Koulpélogo",
+    "BF-KOS" => "Kossi",
+    "BF-KOT" => "Kouritenga",
+    "BF-KOW" => "Kourwéogo",
+    "BF-LER" => "Léraba",
+    "BF-LOR" => "Loroum",
+    "BF-MOU" => "Mouhoun",
+    "BF-NAM" => "Namentenga",
+    "BF-NAY" => "Nayala",
+    "BF-NOU" => "Noumbiel",
+    "BF-OUB" => "Oubritenga",
+    "BF-OUD" => "Oudalan",
+    "BF-PAS" => "Passoré",
+    "BF-PON" => "Poni",
+    "BF-SEN" => "Séno",
+    "BF-SIS" => "Sissili",
+    "BF-SMT" => "Sanmatenga",
+    "BF-SNG" => "Sanguié",
+    "BF-SOM" => "Soum",
+    "BF-SOR" => "Sourou",
+    "BF-TAP" => "Tapoa",
+    "BF-YAG" => "Yagha",
+    "BF-YAT" => "Yatenga",
+    "BF-ZIR" => "Ziro",
+    "BF-ZON" => "Zondoma",
+    "BF-ZOU" => "Zoundéeogo",
+    "BI-BB" => "Bubanza",
+    "BI-BL" => "Bujumbura Rural",
+    "BI-BM" => "Bujumbura Mairie",
+    "BI-BR" => "Bururi",
+    "BI-CA" => "Cankuzo",
+    "BI-CI" => "Cibitoke",
+    "BI-GI" => "Gitega",
+    "BI-KI" => "Kirundo",
+    "BI-KR" => "Karuzi",
+    "BI-KY" => "Kayanza",
+    "BI-MA" => "Makamba",
+    "BI-MU" => "Muramvya",
+    "BI-MW" => "Mwaro",
+    "BI-NG" => "Ngozi",
+    "BI-RT" => "Rutana",
+    "BI-RY" => "Ruyigi",
+    "CV-B" => "Îles de Barlavento",
+    "CV-BR" => "Brava",
+    "CV-BV" => "Boa Vista",
+    "CV-CA" => "Santa-Catarina",
+    "CV-CR" => "Santa Cruz",
+    "CV-MA" => "Maio",
+    "CV-MO" => "Mosteiros",
+    "CV-PA" => "Paul",
+    "CV-PN" => "Porto Novo",
+    "CV-PR" => "Praia",
+    "CV-RB" => "Ribeira Brava",
+    "CV-RG" => "Ribeira Grande",
+    "CV-RS" => "Ribeira Grande de Santiago",
+    "CV-S" => "Îles de Sotavento",
+    "CV-SD" => "São Domingos",
+    "CV-SF" => "São Filipe",
+    "CV-SL" => "Sal",
+    "CV-SM" => "São Miguel",
+    "CV-SO" => "São Lourenço dos Órgãos",
+    "CV-SS" => "São Salvador do Mundo",
+    "CV-SV" => "São Vicente",
+    "CV-TA" => "Tarrafal",
+    "CV-TS" => "Tarrafal de São Nicolau",
+    "KH-11" => "Mondol Kiri",
+    "KH-12" => "Phnom Penh",
+    "KH-13" => "Preah Vihear",
+    "KH-14" => "Prey Veaeng",
+    "KH-15" => "Pothisat",
+    "KH-16" => "Rotanak Kiri",
+    "KH-17" => "Siem Reab",
+    "KH-20" => "Svaay Rieng",
+    "KH-21" => "Taakaev",
+    "KH-22" => "Otdar Mean Chey",
+    "KH-4" => "Kampong Chhnang",
+    "KH-7" => "Kampot",
+    "CM-AD" => "Adamaoua",
+    "CM-CE" => "Centre",
+    "CM-EN" => "Extrême-Nord",
+    "CM-ES" => "Est",
+    "CM-LT" => "Litoral",
+    "CM-NO" => "Nord",
+    "CM-NW" => "Nord-Ouest",
+    "CM-OU" => "Ouest",
+    "CM-SU" => "Sud",
+    "CM-SW" => "Sud-Ouest",
+    "CA-AB" => "Alberta",
+    "CA-BC" => "Colombie-Britannique",
+    "CA-MB" => "Manitoba",
+    "CA-NB" => "Nouveau Brunswick",
+    "CA-NL" => "Terre-Neuve-et-Labrador",
+    "CA-NS" => "Nouvelle-Écosse",
+    "CA-NT" => "Territoires-du-Nord-Ouest",
+    "CA-NU" => "Nunavut",
+    "CA-ON" => "Ontario",
+    "CA-PE" => "Île-du-Prince Édouard",
+    "CA-QC" => "Québec",
+    "CA-SK" => "Saskatchewan",
+    "CF-AC" => "Ouham",
+    "CF-BB" => "Bamingui-Bangoran",
+    "CF-BGF" => "Bangui",
+    "CF-BK" => "Basse-Kotto",
+    "CF-HK" => "Haute-Kotto",
+    "CF-HM" => "Haut-Mbomou",
+    "CF-HS" => "Haute-Sangha / Mambéré-Kadéï",
+    "CF-KB" => "Gribingui",
+    "CF-LB" => "Lobaye",
+    "CF-MB" => "Mbomou",
+    "CF-NM" => "Nana-Mambéré",
+    "CF-OP" => "Ouham-Pendé",
+    "CF-SE" => "Sangha",
+    "CF-UK" => "Ouaka",
+    "CF-VK" => "Vakaga",
+    "TD-ND" => "Ville de Ndjamena",
+    "TD-TI" => "Tibesti",
+    "CL-AN" => "Antofagasta",
+    "CL-AP" => "Arica et Parinacota",
+    "CL-AT" => "Atacama",
+    "CL-CO" => "Coquimbo",
+    "CL-LI" => "Libertador General Bernardo O'Higgins",
+    "CL-LL" => "Los Lagos",
+    "CL-LR" => "Los Rios",
+    "CL-ML" => "Maule",
+    "CL-RM" => "Région métropolitaine de Santiago",
+    "CL-TA" => "Tarapaca",
+    "CL-VS" => "Valparaiso",
+    "CN-AH" => "Anhui Sheng",
+    "CN-BJ" => "Beijing Shi",
+    "CN-CQ" => "Chongqing Shi",
+    "CN-FJ" => "Fujian Sheng",
+    "CN-GD" => "Guangdong Sheng",
+    "CN-GS" => "Gansu Sheng",
+    "CN-GX" => "Guangxi Zhuangzu Zizhiqu",
+    "CN-GZ" => "Guizhou Sheng",
+    "CN-HA" => "Henan Sheng",
+    "CN-HB" => "Hubei Sheng",
+    "CN-HE" => "Hebei Sheng",
+    "CN-HI" => "Hainan Sheng",
+    "CN-HL" => "Heilongjiang Sheng",
+    "CN-HN" => "Hunan Sheng",
+    "CN-JL" => "Jilin Sheng",
+    "CN-JS" => "Jiangsu Sheng",
+    "CN-JX" => "Jiangxi Sheng",
+    "CN-LN" => "Liaoning Sheng",
+    "CN-NM" => "Nei Mongol Zizhiqu",
+    "CN-NX" => "Ningxia Huizi Zizhiqu",
+    "CN-QH" => "Qinghai Sheng",
+    "CN-SC" => "Sichuan Sheng",
+    "CN-SD" => "Shandong Sheng",
+    "CN-SH" => "Shanghai Shi",
+    "CN-SN" => "Shaanxi Sheng",
+    "CN-SX" => "Shanxi Sheng",
+    "CN-TJ" => "Tianjin Shi",
+    "CN-XJ" => "Xinjiang Uygur Zizhiqu",
+    "CN-XZ" => "Xizang Zizhiqu",
+    "CN-YN" => "Yunnan Sheng",
+    "CN-ZJ" => "Zhejiang Sheng",
+    "CO-AMA" => "Amazone",
+    "CO-ANT" => "Antioquia",
+    "CO-ARA" => "Arauca",
+    "CO-ATL" => "Atlántico",
+    "CO-BOL" => "Bolivar",
+    "CO-BOY" => "Boyacá",
+    "CO-CAL" => "Caldas",
+    "CO-CAQ" => "Caquetá",
+    "CO-CAS" => "Casanare",
+    "CO-CAU" => "Cauca",
+    "CO-CES" => "Cesar",
+    "CO-CHO" => "Chocó",
+    "CO-COR" => "Cordoba",
+    "CO-CUN" => "Cundinamarca",
+    "CO-DC" => "District de la capitale Bogota",
+    "CO-GUA" => "Guainía",
+    "CO-GUV" => "Guaviare",
+    "CO-HUI" => "Huila",
+    "CO-LAG" => "La Guajira",
+    "CO-MAG" => "Magdalena",
+    "CO-MET" => "Meta",
+    "CO-NAR" => "Nariño",
+    "CO-NSA" => "Norte de Santander",
+    "CO-PUT" => "Putumayo",
+    "CO-QUI" => "Quindio",
+    "CO-RIS" => "Risaralda",
+    "CO-SAN" => "Santander",
+    "CO-SAP" => "San Andrés y Providencia",
+    "CO-SUC" => "Sucre",
+    "CO-TOL" => "Tolima",
+    "CO-VAC" => "Valle del Cauca",
+    "CO-VAU" => "Vaupés",
+    "CO-VID" => "Vichada",
+    "CG-11" => "Bouenza",
+    "CG-12" => "Pool",
+    "CG-13" => "Sangha",
+    "CG-14" => "Plateaux",
+    "CG-15" => "Cuvette-Ouest",
+    "CG-2" => "Lékoumou",
+    "CG-5" => "Kouilou",
+    "CG-7" => "Likouala",
+    "CG-8" => "Cuvette",
+    "CG-9" => "Niari",
+    "CG-BZV" => "Brazzaville",
+    "CD-EQ" => "Équateur",
+    "CD-KN" => "Kinshasa",
+    "CD-MA" => "Maniema",
+    "CD-NK" => "Nord-Kivu",
+    "CD-SK" => "Sud-Kivu",
+    "CR-A" => "Alajuela",
+    "CR-C" => "Cartago",
+    "CR-G" => "Guanacaste",
+    "CR-H" => "Heredia",
+    "CR-L" => "Limon",
+    "CR-P" => "Puntarenas",
+    "CR-SJ" => "San José",
+    "CI-AB" => "Abidjan",
+    "CI-CM" => "Comoé",
+    "HR-01" => "Zagreb",
+    "HR-02" => "Krapina-Zagorje",
+    "HR-03" => "Sisak-Moslavina",
+    "HR-04" => "Karlovac",
+    "HR-05" => "Varaždin",
+    "HR-06" => "Koprivnica-Križevci",
+    "HR-07" => "Bjelovar-Bilogora",
+    "HR-08" => "Primorje-Gorski Kotar",
+    "HR-09" => "Lika-Senj",
+    "HR-10" => "Virovitica-Podravina",
+    "HR-11" => "Požega-Slavonie",
+    "HR-12" => "Brod-Posavina",
+    "HR-13" => "Zadar",
+    "HR-14" => "Osijek-Baranja",
+    "HR-15" => "Šibenik-Knin",
+    "HR-16" => "Vukovar-Syrmie",
+    "HR-17" => "Split-Dalmatie",
+    "HR-18" => "Istrie",
+    "HR-19" => "Dubrovnik-Neretva",
+    "HR-20" => "Međimurje",
+    "HR-21" => "Ville de Zagreb",
+    "CU-03" => "La Habana",
+    "CU-04" => "Matanzas",
+    "CU-05" => "Villa Clara",
+    "CU-06" => "Cienfuegos",
+    "CU-07" => "Sancti Spiritus",
+    "CU-08" => "Ciego de Avila",
+    "CU-09" => "Camagüey",
+    "CU-10" => "Las Tunas",
+    "CU-11" => "Holguin",
+    "CU-12" => "Granma",
+    "CU-13" => "Santiago de Cuba",
+    "CU-14" => "Guantanamo",
+    "CU-99" => "Île de la Juventud",
+    "CZ-20" => "Bohême centrale",
+    "CZ-201" => "Benešov",
+    "CZ-202" => "Beroun",
+    "CZ-203" => "Kladno",
+    "CZ-204" => "Kolin",
+    "CZ-205" => "Kutna Hora",
+    "CZ-206" => "Melnik",
+    "CZ-207" => "Mlada Boleslav",
+    "CZ-208" => "Nymburk",
+    "CZ-209" => "Prague-Est",
+    "CZ-20A" => "Prague-Ouest",
+    "CZ-20B" => "Pribram",
+    "CZ-20C" => "Rakovnik",
+    "CZ-31" => "Bohême du Sud",
+    "CZ-311" => "Ceske Budejovice",
+    "CZ-312" => "Cesky Krumlov",
+    "CZ-313" => "Jindrichuv Hradec",
+    "CZ-314" => "Pisek",
+    "CZ-315" => "Prachatice",
+    "CZ-316" => "Strakonice",
+    "CZ-317" => "Tabor",
+    "CZ-32" => "Plzeň",
+    "CZ-321" => "Domazlice",
+    "CZ-322" => "Klatovy",
+    "CZ-323" => "Pilsen-ville",
+    "CZ-324" => "Pilsen-Sud",
+    "CZ-325" => "Pilsen-Nord",
+    "CZ-326" => "Rokycany",
+    "CZ-327" => "Tachov",
+    "CZ-41" => "Karlovy Vary",
+    "CZ-411" => "Cheb",
+    "CZ-412" => "Carlsbad",
+    "CZ-413" => "Sokolov",
+    "CZ-42" => "Ústí nad Labem",
+    "CZ-421" => "Decin",
+    "CZ-422" => "Chomutov",
+    "CZ-423" => "Litomerice",
+    "CZ-424" => "Louny",
+    "CZ-425" => "Most",
+    "CZ-426" => "Teplice",
+    "CZ-427" => "Usti nad Labem",
+    "CZ-51" => "liberec",
+    "CZ-511" => "Ceska Lipa",
+    "CZ-512" => "Jablonec nad Nisou",
+    "CZ-513" => "Liberec",
+    "CZ-514" => "Semily",
+    "CZ-52" => "Hradec Králové",
+    "CZ-521" => "Hradec Kralové",
+    "CZ-522" => "Jicin",
+    "CZ-523" => "Nachod",
+    "CZ-524" => "Rychnov nad Kneznou",
+    "CZ-525" => "Trutnov",
+    "CZ-53" => "Pardubice",
+    "CZ-531" => "Chrudim",
+    "CZ-532" => "Pardubice",
+    "CZ-533" => "Svitavy",
+    "CZ-534" => "Usti nad Orlici",
+    "CZ-63" => "Région de Vysocina",
+    "CZ-631" => "Havlickuv Brod",
+    "CZ-632" => "Jihlava",
+    "CZ-633" => "Pelhrimov",
+    "CZ-634" => "Trebic",
+    "CZ-635" => "Zdar nad Sazavou",
+    "CZ-64" => "Moravie du Sud",
+    "CZ-641" => "Blansko",
+    "CZ-642" => "Brno-mesto",
+    "CZ-643" => "Brno-venkov",
+    "CZ-644" => "Breclav",
+    "CZ-645" => "Hodonin",
+    "CZ-646" => "Vyskov",
+    "CZ-647" => "Znojmo",
+    "CZ-71" => "Olomouc",
+    "CZ-711" => "Jesenik",
+    "CZ-712" => "Olomouc",
+    "CZ-713" => "Prostejov",
+    "CZ-714" => "Prerov",
+    "CZ-715" => "Sumperk",
+    "CZ-72" => "Zlin",
+    "CZ-721" => "Kroměříž",
+    "CZ-722" => "Uherske Hradiste",
+    "CZ-723" => "Vsetin",
+    "CZ-724" => "Zlin",
+    "CZ-80" => "Moravie-Silésie",
+    "CZ-801" => "Bruntal",
+    "CZ-803" => "Karvina",
+    "CZ-804" => "Novy Jicin",
+    "CZ-805" => "Opava",
+    "CZ-806" => "Ostrava-ville",
+    "DK-81" => "Jutland septentrional",
+    "DK-82" => "Jutland central",
+    "DK-83" => "Sud-Danemark",
+    "DK-84" => "Hovedstaden",
+    "DK-85" => "Sjælland",
+    "DJ-AR" => "Arta",
+    "DJ-AS" => "Ali Sabieh",
+    "DJ-DI" => "Dikhil",
+    "DJ-DJ" => "Djibouti",
+    "DJ-TA" => "Tadjoura",
+    "DM-02" => "Saint-Andrew",
+    "DM-03" => "Saint-David",
+    "DM-04" => "Saint-George",
+    "DM-05" => "Saint-John",
+    "DM-06" => "Saint-Joseph",
+    "DM-07" => "Saint-Luke",
+    "DM-08" => "Saint-Mark",
+    "DM-09" => "Saint-Patrick",
+    "DM-10" => "Saint-Paul",
+    "DM-11" => "Saint-Peter",
+    "DO-01" => "District National.(Santo Domingo)",
+    "DO-02" => "Azua",
+    "DO-04" => "Barahona",
+    "DO-05" => "Dajabón",
+    "DO-06" => "Duarte",
+    "DO-09" => "Espaillat",
+    "DO-10" => "Independencia",
+    "DO-11" => "La Altagracia",
+    "DO-12" => "La Romana",
+    "DO-13" => "La Vega",
+    "DO-14" => "Maria Trinidad Sanchez",
+    "DO-15" => "Monte Cristi",
+    "DO-16" => "Pedernales",
+    "DO-17" => "Peravia",
+    "DO-18" => "Puerto Plata",
+    "DO-20" => "Samaná",
+    "DO-21" => "San Cristóbal",
+    "DO-22" => "San Juan",
+    "DO-23" => "San Pedro de Macorís",
+    "DO-24" => "Sánchez Ramírez",
+    "DO-25" => "Santiago",
+    "DO-26" => "Santiago Rodríguez",
+    "DO-27" => "Valverde",
+    "DO-28" => "Monseñor Nouel",
+    "DO-29" => "Monte Plata",
+    "DO-30" => "Hato Mayor",
+    "EC-A" => "Azuay",
+    "EC-B" => "Bolivar",
+    "EC-C" => "Carchi",
+    "EC-D" => "Orellana",
+    "EC-E" => "Esmeraldas",
+    "EC-F" => "Cañar",
+    "EC-G" => "Guayas",
+    "EC-H" => "Chimborazo",
+    "EC-I" => "Imbabura",
+    "EC-L" => "Loja",
+    "EC-M" => "Manabi",
+    "EC-N" => "Napo",
+    "EC-O" => "El Oro",
+    "EC-P" => "Pichincha",
+    "EC-R" => "Los Rios",
+    "EC-SD" => "Santo Domingo de los Tsáchilas",
+    "EC-SE" => "Santa Elena",
+    "EC-T" => "Tungurahua",
+    "EC-U" => "Sucumbios",
+    "EC-W" => "Galapagos",
+    "EC-X" => "Cotopaxi",
+    "EC-Y" => "Pastaza",
+    "EG-ALX" => "Al Iskandariyah",
+    "EG-ASN" => "Aswan",
+    "EG-BA" => "Mer Rouge",
+    "EG-BNS" => "Bani Suwayf",
+    "EG-C" => "Al Qahirah",
+    "EG-DK" => "Ad Daqahliyah",
+    "EG-FYM" => "Al Fayyum",
+    "EG-GH" => "Al Gharbiyah",
+    "EG-GZ" => "Al Jizah",
+    "EG-JS" => "Janub Sina",
+    "EG-KB" => "Al Qalyubiyah",
+    "EG-KFS" => "Kafr ash Shaykh",
+    "EG-KN" => "Qina",
+    "EG-MN" => "Al Minya",
+    "EG-MNF" => "Al Minufiyah",
+    "EG-SHG" => "Suhaj",
+    "EG-SHR" => "Ash Sharqiyah",
+    "EG-SUZ" => "As Suways",
+    "EG-WAD" => "Al Wadi al Jadid",
+    "SV-AH" => "Ahuachapán",
+    "SV-CA" => "Cabañas",
+    "SV-CH" => "Chalatenango",
+    "SV-CU" => "Cuscatlán",
+    "SV-LI" => "La Libertad",
+    "SV-MO" => "Morazán",
+    "SV-PA" => "La Paz",
+    "SV-SA" => "Santa Ana",
+    "SV-SM" => "San Miguel",
+    "SV-SO" => "Sonsonate",
+    "SV-SS" => "San Salvador",
+    "SV-SV" => "San Vicente",
+    "SV-UN" => "La Unión",
+    "SV-US" => "Usulután",
+    "GQ-KN" => "Kié-Ntem",
+    "GQ-LI" => "Litoral",
+    "GQ-WN" => "Wele-Nzás",
+    "ER-AN" => "Ansabā",
+    "ER-DU" => "Al Janūbī",
+    "ER-MA" => "Al Awsaţ",
+    "EE-205" => "Hiiumaa",
+    "EE-37" => "Harjumaa",
+    "EE-39" => "Hiiumaa",
+    "EE-45" => "Ida-Virumaa",
+    "EE-50" => "Jogevamaa",
+    "EE-52" => "Järvamaa",
+    "EE-56" => "Läänemaa",
+    "EE-60" => "Lääne-Virumaa",
+    "EE-64" => "Põlvamaa",
+    "EE-68" => "Pärnumaa",
+    "EE-71" => "Raplamaa",
+    "EE-714" => "Saaremaa",
+    "EE-74" => "Saaremaa",
+    "EE-79" => "Tartumaa",
+    "EE-81" => "Valgamaa",
+    "EE-84" => "Viljandimaa",
+    "EE-87" => "Vorumaa",
+    "SZ-HH" => "Hhohho",
+    "SZ-LU" => "Lubombo",
+    "SZ-MA" => "Manzini",
+    "SZ-SH" => "Shiselweni",
+    "ET-AA" => "Addis-Abeba",
+    "FJ-C" => "Central",
+    "FJ-E" => "Est",
+    "FJ-N" => "Nord",
+    "FJ-R" => "Rotuma",
+    "FJ-W" => "Ouest",
+    "FI-01" => "Åland",
+    "FI-02" => "Carélie du Sud",
+    "FI-03" => "Ostrobotnie du Sud",
+    "FI-04" => "Savonie du Sud",
+    "FI-05" => "Kainuu",
+    "FI-07" => "Ostrobotnie-Centrale",
+    "FI-08" => "Finlande-Centrale",
+    "FI-09" => "Vallée de la Kymi",
+    "FI-10" => "Laponie",
+    "FI-12" => "Ostrobotnie",
+    "FR-01" => "Ain",
+    "FR-02" => "Aisne",
+    "FR-03" => "Allier",
+    "FR-04" => "Alpes-de-Haute-Provence",
+    "FR-05" => "Hautes-Alpes",
+    "FR-06" => "Alpes-Maritimes",
+    "FR-07" => "Ardèche",
+    "FR-08" => "Ardennes",
+    "FR-09" => "Ariège",
+    "FR-10" => "Aube",
+    "FR-11" => "Aude",
+    "FR-12" => "Aveyron",
+    "FR-13" => "Bouches-du-Rhône",
+    "FR-14" => "Calvados",
+    "FR-15" => "Cantal",
+    "FR-16" => "Charente",
+    "FR-17" => "Charente-Maritime",
+    "FR-18" => "Cher",
+    "FR-19" => "Corrèze",
+    "FR-20R" => "Corse",
+    "FR-21" => "Côte-d'Or",
+    "FR-22" => "Côtes-d'Armor",
+    "FR-23" => "Creuse",
+    "FR-24" => "Dordogne",
+    "FR-25" => "Doubs",
+    "FR-26" => "Drôme",
+    "FR-27" => "Eure",
+    "FR-28" => "Eure-et-Loir",
+    "FR-29" => "Finistère",
+    "FR-2A" => "Corse-du-Sud",
+    "FR-2B" => "Haute-Corse",
+    "FR-30" => "Gard",
+    "FR-31" => "Haute-Garonne",
+    "FR-32" => "Gers",
+    "FR-33" => "Gironde",
+    "FR-34" => "Hérault",
+    "FR-35" => "Ille-et-Vilaine",
+    "FR-36" => "Indre",
+    "FR-37" => "Indre-et-Loire",
+    "FR-38" => "Isère",
+    "FR-39" => "Jura",
+    "FR-40" => "Landes",
+    "FR-41" => "Loir-et-Cher",
+    "FR-42" => "Loire",
+    "FR-43" => "Haute-Loire",
+    "FR-44" => "Loire-Atlantique",
+    "FR-45" => "Loiret",
+    "FR-46" => "Lot",
+    "FR-47" => "Lot-et-Garonne",
+    "FR-48" => "Lozère",
+    "FR-49" => "Maine-et-Loire",
+    "FR-50" => "Manche",
+    "FR-51" => "Marne",
+    "FR-52" => "Haute-Marne",
+    "FR-53" => "Mayenne",
+    "FR-54" => "Meurthe-et-Moselle",
+    "FR-55" => "Meuse",
+    "FR-56" => "Morbihan",
+    "FR-57" => "Moselle",
+    "FR-58" => "Nièvre",
+    "FR-59" => "Nord",
+    "FR-60" => "Oise",
+    "FR-61" => "Orne",
+    "FR-62" => "Pas-de-Calais",
+    "FR-63" => "Puy-de-Dôme",
+    "FR-64" => "Pyrénées-Atlantiques",
+    "FR-65" => "Hautes-Pyrénées",
+    "FR-66" => "Pyrénées-Orientales",
+    "FR-67" => "Bas-Rhin",
+    "FR-68" => "Haut-Rhin",
+    "FR-69" => "Rhône",
+    "FR-70" => "Haute-Saône",
+    "FR-71" => "Saône-et-Loire",
+    "FR-72" => "Sarthe",
+    "FR-73" => "Savoie",
+    "FR-74" => "Haute-Savoie",
+    "FR-76" => "Seine-Maritime",
+    "FR-77" => "Seine-et-Marne",
+    "FR-78" => "Yvelines",
+    "FR-79" => "Deux-Sèvres",
+    "FR-80" => "Somme",
+    "FR-81" => "Tarn",
+    "FR-82" => "Tarn-et-Garonne",
+    "FR-83" => "Var",
+    "FR-84" => "Vaucluse",
+    "FR-85" => "Vendée",
+    "FR-86" => "Vienne",
+    "FR-87" => "Haute-Vienne",
+    "FR-88" => "Vosges",
+    "FR-89" => "Yonne",
+    "FR-90" => "Territoire de Belfort",
+    "FR-91" => "Essonne",
+    "FR-92" => "Hauts-de-Seine",
+    "FR-93" => "Seine-Saint-Denis",
+    "FR-94" => "Val-de-Marne",
+    "FR-95" => "Val-d’Oise",
+    "FR-971" => "Guadeloupe",
+    "FR-972" => "Martinique",
+    "FR-973" => "Guyane (française)",
+    "FR-974" => "La Réunion",
+    "FR-976" => "Mayotte",
+    "FR-ARA" => "Auvergne-Rhône-Alpes",
+    "FR-BFC" => "Bourgogne-Franche-Comté",
+    "FR-BL" => "Saint-Barthélemy",
+    "FR-BRE" => "Bretagne",
+    "FR-CP" => "Clipperton",
+    "FR-CVL" => "Centre-Val de Loire",
+    "FR-GES" => "Grand Est",
+    "FR-HDF" => "Hauts-de-France",
+    "FR-IDF" => "Île-de-France",
+    "FR-MF" => "Saint-Martin",
+    "FR-NAQ" => "Nouvelle-Aquitaine",
+    "FR-NC" => "Nouvelle-Calédonie",
+    "FR-NOR" => "Normandie",
+    "FR-OCC" => "Occitanie",
+    "FR-PAC" => "Provence-Alpes-Côte d'Azur",
+    "FR-PDL" => "Pays de la Loire",
+    "FR-PF" => "Polynésie française",
+    "FR-PM" => "Saint-Pierre-et-Miquelon",
+    "FR-TF" => "Terres australes françaises",
+    "FR-WF" => "Wallis-et-Futuna",
+    "GA-1" => "Estuaire",
+    "GA-2" => "Haut-Ogooué",
+    "GA-3" => "Moyen-Ogooué",
+    "GA-4" => "Ngounié",
+    "GA-5" => "Nyanga",
+    "GA-6" => "Ogooué-Ivindo",
+    "GA-7" => "Ogooué-Lolo",
+    "GA-8" => "Ogooué-Maritime",
+    "GA-9" => "Woleu-Ntem",
+    "GM-B" => "Banjul",
+    "GM-L" => "Rivière basse",
+    "GM-M" => "Rivière haute",
+    "GM-N" => "Rive nord",
+    "GM-U" => "Rive haute",
+    "GM-W" => "Ouest",
+    "GE-AB" => "Abkhazie",
+    "GE-AJ" => "Adjarie",
+    "GE-GU" => "Guria",
+    "DE-BB" => "Brandebourg",
+    "DE-BE" => "Berlin",
+    "DE-BW" => "Bade-Wurtemberg",
+    "DE-BY" => "Bavière",
+    "DE-HB" => "Brème",
+    "DE-HE" => "Hesse",
+    "DE-HH" => "Hambourg",
+    "DE-MV" => "Mecklembourg-Poméranie occidentale",
+    "DE-NI" => "Basse-Saxe",
+    "DE-NW" => "Rhénanie du Nord-Westfalie",
+    "DE-RP" => "Rhénanie-Palatinat",
+    "DE-SH" => "Schleswig-Holstein",
+    "DE-SL" => "Sarre",
+    "DE-SN" => "Saxe",
+    "DE-ST" => "Saxe-Anhalt",
+    "DE-TH" => "Turinge",
+    "GH-AA" => "Grand Accra",
+    "GH-AH" => "Ashanti",
+    "GH-CP" => "Central",
+    "GH-EP" => "Est",
+    "GH-NE" => "Nord-Est",
+    "GH-NP" => "Nord",
+    "GH-TV" => "Volta",
+    "GH-UE" => "Haut Ghana oriental",
+    "GH-UW" => "Haut Ghana Occidental",
+    "GH-WP" => "Ouest",
+    "GL-KU" => "Municipalité de Kujalleq",
+    "GL-QE" => "Municipalité de Qeqqata",
+    "GL-SM" => "Municipalité de Sermersooq",
+    "GD-01" => "Saint-Andrew",
+    "GD-02" => "Saint-David",
+    "GD-03" => "Saint-George",
+    "GD-04" => "Saint-John",
+    "GD-05" => "Saint-Mark",
+    "GD-06" => "Saint-Patrick",
+    "GD-10" => "Îles Grenadines du Sud",
+    "GN-B" => "Boké",
+    "GN-BE" => "Beyla",
+    "GN-BF" => "Boffa",
+    "GN-BK" => "Boké",
+    "GN-C" => "Conakry",
+    "GN-CO" => "Coyah",
+    "GN-D" => "Kindia",
+    "GN-DB" => "Dabola",
+    "GN-DI" => "Dinguiraye",
+    "GN-DL" => "Dalaba",
+    "GN-DU" => "Dubréka",
+    "GN-F" => "Faranah",
+    "GN-FA" => "Faranah",
+    "GN-FO" => "Forécariah",
+    "GN-FR" => "Fria",
+    "GN-GA" => "Gaoual",
+    "GN-GU" => "Guékédou",
+    "GN-K" => "Kankan",
+    "GN-KA" => "Kankan",
+    "GN-KB" => "Koubia",
+    "GN-KD" => "Kindia",
+    "GN-KE" => "Kérouané",
+    "GN-KN" => "Koundara",
+    "GN-KO" => "Kouroussa",
+    "GN-KS" => "Kissidougou",
+    "GN-L" => "Labé",
+    "GN-LA" => "Labé",
+    "GN-LE" => "Lélouma",
+    "GN-LO" => "Lola",
+    "GN-M" => "Mamou",
+    "GN-MC" => "Macenta",
+    "GN-MD" => "Mandiana",
+    "GN-ML" => "Mali",
+    "GN-MM" => "Mamou",
+    "GN-N" => "Nzérékoré",
+    "GN-NZ" => "Nzérékoré",
+    "GN-PI" => "Pita",
+    "GN-SI" => "Siguiri",
+    "GN-TE" => "Télimélé",
+    "GN-TO" => "Tougué",
+    "GN-YO" => "Yomou",
+    "GW-BA" => "Bafatá",
+    "GW-BM" => "Biombo",
+    "GW-BS" => "Bissau",
+    "GW-CA" => "Cacheu",
+    "GW-GA" => "Gabu",
+    "GW-L" => "Est",
+    "GW-N" => "Nord",
+    "GW-OI" => "Oio",
+    "GW-QU" => "Quinara",
+    "GW-S" => "Sud",
+    "GW-TO" => "Tombali",
+    "GY-BA" => "Barima-Waini",
+    "GY-CU" => "Cuyuni-Mazaruni",
+    "GY-DE" => "Demerara-Mahaica",
+    "GY-EB" => "Berbice Oriental-Courantyne",
+    "GY-ES" => "Îles d'Essequibo-Demerara occidental",
+    "GY-MA" => "Mahaica-Berbice",
+    "GY-PM" => "Pomeroon-Supenaam",
+    "GY-PT" => "Potaro-Siparuni",
+    "GY-UD" => "Haut-Demerara-Berbice",
+    "GY-UT" => "Haut-Takutu-Haut-Essequibo",
+    "HT-AR" => "Artibonite",
+    "HT-CE" => "Centre",
+    "HT-ND" => "Nord",
+    "HT-NE" => "Nord-Est",
+    "HT-NO" => "Nord-Ouest",
+    "HN-AT" => "Atlántida",
+    "HN-CH" => "Choluteca",
+    "HN-CL" => "Colón",
+    "HN-CM" => "Comayagua",
+    "HN-CP" => "Copán",
+    "HN-CR" => "Cortés",
+    "HN-EP" => "El Paraíso",
+    "HN-FM" => "Francisco Morazán",
+    "HN-GD" => "Gracias a Dios",
+    "HN-IB" => "Îles de la Baie",
+    "HN-IN" => "Intibucá",
+    "HN-LE" => "Lempira",
+    "HN-LP" => "La Paz",
+    "HN-OC" => "Ocotepeque",
+    "HN-OL" => "Olancho",
+    "HN-SB" => "Santa Bárbara",
+    "HN-VA" => "Valle",
+    "HN-YO" => "Yoro",
+    "HU-BA" => "Baranya",
+    "HU-BC" => "Békéscsaba",
+    "HU-BE" => "Békés",
+    "HU-BK" => "Bács-Kiskun",
+    "HU-BU" => "Budapest",
+    "HU-BZ" => "Borsod-Abaúj-Zemplén",
+    "HU-CS" => "Csongrád",
+    "HU-DE" => "Debrecen",
+    "HU-DU" => "Dunaújváros",
+    "HU-EG" => "Eger",
+    "HU-ER" => "Érd",
+    "HU-FE" => "Fejér",
+    "HU-GS" => "Győr-Moson-Sopron",
+    "HU-GY" => "Győr",
+    "HU-HB" => "Hajdú-Bihar",
+    "HU-HE" => "Heves",
+    "HU-HV" => "Hódmezővásárhely",
+    "HU-JN" => "Jász-Nagykun-Szolnok",
+    "HU-KE" => "Komárom-Esztergom",
+    "HU-KM" => "Kecskemét",
+    "HU-KV" => "Kaposvár",
+    "HU-MI" => "Miskolc",
+    "HU-NK" => "Nagykanizsa",
+    "HU-NO" => "Nógrád",
+    "HU-NY" => "Nyíregyháza",
+    "HU-PE" => "Pest",
+    "HU-PS" => "Pécs",
+    "HU-SD" => "Szeged",
+    "HU-SF" => "Székesfehérvár",
+    "HU-SH" => "Szombathely",
+    "HU-SK" => "Szolnok",
+    "HU-SN" => "Sopron",
+    "HU-SO" => "Somogy",
+    "HU-SS" => "Szekszárd",
+    "HU-ST" => "Salgótarján",
+    "HU-SZ" => "Szabolcs-Szatmár-Bereg",
+    "HU-TB" => "Tatabánya",
+    "HU-TO" => "Tolna",
+    "HU-VA" => "Vas",
+    "HU-VE" => "Veszprém",
+    "HU-VM" => "Veszprém",
+    "HU-ZA" => "Zala",
+    "HU-ZE" => "Zalaegerszeg",
+    "IS-2" => "Péninsule méridionale",
+    "IS-3" => "Ouest",
+    "IS-4" => "Fjords de l'ouest",
+    "IS-5" => "Nord-Ouest",
+    "IS-6" => "Nord-est",
+    "IS-7" => "Est",
+    "IS-8" => "Sud",
+    "IN-AN" => "Îles Andaman et Nicobar",
+    "IN-AP" => "Andhra Pradesh",
+    "IN-AS" => "Assam",
+    "IN-DL" => "Delhi",
+    "IN-GA" => "Goa",
+    "IN-KL" => "Kerala",
+    "IN-LD" => "Lakshadweep",
+    "IN-MN" => "Manipur",
+    "IN-MP" => "Madhya Pradesh",
+    "IN-MZ" => "Mizoram",
+    "IN-PB" => "Penjab",
+    "IN-PY" => "Pondichéry",
+    "IN-SK" => "Sikkim",
+    "IN-TR" => "Tripura",
+    "IN-UP" => "Uttar Pradesh",
+    "IN-WB" => "Bengale occidental",
+    "ID-AC" => "Aceh",
+    "ID-BA" => "Bali",
+    "ID-BE" => "Bengkulu",
+    "ID-BT" => "Banten",
+    "ID-GO" => "Gorontalo",
+    "ID-JA" => "Jambi",
+    "ID-JB" => "Java-Ouest",
+    "ID-JI" => "Java-Est",
+    "ID-JK" => "Jakarta",
+    "ID-JT" => "Java-Centre",
+    "ID-JW" => "Java",
+    "ID-KA" => "Kalimantan",
+    "ID-KB" => "Kalimantan-Ouest",
+    "ID-KI" => "Kalimantan-Est",
+    "ID-KR" => "Îles Riau",
+    "ID-KS" => "Kalimantan-Sud",
+    "ID-KT" => "Kalimantan-Centre",
+    "ID-LA" => "Lampung",
+    "ID-MA" => "Moluques",
+    "ID-ML" => "Moluques",
+    "ID-MU" => "Moluques-Nord",
+    "ID-NB" => "Îles de la Sonde occidentales",
+    "ID-NT" => "Îles de la Sonde orientales",
+    "ID-NU" => "Nusa Tenggara",
+    "ID-PA" => "Papua",
+    "ID-PB" => "Papua Barat",
+    "ID-PP" => "Papua",
+    "ID-RI" => "Riau",
+    "ID-SA" => "Sulawesi-Nord",
+    "ID-SG" => "Sulawesi du Sud-Est",
+    "ID-SL" => "Célèbes",
+    "ID-SM" => "Sumatra",
+    "ID-SN" => "Sulawesi-Sud",
+    "ID-SR" => "Sulawesi-Ouest",
+    "ID-ST" => "Sulawesi-Centre",
+    "ID-SU" => "Sumatra-Nord",
+    "ID-YO" => "Yogyakarta",
+    "IR-00" => "Markazi",
+    "IR-01" => "Gilan",
+    "IR-02" => "Mazandaran",
+    "IR-05" => "Kermanshah",
+    "IR-06" => "Khuzestan",
+    "IR-07" => "Fars",
+    "IR-08" => "Kerman",
+    "IR-10" => "Ispahan",
+    "IR-11" => "Sistan-o-Balouchestan",
+    "IR-12" => "Kurdistan",
+    "IR-13" => "Hamedan",
+    "IR-15" => "Lorestan",
+    "IR-16" => "Ilam",
+    "IR-18" => "Bushehr",
+    "IR-19" => "Zanjan",
+    "IR-20" => "Semnan",
+    "IR-21" => "Yazd",
+    "IR-22" => "Hormozgan",
+    "IR-23" => "Téhéran",
+    "IR-24" => "Ardabil",
+    "IR-25" => "Qom",
+    "IR-26" => "Qazvin",
+    "IR-27" => "Golestan",
+    "IE-CE" => "Clare",
+    "IE-CN" => "Cavan",
+    "IE-CO" => "Cork",
+    "IE-CW" => "Carlow",
+    "IE-D" => "Dublin",
+    "IE-DL" => "Donegal",
+    "IE-G" => "Galway",
+    "IE-KE" => "Kildare",
+    "IE-KK" => "Kilkenny",
+    "IE-KY" => "Kerry",
+    "IE-L" => "Leinster",
+    "IE-LD" => "Longford",
+    "IE-LH" => "Louth",
+    "IE-LK" => "Limerick",
+    "IE-LM" => "Leitrim",
+    "IE-LS" => "Laois",
+    "IE-M" => "Munster",
+    "IE-MH" => "Meath",
+    "IE-MN" => "Monaghan",
+    "IE-MO" => "Mayo",
+    "IE-OY" => "Offaly",
+    "IE-RN" => "Roscommon",
+    "IE-SO" => "Sligo",
+    "IE-TA" => "Tipperary",
+    "IE-U" => "Ulster",
+    "IE-WD" => "Waterford",
+    "IE-WH" => "Westmeath",
+    "IE-WW" => "Wicklow",
+    "IE-WX" => "Wexford",
+    "IL-D" => "Al Janūbī",
+    "IL-M" => "Al Awsaţ",
+    "IT-21" => "Piémont",
+    "IT-25" => "Lombardie",
+    "IT-32" => "Trentin- Haut Adige",
+    "IT-34" => "Vénétie",
+    "IT-42" => "Ligurie",
+    "IT-45" => "Émilie-Romagne",
+    "IT-52" => "Toscane",
+    "IT-55" => "Ombrie",
+    "IT-57" => "Marche",
+    "IT-62" => "Latium",
+    "IT-65" => "Abruzzes",
+    "IT-67" => "Molise",
+    "IT-72" => "Campanie",
+    "IT-75" => "Pouilles",
+    "IT-77" => "Basilicate",
+    "IT-78" => "Calabre",
+    "IT-82" => "Sicile",
+    "IT-88" => "Sardaigne",
+    "IT-AG" => "Agrigente",
+    "IT-AL" => "Alexandrie",
+    "IT-AN" => "Ancône",
+    "IT-AP" => "Ascoli Piceno",
+    "IT-AQ" => "L'Aquila",
+    "IT-AR" => "Arezzo",
+    "IT-AT" => "Asti",
+    "IT-AV" => "Avellino",
+    "IT-BA" => "Bari",
+    "IT-BG" => "Bergame",
+    "IT-BI" => "Biella",
+    "IT-BL" => "Belluno",
+    "IT-BN" => "Benevento",
+    "IT-BO" => "Bologne",
+    "IT-BR" => "Brindisi",
+    "IT-BS" => "Brescia",
+    "IT-BT" => "Barletta-Andria-Trani",
+    "IT-BZ" => "Bolzano",
+    "IT-CA" => "Cagliari",
+    "IT-CB" => "Campobasso",
+    "IT-CE" => "Caserte",
+    "IT-CH" => "Chieti",
+    "IT-CL" => "Caltanissetta",
+    "IT-CN" => "Cunéo",
+    "IT-CO" => "Côme",
+    "IT-CR" => "Crémone",
+    "IT-CS" => "Cosenza",
+    "IT-CT" => "Catane",
+    "IT-CZ" => "Catanzaro",
+    "IT-EN" => "Enna",
+    "IT-FC" => "Forlì-Cesena",
+    "IT-FE" => "Ferrare",
+    "IT-FG" => "Foggia",
+    "IT-FI" => "Florence",
+    "IT-FM" => "Fermo",
+    "IT-FR" => "Frosinone",
+    "IT-GE" => "Gênes",
+    "IT-GO" => "Gorizia",
+    "IT-GR" => "Grosseto",
+    "IT-IM" => "Imperia",
+    "IT-IS" => "Isernia",
+    "IT-KR" => "Crotone",
+    "IT-LC" => "Lecco",
+    "IT-LE" => "Lecce",
+    "IT-LI" => "Livourne",
+    "IT-LO" => "Lodi",
+    "IT-LT" => "Latina",
+    "IT-LU" => "Lucques",
+    "IT-MB" => "Monza et Brianza",
+    "IT-MC" => "Macerata",
+    "IT-ME" => "Messine",
+    "IT-MI" => "Milan",
+    "IT-MN" => "Mantoue",
+    "IT-MO" => "Modène",
+    "IT-MS" => "Massa-Carrara",
+    "IT-MT" => "Matera",
+    "IT-NA" => "Naples",
+    "IT-NO" => "Novare",
+    "IT-NU" => "Nuoro",
+    "IT-OR" => "Oristano",
+    "IT-PA" => "Palerme",
+    "IT-PC" => "Piacence",
+    "IT-PD" => "Padoue",
+    "IT-PE" => "Pescara",
+    "IT-PG" => "Pérouse",
+    "IT-PI" => "Pise",
+    "IT-PN" => "Pordenone",
+    "IT-PO" => "Prato",
+    "IT-PR" => "Parme",
+    "IT-PT" => "Pistoia",
+    "IT-PU" => "Pésaro et Urbino",
+    "IT-PV" => "Pavie",
+    "IT-PZ" => "Potenza",
+    "IT-RA" => "Ravenne",
+    "IT-RC" => "Reggio Calabria",
+    "IT-RE" => "Reggio Emilia",
+    "IT-RG" => "Ragouse",
+    "IT-RI" => "Rieti",
+    "IT-RM" => "roma",
+    "IT-RN" => "Rimini",
+    "IT-RO" => "Rovigo",
+    "IT-SA" => "Salerne",
+    "IT-SI" => "Sienne",
+    "IT-SO" => "Sondrio",
+    "IT-SP" => "La Spezia",
+    "IT-SR" => "Syracuse",
+    "IT-SS" => "Sassari",
+    "IT-SV" => "Savone",
+    "IT-TA" => "Tarante",
+    "IT-TE" => "Teramo",
+    "IT-TN" => "Trente",
+    "IT-TO" => "Turin",
+    "IT-TP" => "Trapani",
+    "IT-TR" => "Terni",
+    "IT-TS" => "Trieste",
+    "IT-TV" => "Trévise",
+    "IT-UD" => "Udine",
+    "IT-VA" => "Varèse",
+    "IT-VB" => "Verbano-Cusio-Ossola",
+    "IT-VC" => "Vercelli",
+    "IT-VE" => "Venise",
+    "IT-VI" => "Vicence",
+    "IT-VR" => "Vérone",
+    "IT-VT" => "Viterbo",
+    "IT-VV" => "Vibo Valentia",
+    "JM-01" => "Kingston",
+    "JM-02" => "Saint-Andrew",
+    "JM-03" => "Saint-Thomas",
+    "JM-04" => "Portland",
+    "JM-05" => "Saint-Mary",
+    "JM-06" => "Saint Ann",
+    "JM-07" => "Trelawny",
+    "JM-08" => "Saint-James",
+    "JM-09" => "Hanover",
+    "JM-10" => "Westmoreland",
+    "JM-11" => "Saint Elizabeth",
+    "JM-12" => "Manchester",
+    "JM-13" => "Clarendon",
+    "JM-14" => "Saint Catherine",
+    "JP-01" => "Hokkaido",
+    "JP-02" => "Aomori",
+    "JP-03" => "Iwate",
+    "JP-04" => "Miyagi",
+    "JP-05" => "Akita",
+    "JP-06" => "Yamagata",
+    "JP-07" => "Fukushima",
+    "JP-08" => "Ibaraki",
+    "JP-09" => "Tochigi",
+    "JP-10" => "Gunma",
+    "JP-11" => "Saitama",
+    "JP-12" => "Chiba",
+    "JP-13" => "Tokyo",
+    "JP-14" => "Kanagawa",
+    "JP-15" => "Niigata",
+    "JP-16" => "Toyama",
+    "JP-17" => "Ishikawa",
+    "JP-18" => "Fukui",
+    "JP-19" => "Yamanashi",
+    "JP-20" => "Nagano",
+    "JP-21" => "Gifu",
+    "JP-22" => "Shizuoka",
+    "JP-23" => "Aichi",
+    "JP-24" => "Mie",
+    "JP-25" => "Shiga",
+    "JP-26" => "Kyoto",
+    "JP-27" => "Osaka",
+    "JP-28" => "Hyogo",
+    "JP-29" => "Nara",
+    "JP-30" => "Wakayama",
+    "JP-31" => "Tottori",
+    "JP-32" => "Shimane",
+    "JP-33" => "Okayama",
+    "JP-34" => "Hiroshima",
+    "JP-35" => "Yamaguchi",
+    "JP-36" => "Tokushima",
+    "JP-37" => "Kagawa",
+    "JP-38" => "Ehime",
+    "JP-39" => "Kochi",
+    "JP-40" => "Fukuoka",
+    "JP-41" => "Saga",
+    "JP-42" => "Nagasaki",
+    "JP-43" => "Kumamoto",
+    "JP-44" => "Oita",
+    "JP-45" => "Miyazaki",
+    "JP-46" => "Kagoshima",
+    "JP-47" => "Okinawa",
+    "JO-AJ" => "‘Ajlūn",
+    "JO-AQ" => "Al ‘Aqabah",
+    "JO-AT" => "Aţ Ţafīlah",
+    "JO-IR" => "Irbid",
+    "JO-JA" => "Jarash",
+    "JO-KA" => "Al Karak",
+    "JO-MA" => "Al Mafraq",
+    "JO-MD" => "Mādabā",
+    "JO-MN" => "Ma‘ān",
+    "KE-01" => "Baringo",
+    "KE-02" => "Bomet",
+    "KE-03" => "Bungoma",
+    "KE-04" => "Busia",
+    "KE-05" => "Elgeyo/Marakwet",
+    "KE-06" => "Embu",
+    "KE-07" => "Garissa",
+    "KE-08" => "Homa Bay",
+    "KE-09" => "Isiolo",
+    "KE-10" => "Kajiado",
+    "KE-11" => "Kakamega",
+    "KE-12" => "Kericho",
+    "KE-13" => "Kiambu",
+    "KE-14" => "Kilifi",
+    "KE-15" => "Kirinyaga",
+    "KE-16" => "Kisii",
+    "KE-17" => "Kisumu",
+    "KE-18" => "Kitui",
+    "KE-19" => "Kwale",
+    "KE-20" => "Laikipia",
+    "KE-21" => "Lamu",
+    "KE-22" => "Machakos",
+    "KE-23" => "Makueni",
+    "KE-24" => "Mandera",
+    "KE-25" => "Marsabit",
+    "KE-26" => "Meru",
+    "KE-27" => "Migori",
+    "KE-28" => "Mombasa",
+    "KE-29" => "Murang'a",
+    "KE-30" => "Nairobi City",
+    "KE-31" => "Nakuru",
+    "KE-32" => "Nandi",
+    "KE-33" => "Narok",
+    "KE-34" => "Nyamira",
+    "KE-35" => "Nyandarua",
+    "KE-36" => "Nyeri",
+    "KE-37" => "Samburu",
+    "KE-38" => "Siaya",
+    "KE-39" => "Taita/Taveta",
+    "KE-40" => "Tana River",
+    "KE-41" => "Tharaka-Nithi",
+    "KE-42" => "Trans Nzoia",
+    "KE-43" => "Turkana",
+    "KE-44" => "Uasin Gishu",
+    "KE-45" => "Vihiga",
+    "KE-46" => "Wajir",
+    "KE-47" => "West Pokot",
+    "KI-G" => "Îles Gilbert",
+    "KI-L" => "Îles Line",
+    "KI-P" => "Îles Phoenix",
+    "KP-04" => "Chagang-do",
+    "KP-05" => "Hwanghae méridional",
+    "KP-06" => "Hwanghae septentrional",
+    "KW-FA" => "Al Farwānīyah",
+    "KW-MU" => "Mubārak al Kabīr",
+    "KG-B" => "Batken",
+    "KG-N" => "Naryn",
+    "KG-O" => "Osh",
+    "KG-T" => "Talas",
+    "LA-AT" => "Attapu",
+    "LA-BK" => "Bokèo",
+    "LA-BL" => "Bolikhamxai",
+    "LA-CH" => "Champasak",
+    "LA-HO" => "Houaphan",
+    "LA-KH" => "Khammouan",
+    "LA-LM" => "Louang Namtha",
+    "LA-LP" => "Louangphabang",
+    "LA-OU" => "Oudômxai",
+    "LA-PH" => "Phôngsali",
+    "LA-SL" => "Salavan",
+    "LA-SV" => "Savannakhét",
+    "LA-XA" => "Xaignabouli",
+    "LA-XE" => "Xékong",
+    "LA-XI" => "Xiangkhouang",
+    "LA-XS" => "Xaisômboun",
+    "LV-002" => "Aizkraukles (commune)",
+    "LV-007" => "Alūksnes (commune)",
+    "LV-011" => "Ādažu (commune)",
+    "LV-015" => "Balvu (commune)",
+    "LV-016" => "Bauskas (commune)",
+    "LV-022" => "Cēsu (commune)",
+    "LV-026" => "Dobeles (commune)",
+    "LV-033" => "Gulbenes (commune)",
+    "LV-041" => "Jelgavas (commune)",
+    "LV-042" => "Jēkabpils (commune)",
+    "LV-047" => "Krāslavas (commune)",
+    "LV-050" => "Kuldīgas (commune)",
+    "LV-052" => "Ķekavas (commune)",
+    "LV-054" => "Limbažu (commune)",
+    "LV-056" => "Līvānu (commune)",
+    "LV-058" => "Ludzas (commune)",
+    "LV-059" => "Madonas (commune)",
+    "LV-062" => "Mārupes (commune)",
+    "LV-067" => "Ogres (commune)",
+    "LV-068" => "Olaines (commune)",
+    "LV-073" => "Preiļu (commune)",
+    "LV-077" => "Rēzeknes (commune)",
+    "LV-080" => "Ropažu (commune)",
+    "LV-087" => "Salaspils (commune)",
+    "LV-088" => "Saldus (commune)",
+    "LV-089" => "Saulkrastu (commune)",
+    "LV-091" => "Siguldas (commune)",
+    "LV-094" => "Smiltenes (commune)",
+    "LV-097" => "Talsu (commune)",
+    "LV-099" => "Tukuma (commune)",
+    "LV-101" => "Valkas (commune)",
+    "LV-102" => "Varakļānu (commune)",
+    "LV-106" => "Ventspils (commune)",
+    "LV-DGV" => "Daugavpils",
+    "LV-JEL" => "Jelgava",
+    "LV-JUR" => "Jūrmala",
+    "LV-LPX" => "Liepāja",
+    "LV-REZ" => "Rēzekne",
+    "LV-RIX" => "Rīga",
+    "LV-VEN" => "Ventspils",
+    "LB-AK" => "Aakkâr",
+    "LB-BH" => "Baalbek-Hermel",
+    "LS-A" => "Maseru",
+    "LS-C" => "Leribe",
+    "LS-D" => "Berea",
+    "LS-E" => "Mafeteng",
+    "LS-F" => "Mohale's Hoek",
+    "LS-G" => "Quthing",
+    "LS-H" => "Qacha's Nek",
+    "LS-J" => "Mokhotlong",
+    "LS-K" => "Thaba-Tseka",
+    "LR-BG" => "Bong",
+    "LR-BM" => "Bomi",
+    "LR-CM" => "Grand Cape Mount",
+    "LR-GB" => "Grand Bassa",
+    "LR-GG" => "Grand Gedeh",
+    "LR-GK" => "Grand Kru",
+    "LR-LO" => "Lofa",
+    "LR-MG" => "Margibi",
+    "LR-MO" => "Montserrado",
+    "LR-MY" => "Maryland",
+    "LR-NI" => "Nimba",
+    "LR-SI" => "Sinoe",
+    "LY-BA" => "Benghazi",
+    "LY-BU" => "Al Buţnān",
+    "LY-DR" => "Darnah",
+    "LY-GT" => "Ghāt",
+    "LY-JA" => "Al Jabal al Akhḑar",
+    "LY-JG" => "Al Jabal al Gharbī",
+    "LY-JU" => "Al Jufrah",
+    "LY-KF" => "Al Kufrah",
+    "LY-MB" => "Al Marqab",
+    "LY-MI" => "Mişrātah",
+    "LY-MJ" => "Al Marj",
+    "LY-MQ" => "Murzuq",
+    "LY-NL" => "Nālūt",
+    "LY-SB" => "Sabhā",
+    "LY-SR" => "Surt",
+    "LY-TB" => "Ţarābulus",
+    "LY-WD" => "Wādī al Ḩayāt",
+    "LY-ZA" => "Az Zāwiyah",
+    "LI-01" => "Balzers",
+    "LI-02" => "Eschen",
+    "LI-03" => "Gamprin",
+    "LI-04" => "Mauren",
+    "LI-05" => "Planken",
+    "LI-06" => "Ruggell",
+    "LI-07" => "Schaan",
+    "LI-08" => "Schellenberg",
+    "LI-09" => "Triesen",
+    "LI-10" => "Triesenberg",
+    "LI-11" => "Vaduz",
+    "LU-DI" => "Diekirch",
+    "LU-GR" => "Grevenmacher",
+    "LU-LU" => "Luxembourg",
+    "MG-A" => "Toamasina",
+    "MG-D" => "Antsiranana",
+    "MG-F" => "Fianarantsoa",
+    "MG-M" => "Mahajanga",
+    "MG-T" => "Tananarive",
+    "MG-U" => "Toliara",
+    "MW-BA" => "Balaka",
+    "MW-BL" => "Blantyre",
+    "MW-C" => "Région centrale",
+    "MW-CK" => "Chikwawa",
+    "MW-CR" => "Chiradzulu",
+    "MW-CT" => "Chitipa",
+    "MW-DE" => "Dedza",
+    "MW-DO" => "Dowa",
+    "MW-KR" => "Karonga",
+    "MW-KS" => "Kasungu",
+    "MW-LI" => "Lilongwe",
+    "MW-LK" => "Likoma",
+    "MW-MC" => "Mchinji",
+    "MW-MG" => "Mangochi",
+    "MW-MH" => "Machinga",
+    "MW-MU" => "Mulanje",
+    "MW-MW" => "Mwanza",
+    "MW-MZ" => "Mzimba",
+    "MW-N" => "Région septentrionale",
+    "MW-NB" => "Baie de Nkhata",
+    "MW-NE" => "Neno",
+    "MW-NI" => "Ntchisi",
+    "MW-NK" => "Nkhotakota",
+    "MW-NS" => "Nsanje",
+    "MW-NU" => "Ntcheu",
+    "MW-PH" => "Phalombe",
+    "MW-RU" => "Rumphi",
+    "MW-S" => "Région méridionale",
+    "MW-SA" => "Salima",
+    "MW-TH" => "Thyolo",
+    "MW-ZO" => "Zomba",
+    "MY-01" => "Johor",
+    "MY-02" => "Kedah",
+    "MY-03" => "Kelantan",
+    "MY-04" => "Melaka",
+    "MY-05" => "Negeri Sembilan",
+    "MY-06" => "Pahang",
+    "MY-07" => "Pulau Pinang",
+    "MY-08" => "Perak",
+    "MY-09" => "Perlis",
+    "MY-10" => "Selangor",
+    "MY-11" => "Terengganu",
+    "MY-12" => "Sabah",
+    "MY-13" => "Sarawak",
+    "MY-14" => "Kuala Lumpur (territoire fédéral)",
+    "MY-15" => "Labuan (territoire fédéral)",
+    "MY-16" => "Putrajaya (territoire fédéral)",
+    "MV-MLE" => "Male",
+    "ML-1" => "Kayes",
+    "ML-2" => "Koulikoro",
+    "ML-3" => "Sikasso",
+    "ML-4" => "Ségou",
+    "ML-5" => "Mopti",
+    "ML-6" => "Tombouctou",
+    "ML-7" => "Gao",
+    "ML-8" => "Kidal",
+    "ML-BKO" => "Bamako",
+    "MT-01" => "Attard",
+    "MT-02" => "Balzan",
+    "MT-03" => "Birgu",
+    "MT-04" => "Birkirkara",
+    "MT-05" => "Birżebbuġa",
+    "MT-06" => "Bormla",
+    "MT-07" => "Dingli",
+    "MT-08" => "Fgura",
+    "MT-09" => "Floriana",
+    "MT-10" => "Fontana",
+    "MT-11" => "Gudja",
+    "MT-12" => "Gżira",
+    "MT-13" => "Għajnsielem",
+    "MT-14" => "Għarb",
+    "MT-15" => "Għargħur",
+    "MT-16" => "Għasri",
+    "MT-17" => "Għaxaq",
+    "MT-18" => "Ħamrun",
+    "MT-19" => "Iklin",
+    "MT-20" => "Isla",
+    "MT-21" => "Kalkara",
+    "MT-22" => "Kerċem",
+    "MT-23" => "Kirkop",
+    "MT-24" => "Lija",
+    "MT-25" => "Luqa",
+    "MT-26" => "Marsa",
+    "MT-27" => "Marsaskala",
+    "MT-28" => "Marsaxlokk",
+    "MT-29" => "Mdina",
+    "MT-30" => "Mellieħa",
+    "MT-31" => "Mġarr",
+    "MT-32" => "Mosta",
+    "MT-33" => "Mqabba",
+    "MT-34" => "Msida",
+    "MT-35" => "Mtarfa",
+    "MT-36" => "Munxar",
+    "MT-37" => "Nadur",
+    "MT-38" => "Naxxar",
+    "MT-39" => "Paola",
+    "MT-40" => "Pembroke",
+    "MT-41" => "Pietà",
+    "MT-42" => "Qala",
+    "MT-43" => "Qormi",
+    "MT-44" => "Qrendi",
+    "MT-46" => "Rabat Malta",
+    "MT-47" => "Safi",
+    "MT-49" => "Saint-John",
+    "MT-52" => "Sannat",
+    "MT-54" => "Santa Venera",
+    "MT-55" => "Siġġiewi",
+    "MT-56" => "Sliema",
+    "MT-57" => "Swieqi",
+    "MT-59" => "Tarxien",
+    "MT-60" => "La Valette",
+    "MT-61" => "Xagħra",
+    "MT-62" => "Xewkija",
+    "MT-63" => "Xgħajra",
+    "MT-64" => "Żabbar",
+    "MT-66" => "Żebbuġ Malta",
+    "MT-67" => "Żejtun",
+    "MT-68" => "Żurrieq",
+    "MH-ALK" => "Ailuk",
+    "MH-ALL" => "Ailinglaplap",
+    "MH-ARN" => "Arno",
+    "MH-AUR" => "Aur",
+    "MH-EBO" => "Ebon",
+    "MH-JAB" => "Jabat",
+    "MH-JAL" => "Jaluit",
+    "MH-KWA" => "Kwajalein",
+    "MH-L" => "Chaîne de Ralik",
+    "MH-LAE" => "Lae",
+    "MH-LIB" => "Lib",
+    "MH-LIK" => "Likiep",
+    "MH-MAJ" => "Majuro",
+    "MH-MAL" => "Maloelap",
+    "MH-MEJ" => "Mejit",
+    "MH-MIL" => "Mili",
+    "MH-NMK" => "Namdrik",
+    "MH-NMU" => "Namu",
+    "MH-RON" => "Rongelap",
+    "MH-T" => "Chaîne de Ratak",
+    "MH-UJA" => "Ujae",
+    "MH-WTH" => "Wotho",
+    "MH-WTJ" => "Wotje",
+    "MR-01" => "Hodh ech Chargui",
+    "MR-03" => "Assaba",
+    "MR-04" => "Gorgol",
+    "MR-05" => "Brakna",
+    "MR-06" => "Trarza",
+    "MR-07" => "Adrar",
+    "MR-09" => "Tagant",
+    "MR-10" => "Guidimaka",
+    "MR-11" => "Tiris Zemmour",
+    "MR-12" => "Inchiri",
+    "MU-AG" => "Îles Agalega",
+    "MU-BL" => "Black River",
+    "MU-CC" => "Cargados Carajos Shoals",
+    "MU-FL" => "Flacq",
+    "MU-GP" => "Grand Port",
+    "MU-MO" => "Moka",
+    "MU-PA" => "Pamplemousses",
+    "MU-PL" => "Port Louis",
+    "MU-PW" => "Plaines Wilhems",
+    "MU-RO" => "Île Rodrigues",
+    "MU-RR" => "Rivière du Rempart",
+    "MU-SA" => "Savanne",
+    "MX-AGU" => "Aguascalientes",
+    "MX-BCN" => "Basse Californie",
+    "MX-BCS" => "Basse Californie méridionale",
+    "MX-CAM" => "Campeche",
+    "MX-CHH" => "Chihuahua",
+    "MX-CHP" => "Chiapas",
+    "MX-CMX" => "Ville de Mexico",
+    "MX-COA" => "État de Coahuila de Zaragoza",
+    "MX-COL" => "Colima",
+    "MX-DUR" => "Durango",
+    "MX-GRO" => "Guerrero",
+    "MX-GUA" => "Guanajuato",
+    "MX-HID" => "Hidalgo",
+    "MX-JAL" => "Jalisco",
+    "MX-MEX" => "Mexico",
+    "MX-MIC" => "Michoacán de Ocampo",
+    "MX-MOR" => "Morelos",
+    "MX-NAY" => "Nayarit",
+    "MX-NLE" => "Nouveau León",
+    "MX-OAX" => "Oaxaca",
+    "MX-PUE" => "Puebla",
+    "MX-QUE" => "Querétaro",
+    "MX-ROO" => "Quintana Roo",
+    "MX-SIN" => "Sinaloa",
+    "MX-SLP" => "San Luis Potosí",
+    "MX-SON" => "Sonora",
+    "MX-TAB" => "Tabasco",
+    "MX-TAM" => "Tamaulipas",
+    "MX-TLA" => "Tlaxcala",
+    "MX-VER" => "Veracruz de Ignacio de la Llave",
+    "MX-YUC" => "Yucatán",
+    "MX-ZAC" => "Zacatecas",
+    "FM-KSA" => "Kosrae",
+    "FM-PNI" => "Pohnpei",
+    "FM-TRK" => "Chuuk",
+    "FM-YAP" => "Yap",
+    "MD-AN" => "Anenii Noi",
+    "MD-BA" => "Bălți",
+    "MD-BR" => "Briceni",
+    "MD-BS" => "Basarabeasca",
+    "MD-CA" => "Cahul",
+    "MD-CL" => "Călărași",
+    "MD-CM" => "Cimișlia",
+    "MD-CR" => "Criuleni",
+    "MD-CS" => "Căușeni",
+    "MD-CT" => "Cantemir",
+    "MD-CU" => "Chișinău",
+    "MD-DO" => "Dondușeni",
+    "MD-DR" => "Drochia",
+    "MD-DU" => "Dubăsari",
+    "MD-ED" => "Edineț",
+    "MD-FA" => "Fălești",
+    "MD-FL" => "Florești",
+    "MD-GL" => "Glodeni",
+    "MD-HI" => "Hîncești",
+    "MD-IA" => "Ialoveni",
+    "MD-LE" => "Leova",
+    "MD-NI" => "Nisporeni",
+    "MD-OC" => "Ocnița",
+    "MD-OR" => "Orhei",
+    "MD-RE" => "Rezina",
+    "MD-RI" => "Rîșcani",
+    "MD-SD" => "Șoldănești",
+    "MD-SI" => "Sîngerei",
+    "MD-SN" => "Transnistrie",
+    "MD-SO" => "Soroca",
+    "MD-ST" => "Strășeni",
+    "MD-SV" => "Ștefan Vodă",
+    "MD-TA" => "Taraclia",
+    "MD-TE" => "Telenești",
+    "MD-UN" => "Ungheni",
+    "MC-CL" => "La Colle",
+    "MC-CO" => "La Condamine",
+    "MC-FO" => "Fontvieille",
+    "MC-GA" => "La Gare",
+    "MC-JE" => "Jardin Exotique",
+    "MC-LA" => "Larvotto",
+    "MC-MA" => "Malbousquet",
+    "MC-MC" => "Monte-Carlo",
+    "MC-MG" => "Moneghetti",
+    "MC-MO" => "Monaco-Ville",
+    "MC-MU" => "Moulins",
+    "MC-PH" => "Port-Hercule",
+    "MC-SD" => "Sainte-Dévote",
+    "MC-SO" => "La Source",
+    "MC-SP" => "Spélugues",
+    "MC-SR" => "Saint-Roman",
+    "MC-VR" => "Vallon de la Rousse",
+    "MN-035" => "Orhon",
+    "MN-037" => "Darhan uul",
+    "MN-039" => "Hentiy",
+    "MN-041" => "Hövsgöl",
+    "MN-043" => "Hovd",
+    "MN-046" => "Uvs",
+    "MN-047" => "Töv",
+    "MN-049" => "Selenge",
+    "MN-051" => "Sühbaatar",
+    "MN-055" => "Övörhangay",
+    "MN-057" => "Dzavhan",
+    "MN-061" => "Dornod",
+    "MN-067" => "Bulgan",
+    "MN-069" => "Bayanhongor",
+    "MN-071" => "Bayan-Ölgiy",
+    "MN-073" => "Arhangay",
+    "ME-01" => "Andrijevica",
+    "ME-02" => "Bar",
+    "ME-03" => "Berane",
+    "ME-04" => "Bijelo Polje",
+    "ME-05" => "Budva",
+    "ME-06" => "Cetinje",
+    "ME-07" => "Danilovgrad",
+    "ME-08" => "Herceg-Novi",
+    "ME-09" => "Kolašin",
+    "ME-10" => "Kotor",
+    "ME-11" => "Mojkovac",
+    "ME-12" => "Nikšić",
+    "ME-13" => "Plav",
+    "ME-14" => "Pljevlja",
+    "ME-15" => "Plužine",
+    "ME-16" => "Podgorica",
+    "ME-17" => "Rožaje",
+    "ME-18" => "Šavnik",
+    "ME-19" => "Tivat",
+    "ME-20" => "Ulcinj",
+    "ME-21" => "Žabljak",
+    "MA-01" => "Tanger-Tétouan-Al Hoceïma",
+    "MA-02" => "L'Oriental",
+    "MA-03" => "Fès-Meknès",
+    "MA-04" => "Rabat-Salé-Kénitra",
+    "MA-05" => "Béni Mellal-Khénifra",
+    "MA-06" => "Casablanca-Settat",
+    "MA-07" => "Marrakech-Safi",
+    "MA-08" => "Drâa-Tafilalet",
+    "MA-09" => "Souss-Massa",
+    "MA-10" => "Guelmim-Oued Noun (EH partiel)",
+    "MA-11" => "Laâyoune-Sakia El Hamra (EH partiel)",
+    "MA-12" => "Dakhla-Oued Ed-Dahab (EH)",
+    "MA-AGD" => "Agadir-Ida-Ou-Tanane",
+    "MA-AOU" => "Aousserd (EH)",
+    "MA-ASZ" => "Assa-Zag (EH partiel)",
+    "MA-AZI" => "Azilal",
+    "MA-BEM" => "Béni Mellal",
+    "MA-BER" => "Berkane",
+    "MA-BES" => "Benslimane",
+    "MA-BOD" => "Boujdour (EH)",
+    "MA-BOM" => "Boulemane",
+    "MA-BRR" => "Berrechid",
+    "MA-CAS" => "Casablanca",
+    "MA-CHE" => "Chefchaouene",
+    "MA-CHI" => "Chichaoua",
+    "MA-CHT" => "Chtouka-Ait Baha",
+    "MA-DRI" => "Driouch",
+    "MA-ERR" => "Errachidia",
+    "MA-ESI" => "Essaouira",
+    "MA-ESM" => "Es-Semara (EH partiel)",
+    "MA-FAH" => "Fahs-Anjra",
+    "MA-FES" => "Fès",
+    "MA-FIG" => "Figuig",
+    "MA-FQH" => "Fquih Ben Salah",
+    "MA-GUE" => "Guelmim",
+    "MA-GUF" => "Guercif",
+    "MA-HAJ" => "El Hajeb",
+    "MA-HAO" => "Al Haouz",
+    "MA-HOC" => "Al Hoceïma",
+    "MA-IFR" => "Ifrane",
+    "MA-INE" => "Inezgane-Ait Melloul",
+    "MA-JDI" => "El Jadida",
+    "MA-JRA" => "Jerada",
+    "MA-KEN" => "Kénitra",
+    "MA-KES" => "El Kelâa des Sraghna",
+    "MA-KHO" => "Khouribga",
+    "MA-LAA" => "Laâyoune (EH)",
+    "MA-LAR" => "Larache",
+    "MA-MAR" => "Marrakech",
+    "MA-MDF" => "M’diq-Fnideq",
+    "MA-MED" => "Médiouna",
+    "MA-MEK" => "Meknès",
+    "MA-MID" => "Midelt",
+    "MA-MOH" => "Mohammadia",
+    "MA-MOU" => "Moulay Yacoub",
+    "MA-NAD" => "Nador",
+    "MA-NOU" => "Nouaceur",
+    "MA-OUA" => "Ouarzazate",
+    "MA-OUD" => "Oued Ed-Dahab (EH)",
+    "MA-OUJ" => "Oujda-Angad",
+    "MA-OUZ" => "Ouezzane",
+    "MA-RAB" => "Rabat",
+    "MA-REH" => "Rehamna",
+    "MA-SAF" => "Safi",
+    "MA-SAL" => "Salé",
+    "MA-SEF" => "Sefrou",
+    "MA-SET" => "Settat",
+    "MA-SIB" => "Sidi Bennour",
+    "MA-SIF" => "Sidi Ifni",
+    "MA-SIK" => "Sidi Kacem",
+    "MA-SIL" => "Sidi Slimane",
+    "MA-SKH" => "Skhirate-Témara",
+    "MA-TAF" => "Tarfaya (EH partiel)",
+    "MA-TAI" => "Taourirt",
+    "MA-TAO" => "Taounate",
+    "MA-TAT" => "Tata",
+    "MA-TAZ" => "Taza",
+    "MA-TET" => "Tétouan",
+    "MA-TIN" => "Tinghir",
+    "MA-TIZ" => "Tiznit",
+    "MA-TNG" => "Tanger-Assilah",
+    "MA-TNT" => "Tan-Tan (EH partiel)",
+    "MA-YUS" => "Youssoufia",
+    "MA-ZAG" => "Zagora",
+    "MZ-A" => "Niassa",
+    "MZ-B" => "Manica",
+    "MZ-G" => "Gaza",
+    "MZ-I" => "Inhambane",
+    "MZ-L" => "Maputo",
+    "MZ-MPM" => "Maputo",
+    "MZ-P" => "Cabo Delgado",
+    "MZ-S" => "Sofala",
+    "MZ-T" => "Tete",
+    "MM-01" => "Sagaing",
+    "MM-02" => "Bago",
+    "MM-03" => "Magway",
+    "MM-04" => "Mandalay",
+    "MM-05" => "Tanintharyi",
+    "MM-06" => "Yangon",
+    "MM-07" => "Ayeyarwady",
+    "MM-11" => "Kachin",
+    "MM-12" => "Kayah",
+    "MM-13" => "Kayin",
+    "MM-14" => "Chin",
+    "MM-15" => "Mon",
+    "MM-16" => "Rakhine",
+    "MM-17" => "shan",
+    "NA-ER" => "Erongo",
+    "NA-HA" => "Hardap",
+    "NA-KH" => "Khomas",
+    "NA-KU" => "Kunene",
+    "NA-OD" => "Otjozondjupa",
+    "NA-OH" => "Omaheke",
+    "NA-ON" => "Oshana",
+    "NA-OS" => "Omusati",
+    "NA-OT" => "Oshikoto",
+    "NA-OW" => "Ohangwena",
+    "NR-01" => "Aiwo",
+    "NR-02" => "Anabar",
+    "NR-03" => "Anetan",
+    "NR-04" => "Anibare",
+    "NR-06" => "Boe",
+    "NR-07" => "Buada",
+    "NR-08" => "Denigomodu",
+    "NR-09" => "Ewa",
+    "NR-10" => "Ijuw",
+    "NR-11" => "Meneng",
+    "NR-12" => "Nibok",
+    "NR-13" => "Uaboe",
+    "NR-14" => "Yaren",
+    "NP-P4" => "Gandaki",
+    "NP-P6" => "Karnali",
+    "NL-AW" => "Aruba",
+    "NL-BQ1" => "Bonaire",
+    "NL-BQ2" => "Saba",
+    "NL-BQ3" => "Saint Eustache",
+    "NL-CW" => "Curaçao",
+    "NL-DR" => "Drenthe",
+    "NL-FL" => "Flevoland",
+    "NL-GE" => "Gueldre",
+    "NL-GR" => "Groningue",
+    "NL-LI" => "Limourg",
+    "NL-NB" => "Brabant septentrional",
+    "NL-NH" => "Hollande septentrionale",
+    "NL-OV" => "Overijssel",
+    "NL-SX" => "Saint-Martin",
+    "NL-UT" => "Utrecht",
+    "NL-ZE" => "Zélande",
+    "NL-ZH" => "Hollande méridionale",
+    "NZ-AUK" => "Auckland",
+    "NZ-BOP" => "Baie de Plenty",
+    "NZ-CAN" => "Canterbury",
+    "NZ-CIT" => "Territoire des îles Chatham",
+    "NZ-HKB" => "Baie de Hawke",
+    "NZ-MWT" => "Manawatu-Wanganui",
+    "NZ-NTL" => "Pays du Nord",
+    "NZ-OTA" => "Otago",
+    "NZ-STL" => "Pays du Sud",
+    "NZ-TKI" => "Taranaki",
+    "NZ-WGN" => "Wellington",
+    "NZ-WKO" => "Waikato",
+    "NZ-WTC" => "Côte occidentale",
+    "NI-BO" => "Boaco",
+    "NI-CA" => "Carazo",
+    "NI-CI" => "Chinandega",
+    "NI-CO" => "Chontales",
+    "NI-ES" => "Estelí",
+    "NI-GR" => "Grenade",
+    "NI-JI" => "Jinotega",
+    "NI-LE" => "Léon",
+    "NI-MD" => "Madriz",
+    "NI-MN" => "Managua",
+    "NI-MS" => "Masaya",
+    "NI-MT" => "Matagalpa",
+    "NI-NS" => "Nouvelle Ségovie",
+    "NI-RI" => "Rivas",
+    "NI-SJ" => "Rivière San Juan",
+    "NE-1" => "Agadès",
+    "NE-2" => "Diffa",
+    "NE-3" => "Dosso",
+    "NE-4" => "Maradi",
+    "NE-5" => "Tahoua",
+    "NE-6" => "Tillabéri",
+    "NE-7" => "Zinder",
+    "NE-8" => "Niamey",
+    "NG-AB" => "Abia",
+    "NG-AD" => "Adamawa",
+    "NG-AK" => "Akwa Ibom",
+    "NG-AN" => "Anambra",
+    "NG-BA" => "Bauchi",
+    "NG-BE" => "Benue",
+    "NG-BO" => "Borno",
+    "NG-BY" => "Bayelsa",
+    "NG-CR" => "Cross River",
+    "NG-DE" => "Delta",
+    "NG-EB" => "Ebonyi",
+    "NG-ED" => "Edo",
+    "NG-EK" => "Ekiti",
+    "NG-EN" => "Enugu",
+    "NG-GO" => "Gombe",
+    "NG-IM" => "Imo",
+    "NG-JI" => "Jigawa",
+    "NG-KD" => "Kaduna",
+    "NG-KE" => "Kebbi",
+    "NG-KN" => "Kano",
+    "NG-KO" => "Kogi",
+    "NG-KT" => "Katsina",
+    "NG-KW" => "Kwara",
+    "NG-LA" => "Lagos",
+    "NG-NI" => "Niger",
+    "NG-OG" => "Ogun",
+    "NG-ON" => "Ondo",
+    "NG-OS" => "Osun",
+    "NG-OY" => "Oyo",
+    "NG-PL" => "Plateau",
+    "NG-RI" => "Rivières",
+    "NG-SO" => "Sokoto",
+    "NG-TA" => "Taraba",
+    "NG-YO" => "Yobe",
+    "NG-ZA" => "Zamfara",
+    "MK-101" => "Veles",
+    "MK-102" => "Gradsko",
+    "MK-103" => "Demir Kapija",
+    "MK-104" => "Kavadarci",
+    "MK-105" => "Lozovo",
+    "MK-106" => "Negotino",
+    "MK-107" => "Rosoman",
+    "MK-108" => "Sveti Nikole",
+    "MK-109" => "Čaška",
+    "MK-201" => "Berovo",
+    "MK-202" => "Vinica",
+    "MK-203" => "Delčevo",
+    "MK-204" => "Zrnovci",
+    "MK-205" => "Karbinci",
+    "MK-206" => "Kočani",
+    "MK-207" => "Makedonska Kamenica",
+    "MK-208" => "Pehčevo",
+    "MK-209" => "Probištip",
+    "MK-210" => "Češinovo-Obleševo",
+    "MK-211" => "Štip",
+    "MK-301" => "Vevčani",
+    "MK-303" => "Debar",
+    "MK-307" => "Kičevo",
+    "MK-308" => "Makedonski Brod",
+    "MK-310" => "Ohrid",
+    "MK-311" => "Plasnica",
+    "MK-312" => "Struga",
+    "MK-313" => "Centar Župa",
+    "MK-401" => "Bogdanci",
+    "MK-402" => "Bosilovo",
+    "MK-403" => "Valandovo",
+    "MK-404" => "Vasilevo",
+    "MK-405" => "Gevgelija",
+    "MK-406" => "Dojran",
+    "MK-407" => "Konče",
+    "MK-408" => "Novo Selo",
+    "MK-409" => "Radoviš",
+    "MK-410" => "Strumica",
+    "MK-501" => "Bitola",
+    "MK-502" => "Demir Hisar",
+    "MK-503" => "Dolneni",
+    "MK-504" => "Krivogaštani",
+    "MK-505" => "Kruševo",
+    "MK-506" => "Mogila",
+    "MK-507" => "Novaci",
+    "MK-508" => "Prilep",
+    "MK-509" => "Resen",
+    "MK-601" => "Bogovinje",
+    "MK-602" => "Brvenica",
+    "MK-603" => "Vrapčište",
+    "MK-604" => "Gostivar",
+    "MK-605" => "Želino",
+    "MK-606" => "Jegunovce",
+    "MK-608" => "Tearce",
+    "MK-609" => "Tetovo",
+    "MK-701" => "Kratovo",
+    "MK-702" => "Kriva Palanka",
+    "MK-703" => "Kumanovo",
+    "MK-704" => "Lipkovo",
+    "MK-705" => "Rankovce",
+    "MK-706" => "Staro Nagoričane",
+    "MK-802" => "Aračinovo",
+    "MK-806" => "Zelenikovo",
+    "MK-807" => "Ilinden",
+    "MK-810" => "Petrovec",
+    "MK-812" => "Sopište",
+    "MK-813" => "Studeničani",
+    "NO-03" => "Oslo",
+    "NO-11" => "Rogaland",
+    "NO-15" => "Møre og Romsdal",
+    "NO-18" => "Nordland",
+    "NO-21" => "Svalbard (région arctique)",
+    "NO-22" => "Jan Mayen (région arctique)",
+    "OM-BU" => "Al Buraymī",
+    "OM-MA" => "Mascate",
+    "OM-MU" => "Musandam",
+    "OM-WU" => "Al Wusta",
+    "OM-ZA" => "Az̧ Z̧āhirah",
+    "OM-ZU" => "Z̧ufār",
+    "PK-BA" => "Baloutchistan",
+    "PK-GB" => "Gilgit-Baltistan",
+    "PK-IS" => "Islamabad",
+    "PK-KP" => "Khyber Pakhtunkhwa",
+    "PK-PB" => "Penjab",
+    "PK-SD" => "Sindh",
+    "PW-002" => "Aimeliik",
+    "PW-004" => "Airai",
+    "PW-010" => "Angaur",
+    "PW-100" => "Kayangel",
+    "PW-150" => "Koror",
+    "PW-212" => "Melekeok",
+    "PW-214" => "Ngaraard",
+    "PW-218" => "Ngarchelong",
+    "PW-222" => "Ngardmau",
+    "PW-224" => "Ngatpang",
+    "PW-226" => "Ngchesar",
+    "PW-227" => "Ngeremlengui",
+    "PW-228" => "Ngiwal",
+    "PW-350" => "Peleliu",
+    "PW-370" => "Sonsorol",
+    "PS-BTH" => "Bethlehem",
+    "PS-DEB" => "Deir El Balah",
+    "PS-GZA" => "Gaza",
+    "PS-HBN" => "Hébron",
+    "PS-JEM" => "Jérusalem",
+    "PS-JEN" => "Jénine",
+    "PS-KYS" => "Khan Yunis",
+    "PS-NBS" => "Nablus",
+    "PS-NGZ" => "Gaza du Nord",
+    "PS-QQA" => "Qalqilya",
+    "PS-RBH" => "Ramallah",
+    "PS-RFH" => "Rafah",
+    "PS-SLT" => "Salfit",
+    "PS-TBS" => "Tubas",
+    "PS-TKM" => "Tulkarem",
+    "PA-1" => "Bocas del Toro",
+    "PA-2" => "Coclé",
+    "PA-3" => "Colón",
+    "PA-4" => "Chiriquí",
+    "PA-5" => "Darién",
+    "PA-6" => "Herrera",
+    "PA-7" => "Los Santos",
+    "PA-8" => "Panama",
+    "PA-9" => "Veraguas",
+    "PA-EM" => "Emberá",
+    "PA-NB" => "Ngöbe-Buglé",
+    "PG-CPK" => "Chimbu",
+    "PG-CPM" => "Central",
+    "PG-EBR" => "Nouvelle-Bretagne orientale",
+    "PG-EHG" => "Highlands orientaux",
+    "PG-EPW" => "Enga",
+    "PG-ESW" => "Spik oriental",
+    "PG-GPK" => "Golfe",
+    "PG-MBA" => "Baie de Milne",
+    "PG-MPL" => "Morobe",
+    "PG-MPM" => "Madang",
+    "PG-MRL" => "Manus",
+    "PG-NCD" => "Port-Moresby (district de la capitale)",
+    "PG-NIK" => "Nouvelle-Irlande",
+    "PG-NPP" => "Nord",
+    "PG-NSB" => "Bougainville",
+    "PG-SHM" => "Highlands méridionaux",
+    "PG-WBK" => "Nouvelle-Bretagne occidentale",
+    "PG-WHM" => "Highlands occidentaux",
+    "PG-WPD" => "Ouest",
+    "PY-1" => "Concepción",
+    "PY-10" => "Haut-Paraná",
+    "PY-11" => "Central",
+    "PY-12" => "Ñeembucú",
+    "PY-13" => "Amambay",
+    "PY-14" => "Canindeyú",
+    "PY-15" => "Presidente Hayes",
+    "PY-16" => "Haut-Paraguay",
+    "PY-19" => "Boquerón",
+    "PY-2" => "San Pedro",
+    "PY-3" => "Cordillère",
+    "PY-4" => "Guairá",
+    "PY-5" => "Caaguazú",
+    "PY-6" => "Caazapá",
+    "PY-7" => "Itapúa",
+    "PY-8" => "Misiones",
+    "PY-9" => "Paraguarí",
+    "PY-ASU" => "Asunción",
+    "PE-ANC" => "Ancash",
+    "PE-ARE" => "Arequipa",
+    "PE-AYA" => "Ayacucho",
+    "PE-CAJ" => "Cajamarca",
+    "PE-CAL" => "El Callao",
+    "PE-HUC" => "Huánuco",
+    "PE-HUV" => "Huancavelica",
+    "PE-ICA" => "Ica",
+    "PE-LAL" => "La Libertad",
+    "PE-LAM" => "Lambayeque",
+    "PE-LIM" => "Lima",
+    "PE-LOR" => "Loreto",
+    "PE-MDD" => "Madre de Dios",
+    "PE-MOQ" => "Moquegua",
+    "PE-PAS" => "Pasco",
+    "PE-PIU" => "Piura",
+    "PE-PUN" => "Puno",
+    "PE-TAC" => "Tacna",
+    "PE-TUM" => "Tumbes",
+    "PE-UCA" => "Ucayali",
+    "PH-00" => "Région de la capitale nationale",
+    "PH-01" => "Ilocos (Région I)",
+    "PH-02" => "Vallée de Cagayan (Région II)",
+    "PH-03" => "Luzon central (Région III)",
+    "PH-05" => "Bicol (Région V)",
+    "PH-06" => "Visayas occidental (Région VI)",
+    "PH-07" => "Visayas central (Région VII)",
+    "PH-08" => "Visayas oriental (Région VIII)",
+    "PH-09" => "Péninsule de Zamboanga (Région IX)",
+    "PH-10" => "Mindanao septentrional (Région X)",
+    "PH-11" => "Davao (Région XI)",
+    "PH-12" => "Soccsksargen (Région XII)",
+    "PH-13" => "Caraga (Région XIII)",
+    "PH-14" => "Région autonome musulmane de Mindanao (ARMM)",
+    "PH-15" => "Région administrative de Cordillera (CAR)",
+    "PH-ABR" => "Abra",
+    "PH-AGN" => "Agusan du Nord",
+    "PH-AGS" => "Agusan du Sud",
+    "PH-AKL" => "Aklan",
+    "PH-ALB" => "Albay",
+    "PH-ANT" => "Antique",
+    "PH-APA" => "Apayao",
+    "PH-AUR" => "Aurora",
+    "PH-BAS" => "Basilan",
+    "PH-BEN" => "Benguet",
+    "PH-BIL" => "Biliran",
+    "PH-BOH" => "Bohol",
+    "PH-BTG" => "Batangas",
+    "PH-BTN" => "Batanes",
+    "PH-BUK" => "Bukidnon",
+    "PH-BUL" => "Bulacan",
+    "PH-CAG" => "Cagayan",
+    "PH-CAM" => "Camiguin",
+    "PH-CAN" => "Camarines Nord",
+    "PH-CAP" => "Capiz",
+    "PH-CAS" => "Camarines Sud",
+    "PH-CAT" => "Catanduanes",
+    "PH-CAV" => "Cavite",
+    "PH-CEB" => "Cebu",
+    "PH-DAO" => "Davao oriental",
+    "PH-DAS" => "Davao du Sud",
+    "PH-DAV" => "Davao du Nord",
+    "PH-DIN" => "Îles Dinagat",
+    "PH-EAS" => "Samar Oriental",
+    "PH-GUI" => "Guimaras",
+    "PH-IFU" => "Ifugao",
+    "PH-ILI" => "Iloilo",
+    "PH-ILN" => "Ilocos Nord",
+    "PH-ILS" => "Ilocos Sud",
+    "PH-ISA" => "Isabela",
+    "PH-LAG" => "Laguna",
+    "PH-LAN" => "Lanao du Nord",
+    "PH-LAS" => "Lanao du Sud",
+    "PH-LEY" => "Leyte",
+    "PH-LUN" => "La Union",
+    "PH-MAD" => "Marinduque",
+    "PH-MAS" => "Masbate",
+    "PH-MDC" => "Mindoro occidental",
+    "PH-MDR" => "Mindoro oriental",
+    "PH-MOU" => "Province de la Montagne",
+    "PH-MSC" => "Misamis occidental",
+    "PH-MSR" => "Misamis oriental",
+    "PH-NEC" => "Negros occidental",
+    "PH-NER" => "Negros oriental",
+    "PH-NSA" => "Samar septentrional",
+    "PH-NUE" => "Nouvelle-Ecija",
+    "PH-NUV" => "Nouvelle-Biscaye",
+    "PH-PAM" => "pampangan",
+    "PH-PAN" => "Pangasinan",
+    "PH-PLW" => "Palawan",
+    "PH-QUE" => "Quezon",
+    "PH-QUI" => "Quirino",
+    "PH-RIZ" => "Rizal",
+    "PH-ROM" => "Romblon",
+    "PH-SAR" => "Sarangani",
+    "PH-SCO" => "Cotabato Sud",
+    "PH-SIG" => "Siquijor",
+    "PH-SLE" => "Leyte méridional",
+    "PH-SLU" => "Sulu",
+    "PH-SOR" => "Sorsogon",
+    "PH-SUK" => "Sultan Kudarat",
+    "PH-SUN" => "Surigao du Nord",
+    "PH-SUR" => "Surigao du Sud",
+    "PH-TAR" => "Tarlac",
+    "PH-TAW" => "Tawi-Tawi",
+    "PH-ZAN" => "Zamboanga du Nord",
+    "PH-ZAS" => "Zamboanga du Sud",
+    "PH-ZMB" => "Zambales",
+    "PH-ZSI" => "Zamboanga Sibugay",
+    "PL-02" => "Basse-Silésie",
+    "PL-04" => "Cujavie-Poméranie",
+    "PL-06" => "Lublin",
+    "PL-08" => "Lubusz",
+    "PL-10" => "Łódź",
+    "PL-12" => "Petite-Pologne",
+    "PL-14" => "Mazovie",
+    "PL-16" => "Opole (Silésie centrale)",
+    "PL-18" => "Basses-Carpates",
+    "PL-20" => "Podlachie",
+    "PL-22" => "Poméranie",
+    "PL-24" => "Silésie (Haute-Silésie)",
+    "PL-26" => "Sainte-Croix",
+    "PL-28" => "Varmie-Mazurie",
+    "PL-30" => "Grande-Pologne",
+    "PL-32" => "Poméranie occidentale",
+    "PT-01" => "Aveiro",
+    "PT-02" => "Beja",
+    "PT-03" => "Braga",
+    "PT-04" => "Bragança",
+    "PT-05" => "Castelo Branco",
+    "PT-06" => "Coimbra",
+    "PT-07" => "Évora",
+    "PT-08" => "Faro",
+    "PT-09" => "Guarda",
+    "PT-10" => "Leiria",
+    "PT-11" => "Lisbonne",
+    "PT-12" => "Portalegre",
+    "PT-13" => "Porto",
+    "PT-14" => "Santarém",
+    "PT-15" => "Setúbal",
+    "PT-16" => "Viana do Castelo",
+    "PT-17" => "Vila Real",
+    "PT-18" => "Viseu",
+    "PT-20" => "Açores (région autonome)",
+    "PT-30" => "Madère (région autonome)",
+    "QA-KH" => "Al Khawr wa adh Dhakhīrah",
+    "QA-WA" => "Al Wakrah",
+    "QA-ZA" => "Az̧ Z̧a‘āyin",
+    "RO-AB" => "Alba",
+    "RO-AG" => "Argeș",
+    "RO-AR" => "Arad",
+    "RO-B" => "Bucarest",
+    "RO-BC" => "Bacău",
+    "RO-BH" => "Bihor",
+    "RO-BN" => "Bistrița-Năsăud",
+    "RO-BR" => "Brăila",
+    "RO-BT" => "Botoșani",
+    "RO-BV" => "Brașov",
+    "RO-BZ" => "Buzău",
+    "RO-CJ" => "Cluj",
+    "RO-CL" => "Călărași",
+    "RO-CS" => "Caraș-Severin",
+    "RO-CT" => "Constanța",
+    "RO-CV" => "Covasna",
+    "RO-DB" => "Dâmbovița",
+    "RO-DJ" => "Dolj",
+    "RO-GJ" => "Gorj",
+    "RO-GL" => "Galați",
+    "RO-GR" => "Giurgiu",
+    "RO-HD" => "Hunedoara",
+    "RO-HR" => "Harghita",
+    "RO-IF" => "Ilfov",
+    "RO-IL" => "Ialomița",
+    "RO-IS" => "Iași",
+    "RO-MH" => "Mehedinți",
+    "RO-MM" => "Maramureș",
+    "RO-MS" => "Mureș",
+    "RO-NT" => "Neamț",
+    "RO-OT" => "Olt",
+    "RO-PH" => "Prahova",
+    "RO-SB" => "Sibiu",
+    "RO-SJ" => "Sălaj",
+    "RO-SM" => "Satu Mare",
+    "RO-SV" => "Suceava",
+    "RO-TL" => "Tulcea",
+    "RO-TM" => "Timiș",
+    "RO-TR" => "Teleorman",
+    "RO-VL" => "Vâlcea",
+    "RO-VN" => "Vrancea",
+    "RO-VS" => "Vaslui",
+    "RU-BA" => "Bachkirie",
+    "RU-CE" => "Tchétchénie",
+    "RU-CHE" => "Tchéliabinsk",
+    "RU-CHU" => "Tchoukotka",
+    "RU-CU" => "Tchouvachie",
+    "RU-DA" => "Daguestan",
+    "RU-KAM" => "Kamtchatka",
+    "RU-KC" => "Karatchaïévo-Tcherkessie",
+    "RU-KLU" => "Kalouga",
+    "RU-KO" => "Komis",
+    "RU-MOW" => "Moscou",
+    "RU-NIZ" => "Nijni Novgorod",
+    "RU-SPE" => "Saint-Pétersbourg",
+    "RU-TA" => "Tatarstan",
+    "RU-VOR" => "Voronej",
+    "RU-ZAB" => "Transbaïkalie",
+    "RW-02" => "Est",
+    "RW-03" => "Nord",
+    "RW-04" => "Ouest",
+    "RW-05" => "Sud",
+    "SH-AC" => "Ascension",
+    "SH-HL" => "Sainte-Hélène",
+    "SH-TA" => "Tristan da Cunha",
+    "KN-01" => "Christ Church Nichola Town",
+    "KN-02" => "Saint Anne Sandy Point",
+    "KN-03" => "Saint George Basseterre",
+    "KN-04" => "Saint George Gingerland",
+    "KN-05" => "Saint James Windward",
+    "KN-06" => "Saint John Capisterre",
+    "KN-07" => "Saint John Figtree",
+    "KN-08" => "Saint Mary Cayon",
+    "KN-09" => "Saint Paul Capisterre",
+    "KN-10" => "Saint Paul Charlestown",
+    "KN-11" => "Saint Peter Basseterre",
+    "KN-12" => "Saint Thomas Lowland",
+    "KN-13" => "Saint Thomas Middle Island",
+    "KN-15" => "Trinity Palmetto Point",
+    "KN-K" => "Saint Kitts",
+    "KN-N" => "Nevis",
+    "LC-03" => "Choiseul",
+    "VC-01" => "Charlotte",
+    "VC-02" => "Saint-Andrew",
+    "VC-03" => "Saint-David",
+    "VC-04" => "Saint-George",
+    "VC-05" => "Saint-Patrick",
+    "VC-06" => "Grenadines",
+    "WS-AA" => "A'ana",
+    "WS-AL" => "Aiga-i-le-Tai",
+    "WS-AT" => "Atua",
+    "WS-FA" => "Fa'asaleleaga",
+    "WS-GE" => "Gaga'emauga",
+    "WS-GI" => "Gagaifomauga",
+    "WS-PA" => "Palauli",
+    "WS-SA" => "Satupa'itea",
+    "WS-TU" => "Tuamasaga",
+    "WS-VF" => "Va'a-o-Fonoti",
+    "WS-VS" => "Vaisigano",
+    "SM-01" => "Acquaviva",
+    "SM-02" => "Chiesanuova",
+    "SM-03" => "Domagnano",
+    "SM-04" => "Faetano",
+    "SM-05" => "Fiorentino",
+    "SM-06" => "Borgo Maggiore",
+    "SM-08" => "Montegiardino",
+    "SM-09" => "Serravalle",
+    "ST-P" => "Príncipe",
+    "SA-04" => "Ash Sharqiyah",
+    "SA-05" => "Al Qaşīm",
+    "SA-07" => "Tabūk",
+    "SA-10" => "Najrān",
+    "SA-12" => "Al Jawf",
+    "SN-DB" => "Diourbel",
+    "SN-DK" => "Dakar",
+    "SN-FK" => "Fatick",
+    "SN-KA" => "Kaffrine",
+    "SN-KD" => "Kolda",
+    "SN-KE" => "Kédougou",
+    "SN-KL" => "Kaolack",
+    "SN-LG" => "Louga",
+    "SN-MT" => "Matam",
+    "SN-SE" => "Sédhiou",
+    "SN-SL" => "Saint-Louis",
+    "SN-TC" => "Tambacounda",
+    "SN-TH" => "Thiès",
+    "SN-ZG" => "Ziguinchor",
+    "RS-00" => "Belgrade",
+    "RS-01" => "Bačka septentrionale",
+    "RS-02" => "Banatčka méridionale",
+    "RS-03" => "Banatčka septentrionale",
+    "RS-04" => "Južnobanat",
+    "RS-05" => "Bačka occidentale",
+    "RS-06" => "Južnobač",
+    "RS-07" => "Srem",
+    "RS-08" => "Mačvan",
+    "RS-09" => "Kolubara",
+    "RS-10" => "Podunavlje",
+    "RS-11" => "Braničevo",
+    "RS-12" => "Šumadija",
+    "RS-13" => "Pomoravlje",
+    "RS-14" => "Bor",
+    "RS-15" => "Zaječar",
+    "RS-16" => "Zlatibor",
+    "RS-17" => "Moraviča",
+    "RS-18" => "Raška",
+    "RS-19" => "Rasina",
+    "RS-20" => "Nišava",
+    "RS-21" => "Topliča",
+    "RS-22" => "Pirot",
+    "RS-23" => "Jablaniča",
+    "RS-24" => "Pčinja",
+    "RS-25" => "Kosovo",
+    "RS-26" => "Peć",
+    "RS-27" => "Prizren",
+    "RS-28" => "Kosovo Mitroviča",
+    "RS-29" => "Kosovo-Pomoravlje",
+    "RS-KM" => "Kosovo",
+    "RS-VO" => "Voïvodine",
+    "SC-01" => "Anse aux Pins",
+    "SC-02" => "Anse Boileau",
+    "SC-03" => "Anse Étoile",
+    "SC-05" => "Anse Royale",
+    "SC-06" => "Baie Lazare",
+    "SC-07" => "Baie Sainte Anne",
+    "SC-08" => "Beau Vallon",
+    "SC-09" => "Bel Air",
+    "SC-10" => "Bel Ombre",
+    "SC-11" => "Cascade",
+    "SC-12" => "Glacis",
+    "SC-13" => "Grand'Anse Mahé",
+    "SC-14" => "Grand'Anse Praslin",
+    "SC-15" => "La Digue",
+    "SC-16" => "La Rivière Anglaise",
+    "SC-17" => "Mont Buxton",
+    "SC-18" => "Mont Fleuri",
+    "SC-19" => "Plaisance",
+    "SC-20" => "Pointe La Rue",
+    "SC-21" => "Port Glaud",
+    "SC-22" => "Saint Louis",
+    "SC-23" => "Takamaka",
+    "SC-24" => "Les Mamelles",
+    "SC-25" => "Roche Caïman",
+    "SL-E" => "Est",
+    "SL-N" => "Nord",
+    "SL-S" => "Sud",
+    "SL-W" => "Zone Ouest (Freetown)",
+    "SG-01" => "Singapour centre",
+    "SG-02" => "Nord-Est",
+    "SG-03" => "Nord-Ouest",
+    "SG-04" => "Sud-Est",
+    "SG-05" => "Sud-Ouest",
+    "SK-BC" => "Banská Bystrica",
+    "SK-BL" => "Bratislava",
+    "SK-KI" => "Košice",
+    "SK-NI" => "Nitra",
+    "SK-PV" => "Prešov",
+    "SK-TA" => "Trnava",
+    "SK-TC" => "Trenčín",
+    "SK-ZI" => "Žilina",
+    "SI-001" => "Ajdovščina",
+    "SI-002" => "Beltinci",
+    "SI-003" => "Bled",
+    "SI-004" => "Bohinj",
+    "SI-005" => "Borovnica",
+    "SI-006" => "Bovec",
+    "SI-007" => "Brda",
+    "SI-008" => "Brezovica",
+    "SI-009" => "Brežice",
+    "SI-010" => "Tišina",
+    "SI-011" => "Celje",
+    "SI-012" => "Cerklje na Gorenjskem",
+    "SI-013" => "Cerknica",
+    "SI-014" => "Cerkno",
+    "SI-015" => "Črenšovci",
+    "SI-016" => "Črna na Koroškem",
+    "SI-017" => "Črnomelj",
+    "SI-018" => "Destrnik",
+    "SI-019" => "Divača",
+    "SI-020" => "Dobrepolje",
+    "SI-021" => "Dobrova-Polhov Gradec",
+    "SI-022" => "Dol pri Ljubljani",
+    "SI-023" => "Domžale",
+    "SI-024" => "Dornava",
+    "SI-025" => "Dravograd",
+    "SI-026" => "Duplek",
+    "SI-027" => "Gorenja vas-Poljane",
+    "SI-028" => "Gorišnica",
+    "SI-029" => "Gornja Radgona",
+    "SI-030" => "Gornji Grad",
+    "SI-031" => "Gornji Petrovci",
+    "SI-032" => "Grosuplje",
+    "SI-033" => "Šalovci",
+    "SI-034" => "Hrastnik",
+    "SI-035" => "Hrpelje-Kozina",
+    "SI-036" => "Idrija",
+    "SI-037" => "Ig",
+    "SI-038" => "Ilirska Bistrica",
+    "SI-039" => "Ivančna Gorica",
+    "SI-041" => "Jesenice",
+    "SI-042" => "Juršinci",
+    "SI-043" => "Kamnik",
+    "SI-044" => "Kanal",
+    "SI-045" => "Kidričevo",
+    "SI-046" => "Kobarid",
+    "SI-047" => "Kobilje",
+    "SI-048" => "Kočevje",
+    "SI-049" => "Komen",
+    "SI-051" => "Kozje",
+    "SI-052" => "Kranj",
+    "SI-053" => "Kranjska Gora",
+    "SI-054" => "Krško",
+    "SI-055" => "Kungota",
+    "SI-056" => "Kuzma",
+    "SI-057" => "Laško",
+    "SI-058" => "Lenart",
+    "SI-060" => "Litija",
+    "SI-061" => "Ljubljana",
+    "SI-062" => "Ljubno",
+    "SI-063" => "Ljutomer",
+    "SI-064" => "Logatec",
+    "SI-065" => "Loška dolina",
+    "SI-066" => "Loški Potok",
+    "SI-067" => "Luče",
+    "SI-068" => "Lukovica",
+    "SI-069" => "Majšperk",
+    "SI-070" => "Maribor",
+    "SI-071" => "Medvode",
+    "SI-072" => "Mengeš",
+    "SI-073" => "Metlika",
+    "SI-074" => "Mežica",
+    "SI-075" => "Miren-Kostanjevica",
+    "SI-076" => "Mislinja",
+    "SI-077" => "Moravče",
+    "SI-078" => "Moravske Toplice",
+    "SI-079" => "Mozirje",
+    "SI-080" => "Murska Sobota",
+    "SI-081" => "Muta",
+    "SI-082" => "Naklo",
+    "SI-083" => "Nazarje",
+    "SI-084" => "Nova Gorica",
+    "SI-086" => "Odranci",
+    "SI-087" => "Ormož",
+    "SI-088" => "Osilnica",
+    "SI-089" => "Pesnica",
+    "SI-091" => "Pivka",
+    "SI-092" => "Podčetrtek",
+    "SI-093" => "Podvelka",
+    "SI-094" => "Postojna",
+    "SI-095" => "Preddvor",
+    "SI-096" => "Ptuj",
+    "SI-097" => "Puconci",
+    "SI-098" => "Rače-Fram",
+    "SI-099" => "Radeče",
+    "SI-100" => "Radenci",
+    "SI-101" => "Radlje ob Dravi",
+    "SI-102" => "Radovljica",
+    "SI-103" => "Ravne na Koroškem",
+    "SI-104" => "Ribnica",
+    "SI-105" => "Rogašovci",
+    "SI-106" => "Rogaška Slatina",
+    "SI-107" => "Rogatec",
+    "SI-108" => "Ruše",
+    "SI-109" => "Semič",
+    "SI-110" => "Sevnica",
+    "SI-111" => "Sežana",
+    "SI-112" => "Slovenj Gradec",
+    "SI-113" => "Slovenska Bistrica",
+    "SI-114" => "Slovenske Konjice",
+    "SI-117" => "Šenčur",
+    "SI-118" => "Šentilj",
+    "SI-119" => "Šentjernej",
+    "SI-120" => "Šentjur",
+    "SI-121" => "Škocjan",
+    "SI-122" => "Škofja Loka",
+    "SI-123" => "Škofljica",
+    "SI-124" => "Šmarje pri Jelšah",
+    "SI-125" => "Šmartno ob Paki",
+    "SI-126" => "Šoštanj",
+    "SI-127" => "Štore",
+    "SI-128" => "Tolmin",
+    "SI-129" => "Trbovlje",
+    "SI-130" => "Trebnje",
+    "SI-131" => "Tržič",
+    "SI-132" => "Turnišče",
+    "SI-133" => "Velenje",
+    "SI-134" => "Velike Lašče",
+    "SI-135" => "Videm",
+    "SI-136" => "Vipava",
+    "SI-137" => "Vitanje",
+    "SI-138" => "Vodice",
+    "SI-139" => "Vojnik",
+    "SI-140" => "Vrhnika",
+    "SI-141" => "Vuzenica",
+    "SI-142" => "Zagorje ob Savi",
+    "SI-143" => "Zavrč",
+    "SI-144" => "Zreče",
+    "SI-146" => "Železniki",
+    "SI-147" => "Žiri",
+    "SI-148" => "Benedikt",
+    "SI-149" => "Bistrica ob Sotli",
+    "SI-150" => "Bloke",
+    "SI-151" => "Braslovče",
+    "SI-152" => "Cankova",
+    "SI-153" => "Cerkvenjak",
+    "SI-154" => "Dobje",
+    "SI-155" => "Dobrna",
+    "SI-157" => "Dolenjske Toplice",
+    "SI-158" => "Grad",
+    "SI-159" => "Hajdina",
+    "SI-160" => "Hoče-Slivnica",
+    "SI-162" => "Horjul",
+    "SI-163" => "Jezersko",
+    "SI-164" => "Komenda",
+    "SI-165" => "Kostel",
+    "SI-166" => "Križevci",
+    "SI-167" => "Lovrenc na Pohorju",
+    "SI-168" => "Markovci",
+    "SI-169" => "Miklavž na Dravskem polju",
+    "SI-170" => "Mirna Peč",
+    "SI-171" => "Oplotnica",
+    "SI-172" => "Podlehnik",
+    "SI-173" => "Polzela",
+    "SI-174" => "Prebold",
+    "SI-175" => "Prevalje",
+    "SI-176" => "Razkrižje",
+    "SI-177" => "Ribnica na Pohorju",
+    "SI-178" => "Selnica ob Dravi",
+    "SI-179" => "Sodražica",
+    "SI-180" => "Solčava",
+    "SI-181" => "Sveta Ana",
+    "SI-183" => "Šempeter-Vrtojba",
+    "SI-184" => "Tabor",
+    "SI-186" => "Trzin",
+    "SI-187" => "Velika Polana",
+    "SI-188" => "Veržej",
+    "SI-189" => "Vransko",
+    "SI-190" => "Žalec",
+    "SI-191" => "Žetale",
+    "SI-192" => "Žirovnica",
+    "SI-193" => "Žužemberk",
+    "SI-194" => "Šmartno pri Litiji",
+    "SI-195" => "Apače",
+    "SI-196" => "Cirkulane",
+    "SI-197" => "Kosanjevica na Krki",
+    "SI-198" => "Makole",
+    "SI-199" => "Mokronog-Trebelno",
+    "SI-200" => "Poljčane",
+    "SI-201" => "Renče-Vogrsko",
+    "SI-202" => "Središče ob Dravi",
+    "SI-203" => "Straža",
+    "SI-205" => "Sveti Tomaž",
+    "SI-207" => "Gorje",
+    "SI-208" => "Log-Dragomer",
+    "SI-209" => "Rečica ob Savinji",
+    "SI-211" => "Šentrupert",
+    "SB-CE" => "Central",
+    "SB-CH" => "Choiseul",
+    "SB-CT" => "Honiara (territoire de la capitale)",
+    "SB-GU" => "Guadalcanal",
+    "SB-IS" => "Isabel",
+    "SB-ML" => "Malaita",
+    "SB-RB" => "Rennell et Bellona",
+    "SB-TE" => "Temotu",
+    "SB-WE" => "Ouest",
+    "SO-AW" => "Awdal",
+    "SO-BK" => "Bakool",
+    "SO-BN" => "Banaadir",
+    "SO-BR" => "Bari",
+    "SO-BY" => "Bay",
+    "SO-GA" => "Galguduud",
+    "SO-GE" => "Gedo",
+    "SO-JD" => "Jubbada Dhexe",
+    "SO-JH" => "Jubbada Hoose",
+    "SO-MU" => "Mudug",
+    "SO-NU" => "Nugaal",
+    "SO-SD" => "Shabeellaha Dhexe",
+    "SO-SH" => "Shabeellaha Hoose",
+    "SO-SO" => "Sool",
+    "SO-TO" => "Togdheer",
+    "SO-WO" => "Woqooyi Galbeed",
+    "ZA-EC" => "Cap-Oriental",
+    "ZA-FS" => "État-Libre",
+    "ZA-GP" => "Gauteng",
+    "ZA-KZN" => "Kwazulu-Natal",
+    "ZA-LP" => "Limpopo",
+    "ZA-MP" => "Mpumalanga",
+    "ZA-NC" => "Cap-du-Nord",
+    "ZA-NW" => "Nord-Ouest",
+    "ZA-WC" => "Cap-Occidental",
+    "SS-BN" => "Bahr el Ghazal du Nord",
+    "SS-BW" => "Bahr el Gazal occidental",
+    "SS-EC" => "Équateur central",
+    "SS-EE" => "Équateur Oriental",
+    "SS-EW" => "Équateur occidental",
+    "SS-JG" => "Jonglei",
+    "SS-LK" => "Lacs",
+    "SS-NU" => "Haut-Nil",
+    "SS-UY" => "Unité",
+    "SS-WR" => "Warrap",
+    "ES-AB" => "Albacete",
+    "ES-AL" => "Almeria",
+    "ES-AN" => "Andalousie",
+    "ES-AR" => "Aragon",
+    "ES-AS" => "Asturies, principauté des",
+    "ES-AV" => "Avila",
+    "ES-BA" => "Badajoz",
+    "ES-BI" => "Biscaye",
+    "ES-BU" => "Burgos",
+    "ES-C" => "La Corogne",
+    "ES-CA" => "Cadix",
+    "ES-CB" => "Cantabrique",
+    "ES-CC" => "Caceres",
+    "ES-CE" => "Ceuta",
+    "ES-CL" => "Castille et Léon",
+    "ES-CM" => "Castille-La Manche",
+    "ES-CN" => "Canaries",
+    "ES-CO" => "Cordoba",
+    "ES-CR" => "Ciudad Real",
+    "ES-CU" => "Cuenca",
+    "ES-EX" => "Extramadoure",
+    "ES-GC" => "Las Palmas",
+    "ES-GR" => "Grenade",
+    "ES-GU" => "Guadalajara",
+    "ES-H" => "Huelva",
+    "ES-HU" => "Huesca",
+    "ES-J" => "Jaen",
+    "ES-LE" => "Léon",
+    "ES-LO" => "La Rioja",
+    "ES-M" => "Madrid",
+    "ES-MA" => "Malaga",
+    "ES-MC" => "Murcei, région de",
+    "ES-MD" => "Madrid, Communauté de",
+    "ES-ML" => "Melilla",
+    "ES-MU" => "Murcie",
+    "ES-O" => "Asturias",
+    "ES-P" => "Palencia",
+    "ES-RI" => "La Rioja",
+    "ES-S" => "Cantabrique",
+    "ES-SA" => "Salamanque",
+    "ES-SE" => "Séville",
+    "ES-SG" => "Segovie",
+    "ES-SO" => "Soria",
+    "ES-SS" => "Guipuscoa",
+    "ES-TE" => "Téruel",
+    "ES-TF" => "Santa Cruz de Tenerife",
+    "ES-TO" => "Toledo",
+    "ES-VA" => "Valladolid",
+    "ES-Z" => "Saragosse",
+    "ES-ZA" => "Zamora",
+    "LK-12" => "Gampaha",
+    "LK-81" => "Badulla",
+    "LK-91" => "Ratnapura",
+    "SD-NO" => "Nord",
+    "SR-BR" => "Brokopondo",
+    "SR-CM" => "Commewijne",
+    "SR-CR" => "Coronie",
+    "SR-MA" => "Marowijne",
+    "SR-NI" => "Nickerie",
+    "SR-PM" => "Paramaribo",
+    "SR-PR" => "Para",
+    "SR-SA" => "Saramacca",
+    "SR-SI" => "Sipaliwini",
+    "SR-WA" => "Wanica",
+    "CH-AG" => "Argovie",
+    "CH-AI" => "Appenzell Rhodes-Intérieures",
+    "CH-AR" => "Appenzell Rhodes-Extérieures",
+    "CH-BE" => "Berne",
+    "CH-BL" => "Bâle-Campagne",
+    "CH-BS" => "Bâle-Ville",
+    "CH-GE" => "Genève",
+    "CH-GL" => "Glaris",
+    "CH-GR" => "Grisons",
+    "CH-JU" => "Jura",
+    "CH-LU" => "Lucerne",
+    "CH-NE" => "Neuchâtel",
+    "CH-NW" => "Nidwald",
+    "CH-OW" => "Obwald",
+    "CH-SG" => "Saint-Gall",
+    "CH-SH" => "Schaffhouse",
+    "CH-SO" => "Soleure",
+    "CH-SZ" => "Schwytz",
+    "CH-TG" => "Thurgovie",
+    "CH-TI" => "Tessin",
+    "CH-UR" => "Uri",
+    "CH-VD" => "Vaud",
+    "CH-VS" => "Valais",
+    "CH-ZG" => "Zoug",
+    "CH-ZH" => "Zurich",
+    "SY-DI" => "Damas",
+    "SY-DY" => "Dayr az Zawr",
+    "SY-ID" => "Idlib",
+    "SY-RA" => "Ar Raqqah",
+    "TW-CHA" => "Changhua",
+    "TW-CYI" => "Chiayi",
+    "TW-CYQ" => "Chiayi",
+    "TW-HSQ" => "Hsinchu",
+    "TW-HSZ" => "Hsinchu",
+    "TW-HUA" => "Hualien",
+    "TW-KHH" => "Kaohsiung",
+    "TW-MIA" => "Miaoli",
+    "TW-NAN" => "Nantou",
+    "TW-PEN" => "Penghu",
+    "TW-PIF" => "Pingtung",
+    "TW-TAO" => "Taoyuan",
+    "TW-TNN" => "Tainan",
+    "TW-TPE" => "Taipei",
+    "TW-TTT" => "Taitung",
+    "TW-TXG" => "Taichung",
+    "TW-YUN" => "Yunlin",
+    "TJ-KT" => "Khatlon",
+    "TJ-SU" => "Sughd",
+    "TZ-01" => "Arusha",
+    "TZ-03" => "Dodoma",
+    "TZ-04" => "Iringa",
+    "TZ-05" => "Kagera",
+    "TZ-08" => "Kigoma",
+    "TZ-09" => "Kilimandjaro",
+    "TZ-12" => "Lindi",
+    "TZ-13" => "Mara",
+    "TZ-14" => "Mbeya",
+    "TZ-16" => "Morogoro",
+    "TZ-17" => "Mtwara",
+    "TZ-18" => "Mwanza",
+    "TZ-20" => "Rukwa",
+    "TZ-21" => "Ruvuma",
+    "TZ-22" => "Shinyanga",
+    "TZ-23" => "Singida",
+    "TZ-24" => "Tabora",
+    "TZ-25" => "Tanga",
+    "TZ-26" => "Manyara",
+    "TH-11" => "Samut Prakan",
+    "TH-12" => "Nonthaburi",
+    "TH-13" => "Pathum Thani",
+    "TH-14" => "Phra Nakhon Si Ayutthaya",
+    "TH-15" => "Ang Thong",
+    "TH-16" => "Lop Buri",
+    "TH-17" => "Sing Buri",
+    "TH-18" => "Chai Nat",
+    "TH-19" => "Saraburi",
+    "TH-20" => "Chon Buri",
+    "TH-21" => "Rayong",
+    "TH-22" => "Chanthaburi",
+    "TH-23" => "Trat",
+    "TH-24" => "Chachoengsao",
+    "TH-25" => "Prachin Buri",
+    "TH-26" => "Nakhon Nayok",
+    "TH-27" => "Sa Kaeo",
+    "TH-30" => "Nakhon Ratchasima",
+    "TH-31" => "Buri Ram",
+    "TH-32" => "Surin",
+    "TH-33" => "Si Sa Ket",
+    "TH-34" => "Ubon Ratchathani",
+    "TH-35" => "Yasothon",
+    "TH-36" => "Chaiyaphum",
+    "TH-37" => "Amnat Charoen",
+    "TH-39" => "Nong Bua Lam Phu",
+    "TH-40" => "Khon Kaen",
+    "TH-41" => "Udon Thani",
+    "TH-42" => "Loei",
+    "TH-43" => "Nong Khai",
+    "TH-44" => "Maha Sarakham",
+    "TH-45" => "Roi Et",
+    "TH-46" => "Kalasin",
+    "TH-47" => "Sakon Nakhon",
+    "TH-48" => "Nakhon Phanom",
+    "TH-49" => "Mukdahan",
+    "TH-50" => "Chiang Mai",
+    "TH-51" => "Lamphun",
+    "TH-52" => "Lampang",
+    "TH-53" => "Uttaradit",
+    "TH-54" => "Phrae",
+    "TH-55" => "Nan",
+    "TH-56" => "Phayao",
+    "TH-57" => "Chiang Rai",
+    "TH-58" => "Mae Hong Son",
+    "TH-60" => "Nakhon Sawan",
+    "TH-61" => "Uthai Thani",
+    "TH-62" => "Kamphaeng Phet",
+    "TH-63" => "Tak",
+    "TH-64" => "Sukhothai",
+    "TH-65" => "Phitsanulok",
+    "TH-66" => "Phichit",
+    "TH-67" => "Phetchabun",
+    "TH-70" => "Ratchaburi",
+    "TH-71" => "Kanchanaburi",
+    "TH-72" => "Suphan Buri",
+    "TH-73" => "Nakhon Pathom",
+    "TH-74" => "Samut Sakhon",
+    "TH-75" => "Samdrup Jongkhar",
+    "TH-76" => "Phetchaburi",
+    "TH-77" => "Prachuap Khiri Khan",
+    "TH-80" => "Nakhon Si Thammarat",
+    "TH-81" => "Krabi",
+    "TH-82" => "Phangnga",
+    "TH-83" => "Phuket",
+    "TH-84" => "Surat Thani",
+    "TH-85" => "Ranong",
+    "TH-86" => "Chumphon",
+    "TH-90" => "Songkhla",
+    "TH-91" => "Satun",
+    "TH-92" => "Trang",
+    "TH-93" => "Phatthalung",
+    "TH-94" => "Pattani",
+    "TH-95" => "Yala",
+    "TH-96" => "Narathiwat",
+    "TH-S" => "Phatthaya",
+    "TL-AL" => "Aileu",
+    "TL-AN" => "Ainaro",
+    "TL-BA" => "Baucau",
+    "TL-BO" => "Bobonaro",
+    "TL-CO" => "Cova Lima",
+    "TL-DI" => "Díli",
+    "TL-ER" => "Ermera",
+    "TL-MF" => "Manufahi",
+    "TL-MT" => "Manatuto",
+    "TG-P" => "Plateaux",
+    "TO-01" => "'Eua",
+    "TO-02" => "Ha'apai",
+    "TO-03" => "Niuas",
+    "TO-04" => "Tongatapu",
+    "TO-05" => "Vava'u",
+    "TT-ARI" => "Arima",
+    "TT-CHA" => "Chaguanas",
+    "TT-CTT" => "Couva-Tabaquite-Talparo",
+    "TT-DMN" => "Diego Martin",
+    "TT-PED" => "Penal-Debe",
+    "TT-POS" => "Port d'Espagne",
+    "TT-PRT" => "Princes Town",
+    "TT-PTF" => "Point Fortin",
+    "TT-SFO" => "San Fernando",
+    "TT-SGE" => "Sangre Grande",
+    "TT-SIP" => "Siparia",
+    "TT-SJL" => "San Juan-Laventille",
+    "TT-TUP" => "Tunapuna-Piarco",
+    "TN-11" => "Tunis",
+    "TN-13" => "Ben Arous",
+    "TN-14" => "La Manouba",
+    "TN-21" => "Nabeul",
+    "TN-22" => "Zaghouan",
+    "TN-23" => "Bizerte",
+    "TN-31" => "Béja",
+    "TN-32" => "Jendouba",
+    "TN-33" => "Le Kef",
+    "TN-34" => "Siliana",
+    "TN-41" => "Kairouan",
+    "TN-42" => "Kasserine",
+    "TN-43" => "Sidi Bouzid",
+    "TN-51" => "Sousse",
+    "TN-52" => "Monastir",
+    "TN-53" => "Mahdia",
+    "TN-61" => "Sfax",
+    "TN-71" => "Gafsa",
+    "TN-72" => "Tozeur",
+    "TN-81" => "Gabès",
+    "TN-83" => "Tataouine",
+    "TR-01" => "Adana",
+    "TR-02" => "Adıyaman",
+    "TR-03" => "Afyonkarahisar",
+    "TR-04" => "Ağrı",
+    "TR-05" => "Amasya",
+    "TR-06" => "Ankara",
+    "TR-07" => "Antalya",
+    "TR-08" => "Artvin",
+    "TR-09" => "Aydın",
+    "TR-10" => "Balıkesir",
+    "TR-11" => "Bilecik",
+    "TR-12" => "Bingöl",
+    "TR-13" => "Bitlis",
+    "TR-14" => "Bolu",
+    "TR-15" => "Burdur",
+    "TR-16" => "Bursa",
+    "TR-17" => "Çanakkale",
+    "TR-18" => "Çankırı",
+    "TR-19" => "Çorum",
+    "TR-20" => "Denizli",
+    "TR-21" => "Diyarbakır",
+    "TR-22" => "Edirne",
+    "TR-23" => "Elazığ",
+    "TR-24" => "Erzincan",
+    "TR-25" => "Erzurum",
+    "TR-26" => "Eskişehir",
+    "TR-27" => "Gaziantep",
+    "TR-28" => "Giresun",
+    "TR-29" => "Gümüşhane",
+    "TR-30" => "Hakkâri",
+    "TR-31" => "Hatay",
+    "TR-32" => "Isparta",
+    "TR-33" => "Mersin",
+    "TR-34" => "İstanbul",
+    "TR-35" => "İzmir",
+    "TR-36" => "Kars",
+    "TR-37" => "Kastamonu",
+    "TR-38" => "Kayseri",
+    "TR-39" => "Kırklareli",
+    "TR-40" => "Kırşehir",
+    "TR-41" => "Kocaeli",
+    "TR-42" => "Konya",
+    "TR-43" => "Kütahya",
+    "TR-44" => "Malatya",
+    "TR-45" => "Manisa",
+    "TR-46" => "Kahramanmaraş",
+    "TR-47" => "Mardin",
+    "TR-48" => "Muğla",
+    "TR-49" => "Muş",
+    "TR-50" => "Nevşehir",
+    "TR-51" => "Niğde",
+    "TR-52" => "Ordu",
+    "TR-53" => "Rize",
+    "TR-54" => "Sakarya",
+    "TR-55" => "Samsun",
+    "TR-56" => "Siirt",
+    "TR-57" => "Sinop",
+    "TR-58" => "Sivas",
+    "TR-59" => "Tekirdağ",
+    "TR-60" => "Tokat",
+    "TR-61" => "Trabzon",
+    "TR-62" => "Tunceli",
+    "TR-63" => "Şanlıurfa",
+    "TR-64" => "Uşak",
+    "TR-65" => "Van",
+    "TR-66" => "Yozgat",
+    "TR-67" => "Zonguldak",
+    "TR-68" => "Aksaray",
+    "TR-69" => "Bayburt",
+    "TR-70" => "Karaman",
+    "TR-71" => "Kırıkkale",
+    "TR-72" => "Batman",
+    "TR-73" => "Şırnak",
+    "TR-74" => "Bartın",
+    "TR-75" => "Ardahan",
+    "TR-76" => "Iğdır",
+    "TR-77" => "Yalova",
+    "TR-78" => "Karabük",
+    "TR-79" => "Kilis",
+    "TR-80" => "Osmaniye",
+    "TR-81" => "Düzce",
+    "TM-A" => "Ahal",
+    "TM-B" => "Balkan",
+    "TM-D" => "Daşoguz",
+    "TM-L" => "Lebap",
+    "TM-M" => "Mary",
+    "TM-S" => "Achgabat",
+    "TV-FUN" => "Funafuti",
+    "TV-NIT" => "Niutao",
+    "TV-NKF" => "Nukufetau",
+    "TV-NKL" => "Nukulaelae",
+    "TV-NMA" => "Nanumea",
+    "TV-NUI" => "Nui",
+    "TV-VAI" => "Vaitupu",
+    "UG-101" => "Kalangala",
+    "UG-102" => "Kampala",
+    "UG-103" => "Kiboga",
+    "UG-104" => "Luwero",
+    "UG-105" => "Masaka",
+    "UG-106" => "Mpigi",
+    "UG-107" => "Mubende",
+    "UG-108" => "Mukono",
+    "UG-109" => "Nakasongola",
+    "UG-110" => "Rakai",
+    "UG-111" => "Sembabule",
+    "UG-112" => "Kayunga",
+    "UG-113" => "Wakiso",
+    "UG-114" => "Lyantonde",
+    "UG-115" => "Mityana",
+    "UG-116" => "Nakaseke",
+    "UG-201" => "Bugiri",
+    "UG-202" => "Busia",
+    "UG-203" => "Iganga",
+    "UG-204" => "Jinja",
+    "UG-205" => "Kamuli",
+    "UG-206" => "Kapchorwa",
+    "UG-207" => "Katakwi",
+    "UG-208" => "Kumi",
+    "UG-209" => "Mbale",
+    "UG-210" => "Pallisa",
+    "UG-211" => "Soroti",
+    "UG-212" => "Tororo",
+    "UG-213" => "Kaberamaido",
+    "UG-214" => "Mayuge",
+    "UG-215" => "Sironko",
+    "UG-216" => "Amuria",
+    "UG-217" => "Budaka",
+    "UG-218" => "Bududa",
+    "UG-219" => "Bukedea",
+    "UG-221" => "Butaleja",
+    "UG-222" => "Kaliro",
+    "UG-223" => "Manafwa",
+    "UG-224" => "Namutumba",
+    "UG-301" => "Adjumani",
+    "UG-302" => "Apac",
+    "UG-303" => "Arua",
+    "UG-304" => "Gulu",
+    "UG-305" => "Kitgum",
+    "UG-306" => "Kotido",
+    "UG-307" => "Lira",
+    "UG-308" => "Moroto",
+    "UG-309" => "Moyo",
+    "UG-310" => "Nebbi",
+    "UG-311" => "Nakapiripirit",
+    "UG-312" => "Pader",
+    "UG-313" => "Yumbe",
+    "UG-314" => "Abim",
+    "UG-315" => "Amolatar",
+    "UG-316" => "Amuru",
+    "UG-317" => "Dokolo",
+    "UG-318" => "Kaabong",
+    "UG-319" => "Koboko",
+    "UG-320" => "Maracha",
+    "UG-321" => "Oyam",
+    "UG-401" => "Bundibugyo",
+    "UG-402" => "Bushenyi",
+    "UG-403" => "Hoima",
+    "UG-404" => "Kabale",
+    "UG-405" => "Kabarole",
+    "UG-406" => "Kasese",
+    "UG-407" => "Kibaale",
+    "UG-408" => "Kisoro",
+    "UG-409" => "Masindi",
+    "UG-410" => "Mbarara",
+    "UG-411" => "Ntungamo",
+    "UG-412" => "Rukungiri",
+    "UG-413" => "Kamwenge",
+    "UG-414" => "Kanungu",
+    "UG-415" => "Kyenjojo",
+    "UG-416" => "Buliisa",
+    "UG-417" => "Ibanda",
+    "UG-418" => "Isingiro",
+    "UG-419" => "Kiruhura",
+    "UG-C" => "Central",
+    "UG-E" => "Est",
+    "UG-N" => "Nord",
+    "UG-W" => "Ouest",
+    "UA-40" => "Municipalité de Sébastopol",
+    "AE-DU" => "Dubaï",
+    "AE-FU" => "Fujaïrah",
+    "AE-RK" => "Ras el Khaïmah",
+    "AE-SH" => "Ash Shariqah",
+    "AE-UQ" => "Oumm al Qaïwaïn",
+    "GB-ABD" => "Aberdeenshire",
+    "GB-ABE" => "Aberdeen ville",
+    "GB-AGB" => "Argyll et Bute",
+    "GB-AND" => "Ards et North Down",
+    "GB-ANN" => "Antrim et Newtownabbey",
+    "GB-ANS" => "Angus",
+    "GB-BAS" => "Bath et Somerset Nord-Est",
+    "GB-BBD" => "Blackburn avec Darwen",
+    "GB-BDF" => "Bedford",
+    "GB-BDG" => "Barking et Dagenham",
+    "GB-BEN" => "Brent",
+    "GB-BEX" => "Bexley",
+    "GB-BGW" => "Blaenau Gwent",
+    "GB-BIR" => "Birmingham",
+    "GB-BKM" => "Buckinghamshire",
+    "GB-BNE" => "Barnet",
+    "GB-BNH" => "Brighton et Hove",
+    "GB-BNS" => "Barnsley",
+    "GB-BOL" => "Bolton",
+    "GB-BPL" => "Blackpool",
+    "GB-BRC" => "Bracknell Forest",
+    "GB-BRD" => "Bradford",
+    "GB-BRY" => "Bromley",
+    "GB-BST" => "Bristol ville",
+    "GB-BUR" => "Bury",
+    "GB-CAM" => "Cambridgeshire",
+    "GB-CBF" => "Bedfordshire central",
+    "GB-CCG" => "Causeway Coast et Glens",
+    "GB-CHE" => "Cheshire oriental",
+    "GB-CHW" => "Cheshire occidental et Chester",
+    "GB-CLD" => "Calderdale",
+    "GB-CLK" => "Clackmannanshire",
+    "GB-CMA" => "Cumbrie",
+    "GB-CMD" => "Camden",
+    "GB-CON" => "Cornouailles",
+    "GB-COV" => "Coventry",
+    "GB-CRY" => "Croydon",
+    "GB-CWY" => "Conwy",
+    "GB-DAL" => "Darlington",
+    "GB-DBY" => "Derbyshire",
+    "GB-DER" => "Derby",
+    "GB-DEV" => "Devon",
+    "GB-DGY" => "Dumfries et Galloway",
+    "GB-DNC" => "Doncaster",
+    "GB-DND" => "Dundee ville",
+    "GB-DOR" => "Dorset",
+    "GB-DRS" => "Derry et Strabane",
+    "GB-DUD" => "Dudley",
+    "GB-EAL" => "Ealing",
+    "GB-EAY" => "Ayrshire oriental",
+    "GB-EDH" => "Edimbourg ville",
+    "GB-EDU" => "Dunbartonshire oriental",
+    "GB-ELN" => "Lothian oriental",
+    "GB-ELS" => "Eilean Siar",
+    "GB-ENF" => "Enfield",
+    "GB-ERW" => "Renfrewshire oriental",
+    "GB-ERY" => "Riding of Yorkshire oriental",
+    "GB-ESS" => "Essex",
+    "GB-ESX" => "Sussex oriental",
+    "GB-FAL" => "Falkirk",
+    "GB-FIF" => "Fife",
+    "GB-FMO" => "Fermanagh et Omagh",
+    "GB-GAT" => "Gateshead",
+    "GB-GLG" => "Glasgow ville",
+    "GB-GLS" => "Gloucestershire",
+    "GB-GRE" => "Greenwich",
+    "GB-GWN" => "Gwynedd",
+    "GB-HAL" => "Halton",
+    "GB-HAM" => "Hampshire",
+    "GB-HAV" => "Havering",
+    "GB-HCK" => "Hackney",
+    "GB-HEF" => "Herefordshire",
+    "GB-HIL" => "Hillingdon",
+    "GB-HLD" => "Highland",
+    "GB-HMF" => "Hammersmith et Fulham",
+    "GB-HNS" => "Hounslow",
+    "GB-HPL" => "Hartlepool",
+    "GB-HRT" => "Hertfordshire",
+    "GB-HRW" => "Harrow",
+    "GB-HRY" => "Haringey",
+    "GB-IOS" => "Îles Scilly",
+    "GB-IOW" => "Île de Wight",
+    "GB-ISL" => "Islington",
+    "GB-IVC" => "Inverclyde",
+    "GB-KEC" => "Kensington et Chelsea",
+    "GB-KEN" => "Kent",
+    "GB-KHL" => "Kingston upon Hull",
+    "GB-KIR" => "Kirklees",
+    "GB-KTT" => "Kingston s/ Tamise",
+    "GB-KWL" => "Knowsley",
+    "GB-LAN" => "Lancashire",
+    "GB-LBC" => "Lisburn et Castlereagh",
+    "GB-LBH" => "Lambeth",
+    "GB-LCE" => "Leicester",
+    "GB-LDS" => "Leeds",
+    "GB-LEC" => "Leicestershire",
+    "GB-LEW" => "Lewisham",
+    "GB-LIN" => "Lincolnshire",
+    "GB-LIV" => "Liverpool",
+    "GB-LND" => "Londres ville",
+    "GB-LUT" => "Luton",
+    "GB-MAN" => "Manchester",
+    "GB-MDB" => "Middlesbrough",
+    "GB-MDW" => "Medway",
+    "GB-MEA" => "Mid et East Antrim",
+    "GB-MIK" => "Milton Keynes",
+    "GB-MLN" => "Midlothian",
+    "GB-MRT" => "Merton",
+    "GB-MRY" => "Moray",
+    "GB-NAY" => "Ayrshire septentrional",
+    "GB-NBL" => "Northumberland",
+    "GB-NEL" => "Lincolnshire nord-est",
+    "GB-NET" => "Newcastle s/ Tyne",
+    "GB-NFK" => "Norfolk",
+    "GB-NGM" => "Nottingham",
+    "GB-NLK" => "Lanarkshire septentrional",
+    "GB-NLN" => "Lincolnshire septentrional",
+    "GB-NMD" => "Newry, Mourne et Down",
+    "GB-NSM" => "Somerset septentrional",
+    "GB-NTT" => "Nottinghamshire",
+    "GB-NTY" => "Tyneside septentrional",
+    "GB-NWM" => "Newham",
+    "GB-NYK" => "Yorkshire septentrional",
+    "GB-OLD" => "Oldham",
+    "GB-ORK" => "Îles Orkney",
+    "GB-OXF" => "Oxfordshire",
+    "GB-PKN" => "Perth et Kinross",
+    "GB-PLY" => "Plymouth",
+    "GB-POR" => "Portsmouth",
+    "GB-POW" => "Powys",
+    "GB-PTE" => "Peterborough",
+    "GB-RCC" => "Redcar et Cleveland",
+    "GB-RCH" => "Rochdale",
+    "GB-RDB" => "Redbridge",
+    "GB-RDG" => "Reading",
+    "GB-RFW" => "Renfrewshire",
+    "GB-RIC" => "Richmond s/ Tamise",
+    "GB-ROT" => "Rotherham",
+    "GB-RUT" => "Rutland",
+    "GB-SAW" => "Sandwell",
+    "GB-SAY" => "Ayrshire méridional",
+    "GB-SFK" => "Suffolk",
+    "GB-SFT" => "Sefton",
+    "GB-SGC" => "Gloucestershire méridional",
+    "GB-SHF" => "Sheffield",
+    "GB-SHN" => "Sainte-Hélène",
+    "GB-SHR" => "Shropshire",
+    "GB-SKP" => "Stockport",
+    "GB-SLF" => "Salford",
+    "GB-SLG" => "Slough",
+    "GB-SLK" => "Lanarkshire méridional",
+    "GB-SND" => "Sunderland",
+    "GB-SOL" => "Solihull",
+    "GB-SOM" => "Somerset",
+    "GB-SOS" => "Southend sur Mer",
+    "GB-SRY" => "Surrey",
+    "GB-STE" => "Stoke-on-Trent",
+    "GB-STG" => "Stirling",
+    "GB-STH" => "Southampton",
+    "GB-STN" => "Sutton",
+    "GB-STS" => "Staffordshire",
+    "GB-STT" => "Stockton-on-Tees",
+    "GB-STY" => "Tyneside méridional",
+    "GB-SWD" => "Swindon",
+    "GB-SWK" => "Southwark",
+    "GB-TAM" => "Tameside",
+    "GB-TFW" => "Telford et Wrekin",
+    "GB-THR" => "Thurrock",
+    "GB-TOB" => "Torbay",
+    "GB-TRF" => "Trafford",
+    "GB-TWH" => "Tower Hamlets",
+    "GB-WAR" => "Warwickshire",
+    "GB-WBK" => "Berkshire occidental",
+    "GB-WDU" => "Dunbartonshire occidental",
+    "GB-WFT" => "Waltham Forest",
+    "GB-WGN" => "Wigan",
+    "GB-WIL" => "Wiltshire",
+    "GB-WKF" => "Wakefield",
+    "GB-WLL" => "Walsall",
+    "GB-WLN" => "Lothian occidental",
+    "GB-WLV" => "Wolverhampton",
+    "GB-WND" => "Wandsworth",
+    "GB-WNM" => "Windsor et Maidenhead",
+    "GB-WOK" => "Wokingham",
+    "GB-WOR" => "Worcestershire",
+    "GB-WRL" => "Wirral",
+    "GB-WRT" => "Warrington",
+    "GB-WSM" => "Westminster",
+    "GB-WSX" => "Sussex occidental",
+    "GB-YOR" => "York",
+    "GB-ZET" => "Îles Shetland",
+    "US-AK" => "Alaska",
+    "US-AL" => "Alabama",
+    "US-AR" => "Arkansas",
+    "US-AS" => "Samoa américaines",
+    "US-AZ" => "Arizona",
+    "US-CA" => "Californie",
+    "US-CO" => "Colorado",
+    "US-CT" => "Connecticut",
+    "US-DC" => "District de Columbia",
+    "US-DE" => "Delaware",
+    "US-FL" => "Floride",
+    "US-GA" => "Géorgie",
+    "US-GU" => "Guam",
+    "US-HI" => "Hawaii",
+    "US-IA" => "Iowa",
+    "US-ID" => "Idaho",
+    "US-IL" => "Illinois",
+    "US-IN" => "Indiana",
+    "US-KS" => "Kansas",
+    "US-KY" => "Kentucky",
+    "US-LA" => "Louisiane",
+    "US-MA" => "Massachusetts",
+    "US-MD" => "Maryland",
+    "US-ME" => "Maine",
+    "US-MI" => "Michigan",
+    "US-MN" => "Minnesota",
+    "US-MO" => "Missouri",
+    "US-MP" => "Îles Mariannes du Nord",
+    "US-MS" => "Mississippi",
+    "US-MT" => "Montana",
+    "US-NC" => "Caroline du Nord",
+    "US-ND" => "Dakota du Nord",
+    "US-NE" => "Nebraska",
+    "US-NH" => "New Hampshire",
+    "US-NJ" => "New Jersey",
+    "US-NM" => "Nouveau-Mexique",
+    "US-NV" => "Nevada",
+    "US-NY" => "New York",
+    "US-OH" => "Ohio",
+    "US-OK" => "Oklahoma",
+    "US-OR" => "Oregon",
+    "US-PA" => "Pennsylvanie",
+    "US-PR" => "Porto Rico",
+    "US-RI" => "Rhode Island",
+    "US-SC" => "Caroline du Sud",
+    "US-SD" => "Dakota du Sud",
+    "US-TN" => "Tennessee",
+    "US-TX" => "Texas",
+    "US-UM" => "Îles mineures éloignées des États-Unis",
+    "US-UT" => "Utah",
+    "US-VA" => "Virginie",
+    "US-VI" => "Îles Vierges des États-Unis",
+    "US-VT" => "Vermont",
+    "US-WA" => "Washington",
+    "US-WI" => "Wisconsin",
+    "US-WV" => "Virginia occidentale",
+    "US-WY" => "Wyoming",
+    "UM-67" => "Atoll Johnston",
+    "UM-71" => "Îles Midway",
+    "UM-76" => "Île Navassa",
+    "UM-79" => "Wake",
+    "UM-81" => "Île Baker",
+    "UM-84" => "Ile Howland",
+    "UM-86" => "Île Jarvis",
+    "UM-89" => "Kingman Reef",
+    "UM-95" => "Atoll Palmyra",
+    "UY-AR" => "Artigas",
+    "UY-CA" => "Canelones",
+    "UY-CL" => "Cerro Largo",
+    "UY-CO" => "Colonia",
+    "UY-DU" => "Durazno",
+    "UY-FD" => "Floride",
+    "UY-FS" => "Flores",
+    "UY-LA" => "Lavalleja",
+    "UY-MA" => "Maldonado",
+    "UY-MO" => "Montevideo",
+    "UY-PA" => "Paysandú",
+    "UY-RN" => "Río Negro",
+    "UY-RO" => "Rocha",
+    "UY-RV" => "Rivera",
+    "UY-SA" => "Salto",
+    "UY-SJ" => "San José",
+    "UY-SO" => "Soriano",
+    "UY-TA" => "Tacuarembó",
+    "UY-TT" => "Treinta y Tres",
+    "UZ-AN" => "Andijon",
+    "UZ-BU" => "Boukhara",
+    "UZ-JI" => "Jizzakh",
+    "UZ-NG" => "Namangan",
+    "UZ-NW" => "Navoiy",
+    "UZ-QA" => "Qashqadaryo",
+    "UZ-SA" => "Samarcande",
+    "UZ-SI" => "Sirdaryo",
+    "UZ-SU" => "Surxondaryo",
+    "UZ-TK" => "Tachkent",
+    "UZ-TO" => "Tachkent",
+    "UZ-XO" => "Xorazm",
+    "VU-MAP" => "Malampa",
+    "VU-PAM" => "Pénama",
+    "VU-SAM" => "Sanma",
+    "VU-SEE" => "Shéfa",
+    "VU-TAE" => "Taféa",
+    "VU-TOB" => "Torba",
+    "VE-B" => "Anzoátegui",
+    "VE-C" => "Apure",
+    "VE-D" => "Aragua",
+    "VE-E" => "Barinas",
+    "VE-F" => "Bolivar",
+    "VE-G" => "Carabobo",
+    "VE-H" => "Cojedes",
+    "VE-I" => "Falcón",
+    "VE-J" => "Guárico",
+    "VE-K" => "Lara",
+    "VE-L" => "Mérida",
+    "VE-M" => "Miranda",
+    "VE-N" => "Monagas",
+    "VE-O" => "Nueva Esparta",
+    "VE-P" => "Portuguesa",
+    "VE-R" => "Sucre",
+    "VE-S" => "Táchira",
+    "VE-T" => "Trujillo",
+    "VE-U" => "Yaracuy",
+    "VE-V" => "Zulia",
+    "VE-W" => "Dépendances fédérales",
+    "VE-Y" => "Delta Amacuro",
+    "VE-Z" => "Amazone",
+    "VN-01" => "Lai Châu",
+    "VN-02" => "Lào Cai",
+    "VN-03" => "Hà Giang",
+    "VN-04" => "Cao Bằng",
+    "VN-05" => "Sơn La",
+    "VN-06" => "Yên Bái",
+    "VN-07" => "Tuyên Quang",
+    "VN-09" => "Lạng Sơn",
+    "VN-13" => "Quảng Ninh",
+    "VN-18" => "Ninh Bình",
+    "VN-20" => "Thái Bình",
+    "VN-21" => "Thanh Hóa",
+    "VN-22" => "Nghệ An",
+    "VN-24" => "Quảng Bình",
+    "VN-25" => "Quảng Trị",
+    "VN-26" => "Thừa Thiên-Huế",
+    "VN-27" => "Quảng Nam",
+    "VN-28" => "Kon Tum",
+    "VN-29" => "Quảng Ngãi",
+    "VN-30" => "Gia Lai",
+    "VN-31" => "Bình Định",
+    "VN-32" => "Phú Yên",
+    "VN-34" => "Khánh Hòa",
+    "VN-35" => "Lâm Đồng",
+    "VN-36" => "Ninh Thuận",
+    "VN-37" => "Tây Ninh",
+    "VN-39" => "Đồng Nai",
+    "VN-40" => "Bình Thuận",
+    "VN-41" => "Long An",
+    "VN-44" => "An Giang",
+    "VN-45" => "Đồng Tháp",
+    "VN-46" => "Tiền Giang",
+    "VN-49" => "Vĩnh Long",
+    "VN-50" => "Bến Tre",
+    "VN-51" => "Trà Vinh",
+    "VN-52" => "Sóc Trăng",
+    "VN-53" => "Bắc Kạn",
+    "VN-54" => "Bắc Giang",
+    "VN-55" => "Bạc Liêu",
+    "VN-56" => "Bắc Ninh",
+    "VN-57" => "Bình Dương",
+    "VN-58" => "Bình Phước",
+    "VN-59" => "Cà Mau",
+    "VN-63" => "Hà Nam",
+    "VN-66" => "Hưng Yên",
+    "VN-67" => "Nam Định",
+    "VN-68" => "Phú Thọ",
+    "VN-69" => "Thái Nguyên",
+    "VN-70" => "Vĩnh Phúc",
+    "VN-71" => "Điện Biên",
+    "VN-72" => "Đắk Nông",
+    "VN-73" => "Hậu Giang",
+    "VN-CT" => "Cần Thơ",
+    "VN-DN" => "Đà Nẵng",
+    "VN-HN" => "Hanoi",
+    "VN-HP" => "Hai Phong",
+    "YE-DH" => "Dhamār",
+    "YE-HD" => "Ḩaḑramawt",
+    "YE-HJ" => "Ḩajjah",
+    "YE-HU" => "Al Ḩudaydah",
+    "YE-IB" => "Ibb",
+    "YE-JA" => "Al Jawf",
+    "YE-LA" => "Laḩij",
+    "YE-MR" => "Al Mahrah",
+    "YE-MW" => "Al Maḩwīt",
+    "YE-RA" => "Raymah",
+    "YE-SH" => "Shabwah",
+    "ZM-01" => "Ouest",
+    "ZM-02" => "Central",
+    "ZM-03" => "Est",
+    "ZM-04" => "Luapula",
+    "ZM-05" => "Nord",
+    "ZM-06" => "North-Ouest",
+    "ZM-07" => "Sud",
+    "ZM-08" => "Copperbelt",
+    "ZM-09" => "Lusaka",
+    "ZW-BU" => "Bulawayo",
+    "ZW-HA" => "Harare",
+    "ZW-MA" => "Manicaland",
+    "ZW-MC" => "Mashonaland central",
+    "ZW-ME" => "Mashonaland oriental",
+    "ZW-MI" => "Midlands",
+    "ZW-MN" => "Matabeleland septentrional",
+    "ZW-MS" => "Matabeleland méridional",
+    "ZW-MV" => "Masvingo",
+    "ZW-MW" => "Mashonaland occidental",
+};
+
+///French former country names by alpha-4 code
+pub const FORMER_NAME_MAP: Map<&str, &str> = phf_map! {
+    "BQAQ" => "Territoire britannique de l'Antarctique",
+    "BUMM" => "Union de Birmanie",
+    "BYAA" => "République socialiste soviétique de Biélorussie",
+    "CTKI" => "Îles Canton et Enderbury",
+    "CSHH" => "Tchécoslovaquie, république socialiste tchécoslovaque",
+    "DYBJ" => "Dahomey",
+    "NQAQ" => "Terre de la Reine Maud",
+    "TPTL" => "Timor oriental",
+    "FXFR" => "France métropolitaine",
+    "AIDJ" => "Territoire français des Afars et des Issas",
+    "FQHH" => "Terres australes et antarctiques françaises",
+    "DDDE" => "République démocratique allemande",
+    "GEHH" => "Îles Gilbert et Ellice",
+    "JTUM" => "Île Johnston",
+    "MIUM" => "Îles Midway",
+    "ANHH" => "Antilles néerlandaises",
+    "NTHH" => "Zone neutre",
+    "NHVU" => "Nouvelles-Hébrides",
+    "PCHH" => "Îles du Pacifique",
+    "PZPA" => "Zone du canal de Panama",
+    "CSXX" => "Serbie et Monténégro",
+    "SKIN" => "Sikkim",
+    "RHZW" => "Rhodésie du Sud",
+    "PUUM" => "Îles diverses du Pacifique des États-Unis",
+    "HVBF" => "Haute-Volta, République de",
+    "SUHH" => "URSS, Union des républiques socialistes soviétiques",
+    "VDVN" => "Viet-Nam, République démocratique du",
+    "WKUM" => "Wake",
+    "YDYE" => "Yémen, République populaire démocratique du",
+    "ZRCD" => "Zaïre, République du",
+};
@@ -0,0 +1,4100 @@
+use phf::phf_map;
+use phf::Map;
+
+///Italian country names by alpha-2 code
+pub const COUNTRY_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF" => "Afghanistan",
+    "AX" => "Isole Åland",
+    "AL" => "Albania",
+    "DZ" => "Algeria",
+    "AS" => "Samoa americane",
+    "AD" => "Andorra",
+    "AO" => "Angola",
+    "AI" => "Anguilla",
+    "AQ" => "Antartide",
+    "AG" => "Antigua e Barbuda",
+    "AR" => "Argentina",
+    "AM" => "Armenia",
+    "AW" => "Aruba",
+    "AU" => "Australia",
+    "AT" => "Austria",
+    "AZ" => "Azerbaigian",
+    "BS" => "Bahamas",
+    "BH" => "Bahrein",
+    "BD" => "Bangladesh",
+    "BB" => "Barbados",
+    "BY" => "Bielorussia",
+    "BE" => "Belgio",
+    "BZ" => "Belize",
+    "BJ" => "Benin",
+    "BM" => "Bermuda",
+    "BT" => "Bhutan",
+    "BO" => "Bolivia, Stato Plurinazionale della",
+    "BQ" => "Paesi Bassi caraibici",
+    "BA" => "Bosnia-Erzegovina",
+    "BW" => "Botswana",
+    "BV" => "Isola Bouvet",
+    "BR" => "Brasile",
+    "IO" => "Territorio britannico dell'Oceano Indiano",
+    "BN" => "Brunei",
+    "BG" => "Bulgaria",
+    "BF" => "Burkina Faso",
+    "BI" => "Burundi",
+    "CV" => "Capo Verde",
+    "KH" => "Cambogia",
+    "CM" => "Camerun",
+    "CA" => "Canada",
+    "KY" => "Isole Cayman",
+    "CF" => "Repubblica Centrafricana",
+    "TD" => "Ciad",
+    "CL" => "Cile",
+    "CN" => "Cina",
+    "CX" => "Isola di Natale",
+    "CC" => "Isole Cocos (Keeling)",
+    "CO" => "Colombia",
+    "KM" => "Comore",
+    "CG" => "Congo",
+    "CD" => "Repubblica democratica del Congo",
+    "CK" => "Isole Cook",
+    "CR" => "Costa Rica",
+    "CI" => "Costa d'Avorio",
+    "HR" => "Croazia",
+    "CU" => "Cuba",
+    "CW" => "Curaçao",
+    "CY" => "Cipro",
+    "CZ" => "Cechia",
+    "DK" => "Danimarca",
+    "DJ" => "Gibuti",
+    "DM" => "Dominica",
+    "DO" => "Repubblica Dominicana",
+    "EC" => "Ecuador",
+    "EG" => "Egitto",
+    "SV" => "El Salvador",
+    "GQ" => "Guinea equatoriale",
+    "ER" => "Eritrea",
+    "EE" => "Estonia",
+    "SZ" => "Eswatini",
+    "ET" => "Etiopia",
+    "FK" => "Isole Falkland (Malvine)",
+    "FO" => "Isole Fær Øer",
+    "FJ" => "Figi",
+    "FI" => "Finlandia",
+    "FR" => "Francia",
+    "GF" => "Guyana francese",
+    "PF" => "Polinesia francese",
+    "TF" => "Territori francesi meridionali",
+    "GA" => "Gabon",
+    "GM" => "Gambia",
+    "GE" => "Georgia",
+    "DE" => "Germania",
+    "GH" => "Ghana",
+    "GI" => "Gibilterra",
+    "GR" => "Grecia",
+    "GL" => "Groenlandia",
+    "GD" => "Grenada",
+    "GP" => "Guadalupa",
+    "GU" => "Guam",
+    "GT" => "Guatemala",
+    "GG" => "Guernsey",
+    "GN" => "Guinea",
+    "GW" => "Guinea-Bissau",
+    "GY" => "Guyana",
+    "HT" => "Haiti",
+    "HM" => "Isole Heard e McDonald",
+    "VA" => "Santa Sede (Stato della Città del Vaticano)",
+    "HN" => "Honduras",
+    "HK" => "Hong Kong",
+    "HU" => "Ungheria",
+    "IS" => "Islanda",
+    "IN" => "India",
+    "ID" => "Indonesia",
+    "IR" => "Iran",
+    "IQ" => "Iraq",
+    "IE" => "Irlanda",
+    "IM" => "Isola di Man",
+    "IL" => "Israele",
+    "IT" => "Italia",
+    "JM" => "Giamaica",
+    "JP" => "Giappone",
+    "JE" => "Jersey",
+    "JO" => "Giordania",
+    "KZ" => "Kazakistan",
+    "KE" => "Kenya",
+    "KI" => "Kiribati",
+    "KP" => "Corea del Nord",
+    "KR" => "Corea del sud",
+    "KW" => "Kuwait",
+    "KG" => "Kirghizistan",
+    "LA" => "Laos",
+    "LV" => "Lettonia",
+    "LB" => "Libano",
+    "LS" => "Lesotho",
+    "LR" => "Liberia",
+    "LY" => "Libia",
+    "LI" => "Liechtenstein",
+    "LT" => "Lituania",
+    "LU" => "Lussemburgo",
+    "MO" => "Macao",
+    "MG" => "Madagascar",
+    "MW" => "Malawi",
+    "MY" => "Malaysia",
+    "MV" => "Maldive",
+    "ML" => "Mali",
+    "MT" => "Malta",
+    "MH" => "Isole Marshall",
+    "MQ" => "Martinica",
+    "MR" => "Mauritania",
+    "MU" => "Maurizio",
+    "YT" => "Mayotte",
+    "MX" => "Messico",
+    "FM" => "Micronesia",
+    "MD" => "Moldavia",
+    "MC" => "Monaco",
+    "MN" => "Mongolia",
+    "ME" => "Montenegro",
+    "MS" => "Montserrat",
+    "MA" => "Marocco",
+    "MZ" => "Mozambico",
+    "MM" => "Birmania",
+    "NA" => "Namibia",
+    "NR" => "Nauru",
+    "NP" => "Nepal",
+    "NL" => "Paesi Bassi",
+    "NC" => "Nuova Caledonia",
+    "NZ" => "Nuova Zelanda",
+    "NI" => "Nicaragua",
+    "NE" => "Niger",
+    "NG" => "Nigeria",
+    "NU" => "Niue",
+    "NF" => "Isola Norfolk",
+    "MK" => "Macedonia del Nord",
+    "MP" => "Isole Marianne Settentrionali",
+    "NO" => "Norvegia",
+    "OM" => "Oman",
+    "PK" => "Pakistan",
+    "PW" => "Palau",
+    "PS" => "Palestina, Stato di",
+    "PA" => "Panama",
+    "PG" => "Papua Nuova Guinea",
+    "PY" => "Paraguay",
+    "PE" => "Perù",
+    "PH" => "Filippine",
+    "PN" => "Pitcairn",
+    "PL" => "Polonia",
+    "PT" => "Portogallo",
+    "PR" => "Portorico",
+    "QA" => "Qatar",
+    "RE" => "Riunione",
+    "RO" => "Romania",
+    "RU" => "Russia",
+    "RW" => "Ruanda",
+    "BL" => "Saint-Barthélemy",
+    "SH" => "Sant'Elena, Ascensione e Tristan da Cunha",
+    "KN" => "Saint Kitts e Nevis",
+    "LC" => "Saint Lucia",
+    "MF" => "Saint-Martin (Francia)",
+    "PM" => "Saint-Pierre e Miquelon",
+    "VC" => "Saint Vincent e Grenadine",
+    "WS" => "Samoa",
+    "SM" => "San Marino",
+    "ST" => "São Tomé e Príncipe",
+    "SA" => "Arabia Saudita",
+    "SN" => "Senegal",
+    "RS" => "Serbia",
+    "SC" => "Seychelles",
+    "SL" => "Sierra Leone",
+    "SG" => "Singapore",
+    "SX" => "Sint Maarten (Olanda)",
+    "SK" => "Slovacchia",
+    "SI" => "Slovenia",
+    "SB" => "Isole Salomone",
+    "SO" => "Somalia",
+    "ZA" => "Sudafrica",
+    "GS" => "Georgia del Sud e Isole Sandwich Australi",
+    "SS" => "Sudan del sud",
+    "ES" => "Spagna",
+    "LK" => "Sri Lanka",
+    "SD" => "Sudan",
+    "SR" => "Suriname",
+    "SJ" => "Svalbard e Jan Mayen",
+    "SE" => "Svezia",
+    "CH" => "Svizzera",
+    "SY" => "Siria",
+    "TW" => "Taiwan, Repubblica di Cina",
+    "TJ" => "Tagikistan",
+    "TZ" => "Tanzania",
+    "TH" => "Thailandia",
+    "TL" => "Timor Est",
+    "TG" => "Togo",
+    "TK" => "Tokelau",
+    "TO" => "Tonga",
+    "TT" => "Trinidad e Tobago",
+    "TN" => "Tunisia",
+    "TM" => "Turkmenistan",
+    "TC" => "Isole Turks e Caicos",
+    "TV" => "Tuvalu",
+    "UG" => "Uganda",
+    "UA" => "Ucraina",
+    "AE" => "Emirati Arabi Uniti",
+    "GB" => "Regno Unito",
+    "US" => "Stati Uniti",
+    "UM" => "Isole minori esterne degli Stati Uniti d'America",
+    "UY" => "Uruguay",
+    "UZ" => "Uzbekistan",
+    "VU" => "Vanuatu",
+    "VE" => "Venezuela, Repubblica bolivariana del",
+    "VN" => "Vietnam",
+    "VG" => "Isole Vergini, Regno Unito",
+    "VI" => "Isole Vergini, U.S.A.",
+    "WF" => "Wallis e Futuna",
+    "EH" => "Sahara occidentale",
+    "YE" => "Yemen",
+    "ZM" => "Zambia",
+    "ZW" => "Zimbabwe",
+};
+
+///Italian subdivision names by ISO 3166-2 code, 3808 of 5046
+pub const SUBDIVISION_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF-BAL" => "Balkh",
+    "AF-BAM" => "Bāmyān",
+    "AF-BDG" => "Badghis",
+    "AF-BDS" => "Badakhshan",
+    "AF-BGL" => "Baghlan",
+    "AF-DAY" => "Dāykundī",
+    "AF-FRA" => "Farah",
+    "AF-FYB" => "Faryab",
+    "AF-GHA" => "Ghazni",
+    "AF-GHO" => "Ghor",
+    "AF-HEL" => "Helmand",
+    "AF-HER" => "Herāt",
+    "AF-JOW" => "Jowzjan",
+    "AF-KAB" => "Kabul",
+    "AF-KAN" => "Kandahar",
+    "AF-KAP" => "Kapisa",
+    "AF-KDZ" => "Kunduz",
+    "AF-KHO" => "Khost",
+    "AF-LAG" => "Laghman",
+    "AF-LOG" => "Logar",
+    "AF-NAN" => "Nangarhar",
+    "AF-NIM" => "Nimroz",
+    "AF-NUR" => "Nuristan",
+    "AF-PAN" => "Panjshayr",
+    "AF-PAR" => "Parwan",
+    "AF-PIA" => "Paktiya",
+    "AF-PKA" => "Paktika",
+    "AF-SAM" => "Samangan",
+    "AF-SAR" => "Sar-e Pul",
+    "AF-TAK" => "Takhar",
+    "AF-URU" => "Uruzgan",
+    "AF-WAR" => "Wardak",
+    "AF-ZAB" => "Zabul",
+    "AL-01" => "Berat",
+    "AL-02" => "Durazzo",
+    "AL-03" => "Elbasan",
+    "AL-04" => "Fier",
+    "AL-05" => "Argirocastro",
+    "AL-06" => "Corizia",
+    "AL-07" => "Kukes",
+    "AL-08" => "Alessio",
+    "AL-09" => "Diber",
+    "AL-10" => "Scutari",
+    "AL-11" => "Tirana",
+    "AL-12" => "Valona",
+    "DZ-01" => "Adrar",
+    "DZ-02" => "Chlef",
+    "DZ-03" => "Laghouat",
+    "DZ-04" => "Oum el-Bouaghi",
+    "DZ-05" => "Batna",
+    "DZ-06" => "Bejaia",
+    "DZ-07" => "Biskra",
+    "DZ-08" => "Bechar",
+    "DZ-09" => "Blida",
+    "DZ-10" => "Bouira",
+    "DZ-12" => "Tebessa",
+    "DZ-13" => "Tlemcen",
+    "DZ-14" => "Tiaret",
+    "DZ-15" => "Tizi Ouzou",
+    "DZ-16" => "Algeria",
+    "DZ-17" => "Djelfa",
+    "DZ-18" => "Jijel",
+    "DZ-19" => "Setif",
+    "DZ-20" => "Saida",
+    "DZ-21" => "Skikda",
+    "DZ-22" => "Sidi Bel Abbes",
+    "DZ-23" => "Annaba",
+    "DZ-24" => "Guelma",
+    "DZ-25" => "Constantine",
+    "DZ-26" => "Medea",
+    "DZ-27" => "Mostaganem",
+    "DZ-29" => "Mascara",
+    "DZ-30" => "Ouargla",
+    "DZ-31" => "Orano",
+    "DZ-32" => "El Bayadh",
+    "DZ-33" => "Illizi",
+    "DZ-34" => "Bordj Bou Arreridj",
+    "DZ-35" => "Boumerdes",
+    "DZ-36" => "El Tarf",
+    "DZ-37" => "Tindouf",
+    "DZ-38" => "Tissemsilt",
+    "DZ-39" => "El Oued",
+    "DZ-40" => "Khenchela",
+    "DZ-41" => "Souk Ahras",
+    "DZ-42" => "Tipaza",
+    "DZ-43" => "Mila",
+    "DZ-44" => "Ain Defla",
+    "DZ-45" => "Naama",
+    "DZ-46" => "Ain Temouchent",
+    "DZ-47" => "Ghardaia",
+    "DZ-48" => "Relizane",
+    "AD-02" => "Canillo",
+    "AD-03" => "Encamp",
+    "AD-04" => "La Massana",
+    "AD-05" => "Ordino",
+    "AD-06" => "Sant Julià de Lòria",
+    "AD-07" => "Andorra la Vella",
+    "AD-08" => "Escaldes-Engordany",
+    "AO-BGO" => "Bengo",
+    "AO-BGU" => "Benguela",
+    "AO-BIE" => "Bié",
+    "AO-CAB" => "Cabinda",
+    "AO-CNN" => "Cunene",
+    "AO-HUA" => "Huambo",
+    "AO-HUI" => "Huila",
+    "AO-LUA" => "Luanda",
+    "AO-MAL" => "Malange",
+    "AO-MOX" => "Moxico",
+    "AO-NAM" => "Namibe",
+    "AO-UIG" => "Uige",
+    "AO-ZAI" => "Zaire",
+    "AG-03" => "Saint George",
+    "AG-04" => "Saint John",
+    "AG-05" => "Saint Mary",
+    "AG-06" => "Saint Paul",
+    "AG-07" => "Saint Peter",
+    "AG-08" => "Saint Philip",
+    "AG-10" => "Barbuda",
+    "AG-11" => "Redonda",
+    "AR-A" => "Salta",
+    "AR-B" => "Buenos Aires",
+    "AR-C" => "Città autonoma di Buenos Aires",
+    "AR-D" => "San Luis",
+    "AR-F" => "La Rioja",
+    "AR-G" => "Santiago del Estero",
+    "AR-H" => "Chaco",
+    "AR-J" => "San Juan",
+    "AR-K" => "Catamarca",
+    "AR-L" => "La Pampa",
+    "AR-M" => "Mendoza",
+    "AR-N" => "Misiones",
+    "AR-P" => "Formosa",
+    "AR-R" => "Río Negro",
+    "AR-S" => "Santa Fe",
+    "AR-U" => "Chubut",
+    "AR-V" => "Terra del Fuoco",
+    "AR-W" => "Corrientes",
+    "AR-X" => "Cordoba",
+    "AR-Y" => "Jujuy",
+    "AR-Z" => "Santa Cruz",
+    "AM-AR" => "Ararat",
+    "AM-AV" => "Armavir",
+    "AM-ER" => "Yerevan",
+    "AM-KT" => "Kotayk'",
+    "AM-SU" => "Syunik'",
+    "AU-ACT" => "Australian Capital Territory",
+    "AU-NSW" => "Nuovo Galles del Sud",
+    "AU-NT" => "Territorio del Nord",
+    "AU-QLD" => "Queensland",
+    "AU-SA" => "Australia Meridionale",
+    "AU-TAS" => "Tasmania",
+    "AU-VIC" => "Victoria",
+    "AU-WA" => "Australia Occidentale",
+    "AT-1" => "Burgenland",
+    "AT-2" => "Kärnten",
+    "AT-3" => "Niederösterreich",
+    "AT-4" => "Oberösterreich",
+    "AT-5" => "Salisburgo",
+    "AT-6" => "Stiria",
+    "AT-7" => "Tirolo",
+    "AT-8" => "Vorarlberg",
+    "AT-9" => "Vienna",
+    "AZ-ABS" => "Absheron",
+    "AZ-AGA" => "Agstafa",
+    "AZ-AGC" => "Agjabadi",
+    "AZ-AGM" => "Agdam",
+    "AZ-AGS" => "Agdash",
+    "AZ-AGU" => "Agsu",
+    "AZ-AST" => "Astara",
+    "AZ-BA" => "Baku",
+    "AZ-BAB" => "Babek",
+    "AZ-BAL" => "Balakan",
+    "AZ-BAR" => "Barda",
+    "AZ-BEY" => "Beylagan",
+    "AZ-BIL" => "Bilasuvar",
+    "AZ-CAB" => "Jabrayil",
+    "AZ-CUL" => "Julfa",
+    "AZ-DAS" => "Dashkasan",
+    "AZ-FUZ" => "Fizuli",
+    "AZ-GA" => "Ganja",
+    "AZ-GAD" => "Gadabay",
+    "AZ-GOR" => "Goranboy",
+    "AZ-GOY" => "Goychay",
+    "AZ-GYG" => "Goygol",
+    "AZ-HAC" => "Hajigabul",
+    "AZ-IMI" => "Imishli",
+    "AZ-ISM" => "Ismailli",
+    "AZ-KAL" => "Kalbajar",
+    "AZ-KAN" => "Kangarli",
+    "AZ-KUR" => "Kurdamir",
+    "AZ-LA" => "Lankarani",
+    "AZ-LAC" => "Lachin",
+    "AZ-LAN" => "Lankarani",
+    "AZ-LER" => "Lerik",
+    "AZ-MAS" => "Masally",
+    "AZ-MI" => "Mingachevir",
+    "AZ-NA" => "Naftalan",
+    "AZ-NEF" => "Neftchala",
+    "AZ-NV" => "Naxcivan",
+    "AZ-NX" => "Naxcivan",
+    "AZ-OGU" => "Oguz",
+    "AZ-ORD" => "Ordubad",
+    "AZ-QAB" => "Gabala",
+    "AZ-QAX" => "Qakh",
+    "AZ-QAZ" => "Qazakh",
+    "AZ-QBA" => "Quba",
+    "AZ-QBI" => "Qubadli",
+    "AZ-QOB" => "Qobustan",
+    "AZ-QUS" => "Qusar",
+    "AZ-SA" => "Shaki",
+    "AZ-SAB" => "Sabirabad",
+    "AZ-SAD" => "Sadarak",
+    "AZ-SAH" => "Shakhbuz",
+    "AZ-SAK" => "Shaki",
+    "AZ-SAL" => "Salyan",
+    "AZ-SAR" => "Sharur",
+    "AZ-SAT" => "Saatly",
+    "AZ-SBN" => "Sabran",
+    "AZ-SIY" => "Siazan",
+    "AZ-SKR" => "Shamkir",
+    "AZ-SM" => "Sumqayıt",
+    "AZ-SMI" => "Shamakhi",
+    "AZ-SMX" => "Samukh",
+    "AZ-SR" => "Sirvan",
+    "AZ-SUS" => "Shusha",
+    "AZ-TAR" => "Tartar",
+    "AZ-TOV" => "Tovuz",
+    "AZ-UCA" => "Ujar",
+    "AZ-XA" => "Khankendi",
+    "AZ-XAC" => "Khachmaz",
+    "AZ-XCI" => "Khojali",
+    "AZ-XIZ" => "Khizi",
+    "AZ-XVD" => "Khojavend",
+    "AZ-YAR" => "Yardymli",
+    "AZ-YE" => "Evlach",
+    "AZ-YEV" => "Evlach",
+    "AZ-ZAN" => "Zangilan",
+    "AZ-ZAQ" => "Zaqatala",
+    "AZ-ZAR" => "Zardab",
+    "BS-AK" => "Acklins",
+    "BS-BI" => "Bimini",
+    "BS-BP" => "Black Point",
+    "BS-BY" => "Isole Berry",
+    "BS-CE" => "Eleuthera centrale",
+    "BS-CI" => "Cat Island",
+    "BS-CK" => "Crooked Island e Long Cay",
+    "BS-CO" => "Abaco centrale",
+    "BS-CS" => "Andros centrale",
+    "BS-EG" => "Grand Bahama Est",
+    "BS-EX" => "Exuma",
+    "BS-FP" => "Città di Freeport",
+    "BS-GC" => "Grand Cay",
+    "BS-HI" => "Harbour Island",
+    "BS-HT" => "Hope Town",
+    "BS-IN" => "Inagua",
+    "BS-LI" => "Long Island",
+    "BS-MC" => "Mangrove Cay",
+    "BS-MG" => "Mayaguana",
+    "BS-MI" => "Moore's Island",
+    "BS-NE" => "Eleuthera Nord",
+    "BS-NO" => "Abaco Nord",
+    "BS-NS" => "Andros Nord",
+    "BS-RC" => "Rum Cay",
+    "BS-RI" => "Ragged Island",
+    "BS-SA" => "Andros Sud",
+    "BS-SE" => "Eleuthera Sud",
+    "BS-SO" => "Abaco Sud",
+    "BS-SS" => "San Salvador",
+    "BS-SW" => "Spanish Wells",
+    "BS-WG" => "Grand Bahama Ovest",
+    "BH-14" => "Al Janubiyah",
+    "BH-15" => "Al Muharraq",
+    "BH-17" => "Ash Shamaliyah",
+    "BD-01" => "Bandarban",
+    "BD-02" => "Barguna",
+    "BD-04" => "Brahmanbaria",
+    "BD-05" => "Bagerhat",
+    "BD-07" => "Bhola",
+    "BD-09" => "Chandpur",
+    "BD-11" => "Bazaar di Cox",
+    "BD-12" => "Chuadanga",
+    "BD-13" => "Dhaka",
+    "BD-14" => "Dinajpur",
+    "BD-15" => "Faridpur",
+    "BD-16" => "Feni",
+    "BD-17" => "Gopalganj",
+    "BD-18" => "Gazipur",
+    "BD-19" => "Gaibandha",
+    "BD-20" => "Habiganj",
+    "BD-21" => "Jamalpur",
+    "BD-23" => "Jhenaidah",
+    "BD-27" => "Khulna",
+    "BD-28" => "Kurigram",
+    "BD-30" => "Kushtia",
+    "BD-31" => "Lakshmipur",
+    "BD-32" => "Lalmonirhat",
+    "BD-33" => "Manikganj",
+    "BD-34" => "Mymensingh",
+    "BD-35" => "Munshiganj",
+    "BD-36" => "Madaripur",
+    "BD-37" => "Magura",
+    "BD-38" => "Moulvibazar",
+    "BD-39" => "Meherpur",
+    "BD-40" => "Narayanganj",
+    "BD-41" => "Netrakona",
+    "BD-42" => "Narsingdi",
+    "BD-43" => "Narail",
+    "BD-44" => "Natore",
+    "BD-46" => "Nilphamari",
+    "BD-47" => "Noakhali",
+    "BD-48" => "Naogaon",
+    "BD-49" => "Pabna",
+    "BD-50" => "Pirojpur",
+    "BD-51" => "Patuakhali",
+    "BD-52" => "Panchagarh",
+    "BD-53" => "Rajbari",
+    "BD-54" => "Rajshahi",
+    "BD-55" => "Rangpur",
+    "BD-56" => "Rangamati",
+    "BD-57" => "Sherpur",
+    "BD-58" => "Satkhira",
+    "BD-59" => "Sirajganj",
+    "BD-60" => "Sylhet",
+    "BD-61" => "Sunamganj",
+    "BD-62" => "Shariatpur",
+    "BD-63" => "Tangail",
+    "BD-64" => "Thakurgaon",
+    "BD-C" => "Dhaka",
+    "BD-D" => "Khulna",
+    "BD-E" => "Rajshahi",
+    "BD-F" => "Rangpur",
+    "BD-G" => "Sylhet",
+    "BD-H" => "Mymensingh",
+    "BB-01" => "Christ Church",
+    "BB-02" => "Saint Andrew",
+    "BB-03" => "Saint George",
+    "BB-04" => "Saint James",
+    "BB-05" => "Saint John",
+    "BB-06" => "Saint Joseph",
+    "BB-07" => "Saint Lucy",
+    "BB-08" => "Saint Michael",
+    "BB-09" => "Saint Peter",
+    "BB-10" => "Saint Philip",
+    "BB-11" => "Saint Thomas",
+    "BY-MA" => "Regione di Mahilou",
+    "BY-MI" => "Regione di Minsk",
+    "BE-VAN" => "Anversa",
+    "BE-VBR" => "Brabante Fiammingo",
+    "BE-VLG" => "Fiandre",
+    "BE-VLI" => "Limburgo",
+    "BE-VOV" => "Vlaanderen orientale",
+    "BE-VWV" => "Vlaanderen occidentale",
+    "BE-WAL" => "Vallonia, regione",
+    "BE-WBR" => "Brabante Vallone",
+    "BE-WHT" => "Hainaut",
+    "BE-WLG" => "Liegi",
+    "BE-WLX" => "Lussemburgo",
+    "BE-WNA" => "Namur",
+    "BZ-BZ" => "Belize",
+    "BZ-CY" => "Cayo",
+    "BZ-CZL" => "Corozal",
+    "BZ-OW" => "Orange Walk",
+    "BZ-SC" => "Stann Creek",
+    "BZ-TOL" => "Toledo",
+    "BJ-AL" => "Alibori",
+    "BJ-AQ" => "Atlantico",
+    "BJ-BO" => "Borgou",
+    "BJ-CO" => "Colline",
+    "BJ-DO" => "Donga",
+    "BJ-LI" => "Litorale",
+    "BJ-MO" => "Mono",
+    "BJ-OU" => "Ouémé",
+    "BJ-PL" => "Altopiano",
+    "BJ-ZO" => "Zou",
+    "BT-11" => "Paro",
+    "BT-12" => "Chukha",
+    "BT-15" => "Thimphu",
+    "BT-21" => "Tsirang",
+    "BT-22" => "Dagana",
+    "BT-23" => "Punakha",
+    "BT-24" => "Wangdue Phodrang",
+    "BT-31" => "Sarpang",
+    "BT-32" => "Trongsa",
+    "BT-33" => "Bumthang",
+    "BT-34" => "Zhemgang",
+    "BT-41" => "Trashigang",
+    "BT-42" => "Mongar",
+    "BT-44" => "Lhuntse",
+    "BT-GA" => "Gasa",
+    "BT-TY" => "Trashiyangtse",
+    "BO-B" => "Beni",
+    "BO-C" => "Cochabamba",
+    "BO-H" => "Chuquisaca",
+    "BO-L" => "La Paz",
+    "BO-N" => "Pando",
+    "BO-O" => "Oruro",
+    "BO-P" => "Potosì",
+    "BO-S" => "Santa Cruz",
+    "BO-T" => "Tarija",
+    "BQ-BO" => "Bonaire",
+    "BQ-SA" => "Saba",
+    "BQ-SE" => "Sint Eustatius",
+    "BA-BIH" => "Federazione di Bosnia ed Erzegovina",
+    "BA-BRC" => "Distretto di Brčko",
+    "BA-SRP" => "Repubblica Serba",
+    "BW-CE" => "Centrale",
+    "BW-GH" => "Ghanzi",
+    "BW-KG" => "Kgalagadi",
+    "BW-KL" => "Kgatleng",
+    "BW-KW" => "Kweneng",
+    "BW-NE" => "Nord Est",
+    "BW-NW" => "Nord Ovest",
+    "BW-SE" => "Sud Est",
+    "BW-SO" => "Meridionale (Botswana)",
+    "BR-AC" => "Acre",
+    "BR-AL" => "Alagoas",
+    "BR-AM" => "Amazonas",
+    "BR-AP" => "Amapà",
+    "BR-BA" => "Bahia",
+    "BR-CE" => "Cearà",
+    "BR-DF" => "Distretto Federale",
+    "BR-ES" => "Espìrito Santo",
+    "BR-GO" => "Goiàs",
+    "BR-MA" => "Maranhao",
+    "BR-MG" => "Minas Gerais",
+    "BR-MS" => "Mato Grosso del Sud",
+    "BR-MT" => "Mato Grosso",
+    "BR-PA" => "Parà",
+    "BR-PB" => "Paraiba",
+    "BR-PE" => "Pernambuco",
+    "BR-PI" => "Piauì",
+    "BR-PR" => "Paranà",
+    "BR-RJ" => "Rio de Janeiro",
+    "BR-RN" => "Rio Grande del Nord",
+    "BR-RO" => "Rondonia",
+    "BR-RR" => "Roraima",
+    "BR-RS" => "Rio Grande del Sud",
+    "BR-SC" => "Santa Catarina",
+    "BR-SE" => "Sergipe",
+    "BR-SP" => "San Paolo",
+    "BR-TO" => "Tocantins",
+    "BN-BE" => "Belait",
+    "BN-BM" => "Brunei-Muara",
+    "BN-TE" => "Temburong",
+    "BN-TU" => "Tutong",
+    "BG-01" => "Blagoevgrad",
+    "BG-02" => "Burgas",
+    "BG-03" => "Varna",
+    "BG-04" => "Veliko Tarnovo",
+    "BG-05" => "Vidin",
+    "BG-06" => "Vraca",
+    "BG-07" => "Gabrovo",
+    "BG-08" => "Dobric",
+    "BG-09" => "Kardzhali",
+    "BG-10" => "Kjustendil",
+    "BG-11" => "Lovec",
+    "BG-12" => "Montana",
+    "BG-13" => "Pazardzhik",
+    "BG-14" => "Pernik",
+    "BG-15" => "Pleven",
+    "BG-16" => "Plovdiv",
+    "BG-17" => "Razgrad",
+    "BG-18" => "Ruse",
+    "BG-19" => "Silistra",
+    "BG-20" => "Sliven",
+    "BG-21" => "Smoljan",
+    "BG-23" => "Sofia",
+    "BG-24" => "Stara Zagora",
+    "BG-25" => "Targoviste",
+    "BG-26" => "Haskovo",
+    "BG-27" => "Sumen",
+    "BG-28" => "Jambol",
+    "BF-01" => "Boucle du Mouhoun",
+    "BF-02" => "Cascades",
+    "BF-03" => "Centre",
+    "BF-04" => "Centre-Est",
+    "BF-05" => "Centre-Nord",
+    "BF-06" => "Centre-Ouest",
+    "BF-07" => "Centre-Sud",
+    "BF-08" => "Occidentale",
+    "BF-09" => "Hauts-Bassins",
+    "BF-10" => "Nord",
+    "BF-11" => "Plateau-Central",
+    "BF-12" => "Sahel",
+    "BF-13" => "Sud-Ouest",
+    "BF-BAL" => "Balé",
+    "BF-BAM" => "Bam",
+    "BF-BAN" => "Banwa",
+    "BF-BAZ" => "Bazèga",
+    "BF-BGR" => "Bougouriba",
+    "BF-BLG" => "Boulgou",
+    "BF-BLK" => "Boulkiemdé",
+    "BF-COM" => "Comoé",
+    "BF-GAN" => "Ganzourgou",
+    "BF-GNA" => "Gnagna",
+    "BF-GOU" => "Gourma",
+    "BF-HOU" => "Houet",
+    "BF-IOB" => "Ioba",
+    "BF-KAD" => "Kadiogo",
+    "BF-KEN" => "Kénédougou",
+    "BF-KMD" => "Komondjari",
+    "BF-KMP" => "Kompienga",
+    "BF-KOP" => "Koulpélogo",
+    "BF-KOS" => "Kossi",
+    "BF-KOT" => "Kouritenga",
+    "BF-KOW" => "Kourwéogo",
+    "BF-LER" => "Léraba",
+    "BF-LOR" => "Loroum",
+    "BF-MOU" => "Mouhoun",
+    "BF-NAM" => "Namentenga",
+    "BF-NAY" => "Nayala",
+    "BF-NOU" => "Noumbiel",
+    "BF-OUB" => "Oubritenga",
+    "BF-OUD" => "Oudalan",
+    "BF-PAS" => "Passoré",
+    "BF-PON" => "Poni",
+    "BF-SEN" => "Séno",
+    "BF-SIS" => "Sissili",
+    "BF-SMT" => "Sanmatenga",
+    "BF-SNG" => "Sanguié",
+    "BF-SOM" => "Soum",
+    "BF-SOR" => "Sourou",
+    "BF-TAP" => "Tapoa",
+    "BF-YAG" => "Yagha",
+    "BF-YAT" => "Yatenga",
+    "BF-ZIR" => "Ziro",
+    "BF-ZON" => "Zondoma",
+    "BF-ZOU" => "Zoundwéogo",
+    "BI-BB" => "Bubanza",
+    "BI-BL" => "Bujumbura Rural",
+    "BI-BM" => "Bujumbura Mairie",
+    "BI-BR" => "Bururi",
+    "BI-CA" => "Cankuzo",
+    "BI-CI" => "Cibitoke",
+    "BI-GI" => "Gitega",
+    "BI-KI" => "Kirundo",
+    "BI-KR" => "Karuzi",
+    "BI-KY" => "Kayanza",
+    "BI-MA" => "Makamba",
+    "BI-MU" => "Muramvya",
+    "BI-MW" => "Mwaro",
+    "BI-NG" => "Ngozi",
+    "BI-RT" => "Rutana",
+    "BI-RY" => "Ruyigi",
+    "CV-B" => "Distretto di Barlavento",
+    "CV-BR" => "Brava",
+    "CV-BV" => "Boa Vista",
+    "CV-CA" => "Santa Catarina",
+    "CV-CR" => "Santa Cruz",
+    "CV-MA" => "Maio",
+    "CV-MO" => "Mosteiros",
+    "CV-PA" => "Paul",
+    "CV-PN" => "Porto Novo",
+    "CV-PR" => "Praia",
+    "CV-RB" => "Ribeira Brava",
+    "CV-RG" => "Ribeira Grande",
+    "CV-RS" => "Ribeira Grande de Santiago",
+    "CV-S" => "Distretto di Sotavento",
+    "CV-SD" => "São Domingos",
+    "CV-SF" => "São Filipe",
+    "CV-SL" => "Sal",
+    "CV-SM" => "São Miguel",
+    "CV-SO" => "São Lourenço dos Órgãos",
+    "CV-SS" => "São Salvador do Mundo",
+    "CV-SV" => "São Vicente",
+    "CV-TA" => "Tarrafal",
+    "CV-TS" => "Tarrafal de São Nicolau",
+    "KH-11" => "Mondulkiri",
+    "KH-12" => "Phnom Penh",
+    "KH-13" => "Preah Vihear",
+    "KH-14" => "Prey Veng",
+    "KH-15" => "Pursat",
+    "KH-16" => "Ratanakiri",
+    "KH-17" => "Siem Reab",
+    "KH-20" => "Svay Rieng",
+    "KH-21" => "Takéo",
+    "KH-22" => "Oddar Meanchey",
+    "KH-4" => "Kampong Chhnang",
+    "KH-7" => "Kampot",
+    "CM-AD" => "Adamaoua",
+    "CM-CE" => "Centre",
+    "CM-EN" => "Provincia dell'estremo Nord",
+    "CM-ES" => "Provincia dell'Est",
+    "CM-LT" => "Litorale",
+    "CM-NO" => "Provincia del Nord",
+    "CM-NW" => "Nordoccidentale (Botswana)",
+    "CM-OU" => "Provincia dell'Ovest",
+    "CM-SU" => "Provincia del Sud",
+    "CM-SW" => "Provincia di Sudovest",
+    "CA-AB" => "Alberta",
+    "CA-BC" => "Columbia Britannica",
+    "CA-MB" => "Manitoba",
+    "CA-NB" => "New Brunswick",
+    "CA-NL" => "Terranova e Labrador",
+    "CA-NS" => "Nuova Scozia",
+    "CA-NT" => "Territori del Nord-Ovest",
+    "CA-NU" => "Nunavut",
+    "CA-ON" => "Ontario",
+    "CA-PE" => "Isola del Principe Edoardo",
+    "CA-QC" => "Quebec",
+    "CA-SK" => "Saskatchewan",
+    "CF-AC" => "Ouham",
+    "CF-BB" => "Bamingui-Bangoran",
+    "CF-BGF" => "Bangui",
+    "CF-BK" => "Basse Kotto",
+    "CF-HK" => "Haute Kotto",
+    "CF-HM" => "Haut-Mbomou",
+    "CF-HS" => "Haute-Sangha / Mambéré-Kadéï",
+    "CF-KB" => "Gribingui",
+    "CF-LB" => "Lobaye",
+    "CF-MB" => "Mbomou",
+    "CF-NM" => "Nana-Mambere",
+    "CF-OP" => "Ouham-Pendé",
+    "CF-SE" => "Sangha",
+    "CF-UK" => "Ouaka",
+    "CF-VK" => "Vakaga",
+    "TD-ND" => "Madīnat Injamīnā",
+    "TD-TI" => "Tibastī",
+    "CL-AN" => "Antofagasta",
+    "CL-AP" => "Arica e Parinacota",
+    "CL-AT" => "Atacama",
+    "CL-CO" => "Coquimbo",
+    "CL-LI" => "Libertador General Bernardo O'Higgins",
+    "CL-LL" => "Los Lagos",
+    "CL-LR" => "Los Rios",
+    "CL-ML" => "Maule",
+    "CL-RM" => "Regione metropolitana di Santiago",
+    "CL-TA" => "Tarapaca",
+    "CL-VS" => "Valparaiso",
+    "CN-AH" => "Anhui",
+    "CN-BJ" => "Pechino",
+    "CN-CQ" => "Chongqing",
+    "CN-GD" => "Guangdong",
+    "CN-GS" => "Gansu",
+    "CN-GZ" => "Guizhou",
+    "CN-HA" => "Henan",
+    "CN-HB" => "Hubei",
+    "CN-HE" => "Hebei",
+    "CN-HI" => "Hainan",
+    "CN-HL" => "Heilongjiang",
+    "CN-HN" => "Hunan",
+    "CN-JL" => "Jilin",
+    "CN-JS" => "Jiangsu",
+    "CN-JX" => "Jiangxi",
+    "CN-SX" => "Shaanxi",
+    "CN-XJ" => "Regione autonoma uigura dello Xinjiang",
+    "CN-XZ" => "Xinjiang",
+    "CN-YN" => "Yunnan",
+    "CN-ZJ" => "Zhejiang",
+    "CO-AMA" => "Amazonas",
+    "CO-ANT" => "Antioquia",
+    "CO-ARA" => "Arauca",
+    "CO-ATL" => "Atlantico",
+    "CO-BOL" => "Bolivar",
+    "CO-BOY" => "Boyacà",
+    "CO-CAL" => "Caldas",
+    "CO-CAQ" => "Caquetà",
+    "CO-CAS" => "Casanare",
+    "CO-CAU" => "Cauca",
+    "CO-CES" => "Cesar",
+    "CO-CHO" => "Chocò",
+    "CO-COR" => "Cordoba",
+    "CO-CUN" => "Cundinamarca",
+    "CO-DC" => "Distretto Capitale di Bogotà",
+    "CO-GUA" => "Guainia",
+    "CO-GUV" => "Guaviare",
+    "CO-HUI" => "Huila",
+    "CO-LAG" => "La Guajira",
+    "CO-MAG" => "Magdalena",
+    "CO-MET" => "Meta",
+    "CO-NAR" => "Nariño",
+    "CO-NSA" => "Norte de Santander",
+    "CO-PUT" => "Putumayo",
+    "CO-QUI" => "Quindio",
+    "CO-RIS" => "Risaralda",
+    "CO-SAN" => "Santander",
+    "CO-SAP" => "San Andrés, Providencia e Santa Catalina",
+    "CO-SUC" => "Sucre",
+    "CO-TOL" => "Tolima",
+    "CO-VAC" => "Valle del Cauca",
+    "CO-VAU" => "Vaupés",
+    "CO-VID" => "Vichada",
+    "CG-11" => "Bouenza",
+    "CG-12" => "Pool",
+    "CG-13" => "Sangha",
+    "CG-14" => "Altopiani (Congo)",
+    "CG-15" => "Cuvette Ovest",
+    "CG-2" => "Lekoumou",
+    "CG-5" => "Kouilou",
+    "CG-7" => "Likouala",
+    "CG-8" => "Cuvette",
+    "CG-9" => "Niari",
+    "CG-BZV" => "Brazzaville",
+    "CD-EQ" => "Equatore",
+    "CD-KN" => "Kinshasa",
+    "CD-MA" => "Maniema",
+    "CD-NK" => "Kivu Nord",
+    "CD-SK" => "Kivu Sud",
+    "CR-A" => "Alajuela",
+    "CR-C" => "Cartago",
+    "CR-G" => "Guanacaste",
+    "CR-H" => "Heredia",
+    "CR-L" => "Limón",
+    "CR-P" => "Puntarenas",
+    "CR-SJ" => "San José",
+    "CI-CM" => "Comoé",
+    "HR-01" => "Regione di Zagabria",
+    "HR-02" => "Regione di Kaprina e dello Zagorje",
+    "HR-03" => "Regione di Sisak e della Moslavina",
+    "HR-04" => "Regione di Karlovac",
+    "HR-05" => "Regione di Varasdino",
+    "HR-06" => "Regione di Koprivincia e Krizevci",
+    "HR-07" => "Regione di Bjelovar e della Bilogora",
+    "HR-08" => "Regione litoraneo-montana",
+    "HR-09" => "Regione delle Lika e di Segna",
+    "HR-10" => "Regione di Virovitica e della Podravina",
+    "HR-11" => "Regione di Pozega e della Slavonia",
+    "HR-12" => "Regione di Brod e della Posavina",
+    "HR-13" => "Regione zaratina",
+    "HR-14" => "Regione di Osijec e della Baranja",
+    "HR-15" => "Regione di Sebenico e Tenin",
+    "HR-16" => "Regione di Vukorav e della Sirmia",
+    "HR-17" => "Regione spalatino-dalmata",
+    "HR-18" => "Regione istriana",
+    "HR-19" => "Regione raguseo-narentana",
+    "HR-20" => "Regione del Medimurje",
+    "HR-21" => "Zagabria città",
+    "CU-03" => "L'Avana",
+    "CU-04" => "Matanzas",
+    "CU-05" => "Villa Clara",
+    "CU-06" => "Cienfuegos",
+    "CU-07" => "Sancti Spiritus",
+    "CU-08" => "Ciego de Avila",
+    "CU-09" => "Camaguey",
+    "CU-10" => "Las Tunas",
+    "CU-11" => "Holguin",
+    "CU-12" => "Granma",
+    "CU-13" => "Santiago di Cuba",
+    "CU-14" => "Guantanamo",
+    "CU-99" => "Isola della gioventù",
+    "CZ-20" => "Boemia centrale",
+    "CZ-201" => "Benschau",
+    "CZ-202" => "Beroun",
+    "CZ-203" => "Kladno",
+    "CZ-204" => "Kolin",
+    "CZ-205" => "Kutna Hora",
+    "CZ-206" => "Melnik",
+    "CZ-207" => "Mlada Boleslav",
+    "CZ-208" => "Nymburk",
+    "CZ-209" => "Praga-východ",
+    "CZ-20A" => "Praga-západ",
+    "CZ-20B" => "Pribram",
+    "CZ-20C" => "Rakovnik",
+    "CZ-31" => "Boemia meridionale",
+    "CZ-311" => "České Budějovice",
+    "CZ-312" => "Český Krumlov",
+    "CZ-313" => "Jindřichův Hradec",
+    "CZ-314" => "Pisek",
+    "CZ-315" => "Prachatice",
+    "CZ-316" => "Strakonice",
+    "CZ-317" => "Tabor",
+    "CZ-32" => "Regione di Plezn",
+    "CZ-321" => "Domalice",
+    "CZ-322" => "Klatovy",
+    "CZ-323" => "Plzeň-město",
+    "CZ-324" => "Plzeň-jih",
+    "CZ-325" => "Plzeň-sever",
+    "CZ-326" => "Rokycany",
+    "CZ-327" => "Tachov",
+    "CZ-41" => "Regione di Karlovy Vary",
+    "CZ-411" => "Cheb",
+    "CZ-412" => "Karlovy Vary",
+    "CZ-413" => "Sokolov",
+    "CZ-42" => "Regione di Usti",
+    "CZ-421" => "Decin",
+    "CZ-422" => "Chomutov",
+    "CZ-423" => "Litomerice",
+    "CZ-424" => "Louny",
+    "CZ-425" => "Most",
+    "CZ-426" => "Teplice",
+    "CZ-427" => "Usti nad Labem",
+    "CZ-51" => "Regione di Liberec",
+    "CZ-511" => "Ceska Lipa",
+    "CZ-512" => "Jablonec nad Nisou",
+    "CZ-513" => "Liberec",
+    "CZ-514" => "Semily",
+    "CZ-52" => "Regione di Hradec Kralove",
+    "CZ-521" => "Hradec Kralové",
+    "CZ-522" => "Jicin",
+    "CZ-523" => "Nachod",
+    "CZ-524" => "Rychnov nad Kneznou",
+    "CZ-525" => "Trutnov",
+    "CZ-53" => "Regione di Pardubice",
+    "CZ-531" => "Chrudim",
+    "CZ-532" => "Pardubice",
+    "CZ-533" => "Svitavy",
+    "CZ-534" => "Usti nad Orlici",
+    "CZ-63" => "Regione di Vysočina",
+    "CZ-631" => "Havlickuv Brod",
+    "CZ-632" => "Jihlava",
+    "CZ-633" => "Pelhrimov",
+    "CZ-634" => "Trebic",
+    "CZ-635" => "Žďár nad Sázavou",
+    "CZ-64" => "Moravia meridionale",
+    "CZ-641" => "Blansko",
+    "CZ-642" => "Brno città",
+    "CZ-643" => "Brno provincia",
+    "CZ-644" => "Breclav",
+    "CZ-645" => "Hodonin",
+    "CZ-646" => "Vyskov",
+    "CZ-647" => "Znojmo",
+    "CZ-71" => "Regione di Olomouc",
+    "CZ-711" => "Jesenik",
+    "CZ-712" => "Olomouc",
+    "CZ-713" => "Prostějov",
+    "CZ-714" => "Prerov",
+    "CZ-715" => "Sumperk",
+    "CZ-72" => "Regione di Zlin",
+    "CZ-721" => "Kroměříž",
+    "CZ-722" => "Uherské Hradiště",
+    "CZ-723" => "Vsetin",
+    "CZ-724" => "Zlin",
+    "CZ-80" => "Moravia-Slesia",
+    "CZ-801" => "Bruntal",
+    "CZ-803" => "Karvina",
+    "CZ-804" => "Novy Jicin",
+    "CZ-805" => "Opava",
+    "CZ-806" => "Ostrava-město",
+    "DK-81" => "Nordjylland",
+    "DK-82" => "Midtjylland",
+    "DK-83" => "Syddanmark",
+    "DK-84" => "Hovedstaden",
+    "DK-85" => "Sjælland",
+    "DJ-AR" => "Arta",
+    "DJ-AS" => "Ali Sabieh",
+    "DJ-DI" => "Dikhil",
+    "DJ-DJ" => "Gibuti",
+    "DJ-TA" => "Tagiura",
+    "DM-02" => "Saint Andrew",
+    "DM-03" => "Saint David",
+    "DM-04" => "Saint George",
+    "DM-05" => "Saint John",
+    "DM-06" => "Saint Joseph",
+    "DM-07" => "Saint Luke",
+    "DM-08" => "Saint Mark",
+    "DM-09" => "Saint Patrick",
+    "DM-10" => "Saint Paul",
+    "DM-11" => "Saint Peter",
+    "DO-01" => "Distrito Nacional (Santo Domingo)",
+    "DO-02" => "Azua",
+    "DO-04" => "Barahona",
+    "DO-05" => "Dajabon",
+    "DO-06" => "Duarte",
+    "DO-09" => "Espaillat",
+    "DO-10" => "Independencia",
+    "DO-11" => "La Altagracia",
+    "DO-12" => "La Romana",
+    "DO-13" => "La Vega",
+    "DO-14" => "Maria Trinidad Sanchez",
+    "DO-15" => "Monte Cristi",
+    "DO-16" => "Pedernales",
+    "DO-17" => "Peravia",
+    "DO-18" => "Puerto Plata",
+    "DO-20" => "Samanà",
+    "DO-21" => "San Cristobal",
+    "DO-22" => "San Juan",
+    "DO-23" => "San Pedro de Macoris",
+    "DO-24" => "Sanchez Ramirez",
+    "DO-25" => "Santiago",
+    "DO-26" => "Santiago Rodriguez",
+    "DO-27" => "Valverde",
+    "DO-28" => "Monsenor Nouel",
+    "DO-29" => "Monte Plata",
+    "DO-30" => "Hato Mayor",
+    "EC-A" => "Azuay",
+    "EC-B" => "Bolivar",
+    "EC-C" => "Carchi",
+    "EC-D" => "Orellana",
+    "EC-E" => "Esmeraldas",
+    "EC-F" => "Canar",
+    "EC-G" => "Guayas",
+    "EC-H" => "Chimborazo",
+    "EC-I" => "Imbabura",
+    "EC-L" => "Loja",
+    "EC-M" => "Manabi",
+    "EC-N" => "Napo",
+    "EC-O" => "El Oro",
+    "EC-P" => "Pichincha",
+    "EC-R" => "Los Rios",
+    "EC-SD" => "Santo Domingo de los Tsáchilas",
+    "EC-SE" => "Santa Elena",
+    "EC-T" => "Tungurahua",
+    "EC-U" => "Sucumbios",
+    "EC-W" => "Galapagos",
+    "EC-X" => "Cotopaxi",
+    "EC-Y" => "Pastaza",
+    "EG-ALX" => "Alessandria",
+    "EG-ASN" => "Assuan",
+    "EG-BA" => "Mar Rosso",
+    "EG-BNS" => "Beni Suef",
+    "EG-C" => "Il Cairo",
+    "EG-DK" => "Dakahlia",
+    "EG-FYM" => "Fayyum",
+    "EG-GH" => "Gharbiyya",
+    "EG-GZ" => "Giza",
+    "EG-JS" => "Sinai del Sud",
+    "EG-KB" => "Qaliubia",
+    "EG-KFS" => "Kafr el Sheikh",
+    "EG-KN" => "Qena",
+    "EG-MN" => "Al-Minya",
+    "EG-MNF" => "Menufia",
+    "EG-SHG" => "Sohag",
+    "EG-SHR" => "Sharkia",
+    "EG-SUZ" => "Suez",
+    "EG-WAD" => "Wadi al Jadid",
+    "SV-AH" => "Ahuachapán",
+    "SV-CA" => "Cabañas",
+    "SV-CH" => "Chalatenango",
+    "SV-CU" => "Cuscatlán",
+    "SV-LI" => "La Libertad",
+    "SV-MO" => "Morazán",
+    "SV-PA" => "La Paz",
+    "SV-SA" => "Santa Ana",
+    "SV-SM" => "San Miguel",
+    "SV-SO" => "Sonsonate",
+    "SV-SS" => "San Salvador",
+    "SV-SV" => "San Vicente",
+    "SV-UN" => "La Unión",
+    "SV-US" => "Usulután",
+    "GQ-KN" => "Kié-Ntem",
+    "GQ-LI" => "Litorale",
+    "GQ-WN" => "Wele-Nzas",
+    "ER-AN" => "Regione dell'Anseba",
+    "ER-DU" => "Regione del Sud",
+    "ER-MA" => "Regione centrale",
+    "EE-205" => "Hiiumaa",
+    "EE-37" => "Harjumaa",
+    "EE-39" => "Hiiumaa",
+    "EE-45" => "Ida-Virumaa",
+    "EE-50" => "Jogevamaa",
+    "EE-52" => "Jarvamaa",
+    "EE-56" => "Laanemaa",
+    "EE-60" => "Laane-Virumaa",
+    "EE-64" => "Polvamaa",
+    "EE-68" => "Parnumaa",
+    "EE-71" => "Raplamaa",
+    "EE-714" => "Saaremaa",
+    "EE-74" => "Saaremaa",
+    "EE-784" => "Tallinn",
+    "EE-79" => "Tartumaa",
+    "EE-81" => "Valgamaa",
+    "EE-84" => "Viljandimaa",
+    "EE-87" => "Vorumaa",
+    "SZ-HH" => "Hhohho",
+    "SZ-LU" => "Lubombo",
+    "SZ-MA" => "Manzini",
+    "SZ-SH" => "Shiselweni",
+    "ET-AA" => "Addis Abeba",
+    "FJ-C" => "Centrale",
+    "FJ-E" => "Orientale",
+    "FJ-N" => "Settentrionale",
+    "FJ-R" => "Rotuma",
+    "FJ-W" => "Occidentale",
+    "FI-01" => "Isole Åland",
+    "FI-02" => "Carelia meridionale",
+    "FI-03" => "Ostrobotnia meridionale",
+    "FI-04" => "Savo meridionale",
+    "FI-05" => "Kainuu",
+    "FI-07" => "Ostrobotnia centrale",
+    "FI-08" => "Finlandia centrale",
+    "FI-09" => "Kymenlaakso",
+    "FI-10" => "Lapponia",
+    "FI-12" => "Ostrobotnia",
+    "FR-01" => "Ain",
+    "FR-02" => "Aisne",
+    "FR-03" => "Allier",
+    "FR-04" => "Alpi dell'Alta Provenza",
+    "FR-05" => "Alte Alpi",
+    "FR-06" => "Alpi marittime",
+    "FR-07" => "Ardèche",
+    "FR-08" => "Ardenne",
+    "FR-09" => "Ariège",
+    "FR-10" => "Aube",
+    "FR-11" => "Aude",
+    "FR-12" => "Aveyron",
+    "FR-13" => "Bocche del Rodano",
+    "FR-14" => "Calvados",
+    "FR-15" => "Cantal",
+    "FR-16" => "Charente",
+    "FR-17" => "Charente-Maritime",
+    "FR-18" => "Cher",
+    "FR-19" => "Corrèze",
+    "FR-20R" => "Corsica",
+    "FR-21" => "Cote-d'Or",
+    "FR-22" => "Cotes-d'Armor",
+    "FR-23" => "Creuse",
+    "FR-24" => "Dordogna",
+    "FR-25" => "Doubs",
+    "FR-26" => "Drome",
+    "FR-27" => "Eure",
+    "FR-28" => "Eure-et-Loir",
+    "FR-29" => "Finistère",
+    "FR-2A" => "Corsica del Sud",
+    "FR-2B" => "Alta Corsica",
+    "FR-30" => "Gard",
+    "FR-31" => "Alta Garonna",
+    "FR-32" => "Gers",
+    "FR-33" => "Gironda",
+    "FR-34" => "Hérault",
+    "FR-35" => "Ille-et-Vilaine",
+    "FR-36" => "Indre",
+    "FR-37" => "Indre-et-Loire",
+    "FR-38" => "Isère",
+    "FR-39" => "Giura",
+    "FR-40" => "Landes",
+    "FR-41" => "Loir-et-Cher",
+    "FR-42" => "Loira",
+    "FR-43" => "Alta Loira",
+    "FR-44" => "Loira atlantica",
+    "FR-45" => "Loiret",
+    "FR-46" => "Lot",
+    "FR-47" => "Lot-et-Garonne",
+    "FR-48" => "Lozère",
+    "FR-49" => "Maine-et-Loire",
+    "FR-50" => "Manica",
+    "FR-51" => "Marna",
+    "FR-52" => "Alta Marna",
+    "FR-53" => "Mayenne",
+    "FR-54" => "Meurthe-et-Moselle",
+    "FR-55" => "Mosa",
+    "FR-56" => "Morbihan",
+    "FR-57" => "Mosella",
+    "FR-58" => "Nièvre",
+    "FR-59" => "Nord",
+    "FR-60" => "Oise",
+    "FR-61" => "Orne",
+    "FR-62" => "Passo di Calais",
+    "FR-63" => "Puy-de-Dome",
+    "FR-64" => "Pirenei atlantici",
+    "FR-65" => "Alti Pirenei",
+    "FR-66" => "Pirenei orientali",
+    "FR-67" => "Basso Reno",
+    "FR-68" => "Alto Reno",
+    "FR-69" => "Rodano",
+    "FR-70" => "Haute-Saone",
+    "FR-71" => "Saone-et-Loire",
+    "FR-72" => "Sarthe",
+    "FR-73" => "Savoia",
+    "FR-74" => "Alta Savoia",
+    "FR-76" => "Senna marittima",
+    "FR-77" => "Senna e Marna",
+    "FR-78" => "Yvelines",
+    "FR-79" => "Deux-Sèvres",
+    "FR-80" => "Somme",
+    "FR-81" => "Tarn",
+    "FR-82" => "Tarn-et-Garonne",
+    "FR-83" => "Var",
+    "FR-84" => "Vaucluse",
+    "FR-85" => "Vandea",
+    "FR-86" => "Vienne",
+    "FR-87" => "Haute-Vienne",
+    "FR-88" => "Vosgi",
+    "FR-89" => "Yonne",
+    "FR-90" => "Territorio di Belfort",
+    "FR-91" => "Essonne",
+    "FR-92" => "Hauts-de-Seine",
+    "FR-93" => "Seine-Saint-Denis",
+    "FR-94" => "Valle della Marna",
+    "FR-95" => "Val-d'Oise",
+    "FR-971" => "Guadalupa",
+    "FR-972" => "Martinica",
+    "FR-973" => "Guiana francese",
+    "FR-974" => "Isola della Riunione",
+    "FR-976" => "Mayotte",
+    "FR-ARA" => "Alvernia-Rodano-Alpi",
+    "FR-BFC" => "Borgogna-Franca Contea",
+    "FR-BL" => "Saint-Barthélemy",
+    "FR-BRE" => "Bretagna",
+    "FR-CP" => "Isola Clipperton",
+    "FR-CVL" => "Centro-Valle della Loira",
+    "FR-GES" => "Grande Est",
+    "FR-HDF" => "Alta Francia",
+    "FR-IDF" => "Ile-de-France",
+    "FR-MF" => "Saint-Martin",
+    "FR-NAQ" => "Nuova Aquitania",
+    "FR-NC" => "Nuova Caledonia",
+    "FR-NOR" => "Normandia",
+    "FR-OCC" => "Occitania",
+    "FR-PAC" => "Provenza-Alpi-Costa Azzurra",
+    "FR-PDL" => "Paesi della Loira",
+    "FR-PF" => "Polinesia francese",
+    "FR-PM" => "Saint-Pierre e Miquelon",
+    "FR-TF" => "Terre australi francesi",
+    "FR-WF" => "Wallis e Futuna",
+    "GA-1" => "Estuaire",
+    "GA-2" => "Haut-Ogooué",
+    "GA-3" => "Moyen-Ogooué",
+    "GA-4" => "Ngounié",
+    "GA-5" => "Nyanga",
+    "GA-6" => "Ogooué-Ivindo",
+    "GA-7" => "Ogooué-Lolo",
+    "GA-8" => "Ogooué-Maritime",
+    "GA-9" => "Woleu-Ntem",
+    "GM-B" => "Banjul",
+    "GM-L" => "Lower River",
+    "GM-M" => "Central River",
+    "GM-N" => "North Bank",
+    "GM-U" => "Upper River",
+    "GM-W" => "Occidentale",
+    "GE-AB" => "Abkhazia",
+    "GE-AJ" => "Ajaria",
+    "GE-GU" => "Guria",
+    "GE-IM" => "Imereti",
+    "GE-TB" => "Tiblisi",
+    "DE-BB" => "Brandeburgo",
+    "DE-BE" => "Berlino",
+    "DE-BW" => "Baden-Wurttemberg",
+    "DE-BY" => "Baviera",
+    "DE-HB" => "Brema",
+    "DE-HE" => "Assia",
+    "DE-HH" => "Amburgo",
+    "DE-MV" => "Meclemburgo-Pomerania Anteriore",
+    "DE-NI" => "Bassa Sassonia",
+    "DE-NW" => "Nord Reno-Westfalia",
+    "DE-RP" => "Renania-Palatinato",
+    "DE-SH" => "Schleswig-Holstein",
+    "DE-SL" => "Saarland",
+    "DE-SN" => "Sachsen",
+    "DE-ST" => "Sachsen-Anhalt",
+    "DE-TH" => "Turingia",
+    "GH-AA" => "Grande Accra",
+    "GH-AH" => "Ashanti",
+    "GH-CP" => "Centrale",
+    "GH-EP" => "Orientale",
+    "GH-NE" => "Nord Est",
+    "GH-NP" => "Settentrionale",
+    "GH-TV" => "Volta",
+    "GH-UE" => "Nordorientale",
+    "GH-UW" => "Nordoccidentale",
+    "GH-WP" => "Occidentale",
+    "GR-J" => "Peloponneso",
+    "GL-KU" => "Kommune Kujalleq",
+    "GL-QE" => "Qeqqata Kommunia",
+    "GL-SM" => "Kommuneqarfik Sermersooq",
+    "GD-01" => "Saint Andrew",
+    "GD-02" => "Saint David",
+    "GD-03" => "Saint George",
+    "GD-04" => "Saint John",
+    "GD-05" => "Saint Mark",
+    "GD-06" => "Saint Patrick",
+    "GD-10" => "Isole Grenadine meridionali",
+    "GN-B" => "Boké",
+    "GN-BE" => "Beyla",
+    "GN-BF" => "Boffa",
+    "GN-BK" => "Boké",
+    "GN-C" => "Conakry",
+    "GN-CO" => "Coyah",
+    "GN-D" => "Kindia",
+    "GN-DB" => "Dabola",
+    "GN-DI" => "Dinguiraye",
+    "GN-DL" => "Dalaba",
+    "GN-DU" => "Dubréka",
+    "GN-F" => "Faranah",
+    "GN-FA" => "Faranah",
+    "GN-FO" => "Forécariah",
+    "GN-FR" => "Fria",
+    "GN-GA" => "Gaoual",
+    "GN-GU" => "Guéckédou",
+    "GN-K" => "Kankan",
+    "GN-KA" => "Kankan",
+    "GN-KB" => "Koubia",
+    "GN-KD" => "Kindia",
+    "GN-KE" => "Kérouané",
+    "GN-KN" => "Koundara",
+    "GN-KO" => "Kouroussa",
+    "GN-KS" => "Kissidougou",
+    "GN-L" => "Labé",
+    "GN-LA" => "Labé",
+    "GN-LE" => "Lélouma",
+    "GN-LO" => "Lola",
+    "GN-M" => "Marnou",
+    "GN-MC" => "Macenta",
+    "GN-MD" => "Mandiana",
+    "GN-ML" => "Mali",
+    "GN-MM" => "Marnou",
+    "GN-N" => "Nzérékoré",
+    "GN-NZ" => "Nzérékoré",
+    "GN-PI" => "Pita",
+    "GN-SI" => "Siguiri",
+    "GN-TE" => "Télimélé",
+    "GN-TO" => "Tougué",
+    "GN-YO" => "Yomou",
+    "GW-BA" => "Bafatà",
+    "GW-BM" => "Biombo",
+    "GW-BS" => "Bissau",
+    "GW-CA" => "Cacheu",
+    "GW-GA" => "Gabu",
+    "GW-L" => "Leste",
+    "GW-N" => "Norte",
+    "GW-OI" => "Oio",
+    "GW-QU" => "Quinara",
+    "GW-S" => "Sul",
+    "GW-TO" => "Tombali",
+    "GY-BA" => "Barima-Waini",
+    "GY-CU" => "Cuyuni-Mazaruni",
+    "GY-DE" => "Demerara-Mahaica",
+    "GY-EB" => "Berbice Est-Corentyne",
+    "GY-ES" => "Isole Essequibo-Demerara Ovest",
+    "GY-MA" => "Mahaica-Berbice",
+    "GY-PM" => "Pomeroon-Supenaam",
+    "GY-PT" => "Potaro-Siparuni",
+    "GY-UD" => "Demerara superiore-Berbice",
+    "GY-UT" => "Takutu superiore-Essequibo superiore",
+    "HT-AR" => "Artibonite",
+    "HT-CE" => "Centre",
+    "HT-ND" => "Nord",
+    "HT-NE" => "Nord-Est",
+    "HT-NO" => "Nord-Ovest",
+    "HN-AT" => "Atlantida",
+    "HN-CH" => "Choluteca",
+    "HN-CL" => "Colòn",
+    "HN-CM" => "Comayagua",
+    "HN-CP" => "Copàn",
+    "HN-CR" => "Cortés",
+    "HN-EP" => "El Paraiso",
+    "HN-FM" => "Francisco Morazan",
+    "HN-GD" => "Gracias a Dios",
+    "HN-IB" => "Islas de la Bahia",
+    "HN-IN" => "Intibucà",
+    "HN-LE" => "Lempira",
+    "HN-LP" => "La Paz",
+    "HN-OC" => "Ocotepeque",
+    "HN-OL" => "Olancho",
+    "HN-SB" => "Santa Barbara",
+    "HN-VA" => "Valle",
+    "HN-YO" => "Yoro",
+    "HU-BA" => "Baranya",
+    "HU-BC" => "Bekescsaba",
+    "HU-BE" => "Békés",
+    "HU-BK" => "Bacs-Kiskun",
+    "HU-BU" => "Budapest",
+    "HU-BZ" => "Borsod-Abauj-Zemplén",
+    "HU-CS" => "Csongrad",
+    "HU-DE" => "Debrecen",
+    "HU-DU" => "Dunaujvaros",
+    "HU-EG" => "Eger",
+    "HU-ER" => "Érd",
+    "HU-FE" => "Fejér",
+    "HU-GS" => "Gyor-Moson-Sopron",
+    "HU-GY" => "Gyor",
+    "HU-HB" => "Hajdu-Bihar",
+    "HU-HE" => "Heves",
+    "HU-HV" => "Hodmezovasarhely",
+    "HU-JN" => "Jasz-Nagykun-Szolnok",
+    "HU-KE" => "Komarom-Esztergom",
+    "HU-KM" => "Kecskemét",
+    "HU-KV" => "Kaposvar",
+    "HU-MI" => "Miskolc",
+    "HU-NK" => "Nagykanizsa",
+    "HU-NO" => "Nograd",
+    "HU-NY" => "Nyiregyhaza",
+    "HU-PE" => "Pest",
+    "HU-PS" => "Pécs",
+    "HU-SD" => "Szeged",
+    "HU-SF" => "Székesfehérvar",
+    "HU-SH" => "Szombathely",
+    "HU-SK" => "Szolnok",
+    "HU-SN" => "Sopron",
+    "HU-SO" => "Somogy",
+    "HU-SS" => "Szekszard",
+    "HU-ST" => "Salgotarjan",
+    "HU-SZ" => "Szabolcs-Szatmar-Bereg",
+    "HU-TB" => "Tatabanya",
+    "HU-TO" => "Tolna",
+    "HU-VA" => "Vas",
+    "HU-VE" => "Veszprém",
+    "HU-VM" => "Veszprém",
+    "HU-ZA" => "Zala",
+    "HU-ZE" => "Zalaegerszeg",
+    "IS-2" => "Penisola meridionale",
+    "IS-3" => "Terra dell'Ovest",
+    "IS-4" => "Fiordi occidentali",
+    "IS-5" => "Terra del Nordovest",
+    "IS-6" => "Terra del Nordest",
+    "IS-7" => "Terra dell'est",
+    "IS-8" => "Terra del Sud",
+    "IN-AN" => "Isole Andamane e Nicobare",
+    "IN-AP" => "Andhra Pradesh",
+    "IN-AS" => "Assam",
+    "IN-DL" => "Delhi",
+    "IN-GA" => "Goa",
+    "IN-GJ" => "Gujarat",
+    "IN-KL" => "Kerala",
+    "IN-LD" => "Lakshadweep",
+    "IN-MN" => "Manipur",
+    "IN-MP" => "Madhya Pradesh",
+    "IN-MZ" => "Mizoram",
+    "IN-PB" => "Punjab",
+    "IN-PY" => "Puducherry",
+    "IN-SK" => "Sikkim",
+    "IN-TR" => "Tripura",
+    "IN-UP" => "Uttar Pradesh",
+    "IN-WB" => "Bengala occidentale",
+    "ID-AC" => "Aceh",
+    "ID-BA" => "Bali",
+    "ID-BE" => "Bengkulu",
+    "ID-BT" => "Banten",
+    "ID-GO" => "Gorontalo",
+    "ID-JA" => "Jambi",
+    "ID-JB" => "Giava occidentale",
+    "ID-JI" => "Giava orientale",
+    "ID-JK" => "Jakarta Raya",
+    "ID-JT" => "Giava centrale",
+    "ID-JW" => "Giava",
+    "ID-KA" => "Kalimantan",
+    "ID-KB" => "Kalimantan occidentale",
+    "ID-KI" => "Kalimantan orientale",
+    "ID-KR" => "Riau Kepulauan",
+    "ID-KS" => "Kalimantan meridionale",
+    "ID-KT" => "Kalimantan centrale",
+    "ID-LA" => "Lampung",
+    "ID-MA" => "Maluku",
+    "ID-ML" => "Maluku",
+    "ID-MU" => "Maluku settentrionale",
+    "ID-NB" => "Nusa Tenggara occidentale",
+    "ID-NT" => "Nusa Tenggara orientale",
+    "ID-NU" => "Nusa Tenggara",
+    "ID-PA" => "Papua",
+    "ID-PB" => "Papua Barat",
+    "ID-PP" => "Papua",
+    "ID-RI" => "Riau",
+    "ID-SA" => "Sulawesi settentrionale",
+    "ID-SG" => "Sulawesi Tenggara",
+    "ID-SL" => "Sulawesi",
+    "ID-SM" => "Sumatra",
+    "ID-SN" => "Sulawesi meridionale",
+    "ID-SR" => "Sulawesi occidentale",
+    "ID-ST" => "Sulawesi centrale",
+    "ID-SU" => "Sumatra settentrionale",
+    "ID-YO" => "Yogyakarta",
+    "IR-00" => "Markazi",
+    "IR-01" => "Gilan",
+    "IR-02" => "Mazandaran",
+    "IR-05" => "Kermanshah",
+    "IR-06" => "Khuzestan",
+    "IR-07" => "Fars",
+    "IR-08" => "Kerman",
+    "IR-10" => "Esfahan",
+    "IR-11" => "Sistan e Baluchestan",
+    "IR-12" => "Kurdistan",
+    "IR-13" => "Hamadan",
+    "IR-15" => "Lorestan",
+    "IR-16" => "Ilam",
+    "IR-18" => "Bushehr",
+    "IR-19" => "Zanjan",
+    "IR-20" => "Semnan",
+    "IR-21" => "Yazd",
+    "IR-22" => "Hormozgan",
+    "IR-23" => "Teheran",
+    "IR-24" => "Ardabil",
+    "IR-25" => "Qom",
+    "IR-26" => "Qazvin",
+    "IR-27" => "Golestan",
+    "IE-CE" => "Clare",
+    "IE-CN" => "Cavan",
+    "IE-CO" => "Cork",
+    "IE-CW" => "Carlow",
+    "IE-D" => "Dublino",
+    "IE-DL" => "Donegal",
+    "IE-G" => "Galway",
+    "IE-KE" => "Kildare",
+    "IE-KK" => "Kilkenny",
+    "IE-KY" => "Kerry",
+    "IE-L" => "Leinster",
+    "IE-LD" => "Longford",
+    "IE-LH" => "Louth",
+    "IE-LK" => "Limerick",
+    "IE-LM" => "Leitrim",
+    "IE-LS" => "Laois",
+    "IE-M" => "Munster",
+    "IE-MH" => "Meath",
+    "IE-MN" => "Monaghan",
+    "IE-MO" => "Mayo",
+    "IE-OY" => "Offaly",
+    "IE-RN" => "Roscommon",
+    "IE-SO" => "Sligo",
+    "IE-TA" => "Tipperary",
+    "IE-U" => "Ulster",
+    "IE-WD" => "Waterford",
+    "IE-WH" => "Westmeath",
+    "IE-WW" => "Wicklow",
+    "IE-WX" => "Wexford",
+    "IL-D" => "Regione del Sud",
+    "IL-M" => "Regione centrale",
+    "IT-21" => "Piemonte",
+    "IT-23" => "Valle d'Aosta",
+    "IT-25" => "Lombardia",
+    "IT-32" => "Trentino Alto Adige",
+    "IT-34" => "Veneto",
+    "IT-36" => "Friuli Venezia Giulia",
+    "IT-42" => "Liguria",
+    "IT-45" => "Emilia Romagna",
+    "IT-52" => "Toscana",
+    "IT-55" => "Umbria",
+    "IT-57" => "Marche",
+    "IT-62" => "Lazio",
+    "IT-65" => "Abruzzo",
+    "IT-67" => "Molise",
+    "IT-72" => "Campania",
+    "IT-75" => "Puglia",
+    "IT-77" => "Basilicata",
+    "IT-78" => "Calabria",
+    "IT-82" => "Sicilia",
+    "IT-88" => "Sardegna",
+    "IT-AG" => "Agrigento",
+    "IT-AL" => "Alessandria",
+    "IT-AN" => "Ancona",
+    "IT-AP" => "Ascoli Piceno",
+    "IT-AQ" => "L'Aquila",
+    "IT-AR" => "Arezzo",
+    "IT-AT" => "Asti",
+    "IT-AV" => "Avellino",
+    "IT-BA" => "Bari",
+    "IT-BG" => "Bergamo",
+    "IT-BI" => "Biella",
+    "IT-BL" => "Belluno",
+    "IT-BN" => "Benevento",
+    "IT-BO" => "Bologna",
+    "IT-BR" => "Brindisi",
+    "IT-BS" => "Brescia",
+    "IT-BT" => "Barletta-Andria-Trani",
+    "IT-BZ" => "Bolzano",
+    "IT-CA" => "Cagliari",
+    "IT-CB" => "Campobasso",
+    "IT-CE" => "Caserta",
+    "IT-CH" => "Chieti",
+    "IT-CL" => "Caltanissetta",
+    "IT-CN" => "Cuneo",
+    "IT-CO" => "Como",
+    "IT-CR" => "Cremona",
+    "IT-CS" => "Cosenza",
+    "IT-CT" => "Catania",
+    "IT-CZ" => "Catanzaro",
+    "IT-EN" => "Enna",
+    "IT-FC" => "Forlì-Cesena",
+    "IT-FE" => "Ferrara",
+    "IT-FG" => "Foggia",
+    "IT-FI" => "Firenze",
+    "IT-FM" => "Fermo",
+    "IT-FR" => "Frosinone",
+    "IT-GE" => "Genova",
+    "IT-GO" => "Gorizia",
+    "IT-GR" => "Grosseto",
+    "IT-IM" => "Imperia",
+    "IT-IS" => "Isernia",
+    "IT-KR" => "Crotone",
+    "IT-LC" => "Lecco",
+    "IT-LE" => "Lecce",
+    "IT-LI" => "Livorno",
+    "IT-LO" => "Lodi",
+    "IT-LT" => "Latina",
+    "IT-LU" => "Lucca",
+    "IT-MB" => "Monza e Brianza",
+    "IT-MC" => "Macerata",
+    "IT-ME" => "Messina",
+    "IT-MI" => "Milano",
+    "IT-MN" => "Mantova",
+    "IT-MO" => "Modena",
+    "IT-MS" => "Massa Carrara",
+    "IT-MT" => "Matera",
+    "IT-NA" => "Napoli",
+    "IT-NO" => "Novara",
+    "IT-NU" => "Nuoro",
+    "IT-OR" => "Oristano",
+    "IT-PA" => "Palermo",
+    "IT-PC" => "Piacenza",
+    "IT-PD" => "Padova",
+    "IT-PE" => "Pescara",
+    "IT-PG" => "Perugia",
+    "IT-PI" => "Pisa",
+    "IT-PN" => "Pordenone",
+    "IT-PO" => "Prato",
+    "IT-PR" => "Parma",
+    "IT-PT" => "Pistoia",
+    "IT-PU" => "Pesaro e Urbino",
+    "IT-PV" => "Pavia",
+    "IT-PZ" => "Potenza",
+    "IT-RA" => "Ravenna",
+    "IT-RC" => "Reggio Calabria",
+    "IT-RE" => "Reggio Emilia",
+    "IT-RG" => "Ragusa",
+    "IT-RI" => "Rieti",
+    "IT-RM" => "Roma",
+    "IT-RN" => "Rimini",
+    "IT-RO" => "Rovigo",
+    "IT-SA" => "Salerno",
+    "IT-SI" => "Siena",
+    "IT-SO" => "Sondrio",
+    "IT-SP" => "La Spezia",
+    "IT-SR" => "Siracusa",
+    "IT-SS" => "Sassari",
+    "IT-SV" => "Savona",
+    "IT-TA" => "Taranto",
+    "IT-TE" => "Teramo",
+    "IT-TN" => "Trento",
+    "IT-TO" => "Torino",
+    "IT-TP" => "Trapani",
+    "IT-TR" => "Terni",
+    "IT-TS" => "Trieste",
+    "IT-TV" => "Treviso",
+    "IT-UD" => "Udine",
+    "IT-VA" => "Varese",
+    "IT-VB" => "Verbano-Cusio-Ossola",
+    "IT-VC" => "Vercelli",
+    "IT-VE" => "Venezia",
+    "IT-VI" => "Vicenza",
+    "IT-VR" => "Verona",
+    "IT-VT" => "Viterbo",
+    "IT-VV" => "Vibo Valentia",
+    "JM-01" => "Kingston",
+    "JM-02" => "Saint Andrew",
+    "JM-03" => "Saint Thomas",
+    "JM-04" => "Portland",
+    "JM-05" => "Saint Mary",
+    "JM-06" => "Saint Ann",
+    "JM-07" => "Trelawny",
+    "JM-08" => "Saint James",
+    "JM-09" => "Hanover",
+    "JM-10" => "Westmoreland",
+    "JM-11" => "Saint Elizabeth",
+    "JM-12" => "Manchester",
+    "JM-13" => "Clarendon",
+    "JM-14" => "Saint Catherine",
+    "JP-01" => "Hokkaido",
+    "JP-02" => "Aomori",
+    "JP-03" => "Iwate",
+    "JP-04" => "Miyagi",
+    "JP-05" => "Akita",
+    "JP-06" => "Yamagata",
+    "JP-07" => "Fukushima",
+    "JP-08" => "Ibaraki",
+    "JP-09" => "Tochigi",
+    "JP-10" => "Gunma",
+    "JP-11" => "Saitama",
+    "JP-12" => "Chiba",
+    "JP-13" => "Tokyo",
+    "JP-14" => "Kanagawa",
+    "JP-15" => "Niigata",
+    "JP-16" => "Toyama",
+    "JP-17" => "Ishikawa",
+    "JP-18" => "Fukui",
+    "JP-19" => "Yamanashi",
+    "JP-20" => "Nagano",
+    "JP-21" => "Gifu",
+    "JP-22" => "Shizuoka",
+    "JP-23" => "Aichi",
+    "JP-24" => "Mie",
+    "JP-25" => "Shiga",
+    "JP-26" => "Kyoto",
+    "JP-27" => "Osaka",
+    "JP-28" => "Hyogo",
+    "JP-29" => "Nara",
+    "JP-30" => "Wakayama",
+    "JP-31" => "Tottori",
+    "JP-32" => "Shimane",
+    "JP-33" => "Okayama",
+    "JP-34" => "Hiroshima",
+    "JP-35" => "Yamaguchi",
+    "JP-36" => "Tokushima",
+    "JP-37" => "Kagawa",
+    "JP-38" => "Ehime",
+    "JP-39" => "Kochi",
+    "JP-40" => "Fukuoka",
+    "JP-41" => "Saga",
+    "JP-42" => "Nagasaki",
+    "JP-43" => "Kumamoto",
+    "JP-44" => "Oita",
+    "JP-45" => "Miyazaki",
+    "JP-46" => "Kagoshima",
+    "JP-47" => "Okinawa",
+    "JO-AJ" => "Ajloun",
+    "JO-AQ" => "Aqabah",
+    "JO-AT" => "Tafila",
+    "JO-IR" => "Irbid",
+    "JO-JA" => "Jarash",
+    "JO-KA" => "Karak",
+    "JO-MA" => "Mafraq",
+    "JO-MD" => "Madaba",
+    "JO-MN" => "Ma‘ān",
+    "KE-04" => "Busia",
+    "KE-28" => "Mombasa",
+    "KE-30" => "Nairobi",
+    "KI-G" => "Isole Gilbert",
+    "KI-L" => "Isole Line",
+    "KI-P" => "Isole Phoenix",
+    "KP-01" => "Pyongyang",
+    "KP-04" => "Chagang",
+    "KP-05" => "Hwanghae-namdo",
+    "KP-06" => "Hwanghae-bukto",
+    "KW-FA" => "Al Farwanayah",
+    "KW-MU" => "Mubarak al-Kabir",
+    "KG-B" => "Batken",
+    "KG-N" => "Naryn",
+    "KG-O" => "Osh",
+    "KG-T" => "Talas",
+    "LA-AT" => "Attapu",
+    "LA-BK" => "Bokeo",
+    "LA-BL" => "Bolikhamxai",
+    "LA-CH" => "Champasak",
+    "LA-HO" => "Houaphan",
+    "LA-KH" => "Khammouan",
+    "LA-LM" => "Louang Namtha",
+    "LA-LP" => "Louangphabang",
+    "LA-OU" => "Oudomxai",
+    "LA-PH" => "Phongsali",
+    "LA-SL" => "Salavan",
+    "LA-SV" => "Savannakhet",
+    "LA-XA" => "Xaignabouli",
+    "LA-XE" => "Xekong",
+    "LA-XI" => "Xiangkhouang",
+    "LA-XS" => "Xiasomboun",
+    "LV-002" => "Municipalità di Aizkraukle",
+    "LV-007" => "Municipalità di Alūksne",
+    "LV-011" => "Municipalità di Adazu",
+    "LV-015" => "Municipalità di Balvu",
+    "LV-016" => "Municipalità di Bauska",
+    "LV-022" => "Municipalità di Cesu",
+    "LV-026" => "Municipalità di Dobele",
+    "LV-033" => "Municipalità di Gulbene",
+    "LV-041" => "Municipalità di Jelgava",
+    "LV-042" => "Municipalità di Jekabpil",
+    "LV-047" => "Municipalità di Kraslava",
+    "LV-050" => "Municipalità di Kuldiga",
+    "LV-052" => "Municipalità di Ķekavas",
+    "LV-054" => "Municipalità di Limbazu",
+    "LV-056" => "Municipalità di Livanu",
+    "LV-058" => "Municipalità di Ludza",
+    "LV-059" => "Municipalità di Madona",
+    "LV-062" => "Municipalità di Marupe",
+    "LV-067" => "Municipalità di Ogre",
+    "LV-068" => "Municipalità di Olaine",
+    "LV-073" => "Municipalità di Preilu",
+    "LV-077" => "Municipalità di Rezekne",
+    "LV-080" => "Municipalità di Ropazu",
+    "LV-087" => "Municipalità di Salaspil",
+    "LV-088" => "Municipalità di Saldu",
+    "LV-089" => "Municipalità di Saulkrastu",
+    "LV-091" => "Municipalità di Sigulda",
+    "LV-094" => "Municipalità di Smiltene",
+    "LV-097" => "Municipalità di Talsu",
+    "LV-099" => "Municipalità di Tukuma",
+    "LV-101" => "Municipalità di Valka",
+    "LV-102" => "Municipalità di Varaklanu",
+    "LV-106" => "Municipalità di Ventspil",
+    "LV-DGV" => "Daugavpils",
+    "LV-JEL" => "Jelgava",
+    "LV-JUR" => "Jurmala",
+    "LV-LPX" => "Liepaja",
+    "LV-REZ" => "Rezekne",
+    "LV-RIX" => "Riga",
+    "LV-VEN" => "Ventspils",
+    "LB-AK" => "Akkar",
+    "LB-BH" => "Baalbek-Hermel",
+    "LS-A" => "Maseru",
+    "LS-C" => "Leribe",
+    "LS-D" => "Berea",
+    "LS-E" => "Mafeteng",
+    "LS-F" => "Mohale's Hoek",
+    "LS-G" => "Quthing",
+    "LS-H" => "Qacha's Nek",
+    "LS-J" => "Mokhotlong",
+    "LS-K" => "Thaba-Tseka",
+    "LR-BG" => "Bong",
+    "LR-BM" => "Bomi",
+    "LR-CM" => "Grand Cape Mount",
+    "LR-GB" => "Grand Bassa",
+    "LR-GG" => "Grand Gedeh",
+    "LR-GK" => "Grand Kru",
+    "LR-LO" => "Lofa",
+    "LR-MG" => "Margibi",
+    "LR-MO" => "Montserrado",
+    "LR-MY" => "Maryland",
+    "LR-NI" => "Nimba",
+    "LR-SI" => "Sinoe",
+    "LY-BA" => "Bengasi",
+    "LY-BU" => "Al Butnan",
+    "LY-DR" => "Derna",
+    "LY-GT" => "Ghat",
+    "LY-JA" => "Al Jabal al Akhḑar",
+    "LY-JG" => "Al Jabal al Gharbī",
+    "LY-JU" => "Al Jufrah",
+    "LY-KF" => "Al Kufrah",
+    "LY-MB" => "Al Marqab",
+    "LY-MI" => "Misurata",
+    "LY-MJ" => "Al Marj",
+    "LY-MQ" => "Murzuq",
+    "LY-NL" => "Nalut",
+    "LY-SB" => "Sebha",
+    "LY-SR" => "Sirte",
+    "LY-TB" => "Tripoli",
+    "LY-WD" => "Wādī al Ḩayāt",
+    "LY-ZA" => "Ez Zauia",
+    "LI-01" => "Balzers",
+    "LI-02" => "Eschen",
+    "LI-03" => "Gamprin",
+    "LI-04" => "Mauren",
+    "LI-05" => "Planken",
+    "LI-06" => "Ruggell",
+    "LI-07" => "Schaan",
+    "LI-08" => "Schellenberg",
+    "LI-09" => "Triesen",
+    "LI-10" => "Triesenberg",
+    "LI-11" => "Vaduz",
+    "LT-09" => "Ignalina",
+    "LT-58" => "Vilnius",
+    "LU-DI" => "Diekirch",
+    "LU-GR" => "Grevenmacher",
+    "LU-LU" => "Lussemburgo",
+    "MG-A" => "Toamasina",
+    "MG-D" => "Antsiranana",
+    "MG-F" => "Fianarantsoa",
+    "MG-M" => "Mahajanga",
+    "MG-T" => "Antananarivo",
+    "MG-U" => "Toliara",
+    "MW-BA" => "Balaka",
+    "MW-BL" => "Blantyre",
+    "MW-C" => "Central Region",
+    "MW-CK" => "Chikwawa",
+    "MW-CR" => "Chiradzulu",
+    "MW-CT" => "Chitipa",
+    "MW-DE" => "Dedza",
+    "MW-DO" => "Dowa",
+    "MW-KR" => "Karonga",
+    "MW-KS" => "Kasungu",
+    "MW-LI" => "Lilongwe",
+    "MW-LK" => "Likoma",
+    "MW-MC" => "Mchinji",
+    "MW-MG" => "Mangochi",
+    "MW-MH" => "Machinga",
+    "MW-MU" => "Mulanje",
+    "MW-MW" => "Mwanza",
+    "MW-MZ" => "Mzimba",
+    "MW-N" => "Northern Region",
+    "MW-NB" => "Nkhata Bay",
+    "MW-NE" => "Neno",
+    "MW-NI" => "Ntchisi",
+    "MW-NK" => "Nkhotakota",
+    "MW-NS" => "Nsanje",
+    "MW-NU" => "Ntcheu",
+    "MW-PH" => "Phalombe",
+    "MW-RU" => "Rumphi",
+    "MW-S" => "Southern Region",
+    "MW-SA" => "Salima",
+    "MW-TH" => "Thyolo",
+    "MW-ZO" => "Zomba",
+    "MY-01" => "Johor",
+    "MY-02" => "Kedah",
+    "MY-03" => "Kelantan",
+    "MY-04" => "Melaka",
+    "MY-05" => "Negeri Sembilan",
+    "MY-06" => "Pahang",
+    "MY-07" => "Pulau Pinang",
+    "MY-08" => "Perak",
+    "MY-09" => "Perlis",
+    "MY-10" => "Selangor",
+    "MY-11" => "Terengganu",
+    "MY-12" => "Sabah",
+    "MY-13" => "Sarawak",
+    "MY-14" => "Kuala Lumpur",
+    "MY-15" => "Labuan",
+    "MY-16" => "Putrajaya",
+    "MV-MLE" => "Male",
+    "ML-1" => "Kayes",
+    "ML-2" => "Koulikoro",
+    "ML-3" => "Sikasso",
+    "ML-4" => "Ségou",
+    "ML-5" => "Mopti",
+    "ML-6" => "Tombouctou",
+    "ML-7" => "Gao",
+    "ML-8" => "Kidal",
+    "ML-BKO" => "Bamako",
+    "MT-01" => "Attard",
+    "MT-02" => "Balzan",
+    "MT-03" => "Vittoriosa",
+    "MT-04" => "Birchircara",
+    "MT-05" => "Birzebuggia",
+    "MT-06" => "Cospicua",
+    "MT-07" => "Dingli",
+    "MT-08" => "Figura",
+    "MT-09" => "Floriana",
+    "MT-10" => "Fontana",
+    "MT-11" => "Gudia",
+    "MT-12" => "Gzira",
+    "MT-13" => "Ghajnsielem",
+    "MT-14" => "Garbo",
+    "MT-15" => "Gargur",
+    "MT-16" => "Ghasri",
+    "MT-17" => "Asciac",
+    "MT-18" => "Hamrun",
+    "MT-19" => "L'Iclin",
+    "MT-20" => "Senglea",
+    "MT-21" => "Calcara",
+    "MT-22" => "Kercem",
+    "MT-23" => "Chircop",
+    "MT-24" => "Lia",
+    "MT-25" => "Luca",
+    "MT-26" => "Marsa",
+    "MT-27" => "Marsa Scala",
+    "MT-28" => "Marsa Scirocco",
+    "MT-29" => "Medina",
+    "MT-30" => "Mellieha",
+    "MT-31" => "Mugiarro",
+    "MT-32" => "Musta",
+    "MT-33" => "Micabba",
+    "MT-34" => "Msida",
+    "MT-35" => "Marfa",
+    "MT-36" => "Monsciar",
+    "MT-37" => "Nadur",
+    "MT-38" => "Nasciar",
+    "MT-39" => "Paola",
+    "MT-40" => "Pembroke",
+    "MT-41" => "Pietà",
+    "MT-42" => "La Cala",
+    "MT-43" => "Curmi",
+    "MT-44" => "Crendi",
+    "MT-46" => "Rabat Malta",
+    "MT-47" => "Safi",
+    "MT-49" => "Saint John",
+    "MT-52" => "Sannat",
+    "MT-54" => "Santa Venera",
+    "MT-55" => "Suggeui",
+    "MT-56" => "Sliema",
+    "MT-57" => "Swieqi",
+    "MT-59" => "Tarscen",
+    "MT-60" => "La Valletta",
+    "MT-61" => "Caccia",
+    "MT-62" => "Xeuchia",
+    "MT-63" => "Xghajra",
+    "MT-64" => "Żabbar",
+    "MT-66" => "Zebbug Malta",
+    "MT-67" => "Zeitun",
+    "MT-68" => "Zurrico",
+    "MH-ALK" => "Ailuk",
+    "MH-ALL" => "Ailinglaplap",
+    "MH-ARN" => "Arno",
+    "MH-AUR" => "Aur",
+    "MH-EBO" => "Ebon",
+    "MH-JAB" => "Jabat",
+    "MH-JAL" => "Jaluit",
+    "MH-KWA" => "Kwajalein",
+    "MH-L" => "Ralik chain",
+    "MH-LAE" => "Lae",
+    "MH-LIB" => "Lib",
+    "MH-LIK" => "Likiep",
+    "MH-MAJ" => "Majuro",
+    "MH-MAL" => "Maloelap",
+    "MH-MEJ" => "Mejit",
+    "MH-MIL" => "Mili",
+    "MH-NMK" => "Namdrik",
+    "MH-NMU" => "Namu",
+    "MH-RON" => "Rongelap",
+    "MH-T" => "Ratak chain",
+    "MH-UJA" => "Ujae",
+    "MH-WTH" => "Wotho",
+    "MH-WTJ" => "Wotje",
+    "MR-01" => "Hodh ech Chargui",
+    "MR-03" => "Assaba",
+    "MR-04" => "Gorgol",
+    "MR-05" => "Brakna",
+    "MR-06" => "Trarza",
+    "MR-07" => "Adrar",
+    "MR-09" => "Tagant",
+    "MR-10" => "Guidimaka",
+    "MR-11" => "Tiris Zemmour",
+    "MR-12" => "Inchiri",
+    "MU-AG" => "Isole Agalega",
+    "MU-BL" => "Black River",
+    "MU-CC" => "Cargados Carajos Shoals",
+    "MU-FL" => "Flacq",
+    "MU-GP" => "Grand Port",
+    "MU-MO" => "Moka",
+    "MU-PA" => "Pamplemousses",
+    "MU-PL" => "Port Louis",
+    "MU-PW" => "Plaines Wilhems",
+    "MU-RO" => "Isola Rodrigues",
+    "MU-RR" => "Rivière du Rempart",
+    "MU-SA" => "Savanne",
+    "MX-AGU" => "Aguascalientes",
+    "MX-BCN" => "Bassa California",
+    "MX-BCS" => "Bassa California del Sud",
+    "MX-CAM" => "Campeche",
+    "MX-CHH" => "Chihuahua",
+    "MX-CHP" => "Chiapas",
+    "MX-CMX" => "Città del Messico",
+    "MX-COL" => "Colima",
+    "MX-DUR" => "Durango",
+    "MX-GRO" => "Guerrero",
+    "MX-GUA" => "Guanajuato",
+    "MX-HID" => "Hidalgo",
+    "MX-JAL" => "Jalisco",
+    "MX-MEX" => "Messico",
+    "MX-MOR" => "Morelos",
+    "MX-NAY" => "Nayarit",
+    "MX-NLE" => "Nuevo León",
+    "MX-OAX" => "Oaxaca",
+    "MX-PUE" => "Puebla",
+    "MX-QUE" => "Querétaro",
+    "MX-ROO" => "Quintana Roo",
+    "MX-SIN" => "Sinaloa",
+    "MX-SLP" => "San Luis Potosí",
+    "MX-SON" => "Sonora",
+    "MX-TAB" => "Tabasco",
+    "MX-TAM" => "Tamaulipas",
+    "MX-TLA" => "Tlaxcala",
+    "MX-YUC" => "Yucatán",
+    "MX-ZAC" => "Zacatecas",
+    "FM-KSA" => "Kosrae",
+    "FM-PNI" => "Pohnpei",
+    "FM-TRK" => "Chuuk",
+    "FM-YAP" => "Yap",
+    "MD-AN" => "Anenii Noi",
+    "MD-BA" => "Baltsi",
+    "MD-BR" => "Briceni",
+    "MD-BS" => "Basarabeasca",
+    "MD-CA" => "Cahul",
+    "MD-CL" => "Călărași",
+    "MD-CM" => "Cimișlia",
+    "MD-CR" => "Criuleni",
+    "MD-CS" => "Căușeni",
+    "MD-CT" => "Cantemir",
+    "MD-CU" => "Chișinău",
+    "MD-DO" => "Dondușeni",
+    "MD-DR" => "Drochia",
+    "MD-DU" => "Dubăsari",
+    "MD-ED" => "Edineț",
+    "MD-FA" => "Fălești",
+    "MD-FL" => "Florești",
+    "MD-GL" => "Glodeni",
+    "MD-HI" => "Hîncești",
+    "MD-IA" => "Ialoveni",
+    "MD-LE" => "Leova",
+    "MD-NI" => "Nisporeni",
+    "MD-OC" => "Ocnița",
+    "MD-OR" => "Orhei",
+    "MD-RE" => "Rezina",
+    "MD-RI" => "Rîșcani",
+    "MD-SD" => "Șoldănești",
+    "MD-SI" => "Sîngerei",
+    "MD-SN" => "Stanga Nistrului",
+    "MD-SO" => "Soroca",
+    "MD-ST" => "Strășeni",
+    "MD-SV" => "Ștefan Vodă",
+    "MD-TA" => "Taraclia",
+    "MD-TE" => "Telenești",
+    "MD-UN" => "Ungheni",
+    "MC-CL" => "La Colle",
+    "MC-CO" => "La Condamina",
+    "MC-FO" => "Fontevecchia",
+    "MC-GA" => "La Gare",
+    "MC-JE" => "Jardin Exotique",
+    "MC-LA" => "Larvotto",
+    "MC-MA" => "Malbousquet",
+    "MC-MC" => "Monte Carlo",
+    "MC-MG" => "Moneghetti",
+    "MC-MO" => "Monaco-Ville",
+    "MC-MU" => "Moulins",
+    "MC-PH" => "Port-Hercule",
+    "MC-SD" => "Sainte-Dévote",
+    "MC-SO" => "La Source",
+    "MC-SP" => "Spélugues",
+    "MC-SR" => "Saint-Roman",
+    "MC-VR" => "Vallon de la Rousse",
+    "MN-035" => "Orhon",
+    "MN-037" => "Darhan uul",
+    "MN-039" => "Hentiy",
+    "MN-041" => "Hövsgöl",
+    "MN-043" => "Hovd",
+    "MN-046" => "Uvs",
+    "MN-047" => "Töv",
+    "MN-049" => "Selenge",
+    "MN-051" => "Sühbaatar",
+    "MN-055" => "Övörhangay",
+    "MN-057" => "Dzavhan",
+    "MN-061" => "Dornod",
+    "MN-067" => "Bulgan",
+    "MN-069" => "Bayanhongor",
+    "MN-071" => "Bayan-Ölgiy",
+    "MN-073" => "Arhangay",
+    "MN-1" => "Ulan Bator",
+    "ME-01" => "Andrijevica",
+    "ME-02" => "Antivari",
+    "ME-03" => "Berane",
+    "ME-04" => "Bijelo Polje",
+    "ME-05" => "Budua",
+    "ME-06" => "Cettigne",
+    "ME-07" => "Danilovgrad",
+    "ME-08" => "Castelnuovo",
+    "ME-09" => "Kolašin",
+    "ME-10" => "Kotor",
+    "ME-11" => "Mojkovac",
+    "ME-12" => "Nikšić",
+    "ME-13" => "Plav",
+    "ME-14" => "Pljevlja",
+    "ME-15" => "Plužine",
+    "ME-16" => "Podgorica",
+    "ME-17" => "Rožaje",
+    "ME-18" => "Šavnik",
+    "ME-19" => "Tivat",
+    "ME-20" => "Ulcinj",
+    "ME-21" => "Žabljak",
+    "MA-02" => "Regione Orientale",
+    "MA-AZI" => "Azilal",
+    "MA-BER" => "Berkane",
+    "MA-BOD" => "Boujdour (EH)",
+    "MA-BOM" => "Boulemane",
+    "MA-CAS" => "Casablanca",
+    "MA-CHE" => "Chefchaouen",
+    "MA-CHI" => "Chichaoua",
+    "MA-CHT" => "Chtouka-Ait Baha",
+    "MA-ERR" => "Errachidia",
+    "MA-ESI" => "Essaouira",
+    "MA-FES" => "Fez",
+    "MA-FIG" => "Figuig",
+    "MA-GUE" => "Guelmim",
+    "MA-HAJ" => "El Hajeb",
+    "MA-HAO" => "Al Haouz",
+    "MA-HOC" => "Al Hoceïma",
+    "MA-IFR" => "Ifrane",
+    "MA-INE" => "Inezgane-Ait Melloul",
+    "MA-JDI" => "El Jadida",
+    "MA-KEN" => "Kénitra",
+    "MA-KHO" => "Khouribga",
+    "MA-LAA" => "Laâyoune (EH)",
+    "MA-LAR" => "Larache",
+    "MA-MED" => "Médiouna",
+    "MA-MEK" => "Meknès",
+    "MA-MOH" => "Mohammadia",
+    "MA-MOU" => "Moulay Yacoub",
+    "MA-NAD" => "Nador",
+    "MA-NOU" => "Nouaceur",
+    "MA-OUA" => "Ouarzazate",
+    "MA-OUJ" => "Oujda-Angad",
+    "MA-RAB" => "Rabat",
+    "MA-SAF" => "Safi",
+    "MA-SAL" => "Salé",
+    "MA-SEF" => "Sefrou",
+    "MA-SET" => "Settat",
+    "MA-SKH" => "Skhirate-Témara",
+    "MA-TAI" => "Taourirt",
+    "MA-TAO" => "Taounate",
+    "MA-TAT" => "Tata",
+    "MA-TAZ" => "Taza",
+    "MA-TET" => "Tétouan",
+    "MA-TIZ" => "Tiznit",
+    "MA-TNG" => "Tanger-Assilah",
+    "MA-ZAG" => "Zagora",
+    "MZ-A" => "Niassa",
+    "MZ-B" => "Manica",
+    "MZ-G" => "Gaza",
+    "MZ-I" => "Inhambane",
+    "MZ-L" => "Maputo",
+    "MZ-MPM" => "Maputo",
+    "MZ-P" => "Cabo Delgado",
+    "MZ-S" => "Sofala",
+    "MZ-T" => "Tete",
+    "MM-01" => "Sagaing",
+    "MM-02" => "Pegu",
+    "MM-03" => "Magway",
+    "MM-04" => "Mandalay",
+    "MM-05" => "Tanasserim",
+    "MM-06" => "Rangoon",
+    "MM-07" => "Ayeyarwady",
+    "MM-11" => "Kachin",
+    "MM-12" => "Kayah",
+    "MM-13" => "Karen",
+    "MM-14" => "Chin",
+    "MM-15" => "Mon",
+    "MM-16" => "Rakhine",
+    "MM-17" => "Shan",
+    "NA-ER" => "Erongo",
+    "NA-HA" => "Hardap",
+    "NA-KH" => "Khomas",
+    "NA-KU" => "Kunene",
+    "NA-OD" => "Otjozondjupa",
+    "NA-OH" => "Omaheke",
+    "NA-ON" => "Oshana",
+    "NA-OS" => "Omusati",
+    "NA-OT" => "Oshikoto",
+    "NA-OW" => "Ohangwena",
+    "NR-01" => "Aiwo",
+    "NR-02" => "Anabar",
+    "NR-03" => "Anetan",
+    "NR-04" => "Anibare",
+    "NR-06" => "Boe",
+    "NR-07" => "Buada",
+    "NR-08" => "Denigomodu",
+    "NR-09" => "Ewa",
+    "NR-10" => "Ijuw",
+    "NR-11" => "Meneng",
+    "NR-12" => "Nibok",
+    "NR-13" => "Uaboe",
+    "NR-14" => "Yaren",
+    "NP-P4" => "Gandaki",
+    "NP-P6" => "Karnali",
+    "NL-AW" => "Aruba",
+    "NL-BQ1" => "Bonaire",
+    "NL-BQ2" => "Saba",
+    "NL-BQ3" => "Sint Eustatius",
+    "NL-CW" => "Curaçao",
+    "NL-DR" => "Drenthe",
+    "NL-FL" => "Flevoland",
+    "NL-GE" => "Gheldria",
+    "NL-GR" => "Groninga",
+    "NL-LI" => "Limburgo",
+    "NL-NB" => "Brabante settentrionale",
+    "NL-NH" => "Olanda settentrionale",
+    "NL-OV" => "Overijssel",
+    "NL-SX" => "Sint Maarten",
+    "NL-UT" => "Utrecht",
+    "NL-ZE" => "Zelanda",
+    "NL-ZH" => "Olanda meridionale",
+    "NZ-AUK" => "Auckland",
+    "NZ-BOP" => "Bay of Plenty",
+    "NZ-CAN" => "Canterbury",
+    "NZ-CIT" => "Territorio delle Chatham Islands",
+    "NZ-HKB" => "Hawke's Bay",
+    "NZ-MWT" => "Manawatu-Wanganui",
+    "NZ-NTL" => "Northland",
+    "NZ-OTA" => "Otago",
+    "NZ-STL" => "Southland",
+    "NZ-TKI" => "Taranaki",
+    "NZ-WGN" => "Wellington",
+    "NZ-WKO" => "Waikato",
+    "NZ-WTC" => "West Coast",
+    "NI-BO" => "Boaco",
+    "NI-CA" => "Carazo",
+    "NI-CI" => "Chinandega",
+    "NI-CO" => "Chontales",
+    "NI-ES" => "Estelí",
+    "NI-GR" => "Granada",
+    "NI-JI" => "Jinotega",
+    "NI-LE" => "Leon",
+    "NI-MD" => "Madriz",
+    "NI-MN" => "Managua",
+    "NI-MS" => "Masaya",
+    "NI-MT" => "Matagalpa",
+    "NI-NS" => "Nueva Segovia",
+    "NI-RI" => "Rivas",
+    "NI-SJ" => "Rio San Juan",
+    "NE-1" => "Agadez",
+    "NE-2" => "Diffa",
+    "NE-3" => "Dosso",
+    "NE-4" => "Maradi",
+    "NE-5" => "Tahoua",
+    "NE-6" => "Tillabéri",
+    "NE-7" => "Zinder",
+    "NE-8" => "Niamey",
+    "NG-AB" => "Abia",
+    "NG-AD" => "Adamawa",
+    "NG-AK" => "Akwa Ibom",
+    "NG-AN" => "Anambra",
+    "NG-BA" => "Bauchi",
+    "NG-BE" => "Benue",
+    "NG-BO" => "Borno",
+    "NG-BY" => "Bayelsa",
+    "NG-CR" => "Cross River",
+    "NG-DE" => "Delta",
+    "NG-EB" => "Ebonyi",
+    "NG-ED" => "Edo",
+    "NG-EK" => "Ekiti",
+    "NG-EN" => "Enugu",
+    "NG-GO" => "Gombe",
+    "NG-IM" => "Imo",
+    "NG-JI" => "Jigawa",
+    "NG-KD" => "Kaduna",
+    "NG-KE" => "Kebbi",
+    "NG-KN" => "Kano",
+    "NG-KO" => "Kogi",
+    "NG-KT" => "Katsina",
+    "NG-KW" => "Kwara",
+    "NG-LA" => "Lagos",
+    "NG-NI" => "Niger",
+    "NG-OG" => "Ogun",
+    "NG-ON" => "Ondo",
+    "NG-OS" => "Osun",
+    "NG-OY" => "Oyo",
+    "NG-PL" => "Altopiano",
+    "NG-RI" => "Rivers",
+    "NG-SO" => "Sokoto",
+    "NG-TA" => "Taraba",
+    "NG-YO" => "Yobe",
+    "NG-ZA" => "Zamfara",
+    "MK-101" => "Veles",
+    "MK-102" => "Gradsko",
+    "MK-103" => "Demir Kapija",
+    "MK-104" => "Kavadarci",
+    "MK-105" => "Lozovo",
+    "MK-106" => "Negotino",
+    "MK-107" => "Rosoman",
+    "MK-108" => "Sveti Nikole",
+    "MK-109" => "Čaška",
+    "MK-201" => "Berovo",
+    "MK-202" => "Vinica",
+    "MK-203" => "Delčevo",
+    "MK-204" => "Zrnovci",
+    "MK-205" => "Karbinci",
+    "MK-206" => "Kočani",
+    "MK-207" => "Makedonska Kamenica",
+    "MK-208" => "Pehčevo",
+    "MK-209" => "Probištip",
+    "MK-210" => "Češinovo-Obleševo",
+    "MK-211" => "Štip",
+    "MK-301" => "Vevčani",
+    "MK-303" => "Debar",
+    "MK-307" => "Kičevo",
+    "MK-308" => "Makedonski Brod",
+    "MK-310" => "Ohrid",
+    "MK-311" => "Plasnica",
+    "MK-312" => "Struga",
+    "MK-313" => "Centar Župa",
+    "MK-401" => "Bogdanci",
+    "MK-402" => "Bosilovo",
+    "MK-403" => "Valandovo",
+    "MK-404" => "Vasilevo",
+    "MK-405" => "Gevgelija",
+    "MK-406" => "Dojran",
+    "MK-407" => "Konče",
+    "MK-408" => "Novo Selo",
+    "MK-409" => "Radoviš",
+    "MK-410" => "Strumica",
+    "MK-501" => "Bitola",
+    "MK-502" => "Demir Hisar",
+    "MK-503" => "Dolneni",
+    "MK-504" => "Krivogaštani",
+    "MK-505" => "Kruševo",
+    "MK-506" => "Mogila",
+    "MK-507" => "Novaci",
+    "MK-508" => "Prilep",
+    "MK-509" => "Resen",
+    "MK-601" => "Bogovinje",
+    "MK-602" => "Brvenica",
+    "MK-603" => "Vrapčište",
+    "MK-604" => "Gostivar",
+    "MK-605" => "Želino",
+    "MK-606" => "Jegunovce",
+    "MK-608" => "Tearce",
+    "MK-609" => "Tetovo",
+    "MK-701" => "Kratovo",
+    "MK-702" => "Kriva Palanka",
+    "MK-703" => "Kumanovo",
+    "MK-704" => "Lipkovo",
+    "MK-705" => "Rankovce",
+    "MK-706" => "Staro Nagoricane",
+    "MK-802" => "Aračinovo",
+    "MK-806" => "Zelenikovo",
+    "MK-807" => "Ilinden",
+    "MK-810" => "Petrovec",
+    "MK-812" => "Sopište",
+    "MK-813" => "Studeničani",
+    "NO-03" => "Oslo",
+    "NO-11" => "Rogaland",
+    "NO-15" => "Møre og Romsdal",
+    "NO-18" => "Nordland",
+    "NO-21" => "Svalbard (regione artica)",
+    "NO-22" => "Jan Mayen (regione artica)",
+    "OM-BU" => "Al Buraymī",
+    "OM-MA" => "Masqaţ",
+    "OM-MU" => "Musandam",
+    "OM-WU" => "Al Wusta",
+    "OM-ZA" => "Az̧ Z̧āhirah",
+    "OM-ZU" => "Z̧ufār",
+    "PK-BA" => "Balochistan",
+    "PK-GB" => "Gilgit-Baltistan",
+    "PK-IS" => "Islamabad",
+    "PK-KP" => "Khyber Pakhtunkhwa",
+    "PK-PB" => "Punjab",
+    "PK-SD" => "Sindh",
+    "PW-002" => "Aimeliik",
+    "PW-004" => "Airai",
+    "PW-010" => "Angaur",
+    "PW-100" => "Kayangel",
+    "PW-150" => "Koror",
+    "PW-212" => "Melekeok",
+    "PW-214" => "Ngaraard",
+    "PW-218" => "Ngarchelong",
+    "PW-222" => "Ngardmau",
+    "PW-224" => "Ngatpang",
+    "PW-226" => "Ngchesar",
+    "PW-227" => "Ngeremlengui",
+    "PW-228" => "Ngiwal",
+    "PW-350" => "Peleliu",
+    "PW-370" => "Sonsorol",
+    "PS-BTH" => "Betlemme",
+    "PS-DEB" => "Deir El Balah",
+    "PS-GZA" => "Gaza",
+    "PS-HBN" => "Hebron",
+    "PS-JEM" => "Gerusalemme",
+    "PS-JEN" => "Jenin",
+    "PS-KYS" => "Khan Yunis",
+    "PS-NBS" => "Nablus",
+    "PS-NGZ" => "Gaza Nord",
+    "PS-QQA" => "Qalqilya",
+    "PS-RBH" => "Ramallah",
+    "PS-RFH" => "Rafah",
+    "PS-SLT" => "Salfit",
+    "PS-TBS" => "Tubas",
+    "PS-TKM" => "Tulkarm",
+    "PA-1" => "Bocas del Toro",
+    "PA-2" => "Coclé",
+    "PA-3" => "Colòn",
+    "PA-4" => "Chiriquí",
+    "PA-5" => "Darién",
+    "PA-6" => "Herrera",
+    "PA-7" => "Los Santos",
+    "PA-8" => "Panama",
+    "PA-9" => "Veraguas",
+    "PA-EM" => "Emberá",
+    "PA-NB" => "Ngöbe-Buglé",
+    "PG-CPK" => "Simbu",
+    "PG-CPM" => "Centrale",
+    "PG-EBR" => "Nuova Britannia orientale",
+    "PG-EHG" => "Altopiani orientali",
+    "PG-EPW" => "Enga",
+    "PG-ESW" => "Sepik orientale",
+    "PG-GPK" => "Golfo",
+    "PG-MBA" => "Baia Miline",
+    "PG-MPL" => "Morobe",
+    "PG-MPM" => "Madang",
+    "PG-MRL" => "Manus",
+    "PG-NCD" => "Distretto della Capitale Nazionale (Porto Moresby)",
+    "PG-NIK" => "Nuova Irlanda",
+    "PG-NPP" => "Settentrionale",
+    "PG-NSB" => "Bougainville",
+    "PG-SHM" => "Altopiani del Sud",
+    "PG-WBK" => "Nuova Britannia occidentale",
+    "PG-WHM" => "Altopiani occidentali",
+    "PG-WPD" => "Occidentale",
+    "PY-1" => "Concepción",
+    "PY-10" => "Alto Paraná",
+    "PY-11" => "Centrale",
+    "PY-12" => "Ñeembucú",
+    "PY-13" => "Amambay",
+    "PY-14" => "Canindeyú",
+    "PY-15" => "Presidente Hayes",
+    "PY-16" => "Alto Paraguay",
+    "PY-19" => "Boquerón",
+    "PY-2" => "San Pedro",
+    "PY-3" => "Cordillera",
+    "PY-4" => "Guairá",
+    "PY-5" => "Caaguazú",
+    "PY-6" => "Caazapá",
+    "PY-7" => "Itapúa",
+    "PY-8" => "Misiones",
+    "PY-9" => "Paraguarí",
+    "PY-ASU" => "Asunción",
+    "PE-ANC" => "Ancash",
+    "PE-ARE" => "Arequipa",
+    "PE-AYA" => "Ayacucho",
+    "PE-CAJ" => "Cajamarca",
+    "PE-CAL" => "Callao",
+    "PE-HUC" => "Huánuco",
+    "PE-HUV" => "Huancavelica",
+    "PE-ICA" => "Ica",
+    "PE-LAL" => "La Libertad",
+    "PE-LAM" => "Lambayeque",
+    "PE-LIM" => "Lima",
+    "PE-LOR" => "Loreto",
+    "PE-MDD" => "Madre de Dios",
+    "PE-MOQ" => "Moquegua",
+    "PE-PAS" => "Pasco",
+    "PE-PIU" => "Piura",
+    "PE-PUN" => "Puno",
+    "PE-TAC" => "Tacna",
+    "PE-TUM" => "Tumbes",
+    "PE-UCA" => "Ucayali",
+    "PH-00" => "Regione Capitale Nazionale",
+    "PH-01" => "Ilocos (Regione I)",
+    "PH-02" => "Cagayan Valley (Regione II)",
+    "PH-03" => "Luzon centrale (Regione III)",
+    "PH-05" => "Bicol (Regione V)",
+    "PH-06" => "Visayas occidentale (Regione VI)",
+    "PH-07" => "Visayas centrale (Regione VII)",
+    "PH-08" => "Visayas orientale (Regione VIII)",
+    "PH-09" => "Penisola della Zamboanga (Regione IX)",
+    "PH-10" => "Mindanao settentrionale (Regione X)",
+    "PH-11" => "Davao (Regione XI)",
+    "PH-12" => "Soccsksargen (Regione XII)",
+    "PH-13" => "Caraga (Regione XIII)",
+    "PH-14" => "Regione autonoma del Mindanao musulmano (ARMM)",
+    "PH-15" => "Regione Cordigliera amministrativa (CAR)",
+    "PH-ABR" => "Abra",
+    "PH-AGN" => "Agusan del Nord",
+    "PH-AGS" => "Agusan del Sud",
+    "PH-AKL" => "Aklan",
+    "PH-ALB" => "Albay",
+    "PH-ANT" => "Antique",
+    "PH-APA" => "Apayao",
+    "PH-AUR" => "Aurora",
+    "PH-BAS" => "Basilan",
+    "PH-BEN" => "Benguet",
+    "PH-BIL" => "Biliran",
+    "PH-BOH" => "Bohol",
+    "PH-BTG" => "Batangas",
+    "PH-BTN" => "Batanes",
+    "PH-BUK" => "Bukidnon",
+    "PH-BUL" => "Bulacan",
+    "PH-CAG" => "Cagayan",
+    "PH-CAM" => "Camiguin",
+    "PH-CAN" => "Camarines Nord",
+    "PH-CAP" => "Capiz",
+    "PH-CAS" => "Camarines Sud",
+    "PH-CAT" => "Catanduanes",
+    "PH-CAV" => "Cavite",
+    "PH-CEB" => "Cebu",
+    "PH-DAO" => "Davao orientale",
+    "PH-DAS" => "Davao del Sud",
+    "PH-DAV" => "Davao del Nord",
+    "PH-DIN" => "Isole Dinagat",
+    "PH-EAS" => "Samar orientale",
+    "PH-GUI" => "Guimaras",
+    "PH-IFU" => "Ifugao",
+    "PH-ILI" => "Iloilo",
+    "PH-ILN" => "Ilocos Nord",
+    "PH-ILS" => "Ilocos Sud",
+    "PH-ISA" => "Isabela",
+    "PH-LAG" => "Laguna",
+    "PH-LAN" => "Lanao del Nord",
+    "PH-LAS" => "Lanao del Sud",
+    "PH-LEY" => "Leyte",
+    "PH-LUN" => "La Union",
+    "PH-MAD" => "Marinduque",
+    "PH-MAS" => "Masbate",
+    "PH-MDC" => "Mindoro occidentale",
+    "PH-MDR" => "Mindoro orientale",
+    "PH-MOU" => "Mountain Province",
+    "PH-MSC" => "Misamis occidentale",
+    "PH-MSR" => "Misamis orientale",
+    "PH-NEC" => "Negros occidentale",
+    "PH-NER" => "Negros orientale",
+    "PH-NSA" => "Samar settentrionale",
+    "PH-NUE" => "Nueva Ecija",
+    "PH-NUV" => "Nueva Vizcaya",
+    "PH-PAM" => "Pampanga",
+    "PH-PAN" => "Pangasinan",
+    "PH-PLW" => "Palawan",
+    "PH-QUE" => "Quezon",
+    "PH-QUI" => "Quirino",
+    "PH-RIZ" => "Rizal",
+    "PH-ROM" => "Romblon",
+    "PH-SAR" => "Sarangani",
+    "PH-SCO" => "Cotabato Sud",
+    "PH-SIG" => "Siquijor",
+    "PH-SLE" => "Leyte meridionale",
+    "PH-SLU" => "Sulu",
+    "PH-SOR" => "Sorsogon",
+    "PH-SUK" => "Sultan Kudarat",
+    "PH-SUN" => "Surigao del Nord",
+    "PH-SUR" => "Surigao del Sud",
+    "PH-TAR" => "Tarlac",
+    "PH-TAW" => "Tawi-Tawi",
+    "PH-ZAN" => "Zamboanga del Nord",
+    "PH-ZAS" => "Zamboanga del Sud",
+    "PH-ZMB" => "Zambales",
+    "PH-ZSI" => "Zamboanga Sibugay",
+    "PL-02" => "Bassa Slesia",
+    "PL-04" => "Cuiavia-Pomerania",
+    "PL-06" => "Lublino",
+    "PL-08" => "Lebus",
+    "PL-10" => "Łódz",
+    "PL-12" => "Piccola Polonia",
+    "PL-14" => "Masovia",
+    "PL-16" => "Opole",
+    "PL-18" => "Precarpazi",
+    "PL-20" => "Podlachia",
+    "PL-22" => "Pomerania",
+    "PL-24" => "Slesia",
+    "PL-26" => "Santacroce",
+    "PL-28" => "Varmia-Masuria",
+    "PL-30" => "Grande Polonia",
+    "PL-32" => "Pomerania occidentale",
+    "PT-01" => "Aveiro",
+    "PT-02" => "Beja",
+    "PT-03" => "Braga",
+    "PT-04" => "Bragança",
+    "PT-05" => "Castelo Branco",
+    "PT-06" => "Coimbra",
+    "PT-07" => "Évora",
+    "PT-08" => "Faro",
+    "PT-09" => "Guarda",
+    "PT-10" => "Leiria",
+    "PT-11" => "Lisbona",
+    "PT-12" => "Portalegre",
+    "PT-13" => "Oporto",
+    "PT-14" => "Santarém",
+    "PT-15" => "Setúbal",
+    "PT-16" => "Viana do Castelo",
+    "PT-17" => "Vila Real",
+    "PT-18" => "Viseu",
+    "PT-20" => "Regione autonoma delle Azorre",
+    "PT-30" => "Regione autonoma di Madeira",
+    "QA-KH" => "Al Khawr wa adh Dhakhīrah",
+    "QA-WA" => "Al Wakrah",
+    "QA-ZA" => "Az̧ Z̧a‘āyin",
+    "RO-AB" => "Alba",
+    "RO-AG" => "Argeș",
+    "RO-AR" => "Arad",
+    "RO-B" => "Bucarest",
+    "RO-BC" => "Bacău",
+    "RO-BH" => "Bihor",
+    "RO-BN" => "Bistrița-Năsăud",
+    "RO-BR" => "Brăila",
+    "RO-BT" => "Botoșani",
+    "RO-BV" => "Brașov",
+    "RO-BZ" => "Buzău",
+    "RO-CJ" => "Cluj",
+    "RO-CL" => "Călărași",
+    "RO-CS" => "Caraș-Severin",
+    "RO-CT" => "Constanța",
+    "RO-CV" => "Covasna",
+    "RO-DB" => "Dâmbovița",
+    "RO-DJ" => "Dolj",
+    "RO-GJ" => "Gorj",
+    "RO-GL" => "Galați",
+    "RO-GR" => "Giurgiu",
+    "RO-HD" => "Hunedoara",
+    "RO-HR" => "Harghita",
+    "RO-IF" => "Ilfov",
+    "RO-IL" => "Ialomița",
+    "RO-IS" => "Iași",
+    "RO-MH" => "Mehedinți",
+    "RO-MM" => "Maramureș",
+    "RO-MS" => "Mureș",
+    "RO-NT" => "Neamț",
+    "RO-OT" => "Olt",
+    "RO-PH" => "Prahova",
+    "RO-SB" => "Sibiu",
+    "RO-SJ" => "Sălaj",
+    "RO-SM" => "Satu Mare",
+    "RO-SV" => "Suceava",
+    "RO-TL" => "Tulcea",
+    "RO-TM" => "Timiș",
+    "RO-TR" => "Teleorman",
+    "RO-VL" => "Vâlcea",
+    "RO-VN" => "Vrancea",
+    "RO-VS" => "Vaslui",
+    "RU-BA" => "Repubblica del Baskortostan",
+    "RU-CE" => "Repubblica Cecena",
+    "RU-CHE" => "Oblast' di Celjabinsk",
+    "RU-CHU" => "Okrug autonomo di Chukotka",
+    "RU-CU" => "Chuvashsk",
+    "RU-DA" => "Daghestan",
+    "RU-KAM" => "Krai di Kamchatka",
+    "RU-KC" => "Karacaj-Circassia",
+    "RU-KLU" => "Oblast' di Kaluga",
+    "RU-KO" => "Repubblica dei Komi",
+    "RU-MOW" => "Mosca",
+    "RU-NIZ" => "Oblast' di Nižnij Novgorod",
+    "RU-OMS" => "Oblast' di Omsk",
+    "RU-ORE" => "Oblast' di Orenburg",
+    "RU-PNZ" => "Oblast' di Penza",
+    "RU-PSK" => "Oblast' di Pskov",
+    "RU-ROS" => "Oblast' di Rostov",
+    "RU-RYA" => "Oblast' di Rjazan",
+    "RU-SAK" => "Oblast' di Sachalin",
+    "RU-SAM" => "Oblast' di Samara",
+    "RU-SAR" => "Oblast' di Saratov",
+    "RU-SMO" => "Oblast' di Smolensk",
+    "RU-SPE" => "San Pietroburgo",
+    "RU-SVE" => "Oblast' di Sverdlovsk",
+    "RU-TA" => "Repubblica del Tatarstan",
+    "RU-TAM" => "Oblast' di Tambov",
+    "RU-TOM" => "Oblast' di Tomsk",
+    "RU-TUL" => "Oblast' di Tula",
+    "RU-TVE" => "Oblast' di Tver",
+    "RU-TYU" => "Oblast' di Tjumen",
+    "RU-VGG" => "Oblast' di Volgograd",
+    "RU-VLA" => "Oblast' di Vladimir",
+    "RU-VLG" => "Oblast' di Vologda",
+    "RU-VOR" => "Oblast' di Voronež",
+    "RU-YAR" => "Oblast' di Jaroslavl",
+    "RU-ZAB" => "Krai di Zabajkal'",
+    "RW-02" => "Orientale",
+    "RW-03" => "Settentrionale",
+    "RW-04" => "Occidentale",
+    "RW-05" => "Meridionale (Botswana)",
+    "SH-AC" => "Ascension",
+    "SH-HL" => "Sant'Elena",
+    "SH-TA" => "Tristan da Cunha",
+    "KN-01" => "Christ Church Nichola Town",
+    "KN-02" => "Saint Anne Sandy Point",
+    "KN-03" => "Saint George Basseterre",
+    "KN-04" => "Saint George Gingerland",
+    "KN-05" => "Saint James Windward",
+    "KN-06" => "Saint John Capisterre",
+    "KN-07" => "Saint John Figtree",
+    "KN-08" => "Saint Mary Cayon",
+    "KN-09" => "Saint Paul Capisterre",
+    "KN-10" => "Saint Paul Charlestown",
+    "KN-11" => "Saint Peter Basseterre",
+    "KN-12" => "Saint Thomas Lowland",
+    "KN-13" => "Saint Thomas Middle Island",
+    "KN-15" => "Trinity Palmetto Point",
+    "KN-K" => "Saint Kitts",
+    "KN-N" => "Nevis",
+    "LC-03" => "Choiseul",
+    "LC-12" => "Canarie",
+    "VC-01" => "Charlotte",
+    "VC-02" => "Saint Andrew",
+    "VC-03" => "Saint David",
+    "VC-04" => "Saint George",
+    "VC-05" => "Saint Patrick",
+    "VC-06" => "Grenadine",
+    "WS-AA" => "A'ana",
+    "WS-AL" => "Aiga-i-le-Tai",
+    "WS-AT" => "Atua",
+    "WS-FA" => "Fa'asaleleaga",
+    "WS-GE" => "Gaga'emauga",
+    "WS-GI" => "Gagaifomauga",
+    "WS-PA" => "Palauli",
+    "WS-SA" => "Satupa'itea",
+    "WS-TU" => "Tuamasaga",
+    "WS-VF" => "Va'a-o-Fonoti",
+    "WS-VS" => "Vaisigano",
+    "SM-01" => "Acquaviva",
+    "SM-02" => "Chiesanuova",
+    "SM-03" => "Domagnano",
+    "SM-04" => "Faetano",
+    "SM-05" => "Fiorentino",
+    "SM-06" => "Borgo Maggiore",
+    "SM-07" => "San Marino",
+    "SM-08" => "Montegiardino",
+    "SM-09" => "Serravalle",
+    "ST-P" => "Principe",
+    "SA-04" => "Sharkia",
+    "SA-05" => "Al Qasim",
+    "SA-07" => "Tabuk",
+    "SA-10" => "Najran",
+    "SA-12" => "Al Jawf",
+    "SN-DB" => "Diourbel",
+    "SN-DK" => "Dakar",
+    "SN-FK" => "Fatick",
+    "SN-KA" => "Kaffrine",
+    "SN-KD" => "Kolda",
+    "SN-KE" => "Kédougou",
+    "SN-KL" => "Kaolack",
+    "SN-LG" => "Louga",
+    "SN-MT" => "Matam",
+    "SN-SE" => "Sédhiou",
+    "SN-SL" => "Saint-Louis",
+    "SN-TC" => "Tambacounda",
+    "SN-TH" => "Thiès",
+    "SN-ZG" => "Ziguinchor",
+    "RS-00" => "Belgrado",
+    "RS-01" => "Severnobački okrug",
+    "RS-02" => "Srednjebanatski okrug",
+    "RS-03" => "Severnobanatski okrug",
+    "RS-04" => "Južnobanatski okrug",
+    "RS-05" => "Zapadnobački okrug",
+    "RS-06" => "Južnobački okrug",
+    "RS-07" => "Sremski okrug",
+    "RS-08" => "Mačvanski okrug",
+    "RS-09" => "Kolubarski okrug",
+    "RS-10" => "Podunavski okrug",
+    "RS-11" => "Braničevski okrug",
+    "RS-12" => "Šumadijski okrug",
+    "RS-13" => "Pomoravski okrug",
+    "RS-14" => "Borski okrug",
+    "RS-15" => "Zaječarski okrug",
+    "RS-16" => "Zlatiborski okrug",
+    "RS-17" => "Moravički okrug",
+    "RS-18" => "Raški okrug",
+    "RS-19" => "Rasinski okrug",
+    "RS-20" => "Nišavski okrug",
+    "RS-21" => "Toplički okrug",
+    "RS-22" => "Pirotski okrug",
+    "RS-23" => "Jablanički okrug",
+    "RS-24" => "Pčinjski okrug",
+    "RS-25" => "Kosovski okrug",
+    "RS-26" => "Pećki okrug",
+    "RS-27" => "Prizrenski okrug",
+    "RS-28" => "Kosovsko-Mitrovački okrug",
+    "RS-29" => "Kosovsko-Pomoravski okrug",
+    "RS-KM" => "Kosovo e Metohija",
+    "RS-VO" => "Vojvodina",
+    "SC-01" => "Anse aux Pins",
+    "SC-02" => "Anse Boileau",
+    "SC-03" => "Anse Etoile",
+    "SC-05" => "Anse Royale",
+    "SC-06" => "Baie Lazare",
+    "SC-07" => "Baie Sainte Anne",
+    "SC-08" => "Beau Vallon",
+    "SC-09" => "Bel Air",
+    "SC-10" => "Bel Ombre",
+    "SC-11" => "Cascade",
+    "SC-12" => "Glacis",
+    "SC-13" => "Grand Anse Mahe",
+    "SC-14" => "Grand Anse Praslin",
+    "SC-15" => "La Digue",
+    "SC-16" => "English River",
+    "SC-17" => "Mont Buxton",
+    "SC-18" => "Mont Fleuri",
+    "SC-19" => "Plaisance",
+    "SC-20" => "Pointe Larue",
+    "SC-21" => "Port Glaud",
+    "SC-22" => "Saint Louis",
+    "SC-23" => "Takamaka",
+    "SC-24" => "Les Mamelles",
+    "SC-25" => "Roche Caiman",
+    "SL-E" => "Orientale",
+    "SL-N" => "Settentrionale",
+    "SL-S" => "Meridionale (Botswana)",
+    "SL-W" => "Area occidentale (Freetown)",
+    "SG-01" => "Singapore centro",
+    "SG-02" => "Nord Est",
+    "SG-03" => "Nord Ovest",
+    "SG-04" => "Sud Est",
+    "SG-05" => "Sud Ovest",
+    "SK-BC" => "Regione di Banskà Bystrica",
+    "SK-BL" => "Regione di Bratislava",
+    "SK-KI" => "Regione di Košice",
+    "SK-NI" => "Regione di Nitra",
+    "SK-PV" => "Regione di Prešov",
+    "SK-TA" => "Regione di Trnava",
+    "SK-TC" => "Regione di Trenčin",
+    "SK-ZI" => "Regione di Žilina",
+    "SI-001" => "Aidussina",
+    "SI-002" => "Beltinci",
+    "SI-003" => "Bled",
+    "SI-004" => "Bohinj",
+    "SI-005" => "Borovnica",
+    "SI-006" => "Plezzo",
+    "SI-007" => "Collio",
+    "SI-008" => "Brezovica",
+    "SI-009" => "Brežice",
+    "SI-010" => "Tišina",
+    "SI-011" => "Celje",
+    "SI-012" => "Cerklje na Gorenjskem",
+    "SI-013" => "Circonico",
+    "SI-014" => "Circhina",
+    "SI-015" => "Črenšovci",
+    "SI-016" => "Črna na Koroškem",
+    "SI-017" => "Črnomelj",
+    "SI-018" => "Destrnik",
+    "SI-019" => "Divaccia",
+    "SI-020" => "Dobrepolje",
+    "SI-021" => "Dobrova-Polhov Gradec",
+    "SI-022" => "Dol pri Ljubljani",
+    "SI-023" => "Domžale",
+    "SI-024" => "Dornava",
+    "SI-025" => "Dravograd",
+    "SI-026" => "Duplek",
+    "SI-027" => "Gorenja vas-Poljane",
+    "SI-028" => "Gorišnica",
+    "SI-029" => "Gornja Radgona",
+    "SI-030" => "Gornji Grad",
+    "SI-031" => "Gornji Petrovci",
+    "SI-032" => "Grosuplje",
+    "SI-033" => "Šalovci",
+    "SI-034" => "Hrastnik",
+    "SI-035" => "Erpelle-Cosina",
+    "SI-036" => "Idria",
+    "SI-037" => "Ig",
+    "SI-038" => "Villa del Nevoso",
+    "SI-039" => "Ivančna Gorica",
+    "SI-041" => "Jesenice",
+    "SI-042" => "Juršinci",
+    "SI-043" => "Kamnik",
+    "SI-044" => "Canale d'Isonzo",
+    "SI-045" => "Kidričevo",
+    "SI-046" => "Caporetto",
+    "SI-047" => "Kobilje",
+    "SI-048" => "Kočevje",
+    "SI-049" => "Comeno",
+    "SI-051" => "Kozje",
+    "SI-052" => "Kranj",
+    "SI-053" => "Kranjska Gora",
+    "SI-054" => "Krško",
+    "SI-055" => "Kungota",
+    "SI-056" => "Kuzma",
+    "SI-057" => "Laško",
+    "SI-058" => "Lenart",
+    "SI-060" => "Litija",
+    "SI-061" => "Lubiana",
+    "SI-062" => "Ljubno",
+    "SI-063" => "Ljutomer",
+    "SI-064" => "Logatec",
+    "SI-065" => "Loška Dolina",
+    "SI-066" => "Loški Potok",
+    "SI-067" => "Luče",
+    "SI-068" => "Lukovica",
+    "SI-069" => "Majšperk",
+    "SI-070" => "Maribor",
+    "SI-071" => "Medvode",
+    "SI-072" => "Mengeš",
+    "SI-073" => "Metlika",
+    "SI-074" => "Mežica",
+    "SI-075" => "Merna-Castagnevizza",
+    "SI-076" => "Mislinja",
+    "SI-077" => "Moravče",
+    "SI-078" => "Moravske Toplice",
+    "SI-079" => "Mozirje",
+    "SI-080" => "Murska Sobota",
+    "SI-081" => "Muta",
+    "SI-082" => "Naklo",
+    "SI-083" => "Nazarje",
+    "SI-084" => "Nova Gorica",
+    "SI-086" => "Odranci",
+    "SI-087" => "Ormož",
+    "SI-088" => "Osilnica",
+    "SI-089" => "Pesnica",
+    "SI-091" => "San Pietro del Carso",
+    "SI-092" => "Podčetrtek",
+    "SI-093" => "Podvelka",
+    "SI-094" => "Postumia",
+    "SI-095" => "Preddvor",
+    "SI-096" => "Ptuj",
+    "SI-097" => "Puconci",
+    "SI-098" => "Rače-Fram",
+    "SI-099" => "Radeče",
+    "SI-100" => "Radenci",
+    "SI-101" => "Radlje ob Dravi",
+    "SI-102" => "Radovljica",
+    "SI-103" => "Ravne na Koroškem",
+    "SI-104" => "Ribnica",
+    "SI-105" => "Rogašovci",
+    "SI-106" => "Rogaška Slatina",
+    "SI-107" => "Rogatec",
+    "SI-108" => "Ruše",
+    "SI-109" => "Semič",
+    "SI-110" => "Sevnica",
+    "SI-111" => "Sesana",
+    "SI-112" => "Slovenj Gradec",
+    "SI-113" => "Slovenska Bistrica",
+    "SI-114" => "Slovenske Konjice",
+    "SI-117" => "Šenčur",
+    "SI-118" => "Šentilj",
+    "SI-119" => "Šentjernej",
+    "SI-120" => "Šentjur",
+    "SI-121" => "Škocjan",
+    "SI-122" => "Škofja Loka",
+    "SI-123" => "Škofljica",
+    "SI-124" => "Šmarje pri Jelšah",
+    "SI-125" => "Šmartno ob Paki",
+    "SI-126" => "Šoštanj",
+    "SI-127" => "Štore",
+    "SI-128" => "Tolmino",
+    "SI-129" => "Trbovlje",
+    "SI-130" => "Trebnje",
+    "SI-131" => "Tržič",
+    "SI-132" => "Turnišče",
+    "SI-133" => "Velenje",
+    "SI-134" => "Velike Lašče",
+    "SI-135" => "Videm",
+    "SI-136" => "Vipacco",
+    "SI-137" => "Vitanje",
+    "SI-138" => "Vodice",
+    "SI-139" => "Vojnik",
+    "SI-140" => "Vrhnika",
+    "SI-141" => "Vuzenica",
+    "SI-142" => "Zagorje ob Savi",
+    "SI-143" => "Zavrč",
+    "SI-144" => "Zreče",
+    "SI-146" => "Železniki",
+    "SI-147" => "Žiri",
+    "SI-148" => "Benedikt",
+    "SI-149" => "Bistrica ob Sotli",
+    "SI-150" => "Bloke",
+    "SI-151" => "Braslovče",
+    "SI-152" => "Cankova",
+    "SI-153" => "Cerkvenjak",
+    "SI-154" => "Dobje",
+    "SI-155" => "Dobrna",
+    "SI-157" => "Dolenjske Toplice",
+    "SI-158" => "Grad",
+    "SI-159" => "Hajdina",
+    "SI-160" => "Hoče-Slivnica",
+    "SI-162" => "Horjul",
+    "SI-163" => "Jezersko",
+    "SI-164" => "Komenda",
+    "SI-165" => "Kostel",
+    "SI-166" => "Križevci",
+    "SI-167" => "Lovrenc na Pohorju",
+    "SI-168" => "Markovci",
+    "SI-169" => "Miklavž na Dravskem polju",
+    "SI-170" => "Mirna Peč",
+    "SI-171" => "Oplotnica",
+    "SI-172" => "Podlehnik",
+    "SI-173" => "Polzela",
+    "SI-174" => "Prebold",
+    "SI-175" => "Prevalje",
+    "SI-176" => "Razkrižje",
+    "SI-177" => "Ribnica na Pohorju",
+    "SI-178" => "Selnica ob Dravi",
+    "SI-179" => "Sodražica",
+    "SI-180" => "Solčava",
+    "SI-181" => "Sveta Ana",
+    "SI-183" => "San Pietro-Vertoiba",
+    "SI-184" => "Tabor",
+    "SI-186" => "Trzin",
+    "SI-187" => "Velika Polana",
+    "SI-188" => "Veržej",
+    "SI-189" => "Vransko",
+    "SI-190" => "Žalec",
+    "SI-191" => "Žetale",
+    "SI-192" => "Žirovnica",
+    "SI-193" => "Žužemberk",
+    "SI-194" => "Šmartno pri Litiji",
+    "SI-195" => "Apače",
+    "SI-196" => "Cirkulane",
+    "SI-197" => "Kosanjevica na Krki",
+    "SI-198" => "Makole",
+    "SI-199" => "Mokronog-Trebelno",
+    "SI-200" => "Poljčane",
+    "SI-201" => "Renče-Vogrsko",
+    "SI-202" => "Središče ob Dravi",
+    "SI-203" => "Straža",
+    "SI-205" => "Sveti Tomaž",
+    "SI-207" => "Gorje",
+    "SI-208" => "Log-Dragomer",
+    "SI-209" => "Rečica ob Savinji",
+    "SI-211" => "Šentrupert",
+    "SB-CE" => "Centrale",
+    "SB-CH" => "Choiseul",
+    "SB-CT" => "Territorio della Capitale (Honiara)",
+    "SB-GU" => "Guadalcanal",
+    "SB-IS" => "Isabel",
+    "SB-ML" => "Malaita",
+    "SB-RB" => "Rennell e Bellona",
+    "SB-TE" => "Temotu",
+    "SB-WE" => "Occidentale",
+    "SO-AW" => "Awdal",
+    "SO-BK" => "Bakool",
+    "SO-BN" => "Banaadir",
+    "SO-BR" => "Bari",
+    "SO-BY" => "Bay",
+    "SO-GA" => "Galguduud",
+    "SO-GE" => "Gedo",
+    "SO-JD" => "Jubbada Dhexe",
+    "SO-JH" => "Jubbada Hoose",
+    "SO-MU" => "Mudug",
+    "SO-NU" => "Nugaal",
+    "SO-SD" => "Shabeellaha Dhexe",
+    "SO-SH" => "Shabeellaha Hoose",
+    "SO-SO" => "Sool",
+    "SO-TO" => "Togdheer",
+    "SO-WO" => "Woqooyi Galbeed",
+    "ZA-EC" => "Capo orientale",
+    "ZA-FS" => "Free State",
+    "ZA-GP" => "Gauteng",
+    "ZA-KZN" => "Kwazulu-Natal",
+    "ZA-LP" => "Limpopo",
+    "ZA-MP" => "Mpumalanga",
+    "ZA-NC" => "Capo settentrionale",
+    "ZA-NW" => "Nordoccidentale (Botswana)",
+    "ZA-WC" => "Capo orientale",
+    "SS-EC" => "Equatoria centrale",
+    "SS-EE" => "Equatoria orientale",
+    "SS-EW" => "Equatoria occidentale",
+    "SS-JG" => "Jonglei",
+    "SS-LK" => "Lakes",
+    "SS-NU" => "Nilo superiore",
+    "SS-UY" => "Unity",
+    "SS-WR" => "Warrap",
+    "ES-AB" => "Albacete",
+    "ES-AL" => "Almeria",
+    "ES-AN" => "Andalusia",
+    "ES-AR" => "Aragona",
+    "ES-AS" => "Asturie",
+    "ES-AV" => "Ávila",
+    "ES-BA" => "Badajoz",
+    "ES-BI" => "Biscaglia",
+    "ES-BU" => "Burgos",
+    "ES-CA" => "Cadice",
+    "ES-CB" => "Cantabria",
+    "ES-CC" => "Caceres",
+    "ES-CE" => "Ceuta",
+    "ES-CL" => "Castiglia e Leon",
+    "ES-CM" => "Castiglia-La Mancia",
+    "ES-CN" => "Canarie",
+    "ES-CO" => "Cordoba",
+    "ES-CR" => "Ciudad Real",
+    "ES-CU" => "Cuenca",
+    "ES-EX" => "Estremadura",
+    "ES-GC" => "Las Palmas",
+    "ES-GR" => "Granada",
+    "ES-GU" => "Guadalajara",
+    "ES-H" => "Huelva",
+    "ES-HU" => "Huesca",
+    "ES-J" => "Jaen",
+    "ES-LE" => "Leon",
+    "ES-LO" => "La Rioja",
+    "ES-M" => "Madrid",
+    "ES-MA" => "Malaga",
+    "ES-MC" => "Murcia",
+    "ES-MD" => "Madrid",
+    "ES-ML" => "Melilla",
+    "ES-MU" => "Murcia",
+    "ES-O" => "Asturie",
+    "ES-P" => "Palencia",
+    "ES-RI" => "La Rioja",
+    "ES-S" => "Cantabria",
+    "ES-SA" => "Salamanca",
+    "ES-SE" => "Siviglia",
+    "ES-SG" => "Segovia",
+    "ES-SO" => "Soria",
+    "ES-SS" => "Guipúzcoa",
+    "ES-TE" => "Teruel",
+    "ES-TF" => "Santa Cruz de Tenerife",
+    "ES-TO" => "Toledo",
+    "ES-VA" => "Valladolid",
+    "ES-Z" => "Saragozza",
+    "ES-ZA" => "Zamora",
+    "LK-12" => "Gampaha",
+    "LK-81" => "Badulla",
+    "LK-91" => "Ratnapura",
+    "SD-KH" => "Khartum",
+    "SD-NO" => "Settentrionale",
+    "SR-BR" => "Brokopondo",
+    "SR-CM" => "Commewijne",
+    "SR-CR" => "Coronie",
+    "SR-MA" => "Marowijne",
+    "SR-NI" => "Nickerie",
+    "SR-PM" => "Paramaribo",
+    "SR-PR" => "Para",
+    "SR-SA" => "Saramacca",
+    "SR-SI" => "Sipaliwini",
+    "SR-WA" => "Wanica",
+    "CH-AG" => "Argovia",
+    "CH-AI" => "Appenzello Interno",
+    "CH-AR" => "Appenzello Esterno",
+    "CH-BE" => "Berna",
+    "CH-BL" => "Basilea Campagna",
+    "CH-BS" => "Basilea Città",
+    "CH-GE" => "Ginevra",
+    "CH-GL" => "Glarona",
+    "CH-GR" => "Grigioni",
+    "CH-JU" => "Giura",
+    "CH-LU" => "Lucerna",
+    "CH-NE" => "Neuchâtel",
+    "CH-NW" => "Nidvaldo",
+    "CH-OW" => "Obvaldo",
+    "CH-SG" => "San Gallo",
+    "CH-SH" => "Sciaffusa",
+    "CH-SO" => "Soletta",
+    "CH-SZ" => "Svitto",
+    "CH-TG" => "Turgovia",
+    "CH-TI" => "Ticino",
+    "CH-UR" => "Uri",
+    "CH-VD" => "Vaud",
+    "CH-VS" => "Vallese",
+    "CH-ZG" => "Zugo",
+    "CH-ZH" => "Zurigo",
+    "SY-DI" => "Damasco",
+    "SY-DY" => "Deir ez-Zor",
+    "SY-ID" => "Idlib",
+    "SY-RA" => "Ar Raqqah",
+    "TW-CHA" => "Changhua",
+    "TW-CYI" => "Chiayi",
+    "TW-CYQ" => "Chiayi",
+    "TW-HSQ" => "Hsinchu",
+    "TW-HSZ" => "Hsinchu",
+    "TW-HUA" => "Hualien",
+    "TW-KHH" => "Kaohsiung",
+    "TW-MIA" => "Miaoli",
+    "TW-NAN" => "Nantou",
+    "TW-PEN" => "Penghu",
+    "TW-PIF" => "Pingtung",
+    "TW-TAO" => "Taoyuan",
+    "TW-TNN" => "Tainan",
+    "TW-TPE" => "Taipei",
+    "TW-TTT" => "Taitung",
+    "TW-TXG" => "Taichung",
+    "TW-YUN" => "Yunlin",
+    "TJ-DU" => "Dushanbe",
+    "TJ-KT" => "Chatlon",
+    "TJ-SU" => "Sugd",
+    "TZ-01" => "Arusha",
+    "TZ-02" => "Dar es Salaam",
+    "TZ-03" => "Dodoma",
+    "TZ-04" => "Iringa",
+    "TZ-05" => "Kagera",
+    "TZ-08" => "Kigoma",
+    "TZ-09" => "Kilimanjaro",
+    "TZ-12" => "Lindi",
+    "TZ-13" => "Mara",
+    "TZ-14" => "Mbeya",
+    "TZ-16" => "Morogoro",
+    "TZ-17" => "Mtwara",
+    "TZ-18" => "Mwanza",
+    "TZ-20" => "Rukwa",
+    "TZ-21" => "Ruvuma",
+    "TZ-22" => "Shinyanga",
+    "TZ-23" => "Singida",
+    "TZ-24" => "Tabora",
+    "TZ-25" => "Tanga",
+    "TZ-26" => "Manyara",
+    "TH-11" => "Samut Prakan",
+    "TH-12" => "Nonthaburi",
+    "TH-13" => "Pathum Thani",
+    "TH-14" => "Phra Nakhon Si Ayutthaya",
+    "TH-15" => "Ang Thong",
+    "TH-16" => "Lop Buri",
+    "TH-17" => "Sing Buri",
+    "TH-18" => "Chai Nat",
+    "TH-19" => "Saraburi",
+    "TH-20" => "Chon Buri",
+    "TH-21" => "Rayong",
+    "TH-22" => "Chanthaburi",
+    "TH-23" => "Trat",
+    "TH-24" => "Chachoengsao",
+    "TH-25" => "Prachin Buri",
+    "TH-26" => "Nakhon Nayok",
+    "TH-27" => "Sa Kaeo",
+    "TH-30" => "Nakhon Ratchasima",
+    "TH-31" => "Buri Ram",
+    "TH-32" => "Surin",
+    "TH-33" => "Si Sa Ket",
+    "TH-34" => "Ubon Ratchathani",
+    "TH-35" => "Yasothon",
+    "TH-36" => "Chaiyaphum",
+    "TH-37" => "Amnat Charoen",
+    "TH-39" => "Nong Bua Lam Phu",
+    "TH-40" => "Khon Kaen",
+    "TH-41" => "Udon Thani",
+    "TH-42" => "Loei",
+    "TH-43" => "Nong Khai",
+    "TH-44" => "Maha Sarakham",
+    "TH-45" => "Roi Et",
+    "TH-46" => "Kalasin",
+    "TH-47" => "Sakon Nakhon",
+    "TH-48" => "Nakhon Phanom",
+    "TH-49" => "Mukdahan",
+    "TH-50" => "Chiang Mai",
+    "TH-51" => "Lamphun",
+    "TH-52" => "Lampang",
+    "TH-53" => "Uttaradit",
+    "TH-54" => "Phrae",
+    "TH-55" => "Nan",
+    "TH-56" => "Phayao",
+    "TH-57" => "Chiang Rai",
+    "TH-58" => "Mae Hong Son",
+    "TH-60" => "Nakhon Sawan",
+    "TH-61" => "Uthai Thani",
+    "TH-62" => "Kamphaeng Phet",
+    "TH-63" => "Tak",
+    "TH-64" => "Sukhothai",
+    "TH-65" => "Phitsanulok",
+    "TH-66" => "Phichit",
+    "TH-67" => "Phetchabun",
+    "TH-70" => "Ratchaburi",
+    "TH-71" => "Kanchanaburi",
+    "TH-72" => "Suphan Buri",
+    "TH-73" => "Nakhon Pathom",
+    "TH-74" => "Samut Sakhon",
+    "TH-75" => "Samut Songkhram",
+    "TH-76" => "Phetchaburi",
+    "TH-77" => "Prachuap Khiri Khan",
+    "TH-80" => "Nakhon Si Thammarat",
+    "TH-81" => "Krabi",
+    "TH-82" => "Phangnga",
+    "TH-83" => "Phuket",
+    "TH-84" => "Surat Thani",
+    "TH-85" => "Ranong",
+    "TH-86" => "Chumphon",
+    "TH-90" => "Songkhla",
+    "TH-91" => "Satun",
+    "TH-92" => "Trang",
+    "TH-93" => "Phatthalung",
+    "TH-94" => "Pattani",
+    "TH-95" => "Yala",
+    "TH-96" => "Narathiwat",
+    "TH-S" => "Pattaya",
+    "TL-AL" => "Aileu",
+    "TL-AN" => "Ainaro",
+    "TL-BA" => "Baucau",
+    "TL-BO" => "Bobonaro",
+    "TL-CO" => "Cova-Lima",
+    "TL-DI" => "Dili",
+    "TL-ER" => "Ermera",
+    "TL-MF" => "Manufahi",
+    "TL-MT" => "Manatuto",
+    "TG-P" => "Altopiani (Congo)",
+    "TO-01" => "'Eua",
+    "TO-02" => "Ha'apai",
+    "TO-03" => "Niuas",
+    "TO-04" => "Tongatapu",
+    "TO-05" => "Vava'u",
+    "TT-ARI" => "Arima",
+    "TT-CHA" => "Chaguanas",
+    "TT-CTT" => "Couva-Tabaquite-Talparo",
+    "TT-DMN" => "Diego Martin",
+    "TT-PED" => "Penal-Debe",
+    "TT-POS" => "Port of Spain",
+    "TT-PRT" => "Princes Town",
+    "TT-PTF" => "Point Fortin",
+    "TT-SFO" => "San Fernando",
+    "TT-SGE" => "Sangre Grande",
+    "TT-SIP" => "Siparia",
+    "TT-SJL" => "San Juan-Laventille",
+    "TT-TUP" => "Tunapuna-Piarco",
+    "TN-11" => "Tunisi",
+    "TN-13" => "Ben Arous",
+    "TN-14" => "Manouba",
+    "TN-21" => "Nabeul",
+    "TN-22" => "Zaghouan",
+    "TN-23" => "Bizerte",
+    "TN-31" => "Béja",
+    "TN-32" => "Jendouba",
+    "TN-33" => "Le Kef",
+    "TN-34" => "Siliana",
+    "TN-41" => "Kairouan",
+    "TN-42" => "Kasserine",
+    "TN-43" => "Sidi Bouzid",
+    "TN-51" => "Sousse",
+    "TN-52" => "Monastir",
+    "TN-53" => "Mahdia",
+    "TN-61" => "Sfax",
+    "TN-71" => "Gafsa",
+    "TN-72" => "Tozeur",
+    "TN-81" => "Gabès",
+    "TN-83" => "Tataouine",
+    "TR-01" => "Adana",
+    "TR-02" => "Adıyaman",
+    "TR-03" => "Afyonkarahisar",
+    "TR-04" => "Ağrı",
+    "TR-05" => "Amasya",
+    "TR-06" => "Ankara",
+    "TR-07" => "Antalya",
+    "TR-08" => "Artvin",
+    "TR-09" => "Aydın",
+    "TR-10" => "Balıkesir",
+    "TR-11" => "Bilecik",
+    "TR-12" => "Bingöl",
+    "TR-13" => "Bitlis",
+    "TR-14" => "Bolu",
+    "TR-15" => "Burdur",
+    "TR-16" => "Bursa",
+    "TR-17" => "Çanakkale",
+    "TR-18" => "Çankırı",
+    "TR-19" => "Çorum",
+    "TR-20" => "Denizli",
+    "TR-21" => "Diyarbakır",
+    "TR-22" => "Edirne",
+    "TR-23" => "Elazığ",
+    "TR-24" => "Erzincan",
+    "TR-25" => "Erzurum",
+    "TR-26" => "Eskişehir",
+    "TR-27" => "Gaziantep",
+    "TR-28" => "Giresun",
+    "TR-29" => "Gümüşhane",
+    "TR-30" => "Hakkâri",
+    "TR-31" => "Hatay",
+    "TR-32" => "Isparta",
+    "TR-33" => "Mersin",
+    "TR-34" => "Istanbul",
+    "TR-35" => "İzmir",
+    "TR-36" => "Kars",
+    "TR-37" => "Kastamonu",
+    "TR-38" => "Kayseri",
+    "TR-39" => "Kırklareli",
+    "TR-40" => "Kırşehir",
+    "TR-41" => "Kocaeli",
+    "TR-42" => "Konya",
+    "TR-43" => "Kütahya",
+    "TR-44" => "Malatya",
+    "TR-45" => "Manisa",
+    "TR-46" => "Kahramanmaraş",
+    "TR-47" => "Mardin",
+    "TR-48" => "Muğla",
+    "TR-49" => "Muş",
+    "TR-50" => "Nevşehir",
+    "TR-51" => "Niğde",
+    "TR-52" => "Ordu",
+    "TR-53" => "Rize",
+    "TR-54" => "Sakarya",
+    "TR-55" => "Samsun",
+    "TR-56" => "Siirt",
+    "TR-57" => "Sinop",
+    "TR-58" => "Sivas",
+    "TR-59" => "Tekirdağ",
+    "TR-60" => "Tokat",
+    "TR-61" => "Trabzon",
+    "TR-62" => "Tunceli",
+    "TR-63" => "Şanlıurfa",
+    "TR-64" => "Uşak",
+    "TR-65" => "Van",
+    "TR-66" => "Yozgat",
+    "TR-67" => "Zonguldak",
+    "TR-68" => "Aksaray",
+    "TR-69" => "Bayburt",
+    "TR-70" => "Karaman",
+    "TR-71" => "Kırıkkale",
+    "TR-72" => "Batman",
+    "TR-73" => "Şırnak",
+    "TR-74" => "Bartın",
+    "TR-75" => "Ardahan",
+    "TR-76" => "Iğdır",
+    "TR-77" => "Yalova",
+    "TR-78" => "Karabük",
+    "TR-79" => "Kilis",
+    "TR-80" => "Osmaniye",
+    "TR-81" => "Düzce",
+    "TM-A" => "Ahal",
+    "TM-B" => "Balkan",
+    "TM-D" => "Daşoguz",
+    "TM-L" => "Lebap",
+    "TM-M" => "Mary",
+    "TM-S" => "Aşgabat",
+    "TV-FUN" => "Funafuti",
+    "TV-NIT" => "Niutao",
+    "TV-NKF" => "Nukufetau",
+    "TV-NKL" => "Nukulaelae",
+    "TV-NMA" => "Nanumea",
+    "TV-NUI" => "Nui",
+    "TV-VAI" => "Vaitupu",
+    "UG-101" => "Kalangala",
+    "UG-102" => "Kampala",
+    "UG-103" => "Kiboga",
+    "UG-104" => "Luwero",
+    "UG-105" => "Masaka",
+    "UG-106" => "Mpigi",
+    "UG-107" => "Mubende",
+    "UG-108" => "Mukono",
+    "UG-109" => "Nakasongola",
+    "UG-110" => "Rakai",
+    "UG-111" => "Sembabule",
+    "UG-112" => "Kayunga",
+    "UG-113" => "Wakiso",
+    "UG-114" => "Lyantonde",
+    "UG-115" => "Mityana",
+    "UG-116" => "Nakaseke",
+    "UG-201" => "Bugiri",
+    "UG-202" => "Busia",
+    "UG-203" => "Iganga",
+    "UG-204" => "Jinja",
+    "UG-205" => "Kamuli",
+    "UG-206" => "Kapchorwa",
+    "UG-207" => "Katakwi",
+    "UG-208" => "Kumi",
+    "UG-209" => "Mbale",
+    "UG-210" => "Pallisa",
+    "UG-211" => "Soroti",
+    "UG-212" => "Tororo",
+    "UG-213" => "Kaberamaido",
+    "UG-214" => "Mayuge",
+    "UG-215" => "Sironko",
+    "UG-216" => "Amuria",
+    "UG-217" => "Budaka",
+    "UG-218" => "Bududa",
+    "UG-219" => "Bukedea",
+    "UG-221" => "Butaleja",
+    "UG-222" => "Kaliro",
+    "UG-223" => "Manafwa",
+    "UG-224" => "Namutumba",
+    "UG-301" => "Adjumani",
+    "UG-302" => "Apac",
+    "UG-303" => "Arua",
+    "UG-304" => "Gulu",
+    "UG-305" => "Kitgum",
+    "UG-306" => "Kotido",
+    "UG-307" => "Lira",
+    "UG-308" => "Moroto",
+    "UG-309" => "Moyo",
+    "UG-310" => "Nebbi",
+    "UG-311" => "Nakapiripirit",
+    "UG-312" => "Pader",
+    "UG-313" => "Yumbe",
+    "UG-314" => "Abim",
+    "UG-315" => "Amolatar",
+    "UG-316" => "Amuru",
+    "UG-317" => "Dokolo",
+    "UG-318" => "Kaabong",
+    "UG-319" => "Koboko",
+    "UG-320" => "Maracha",
+    "UG-321" => "Oyam",
+    "UG-401" => "Bundibugyo",
+    "UG-402" => "Bushenyi",
+    "UG-403" => "Hoima",
+    "UG-404" => "Kabale",
+    "UG-405" => "Kabarole",
+    "UG-406" => "Kasese",
+    "UG-407" => "Kibaale",
+    "UG-408" => "Kisoro",
+    "UG-409" => "Masindi",
+    "UG-410" => "Mbarara",
+    "UG-411" => "Ntungamo",
+    "UG-412" => "Rukungiri",
+    "UG-413" => "Kamwenge",
+    "UG-414" => "Kanungu",
+    "UG-415" => "Kyenjojo",
+    "UG-416" => "Buliisa",
+    "UG-417" => "Ibanda",
+    "UG-418" => "Isingiro",
+    "UG-419" => "Kiruhura",
+    "UG-C" => "Centrale",
+    "UG-E" => "Orientale",
+    "UG-N" => "Settentrionale",
+    "UG-W" => "Occidentale",
+    "UA-07" => "Oblast' di Volinia",
+    "UA-40" => "Sevastopoli",
+    "UA-53" => "Oblast' di Poltava",
+    "AE-DU" => "Dubai",
+    "AE-FU" => "Al Fujayrah",
+    "AE-RK" => "Ra’s al Khaymah",
+    "AE-SH" => "Ash Shariqah",
+    "AE-UQ" => "Umm al Qaywayn",
+    "GB-ABD" => "Aberdeenshire",
+    "GB-ABE" => "Aberdeen City",
+    "GB-AGB" => "Argyll e Bute",
+    "GB-AND" => "Ards e North Down",
+    "GB-ANN" => "Antrim e Newtownabbey",
+    "GB-ANS" => "Angus",
+    "GB-BAS" => "Bath e North East Somerset",
+    "GB-BBD" => "Blackburn con Darwen",
+    "GB-BDF" => "Bedford",
+    "GB-BDG" => "Barking e Dagenham",
+    "GB-BEN" => "Brent",
+    "GB-BEX" => "Bexley",
+    "GB-BGW" => "Blaenau Gwent",
+    "GB-BIR" => "Birmingham",
+    "GB-BKM" => "Buckinghamshire",
+    "GB-BNE" => "Barnet",
+    "GB-BNH" => "Brighton e Hove",
+    "GB-BNS" => "Barnsley",
+    "GB-BOL" => "Bolton",
+    "GB-BPL" => "Blackpool",
+    "GB-BRC" => "Bracknell Forest",
+    "GB-BRD" => "Bradford",
+    "GB-BRY" => "Bromley",
+    "GB-BST" => "Città di Bristol",
+    "GB-BUR" => "Bury",
+    "GB-CAM" => "Cambridgeshire",
+    "GB-CBF" => "Bedfordshire centrale",
+    "GB-CCG" => "Causeway Coast e Glens",
+    "GB-CHE" => "Cheshire Est",
+    "GB-CHW" => "Cheshire Ovest Chester",
+    "GB-CLD" => "Calderdale",
+    "GB-CLK" => "Clackmannanshire",
+    "GB-CMA" => "Cumbria",
+    "GB-CMD" => "Camden",
+    "GB-CON" => "Cornovaglia",
+    "GB-COV" => "Coventry",
+    "GB-CRY" => "Croydon",
+    "GB-CWY" => "Conwy",
+    "GB-DAL" => "Darlington",
+    "GB-DBY" => "Derbyshire",
+    "GB-DER" => "Derby",
+    "GB-DEV" => "Devon",
+    "GB-DGY" => "Dumfries e Galloway",
+    "GB-DNC" => "Doncaster",
+    "GB-DND" => "Dundee City",
+    "GB-DOR" => "Dorset",
+    "GB-DRS" => "Derry e Strabane",
+    "GB-DUD" => "Dudley",
+    "GB-EAL" => "Ealing",
+    "GB-EAY" => "East Ayrshire",
+    "GB-EDH" => "Città di Edinburgh",
+    "GB-EDU" => "East Dunbartonshire",
+    "GB-ELN" => "East Lothian",
+    "GB-ELS" => "Eilean Siar",
+    "GB-ENF" => "Enfield",
+    "GB-ERW" => "East Renfrewshire",
+    "GB-ERY" => "East Riding di Yorkshire",
+    "GB-ESS" => "Essex",
+    "GB-ESX" => "East Sussex",
+    "GB-FAL" => "Falkirk",
+    "GB-FIF" => "Fife",
+    "GB-FMO" => "Fermanagh e Omagh",
+    "GB-GAT" => "Gateshead",
+    "GB-GLG" => "Citta di Glasgow",
+    "GB-GLS" => "Gloucestershire",
+    "GB-GRE" => "Greenwich",
+    "GB-GWN" => "Gwynedd",
+    "GB-HAL" => "Halton",
+    "GB-HAM" => "Hampshire",
+    "GB-HAV" => "Havering",
+    "GB-HCK" => "Hackney",
+    "GB-HEF" => "Herefordshire",
+    "GB-HIL" => "Hillingdon",
+    "GB-HLD" => "Highland",
+    "GB-HMF" => "Hammersmith e Fulham",
+    "GB-HNS" => "Hounslow",
+    "GB-HPL" => "Hartlepool",
+    "GB-HRT" => "Hertfordshire",
+    "GB-HRW" => "Harrow",
+    "GB-HRY" => "Haringey",
+    "GB-IOS" => "Isola di Scilly",
+    "GB-IOW" => "Isola di Wight",
+    "GB-ISL" => "Islington",
+    "GB-IVC" => "Inverclyde",
+    "GB-KEC" => "Kensington e Chelsea",
+    "GB-KEN" => "Kent",
+    "GB-KHL" => "Kingston upon Hull",
+    "GB-KIR" => "Kirklees",
+    "GB-KTT" => "Kingston sul Tamigi",
+    "GB-KWL" => "Knowsley",
+    "GB-LAN" => "Lancashire",
+    "GB-LBC" => "Lisburn e Castlereagh",
+    "GB-LBH" => "Lambeth",
+    "GB-LCE" => "Leicester",
+    "GB-LDS" => "Leeds",
+    "GB-LEC" => "Leicestershire",
+    "GB-LEW" => "Lewisham",
+    "GB-LIN" => "Lincolnshire",
+    "GB-LIV" => "Liverpool",
+    "GB-LND" => "Città di Londra",
+    "GB-LUT" => "Luton",
+    "GB-MAN" => "Manchester",
+    "GB-MDB" => "Middlesbrough",
+    "GB-MDW" => "Medway",
+    "GB-MEA" => "Mid ed East Antrim",
+    "GB-MIK" => "Milton Keynes",
+    "GB-MLN" => "Midlothian",
+    "GB-MRT" => "Merton",
+    "GB-MRY" => "Moray",
+    "GB-NAY" => "Ayrshire Nord",
+    "GB-NBL" => "Northumberland",
+    "GB-NEL" => "North East Lincolnshire",
+    "GB-NET" => "Newcastle upon Tyne",
+    "GB-NFK" => "Norfolk",
+    "GB-NGM" => "Nottingham",
+    "GB-NLK" => "North Lanarkshire",
+    "GB-NLN" => "North Lincolnshire",
+    "GB-NMD" => "Newry, Mourne e Down",
+    "GB-NSM" => "North Somerset",
+    "GB-NTT" => "Nottinghamshire",
+    "GB-NTY" => "North Tyneside",
+    "GB-NWM" => "Newham",
+    "GB-NYK" => "North Yorkshire",
+    "GB-OLD" => "Oldham",
+    "GB-ORK" => "Isole Orkney",
+    "GB-OXF" => "Oxfordshire",
+    "GB-PKN" => "Perth e Kinross",
+    "GB-PLY" => "Plymouth",
+    "GB-POR" => "Portsmouth",
+    "GB-POW" => "Powys",
+    "GB-PTE" => "Peterborough",
+    "GB-RCC" => "Redcar e Cleveland",
+    "GB-RCH" => "Rochdale",
+    "GB-RDB" => "Redbridge",
+    "GB-RDG" => "Reading",
+    "GB-RFW" => "Renfrewshire",
+    "GB-RIC" => "Richmond sul Tamigi",
+    "GB-ROT" => "Rotherham",
+    "GB-RUT" => "Rutland",
+    "GB-SAW" => "Sandwell",
+    "GB-SAY" => "South Ayrshire",
+    "GB-SFK" => "Suffolk",
+    "GB-SFT" => "Sefton",
+    "GB-SGC" => "South Gloucestershire",
+    "GB-SHF" => "Sheffield",
+    "GB-SHN" => "St. Helens",
+    "GB-SHR" => "Shropshire",
+    "GB-SKP" => "Stockport",
+    "GB-SLF" => "Salford",
+    "GB-SLG" => "Slough",
+    "GB-SLK" => "South Lanarkshire",
+    "GB-SND" => "Sunderland",
+    "GB-SOL" => "Solihull",
+    "GB-SOM" => "Somerset",
+    "GB-SOS" => "Southend-on-Sea",
+    "GB-SRY" => "Surrey",
+    "GB-STE" => "Stoke-on-Trent",
+    "GB-STG" => "Stirling",
+    "GB-STH" => "Southampton",
+    "GB-STN" => "Sutton",
+    "GB-STS" => "Staffordshire",
+    "GB-STT" => "Stockton-on-Tees",
+    "GB-STY" => "South Tyneside",
+    "GB-SWD" => "Swindon",
+    "GB-SWK" => "Southwark",
+    "GB-TAM" => "Tameside",
+    "GB-TFW" => "Telford e Wrekin",
+    "GB-THR" => "Thurrock",
+    "GB-TOB" => "Torbay",
+    "GB-TRF" => "Trafford",
+    "GB-TWH" => "Tower Hamlets",
+    "GB-WAR" => "Warwickshire",
+    "GB-WBK" => "West Berkshire",
+    "GB-WDU" => "West Dunbartonshire",
+    "GB-WFT" => "Waltham Forest",
+    "GB-WGN" => "Wigan",
+    "GB-WIL" => "Wiltshire",
+    "GB-WKF" => "Wakefield",
+    "GB-WLL" => "Walsall",
+    "GB-WLN" => "West Lothian",
+    "GB-WLV" => "Wolverhampton",
+    "GB-WND" => "Wandsworth",
+    "GB-WNM" => "Windsor e Maidenhead",
+    "GB-WOK" => "Wokingham",
+    "GB-WOR" => "Worcestershire",
+    "GB-WRL" => "Wirral",
+    "GB-WRT" => "Warrington",
+    "GB-WSM" => "Westminster",
+    "GB-WSX" => "West Sussex",
+    "GB-YOR" => "York",
+    "GB-ZET" => "Isole Shetland",
+    "US-AK" => "Alaska",
+    "US-AL" => "Alabama",
+    "US-AR" => "Arkansas",
+    "US-AS" => "Samoa americane",
+    "US-AZ" => "Arizona",
+    "US-CA" => "California",
+    "US-CO" => "Colorado",
+    "US-CT" => "Connecticut",
+    "US-DC" => "District of Columbia",
+    "US-DE" => "Delaware",
+    "US-FL" => "Florida",
+    "US-GA" => "Georgia",
+    "US-GU" => "Guam",
+    "US-HI" => "Hawaii",
+    "US-IA" => "Iowa",
+    "US-ID" => "Idaho",
+    "US-IL" => "Illinois",
+    "US-IN" => "Indiana",
+    "US-KS" => "Kansas",
+    "US-KY" => "Kentucky",
+    "US-LA" => "Louisiana",
+    "US-MA" => "Massachusetts",
+    "US-MD" => "Maryland",
+    "US-ME" => "Maine",
+    "US-MI" => "Michigan",
+    "US-MN" => "Minnesota",
+    "US-MO" => "Missouri",
+    "US-MP" => "Isole Marianne Settentrionali",
+    "US-MS" => "Mississippi",
+    "US-MT" => "Montana",
+    "US-NC" => "Carolina del Nord",
+    "US-ND" => "Dakota del Nord",
+    "US-NE" => "Nebraska",
+    "US-NH" => "New Hampshire",
+    "US-NJ" => "New Jersey",
+    "US-NM" => "New Mexico",
+    "US-NV" => "Nevada",
+    "US-NY" => "New York",
+    "US-OH" => "Ohio",
+    "US-OK" => "Oklahoma",
+    "US-OR" => "Oregon",
+    "US-PA" => "Pennsylvania",
+    "US-PR" => "Portorico",
+    "US-RI" => "Rhode Island",
+    "US-SC" => "Carolina del Sud",
+    "US-SD" => "Dakota del Sud",
+    "US-TN" => "Tennessee",
+    "US-TX" => "Texas",
+    "US-UM" => "Isole minori esterne degli Stati Uniti d'America",
+    "US-UT" => "Utah",
+    "US-VA" => "Virginia",
+    "US-VT" => "Vermont",
+    "US-WA" => "Washington",
+    "US-WI" => "Wisconsin",
+    "US-WV" => "Virginia Occidentale",
+    "US-WY" => "Wyoming",
+    "UM-67" => "Atollo Johnston",
+    "UM-71" => "Isole Midway",
+    "UM-76" => "Isola Navassa",
+    "UM-79" => "Isola Wake",
+    "UM-81" => "Isola Baker",
+    "UM-84" => "Isola Howland",
+    "UM-86" => "Isola Jarvis",
+    "UM-89" => "Kingman Reef",
+    "UM-95" => "Atollo Palmyra",
+    "UY-AR" => "Artigas",
+    "UY-CA" => "Canelones",
+    "UY-CL" => "Cerro Largo",
+    "UY-CO" => "Colonia",
+    "UY-DU" => "Durazno",
+    "UY-FD" => "Florida",
+    "UY-FS" => "Flores",
+    "UY-LA" => "Lavalleja",
+    "UY-MA" => "Maldonado",
+    "UY-MO" => "Montevideo",
+    "UY-PA" => "Paysandú",
+    "UY-RN" => "Río Negro",
+    "UY-RO" => "Rocha",
+    "UY-RV" => "Rivera",
+    "UY-SA" => "Salto",
+    "UY-SJ" => "San José",
+    "UY-SO" => "Soriano",
+    "UY-TA" => "Tacuarembó",
+    "UY-TT" => "Treinta y Tres",
+    "UZ-AN" => "Andijan",
+    "UZ-BU" => "Bukhara",
+    "UZ-JI" => "Jizzax",
+    "UZ-NG" => "Namangan",
+    "UZ-NW" => "Navoiy",
+    "UZ-QA" => "Qashqadaryo",
+    "UZ-SA" => "Samarcanda",
+    "UZ-SI" => "Sirdarya",
+    "UZ-SU" => "Surxondaryo",
+    "UZ-TK" => "Taskent",
+    "UZ-TO" => "Taskent",
+    "UZ-XO" => "Xorazm",
+    "VU-MAP" => "Malampa",
+    "VU-PAM" => "Pénama",
+    "VU-SAM" => "Sanma",
+    "VU-SEE" => "Shéfa",
+    "VU-TAE" => "Taféa",
+    "VU-TOB" => "Torba",
+    "VE-B" => "Anzoátegui",
+    "VE-C" => "Apure",
+    "VE-D" => "Aragua",
+    "VE-E" => "Barinas",
+    "VE-F" => "Bolivar",
+    "VE-G" => "Carabobo",
+    "VE-H" => "Cojedes",
+    "VE-I" => "Falcón",
+    "VE-J" => "Guárico",
+    "VE-K" => "Lara",
+    "VE-L" => "Mérida",
+    "VE-M" => "Miranda",
+    "VE-N" => "Monagas",
+    "VE-O" => "Nueva Esparta",
+    "VE-P" => "Portuguesa",
+    "VE-R" => "Sucre",
+    "VE-S" => "Táchira",
+    "VE-T" => "Trujillo",
+    "VE-U" => "Yaracuy",
+    "VE-V" => "Zulia",
+    "VE-W" => "Dipendenze Federali",
+    "VE-Y" => "Delta Amacuro",
+    "VE-Z" => "Amazonas",
+    "VN-01" => "Lai Châu",
+    "VN-02" => "Lào Cai",
+    "VN-03" => "Hà Giang",
+    "VN-04" => "Cao Bằng",
+    "VN-05" => "Sơn La",
+    "VN-06" => "Yên Bái",
+    "VN-07" => "Tuyên Quang",
+    "VN-09" => "Lạng Sơn",
+    "VN-13" => "Quang Ninh",
+    "VN-18" => "Ninh Bình",
+    "VN-20" => "Thái Bình",
+    "VN-21" => "Thanh Hóa",
+    "VN-22" => "Nghệ An",
+    "VN-24" => "Quang Bình",
+    "VN-25" => "Quang Trị",
+    "VN-26" => "Thừa Thiên-Huế",
+    "VN-27" => "Quang Nam",
+    "VN-28" => "Kon Tum",
+    "VN-29" => "Quang Ngai",
+    "VN-30" => "Gia Lai",
+    "VN-31" => "Bình Định",
+    "VN-32" => "Phú Yên",
+    "VN-34" => "Khánh Hòa",
+    "VN-35" => "Lâm Đồng",
+    "VN-36" => "Ninh Thuận",
+    "VN-37" => "Tây Ninh",
+    "VN-39" => "Đồng Nai",
+    "VN-40" => "Bình Thuận",
+    "VN-41" => "Long An",
+    "VN-44" => "An Giang",
+    "VN-45" => "Đồng Tháp",
+    "VN-46" => "Tiền Giang",
+    "VN-49" => "Vĩnh Long",
+    "VN-50" => "Bến Tre",
+    "VN-51" => "Trà Vinh",
+    "VN-52" => "Sóc Trăng",
+    "VN-53" => "Bắc Kạn",
+    "VN-54" => "Bắc Giang",
+    "VN-55" => "Bạc Liêu",
+    "VN-56" => "Bắc Ninh",
+    "VN-57" => "Bình Dương",
+    "VN-58" => "Bình Phước",
+    "VN-59" => "Cà Mau",
+    "VN-63" => "Hà Nam",
+    "VN-66" => "Hưng Yên",
+    "VN-67" => "Nam Định",
+    "VN-68" => "Phú Thọ",
+    "VN-69" => "Thái Nguyên",
+    "VN-70" => "Vĩnh Phúc",
+    "VN-71" => "Điện Biên",
+    "VN-72" => "Đắk Nông",
+    "VN-73" => "Hậu Giang",
+    "VN-CT" => "Cần Thơ",
+    "VN-DN" => "Đà Nẵng",
+    "VN-HN" => "Hanoi",
+    "VN-HP" => "Hai Phong",
+    "YE-DH" => "Dhamar",
+    "YE-HD" => "Hadramawt",
+    "YE-HJ" => "Hajjah",
+    "YE-HU" => "Al-Hudayda",
+    "YE-IB" => "Ibb",
+    "YE-JA" => "Al Jawf",
+    "YE-LA" => "Lahij",
+    "YE-MR" => "Al-Mahra",
+    "YE-MW" => "Al-Mahwit",
+    "YE-RA" => "Raymah",
+    "YE-SH" => "Shabwa",
+    "ZM-01" => "Occidentale",
+    "ZM-02" => "Centrale",
+    "ZM-03" => "Orientale",
+    "ZM-04" => "Luapula",
+    "ZM-05" => "Settentrionale",
+    "ZM-06" => "Nord-Occidentale",
+    "ZM-07" => "Meridionale (Botswana)",
+    "ZM-08" => "Copperbelt",
+    "ZM-09" => "Lusaka",
+    "ZW-BU" => "Bulawayo",
+    "ZW-HA" => "Harare",
+    "ZW-MA" => "Manicaland",
+    "ZW-MC" => "Mashonaland Centrale",
+    "ZW-ME" => "Mashonaland Est",
+    "ZW-MI" => "Midlands",
+    "ZW-MN" => "Matabeleland Nord",
+    "ZW-MS" => "Matabeleland Sud",
+    "ZW-MV" => "Masvingo",
+    "ZW-MW" => "Mashonaland Ovest",
+};
+
+///Italian former country names by alpha-4 code
+pub const FORMER_NAME_MAP: Map<&str, &str> = phf_map! {
+    "BQAQ" => "Territorio antartico britannico",
+    "BUMM" => "Birmania, Repubblica Socialista dell'Unione della",
+    "BYAA" => "Repubblica Socialista Sovietica Bielorussa",
+    "CTKI" => "Isole Canton ed Enderbury",
+    "CSHH" => "Cecoslovacchia, Repubblica Socialista Cecoslovacca",
+    "DYBJ" => "Dahomey",
+    "NQAQ" => "Terra della regina Maud",
+    "TPTL" => "Timor Est",
+    "FXFR" => "Francia metropolitana",
+    "AIDJ" => "Territorio francese degli Afar e degli Issa",
+    "FQHH" => "Terre australi e antartiche francesi",
+    "DDDE" => "Repubblica Democratica Tedesca",
+    "GEHH" => "Isole Gilbert ed Ellice",
+    "JTUM" => "Atollo Johnston",
+    "MIUM" => "Atollo di Midway",
+    "ANHH" => "Antille Olandesi",
+    "NTHH" => "Zona neutrale",
+    "NHVU" => "Nuove Ebridi",
+    "PCHH" => "Territorio fiduciario delle Isole del Pacifico",
+    "PZPA" => "Zona del Canale di Panama",
+    "CSXX" => "Serbia e Montenegro",
+    "SKIN" => "Sikkim",
+    "RHZW" => "Rhodesia meridionale",
+    "PUUM" => "USA, altre isole del Pacifico",
+    "HVBF" => "Alto Volta, Repubblica dell'",
+    "SUHH" => "URSS, Unione delle Repubbliche Socialiste Sovietiche",
+    "VDVN" => "Vietnam, Repubblica democratica del",
+    "WKUM" => "Atollo di Wake",
+    "YDYE" => "Yemen, Repubblica Democratica Popolare dello",
+    "ZRCD" => "Zaire, Repubblica dello",
+};
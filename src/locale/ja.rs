@@ -0,0 +1,2454 @@
+use phf::phf_map;
+use phf::Map;
+
+///Japanese country names by alpha-2 code
+pub const COUNTRY_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF" => "アフガニスタン",
+    "AX" => "オーランド諸島",
+    "AL" => "アルバニア",
+    "DZ" => "アルジェリア",
+    "AS" => "米領サモア",
+    "AD" => "アンドラ",
+    "AO" => "アンゴラ",
+    "AI" => "アングイラ",
+    "AQ" => "南極大陸",
+    "AG" => "アンティグア・バーブーダ",
+    "AR" => "アルゼンチン",
+    "AM" => "アルメニア",
+    "AW" => "アルーバ",
+    "AU" => "オーストラリア連邦",
+    "AT" => "オーストリア",
+    "AZ" => "アゼルバイジャン",
+    "BS" => "バハマ",
+    "BH" => "バーレーン",
+    "BD" => "バングラデシュ",
+    "BB" => "バルバドス",
+    "BY" => "ベラルーシ",
+    "BE" => "ベルギー",
+    "BZ" => "ベリーズ",
+    "BJ" => "ベナン",
+    "BM" => "バーミューダ",
+    "BT" => "ブータン",
+    "BO" => "ボリビア多民族国",
+    "BQ" => "ボネール、シントユースタティウス及びサバ",
+    "BA" => "ボスニア・ヘルツェゴビナ",
+    "BW" => "ボツワナ",
+    "BV" => "ブーベ島",
+    "BR" => "ブラジル",
+    "IO" => "英国インド洋領土",
+    "BN" => "ブルネイ・ダルサラーム国",
+    "BG" => "ブルガリア",
+    "BF" => "ブルキナファソ",
+    "BI" => "ブルンジ",
+    "CV" => "カーボヴェルデ",
+    "KH" => "カンボジア",
+    "CM" => "カメルーン",
+    "CA" => "カナダ",
+    "KY" => "ケイマン諸島",
+    "CF" => "中央アフリカ共和国",
+    "TD" => "チャド",
+    "CL" => "チリ",
+    "CN" => "中国",
+    "CX" => "クリスマス島",
+    "CC" => "ココス (キーリング) 諸島",
+    "CO" => "コロンビア",
+    "KM" => "コモロ",
+    "CG" => "コンゴ",
+    "CD" => "コンゴ民主共和国",
+    "CK" => "クック諸島",
+    "CR" => "コスタリカ",
+    "CI" => "コートジボワール",
+    "HR" => "クロアチア",
+    "CU" => "キューバ",
+    "CW" => "キュラソー",
+    "CY" => "キプロス",
+    "DK" => "デンマーク",
+    "DJ" => "ジブチ",
+    "DM" => "ドミニカ",
+    "DO" => "ドミニカ共和国",
+    "EC" => "エクアドル",
+    "EG" => "エジプト",
+    "SV" => "エルサルバドル",
+    "GQ" => "赤道ギニア",
+    "ER" => "エリトリア国",
+    "EE" => "エストニア",
+    "ET" => "エチオピア",
+    "FK" => "フォークランド諸島 (マルビナス)",
+    "FO" => "フェロー諸島",
+    "FJ" => "フィジー",
+    "FI" => "フィンランド",
+    "FR" => "フランス",
+    "GF" => "仏領ギアナ",
+    "PF" => "仏領ポリネシア",
+    "TF" => "フランス南方領土",
+    "GA" => "ガボン",
+    "GM" => "ガンビア",
+    "GE" => "グルジア",
+    "DE" => "ドイツ",
+    "GH" => "ガーナ",
+    "GI" => "ジブラルタル",
+    "GR" => "ギリシャ",
+    "GL" => "グリーンランド",
+    "GD" => "グレナダ",
+    "GP" => "グアドループ",
+    "GU" => "グアム",
+    "GT" => "グアテマラ",
+    "GG" => "ガーンジー",
+    "GN" => "ギニア",
+    "GW" => "ギニアビサウ",
+    "GY" => "ガイアナ",
+    "HT" => "ハイチ",
+    "HM" => "ハード島及びマクドナルド諸島",
+    "VA" => "聖庁 (バチカン市国)",
+    "HN" => "ホンジュラス",
+    "HK" => "香港",
+    "HU" => "ハンガリー",
+    "IS" => "アイスランド",
+    "IN" => "インド",
+    "ID" => "インドネシア",
+    "IR" => "イラン・イスラム共和国",
+    "IQ" => "イラク",
+    "IE" => "アイルランド",
+    "IM" => "マン島",
+    "IL" => "イスラエル",
+    "IT" => "イタリア",
+    "JM" => "ジャマイカ",
+    "JP" => "日本",
+    "JE" => "ジャージー",
+    "JO" => "ヨルダン",
+    "KZ" => "カザフスタン",
+    "KE" => "ケニア",
+    "KI" => "キリバス",
+    "KP" => "朝鮮民主主義人民共和国",
+    "KR" => "大韓民国 (韓国)",
+    "KW" => "クウェート",
+    "KG" => "キルギスタン",
+    "LA" => "ラオス人民民主共和国",
+    "LV" => "ラトビア",
+    "LB" => "レバノン",
+    "LS" => "レソト",
+    "LR" => "リベリア",
+    "LY" => "リビア",
+    "LI" => "リヒテンシュタイン",
+    "LT" => "リトアニア",
+    "LU" => "ルクセンブルク",
+    "MO" => "マカオ",
+    "MG" => "マダガスカル",
+    "MW" => "マラウイ",
+    "MY" => "マレーシア",
+    "MV" => "モルディブ",
+    "ML" => "マリ",
+    "MT" => "マルタ",
+    "MH" => "マーシャル諸島",
+    "MQ" => "マルティニーク",
+    "MR" => "モーリタニア",
+    "MU" => "モーリシャス",
+    "YT" => "マヨット",
+    "MX" => "メキシコ",
+    "FM" => "ミクロネシア連邦",
+    "MD" => "モルドバ共和国",
+    "MC" => "モナコ",
+    "MN" => "モンゴル国",
+    "ME" => "モンテネグロ",
+    "MS" => "モントセラト",
+    "MA" => "モロッコ",
+    "MZ" => "モザンビーク",
+    "MM" => "ミャンマー",
+    "NA" => "ナミビア",
+    "NR" => "ナウル",
+    "NP" => "ネパール",
+    "NL" => "オランダ",
+    "NC" => "ニューカレドニア",
+    "NZ" => "ニュージーランド",
+    "NI" => "ニカラグア",
+    "NE" => "ニジェール",
+    "NG" => "ナイジェリア",
+    "NU" => "ニウエ",
+    "NF" => "ノーフォーク島",
+    "MP" => "北マリアナ諸島",
+    "NO" => "ノルウェー",
+    "OM" => "オマーン",
+    "PK" => "パキスタン",
+    "PW" => "パラオ",
+    "PS" => "パレスチナ",
+    "PA" => "パナマ",
+    "PG" => "パプアニューギニア",
+    "PY" => "パラグアイ",
+    "PE" => "ペルー",
+    "PH" => "フィリピン",
+    "PN" => "ピトケアン",
+    "PL" => "ポーランド",
+    "PT" => "ポルトガル",
+    "PR" => "プエルトリコ",
+    "QA" => "カタール",
+    "RE" => "レユニオン",
+    "RO" => "ルーマニア",
+    "RU" => "ロシア連邦",
+    "RW" => "ルワンダ",
+    "BL" => "サンバルテルミ",
+    "SH" => "セントヘレナ、アセンション及びトリスタン・ダ・クーニャ",
+    "KN" => "セントクリストファー・ネーヴィス",
+    "LC" => "セントルシア",
+    "MF" => "サンマルタン (仏領)",
+    "PM" => "サンピエール及びミクロン",
+    "VC" => "セントビンセント及びグレナディーン諸島",
+    "WS" => "サモア",
+    "SM" => "サンマリノ",
+    "ST" => "サントメ・プリンシペ",
+    "SA" => "サウジアラビア",
+    "SN" => "セネガル",
+    "RS" => "セルビア",
+    "SC" => "セーシェル",
+    "SL" => "シエラレオネ",
+    "SG" => "シンガポール",
+    "SX" => "サンマルタン (オランダ領)",
+    "SK" => "スロバキア",
+    "SI" => "スロベニア",
+    "SB" => "ソロモン諸島",
+    "SO" => "ソマリア",
+    "ZA" => "南アフリカ",
+    "GS" => "サウスジョージア及びサウスサンドウィッチ諸島",
+    "SS" => "南スーダン",
+    "ES" => "スペイン",
+    "LK" => "スリランカ",
+    "SD" => "スーダン",
+    "SR" => "スリナム",
+    "SJ" => "スヴァールバル及びヤンマイエン",
+    "SE" => "スウェーデン",
+    "CH" => "スイス",
+    "SY" => "シリア・アラブ共和国",
+    "TW" => "中国領・台湾",
+    "TJ" => "タジキスタン",
+    "TZ" => "タニザニア連合共和国",
+    "TH" => "タイ",
+    "TL" => "東ティモール",
+    "TG" => "トーゴ",
+    "TK" => "トケラウ",
+    "TO" => "トンガ",
+    "TT" => "トリニダード・トバゴ",
+    "TN" => "チュニジア",
+    "TM" => "トルクメニスタン",
+    "TC" => "タークス及びカイコス諸島",
+    "TV" => "ツバル",
+    "UG" => "ウガンダ",
+    "UA" => "ウクライナ",
+    "AE" => "アラブ首長国連邦",
+    "GB" => "英国",
+    "US" => "米国",
+    "UM" => "アメリカ合衆国外諸島",
+    "UY" => "ウルグアイ",
+    "UZ" => "ウズベキスタン",
+    "VU" => "バヌアツ",
+    "VE" => "ベネズエラ・ボリバル共和国",
+    "VN" => "ベトナム",
+    "VG" => "英領ヴァージン諸島",
+    "VI" => "米領ヴァージン諸島",
+    "WF" => "ワリー及びフテュナ",
+    "EH" => "西サハラ",
+    "YE" => "イエメン",
+    "ZM" => "ザンビア",
+    "ZW" => "ジンバブエ",
+};
+
+///Japanese subdivision names by ISO 3166-2 code, 2165 of 5046
+pub const SUBDIVISION_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF-BAL" => "バルフ",
+    "AF-BAM" => "バーミヤン",
+    "AF-BDG" => "バドギース",
+    "AF-BDS" => "バダフシャーン",
+    "AF-BGL" => "バグラーン",
+    "AF-DAY" => "ダイクンディ",
+    "AF-FRA" => "ファラー",
+    "AF-FYB" => "ファリヤーブ",
+    "AF-GHA" => "ガズニ",
+    "AF-GHO" => "ゴール",
+    "AF-HEL" => "ヘルマンド",
+    "AF-HER" => "ヘラート",
+    "AF-JOW" => "ジョウズジャーン",
+    "AF-KAB" => "カブール",
+    "AF-KAN" => "カンダハール",
+    "AF-KAP" => "カピサ",
+    "AF-KDZ" => "クンドゥーズ",
+    "AF-KHO" => "ホースト",
+    "AF-LAG" => "ラグマーン",
+    "AF-LOG" => "ローガル",
+    "AF-NAN" => "ナンガルハール",
+    "AF-NIM" => "ニームローズ",
+    "AF-NUR" => "ヌーリスタン",
+    "AF-PAR" => "パルワーン",
+    "AF-PIA" => "パクティア",
+    "AF-PKA" => "パクティカ",
+    "AF-SAM" => "サマンガーン",
+    "AF-SAR" => "サリプル",
+    "AF-TAK" => "タハール",
+    "AF-URU" => "ウルズガン",
+    "AF-WAR" => "ワルダック",
+    "AF-ZAB" => "ザーボル",
+    "AL-02" => "ドゥラス",
+    "AL-03" => "エルバサン",
+    "AL-04" => "フィエル",
+    "AL-06" => "コルチャ",
+    "AL-07" => "クカス",
+    "AL-10" => "シュコダル",
+    "AL-11" => "ティラナ",
+    "AL-12" => "ヴローラ",
+    "DZ-01" => "アドラル",
+    "DZ-02" => "シェリーフ",
+    "DZ-03" => "ラグアッツト",
+    "DZ-04" => "ウメルプアーギ",
+    "DZ-05" => "バトナ",
+    "DZ-06" => "ベジャイア",
+    "DZ-07" => "ビスクラ",
+    "DZ-08" => "ベシャル",
+    "DZ-09" => "ブリダ",
+    "DZ-10" => "ブイラ",
+    "DZ-12" => "テベサ",
+    "DZ-13" => "トレムセン",
+    "DZ-14" => "ティアレト",
+    "DZ-15" => "ティジウズ",
+    "DZ-16" => "アルジェ",
+    "DZ-17" => "ジェルファ",
+    "DZ-18" => "ジジェル",
+    "DZ-19" => "セティフ",
+    "DZ-20" => "サイダ",
+    "DZ-21" => "スキクダ",
+    "DZ-22" => "シディベルアベス",
+    "DZ-23" => "アナバ",
+    "DZ-24" => "ゲルマ",
+    "DZ-25" => "コンスタンティーヌ",
+    "DZ-26" => "メデア",
+    "DZ-27" => "モスタガネム",
+    "DZ-29" => "マスカラ",
+    "DZ-30" => "ワルグラ",
+    "DZ-31" => "オラン",
+    "DZ-32" => "エルバヤード",
+    "DZ-33" => "イリジ",
+    "DZ-34" => "ボルジブアレリジ",
+    "DZ-35" => "ブーメルデス",
+    "DZ-36" => "エルタルフ",
+    "DZ-37" => "ティンドーフ",
+    "DZ-38" => "ティセムシルト",
+    "DZ-39" => "エルウエッド",
+    "DZ-40" => "ヘンシュラ",
+    "DZ-41" => "スーカハラス",
+    "DZ-42" => "ティパザ",
+    "DZ-43" => "ミラ",
+    "DZ-44" => "アインデフラ",
+    "DZ-45" => "ナーマ",
+    "DZ-46" => "アインテムシェント",
+    "DZ-47" => "ガルダイア",
+    "DZ-48" => "ルリザンヌ",
+    "AD-07" => "アンドララベリャ",
+    "AO-BGO" => "ベンゴ",
+    "AO-BGU" => "ベンゲラ",
+    "AO-BIE" => "ビエ",
+    "AO-CAB" => "カビンダ",
+    "AO-CNN" => "クネネ",
+    "AO-HUA" => "ワンボー",
+    "AO-HUI" => "ウイラ",
+    "AO-LUA" => "ルアンダ",
+    "AO-MAL" => "マランジェ",
+    "AO-MOX" => "モシコ",
+    "AO-NAM" => "ナミベ",
+    "AO-UIG" => "ウイジェ",
+    "AO-ZAI" => "ザイール",
+    "AG-03" => "セントジョージ",
+    "AG-04" => "セントジョン",
+    "AG-05" => "セントメアリー",
+    "AG-06" => "セントポール",
+    "AG-07" => "セントピーター",
+    "AG-08" => "セントフィリップ",
+    "AG-10" => "バーブーダ",
+    "AR-A" => "サルタ",
+    "AR-B" => "ブエノスアイレス",
+    "AR-D" => "サンルイス",
+    "AR-F" => "ラリオハ",
+    "AR-G" => "サンティアゴデルエストロ",
+    "AR-H" => "チャコ",
+    "AR-J" => "サンファン",
+    "AR-K" => "カタマルカ",
+    "AR-L" => "ラパンパ",
+    "AR-M" => "メンドサ",
+    "AR-N" => "ミシオネス",
+    "AR-P" => "フォルモサ",
+    "AR-R" => "リオネグロ",
+    "AR-S" => "サンタフェ",
+    "AR-U" => "チュブト",
+    "AR-V" => "ティエラデルフエゴ",
+    "AR-W" => "コリエンテス",
+    "AR-X" => "コルドバ",
+    "AR-Y" => "フフイ",
+    "AR-Z" => "サンタクルス",
+    "AM-AR" => "アララト",
+    "AM-AV" => "アルマヴィル",
+    "AM-ER" => "エレバン",
+    "AU-ACT" => "オーストラリアキャピタルテリトリー",
+    "AU-NSW" => "ニューサウスウェールズ",
+    "AU-NT" => "ノーザンテリトリー",
+    "AU-QLD" => "クインズランド",
+    "AU-SA" => "サウスオーストラリア",
+    "AU-TAS" => "タスマニア",
+    "AU-VIC" => "ビクトリア",
+    "AU-WA" => "ウェスタンオーストラリア",
+    "AT-1" => "ブルケンラント",
+    "AT-2" => "ケルンテン",
+    "AT-3" => "ニーダーエスターライヒ",
+    "AT-4" => "オーバーエスターライヒ",
+    "AT-5" => "ザルツブルク",
+    "AT-6" => "シュタイアーマルク",
+    "AT-7" => "ティロル",
+    "AT-8" => "フォーラールベルク",
+    "AT-9" => "ウィーン",
+    "AZ-BA" => "バクー",
+    "AZ-QBA" => "クバ",
+    "AZ-SA" => "シェキ",
+    "AZ-SAK" => "シェキ",
+    "AZ-SAL" => "サルヤン",
+    "AZ-SM" => "スムカイト",
+    "BS-AK" => "アクリンズ島",
+    "BS-CI" => "キャット島",
+    "BS-IN" => "イナグア島",
+    "BS-LI" => "ロング島",
+    "BS-MG" => "マヤグアナ島",
+    "BS-SS" => "サンサルバドル",
+    "BD-01" => "バンドルボン",
+    "BD-05" => "バゲルハット",
+    "BD-11" => "コックスバザール",
+    "BD-12" => "チュアダンガ",
+    "BD-13" => "ダッカ",
+    "BD-14" => "ディナジプル",
+    "BD-15" => "ファリドプル",
+    "BD-21" => "ジャマールプル",
+    "BD-27" => "クールナ",
+    "BD-30" => "クシュティア",
+    "BD-47" => "ノアカリ",
+    "BD-51" => "パトゥアカリ",
+    "BD-54" => "ラージシャヒ",
+    "BD-55" => "ラングプル",
+    "BD-60" => "シルヘット",
+    "BD-63" => "タンガイル",
+    "BD-C" => "ダッカ",
+    "BD-D" => "クールナ",
+    "BD-E" => "ラージシャヒ",
+    "BD-F" => "ラングプル",
+    "BD-G" => "シルヘット",
+    "BB-03" => "セントジョージ",
+    "BB-05" => "セントジョン",
+    "BB-09" => "セントピーター",
+    "BB-10" => "セントフィリップ",
+    "BE-VAN" => "アントワープ",
+    "BE-VBR" => "フラームスブラバント",
+    "BE-VLI" => "リンブルフ",
+    "BE-VOV" => "オーストフラーンデレン",
+    "BE-VWV" => "ウエストフラーンデレン",
+    "BE-WAL" => "ワロン",
+    "BE-WBR" => "ブラバントワロン",
+    "BE-WHT" => "エノー",
+    "BE-WLG" => "リエージュ",
+    "BE-WLX" => "ルクセンブルク",
+    "BE-WNA" => "ナミュール",
+    "BZ-BZ" => "ベリーズ",
+    "BZ-CZL" => "コロサル",
+    "BZ-OW" => "オレンジウォーク",
+    "BZ-TOL" => "トレド",
+    "BJ-AL" => "アリボリ",
+    "BJ-AQ" => "アトランティック",
+    "BJ-BO" => "ボルゴー",
+    "BJ-CO" => "コリネ",
+    "BJ-DO" => "ドンガ",
+    "BJ-LI" => "リトラル",
+    "BJ-MO" => "モノ",
+    "BJ-OU" => "ウェメ",
+    "BJ-PL" => "プラトー",
+    "BJ-ZO" => "ズー",
+    "BT-11" => "パロ",
+    "BT-12" => "チュカ",
+    "BT-15" => "ティンプー",
+    "BT-21" => "チラン",
+    "BT-22" => "タカナ",
+    "BT-23" => "プナカ",
+    "BT-24" => "ワンドゥポダン",
+    "BT-31" => "サルパン",
+    "BT-33" => "ブムタン",
+    "BT-34" => "シェムガン",
+    "BT-41" => "タシガン",
+    "BT-42" => "モンガル",
+    "BT-GA" => "ガサ",
+    "BT-TY" => "ヤンツェ",
+    "BO-C" => "コチャバンバ",
+    "BO-L" => "ラパス",
+    "BO-N" => "パンド",
+    "BO-O" => "オルロ",
+    "BO-P" => "ポトシ",
+    "BO-S" => "サンタクルス",
+    "BO-T" => "タリハ",
+    "BA-BIH" => "ボスニアヘルツェゴビナ連邦",
+    "BA-SRP" => "スルプスカ共和国",
+    "BW-CE" => "セントラル",
+    "BW-GH" => "ガンジ",
+    "BW-KG" => "ガラガティ",
+    "BW-KL" => "ガトレング",
+    "BW-KW" => "クウェネング",
+    "BR-AC" => "アクレ",
+    "BR-AL" => "アラゴアス",
+    "BR-AM" => "アマゾナス",
+    "BR-AP" => "アマパ",
+    "BR-BA" => "バイア",
+    "BR-CE" => "セアラ",
+    "BR-ES" => "エスピリトサント",
+    "BR-GO" => "ゴイアス",
+    "BR-MA" => "マラニョン",
+    "BR-MG" => "ミナスジェライス",
+    "BR-MS" => "マトグロッソドスル",
+    "BR-MT" => "マトグロッソ",
+    "BR-PA" => "パラ",
+    "BR-PB" => "パライバ",
+    "BR-PE" => "ペルナンブコ",
+    "BR-PI" => "ピアウイ",
+    "BR-PR" => "パラナ",
+    "BR-RJ" => "リオデジャネイロ",
+    "BR-RN" => "リオグランデドノルテ",
+    "BR-RO" => "ロンドニア",
+    "BR-RR" => "ロライマ",
+    "BR-RS" => "リオグランデドスル",
+    "BR-SC" => "サンタカタリナ",
+    "BR-SE" => "セルジッペ",
+    "BR-SP" => "サンパウロ",
+    "BR-TO" => "トカンチンス",
+    "BN-BE" => "ブライト",
+    "BN-BM" => "ブルネイムアラ",
+    "BN-TE" => "トゥンブロン",
+    "BN-TU" => "トゥトン",
+    "BG-01" => "ブラゴエフグラード",
+    "BG-02" => "ブルガス",
+    "BG-03" => "ヴァルナ",
+    "BG-04" => "ヴェリコトゥルノヴォ",
+    "BG-05" => "ヴィディン",
+    "BG-06" => "ヴラツァ",
+    "BG-08" => "ドブリチ",
+    "BG-11" => "ロベチ",
+    "BG-12" => "モンタナ",
+    "BG-14" => "ペルニク",
+    "BG-15" => "プレヴェン",
+    "BG-16" => "プロヴディフ",
+    "BG-18" => "ルーセ",
+    "BG-20" => "スリヴェン",
+    "BG-23" => "ソフィア",
+    "BG-24" => "スタラザゴラ",
+    "BG-26" => "ハスコヴォ",
+    "BG-27" => "シューメン",
+    "BF-BAL" => "バレ",
+    "BF-BAM" => "バム",
+    "BF-BAN" => "バンワ",
+    "BF-BAZ" => "バゼーガ",
+    "BF-BGR" => "ブグリバ",
+    "BF-BLG" => "ブルグ",
+    "BF-BLK" => "ブルキエムデ",
+    "BF-COM" => "コモエ",
+    "BF-GAN" => "ガンズルグ",
+    "BF-GNA" => "グナグナ",
+    "BF-GOU" => "グルマ",
+    "BF-HOU" => "フエ",
+    "BF-IOB" => "イオバ",
+    "BF-KAD" => "カディオゴ",
+    "BF-KEN" => "ケネドゥグ",
+    "BF-KMD" => "コモンジャリ",
+    "BF-KMP" => "コンピエンガ",
+    "BF-KOP" => "クルペロゴ",
+    "BF-KOS" => "コッシ",
+    "BF-KOW" => "クルウェオゴ",
+    "BF-LER" => "レラバ",
+    "BF-LOR" => "ロルム",
+    "BF-MOU" => "ムフン",
+    "BF-NAM" => "ナメテンガ",
+    "BF-NAY" => "ナヤラ",
+    "BF-NOU" => "ヌンビエル",
+    "BF-OUB" => "ウブリテンガ",
+    "BF-OUD" => "ウダラン",
+    "BF-PAS" => "パッソレ",
+    "BF-PON" => "ポニ",
+    "BF-SEN" => "セノ",
+    "BF-SIS" => "シッシリ",
+    "BF-SMT" => "サンマテンガ",
+    "BF-SNG" => "サンギエ",
+    "BF-SOM" => "スム",
+    "BF-SOR" => "スル",
+    "BF-TAP" => "タポア",
+    "BF-YAG" => "ヤガ",
+    "BF-YAT" => "ヤテンガ",
+    "BF-ZIR" => "ジロ",
+    "BF-ZON" => "ゾンドマ",
+    "BF-ZOU" => "ズンドウェオゴ",
+    "BI-BB" => "ブバンザ",
+    "BI-BR" => "ブルリ",
+    "BI-CA" => "カンクゾ",
+    "BI-CI" => "チビトケ",
+    "BI-GI" => "ギテガ",
+    "BI-KI" => "キルンド",
+    "BI-KY" => "カヤンザ",
+    "BI-MA" => "マカンバ",
+    "BI-MU" => "ムランビア",
+    "BI-NG" => "ヌゴジ",
+    "BI-RY" => "ルイギ",
+    "CV-BV" => "ボアヴィスタ",
+    "CV-CA" => "サンタカタリナ",
+    "CV-CR" => "サンタクルス",
+    "CV-MA" => "マイオ島",
+    "CV-PR" => "プライア",
+    "CV-SL" => "サル",
+    "CV-SV" => "サンビセンテ島",
+    "KH-11" => "モンドルキリ",
+    "KH-12" => "プノンペン",
+    "KH-13" => "プレアビヒア",
+    "KH-14" => "プレイベーン",
+    "KH-15" => "プルサット",
+    "KH-16" => "ラタナキリ",
+    "KH-17" => "シエムリアプ",
+    "KH-20" => "スバイリエン",
+    "KH-21" => "タケオ",
+    "KH-22" => "オッドーミアンチェイ",
+    "KH-4" => "コンポンチュナン",
+    "KH-7" => "カンポット",
+    "CM-AD" => "アダマワ州",
+    "CM-EN" => "極北州",
+    "CM-ES" => "東部州",
+    "CM-LT" => "リトラル",
+    "CM-NO" => "北部州",
+    "CM-OU" => "西部州",
+    "CM-SU" => "南部州",
+    "CM-SW" => "南西州",
+    "CA-AB" => "アルバータ",
+    "CA-BC" => "ブリティッシュコロンビア",
+    "CA-MB" => "マニトバ",
+    "CA-NB" => "ニューブランズウィック",
+    "CA-NL" => "ニューファンドランド=ラブラドル",
+    "CA-NS" => "ノヴァスコシア",
+    "CA-NT" => "ノースウェストテリトリーズ",
+    "CA-NU" => "ヌナブット",
+    "CA-ON" => "オンタリオ",
+    "CA-PE" => "プリンスエドワードアイランド",
+    "CA-QC" => "ケベック",
+    "CA-SK" => "サスカチェワン",
+    "CF-AC" => "ウハム州",
+    "CF-BB" => "バミンギバンゴラン州",
+    "CF-BGF" => "バンギ",
+    "CF-BK" => "バスコト州",
+    "CF-HK" => "オートコト州",
+    "CF-HM" => "オームボム州",
+    "CF-HS" => "マンベレカデイ州",
+    "CF-LB" => "ロバイエ州",
+    "CF-MB" => "ムボム州",
+    "CF-NM" => "ナナメンベレ州",
+    "CF-OP" => "ウハムペンデ州",
+    "CF-UK" => "ワカ州",
+    "CF-VK" => "バカガ州州",
+    "CL-AN" => "アントファガスタ",
+    "CL-AT" => "アタカマ",
+    "CL-CO" => "コキンボ",
+    "CL-LL" => "ロスラゴス",
+    "CL-ML" => "マウレ",
+    "CL-TA" => "タラパカ",
+    "CL-VS" => "バルパライソ",
+    "CO-AMA" => "アマゾナス",
+    "CO-ANT" => "アンティオキア",
+    "CO-ARA" => "アラウカ",
+    "CO-ATL" => "アトランティコ",
+    "CO-BOL" => "ボリバル",
+    "CO-CAL" => "カルダス",
+    "CO-CAQ" => "カケタ",
+    "CO-CAS" => "カサナレ",
+    "CO-CAU" => "カウカ",
+    "CO-CES" => "セサール",
+    "CO-CHO" => "チョコ",
+    "CO-COR" => "コルドバ",
+    "CO-DC" => "ボゴタ",
+    "CO-GUA" => "グアイニア",
+    "CO-GUV" => "グアビアレ",
+    "CO-HUI" => "ウイラ",
+    "CO-MAG" => "マグダレナ",
+    "CO-MET" => "メタ",
+    "CO-NAR" => "ナリーニョ",
+    "CO-NSA" => "ノンテデサンタンデール",
+    "CO-PUT" => "プトゥマジョ",
+    "CO-QUI" => "キンディオ",
+    "CO-RIS" => "リサラルダ",
+    "CO-SAN" => "サンタンデール",
+    "CO-SUC" => "スクレ",
+    "CO-TOL" => "トリマ",
+    "CO-VAU" => "バウペス",
+    "CO-VID" => "ビチャダ",
+    "CG-11" => "ブエンザ",
+    "CG-12" => "プール",
+    "CG-14" => "プラトー",
+    "CG-2" => "レクム",
+    "CG-5" => "クイール",
+    "CG-7" => "リクアラ",
+    "CG-9" => "ニアリ",
+    "CG-BZV" => "ブラザビル",
+    "CD-EQ" => "赤道州",
+    "CD-KN" => "キンシャサ",
+    "CD-MA" => "マニエマ州",
+    "CD-NK" => "北キブ州",
+    "CD-SK" => "南キブ州",
+    "CR-A" => "アラフエラ",
+    "CR-C" => "カルタゴ",
+    "CR-G" => "グアナカステ",
+    "CR-H" => "エレディア",
+    "CR-L" => "リモン",
+    "CR-P" => "プンタレナス",
+    "CR-SJ" => "サンホゼ",
+    "CI-CM" => "コモエ",
+    "CU-04" => "マタンサス",
+    "CU-05" => "ビヤクララ",
+    "CU-06" => "シエンフエゴス",
+    "CU-07" => "サンクティスピリトゥス",
+    "CU-08" => "シエゴデアビラ",
+    "CU-09" => "カマグエイ",
+    "CU-10" => "ラストゥーナス",
+    "CU-11" => "オルギン",
+    "CU-12" => "グランマ",
+    "CU-13" => "サンティアゴデクーバ",
+    "CU-14" => "グアンタナモ",
+    "CU-99" => "ラフベントゥド島",
+    "CZ-311" => "ブジョヨビツェ",
+    "CZ-32" => "プルゼニ",
+    "CZ-41" => "カルロバリ",
+    "CZ-412" => "カルロバリ",
+    "CZ-42" => "ウースチー",
+    "CZ-425" => "モスト",
+    "CZ-51" => "リベレツ",
+    "CZ-52" => "クラードベーフラデツ",
+    "CZ-53" => "パルドゥビツェ",
+    "CZ-532" => "パルドゥビツェ",
+    "CZ-71" => "オロモウツ",
+    "CZ-712" => "オロモウツ",
+    "CZ-72" => "ズリーン",
+    "DJ-AR" => "アルタ",
+    "DJ-AS" => "アリサビエ",
+    "DJ-DI" => "ディキル",
+    "DJ-DJ" => "ジブチ",
+    "DJ-TA" => "タジュラ",
+    "DM-04" => "セントジョージ",
+    "DM-05" => "セントジョン",
+    "DM-10" => "セントポール",
+    "DM-11" => "セントピーター",
+    "DO-01" => "首都圏",
+    "DO-02" => "アスア",
+    "DO-04" => "バラオナ",
+    "DO-05" => "ダハボン",
+    "DO-06" => "ドゥアルテ",
+    "DO-09" => "エスパイジャット",
+    "DO-10" => "インディペンデンシア",
+    "DO-11" => "ラアルタグラシア",
+    "DO-12" => "ラロマーナ",
+    "DO-13" => "ラベーガ",
+    "DO-14" => "マリアトリニダードサンチェス",
+    "DO-15" => "モンテクリスティ",
+    "DO-16" => "ペデルナレス",
+    "DO-18" => "プエルトプラタ",
+    "DO-20" => "サマナ",
+    "DO-21" => "サンクリストバル",
+    "DO-22" => "サンファン",
+    "DO-23" => "サンペドロデマコリス",
+    "DO-24" => "サンチェスラミレス",
+    "DO-25" => "サンティアゴ",
+    "DO-27" => "バルベルデ",
+    "DO-28" => "モンセニョールノエル",
+    "DO-29" => "モンテプラタ",
+    "DO-30" => "アトマヨール",
+    "EC-A" => "アスアイ",
+    "EC-B" => "ボリバル",
+    "EC-C" => "カルチ",
+    "EC-D" => "オレジャナ",
+    "EC-E" => "エスメラルダス",
+    "EC-F" => "カニャル",
+    "EC-G" => "グアヤス",
+    "EC-H" => "チンボラソ",
+    "EC-I" => "インバブラ",
+    "EC-L" => "ロハ",
+    "EC-M" => "マナビ",
+    "EC-N" => "ナポ",
+    "EC-O" => "エルオロ",
+    "EC-P" => "ピチンチャ",
+    "EC-T" => "トゥングラウア",
+    "EC-U" => "スクンビオス",
+    "EC-W" => "ガラパゴス",
+    "EC-X" => "コトパクシ",
+    "EC-Y" => "パスタサ",
+    "EG-ASN" => "アスワン",
+    "EG-BA" => "紅海",
+    "EG-BNS" => "ベニスエフ",
+    "EG-FYM" => "ファユーム",
+    "EG-GH" => "ガルビーヤ",
+    "EG-KN" => "キーナ",
+    "EG-MN" => "ミニヤ",
+    "EG-SHG" => "ソハーグ",
+    "EG-SUZ" => "スエズ",
+    "SV-AH" => "アウアチャパン",
+    "SV-CA" => "カバニャス",
+    "SV-CH" => "チャラテナンゴ",
+    "SV-CU" => "クスカトラン",
+    "SV-LI" => "ラリベルタッド",
+    "SV-MO" => "モラサン",
+    "SV-PA" => "ラパス",
+    "SV-SA" => "サンタ・アナ",
+    "SV-SM" => "サン・ミゲル",
+    "SV-SO" => "ソンソナテ",
+    "SV-SS" => "サンサルバドル",
+    "SV-SV" => "サン・ビセンテ",
+    "SV-UN" => "ラウニオン",
+    "SV-US" => "ウスルタン",
+    "EE-205" => "ヒーウマー",
+    "EE-39" => "ヒーウマー",
+    "EE-714" => "サーレマー",
+    "EE-74" => "サーレマー",
+    "SZ-MA" => "マンジーニ",
+    "FJ-C" => "セントラル",
+    "FJ-R" => "ロトゥーマ",
+    "FI-10" => "ラッピ",
+    "FR-20R" => "コルス (コルシカ)",
+    "FR-39" => "ジュラ",
+    "FR-971" => "グアドループ",
+    "FR-972" => "マルティニーク",
+    "FR-976" => "マヨット",
+    "FR-BRE" => "ブルターニュ",
+    "FR-IDF" => "イルドフランス",
+    "GA-1" => "エスチュエール",
+    "GA-2" => "オートオグエ",
+    "GA-3" => "モアイヤンオグエ",
+    "GA-4" => "ングニエ",
+    "GA-5" => "ニヤンガ",
+    "GA-6" => "オグエイビンド",
+    "GA-7" => "オグエロロ",
+    "GA-8" => "オグエマリティーム",
+    "GA-9" => "ワォルンテム",
+    "GM-B" => "バンジュール",
+    "GE-AB" => "アブハジア",
+    "GE-AJ" => "アジァリア",
+    "DE-BB" => "ブランデンブルク",
+    "DE-BE" => "ベルリン",
+    "DE-BW" => "バーデンヴュルテンベルク",
+    "DE-BY" => "バイエルン",
+    "DE-HB" => "ブレーメン",
+    "DE-HE" => "ヘッセン",
+    "DE-HH" => "ハンブルク",
+    "DE-MV" => "メクレンブルク=フォアポンメルン",
+    "DE-NI" => "ニーダーザクセン",
+    "DE-NW" => "ノルトライン=ヴェストファーレン",
+    "DE-RP" => "ラインラントファルツ",
+    "DE-SH" => "シュレースヴィヒホルシュタイン",
+    "DE-SL" => "ザールラント",
+    "DE-SN" => "ザクセン",
+    "DE-ST" => "ザクセン=アンハルト",
+    "DE-TH" => "テューリンゲン",
+    "GH-AA" => "グレーターアクラ",
+    "GH-AH" => "アシャンティ",
+    "GH-CP" => "セントラル",
+    "GH-TV" => "ヴォルタ",
+    "GH-UE" => "アッパーイースト",
+    "GH-UW" => "アッパーウエスト",
+    "GD-03" => "セントジョージ",
+    "GD-04" => "セントジョン",
+    "GN-B" => "ボケ",
+    "GN-BE" => "ベイラ",
+    "GN-BF" => "ボッファ",
+    "GN-BK" => "ボケ",
+    "GN-C" => "コナクリ",
+    "GN-CO" => "コヤ",
+    "GN-D" => "キンディア",
+    "GN-DB" => "ダボラ",
+    "GN-DI" => "ディンギライ",
+    "GN-DL" => "ダラバ",
+    "GN-DU" => "デブレカ",
+    "GN-F" => "ファラナ",
+    "GN-FA" => "ファラナ",
+    "GN-FO" => "フォレカリア",
+    "GN-FR" => "フリア",
+    "GN-GA" => "ガワアル",
+    "GN-GU" => "ゲケドゥー",
+    "GN-K" => "カンカン",
+    "GN-KA" => "カンカン",
+    "GN-KB" => "クビア",
+    "GN-KD" => "キンディア",
+    "GN-KE" => "ケルアネ",
+    "GN-KN" => "クンダラ",
+    "GN-KO" => "クルーサ",
+    "GN-KS" => "キシドゥーグー",
+    "GN-L" => "ラベー",
+    "GN-LA" => "ラベー",
+    "GN-LE" => "レルーマ",
+    "GN-LO" => "ローラ",
+    "GN-M" => "マムー",
+    "GN-MC" => "マセンタ",
+    "GN-MD" => "マンディアナ",
+    "GN-ML" => "マリ",
+    "GN-MM" => "マムー",
+    "GN-N" => "ゼレコレ",
+    "GN-NZ" => "ゼレコレ",
+    "GN-PI" => "ピタ",
+    "GN-SI" => "シギリ",
+    "GN-TE" => "テリメレ",
+    "GN-TO" => "トゥゲ",
+    "GN-YO" => "ヨムー",
+    "GW-BA" => "バファター",
+    "GW-BM" => "ビオンボ",
+    "GW-BS" => "ビサウ",
+    "GW-CA" => "カシュウ",
+    "GW-GA" => "ガブー",
+    "GW-OI" => "オイオ",
+    "GW-QU" => "キナラ",
+    "GW-TO" => "トンバリ",
+    "GY-BA" => "バリマワイニ",
+    "GY-CU" => "クユニマザルニ",
+    "GY-DE" => "デメララマハイカ",
+    "GY-EB" => "イーストバービーズコレンタイン",
+    "GY-ES" => "エセキポアイランズウエストデメララ",
+    "GY-MA" => "マハイカバービーズ",
+    "GY-PM" => "ポメルーンスペナーム",
+    "GY-PT" => "ポタロシパルニ",
+    "GY-UD" => "アッパーデメララバービーズ",
+    "GY-UT" => "アッパータクトゥアッパーエセキポ",
+    "HT-AR" => "アルトヴィン",
+    "HT-NE" => "北東県",
+    "HT-NO" => "北西県",
+    "HN-AT" => "アトランティダ",
+    "HN-CH" => "チョルテカ",
+    "HN-CL" => "コロン",
+    "HN-CM" => "コマイアグア",
+    "HN-CP" => "コパン",
+    "HN-CR" => "コルテス",
+    "HN-EP" => "エルパライソ",
+    "HN-FM" => "フランシスコモラサン",
+    "HN-GD" => "グラシアスアディオス",
+    "HN-IN" => "インティブカ",
+    "HN-LE" => "レンピラ",
+    "HN-LP" => "ラパス",
+    "HN-OC" => "オコテペク",
+    "HN-OL" => "オランチョ",
+    "HN-SB" => "サンタバルバラ",
+    "HN-YO" => "ヨロ",
+    "HU-BA" => "バラニャ",
+    "HU-BE" => "ベーケーシュ",
+    "HU-BK" => "バーチキシュクン",
+    "HU-BU" => "ブダペスト",
+    "HU-BZ" => "ボルショドアバウジゼムプレーン",
+    "HU-CS" => "チョングラード",
+    "HU-DE" => "デブレツェン",
+    "HU-FE" => "フェイエール",
+    "HU-GS" => "ジェールモションショプロン",
+    "HU-HB" => "ハイデュービハル",
+    "HU-HE" => "ヘベシュ",
+    "HU-JN" => "ヤースナジクンソルノク",
+    "HU-KE" => "コマーロムエステルゴム",
+    "HU-MI" => "ミシュコルツ",
+    "HU-NO" => "ノーグラード",
+    "HU-PE" => "ペシュト",
+    "HU-SO" => "ソモジ",
+    "HU-SZ" => "サボルチサトマールベレグ",
+    "HU-TO" => "トルナ",
+    "HU-VA" => "バシュ",
+    "HU-VE" => "ベスフレーム",
+    "HU-VM" => "ベスフレーム",
+    "HU-ZA" => "ザラ",
+    "IS-3" => "ベストゥルラント",
+    "IS-4" => "ベストフィルジル",
+    "IS-5" => "ノルズルラントベストラ",
+    "IS-6" => "ノルズルラントエイストラ",
+    "IS-7" => "オイストゥルラント",
+    "IS-8" => "スーズルラント",
+    "IN-AN" => "アンダマン=ニコバル諸島",
+    "IN-AP" => "アンドラプラデシュ",
+    "IN-AS" => "アッサム",
+    "IN-DL" => "デリー",
+    "IN-GA" => "ゴア",
+    "IN-KL" => "ケーララ",
+    "IN-LD" => "ラクシャドウィープ",
+    "IN-MN" => "マニプル",
+    "IN-MP" => "マッディアプラデシュ",
+    "IN-MZ" => "ミゾラム",
+    "IN-PB" => "パンジャーブ",
+    "IN-SK" => "シッキム",
+    "IN-TR" => "トリプラ",
+    "IN-UP" => "ウッタルプラデシュ",
+    "IN-WB" => "ウェストベンガル",
+    "ID-AC" => "アチェ",
+    "ID-BA" => "バリ",
+    "ID-BE" => "ブンクル",
+    "ID-GO" => "ゴロンタロ",
+    "ID-JA" => "ジャンビ",
+    "ID-JB" => "ジャワ州",
+    "ID-JI" => "東ジャワ",
+    "ID-JT" => "中部ジャワ",
+    "ID-JW" => "ジャワ",
+    "ID-KA" => "カリマンタン",
+    "ID-KB" => "カリマンタン州",
+    "ID-KI" => "東カリマンタン",
+    "ID-KS" => "南カリマンタン",
+    "ID-KT" => "中部カリマンタン",
+    "ID-LA" => "ランポン",
+    "ID-MA" => "マルク",
+    "ID-ML" => "マルク",
+    "ID-MU" => "北マルク",
+    "ID-NB" => "ヌサトゥンガラ州",
+    "ID-NT" => "東ヌサトゥンガラ",
+    "ID-NU" => "ヌサトゥンガラ",
+    "ID-PA" => "パプア",
+    "ID-PB" => "パプア州",
+    "ID-PP" => "パプア",
+    "ID-RI" => "リアウ",
+    "ID-SA" => "北スラウェシ",
+    "ID-SG" => "東南スラウェシ",
+    "ID-SL" => "スラウェシ",
+    "ID-SM" => "スマトラ",
+    "ID-SN" => "南スラウェシ",
+    "ID-SR" => "スラウェシ州",
+    "ID-ST" => "中部スラウェシ",
+    "ID-SU" => "北スマトラ",
+    "ID-YO" => "ジョクジャカルタ",
+    "IR-00" => "マルキャズィ",
+    "IR-01" => "ギーラーン",
+    "IR-02" => "マーザンダラン",
+    "IR-05" => "ケルマンシャー",
+    "IR-06" => "フーゼスタン",
+    "IR-07" => "ファールス",
+    "IR-08" => "ケルマーン",
+    "IR-10" => "イスファハン",
+    "IR-11" => "シスタンバルスチスタン",
+    "IR-12" => "クルディスタン",
+    "IR-13" => "ハマダーン",
+    "IR-15" => "ロレスタン",
+    "IR-16" => "イーラーム",
+    "IR-18" => "ブシエール",
+    "IR-19" => "ザンジャーン",
+    "IR-20" => "セムナーン",
+    "IR-21" => "ヤズド",
+    "IR-22" => "ホルモズガン",
+    "IR-23" => "テヘラン",
+    "IR-24" => "アルダビール",
+    "IR-25" => "コム",
+    "IR-26" => "ガズヴィーン",
+    "IR-27" => "ゴレスタン",
+    "IE-CE" => "クレア",
+    "IE-CO" => "コーク",
+    "IE-D" => "ダブリン",
+    "IE-DL" => "ドニゴール",
+    "IE-G" => "ゴルウェー",
+    "IE-KE" => "キルデア",
+    "IE-KK" => "キルケニ",
+    "IE-KY" => "ケリー",
+    "IE-LD" => "ロングフォード",
+    "IE-LK" => "リムリック",
+    "IE-LM" => "リートリム",
+    "IE-MH" => "ミース",
+    "IE-MN" => "モナガン",
+    "IE-MO" => "メーオー",
+    "IE-OY" => "オファリー",
+    "IE-RN" => "ロスコモン",
+    "IE-SO" => "スライゴ",
+    "IE-TA" => "ティペレアリ",
+    "IE-WD" => "ウォーターフォード",
+    "IE-WH" => "ウェストミース",
+    "IE-WW" => "ウィックロー",
+    "IE-WX" => "ウェクスフォード",
+    "IT-21" => "ピエモンテ",
+    "IT-25" => "ロンバルディア",
+    "IT-32" => "トレンティノ=アルトアディジェ",
+    "IT-34" => "ヴェネト",
+    "IT-42" => "リグーリア",
+    "IT-45" => "エミリア=ロマーニャ",
+    "IT-52" => "トスカーナ",
+    "IT-55" => "ウンブリア",
+    "IT-57" => "マルケ",
+    "IT-62" => "ラツィオ",
+    "IT-65" => "アブルッツォ",
+    "IT-67" => "モリーゼ",
+    "IT-72" => "カンパニア",
+    "IT-75" => "プーリア",
+    "IT-77" => "バジリカータ",
+    "IT-78" => "カラブリア",
+    "IT-82" => "シチリア",
+    "IT-88" => "サルデーニャ",
+    "IT-AG" => "アグリジェント",
+    "IT-AN" => "アンコーナ",
+    "IT-AP" => "アスコリピチェーノ",
+    "IT-AQ" => "ラクイラ",
+    "IT-AR" => "アレッツォ",
+    "IT-AT" => "アスティ",
+    "IT-AV" => "アヴェリーノ",
+    "IT-BA" => "バリ",
+    "IT-BG" => "ベルガモ",
+    "IT-BN" => "ベネヴェント",
+    "IT-BO" => "ボローニャ",
+    "IT-BR" => "ブリンディジ",
+    "IT-BS" => "ブレシア",
+    "IT-CA" => "カリアリ",
+    "IT-CH" => "キエティ",
+    "IT-CL" => "カルタニセッタ",
+    "IT-CN" => "クーネオ",
+    "IT-CO" => "コモ",
+    "IT-CS" => "コゼンツァ",
+    "IT-CT" => "カターニア",
+    "IT-CZ" => "カタンザーロ",
+    "IT-EN" => "エンナ",
+    "IT-FE" => "フェラーラ",
+    "IT-FG" => "フォッジャ",
+    "IT-FI" => "フィレンツェ",
+    "IT-FR" => "フロジノーネ",
+    "IT-GE" => "ジェノバ",
+    "IT-IS" => "イゼルニア",
+    "IT-KR" => "クロトーネ",
+    "IT-LE" => "レッチェ",
+    "IT-LI" => "リヴォルノ",
+    "IT-MC" => "マチェラータ",
+    "IT-ME" => "メッシーナ",
+    "IT-MI" => "ミラノ",
+    "IT-MO" => "モデナ",
+    "IT-MT" => "マテーラ",
+    "IT-NA" => "ナポリ",
+    "IT-NO" => "ノヴァーラ",
+    "IT-PA" => "パレルモ",
+    "IT-PC" => "ピアチェンツァ",
+    "IT-PD" => "パドヴァ",
+    "IT-PE" => "ペスカーラ",
+    "IT-PG" => "ペルージャ",
+    "IT-PR" => "パルマ",
+    "IT-PZ" => "ポテンツァ",
+    "IT-RA" => "ラヴェンナ",
+    "IT-RC" => "レッジョディカラブリア",
+    "IT-RG" => "ラグーサ",
+    "IT-RM" => "ローマ",
+    "IT-RN" => "リミニ",
+    "IT-SA" => "サレルノ",
+    "IT-SI" => "シエナ",
+    "IT-SO" => "ソンドリオ",
+    "IT-SR" => "シラクーサ",
+    "IT-SS" => "サッサリ",
+    "IT-SV" => "サヴォーナ",
+    "IT-TA" => "タラント",
+    "IT-TN" => "トレント",
+    "IT-TO" => "トリノ",
+    "IT-TP" => "トラパニ",
+    "IT-TS" => "トリエステ",
+    "IT-TV" => "トレヴィーゾ",
+    "IT-UD" => "ウーディネ",
+    "IT-VE" => "ヴェネツィア",
+    "IT-VI" => "ヴィチェンツァ",
+    "IT-VR" => "ヴェローナ",
+    "IT-VT" => "ヴィテルボ",
+    "JM-01" => "キングストン",
+    "JM-04" => "ポートランド",
+    "JM-05" => "セントメアリー",
+    "JM-06" => "セントアン",
+    "JM-07" => "トリローニー",
+    "JM-09" => "ハノーバー",
+    "JM-10" => "ウェストモアランド",
+    "JM-11" => "セントエリザベス",
+    "JM-12" => "マンチェスター",
+    "JM-13" => "クラレンドン",
+    "JM-14" => "セントキャサリン",
+    "JP-01" => "北海道",
+    "JP-02" => "青森",
+    "JP-03" => "岩手",
+    "JP-04" => "宮城",
+    "JP-05" => "秋田",
+    "JP-06" => "山形",
+    "JP-07" => "福島",
+    "JP-08" => "茨城",
+    "JP-09" => "栃木",
+    "JP-10" => "群馬",
+    "JP-11" => "埼玉",
+    "JP-12" => "千葉",
+    "JP-13" => "東京",
+    "JP-14" => "神奈川",
+    "JP-15" => "新潟",
+    "JP-16" => "富山",
+    "JP-17" => "石川",
+    "JP-18" => "福井",
+    "JP-19" => "山梨",
+    "JP-20" => "長野",
+    "JP-21" => "岐阜",
+    "JP-22" => "静岡",
+    "JP-23" => "愛知",
+    "JP-24" => "三重",
+    "JP-25" => "滋賀",
+    "JP-26" => "京都",
+    "JP-27" => "大阪",
+    "JP-28" => "兵庫",
+    "JP-29" => "奈良",
+    "JP-30" => "和歌山",
+    "JP-31" => "鳥取",
+    "JP-32" => "島根",
+    "JP-33" => "岡山",
+    "JP-34" => "広島",
+    "JP-35" => "山口",
+    "JP-36" => "徳島",
+    "JP-37" => "香川",
+    "JP-38" => "愛媛",
+    "JP-39" => "高知",
+    "JP-40" => "福岡",
+    "JP-41" => "佐賀",
+    "JP-42" => "長崎",
+    "JP-43" => "熊本",
+    "JP-44" => "大分",
+    "JP-45" => "宮崎",
+    "JP-46" => "鹿児島",
+    "JP-47" => "沖縄",
+    "JO-AJ" => "アジュルン",
+    "JO-AQ" => "アカバ",
+    "JO-AT" => "タフィーラ",
+    "JO-IR" => "イルビド",
+    "JO-JA" => "ジャラシュ",
+    "JO-KA" => "カラク",
+    "JO-MA" => "マフラク",
+    "JO-MD" => "マーダバー",
+    "JO-MN" => "マアーン",
+    "KE-04" => "ブシア",
+    "KI-G" => "ギルバート諸島",
+    "KI-L" => "ライン諸島",
+    "KI-P" => "フェニックス諸島",
+    "KP-04" => "チャガンド(慈江道)",
+    "KP-05" => "ファンヘナムド(黄海南道)",
+    "KP-06" => "ファンヘブクド(黄海北道)",
+    "KW-FA" => "ファルワーニーヤ",
+    "KG-B" => "バトケン",
+    "KG-N" => "ナルイン",
+    "KG-O" => "オシ",
+    "KG-T" => "タラス",
+    "LA-AT" => "アタプー",
+    "LA-BK" => "ボケオ",
+    "LA-CH" => "チャンパサック",
+    "LA-HO" => "フワパン",
+    "LA-KH" => "カムアン",
+    "LA-LM" => "ルアンナムタ",
+    "LA-LP" => "ルアンパバーン",
+    "LA-OU" => "ウドムサイ",
+    "LA-PH" => "ポーンサリー",
+    "LA-SL" => "サラワン",
+    "LA-SV" => "サバナケット",
+    "LA-XA" => "サイヤブリ",
+    "LA-XE" => "セコン",
+    "LV-DGV" => "ダウガフピルス",
+    "LV-JEL" => "エルガヴァ",
+    "LV-LPX" => "リエパヤ",
+    "LV-REZ" => "レゼクネ",
+    "LV-RIX" => "リガ",
+    "LB-BH" => "バールベック",
+    "LS-A" => "マセル",
+    "LS-E" => "マフェテング",
+    "LS-F" => "モハレスフーク",
+    "LS-H" => "カチャズネック",
+    "LS-J" => "モコトロング",
+    "LR-BG" => "ボング",
+    "LR-BM" => "ボミ",
+    "LR-CM" => "グランドケープマウント",
+    "LR-GB" => "グランドバッサ",
+    "LR-GG" => "グランドゲデー",
+    "LR-GK" => "グランドクルー",
+    "LR-LO" => "ロファ",
+    "LR-MG" => "マルギビ",
+    "LR-MO" => "モントセラド",
+    "LR-MY" => "メリーランド",
+    "LR-NI" => "ニンバ",
+    "LR-SI" => "シノー",
+    "LY-BA" => "ベンガジ",
+    "LY-DR" => "ダルナ",
+    "LY-GT" => "ガート",
+    "LY-KF" => "クフラ",
+    "LY-MI" => "ミスラータ",
+    "LY-MQ" => "ムルズーク",
+    "LY-NL" => "ナールート",
+    "LY-SB" => "セブハー",
+    "LY-SR" => "スルト",
+    "LY-ZA" => "ザーウィア",
+    "LI-01" => "バルツェルス",
+    "LI-04" => "マウレン",
+    "LI-07" => "シャーン",
+    "LI-11" => "ファドゥーツ",
+    "LU-DI" => "ディーキルヒ",
+    "LU-GR" => "グレーベンマッハー",
+    "LU-LU" => "ルクセンブルク",
+    "MG-A" => "トアマシナ",
+    "MG-D" => "アンツィラナナ",
+    "MG-F" => "フィアナランツォア",
+    "MG-M" => "マハジャンガ",
+    "MG-T" => "アンタナナリボ",
+    "MG-U" => "トリアラ",
+    "MW-C" => "中部",
+    "MW-LI" => "リロングウェ",
+    "MW-N" => "北部",
+    "MW-S" => "南部",
+    "MY-01" => "ジョホール",
+    "MY-03" => "クランタン",
+    "MY-05" => "ヌグリスンビラン",
+    "MY-06" => "パハン",
+    "MY-09" => "ペルリス",
+    "MY-10" => "スランゴール",
+    "MY-11" => "トレンガヌ",
+    "MY-12" => "サバ",
+    "MY-13" => "サラワク",
+    "MV-MLE" => "マレ",
+    "ML-1" => "カエス",
+    "ML-2" => "クリコロ",
+    "ML-3" => "シカソ",
+    "ML-4" => "セグー",
+    "ML-5" => "モプティ",
+    "ML-6" => "トンブクトゥ",
+    "ML-7" => "ガオ",
+    "ML-8" => "キダル",
+    "ML-BKO" => "バマコ",
+    "MT-04" => "ビルキルカーラ",
+    "MT-28" => "マルサシュロック",
+    "MT-47" => "サフィー",
+    "MT-49" => "セントジョン",
+    "MT-60" => "バレッタ",
+    "MH-KWA" => "クワジャリン",
+    "MH-L" => "ラリク列島",
+    "MH-MAJ" => "マジュロ",
+    "MH-MAL" => "マロエラプ",
+    "MH-T" => "ラタク列島",
+    "MR-01" => "ホッドエッシャルギ",
+    "MR-03" => "アッサバ",
+    "MR-04" => "ゴルゴル",
+    "MR-05" => "ブラクナ",
+    "MR-06" => "トラルザ",
+    "MR-07" => "アドラル",
+    "MR-09" => "タガント",
+    "MR-10" => "ギディマカ",
+    "MR-11" => "ティリスゼムール",
+    "MR-12" => "インシリ",
+    "MU-PL" => "ポートルイス",
+    "MX-AGU" => "アグアスカリエンテス",
+    "MX-BCN" => "バハカリフォルニア",
+    "MX-BCS" => "バハカリフォルニアスル",
+    "MX-CAM" => "カンペチェ",
+    "MX-CHH" => "チワワ",
+    "MX-CHP" => "チアパス",
+    "MX-COL" => "コリマ",
+    "MX-DUR" => "ドゥランゴ",
+    "MX-GRO" => "ゲレロ",
+    "MX-GUA" => "グアナフアト",
+    "MX-HID" => "イダルゴ",
+    "MX-JAL" => "ハリスコ",
+    "MX-MEX" => "メキシコ",
+    "MX-MOR" => "モレロス",
+    "MX-NAY" => "ナヤリット",
+    "MX-NLE" => "ヌエボレオン",
+    "MX-OAX" => "オアハカ",
+    "MX-PUE" => "プエブラ",
+    "MX-QUE" => "ケレタロ",
+    "MX-ROO" => "キンタナロー",
+    "MX-SIN" => "シナロア",
+    "MX-SLP" => "サンルイスポトシ",
+    "MX-SON" => "ソノラ",
+    "MX-TAB" => "タバスコ",
+    "MX-TAM" => "タマウリパス",
+    "MX-TLA" => "トラスカラ",
+    "MX-YUC" => "ユカタン",
+    "MX-ZAC" => "サカテカス",
+    "FM-KSA" => "コスラエ",
+    "FM-PNI" => "ポーンペイ",
+    "FM-YAP" => "ヤップ",
+    "MD-BA" => "バルツィ",
+    "MD-CU" => "キシナウ",
+    "MC-CO" => "ラコンダミーヌ",
+    "MC-FO" => "フォンビエイユ",
+    "MC-MC" => "モンテカルロ",
+    "MN-067" => "ブルガン",
+    "MN-069" => "バヤンホンゴル",
+    "ME-12" => "ニクシッチ",
+    "ME-16" => "ポドゴリツァ",
+    "MA-AZI" => "アジラル",
+    "MA-FIG" => "フィギグ",
+    "MA-GUE" => "ゲルミン",
+    "MA-IFR" => "イフラーヌ",
+    "MA-JDI" => "ジャディーダ",
+    "MA-KEN" => "ケニトラ",
+    "MA-KHO" => "クーリブガ",
+    "MA-LAR" => "ララシェ",
+    "MA-NAD" => "ナドル",
+    "MA-RAB" => "ラバト",
+    "MA-SAF" => "サフィー",
+    "MA-SET" => "セッタート",
+    "MA-TAO" => "タウナト",
+    "MA-TAT" => "タタ",
+    "MA-TAZ" => "タザ",
+    "MA-TET" => "テトワン",
+    "MA-TIZ" => "ティズニト",
+    "MZ-A" => "ニアサ",
+    "MZ-B" => "マニカ",
+    "MZ-G" => "ガザ",
+    "MZ-I" => "イニャンバネ",
+    "MZ-L" => "マプト",
+    "MZ-MPM" => "マプト",
+    "MZ-P" => "カーボデルガード",
+    "MZ-S" => "ソファラ",
+    "MZ-T" => "テテ",
+    "MM-01" => "サガイン",
+    "MM-02" => "バゴー",
+    "MM-03" => "マグウェ",
+    "MM-04" => "マンダレー",
+    "MM-05" => "タニンダリー",
+    "MM-06" => "ヤンゴン",
+    "MM-07" => "エーヤワディー",
+    "MM-11" => "カチン",
+    "MM-12" => "カヤー",
+    "MM-14" => "チン",
+    "MM-15" => "モン",
+    "MM-16" => "ラカイン",
+    "MM-17" => "シャン",
+    "NA-ER" => "エロンゴ",
+    "NA-HA" => "ハルダプ",
+    "NA-KH" => "ホマス",
+    "NA-KU" => "ケネネ",
+    "NA-OD" => "オチョゾンデュパ",
+    "NA-OH" => "オマヘケ",
+    "NA-ON" => "オシャナ",
+    "NA-OS" => "オムサティ",
+    "NA-OT" => "オシコト",
+    "NA-OW" => "オハンウェナ",
+    "NR-02" => "アナバル",
+    "NR-04" => "アニバレ",
+    "NR-14" => "ヤレン",
+    "NL-AW" => "アルーバ",
+    "NL-CW" => "キュラソー",
+    "NL-DR" => "ドレンテ",
+    "NL-FL" => "フレーフォラント",
+    "NL-GE" => "ヘルデルラント",
+    "NL-GR" => "フローニンゲン",
+    "NL-LI" => "リンブルフ",
+    "NL-NB" => "ノールトブラーバント",
+    "NL-NH" => "ノールトホラント",
+    "NL-OV" => "オーフェルアイセル",
+    "NL-UT" => "ユトレヒト",
+    "NL-ZE" => "ゼーラント",
+    "NL-ZH" => "ザイトホラント",
+    "NZ-AUK" => "オークランド",
+    "NZ-BOP" => "ベイオブプレンティ",
+    "NZ-CAN" => "カンタベリー",
+    "NZ-HKB" => "ホークスベイ",
+    "NZ-MWT" => "マナワツウォンガヌイ",
+    "NZ-NTL" => "ノースランド",
+    "NZ-OTA" => "オターゴ",
+    "NZ-STL" => "サウスランド",
+    "NZ-WGN" => "ウェリントン",
+    "NZ-WKO" => "ワイカト",
+    "NZ-WTC" => "ウエストコースト",
+    "NI-BO" => "ボアコ",
+    "NI-CA" => "カラソ",
+    "NI-CI" => "チナンデガ",
+    "NI-CO" => "チョンタレス",
+    "NI-ES" => "エステリ",
+    "NI-GR" => "グラナダ",
+    "NI-JI" => "ヒノテガ",
+    "NI-LE" => "レオン",
+    "NI-MD" => "マドリス",
+    "NI-MN" => "マナグア",
+    "NI-MS" => "マサヤ",
+    "NI-MT" => "マタガルパ",
+    "NI-NS" => "ヌエバセゴビア",
+    "NI-RI" => "リバス",
+    "NI-SJ" => "リオサンファン",
+    "NE-1" => "アガデズ",
+    "NE-2" => "ディファ",
+    "NE-3" => "ドーソ",
+    "NE-4" => "マラディ",
+    "NE-5" => "タウア",
+    "NE-6" => "ティラベリ",
+    "NE-7" => "ジンデル",
+    "NE-8" => "ニアメ",
+    "NG-AB" => "アビア",
+    "NG-AD" => "アダマワ",
+    "NG-AK" => "アクワイボム",
+    "NG-AN" => "アナンブラ",
+    "NG-BA" => "バウチ",
+    "NG-BE" => "ベルエ",
+    "NG-BO" => "ボルノ",
+    "NG-BY" => "バイエルサ",
+    "NG-CR" => "クロスリバー",
+    "NG-DE" => "デルタ",
+    "NG-EB" => "エボニー",
+    "NG-ED" => "エド",
+    "NG-EK" => "エキティ",
+    "NG-EN" => "エヌーグー",
+    "NG-GO" => "ゴンベ",
+    "NG-IM" => "イモ",
+    "NG-JI" => "ジガワ",
+    "NG-KD" => "カドゥナ",
+    "NG-KE" => "ケビ",
+    "NG-KN" => "カノ",
+    "NG-KO" => "コギ",
+    "NG-KT" => "カッシナ",
+    "NG-KW" => "クワラ",
+    "NG-LA" => "ラゴス",
+    "NG-NI" => "ナイジャー",
+    "NG-OG" => "オグン",
+    "NG-ON" => "オンド",
+    "NG-OS" => "オスン",
+    "NG-OY" => "オヨ",
+    "NG-PL" => "プラトー",
+    "NG-RI" => "リバーズ",
+    "NG-SO" => "ソコト",
+    "NG-TA" => "タラバ",
+    "NG-YO" => "ヨベ",
+    "NG-ZA" => "ザンファラ",
+    "MK-303" => "デバル",
+    "MK-310" => "オフリド",
+    "MK-312" => "ストルガ",
+    "MK-501" => "ビトラ",
+    "MK-508" => "プリレップ",
+    "MK-604" => "ゴスティバル",
+    "MK-609" => "テトボ",
+    "MK-703" => "クマノボ",
+    "MK-802" => "アラチノボ",
+    "NO-03" => "オスロ",
+    "NO-11" => "ローガラン",
+    "NO-15" => "メーレオロムスダール",
+    "NO-18" => "ノールラン",
+    "OM-MA" => "マスカット",
+    "OM-MU" => "ムサンダム",
+    "OM-ZA" => "ザーヒラ",
+    "OM-ZU" => "ズファール",
+    "PK-BA" => "バルチスターン",
+    "PK-GB" => "ギルギットバルチスタン",
+    "PK-IS" => "イスラマバード",
+    "PK-KP" => "ハイバルパフトゥンハー",
+    "PK-PB" => "パンジャーブ",
+    "PK-SD" => "シンド",
+    "PW-010" => "アンガウル",
+    "PW-150" => "コロール",
+    "PW-350" => "ペリリュー",
+    "PS-BTH" => "ベツレヘム",
+    "PS-GZA" => "ガザ",
+    "PS-HBN" => "ヘブロン",
+    "PS-JEM" => "エルサレム",
+    "PS-QQA" => "カルキリヤ",
+    "PA-1" => "ボカスデルトロ",
+    "PA-2" => "コクレ",
+    "PA-3" => "コロン",
+    "PA-4" => "チリキ",
+    "PA-5" => "ダリエン",
+    "PA-6" => "エレーラ",
+    "PA-7" => "ロスサントス",
+    "PA-8" => "パナマ",
+    "PA-9" => "ベアグアス",
+    "PA-EM" => "エンベラ",
+    "PA-NB" => "ノーベブグレ",
+    "PG-CPK" => "チンブ",
+    "PG-CPM" => "セントラル",
+    "PG-EBR" => "東ニューブリテン",
+    "PG-EHG" => "東ハイランド",
+    "PG-EPW" => "エンガ",
+    "PG-ESW" => "東セピック",
+    "PG-GPK" => "ガルフ",
+    "PG-MBA" => "ミルンベイ",
+    "PG-MPL" => "モロベ",
+    "PG-MPM" => "マダン",
+    "PG-MRL" => "マヌス島",
+    "PG-NCD" => "首都特別区 (ポートモレスビー)",
+    "PG-NIK" => "ニューアイルランド",
+    "PG-NSB" => "ブーゲンビル",
+    "PG-SHM" => "南ハイランド",
+    "PG-WBK" => "西ニューブリテン",
+    "PG-WHM" => "西ハイランド",
+    "PY-1" => "コンセプシオン",
+    "PY-10" => "アルトパラナ",
+    "PY-11" => "セントラル",
+    "PY-12" => "ニェエンプケ",
+    "PY-13" => "アマンバイ",
+    "PY-14" => "カネンディユ",
+    "PY-15" => "プレジデンテアイエス",
+    "PY-16" => "アルトパラグアイ",
+    "PY-19" => "ボケロン",
+    "PY-2" => "サンペドロ",
+    "PY-3" => "コルディエラ",
+    "PY-4" => "グアイラ",
+    "PY-5" => "カーグア",
+    "PY-6" => "カアサパ",
+    "PY-7" => "イタプア",
+    "PY-8" => "ミシオネス",
+    "PY-9" => "パラグアリ",
+    "PY-ASU" => "アスンシオン",
+    "PE-ANC" => "アンカシュ",
+    "PE-ARE" => "アレキパ",
+    "PE-AYA" => "アヤクチョ",
+    "PE-CAJ" => "カハマルカ",
+    "PE-CAL" => "カリャオ憲法特別市",
+    "PE-HUC" => "ワヌコ",
+    "PE-HUV" => "ワンカベリカ",
+    "PE-ICA" => "イカ",
+    "PE-LAL" => "ラリベルタッド",
+    "PE-LAM" => "ランバイエケ",
+    "PE-LIM" => "リマ",
+    "PE-LOR" => "ロレト",
+    "PE-MDD" => "マドレデディオス",
+    "PE-MOQ" => "モケグア",
+    "PE-PAS" => "パスコ",
+    "PE-PIU" => "ピウラ",
+    "PE-PUN" => "プノ",
+    "PE-TAC" => "タクナ",
+    "PE-TUM" => "トゥンペス",
+    "PE-UCA" => "ウカヤリ",
+    "PH-11" => "ダバオ",
+    "PH-AGN" => "北アグサン",
+    "PH-AGS" => "南アグサン",
+    "PH-BOH" => "ボホール",
+    "PH-BTG" => "バタンガス",
+    "PH-BUK" => "ブキドノン",
+    "PH-CAM" => "カミギン",
+    "PH-CEB" => "セブ",
+    "PH-DAS" => "南ダバオ",
+    "PH-DIN" => "ディナガットアイランズ",
+    "PH-ILI" => "イロイロ",
+    "PH-ISA" => "イサベラ",
+    "PH-LAN" => "北ラナオ",
+    "PH-LAS" => "南ラナオ",
+    "PH-LEY" => "レイテ",
+    "PH-MAS" => "マスバテ",
+    "PH-MSC" => "西ミサミス",
+    "PH-MSR" => "東ミサミス",
+    "PH-NER" => "東ネグロス",
+    "PH-QUE" => "ケソン",
+    "PH-SAR" => "サランガニ",
+    "PH-SCO" => "南コタバト",
+    "PH-SLE" => "南レイテ",
+    "PH-SLU" => "スールー",
+    "PH-SUK" => "スルタンクダラット",
+    "PH-SUN" => "北スリガオ",
+    "PH-SUR" => "南スリガオ",
+    "PH-ZAN" => "北サンボアンガ",
+    "PH-ZAS" => "南サンボアンガ",
+    "PH-ZSI" => "サンボアンガシブガイ",
+    "PL-02" => "ドルノシロンスク",
+    "PL-04" => "クヤウスコポモジェ",
+    "PL-08" => "ルブスコ",
+    "PL-12" => "マウォポルスカ",
+    "PL-18" => "ポドカルパチ",
+    "PL-20" => "ポドラスカ",
+    "PL-28" => "ワルミンスコマズーリ",
+    "PL-32" => "ザホドニオポモジェ",
+    "PT-01" => "アヴェイロ",
+    "PT-02" => "ベジャ",
+    "PT-03" => "ブラガ",
+    "PT-04" => "ブラガンサ",
+    "PT-05" => "カステロブランコ",
+    "PT-06" => "コインブラ",
+    "PT-07" => "エヴォラ",
+    "PT-08" => "ファロ",
+    "PT-09" => "グアルダ",
+    "PT-10" => "レイリア",
+    "PT-11" => "リスボン",
+    "PT-12" => "ポルタレグレ",
+    "PT-13" => "ポルト",
+    "PT-14" => "サンタレン",
+    "PT-15" => "セトゥーヴァル",
+    "PT-16" => "ビアナドカステロ",
+    "PT-17" => "ヴィラレアル",
+    "PT-18" => "ビゼウ",
+    "QA-WA" => "ワクラ",
+    "RO-AB" => "アルバ",
+    "RO-AG" => "アルジェシュ",
+    "RO-AR" => "アラド",
+    "RO-B" => "ブカレスト",
+    "RO-BC" => "バカウ",
+    "RO-BH" => "ビホル",
+    "RO-BN" => "ビストリツァナサウド",
+    "RO-BR" => "ブライラ",
+    "RO-BT" => "ボトシャニ",
+    "RO-BV" => "ブラショブ",
+    "RO-BZ" => "ブザウ",
+    "RO-CJ" => "クルジュ",
+    "RO-CS" => "カラシュセベリン",
+    "RO-CT" => "コンスタンツァ",
+    "RO-CV" => "コバスナ",
+    "RO-DB" => "ドゥンボビツァ",
+    "RO-DJ" => "ドルジュ",
+    "RO-GJ" => "ゴルジュ",
+    "RO-GL" => "ガラツィ",
+    "RO-GR" => "ジュルジュ",
+    "RO-HD" => "フネドアラ",
+    "RO-HR" => "ハルギータ",
+    "RO-IL" => "ヤロミツァ",
+    "RO-IS" => "ヤシ",
+    "RO-MH" => "メヘディンツ",
+    "RO-MM" => "マラムレシュ",
+    "RO-MS" => "ムレシュ",
+    "RO-NT" => "ネアムツ",
+    "RO-OT" => "オルト",
+    "RO-PH" => "プラホバ",
+    "RO-SB" => "シビウ",
+    "RO-SJ" => "サラジュ",
+    "RO-SM" => "サトゥマーレ",
+    "RO-SV" => "スチャバ",
+    "RO-TL" => "トゥルチャ",
+    "RO-TM" => "ティミシュ",
+    "RO-TR" => "テレオルマン",
+    "RO-VL" => "バルチャ",
+    "RO-VN" => "ブランチャ",
+    "RO-VS" => "バスルイ",
+    "RU-CE" => "チェチェン共和国",
+    "RU-DA" => "ダゲスタン共和国",
+    "RU-KAM" => "カムチャツカ",
+    "RU-KC" => "カラチャイチェルケス共和国",
+    "RU-KO" => "コミ共和国",
+    "KN-K" => "セントキッツ",
+    "KN-N" => "ネーヴィス",
+    "LC-03" => "チョイセル",
+    "VC-04" => "セントジョージ",
+    "VC-06" => "グレナディーン",
+    "WS-PA" => "パラウリ",
+    "SM-09" => "セッラバッレ",
+    "ST-P" => "プリンシペ",
+    "SA-05" => "カシーム",
+    "SA-07" => "タブーク",
+    "SA-10" => "ナジュラーン",
+    "SA-12" => "ジャウフ",
+    "SN-DB" => "ジュルベル",
+    "SN-DK" => "ダカール",
+    "SN-FK" => "ファティック",
+    "SN-KA" => "カフリン",
+    "SN-KD" => "コルダ",
+    "SN-KE" => "ケドゥグ",
+    "SN-KL" => "カオラック",
+    "SN-LG" => "ルガ",
+    "SN-MT" => "マタム",
+    "SN-SE" => "セジュ",
+    "SN-SL" => "サン・ルイ",
+    "SN-TC" => "タンバクンダ",
+    "SN-TH" => "チェス",
+    "SN-ZG" => "ジガンショール",
+    "RS-KM" => "コソボ-Metohija",
+    "RS-VO" => "ヴォイヴォディナ",
+    "SK-BC" => "バンスカービストリツァ",
+    "SK-BL" => "ブラチスラバ",
+    "SK-KI" => "コシツェ",
+    "SK-NI" => "ニトラ",
+    "SK-PV" => "プレショフ",
+    "SK-TA" => "トルナバ",
+    "SK-TC" => "トレンチーン",
+    "SK-ZI" => "ジリナ",
+    "SB-CE" => "セントラル",
+    "SB-CH" => "チョイセル",
+    "SB-GU" => "ガダルカナル",
+    "SB-ML" => "マライタ",
+    "SO-BK" => "バコール",
+    "SO-BR" => "バリ",
+    "SO-BY" => "バイ",
+    "SO-GA" => "ガルグドゥード",
+    "SO-GE" => "ゲド",
+    "SO-JD" => "ジュバダデヘ",
+    "SO-JH" => "ジュバダホーセ",
+    "SO-MU" => "ムドゥグ",
+    "SO-NU" => "ヌガール",
+    "SO-SD" => "シャベーラハデヘ",
+    "SO-SH" => "シャベーラハホーセ",
+    "SO-TO" => "トクデール",
+    "SO-WO" => "ウォコーイガルベード",
+    "ZA-EC" => "東ケープ",
+    "ZA-FS" => "自由州",
+    "ZA-GP" => "ガウテング",
+    "ZA-KZN" => "クワズールーナタール",
+    "ZA-LP" => "リンポポ",
+    "ZA-MP" => "マプマランガ",
+    "ZA-NC" => "北ケープ",
+    "ZA-WC" => "西ケープ",
+    "ES-AB" => "アルバセテ",
+    "ES-AL" => "アルメリア",
+    "ES-AN" => "アンダルシア",
+    "ES-AR" => "アラゴン",
+    "ES-AS" => "アストゥリアス",
+    "ES-AV" => "アビラ",
+    "ES-BA" => "バダホス",
+    "ES-BU" => "ブルゴス",
+    "ES-CA" => "カディス",
+    "ES-CB" => "カンタブリア",
+    "ES-CC" => "カセレス",
+    "ES-CE" => "セウタ",
+    "ES-CL" => "カスティーリャレオン",
+    "ES-CM" => "カスティーリャラマンチャ",
+    "ES-CN" => "カナリア",
+    "ES-CO" => "コルドバ",
+    "ES-CR" => "シウダーレアル",
+    "ES-CU" => "クエンカ",
+    "ES-EX" => "エストレマドゥーラ",
+    "ES-GC" => "ラスパルマス",
+    "ES-GR" => "グラナダ",
+    "ES-GU" => "グアダラハラ",
+    "ES-H" => "ウエルバ",
+    "ES-HU" => "ウエスカ",
+    "ES-J" => "ハエン",
+    "ES-LE" => "レオン",
+    "ES-LO" => "ラリオハ",
+    "ES-M" => "マドリード",
+    "ES-MA" => "マラガ",
+    "ES-MC" => "ムルシア",
+    "ES-MD" => "マドリード州",
+    "ES-ML" => "メリリヤ",
+    "ES-MU" => "ムルシア",
+    "ES-O" => "アストゥリアス",
+    "ES-P" => "パレンシア",
+    "ES-RI" => "ラリオハ",
+    "ES-S" => "カンタブリア",
+    "ES-SA" => "サラマンカ",
+    "ES-SE" => "セビーリャ",
+    "ES-SG" => "セゴビア",
+    "ES-SO" => "ソリア",
+    "ES-TE" => "テルエル",
+    "ES-TF" => "サンタクルス・デ・テネリフェ",
+    "ES-TO" => "トレド",
+    "ES-VA" => "バリャドリード",
+    "ES-Z" => "サラゴーサ",
+    "ES-ZA" => "サモラ",
+    "LK-91" => "ラトナプラ",
+    "SR-BR" => "ブロコポンド",
+    "SR-CM" => "コメウィーネ",
+    "SR-CR" => "コロニー",
+    "SR-MA" => "マロウィーネ",
+    "SR-NI" => "ニッケリエ",
+    "SR-PM" => "パラマリボ",
+    "SR-PR" => "パラ",
+    "SR-SA" => "サラマクカ",
+    "SR-SI" => "シパリウィニ",
+    "SR-WA" => "ワニカ",
+    "CH-AG" => "アールガウ",
+    "CH-AI" => "アペンツェルインナーローデン",
+    "CH-AR" => "アペンツェルアウサーローデン",
+    "CH-BE" => "ベルン",
+    "CH-BL" => "バーゼルランドシャフト",
+    "CH-BS" => "バーゼルシュタット",
+    "CH-GE" => "ジュネーブ",
+    "CH-GL" => "グラールス",
+    "CH-GR" => "グラウビュンデン",
+    "CH-JU" => "ジュラ",
+    "CH-LU" => "ルツェルン",
+    "CH-NE" => "ヌシャテル",
+    "CH-OW" => "オブワルデン",
+    "CH-SG" => "ザンクトガレン",
+    "CH-SH" => "シャフハウゼン",
+    "CH-SO" => "ソーロトゥルン",
+    "CH-SZ" => "シュウィーツ",
+    "CH-TI" => "ティチーノ",
+    "CH-UR" => "ウーリ",
+    "CH-VD" => "ボー",
+    "CH-VS" => "バレ",
+    "CH-ZG" => "ツーク",
+    "CH-ZH" => "チューリヒ",
+    "SY-DI" => "ダマスカス",
+    "SY-DY" => "デリゾール",
+    "SY-ID" => "イドゥリブ",
+    "SY-RA" => "ラッカ",
+    "TW-CHA" => "彰化",
+    "TW-CYI" => "嘉義",
+    "TW-CYQ" => "嘉義",
+    "TW-HSQ" => "新竹",
+    "TW-HSZ" => "新竹",
+    "TW-HUA" => "花蓮",
+    "TW-KHH" => "高雄",
+    "TW-MIA" => "苗栗",
+    "TW-NAN" => "南投",
+    "TW-PEN" => "澎湖",
+    "TW-PIF" => "屏東",
+    "TW-TAO" => "桃園",
+    "TW-TNN" => "台南",
+    "TW-TPE" => "台北",
+    "TW-TTT" => "台東",
+    "TW-TXG" => "台中",
+    "TW-YUN" => "雲林",
+    "TJ-KT" => "ハトロン",
+    "TJ-SU" => "スド",
+    "TZ-01" => "アルーシャ",
+    "TZ-03" => "ドドマ",
+    "TZ-04" => "イリンガ",
+    "TZ-05" => "カゲラ",
+    "TZ-08" => "キゴマ",
+    "TZ-09" => "キリマンジャロ",
+    "TZ-12" => "リンディ",
+    "TZ-13" => "マラ",
+    "TZ-14" => "ムベヤ",
+    "TZ-16" => "モロゴロ",
+    "TZ-17" => "ムトワラ",
+    "TZ-20" => "ルークワ",
+    "TZ-21" => "ルブマ",
+    "TZ-22" => "シニャンガ",
+    "TZ-23" => "シンギダ",
+    "TZ-24" => "タボラ",
+    "TZ-25" => "タンガ",
+    "TH-11" => "サムットプラカーン",
+    "TH-12" => "ノンタブリー",
+    "TH-13" => "パトンターニー",
+    "TH-14" => "プラナコンシータマラート,",
+    "TH-15" => "アントーン",
+    "TH-16" => "ロッブリー",
+    "TH-17" => "シンブリー",
+    "TH-18" => "チャイナート",
+    "TH-19" => "サラブリー",
+    "TH-20" => "チョンブリー",
+    "TH-21" => "ラヨーン",
+    "TH-22" => "チャンタブリー",
+    "TH-23" => "トラート",
+    "TH-24" => "チャチェンサオ",
+    "TH-25" => "プラチンブリー",
+    "TH-26" => "ナコンナヨーク",
+    "TH-27" => "サケオ",
+    "TH-30" => "ナコンラチャシマ",
+    "TH-31" => "ブリラム",
+    "TH-32" => "スリン",
+    "TH-33" => "シーサケット",
+    "TH-34" => "ウボンラチャタニー",
+    "TH-35" => "ヤソートーン",
+    "TH-36" => "チャイヤプーム",
+    "TH-37" => "アムナートチャルン",
+    "TH-39" => "ノーンブアランプー",
+    "TH-40" => "コーンケーン",
+    "TH-41" => "ウドンタニー",
+    "TH-42" => "ルーイ",
+    "TH-43" => "ノーンカーイ",
+    "TH-44" => "マハーサーラーカム",
+    "TH-45" => "ロイエット",
+    "TH-46" => "カラシン",
+    "TH-47" => "サコンナコン",
+    "TH-48" => "ナコンパノム",
+    "TH-49" => "ムクダハーン",
+    "TH-50" => "チェンマイ",
+    "TH-51" => "ランパーン",
+    "TH-52" => "ランパーン",
+    "TH-53" => "ウタラディット",
+    "TH-54" => "プレー",
+    "TH-55" => "ナン",
+    "TH-56" => "パヤオ",
+    "TH-57" => "チェンライ",
+    "TH-58" => "メーホーンソン",
+    "TH-60" => "ナコンサワン",
+    "TH-61" => "ウタイタニー",
+    "TH-62" => "カンペーンペット",
+    "TH-63" => "ターク",
+    "TH-64" => "スコータイ",
+    "TH-65" => "ピサヌローク",
+    "TH-66" => "ピチット",
+    "TH-67" => "ペチャブーン",
+    "TH-70" => "ラッブリー",
+    "TH-71" => "カンチャナブリ",
+    "TH-72" => "スパンブリー",
+    "TH-73" => "ナコンパトム",
+    "TH-74" => "サムットサコーン",
+    "TH-75" => "サムットソンクラム",
+    "TH-76" => "ペッブリー",
+    "TH-77" => "プラチュアップキリカン",
+    "TH-80" => "ナコンシータマラート",
+    "TH-81" => "クラビー",
+    "TH-82" => "パンガー",
+    "TH-83" => "プーケット",
+    "TH-84" => "スラーターニー",
+    "TH-85" => "ラノーン",
+    "TH-86" => "チュムポーン",
+    "TH-90" => "ソンクラー",
+    "TH-91" => "サトゥーン",
+    "TH-92" => "トラン",
+    "TH-93" => "パッタルン",
+    "TH-94" => "パッタニー",
+    "TH-95" => "ヤラー",
+    "TH-96" => "ナラティワート",
+    "TL-AL" => "アイレウ",
+    "TL-AN" => "アイナロ",
+    "TL-BA" => "バウカウ",
+    "TL-BO" => "ボボナロ",
+    "TL-CO" => "コバリマ",
+    "TL-DI" => "ディリ",
+    "TL-ER" => "エルメラ",
+    "TL-MF" => "マヌファイ",
+    "TL-MT" => "マナトゥトゥ",
+    "TG-P" => "プラトー",
+    "TO-01" => "エウア",
+    "TO-02" => "ハアパイ",
+    "TO-04" => "トンガタプ",
+    "TO-05" => "ババウ",
+    "TT-ARI" => "アリマ",
+    "TT-CHA" => "チャグアナス",
+    "TT-POS" => "ポートオブスペイン",
+    "TT-SFO" => "サンフェルナンド",
+    "TN-11" => "チュニス",
+    "TN-13" => "ベンアルース",
+    "TN-14" => "マヌーバ",
+    "TN-21" => "ナブール",
+    "TN-22" => "ザグアン",
+    "TN-23" => "ビゼルト",
+    "TN-31" => "ベジャ",
+    "TN-32" => "ジャンドゥーバ",
+    "TN-33" => "ケフ",
+    "TN-34" => "シリアナ",
+    "TN-41" => "カイロアン",
+    "TN-42" => "カスリン",
+    "TN-43" => "シディブジット",
+    "TN-51" => "スース",
+    "TN-52" => "モナスティール",
+    "TN-53" => "マハディア",
+    "TN-61" => "スファックス",
+    "TN-71" => "ガフサ",
+    "TN-72" => "トズール",
+    "TN-81" => "ガベス",
+    "TN-83" => "タタウィン",
+    "TR-01" => "アダナ",
+    "TR-02" => "アドゥヤマン",
+    "TR-03" => "アフヨン",
+    "TR-04" => "アール",
+    "TR-05" => "アマスヤ",
+    "TR-06" => "アンカラ",
+    "TR-07" => "アンタルヤ",
+    "TR-08" => "アルトヴィン",
+    "TR-09" => "アイドゥン",
+    "TR-10" => "バルケシル",
+    "TR-11" => "ビレジック",
+    "TR-12" => "ビンギョル",
+    "TR-13" => "ビトリス",
+    "TR-14" => "ボル",
+    "TR-15" => "ブルドゥル",
+    "TR-16" => "ブルサ",
+    "TR-17" => "チャナッカレ",
+    "TR-18" => "チャンクル",
+    "TR-19" => "チョルム",
+    "TR-20" => "デニズリ",
+    "TR-21" => "ディヤルバクル",
+    "TR-22" => "エディルネ",
+    "TR-23" => "エラズ",
+    "TR-24" => "エルジンジャン",
+    "TR-25" => "エルズルム",
+    "TR-26" => "エスキシェヒル",
+    "TR-27" => "ガジアンテップ",
+    "TR-28" => "ギレスン",
+    "TR-29" => "ギュミュシュネ",
+    "TR-30" => "ハッカーリ",
+    "TR-31" => "ハタイ",
+    "TR-32" => "イスパルタ",
+    "TR-34" => "イスタンブール",
+    "TR-35" => "イズミール",
+    "TR-36" => "カルス",
+    "TR-37" => "カスタモヌ",
+    "TR-38" => "カイセリ",
+    "TR-39" => "クルクラレリ",
+    "TR-40" => "クルシェヒル",
+    "TR-41" => "コジャエリ",
+    "TR-42" => "コンヤ",
+    "TR-43" => "キュタヒヤ",
+    "TR-44" => "マラティア",
+    "TR-45" => "マニサ",
+    "TR-46" => "カラマンマラシュ",
+    "TR-47" => "マルディン",
+    "TR-48" => "ムーラ",
+    "TR-49" => "ムシュ",
+    "TR-50" => "ネウシェヒル",
+    "TR-51" => "ニーデ",
+    "TR-52" => "オルデュ",
+    "TR-53" => "リゼ",
+    "TR-54" => "サカルヤ",
+    "TR-55" => "サムスン",
+    "TR-56" => "シイルト",
+    "TR-57" => "シノップ",
+    "TR-58" => "シワス",
+    "TR-59" => "テキルダー",
+    "TR-60" => "トカト",
+    "TR-61" => "トラブゾン",
+    "TR-62" => "トゥンジェリ",
+    "TR-63" => "シャンルウルファ",
+    "TR-64" => "ウシャク",
+    "TR-65" => "ヴァン",
+    "TR-66" => "ヨズガット",
+    "TR-67" => "ゾングルダク",
+    "TR-68" => "アクサライ",
+    "TR-69" => "バイブルト",
+    "TR-70" => "カラマン",
+    "TR-71" => "クルツカレ",
+    "TR-72" => "バトマン",
+    "TR-73" => "シュルナク",
+    "TR-74" => "バルトゥン",
+    "TR-75" => "アルダハン",
+    "TR-76" => "イーディル",
+    "TR-77" => "ヤロバ",
+    "TR-78" => "カラビュク",
+    "TR-79" => "キリス",
+    "TR-80" => "オスマニエ",
+    "TR-81" => "デュズジェ",
+    "TM-A" => "アハル",
+    "TM-B" => "バルカン",
+    "TM-D" => "ダシュハウズ",
+    "TM-L" => "レバプ",
+    "TM-M" => "マリー",
+    "TM-S" => "アシガバット",
+    "TV-FUN" => "フナフティ",
+    "TV-NIT" => "ニウタオ",
+    "TV-NKF" => "ヌクフェタウ",
+    "TV-NKL" => "ヌクライライ",
+    "TV-NMA" => "ナヌーメア",
+    "TV-NUI" => "ヌイ",
+    "TV-VAI" => "ヴァイトゥプ",
+    "UG-101" => "カランガラ",
+    "UG-102" => "カンパラ",
+    "UG-103" => "キボガ",
+    "UG-104" => "ルウェロ",
+    "UG-105" => "マサカ",
+    "UG-106" => "ムピギ",
+    "UG-107" => "ムベンデ",
+    "UG-108" => "ムコノ",
+    "UG-109" => "ナカソンゴラ",
+    "UG-110" => "ラカイ",
+    "UG-111" => "センバブレ",
+    "UG-112" => "カユンガ",
+    "UG-113" => "ワキソ",
+    "UG-115" => "ミチャナ",
+    "UG-116" => "ナカセケ",
+    "UG-201" => "ブギリ",
+    "UG-202" => "ブシア",
+    "UG-203" => "イガンガ",
+    "UG-204" => "ジンジャ",
+    "UG-205" => "カムリ",
+    "UG-206" => "カプチョールワ",
+    "UG-207" => "カタクイ",
+    "UG-208" => "クミ",
+    "UG-209" => "ムバレ",
+    "UG-210" => "パッリサ",
+    "UG-211" => "ソロティ",
+    "UG-212" => "トロロ",
+    "UG-213" => "カベラマイド",
+    "UG-214" => "マユゲ",
+    "UG-215" => "シロンコ",
+    "UG-216" => "アムリア",
+    "UG-217" => "ブダカ",
+    "UG-221" => "ブタレジャ",
+    "UG-222" => "カリロ",
+    "UG-223" => "マナフワ",
+    "UG-224" => "ナムトゥンバ",
+    "UG-301" => "アジュマニ",
+    "UG-302" => "アパク",
+    "UG-303" => "アルア",
+    "UG-304" => "グル",
+    "UG-305" => "キトゥグム",
+    "UG-306" => "コティド",
+    "UG-307" => "リラ",
+    "UG-308" => "モロト",
+    "UG-309" => "モヨ",
+    "UG-310" => "ネビ",
+    "UG-311" => "ナカピリピリティ",
+    "UG-312" => "パデル",
+    "UG-313" => "ユンベ",
+    "UG-314" => "アビム",
+    "UG-315" => "アモラタル",
+    "UG-316" => "アムル",
+    "UG-317" => "ドコロ",
+    "UG-318" => "カボング",
+    "UG-319" => "コボコ",
+    "UG-320" => "マナチャ",
+    "UG-321" => "オヤム",
+    "UG-401" => "ブンディブギョ",
+    "UG-402" => "ブシェニ",
+    "UG-403" => "ホイマ",
+    "UG-404" => "カバレ",
+    "UG-405" => "カバロレ",
+    "UG-406" => "カセセ",
+    "UG-407" => "キバーレ",
+    "UG-408" => "キソロ",
+    "UG-409" => "マシンディ",
+    "UG-410" => "ムバララ",
+    "UG-411" => "ヌトゥンガモ",
+    "UG-412" => "ルクンギリ",
+    "UG-413" => "カムウェンゲ",
+    "UG-414" => "カヌング",
+    "UG-415" => "キエンジョジョ",
+    "UG-416" => "ブリリサ",
+    "UG-417" => "イバンダ",
+    "UG-418" => "イシンギロ",
+    "UG-419" => "キルフラ",
+    "UG-C" => "セントラル",
+    "AE-DU" => "ドバイ",
+    "AE-FU" => "フジャイラ",
+    "AE-RK" => "ラスアルカイマ",
+    "AE-SH" => "シャルジャ",
+    "GB-ABD" => "アバディーンシア",
+    "GB-ABE" => "アバディーン",
+    "GB-AGB" => "アーガイル及びビュート",
+    "GB-ANS" => "アンガス",
+    "GB-BAS" => "バス及び北西サマセット",
+    "GB-BBD" => "ブラックバーン with ダーウェン",
+    "GB-BDF" => "ベッドフォード",
+    "GB-BDG" => "バーキング及びダゲナム",
+    "GB-BEN" => "ブレント",
+    "GB-BEX" => "ベクスリー",
+    "GB-BGW" => "ブレナウ グウェント",
+    "GB-BIR" => "バーミンガム",
+    "GB-BKM" => "バッキンガムシャー",
+    "GB-BNE" => "バーネット",
+    "GB-BNH" => "ブリントンアンドホーブ",
+    "GB-BNS" => "バーンズリー",
+    "GB-BOL" => "ボールトン",
+    "GB-BPL" => "ブラックプール",
+    "GB-BRC" => "ブラックネル フォレスト",
+    "GB-BRD" => "ブラッドフォード",
+    "GB-BRY" => "ブロムリー",
+    "GB-BST" => "ブリストル",
+    "GB-BUR" => "ベリー",
+    "GB-CAM" => "ケンブリッジシャー",
+    "GB-CLK" => "クラックマナンシア",
+    "GB-CMA" => "カンブリア",
+    "GB-CMD" => "カムデン",
+    "GB-CON" => "コーンウォール",
+    "GB-COV" => "コヴェントリー",
+    "GB-CRY" => "クロイドン",
+    "GB-CWY" => "コンウェー",
+    "GB-DAL" => "ダーリントン",
+    "GB-DBY" => "ダービーシャー",
+    "GB-DER" => "ダービー",
+    "GB-DEV" => "デボン",
+    "GB-DGY" => "ダムフリースアンドギャラウェイ",
+    "GB-DNC" => "ドンカスター",
+    "GB-DND" => "ダンディーシティ",
+    "GB-DOR" => "ドーセット",
+    "GB-DUD" => "ダッドリー",
+    "GB-EAL" => "イーリング",
+    "GB-EAY" => "東エアーシア",
+    "GB-EDH" => "エディンバラ",
+    "GB-EDU" => "東ダンバートンシャー",
+    "GB-ERW" => "東レンフルーシャー",
+    "GB-ERY" => "ヨークシャー州イーストライディング",
+    "GB-ESX" => "東サセックス",
+    "GB-GLG" => "グラスゴー",
+    "GB-GLS" => "グロスターシャー",
+    "GB-GRE" => "グリニッジ",
+    "GB-HAM" => "ハンプシャー",
+    "GB-HEF" => "ヘレフォードシャー",
+    "GB-LAN" => "ランカシャー",
+    "GB-LIV" => "リヴァプール",
+    "GB-LND" => "ロンドン",
+    "GB-MAN" => "マンチェスター",
+    "GB-MDB" => "ミドルズブラ",
+    "GB-MIK" => "ミルトンキーンズ",
+    "GB-NET" => "ニューカッスル",
+    "GB-NFK" => "ノーフォーク",
+    "GB-PLY" => "プリマス",
+    "GB-POR" => "ポーツマス",
+    "GB-PTE" => "ピーターバラ",
+    "GB-SND" => "サンダーランド",
+    "GB-STH" => "サウサンプトン",
+    "GB-WLV" => "ウルヴァーハンプトン",
+    "GB-YOR" => "ヨーク",
+    "US-AK" => "アラスカ",
+    "US-AL" => "アラバマ",
+    "US-AR" => "アーカンソー",
+    "US-AS" => "米領サモア",
+    "US-AZ" => "アリゾナ",
+    "US-CA" => "カリフォルニア",
+    "US-CO" => "コロラド",
+    "US-CT" => "コネティカット",
+    "US-DC" => "コロンビア特別区",
+    "US-DE" => "デラウェア",
+    "US-FL" => "フロリダ",
+    "US-GA" => "ジョージア",
+    "US-GU" => "グアム",
+    "US-HI" => "ハワイ",
+    "US-IA" => "アイオワ",
+    "US-ID" => "アイダホ",
+    "US-IL" => "イリノイ",
+    "US-IN" => "インディアナ",
+    "US-KS" => "カンザス",
+    "US-KY" => "ケンタッキー",
+    "US-LA" => "ルイジアナ",
+    "US-MA" => "マサチューセッツ",
+    "US-MD" => "メリーランド",
+    "US-ME" => "メーン",
+    "US-MI" => "ミシガン",
+    "US-MN" => "ミネソタ",
+    "US-MO" => "ミズーリ",
+    "US-MP" => "北マリアナ諸島",
+    "US-MS" => "ミシシッピ",
+    "US-MT" => "モンタナ",
+    "US-NC" => "ノースカロライナ",
+    "US-ND" => "ノースダコタ",
+    "US-NE" => "ネブラスカ",
+    "US-NH" => "ニューハンプシャー",
+    "US-NJ" => "ニュージャージー",
+    "US-NM" => "ニューメキシコ",
+    "US-NV" => "ネヴァダ",
+    "US-NY" => "ニューヨーク",
+    "US-OH" => "オハイオ",
+    "US-OK" => "オクラホマ",
+    "US-OR" => "オレゴン",
+    "US-PA" => "ペンシルヴェニア",
+    "US-PR" => "プエルトリコ",
+    "US-RI" => "ロードアイランド",
+    "US-SC" => "サウスカロライナ",
+    "US-SD" => "サウスダコタ",
+    "US-TN" => "テネシー",
+    "US-TX" => "テキサス",
+    "US-UM" => "アメリカ合衆国外諸島",
+    "US-UT" => "ユタ",
+    "US-VA" => "ヴァージニア",
+    "US-VT" => "ヴァーモント",
+    "US-WA" => "ワシントン",
+    "US-WI" => "ウィスコンシン",
+    "US-WV" => "ウェストヴァージニア",
+    "US-WY" => "ワイオミング",
+    "UM-67" => "ジョンストン環礁",
+    "UM-71" => "ミッドウェイ諸島",
+    "UM-76" => "ナヴァッサ島",
+    "UM-79" => "ウェーク島",
+    "UM-81" => "ベーカー島",
+    "UM-84" => "ハウランド島",
+    "UM-86" => "ジャーヴィス島",
+    "UM-89" => "キングマン岩礁",
+    "UM-95" => "パルミラ環礁",
+    "UY-AR" => "アルテイガス",
+    "UY-CA" => "カネローネス",
+    "UY-CL" => "セロラルゴ",
+    "UY-CO" => "コロニア",
+    "UY-DU" => "ドゥラスノ",
+    "UY-FD" => "フロリダ",
+    "UY-FS" => "フロレス",
+    "UY-LA" => "ラバイェハ",
+    "UY-MA" => "マルドナド",
+    "UY-MO" => "モンテビデオ",
+    "UY-PA" => "パイサンドゥ",
+    "UY-RN" => "リオネグロ",
+    "UY-RO" => "ロチャ",
+    "UY-RV" => "リベラ",
+    "UY-SA" => "サルト",
+    "UY-SJ" => "サンホゼ",
+    "UY-SO" => "ソリアノ",
+    "UY-TA" => "タクアレンボ",
+    "UY-TT" => "トレインタ ィ トレス",
+    "UZ-AN" => "アンジャン",
+    "UZ-NG" => "ナマンガン",
+    "UZ-NW" => "ナボイ",
+    "UZ-QA" => "カシュカダリア",
+    "UZ-SA" => "サマルカンド",
+    "UZ-SI" => "シルダリャ",
+    "UZ-SU" => "スルハンダリア",
+    "UZ-TK" => "タシケント",
+    "UZ-TO" => "タシケント",
+    "UZ-XO" => "ホレズム",
+    "VE-C" => "アプレ",
+    "VE-D" => "アラゲグア",
+    "VE-E" => "バリナス",
+    "VE-F" => "ボリバル",
+    "VE-G" => "カラボボ",
+    "VE-H" => "コジュデス",
+    "VE-I" => "ファルコン",
+    "VE-J" => "グアリコ",
+    "VE-K" => "ララ",
+    "VE-L" => "メリダ",
+    "VE-M" => "ミランダ",
+    "VE-N" => "モナガス",
+    "VE-O" => "ヌエヴァ･エスパルタ",
+    "VE-P" => "ポルトゲサ",
+    "VE-R" => "スクレ",
+    "VE-S" => "タチラ",
+    "VE-T" => "トルヒヨ",
+    "VE-U" => "ヤラクイ",
+    "VE-V" => "スリア",
+    "VE-Y" => "デルタ・アマクロ",
+    "VE-Z" => "アマゾナス",
+    "VN-01" => "ライチャウ",
+    "VN-02" => "ラオカイ",
+    "VN-03" => "ハジアン",
+    "VN-04" => "カオバン",
+    "VN-05" => "ソンラ",
+    "VN-06" => "イェンバイ",
+    "VN-07" => "トゥエンクァン",
+    "VN-09" => "ランソン",
+    "VN-13" => "クァンニン",
+    "VN-18" => "ニンビン",
+    "VN-20" => "タイビン",
+    "VN-21" => "タンホア",
+    "VN-22" => "ゲーアン",
+    "VN-24" => "クァンビン",
+    "VN-25" => "クァンチ",
+    "VN-27" => "クァンナム",
+    "VN-28" => "コントゥム",
+    "VN-29" => "クァンガイ",
+    "VN-30" => "ジアライ",
+    "VN-31" => "ビンディン",
+    "VN-32" => "フーイェン",
+    "VN-34" => "コンホア",
+    "VN-35" => "ラムドン",
+    "VN-36" => "ニントゥアン",
+    "VN-37" => "テイニン",
+    "VN-39" => "ドンナイ",
+    "VN-40" => "ビントゥアン",
+    "VN-41" => "ロンアン",
+    "VN-44" => "アンザン",
+    "VN-45" => "ドンタプ",
+    "VN-46" => "ティエンジアン",
+    "VN-49" => "ビンロン",
+    "VN-50" => "ベンチェ",
+    "VN-51" => "チャビン",
+    "VN-52" => "ソクチャン",
+    "VN-53" => "バクカン",
+    "VN-54" => "バクジアン",
+    "VN-55" => "バクリュウ",
+    "VN-56" => "バクニン",
+    "VN-57" => "ビンドゥオン",
+    "VN-58" => "ビンフォク",
+    "VN-59" => "カマウ",
+    "VN-63" => "ハナム",
+    "VN-66" => "フンイェン",
+    "VN-68" => "フート",
+    "VN-69" => "タイグエン",
+    "VN-DN" => "ダナン",
+    "VN-HN" => "ハノイ",
+    "VN-HP" => "ハイフォン",
+    "YE-DH" => "ダマール",
+    "YE-HD" => "ハドラマウト",
+    "YE-HJ" => "ハッジャ",
+    "YE-HU" => "ホデイダ",
+    "YE-IB" => "イップ",
+    "YE-JA" => "ジャウフ",
+    "YE-LA" => "ラヘジ",
+    "YE-MR" => "アルマハラ",
+    "YE-MW" => "アルマハウィット",
+    "YE-RA" => "ライマ",
+    "YE-SH" => "シャブワ",
+    "ZM-02" => "セントラル",
+    "ZM-04" => "ルアプラ",
+    "ZM-06" => "北西部州",
+    "ZM-08" => "コッパーベルト",
+    "ZM-09" => "ルサカ",
+    "ZW-BU" => "ブラワヨ",
+    "ZW-HA" => "ハラレ",
+    "ZW-MA" => "マニカランド",
+    "ZW-MC" => "マショナランド・セントラル",
+    "ZW-ME" => "マショナランド・イースト",
+    "ZW-MI" => "ミッドランズ",
+    "ZW-MN" => "北マタベランド",
+    "ZW-MS" => "南マタベランド",
+    "ZW-MV" => "マスビンゴ",
+    "ZW-MW" => "マショナランド・ウエスト",
+};
+
+///Japanese former country names by alpha-4 code
+pub const FORMER_NAME_MAP: Map<&str, &str> = phf_map! {
+    "BQAQ" => "英国南極領土",
+    "BUMM" => "ビルマ社会主義連邦共和国",
+    "BYAA" => "白ロシア SSR ソビエト社会主義共和国",
+    "CTKI" => "カントン及びエンダーベリ島",
+    "CSHH" => "チェコスロヴァキア社会主義共和国",
+    "DYBJ" => "ダオメー",
+    "NQAQ" => "ドローニングモードランド",
+    "TPTL" => "東ティモール",
+    "FXFR" => "フランス, メトロポリタン",
+    "AIDJ" => "仏領アファール及びイッサ",
+    "FQHH" => "フランス南方および南極領土",
+    "DDDE" => "ドイツ民主共和国",
+    "GEHH" => "ギルバート及びエリス諸島",
+    "JTUM" => "ジョンストン島",
+    "MIUM" => "ミッドウェー諸島",
+    "ANHH" => "オランダ領アンティル諸島",
+    "NTHH" => "中立地帯",
+    "NHVU" => "ニューヘブリディーズ",
+    "PCHH" => "太平洋諸島 (信託統治領)",
+    "PZPA" => "パナマ運河地区",
+    "CSXX" => "セルビア及びモンテネグロ",
+    "SKIN" => "シッキム",
+    "RHZW" => "南ローデシア",
+    "PUUM" => "米領太平洋諸島",
+    "HVBF" => "アッパーボルタ共和国",
+    "SUHH" => "USSR ソビエト社会主義共和国連邦",
+    "VDVN" => "ベトナム民主共和国",
+    "WKUM" => "ウェーク島",
+    "YDYE" => "イエメン民主主義人民共和国",
+    "ZRCD" => "ザイール共和国",
+};
@@ -0,0 +1,234 @@
+//! Country, subdivision and former country names in other languages.
+//!
+//! Each language is behind its own cargo feature (`locale-de`, `locale-ja`,
+//! ...) so binaries only embed the tables they use; `all-locales` enables
+//! every one. The translations come from the
+//! [iso-codes](https://salsa.debian.org/iso-codes-team/iso-codes) gettext
+//! catalogs and are regenerated by `scripts/locales.py`.
+//!
+//! # Sample code
+//! ```
+//! # #[cfg(feature = "locale-de")] {
+//! let country = rust_iso3166::from_alpha2("DE").unwrap();
+//! assert_eq!(Some("Deutschland"), country.name_in("de-AT"));
+//! # }
+//! ```
+
+use crate::iso3166_2::Subdivision;
+use crate::iso3166_3::CountryCode3;
+use crate::CountryCode;
+use phf::Map;
+
+#[cfg(feature = "locale-ar")]
+pub mod ar;
+#[cfg(feature = "locale-de")]
+pub mod de;
+#[cfg(feature = "locale-es")]
+pub mod es;
+#[cfg(feature = "locale-fr")]
+pub mod fr;
+#[cfg(feature = "locale-it")]
+pub mod it;
+#[cfg(feature = "locale-ja")]
+pub mod ja;
+#[cfg(feature = "locale-ko")]
+pub mod ko;
+#[cfg(feature = "locale-nl")]
+pub mod nl;
+#[cfg(feature = "locale-pl")]
+pub mod pl;
+#[cfg(feature = "locale-pt")]
+pub mod pt;
+#[cfg(feature = "locale-ru")]
+pub mod ru;
+#[cfg(feature = "locale-sv")]
+pub mod sv;
+#[cfg(feature = "locale-tr")]
+pub mod tr;
+#[cfg(feature = "locale-zh")]
+pub mod zh;
+#[cfg(feature = "locale-zh-hant")]
+pub mod zh_hant;
+
+///The language tags of the locales compiled in
+pub const LOCALES: &[&str] = &[
+    #[cfg(feature = "locale-ar")]
+    "ar",
+    #[cfg(feature = "locale-de")]
+    "de",
+    #[cfg(feature = "locale-es")]
+    "es",
+    #[cfg(feature = "locale-fr")]
+    "fr",
+    #[cfg(feature = "locale-it")]
+    "it",
+    #[cfg(feature = "locale-ja")]
+    "ja",
+    #[cfg(feature = "locale-ko")]
+    "ko",
+    #[cfg(feature = "locale-nl")]
+    "nl",
+    #[cfg(feature = "locale-pl")]
+    "pl",
+    #[cfg(feature = "locale-pt")]
+    "pt",
+    #[cfg(feature = "locale-ru")]
+    "ru",
+    #[cfg(feature = "locale-sv")]
+    "sv",
+    #[cfg(feature = "locale-tr")]
+    "tr",
+    #[cfg(feature = "locale-zh")]
+    "zh",
+    #[cfg(feature = "locale-zh-hant")]
+    "zh-hant",
+];
+
+struct Tables {
+    countries: &'static Map<&'static str, &'static str>,
+    subdivisions: &'static Map<&'static str, &'static str>,
+    former: &'static Map<&'static str, &'static str>,
+}
+
+/// Picks the tables for a BCP 47 style tag such as `de`, `pt-BR`, `zh_Hant`
+/// or `zh-Hant-TW-u-ca-roc`. Only the language, and for Chinese the script or
+/// region right after it, is used; later subtags and extensions are ignored.
+fn tables(locale: &str) -> Option<Tables> {
+    let mut subtags = locale.trim().split(['-', '_']);
+    let language = subtags.next()?;
+    let hant = |s: &str| {
+        ["hant", "tw", "hk", "mo"]
+            .iter()
+            .any(|t| s.eq_ignore_ascii_case(t))
+    };
+    let tag = if language.eq_ignore_ascii_case("zh") && subtags.take(2).any(hant) {
+        "zh-hant"
+    } else {
+        LOCALES
+            .iter()
+            .copied()
+            .find(|l| l.eq_ignore_ascii_case(language))?
+    };
+    match tag {
+        #[cfg(feature = "locale-ar")]
+        "ar" => Some(Tables {
+            countries: &ar::COUNTRY_NAME_MAP,
+            subdivisions: &ar::SUBDIVISION_NAME_MAP,
+            former: &ar::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-de")]
+        "de" => Some(Tables {
+            countries: &de::COUNTRY_NAME_MAP,
+            subdivisions: &de::SUBDIVISION_NAME_MAP,
+            former: &de::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-es")]
+        "es" => Some(Tables {
+            countries: &es::COUNTRY_NAME_MAP,
+            subdivisions: &es::SUBDIVISION_NAME_MAP,
+            former: &es::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-fr")]
+        "fr" => Some(Tables {
+            countries: &fr::COUNTRY_NAME_MAP,
+            subdivisions: &fr::SUBDIVISION_NAME_MAP,
+            former: &fr::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-it")]
+        "it" => Some(Tables {
+            countries: &it::COUNTRY_NAME_MAP,
+            subdivisions: &it::SUBDIVISION_NAME_MAP,
+            former: &it::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-ja")]
+        "ja" => Some(Tables {
+            countries: &ja::COUNTRY_NAME_MAP,
+            subdivisions: &ja::SUBDIVISION_NAME_MAP,
+            former: &ja::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-ko")]
+        "ko" => Some(Tables {
+            countries: &ko::COUNTRY_NAME_MAP,
+            subdivisions: &ko::SUBDIVISION_NAME_MAP,
+            former: &ko::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-nl")]
+        "nl" => Some(Tables {
+            countries: &nl::COUNTRY_NAME_MAP,
+            subdivisions: &nl::SUBDIVISION_NAME_MAP,
+            former: &nl::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-pl")]
+        "pl" => Some(Tables {
+            countries: &pl::COUNTRY_NAME_MAP,
+            subdivisions: &pl::SUBDIVISION_NAME_MAP,
+            former: &pl::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-pt")]
+        "pt" => Some(Tables {
+            countries: &pt::COUNTRY_NAME_MAP,
+            subdivisions: &pt::SUBDIVISION_NAME_MAP,
+            former: &pt::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-ru")]
+        "ru" => Some(Tables {
+            countries: &ru::COUNTRY_NAME_MAP,
+            subdivisions: &ru::SUBDIVISION_NAME_MAP,
+            former: &ru::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-sv")]
+        "sv" => Some(Tables {
+            countries: &sv::COUNTRY_NAME_MAP,
+            subdivisions: &sv::SUBDIVISION_NAME_MAP,
+            former: &sv::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-tr")]
+        "tr" => Some(Tables {
+            countries: &tr::COUNTRY_NAME_MAP,
+            subdivisions: &tr::SUBDIVISION_NAME_MAP,
+            former: &tr::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-zh")]
+        "zh" => Some(Tables {
+            countries: &zh::COUNTRY_NAME_MAP,
+            subdivisions: &zh::SUBDIVISION_NAME_MAP,
+            former: &zh::FORMER_NAME_MAP,
+        }),
+        #[cfg(feature = "locale-zh-hant")]
+        "zh-hant" => Some(Tables {
+            countries: &zh_hant::COUNTRY_NAME_MAP,
+            subdivisions: &zh_hant::SUBDIVISION_NAME_MAP,
+            former: &zh_hant::FORMER_NAME_MAP,
+        }),
+        _ => None,
+    }
+}
+
+impl CountryCode {
+    ///Return the name in the given language, if its `locale-*` feature is
+    ///enabled and a translation exists
+    pub fn name_in(&self, locale: &str) -> Option<&'static str> {
+        tables(locale)?.countries.get(self.alpha2).copied()
+    }
+}
+
+impl Subdivision {
+    ///Return the name in the given language, if its `locale-*` feature is
+    ///enabled and a translation exists. iso-codes translates far fewer
+    ///subdivisions than countries: German, Dutch, Polish, French, Swedish and
+    ///Italian cover three quarters or more, Simplified Chinese, Japanese,
+    ///Turkish and Russian 40 to 55 percent, Traditional Chinese and Spanish
+    ///under a tenth, Korean and Portuguese a handful and Arabic none. Each
+    ///`SUBDIVISION_NAME_MAP` doc gives the count.
+    pub fn name_in(&self, locale: &str) -> Option<&'static str> {
+        tables(locale)?.subdivisions.get(self.code).copied()
+    }
+}
+
+impl CountryCode3 {
+    ///Return the name in the given language, if its `locale-*` feature is
+    ///enabled and a translation exists
+    pub fn name_in(&self, locale: &str) -> Option<&'static str> {
+        tables(locale)?.former.get(self.code).copied()
+    }
+}
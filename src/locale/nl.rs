@@ -0,0 +1,5161 @@
+use phf::phf_map;
+use phf::Map;
+
+///Dutch country names by alpha-2 code
+pub const COUNTRY_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF" => "Afghanistan",
+    "AX" => "Ålandseilanden",
+    "AL" => "Albanië",
+    "DZ" => "Algerije",
+    "AS" => "Amerikaans-Samoa",
+    "AD" => "Andorra",
+    "AO" => "Angola",
+    "AI" => "Anguilla",
+    "AQ" => "Antarctica",
+    "AG" => "Antigua en Barbuda",
+    "AR" => "Argentinië",
+    "AM" => "Armenië",
+    "AW" => "Aruba",
+    "AU" => "Australië",
+    "AT" => "Oostenrijk",
+    "AZ" => "Azerbeidzjan",
+    "BS" => "Bahama's",
+    "BH" => "Bahrein",
+    "BD" => "Bangladesh",
+    "BB" => "Barbados",
+    "BY" => "Wit-Rusland",
+    "BE" => "België",
+    "BZ" => "Belize",
+    "BJ" => "Benin",
+    "BM" => "Bermuda",
+    "BT" => "Bhutan",
+    "BO" => "Bolivia, Multinationale Staat",
+    "BQ" => "Bonaire, Sint Eustatius en Saba",
+    "BA" => "Bosnië en Herzegovina",
+    "BW" => "Botswana",
+    "BV" => "Bouveteiland",
+    "BR" => "Brazilië",
+    "IO" => "Brits Indische Oceaanterritorium",
+    "BN" => "Brunei",
+    "BG" => "Bulgarije",
+    "BF" => "Burkina Faso",
+    "BI" => "Burundi",
+    "CV" => "Kaapverdië",
+    "KH" => "Cambodja",
+    "CM" => "Kameroen",
+    "CA" => "Canada",
+    "KY" => "Kaaimaneilanden",
+    "CF" => "Centraal-Afrikaanse Republiek",
+    "TD" => "Tsjaad",
+    "CL" => "Chili",
+    "CN" => "China",
+    "CX" => "Christmaseiland",
+    "CC" => "Cocoseilanden (Keelingeilanden)",
+    "CO" => "Colombia",
+    "KM" => "Comoren",
+    "CG" => "Congo",
+    "CD" => "Congo, Democratische Republiek",
+    "CK" => "Cookeilanden",
+    "CR" => "Costa Rica",
+    "CI" => "Ivoorkust",
+    "HR" => "Kroatië",
+    "CU" => "Cuba",
+    "CW" => "Curaçao",
+    "CY" => "Cyprus",
+    "CZ" => "Tsjechië",
+    "DK" => "Denemarken",
+    "DJ" => "Djibouti",
+    "DM" => "Dominica",
+    "DO" => "Dominicaanse Republiek",
+    "EC" => "Ecuador",
+    "EG" => "Egypte",
+    "SV" => "El Salvador",
+    "GQ" => "Equatoriaal-Guinea",
+    "ER" => "Eritrea",
+    "EE" => "Estland",
+    "SZ" => "Eswatini",
+    "ET" => "Ethiopië",
+    "FK" => "Falklandeilanden (Malvinas)",
+    "FO" => "Faeröer",
+    "FJ" => "Fiji",
+    "FI" => "Finland",
+    "FR" => "Frankrijk",
+    "GF" => "Frans-Guyana",
+    "PF" => "Frans-Polynesië",
+    "TF" => "Franse Zuidelijke Gebieden",
+    "GA" => "Gabon",
+    "GM" => "Gambia",
+    "GE" => "Georgia",
+    "DE" => "Duitsland",
+    "GH" => "Ghana",
+    "GI" => "Gibraltar",
+    "GR" => "Griekenland",
+    "GL" => "Groenland",
+    "GD" => "Grenada",
+    "GP" => "Guadeloupe",
+    "GU" => "Guam",
+    "GT" => "Guatemala",
+    "GG" => "Guernsey",
+    "GN" => "Guinee",
+    "GW" => "Guinee-Bissau",
+    "GY" => "Guyana",
+    "HT" => "Haïti",
+    "HM" => "Heardeiland en McDonaldeilanden",
+    "VA" => "Vaticaanstad, Staat",
+    "HN" => "Honduras",
+    "HK" => "Hongkong",
+    "HU" => "Hongarije",
+    "IS" => "IJsland",
+    "IN" => "India",
+    "ID" => "Indonesië",
+    "IR" => "Iran",
+    "IQ" => "Irak",
+    "IE" => "Ierland",
+    "IM" => "Eiland Man",
+    "IL" => "Israël",
+    "IT" => "Italië",
+    "JM" => "Jamaica",
+    "JP" => "Japan",
+    "JE" => "Jersey",
+    "JO" => "Jordanië",
+    "KZ" => "Kazachstan",
+    "KE" => "Kenia",
+    "KI" => "Kiribati",
+    "KP" => "Korea, Democratische Volksrepubliek",
+    "KR" => "Korea, Republiek",
+    "KW" => "Koeweit",
+    "KG" => "Kirgizië",
+    "LA" => "Laos Democratische Volksrepubliek",
+    "LV" => "Letland",
+    "LB" => "Libanon",
+    "LS" => "Lesotho",
+    "LR" => "Liberia",
+    "LY" => "Libië",
+    "LI" => "Liechtenstein",
+    "LT" => "Litouwen",
+    "LU" => "Luxemburg",
+    "MO" => "Macau",
+    "MG" => "Madagaskar",
+    "MW" => "Malawi",
+    "MY" => "Maleisië",
+    "MV" => "Maldiven",
+    "ML" => "Mali",
+    "MT" => "Malta",
+    "MH" => "Marshalleilanden",
+    "MQ" => "Martinique",
+    "MR" => "Mauritanië",
+    "MU" => "Mauritius",
+    "YT" => "Mayotte",
+    "MX" => "Mexico",
+    "FM" => "Micronesia",
+    "MD" => "Moldavië, Republiek",
+    "MC" => "Monaco",
+    "MN" => "Mongolië",
+    "ME" => "Montenegro",
+    "MS" => "Montserrat",
+    "MA" => "Marokko",
+    "MZ" => "Mozambique",
+    "MM" => "Myanmar",
+    "NA" => "Namibië",
+    "NR" => "Nauru",
+    "NP" => "Nepal",
+    "NL" => "Nederland",
+    "NC" => "Nieuw-Caledonië",
+    "NZ" => "Nieuw-Zeeland",
+    "NI" => "Nicaragua",
+    "NE" => "Niger",
+    "NG" => "Nigeria",
+    "NU" => "Niue",
+    "NF" => "Norfolk",
+    "MK" => "Noord-Macedonië",
+    "MP" => "Noordelijke Marianen",
+    "NO" => "Noorwegen",
+    "OM" => "Oman",
+    "PK" => "Pakistan",
+    "PW" => "Palau",
+    "PS" => "Palestina, Staat",
+    "PA" => "Panama",
+    "PG" => "Papoea-Nieuw-Guinea",
+    "PY" => "Paraguay",
+    "PE" => "Peru",
+    "PH" => "Filipijnen",
+    "PN" => "Pitcairneilanden",
+    "PL" => "Polen",
+    "PT" => "Portugal",
+    "PR" => "Puerto Rico",
+    "QA" => "Qatar",
+    "RE" => "Réunion",
+    "RO" => "Roemenië",
+    "RU" => "Rusland",
+    "RW" => "Rwanda",
+    "BL" => "Saint-Barthélemy",
+    "SH" => "Sint-Helena, Ascension en Tristan da Cunha",
+    "KN" => "Saint Kitts en Nevis",
+    "LC" => "Saint Lucia",
+    "MF" => "Sint-Maarten (Frans deel)",
+    "PM" => "Saint-Pierre en Miquelon",
+    "VC" => "Saint Vincent en de Grenadines",
+    "WS" => "Samoa",
+    "SM" => "San Marino",
+    "ST" => "Sao Tomé en Principe",
+    "SA" => "Saoedi-Arabië",
+    "SN" => "Senegal",
+    "RS" => "Servië",
+    "SC" => "Seychellen",
+    "SL" => "Sierra Leone",
+    "SG" => "Singapore",
+    "SX" => "Sint Maarten (Nederlands deel)",
+    "SK" => "Slowakije",
+    "SI" => "Slovenië",
+    "SB" => "Salomonseilanden",
+    "SO" => "Somalië",
+    "ZA" => "Zuid-Afrika",
+    "GS" => "Zuid-Georgia en de Zuidelijke Sandwicheilanden",
+    "SS" => "Zuid-Soedan",
+    "ES" => "Spanje",
+    "LK" => "Sri Lanka",
+    "SD" => "Soedan",
+    "SR" => "Suriname",
+    "SJ" => "Spitsbergen en Jan Mayen",
+    "SE" => "Zweden",
+    "CH" => "Zwitserland",
+    "SY" => "Syrië",
+    "TW" => "Taiwan",
+    "TJ" => "Tadzjikistan",
+    "TZ" => "Tanzania",
+    "TH" => "Thailand",
+    "TL" => "Oost-Timor",
+    "TG" => "Togo",
+    "TK" => "Tokelau",
+    "TO" => "Tonga",
+    "TT" => "Trinidad en Tobago",
+    "TN" => "Tunesië",
+    "TR" => "Turkije",
+    "TM" => "Turkmenistan",
+    "TC" => "Turks- en Caicoseilanden",
+    "TV" => "Tuvalu",
+    "UG" => "Oeganda",
+    "UA" => "Oekraïne",
+    "AE" => "Verenigde Arabische Emiraten",
+    "GB" => "Verenigd Koninkrijk",
+    "US" => "Verenigde Staten",
+    "UM" => "Kleine afgelegen eilanden van de Verenigde Staten",
+    "UY" => "Uruguay",
+    "UZ" => "Oezbekistan",
+    "VU" => "Vanuatu",
+    "VE" => "Venezuela, Bolivariaanse Republiek",
+    "VN" => "Vietnam",
+    "VG" => "Maagdeneilanden, Britse",
+    "VI" => "Maagdeneilanden, Amerikaanse",
+    "WF" => "Wallis en Futuna",
+    "EH" => "Westelijke Sahara",
+    "YE" => "Jemen",
+    "ZM" => "Zambia",
+    "ZW" => "Zimbabwe",
+};
+
+///Dutch subdivision names by ISO 3166-2 code, 4867 of 5046
+pub const SUBDIVISION_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF-BAL" => "Balkh",
+    "AF-BAM" => "Bamyan",
+    "AF-BDG" => "Badghis",
+    "AF-BDS" => "Badakhshan",
+    "AF-BGL" => "Baghlan",
+    "AF-DAY" => "Daikondi",
+    "AF-FRA" => "Farah",
+    "AF-FYB" => "Faryab",
+    "AF-GHA" => "Ghazni",
+    "AF-GHO" => "Ghowr",
+    "AF-HEL" => "Helmand",
+    "AF-HER" => "Herat",
+    "AF-JOW" => "Jowzjan",
+    "AF-KAB" => "Kabul",
+    "AF-KAN" => "Kandahar",
+    "AF-KAP" => "Kapisa",
+    "AF-KDZ" => "Kunduz",
+    "AF-KHO" => "Khost",
+    "AF-KNR" => "Kunar",
+    "AF-LAG" => "Laghman",
+    "AF-LOG" => "Logar",
+    "AF-NAN" => "Nangarhar",
+    "AF-NIM" => "Nimroz",
+    "AF-NUR" => "Nooristan",
+    "AF-PAN" => "Panjshir",
+    "AF-PAR" => "Parwan",
+    "AF-PIA" => "Paktia",
+    "AF-PKA" => "Paktika",
+    "AF-SAM" => "Samangan",
+    "AF-SAR" => "Sar-e Pol",
+    "AF-TAK" => "Takhār",
+    "AF-URU" => "Uruzgan",
+    "AF-WAR" => "Wardak",
+    "AF-ZAB" => "Zabul",
+    "AL-01" => "Berat",
+    "AL-02" => "Durrsës",
+    "AL-03" => "Elbasan",
+    "AL-04" => "Fier",
+    "AL-05" => "Gjirokastër",
+    "AL-06" => "Korçë",
+    "AL-07" => "Kukës",
+    "AL-08" => "Lezhë",
+    "AL-09" => "Dibër",
+    "AL-10" => "Shkodër",
+    "AL-11" => "Tirana",
+    "AL-12" => "Vlorë",
+    "DZ-01" => "Adrar",
+    "DZ-02" => "Chlef",
+    "DZ-03" => "Laghouat",
+    "DZ-04" => "Oum el Bouaghi",
+    "DZ-05" => "Batna",
+    "DZ-06" => "Béjaïa",
+    "DZ-07" => "Biskra",
+    "DZ-08" => "Béchar",
+    "DZ-09" => "Blida",
+    "DZ-10" => "Bouira",
+    "DZ-11" => "Tamanrasset",
+    "DZ-12" => "Tébessa",
+    "DZ-13" => "Tlemcen",
+    "DZ-14" => "Tiaret",
+    "DZ-15" => "Tizi Ouzou",
+    "DZ-16" => "Algiers",
+    "DZ-17" => "Djelfa",
+    "DZ-18" => "Jijel",
+    "DZ-19" => "Sétif",
+    "DZ-20" => "Saida",
+    "DZ-21" => "Skikda",
+    "DZ-22" => "Sidi-bel-Abbes",
+    "DZ-23" => "Annaba",
+    "DZ-24" => "Guelma",
+    "DZ-25" => "Constantine",
+    "DZ-26" => "Médéa",
+    "DZ-27" => "Mostaganem",
+    "DZ-28" => "M'sila",
+    "DZ-29" => "Mascara",
+    "DZ-30" => "Ouargla",
+    "DZ-31" => "Oran",
+    "DZ-32" => "El Bayadh",
+    "DZ-33" => "Illizi",
+    "DZ-34" => "Bordj Bou Arréridj",
+    "DZ-35" => "Boumerdès",
+    "DZ-36" => "El Tarf",
+    "DZ-37" => "Tindouf",
+    "DZ-38" => "Tissemsilt",
+    "DZ-39" => "El Oued",
+    "DZ-40" => "Khenchela",
+    "DZ-41" => "Souk Ahras",
+    "DZ-42" => "Tipaza",
+    "DZ-43" => "Mila",
+    "DZ-44" => "Aïn Defla",
+    "DZ-45" => "Naama",
+    "DZ-46" => "Aïn Témouchent",
+    "DZ-47" => "Ghardaïa",
+    "DZ-48" => "Relizane",
+    "AD-02" => "Canillo",
+    "AD-03" => "Encamp",
+    "AD-04" => "La Massana",
+    "AD-05" => "Ordino",
+    "AD-06" => "Sant Julià de Lòria",
+    "AD-07" => "Andorra la Vella",
+    "AD-08" => "Escaldes-Engordany",
+    "AO-BGO" => "Bengo",
+    "AO-BGU" => "Benguela",
+    "AO-BIE" => "Bié",
+    "AO-CAB" => "Cabinda",
+    "AO-CCU" => "Cuando-Cubango",
+    "AO-CNN" => "Cunene",
+    "AO-CNO" => "Noord-Cuanza",
+    "AO-CUS" => "Zuid-Cuanza",
+    "AO-HUA" => "Huambo",
+    "AO-HUI" => "Huíla",
+    "AO-LNO" => "Noord-Lunda",
+    "AO-LSU" => "Zuid-Lunda",
+    "AO-LUA" => "Luanda",
+    "AO-MAL" => "Malange",
+    "AO-MOX" => "Moxico",
+    "AO-NAM" => "Namibe",
+    "AO-UIG" => "Uíge",
+    "AO-ZAI" => "Zaïre",
+    "AG-03" => "Saint George",
+    "AG-04" => "Saint John",
+    "AG-05" => "Saint Mary",
+    "AG-06" => "Saint Paul",
+    "AG-07" => "Saint Peter",
+    "AG-08" => "Saint Philip",
+    "AG-10" => "Barbuda",
+    "AG-11" => "Redonda",
+    "AR-A" => "Salta",
+    "AR-B" => "Buenos Aires",
+    "AR-C" => "Autonome Stad Buenos Aires",
+    "AR-D" => "San Luis",
+    "AR-E" => "Entre Ríos",
+    "AR-F" => "La Rioja",
+    "AR-G" => "Santiago del Estero",
+    "AR-H" => "Chaco",
+    "AR-J" => "San Juan",
+    "AR-K" => "Catamarca",
+    "AR-L" => "La Pampa",
+    "AR-M" => "Mendoza",
+    "AR-N" => "Misiones",
+    "AR-P" => "Formosa",
+    "AR-Q" => "Neuquén",
+    "AR-R" => "Río Negro",
+    "AR-S" => "Santa Fe",
+    "AR-T" => "Tucumán",
+    "AR-U" => "Chubut",
+    "AR-V" => "Tierra del Fuego",
+    "AR-W" => "Corrientes",
+    "AR-X" => "Córdoba",
+    "AR-Y" => "Jujuy",
+    "AR-Z" => "Santa Cruz",
+    "AM-AG" => "Aragacotn",
+    "AM-AR" => "Ararat",
+    "AM-AV" => "Armavir",
+    "AM-ER" => "Jerevan",
+    "AM-GR" => "Gegark'unik'",
+    "AM-KT" => "Kotayk'",
+    "AM-LO" => "Lori",
+    "AM-SH" => "Sirak",
+    "AM-SU" => "Syunik'",
+    "AM-TV" => "Tavus",
+    "AM-VD" => "Vayoc Jor",
+    "AU-ACT" => "Australisch Hoofdstedelijk Territorium",
+    "AU-NSW" => "Nieuw-Zuid-Wales",
+    "AU-NT" => "Noordelijk Territorium",
+    "AU-QLD" => "Queensland",
+    "AU-SA" => "Zuid-Australië",
+    "AU-TAS" => "Tasmanië",
+    "AU-VIC" => "Victoria",
+    "AU-WA" => "West-Australië",
+    "AT-1" => "Burgenland",
+    "AT-2" => "Karinthië",
+    "AT-3" => "Neder-Oostenrijk",
+    "AT-4" => "Opper-Oostenrijk",
+    "AT-5" => "Salzburg",
+    "AT-6" => "Steiermark",
+    "AT-7" => "Tirol",
+    "AT-8" => "Vorarlberg",
+    "AT-9" => "Wenen",
+    "AZ-ABS" => "Abseron",
+    "AZ-AGA" => "Agstafa",
+    "AZ-AGC" => "Agcabadi",
+    "AZ-AGM" => "Agdam",
+    "AZ-AGS" => "Agdas",
+    "AZ-AGU" => "Agsu",
+    "AZ-AST" => "Astara",
+    "AZ-BA" => "Bakoe",
+    "AZ-BAB" => "Babak",
+    "AZ-BAL" => "Balakan",
+    "AZ-BAR" => "Barda",
+    "AZ-BEY" => "Beylaqan",
+    "AZ-BIL" => "Bilasuvar",
+    "AZ-CAB" => "Cabrayil",
+    "AZ-CAL" => "Cəlilabad",
+    "AZ-CUL" => "Culfa",
+    "AZ-DAS" => "Daskasan",
+    "AZ-FUZ" => "Fuzuli",
+    "AZ-GA" => "Ganca",
+    "AZ-GAD" => "Gadabay",
+    "AZ-GOR" => "Goranboy",
+    "AZ-GOY" => "Goycay",
+    "AZ-GYG" => "Göygöl",
+    "AZ-HAC" => "Haciqabul",
+    "AZ-IMI" => "Imisli",
+    "AZ-ISM" => "Ismayilli",
+    "AZ-KAL" => "Kalbacar",
+    "AZ-KAN" => "Kangarli",
+    "AZ-KUR" => "Kurdamir",
+    "AZ-LA" => "Lankaran",
+    "AZ-LAC" => "Lacin",
+    "AZ-LAN" => "Lankaran",
+    "AZ-LER" => "Lerik",
+    "AZ-MAS" => "Masalli",
+    "AZ-MI" => "Mingacevir",
+    "AZ-NA" => "Naftalan",
+    "AZ-NEF" => "Neftcala",
+    "AZ-NV" => "Nachitsjevan",
+    "AZ-NX" => "Nachitsjevan",
+    "AZ-OGU" => "Oguz",
+    "AZ-ORD" => "Ordubad",
+    "AZ-QAB" => "Qabala",
+    "AZ-QAX" => "Qax",
+    "AZ-QAZ" => "Qazax",
+    "AZ-QBA" => "Quba",
+    "AZ-QBI" => "Qubadli",
+    "AZ-QOB" => "Qobustan",
+    "AZ-QUS" => "Qusar",
+    "AZ-SA" => "Saki",
+    "AZ-SAB" => "Sabirabad",
+    "AZ-SAD" => "Sadarak",
+    "AZ-SAH" => "Sahbuz",
+    "AZ-SAK" => "Saki",
+    "AZ-SAL" => "Salyan",
+    "AZ-SAR" => "Sarur",
+    "AZ-SAT" => "Saatli",
+    "AZ-SBN" => "Şabran",
+    "AZ-SIY" => "Siyazan",
+    "AZ-SKR" => "Samkir",
+    "AZ-SM" => "Sumqayit",
+    "AZ-SMI" => "Samaxi",
+    "AZ-SMX" => "Samux",
+    "AZ-SR" => "Şirvan",
+    "AZ-SUS" => "Susa",
+    "AZ-TAR" => "Tartar",
+    "AZ-TOV" => "Tovuz",
+    "AZ-UCA" => "Ucar",
+    "AZ-XA" => "Xankandi",
+    "AZ-XAC" => "Xacmaz",
+    "AZ-XCI" => "Xocali",
+    "AZ-XIZ" => "Xizi",
+    "AZ-XVD" => "Xocavand",
+    "AZ-YAR" => "Yardimli",
+    "AZ-YE" => "Yevlax",
+    "AZ-YEV" => "Yevlax",
+    "AZ-ZAN" => "Zangilan",
+    "AZ-ZAQ" => "Zaqatala",
+    "AZ-ZAR" => "Zardab",
+    "BS-AK" => "Acklins",
+    "BS-BI" => "Bimini",
+    "BS-BP" => "Black Point",
+    "BS-BY" => "Berry-eilanden",
+    "BS-CE" => "Central Eleuthera",
+    "BS-CI" => "Cat Island",
+    "BS-CK" => "Crooked Island en Long Cay",
+    "BS-CO" => "Central Abaco",
+    "BS-CS" => "Central Andros",
+    "BS-EG" => "East Grand Bahama",
+    "BS-EX" => "Exuma",
+    "BS-FP" => "Freeport (stad)",
+    "BS-GC" => "Grand Cay",
+    "BS-HI" => "Harbour Island",
+    "BS-HT" => "Hope Town",
+    "BS-IN" => "Inagua",
+    "BS-LI" => "Long Island",
+    "BS-MC" => "Mangrove Cay",
+    "BS-MG" => "Mayaguana",
+    "BS-MI" => "Moore's Island",
+    "BS-NE" => "North Eleuthera",
+    "BS-NO" => "North Abaco",
+    "BS-NP" => "New Providence",
+    "BS-NS" => "North Andros",
+    "BS-RC" => "Rum Cay",
+    "BS-RI" => "Ragged Island",
+    "BS-SA" => "South Andros",
+    "BS-SE" => "South Eleuthera",
+    "BS-SO" => "South Abaco",
+    "BS-SS" => "San Salvador",
+    "BS-SW" => "Spanish Wells",
+    "BS-WG" => "West Grand Bahama",
+    "BH-13" => "Al-Asimah",
+    "BH-14" => "Al Janblyah",
+    "BH-15" => "Al Muharraq",
+    "BH-17" => "Ash Shamallyah",
+    "BD-01" => "Bandarban",
+    "BD-02" => "Barguna",
+    "BD-03" => "Bogura",
+    "BD-04" => "Brahmanbaria",
+    "BD-05" => "Bagerhat",
+    "BD-06" => "Barisal",
+    "BD-07" => "Bhola",
+    "BD-08" => "Comilla",
+    "BD-09" => "Chandpur",
+    "BD-10" => "Chittagong",
+    "BD-11" => "Cox's Bazar",
+    "BD-12" => "Chuadanga",
+    "BD-13" => "Dhaka",
+    "BD-14" => "Dinajpur",
+    "BD-15" => "Faridpur",
+    "BD-16" => "Feni",
+    "BD-17" => "Gopalganj",
+    "BD-18" => "Gazipur",
+    "BD-19" => "Gaibandha",
+    "BD-20" => "Habiganj",
+    "BD-21" => "Jamalpur",
+    "BD-22" => "Jessore",
+    "BD-23" => "Jhenaidah",
+    "BD-24" => "Jaipurhat",
+    "BD-25" => "Jhalokati",
+    "BD-26" => "Kishoreganj",
+    "BD-27" => "Khulna",
+    "BD-28" => "Kurigram",
+    "BD-29" => "Khagrachari",
+    "BD-30" => "Kushtia",
+    "BD-31" => "Lakshmipur",
+    "BD-32" => "Lalmonirhat",
+    "BD-33" => "Manikganj",
+    "BD-34" => "Mymensingh",
+    "BD-35" => "Munshiganj",
+    "BD-36" => "Madaripur",
+    "BD-37" => "Magura",
+    "BD-38" => "Moulvibazar",
+    "BD-39" => "Meherpur",
+    "BD-40" => "Narayanganj",
+    "BD-41" => "Netrakona",
+    "BD-42" => "Narsingdi",
+    "BD-43" => "Narail",
+    "BD-44" => "Natore",
+    "BD-45" => "Chapai Nawabganj",
+    "BD-46" => "Nilphamari",
+    "BD-47" => "Noakhali",
+    "BD-48" => "Naogaon",
+    "BD-49" => "Pabna",
+    "BD-50" => "Pirojpur",
+    "BD-51" => "Patuakhali",
+    "BD-52" => "Panchagarh",
+    "BD-53" => "Rajbari",
+    "BD-54" => "Rajshahi",
+    "BD-55" => "Rangpur",
+    "BD-56" => "Rangamati",
+    "BD-57" => "Sherpur",
+    "BD-58" => "Satkhira",
+    "BD-59" => "Sirajganj",
+    "BD-60" => "Sylhet",
+    "BD-61" => "Sunamganj",
+    "BD-62" => "Shariatpur",
+    "BD-63" => "Tangail",
+    "BD-64" => "Thakurgaon",
+    "BD-A" => "Barisal",
+    "BD-B" => "Chittagong",
+    "BD-C" => "Dhaka",
+    "BD-D" => "Khulna",
+    "BD-E" => "Rajshahi",
+    "BD-F" => "Rangpur",
+    "BD-G" => "Sylhet",
+    "BD-H" => "Mymensingh",
+    "BB-01" => "Christ Church",
+    "BB-02" => "Saint Andrew",
+    "BB-03" => "Saint George",
+    "BB-04" => "Saint James",
+    "BB-05" => "Saint John",
+    "BB-06" => "Saint Joseph",
+    "BB-07" => "Saint Lucy",
+    "BB-08" => "Saint Michael",
+    "BB-09" => "Saint Peter",
+    "BB-10" => "Saint Philip",
+    "BB-11" => "Saint Thomas",
+    "BY-BR" => "Brest, Provincie",
+    "BY-HM" => "Minsk (stad)",
+    "BY-HO" => "Homel, Provincie",
+    "BY-HR" => "Grodno, Provincie",
+    "BY-MA" => "Mahiljow, Provincie",
+    "BY-MI" => "Minsk, Oblast",
+    "BY-VI" => "Vitebsk, Provincie",
+    "BE-BRU" => "Brussels Hoofdstedelijk Gewest",
+    "BE-VAN" => "Antwerpen",
+    "BE-VBR" => "Vlaams-Brabant",
+    "BE-VLG" => "Vlaams Gewest",
+    "BE-VLI" => "Limburg",
+    "BE-VOV" => "Oost-Vlaanderen",
+    "BE-VWV" => "West-Vlaanderen",
+    "BE-WAL" => "Wallonië, Gewest",
+    "BE-WBR" => "Waals-Brabant",
+    "BE-WHT" => "Henegouwen",
+    "BE-WLG" => "Luik",
+    "BE-WLX" => "Luxemburg",
+    "BE-WNA" => "Namen",
+    "BZ-BZ" => "Belize",
+    "BZ-CY" => "Cayo",
+    "BZ-CZL" => "Corozal",
+    "BZ-OW" => "Orange Walk",
+    "BZ-SC" => "Stann Creek",
+    "BZ-TOL" => "Toledo",
+    "BJ-AK" => "Atacora",
+    "BJ-AL" => "Alibori",
+    "BJ-AQ" => "Atlantique",
+    "BJ-BO" => "Borgou",
+    "BJ-CO" => "Collines",
+    "BJ-DO" => "Donga",
+    "BJ-KO" => "Couffo",
+    "BJ-LI" => "Littoral",
+    "BJ-MO" => "Mono",
+    "BJ-OU" => "Ouémé",
+    "BJ-PL" => "Plateau",
+    "BJ-ZO" => "Zou",
+    "BT-11" => "Paro",
+    "BT-12" => "Chhukha",
+    "BT-13" => "Haa",
+    "BT-14" => "Samtse",
+    "BT-15" => "Thimphu",
+    "BT-21" => "Tsirang",
+    "BT-22" => "Dagana",
+    "BT-23" => "Punakha",
+    "BT-24" => "Wangdue Phodrang",
+    "BT-31" => "Sarpang",
+    "BT-32" => "Trongsa",
+    "BT-33" => "Bumthang",
+    "BT-34" => "Zhemgang",
+    "BT-41" => "Trashigang",
+    "BT-42" => "Monggar",
+    "BT-43" => "Pemagatshel",
+    "BT-44" => "Lhuentse",
+    "BT-45" => "Samdrup Jongkhar",
+    "BT-GA" => "Gasa",
+    "BT-TY" => "Trashi Yangtse",
+    "BO-B" => "El Beni",
+    "BO-C" => "Cochabamba",
+    "BO-H" => "Chuquisaca",
+    "BO-L" => "La Paz",
+    "BO-N" => "Pando",
+    "BO-O" => "Oruro",
+    "BO-P" => "Potosí",
+    "BO-S" => "Santa Cruz",
+    "BO-T" => "Tarija",
+    "BQ-BO" => "Bonaire",
+    "BQ-SA" => "Saba",
+    "BQ-SE" => "Sint Eustatius",
+    "BA-BIH" => "Bosnië-Herzegovina, Federatie",
+    "BA-BRC" => "Brcko-district",
+    "BA-SRP" => "Servië, Republiek",
+    "BW-CE" => "Central",
+    "BW-CH" => "Chobe",
+    "BW-FR" => "Francistown",
+    "BW-GA" => "Gaborone",
+    "BW-GH" => "Ghanzi",
+    "BW-JW" => "Jwaneng",
+    "BW-KG" => "Kgalagadi",
+    "BW-KL" => "Kgatleng",
+    "BW-KW" => "Kweneng",
+    "BW-LO" => "Lobatse",
+    "BW-NE" => "Noord-Oost",
+    "BW-NW" => "Noord-West",
+    "BW-SE" => "Zuid-Oost",
+    "BW-SO" => "Zuid",
+    "BW-SP" => "Selibe Phikwe",
+    "BW-ST" => "Sowa Town",
+    "BR-AC" => "Acre",
+    "BR-AL" => "Alagoas",
+    "BR-AM" => "Amazonas",
+    "BR-AP" => "Amapá",
+    "BR-BA" => "Bahia",
+    "BR-CE" => "Ceará",
+    "BR-DF" => "Federaal District",
+    "BR-ES" => "Espírito Santo",
+    "BR-GO" => "Goiás",
+    "BR-MA" => "Maranhão",
+    "BR-MG" => "Minas Gerais",
+    "BR-MS" => "Zuidelijk Mato Grosso",
+    "BR-MT" => "Mato Grosso",
+    "BR-PA" => "Pará",
+    "BR-PB" => "Paraíba",
+    "BR-PE" => "Pernambuco",
+    "BR-PI" => "Piauí",
+    "BR-PR" => "Paraná",
+    "BR-RJ" => "Rio de Janeiro",
+    "BR-RN" => "Rio Grande do Norte",
+    "BR-RO" => "Rondônia",
+    "BR-RR" => "Roraima",
+    "BR-RS" => "Rio Grande do Sul",
+    "BR-SC" => "Santa Catarina",
+    "BR-SE" => "Sergipe",
+    "BR-SP" => "São Paulo",
+    "BR-TO" => "Tocantins",
+    "BN-BE" => "Belait",
+    "BN-BM" => "Brunei en Muara",
+    "BN-TE" => "Temburong",
+    "BN-TU" => "Tutong",
+    "BG-01" => "Blagoëvgrad",
+    "BG-02" => "Boergas",
+    "BG-03" => "Varna",
+    "BG-04" => "Veliko Tarnovo",
+    "BG-05" => "Vidin",
+    "BG-06" => "Vratsa",
+    "BG-07" => "Gabrovo",
+    "BG-08" => "Dobritsj",
+    "BG-09" => "Kardzjali",
+    "BG-10" => "Kjoestendil",
+    "BG-11" => "Lovetsj",
+    "BG-12" => "Montana",
+    "BG-13" => "Pazardzjik",
+    "BG-14" => "Pernik",
+    "BG-15" => "Pleven",
+    "BG-16" => "Plovdiv",
+    "BG-17" => "Razgrad",
+    "BG-18" => "Roese",
+    "BG-19" => "Silistra",
+    "BG-20" => "Sliven",
+    "BG-21" => "Smoljan",
+    "BG-22" => "Sofia-Hoofdstad",
+    "BG-23" => "Sofia",
+    "BG-24" => "Stara Zagora",
+    "BG-25" => "Targovisjte",
+    "BG-26" => "Chaskovo",
+    "BG-27" => "Sjoemen",
+    "BG-28" => "Jambol",
+    "BF-01" => "Boucle du Mouhoun",
+    "BF-02" => "Cascades",
+    "BF-03" => "Centre",
+    "BF-04" => "Centre-Est",
+    "BF-05" => "Centre-Nord",
+    "BF-06" => "Centre-Ouest",
+    "BF-07" => "Centre-Sud",
+    "BF-08" => "Est",
+    "BF-09" => "Hauts-Bassins",
+    "BF-10" => "Noord",
+    "BF-11" => "Plateau-Central",
+    "BF-12" => "Sahel",
+    "BF-13" => "Sud-Ouest",
+    "BF-BAL" => "Balé",
+    "BF-BAM" => "Bam",
+    "BF-BAN" => "Banwa",
+    "BF-BAZ" => "Bazéga",
+    "BF-BGR" => "Bougouriba",
+    "BF-BLG" => "Boulgou",
+    "BF-BLK" => "Boulkiemdé",
+    "BF-COM" => "Comoé",
+    "BF-GAN" => "Ganzourgou",
+    "BF-GNA" => "Gnagna",
+    "BF-GOU" => "Gourma",
+    "BF-HOU" => "Houet",
+    "BF-IOB" => "Ioba",
+    "BF-KAD" => "Kadiogo",
+    "BF-KEN" => "Kénédougou",
+    "BF-KMD" => "Komondjari",
+    "BF-KMP" => "Kompienga",
+    "BF-KOP" => "Koulpélogo",
+    "BF-KOS" => "Kossi",
+    "BF-KOT" => "Kouritenga",
+    "BF-KOW" => "Kourwéogo",
+    "BF-LER" => "Léraba",
+    "BF-LOR" => "Loroum",
+    "BF-MOU" => "Mouhoun",
+    "BF-NAM" => "Namentenga",
+    "BF-NAO" => "Nahouri",
+    "BF-NAY" => "Nayala",
+    "BF-NOU" => "Noumbiel",
+    "BF-OUB" => "Oubritenga",
+    "BF-OUD" => "Oudalan",
+    "BF-PAS" => "Passoré",
+    "BF-PON" => "Poni",
+    "BF-SEN" => "Séno",
+    "BF-SIS" => "Sissili",
+    "BF-SMT" => "Sanmatenga",
+    "BF-SNG" => "Sanguié",
+    "BF-SOM" => "Soum",
+    "BF-SOR" => "Sourou",
+    "BF-TAP" => "Tapoa",
+    "BF-TUI" => "Tuy",
+    "BF-YAG" => "Yagha",
+    "BF-YAT" => "Yatenga",
+    "BF-ZIR" => "Ziro",
+    "BF-ZON" => "Zondoma",
+    "BF-ZOU" => "Zoundwéogo",
+    "BI-BB" => "Bubanza",
+    "BI-BL" => "Bujumbura Rural",
+    "BI-BM" => "Bujumbura Mairie",
+    "BI-BR" => "Bururi",
+    "BI-CA" => "Cankuzo",
+    "BI-CI" => "Cibitoke",
+    "BI-GI" => "Gitega",
+    "BI-KI" => "Kirundo",
+    "BI-KR" => "Karuzi",
+    "BI-KY" => "Kayanza",
+    "BI-MA" => "Makamba",
+    "BI-MU" => "Muramvya",
+    "BI-MW" => "Mwaro",
+    "BI-MY" => "Muyinga",
+    "BI-NG" => "Ngozi",
+    "BI-RM" => "Rumonge",
+    "BI-RT" => "Rutana",
+    "BI-RY" => "Ruyigi",
+    "CV-B" => "Ilhas de Barlavento",
+    "CV-BR" => "Brava",
+    "CV-BV" => "Boa Vista",
+    "CV-CA" => "Santa Catarina",
+    "CV-CF" => "Santa Catarina do Fogo",
+    "CV-CR" => "Santa Cruz",
+    "CV-MA" => "Maio",
+    "CV-MO" => "Mosteiros",
+    "CV-PA" => "Paul",
+    "CV-PN" => "Porto Novo",
+    "CV-PR" => "Praia",
+    "CV-RB" => "Ribeira Brava",
+    "CV-RG" => "Ribeira Grande",
+    "CV-RS" => "Ribeira Grande de Santiago",
+    "CV-S" => "Ilhas de Sotavento",
+    "CV-SD" => "São Domingos",
+    "CV-SF" => "São Filipe",
+    "CV-SL" => "Sal",
+    "CV-SM" => "São Miguel",
+    "CV-SO" => "São Lourenço dos Órgãos",
+    "CV-SS" => "São Salvador do Mundo",
+    "CV-SV" => "São Vicente",
+    "CV-TA" => "Tarrafal",
+    "CV-TS" => "Tarrafal de São Nicolau",
+    "KH-1" => "Banteay Mean Cheay",
+    "KH-10" => "Kracheh",
+    "KH-11" => "Mondol Kiri",
+    "KH-12" => "Phnom-Penh, Stedelijk Gewest",
+    "KH-13" => "Preah Vihear",
+    "KH-14" => "Prey Veng",
+    "KH-15" => "Pouthisat",
+    "KH-16" => "Ratanakiri",
+    "KH-17" => "Siem Reab",
+    "KH-18" => "Sihanoukville, Stedelijk Gewest",
+    "KH-19" => "Stoeng Treng",
+    "KH-2" => "Battambang",
+    "KH-20" => "Svay Rieng",
+    "KH-21" => "Takev",
+    "KH-22" => "Oddar Meancheay",
+    "KH-23" => "Keb; Kaeb",
+    "KH-24" => "Pailin, Stedelijk Gewest",
+    "KH-25" => "Tbong Khmum",
+    "KH-3" => "Kampong Cham",
+    "KH-4" => "Kampong Chhnang",
+    "KH-5" => "Kampong Spoe",
+    "KH-6" => "Kampong Thum",
+    "KH-7" => "Kampot",
+    "KH-8" => "Kandal",
+    "KH-9" => "Koh Kong",
+    "CM-AD" => "Adamaoua",
+    "CM-CE" => "Centre",
+    "CM-EN" => "Verre Noorden",
+    "CM-ES" => "Oost",
+    "CM-LT" => "Littoral",
+    "CM-NO" => "Noord",
+    "CM-NW" => "Noord-West",
+    "CM-OU" => "West",
+    "CM-SU" => "Zuid",
+    "CM-SW" => "Zuid-West",
+    "CA-AB" => "Alberta",
+    "CA-BC" => "Brits-Columbia",
+    "CA-MB" => "Manitoba",
+    "CA-NB" => "New Brunswick",
+    "CA-NL" => "Newfoundland en Labrador",
+    "CA-NS" => "Nova Scotia",
+    "CA-NT" => "Noord-West Territoria",
+    "CA-NU" => "Nunavut",
+    "CA-ON" => "Ontario",
+    "CA-PE" => "Prins Edwardeiland",
+    "CA-QC" => "Quebec",
+    "CA-SK" => "Saskatchewan",
+    "CA-YT" => "Yukon",
+    "CF-AC" => "Ouham",
+    "CF-BB" => "Bamingui-Bangoran",
+    "CF-BGF" => "Bangui",
+    "CF-BK" => "Basse-Kotto",
+    "CF-HK" => "Haute-Kotto",
+    "CF-HM" => "Haut-Mbomou",
+    "CF-HS" => "Mambéré-Kadéï",
+    "CF-KB" => "Gribingui",
+    "CF-KG" => "Kémo",
+    "CF-LB" => "Lobaye",
+    "CF-MB" => "Mbomou",
+    "CF-MP" => "Ombella-Mpoko",
+    "CF-NM" => "Nana-Mambéré",
+    "CF-OP" => "Ouham-Pendé",
+    "CF-SE" => "Sangha",
+    "CF-UK" => "Ouaka",
+    "CF-VK" => "Vakaga",
+    "TD-BA" => "Batha",
+    "TD-BG" => "Bahr el Ghazal",
+    "TD-BO" => "Borkou",
+    "TD-CB" => "Chari-Baguirmi",
+    "TD-EE" => "Ennedi Est",
+    "TD-EO" => "Ennedi Ouest",
+    "TD-GR" => "Guéra",
+    "TD-HL" => "Hadjer-Lamis",
+    "TD-KA" => "Kanem",
+    "TD-LC" => "Al Buhayrah",
+    "TD-LO" => "Logone Occidental",
+    "TD-LR" => "Logone Oriental",
+    "TD-MA" => "Mandoul",
+    "TD-MC" => "Moyen-Chari",
+    "TD-ME" => "Mayo-Kebbi Est",
+    "TD-MO" => "Mayo-Kebbi Ouest",
+    "TD-ND" => "Ndjamena",
+    "TD-OD" => "Ouaddaï",
+    "TD-SA" => "Salamat",
+    "TD-SI" => "Sila",
+    "TD-TA" => "Tandjilé",
+    "TD-TI" => "Tibesti",
+    "TD-WF" => "Wadi Fira",
+    "CL-AI" => "Aysén del General Carlos Ibáñez del Campo",
+    "CL-AN" => "Antofagasta",
+    "CL-AP" => "Arica y Parinacota",
+    "CL-AR" => "La Araucanía",
+    "CL-AT" => "Atacama",
+    "CL-BI" => "Biobío",
+    "CL-CO" => "Coquimbo",
+    "CL-LI" => "Bevrijder Generaal Bernard O'Higgins",
+    "CL-LL" => "Los Lagos",
+    "CL-LR" => "Los Ríos",
+    "CL-MA" => "Magallanes",
+    "CL-ML" => "Maule",
+    "CL-NB" => "Ñuble",
+    "CL-RM" => "Santiago, Hoofdstedelijke Regio van",
+    "CL-TA" => "Tarapacá",
+    "CL-VS" => "Valparaíso",
+    "CN-AH" => "Anhui, Provincie",
+    "CN-BJ" => "Peking, Stadsprovincie; Beijing, Stadsprovincie",
+    "CN-CQ" => "Tsjoengking, Stadsprovincie; Chongqing, Stadsprovincie",
+    "CN-FJ" => "Fujian, Provincie",
+    "CN-GD" => "Guangdong, Provincie",
+    "CN-GS" => "Gansu, Provincie",
+    "CN-GX" => "Guangxi Zhuang Autonome Regio",
+    "CN-GZ" => "Guizhou, Provincie",
+    "CN-HA" => "Henan, Provincie",
+    "CN-HB" => "Hubei, Provincie",
+    "CN-HE" => "Hebei, Provincie",
+    "CN-HI" => "Hainan, Provincie",
+    "CN-HK" => "Hongkong (speciale bestuurlijke regio)",
+    "CN-HL" => "Heilongjiang, Provincie",
+    "CN-HN" => "Hunan, Provincie",
+    "CN-JL" => "Jilin, Provincie",
+    "CN-JS" => "Jiangsu, Provincie",
+    "CN-JX" => "Jiangxi, Provincie",
+    "CN-LN" => "Liaoning, Provincie",
+    "CN-MO" => "Macau (speciale bestuurlijke regio)",
+    "CN-NM" => "Binnen-Mongolië, Autonome Regio",
+    "CN-NX" => "Autonome Hui Regio Ningxia",
+    "CN-QH" => "Qinghai, Provincie",
+    "CN-SC" => "Sichuan, Provincie",
+    "CN-SD" => "Shandong, Provincie",
+    "CN-SH" => "Sjanghai, Stadsprovincie",
+    "CN-SN" => "Shaanxi, Provincie",
+    "CN-SX" => "Shanxi, Provincie",
+    "CN-TJ" => "Tianjin, Stadsprovincie",
+    "CN-TW" => "Hainan, Provincie",
+    "CN-XJ" => "Xinjiang, Autonome Oeigoerse Regio",
+    "CN-XZ" => "Tibetaanse Autonome Regio",
+    "CN-YN" => "Yunnan, Provincie",
+    "CN-ZJ" => "Zhejiang, Provincie",
+    "CO-AMA" => "Amazonas",
+    "CO-ANT" => "Antioquia",
+    "CO-ARA" => "Arauca",
+    "CO-ATL" => "Atlántico",
+    "CO-BOL" => "Bolívar",
+    "CO-BOY" => "Boyacá",
+    "CO-CAL" => "Caldas",
+    "CO-CAQ" => "Caquetá",
+    "CO-CAS" => "Casanare",
+    "CO-CAU" => "Cauca",
+    "CO-CES" => "Cesar",
+    "CO-CHO" => "Chocó",
+    "CO-COR" => "Córdoba",
+    "CO-CUN" => "Cundinamarca",
+    "CO-DC" => "Bogotá, Hoofdstedelijk Gewest van",
+    "CO-GUA" => "Guainía",
+    "CO-GUV" => "Guaviare",
+    "CO-HUI" => "Huila",
+    "CO-LAG" => "La Guajira",
+    "CO-MAG" => "Magdalena",
+    "CO-MET" => "Meta",
+    "CO-NAR" => "Nariño",
+    "CO-NSA" => "Noordelijk van Santander",
+    "CO-PUT" => "Putumayo",
+    "CO-QUI" => "Quindío",
+    "CO-RIS" => "Risaralda",
+    "CO-SAN" => "Santander",
+    "CO-SAP" => "San Andrés, Providencia en Santa Catalina",
+    "CO-SUC" => "Sucre",
+    "CO-TOL" => "Tolima",
+    "CO-VAC" => "Vallei van de Cauca",
+    "CO-VAU" => "Vaupés",
+    "CO-VID" => "Vichada",
+    "KM-A" => "Anjouan",
+    "KM-G" => "Grande Comore",
+    "KM-M" => "Mohéli",
+    "CG-11" => "Bouenza",
+    "CG-12" => "Pool",
+    "CG-13" => "Sangha",
+    "CG-14" => "Plateaux",
+    "CG-15" => "Cuvette-West",
+    "CG-16" => "Pointe-Noire",
+    "CG-2" => "Lékoumou",
+    "CG-5" => "Kouilou",
+    "CG-7" => "Likouala",
+    "CG-8" => "Cuvette",
+    "CG-9" => "Niari",
+    "CG-BZV" => "Brazzaville",
+    "CD-BC" => "Kongo Central",
+    "CD-BU" => "Bas-Uele",
+    "CD-EQ" => "Evenaarsprovincie",
+    "CD-HK" => "Haut-Katanga",
+    "CD-HL" => "Opper-Lomami",
+    "CD-HU" => "Opper-Uele",
+    "CD-IT" => "Ituri",
+    "CD-KC" => "Centraal-Kasaï",
+    "CD-KE" => "Oost-Kasaï",
+    "CD-KG" => "Kwango",
+    "CD-KL" => "Kwilu",
+    "CD-KN" => "Kinshasa",
+    "CD-KS" => "Kasaï",
+    "CD-LO" => "Lomami",
+    "CD-LU" => "Lualaba",
+    "CD-MA" => "Maniema",
+    "CD-MN" => "Mai-Ndombe",
+    "CD-MO" => "Mongala",
+    "CD-NK" => "Noord-Kivu",
+    "CD-NU" => "Noord-Ubangi",
+    "CD-SA" => "Sankuru",
+    "CD-SK" => "Zuid-Kivu",
+    "CD-SU" => "Zuid-Ubangi",
+    "CD-TA" => "Tanganyika",
+    "CD-TO" => "Tshopo",
+    "CD-TU" => "Tshuapa",
+    "CR-A" => "Alajuela",
+    "CR-C" => "Cartago",
+    "CR-G" => "Guanacaste",
+    "CR-H" => "Heredia",
+    "CR-L" => "Limón",
+    "CR-P" => "Puntarenas",
+    "CR-SJ" => "San José",
+    "CI-AB" => "Abidjan",
+    "CI-BS" => "Bas-Sassandra",
+    "CI-CM" => "Comoé",
+    "CI-DN" => "Denguélé",
+    "CI-GD" => "Gôh-Djiboua",
+    "CI-LC" => "Lacs",
+    "CI-LG" => "Lagunes",
+    "CI-MG" => "Montagnes",
+    "CI-SM" => "Sassandra-Marahoué",
+    "CI-SV" => "Savanes",
+    "CI-VB" => "Vallée du Bandama",
+    "CI-WR" => "Worobe",
+    "CI-YM" => "Yamoussoukro",
+    "CI-ZZ" => "Zanzan",
+    "HR-01" => "Zagreb, Provincie",
+    "HR-02" => "Krapina-Zagorje, Provincie",
+    "HR-03" => "Sisak-Moslavina, Provincie",
+    "HR-04" => "Karlovac, Provincie",
+    "HR-05" => "Varazdin, Provincie",
+    "HR-06" => "Koprivnica-Križevci, Provincie",
+    "HR-07" => "Bjelovar-Bilogora, Provincie",
+    "HR-08" => "Primorje-Gorski, Provincie",
+    "HR-09" => "Lika-Senj, Provincie",
+    "HR-10" => "Virovitica-Podravina, Provincie",
+    "HR-11" => "Pozega-Slavonië, Provincie",
+    "HR-12" => "Brod-Posavina, Provincie",
+    "HR-13" => "Zadar, Provincie",
+    "HR-14" => "Osijek-Baranja, Provincie",
+    "HR-15" => "Sibenik-Knin, Provincie",
+    "HR-16" => "Vukovar-Srijem, Provincie",
+    "HR-17" => "Split-Dalmatië, Provincie",
+    "HR-18" => "Istrië, Provincie",
+    "HR-19" => "Dubrovnik-Neretva, Provincie",
+    "HR-20" => "Medjimurje, Provincie",
+    "HR-21" => "Zagreb (stad)",
+    "CU-01" => "Pinar del Río",
+    "CU-03" => "La Habana",
+    "CU-04" => "Matanzas",
+    "CU-05" => "Villa Clara",
+    "CU-06" => "Cienfuegos",
+    "CU-07" => "Sancti Spíritus",
+    "CU-08" => "Ciego de Ávila",
+    "CU-09" => "Camagüey",
+    "CU-10" => "Las Tunas",
+    "CU-11" => "Holguín",
+    "CU-12" => "Granma",
+    "CU-13" => "Santiago de Cuba",
+    "CU-14" => "Guantánamo",
+    "CU-15" => "Artemisa",
+    "CU-16" => "Mayabeque",
+    "CU-99" => "Isla de la Juventud",
+    "CY-01" => "Nicosia",
+    "CY-02" => "Limasol",
+    "CY-03" => "Larnaca",
+    "CY-04" => "Famagusta",
+    "CY-05" => "Paphos; Pafos",
+    "CY-06" => "Girne",
+    "CZ-10" => "Praag, Hoofdstedelijk Gewest",
+    "CZ-20" => "Midden-Bohemen, Regio",
+    "CZ-201" => "Benešov",
+    "CZ-202" => "Beroun",
+    "CZ-203" => "Kladno",
+    "CZ-204" => "Kolín",
+    "CZ-205" => "Kutná Hora",
+    "CZ-206" => "Mělník",
+    "CZ-207" => "Mladá Boleslav",
+    "CZ-208" => "Nymburk",
+    "CZ-209" => "Praag-Oost",
+    "CZ-20A" => "Praag-West",
+    "CZ-20B" => "Příbram",
+    "CZ-20C" => "Rakovník",
+    "CZ-31" => "Zuid-Bohemen, Regio",
+    "CZ-311" => "České Budějovice",
+    "CZ-312" => "Český Krumlov",
+    "CZ-313" => "Jindřichův Hradec",
+    "CZ-314" => "Písek",
+    "CZ-315" => "Prachatice",
+    "CZ-316" => "Strakonice",
+    "CZ-317" => "Tábor",
+    "CZ-32" => "Pilsen, Regio",
+    "CZ-321" => "Domažlice",
+    "CZ-322" => "Klatovy",
+    "CZ-323" => "Pilsen-Stad",
+    "CZ-324" => "Pilsen-Zuid",
+    "CZ-325" => "Pilsen-Noord",
+    "CZ-326" => "Rokycany",
+    "CZ-327" => "Tachov",
+    "CZ-41" => "Karlsbad, Regio",
+    "CZ-411" => "Cheb",
+    "CZ-412" => "Karlsbad",
+    "CZ-413" => "Sokolov",
+    "CZ-42" => "Ústí nad Labem, Regio",
+    "CZ-421" => "Děčín",
+    "CZ-422" => "Chomutov",
+    "CZ-423" => "Litoměřice",
+    "CZ-424" => "Louny",
+    "CZ-425" => "Most",
+    "CZ-426" => "Teplice",
+    "CZ-427" => "Ústí nad Labem",
+    "CZ-51" => "Liberec, Regio",
+    "CZ-511" => "Česká Lípa",
+    "CZ-512" => "Jablonec nad Nisou",
+    "CZ-513" => "Liberec",
+    "CZ-514" => "Semily",
+    "CZ-52" => "Hradec Králové, Regio",
+    "CZ-521" => "Hradec Králové",
+    "CZ-522" => "Jičín",
+    "CZ-523" => "Náchod",
+    "CZ-524" => "Rychnov nad Kněžnou",
+    "CZ-525" => "Trutnov",
+    "CZ-53" => "Pardubice, Regio",
+    "CZ-531" => "Chrudim",
+    "CZ-532" => "Pardubice",
+    "CZ-533" => "Svitavy",
+    "CZ-534" => "Ústí nad Orlicí",
+    "CZ-63" => "Vysočina, Regio",
+    "CZ-631" => "Havlíčkův Brod",
+    "CZ-632" => "Jihlava",
+    "CZ-633" => "Pelhřimov",
+    "CZ-634" => "Třebíč",
+    "CZ-635" => "Žďár nad Sázavou",
+    "CZ-64" => "Zuid-Moravië, Regio",
+    "CZ-641" => "Blansko",
+    "CZ-642" => "Brno-město",
+    "CZ-643" => "Brno-venkov",
+    "CZ-644" => "Břeclav",
+    "CZ-645" => "Hodonín",
+    "CZ-646" => "Vyškov",
+    "CZ-647" => "Znojmo",
+    "CZ-71" => "Olomouc, Regio",
+    "CZ-711" => "Jeseník",
+    "CZ-712" => "Olomouc",
+    "CZ-713" => "Prostějov",
+    "CZ-714" => "Přerov",
+    "CZ-715" => "Šumperk",
+    "CZ-72" => "Zlín, Regio",
+    "CZ-721" => "Kroměříž",
+    "CZ-722" => "Uherské Hradiště",
+    "CZ-723" => "Vsetín",
+    "CZ-724" => "Zlín",
+    "CZ-80" => "Moravisch-Silezië, Regio",
+    "CZ-801" => "Bruntál",
+    "CZ-802" => "Frýdek-Místek",
+    "CZ-803" => "Karviná",
+    "CZ-804" => "Nový Jičín",
+    "CZ-805" => "Opava",
+    "CZ-806" => "Ostrava-město",
+    "DK-81" => "Noord-Jutland",
+    "DK-82" => "Midden-Jutland",
+    "DK-83" => "Zuid-Denemarken",
+    "DK-84" => "Hoofdstad",
+    "DK-85" => "Seeland",
+    "DJ-AR" => "Arta",
+    "DJ-AS" => "Ali Sabieh",
+    "DJ-DI" => "Dikhil",
+    "DJ-DJ" => "Djibouti",
+    "DJ-OB" => "Awbuk",
+    "DJ-TA" => "Tadjourah",
+    "DM-02" => "Saint Andrew",
+    "DM-03" => "Saint David",
+    "DM-04" => "Saint George",
+    "DM-05" => "Saint John",
+    "DM-06" => "Saint Joseph",
+    "DM-07" => "Saint Luke",
+    "DM-08" => "Saint Mark",
+    "DM-09" => "Saint Patrick",
+    "DM-10" => "Saint Paul",
+    "DM-11" => "Saint Peter",
+    "DO-01" => "Distrito Nacional (Santo Domingo de Guzmán)",
+    "DO-02" => "Azua",
+    "DO-03" => "Baoruco",
+    "DO-04" => "Barahona",
+    "DO-05" => "Dajabón",
+    "DO-06" => "Duarte",
+    "DO-07" => "Elías Piña",
+    "DO-08" => "El Seibo",
+    "DO-09" => "Espaillat",
+    "DO-10" => "Independencia",
+    "DO-11" => "La Altagracia",
+    "DO-12" => "La Romana",
+    "DO-13" => "La Vega",
+    "DO-14" => "María Trinidad Sánchez",
+    "DO-15" => "Monte Cristi",
+    "DO-16" => "Pedernales",
+    "DO-17" => "Peravia",
+    "DO-18" => "Puerto Plata",
+    "DO-19" => "Hermanas Mirabal",
+    "DO-20" => "Samaná",
+    "DO-21" => "San Cristóbal",
+    "DO-22" => "San Juan",
+    "DO-23" => "San Pedro de Macorís",
+    "DO-24" => "Sánchez Ramírez",
+    "DO-25" => "Santiago",
+    "DO-26" => "Santiago Rodríguez",
+    "DO-27" => "Valverde",
+    "DO-28" => "Monseñor Nouel",
+    "DO-29" => "Monte Plata",
+    "DO-30" => "Hato Mayor",
+    "DO-31" => "San José de Ocoa",
+    "DO-32" => "Santo Domingo",
+    "DO-33" => "Cibao Nordeste (III)",
+    "DO-34" => "Cibao Noroeste (IV)",
+    "DO-35" => "Bioko Norte (I)",
+    "DO-36" => "Cibao Sur (II)",
+    "DO-37" => "El Valle",
+    "DO-38" => "Enriquillo",
+    "DO-39" => "Higüamo",
+    "DO-40" => "Ozama",
+    "DO-41" => "Valdesia",
+    "DO-42" => "Yuma",
+    "EC-A" => "Azuay",
+    "EC-B" => "Bolívar",
+    "EC-C" => "Carchi",
+    "EC-D" => "Orellana",
+    "EC-E" => "Esmeraldas",
+    "EC-F" => "Cañar",
+    "EC-G" => "Guayas",
+    "EC-H" => "Chimborazo",
+    "EC-I" => "Imbabura",
+    "EC-L" => "Loja",
+    "EC-M" => "Manabí",
+    "EC-N" => "Napo",
+    "EC-O" => "El Oro",
+    "EC-P" => "Pichincha",
+    "EC-R" => "Los Ríos",
+    "EC-S" => "Morona-Santiago",
+    "EC-SD" => "Santo Domingo de los Tsáchilas",
+    "EC-SE" => "Santa Elena",
+    "EC-T" => "Tungurahua",
+    "EC-U" => "Sucumbíos",
+    "EC-W" => "Galápagos",
+    "EC-X" => "Cotopaxi",
+    "EC-Y" => "Pastaza",
+    "EC-Z" => "Zamora-Chinchipe",
+    "EG-ALX" => "Alexandrië",
+    "EG-ASN" => "Aswan",
+    "EG-AST" => "Assioet",
+    "EG-BA" => "Al-Bahr-al-Ahmar",
+    "EG-BH" => "Al Buhayrah",
+    "EG-BNS" => "Beni Suef",
+    "EG-C" => "Caïro",
+    "EG-DK" => "Ad Daqahlyah",
+    "EG-DT" => "Damietta",
+    "EG-FYM" => "Al Fajoem",
+    "EG-GH" => "Al Gharbiyah",
+    "EG-GZ" => "Gizeh",
+    "EG-IS" => "Ismaïlia",
+    "EG-JS" => "Zuidelijke Sinaï",
+    "EG-KB" => "Al Qalyubiyah",
+    "EG-KFS" => "Kafr ash Shaykh",
+    "EG-KN" => "Qina",
+    "EG-LX" => "Luxor",
+    "EG-MN" => "Minya",
+    "EG-MNF" => "Al Minufiyah",
+    "EG-MT" => "Matruh",
+    "EG-PTS" => "Port Said",
+    "EG-SHG" => "Suhaj",
+    "EG-SHR" => "Ash Sharqiyah",
+    "EG-SIN" => "Noord-Sinaï",
+    "EG-SUZ" => "As Suways",
+    "EG-WAD" => "Al Wadi al Jadid",
+    "SV-AH" => "Ahuachapán",
+    "SV-CA" => "Cabañas",
+    "SV-CH" => "Chalatenango",
+    "SV-CU" => "Cuscatlán",
+    "SV-LI" => "La Libertad",
+    "SV-MO" => "Morazán",
+    "SV-PA" => "La Paz",
+    "SV-SA" => "Santa Ana",
+    "SV-SM" => "San Miguel",
+    "SV-SO" => "Sonsonate",
+    "SV-SS" => "San Salvador",
+    "SV-SV" => "San Vicente",
+    "SV-UN" => "La Unión",
+    "SV-US" => "Usulután",
+    "GQ-AN" => "Annobón",
+    "GQ-BN" => "Bioko Norte",
+    "GQ-BS" => "Bioko Sur",
+    "GQ-C" => "Continentale Regio",
+    "GQ-CS" => "Centro Sur",
+    "GQ-DJ" => "Djibloho",
+    "GQ-I" => "Eilandenregio",
+    "GQ-KN" => "Kié-Ntem",
+    "GQ-LI" => "Litoral",
+    "GQ-WN" => "Wele-Nzas",
+    "ER-AN" => "Anseba",
+    "ER-DK" => "Debubawi Keyih Bahri",
+    "ER-DU" => "Debub",
+    "ER-GB" => "Gash-Barka",
+    "ER-MA" => "Maekel",
+    "ER-SK" => "Semenawi Keyih Bahri",
+    "EE-130" => "Alutaguse",
+    "EE-141" => "Anija",
+    "EE-142" => "Antsla",
+    "EE-171" => "Elva",
+    "EE-184" => "Haapsalu",
+    "EE-191" => "Haljala",
+    "EE-198" => "Harku",
+    "EE-205" => "Hiiumaa",
+    "EE-214" => "Häädemeeste",
+    "EE-245" => "Jõelähtme",
+    "EE-247" => "Jõgeva",
+    "EE-251" => "Jõhvi",
+    "EE-255" => "Järva",
+    "EE-272" => "Kadrina",
+    "EE-283" => "Kambja",
+    "EE-284" => "Kanepi",
+    "EE-291" => "Kastre",
+    "EE-293" => "Kehtna",
+    "EE-296" => "Keila",
+    "EE-303" => "Kihnu",
+    "EE-305" => "Kiili",
+    "EE-317" => "Kohila",
+    "EE-321" => "Kohtla-Järve",
+    "EE-338" => "Kose",
+    "EE-353" => "Kuusalu",
+    "EE-37" => "Harjumaa",
+    "EE-39" => "Hiiumaa",
+    "EE-424" => "Loksa",
+    "EE-430" => "Lääneranna",
+    "EE-431" => "Lääne-Harju",
+    "EE-432" => "Luunja",
+    "EE-441" => "Lääne-Nigula",
+    "EE-442" => "Lüganuse",
+    "EE-446" => "Maardu",
+    "EE-45" => "Ida-Virumaa",
+    "EE-478" => "Muhu",
+    "EE-480" => "Mulgi",
+    "EE-486" => "Mustvee",
+    "EE-50" => "Jõgevamaa",
+    "EE-503" => "Märjamaa",
+    "EE-511" => "Narva",
+    "EE-514" => "Narva-Jõesuu",
+    "EE-52" => "Järvamaa",
+    "EE-528" => "Nõo",
+    "EE-557" => "Otepää",
+    "EE-56" => "Läänemaa",
+    "EE-567" => "Paide",
+    "EE-586" => "Peipsiääre",
+    "EE-60" => "Lääne-Virumaa",
+    "EE-615" => "Põhja-Sakala",
+    "EE-618" => "Põltsamaa",
+    "EE-622" => "Põlva",
+    "EE-624" => "Pärnu",
+    "EE-638" => "Põhja-Pärnumaa",
+    "EE-64" => "Põlvamaa",
+    "EE-651" => "Raasiku",
+    "EE-653" => "Rae",
+    "EE-661" => "Rakvere",
+    "EE-663" => "Rakvere",
+    "EE-668" => "Rapla",
+    "EE-68" => "Pärnumaa",
+    "EE-689" => "Ruhnu",
+    "EE-698" => "Rõuge",
+    "EE-708" => "Räpina",
+    "EE-71" => "Raplamaa",
+    "EE-712" => "Saarde",
+    "EE-714" => "Saaremaa",
+    "EE-719" => "Saku",
+    "EE-726" => "Saue",
+    "EE-732" => "Setomaa",
+    "EE-735" => "Sillamäe",
+    "EE-74" => "Saaremaa",
+    "EE-784" => "Tallinn",
+    "EE-79" => "Tartumaa",
+    "EE-792" => "Tapa",
+    "EE-793" => "Tartu",
+    "EE-796" => "Tartu",
+    "EE-803" => "Toila",
+    "EE-809" => "Tori",
+    "EE-81" => "Valgamaa",
+    "EE-824" => "Tõrva",
+    "EE-834" => "Türi",
+    "EE-84" => "Viljandimaa",
+    "EE-855" => "Valga",
+    "EE-87" => "Võrumaa",
+    "EE-890" => "Viimsi",
+    "EE-897" => "Viljandi",
+    "EE-899" => "Viljandi",
+    "EE-901" => "Vinni",
+    "EE-903" => "Viru-Nigula",
+    "EE-907" => "Vormsi",
+    "EE-917" => "Võru",
+    "EE-919" => "Võru",
+    "EE-928" => "Väike-Maarja",
+    "SZ-HH" => "Hhohho",
+    "SZ-LU" => "Lubombo",
+    "SZ-MA" => "Manzini",
+    "SZ-SH" => "Shiselweni",
+    "ET-AA" => "Addis Abeba",
+    "ET-AF" => "Afar",
+    "ET-AM" => "Amhara",
+    "ET-BE" => "Benishangul-Gumuz",
+    "ET-DD" => "Dire Dawa",
+    "ET-GA" => "Gambela",
+    "ET-HA" => "Harari",
+    "ET-OR" => "Oromia",
+    "ET-SN" => "Yedebub Biheroch Bihereseboch na Hizboch",
+    "ET-SO" => "Somali",
+    "ET-TI" => "Tigray",
+    "FJ-01" => "Ba",
+    "FJ-02" => "Bua",
+    "FJ-03" => "Cakaudrove",
+    "FJ-04" => "Kadavu",
+    "FJ-05" => "Lau",
+    "FJ-06" => "Lomaiviti",
+    "FJ-07" => "Macuata",
+    "FJ-08" => "Nadroga-Navosa",
+    "FJ-09" => "Naitasiri",
+    "FJ-10" => "Namosi",
+    "FJ-11" => "Ra",
+    "FJ-12" => "Rewa",
+    "FJ-13" => "Serua",
+    "FJ-14" => "Tailevu",
+    "FJ-C" => "Central",
+    "FJ-E" => "Oost",
+    "FJ-N" => "Northern",
+    "FJ-R" => "Rotuma",
+    "FJ-W" => "Western",
+    "FI-01" => "Åland",
+    "FI-02" => "Zuid-Karelië",
+    "FI-03" => "Zuid-Ostrobothnië",
+    "FI-04" => "Zuid-Savonië",
+    "FI-05" => "Kainuu",
+    "FI-06" => "Kanta-Häme",
+    "FI-07" => "Centraal-Ostrobothnië",
+    "FI-08" => "Centraal-Finland",
+    "FI-09" => "Kymenlaakso",
+    "FI-10" => "Lapland",
+    "FI-11" => "Pirkanmaa",
+    "FI-12" => "Ostrobothnië",
+    "FI-13" => "Noord-Karelië",
+    "FI-14" => "Noord-Ostrobothnië",
+    "FI-15" => "Noord-Savonië",
+    "FI-16" => "Päijät-Häme",
+    "FI-17" => "Satakunta",
+    "FI-18" => "Uusimaa",
+    "FI-19" => "Zuidwest-Finland",
+    "FR-01" => "Ain",
+    "FR-02" => "Aisne",
+    "FR-03" => "Allier",
+    "FR-04" => "Alpen van Hoog-Provence",
+    "FR-05" => "Hoog-Alpen",
+    "FR-06" => "Zee-Alpen",
+    "FR-07" => "Ardèche",
+    "FR-08" => "Ardennen",
+    "FR-09" => "Ariège",
+    "FR-10" => "Aube",
+    "FR-11" => "Aude",
+    "FR-12" => "Aveyron",
+    "FR-13" => "Oevers van de Rhône",
+    "FR-14" => "Calvados",
+    "FR-15" => "Cantal",
+    "FR-16" => "Charente",
+    "FR-17" => "Zee-Charente",
+    "FR-18" => "Cher",
+    "FR-19" => "Corrèze",
+    "FR-20R" => "Corsica",
+    "FR-21" => "Goudkust",
+    "FR-22" => "Kusten van Armor",
+    "FR-23" => "Creuse",
+    "FR-24" => "Dordogne",
+    "FR-25" => "Doubs",
+    "FR-26" => "Drôme",
+    "FR-27" => "Eure",
+    "FR-28" => "Eure en Loir",
+    "FR-29" => "Finistère",
+    "FR-2A" => "Zuid-Corsica",
+    "FR-2B" => "Hoog-Corsica",
+    "FR-30" => "Gard",
+    "FR-31" => "Hoog-Garonne",
+    "FR-32" => "Gers",
+    "FR-33" => "Gironde",
+    "FR-34" => "Hérault",
+    "FR-35" => "Ille en Vilaine",
+    "FR-36" => "Indre",
+    "FR-37" => "Indre en Loire",
+    "FR-38" => "Isère",
+    "FR-39" => "Jura",
+    "FR-40" => "Landes",
+    "FR-41" => "Loir en Cher",
+    "FR-42" => "Loire",
+    "FR-43" => "Hoog-Loire",
+    "FR-44" => "Loire-Atlantisch",
+    "FR-45" => "Loiret",
+    "FR-46" => "Lot",
+    "FR-47" => "Lot en Garonne",
+    "FR-48" => "Lozère",
+    "FR-49" => "Maine en Loire",
+    "FR-50" => "Manche",
+    "FR-51" => "Marne",
+    "FR-52" => "Haute-Marne",
+    "FR-53" => "Mayenne",
+    "FR-54" => "Meurthe en Moezel",
+    "FR-55" => "Meuse",
+    "FR-56" => "Morbihan",
+    "FR-57" => "Moezel",
+    "FR-58" => "Nièvre",
+    "FR-59" => "Noord",
+    "FR-60" => "Oise",
+    "FR-61" => "Orne",
+    "FR-62" => "Nauw van Calais",
+    "FR-63" => "Puy-de-Dôme",
+    "FR-64" => "Pyreneeën-Atlantisch",
+    "FR-65" => "Hoog-Pyreneeën",
+    "FR-66" => "Oost-Pyreneeën",
+    "FR-67" => "Laag-Rijn",
+    "FR-68" => "Hoog-Rijn",
+    "FR-69" => "Rhône",
+    "FR-70" => "Hoog-Saône",
+    "FR-71" => "Saône en Loire",
+    "FR-72" => "Sarthe",
+    "FR-73" => "Savoie",
+    "FR-74" => "Hoog-Savoie",
+    "FR-76" => "Zee-Seine",
+    "FR-77" => "Seine en Marne",
+    "FR-78" => "Yvelines",
+    "FR-79" => "Deux-Sèvres",
+    "FR-80" => "Somme",
+    "FR-81" => "Tarn",
+    "FR-82" => "Tarn en Garonne",
+    "FR-83" => "Var",
+    "FR-84" => "Vaucluse",
+    "FR-85" => "Vendée",
+    "FR-86" => "Vienne",
+    "FR-87" => "Hoog-Vienne",
+    "FR-88" => "Vogezen",
+    "FR-89" => "Yonne",
+    "FR-90" => "Belfort, Territorium van",
+    "FR-91" => "Essonne",
+    "FR-92" => "Hoog-Seine",
+    "FR-93" => "Seine-Saint-Denis",
+    "FR-94" => "Marne Vallei",
+    "FR-95" => "Val-d'Oise",
+    "FR-971" => "Guadeloupe",
+    "FR-972" => "Martinique",
+    "FR-973" => "Frans-Guyane",
+    "FR-974" => "La Réunion",
+    "FR-976" => "Mayotte",
+    "FR-ARA" => "Auvergne-Rhône-Alpes",
+    "FR-BFC" => "Bourgogne-Franche-Comté",
+    "FR-BL" => "Saint-Barthélemy",
+    "FR-BRE" => "Bretagne",
+    "FR-CP" => "Clipperton",
+    "FR-CVL" => "Centre-Val de Loire",
+    "FR-GES" => "Grand-Est",
+    "FR-HDF" => "Hauts-de-France",
+    "FR-IDF" => "Île-de-France",
+    "FR-MF" => "Sint Maarten",
+    "FR-NAQ" => "Nouvelle-Aquitaine",
+    "FR-NC" => "Nieuw-Caledonië",
+    "FR-NOR" => "Normandië",
+    "FR-OCC" => "Occitanie",
+    "FR-PAC" => "Provence-Alpes-Côte-d'Azur",
+    "FR-PDL" => "Pays-de-la-Loire",
+    "FR-PF" => "Frans-Polynesië",
+    "FR-PM" => "Saint-Pierre-et-Miquelon",
+    "FR-TF" => "Franse Zuidelijke Gebieden",
+    "FR-WF" => "Wallis en Futuna",
+    "GA-1" => "Estuaire",
+    "GA-2" => "Haut-Ogooué",
+    "GA-3" => "Moyen-Ogooué",
+    "GA-4" => "Ngounié",
+    "GA-5" => "Nyanga",
+    "GA-6" => "Ogooué-Ivindo",
+    "GA-7" => "Ogooué-Lolo",
+    "GA-8" => "Ogooué-Maritime",
+    "GA-9" => "Woleu-Ntem",
+    "GM-B" => "Banjul",
+    "GM-L" => "Lower River",
+    "GM-M" => "Central River",
+    "GM-N" => "North Bank",
+    "GM-U" => "Upper River",
+    "GM-W" => "Western",
+    "GE-AB" => "Abchazië",
+    "GE-AJ" => "Adzjarië",
+    "GE-GU" => "Guria",
+    "GE-IM" => "Imereti",
+    "GE-KA" => "Kakheti",
+    "GE-KK" => "Kvemo Kartli",
+    "GE-MM" => "Mtscheta-Mtianeti",
+    "GE-RL" => "Ratsja-Letsjchoemi en Kvemo Svaneti",
+    "GE-SJ" => "Samtsche-Dzjavacheti",
+    "GE-SK" => "Sjida Kartli",
+    "GE-SZ" => "Samegrelo-Zemo Svaneti",
+    "GE-TB" => "Tbilisi",
+    "DE-BB" => "Brandenburg",
+    "DE-BE" => "Berlijn",
+    "DE-BW" => "Baden-Württemberg",
+    "DE-BY" => "Beieren",
+    "DE-HB" => "Bremen",
+    "DE-HE" => "Hessen",
+    "DE-HH" => "Hamburg",
+    "DE-MV" => "Mecklenburg-Vorpommern",
+    "DE-NI" => "Nedersaksen",
+    "DE-NW" => "Nordrhein-Westfalen",
+    "DE-RP" => "Rheinland-Pfalz",
+    "DE-SH" => "Schleswig-Holstein",
+    "DE-SL" => "Saarland",
+    "DE-SN" => "Saksen",
+    "DE-ST" => "Sachsen-Anhalt",
+    "DE-TH" => "Thüringen",
+    "GH-AA" => "Groot-Accra",
+    "GH-AF" => "Ahafo",
+    "GH-AH" => "Ashanti",
+    "GH-BE" => "Bono Oost",
+    "GH-BO" => "Bono",
+    "GH-CP" => "Central",
+    "GH-EP" => "Oost",
+    "GH-NE" => "Noord-Oost",
+    "GH-NP" => "Northern",
+    "GH-OT" => "Oti",
+    "GH-SV" => "Savannah",
+    "GH-TV" => "Volta",
+    "GH-UE" => "Upper East",
+    "GH-UW" => "Upper West",
+    "GH-WN" => "Western North",
+    "GH-WP" => "Western",
+    "GR-69" => "Oros Athos",
+    "GR-A" => "Oost-Macedonië en Thracië",
+    "GR-B" => "Centraal-Macedonië",
+    "GR-C" => "West-Macedonië",
+    "GR-D" => "Epirus",
+    "GR-E" => "Thessalië",
+    "GR-F" => "Ionische Eilanden",
+    "GR-G" => "West-Griekenland",
+    "GR-H" => "Centraal-Griekenland",
+    "GR-I" => "Attica",
+    "GR-J" => "Peloponnesos",
+    "GR-K" => "Noord-Egeïsche Eilanden",
+    "GR-L" => "Zuid-Egeïsche Eilanden",
+    "GR-M" => "Kreta",
+    "GL-AV" => "Gemeente Qeqqata",
+    "GL-KU" => "Gemeente Kujalleq",
+    "GL-QE" => "Gemeente Qeqqata",
+    "GL-QT" => "Gemeente Qeqertalik",
+    "GL-SM" => "Gemeente Sermersooq",
+    "GD-01" => "Saint Andrew",
+    "GD-02" => "Saint David",
+    "GD-03" => "Saint George",
+    "GD-04" => "Saint John",
+    "GD-05" => "Saint Mark",
+    "GD-06" => "Saint Patrick",
+    "GD-10" => "Southern Grenadine Islands",
+    "GN-B" => "Boké",
+    "GN-BE" => "Beyla",
+    "GN-BF" => "Boffa",
+    "GN-BK" => "Boké",
+    "GN-C" => "Conakry",
+    "GN-CO" => "Coyah",
+    "GN-D" => "Kindia",
+    "GN-DB" => "Dabola",
+    "GN-DI" => "Dinguiraye",
+    "GN-DL" => "Dalaba",
+    "GN-DU" => "Dubréka",
+    "GN-F" => "Faranah",
+    "GN-FA" => "Faranah",
+    "GN-FO" => "Forécariah",
+    "GN-FR" => "Fria",
+    "GN-GA" => "Gaoual",
+    "GN-GU" => "Guékédou",
+    "GN-K" => "Kankan",
+    "GN-KA" => "Kankan",
+    "GN-KB" => "Koubia",
+    "GN-KD" => "Kindia",
+    "GN-KE" => "Kérouané",
+    "GN-KN" => "Koundara",
+    "GN-KO" => "Kouroussa",
+    "GN-KS" => "Kissidougou",
+    "GN-L" => "Labé",
+    "GN-LA" => "Labé",
+    "GN-LE" => "Lélouma",
+    "GN-LO" => "Lola",
+    "GN-M" => "Mamou",
+    "GN-MC" => "Macenta",
+    "GN-MD" => "Mandiana",
+    "GN-ML" => "Mali",
+    "GN-MM" => "Mamou",
+    "GN-N" => "Nzérékoré",
+    "GN-NZ" => "Nzérékoré",
+    "GN-PI" => "Pita",
+    "GN-SI" => "Siguiri",
+    "GN-TE" => "Télimélé",
+    "GN-TO" => "Tougué",
+    "GN-YO" => "Yomou",
+    "GW-BA" => "Bafatá",
+    "GW-BL" => "Boloma; Bissagoseilanden",
+    "GW-BM" => "Biombo",
+    "GW-BS" => "Bissau",
+    "GW-CA" => "Cacheu",
+    "GW-GA" => "Gabú",
+    "GW-L" => "West",
+    "GW-N" => "Noord",
+    "GW-OI" => "Oio",
+    "GW-QU" => "Quinara",
+    "GW-S" => "Zuid",
+    "GW-TO" => "Tombali",
+    "GY-BA" => "Barima-Waini",
+    "GY-CU" => "Cuyuni-Mazaruniazaruni",
+    "GY-DE" => "Demerara-Mahaica",
+    "GY-EB" => "Oost-Berbice-Corantijn",
+    "GY-ES" => "Essequibo-eilanden-West-Demerara",
+    "GY-MA" => "Mahaica-Berbice",
+    "GY-PM" => "Pomeroon-Supenaam",
+    "GY-PT" => "Potaro-Siparuni",
+    "GY-UD" => "Boven-Demerara-Berbice",
+    "GY-UT" => "Boven-Takutu-Boven-Essequibo",
+    "HT-AR" => "Artibonite",
+    "HT-CE" => "Centre",
+    "HT-GA" => "Grand' Anse",
+    "HT-ND" => "Noord",
+    "HT-NE" => "Nord-Est",
+    "HT-NI" => "Nippes",
+    "HT-NO" => "Nord-Ouest",
+    "HT-OU" => "Ouest",
+    "HT-SD" => "Sud",
+    "HT-SE" => "Sud-Est",
+    "HN-AT" => "Atlántida",
+    "HN-CH" => "Choluteca",
+    "HN-CL" => "Colón",
+    "HN-CM" => "Comayagua",
+    "HN-CP" => "Copán",
+    "HN-CR" => "Cortés",
+    "HN-EP" => "El Paraíso",
+    "HN-FM" => "Francisco Morazán",
+    "HN-GD" => "Gracias a Dios",
+    "HN-IB" => "Islas de la Bahía",
+    "HN-IN" => "Intibucá",
+    "HN-LE" => "Hondurese lempira",
+    "HN-LP" => "La Paz",
+    "HN-OC" => "Ocotepeque",
+    "HN-OL" => "Olancho",
+    "HN-SB" => "Santa Bárbara",
+    "HN-VA" => "Valle",
+    "HN-YO" => "Yoro",
+    "HU-BA" => "Baranya",
+    "HU-BC" => "Békéscsaba",
+    "HU-BE" => "Békés",
+    "HU-BK" => "Bács-Kiskun",
+    "HU-BU" => "Budapest",
+    "HU-BZ" => "Borsod-Abaúj-Zemplén",
+    "HU-CS" => "Csongrád",
+    "HU-DE" => "Debrecen",
+    "HU-DU" => "Dunaújváros",
+    "HU-EG" => "Eger",
+    "HU-ER" => "Érd",
+    "HU-FE" => "Fejér",
+    "HU-GS" => "Győr-Moson-Sopron",
+    "HU-GY" => "Győr",
+    "HU-HB" => "Hajdú-Bihar",
+    "HU-HE" => "Heves",
+    "HU-HV" => "Hódmezővásárhely",
+    "HU-JN" => "Jász-Nagykun-Szolnok",
+    "HU-KE" => "Komárom-Esztergom",
+    "HU-KM" => "Kecskemét",
+    "HU-KV" => "Kaposvár",
+    "HU-MI" => "Miskolc",
+    "HU-NK" => "Nagykanizsa",
+    "HU-NO" => "Nógrád",
+    "HU-NY" => "Nyíregyháza",
+    "HU-PE" => "Pest",
+    "HU-PS" => "Pécs",
+    "HU-SD" => "Szeged",
+    "HU-SF" => "Székesfehérvár",
+    "HU-SH" => "Szombathely",
+    "HU-SK" => "Szolnok",
+    "HU-SN" => "Sopron",
+    "HU-SO" => "Somogy",
+    "HU-SS" => "Szekszárd",
+    "HU-ST" => "Salgótarján",
+    "HU-SZ" => "Szabolcs-Szatmár-Bereg",
+    "HU-TB" => "Tatabánya",
+    "HU-TO" => "Tolna",
+    "HU-VA" => "Vas",
+    "HU-VE" => "Veszprém",
+    "HU-VM" => "Veszprém",
+    "HU-ZA" => "Zala",
+    "HU-ZE" => "Zalaegerszeg",
+    "IS-1" => "Hoofdstedelijke Regio",
+    "IS-2" => "Zuidelijk schiereiland",
+    "IS-3" => "Westland",
+    "IS-4" => "Westfjorden",
+    "IS-5" => "Noordland west",
+    "IS-6" => "Noordland oost",
+    "IS-7" => "Oostland",
+    "IS-8" => "Zuidland",
+    "IS-AKN" => "Akranes",
+    "IS-AKU" => "Akureyri",
+    "IS-ARN" => "Árneshreppur",
+    "IS-ASA" => "Ásahreppur",
+    "IS-BLA" => "Bláskógabyggð",
+    "IS-BOG" => "Borgarbyggð",
+    "IS-BOL" => "Bolungarvík",
+    "IS-DAB" => "Dalabyggð",
+    "IS-DAV" => "Dalvíkurbyggð",
+    "IS-EOM" => "Eyja- og Miklaholtshreppur",
+    "IS-EYF" => "Eyjafjarðarsveit",
+    "IS-FJD" => "Fjarðabyggð",
+    "IS-FJL" => "Fjallabyggð",
+    "IS-FLA" => "Flóahreppur",
+    "IS-FLR" => "Fljótsdalshreppur",
+    "IS-GAR" => "Garðabær",
+    "IS-GOG" => "Grímsnes- og Grafningshreppur",
+    "IS-GRN" => "Grindavík",
+    "IS-GRU" => "Grundarfjörður",
+    "IS-GRY" => "Grýtubakkahreppur",
+    "IS-HAF" => "Hafnarfjörður",
+    "IS-HRG" => "Hörgársveit",
+    "IS-HRU" => "Hrunamannahreppur",
+    "IS-HUV" => "Húnaþing vestra",
+    "IS-HVA" => "Hvalfjarðarsveit",
+    "IS-HVE" => "Hveragerðisbær",
+    "IS-ISA" => "Ísafjarðarbær",
+    "IS-KAL" => "Kaldrananeshreppur",
+    "IS-KJO" => "Kjósarhreppur",
+    "IS-KOP" => "Kópavogur",
+    "IS-LAN" => "Langanesbyggð",
+    "IS-MOS" => "Mosfellsbær",
+    "IS-MYR" => "Mýrdalshreppur",
+    "IS-NOR" => "Norðurþing",
+    "IS-RGE" => "Rangárþing eystra",
+    "IS-RGY" => "Rangárþing ytra",
+    "IS-RHH" => "Reykhólahreppur",
+    "IS-RKN" => "Reykjanesbær",
+    "IS-RKV" => "Reykjavik",
+    "IS-SBT" => "Svalbarðsstrandarhreppur",
+    "IS-SDN" => "Suðurnesjabær",
+    "IS-SDV" => "Súðavíkurhreppur",
+    "IS-SEL" => "Seltjarnarnesbær",
+    "IS-SFA" => "Árborg",
+    "IS-SHF" => "Hornafjörður",
+    "IS-SKF" => "Skaftárhreppur",
+    "IS-SKG" => "Skagabyggð",
+    "IS-SKO" => "Skorradalshreppur",
+    "IS-SNF" => "Snæfellsbær",
+    "IS-SOG" => "Skeiða- og Gnúpverjahreppur",
+    "IS-SOL" => "Ölfus",
+    "IS-SSS" => "Skagaströnd",
+    "IS-STR" => "Strandabyggð",
+    "IS-STY" => "Stykkishólmsbær",
+    "IS-SVG" => "Sveitarfélagið Vogar",
+    "IS-TAL" => "Tálknafjarðarhreppur",
+    "IS-THG" => "Þingeyjarsveit",
+    "IS-TJO" => "Tjörneshreppur",
+    "IS-VEM" => "Vestmannaeyjabær",
+    "IS-VER" => "Vesturbyggð",
+    "IS-VOP" => "Vopnafjarðarhreppur",
+    "IN-AN" => "Andaman- en Nicobar-eilanden",
+    "IN-AP" => "Andhra Pradesh",
+    "IN-AR" => "Arunachal Pradesh",
+    "IN-AS" => "Assam",
+    "IN-BR" => "Bihar",
+    "IN-CH" => "Chandigarh",
+    "IN-DH" => "Dadra en Nagar Haveli en Daman en Diu",
+    "IN-DL" => "Delhi",
+    "IN-GA" => "Goa",
+    "IN-GJ" => "Gujarat",
+    "IN-HP" => "Himachal Pradesh",
+    "IN-HR" => "Haryana",
+    "IN-JH" => "Jharkhand",
+    "IN-JK" => "Jammu en Kasjmir",
+    "IN-KA" => "Karnataka",
+    "IN-KL" => "Kerala",
+    "IN-LA" => "Ladakh",
+    "IN-LD" => "Lakshadweep",
+    "IN-MH" => "Maharashtra",
+    "IN-ML" => "Meghalaya",
+    "IN-MN" => "Manipur",
+    "IN-MP" => "Madhya Pradesh",
+    "IN-MZ" => "Mizoram",
+    "IN-NL" => "Nagaland",
+    "IN-PB" => "Punjab",
+    "IN-PY" => "Puducherry",
+    "IN-RJ" => "Rajasthan",
+    "IN-SK" => "Sikkim",
+    "IN-TN" => "Tamil Nadu",
+    "IN-TR" => "Tripura",
+    "IN-UP" => "Uttar Pradesh",
+    "IN-WB" => "West-Bengalen",
+    "ID-AC" => "Aceh",
+    "ID-BA" => "Bali",
+    "ID-BB" => "Bangka-Belitung",
+    "ID-BE" => "Bengkulu",
+    "ID-BT" => "Banten",
+    "ID-GO" => "Gorontalo",
+    "ID-JA" => "Jambi",
+    "ID-JB" => "West-Java",
+    "ID-JI" => "Oost-Java",
+    "ID-JK" => "Groot-Jakarta",
+    "ID-JT" => "Midden-Java",
+    "ID-JW" => "Java",
+    "ID-KA" => "Kalimantan",
+    "ID-KB" => "West-Kalimantan",
+    "ID-KI" => "Oost-Kalimantan",
+    "ID-KR" => "Riau-eilanden",
+    "ID-KS" => "Zuid-Kalimantan",
+    "ID-KT" => "Centraal-Kalimantan",
+    "ID-KU" => "Noord-Kalimantan",
+    "ID-LA" => "Lampung",
+    "ID-MA" => "Molukken",
+    "ID-ML" => "Molukken",
+    "ID-MU" => "Noord-Molukken",
+    "ID-NB" => "West-Nusa Tenggara",
+    "ID-NT" => "Oost-Nusa Tenggara",
+    "ID-NU" => "Nusa Tenggara",
+    "ID-PA" => "Papoea",
+    "ID-PB" => "West-Papua",
+    "ID-PP" => "Papoea",
+    "ID-RI" => "Riau",
+    "ID-SA" => "Noord-Sulawesi",
+    "ID-SB" => "West-Sumatra",
+    "ID-SG" => "Zuidoost-Sulawesi",
+    "ID-SL" => "Sulawesi",
+    "ID-SM" => "Sumatra",
+    "ID-SN" => "Zuid-Sulawesi",
+    "ID-SR" => "West-Sulawesi",
+    "ID-SS" => "Zuid-Sumatra",
+    "ID-ST" => "Centraal Sulawesi",
+    "ID-SU" => "Noord-Sumatra",
+    "ID-YO" => "Yogyakarta",
+    "IR-00" => "Markazi",
+    "IR-01" => "Gilan",
+    "IR-02" => "Mazandaran",
+    "IR-03" => "Oost-Azerbeidzjan",
+    "IR-04" => "West-Azerbeidzjan",
+    "IR-05" => "Kermanshah",
+    "IR-06" => "Khoezistan",
+    "IR-07" => "Fars",
+    "IR-08" => "Kerman",
+    "IR-09" => "Razavi-Khorasan",
+    "IR-10" => "Isfahan",
+    "IR-11" => "Sistan en Beloetsjistan",
+    "IR-12" => "Kordestan",
+    "IR-13" => "Hamadan",
+    "IR-14" => "Chahar Mahaal en Bakhtiari",
+    "IR-15" => "Lorestan",
+    "IR-16" => "Ilam",
+    "IR-17" => "Kohgiluyeh en Boyer Ahmad",
+    "IR-18" => "Bushehr",
+    "IR-19" => "Zanjan",
+    "IR-20" => "Semnan",
+    "IR-21" => "Yazd",
+    "IR-22" => "Hormosgan",
+    "IR-23" => "Teheran",
+    "IR-24" => "Ardebil",
+    "IR-25" => "Qom",
+    "IR-26" => "Qazvin",
+    "IR-27" => "Golestan",
+    "IR-28" => "Noord-Khorasan",
+    "IR-29" => "Zuid-Khorasan",
+    "IR-30" => "Elboers; Elburz; Alborz",
+    "IQ-AN" => "Al-Anbar",
+    "IQ-AR" => "Erbil",
+    "IQ-BA" => "Al-Basrah",
+    "IQ-BB" => "Babil",
+    "IQ-BG" => "Bagdad",
+    "IQ-DA" => "Duhok",
+    "IQ-DI" => "Diyala",
+    "IQ-DQ" => "Dhi Qar",
+    "IQ-KA" => "Karbala",
+    "IQ-KI" => "Kirkuk",
+    "IQ-MA" => "Maysan",
+    "IQ-MU" => "Al-Muthanna",
+    "IQ-NA" => "An-Najaf",
+    "IQ-NI" => "Ninawa",
+    "IQ-QA" => "Al-Qadisiyah",
+    "IQ-SD" => "Salah ad Din",
+    "IQ-SU" => "Suleimaniya",
+    "IQ-WA" => "Wasit",
+    "IE-C" => "Connacht",
+    "IE-CE" => "Clare",
+    "IE-CN" => "Cavan",
+    "IE-CO" => "Cork",
+    "IE-CW" => "Carlow",
+    "IE-D" => "Dublin",
+    "IE-DL" => "Donegal",
+    "IE-G" => "Galway",
+    "IE-KE" => "Kildare",
+    "IE-KK" => "Kilkenny",
+    "IE-KY" => "Kerry",
+    "IE-L" => "Leinster",
+    "IE-LD" => "Longford",
+    "IE-LH" => "Louth",
+    "IE-LK" => "Limerick",
+    "IE-LM" => "Leitrim",
+    "IE-LS" => "Laois",
+    "IE-M" => "Munster",
+    "IE-MH" => "Meath",
+    "IE-MN" => "Monaghan",
+    "IE-MO" => "Mayo",
+    "IE-OY" => "Offaly",
+    "IE-RN" => "Roscommon",
+    "IE-SO" => "Sligo",
+    "IE-TA" => "Tipperary",
+    "IE-U" => "Ulster",
+    "IE-WD" => "Waterford",
+    "IE-WH" => "Westmeath",
+    "IE-WW" => "Wicklow",
+    "IE-WX" => "Wexford",
+    "IL-D" => "Debub",
+    "IL-HA" => "Haifa",
+    "IL-JM" => "Al-Quds",
+    "IL-M" => "Maekel",
+    "IL-TA" => "Tel Aviv",
+    "IL-Z" => "Ash Shamālī",
+    "IT-21" => "Piëmont",
+    "IT-23" => "Aostadal",
+    "IT-25" => "Lombardije",
+    "IT-32" => "Trentino-Zuid-Tirol",
+    "IT-34" => "Veneto",
+    "IT-36" => "Friuli-Venezia Giulia",
+    "IT-42" => "Ligurië",
+    "IT-45" => "Emilia-Romagna",
+    "IT-52" => "Toscane",
+    "IT-55" => "Umbrië",
+    "IT-57" => "Marken",
+    "IT-62" => "Latium",
+    "IT-65" => "Abruzzen",
+    "IT-67" => "Molise",
+    "IT-72" => "Campanië",
+    "IT-75" => "Apulië",
+    "IT-77" => "Basilicata",
+    "IT-78" => "Calabrië",
+    "IT-82" => "Sicilië",
+    "IT-88" => "Sardinië",
+    "IT-AG" => "Agrigento",
+    "IT-AL" => "Alessandria",
+    "IT-AN" => "Ancona",
+    "IT-AP" => "Ascoli Piceno",
+    "IT-AQ" => "L'Aquila",
+    "IT-AR" => "Arezzo",
+    "IT-AT" => "Asti",
+    "IT-AV" => "Avellino",
+    "IT-BA" => "Bari",
+    "IT-BG" => "Bergamo",
+    "IT-BI" => "Biella",
+    "IT-BL" => "Belluno",
+    "IT-BN" => "Benevento",
+    "IT-BO" => "Bologna",
+    "IT-BR" => "Brindisi",
+    "IT-BS" => "Brescia",
+    "IT-BT" => "Barletta-Andria-Trani",
+    "IT-BZ" => "Bolzano",
+    "IT-CA" => "Cagliari",
+    "IT-CB" => "Campobasso",
+    "IT-CE" => "Caserta",
+    "IT-CH" => "Chieti",
+    "IT-CL" => "Caltanissetta",
+    "IT-CN" => "Cuneo",
+    "IT-CO" => "Como",
+    "IT-CR" => "Cremona",
+    "IT-CS" => "Cosenza",
+    "IT-CT" => "Catanië",
+    "IT-CZ" => "Catanzaro",
+    "IT-EN" => "Enna",
+    "IT-FC" => "Forlì-Cesena",
+    "IT-FE" => "Ferrara",
+    "IT-FG" => "Foggia",
+    "IT-FI" => "Florence",
+    "IT-FM" => "Fermo",
+    "IT-FR" => "Frosinone",
+    "IT-GE" => "Genua",
+    "IT-GO" => "Gorizia",
+    "IT-GR" => "Grosseto",
+    "IT-IM" => "Imperia",
+    "IT-IS" => "Isernia",
+    "IT-KR" => "Crotone",
+    "IT-LC" => "Lecco",
+    "IT-LE" => "Lecce",
+    "IT-LI" => "Livorno",
+    "IT-LO" => "Lodi",
+    "IT-LT" => "Latina",
+    "IT-LU" => "Lucca",
+    "IT-MB" => "Monza e Brianza",
+    "IT-MC" => "Macerata",
+    "IT-ME" => "Messina",
+    "IT-MI" => "Milaan",
+    "IT-MN" => "Mantova",
+    "IT-MO" => "Modena",
+    "IT-MS" => "Massa-Carrara",
+    "IT-MT" => "Matera",
+    "IT-NA" => "Napels",
+    "IT-NO" => "Novara",
+    "IT-NU" => "Nuoro",
+    "IT-OR" => "Oristano",
+    "IT-PA" => "Palermo",
+    "IT-PC" => "Piacenza",
+    "IT-PD" => "Padova",
+    "IT-PE" => "Pescara",
+    "IT-PG" => "Perugia",
+    "IT-PI" => "Pisa",
+    "IT-PN" => "Pordenone",
+    "IT-PO" => "Prato",
+    "IT-PR" => "Parma",
+    "IT-PT" => "Pistoia",
+    "IT-PU" => "Pesaro en Urbino",
+    "IT-PV" => "Pavia",
+    "IT-PZ" => "Potenza",
+    "IT-RA" => "Ravenna",
+    "IT-RC" => "Calabrië, Regio",
+    "IT-RE" => "Emilië, Regio",
+    "IT-RG" => "Ragusa",
+    "IT-RI" => "Rieti",
+    "IT-RM" => "Rome",
+    "IT-RN" => "Rimini",
+    "IT-RO" => "Rovigo",
+    "IT-SA" => "Salerno",
+    "IT-SI" => "Siena",
+    "IT-SO" => "Sondrio",
+    "IT-SP" => "La Spezia",
+    "IT-SR" => "Siracuse",
+    "IT-SS" => "Sassari",
+    "IT-SU" => "Zuid-Sardinië",
+    "IT-SV" => "Savona",
+    "IT-TA" => "Taranto",
+    "IT-TE" => "Teramo",
+    "IT-TN" => "Trento",
+    "IT-TO" => "Turijn",
+    "IT-TP" => "Trapani",
+    "IT-TR" => "Terni",
+    "IT-TS" => "Triëst",
+    "IT-TV" => "Treviso",
+    "IT-UD" => "Udine",
+    "IT-VA" => "Varese",
+    "IT-VB" => "Verbano-Cusio-Ossola",
+    "IT-VC" => "Vercelli",
+    "IT-VE" => "Venetië",
+    "IT-VI" => "Vicenza",
+    "IT-VR" => "Verona",
+    "IT-VT" => "Viterbo",
+    "IT-VV" => "Vibo Valentia",
+    "JM-01" => "Kingston",
+    "JM-02" => "Saint Andrew",
+    "JM-03" => "Saint Thomas",
+    "JM-04" => "Portland",
+    "JM-05" => "Saint Mary",
+    "JM-06" => "Saint Ann",
+    "JM-07" => "Trelawny",
+    "JM-08" => "Saint James",
+    "JM-09" => "Hanover",
+    "JM-10" => "Westmoreland",
+    "JM-11" => "Saint Elizabeth",
+    "JM-12" => "Manchester",
+    "JM-13" => "Clarendon",
+    "JM-14" => "Saint Catherine",
+    "JP-01" => "Hokkaido",
+    "JP-02" => "Aomori",
+    "JP-03" => "Iwate",
+    "JP-04" => "Miyagi",
+    "JP-05" => "Akita",
+    "JP-06" => "Yamagata",
+    "JP-07" => "Fukushima",
+    "JP-08" => "Ibaraki",
+    "JP-09" => "Tochigi",
+    "JP-10" => "Gunma",
+    "JP-11" => "Saitama",
+    "JP-12" => "Chiba",
+    "JP-13" => "Tokio; Tokyo",
+    "JP-14" => "Kanagawa",
+    "JP-15" => "Niigata",
+    "JP-16" => "Toyama",
+    "JP-17" => "Ishikawa",
+    "JP-18" => "Fukui",
+    "JP-19" => "Yamanashi",
+    "JP-20" => "Nagano",
+    "JP-21" => "Gifu",
+    "JP-22" => "Shizuoka",
+    "JP-23" => "Aichi",
+    "JP-24" => "Mie",
+    "JP-25" => "Shiga",
+    "JP-26" => "Kioto; Kyoto",
+    "JP-27" => "Osaka",
+    "JP-28" => "Hyogo",
+    "JP-29" => "Nara",
+    "JP-30" => "Wakayama",
+    "JP-31" => "Tottori",
+    "JP-32" => "Shimane",
+    "JP-33" => "Okayama",
+    "JP-34" => "Hiroshima",
+    "JP-35" => "Yamaguchi",
+    "JP-36" => "Tokushima",
+    "JP-37" => "Kagawa",
+    "JP-38" => "Ehime",
+    "JP-39" => "Kochi",
+    "JP-40" => "Fukuoka",
+    "JP-41" => "Saga",
+    "JP-42" => "Nagasaki",
+    "JP-43" => "Kumamoto",
+    "JP-44" => "Oita",
+    "JP-45" => "Miyazaki",
+    "JP-46" => "Kagoshima",
+    "JP-47" => "Okinawa",
+    "JO-AJ" => "Ajlun",
+    "JO-AM" => "Amman",
+    "JO-AQ" => "Akaba",
+    "JO-AT" => "Tafilah",
+    "JO-AZ" => "Zarka",
+    "JO-BA" => "Balka",
+    "JO-IR" => "Irbid",
+    "JO-JA" => "Jerash",
+    "JO-KA" => "Kerak",
+    "JO-MA" => "Mafrak",
+    "JO-MD" => "Madaba",
+    "JO-MN" => "Ma'an",
+    "KE-01" => "Baringo",
+    "KE-02" => "Bomet",
+    "KE-03" => "Bungoma",
+    "KE-04" => "Busia",
+    "KE-05" => "Elgeyo-Marakwet",
+    "KE-06" => "Embu",
+    "KE-07" => "Garissa",
+    "KE-08" => "Homa Bay",
+    "KE-09" => "Isiolo",
+    "KE-10" => "Kajiado",
+    "KE-11" => "Kakamega",
+    "KE-12" => "Kericho",
+    "KE-13" => "Kiambu",
+    "KE-14" => "Kilifi",
+    "KE-15" => "Kirinyaga",
+    "KE-16" => "Kisii",
+    "KE-17" => "Kisumu",
+    "KE-18" => "Kitui",
+    "KE-19" => "Kwale",
+    "KE-20" => "Laikipia",
+    "KE-21" => "Lamu",
+    "KE-22" => "Machakos",
+    "KE-23" => "Makueni",
+    "KE-24" => "Mandera",
+    "KE-25" => "Marsabit",
+    "KE-26" => "Meru",
+    "KE-27" => "Migori",
+    "KE-28" => "Mombasa",
+    "KE-29" => "Murang'a",
+    "KE-30" => "Nairobi County",
+    "KE-31" => "Nakuru",
+    "KE-32" => "Nandi",
+    "KE-33" => "Narok",
+    "KE-34" => "Nyamira",
+    "KE-35" => "Nyandarua",
+    "KE-36" => "Nyeri",
+    "KE-37" => "Samburu",
+    "KE-38" => "Siaya",
+    "KE-39" => "Taveta",
+    "KE-40" => "Tana River",
+    "KE-41" => "Tharaka-Nithi",
+    "KE-42" => "Trans-Nzoia",
+    "KE-43" => "Turkana",
+    "KE-44" => "Uasin Gishu",
+    "KE-45" => "Vihiga",
+    "KE-46" => "Wajir",
+    "KE-47" => "West-Pokot",
+    "KI-G" => "Gilbert-eilanden",
+    "KI-L" => "Line-eilanden",
+    "KI-P" => "Phoenix-eilanden",
+    "KP-01" => "Pyongyang",
+    "KP-02" => "P'yŏngan-namdo",
+    "KP-03" => "P'yŏngan-pukto",
+    "KP-04" => "Chagang-do",
+    "KP-05" => "Hwanghae-namdo",
+    "KP-06" => "Hwanghae-bukto",
+    "KP-07" => "Kangwŏn-do",
+    "KP-08" => "Hamgyŏng-namdo",
+    "KP-09" => "Hamgyŏng-pukto",
+    "KP-10" => "Ryanggang-do",
+    "KP-13" => "Rason",
+    "KP-14" => "Namp'o",
+    "KR-11" => "Seoul, Stadsgewest; Seoel, Stadsgewest",
+    "KR-26" => "Busan Metropolitan City",
+    "KR-27" => "Daegu Metropolitan City",
+    "KR-28" => "Incheon Metropolitan City",
+    "KR-29" => "Gwangju Metropolitan City",
+    "KR-30" => "Daejeon Metropolitan City",
+    "KR-31" => "Ulsan Metropolitan City",
+    "KR-41" => "Gyeonggi-do",
+    "KR-42" => "Gangwon-do",
+    "KR-43" => "Chungcheongbuk-do; Noord-Chungcheong",
+    "KR-44" => "Chungcheongnam-do; Zuid-Chungcheong",
+    "KR-45" => "Jeollabuk-do; Noord-Jeolla",
+    "KR-46" => "Jeollanam-do; Zuid-Jeolla",
+    "KR-47" => "Gyeongsangbuk-do; Noord-Gyeongsang",
+    "KR-48" => "Gyeongsangnam-do; Zuid-Gyeongsang",
+    "KR-49" => "Jeju-do; Jeju Eiland; Quelpaardseiland",
+    "KR-50" => "Sejong",
+    "KW-AH" => "Ahmadi",
+    "KW-FA" => "Al Farwanlyah",
+    "KW-HA" => "Hawalli",
+    "KW-JA" => "Jahra",
+    "KW-KU" => "Al-Asimah",
+    "KW-MU" => "Mubarak Al-Kabier",
+    "KG-B" => "Batken, Provincie",
+    "KG-C" => "Tsjoej, Provincie",
+    "KG-GB" => "Bisjkek",
+    "KG-GO" => "Oš (stad)",
+    "KG-J" => "Dzjalal-Abad, Provincie",
+    "KG-N" => "Naryn, Provincie",
+    "KG-O" => "Oš, Provincie",
+    "KG-T" => "Talas, Provincie",
+    "KG-Y" => "Ysykköl, Provincie",
+    "LA-AT" => "Attapeu",
+    "LA-BK" => "Bokeo",
+    "LA-BL" => "Bolikhamsai",
+    "LA-CH" => "Champassak",
+    "LA-HO" => "Hua Phan",
+    "LA-KH" => "Khammuan",
+    "LA-LM" => "Luang Namtha",
+    "LA-LP" => "Luang Prabang",
+    "LA-OU" => "Udomxai",
+    "LA-PH" => "Phongsali",
+    "LA-SL" => "Salavan",
+    "LA-SV" => "Savannakhet",
+    "LA-VI" => "Viangchan",
+    "LA-VT" => "Viangchan",
+    "LA-XA" => "Sainyabuli",
+    "LA-XE" => "Sekong",
+    "LA-XI" => "Xiangkhouang",
+    "LA-XS" => "Xaisômboun",
+    "LV-002" => "Aglona",
+    "LV-007" => "Alūksne",
+    "LV-011" => "Ādaži",
+    "LV-015" => "Balvi",
+    "LV-016" => "Bauska",
+    "LV-022" => "Cēsis",
+    "LV-026" => "Dobele",
+    "LV-033" => "Gulbene",
+    "LV-041" => "Jelgava",
+    "LV-042" => "Jēkabpils",
+    "LV-047" => "Krāslava",
+    "LV-050" => "Kuldīga",
+    "LV-052" => "Ķekava",
+    "LV-054" => "Limbaži",
+    "LV-056" => "Līvāni",
+    "LV-058" => "Ludza",
+    "LV-059" => "Madona",
+    "LV-062" => "Mārupe",
+    "LV-067" => "Ogre",
+    "LV-068" => "Olaine",
+    "LV-073" => "Preiļi",
+    "LV-077" => "Rēzekne",
+    "LV-080" => "Ropaži",
+    "LV-087" => "Salaspils",
+    "LV-088" => "Saldus",
+    "LV-089" => "Saulkrasti",
+    "LV-091" => "Sigulda",
+    "LV-094" => "Smiltene",
+    "LV-097" => "Talsi",
+    "LV-099" => "Tukums",
+    "LV-101" => "Valka",
+    "LV-102" => "Varakļāni",
+    "LV-106" => "Ventspils",
+    "LV-DGV" => "Daugavpils (stad)",
+    "LV-JEL" => "Jelgava (stad)",
+    "LV-JUR" => "Jūrmala",
+    "LV-LPX" => "Liepāja",
+    "LV-REZ" => "Rēzekne (stad)",
+    "LV-RIX" => "Riga",
+    "LV-VEN" => "Ventspils",
+    "LB-AK" => "Akkar",
+    "LB-AS" => "Noord-Libanon",
+    "LB-BA" => "Beiroet",
+    "LB-BH" => "Baalbek-Hermel",
+    "LB-BI" => "Bekavallei",
+    "LB-JA" => "Zuid-Libanon",
+    "LB-JL" => "Libanongebergte",
+    "LB-NA" => "Nabatiye",
+    "LS-A" => "Maseru",
+    "LS-B" => "Butha-Buthe",
+    "LS-C" => "Leribe",
+    "LS-D" => "Berea",
+    "LS-E" => "Mafeteng",
+    "LS-F" => "Mohale's Hoek",
+    "LS-G" => "Quthing",
+    "LS-H" => "Qacha's Nek",
+    "LS-J" => "Mokhotlong",
+    "LS-K" => "Thaba-Tseka",
+    "LR-BG" => "Bong",
+    "LR-BM" => "Bomi",
+    "LR-CM" => "Grand Cape Mount",
+    "LR-GB" => "Grand Bassa",
+    "LR-GG" => "Grand Gedeh",
+    "LR-GK" => "Grand Kru",
+    "LR-GP" => "Gbarpolu",
+    "LR-LO" => "Lofa",
+    "LR-MG" => "Margibi",
+    "LR-MO" => "Montserrado",
+    "LR-MY" => "Maryland",
+    "LR-NI" => "Nimba",
+    "LR-RG" => "River Gee",
+    "LR-RI" => "River Cess",
+    "LR-SI" => "Sinoe",
+    "LY-BA" => "Benghazi",
+    "LY-BU" => "Al Butnan",
+    "LY-DR" => "Darnah",
+    "LY-GT" => "Ghat",
+    "LY-JA" => "Al Jabal al Akhdar",
+    "LY-JG" => "Al Jabal al Gharbī",
+    "LY-JI" => "Al Jfara",
+    "LY-JU" => "Al Jufrah",
+    "LY-KF" => "Al Kufrah",
+    "LY-MB" => "Al Marqab",
+    "LY-MI" => "Misratah",
+    "LY-MJ" => "Al Marj",
+    "LY-MQ" => "Murzuq",
+    "LY-NL" => "Nalut",
+    "LY-NQ" => "An Nuqat al Khams",
+    "LY-SB" => "Sabha",
+    "LY-SR" => "Surt",
+    "LY-TB" => "Tarabulus",
+    "LY-WA" => "Al Wahat",
+    "LY-WD" => "Wadi al Hayat",
+    "LY-WS" => "Wādī ash Shāţi’",
+    "LY-ZA" => "Az Zawiyah",
+    "LI-01" => "Balzers",
+    "LI-02" => "Eschen",
+    "LI-03" => "Gamprin",
+    "LI-04" => "Mauren",
+    "LI-05" => "Planken",
+    "LI-06" => "Ruggell",
+    "LI-07" => "Schaan",
+    "LI-08" => "Schellenberg",
+    "LI-09" => "Triesen",
+    "LI-10" => "Triesenberg",
+    "LI-11" => "Vaduz",
+    "LT-01" => "Akmenė",
+    "LT-02" => "Alytaus miestas",
+    "LT-03" => "Alytus",
+    "LT-04" => "Anykščiai",
+    "LT-05" => "Birštonas",
+    "LT-06" => "Biržai",
+    "LT-07" => "Druskininkai",
+    "LT-08" => "Elektrėnai",
+    "LT-09" => "Ignalina",
+    "LT-10" => "Jonava",
+    "LT-11" => "Joniškis",
+    "LT-12" => "Jurbarkas",
+    "LT-13" => "Kaišiadorys",
+    "LT-14" => "Kalvarija",
+    "LT-15" => "Kauno miestas",
+    "LT-16" => "Kaunas",
+    "LT-17" => "Kazlų Rūda",
+    "LT-18" => "Kėdainiai",
+    "LT-19" => "Kelmė",
+    "LT-20" => "Klaipėda",
+    "LT-21" => "Klaipėda",
+    "LT-22" => "Kretinga",
+    "LT-23" => "Kupiškis",
+    "LT-24" => "Lazdijai",
+    "LT-25" => "Marijampolė",
+    "LT-26" => "Mažeikiai",
+    "LT-27" => "Molėtai",
+    "LT-28" => "Neringa",
+    "LT-29" => "Pagėgiai",
+    "LT-30" => "Pakruojis",
+    "LT-31" => "Palangos miestas",
+    "LT-32" => "Panevėžys",
+    "LT-33" => "Panevėžys",
+    "LT-34" => "Pasvalys",
+    "LT-35" => "Plungė",
+    "LT-36" => "Prienai",
+    "LT-37" => "Radviliškis",
+    "LT-38" => "Raseiniai",
+    "LT-39" => "Rietavo",
+    "LT-40" => "Rokiškis",
+    "LT-41" => "Šakiai",
+    "LT-42" => "Šalčininkai",
+    "LT-43" => "Šiauliai",
+    "LT-44" => "Šiauliai",
+    "LT-45" => "Šilalė",
+    "LT-46" => "Šilutė",
+    "LT-47" => "Širvintos",
+    "LT-48" => "Skuodas",
+    "LT-49" => "Švenčionys",
+    "LT-50" => "Tauragė",
+    "LT-51" => "Telšiai",
+    "LT-52" => "Trakai",
+    "LT-53" => "Ukmergė",
+    "LT-54" => "Utena",
+    "LT-55" => "Varėna",
+    "LT-56" => "Vilkaviškis",
+    "LT-57" => "Vilniaus miestas",
+    "LT-58" => "Vilnius",
+    "LT-59" => "Visaginas",
+    "LT-60" => "Zarasai",
+    "LT-AL" => "Alytus (district)",
+    "LT-KL" => "Klaipėda",
+    "LT-KU" => "Kaunas (district)",
+    "LT-MR" => "Marijampolė (district)",
+    "LT-PN" => "Panevėžys (district)",
+    "LT-SA" => "Šiauliai (district)",
+    "LT-TA" => "Tauragė",
+    "LT-TE" => "Telšiai (district)",
+    "LT-UT" => "Utena (district)",
+    "LT-VL" => "Vilnius (district)",
+    "LU-CA" => "Capellen",
+    "LU-CL" => "Clervaux",
+    "LU-DI" => "Diekirch",
+    "LU-EC" => "Echternach",
+    "LU-ES" => "Esch-sur-Alzette",
+    "LU-GR" => "Grevenmacher",
+    "LU-LU" => "Luxemburg",
+    "LU-ME" => "Mersch",
+    "LU-RD" => "Redange",
+    "LU-RM" => "Remich",
+    "LU-VD" => "Vianden",
+    "LU-WI" => "Wiltz",
+    "MG-A" => "Toamasina",
+    "MG-D" => "Antsiranana",
+    "MG-F" => "Fianarantsoa",
+    "MG-M" => "Mahajanga",
+    "MG-T" => "Antananarivo",
+    "MG-U" => "Toliara",
+    "MW-BA" => "Balaka",
+    "MW-BL" => "Blantyre",
+    "MW-C" => "Centrale Regio",
+    "MW-CK" => "Chikwawa",
+    "MW-CR" => "Chiradzulu",
+    "MW-CT" => "Chitipa",
+    "MW-DE" => "Dedza",
+    "MW-DO" => "Dowa",
+    "MW-KR" => "Karonga",
+    "MW-KS" => "Kasungu",
+    "MW-LI" => "Lilongwe",
+    "MW-LK" => "Likoma",
+    "MW-MC" => "Mchinji",
+    "MW-MG" => "Mangochi",
+    "MW-MH" => "Machinga",
+    "MW-MU" => "Mulanje",
+    "MW-MW" => "Mwanza",
+    "MW-MZ" => "Mzimba",
+    "MW-N" => "Noordelijke Regio",
+    "MW-NB" => "Nkhata Baai",
+    "MW-NE" => "Neno",
+    "MW-NI" => "Ntchisi",
+    "MW-NK" => "Nkhotakota",
+    "MW-NS" => "Nsanje",
+    "MW-NU" => "Ntcheu",
+    "MW-PH" => "Phalombe",
+    "MW-RU" => "Rumphi",
+    "MW-S" => "Zuidelijke Regio",
+    "MW-SA" => "Salima",
+    "MW-TH" => "Thyolo",
+    "MW-ZO" => "Zomba",
+    "MY-01" => "Johor",
+    "MY-02" => "Kedah",
+    "MY-03" => "Kelantan",
+    "MY-04" => "Malakka; Melaka",
+    "MY-05" => "Negeri Sembilan",
+    "MY-06" => "Pahang",
+    "MY-07" => "Penang; Pulau Pinang",
+    "MY-08" => "Perak",
+    "MY-09" => "Perlis",
+    "MY-10" => "Selangor",
+    "MY-11" => "Terengganu",
+    "MY-12" => "Sabah",
+    "MY-13" => "Sarawak",
+    "MY-14" => "Kuala Lumpur, Federaal Territorium",
+    "MY-15" => "Labuan, Federaal Territorium",
+    "MY-16" => "Putrajaya, Federaal Territorium",
+    "MV-00" => "Zuidelijke Ari-atol",
+    "MV-01" => "Addu-atol",
+    "MV-02" => "Noordelijke Ari-atol",
+    "MV-03" => "Faadhippolhu-atol",
+    "MV-04" => "Felidhu-atol",
+    "MV-05" => "Haddhunmathi-atol",
+    "MV-07" => "Noordelijke Thiladhunmathi-atol",
+    "MV-08" => "Kolhumadulu-atol",
+    "MV-12" => "Mulaku-atol",
+    "MV-13" => "Noordelijke Maalhosmadulhu-atol",
+    "MV-14" => "Noordelijke Nilandhé-atol",
+    "MV-17" => "Zuidelijke Nilandhé-atol",
+    "MV-20" => "Zuidelijke Maalhosmadulhu-atol",
+    "MV-23" => "Zuidelijke Thiladhunmathi-atol",
+    "MV-24" => "Noordelijke Miladhunmadulhu-atol",
+    "MV-25" => "Zuidelijke Miladhunmadulhu-atol",
+    "MV-26" => "Malé-atol",
+    "MV-27" => "Noordelijke Huvadhu-atol",
+    "MV-28" => "Zuidelijke Huvadhu-atol",
+    "MV-29" => "Fuvammulah",
+    "MV-MLE" => "Malé",
+    "ML-1" => "Kayes",
+    "ML-10" => "Taoudenni",
+    "ML-2" => "Koulikoro",
+    "ML-3" => "Sikasso",
+    "ML-4" => "Ségou",
+    "ML-5" => "Mopti",
+    "ML-6" => "Timboektoe",
+    "ML-7" => "Gao",
+    "ML-8" => "Kidal",
+    "ML-9" => "Ménaka",
+    "ML-BKO" => "Bamako",
+    "MT-01" => "Attard",
+    "MT-02" => "Balzan",
+    "MT-03" => "Birgu",
+    "MT-04" => "Birkirkara",
+    "MT-05" => "Birżebbuġa",
+    "MT-06" => "Bormla",
+    "MT-07" => "Dingli",
+    "MT-08" => "Fgura",
+    "MT-09" => "Floriana",
+    "MT-10" => "Fontana",
+    "MT-11" => "Gudja",
+    "MT-12" => "Gżira",
+    "MT-13" => "Għajnsielem",
+    "MT-14" => "Għarb",
+    "MT-15" => "Għargħur",
+    "MT-16" => "Għasri",
+    "MT-17" => "Għaxaq",
+    "MT-18" => "Ħamrun",
+    "MT-19" => "Iklin",
+    "MT-20" => "Isla",
+    "MT-21" => "Kalkara",
+    "MT-22" => "Kerċem",
+    "MT-23" => "Kirkop",
+    "MT-24" => "Lija",
+    "MT-25" => "Luqa",
+    "MT-26" => "Marsa",
+    "MT-27" => "Marsaskala",
+    "MT-28" => "Marsaxlokk",
+    "MT-29" => "Mdina",
+    "MT-30" => "Mellieħa",
+    "MT-31" => "Mġarr",
+    "MT-32" => "Mosta",
+    "MT-33" => "Mqabba",
+    "MT-34" => "Msida",
+    "MT-35" => "Mtarfa",
+    "MT-36" => "Munxar",
+    "MT-37" => "Nadur",
+    "MT-38" => "Naxxar",
+    "MT-39" => "Paola",
+    "MT-40" => "Pembroke",
+    "MT-41" => "Pietà",
+    "MT-42" => "Qala",
+    "MT-43" => "Qormi",
+    "MT-44" => "Qrendi",
+    "MT-45" => "Victoria",
+    "MT-46" => "Rabat Malta",
+    "MT-47" => "Safi",
+    "MT-48" => "San Ġiljan",
+    "MT-49" => "Saint John",
+    "MT-50" => "San Lawrenz",
+    "MT-51" => "San Pawl il-Baħar",
+    "MT-52" => "Sannat",
+    "MT-53" => "Santa Luċija",
+    "MT-54" => "Santa Venera",
+    "MT-55" => "Siġġiewi",
+    "MT-56" => "Sliema",
+    "MT-57" => "Swieqi",
+    "MT-58" => "Ta' Xbiex",
+    "MT-59" => "Tarxien",
+    "MT-60" => "Valletta",
+    "MT-61" => "Xagħra",
+    "MT-62" => "Xewkija",
+    "MT-63" => "Xgħajra",
+    "MT-64" => "Żabbar",
+    "MT-65" => "Żebbuġ",
+    "MT-66" => "Żebbuġ Malta",
+    "MT-67" => "Żejtun",
+    "MT-68" => "Żurrieq",
+    "MH-ALK" => "Ailuk",
+    "MH-ALL" => "Ailinglaplap",
+    "MH-ARN" => "Arno",
+    "MH-AUR" => "Aur",
+    "MH-EBO" => "Ebon",
+    "MH-ENI" => "Eniwetok en Ujelang",
+    "MH-JAB" => "Jabat",
+    "MH-JAL" => "Jaluit",
+    "MH-KIL" => "Bikini en Kili",
+    "MH-KWA" => "Kwajalein",
+    "MH-L" => "Ralik-keten",
+    "MH-LAE" => "Lae",
+    "MH-LIB" => "Lib",
+    "MH-LIK" => "Likiep",
+    "MH-MAJ" => "Majuro",
+    "MH-MAL" => "Maloelap",
+    "MH-MEJ" => "Mejit",
+    "MH-MIL" => "Mili",
+    "MH-NMK" => "Namorik",
+    "MH-NMU" => "Namu",
+    "MH-RON" => "Rongelap",
+    "MH-T" => "Ratak-keten",
+    "MH-UJA" => "Ujae",
+    "MH-UTI" => "Utirik",
+    "MH-WTH" => "Wotho",
+    "MH-WTJ" => "Wotje",
+    "MR-01" => "Hodh ech Chargui",
+    "MR-02" => "Hodh El Gharbi",
+    "MR-03" => "Assaba",
+    "MR-04" => "Gorgol",
+    "MR-05" => "Brakna",
+    "MR-06" => "Trarza",
+    "MR-07" => "Adrar",
+    "MR-08" => "Dakhlet Nouadhibou",
+    "MR-09" => "Tagant",
+    "MR-10" => "Guidimaka",
+    "MR-11" => "Tiris Zemmour",
+    "MR-12" => "Inchiri",
+    "MR-13" => "Nouakchott Ouest",
+    "MR-14" => "Nouakchott Nord",
+    "MR-15" => "Nouakchott Sud",
+    "MU-AG" => "Agalega-eilanden",
+    "MU-BL" => "Black River",
+    "MU-CC" => "Cargados Carajos Shoals",
+    "MU-FL" => "Flacq",
+    "MU-GP" => "Grand Port",
+    "MU-MO" => "Moka",
+    "MU-PA" => "Pamplemousses",
+    "MU-PL" => "Port Louis",
+    "MU-PW" => "Plaines Wilhems",
+    "MU-RO" => "Rodrigues-eiland",
+    "MU-RR" => "Rempart-rivier",
+    "MU-SA" => "Savanne",
+    "MX-AGU" => "Aguascalientes",
+    "MX-BCN" => "Baja California; Neder-Californië",
+    "MX-BCS" => "Baja California Sur; Zuid-Neder-Californië",
+    "MX-CAM" => "Campeche",
+    "MX-CHH" => "Chihuahua",
+    "MX-CHP" => "Chiapas",
+    "MX-CMX" => "Mexico-Stad",
+    "MX-COA" => "Coahuila de Zaragoza",
+    "MX-COL" => "Colima",
+    "MX-DUR" => "Durango",
+    "MX-GRO" => "Guerrero",
+    "MX-GUA" => "Guanajuato",
+    "MX-HID" => "Hidalgo",
+    "MX-JAL" => "Jalisco",
+    "MX-MEX" => "Mexico",
+    "MX-MIC" => "Michoacán de Ocampo",
+    "MX-MOR" => "Morelos",
+    "MX-NAY" => "Nayarit",
+    "MX-NLE" => "Nuevo León",
+    "MX-OAX" => "Oaxaca",
+    "MX-PUE" => "Puebla",
+    "MX-QUE" => "Querétaro",
+    "MX-ROO" => "Quintana Roo",
+    "MX-SIN" => "Sinaloa",
+    "MX-SLP" => "San Luis Potosí",
+    "MX-SON" => "Sonora",
+    "MX-TAB" => "Tabasco",
+    "MX-TAM" => "Tamaulipas",
+    "MX-TLA" => "Tlaxcala",
+    "MX-VER" => "Veracruz de Ignacio de la Llave",
+    "MX-YUC" => "Yucatán",
+    "MX-ZAC" => "Zacatecas",
+    "FM-KSA" => "Kosrae",
+    "FM-PNI" => "Pohnpei",
+    "FM-TRK" => "Chuuk",
+    "FM-YAP" => "Yap",
+    "MD-AN" => "Anenii Noi",
+    "MD-BA" => "Bălți",
+    "MD-BD" => "Bender",
+    "MD-BR" => "Briceni",
+    "MD-BS" => "Basarabeasca",
+    "MD-CA" => "Cahul",
+    "MD-CL" => "Călărași",
+    "MD-CM" => "Cimișlia",
+    "MD-CR" => "Criuleni",
+    "MD-CS" => "Căușeni",
+    "MD-CT" => "Cantemir",
+    "MD-CU" => "Chișinău",
+    "MD-DO" => "Dondușeni",
+    "MD-DR" => "Drochia",
+    "MD-DU" => "Dubăsari",
+    "MD-ED" => "Edineț",
+    "MD-FA" => "Fălești",
+    "MD-FL" => "Florești",
+    "MD-GA" => "Gagaoezië, Autonome Territoriale Eenheid; UTAG",
+    "MD-GL" => "Glodeni",
+    "MD-HI" => "Hîncești",
+    "MD-IA" => "Ialoveni",
+    "MD-LE" => "Leova",
+    "MD-NI" => "Nisporeni",
+    "MD-OC" => "Ocnița",
+    "MD-OR" => "Orhei",
+    "MD-RE" => "Rezina",
+    "MD-RI" => "Rîșcani",
+    "MD-SD" => "Șoldănești",
+    "MD-SI" => "Sîngerei",
+    "MD-SN" => "Transnistrië, Territoriale Eenheid",
+    "MD-SO" => "Soroca",
+    "MD-ST" => "Strășeni",
+    "MD-SV" => "Ștefan Vodă",
+    "MD-TA" => "Taraclia",
+    "MD-TE" => "Telenești",
+    "MD-UN" => "Ungheni",
+    "MC-CL" => "La Colle",
+    "MC-CO" => "La Condamine",
+    "MC-FO" => "Fontvieille",
+    "MC-GA" => "La Gare",
+    "MC-JE" => "Jardin Exotique",
+    "MC-LA" => "Larvotto",
+    "MC-MA" => "Malbousquet",
+    "MC-MC" => "Monte-Carlo",
+    "MC-MG" => "Moneghetti",
+    "MC-MO" => "Monaco-Stad",
+    "MC-MU" => "Moulins",
+    "MC-PH" => "Port-Hercule",
+    "MC-SD" => "Sainte-Dévote",
+    "MC-SO" => "La Source",
+    "MC-SP" => "Spélugues",
+    "MC-SR" => "Saint-Roman",
+    "MC-VR" => "Vallon de la Rousse",
+    "MN-035" => "Orhon",
+    "MN-037" => "Darhan uul",
+    "MN-039" => "Hentiy",
+    "MN-041" => "Hövsgöl",
+    "MN-043" => "Hovd",
+    "MN-046" => "Uvs",
+    "MN-047" => "Töv",
+    "MN-049" => "Selenge",
+    "MN-051" => "Sühbaatar",
+    "MN-053" => "Ömnögovĭ",
+    "MN-055" => "Övörhangaj",
+    "MN-057" => "Dzavhan",
+    "MN-059" => "Dundgovĭ",
+    "MN-061" => "Dornod",
+    "MN-063" => "Dornogovĭ",
+    "MN-064" => "Govĭsümber",
+    "MN-065" => "Govĭ-Altaj",
+    "MN-067" => "Bulgan",
+    "MN-069" => "Bayanhongor",
+    "MN-071" => "Bayan-Ölgi",
+    "MN-073" => "Arhangay",
+    "MN-1" => "Ulaanbaatar",
+    "ME-01" => "Andrijavica",
+    "ME-02" => "Bar",
+    "ME-03" => "Berane",
+    "ME-04" => "Bijelo Polje",
+    "ME-05" => "Budva",
+    "ME-06" => "Cetinje",
+    "ME-07" => "Danilovgrad",
+    "ME-08" => "Herceg-Novi",
+    "ME-09" => "Kolašin",
+    "ME-10" => "Kotor",
+    "ME-11" => "Mojkovac",
+    "ME-12" => "Nikšić",
+    "ME-13" => "Plav",
+    "ME-14" => "Pljevlja",
+    "ME-15" => "Plužine",
+    "ME-16" => "Podgorica",
+    "ME-17" => "Rožaje",
+    "ME-18" => "Šavnik",
+    "ME-19" => "Tivat",
+    "ME-20" => "Ulcinj",
+    "ME-21" => "Žabljak",
+    "ME-22" => "Gusinje",
+    "ME-23" => "Petnjica",
+    "ME-24" => "Tuzi",
+    "MA-01" => "Tanger-Tétouan-Al Hoceïma",
+    "MA-02" => "Oriental",
+    "MA-03" => "Fès-Meknès",
+    "MA-04" => "Rabat-Salé-Kénitra",
+    "MA-05" => "Béni Mellal-Khénifra",
+    "MA-06" => "Casablanca-Settat",
+    "MA-07" => "Marrakech-Safi",
+    "MA-08" => "Drâa-Tafilalet",
+    "MA-09" => "Souss-Massa",
+    "MA-10" => "Guelmim-Oued Noun (EH-gedeeltelijk)",
+    "MA-11" => "Laâyoune-Sakia El Hamra (EH-gedeeltelijk)",
+    "MA-12" => "Dakhla-Oued ed Dahab (EH)",
+    "MA-AGD" => "Agadir-Ida-Ou-Tanane",
+    "MA-AOU" => "Aousserd (EH)",
+    "MA-ASZ" => "Assa-Zag (EH-gedeeltelijk)",
+    "MA-AZI" => "Azilal",
+    "MA-BEM" => "Béni Mellal",
+    "MA-BER" => "Berkane",
+    "MA-BES" => "Benslimane",
+    "MA-BOD" => "Boujdour (EH)",
+    "MA-BOM" => "Boulemane",
+    "MA-BRR" => "Errachidia",
+    "MA-CAS" => "Casablanca",
+    "MA-CHE" => "Chefchaouen",
+    "MA-CHI" => "Chichaoua",
+    "MA-CHT" => "Chtouka-Ait Baha",
+    "MA-DRI" => "Driouch",
+    "MA-ERR" => "Errachidia",
+    "MA-ESI" => "Essaouira",
+    "MA-ESM" => "Es-Smara (EH-gedeeltelijk)",
+    "MA-FAH" => "Fahs-Anjra",
+    "MA-FES" => "Fès",
+    "MA-FIG" => "Figuig",
+    "MA-FQH" => "Fquih Ben Salah",
+    "MA-GUE" => "Guelmim",
+    "MA-GUF" => "Guercif",
+    "MA-HAJ" => "El Hajeb",
+    "MA-HAO" => "Al Haouz",
+    "MA-HOC" => "Al Hoceïma",
+    "MA-IFR" => "Ifrane",
+    "MA-INE" => "Inezgane-Ait Melloul",
+    "MA-JDI" => "El Jadida",
+    "MA-JRA" => "Jerada",
+    "MA-KEN" => "Kénitra",
+    "MA-KES" => "El Kelâa des Sraghna",
+    "MA-KHE" => "Khémisset",
+    "MA-KHN" => "Khénifra",
+    "MA-KHO" => "Khouribga",
+    "MA-LAA" => "Laâyoune (EH)",
+    "MA-LAR" => "Larache",
+    "MA-MAR" => "Marrakech",
+    "MA-MDF" => "M'diq-Fnideq",
+    "MA-MED" => "Médiouna",
+    "MA-MEK" => "Meknès",
+    "MA-MID" => "Midelt",
+    "MA-MOH" => "Mohammedia",
+    "MA-MOU" => "Moulay Yacoub",
+    "MA-NAD" => "Nador",
+    "MA-NOU" => "Nouaceur",
+    "MA-OUA" => "Ouarzazate",
+    "MA-OUD" => "Oued ed Dahab (EH)",
+    "MA-OUJ" => "Oujda-Angad",
+    "MA-OUZ" => "Ouezzane",
+    "MA-RAB" => "Rabat",
+    "MA-REH" => "Rehamna",
+    "MA-SAF" => "Safi",
+    "MA-SAL" => "Salé",
+    "MA-SEF" => "Sefrou",
+    "MA-SET" => "Settat",
+    "MA-SIB" => "Sidi Bennour",
+    "MA-SIF" => "Sidi Ifni",
+    "MA-SIK" => "Sidi Kacem",
+    "MA-SIL" => "Sidi Slimane",
+    "MA-SKH" => "Skhirate-Témara",
+    "MA-TAF" => "Tarfaya (EH-gedeeltelijk)",
+    "MA-TAI" => "Taourirt",
+    "MA-TAO" => "Taounate",
+    "MA-TAR" => "Taroudant",
+    "MA-TAT" => "Tata",
+    "MA-TAZ" => "Taza",
+    "MA-TET" => "Tétouan",
+    "MA-TIN" => "Tinghir",
+    "MA-TIZ" => "Tiznit",
+    "MA-TNG" => "Tanger-Assilah",
+    "MA-TNT" => "Tan-Tan (EH-gedeeltelijk)",
+    "MA-YUS" => "Youssoufia",
+    "MA-ZAG" => "Zagora",
+    "MZ-A" => "Niassa",
+    "MZ-B" => "Manica",
+    "MZ-G" => "Gaza",
+    "MZ-I" => "Inhambane",
+    "MZ-L" => "Maputo",
+    "MZ-MPM" => "Maputo",
+    "MZ-N" => "Nampula",
+    "MZ-P" => "Cabo Delgado",
+    "MZ-Q" => "Zambezia",
+    "MZ-S" => "Sofala",
+    "MZ-T" => "Tete",
+    "MM-01" => "Sagaing",
+    "MM-02" => "Bago",
+    "MM-03" => "Magway",
+    "MM-04" => "Mandalay",
+    "MM-05" => "Tanintharyi",
+    "MM-06" => "Yangon",
+    "MM-07" => "Ayeyarwady",
+    "MM-11" => "Kachin",
+    "MM-12" => "Kayah",
+    "MM-13" => "Kayin",
+    "MM-14" => "Chin",
+    "MM-15" => "Mon",
+    "MM-16" => "Rakhine",
+    "MM-17" => "Shan",
+    "MM-18" => "Naypyidaw",
+    "NA-CA" => "Zambezi",
+    "NA-ER" => "Erongo",
+    "NA-HA" => "Hardap",
+    "NA-KA" => "//Karas",
+    "NA-KE" => "Kavango-Oost",
+    "NA-KH" => "Khomas",
+    "NA-KU" => "Kunene",
+    "NA-KW" => "Kavango-West",
+    "NA-OD" => "Otjozondjupa",
+    "NA-OH" => "Omaheke",
+    "NA-ON" => "Oshana",
+    "NA-OS" => "Omusati",
+    "NA-OT" => "Oshikoto",
+    "NA-OW" => "Ohangwena",
+    "NR-01" => "Aiwo",
+    "NR-02" => "Anabar",
+    "NR-03" => "Anetan",
+    "NR-04" => "Anibare",
+    "NR-05" => "Baiti",
+    "NR-06" => "Boe",
+    "NR-07" => "Buada",
+    "NR-08" => "Denigomodu",
+    "NR-09" => "Ewa",
+    "NR-10" => "Ijuw",
+    "NR-11" => "Meneng",
+    "NR-12" => "Nibok",
+    "NR-13" => "Uaboe",
+    "NR-14" => "Yaren",
+    "NP-P1" => "Provincie 1",
+    "NP-P2" => "Provincie 2",
+    "NP-P3" => "Bagmati",
+    "NP-P4" => "Gandaki",
+    "NP-P5" => "Provincie 5",
+    "NP-P6" => "Karnali",
+    "NP-P7" => "Sudurpashchim",
+    "NL-AW" => "Aruba",
+    "NL-BQ1" => "Bonaire",
+    "NL-BQ2" => "Saba",
+    "NL-BQ3" => "Sint Eustatius",
+    "NL-CW" => "Curaçao",
+    "NL-DR" => "Drenthe",
+    "NL-FL" => "Flevoland",
+    "NL-FR" => "Friesland",
+    "NL-GE" => "Gelderland",
+    "NL-GR" => "Groningen",
+    "NL-LI" => "Limburg",
+    "NL-NB" => "Noord-Brabant",
+    "NL-NH" => "Noord-Holland",
+    "NL-OV" => "Overijssel",
+    "NL-SX" => "Sint Maarten",
+    "NL-UT" => "Utrecht",
+    "NL-ZE" => "Zeeland",
+    "NL-ZH" => "Zuid-Holland",
+    "NZ-AUK" => "Auckland",
+    "NZ-BOP" => "Bay of Plenty",
+    "NZ-CAN" => "Canterbury",
+    "NZ-CIT" => "Chatham Islands Territory",
+    "NZ-GIS" => "Gisborne",
+    "NZ-HKB" => "Hawke's Bay",
+    "NZ-MBH" => "Marlborough",
+    "NZ-MWT" => "Manawatu-Wanganui",
+    "NZ-NSN" => "Nelson",
+    "NZ-NTL" => "Noordland",
+    "NZ-OTA" => "Otago",
+    "NZ-STL" => "Zuidland",
+    "NZ-TAS" => "Tasman",
+    "NZ-TKI" => "Taranaki",
+    "NZ-WGN" => "Wellington",
+    "NZ-WKO" => "Waikato",
+    "NZ-WTC" => "Westkust",
+    "NI-AN" => "Región Autónoma de la Costa Caribe Norte",
+    "NI-AS" => "Región Autónoma de la Costa Caribe Sur",
+    "NI-BO" => "Boaco",
+    "NI-CA" => "Carazo",
+    "NI-CI" => "Chinandega",
+    "NI-CO" => "Chontales",
+    "NI-ES" => "Estelí",
+    "NI-GR" => "Granada",
+    "NI-JI" => "Jinotega",
+    "NI-LE" => "León",
+    "NI-MD" => "Madriz",
+    "NI-MN" => "Managua",
+    "NI-MS" => "Masaya",
+    "NI-MT" => "Matagalpa",
+    "NI-NS" => "Nueva Segovia",
+    "NI-RI" => "Rivas",
+    "NI-SJ" => "Río San Juan",
+    "NE-1" => "Agadez",
+    "NE-2" => "Diffa",
+    "NE-3" => "Dosso",
+    "NE-4" => "Maradi",
+    "NE-5" => "Tahoua",
+    "NE-6" => "Tillabéri",
+    "NE-7" => "Zinder",
+    "NE-8" => "Niamey",
+    "NG-AB" => "Abia",
+    "NG-AD" => "Adamawa",
+    "NG-AK" => "Akwa Ibom",
+    "NG-AN" => "Anambra",
+    "NG-BA" => "Bauchi",
+    "NG-BE" => "Benue",
+    "NG-BO" => "Borno",
+    "NG-BY" => "Bayelsa",
+    "NG-CR" => "Cross River",
+    "NG-DE" => "Delta",
+    "NG-EB" => "Ebonyi",
+    "NG-ED" => "Edo",
+    "NG-EK" => "Ekiti",
+    "NG-EN" => "Enugu",
+    "NG-FC" => "Federaal Hoofdstedelijk Territorium",
+    "NG-GO" => "Gombe",
+    "NG-IM" => "Imo",
+    "NG-JI" => "Jigawa",
+    "NG-KD" => "Kaduna",
+    "NG-KE" => "Kebbi",
+    "NG-KN" => "Kano",
+    "NG-KO" => "Kogi",
+    "NG-KT" => "Katsina",
+    "NG-KW" => "Kwara",
+    "NG-LA" => "Lagos",
+    "NG-NA" => "Nassarawa",
+    "NG-NI" => "Niger",
+    "NG-OG" => "Ogun",
+    "NG-ON" => "Ondo",
+    "NG-OS" => "Osun",
+    "NG-OY" => "Oyo",
+    "NG-PL" => "Plateau",
+    "NG-RI" => "Rivers",
+    "NG-SO" => "Sokoto",
+    "NG-TA" => "Taraba",
+    "NG-YO" => "Yobe",
+    "NG-ZA" => "Zamfara",
+    "MK-101" => "Veles",
+    "MK-102" => "Gradsko",
+    "MK-103" => "Demir Kapija",
+    "MK-104" => "Kavadarci",
+    "MK-105" => "Lozovo",
+    "MK-106" => "Negotino",
+    "MK-107" => "Rosoman",
+    "MK-108" => "Sveti Nikole",
+    "MK-109" => "Čaška",
+    "MK-201" => "Berovo",
+    "MK-202" => "Vinica",
+    "MK-203" => "Delčevo",
+    "MK-204" => "Zrnovci",
+    "MK-205" => "Karbinci",
+    "MK-206" => "Kočani",
+    "MK-207" => "Makedonska Kamenica",
+    "MK-208" => "Pehčevo",
+    "MK-209" => "Probištip",
+    "MK-210" => "Češinovo-Obleševo",
+    "MK-211" => "Štip",
+    "MK-301" => "Vevčani",
+    "MK-303" => "Debar",
+    "MK-304" => "Debarca",
+    "MK-307" => "Kičevo",
+    "MK-308" => "Makedonski Brod",
+    "MK-310" => "Ohrid",
+    "MK-311" => "Plasnica",
+    "MK-312" => "Struga",
+    "MK-313" => "Centar Župa",
+    "MK-401" => "Bogdanci",
+    "MK-402" => "Bosilovo",
+    "MK-403" => "Valandovo",
+    "MK-404" => "Vasilevo",
+    "MK-405" => "Gevgelija",
+    "MK-406" => "Dojran",
+    "MK-407" => "Konče",
+    "MK-408" => "Novo Selo",
+    "MK-409" => "Radoviš",
+    "MK-410" => "Strumica",
+    "MK-501" => "Bitola",
+    "MK-502" => "Demir Hisar",
+    "MK-503" => "Dolneni",
+    "MK-504" => "Krivogaštani",
+    "MK-505" => "Kruševo",
+    "MK-506" => "Mogila",
+    "MK-507" => "Novaci",
+    "MK-508" => "Prilep",
+    "MK-509" => "Resen",
+    "MK-601" => "Bogovinje",
+    "MK-602" => "Brvenica",
+    "MK-603" => "Vrapčište",
+    "MK-604" => "Gostivar",
+    "MK-605" => "Želino",
+    "MK-606" => "Jegunovce",
+    "MK-607" => "Mavrovo en Rostuša",
+    "MK-608" => "Tearce",
+    "MK-609" => "Tetovo",
+    "MK-701" => "Kratovo",
+    "MK-702" => "Kriva Palanka",
+    "MK-703" => "Kumanovo",
+    "MK-704" => "Lipkovo",
+    "MK-705" => "Rankovce",
+    "MK-706" => "Staro Nagoričane",
+    "MK-801" => "Aerodrom †",
+    "MK-802" => "Aračinovo",
+    "MK-803" => "Butel †",
+    "MK-804" => "Gazi Baba †",
+    "MK-805" => "Gorče Petrov †",
+    "MK-806" => "Zelenikovo",
+    "MK-807" => "Ilinden",
+    "MK-808" => "Karpoš †",
+    "MK-809" => "Kisela Voda †",
+    "MK-810" => "Petrovec",
+    "MK-811" => "Saraj †",
+    "MK-812" => "Sopište",
+    "MK-813" => "Studeničani",
+    "MK-814" => "Centar †",
+    "MK-815" => "Čair †",
+    "MK-816" => "Čučer-Sandevo",
+    "MK-817" => "Šuto Orizari †",
+    "NO-03" => "Oslo",
+    "NO-11" => "Rogaland",
+    "NO-15" => "Møre og Romsdal",
+    "NO-18" => "Nordland",
+    "NO-21" => "Spitsbergen (Arctische regio); Svalbard (Arctische regio)",
+    "NO-22" => "Jan Mayen (Arctische regio)",
+    "NO-30" => "Viken",
+    "NO-34" => "Innlandet",
+    "NO-38" => "Vestfold og Telemark",
+    "NO-42" => "Agder",
+    "NO-46" => "Vestland",
+    "NO-50" => "Trøndelag",
+    "NO-54" => "Troms og Finnmark",
+    "OM-BJ" => "Gouvernement Zuid Al Batinah",
+    "OM-BS" => "Gouvernement Noord Al Batinah",
+    "OM-BU" => "Al Buraymī",
+    "OM-DA" => "Ad Dachiliyah",
+    "OM-MA" => "Masqaţ",
+    "OM-MU" => "Musandam",
+    "OM-SJ" => "Gouvernement Zuid Ash Sharqiyah",
+    "OM-SS" => "Gouvernement Noord Ash Sharqiyah",
+    "OM-WU" => "Al Wusta",
+    "OM-ZA" => "Az Zahirah",
+    "OM-ZU" => "Z̧ufār",
+    "PK-BA" => "Beloetsjistan",
+    "PK-GB" => "Gilgit-Baltistan",
+    "PK-IS" => "Islamabad",
+    "PK-JK" => "Azad-staat van Jammu en Kasjmir",
+    "PK-KP" => "Khyber Pakhtunkhwa",
+    "PK-PB" => "Punjab",
+    "PK-SD" => "Sindh",
+    "PW-002" => "Aimeliik",
+    "PW-004" => "Airai",
+    "PW-010" => "Angaur",
+    "PW-050" => "Hatohobei",
+    "PW-100" => "Kayangel",
+    "PW-150" => "Koror",
+    "PW-212" => "Melekeok",
+    "PW-214" => "Ngaraard",
+    "PW-218" => "Ngarchelong",
+    "PW-222" => "Ngardmau",
+    "PW-224" => "Ngatpang",
+    "PW-226" => "Ngchesar",
+    "PW-227" => "Ngeremlengui",
+    "PW-228" => "Ngiwal",
+    "PW-350" => "Peleliu",
+    "PW-370" => "Sonsorol",
+    "PS-BTH" => "Bethlehem",
+    "PS-DEB" => "Deir El Balah",
+    "PS-GZA" => "Gaza",
+    "PS-HBN" => "Hebron",
+    "PS-JEM" => "Jeruzalem",
+    "PS-JEN" => "Jenin",
+    "PS-JRH" => "Jericho - Al Aghwar",
+    "PS-KYS" => "Khan Yunis",
+    "PS-NBS" => "Nablus",
+    "PS-NGZ" => "Noord-Gaza",
+    "PS-QQA" => "Qalqilya",
+    "PS-RBH" => "Ramallah",
+    "PS-RFH" => "Rafah",
+    "PS-SLT" => "Salfit",
+    "PS-TBS" => "Tubas",
+    "PS-TKM" => "Tulkarm",
+    "PA-1" => "Bocas del Toro",
+    "PA-10" => "Panama",
+    "PA-2" => "Coclé",
+    "PA-3" => "Colón",
+    "PA-4" => "Chiriquí",
+    "PA-5" => "Darién",
+    "PA-6" => "Herrera",
+    "PA-7" => "Los Santos",
+    "PA-8" => "Panama",
+    "PA-9" => "Veraguas",
+    "PA-EM" => "Emberá",
+    "PA-KY" => "Guna Yala",
+    "PA-NB" => "Ngöbe-Buglé",
+    "PG-CPK" => "Chimbu",
+    "PG-CPM" => "Central",
+    "PG-EBR" => "East New Britain",
+    "PG-EHG" => "Eastern Highlands",
+    "PG-EPW" => "Enga",
+    "PG-ESW" => "East Sepik",
+    "PG-GPK" => "Gulf",
+    "PG-HLA" => "Hela",
+    "PG-JWK" => "Jiwaka",
+    "PG-MBA" => "Milne Bay",
+    "PG-MPL" => "Morobe",
+    "PG-MPM" => "Madang",
+    "PG-MRL" => "Manus",
+    "PG-NCD" => "National Capital District (Port Moresby)",
+    "PG-NIK" => "New Ireland",
+    "PG-NPP" => "Northern",
+    "PG-NSB" => "Bougainville",
+    "PG-SAN" => "West Sepik",
+    "PG-SHM" => "Southern Highlands",
+    "PG-WBK" => "West New Britain",
+    "PG-WHM" => "Western Highlands",
+    "PG-WPD" => "Western",
+    "PY-1" => "Concepción",
+    "PY-10" => "Hoog-Paraná",
+    "PY-11" => "Central",
+    "PY-12" => "Ñeembucú",
+    "PY-13" => "Amambay",
+    "PY-14" => "Canindeyú",
+    "PY-15" => "President Hayes",
+    "PY-16" => "Hoog-Paraguay",
+    "PY-19" => "Boquerón",
+    "PY-2" => "San Pedro",
+    "PY-3" => "Cordillera",
+    "PY-4" => "Guairá",
+    "PY-5" => "Caaguazú",
+    "PY-6" => "Caazapá",
+    "PY-7" => "Itapúa",
+    "PY-8" => "Misiones",
+    "PY-9" => "Paraguarí",
+    "PY-ASU" => "Asunción",
+    "PE-AMA" => "Amazonas",
+    "PE-ANC" => "Áncash",
+    "PE-APU" => "Apurímac",
+    "PE-ARE" => "Arequipa",
+    "PE-AYA" => "Ayacucho",
+    "PE-CAJ" => "Cajamarca",
+    "PE-CAL" => "Callao",
+    "PE-CUS" => "Cuzco",
+    "PE-HUC" => "Huánuco",
+    "PE-HUV" => "Huancavelica",
+    "PE-ICA" => "Ica",
+    "PE-JUN" => "Junín",
+    "PE-LAL" => "La Libertad",
+    "PE-LAM" => "Lambayeque",
+    "PE-LIM" => "Lima",
+    "PE-LMA" => "Lima (stad)",
+    "PE-LOR" => "Loreto",
+    "PE-MDD" => "Madre de Dios",
+    "PE-MOQ" => "Moquegua",
+    "PE-PAS" => "Pasco",
+    "PE-PIU" => "Piura",
+    "PE-PUN" => "Puno",
+    "PE-SAM" => "San Martín",
+    "PE-TAC" => "Tacna",
+    "PE-TUM" => "Tumbes",
+    "PE-UCA" => "Ucayali",
+    "PH-00" => "Nationale Hoofstadregio",
+    "PH-01" => "Ilocos (Regio I)",
+    "PH-02" => "Cagayan Valley (Regio II)",
+    "PH-03" => "Central Luzon (Regio III)",
+    "PH-05" => "Bicol (Regio V)",
+    "PH-06" => "Western Visayas (Regio VI)",
+    "PH-07" => "Central Visayas (Regio VII)",
+    "PH-08" => "Eastern Visayas (Regio VIII)",
+    "PH-09" => "Zamboanga Peninsula (Regio IX)",
+    "PH-10" => "Northern Mindanao (Regio X)",
+    "PH-11" => "Davao (Regio XI)",
+    "PH-12" => "Soccsksargen (Regio XII)",
+    "PH-13" => "Caraga (Regio XIII)",
+    "PH-14" => "Autonome Regio in Muslim Mindanao (ARMM)",
+    "PH-15" => "Cordillera Administratieve Regio (CAR)",
+    "PH-40" => "Calabarzon (Regio IV-A)",
+    "PH-41" => "Mimaropa (Regio IV-B)",
+    "PH-ABR" => "Abra",
+    "PH-AGN" => "Agusan del Norte",
+    "PH-AGS" => "Agusan del Sur",
+    "PH-AKL" => "Aklan",
+    "PH-ALB" => "Albay",
+    "PH-ANT" => "Antique",
+    "PH-APA" => "Apayao",
+    "PH-AUR" => "Aurora",
+    "PH-BAN" => "Bataan",
+    "PH-BAS" => "Basilan",
+    "PH-BEN" => "Benguet",
+    "PH-BIL" => "Biliran",
+    "PH-BOH" => "Bohol",
+    "PH-BTG" => "Batangas",
+    "PH-BTN" => "Batanes",
+    "PH-BUK" => "Bukidnon",
+    "PH-BUL" => "Bulacan",
+    "PH-CAG" => "Cagayan",
+    "PH-CAM" => "Camiguin",
+    "PH-CAN" => "Camarines Norte",
+    "PH-CAP" => "Capiz",
+    "PH-CAS" => "Camarines Sur",
+    "PH-CAT" => "Catanduanes",
+    "PH-CAV" => "Cavite",
+    "PH-CEB" => "Cebu",
+    "PH-COM" => "Davao de Oro",
+    "PH-DAO" => "Davao Oriental",
+    "PH-DAS" => "Davao del Sur",
+    "PH-DAV" => "Davao del Norte",
+    "PH-DIN" => "Dinagat Islands",
+    "PH-DVO" => "Davao Occidental",
+    "PH-EAS" => "Eastern Samar",
+    "PH-GUI" => "Guimaras",
+    "PH-IFU" => "Ifugao",
+    "PH-ILI" => "Iloilo",
+    "PH-ILN" => "Ilocos Norte",
+    "PH-ILS" => "Ilocos Sur",
+    "PH-ISA" => "Isabela",
+    "PH-KAL" => "Kalinga",
+    "PH-LAG" => "Laguna",
+    "PH-LAN" => "Lanao del Norte",
+    "PH-LAS" => "Lanao del Sur",
+    "PH-LEY" => "Leyte",
+    "PH-LUN" => "La Union",
+    "PH-MAD" => "Marinduque",
+    "PH-MAS" => "Masbate",
+    "PH-MDC" => "Mindoro Occidental",
+    "PH-MDR" => "Mindoro Oriental",
+    "PH-MOU" => "Mountain Province",
+    "PH-MSC" => "Misamis Occidental",
+    "PH-MSR" => "Misamis Oriental",
+    "PH-NCO" => "Cotabato",
+    "PH-NEC" => "Negros Occidental",
+    "PH-NER" => "Negros Oriental",
+    "PH-NSA" => "Northern Samar",
+    "PH-NUE" => "Nueva Ecija",
+    "PH-NUV" => "Nueva Vizcaya",
+    "PH-PAM" => "Pampanga",
+    "PH-PAN" => "Pangasinaans",
+    "PH-PLW" => "Palawan",
+    "PH-QUE" => "Quezon",
+    "PH-QUI" => "Quirino",
+    "PH-RIZ" => "Rizal",
+    "PH-ROM" => "Romblon",
+    "PH-SAR" => "Sarangani",
+    "PH-SCO" => "South Cotabato",
+    "PH-SIG" => "Siquijor",
+    "PH-SLE" => "Southern Leyte",
+    "PH-SLU" => "Sulu",
+    "PH-SOR" => "Sorsogon",
+    "PH-SUK" => "Sultan Kudarat",
+    "PH-SUN" => "Surigao del Norte",
+    "PH-SUR" => "Surigao del Sur",
+    "PH-TAR" => "Tarlac",
+    "PH-TAW" => "Tawi-Tawi",
+    "PH-WSA" => "Samar",
+    "PH-ZAN" => "Zamboanga del Norte",
+    "PH-ZAS" => "Zamboanga del Sur",
+    "PH-ZMB" => "Zambales",
+    "PH-ZSI" => "Zamboanga Sibugay",
+    "PL-02" => "Dolnoslazki",
+    "PL-04" => "Kujawsko-pomorski",
+    "PL-06" => "Lublin",
+    "PL-08" => "Lubusz",
+    "PL-10" => "Łódź",
+    "PL-12" => "Klein-Polen",
+    "PL-14" => "Masovië",
+    "PL-16" => "Opole",
+    "PL-18" => "Subkarpaten",
+    "PL-20" => "Podlachië",
+    "PL-22" => "Pommeren",
+    "PL-24" => "Silezië",
+    "PL-26" => "Heilig Kruis; Święty Krzyż",
+    "PL-28" => "Ermland-Mazurië",
+    "PL-30" => "Groot-Polen",
+    "PL-32" => "West-Pommeren",
+    "PT-01" => "Aveiro",
+    "PT-02" => "Beja",
+    "PT-03" => "Braga",
+    "PT-04" => "Bragança",
+    "PT-05" => "Castelo Branco",
+    "PT-06" => "Coimbra",
+    "PT-07" => "Évora",
+    "PT-08" => "Faro",
+    "PT-09" => "Guarda",
+    "PT-10" => "Leiria",
+    "PT-11" => "Lissabon",
+    "PT-12" => "Portalegre",
+    "PT-13" => "Porto",
+    "PT-14" => "Santarém",
+    "PT-15" => "Setúbal",
+    "PT-16" => "Viana do Castelo",
+    "PT-17" => "Vila Real",
+    "PT-18" => "Viseu",
+    "PT-20" => "Autonome Regio Azoren",
+    "PT-30" => "Autonome Regio Madeira",
+    "QA-DA" => "Doha",
+    "QA-KH" => "Al Khawr wa adh Dhakhīrah",
+    "QA-MS" => "Ash Shamal",
+    "QA-RA" => "Ar Rayyan",
+    "QA-SH" => "Al-Shahaniya",
+    "QA-US" => "Umm Salal",
+    "QA-WA" => "Al Wakrah",
+    "QA-ZA" => "Az Za'ayin",
+    "RO-AB" => "Alba",
+    "RO-AG" => "Argeș",
+    "RO-AR" => "Arad",
+    "RO-B" => "Boekarest",
+    "RO-BC" => "Bacău",
+    "RO-BH" => "Bihor",
+    "RO-BN" => "Bistrița-Năsăud",
+    "RO-BR" => "Brăila",
+    "RO-BT" => "Botoșani",
+    "RO-BV" => "Brașov",
+    "RO-BZ" => "Buzău",
+    "RO-CJ" => "Cluj",
+    "RO-CL" => "Călărași",
+    "RO-CS" => "Caraș-Severin",
+    "RO-CT" => "Constanța",
+    "RO-CV" => "Covasna",
+    "RO-DB" => "Dâmbovița",
+    "RO-DJ" => "Dolj",
+    "RO-GJ" => "Gorj",
+    "RO-GL" => "Galați",
+    "RO-GR" => "Giurgiu",
+    "RO-HD" => "Hunedoara",
+    "RO-HR" => "Harghita",
+    "RO-IF" => "Ilfov",
+    "RO-IL" => "Ialomița",
+    "RO-IS" => "Iași",
+    "RO-MH" => "Mehedinți",
+    "RO-MM" => "Maramureș",
+    "RO-MS" => "Mureș",
+    "RO-NT" => "Neamț",
+    "RO-OT" => "Olt",
+    "RO-PH" => "Prahova",
+    "RO-SB" => "Sibiu",
+    "RO-SJ" => "Sălaj",
+    "RO-SM" => "Satu Mare",
+    "RO-SV" => "Suceava",
+    "RO-TL" => "Tulcea",
+    "RO-TM" => "Timiș",
+    "RO-TR" => "Teleorman",
+    "RO-VL" => "Vâlcea",
+    "RO-VN" => "Vrancea",
+    "RO-VS" => "Vaslui",
+    "RU-AD" => "Adygeya, Republiek",
+    "RU-AL" => "Altaj, Republiek",
+    "RU-ALT" => "Altaj, Kraj",
+    "RU-AMU" => "Amoer, Oblast",
+    "RU-ARK" => "Arkhangelsk, Oblast",
+    "RU-AST" => "Astrachan, Oblast",
+    "RU-BA" => "Basjkirostan, Republiek",
+    "RU-BEL" => "Belgorod, Oblast",
+    "RU-BRY" => "Brjansk, Oblast",
+    "RU-BU" => "Boerjatië, Republiek",
+    "RU-CE" => "Tsjetsjenië, Republiek",
+    "RU-CHE" => "Tsjeljabinsk, Oblast",
+    "RU-CHU" => "Tsjoekotka, Autonoom District",
+    "RU-CU" => "Tsjoevasjië, Republiek",
+    "RU-DA" => "Dagestan, Republiek",
+    "RU-IN" => "Ingoesjetië, Republiek",
+    "RU-IRK" => "Irkoetsk, Oblast",
+    "RU-IVA" => "Ivanovo, Oblast",
+    "RU-KAM" => "Kamtsjatka, Kraj",
+    "RU-KB" => "Kabardië-Balkarië, Republiek",
+    "RU-KC" => "Karatsjaj-Tsjerkessië, Republiek",
+    "RU-KDA" => "Krasnodar, Kraj",
+    "RU-KEM" => "Kemerovo, Oblast",
+    "RU-KGD" => "Kaliningrad, Oblast",
+    "RU-KGN" => "Koergan, Oblast",
+    "RU-KHA" => "Chabarovsk, Kraj",
+    "RU-KHM" => "Chanto-Mansië, Autonoom District",
+    "RU-KIR" => "Kirov, Oblast",
+    "RU-KK" => "Chakassië, Republiek",
+    "RU-KL" => "Kalmukkië, Republiek",
+    "RU-KLU" => "Kaloega, Oblast",
+    "RU-KO" => "Komi, Republiek",
+    "RU-KOS" => "Kostroma, Oblast",
+    "RU-KR" => "Karelië, Republiek",
+    "RU-KRS" => "Koersk, Oblast",
+    "RU-KYA" => "Krasnojarsk, Kraj",
+    "RU-LEN" => "Leningrad, Oblast",
+    "RU-LIP" => "Lipetsk, Oblast",
+    "RU-MAG" => "Magadan, Oblast",
+    "RU-ME" => "Mari El, Republiek",
+    "RU-MO" => "Mordovië, Republiek",
+    "RU-MOS" => "Moskou, Oblast",
+    "RU-MOW" => "Moskou",
+    "RU-MUR" => "Moermansk, Oblast",
+    "RU-NEN" => "Nenetsië, Autonoom District",
+    "RU-NGR" => "Novgorod, Oblast",
+    "RU-NIZ" => "Nizjni Novgorod, Oblast",
+    "RU-NVS" => "Novosibirsk, Oblast",
+    "RU-OMS" => "Omsk, Oblast",
+    "RU-ORE" => "Orenburg, Oblast",
+    "RU-ORL" => "Orjol, Oblast",
+    "RU-PER" => "Perm, Kraj",
+    "RU-PNZ" => "Penza, Oblast",
+    "RU-PRI" => "Primorje, Kraj",
+    "RU-PSK" => "Pskov, Oblast",
+    "RU-ROS" => "Rostov, Oblast",
+    "RU-RYA" => "Rjazan, Oblast",
+    "RU-SA" => "Jakoetië; Sacha, Republiek",
+    "RU-SAK" => "Sachalin, Oblast",
+    "RU-SAM" => "Samara, Oblast",
+    "RU-SAR" => "Saratov, Oblast",
+    "RU-SE" => "Noord-Ossetië; Noord-Ossetië-Alanië, Republiek",
+    "RU-SMO" => "Smolensk, Oblast",
+    "RU-SPE" => "Sint-Petersburg",
+    "RU-STA" => "Stavropol, Kraj",
+    "RU-SVE" => "Sverdlovsk, Oblast",
+    "RU-TA" => "Tatarije, Republiek; Tatarstan, Republiek",
+    "RU-TAM" => "Tambov, Oblast",
+    "RU-TOM" => "Tomsk, Oblast",
+    "RU-TUL" => "Toela, Oblast",
+    "RU-TVE" => "Tver, Oblast",
+    "RU-TY" => "Toeva, Republiek",
+    "RU-TYU" => "Tjoemen, Oblast",
+    "RU-UD" => "Oedmoertië, Republiek",
+    "RU-ULY" => "Oeljanovsk, Oblast",
+    "RU-VGG" => "Wolgograd, Oblast",
+    "RU-VLA" => "Vladimir, Oblast",
+    "RU-VLG" => "Vologda, Oblast",
+    "RU-VOR" => "Voronezj, Oblast",
+    "RU-YAN" => "Jamalië, Autonoom District",
+    "RU-YAR" => "Jaroslavl, Oblast",
+    "RU-YEV" => "Joodse Autonome Oblast",
+    "RU-ZAB" => "Zabajkalski, Kraj",
+    "RW-02" => "Oost",
+    "RW-03" => "Northern",
+    "RW-04" => "Western",
+    "RW-05" => "Zuid",
+    "SH-AC" => "Ascension",
+    "SH-HL" => "Sint Helena",
+    "SH-TA" => "Tristan da Cunha",
+    "KN-01" => "Christ Church Nichola Town",
+    "KN-02" => "Saint Anne Sandy Point",
+    "KN-03" => "Saint George Basseterre",
+    "KN-04" => "Saint George Gingerland",
+    "KN-05" => "Saint James Windward",
+    "KN-06" => "Saint John Capisterre",
+    "KN-07" => "Saint John Figtree",
+    "KN-08" => "Saint Mary Cayon",
+    "KN-09" => "Saint Paul Capisterre",
+    "KN-10" => "Saint Paul Charlestown",
+    "KN-11" => "Saint Peter Basseterre",
+    "KN-12" => "Saint Thomas Lowland",
+    "KN-13" => "Saint Thomas Middle Island",
+    "KN-15" => "Trinity Palmetto Point",
+    "KN-K" => "Saint Kitts",
+    "KN-N" => "Nevis",
+    "LC-01" => "Anse-la-Raye",
+    "LC-02" => "Castries",
+    "LC-03" => "Choiseul",
+    "LC-05" => "Dennery",
+    "LC-06" => "Gros Islet",
+    "LC-07" => "Laborie",
+    "LC-08" => "Micoud",
+    "LC-10" => "Soufrière",
+    "LC-11" => "Vieux Fort",
+    "LC-12" => "Canaries",
+    "VC-01" => "Charlotte",
+    "VC-02" => "Saint Andrew",
+    "VC-03" => "Saint David",
+    "VC-04" => "Saint George",
+    "VC-05" => "Saint Patrick",
+    "VC-06" => "Grenadines",
+    "WS-AA" => "A'ana",
+    "WS-AL" => "Aiga-i-le-Tai",
+    "WS-AT" => "Atua",
+    "WS-FA" => "Fa'asaleleaga",
+    "WS-GE" => "Gaga'emauga",
+    "WS-GI" => "Gagaifomauga",
+    "WS-PA" => "Palauli",
+    "WS-SA" => "Satupa'itea",
+    "WS-TU" => "Tuamasaga",
+    "WS-VF" => "Va'a-o-Fonoti",
+    "WS-VS" => "Vaisigano",
+    "SM-01" => "Acquaviva",
+    "SM-02" => "Chiesanuova",
+    "SM-03" => "Domagnano",
+    "SM-04" => "Faetano",
+    "SM-05" => "Fiorentino",
+    "SM-06" => "Borgo Maggiore",
+    "SM-08" => "Monte Giardino",
+    "SM-09" => "Serravalle",
+    "ST-01" => "Água Grande",
+    "ST-02" => "Cantagalo",
+    "ST-03" => "Caué",
+    "ST-04" => "Lembá",
+    "ST-05" => "Lobata",
+    "ST-06" => "Mé-Zóchi",
+    "ST-P" => "Principe",
+    "SA-02" => "Mekka",
+    "SA-04" => "Ash Sharqiyah",
+    "SA-05" => "Al Qasim",
+    "SA-07" => "Tabuk",
+    "SA-09" => "Jizan",
+    "SA-10" => "Najran",
+    "SA-12" => "Al Jawf",
+    "SN-DB" => "Diourbel",
+    "SN-DK" => "Dakar",
+    "SN-FK" => "Fatick",
+    "SN-KA" => "Kaffrine",
+    "SN-KD" => "Kolda",
+    "SN-KE" => "Kédougou",
+    "SN-KL" => "Kaolack",
+    "SN-LG" => "Louga",
+    "SN-MT" => "Matam",
+    "SN-SE" => "Sédhiou",
+    "SN-SL" => "Saint-Louis",
+    "SN-TC" => "Tambacounda",
+    "SN-TH" => "Thiès",
+    "SN-ZG" => "Ziguinchor",
+    "RS-00" => "Beograd",
+    "RS-01" => "Noord-Bačka",
+    "RS-02" => "Centraal-Banaat",
+    "RS-03" => "Noord-Banaat",
+    "RS-04" => "Zuid-Banaat",
+    "RS-05" => "West-Bačka",
+    "RS-06" => "Zuid-Bačka",
+    "RS-07" => "Srem",
+    "RS-08" => "Mačva",
+    "RS-09" => "Kolubara",
+    "RS-10" => "Podunavlje",
+    "RS-11" => "Braničevo",
+    "RS-12" => "Šumadija",
+    "RS-13" => "Pomoravlje",
+    "RS-14" => "Bor",
+    "RS-15" => "Zaječar",
+    "RS-16" => "Zlatibor",
+    "RS-17" => "Moravica",
+    "RS-18" => "Raška",
+    "RS-19" => "Rasina",
+    "RS-20" => "Nišava",
+    "RS-21" => "Toplica",
+    "RS-22" => "Pirot",
+    "RS-23" => "Jablanica",
+    "RS-24" => "Pčinja",
+    "RS-25" => "Kosovo",
+    "RS-26" => "Peć",
+    "RS-27" => "Prizren",
+    "RS-28" => "Kosovska Mitrovica",
+    "RS-29" => "Kosovo-Pomoravlje",
+    "RS-KM" => "Kosovo-Metohija",
+    "RS-VO" => "Vojvodina",
+    "SC-01" => "Anse aux Pins",
+    "SC-02" => "Anse Boileau",
+    "SC-03" => "Anse Etoile",
+    "SC-04" => "Au Cap",
+    "SC-05" => "Anse Royale",
+    "SC-06" => "Baie Lazare",
+    "SC-07" => "Baie Sainte Anne",
+    "SC-08" => "Beau Vallon",
+    "SC-09" => "Bel Air",
+    "SC-10" => "Bel Ombre",
+    "SC-11" => "Cascade",
+    "SC-12" => "Glacis",
+    "SC-13" => "Grand' Anse Mahé",
+    "SC-14" => "Grand' Anse Praslin",
+    "SC-15" => "La Digue",
+    "SC-16" => "La Riviere Anglaise",
+    "SC-17" => "Mont Buxton",
+    "SC-18" => "Mont Fleuri",
+    "SC-19" => "Plaisance",
+    "SC-20" => "Pointe La Rue",
+    "SC-21" => "Port Glaud",
+    "SC-22" => "Saint Louis",
+    "SC-23" => "Takamaka",
+    "SC-24" => "Les Mamelles",
+    "SC-25" => "Roche Caiman",
+    "SC-26" => "Ile Perseverance I",
+    "SC-27" => "Ile Perseverance II",
+    "SL-E" => "Oost",
+    "SL-N" => "Northern",
+    "SL-S" => "Zuid",
+    "SL-W" => "Westelijk Gebied (Freetown)",
+    "SG-01" => "Singapore-Centrale-Regio",
+    "SG-02" => "Noord-Oost",
+    "SG-03" => "Noord-West",
+    "SG-04" => "Zuid-Oost",
+    "SG-05" => "Zuid-West",
+    "SK-BC" => "Banská Bystrica, Regio",
+    "SK-BL" => "Bratislava, Regio",
+    "SK-KI" => "Košice, Regio",
+    "SK-NI" => "Nitra, Regio",
+    "SK-PV" => "Prešov, Regio",
+    "SK-TA" => "Trnava, Regio",
+    "SK-TC" => "Trenčín, Regio",
+    "SK-ZI" => "Žilina, Regio",
+    "SI-001" => "Ajdovscina",
+    "SI-002" => "Beltinci",
+    "SI-003" => "Bled",
+    "SI-004" => "Bohinj",
+    "SI-005" => "Borovnica",
+    "SI-006" => "Bovec",
+    "SI-007" => "Brda",
+    "SI-008" => "Brezovica",
+    "SI-009" => "Brezica",
+    "SI-010" => "Tisina",
+    "SI-011" => "Celje",
+    "SI-012" => "Cerklje na Gorenjskem",
+    "SI-013" => "Cerknica",
+    "SI-014" => "Cerkno",
+    "SI-015" => "Crensovci",
+    "SI-016" => "Crna na Koroskem",
+    "SI-017" => "Crnomelj",
+    "SI-018" => "Destrnik",
+    "SI-019" => "Divaca",
+    "SI-020" => "Dobrepolje",
+    "SI-021" => "Dobrova-Polhov Gradec",
+    "SI-022" => "Dol pri Ljubljani",
+    "SI-023" => "Domzale",
+    "SI-024" => "Dornava",
+    "SI-025" => "Dravograd",
+    "SI-026" => "Duplek",
+    "SI-027" => "Gorenja vas-Poljane",
+    "SI-028" => "Gorsnica",
+    "SI-029" => "Gornja Radgona",
+    "SI-030" => "Gornji Grad",
+    "SI-031" => "Gornji Petrovci",
+    "SI-032" => "Grosuplje",
+    "SI-033" => "Salovci",
+    "SI-034" => "Hrastnik",
+    "SI-035" => "Hrpelje-Kozina",
+    "SI-036" => "Idrija",
+    "SI-037" => "Ig",
+    "SI-038" => "Ilirska Bistrica",
+    "SI-039" => "Ivancna Gorica",
+    "SI-040" => "Izola",
+    "SI-041" => "Jesenice",
+    "SI-042" => "Jursinci",
+    "SI-043" => "Kamnik",
+    "SI-044" => "Kanal",
+    "SI-045" => "Kidricevo",
+    "SI-046" => "Kobarid",
+    "SI-047" => "Kobilje",
+    "SI-048" => "Kočevje",
+    "SI-049" => "Komen",
+    "SI-050" => "Koper",
+    "SI-051" => "Kozje",
+    "SI-052" => "Kranj",
+    "SI-053" => "Kranjska Gora",
+    "SI-054" => "Krsko",
+    "SI-055" => "Kungota",
+    "SI-056" => "Kuzma",
+    "SI-057" => "Lasko",
+    "SI-058" => "Lenart",
+    "SI-059" => "Lendava",
+    "SI-060" => "Litija",
+    "SI-061" => "Ljubljana",
+    "SI-062" => "Ljubno",
+    "SI-063" => "Ljutomer",
+    "SI-064" => "Logatec",
+    "SI-065" => "Loska dolina",
+    "SI-066" => "Loski Potok",
+    "SI-067" => "Luce",
+    "SI-068" => "Lukovica",
+    "SI-069" => "Majsperk",
+    "SI-070" => "Maribor",
+    "SI-071" => "Medvode",
+    "SI-072" => "Menges",
+    "SI-073" => "Metlika",
+    "SI-074" => "Mezica",
+    "SI-075" => "Miren-Kostanjevica",
+    "SI-076" => "Mislinja",
+    "SI-077" => "Moravce",
+    "SI-078" => "Moravske Toplice",
+    "SI-079" => "Mozirje",
+    "SI-080" => "Murska Sobota",
+    "SI-081" => "Muta",
+    "SI-082" => "Naklo",
+    "SI-083" => "Nazarje",
+    "SI-084" => "Nova Gorica",
+    "SI-085" => "Novo mesto",
+    "SI-086" => "Odranci",
+    "SI-087" => "Ormoz",
+    "SI-088" => "Osilnica",
+    "SI-089" => "Pesnica",
+    "SI-090" => "Piran",
+    "SI-091" => "Pivka",
+    "SI-092" => "Podcetrtek",
+    "SI-093" => "Podvelka",
+    "SI-094" => "Postojna",
+    "SI-095" => "Preddvor",
+    "SI-096" => "Ptuj",
+    "SI-097" => "Puconci",
+    "SI-098" => "Race-Fram",
+    "SI-099" => "Radece",
+    "SI-100" => "Radenci",
+    "SI-101" => "Radlje ob Dravi",
+    "SI-102" => "Radovljica",
+    "SI-103" => "Ravne na Koroskem",
+    "SI-104" => "Ribnica",
+    "SI-105" => "Rogasovci",
+    "SI-106" => "Rogaska Slatina",
+    "SI-107" => "Rogatec",
+    "SI-108" => "Ruse",
+    "SI-109" => "Semic",
+    "SI-110" => "Sevnica",
+    "SI-111" => "Sezana",
+    "SI-112" => "Slovenj Gradec",
+    "SI-113" => "Slovenska Bistrica",
+    "SI-114" => "Slovenske Konjice",
+    "SI-117" => "Sencur",
+    "SI-118" => "Sentilj",
+    "SI-119" => "Sentjernej",
+    "SI-120" => "Šentjur",
+    "SI-121" => "Skocjan",
+    "SI-122" => "Skofja Loka",
+    "SI-123" => "Skoftjica",
+    "SI-124" => "Smarje pri Jelsah",
+    "SI-125" => "Smartno ob Paki",
+    "SI-126" => "Sostanj",
+    "SI-127" => "Store",
+    "SI-128" => "Tolmin",
+    "SI-129" => "Trbovje",
+    "SI-130" => "Trebnje",
+    "SI-131" => "Trzic",
+    "SI-132" => "Turnisce",
+    "SI-133" => "Velenje",
+    "SI-134" => "Velika Lasce",
+    "SI-135" => "Videm",
+    "SI-136" => "Vipava",
+    "SI-137" => "Vitanje",
+    "SI-138" => "Vodice",
+    "SI-139" => "Vojnik",
+    "SI-140" => "Vrhnika",
+    "SI-141" => "Vuzenica",
+    "SI-142" => "Zagorje ob Savi",
+    "SI-143" => "Zavrc",
+    "SI-144" => "Zrece",
+    "SI-146" => "Zelezniki",
+    "SI-147" => "Ziri",
+    "SI-148" => "Benedikt",
+    "SI-149" => "Bistrica ob Sotli",
+    "SI-150" => "Bloke",
+    "SI-151" => "Braslovce",
+    "SI-152" => "Cankova",
+    "SI-153" => "Cerkvenjak",
+    "SI-154" => "Dobje",
+    "SI-155" => "Dobrna",
+    "SI-157" => "Dolenjske Toplice",
+    "SI-158" => "Grad",
+    "SI-159" => "Hajdina",
+    "SI-160" => "Hoce-Slivnica",
+    "SI-161" => "Hodoš",
+    "SI-162" => "Jorjul",
+    "SI-163" => "Jezersko",
+    "SI-164" => "Komenda",
+    "SI-165" => "Kostel",
+    "SI-166" => "Krizevci",
+    "SI-167" => "Lovrenc na Pohorju",
+    "SI-168" => "Markovci",
+    "SI-169" => "Miklavz na Dravskern polju",
+    "SI-170" => "Mirna Pec",
+    "SI-171" => "Oplotnica",
+    "SI-172" => "Podlehnik",
+    "SI-173" => "Polzela",
+    "SI-174" => "Prebold",
+    "SI-175" => "Prevalje",
+    "SI-176" => "Razkrizje",
+    "SI-177" => "Ribnica na Pohorju",
+    "SI-178" => "Selnica ob Dravi",
+    "SI-179" => "Sodrazica",
+    "SI-180" => "Solcava",
+    "SI-181" => "Sveta Ana",
+    "SI-183" => "Sempeter-Vrtojba",
+    "SI-184" => "Tabor",
+    "SI-186" => "Trzin",
+    "SI-187" => "Velika Polana",
+    "SI-188" => "Verzej",
+    "SI-189" => "Vransko",
+    "SI-190" => "Žalec",
+    "SI-191" => "Zetale",
+    "SI-192" => "Zirovnica",
+    "SI-193" => "Zuzemberk",
+    "SI-194" => "Smartno pri Litiji",
+    "SI-195" => "Apače",
+    "SI-196" => "Cirkulane",
+    "SI-197" => "Kosanjevica na Krki",
+    "SI-198" => "Makole",
+    "SI-199" => "Mokronog-Trebelno",
+    "SI-200" => "Poljčane",
+    "SI-201" => "Renče-Vogrsko",
+    "SI-202" => "Središče ob Dravi",
+    "SI-203" => "Straža",
+    "SI-205" => "Sveti Tomaž",
+    "SI-207" => "Gorje",
+    "SI-208" => "Log-Dragomer",
+    "SI-209" => "Rečica ob Savinji",
+    "SI-211" => "Šentrupert",
+    "SB-CE" => "Central",
+    "SB-CH" => "Choiseul",
+    "SB-CT" => "Honiara, Hoofdstedelijk Gewest",
+    "SB-GU" => "Guadalcanal",
+    "SB-IS" => "Isabel",
+    "SB-ML" => "Malaita",
+    "SB-RB" => "Rennell-Bellona",
+    "SB-TE" => "Temotu",
+    "SB-WE" => "Western",
+    "SO-AW" => "Awdal",
+    "SO-BK" => "Bakool",
+    "SO-BN" => "Banaadir",
+    "SO-BR" => "Bari",
+    "SO-BY" => "Bay",
+    "SO-GA" => "Galguduud",
+    "SO-GE" => "Gedo",
+    "SO-HI" => "Hiiraan",
+    "SO-JD" => "Midden-Juba",
+    "SO-JH" => "Neder-Juba",
+    "SO-MU" => "Mudug",
+    "SO-NU" => "Nugaal",
+    "SO-SA" => "Sanaag",
+    "SO-SD" => "Midden-Shabelle",
+    "SO-SH" => "Neder-Shabelle",
+    "SO-SO" => "Sool",
+    "SO-TO" => "Togdheer",
+    "SO-WO" => "Woqooyi Galbeed",
+    "ZA-EC" => "Oost-Kaap",
+    "ZA-FS" => "Oranje-Vrystaat",
+    "ZA-GP" => "Gauteng",
+    "ZA-KZN" => "Kwazulu-Natal",
+    "ZA-LP" => "Limpopo",
+    "ZA-MP" => "Mpumalanga",
+    "ZA-NC" => "Noord-Kaap",
+    "ZA-NW" => "Noord-West",
+    "ZA-WC" => "West-Kaap",
+    "SS-BN" => "Northern Bahr el Ghazal",
+    "SS-BW" => "Western Bahr el Ghazal",
+    "SS-EC" => "Centraal-Equatoria",
+    "SS-EE" => "Oost-Equatoria",
+    "SS-EW" => "West-Equatoria",
+    "SS-JG" => "Jonquali",
+    "SS-LK" => "Meren",
+    "SS-NU" => "Opper-Nijl",
+    "SS-UY" => "Eenheid",
+    "SS-WR" => "Warab",
+    "ES-A" => "Alicante",
+    "ES-AB" => "Albacete",
+    "ES-AL" => "Almería",
+    "ES-AN" => "Andalusië",
+    "ES-AR" => "Aragón",
+    "ES-AS" => "Asturië, Vorstendom",
+    "ES-AV" => "Ávila",
+    "ES-B" => "Barcelona",
+    "ES-BA" => "Badajoz",
+    "ES-BI" => "Bizkaia",
+    "ES-BU" => "Burgos",
+    "ES-C" => "A Coruña",
+    "ES-CA" => "Cádiz",
+    "ES-CB" => "Cantabrië",
+    "ES-CC" => "Cáceres",
+    "ES-CE" => "Ceuta",
+    "ES-CL" => "Castilla y León",
+    "ES-CM" => "Castilla-La Mancha",
+    "ES-CN" => "Canarische Eilanden",
+    "ES-CO" => "Córdoba",
+    "ES-CR" => "Ciudad Real",
+    "ES-CS" => "Castellón",
+    "ES-CT" => "Catalonië",
+    "ES-CU" => "Cuenca",
+    "ES-EX" => "Extremadura",
+    "ES-GA" => "Galicia",
+    "ES-GC" => "Las Palmas",
+    "ES-GI" => "Gerona",
+    "ES-GR" => "Granada",
+    "ES-GU" => "Guadalajara",
+    "ES-H" => "Huelva",
+    "ES-HU" => "Huesca",
+    "ES-IB" => "Balearen",
+    "ES-J" => "Jaén",
+    "ES-L" => "Lerida",
+    "ES-LE" => "León",
+    "ES-LO" => "La Rioja",
+    "ES-LU" => "Lugo",
+    "ES-M" => "Madrid",
+    "ES-MA" => "Málaga",
+    "ES-MC" => "Murcia, Regio",
+    "ES-MD" => "Madrid, Gemeenschap",
+    "ES-ML" => "Melilla",
+    "ES-MU" => "Murcia",
+    "ES-NA" => "Navarra",
+    "ES-NC" => "Navarra, Forale Gemeenschap",
+    "ES-O" => "Asturië",
+    "ES-OR" => "Ourense",
+    "ES-P" => "Palencia",
+    "ES-PM" => "Balearen",
+    "ES-PO" => "Pontevedra",
+    "ES-PV" => "Baskenland",
+    "ES-RI" => "La Rioja",
+    "ES-S" => "Cantabrië",
+    "ES-SA" => "Salamanca",
+    "ES-SE" => "Sevilla",
+    "ES-SG" => "Segovia",
+    "ES-SO" => "Soria",
+    "ES-SS" => "Gipuzkoa",
+    "ES-T" => "Tarragona",
+    "ES-TE" => "Teruel",
+    "ES-TF" => "Santa Cruz de Tenerife",
+    "ES-TO" => "Toledo",
+    "ES-V" => "Valencia",
+    "ES-VA" => "Valladolid",
+    "ES-VC" => "Valencia, Autonome Gemeenschap",
+    "ES-VI" => "Álava",
+    "ES-Z" => "Zaragoza",
+    "ES-ZA" => "Zamora",
+    "LK-1" => "Westelijke Provincie",
+    "LK-11" => "Colombo",
+    "LK-12" => "Gampaha",
+    "LK-13" => "Kalutara",
+    "LK-2" => "Centrale Provincie",
+    "LK-21" => "Kandy",
+    "LK-22" => "Matale",
+    "LK-23" => "Nuwara Eliya",
+    "LK-3" => "Zuidelijke Provincie",
+    "LK-31" => "Galle",
+    "LK-32" => "Matara",
+    "LK-33" => "Hambantota",
+    "LK-4" => "Noordelijke Provincie",
+    "LK-41" => "Jaffna",
+    "LK-42" => "Kilinochchi",
+    "LK-43" => "Mannar",
+    "LK-44" => "Vavuniya",
+    "LK-45" => "Mullaitivu",
+    "LK-5" => "Oostelijke Provincie",
+    "LK-51" => "Batticaloa",
+    "LK-52" => "Ampara",
+    "LK-53" => "Trincomalee",
+    "LK-6" => "Noordwestelijke Provincie",
+    "LK-61" => "Kurunegala",
+    "LK-62" => "Puttalam",
+    "LK-7" => "Noordelijke Centrale Provincie",
+    "LK-71" => "Anuradhapura",
+    "LK-72" => "Polonnaruwa",
+    "LK-8" => "Provincie Uva",
+    "LK-81" => "Badulla",
+    "LK-82" => "Moneragala",
+    "LK-9" => "Sabaragamuwa",
+    "LK-91" => "Ratnapura",
+    "LK-92" => "Kegalle",
+    "SD-DC" => "Centraal-Darfoer",
+    "SD-DE" => "Oost-Darfoer",
+    "SD-DN" => "Noord-Darfoer",
+    "SD-DS" => "Zuid-Darfoer",
+    "SD-DW" => "West-Darfoer",
+    "SD-GD" => "Al-Qadarif",
+    "SD-GK" => "West-Kordofan",
+    "SD-GZ" => "Al-Jazirah",
+    "SD-KA" => "Kassala",
+    "SD-KH" => "Khartoem",
+    "SD-KN" => "Noord-Kordofan",
+    "SD-KS" => "Zuid-Kordofan",
+    "SD-NB" => "Blauwe Nijl",
+    "SD-NO" => "Northern",
+    "SD-NR" => "Nijl",
+    "SD-NW" => "Witte Nijl",
+    "SD-RS" => "Rode zee",
+    "SD-SI" => "Sennar",
+    "SR-BR" => "Brokopondo",
+    "SR-CM" => "Commewijne",
+    "SR-CR" => "Coronie",
+    "SR-MA" => "Marowijne",
+    "SR-NI" => "Nickerie",
+    "SR-PM" => "Paramaribo",
+    "SR-PR" => "Para",
+    "SR-SA" => "Saramacca",
+    "SR-SI" => "Sipaliwini",
+    "SR-WA" => "Wanica",
+    "SE-AB" => "Stockholms län",
+    "SE-AC" => "Västerbottens län",
+    "SE-BD" => "Norrbottens län",
+    "SE-C" => "Uppsala län",
+    "SE-D" => "Södermanlands län",
+    "SE-E" => "Östergötlands län",
+    "SE-F" => "Jönköpings län",
+    "SE-G" => "Kronobergs län",
+    "SE-H" => "Kalmar län",
+    "SE-I" => "Gotlands län",
+    "SE-K" => "Blekinge län",
+    "SE-M" => "Skåne län",
+    "SE-N" => "Hallands län",
+    "SE-O" => "Västra Götalands län",
+    "SE-S" => "Värmlands län",
+    "SE-T" => "Örebro län",
+    "SE-U" => "Västmanlands län",
+    "SE-W" => "Dalarnas län",
+    "SE-X" => "Gävleborgs län",
+    "SE-Y" => "Västernorrlands län",
+    "SE-Z" => "Jämtlands län",
+    "CH-AG" => "Aargau",
+    "CH-AI" => "Appenzell Innerrhoden",
+    "CH-AR" => "Appenzell Ausserrhoden",
+    "CH-BE" => "Bern",
+    "CH-BL" => "Bazel-Landschap",
+    "CH-BS" => "Basel-Stad",
+    "CH-FR" => "Fribourg",
+    "CH-GE" => "Genève",
+    "CH-GL" => "Glarus",
+    "CH-GR" => "Graubünden",
+    "CH-JU" => "Jura",
+    "CH-LU" => "Luzern",
+    "CH-NE" => "Neuchâtel",
+    "CH-NW" => "Nidwalden",
+    "CH-OW" => "Obwalden",
+    "CH-SG" => "Sankt Gallen",
+    "CH-SH" => "Schaffhausen",
+    "CH-SO" => "Solothurn",
+    "CH-SZ" => "Schwyz",
+    "CH-TG" => "Thurgau",
+    "CH-TI" => "Ticino",
+    "CH-UR" => "Uri",
+    "CH-VD" => "Vaud",
+    "CH-VS" => "Valais",
+    "CH-ZG" => "Zug",
+    "CH-ZH" => "Zürich",
+    "SY-DI" => "Dimashq",
+    "SY-DR" => "Dera",
+    "SY-DY" => "Dayr az Zawr",
+    "SY-HA" => "Al-Hasakah",
+    "SY-HI" => "Homs",
+    "SY-HL" => "Aleppo",
+    "SY-HM" => "Hama",
+    "SY-ID" => "Idlib",
+    "SY-LA" => "Latakia",
+    "SY-QU" => "Quneitra;Al Quneitra",
+    "SY-RA" => "Ar-Raqqah",
+    "SY-RD" => "Rif Dimashq",
+    "SY-SU" => "As-Suwayda",
+    "SY-TA" => "Tartous; Tartus; Tartoes",
+    "TW-CHA" => "Changhua",
+    "TW-CYI" => "Chiayi",
+    "TW-CYQ" => "Chiayi",
+    "TW-HSQ" => "Hsinchu",
+    "TW-HSZ" => "Hsinchu",
+    "TW-HUA" => "Hualien",
+    "TW-ILA" => "Yilan",
+    "TW-KEE" => "Keelung",
+    "TW-KHH" => "Kaohsiung",
+    "TW-KIN" => "Kinmen",
+    "TW-LIE" => "Lienchiang",
+    "TW-MIA" => "Miaoli",
+    "TW-NAN" => "Nantou",
+    "TW-NWT" => "Nieuw Taipei",
+    "TW-PEN" => "Penghu",
+    "TW-PIF" => "Pingtung",
+    "TW-TAO" => "Taoyuan",
+    "TW-TNN" => "Tainan",
+    "TW-TPE" => "Taipei",
+    "TW-TTT" => "Taitung",
+    "TW-TXG" => "Taichung",
+    "TW-YUN" => "Yunlin",
+    "TJ-DU" => "Doesjanbe",
+    "TJ-GB" => "Gorno-Badachsjan",
+    "TJ-KT" => "Khatlon",
+    "TJ-RA" => "districten onder jurisdictie van de republiek",
+    "TJ-SU" => "Sughd",
+    "TZ-01" => "Arusha",
+    "TZ-02" => "Dar es Salaam",
+    "TZ-03" => "Dodoma",
+    "TZ-04" => "Iringa",
+    "TZ-05" => "Kagera",
+    "TZ-06" => "Noord-Pemba",
+    "TZ-07" => "Noord-Zanzibar",
+    "TZ-08" => "Kigoma",
+    "TZ-09" => "Kilimanjaro",
+    "TZ-10" => "Zuid-Pemba",
+    "TZ-11" => "Zuid-Zanzibar, Centraal- en",
+    "TZ-12" => "Lindi",
+    "TZ-13" => "Mara",
+    "TZ-14" => "Mbeya",
+    "TZ-15" => "West-Zanzibar, Stedelijk- en",
+    "TZ-16" => "Morogoro",
+    "TZ-17" => "Mtwara",
+    "TZ-18" => "Mwanza",
+    "TZ-19" => "Pwani",
+    "TZ-20" => "Rukwa",
+    "TZ-21" => "Ruvuma",
+    "TZ-22" => "Shinyanga",
+    "TZ-23" => "Singida",
+    "TZ-24" => "Tabora",
+    "TZ-25" => "Tanga",
+    "TZ-26" => "Manyara",
+    "TZ-27" => "Geita",
+    "TZ-28" => "Katavi",
+    "TZ-29" => "Njombe",
+    "TZ-30" => "Simiyu",
+    "TZ-31" => "Songwe",
+    "TH-11" => "Samut Prakan",
+    "TH-12" => "Nonthaburi",
+    "TH-13" => "Pathum Thani",
+    "TH-14" => "Phra Nakhon Si Ayutthaya",
+    "TH-15" => "Ang Thong",
+    "TH-16" => "Lop Buri",
+    "TH-17" => "Sing Buri",
+    "TH-18" => "Chai Nat",
+    "TH-19" => "Saraburi",
+    "TH-20" => "Chon Buri",
+    "TH-21" => "Rayong",
+    "TH-22" => "Chanthaburi",
+    "TH-23" => "Trat",
+    "TH-24" => "Chachoengsao",
+    "TH-25" => "Prachin Buri",
+    "TH-26" => "Nakhon Nayok",
+    "TH-27" => "Sa Kaeo",
+    "TH-30" => "Nakhon Ratchasima",
+    "TH-31" => "Buri Ram",
+    "TH-32" => "Surin",
+    "TH-33" => "Si Sa Ket",
+    "TH-34" => "Ubon Ratchathani",
+    "TH-35" => "Yasothon",
+    "TH-36" => "Chaiyaphum",
+    "TH-37" => "Amnat Charoen",
+    "TH-39" => "Nong Bua Lam Phu",
+    "TH-40" => "Khon Kaen",
+    "TH-41" => "Udon Thani",
+    "TH-42" => "Loei",
+    "TH-43" => "Nong Khai",
+    "TH-44" => "Maha Sarakham",
+    "TH-45" => "Roi Et",
+    "TH-46" => "Kalasin",
+    "TH-47" => "Sakon Nakhon",
+    "TH-48" => "Nakhon Phanom",
+    "TH-49" => "Mukdahan",
+    "TH-50" => "Chiang Mai",
+    "TH-51" => "Lamphun",
+    "TH-52" => "Lampang",
+    "TH-53" => "Uttaradit",
+    "TH-54" => "Phrae",
+    "TH-55" => "Nan",
+    "TH-56" => "Phayao",
+    "TH-57" => "Chiang Rai",
+    "TH-58" => "Mae Hong Son",
+    "TH-60" => "Nakhon Sawan",
+    "TH-61" => "Uthai Thani",
+    "TH-62" => "Kamphaeng Phet",
+    "TH-63" => "Tak",
+    "TH-64" => "Sukhothai",
+    "TH-65" => "Phitsanulok",
+    "TH-66" => "Phichit",
+    "TH-67" => "Phetchabun",
+    "TH-70" => "Ratchaburi",
+    "TH-71" => "Kanchanaburi",
+    "TH-72" => "Suphan Buri",
+    "TH-73" => "Nakhon Pathom",
+    "TH-74" => "Samut Sakhon",
+    "TH-75" => "Samut Songkhram",
+    "TH-76" => "Phetchaburi",
+    "TH-77" => "Prachuap Khiri Khan",
+    "TH-80" => "Nakhon Si Thammarat",
+    "TH-81" => "Krabi",
+    "TH-82" => "Phangnga",
+    "TH-83" => "Phuket",
+    "TH-84" => "Surat Thani",
+    "TH-85" => "Ranong",
+    "TH-86" => "Chumphon",
+    "TH-90" => "Songkhla",
+    "TH-91" => "Satun",
+    "TH-92" => "Trang",
+    "TH-93" => "Phatthalung",
+    "TH-94" => "Pattani",
+    "TH-95" => "Yala",
+    "TH-96" => "Narathiwat",
+    "TH-S" => "Phatthaya",
+    "TL-AL" => "Aileu",
+    "TL-AN" => "Ainaro",
+    "TL-BA" => "Baucau",
+    "TL-BO" => "Bobonaro",
+    "TL-CO" => "Cova Lima",
+    "TL-DI" => "Dili",
+    "TL-ER" => "Ermera",
+    "TL-LA" => "Lautém",
+    "TL-LI" => "Liquiçá",
+    "TL-MF" => "Manufahi",
+    "TL-MT" => "Manatuto",
+    "TL-OE" => "Oe-Cusse Ambeno",
+    "TL-VI" => "Viqueque",
+    "TG-P" => "Plateaux",
+    "TG-S" => "Savanes",
+    "TO-01" => "'Eua",
+    "TO-02" => "Ha'apai",
+    "TO-03" => "Niuas",
+    "TO-04" => "Tongatapu",
+    "TO-05" => "Vava'u",
+    "TT-ARI" => "Arima",
+    "TT-CHA" => "Chaguanas",
+    "TT-CTT" => "Couva - Tabaquite - Talparo",
+    "TT-DMN" => "Diego Martin",
+    "TT-MRC" => "Rio Claro - Mayaro",
+    "TT-PED" => "Penal - Debe",
+    "TT-POS" => "Port of Spain",
+    "TT-PRT" => "Princes Town",
+    "TT-PTF" => "Point Fortin",
+    "TT-SFO" => "San Fernando",
+    "TT-SGE" => "Sangre Grande",
+    "TT-SIP" => "Siparia",
+    "TT-SJL" => "San Juan - Laventille",
+    "TT-TOB" => "Tobago",
+    "TT-TUP" => "Tunapuna - Piarco",
+    "TN-11" => "Tunis",
+    "TN-13" => "Ben Arous",
+    "TN-14" => "Manouba",
+    "TN-21" => "Nabeul",
+    "TN-22" => "Zaghouan",
+    "TN-23" => "Bizerte",
+    "TN-31" => "Béja",
+    "TN-32" => "Jendouba",
+    "TN-33" => "Le Kef",
+    "TN-34" => "Siliana",
+    "TN-41" => "Kairouan",
+    "TN-42" => "Kasserine",
+    "TN-43" => "Sidi Bouzid",
+    "TN-51" => "Sousse",
+    "TN-52" => "Monastir",
+    "TN-53" => "Mahdia",
+    "TN-61" => "Sfax",
+    "TN-71" => "Gafsa",
+    "TN-72" => "Tozeur",
+    "TN-81" => "Gabès",
+    "TN-83" => "Tataouine",
+    "TR-01" => "Adana",
+    "TR-02" => "Adıyaman",
+    "TR-03" => "Afyonkarahisar",
+    "TR-04" => "Ağrı",
+    "TR-05" => "Amasya",
+    "TR-06" => "Ankara",
+    "TR-07" => "Antalya",
+    "TR-08" => "Artvin",
+    "TR-09" => "Aydin",
+    "TR-10" => "Balikesir",
+    "TR-11" => "Bilecik",
+    "TR-12" => "Bingol",
+    "TR-13" => "Bitlis",
+    "TR-14" => "Bolu",
+    "TR-15" => "Burdur",
+    "TR-16" => "Bursa",
+    "TR-17" => "Çanakkale",
+    "TR-18" => "Çankırı",
+    "TR-19" => "Çorum",
+    "TR-20" => "Denizli",
+    "TR-21" => "Diyarbakır",
+    "TR-22" => "Edirne",
+    "TR-23" => "Elazığ",
+    "TR-24" => "Erzincan",
+    "TR-25" => "Erzurum",
+    "TR-26" => "Eskişehir",
+    "TR-27" => "Gaziantep",
+    "TR-28" => "Giresun",
+    "TR-29" => "Gümüşhane",
+    "TR-30" => "Hakkâri",
+    "TR-31" => "Hatay",
+    "TR-32" => "Isparta",
+    "TR-33" => "Mersin",
+    "TR-34" => "Istanbul",
+    "TR-35" => "Izmir",
+    "TR-36" => "Kars",
+    "TR-37" => "Kastamonu",
+    "TR-38" => "Kayseri",
+    "TR-39" => "Kırklareli",
+    "TR-40" => "Kırşehir",
+    "TR-41" => "Kocaeli",
+    "TR-42" => "Konya",
+    "TR-43" => "Kütahya",
+    "TR-44" => "Malatya",
+    "TR-45" => "Manisa",
+    "TR-46" => "Kahramanmaraş",
+    "TR-47" => "Mardin",
+    "TR-48" => "Muğla",
+    "TR-49" => "Muş",
+    "TR-50" => "Nevşehir",
+    "TR-51" => "Niğde",
+    "TR-52" => "Ordu",
+    "TR-53" => "Rize",
+    "TR-54" => "Sakarya",
+    "TR-55" => "Samsun",
+    "TR-56" => "Siirt",
+    "TR-57" => "Sinop",
+    "TR-58" => "Sivas",
+    "TR-59" => "Tekirdağ",
+    "TR-60" => "Tokat",
+    "TR-61" => "Trabzon",
+    "TR-62" => "Tunceli",
+    "TR-63" => "Şanlıurfa",
+    "TR-64" => "Uşak",
+    "TR-65" => "Van",
+    "TR-66" => "Yozgat",
+    "TR-67" => "Zonguldak",
+    "TR-68" => "Aksaray",
+    "TR-69" => "Bayburt",
+    "TR-70" => "Karaman",
+    "TR-71" => "Kırıkkale",
+    "TR-72" => "Batman",
+    "TR-73" => "Şırnak",
+    "TR-74" => "Bartin",
+    "TR-75" => "Ardahan",
+    "TR-76" => "Iğdır",
+    "TR-77" => "Yalova",
+    "TR-78" => "Karabük",
+    "TR-79" => "Kilis",
+    "TR-80" => "Osmaniye",
+    "TR-81" => "Düzce",
+    "TM-A" => "Ahal",
+    "TM-B" => "Balkan",
+    "TM-D" => "Daşoguz",
+    "TM-L" => "Lebap",
+    "TM-M" => "Mary",
+    "TM-S" => "Aşgabat",
+    "TV-FUN" => "Funafuti",
+    "TV-NIT" => "Niutao",
+    "TV-NKF" => "Nukufetau",
+    "TV-NKL" => "Nukulaelae",
+    "TV-NMA" => "Nanumea",
+    "TV-NUI" => "Nui",
+    "TV-VAI" => "Vaitupu",
+    "UG-101" => "Kalangala",
+    "UG-102" => "Kampala",
+    "UG-103" => "Kiboga",
+    "UG-104" => "Luwero",
+    "UG-105" => "Masaka",
+    "UG-106" => "Mpigi",
+    "UG-107" => "Mubende",
+    "UG-108" => "Mukono",
+    "UG-109" => "Nakasongola",
+    "UG-110" => "Rakai",
+    "UG-111" => "Sembabule",
+    "UG-112" => "Kayunga",
+    "UG-113" => "Wakiso",
+    "UG-114" => "Lyantonde",
+    "UG-115" => "Mityana",
+    "UG-116" => "Nakaseke",
+    "UG-201" => "Bugiri",
+    "UG-202" => "Busia",
+    "UG-203" => "Iganga",
+    "UG-204" => "Jinja",
+    "UG-205" => "Kamuli",
+    "UG-206" => "Kapchorwa",
+    "UG-207" => "Katakwi",
+    "UG-208" => "Kumi",
+    "UG-209" => "Mbale",
+    "UG-210" => "Pallisa",
+    "UG-211" => "Soroti",
+    "UG-212" => "Tororo",
+    "UG-213" => "Kaberamaido",
+    "UG-214" => "Mayuge",
+    "UG-215" => "Sironko",
+    "UG-216" => "Amuria",
+    "UG-217" => "Budaka",
+    "UG-218" => "Bududa",
+    "UG-219" => "Bukedea",
+    "UG-221" => "Butaleja",
+    "UG-222" => "Kaliro",
+    "UG-223" => "Manafwa",
+    "UG-224" => "Namutumba",
+    "UG-301" => "Adjumani",
+    "UG-302" => "Apac",
+    "UG-303" => "Arua",
+    "UG-304" => "Gulu",
+    "UG-305" => "Kitgum",
+    "UG-306" => "Kotido",
+    "UG-307" => "Lira",
+    "UG-308" => "Moroto",
+    "UG-309" => "Moyo",
+    "UG-310" => "Nebbi",
+    "UG-311" => "Nakapiripirit",
+    "UG-312" => "Pader",
+    "UG-313" => "Yumbe",
+    "UG-314" => "Abim",
+    "UG-315" => "Amolatar",
+    "UG-316" => "Amuru",
+    "UG-317" => "Dokolo",
+    "UG-318" => "Kaabong",
+    "UG-319" => "Koboko",
+    "UG-320" => "Maracha",
+    "UG-321" => "Oyam",
+    "UG-401" => "Bundibugyo",
+    "UG-402" => "Bushenyi",
+    "UG-403" => "Hoima",
+    "UG-404" => "Kabale",
+    "UG-405" => "Kabarole",
+    "UG-406" => "Kasese",
+    "UG-407" => "Kibaale",
+    "UG-408" => "Kisoro",
+    "UG-409" => "Masindi",
+    "UG-410" => "Mbarara",
+    "UG-411" => "Ntungamo",
+    "UG-412" => "Rukungiri",
+    "UG-413" => "Kamwenge",
+    "UG-414" => "Kanungu",
+    "UG-415" => "Kyenjojo",
+    "UG-416" => "Bulisa",
+    "UG-417" => "Ibanda",
+    "UG-418" => "Isingiro",
+    "UG-419" => "Kiruhura",
+    "UG-C" => "Central",
+    "UG-E" => "Oost",
+    "UG-N" => "Northern",
+    "UG-W" => "Western",
+    "UA-05" => "Vinnytsja, Provincie",
+    "UA-07" => "Wolynië, Provincie",
+    "UA-09" => "Loehansk, Provindie",
+    "UA-12" => "Dnjepropetrovsk, Provincie",
+    "UA-14" => "Donetsk, Provincie",
+    "UA-18" => "Zjytomyr, Provincie",
+    "UA-21" => "Transkarpatië, Provincie",
+    "UA-23" => "Zaporizja, Provincie",
+    "UA-26" => "Ivano-Frankivsk, Provincie",
+    "UA-30" => "Kiev (stad)",
+    "UA-32" => "Kiev, Provincie",
+    "UA-35" => "Kirovohrad, Provincie",
+    "UA-40" => "Sebastopol",
+    "UA-46" => "Lviv, Provincie",
+    "UA-48" => "Mykolajiv, Provincie",
+    "UA-51" => "Odessa, Provincie",
+    "UA-53" => "Poltava, Provincie",
+    "UA-56" => "Rivne, Provincie",
+    "UA-59" => "Soemy, Provincie",
+    "UA-61" => "Ternopil, Provincie",
+    "UA-63" => "Charkov, Provincie",
+    "UA-65" => "Cherson, Provincie",
+    "UA-68" => "Chmelnytsky, Provincie",
+    "UA-71" => "Tsjerkasy, Provincie",
+    "UA-74" => "Tsjernihiv, Provincie",
+    "UA-77" => "Tsjernivtsi, Provincie",
+    "AE-AJ" => "Ajman",
+    "AE-AZ" => "Abu Dhabi",
+    "AE-DU" => "Dubai",
+    "AE-FU" => "Fujairah",
+    "AE-RK" => "Ras al-Khaimah",
+    "AE-SH" => "Sharjah",
+    "AE-UQ" => "Umm al-Qawain",
+    "GB-ABC" => "Armagh, Banbridge and Craigavon",
+    "GB-ABD" => "Aberdeenshire",
+    "GB-ABE" => "Aberdeen",
+    "GB-AGB" => "Argyll en Bute",
+    "GB-AGY" => "Anglesey",
+    "GB-AND" => "Ards en North Down",
+    "GB-ANN" => "Antrim en Newtownabbey",
+    "GB-ANS" => "Angus",
+    "GB-BAS" => "Bath en Noord-Oost-Somerset",
+    "GB-BBD" => "Blackburn met Darwen",
+    "GB-BCP" => "Bournemouth, Christchurch and Poole",
+    "GB-BDF" => "Bedford",
+    "GB-BDG" => "Barking en Dagenham",
+    "GB-BEN" => "Brent",
+    "GB-BEX" => "Bexley",
+    "GB-BFS" => "Belfast",
+    "GB-BGE" => "Bridgend",
+    "GB-BGW" => "Blaenau Gwent",
+    "GB-BIR" => "Birmingham",
+    "GB-BKM" => "Buckinghamshire",
+    "GB-BNE" => "Barnet",
+    "GB-BNH" => "Brighton en Hove",
+    "GB-BNS" => "Barnsley",
+    "GB-BOL" => "Bolton",
+    "GB-BPL" => "Blackpool",
+    "GB-BRC" => "Bracknell Forest",
+    "GB-BRD" => "Bradford",
+    "GB-BRY" => "Bromley",
+    "GB-BST" => "Bristol, City of",
+    "GB-BUR" => "Bury",
+    "GB-CAM" => "Cambridgeshire",
+    "GB-CAY" => "Caerphilly",
+    "GB-CBF" => "Centraal-Bedfordshire",
+    "GB-CCG" => "Causeway Coast en Glens",
+    "GB-CGN" => "Ceredigion",
+    "GB-CHE" => "Oost-Cheshire",
+    "GB-CHW" => "West-Cheshire en Chester",
+    "GB-CLD" => "Calderdale",
+    "GB-CLK" => "Clackmannanshire",
+    "GB-CMA" => "Cumbria",
+    "GB-CMD" => "Camden",
+    "GB-CMN" => "Carmarthenshire",
+    "GB-CON" => "Cornwall",
+    "GB-COV" => "Coventry",
+    "GB-CRF" => "Cardiff",
+    "GB-CRY" => "Croydon",
+    "GB-CWY" => "Conwy",
+    "GB-DAL" => "Darlington",
+    "GB-DBY" => "Derbyshire",
+    "GB-DEN" => "Denbighshire",
+    "GB-DER" => "Derby",
+    "GB-DEV" => "Devon",
+    "GB-DGY" => "Dumfries en Galloway",
+    "GB-DNC" => "Doncaster",
+    "GB-DND" => "Dundee",
+    "GB-DOR" => "Dorset",
+    "GB-DRS" => "Derry en Strabane",
+    "GB-DUD" => "Dudley",
+    "GB-DUR" => "Durham",
+    "GB-EAL" => "Ealing",
+    "GB-EAY" => "East Ayrshire",
+    "GB-EDH" => "Edinburgh, City of",
+    "GB-EDU" => "East Dunbartonshire",
+    "GB-ELN" => "Oost-Lothian",
+    "GB-ELS" => "Eilean Siar",
+    "GB-ENF" => "Enfield",
+    "GB-ENG" => "Engeland",
+    "GB-ERW" => "East Renfrewshire",
+    "GB-ERY" => "East Riding of Yorkshire",
+    "GB-ESS" => "Essex",
+    "GB-ESX" => "Oost-Sussex",
+    "GB-FAL" => "Falkirk",
+    "GB-FIF" => "Fife",
+    "GB-FLN" => "Flintshire",
+    "GB-FMO" => "Fermanagh en Omagh",
+    "GB-GAT" => "Gateshead",
+    "GB-GLG" => "Glasgow",
+    "GB-GLS" => "Gloucestershire",
+    "GB-GRE" => "Greenwich",
+    "GB-GWN" => "Gwynedd",
+    "GB-HAL" => "Halton",
+    "GB-HAM" => "Hampshire",
+    "GB-HAV" => "Havering",
+    "GB-HCK" => "Hackney",
+    "GB-HEF" => "Herefordshire",
+    "GB-HIL" => "Hillingdon",
+    "GB-HLD" => "Highland",
+    "GB-HMF" => "Hammersmith en Fulham",
+    "GB-HNS" => "Hounslow",
+    "GB-HPL" => "Hartlepool",
+    "GB-HRT" => "Hertfordshire",
+    "GB-HRW" => "Harrow",
+    "GB-HRY" => "Haringey",
+    "GB-IOS" => "Scilly-eiland",
+    "GB-IOW" => "Wight-eiland",
+    "GB-ISL" => "Islington",
+    "GB-IVC" => "Inverclyde",
+    "GB-KEC" => "Kensington en Chelsea",
+    "GB-KEN" => "Kent",
+    "GB-KHL" => "Kingston upon Hull",
+    "GB-KIR" => "Kirklees",
+    "GB-KTT" => "Kingston upon Thames",
+    "GB-KWL" => "Knowsley",
+    "GB-LAN" => "Lancashire",
+    "GB-LBC" => "Lisburn en Castlereagh",
+    "GB-LBH" => "Lambeth",
+    "GB-LCE" => "Leicester",
+    "GB-LDS" => "Leeds",
+    "GB-LEC" => "Leicestershire",
+    "GB-LEW" => "Lewisham",
+    "GB-LIN" => "Lincolnshire",
+    "GB-LIV" => "Liverpool",
+    "GB-LND" => "London, City of",
+    "GB-LUT" => "Luton",
+    "GB-MAN" => "Manchester",
+    "GB-MDB" => "Middlesbrough",
+    "GB-MDW" => "Medway",
+    "GB-MEA" => "Mid and East Antrim",
+    "GB-MIK" => "Milton Keynes",
+    "GB-MLN" => "Midlothian",
+    "GB-MON" => "Monmouthshire",
+    "GB-MRT" => "Merton",
+    "GB-MRY" => "Moray",
+    "GB-MTY" => "Merthyr Tydfil",
+    "GB-MUL" => "Mid Ulster",
+    "GB-NAY" => "Noord-Ayrshire",
+    "GB-NBL" => "Northumberland",
+    "GB-NEL" => "North East Lincolnshire",
+    "GB-NET" => "Newcastle upon Tyne",
+    "GB-NFK" => "Norfolk",
+    "GB-NGM" => "Nottingham",
+    "GB-NIR" => "Noord-Ierland",
+    "GB-NLK" => "North Lanarkshire",
+    "GB-NLN" => "North Lincolnshire",
+    "GB-NMD" => "Newry, Mourne and Down",
+    "GB-NSM" => "Noord-Somerset",
+    "GB-NTL" => "Neath Port Talbot",
+    "GB-NTT" => "Nottinghamshire",
+    "GB-NTY" => "Noord-Tyneside",
+    "GB-NWM" => "Newham",
+    "GB-NWP" => "Newport",
+    "GB-NYK" => "North Yorkshire",
+    "GB-OLD" => "Oldham",
+    "GB-ORK" => "Orkney-eilanden",
+    "GB-OXF" => "Oxfordshire",
+    "GB-PEM" => "Pembrokeshire",
+    "GB-PKN" => "Perth en Kinross",
+    "GB-PLY" => "Plymouth",
+    "GB-POR" => "Portsmouth",
+    "GB-POW" => "Powys",
+    "GB-PTE" => "Peterborough",
+    "GB-RCC" => "Redcar en Cleveland",
+    "GB-RCH" => "Rochdale",
+    "GB-RCT" => "Rhondda Cynon Taf",
+    "GB-RDB" => "Redbridge",
+    "GB-RDG" => "Reading",
+    "GB-RFW" => "Renfrewshire",
+    "GB-RIC" => "Richmond upon Thames",
+    "GB-ROT" => "Rotherham",
+    "GB-RUT" => "Rutland",
+    "GB-SAW" => "Sandwell",
+    "GB-SAY" => "South Ayrshire",
+    "GB-SCB" => "Scottish Borders",
+    "GB-SCT" => "Schotland",
+    "GB-SFK" => "Suffolk",
+    "GB-SFT" => "Sefton",
+    "GB-SGC" => "South Gloucestershire",
+    "GB-SHF" => "Sheffield",
+    "GB-SHN" => "St. Helens",
+    "GB-SHR" => "Shropshire",
+    "GB-SKP" => "Stockport",
+    "GB-SLF" => "Salford",
+    "GB-SLG" => "Slough",
+    "GB-SLK" => "South Lanarkshire",
+    "GB-SND" => "Sunderland",
+    "GB-SOL" => "Solihull",
+    "GB-SOM" => "Somerset",
+    "GB-SOS" => "Southend aan Zee",
+    "GB-SRY" => "Surrey",
+    "GB-STE" => "Stoke aan Trent",
+    "GB-STG" => "Stirling",
+    "GB-STH" => "Southampton",
+    "GB-STN" => "Sutton",
+    "GB-STS" => "Staffordshire",
+    "GB-STT" => "Stockton aan Tees",
+    "GB-STY" => "Zuid-Tyneside",
+    "GB-SWA" => "Swansea",
+    "GB-SWD" => "Swindon",
+    "GB-SWK" => "Southwark",
+    "GB-TAM" => "Tameside",
+    "GB-TFW" => "Telford en Wrekin",
+    "GB-THR" => "Thurrock",
+    "GB-TOB" => "Torbay",
+    "GB-TOF" => "Torfaen",
+    "GB-TRF" => "Trafford",
+    "GB-TWH" => "Tower Hamlets",
+    "GB-VGL" => "Vale of Glamorgan",
+    "GB-WAR" => "Warwickshire",
+    "GB-WBK" => "Westberkshire",
+    "GB-WDU" => "Westdunbartonshire",
+    "GB-WFT" => "Waltham Forest",
+    "GB-WGN" => "Wigan",
+    "GB-WIL" => "Wiltshire",
+    "GB-WKF" => "Wakefield",
+    "GB-WLL" => "Walsall",
+    "GB-WLN" => "West-Lothian",
+    "GB-WLS" => "Wales",
+    "GB-WLV" => "Wolverhampton",
+    "GB-WND" => "Wandsworth",
+    "GB-WNM" => "Windsor en Maidenhead",
+    "GB-WOK" => "Wokingham",
+    "GB-WOR" => "Worcestershire",
+    "GB-WRL" => "Wirral",
+    "GB-WRT" => "Warrington",
+    "GB-WRX" => "Wrexham",
+    "GB-WSM" => "Westminster",
+    "GB-WSX" => "West-Sussex",
+    "GB-YOR" => "York",
+    "GB-ZET" => "Shetland-eilanden",
+    "US-AK" => "Alaska",
+    "US-AL" => "Alabama",
+    "US-AR" => "Arkansas",
+    "US-AS" => "Amerikaans-Samoa",
+    "US-AZ" => "Arizona",
+    "US-CA" => "Californië",
+    "US-CO" => "Colorado",
+    "US-CT" => "Connecticut",
+    "US-DC" => "District van Columbia",
+    "US-DE" => "Delaware",
+    "US-FL" => "Florida",
+    "US-GA" => "Georgia",
+    "US-GU" => "Guam",
+    "US-HI" => "Hawaii",
+    "US-IA" => "Iowa",
+    "US-ID" => "Idaho",
+    "US-IL" => "Illinois",
+    "US-IN" => "Indiana",
+    "US-KS" => "Kansas",
+    "US-KY" => "Kentucky",
+    "US-LA" => "Louisiana",
+    "US-MA" => "Massachusetts",
+    "US-MD" => "Maryland",
+    "US-ME" => "Maine",
+    "US-MI" => "Michigan",
+    "US-MN" => "Minnesota",
+    "US-MO" => "Missouri",
+    "US-MP" => "Noordelijke Marianen",
+    "US-MS" => "Mississippi",
+    "US-MT" => "Montana",
+    "US-NC" => "Noord-Carolina",
+    "US-ND" => "Noord-Dakota",
+    "US-NE" => "Nebraska",
+    "US-NH" => "New Hampshire",
+    "US-NJ" => "New Jersey",
+    "US-NM" => "New Mexico",
+    "US-NV" => "Nevada",
+    "US-NY" => "New York",
+    "US-OH" => "Ohio",
+    "US-OK" => "Oklahoma",
+    "US-OR" => "Oregon",
+    "US-PA" => "Pennsylvania",
+    "US-PR" => "Puerto Rico",
+    "US-RI" => "Rhode-eiland",
+    "US-SC" => "Zuid-Carolina",
+    "US-SD" => "Zuid-Dakota",
+    "US-TN" => "Tennessee",
+    "US-TX" => "Texas",
+    "US-UM" => "Kleine afgelegen eilanden van de Verenigde Staten",
+    "US-UT" => "Utah",
+    "US-VA" => "Virginia",
+    "US-VI" => "Maagdeneilanden, Amerikaanse",
+    "US-VT" => "Vermont",
+    "US-WA" => "Washington",
+    "US-WI" => "Wisconsin",
+    "US-WV" => "West-Virginia",
+    "US-WY" => "Wyoming",
+    "UM-67" => "Johnston-atol",
+    "UM-71" => "Midway-eilanden",
+    "UM-76" => "Navassa-eiland",
+    "UM-79" => "Wake-eiland",
+    "UM-81" => "Baker-eiland",
+    "UM-84" => "Howland-eiland",
+    "UM-86" => "Jarvis-eiland",
+    "UM-89" => "Kingman Rif",
+    "UM-95" => "Palmyra-atol",
+    "UY-AR" => "Artigas",
+    "UY-CA" => "Canelones",
+    "UY-CL" => "Cerro Largo",
+    "UY-CO" => "Colonia",
+    "UY-DU" => "Durazno",
+    "UY-FD" => "Florida",
+    "UY-FS" => "Flores",
+    "UY-LA" => "Lavalleja",
+    "UY-MA" => "Maldonado",
+    "UY-MO" => "Montevideo",
+    "UY-PA" => "Paysandú",
+    "UY-RN" => "Río Negro",
+    "UY-RO" => "Rocha",
+    "UY-RV" => "Rivera",
+    "UY-SA" => "Salto",
+    "UY-SJ" => "San José",
+    "UY-SO" => "Soriano",
+    "UY-TA" => "Tacuarembó",
+    "UY-TT" => "Treinta y Tres",
+    "UZ-AN" => "Andijon",
+    "UZ-BU" => "Buchara",
+    "UZ-JI" => "Jizzax",
+    "UZ-NG" => "Namangan",
+    "UZ-NW" => "Navoiy",
+    "UZ-QA" => "Qashqadaryo",
+    "UZ-SA" => "Samarkand",
+    "UZ-SI" => "Sirdaryo",
+    "UZ-SU" => "Surxondaryo",
+    "UZ-TK" => "Toshkent",
+    "UZ-TO" => "Toshkent",
+    "UZ-XO" => "Xorazm",
+    "VU-MAP" => "Malampa",
+    "VU-PAM" => "Penama",
+    "VU-SAM" => "Sanma",
+    "VU-SEE" => "Shefa",
+    "VU-TAE" => "Tafea",
+    "VU-TOB" => "Torba",
+    "VE-A" => "Bogotá, Hoofdstedelijk Gewest van",
+    "VE-B" => "Anzoátegui",
+    "VE-C" => "Apure",
+    "VE-D" => "Aragua",
+    "VE-E" => "Barinas",
+    "VE-F" => "Bolívar",
+    "VE-G" => "Carabobo",
+    "VE-H" => "Cojedes",
+    "VE-I" => "Falcón",
+    "VE-J" => "Guárico",
+    "VE-K" => "Lara",
+    "VE-L" => "Mérida",
+    "VE-M" => "Miranda",
+    "VE-N" => "Monagas",
+    "VE-O" => "Nueva Esparta",
+    "VE-P" => "Portuguesa",
+    "VE-R" => "Sucre",
+    "VE-S" => "Táchira",
+    "VE-T" => "Trujillo",
+    "VE-U" => "Yaracuy",
+    "VE-V" => "Zulia",
+    "VE-W" => "Federale Afhankelijkheden",
+    "VE-Y" => "Delta Amacuro",
+    "VE-Z" => "Amazonas",
+    "VN-01" => "Lai Chau",
+    "VN-02" => "Lao Cai",
+    "VN-03" => "Ha Giang",
+    "VN-04" => "Cao Bang",
+    "VN-05" => "Son La",
+    "VN-06" => "Yen Bai",
+    "VN-07" => "Tuyen Quang",
+    "VN-09" => "Lang Son",
+    "VN-13" => "Quang Ninh",
+    "VN-18" => "Ninh Binh",
+    "VN-20" => "Thai Binh",
+    "VN-21" => "Thanh Hoa",
+    "VN-22" => "Nghe An",
+    "VN-24" => "Quang Binh",
+    "VN-25" => "Quang Tri",
+    "VN-26" => "Thua Thien-Hue",
+    "VN-27" => "Quang Nam",
+    "VN-28" => "Kon Tum",
+    "VN-29" => "Quang Ngai",
+    "VN-30" => "Gia Lai",
+    "VN-31" => "Binh Dinh",
+    "VN-32" => "Phu Yen",
+    "VN-34" => "Khanh Hoa",
+    "VN-35" => "Lam Dong",
+    "VN-36" => "Ninh Thuan",
+    "VN-37" => "Tay Ninh",
+    "VN-39" => "Dong Nai",
+    "VN-40" => "Binh Thuan",
+    "VN-41" => "Long An",
+    "VN-44" => "An Giang",
+    "VN-45" => "Dong Thap",
+    "VN-46" => "Tien Giang",
+    "VN-49" => "Vinh Long",
+    "VN-50" => "Ben Tre",
+    "VN-51" => "Tra Vinh",
+    "VN-52" => "Soc Trang",
+    "VN-53" => "Bac Kan",
+    "VN-54" => "Bac Giang",
+    "VN-55" => "Bac Lieu",
+    "VN-56" => "Bac Ninh",
+    "VN-57" => "Binh Duong",
+    "VN-58" => "Binh Phuoc",
+    "VN-59" => "Ca Mau",
+    "VN-63" => "Ha Nam",
+    "VN-66" => "Hung Yen",
+    "VN-67" => "Nam Dinh",
+    "VN-68" => "Phu Tho",
+    "VN-69" => "Thai Nguyen",
+    "VN-70" => "Vinh Phuc",
+    "VN-71" => "Dien Bien",
+    "VN-72" => "Dak Nong",
+    "VN-73" => "Hau Giang",
+    "VN-CT" => "Can Tho",
+    "VN-DN" => "Da Nang (stad)",
+    "VN-HN" => "Hanoi",
+    "VN-HP" => "Hai Phong",
+    "VN-SG" => "Ho Chi Minhstad",
+    "WF-AL" => "Alo",
+    "WF-SG" => "Sigave",
+    "WF-UV" => "Uvea",
+    "YE-AB" => "Abyan",
+    "YE-DH" => "Dhamar",
+    "YE-HD" => "Hadramaut",
+    "YE-HJ" => "Hajjah",
+    "YE-HU" => "Al Hudaydah",
+    "YE-IB" => "Ibb",
+    "YE-JA" => "Al Jawf",
+    "YE-LA" => "Lahij",
+    "YE-MA" => "Ma'rib",
+    "YE-MR" => "Al Mahrah",
+    "YE-MW" => "Al Mahwit",
+    "YE-RA" => "Raymah",
+    "YE-SA" => "Amanat al-Asimah (stad Sanaa)",
+    "YE-SD" => "Sa'dah",
+    "YE-SH" => "Shabwah",
+    "YE-SN" => "Sanaa",
+    "YE-SU" => "Socotra",
+    "YE-TA" => "Ta'izz",
+    "ZM-01" => "Western",
+    "ZM-02" => "Central",
+    "ZM-03" => "Oost",
+    "ZM-04" => "Luapula",
+    "ZM-05" => "Northern",
+    "ZM-06" => "Noord-West",
+    "ZM-07" => "Zuid",
+    "ZM-08" => "Copperbelt; Kopergordel",
+    "ZM-09" => "Lusaka",
+    "ZW-BU" => "Bulawayo",
+    "ZW-HA" => "Harare",
+    "ZW-MA" => "Manicaland",
+    "ZW-MC" => "Mashonaland Centraal",
+    "ZW-ME" => "Mashonaland Oost",
+    "ZW-MI" => "Midlands",
+    "ZW-MN" => "Matabeleland Noord",
+    "ZW-MS" => "Matabeleland Zuid",
+    "ZW-MV" => "Masvingo",
+    "ZW-MW" => "Mashonaland West",
+};
+
+///Dutch former country names by alpha-4 code
+pub const FORMER_NAME_MAP: Map<&str, &str> = phf_map! {
+    "BQAQ" => "Brits Antarctisch Territorium",
+    "BUMM" => "Birma, Socialistische Republiek van de Unie van",
+    "BYAA" => "Wit-Russische Socialistische Sovjetrepubliek; Belarussische Socialistische Sovjetrepubliek",
+    "CTKI" => "Kanton en Enderbury Eilanden",
+    "CSHH" => "Tsjecho-Slowakije; Tsjecho-Slowaakse Socialistische Republiek",
+    "DYBJ" => "Dahomey",
+    "NQAQ" => "Dronning Maud-land",
+    "TPTL" => "Oost-Timor",
+    "FXFR" => "Frankrijk, Metropolitaans",
+    "AIDJ" => "Frans Afars en Issas",
+    "FQHH" => "Franse Zuidelijke en Antarctische Gebieden",
+    "DDDE" => "Duitse Democratische Republiek",
+    "GEHH" => "Gilbert- en Ellice-eilanden",
+    "JTUM" => "Johnston-eiland",
+    "MIUM" => "Midway-eilanden",
+    "ANHH" => "Nederlandse Antillen",
+    "NTHH" => "Neutrale Zone",
+    "NHVU" => "Nieuwe Hebriden",
+    "PCHH" => "Pacifische Eilanden, Trustschap van de",
+    "PZPA" => "Panamakanaalzone",
+    "CSXX" => "Servië en Montenegro",
+    "SKIN" => "Sikkim",
+    "RHZW" => "Zuid-Rhodesië",
+    "PUUM" => "Verenigde Staten Diverse Pacifische Eilanden",
+    "HVBF" => "Opper-Volta, Republiek; Boven-Volta, Republiek",
+    "SUHH" => "USSR; Unie van Socialistische Sovjetrepublieken",
+    "VDVN" => "Vietnam, Democratische Republiek",
+    "WKUM" => "Wake-eiland",
+    "YDYE" => "Yemen, Republiek",
+    "YUCS" => "Joegoslavië, (Socialistische) Federale Republiek",
+    "ZRCD" => "Zaïre, Republiek",
+};
@@ -0,0 +1,4610 @@
+use phf::phf_map;
+use phf::Map;
+
+///Polish country names by alpha-2 code
+pub const COUNTRY_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF" => "Afganistan",
+    "AX" => "Wyspy Alandzkie",
+    "AL" => "Albania",
+    "DZ" => "Algieria",
+    "AS" => "Samoa Amerykańskie",
+    "AD" => "Andora",
+    "AO" => "Angola",
+    "AI" => "Anguilla",
+    "AQ" => "Antarktyka",
+    "AG" => "Antigua i Barbuda",
+    "AR" => "Argentyna",
+    "AM" => "Armenia",
+    "AW" => "Aruba",
+    "AU" => "Australia",
+    "AT" => "Austria",
+    "AZ" => "Azerbejdżan",
+    "BS" => "Bahamy",
+    "BH" => "Bahrajn",
+    "BD" => "Bangladesz",
+    "BB" => "Barbados",
+    "BY" => "Białoruś",
+    "BE" => "Belgia",
+    "BZ" => "Belize",
+    "BJ" => "Benin",
+    "BM" => "Bermudy",
+    "BT" => "Bhutan",
+    "BO" => "Boliwia - Wielonarodowe Państwo",
+    "BQ" => "Bonaire, Sint Eustatius i Saba",
+    "BA" => "Bośnia i Hercegowina",
+    "BW" => "Botswana",
+    "BV" => "Wyspa Bouveta",
+    "BR" => "Brazylia",
+    "IO" => "Brytyjskie Terytorium Oceanu Indyjskiego",
+    "BN" => "Państwo Brunei",
+    "BG" => "Bułgaria",
+    "BF" => "Burkina Faso",
+    "BI" => "Burundi",
+    "CV" => "Republika Zielonego Przylądka",
+    "KH" => "Kambodża",
+    "CM" => "Kamerun",
+    "CA" => "Kanada",
+    "KY" => "Kajmany",
+    "CF" => "Republika Środkowoafrykańska",
+    "TD" => "Czad",
+    "CL" => "Chile",
+    "CN" => "Chiny",
+    "CX" => "Wyspa Bożego Narodzenia",
+    "CC" => "Wyspy Kokosowe (Wyspy Keelinga)",
+    "CO" => "Kolumbia",
+    "KM" => "Komory",
+    "CG" => "Kongo",
+    "CD" => "Kongo, Demokratyczna Republika Konga",
+    "CK" => "Wyspy Cooka",
+    "CR" => "Kostaryka",
+    "CI" => "Wybrzeże Kości Słoniowej",
+    "HR" => "Chorwacja",
+    "CU" => "Kuba",
+    "CW" => "Curaçao",
+    "CY" => "Cypr",
+    "CZ" => "Czechy",
+    "DK" => "Dania",
+    "DJ" => "Dżibuti",
+    "DM" => "Dominika",
+    "DO" => "Republika Dominikańska",
+    "EC" => "Ekwador",
+    "EG" => "Egipt",
+    "SV" => "Salwador",
+    "GQ" => "Gwinea Równikowa",
+    "ER" => "Erytrea",
+    "EE" => "Estonia",
+    "SZ" => "Eswatini",
+    "ET" => "Etiopia",
+    "FK" => "Falklandy (Malwiny)",
+    "FO" => "Wyspy Owcze",
+    "FJ" => "Fidżi",
+    "FI" => "Finlandia",
+    "FR" => "Francja",
+    "GF" => "Gujana Francuska",
+    "PF" => "Polinezja Francuska",
+    "TF" => "Francuskie Terytoria Południowe",
+    "GA" => "Gabon",
+    "GM" => "Gambia",
+    "GE" => "Gruzja",
+    "DE" => "Niemcy",
+    "GH" => "Ghana",
+    "GI" => "Gibraltar",
+    "GR" => "Grecja",
+    "GL" => "Grenlandia",
+    "GD" => "Grenada",
+    "GP" => "Gwadelupa",
+    "GU" => "Guam",
+    "GT" => "Gwatemala",
+    "GG" => "Guernsey",
+    "GN" => "Gwinea",
+    "GW" => "Gwinea Bissau",
+    "GY" => "Gujana",
+    "HT" => "Haiti",
+    "HM" => "Wyspy Heard i McDonalda",
+    "VA" => "Państwo Watykańskie (Stolica Apostolska)",
+    "HN" => "Honduras",
+    "HK" => "Hongkong",
+    "HU" => "Węgry",
+    "IS" => "Islandia",
+    "IN" => "Indie",
+    "ID" => "Indonezja",
+    "IR" => "Iran, Islamska Republika",
+    "IQ" => "Irak",
+    "IE" => "Irlandia",
+    "IM" => "Wyspa Man",
+    "IL" => "Izrael",
+    "IT" => "Włochy",
+    "JM" => "Jamajka",
+    "JP" => "Japonia",
+    "JE" => "Jersey",
+    "JO" => "Jordania",
+    "KZ" => "Kazachstan",
+    "KE" => "Kenia",
+    "KI" => "Kiribati",
+    "KP" => "Korea - Republika Ludowo-Demokratyczna",
+    "KR" => "Republika Korei",
+    "KW" => "Kuwejt",
+    "KG" => "Kirgistan",
+    "LA" => "Laotańska Republika Ludowo-Demokratyczna",
+    "LV" => "Łotwa",
+    "LB" => "Liban",
+    "LS" => "Lesotho",
+    "LR" => "Liberia",
+    "LY" => "Libia",
+    "LI" => "Liechtenstein",
+    "LT" => "Litwa",
+    "LU" => "Luksemburg",
+    "MO" => "Makau",
+    "MG" => "Madagaskar",
+    "MW" => "Malawi",
+    "MY" => "Malezja",
+    "MV" => "Malediwy",
+    "ML" => "Mali",
+    "MT" => "Malta",
+    "MH" => "Wyspy Marshalla",
+    "MQ" => "Martynika",
+    "MR" => "Mauretania",
+    "MU" => "Mauritius",
+    "YT" => "Majotta",
+    "MX" => "Meksyk",
+    "FM" => "Mikronezja",
+    "MD" => "Mołdawia - Republika",
+    "MC" => "Monako",
+    "MN" => "Mongolia",
+    "ME" => "Czarnogóra",
+    "MS" => "Montserrat",
+    "MA" => "Maroko",
+    "MZ" => "Mozambik",
+    "MM" => "Mjanma",
+    "NA" => "Namibia",
+    "NR" => "Nauru",
+    "NP" => "Nepal",
+    "NL" => "Holandia",
+    "NC" => "Nowa Kaledonia",
+    "NZ" => "Nowa Zelandia",
+    "NI" => "Nikaragua",
+    "NE" => "Niger",
+    "NG" => "Nigeria",
+    "NU" => "Niue",
+    "NF" => "Wyspy Norfolk",
+    "MK" => "Macedonia Północna",
+    "MP" => "Mariany Północne",
+    "NO" => "Norwegia",
+    "OM" => "Oman",
+    "PK" => "Pakistan",
+    "PW" => "Palau",
+    "PS" => "Palestyna (państwo)",
+    "PA" => "Panama",
+    "PG" => "Papua-Nowa Gwinea",
+    "PY" => "Paragwaj",
+    "PE" => "Peru",
+    "PH" => "Filipiny",
+    "PN" => "Pitcairn",
+    "PL" => "Polska",
+    "PT" => "Portugalia",
+    "PR" => "Portoryko",
+    "QA" => "Katar",
+    "RE" => "Reunion",
+    "RO" => "Rumunia",
+    "RU" => "Federacja Rosyjska",
+    "RW" => "Ruanda",
+    "BL" => "Saint-Barthélemy",
+    "SH" => "Wyspa Świętej Heleny, Wyspa Wniebowstąpienia i Tristan da Cunha",
+    "KN" => "Saint Kitts i Nevis",
+    "LC" => "Saint Lucia",
+    "MF" => "Saint-Martin (część francuska)",
+    "PM" => "Saint-Pierre i Miquelon",
+    "VC" => "Saint Vincent i Grenadyny",
+    "WS" => "Samoa",
+    "SM" => "San Marino",
+    "ST" => "Wyspy Świętego Tomasza i Książęca",
+    "SA" => "Arabia Saudyjska",
+    "SN" => "Senegal",
+    "RS" => "Serbia",
+    "SC" => "Seszele",
+    "SL" => "Sierra Leone",
+    "SG" => "Singapur",
+    "SX" => "Sint Maarten (część holenderska)",
+    "SK" => "Słowacja",
+    "SI" => "Słowenia",
+    "SB" => "Wyspy Salomona",
+    "SO" => "Somalia",
+    "ZA" => "Południowa Afryka",
+    "GS" => "Georgia Południowa i Sandwich Południowy",
+    "SS" => "Sudan Południowy",
+    "ES" => "Hiszpania",
+    "LK" => "Sri Lanka",
+    "SD" => "Sudan",
+    "SR" => "Surinam",
+    "SJ" => "Svalbard i Jan Mayen",
+    "SE" => "Szwecja",
+    "CH" => "Szwajcaria",
+    "SY" => "Syryjska Republika Arabska",
+    "TW" => "Tajwan, Prowincja Chińska",
+    "TJ" => "Tadżykistan",
+    "TZ" => "Tanzania, Zjednoczona Republika",
+    "TH" => "Tajlandia",
+    "TL" => "Timor Wschodni",
+    "TG" => "Togo",
+    "TK" => "Tokelau",
+    "TO" => "Tonga",
+    "TT" => "Trynidad i Tobago",
+    "TN" => "Tunezja",
+    "TR" => "Turcja",
+    "TM" => "Turkmenistan",
+    "TC" => "Turks i Caicos",
+    "TV" => "Tuvalu",
+    "UG" => "Uganda",
+    "UA" => "Ukraina",
+    "AE" => "Zjednoczone Emiraty Arabskie",
+    "GB" => "Wielka Brytania",
+    "US" => "Stany Zjednoczone",
+    "UM" => "Dalekie Wyspy Mniejsze Stanów Zjednoczonych",
+    "UY" => "Urugwaj",
+    "UZ" => "Uzbekistan",
+    "VU" => "Vanuatu",
+    "VE" => "Wenezuela - Boliwariańska Republika",
+    "VN" => "Wietnam",
+    "VG" => "Brytyjskie Wyspy Dziewicze",
+    "VI" => "Wyspy Dziewicze Stanów Zjednoczonych",
+    "WF" => "Wallis i Futuna",
+    "EH" => "Sahara Zachodnia",
+    "YE" => "Jemen",
+    "ZM" => "Zambia",
+    "ZW" => "Zimbabwe",
+};
+
+///Polish subdivision names by ISO 3166-2 code, 4316 of 5046
+pub const SUBDIVISION_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF-BAL" => "Balch",
+    "AF-BAM" => "Bamian",
+    "AF-BDG" => "Badghis",
+    "AF-BDS" => "Badachszan",
+    "AF-BGL" => "Baghlan",
+    "AF-DAY" => "Dajkondi",
+    "AF-FRA" => "Farah",
+    "AF-FYB" => "Farjab",
+    "AF-GHA" => "Ghazni",
+    "AF-GHO" => "Ghor",
+    "AF-HEL" => "Helmand",
+    "AF-HER" => "Herat",
+    "AF-JOW" => "Dżozdżan",
+    "AF-KAB" => "Kabul",
+    "AF-KAN" => "Kandahar",
+    "AF-KAP" => "Kapisa",
+    "AF-KDZ" => "Kunduz",
+    "AF-KHO" => "Chost",
+    "AF-KNR" => "Kunar",
+    "AF-LAG" => "Laghman",
+    "AF-LOG" => "Logar",
+    "AF-NAN" => "Nangarhar",
+    "AF-NIM" => "Nimruz",
+    "AF-NUR" => "Nuristan",
+    "AF-PAN" => "Panczszir",
+    "AF-PAR" => "Parwan",
+    "AF-PIA" => "Paktija",
+    "AF-PKA" => "Paktika",
+    "AF-SAM" => "Samangan",
+    "AF-SAR" => "Sar-e Pol",
+    "AF-TAK" => "Tachar",
+    "AF-URU" => "Oruzgan",
+    "AF-WAR" => "Wardak",
+    "AF-ZAB" => "Zabol",
+    "AL-01" => "Berat",
+    "AL-02" => "Durrës",
+    "AL-03" => "Elbasan",
+    "AL-04" => "Fier",
+    "AL-05" => "Gjirokastra",
+    "AL-06" => "Korcza",
+    "AL-07" => "Kukës",
+    "AL-08" => "Lezha",
+    "AL-09" => "Dibra",
+    "AL-10" => "Szkodra",
+    "AL-11" => "Tirana",
+    "AL-12" => "Vlora",
+    "DZ-01" => "Adrar",
+    "DZ-02" => "Asz-Szalif",
+    "DZ-03" => "Al-Aghwat",
+    "DZ-04" => "Umm al-Bawaki",
+    "DZ-05" => "Batna",
+    "DZ-06" => "Bidżaja",
+    "DZ-07" => "Biskira",
+    "DZ-08" => "Baszszar",
+    "DZ-09" => "Al-Bulajda",
+    "DZ-10" => "Al-Buwajra",
+    "DZ-11" => "Tamanrasset",
+    "DZ-12" => "Tibissa",
+    "DZ-13" => "Tilimsan",
+    "DZ-14" => "Tijarat",
+    "DZ-15" => "Tizi Wuzu",
+    "DZ-16" => "Algier",
+    "DZ-17" => "Dżilfa",
+    "DZ-18" => "Dżidżal",
+    "DZ-19" => "Satif",
+    "DZ-20" => "Saida",
+    "DZ-21" => "Sukajkida",
+    "DZ-22" => "Sidi Bu-l-Abbas",
+    "DZ-23" => "Annaba",
+    "DZ-24" => "Kalima",
+    "DZ-25" => "Konstantyna",
+    "DZ-26" => "Al-Midija",
+    "DZ-27" => "Mustaghanam",
+    "DZ-28" => "Al-Masila",
+    "DZ-29" => "Muaskar",
+    "DZ-30" => "Warkala",
+    "DZ-31" => "Oran",
+    "DZ-32" => "Al-Bajad",
+    "DZ-33" => "Illizi",
+    "DZ-34" => "Burdż Bu Urajridż",
+    "DZ-35" => "Bumardas",
+    "DZ-36" => "At-Tarif",
+    "DZ-37" => "Tinduf",
+    "DZ-38" => "Tisamsilt",
+    "DZ-39" => "Al-Wadi",
+    "DZ-40" => "Khenchela",
+    "DZ-41" => "Suk Ahras",
+    "DZ-42" => "Tibaza",
+    "DZ-43" => "Mila",
+    "DZ-44" => "Ajn ad-Dafla",
+    "DZ-45" => "Naama",
+    "DZ-46" => "Ahn Tumuszanat",
+    "DZ-47" => "Ghardaja",
+    "DZ-48" => "Ghulajzan",
+    "AD-02" => "Canillo",
+    "AD-03" => "Encamp",
+    "AD-04" => "La Massana",
+    "AD-05" => "Ordino",
+    "AD-06" => "Sant Julià de Lòria",
+    "AD-07" => "Andora",
+    "AD-08" => "Escaldes-Engordany",
+    "AO-BGO" => "Bengo",
+    "AO-BGU" => "Benguela",
+    "AO-BIE" => "Bié",
+    "AO-CAB" => "Kabinda",
+    "AO-CCU" => "Cuando-Cubango",
+    "AO-CNN" => "Kunene",
+    "AO-CNO" => "Kwanza Północna",
+    "AO-CUS" => "Kwanza Południowa",
+    "AO-HUA" => "Huambo",
+    "AO-HUI" => "Huíla",
+    "AO-LNO" => "Lunda Północna",
+    "AO-LSU" => "Lunda Południowa",
+    "AO-LUA" => "Luanda",
+    "AO-MAL" => "Malanje",
+    "AO-MOX" => "Moxico",
+    "AO-NAM" => "Namibe",
+    "AO-UIG" => "Uíge",
+    "AO-ZAI" => "Zair",
+    "AG-03" => "Saint George",
+    "AG-04" => "Saint John",
+    "AG-05" => "Saint Mary",
+    "AG-06" => "Saint Paul",
+    "AG-07" => "Saint Peter",
+    "AG-08" => "Saint Philip",
+    "AG-10" => "Barbuda",
+    "AG-11" => "Redonda",
+    "AR-A" => "Salta",
+    "AR-B" => "Buenos Aires",
+    "AR-C" => "Autonomiczne Miasto Buenos Aires",
+    "AR-D" => "San Luis",
+    "AR-E" => "Entre Ríos",
+    "AR-F" => "La Rioja",
+    "AR-G" => "Santiago del Estero",
+    "AR-H" => "Chaco",
+    "AR-J" => "San Juan",
+    "AR-K" => "Catamarca",
+    "AR-L" => "La Pampa",
+    "AR-M" => "Mendoza",
+    "AR-N" => "Misiones",
+    "AR-P" => "Formosa",
+    "AR-Q" => "Neuquén",
+    "AR-R" => "Río Negro",
+    "AR-S" => "Santa Fe",
+    "AR-T" => "Tucumán",
+    "AR-U" => "Chubut",
+    "AR-V" => "Ziemia Ognista",
+    "AR-W" => "Cirrientes",
+    "AR-X" => "Córdoba",
+    "AR-Y" => "Jujuy",
+    "AR-Z" => "Santa Cruz",
+    "AM-AG" => "Aragacotn",
+    "AM-AR" => "Ararat",
+    "AM-AV" => "Armawir",
+    "AM-ER" => "Erywań",
+    "AM-GR" => "Gegharkunik",
+    "AM-KT" => "Kotajk",
+    "AM-SH" => "Szirak",
+    "AM-SU" => "Sjunik",
+    "AM-TV" => "Tawusz",
+    "AM-VD" => "Wajoc Dzor",
+    "AU-ACT" => "Australijskie Terytorium Stołeczne",
+    "AU-NSW" => "Nowa Południowa Walia",
+    "AU-NT" => "Terytorium Północne",
+    "AU-QLD" => "Queensland",
+    "AU-SA" => "Australia Południowa",
+    "AU-TAS" => "Tasmania",
+    "AU-VIC" => "Wiktoria",
+    "AU-WA" => "Australia Zachodnia",
+    "AT-1" => "Burgenland",
+    "AT-2" => "Karyntia",
+    "AT-3" => "Dolna Austria",
+    "AT-4" => "Górna Austria",
+    "AT-5" => "Salzburg",
+    "AT-6" => "Styria",
+    "AT-7" => "Tyrol",
+    "AT-8" => "Vorarlberg",
+    "AT-9" => "Wiedeń",
+    "AZ-ABS" => "Apszeron",
+    "AZ-AGA" => "Agstafa",
+    "AZ-AGC" => "Agdżabedi",
+    "AZ-AGM" => "Agdam",
+    "AZ-AGS" => "Agdasz",
+    "AZ-AGU" => "Agsu",
+    "AZ-AST" => "Astara",
+    "AZ-BA" => "Baku",
+    "AZ-BAB" => "Babek",
+    "AZ-BAL" => "Balakan",
+    "AZ-BAR" => "Barda",
+    "AZ-BEY" => "Bejlagan",
+    "AZ-BIL" => "Bilasuvar",
+    "AZ-CAB" => "Dżabrail",
+    "AZ-CAL" => "Cəlilabad",
+    "AZ-CUL" => "Dżulfa",
+    "AZ-DAS" => "Daszkesan",
+    "AZ-FUZ" => "Fizuli",
+    "AZ-GA" => "Gandża",
+    "AZ-GAD" => "Gadabay",
+    "AZ-GOR" => "Goranboy",
+    "AZ-GOY" => "Geokczaj",
+    "AZ-GYG" => "Göygöl",
+    "AZ-HAC" => "Hadżigabul",
+    "AZ-IMI" => "Imiszli",
+    "AZ-ISM" => "Ismailli",
+    "AZ-KAL" => "Kelbadżar",
+    "AZ-KAN" => "Kǝngǝrli",
+    "AZ-KUR" => "Kurdamir",
+    "AZ-LA" => "Lenkoran",
+    "AZ-LAC" => "Łaczyn",
+    "AZ-LAN" => "Lenkoran",
+    "AZ-LER" => "Lerik",
+    "AZ-MAS" => "Masałły",
+    "AZ-MI" => "Mingeczaur",
+    "AZ-NA" => "Naftalan",
+    "AZ-NEF" => "Neftczała",
+    "AZ-NV" => "Nachiczewan",
+    "AZ-NX" => "Nachiczewan",
+    "AZ-OGU" => "Oguz",
+    "AZ-ORD" => "Ordubad",
+    "AZ-QAB" => "Kabala",
+    "AZ-QAX" => "Kach",
+    "AZ-QAZ" => "Kazach",
+    "AZ-QBA" => "Kuba",
+    "AZ-QBI" => "Kubatły",
+    "AZ-QOB" => "Kobustan",
+    "AZ-QUS" => "Kusary",
+    "AZ-SA" => "Szeki",
+    "AZ-SAB" => "Sabirabad",
+    "AZ-SAD" => "Sadarak",
+    "AZ-SAH" => "Szachbuz",
+    "AZ-SAK" => "Szeki",
+    "AZ-SAL" => "Saljany",
+    "AZ-SAR" => "Szarur",
+    "AZ-SAT" => "Saatły",
+    "AZ-SBN" => "Szabran",
+    "AZ-SIY" => "Siazan",
+    "AZ-SKR" => "Szamkir",
+    "AZ-SM" => "Sumgait",
+    "AZ-SMI" => "Szemacha",
+    "AZ-SMX" => "Samuch",
+    "AZ-SR" => "Szyrwan",
+    "AZ-SUS" => "Szusza",
+    "AZ-TAR" => "Tartar",
+    "AZ-TOV" => "Tovuz",
+    "AZ-UCA" => "Udżary",
+    "AZ-XA" => "Chankandi",
+    "AZ-XAC" => "Chaczmaz",
+    "AZ-XCI" => "Chodżały",
+    "AZ-XIZ" => "Chyzy",
+    "AZ-XVD" => "Chodżawend",
+    "AZ-YAR" => "Jardymły",
+    "AZ-YE" => "Jewłach",
+    "AZ-YEV" => "Jewłach",
+    "AZ-ZAN" => "Zangilan",
+    "AZ-ZAQ" => "Zakatały",
+    "AZ-ZAR" => "Zardab",
+    "BS-AK" => "Acklins",
+    "BS-BI" => "Bimini",
+    "BS-BP" => "Black Point",
+    "BS-BY" => "Berry Islands",
+    "BS-CE" => "Central Eleuthera",
+    "BS-CI" => "Cat Island",
+    "BS-CK" => "Crooked Island i Long Cay",
+    "BS-CO" => "Central Abaco",
+    "BS-CS" => "Central Andros",
+    "BS-EG" => "East Grand Bahama",
+    "BS-EX" => "Exuma",
+    "BS-FP" => "City of Freeport",
+    "BS-GC" => "Grend Cay",
+    "BS-HI" => "Harbour Island",
+    "BS-HT" => "Hope Town",
+    "BS-IN" => "Inagua",
+    "BS-LI" => "Long Island",
+    "BS-MC" => "Mangrove Cay",
+    "BS-MG" => "Mayaguana",
+    "BS-MI" => "Moore's Island",
+    "BS-NE" => "North Eleuthera",
+    "BS-NO" => "North Abaco",
+    "BS-NS" => "North Andros",
+    "BS-RC" => "Rum Cay",
+    "BS-RI" => "Ragged Island",
+    "BS-SA" => "South Andros",
+    "BS-SE" => "South Eleuthera",
+    "BS-SO" => "South Abaco",
+    "BS-SS" => "San Salvador",
+    "BS-SW" => "Spanish Wells",
+    "BS-WG" => "West Grand Bahama",
+    "BH-13" => "Al-Asima",
+    "BH-14" => "Gubernatorstwo Południowe",
+    "BH-15" => "Gubernatorstwo Muharraq",
+    "BH-17" => "Asz-Szimalijja / Prowincja Północna",
+    "BD-01" => "Bandarban",
+    "BD-02" => "Barguna",
+    "BD-03" => "Bogra",
+    "BD-04" => "Brammanbarija",
+    "BD-05" => "Bagerhat",
+    "BD-06" => "Barisal",
+    "BD-07" => "Bhola",
+    "BD-08" => "Kumilla",
+    "BD-09" => "Ćandpur",
+    "BD-10" => "Ćottogram",
+    "BD-11" => "Koks Badźar",
+    "BD-12" => "Ćuadanga",
+    "BD-13" => "Dhaka",
+    "BD-14" => "Dinadźpur",
+    "BD-15" => "Phoridpur",
+    "BD-16" => "Pheni",
+    "BD-17" => "Gopalgandź",
+    "BD-18" => "Gadźipur",
+    "BD-19" => "Gaibanda",
+    "BD-20" => "Hobigondźo",
+    "BD-21" => "Dźomalpur",
+    "BD-23" => "Jhenaidah",
+    "BD-24" => "Dźaipur Hat",
+    "BD-25" => "Jhalakati",
+    "BD-26" => "Kiszorgandź",
+    "BD-27" => "Khulna",
+    "BD-28" => "Kurigram",
+    "BD-29" => "Khagraćori",
+    "BD-30" => "Kusztija",
+    "BD-31" => "Lakszmipur",
+    "BD-32" => "Lalmonir Hat",
+    "BD-33" => "Manikgandź",
+    "BD-34" => "Mojmoszinho",
+    "BD-35" => "Munszigondźo",
+    "BD-36" => "Madaripur",
+    "BD-37" => "Magura",
+    "BD-38" => "Moulbi Badźar",
+    "BD-39" => "Meherpur",
+    "BD-40" => "Narajangondźo",
+    "BD-41" => "Netrokona",
+    "BD-42" => "Norszinhdi",
+    "BD-43" => "Narail",
+    "BD-44" => "Nator",
+    "BD-45" => "Nobabgondźo",
+    "BD-46" => "Nilphamari",
+    "BD-47" => "Noakhali",
+    "BD-48" => "Naogaon",
+    "BD-49" => "Pabna",
+    "BD-50" => "Pirodźpur",
+    "BD-51" => "Patuakhali",
+    "BD-52" => "Panczagarh",
+    "BD-53" => "Radźbari",
+    "BD-54" => "Radźszahi",
+    "BD-55" => "Rangpur",
+    "BD-56" => "Rangamati",
+    "BD-57" => "Szerpur",
+    "BD-58" => "Szatkhira",
+    "BD-59" => "Sziradźgondźo",
+    "BD-60" => "Srihotto",
+    "BD-61" => "Sunamgandź",
+    "BD-62" => "Szariatpur",
+    "BD-63" => "Tangail",
+    "BD-64" => "Thakurgaon",
+    "BD-A" => "Barisal",
+    "BD-B" => "Ćottogram",
+    "BD-C" => "Dhaka",
+    "BD-D" => "Khulna",
+    "BD-E" => "Radźszahi",
+    "BD-F" => "Rangpur",
+    "BD-G" => "Srihotto",
+    "BD-H" => "Mojmoszinho",
+    "BB-01" => "Christ Church",
+    "BB-02" => "Saint Andrew",
+    "BB-03" => "Saint George",
+    "BB-04" => "Saint James",
+    "BB-05" => "Saint John",
+    "BB-06" => "Saint Joseph",
+    "BB-07" => "Saint Lucy",
+    "BB-08" => "Saint Michael",
+    "BB-09" => "Saint Peter",
+    "BB-10" => "Saint Philip",
+    "BB-11" => "Saint Thomas",
+    "BY-BR" => "obwód brzeski",
+    "BY-HM" => "Miasto Mińsk",
+    "BY-HO" => "obwód homelski",
+    "BY-HR" => "obwód grodzieński",
+    "BY-MA" => "obwód mohylewski",
+    "BY-MI" => "obwód miński",
+    "BY-VI" => "obwód witebski",
+    "BE-BRU" => "Region Stołeczny Brukseli",
+    "BE-VAN" => "Antwerpia",
+    "BE-VBR" => "Brabancja Flamandzka",
+    "BE-VLG" => "Region Flamandzki",
+    "BE-VLI" => "Limburgia",
+    "BE-VOV" => "Flandria Wschodnia",
+    "BE-VWV" => "Flandria Zachodnia",
+    "BE-WAL" => "Region Waloński",
+    "BE-WBR" => "Brabancja Walońska",
+    "BE-WHT" => "Hainaut",
+    "BE-WLG" => "Liège",
+    "BE-WLX" => "Luksemburg",
+    "BE-WNA" => "Namur",
+    "BZ-BZ" => "Belize",
+    "BZ-CY" => "Cayo",
+    "BZ-CZL" => "Corozal",
+    "BZ-OW" => "Orange Walk",
+    "BZ-SC" => "Stann Creek",
+    "BZ-TOL" => "Toledo",
+    "BJ-AK" => "Atacora",
+    "BJ-AL" => "Alibori",
+    "BJ-AQ" => "Atlantique",
+    "BJ-BO" => "Borgou",
+    "BJ-CO" => "Collines",
+    "BJ-DO" => "Donga",
+    "BJ-KO" => "Couffo",
+    "BJ-LI" => "Littoral",
+    "BJ-MO" => "Mono",
+    "BJ-OU" => "Ouémé",
+    "BJ-PL" => "Plateau",
+    "BJ-ZO" => "Zou",
+    "BT-11" => "Paro",
+    "BT-12" => "Chukha",
+    "BT-13" => "Haa",
+    "BT-14" => "Samtse",
+    "BT-15" => "Thimphu",
+    "BT-21" => "Tsirang",
+    "BT-22" => "Dagana",
+    "BT-23" => "Punakha",
+    "BT-24" => "Wangdue Phodrang",
+    "BT-31" => "Sarpang",
+    "BT-32" => "Trongsa",
+    "BT-33" => "Bumthang",
+    "BT-34" => "Zhamgang",
+    "BT-41" => "Trashigang",
+    "BT-42" => "Mongar",
+    "BT-43" => "Pemagatshel",
+    "BT-44" => "Lhuntse",
+    "BT-45" => "Samdrup Jongkhar",
+    "BT-GA" => "Gasa",
+    "BT-TY" => "Trashiyangtse",
+    "BO-B" => "Beni",
+    "BO-C" => "Cochabamba",
+    "BO-H" => "Chuquisaca",
+    "BO-L" => "La Paz",
+    "BO-N" => "Pando",
+    "BO-O" => "Oruro",
+    "BO-P" => "Potosí",
+    "BO-S" => "Santa Cruz",
+    "BO-T" => "Tarija",
+    "BQ-BO" => "Bonaire",
+    "BQ-SA" => "Saba",
+    "BQ-SE" => "Sint Eustatius",
+    "BA-BIH" => "Federacja Bośni i Hercegowiny",
+    "BA-BRC" => "Dystrykt Brczko",
+    "BA-SRP" => "Republika Serbska",
+    "BW-CE" => "Dystrykt Centralny / Region Centralny / Prowincja Centralna / Departament Centralny",
+    "BW-FR" => "Francistown",
+    "BW-GA" => "Gaborone",
+    "BW-GH" => "Ghanzi",
+    "BW-JW" => "Jwaneng",
+    "BW-KG" => "kgalagadi",
+    "BW-KL" => "Kgatleng",
+    "BW-KW" => "Kweneng",
+    "BW-LO" => "Lobatse",
+    "BW-NE" => "Północno-Wschodni",
+    "BW-NW" => "Północno-Zachodni",
+    "BW-SE" => "Południowo-Wschodni",
+    "BW-SO" => "Południowy",
+    "BW-ST" => "Sowa Town",
+    "BR-AC" => "Acre",
+    "BR-AL" => "Alagoas",
+    "BR-AM" => "Amazonas",
+    "BR-AP" => "Amapá",
+    "BR-BA" => "Bahia",
+    "BR-CE" => "Ceará",
+    "BR-DF" => "Dystrykt Federalny",
+    "BR-ES" => "Espírito Santo",
+    "BR-GO" => "Goiás",
+    "BR-MA" => "Maranhão",
+    "BR-MG" => "Minas Gerais",
+    "BR-MS" => "Mato Grosso do Sul",
+    "BR-MT" => "Mato Grosso",
+    "BR-PA" => "Pará",
+    "BR-PB" => "Paraíba",
+    "BR-PE" => "Pernambuco",
+    "BR-PI" => "Piauí",
+    "BR-PR" => "Paraná",
+    "BR-RJ" => "Rio de Janeiro",
+    "BR-RN" => "Rio Grande do Norte",
+    "BR-RO" => "Rondônia",
+    "BR-RR" => "Roraima",
+    "BR-RS" => "Rio Grande do Sul",
+    "BR-SC" => "Santa Catarina",
+    "BR-SE" => "Sergipe",
+    "BR-SP" => "São Paulo",
+    "BR-TO" => "Tocantins",
+    "BN-BE" => "Belait",
+    "BN-BM" => "Brunei i Muara",
+    "BN-TE" => "Temburong",
+    "BN-TU" => "Tutong",
+    "BG-01" => "Błagojewgrad",
+    "BG-02" => "Burgas",
+    "BG-03" => "Warna",
+    "BG-04" => "Wielkie Tyrnowo",
+    "BG-05" => "Widin",
+    "BG-06" => "Wraca",
+    "BG-07" => "Grabrowo",
+    "BG-08" => "Dobricz",
+    "BG-09" => "Kyrdżali",
+    "BG-10" => "Kiustendił",
+    "BG-11" => "Łowecz",
+    "BG-12" => "Montana",
+    "BG-13" => "Pazardżik",
+    "BG-14" => "Pernik",
+    "BG-15" => "Plewen",
+    "BG-16" => "Płowdiw",
+    "BG-17" => "Razgrad",
+    "BG-18" => "Ruse",
+    "BG-19" => "Silistra",
+    "BG-20" => "Sliwen",
+    "BG-21" => "Smolan",
+    "BG-23" => "Sofia",
+    "BG-24" => "Stara Zagora",
+    "BG-25" => "Tyrgowiszte",
+    "BG-26" => "Chaskowo",
+    "BG-27" => "Szumen",
+    "BG-28" => "Jamboł",
+    "BF-01" => "Boucle du Mouhoun",
+    "BF-02" => "Cascades",
+    "BF-03" => "Centralny",
+    "BF-04" => "Centre-Est",
+    "BF-05" => "Centre-Nord",
+    "BF-06" => "Centre-Ouest",
+    "BF-07" => "Centre-Sud",
+    "BF-08" => "Wschodni / Wschodnia",
+    "BF-09" => "Hauts-Bassins",
+    "BF-10" => "Nord / Północny / Północna",
+    "BF-11" => "Plateau-Central",
+    "BF-12" => "Sahel",
+    "BF-13" => "Sud-Ouest",
+    "BF-BAL" => "Balé",
+    "BF-BAM" => "Bam",
+    "BF-BAN" => "Banwa",
+    "BF-BAZ" => "Bazèga",
+    "BF-BGR" => "Bougouriba",
+    "BF-BLG" => "Boulgou",
+    "BF-BLK" => "Boulkiemdé",
+    "BF-COM" => "Comoé",
+    "BF-GAN" => "Ganzourgou",
+    "BF-GNA" => "Gnagna",
+    "BF-GOU" => "Gourma",
+    "BF-HOU" => "Houet",
+    "BF-IOB" => "Ioba",
+    "BF-KAD" => "Kadiogo",
+    "BF-KEN" => "Kénédougou",
+    "BF-KMD" => "Komondjari",
+    "BF-KMP" => "Kompienga",
+    "BF-KOP" => "Koulpélogo",
+    "BF-KOS" => "Kossi",
+    "BF-KOT" => "Kouritenga",
+    "BF-KOW" => "Kourwéogo",
+    "BF-LER" => "Léraba",
+    "BF-LOR" => "Loroum",
+    "BF-MOU" => "Mouhoun",
+    "BF-NAM" => "Namentenga",
+    "BF-NAO" => "Nahouri",
+    "BF-NAY" => "Nayala",
+    "BF-NOU" => "Noumbiel",
+    "BF-OUB" => "Oubritenga",
+    "BF-OUD" => "Oudalan",
+    "BF-PAS" => "Passoré",
+    "BF-PON" => "Poni",
+    "BF-SEN" => "Séno",
+    "BF-SIS" => "Sissili",
+    "BF-SMT" => "Sanmatenga",
+    "BF-SNG" => "Sanguié",
+    "BF-SOM" => "Soum",
+    "BF-SOR" => "Sourou",
+    "BF-TAP" => "Tapoa",
+    "BF-TUI" => "Tuy",
+    "BF-YAG" => "Yagha",
+    "BF-YAT" => "Yatenga",
+    "BF-ZIR" => "Ziro",
+    "BF-ZON" => "Zondoma",
+    "BF-ZOU" => "Zoundwéogo",
+    "BI-BB" => "Bubanza",
+    "BI-BL" => "Bujumbura Rural",
+    "BI-BM" => "Bujumbura Mairie",
+    "BI-BR" => "Bururi",
+    "BI-CA" => "Cankuzo",
+    "BI-CI" => "Cibitoke",
+    "BI-GI" => "Gitega",
+    "BI-KI" => "Kirundo",
+    "BI-KR" => "Karuzi",
+    "BI-KY" => "Kayanza",
+    "BI-MA" => "Makamba",
+    "BI-MU" => "Muramvya",
+    "BI-MW" => "Mwaro",
+    "BI-MY" => "Muyinga",
+    "BI-NG" => "Ngozi",
+    "BI-RM" => "Rumonge",
+    "BI-RT" => "Rutana",
+    "BI-RY" => "Ruyigi",
+    "CV-B" => "Wyspy Zawietrzne",
+    "CV-BR" => "Brava",
+    "CV-BV" => "Boa Vista",
+    "CV-CA" => "Santa Catarina",
+    "CV-CF" => "Santa Catarina do Fogo",
+    "CV-CR" => "Santa Cruz",
+    "CV-MA" => "Maio",
+    "CV-MO" => "Mosteiros",
+    "CV-PA" => "Paul",
+    "CV-PN" => "Porto Novo",
+    "CV-PR" => "Praia",
+    "CV-RB" => "Ribeira Brava",
+    "CV-RG" => "Ribeira Grande",
+    "CV-RS" => "Ribeira Grande de Santiago",
+    "CV-S" => "Wyspy Podwietrzne",
+    "CV-SD" => "São Domingos",
+    "CV-SF" => "São Filipe",
+    "CV-SL" => "Sal",
+    "CV-SM" => "São Miguel",
+    "CV-SO" => "São Lourenço dos Órgãos",
+    "CV-SS" => "São Salvador do Mundo",
+    "CV-SV" => "São Vicente",
+    "CV-TA" => "Tarrafal",
+    "CV-TS" => "Tarrafal de São Nicolau",
+    "KH-1" => "Bântéay Méanchey",
+    "KH-10" => "Krâchéh",
+    "KH-11" => "Môndôl Kiri",
+    "KH-12" => "Phnom Penh",
+    "KH-13" => "Preăh Vĭhéar",
+    "KH-14" => "Prey Vêng",
+    "KH-15" => "Poŭrsăt",
+    "KH-16" => "Rôttanak Kiri",
+    "KH-17" => "Siĕm Réab",
+    "KH-20" => "Svay Rieng",
+    "KH-21" => "Takev",
+    "KH-22" => "Otdar Mean Cheay",
+    "KH-4" => "Kampong Czhnang",
+    "KH-7" => "Kampot",
+    "CM-AD" => "Adamawa",
+    "CM-CE" => "Centralny",
+    "CM-EN" => "Północna Dalsza",
+    "CM-ES" => "Wschodnia",
+    "CM-LT" => "Littoral",
+    "CM-NO" => "Północna",
+    "CM-NW" => "Północno-Zachodni",
+    "CM-OU" => "Zachodnia",
+    "CM-SU" => "Południowa",
+    "CM-SW" => "Południowo-Zachodnia",
+    "CA-AB" => "Alberta",
+    "CA-BC" => "Kolumbia Brytyjska",
+    "CA-MB" => "Manitoba",
+    "CA-NB" => "Nowy Brunszwik",
+    "CA-NL" => "Nowa Funlandia i Labrador",
+    "CA-NS" => "Nowa Szkocja",
+    "CA-NT" => "Terytoria Północno-Zachodnie",
+    "CA-NU" => "Nunavut",
+    "CA-ON" => "Ontario",
+    "CA-PE" => "Wyspa Księcia Edwarda",
+    "CA-QC" => "Quebec",
+    "CA-SK" => "Saskatchewan",
+    "CA-YT" => "Jukon",
+    "CF-AC" => "Ouham",
+    "CF-BB" => "Bamingui-Bangoran",
+    "CF-BGF" => "Bangui",
+    "CF-BK" => "Basse-Kotto",
+    "CF-HK" => "Haute-Kotto",
+    "CF-HM" => "Haut-Mbomou",
+    "CF-HS" => "Mambéré-Kadéï",
+    "CF-KB" => "Nana-Grébizi",
+    "CF-LB" => "Lobaye",
+    "CF-MB" => "Mbomou",
+    "CF-MP" => "Ombella-Mpoko",
+    "CF-NM" => "Nana-Mambéré",
+    "CF-OP" => "Ouham-Pendé",
+    "CF-SE" => "Sangha",
+    "CF-UK" => "Ouaka",
+    "CF-VK" => "Vakaga",
+    "TD-LC" => "Al-Buhajra",
+    "TD-ND" => "Ndżamena",
+    "TD-TI" => "Tibesti",
+    "CL-AI" => "Aisén",
+    "CL-AN" => "Antofagasta",
+    "CL-AP" => "Arica y Parinacota",
+    "CL-AR" => "Araukania",
+    "CL-AT" => "Atakama",
+    "CL-CO" => "Coquimbo",
+    "CL-LI" => "Libertador",
+    "CL-LL" => "Los Lagos",
+    "CL-LR" => "Los Ríos",
+    "CL-MA" => "Magallanes",
+    "CL-ML" => "Maule",
+    "CL-RM" => "Metropolitana",
+    "CL-TA" => "Tarapacá",
+    "CL-VS" => "Valparaíso",
+    "CN-AH" => "Anhui",
+    "CN-BJ" => "Pekin",
+    "CN-CQ" => "Chongqing",
+    "CN-FJ" => "Fujian",
+    "CN-GD" => "Guangdong",
+    "CN-GS" => "Gansu",
+    "CN-GX" => "Region Autonomiczny Kuangsi-Czuang",
+    "CN-GZ" => "Kuejczou",
+    "CN-HA" => "Henan",
+    "CN-HB" => "Hubei",
+    "CN-HE" => "Hebei",
+    "CN-HI" => "Hajnan",
+    "CN-HL" => "Heilongjiang",
+    "CN-HN" => "Hunan",
+    "CN-JL" => "Jilin",
+    "CN-JS" => "Jiangsu",
+    "CN-JX" => "Jiangxi",
+    "CN-LN" => "Liaoning",
+    "CN-NM" => "Region Autonomiczny Mongolii Wewnętrznej",
+    "CN-NX" => "Region Autonomiczny Ningxia Hui",
+    "CN-QH" => "Qinghai",
+    "CN-SC" => "Syczuan",
+    "CN-SD" => "Szantung",
+    "CN-SH" => "Szanghaj",
+    "CN-SN" => "Shaanxi",
+    "CN-SX" => "Shanxi",
+    "CN-TJ" => "Tiencin",
+    "CN-TW" => "Tajwan",
+    "CN-XJ" => "Region Autonomiczny Sinciang-Ujgur",
+    "CN-XZ" => "Tybetański Region Autonomiczny",
+    "CN-YN" => "Junnan",
+    "CN-ZJ" => "Zhejiang",
+    "CO-AMA" => "Amazonas",
+    "CO-ANT" => "Antioquia",
+    "CO-ARA" => "Arauca",
+    "CO-ATL" => "Atlántico",
+    "CO-BOL" => "Bolívar",
+    "CO-BOY" => "Boyacá",
+    "CO-CAL" => "Caldas",
+    "CO-CAQ" => "Caquetá",
+    "CO-CAS" => "Casanare",
+    "CO-CAU" => "Cauca",
+    "CO-CES" => "Cesar",
+    "CO-CHO" => "Chocó",
+    "CO-COR" => "Córdoba",
+    "CO-CUN" => "Cundinamarca",
+    "CO-DC" => "Dystrykt Specjalny Bogota",
+    "CO-GUA" => "Guainía",
+    "CO-GUV" => "Guaviare",
+    "CO-HUI" => "Huila",
+    "CO-LAG" => "La Guajira",
+    "CO-MAG" => "Magdalena",
+    "CO-MET" => "Meta",
+    "CO-NAR" => "Nariño",
+    "CO-NSA" => "Norte de Santander",
+    "CO-PUT" => "Putumayo",
+    "CO-QUI" => "Quindío",
+    "CO-RIS" => "Risaralda",
+    "CO-SAN" => "Santander",
+    "CO-SAP" => "San Andrés, Providencia i Santa Catalina",
+    "CO-SUC" => "Sucre",
+    "CO-TOL" => "Tolima",
+    "CO-VAC" => "Valle del Cauca",
+    "CO-VAU" => "Vaupés",
+    "CO-VID" => "Vichada",
+    "CG-11" => "Bouenza",
+    "CG-12" => "Pool",
+    "CG-13" => "Sangha",
+    "CG-14" => "Plateaux",
+    "CG-15" => "Cuvette-Ouest",
+    "CG-16" => "Pointe-Noire",
+    "CG-2" => "Lékoumou",
+    "CG-5" => "Kouilou",
+    "CG-7" => "Likouala",
+    "CG-8" => "Cuvette",
+    "CG-9" => "Niari",
+    "CG-BZV" => "Brazzaville",
+    "CD-BC" => "Kongo Środkowe",
+    "CD-EQ" => "Prowincja Równikowa",
+    "CD-HK" => "Górna Katanga",
+    "CD-HL" => "Górne Lomami",
+    "CD-HU" => "Górne Uele",
+    "CD-IT" => "Ituri",
+    "CD-KC" => "Kasai Środkowe",
+    "CD-KE" => "Kasai Wschodnie",
+    "CD-KG" => "Kwango",
+    "CD-KN" => "Kinszasa",
+    "CD-LU" => "Lualaba",
+    "CD-MA" => "Maniema",
+    "CD-MO" => "Mongala",
+    "CD-NK" => "Kiwu Północne",
+    "CD-NU" => "Ubangi Północne",
+    "CD-SA" => "Sankuru",
+    "CD-SK" => "Kiwu Południowe",
+    "CD-TA" => "Tanganika",
+    "CR-A" => "Alajuela",
+    "CR-C" => "Cartago",
+    "CR-G" => "Guanacaste",
+    "CR-H" => "Heredia",
+    "CR-L" => "Limón",
+    "CR-P" => "Puntarenas",
+    "CR-SJ" => "San José",
+    "CI-BS" => "Bas-Sassandra",
+    "CI-CM" => "Comoé",
+    "CI-DN" => "Denguélé",
+    "CI-GD" => "Gôh-Djiboua",
+    "CI-LG" => "Lagunes",
+    "CI-MG" => "Montagnes",
+    "CI-SV" => "Savanes",
+    "CI-VB" => "Vallée du Bandama",
+    "CI-WR" => "Woroba",
+    "CI-YM" => "Jamusukro",
+    "CI-ZZ" => "Zanzan",
+    "HR-01" => "żupania zagrzebska",
+    "HR-02" => "żupania krapińsko-zagorska",
+    "HR-03" => "żupania sisacko-moslawińska",
+    "HR-04" => "żupania karlowacka",
+    "HR-05" => "żupania varażdińska",
+    "HR-06" => "żupania kopriwnicko-kriżewczyńska",
+    "HR-07" => "żupania bielowarsko-bilogorska",
+    "HR-08" => "żupania primorsko-gorska",
+    "HR-09" => "żupania licko-seńska",
+    "HR-10" => "żupania virowiticko-podrawska",
+    "HR-11" => "żupania pożedzko-slawońska",
+    "HR-12" => "żupania brodzko-posawska",
+    "HR-13" => "żupania zadarska",
+    "HR-14" => "żupania osijecko-barańska",
+    "HR-15" => "żupania szybenicko-knińska",
+    "HR-16" => "żupania vukowarsko-srijemska",
+    "HR-17" => "żupania splicko-dalmatyńska",
+    "HR-18" => "żupania istryjska",
+    "HR-19" => "żupania dubrownicko-neretwiańska",
+    "HR-20" => "żupania medzimurska",
+    "HR-21" => "Zagrzeb",
+    "CU-01" => "Pinar del Río",
+    "CU-03" => "La Habana",
+    "CU-04" => "Matanzas",
+    "CU-05" => "Villa Clara",
+    "CU-06" => "Cienfuegos",
+    "CU-07" => "Sancti Spíritus",
+    "CU-08" => "Ciego de Ávila",
+    "CU-09" => "Camagüey",
+    "CU-10" => "Las Tunas",
+    "CU-11" => "Holguín",
+    "CU-12" => "Granma",
+    "CU-13" => "Santiago de Cuba",
+    "CU-14" => "Guantánamo",
+    "CU-16" => "Mayabeque",
+    "CU-99" => "Isla de la Juventud",
+    "CY-01" => "Nikozja",
+    "CY-02" => "Limassol",
+    "CY-03" => "Larnaka",
+    "CY-04" => "Famagusta",
+    "CY-05" => "Pafos",
+    "CY-06" => "Kirenia",
+    "CZ-10" => "Praga, miasto stołeczne",
+    "CZ-20" => "kraj środkowoczeski",
+    "CZ-201" => "Benešov",
+    "CZ-202" => "Beroun",
+    "CZ-203" => "Kladno",
+    "CZ-204" => "Kolín",
+    "CZ-205" => "Kutná Hora",
+    "CZ-206" => "Mielnik",
+    "CZ-207" => "Mladá Boleslav",
+    "CZ-208" => "Nymburk",
+    "CZ-209" => "Praga-Wschód",
+    "CZ-20A" => "Praga-Zachód",
+    "CZ-20B" => "Przybram",
+    "CZ-20C" => "Rakovník",
+    "CZ-31" => "kraj południowoczeski",
+    "CZ-311" => "Czeskie Budziejowice",
+    "CZ-312" => "Český Krumlov",
+    "CZ-313" => "Jindřichův Hradec",
+    "CZ-314" => "Písek",
+    "CZ-315" => "Prachatice",
+    "CZ-316" => "Strakonice",
+    "CZ-317" => "Tabor",
+    "CZ-32" => "kraj pilzneński",
+    "CZ-321" => "Domažlice",
+    "CZ-322" => "Klatovy",
+    "CZ-323" => "Pilzno-miasto",
+    "CZ-324" => "Pilzno-Południe",
+    "CZ-325" => "Pilzno-Północ",
+    "CZ-326" => "Rokycany",
+    "CZ-327" => "Tachov",
+    "CZ-41" => "kraj karlowarski",
+    "CZ-411" => "Cheb",
+    "CZ-412" => "Karlowe Wary",
+    "CZ-413" => "Sokolov",
+    "CZ-42" => "kraj ustecki",
+    "CZ-421" => "Děčín",
+    "CZ-422" => "Chomutov",
+    "CZ-423" => "Litomierzyce",
+    "CZ-424" => "Louny",
+    "CZ-425" => "Most",
+    "CZ-426" => "Cieplice",
+    "CZ-427" => "Uście nad Łabą",
+    "CZ-51" => "kraj liberecki",
+    "CZ-511" => "Czeska Lipa",
+    "CZ-512" => "Jablonec nad Nysą",
+    "CZ-513" => "Liberec",
+    "CZ-514" => "Semily",
+    "CZ-52" => "kraj hradecki",
+    "CZ-521" => "Hradec Králové",
+    "CZ-522" => "Jiczyn",
+    "CZ-523" => "Náchod",
+    "CZ-524" => "Rychnov nad Kněžnou",
+    "CZ-525" => "Trutnov",
+    "CZ-53" => "kraj pardubicki",
+    "CZ-531" => "Chrudim",
+    "CZ-532" => "Pardubice",
+    "CZ-533" => "Svitavy",
+    "CZ-534" => "Uście nad Orlicą",
+    "CZ-63" => "kraj Wysoczyna",
+    "CZ-631" => "Havlíčkův Brod",
+    "CZ-632" => "Igława",
+    "CZ-633" => "Pelhřimov",
+    "CZ-634" => "Třebíč",
+    "CZ-635" => "Zdziar nad Sazawą",
+    "CZ-64" => "kraj południowomorawski",
+    "CZ-641" => "Blansko",
+    "CZ-642" => "powiat grodzki Brno",
+    "CZ-643" => "powiat Brno",
+    "CZ-644" => "Brzecław",
+    "CZ-645" => "Hodonín",
+    "CZ-646" => "Vyškov",
+    "CZ-647" => "Znojmo",
+    "CZ-71" => "kraj ołomuniecki",
+    "CZ-711" => "Jeseník",
+    "CZ-712" => "Ołomuniec",
+    "CZ-713" => "Prościejów",
+    "CZ-714" => "Przerów",
+    "CZ-715" => "Šumperk",
+    "CZ-72" => "kraj zliński",
+    "CZ-721" => "Kromieryż",
+    "CZ-722" => "Uherské Hradiště",
+    "CZ-723" => "Vsetín",
+    "CZ-724" => "Zlín",
+    "CZ-80" => "kraj morawsko-śląski",
+    "CZ-801" => "Bruntál",
+    "CZ-802" => "Frydek-Mistek",
+    "CZ-803" => "Karviná",
+    "CZ-804" => "Nowy Jiczyn",
+    "CZ-805" => "Opawa",
+    "CZ-806" => "Ostrawa-miasto",
+    "DK-81" => "Nordjylland",
+    "DK-82" => "Midtjylland",
+    "DK-83" => "Syddanmark",
+    "DK-84" => "Hovedstaden",
+    "DK-85" => "Sjælland",
+    "DJ-AR" => "arta",
+    "DJ-AS" => "Ali Sabieh",
+    "DJ-DI" => "Dikhil",
+    "DJ-DJ" => "Dżibuti",
+    "DJ-TA" => "Tadżura",
+    "DM-02" => "Saint Andrew",
+    "DM-03" => "Saint David",
+    "DM-04" => "Saint George",
+    "DM-05" => "Saint John",
+    "DM-06" => "Saint Joseph",
+    "DM-07" => "Saint Luke",
+    "DM-08" => "Saint Mark",
+    "DM-09" => "Saint Patrick",
+    "DM-10" => "Saint Paul",
+    "DM-11" => "Saint Peter",
+    "DO-01" => "Distrito Nacional (Santo Domingo)",
+    "DO-02" => "Azua",
+    "DO-03" => "Baoruco",
+    "DO-04" => "Barahona",
+    "DO-05" => "Dajabón",
+    "DO-06" => "Duarte",
+    "DO-07" => "Elías Piña",
+    "DO-09" => "Espaillat",
+    "DO-10" => "Independencia",
+    "DO-11" => "La Altagracia",
+    "DO-12" => "La Romana",
+    "DO-13" => "La Vega",
+    "DO-14" => "María Trinidad Sánchez",
+    "DO-15" => "Monte Cristi",
+    "DO-16" => "Pedernales",
+    "DO-17" => "Peravia",
+    "DO-18" => "Puerto Plata",
+    "DO-20" => "Samaná",
+    "DO-21" => "San Cristóbal",
+    "DO-22" => "San Juan",
+    "DO-23" => "San Pedro de Macorís",
+    "DO-24" => "Sánchez Ramírez",
+    "DO-25" => "Santiago",
+    "DO-26" => "Santiago Rodríguez",
+    "DO-27" => "Valverde",
+    "DO-28" => "Monseñor Nouel",
+    "DO-29" => "Monte Plata",
+    "DO-30" => "Hato Mayor",
+    "DO-31" => "San José de Ocoa",
+    "DO-32" => "Santo Domingo",
+    "DO-33" => "Cibao Nordeste",
+    "DO-34" => "Cibao Noroeste",
+    "DO-35" => "Cibao Norte",
+    "DO-36" => "Cibao Sur",
+    "DO-37" => "El Valle",
+    "DO-38" => "Enriquillo",
+    "EC-A" => "Azuay",
+    "EC-B" => "Bolívar",
+    "EC-C" => "Carchi",
+    "EC-D" => "Orellana",
+    "EC-E" => "Esmeraldas",
+    "EC-F" => "Cañar",
+    "EC-G" => "Guayas",
+    "EC-H" => "Chimborazo",
+    "EC-I" => "Imbabura",
+    "EC-L" => "Loja",
+    "EC-M" => "Manabí",
+    "EC-N" => "Napo",
+    "EC-O" => "El Oro",
+    "EC-P" => "Pichincha",
+    "EC-R" => "Los Ríos",
+    "EC-S" => "Morona-Santiago",
+    "EC-SD" => "Santo Domingo de los Tsáchilas",
+    "EC-SE" => "Santa Elena",
+    "EC-T" => "Tungurahua",
+    "EC-U" => "Sucumbíos",
+    "EC-W" => "Galápagos",
+    "EC-X" => "Cotopaxi",
+    "EC-Y" => "Pastaza",
+    "EC-Z" => "Zamora-Chinchipe",
+    "EG-ALX" => "Aleksandria",
+    "EG-ASN" => "Asuan",
+    "EG-AST" => "Asjut",
+    "EG-BA" => "Al-Bahr al-Ahmar",
+    "EG-BH" => "Al-Buhajra",
+    "EG-BNS" => "Bani Suwajf",
+    "EG-C" => "Kair",
+    "EG-DK" => "Ad Dakahlijja",
+    "EG-DT" => "Damietta",
+    "EG-FYM" => "Fajum",
+    "EG-GH" => "Al-Gharbijja",
+    "EG-GZ" => "Giza",
+    "EG-IS" => "Ismailia",
+    "EG-JS" => "Synaj Południowy",
+    "EG-KB" => "Al-Kaljubijja",
+    "EG-KFS" => "Kaft asz-Shajch",
+    "EG-KN" => "Kina",
+    "EG-LX" => "Luksor",
+    "EG-MN" => "Al-Minja",
+    "EG-MNF" => "Al-Minufijja",
+    "EG-MT" => "Matruh",
+    "EG-PTS" => "Port Said",
+    "EG-SHG" => "Sauhadż",
+    "EG-SHR" => "Asz-Szarkijja",
+    "EG-SIN" => "Synaj Północny",
+    "EG-SUZ" => "Suez",
+    "EG-WAD" => "Nowa Dolina",
+    "SV-AH" => "Ahuachapán",
+    "SV-CA" => "Cabañas",
+    "SV-CH" => "Chalatenango",
+    "SV-CU" => "Cuscatlán",
+    "SV-LI" => "La Libertad",
+    "SV-MO" => "Morazán",
+    "SV-PA" => "La Paz",
+    "SV-SA" => "Santa Ana",
+    "SV-SM" => "San Miguel",
+    "SV-SO" => "Sonsonate",
+    "SV-SS" => "San Salvador",
+    "SV-SV" => "San Vicente",
+    "SV-UN" => "La Unión",
+    "SV-US" => "Usulután",
+    "GQ-AN" => "Annobón",
+    "GQ-BN" => "Bioko Północne",
+    "GQ-BS" => "Bioko Południowe",
+    "GQ-C" => "Region kontynentalny",
+    "GQ-CS" => "Prowincja Środkowo-Południowa",
+    "GQ-DJ" => "Djibloho",
+    "GQ-I" => "Region Wyspiarski",
+    "GQ-KN" => "Kié-Ntem",
+    "GQ-LI" => "Prowincja Nadmorska",
+    "GQ-WN" => "Wele-Nzas",
+    "ER-AN" => "Anseba",
+    "ER-DK" => "Region Południowy Morza Czerwonego",
+    "ER-DU" => "Region Południowy",
+    "ER-GB" => "Gasz-Barka",
+    "ER-MA" => "Region Centralny",
+    "ER-SK" => "Region Północny Morza Czerwonego",
+    "EE-130" => "Alutaguse",
+    "EE-141" => "Anija",
+    "EE-142" => "Antsla",
+    "EE-171" => "Elva",
+    "EE-184" => "Haapsalu",
+    "EE-191" => "Haljala",
+    "EE-198" => "Harku",
+    "EE-205" => "Hiuma",
+    "EE-214" => "Häädemeeste",
+    "EE-245" => "Jõelähtme",
+    "EE-247" => "Jõgeva",
+    "EE-251" => "Jõhvi",
+    "EE-255" => "Järva",
+    "EE-272" => "Kadrina",
+    "EE-283" => "Kambja",
+    "EE-284" => "Kambja",
+    "EE-291" => "Kastre",
+    "EE-293" => "Kehtna",
+    "EE-296" => "Keila",
+    "EE-303" => "Kihnu",
+    "EE-305" => "Kiili",
+    "EE-317" => "Kohila",
+    "EE-321" => "Kohtla-Järve",
+    "EE-338" => "Kose",
+    "EE-353" => "Kuusalu",
+    "EE-37" => "Harjumaa",
+    "EE-39" => "Hiuma",
+    "EE-424" => "Loksa",
+    "EE-430" => "Lääne",
+    "EE-431" => "Lääne-Harju",
+    "EE-432" => "Luunja",
+    "EE-441" => "Lääne-Nigula",
+    "EE-442" => "Lüganuse",
+    "EE-446" => "Maardu",
+    "EE-45" => "Virumaa Wschodnia",
+    "EE-478" => "Muhu",
+    "EE-480" => "Mulgi",
+    "EE-486" => "Mustvee",
+    "EE-50" => "Jõgevamaa",
+    "EE-503" => "Märjamaa",
+    "EE-511" => "Narwa",
+    "EE-514" => "Narva-Jõesuu",
+    "EE-52" => "Järvamaa",
+    "EE-528" => "Nõo",
+    "EE-557" => "Otepää",
+    "EE-56" => "Läänemaa",
+    "EE-567" => "Paide",
+    "EE-586" => "Peipsiääre",
+    "EE-60" => "Virumaa Zachodnia",
+    "EE-615" => "Põhja-Sakala",
+    "EE-618" => "Põltsamaa",
+    "EE-622" => "Põlva",
+    "EE-624" => "Parnawa",
+    "EE-638" => "Põhja-Pärnumaa",
+    "EE-64" => "Põlvamaa",
+    "EE-651" => "Raasiku",
+    "EE-653" => "Rae",
+    "EE-661" => "Rakvere",
+    "EE-663" => "Rakvere",
+    "EE-668" => "Rapla",
+    "EE-68" => "Parnawa",
+    "EE-689" => "Ruhnu",
+    "EE-698" => "Rõuge",
+    "EE-708" => "Räpina",
+    "EE-71" => "Raplamaa",
+    "EE-712" => "Saarde",
+    "EE-714" => "Sarema",
+    "EE-719" => "Saku",
+    "EE-726" => "Saue",
+    "EE-732" => "Setomaa",
+    "EE-735" => "Sillamäe",
+    "EE-74" => "Sarema",
+    "EE-784" => "Tallinn",
+    "EE-79" => "Tartu",
+    "EE-792" => "Tapa",
+    "EE-793" => "Tartu",
+    "EE-796" => "Tartu",
+    "EE-803" => "Toila",
+    "EE-809" => "Tori",
+    "EE-81" => "Valgamaa",
+    "EE-824" => "Tõrva",
+    "EE-834" => "Türi",
+    "EE-84" => "Viljandimaa",
+    "EE-855" => "Valga",
+    "EE-87" => "Võrumaa",
+    "EE-890" => "Viimsi",
+    "EE-897" => "Viljandi",
+    "EE-899" => "Viljandi",
+    "EE-901" => "Vinni",
+    "EE-903" => "Viru-Nigula",
+    "EE-907" => "Vormsi",
+    "EE-917" => "Võru",
+    "EE-919" => "Võru",
+    "EE-928" => "Väike-Maarja",
+    "SZ-HH" => "Hhohho",
+    "SZ-LU" => "Lubombo",
+    "SZ-MA" => "Manzini",
+    "SZ-SH" => "Shiselweni",
+    "ET-AA" => "Addis Abeba",
+    "ET-AF" => "Afar",
+    "ET-AM" => "Amhara",
+    "ET-BE" => "Bienszangul-Gumuz",
+    "ET-DD" => "Dire Daua",
+    "ET-GA" => "Region Ludów Gambeli",
+    "ET-HA" => "Region Ludu Hareri",
+    "ET-OR" => "Oromia",
+    "ET-SN" => "Region Narodów, Narodowości i Ludów Południa",
+    "ET-SO" => "Somali",
+    "ET-TI" => "Tigraj",
+    "FJ-01" => "Ba",
+    "FJ-02" => "Bua",
+    "FJ-03" => "Cakaudrove",
+    "FJ-04" => "Kadavu",
+    "FJ-05" => "Lau",
+    "FJ-06" => "Lomaiviti",
+    "FJ-07" => "Macuata",
+    "FJ-08" => "Nadroga-Navosa",
+    "FJ-09" => "Naitasiri",
+    "FJ-10" => "Namosi",
+    "FJ-11" => "Ra",
+    "FJ-12" => "Rewa",
+    "FJ-13" => "Serua",
+    "FJ-14" => "Tailevu",
+    "FJ-C" => "Dystrykt Centralny / Region Centralny / Prowincja Centralna / Departament Centralny",
+    "FJ-E" => "Dystrykt Wschodni / Region Wschodni / Prowincja Wschodnia",
+    "FJ-N" => "Dystrykt Północny / Region Północny / Prowincja Północna",
+    "FJ-R" => "Rotuma",
+    "FJ-W" => "Dystrykt Zachodni / Region Zachodni / Western Division / Prowincja Zachodnia",
+    "FI-01" => "Wyspy Alandzkie",
+    "FI-02" => "Karelia Południowa",
+    "FI-03" => "Ostrobotnia Południowa",
+    "FI-04" => "Sawonia Południowa",
+    "FI-05" => "Kainuu",
+    "FI-06" => "Kanta-Häme",
+    "FI-07" => "Ostrobotnia Środkowa",
+    "FI-08" => "Finlandia Środkowa",
+    "FI-09" => "Kymenlaakso",
+    "FI-10" => "Laponia",
+    "FI-11" => "Pirkanmaa",
+    "FI-12" => "Ostrobotnia",
+    "FI-13" => "Karelia Północna",
+    "FI-14" => "Ostrobotnia Północna",
+    "FI-15" => "Sawonia Północna",
+    "FI-16" => "Päijät-Häme",
+    "FI-17" => "Satakunta",
+    "FI-18" => "Uusimaa",
+    "FI-19" => "Finlandia Południowo-Zachodnia",
+    "FR-01" => "Ain",
+    "FR-02" => "Aisne",
+    "FR-03" => "Allier",
+    "FR-04" => "Alpy Górnej Prowansji",
+    "FR-05" => "Alpy Wysokie",
+    "FR-06" => "Alpy Nadmorskie",
+    "FR-07" => "Ardèche",
+    "FR-08" => "Ardeny",
+    "FR-09" => "Ariège",
+    "FR-10" => "Aube",
+    "FR-11" => "Aude",
+    "FR-12" => "Aveyron",
+    "FR-13" => "Delta Rodanu",
+    "FR-14" => "Calvados",
+    "FR-15" => "Cantal",
+    "FR-16" => "Charente",
+    "FR-17" => "Charente-Maritime",
+    "FR-18" => "Cher",
+    "FR-19" => "Corrèze",
+    "FR-20R" => "Korsyka",
+    "FR-21" => "Côte-d'Or",
+    "FR-22" => "Côtes-d'Armor",
+    "FR-23" => "Creuse",
+    "FR-24" => "Dordogne",
+    "FR-25" => "Doubs",
+    "FR-26" => "Drôme",
+    "FR-27" => "Eure",
+    "FR-28" => "Eure-et-Loir",
+    "FR-29" => "Finistère",
+    "FR-2A" => "Korsyka Południowa",
+    "FR-2B" => "Górna Korsyka",
+    "FR-30" => "Gard",
+    "FR-31" => "Górna Garonna",
+    "FR-32" => "Gers",
+    "FR-33" => "Żyronda",
+    "FR-34" => "Hérault",
+    "FR-35" => "Ille-et-Vilaine",
+    "FR-36" => "Indre",
+    "FR-37" => "Indre-et-Loire",
+    "FR-38" => "Isère",
+    "FR-39" => "Jura",
+    "FR-40" => "Landy",
+    "FR-41" => "Loir-et-Cher",
+    "FR-42" => "Loara",
+    "FR-43" => "Górna Loara",
+    "FR-44" => "Loara Atlantycka",
+    "FR-45" => "Loiret",
+    "FR-46" => "Lot",
+    "FR-47" => "Lot-et-Garonne",
+    "FR-48" => "Lozère",
+    "FR-49" => "Maine-et-Loire",
+    "FR-50" => "Manche",
+    "FR-51" => "Marna",
+    "FR-52" => "Górna Marna",
+    "FR-53" => "Mayenne",
+    "FR-54" => "Meurthe-et-Moselle",
+    "FR-55" => "Moza",
+    "FR-56" => "Morbihan",
+    "FR-57" => "Mozela",
+    "FR-58" => "Nièvre",
+    "FR-59" => "Nord / Północny / Północna",
+    "FR-60" => "Oise",
+    "FR-61" => "Orne",
+    "FR-62" => "Pas-de-Calais",
+    "FR-63" => "Puy-de-Dôme",
+    "FR-64" => "Pireneje Atlantyckie",
+    "FR-65" => "Pireneje Wysokie",
+    "FR-66" => "Pireneje Wschodnie",
+    "FR-67" => "Dolny Ren",
+    "FR-68" => "Górny Ren",
+    "FR-69" => "Rodan",
+    "FR-70" => "Górna Saona",
+    "FR-71" => "Saona i Loara",
+    "FR-72" => "Sarthe",
+    "FR-73" => "Sabaudia",
+    "FR-74" => "Górna Sabaudia",
+    "FR-76" => "Seine-Maritime",
+    "FR-77" => "Sekwana i Marna",
+    "FR-78" => "Yvelines",
+    "FR-79" => "Deux-Sèvres",
+    "FR-80" => "Somma",
+    "FR-81" => "Tarn",
+    "FR-82" => "Tarn i Garonna",
+    "FR-83" => "Var",
+    "FR-84" => "Vaucluse",
+    "FR-85" => "Wandea",
+    "FR-86" => "Vienne",
+    "FR-87" => "Haute-Vienne",
+    "FR-88" => "Wogezy",
+    "FR-89" => "Yonne",
+    "FR-90" => "Territoire de Belfort",
+    "FR-91" => "Esonne",
+    "FR-92" => "Hauts-de-Seine",
+    "FR-93" => "Seine-Saint-Denis",
+    "FR-94" => "Dolina Marny",
+    "FR-95" => "Dolina Oise",
+    "FR-971" => "Gwadelupa",
+    "FR-972" => "Martynika",
+    "FR-973" => "Gujana Francuska",
+    "FR-974" => "Reunion",
+    "FR-976" => "Majotta",
+    "FR-ARA" => "Owernia-Rodan-Alpy",
+    "FR-BFC" => "Burgundia-Franche-Comté",
+    "FR-BL" => "Saint-Barthélemy",
+    "FR-BRE" => "Bretania",
+    "FR-CP" => "Clipperton",
+    "FR-CVL" => "Centralny-Dolina Loary",
+    "FR-GES" => "Grand Est",
+    "FR-HDF" => "Hauts-de-France",
+    "FR-IDF" => "Île-de-France",
+    "FR-MF" => "Saint-Martin",
+    "FR-NAQ" => "Nowa Akwitania",
+    "FR-NC" => "Nowa Kaledonia",
+    "FR-NOR" => "Normandia",
+    "FR-OCC" => "Oksytania",
+    "FR-PAC" => "Prowansja-Alpy-Lazurowe Wybrzeże",
+    "FR-PDL" => "Kraj Loary",
+    "FR-PF" => "Polinezja Francuska",
+    "FR-PM" => "Saint-Pierre i Miquelon",
+    "FR-TF" => "Francuskie Terytoria Południowe",
+    "FR-WF" => "Wallis i Futuna",
+    "GA-1" => "Estuaire",
+    "GA-2" => "Górne Ogowe",
+    "GA-3" => "Środkowe Ogowe",
+    "GA-4" => "Ngounié",
+    "GA-5" => "nyanga",
+    "GA-6" => "Ogowe-Ivindo",
+    "GA-7" => "Ogowe-Lolo",
+    "GA-8" => "Ogowe Nadmorskie",
+    "GA-9" => "Woleu-Ntem",
+    "GM-B" => "Bandżul",
+    "GM-L" => "Lower River",
+    "GM-M" => "Central River",
+    "GM-N" => "North Bank",
+    "GM-U" => "Upper River",
+    "GM-W" => "Dystrykt Zachodni / Region Zachodni / Western Division / Prowincja Zachodnia",
+    "GE-AB" => "Abchazja",
+    "GE-AJ" => "Adżaria",
+    "GE-GU" => "Guria",
+    "GE-IM" => "Imeretia",
+    "GE-KA" => "Kachetia",
+    "GE-KK" => "Dolna Kartlia",
+    "GE-MM" => "Mccheta-Mtianetia",
+    "GE-RL" => "Racza-Leczchumi i Dolna Swanetia",
+    "GE-SJ" => "Samcche-Dżawachetia",
+    "GE-SK" => "Wewnętrzna Kartlia",
+    "GE-SZ" => "Megrelia-Górna Swanetia",
+    "GE-TB" => "Tbilisi",
+    "DE-BB" => "Brandenburgia",
+    "DE-BE" => "Berlin",
+    "DE-BW" => "Badenia-Wirtembergia",
+    "DE-BY" => "Bawaria",
+    "DE-HB" => "Brema",
+    "DE-HE" => "Hesja",
+    "DE-HH" => "Hamburg",
+    "DE-MV" => "Meklemburgia-Pomorze Przednie",
+    "DE-NI" => "Dolna Saksonia",
+    "DE-NW" => "Nadrenia Północna-Westfalia",
+    "DE-RP" => "Nadrenia-Palatynat",
+    "DE-SH" => "Szlezwik-Holsztyn",
+    "DE-SL" => "Saara",
+    "DE-SN" => "Saksonia",
+    "DE-ST" => "Saksonia-Anhalt",
+    "DE-TH" => "Turyngia",
+    "GH-AA" => "Wielka Akra",
+    "GH-AF" => "Ahafo",
+    "GH-AH" => "Aszanti",
+    "GH-BE" => "Bono Wschodnie",
+    "GH-BO" => "Bono",
+    "GH-CP" => "Dystrykt Centralny / Region Centralny / Prowincja Centralna / Departament Centralny",
+    "GH-EP" => "Dystrykt Wschodni / Region Wschodni / Prowincja Wschodnia",
+    "GH-NE" => "Północno-Wschodni",
+    "GH-NP" => "Dystrykt Północny / Region Północny / Prowincja Północna",
+    "GH-OT" => "Oti",
+    "GH-SV" => "Savannah",
+    "GH-TV" => "Wolta",
+    "GH-UE" => "Górny Region Wschodni",
+    "GH-UW" => "Górny Region Zachodni",
+    "GH-WN" => "Region Północno-Zachodni",
+    "GH-WP" => "Dystrykt Zachodni / Region Zachodni / Western Division / Prowincja Zachodnia",
+    "GR-69" => "Athos",
+    "GR-A" => "Macedonia Wschodnia i Tracja",
+    "GR-B" => "Macedonia Środkowa",
+    "GR-C" => "Macedonia Zachodnia",
+    "GR-D" => "Epir",
+    "GR-E" => "Tesalia",
+    "GR-F" => "Wyspy Jońskie",
+    "GR-G" => "Grecja Zachodnia",
+    "GR-H" => "Grecja Środkowa",
+    "GR-I" => "Attyka",
+    "GR-J" => "Peloponez",
+    "GR-K" => "Wyspy Egejskie Północne",
+    "GR-L" => "Wyspy Egejskie Południowe",
+    "GR-M" => "Kreta",
+    "GL-AV" => "Avannaata",
+    "GL-KU" => "Kujalleq",
+    "GL-QE" => "Qeqqata",
+    "GL-QT" => "Qeqertalik",
+    "GL-SM" => "Sermersooq",
+    "GD-01" => "Saint Andrew",
+    "GD-02" => "Saint David",
+    "GD-03" => "Saint George",
+    "GD-04" => "Saint John",
+    "GD-05" => "Saint Mark",
+    "GD-06" => "Saint Patrick",
+    "GD-10" => "Carriacou i Mała Martynika",
+    "GN-B" => "Boké",
+    "GN-BE" => "Beyla",
+    "GN-BF" => "Boffa",
+    "GN-BK" => "Boké",
+    "GN-C" => "Region Konakry",
+    "GN-CO" => "Coyah",
+    "GN-D" => "Kindia",
+    "GN-DB" => "Dabola",
+    "GN-DI" => "Dinguiraye",
+    "GN-DL" => "Dalaba",
+    "GN-DU" => "Dubréka",
+    "GN-F" => "Faranah",
+    "GN-FA" => "Faranah",
+    "GN-FO" => "Forécariah",
+    "GN-FR" => "Fria",
+    "GN-GA" => "Gaoual",
+    "GN-GU" => "Guéckédou",
+    "GN-K" => "Kankan",
+    "GN-KA" => "Kankan",
+    "GN-KB" => "Koubia",
+    "GN-KD" => "Kindia",
+    "GN-KE" => "Kérouané",
+    "GN-KN" => "Koundara",
+    "GN-KO" => "Kouroussa",
+    "GN-KS" => "Kissidougou",
+    "GN-L" => "Labé",
+    "GN-LA" => "Labé",
+    "GN-LE" => "Lélouma",
+    "GN-LO" => "Lola",
+    "GN-M" => "Mamou",
+    "GN-MC" => "Macenta",
+    "GN-MD" => "Mandiana",
+    "GN-ML" => "Mali",
+    "GN-MM" => "Mamou",
+    "GN-N" => "Nzérékoré",
+    "GN-NZ" => "Nzérékoré",
+    "GN-PI" => "Pita",
+    "GN-SI" => "Siguiri",
+    "GN-TE" => "Télimélé",
+    "GN-TO" => "Tougué",
+    "GN-YO" => "Yomou",
+    "GW-BA" => "Bafatá",
+    "GW-BL" => "Bolama / Bijagós",
+    "GW-BM" => "Biombo",
+    "GW-BS" => "Bissau",
+    "GW-CA" => "Cacheu",
+    "GW-GA" => "Gabú",
+    "GW-L" => "Wschodnia",
+    "GW-N" => "Północna",
+    "GW-OI" => "Oio",
+    "GW-QU" => "Quinara",
+    "GW-S" => "Południowa",
+    "GW-TO" => "Tombali",
+    "GY-BA" => "Barima-Waini",
+    "GY-CU" => "Cuyuni-Mazaruni",
+    "GY-DE" => "Demerara-Mahaica",
+    "GY-EB" => "East Berbice-Corentyne",
+    "GY-ES" => "Essequibo Islands-West Demerara",
+    "GY-MA" => "Mahaica-Berbice",
+    "GY-PM" => "Pomeroon-Supenaam",
+    "GY-PT" => "Potaro-Siparuni",
+    "GY-UD" => "Upper Demerara-Berbice",
+    "GY-UT" => "Upper Takutu-Upper Essequibo",
+    "HT-AR" => "Artibonite",
+    "HT-CE" => "Centralny",
+    "HT-GA" => "Grand’Anse",
+    "HT-ND" => "Nord / Północny / Północna",
+    "HT-NE" => "Północno-Wschodni",
+    "HT-NI" => "Nippes",
+    "HT-NO" => "Północno-Zachodni",
+    "HT-OU" => "Wschodni",
+    "HT-SD" => "Południowy",
+    "HT-SE" => "Południowo-Wschodni",
+    "HN-AT" => "Atlántida",
+    "HN-CH" => "Choluteca",
+    "HN-CL" => "Colón",
+    "HN-CM" => "Comayagua",
+    "HN-CP" => "Copán",
+    "HN-CR" => "Cortés",
+    "HN-EP" => "El Paraíso",
+    "HN-FM" => "Francisco Morazán",
+    "HN-GD" => "Gracias a Dios",
+    "HN-IB" => "Wyspy Bahia",
+    "HN-IN" => "Intibucá",
+    "HN-LE" => "Lempira",
+    "HN-LP" => "La Paz",
+    "HN-OC" => "Ocotepeque",
+    "HN-OL" => "Olancho",
+    "HN-SB" => "Santa Bárbara",
+    "HN-VA" => "Valle",
+    "HN-YO" => "Yoro",
+    "HU-BA" => "Baranya",
+    "HU-BC" => "Békéscsaba",
+    "HU-BE" => "Békés",
+    "HU-BK" => "Bács-Kiskun",
+    "HU-BU" => "Budapeszt",
+    "HU-BZ" => "Borsod-Abaúj-Zemplén",
+    "HU-CS" => "Csongrád",
+    "HU-DE" => "Debreczyn",
+    "HU-DU" => "Dunaújváros",
+    "HU-EG" => "Eger",
+    "HU-ER" => "Érd",
+    "HU-FE" => "Fejér",
+    "HU-GS" => "Győr-Moson-Sopron",
+    "HU-GY" => "Győr",
+    "HU-HB" => "Hajdú-Bihar",
+    "HU-HE" => "Heves",
+    "HU-HV" => "Hódmezővásárhely",
+    "HU-JN" => "Jász-Nagykun-Szolnok",
+    "HU-KE" => "Komárom-Esztergom",
+    "HU-KM" => "Kecskemét",
+    "HU-KV" => "Kaposvár",
+    "HU-MI" => "Miszkolc",
+    "HU-NK" => "Nagykanizsa",
+    "HU-NO" => "Nógrád",
+    "HU-NY" => "Nyíregyháza",
+    "HU-PE" => "Peszt",
+    "HU-PS" => "Pecz",
+    "HU-SD" => "Segedyn",
+    "HU-SF" => "Székesfehérvár",
+    "HU-SH" => "Szombathely",
+    "HU-SK" => "Szolnok",
+    "HU-SN" => "Sopron",
+    "HU-SO" => "Somogy",
+    "HU-SS" => "Szekszárd",
+    "HU-ST" => "Salgótarján",
+    "HU-SZ" => "Szabolcs-Szatmár-Bereg",
+    "HU-TB" => "Tatabánya",
+    "HU-TO" => "Tolna",
+    "HU-VA" => "Vas",
+    "HU-VE" => "Veszprém",
+    "HU-VM" => "Veszprém",
+    "HU-ZA" => "Zala",
+    "HU-ZE" => "Zalaegerszeg",
+    "IS-1" => "Höfuðborgarsvæði",
+    "IS-2" => "Suðurnes",
+    "IS-3" => "Vesturland",
+    "IS-4" => "Vestfirðir",
+    "IS-5" => "Norðurland vestra",
+    "IS-6" => "Norðurland eystra",
+    "IS-7" => "Austurland",
+    "IS-8" => "Suðurland",
+    "IS-AKN" => "Akraneskaupstaður",
+    "IS-AKU" => "Akureyrarbær",
+    "IS-ARN" => "Árneshreppur",
+    "IS-ASA" => "Ásahreppur",
+    "IS-BLA" => "Bláskógabyggð",
+    "IS-BOG" => "Borgarbyggð",
+    "IS-BOL" => "Bolungarvíkurkaupstaður",
+    "IS-DAB" => "Dalabyggð",
+    "IS-DAV" => "Dalvíkurbyggð",
+    "IS-EOM" => "Eyja- og Miklaholtshreppur",
+    "IS-EYF" => "Eyjafjarðarsveit",
+    "IS-FJD" => "Fjarðabyggð",
+    "IS-FJL" => "Fjallabyggð",
+    "IS-FLA" => "Flóahreppur",
+    "IS-FLR" => "Fljótsdalshreppur",
+    "IS-GAR" => "Garðabær",
+    "IS-GOG" => "Grímsnes- og Grafningshreppur",
+    "IS-GRN" => "Grindavíkurbær",
+    "IS-GRU" => "Grundarfjarðarbær",
+    "IS-GRY" => "Grýtubakkahreppur",
+    "IS-HAF" => "Hafnarfjarðarkaupstaður",
+    "IS-HRG" => "Hörgársveit",
+    "IS-HRU" => "Hrunamannahreppur",
+    "IS-HUV" => "Húnaþing vestra",
+    "IS-HVA" => "Hvalfjarðarsveit",
+    "IS-HVE" => "Hveragerðisbær",
+    "IS-ISA" => "Ísafjarðarbær",
+    "IS-KAL" => "Kaldrananeshreppur",
+    "IS-KJO" => "Kjósarhreppur",
+    "IS-KOP" => "Kópavogsbær",
+    "IS-LAN" => "Langanesbyggð",
+    "IS-MOS" => "Mosfellsbær",
+    "IS-MYR" => "Mýrdalshreppur",
+    "IS-NOR" => "Norðurþing",
+    "IS-RGE" => "Rangárþing eystra",
+    "IS-RGY" => "Rangárþing ytra",
+    "IS-RHH" => "Reykhólahreppur",
+    "IS-RKN" => "Reykjanesbær",
+    "IS-RKV" => "Reykjavíkurborg",
+    "IS-SBT" => "Svalbarðsstrandarhreppur",
+    "IS-SDN" => "Suðurnesjabær",
+    "IS-SDV" => "Súðavíkurhreppur",
+    "IS-SEL" => "Seltjarnarnesbær",
+    "IS-SFA" => "Sveitarfélagið Árborg",
+    "IS-SHF" => "Sveitarfélagið Hornafjörður",
+    "IS-SKF" => "Skaftárhreppur",
+    "IS-SKG" => "Skagabyggð",
+    "IS-SKO" => "Skorradalshreppur",
+    "IS-SNF" => "Snæfellsbær",
+    "IS-SOG" => "Skeiða- og Gnúpverjahreppur",
+    "IS-SOL" => "Sveitarfélagið Ölfus",
+    "IS-SSS" => "Sveitarfélagið Skagaströnd",
+    "IS-STR" => "Strandabyggð",
+    "IS-STY" => "Stykkishólmsbær",
+    "IS-SVG" => "Sveitarfélagið Vogar",
+    "IS-TAL" => "Tálknafjarðarhreppur",
+    "IS-THG" => "Þingeyjarsveit",
+    "IS-TJO" => "Tjörneshreppur",
+    "IS-VEM" => "Vestmannaeyjabær",
+    "IS-VER" => "Vesturbyggð",
+    "IS-VOP" => "Vopnafjarðarhreppur",
+    "IN-AN" => "Andamany i Nikobary",
+    "IN-AP" => "Andhra Pradesh",
+    "IN-AR" => "Arunachal Pradesh",
+    "IN-AS" => "Asam",
+    "IN-BR" => "Bihar",
+    "IN-CH" => "Czandigarh",
+    "IN-DH" => "Dadra i Nagarhaweli",
+    "IN-DL" => "Delhi",
+    "IN-GA" => "Goa",
+    "IN-GJ" => "Gudźarat",
+    "IN-HP" => "Himachal Pradesh",
+    "IN-HR" => "Haryana",
+    "IN-JH" => "Jharkhand",
+    "IN-JK" => "Dżammu i Kaszmir",
+    "IN-KA" => "Karnataka",
+    "IN-KL" => "Kerala",
+    "IN-LA" => "Ladakh",
+    "IN-LD" => "Lakszadiwy",
+    "IN-MH" => "Maharasztra",
+    "IN-ML" => "Meghalaya",
+    "IN-MN" => "Manipur",
+    "IN-MP" => "Madhya Pradesh",
+    "IN-MZ" => "Mizoram",
+    "IN-NL" => "Nagaland",
+    "IN-PB" => "Pendżab",
+    "IN-PY" => "Puducherry",
+    "IN-RJ" => "Radżastan",
+    "IN-SK" => "Sikkim",
+    "IN-TN" => "Tamilnadu",
+    "IN-TR" => "Tripura",
+    "IN-UP" => "Uttar Pradesh",
+    "IN-WB" => "Bengal Zachodni",
+    "ID-AC" => "Aceh",
+    "ID-BA" => "Bali",
+    "ID-BB" => "Wyspy Bangka i Belitung",
+    "ID-BE" => "Bengkulu",
+    "ID-BT" => "Banten",
+    "ID-GO" => "Gorontalo",
+    "ID-JA" => "Jambi",
+    "ID-JB" => "Jawa Zachodnia",
+    "ID-JI" => "Jawa Wschodnia",
+    "ID-JK" => "Dżakarta",
+    "ID-JT" => "Jawa Środkowa",
+    "ID-JW" => "Jawa",
+    "ID-KA" => "Borneo",
+    "ID-KB" => "Borneo Zachodnie",
+    "ID-KI" => "Borneo Wschodnie",
+    "ID-KR" => "Archipelag Riau",
+    "ID-KS" => "Borneo Południowe",
+    "ID-KT" => "Borneo Środkowe",
+    "ID-KU" => "Borneo Północne",
+    "ID-LA" => "Lampung",
+    "ID-MA" => "Moluki",
+    "ID-ML" => "Moluki",
+    "ID-MU" => "Moluki Północne",
+    "ID-NB" => "Małe Wyspy Sundajskie Zachodnie",
+    "ID-NT" => "Małe Wyspy Sundajskie Wschodnie",
+    "ID-NU" => "Małe Wyspy Sundajskie",
+    "ID-PA" => "Papua",
+    "ID-PB" => "Papua Zachodnia",
+    "ID-PP" => "Papua",
+    "ID-RI" => "Riau",
+    "ID-SA" => "Celebes Północny",
+    "ID-SB" => "Sumatra Zachodnia",
+    "ID-SG" => "Celebes Południowo-Wschodni",
+    "ID-SL" => "Celebes",
+    "ID-SM" => "Sumatra",
+    "ID-SN" => "Celebes Południowy",
+    "ID-SR" => "Celebes Zachodni",
+    "ID-SS" => "Sumatra Południowa",
+    "ID-ST" => "Celebes Środkowy",
+    "ID-SU" => "Sumatra Północna",
+    "ID-YO" => "Yogyakarta",
+    "IR-00" => "Markazi",
+    "IR-01" => "Gilan",
+    "IR-02" => "Mazandaran",
+    "IR-03" => "Azerbejdżan Wschodni",
+    "IR-04" => "Azerbejdżan Zachodni",
+    "IR-05" => "Kermanszach",
+    "IR-06" => "Chuzestan",
+    "IR-07" => "Fars",
+    "IR-08" => "Kerman",
+    "IR-09" => "Chorasan-e Razawi",
+    "IR-10" => "Isfahan",
+    "IR-11" => "Sistan i Beludżystan",
+    "IR-12" => "Kurdystan",
+    "IR-13" => "Hamadan",
+    "IR-14" => "Czahar Mahal wa Bachtijari",
+    "IR-15" => "Lorestan",
+    "IR-16" => "Ilam",
+    "IR-17" => "Kohgiluje wa Bujerahmad",
+    "IR-18" => "Buszehr",
+    "IR-19" => "Zandżan",
+    "IR-20" => "Semnan",
+    "IR-21" => "Jazd",
+    "IR-22" => "Hormozgan",
+    "IR-23" => "Teheran",
+    "IR-24" => "Ardabil",
+    "IR-25" => "Kom",
+    "IR-26" => "Kazwin",
+    "IR-27" => "Golestan",
+    "IR-28" => "Chorasan Północny",
+    "IR-29" => "Chorasan Południowy",
+    "IR-30" => "Alborz",
+    "IQ-AN" => "Al-Anbar",
+    "IQ-AR" => "Irbil",
+    "IQ-BA" => "Basra",
+    "IQ-BB" => "Babilon",
+    "IQ-BG" => "Bagdad",
+    "IQ-DA" => "Dahuk",
+    "IQ-DI" => "Dijala",
+    "IQ-DQ" => "Zi Kar",
+    "IQ-KA" => "Karbala",
+    "IQ-KI" => "Kirkuk",
+    "IQ-MA" => "Majsan",
+    "IQ-MU" => "Al-Musanna",
+    "IQ-NA" => "An-Nadżaf",
+    "IQ-NI" => "Niniwa",
+    "IQ-QA" => "Al-Kadisijja",
+    "IQ-SD" => "Salah Ad-Din",
+    "IQ-SU" => "As-Sulajmanijja",
+    "IQ-WA" => "Wasit",
+    "IE-C" => "Connacht",
+    "IE-CE" => "Clare",
+    "IE-CN" => "Cavan",
+    "IE-CO" => "Cork",
+    "IE-CW" => "Carlow",
+    "IE-D" => "Dublin",
+    "IE-DL" => "Donegal",
+    "IE-G" => "Galway",
+    "IE-KE" => "Kildare",
+    "IE-KK" => "Kilkenny",
+    "IE-KY" => "Kerry",
+    "IE-L" => "Leinster",
+    "IE-LD" => "Longford",
+    "IE-LH" => "Louth",
+    "IE-LK" => "Limerick",
+    "IE-LM" => "Leitrim",
+    "IE-LS" => "Laois",
+    "IE-M" => "Munster",
+    "IE-MH" => "Meath",
+    "IE-MN" => "Monaghan",
+    "IE-MO" => "Mayo",
+    "IE-OY" => "Offaly",
+    "IE-RN" => "Roscommon",
+    "IE-SO" => "Sligo",
+    "IE-TA" => "Tipperary",
+    "IE-U" => "Ulster",
+    "IE-WD" => "Waterford",
+    "IE-WH" => "Westmeath",
+    "IE-WW" => "Wicklow",
+    "IE-WX" => "Wexford",
+    "IL-D" => "Region Południowy",
+    "IL-HA" => "Hajfa",
+    "IL-JM" => "Jerozolima",
+    "IL-M" => "Region Centralny",
+    "IL-TA" => "Tel Awiw",
+    "IL-Z" => "Dystrykt Północny",
+    "IT-21" => "Piemont",
+    "IT-23" => "Dolina Aosty",
+    "IT-25" => "Lombardia",
+    "IT-32" => "Trydent-Górna Adyga",
+    "IT-34" => "Wenecja Euganejska",
+    "IT-36" => "Friuli-Wenecja Julijska",
+    "IT-42" => "Liguria",
+    "IT-45" => "Emilia-Romania",
+    "IT-52" => "Toskania",
+    "IT-55" => "Umbria",
+    "IT-57" => "Marche",
+    "IT-62" => "Lacjum",
+    "IT-65" => "Abruzja",
+    "IT-67" => "Molise",
+    "IT-72" => "Kampania",
+    "IT-75" => "Apulia",
+    "IT-77" => "Basilicata",
+    "IT-78" => "Kalabria",
+    "IT-82" => "Sycylia",
+    "IT-88" => "Sardynia",
+    "IT-AG" => "Agrigento",
+    "IT-AL" => "Alessandria",
+    "IT-AN" => "Ankona",
+    "IT-AP" => "Ascoli Piceno",
+    "IT-AQ" => "L'Aquila",
+    "IT-AR" => "Arezzo",
+    "IT-AT" => "Asti",
+    "IT-AV" => "Avellino",
+    "IT-BA" => "Bari",
+    "IT-BG" => "Bergamo",
+    "IT-BI" => "Biella",
+    "IT-BL" => "Belluno",
+    "IT-BN" => "Benewent",
+    "IT-BO" => "Bolonia",
+    "IT-BR" => "Brindisi",
+    "IT-BS" => "Brescia",
+    "IT-BT" => "Barletta-Andria-Trani",
+    "IT-BZ" => "Bolzano",
+    "IT-CA" => "Cagliari",
+    "IT-CB" => "Campobasso",
+    "IT-CE" => "Caserta",
+    "IT-CH" => "Chieti",
+    "IT-CL" => "Caltanissetta",
+    "IT-CN" => "Cuneo",
+    "IT-CO" => "Como",
+    "IT-CR" => "Cremona",
+    "IT-CS" => "Cosenza",
+    "IT-CT" => "Katania",
+    "IT-CZ" => "Catanzaro",
+    "IT-EN" => "Enna",
+    "IT-FC" => "Forli-Cesena",
+    "IT-FE" => "Ferrara",
+    "IT-FG" => "Foggia",
+    "IT-FI" => "Florencja",
+    "IT-FM" => "Fermo",
+    "IT-FR" => "Frosinone",
+    "IT-GE" => "Genua",
+    "IT-GO" => "Gorycja",
+    "IT-GR" => "Grosseto",
+    "IT-IM" => "Imperia",
+    "IT-IS" => "Isernia",
+    "IT-KR" => "Crotone",
+    "IT-LC" => "Lecco",
+    "IT-LE" => "Lecce",
+    "IT-LI" => "Livorno",
+    "IT-LO" => "Lodi",
+    "IT-LT" => "Latina",
+    "IT-LU" => "Lukka",
+    "IT-MB" => "Monza i Brianza",
+    "IT-MC" => "Macerata",
+    "IT-ME" => "Mesyna",
+    "IT-MI" => "Mediolan",
+    "IT-MN" => "Mantua",
+    "IT-MO" => "Modena",
+    "IT-MS" => "Massa-Carrara",
+    "IT-MT" => "Matera",
+    "IT-NA" => "Neapol",
+    "IT-NO" => "Novara",
+    "IT-NU" => "Nuoro",
+    "IT-OR" => "Oristano",
+    "IT-PA" => "Palermo",
+    "IT-PC" => "Piacenza",
+    "IT-PD" => "Padwa",
+    "IT-PE" => "Pescara",
+    "IT-PG" => "Perugia",
+    "IT-PI" => "Piza",
+    "IT-PN" => "Pordenone",
+    "IT-PO" => "Prato",
+    "IT-PR" => "Parma",
+    "IT-PT" => "Pistoia",
+    "IT-PU" => "Pesaro e Urbino",
+    "IT-PV" => "Pawia",
+    "IT-PZ" => "Potenza",
+    "IT-RA" => "Rawenna",
+    "IT-RC" => "Reggio di Calabria",
+    "IT-RE" => "Reggio Emilia",
+    "IT-RG" => "Ragusa",
+    "IT-RI" => "Rieti",
+    "IT-RM" => "Rzym",
+    "IT-RN" => "Rimini",
+    "IT-RO" => "Rovigo",
+    "IT-SA" => "Salerno",
+    "IT-SI" => "Siena",
+    "IT-SO" => "Sondrio",
+    "IT-SP" => "La Spezia",
+    "IT-SR" => "Syrakuzy",
+    "IT-SS" => "Sassari",
+    "IT-SU" => "Sud Sardegna",
+    "IT-SV" => "Savona",
+    "IT-TA" => "Tarent",
+    "IT-TE" => "Teramo",
+    "IT-TN" => "Trydent",
+    "IT-TO" => "Turyn",
+    "IT-TP" => "Trapani",
+    "IT-TR" => "Terni",
+    "IT-TS" => "Triest",
+    "IT-TV" => "Treviso",
+    "IT-UD" => "Udine",
+    "IT-VA" => "Varese",
+    "IT-VB" => "Verbano-Cusio-Ossola",
+    "IT-VC" => "Vercelli",
+    "IT-VE" => "Wenecja",
+    "IT-VI" => "Vicenza",
+    "IT-VR" => "Werona",
+    "IT-VT" => "Viterbo",
+    "IT-VV" => "Vibo Valentia",
+    "JM-01" => "Kingston",
+    "JM-02" => "Saint Andrew",
+    "JM-03" => "Saint Thomas",
+    "JM-04" => "Portland",
+    "JM-05" => "Saint Mary",
+    "JM-06" => "Saint Ann",
+    "JM-07" => "Trelawny",
+    "JM-08" => "Saint James",
+    "JM-09" => "Hanover",
+    "JM-10" => "Westmoreland",
+    "JM-11" => "Saint Elizabeth",
+    "JM-12" => "Manchester",
+    "JM-13" => "Clarendon",
+    "JM-14" => "Saint Catherine",
+    "JP-01" => "Hokkaido",
+    "JP-02" => "Aomori",
+    "JP-03" => "Iwate",
+    "JP-04" => "Miyagi",
+    "JP-05" => "Akita",
+    "JP-06" => "Yamagata",
+    "JP-07" => "Fukushima",
+    "JP-08" => "Ibaraki",
+    "JP-09" => "Tochigi",
+    "JP-10" => "Gunma",
+    "JP-11" => "Saitama",
+    "JP-12" => "Chiba",
+    "JP-13" => "Tokio",
+    "JP-14" => "Kanagawa",
+    "JP-15" => "Niigate",
+    "JP-16" => "Toyama",
+    "JP-17" => "Ishikawa",
+    "JP-18" => "Fukui",
+    "JP-19" => "Yamanashi",
+    "JP-20" => "Nagano",
+    "JP-21" => "Gifu",
+    "JP-22" => "Shizuoka",
+    "JP-23" => "Aichi",
+    "JP-24" => "Mie",
+    "JP-25" => "Shiga",
+    "JP-26" => "Kioto",
+    "JP-27" => "Osaka",
+    "JP-28" => "Hyogo",
+    "JP-29" => "Nara",
+    "JP-30" => "Wakayama",
+    "JP-31" => "Tottori",
+    "JP-32" => "Shimane",
+    "JP-33" => "Okayama",
+    "JP-34" => "Hiroszima",
+    "JP-35" => "Yamaguchi",
+    "JP-36" => "Tokushima",
+    "JP-37" => "Kagawa",
+    "JP-38" => "Ehime",
+    "JP-39" => "Kochi",
+    "JP-40" => "Fukuoka",
+    "JP-41" => "Saga",
+    "JP-42" => "Nagasaki",
+    "JP-43" => "Kumamoto",
+    "JP-44" => "Oita",
+    "JP-45" => "Miyazaki",
+    "JP-46" => "Kagoshima",
+    "JP-47" => "Okinawa",
+    "JO-AJ" => "Adżlun",
+    "JO-AM" => "Prowincja Stołeczna",
+    "JO-AQ" => "Akaba",
+    "JO-AT" => "At-Tafila",
+    "JO-AZ" => "Az-Zarka",
+    "JO-BA" => "Al-Balka",
+    "JO-IR" => "Irbid",
+    "JO-JA" => "Dżarasz",
+    "JO-KA" => "Al-Karak",
+    "JO-MA" => "Al-Mafrak",
+    "JO-MD" => "Madaba",
+    "JO-MN" => "Ma'an",
+    "KE-01" => "Baringo",
+    "KE-02" => "Bomet",
+    "KE-03" => "Bungoma",
+    "KE-04" => "Busia",
+    "KE-05" => "Elgeyo-Marakwet",
+    "KE-06" => "Embu",
+    "KE-07" => "Garissa",
+    "KE-08" => "Homa Bay",
+    "KE-09" => "Isiolo",
+    "KE-10" => "Kajiado",
+    "KE-11" => "Kakamega",
+    "KE-12" => "Kericho",
+    "KE-13" => "Kiambu",
+    "KE-14" => "Kilifi",
+    "KE-15" => "Kirinyaga",
+    "KE-16" => "Kisii",
+    "KE-17" => "Kisumu",
+    "KE-18" => "Kitui",
+    "KE-19" => "Kwale",
+    "KE-20" => "Laikipia",
+    "KE-21" => "Lamu",
+    "KE-22" => "Machakos",
+    "KE-23" => "Makueni",
+    "KE-24" => "Mandera",
+    "KE-25" => "Marsabit",
+    "KE-26" => "Meru",
+    "KE-27" => "Migori",
+    "KE-28" => "Mombasa",
+    "KE-29" => "Murang'a",
+    "KE-30" => "Nairobi",
+    "KE-31" => "Nakuru",
+    "KE-32" => "Nandi",
+    "KE-33" => "Narok",
+    "KE-34" => "Nyamira",
+    "KE-35" => "Nyandarua",
+    "KE-36" => "Nyeri",
+    "KE-37" => "Samburu",
+    "KE-38" => "Siaya",
+    "KE-39" => "Taita-Taveta",
+    "KE-40" => "Tana River",
+    "KE-41" => "Tharaka-Nithi",
+    "KE-42" => "Trans Nzoia",
+    "KE-43" => "Turkana",
+    "KE-44" => "Uasin Gishu",
+    "KE-45" => "Vihiga",
+    "KE-46" => "Wajir",
+    "KE-47" => "West Pokot",
+    "KI-G" => "Wyspy Gilberta",
+    "KI-L" => "Line Islands",
+    "KI-P" => "Wyspy Feniks",
+    "KP-04" => "Chagang",
+    "KP-05" => "Hwanghae Południowe",
+    "KP-06" => "Hwanghae Północne",
+    "KW-FA" => "Al-Farwanija",
+    "KW-KU" => "Al-Asima",
+    "KW-MU" => "Mubarak al-Kabir",
+    "KG-B" => "obwód batkeński",
+    "KG-C" => "obwód czujski",
+    "KG-GB" => "Biszkek",
+    "KG-GO" => "Osz",
+    "KG-J" => "obwód dżalalabadzki",
+    "KG-N" => "obwód naryński",
+    "KG-O" => "obwód oszyński",
+    "KG-T" => "obwód tałaski",
+    "KG-Y" => "obwód issykkulski",
+    "LA-AT" => "Attapeu",
+    "LA-BK" => "Bokeo",
+    "LA-BL" => "Borikhamxai",
+    "LA-CH" => "Champassak",
+    "LA-HO" => "Houaphan",
+    "LA-KH" => "Khammouane",
+    "LA-LM" => "Louang Namtha",
+    "LA-LP" => "Louangphabang",
+    "LA-OU" => "Oudomxay",
+    "LA-PH" => "Phongsaly",
+    "LA-SL" => "Saravane",
+    "LA-SV" => "Savannakhet",
+    "LA-XA" => "Sayaboury",
+    "LA-XE" => "Xekong",
+    "LA-XI" => "Xieng Khouang",
+    "LA-XS" => "Xiasômboun",
+    "LV-002" => "Aizkraukles novads",
+    "LV-007" => "Alūksnes novads",
+    "LV-011" => "Ādažu novads",
+    "LV-015" => "Balvu novads",
+    "LV-016" => "Bauskas novads",
+    "LV-022" => "Powiat Kieś",
+    "LV-026" => "Dobeles novads",
+    "LV-033" => "Gulbenes novads",
+    "LV-041" => "Powiat Jełgawa",
+    "LV-042" => "Jēkabpils novads",
+    "LV-047" => "Krāslavas novads",
+    "LV-050" => "Kuldīgas novads",
+    "LV-052" => "Ķekavas novads",
+    "LV-054" => "Limbažu novads",
+    "LV-056" => "Līvānu novads",
+    "LV-058" => "Powiat Lucyn",
+    "LV-059" => "Madonas novads",
+    "LV-062" => "Mārupes novads",
+    "LV-067" => "Ogres novads",
+    "LV-068" => "Olaines novads",
+    "LV-073" => "Preiļu novads",
+    "LV-077" => "Powiat Rzeżyca",
+    "LV-080" => "Ropažu novads",
+    "LV-087" => "Salaspils novads",
+    "LV-088" => "Saldus novads",
+    "LV-089" => "Saulkrastu novads",
+    "LV-091" => "Siguldas novads",
+    "LV-094" => "Smiltenes novads",
+    "LV-097" => "Talsu novads",
+    "LV-099" => "Tukuma novads",
+    "LV-101" => "Valkas novads",
+    "LV-102" => "Varakļānu novads",
+    "LV-106" => "Powiat Windawa",
+    "LV-DGV" => "Dyneburg",
+    "LV-JEL" => "Jełgawa",
+    "LV-JUR" => "Jurmała",
+    "LV-LPX" => "Lipawa",
+    "LV-REZ" => "Rzeżyca",
+    "LV-RIX" => "Ryga",
+    "LV-VEN" => "Windawa",
+    "LB-AK" => "Akkar",
+    "LB-BH" => "Baalbek-Hirmil",
+    "LS-A" => "Maseru",
+    "LS-C" => "Leribe",
+    "LS-D" => "Berea",
+    "LS-E" => "Mafeteng",
+    "LS-F" => "Mohale's Hoek",
+    "LS-G" => "Quthing",
+    "LS-H" => "Qacha's Nek",
+    "LS-J" => "Mokhotlong",
+    "LS-K" => "Thaba-Tseka",
+    "LR-BG" => "Bong",
+    "LR-BM" => "Bomi",
+    "LR-CM" => "Grand Cape Mount",
+    "LR-GB" => "Grand Bassa",
+    "LR-GG" => "Grand Gedeh",
+    "LR-GK" => "Grand Kru",
+    "LR-LO" => "Lofa",
+    "LR-MG" => "Margibi",
+    "LR-MO" => "Montserrado",
+    "LR-MY" => "Maryland",
+    "LR-NI" => "Nimba",
+    "LR-SI" => "Sinoe",
+    "LY-BA" => "Bengazi",
+    "LY-BU" => "Al-Butnan",
+    "LY-DR" => "Darna",
+    "LY-GT" => "Ghat",
+    "LY-JA" => "Al-Dżabal al-Achdar",
+    "LY-JG" => "Al-Dżabal al-Gharbi",
+    "LY-JU" => "Al-Dżufra",
+    "LY-KF" => "Al-Kufra",
+    "LY-MB" => "Al-Marakib",
+    "LY-MI" => "Misrata",
+    "LY-MJ" => "Al-Mardż",
+    "LY-MQ" => "Marzuk",
+    "LY-NL" => "Nalut",
+    "LY-SB" => "Sabha",
+    "LY-SR" => "Syrta",
+    "LY-TB" => "Trypolis",
+    "LY-WD" => "Wadi al-Hajat",
+    "LY-ZA" => "Az-Zawija",
+    "LI-01" => "Balzers",
+    "LI-02" => "Eschen",
+    "LI-03" => "Gamprin",
+    "LI-04" => "Mauren",
+    "LI-05" => "Planken",
+    "LI-06" => "Ruggell",
+    "LI-07" => "Schaan",
+    "LI-08" => "Schellenberg",
+    "LI-09" => "Triesen",
+    "LI-10" => "Triesenberg",
+    "LI-11" => "Vaduz",
+    "LU-DI" => "Diekirch",
+    "LU-GR" => "Grevenmacher",
+    "LU-LU" => "Luksemburg",
+    "MG-A" => "Toamasina",
+    "MG-D" => "Antsiranana",
+    "MG-F" => "Fianarantsoa",
+    "MG-M" => "Mahajanga",
+    "MG-T" => "Antananarywa",
+    "MG-U" => "Toliara",
+    "MW-BA" => "Balaka",
+    "MW-BL" => "Blantyre",
+    "MW-C" => "Region Centrum",
+    "MW-CK" => "Chikwawa",
+    "MW-CR" => "Chiradzulu",
+    "MW-CT" => "Chitipa",
+    "MW-DE" => "Dedza",
+    "MW-DO" => "Dowa",
+    "MW-KR" => "Karonga",
+    "MW-KS" => "Kasungu",
+    "MW-LI" => "Lilongwe",
+    "MW-LK" => "Likoma",
+    "MW-MC" => "Mchinji",
+    "MW-MG" => "Mangochi",
+    "MW-MH" => "Machinga",
+    "MW-MU" => "Mulanje",
+    "MW-MW" => "Mwanza",
+    "MW-MZ" => "Mzimba",
+    "MW-N" => "Region Północ",
+    "MW-NB" => "Nkhata Bay",
+    "MW-NE" => "Neno",
+    "MW-NI" => "Ntchisi",
+    "MW-NK" => "Nkhotakota",
+    "MW-NS" => "Nsanje",
+    "MW-NU" => "Ntcheu",
+    "MW-PH" => "Phalombe",
+    "MW-RU" => "Rumphi",
+    "MW-S" => "Region Południe",
+    "MW-SA" => "Salima",
+    "MW-TH" => "Thyolo",
+    "MW-ZO" => "Zomba",
+    "MY-01" => "Johor",
+    "MY-02" => "Kedah",
+    "MY-03" => "Kelantan",
+    "MY-04" => "Malakka",
+    "MY-05" => "Negeri Sembilan",
+    "MY-06" => "Pagang",
+    "MY-07" => "Penang",
+    "MY-08" => "Perak",
+    "MY-09" => "Perlis",
+    "MY-10" => "Selangor",
+    "MY-11" => "Terengganu",
+    "MY-12" => "Sabah",
+    "MY-13" => "Sarawak",
+    "MY-14" => "Kuala Lumpur (terytorium federalne)",
+    "MY-15" => "Labuan (terytorium federalne)",
+    "MY-16" => "Putrajaya (terytorium federalne)",
+    "MV-MLE" => "Malé",
+    "ML-1" => "Kayes",
+    "ML-2" => "Koulikoro",
+    "ML-3" => "Sikasso",
+    "ML-4" => "Ségou",
+    "ML-5" => "Mopti",
+    "ML-6" => "Timbuktu",
+    "ML-7" => "Gao",
+    "ML-8" => "Kidal",
+    "ML-BKO" => "Bamako",
+    "MT-01" => "Attard",
+    "MT-02" => "Balzan",
+    "MT-03" => "Birgu",
+    "MT-04" => "Birkirkara",
+    "MT-05" => "Birżebbuġa",
+    "MT-06" => "Bormla",
+    "MT-07" => "Dingli",
+    "MT-08" => "Fgura",
+    "MT-09" => "Floriana",
+    "MT-10" => "Fontana",
+    "MT-11" => "Gudja",
+    "MT-12" => "Gżira",
+    "MT-13" => "Għajnsielem",
+    "MT-14" => "Għarb",
+    "MT-15" => "Għargħur",
+    "MT-16" => "Għasri",
+    "MT-17" => "Għaxaq",
+    "MT-18" => "Ħamrun",
+    "MT-19" => "Iklin",
+    "MT-20" => "Isla",
+    "MT-21" => "Kalkara",
+    "MT-22" => "Kerċem",
+    "MT-23" => "Kirkop",
+    "MT-24" => "Lija",
+    "MT-25" => "Luqa",
+    "MT-26" => "Marsa",
+    "MT-27" => "Marsaskala",
+    "MT-28" => "Marsaxlokk",
+    "MT-29" => "Mdina",
+    "MT-30" => "Mellieħa",
+    "MT-31" => "Mġarr",
+    "MT-32" => "Mosta",
+    "MT-33" => "Mqabba",
+    "MT-34" => "Msida",
+    "MT-35" => "Mtarfa",
+    "MT-36" => "Munxar",
+    "MT-37" => "Nadur",
+    "MT-38" => "Naxxar",
+    "MT-39" => "Paola",
+    "MT-40" => "Pembroke",
+    "MT-41" => "Pietà",
+    "MT-42" => "Qala",
+    "MT-43" => "Qormi",
+    "MT-44" => "Qrendi",
+    "MT-46" => "Rabat (Malta)",
+    "MT-47" => "Safi",
+    "MT-49" => "Saint John",
+    "MT-52" => "Sannat",
+    "MT-54" => "Santa Venera",
+    "MT-55" => "Siġġiewi",
+    "MT-56" => "Sliema",
+    "MT-57" => "Swieqi",
+    "MT-59" => "Tarxien",
+    "MT-60" => "Valletta",
+    "MT-61" => "Xagħra",
+    "MT-62" => "Xewkija",
+    "MT-63" => "Xgħajra",
+    "MT-64" => "Żabbar",
+    "MT-66" => "Żebbuġ (Malta)",
+    "MT-67" => "Żejtun",
+    "MT-68" => "Żurrieq",
+    "MH-ALK" => "Ailuk",
+    "MH-ALL" => "Ailinglaplap",
+    "MH-ARN" => "Arno",
+    "MH-AUR" => "Aur",
+    "MH-EBO" => "Ebon",
+    "MH-JAB" => "Jabat",
+    "MH-JAL" => "Jaluit",
+    "MH-KWA" => "Kwajalein",
+    "MH-L" => "Ralik (grupa)",
+    "MH-LAE" => "Lae",
+    "MH-LIB" => "Lib",
+    "MH-LIK" => "Likiep",
+    "MH-MAJ" => "Majuro",
+    "MH-MAL" => "Maloelap",
+    "MH-MEJ" => "Mejit",
+    "MH-MIL" => "Mili",
+    "MH-NMK" => "Namdrik",
+    "MH-NMU" => "Namu",
+    "MH-RON" => "Rongelap",
+    "MH-T" => "Ratak (grupa)",
+    "MH-UJA" => "Ujae",
+    "MH-WTH" => "Wotho",
+    "MH-WTJ" => "Wotje",
+    "MR-01" => "Haud asz-Szarki",
+    "MR-03" => "Al-Asaba",
+    "MR-04" => "Kurkul",
+    "MR-05" => "Al-Barakina",
+    "MR-06" => "At-Tarariza",
+    "MR-07" => "Adrar",
+    "MR-09" => "Takant",
+    "MR-10" => "Ghidimagha",
+    "MR-11" => "Tiris Zammur",
+    "MR-12" => "Insziri",
+    "MU-AG" => "Wyspy Agalega",
+    "MU-BL" => "Black River",
+    "MU-CC" => "Cargados Carajos",
+    "MU-FL" => "Flacq",
+    "MU-GP" => "Grand Port",
+    "MU-MO" => "Moka",
+    "MU-PA" => "Pamplemousses",
+    "MU-PL" => "Port Louis",
+    "MU-PW" => "Plaines Wilhems",
+    "MU-RO" => "Rodrigues",
+    "MU-RR" => "Rivière du Rempart",
+    "MU-SA" => "Savanne",
+    "MX-AGU" => "Aguascalientes",
+    "MX-BCN" => "Kalifornia Dolna",
+    "MX-BCS" => "Kalifornia Dolna Południowa",
+    "MX-CAM" => "Campeche",
+    "MX-CHH" => "Chihuahua",
+    "MX-CHP" => "Chiapas",
+    "MX-CMX" => "Meksyk",
+    "MX-COA" => "Coahuila de Zaragoza",
+    "MX-COL" => "Colima",
+    "MX-DUR" => "Durango",
+    "MX-GRO" => "Guerrero",
+    "MX-GUA" => "Guanajuato",
+    "MX-HID" => "Hidalgo",
+    "MX-JAL" => "Jalisco",
+    "MX-MEX" => "Meksyk",
+    "MX-MIC" => "Michoacán",
+    "MX-MOR" => "Morelos",
+    "MX-NAY" => "Nayarit",
+    "MX-NLE" => "Nuevo León",
+    "MX-OAX" => "Oaxaca",
+    "MX-PUE" => "Puebla",
+    "MX-QUE" => "Querétaro",
+    "MX-ROO" => "Quintana Roo",
+    "MX-SIN" => "Sinaloa",
+    "MX-SLP" => "San Luis Potosí",
+    "MX-SON" => "Sonora",
+    "MX-TAB" => "Tabasco",
+    "MX-TAM" => "Tamaulipas",
+    "MX-TLA" => "Tlaxcala",
+    "MX-VER" => "Veracruz",
+    "MX-YUC" => "Jukatan",
+    "MX-ZAC" => "Zacatecas",
+    "FM-KSA" => "Kosrae",
+    "FM-PNI" => "Pohnpei",
+    "FM-TRK" => "Chuuk",
+    "FM-YAP" => "Yap",
+    "MD-AN" => "Anenii Noi",
+    "MD-BA" => "Bielce",
+    "MD-BR" => "Briceni",
+    "MD-BS" => "Basarabeasca",
+    "MD-CA" => "Cahul",
+    "MD-CL" => "Călăraşi",
+    "MD-CM" => "Cimişlia",
+    "MD-CR" => "Criuleni",
+    "MD-CS" => "Căuşeni",
+    "MD-CT" => "Cantemir",
+    "MD-CU" => "Kiszyniów",
+    "MD-DO" => "Donduşeni",
+    "MD-DR" => "Drochia",
+    "MD-DU" => "Dubosary",
+    "MD-ED" => "Edineţ",
+    "MD-FA" => "Făleşti",
+    "MD-FL" => "Floreşti",
+    "MD-GL" => "Glodeni",
+    "MD-HI" => "Hînceşti",
+    "MD-IA" => "Ialoveni",
+    "MD-LE" => "Leova",
+    "MD-NI" => "Nisporeni",
+    "MD-OC" => "Ocniţa",
+    "MD-OR" => "Orhei",
+    "MD-RE" => "Rezina",
+    "MD-RI" => "Rîşcani",
+    "MD-SD" => "Şoldăneşti",
+    "MD-SI" => "Sîngerei",
+    "MD-SN" => "Stînga Nistrului (jednostka terytorialna)",
+    "MD-SO" => "Soroca",
+    "MD-ST" => "Străşeni",
+    "MD-SV" => "Ştefan Vodă",
+    "MD-TA" => "Taraclia",
+    "MD-TE" => "Teleneşti",
+    "MD-UN" => "Ungheni",
+    "MC-CL" => "La Colle",
+    "MC-CO" => "La Condamine",
+    "MC-FO" => "Fontvieille",
+    "MC-GA" => "La Gare",
+    "MC-JE" => "Jardin Exotique",
+    "MC-LA" => "Larvotto",
+    "MC-MA" => "Malbousquet",
+    "MC-MC" => "Monte Carlo",
+    "MC-MG" => "Moneghetti",
+    "MC-MO" => "Monaco-Ville",
+    "MC-MU" => "Moulins",
+    "MC-PH" => "Port-Hercule",
+    "MC-SD" => "Sainte-Dévote",
+    "MC-SO" => "La Source",
+    "MC-SP" => "Spélugues",
+    "MC-SR" => "Saint Roman",
+    "MC-VR" => "Vallon de la Rousse",
+    "MN-035" => "ajmak orchoński",
+    "MN-037" => "ajmak darchański",
+    "MN-039" => "ajmak chentejski",
+    "MN-041" => "ajmak chubsugulski",
+    "MN-043" => "ajmak kobdojski",
+    "MN-046" => "ajmak uwski",
+    "MN-047" => "ajmak centralny",
+    "MN-049" => "ajmak selengijski",
+    "MN-051" => "ajmak suchebatorski",
+    "MN-055" => "ajmak południowochangajski",
+    "MN-057" => "ajmak dzawchański",
+    "MN-061" => "ajmak wschodni",
+    "MN-067" => "ajmak bulgański",
+    "MN-069" => "ajmak bajanchongorski",
+    "MN-071" => "ajmak bajanolgijski",
+    "MN-073" => "ajmak północnochangajski",
+    "ME-01" => "Andrijevica",
+    "ME-02" => "Bar",
+    "ME-03" => "Berane",
+    "ME-04" => "Bijelo Polje",
+    "ME-05" => "Budva",
+    "ME-06" => "Cetynia",
+    "ME-07" => "Danilovgrad",
+    "ME-08" => "Herceg Novi",
+    "ME-09" => "Kolašin",
+    "ME-10" => "Kotor",
+    "ME-11" => "Mojkovac",
+    "ME-12" => "Nikšić",
+    "ME-13" => "Plav",
+    "ME-14" => "Pljevlja",
+    "ME-15" => "Plužine",
+    "ME-16" => "Podgorica",
+    "ME-17" => "Rožaje",
+    "ME-18" => "Šavnik",
+    "ME-19" => "Tivat",
+    "ME-20" => "Ulcinj",
+    "ME-21" => "Žabljak",
+    "MA-01" => "Tanger-Tetuan-Al-Husajma",
+    "MA-02" => "Region Wschodni",
+    "MA-03" => "Fez-Meknes",
+    "MA-04" => "Rabat-Sala-Al-Kunajtira",
+    "MA-05" => "Bani Mallal-Chunajfira",
+    "MA-06" => "Casablanca-Sattat",
+    "MA-07" => "Marrakesz-Safi",
+    "MA-08" => "Dara-Tafilalt",
+    "MA-09" => "Sus-Massa",
+    "MA-10" => "Kulmim-Wad Nun (częściowo EH)",
+    "MA-11" => "Al-Ujun-As-Sakija al-Hamra (częściowo EH)",
+    "MA-12" => "Ad-Dachla-Wadi az-Zahab (EH)",
+    "MA-AGD" => "Agadir-Ida-Utanan",
+    "MA-AOU" => "Ausard (EH)",
+    "MA-ASZ" => "Assa-Zag (częściowo EH)",
+    "MA-AZI" => "Azilal",
+    "MA-BEM" => "Bani Mallal",
+    "MA-BER" => "Birkan",
+    "MA-BES" => "Bin Sulajman",
+    "MA-BOD" => "Budżdur (EH)",
+    "MA-BOM" => "Bulman",
+    "MA-BRR" => "Bu-r-Raszid",
+    "MA-CAS" => "Casablanca",
+    "MA-CHE" => "Szefszawan",
+    "MA-CHI" => "Sziszawa",
+    "MA-CHT" => "Szatuka-Ajt Baha",
+    "MA-DRI" => "Driouch",
+    "MA-ERR" => "Ar-Raszidija",
+    "MA-ESI" => "As-Sawira",
+    "MA-ESM" => "Asmara (częściowo EH)",
+    "MA-FAH" => "Fahs-Andżira",
+    "MA-FES" => "Fez",
+    "MA-FIG" => "Fidżidż",
+    "MA-FQH" => "Al-Fakih Bin Salih",
+    "MA-GUE" => "Kulmim",
+    "MA-GUF" => "Dżarsif",
+    "MA-HAJ" => "Al-Hadżab",
+    "MA-HAO" => "Al-Hauz",
+    "MA-HOC" => "Al-Husajma",
+    "MA-IFR" => "Ifran",
+    "MA-INE" => "Inazkan-Ajt Mallul",
+    "MA-JDI" => "Al-Dżadida",
+    "MA-JRA" => "Dżarada",
+    "MA-KEN" => "Al-Kunajtira",
+    "MA-KES" => "Al-Kalat as-Saraghna",
+    "MA-KHO" => "Churibka",
+    "MA-LAA" => "Al-Ujun (EH)",
+    "MA-LAR" => "Al-Araisz",
+    "MA-MAR" => "Marrakesz",
+    "MA-MDF" => "Al-Madik-Al-Funajdik",
+    "MA-MED" => "Madjuna",
+    "MA-MEK" => "Meknes",
+    "MA-MID" => "Midalt",
+    "MA-MOH" => "Al-Muhammadija",
+    "MA-MOU" => "Maulaj Jakub",
+    "MA-NAD" => "An-Nazur",
+    "MA-NOU" => "An-Nuwasar",
+    "MA-OUA" => "Warzazat",
+    "MA-OUD" => "Wadi az-Zahab (EH)",
+    "MA-OUJ" => "Wadżda-Angad",
+    "MA-OUZ" => "Wazzan",
+    "MA-RAB" => "Rabat",
+    "MA-REH" => "Ar-Rahamina",
+    "MA-SAF" => "Safi",
+    "MA-SAL" => "Sala",
+    "MA-SEF" => "Safru",
+    "MA-SET" => "Sattat",
+    "MA-SIB" => "Sidi Bu-n-Nur",
+    "MA-SIF" => "Sidi Ifni",
+    "MA-SIK" => "Sidi Kasim",
+    "MA-SIL" => "Sidi Sulajman",
+    "MA-SKH" => "Sachirat-Timara",
+    "MA-TAF" => "Tarfaja (częściowo EH)",
+    "MA-TAI" => "Taurirt",
+    "MA-TAO" => "Taunat",
+    "MA-TAT" => "Tata",
+    "MA-TAZ" => "Taza",
+    "MA-TET" => "Tetuan",
+    "MA-TIN" => "Tinghir",
+    "MA-TIZ" => "Tiznit",
+    "MA-TNG" => "Tanger-Asila",
+    "MA-TNT" => "Tantan (częściowo EH)",
+    "MA-YUS" => "Jusufijja",
+    "MA-ZAG" => "Zaghura",
+    "MZ-A" => "Niassa",
+    "MZ-B" => "Manica",
+    "MZ-G" => "Gaza",
+    "MZ-I" => "Inhambane",
+    "MZ-L" => "Maputo",
+    "MZ-MPM" => "Maputo",
+    "MZ-P" => "Cabo Delgado",
+    "MZ-S" => "Sofala",
+    "MZ-T" => "Tete",
+    "MM-01" => "Sikong",
+    "MM-02" => "Pegu",
+    "MM-03" => "Magwe",
+    "MM-04" => "Mandalaj",
+    "MM-05" => "Taninthayi",
+    "MM-06" => "Rangun",
+    "MM-07" => "Irawadi",
+    "MM-11" => "Kaczin",
+    "MM-12" => "Kaja",
+    "MM-13" => "Karen",
+    "MM-14" => "Czin",
+    "MM-15" => "Mon",
+    "MM-16" => "Arakan",
+    "MM-17" => "Szan",
+    "NA-ER" => "Erongo",
+    "NA-HA" => "Hardap",
+    "NA-KH" => "Khomas",
+    "NA-KU" => "Kunene",
+    "NA-OD" => "Otjozondjupa",
+    "NA-OH" => "Omaheke",
+    "NA-ON" => "Oshana",
+    "NA-OS" => "Omusati",
+    "NA-OT" => "Oshikoto",
+    "NA-OW" => "Ohangwena",
+    "NR-01" => "Aiwo",
+    "NR-02" => "Anabar",
+    "NR-03" => "Anetan",
+    "NR-04" => "Anibare",
+    "NR-06" => "Boe",
+    "NR-07" => "Buada",
+    "NR-08" => "Denigomodu",
+    "NR-09" => "Ewa",
+    "NR-10" => "Ijuw",
+    "NR-11" => "Meneng",
+    "NR-12" => "Nibok",
+    "NR-13" => "Uaboe",
+    "NR-14" => "Yaren",
+    "NP-P4" => "Gandaki",
+    "NP-P6" => "Karnali",
+    "NL-AW" => "Aruba",
+    "NL-BQ1" => "Bonaire",
+    "NL-BQ2" => "Saba",
+    "NL-BQ3" => "Sint Eustatius",
+    "NL-CW" => "Curaçao",
+    "NL-DR" => "Drenthe",
+    "NL-FL" => "Flevoland",
+    "NL-GE" => "Geldria",
+    "NL-GR" => "Groningen",
+    "NL-LI" => "Limburgia",
+    "NL-NB" => "Brabancja Północna",
+    "NL-NH" => "Holandia Północna",
+    "NL-OV" => "Overijssel",
+    "NL-SX" => "Sint Maarten",
+    "NL-UT" => "Utrecht",
+    "NL-ZE" => "Zelandia",
+    "NL-ZH" => "Holandia Południowa",
+    "NZ-AUK" => "Auckland",
+    "NZ-BOP" => "Bay of Plenty",
+    "NZ-CAN" => "Canterbury",
+    "NZ-CIT" => "Wyspy Chatham",
+    "NZ-HKB" => "Hawke's Bay",
+    "NZ-MWT" => "Manawatu-Wanganui",
+    "NZ-NTL" => "Northland",
+    "NZ-OTA" => "Otago",
+    "NZ-STL" => "Southland",
+    "NZ-TKI" => "Taranaki",
+    "NZ-WGN" => "Wellington",
+    "NZ-WKO" => "Waikato",
+    "NZ-WTC" => "West Coast",
+    "NI-BO" => "Boaco",
+    "NI-CA" => "Carazo",
+    "NI-CI" => "Chinandega",
+    "NI-CO" => "Chontales",
+    "NI-ES" => "Estelí",
+    "NI-GR" => "Grenada / Granada",
+    "NI-JI" => "Jinotega",
+    "NI-LE" => "León",
+    "NI-MD" => "Madriz",
+    "NI-MN" => "Managua",
+    "NI-MS" => "Masaya",
+    "NI-MT" => "Matagalpa",
+    "NI-NS" => "Nueva Segovia",
+    "NI-RI" => "Rivas",
+    "NI-SJ" => "Río San Juan",
+    "NE-1" => "Agadez",
+    "NE-2" => "Diffa",
+    "NE-3" => "Dosso",
+    "NE-4" => "Maradi",
+    "NE-5" => "Tahoua",
+    "NE-6" => "Tillaberi",
+    "NE-7" => "Zinder",
+    "NE-8" => "Niamey",
+    "NG-AB" => "Abia",
+    "NG-AD" => "Adamawa",
+    "NG-AK" => "Akwa Ibom",
+    "NG-AN" => "Anambra",
+    "NG-BA" => "Bauchi",
+    "NG-BE" => "Benue",
+    "NG-BO" => "Borno",
+    "NG-BY" => "Bayelsa",
+    "NG-CR" => "Cross River",
+    "NG-DE" => "Delta",
+    "NG-EB" => "Ebonyi",
+    "NG-ED" => "Edo",
+    "NG-EK" => "Ekiti",
+    "NG-EN" => "Enugu",
+    "NG-GO" => "Gombe",
+    "NG-IM" => "Imo",
+    "NG-JI" => "Jigawa",
+    "NG-KD" => "Kaduna",
+    "NG-KE" => "Kebbi",
+    "NG-KN" => "Kano",
+    "NG-KO" => "Kogi",
+    "NG-KT" => "Katsina",
+    "NG-KW" => "Kwara",
+    "NG-LA" => "Lagos",
+    "NG-NI" => "Niger",
+    "NG-OG" => "Ogun",
+    "NG-ON" => "Ondo",
+    "NG-OS" => "Osun",
+    "NG-OY" => "Oyo",
+    "NG-PL" => "Plateau",
+    "NG-RI" => "Rivers",
+    "NG-SO" => "Sokoto",
+    "NG-TA" => "Taraba",
+    "NG-YO" => "Yobe",
+    "NG-ZA" => "Zamfara",
+    "MK-101" => "Weles",
+    "MK-102" => "Gradsko",
+    "MK-103" => "Demir Kapija",
+    "MK-104" => "Kawadarci",
+    "MK-105" => "Łozowo",
+    "MK-106" => "Negotino",
+    "MK-107" => "Rosoman",
+    "MK-108" => "Sweti Nikole",
+    "MK-109" => "Czaszka",
+    "MK-201" => "Berowo",
+    "MK-202" => "Winica",
+    "MK-203" => "Dełczewo",
+    "MK-204" => "Zrnowci",
+    "MK-205" => "Karbinci",
+    "MK-206" => "Koczani",
+    "MK-207" => "Makedonska Kamenica",
+    "MK-208" => "Pehczewo",
+    "MK-209" => "Probisztip",
+    "MK-210" => "Czeszinowo-Obleszewo",
+    "MK-211" => "Sztip",
+    "MK-301" => "Wewczani",
+    "MK-303" => "Debar",
+    "MK-307" => "Kiczewo",
+    "MK-308" => "Makedonski Brod",
+    "MK-310" => "Ochryda",
+    "MK-311" => "Płasnica",
+    "MK-312" => "Struga",
+    "MK-313" => "Centar Żupa",
+    "MK-401" => "Bogdanci",
+    "MK-402" => "Bosiłowo",
+    "MK-403" => "Wałandowo",
+    "MK-404" => "Wasilewo",
+    "MK-405" => "Gewgelija",
+    "MK-406" => "Dojran",
+    "MK-407" => "Koncze",
+    "MK-408" => "Nowo Seło",
+    "MK-409" => "Radowisz",
+    "MK-410" => "Strumica",
+    "MK-501" => "Bitola",
+    "MK-502" => "Demir Hisar",
+    "MK-503" => "Dołneni",
+    "MK-504" => "Kriwogasztani",
+    "MK-505" => "Kruszewo",
+    "MK-506" => "Mogiła",
+    "MK-507" => "Nowaci",
+    "MK-508" => "Prilep",
+    "MK-509" => "Resen",
+    "MK-601" => "Bogowinje",
+    "MK-602" => "Brwenica",
+    "MK-603" => "Wrabcziszte",
+    "MK-604" => "Gostiwar",
+    "MK-605" => "Żelino",
+    "MK-606" => "Jegunowce",
+    "MK-608" => "Tearce",
+    "MK-609" => "Tetowo",
+    "MK-701" => "Kratowo",
+    "MK-702" => "Kriwa Pałanka",
+    "MK-703" => "Kumanowo",
+    "MK-704" => "Lipkowo",
+    "MK-705" => "Rankowce",
+    "MK-706" => "Staro Nagoriczane",
+    "MK-802" => "Araczinowo",
+    "MK-806" => "Zelenikowo",
+    "MK-807" => "Ilinden",
+    "MK-810" => "Petrowec",
+    "MK-812" => "Soliszte",
+    "MK-813" => "Studenczani",
+    "NO-03" => "Oslo",
+    "NO-11" => "Rogaland",
+    "NO-15" => "Møre og Romsdal",
+    "NO-18" => "Nordland",
+    "NO-21" => "Svalbard (terytorium arktyczne)",
+    "NO-22" => "Jan Mayen (terytorium arktyczne)",
+    "OM-BU" => "Al-Burajmi",
+    "OM-MA" => "Maskat",
+    "OM-MU" => "Musandam",
+    "OM-WU" => "Gubernatorstwo Centralne",
+    "OM-ZA" => "az-Zahira",
+    "OM-ZU" => "Zufar",
+    "PK-BA" => "Beludżystan",
+    "PK-GB" => "Gilgit-Baltistan",
+    "PK-IS" => "Islamabad",
+    "PK-KP" => "Chajber Pachtunchwa",
+    "PK-PB" => "Pendżab",
+    "PK-SD" => "Sindh",
+    "PW-002" => "Aimeliik",
+    "PW-004" => "Airai",
+    "PW-010" => "Angaur",
+    "PW-100" => "Kayangel",
+    "PW-150" => "Koror",
+    "PW-212" => "Melekeok",
+    "PW-214" => "Ngaraard",
+    "PW-218" => "Ngarchelong",
+    "PW-222" => "Ngardmau",
+    "PW-224" => "Ngatpang",
+    "PW-226" => "Ngchesar",
+    "PW-227" => "Ngeremlengui",
+    "PW-228" => "Ngiwal",
+    "PW-350" => "Peleliu",
+    "PW-370" => "Sonsorol",
+    "PS-BTH" => "Betlejem",
+    "PS-DEB" => "Dajr al-Balah",
+    "PS-GZA" => "Gaza",
+    "PS-HBN" => "Hebron",
+    "PS-JEM" => "Jerozolima",
+    "PS-JEN" => "Dżanin",
+    "PS-KYS" => "Chan Junis",
+    "PS-NBS" => "Nablus",
+    "PS-NGZ" => "Gaza Północna",
+    "PS-QQA" => "Kalkilja",
+    "PS-RBH" => "Ramallah",
+    "PS-RFH" => "Rafah",
+    "PS-SLT" => "Salfit",
+    "PS-TBS" => "Tubas",
+    "PS-TKM" => "Tulkarm",
+    "PA-1" => "Bocas del Toro",
+    "PA-2" => "Coclé",
+    "PA-3" => "Colón",
+    "PA-4" => "Chiriquí",
+    "PA-5" => "Darién",
+    "PA-6" => "Herrera",
+    "PA-7" => "Los Santos",
+    "PA-8" => "Panamá",
+    "PA-9" => "Veraguas",
+    "PA-EM" => "Emberá",
+    "PA-NB" => "Ngöbe-Buglé",
+    "PG-CPK" => "Simbu",
+    "PG-CPM" => "Dystrykt Centralny / Region Centralny / Prowincja Centralna / Departament Centralny",
+    "PG-EBR" => "Nowa Brytania Wschodnia",
+    "PG-EHG" => "Eastern Highlands",
+    "PG-EPW" => "Enga",
+    "PG-ESW" => "Sepik Wschodni",
+    "PG-GPK" => "Gulf",
+    "PG-MBA" => "Milne Bay",
+    "PG-MPL" => "Morobe",
+    "PG-MPM" => "Madang",
+    "PG-MRL" => "Manus",
+    "PG-NCD" => "Narodowy Dystrykt Stołeczny (Port Moresby)",
+    "PG-NIK" => "Nowa Irlandia",
+    "PG-NPP" => "Dystrykt Północny / Region Północny / Prowincja Północna",
+    "PG-NSB" => "Bougainville",
+    "PG-SHM" => "Southern Highlands",
+    "PG-WBK" => "Nowa Brytania Zachodnia",
+    "PG-WHM" => "Western Highlands",
+    "PG-WPD" => "Dystrykt Zachodni / Region Zachodni / Western Division / Prowincja Zachodnia",
+    "PY-1" => "Concepción",
+    "PY-10" => "Alto Paraná",
+    "PY-11" => "Dystrykt Centralny / Region Centralny / Prowincja Centralna / Departament Centralny",
+    "PY-12" => "Ñeembucú",
+    "PY-13" => "Amambay",
+    "PY-14" => "Canindeyú",
+    "PY-15" => "Presidente Hayes",
+    "PY-16" => "Alto Paraguay",
+    "PY-19" => "Boquerón",
+    "PY-2" => "San Pedro",
+    "PY-3" => "Cordillera",
+    "PY-4" => "Guairá",
+    "PY-5" => "Caaguazú",
+    "PY-6" => "Caazapá",
+    "PY-7" => "Itapúa",
+    "PY-8" => "Misiones",
+    "PY-9" => "Paraguarí",
+    "PY-ASU" => "Asunción",
+    "PE-ANC" => "Ancash",
+    "PE-ARE" => "Arequipa",
+    "PE-AYA" => "Ayacucho",
+    "PE-CAJ" => "Cajamarca",
+    "PE-CAL" => "Callao",
+    "PE-HUC" => "Huánuco",
+    "PE-HUV" => "Huancavelica",
+    "PE-ICA" => "Ica",
+    "PE-LAL" => "La Libertad",
+    "PE-LAM" => "Lambayeque",
+    "PE-LIM" => "Lima",
+    "PE-LOR" => "Loreto",
+    "PE-MDD" => "Madre de Dios",
+    "PE-MOQ" => "Moquegua",
+    "PE-PAS" => "Pasco",
+    "PE-PIU" => "Piura",
+    "PE-PUN" => "Puno",
+    "PE-TAC" => "Tacna",
+    "PE-TUM" => "Tumbes",
+    "PE-UCA" => "Ucayali",
+    "PH-00" => "Region Stołeczny (Manila)",
+    "PH-01" => "Ilocos (Region I)",
+    "PH-02" => "Cagayan Valley (Region II)",
+    "PH-03" => "Luzon Środkowy (Region III)",
+    "PH-05" => "Bicol (Region V)",
+    "PH-06" => "Western Visayas (Region VI)",
+    "PH-07" => "Central Visayas (Region VII)",
+    "PH-08" => "Eastern Visayas (Region VIII)",
+    "PH-09" => "Zamboanga Peninsula (Region IX)",
+    "PH-10" => "Mindanao Północne (Region X)",
+    "PH-11" => "Davao (Region XI)",
+    "PH-12" => "SOCCSKSARGEN (Region XII)",
+    "PH-13" => "Caraga (Region XIII)",
+    "PH-14" => "Region Autonomiczny na Muzułmańskim Mindanao (ARMM)",
+    "PH-15" => "Cordillera Administrative Region (CAR)",
+    "PH-ABR" => "Abra",
+    "PH-AGN" => "Agusan del Norte",
+    "PH-AGS" => "Agusan del Sur",
+    "PH-AKL" => "Aklan",
+    "PH-ALB" => "Albay",
+    "PH-ANT" => "Antique",
+    "PH-APA" => "Apayao",
+    "PH-AUR" => "Aurora",
+    "PH-BAS" => "Basilan",
+    "PH-BEN" => "Benguet",
+    "PH-BIL" => "Biliran",
+    "PH-BOH" => "Bohol",
+    "PH-BTG" => "Batangas",
+    "PH-BTN" => "Batanes",
+    "PH-BUK" => "Bukidnon",
+    "PH-BUL" => "Bulacan",
+    "PH-CAG" => "Cagayan",
+    "PH-CAM" => "Camiguin",
+    "PH-CAN" => "Camarines Norte",
+    "PH-CAP" => "Capiz",
+    "PH-CAS" => "Camarines Sur",
+    "PH-CAT" => "Catanduanes",
+    "PH-CAV" => "Cavite",
+    "PH-CEB" => "Cebu",
+    "PH-DAO" => "Davao Oriental",
+    "PH-DAS" => "Davao del Sur",
+    "PH-DAV" => "Davao del Norte",
+    "PH-DIN" => "Dinagat Islands",
+    "PH-EAS" => "Eastern Samar",
+    "PH-GUI" => "Guimaras",
+    "PH-IFU" => "Ifugao",
+    "PH-ILI" => "Iloilo",
+    "PH-ILN" => "Ilocos Norte",
+    "PH-ILS" => "Ilocos Sur",
+    "PH-ISA" => "Isabela",
+    "PH-LAG" => "Laguna",
+    "PH-LAN" => "Lanao del Norte",
+    "PH-LAS" => "Lanao del Sur",
+    "PH-LEY" => "Leyte",
+    "PH-LUN" => "La Union",
+    "PH-MAD" => "Marinduque",
+    "PH-MAS" => "Masbate",
+    "PH-MDC" => "Mindoro Occidental",
+    "PH-MDR" => "Mindoro Oriental",
+    "PH-MOU" => "Mountain Province",
+    "PH-MSC" => "Misamis Occidental",
+    "PH-MSR" => "Misamis Oriental",
+    "PH-NEC" => "Negros Occidental",
+    "PH-NER" => "Negros Oriental",
+    "PH-NSA" => "Northern Samar",
+    "PH-NUE" => "Nueva Ecija",
+    "PH-NUV" => "Nueva Vizcaya",
+    "PH-PAM" => "Pampanga",
+    "PH-PAN" => "Pangasinan",
+    "PH-PLW" => "Palawan",
+    "PH-QUE" => "Quezon",
+    "PH-QUI" => "Quirino",
+    "PH-RIZ" => "Rizal",
+    "PH-ROM" => "Romblon",
+    "PH-SAR" => "Sarangani",
+    "PH-SCO" => "South Cotabato",
+    "PH-SIG" => "Siquijor",
+    "PH-SLE" => "Southern Leyte",
+    "PH-SLU" => "Sulu",
+    "PH-SOR" => "Sorsogon",
+    "PH-SUK" => "Sultan Kudarat",
+    "PH-SUN" => "Surigao del Norte",
+    "PH-SUR" => "Surigao del Sur",
+    "PH-TAR" => "Tarlac",
+    "PH-TAW" => "Tawi-Tawi",
+    "PH-ZAN" => "Zamboanga del Norte",
+    "PH-ZAS" => "Zamboanga del Sur",
+    "PH-ZMB" => "Zambales",
+    "PH-ZSI" => "Zamboanga Sibugay",
+    "PL-02" => "dolnośląskie",
+    "PL-04" => "kujawsko-pomorskie",
+    "PL-06" => "lubelskie",
+    "PL-08" => "lubuskie",
+    "PL-10" => "łódzkie",
+    "PL-12" => "małopolskie",
+    "PL-14" => "mazowieckie",
+    "PL-16" => "opolskie",
+    "PL-18" => "podkarpackie",
+    "PL-20" => "podlaskie",
+    "PL-22" => "pomorskie",
+    "PL-24" => "śląskie",
+    "PL-26" => "świętokrzyskie",
+    "PL-28" => "warmińsko-mazurskie",
+    "PL-30" => "wielkopolskie",
+    "PL-32" => "zachodniopomorskie",
+    "PT-01" => "Aveiro",
+    "PT-02" => "Beja",
+    "PT-03" => "Braga",
+    "PT-04" => "Bragança",
+    "PT-05" => "Castelo Branco",
+    "PT-06" => "Coimbra",
+    "PT-07" => "Évora",
+    "PT-08" => "Faro",
+    "PT-09" => "Guarda",
+    "PT-10" => "Leiria",
+    "PT-11" => "Lizbona",
+    "PT-12" => "Portalegre",
+    "PT-13" => "Porto",
+    "PT-14" => "Santarém",
+    "PT-15" => "Setúbal",
+    "PT-16" => "Viana do Castelo",
+    "PT-17" => "Vila Real",
+    "PT-18" => "Viseu",
+    "PT-20" => "Autonomiczny Region Azorów",
+    "PT-30" => "Autonomiczny Region Madery",
+    "QA-KH" => "Al-Chaur",
+    "QA-WA" => "Al-Wakra",
+    "QA-ZA" => "Ad-Da’ajin",
+    "RO-AB" => "Alba",
+    "RO-AG" => "Ardżesz",
+    "RO-AR" => "Arad",
+    "RO-B" => "Bukareszt",
+    "RO-BC" => "Bacău",
+    "RO-BH" => "Bihor",
+    "RO-BN" => "Bistriţa-Năsăud",
+    "RO-BR" => "Braiła",
+    "RO-BT" => "Botoszany",
+    "RO-BV" => "Braszów",
+    "RO-BZ" => "Buzău",
+    "RO-CJ" => "Kluż",
+    "RO-CL" => "Călăraşi",
+    "RO-CS" => "Caraş-Severin",
+    "RO-CT" => "Konstanca",
+    "RO-CV" => "Covasna",
+    "RO-DB" => "Dymbowica",
+    "RO-DJ" => "Dolj",
+    "RO-GJ" => "Gorj",
+    "RO-GL" => "Gałacz",
+    "RO-GR" => "Giurgiu",
+    "RO-HD" => "Hunedoara",
+    "RO-HR" => "Harghita",
+    "RO-IF" => "Ilfov",
+    "RO-IL" => "Jałomica",
+    "RO-IS" => "Jassy",
+    "RO-MH" => "Mehedinţi",
+    "RO-MM" => "Marmarosz",
+    "RO-MS" => "Marusza",
+    "RO-NT" => "Neamţ",
+    "RO-OT" => "Aluta",
+    "RO-PH" => "Prahova",
+    "RO-SB" => "Sybin",
+    "RO-SJ" => "Sălaj",
+    "RO-SM" => "Satu Mare",
+    "RO-SV" => "Suczawa",
+    "RO-TL" => "Tulcza",
+    "RO-TM" => "Temesz",
+    "RO-TR" => "Teleorman",
+    "RO-VL" => "Vâlcea",
+    "RO-VN" => "Vrancea",
+    "RO-VS" => "Vaslui",
+    "RU-BA" => "Republika Baszkirii",
+    "RU-CE" => "Republika Czeczeńska",
+    "RU-CHE" => "obwód czelabiński",
+    "RU-CHU" => "Czukocki Okręg Autonomiczny",
+    "RU-CU" => "Republika Czuwaska",
+    "RU-DA" => "Republika Dagestanu",
+    "RU-KAM" => "Kraj Kamczacki",
+    "RU-KC" => "Republika Karaczajsko-Czerkieska",
+    "RU-KLU" => "obwód kałuski",
+    "RU-KO" => "Republika Komi",
+    "RU-MOW" => "Moskwa",
+    "RU-NIZ" => "obwód niżnonowogrodzki",
+    "RU-SPE" => "Sankt Petersburg",
+    "RU-TA" => "Republika Tatarstanu",
+    "RU-VOR" => "obwód woroneski",
+    "RU-ZAB" => "Kraj Zabajkalski",
+    "RW-02" => "Dystrykt Wschodni / Region Wschodni / Prowincja Wschodnia",
+    "RW-03" => "Dystrykt Północny / Region Północny / Prowincja Północna",
+    "RW-04" => "Dystrykt Zachodni / Region Zachodni / Western Division / Prowincja Zachodnia",
+    "RW-05" => "Południowy",
+    "SH-AC" => "Wyspa Wniebowstąpienia",
+    "SH-HL" => "Wyspa Świętej Heleny",
+    "SH-TA" => "Tristan da Cunha",
+    "KN-01" => "Christ Church Nichola Town",
+    "KN-02" => "Saint Anne Sandy Point",
+    "KN-03" => "Saint George Basseterre",
+    "KN-04" => "Saint George Gingerland",
+    "KN-05" => "Saint James Windward",
+    "KN-06" => "Saint John Capisterre",
+    "KN-07" => "Saint John Figtree",
+    "KN-08" => "Saint Mary Cayon",
+    "KN-09" => "Saint Paul Capisterre",
+    "KN-10" => "Saint Paul Charlestown",
+    "KN-11" => "Saint Peter Basseterre",
+    "KN-12" => "Saint Thomas Lowland",
+    "KN-13" => "Saint Thomas Middle Island",
+    "KN-15" => "Trinity Palmetto Point",
+    "KN-K" => "Saint Kitts",
+    "KN-N" => "Nevis",
+    "LC-03" => "Choiseul",
+    "VC-01" => "Charlotte",
+    "VC-02" => "Saint Andrew",
+    "VC-03" => "Saint David",
+    "VC-04" => "Saint George",
+    "VC-05" => "Saint Patrick",
+    "VC-06" => "Grenadyny",
+    "WS-AA" => "A'ana",
+    "WS-AL" => "Aiga-i-le-Tai",
+    "WS-AT" => "Atua",
+    "WS-FA" => "Fa'asaleleaga",
+    "WS-GE" => "Gaga'emauga",
+    "WS-GI" => "Gaga'ifomauga",
+    "WS-PA" => "Palauli",
+    "WS-SA" => "Satupa'itea",
+    "WS-TU" => "Tuamasaga",
+    "WS-VF" => "Va'a-o-Fonoti",
+    "WS-VS" => "Vaisigano",
+    "SM-01" => "Acquaviva",
+    "SM-02" => "Chiesanuova",
+    "SM-03" => "Domagnano",
+    "SM-04" => "Faetano",
+    "SM-05" => "Fiorentino",
+    "SM-06" => "Borgo Maggiore",
+    "SM-08" => "Montegiardino",
+    "SM-09" => "Serravalle",
+    "ST-P" => "Wyspa Książęca",
+    "SA-04" => "Asz-Szarkijja",
+    "SA-05" => "Al-Kasim",
+    "SA-07" => "Tabuk",
+    "SA-10" => "Nadżran",
+    "SA-12" => "Al-Dżauf",
+    "SN-DB" => "Diourbel",
+    "SN-DK" => "Dakar",
+    "SN-FK" => "Fatick",
+    "SN-KA" => "Kaffrine",
+    "SN-KD" => "Kolda",
+    "SN-KE" => "Kédougou",
+    "SN-KL" => "Kaolack",
+    "SN-LG" => "Louga",
+    "SN-MT" => "Matam",
+    "SN-SE" => "Sédhiou",
+    "SN-SL" => "Saint-Louis",
+    "SN-TC" => "Tambacounda",
+    "SN-TH" => "Thiès",
+    "SN-ZG" => "Ziguinchor",
+    "RS-00" => "Belgrad",
+    "RS-01" => "okręg północnobacki",
+    "RS-02" => "okręg środkowobanacki",
+    "RS-03" => "okręg północnobanacki",
+    "RS-04" => "okręg południowobanacki",
+    "RS-05" => "okręg zachodniobacki",
+    "RS-06" => "okręg południowobacki",
+    "RS-07" => "okręg sremski",
+    "RS-08" => "okręg maczwański",
+    "RS-09" => "okręg kolubarski",
+    "RS-10" => "okręg podunajski",
+    "RS-11" => "okręg braniczewski",
+    "RS-12" => "okręg szumadijski",
+    "RS-13" => "okręg pomorawski",
+    "RS-14" => "okręg borski",
+    "RS-15" => "okręg zajeczarski",
+    "RS-16" => "okręg zlatiborski",
+    "RS-17" => "okręg morawicki",
+    "RS-18" => "okręg raski",
+    "RS-19" => "okręg rasiński",
+    "RS-20" => "okręg niszawski",
+    "RS-21" => "okręg toplicki",
+    "RS-22" => "okręg pirocki",
+    "RS-23" => "ogręg jablanicki",
+    "RS-24" => "okręg pczyński",
+    "RS-25" => "okręg Kosowo",
+    "RS-26" => "okręg Peć",
+    "RS-27" => "okręg Prizren",
+    "RS-28" => "okręg Kosowska Mitrowica",
+    "RS-29" => "okręg Kosovo-Pomoravlje",
+    "RS-KM" => "Kosowo i Metochia",
+    "RS-VO" => "Wojwodina",
+    "SC-01" => "Anse aux Pins",
+    "SC-02" => "Anse Boileau",
+    "SC-03" => "Anse Etoile",
+    "SC-05" => "Anse Royale",
+    "SC-06" => "Baie Lazare",
+    "SC-07" => "Baie Sainte Anne",
+    "SC-08" => "Beau Vallon",
+    "SC-09" => "Bel Air",
+    "SC-10" => "Bel Ombre",
+    "SC-11" => "Cascade",
+    "SC-12" => "Glacis",
+    "SC-13" => "Grand' Anse (Mahe)",
+    "SC-14" => "Grand' Anse Praslin",
+    "SC-15" => "La Digue",
+    "SC-16" => "La Riviere Anglaise",
+    "SC-17" => "Mont Buxton",
+    "SC-18" => "Mont Fleuri",
+    "SC-19" => "Plaisance",
+    "SC-20" => "Pointe La Rue",
+    "SC-21" => "Port Glaud",
+    "SC-22" => "Saint Louis",
+    "SC-23" => "Takamaka",
+    "SC-24" => "Les Mamelles",
+    "SC-25" => "Roche Caïman",
+    "SL-E" => "Dystrykt Wschodni / Region Wschodni / Prowincja Wschodnia",
+    "SL-N" => "Dystrykt Północny / Region Północny / Prowincja Północna",
+    "SL-S" => "Południowy",
+    "SL-W" => "Obszar Zachodni (Freetown)",
+    "SG-01" => "Singapur Centralny",
+    "SG-02" => "Północno-Wschodni",
+    "SG-03" => "Północno-Zachodni",
+    "SG-04" => "Południowo-Wschodni",
+    "SG-05" => "Południowo-Zachodni",
+    "SK-BC" => "Kraj bańskobystrzycki",
+    "SK-BL" => "Kraj bratysławski",
+    "SK-KI" => "Kraj koszycki",
+    "SK-NI" => "Kraj nitrzański",
+    "SK-PV" => "Kraj preszowski",
+    "SK-TA" => "Kraj trnawski",
+    "SK-TC" => "Kraj trenczyński",
+    "SK-ZI" => "Kraj żyliński",
+    "SI-001" => "Ajdovščina",
+    "SI-002" => "Beltinci",
+    "SI-003" => "Bled",
+    "SI-004" => "Bohinj",
+    "SI-005" => "Borovnica",
+    "SI-006" => "Bovec",
+    "SI-007" => "Brda",
+    "SI-008" => "Brezovica",
+    "SI-009" => "Brežice",
+    "SI-010" => "Tišina",
+    "SI-011" => "Celje",
+    "SI-012" => "Cerklje na Gorenjskem",
+    "SI-013" => "Cerknica",
+    "SI-014" => "Cerkno",
+    "SI-015" => "Črenšovci",
+    "SI-016" => "Črna na Koroškem",
+    "SI-017" => "Črnomelj",
+    "SI-018" => "Destrnik",
+    "SI-019" => "Divača",
+    "SI-020" => "Dobrepolje",
+    "SI-021" => "Dobrova-Polhov Gradec",
+    "SI-022" => "Dol pri Ljubljani",
+    "SI-023" => "Domžale",
+    "SI-024" => "Dornava",
+    "SI-025" => "Dravograd",
+    "SI-026" => "Duplek",
+    "SI-027" => "Gorenja vas-Poljane",
+    "SI-028" => "Gorišnica",
+    "SI-029" => "Gornja Radgona",
+    "SI-030" => "Gornji Grad",
+    "SI-031" => "Gornji Petrovci",
+    "SI-032" => "Grosuplje",
+    "SI-033" => "Šalovci",
+    "SI-034" => "Hrastnik",
+    "SI-035" => "Hrpelje-Kozina",
+    "SI-036" => "Idrija",
+    "SI-037" => "Ig",
+    "SI-038" => "Ilirska Bistrica",
+    "SI-039" => "Ivančna Gorica",
+    "SI-041" => "Jesenice",
+    "SI-042" => "Juršinci",
+    "SI-043" => "Kamnik",
+    "SI-044" => "Kanal",
+    "SI-045" => "Kidričevo",
+    "SI-046" => "Kobarid",
+    "SI-047" => "Kobilje",
+    "SI-048" => "Kočevje",
+    "SI-049" => "Komen",
+    "SI-051" => "Kozje",
+    "SI-052" => "Kranj",
+    "SI-053" => "Kranjska Gora",
+    "SI-054" => "Krško",
+    "SI-055" => "Kungota",
+    "SI-056" => "Kuzma",
+    "SI-057" => "Laško",
+    "SI-058" => "Lenart",
+    "SI-060" => "Litija",
+    "SI-061" => "Lublana",
+    "SI-062" => "Ljubno",
+    "SI-063" => "Ljutomer",
+    "SI-064" => "Logatec",
+    "SI-065" => "Loška Dolina",
+    "SI-066" => "Loški Potok",
+    "SI-067" => "Luče",
+    "SI-068" => "Lukovica",
+    "SI-069" => "Majšperk",
+    "SI-070" => "Maribor",
+    "SI-071" => "Medvode",
+    "SI-072" => "Mengeš",
+    "SI-073" => "Metlika",
+    "SI-074" => "Mežica",
+    "SI-075" => "Miren-Kostanjevica",
+    "SI-076" => "Mislinja",
+    "SI-077" => "Moravče",
+    "SI-078" => "Moravske Toplice",
+    "SI-079" => "Mozirje",
+    "SI-080" => "Murska Sobota",
+    "SI-081" => "Muta",
+    "SI-082" => "Naklo",
+    "SI-083" => "Nazarje",
+    "SI-084" => "Nova Gorica",
+    "SI-086" => "Odranci",
+    "SI-087" => "Ormož",
+    "SI-088" => "Osilnica",
+    "SI-089" => "Pesnica",
+    "SI-091" => "Pivka",
+    "SI-092" => "Podčetrtek",
+    "SI-093" => "Podvelka",
+    "SI-094" => "Postojna",
+    "SI-095" => "Preddvor",
+    "SI-096" => "Ptuj",
+    "SI-097" => "Puconci",
+    "SI-098" => "Rače-Fram",
+    "SI-099" => "Radeče",
+    "SI-100" => "Radenci",
+    "SI-101" => "Radlje ob Dravi",
+    "SI-102" => "Radovljica",
+    "SI-103" => "Ravne na Koroškem",
+    "SI-104" => "Ribnica",
+    "SI-105" => "Rogašovci",
+    "SI-106" => "Rogaška Slatina",
+    "SI-107" => "Rogatec",
+    "SI-108" => "Ruše",
+    "SI-109" => "Semič",
+    "SI-110" => "Sevnica",
+    "SI-111" => "Sežana",
+    "SI-112" => "Slovenj Gradec",
+    "SI-113" => "Slovenska Bistrica",
+    "SI-114" => "Slovenske Konjice",
+    "SI-117" => "Šenčur",
+    "SI-118" => "Šentilj",
+    "SI-119" => "Šentjernej",
+    "SI-120" => "Šentjur",
+    "SI-121" => "Škocjan",
+    "SI-122" => "Škofja Loka",
+    "SI-123" => "Škofljica",
+    "SI-124" => "Šmarje pri Jelšah",
+    "SI-125" => "Šmartno ob Paki",
+    "SI-126" => "Šoštanj",
+    "SI-127" => "Štore",
+    "SI-128" => "Tolmin",
+    "SI-129" => "Trbovlje",
+    "SI-130" => "Trebnje",
+    "SI-131" => "Tržič",
+    "SI-132" => "Turnišče",
+    "SI-133" => "Velenje",
+    "SI-134" => "Velike Lašče",
+    "SI-135" => "Videm",
+    "SI-136" => "Vipava",
+    "SI-137" => "Vitanje",
+    "SI-138" => "Vodice",
+    "SI-139" => "Vojnik",
+    "SI-140" => "Vrhnika",
+    "SI-141" => "Vuzenica",
+    "SI-142" => "Zagorje ob Savi",
+    "SI-143" => "Zavrč",
+    "SI-144" => "Zreče",
+    "SI-146" => "Železniki",
+    "SI-147" => "Žiri",
+    "SI-148" => "Benedikt",
+    "SI-149" => "Bistrica ob Sotli",
+    "SI-150" => "Bloke",
+    "SI-151" => "Braslovče",
+    "SI-152" => "Cankova",
+    "SI-153" => "Cerkvenjak",
+    "SI-154" => "Dobje",
+    "SI-155" => "Dobrna",
+    "SI-157" => "Dolenjske Toplice",
+    "SI-158" => "Grad",
+    "SI-159" => "Hajdina",
+    "SI-160" => "Hoče-Slivnica",
+    "SI-162" => "Horjul",
+    "SI-163" => "Jezersko",
+    "SI-164" => "Komenda",
+    "SI-165" => "Kostel",
+    "SI-166" => "Križevci",
+    "SI-167" => "Lovrenc na Pohorju",
+    "SI-168" => "Markovci",
+    "SI-169" => "Miklavž na Dravskem polju",
+    "SI-170" => "Mirna Peč",
+    "SI-171" => "Oplotnica",
+    "SI-172" => "Podlehnik",
+    "SI-173" => "Polzela",
+    "SI-174" => "Prebold",
+    "SI-175" => "Prevalje",
+    "SI-176" => "Razkrižje",
+    "SI-177" => "Ribnica na Pohorju",
+    "SI-178" => "Selnica ob Dravi",
+    "SI-179" => "Sodražica",
+    "SI-180" => "Solčava",
+    "SI-181" => "Sveta Ana",
+    "SI-183" => "Šempeter-Vrtojba",
+    "SI-184" => "Tabor",
+    "SI-186" => "Trzin",
+    "SI-187" => "Velika Polana",
+    "SI-188" => "Veržej",
+    "SI-189" => "Vransko",
+    "SI-190" => "Žalec",
+    "SI-191" => "Žetale",
+    "SI-192" => "Žirovnica",
+    "SI-193" => "Žužemberk",
+    "SI-194" => "Šmartno pri Litiji",
+    "SI-195" => "Apače",
+    "SI-196" => "Cirkulane",
+    "SI-197" => "Kosanjevica na Krki",
+    "SI-198" => "Makole",
+    "SI-199" => "Mokronog-Trebelno",
+    "SI-200" => "Poljčane",
+    "SI-201" => "Renče-Vogrsko",
+    "SI-202" => "Središče ob Dravi",
+    "SI-203" => "Straža",
+    "SI-205" => "Sveti Tomaž",
+    "SI-207" => "Gorje",
+    "SI-208" => "Log-Dragomer",
+    "SI-209" => "Rečica ob Savinji",
+    "SI-211" => "Šentrupert",
+    "SB-CE" => "Dystrykt Centralny / Region Centralny / Prowincja Centralna / Departament Centralny",
+    "SB-CH" => "Choiseul",
+    "SB-CT" => "Terytorium Stołeczne (Honiara)",
+    "SB-GU" => "Guadalcanal",
+    "SB-IS" => "Isabel",
+    "SB-ML" => "Malaita",
+    "SB-RB" => "Rennell i Bellona",
+    "SB-TE" => "Temotu",
+    "SB-WE" => "Dystrykt Zachodni / Region Zachodni / Western Division / Prowincja Zachodnia",
+    "SO-AW" => "Awdal",
+    "SO-BK" => "Bakool",
+    "SO-BN" => "Banaadir",
+    "SO-BR" => "Bari",
+    "SO-BY" => "Bay",
+    "SO-GA" => "Galguduud",
+    "SO-GE" => "Gedo",
+    "SO-JD" => "Jubbada Dhexe",
+    "SO-JH" => "Jubbada Hoose",
+    "SO-MU" => "Mudug",
+    "SO-NU" => "Nugaal",
+    "SO-SD" => "Shabeellaha Dhexe",
+    "SO-SH" => "Shabeellaha Hoose",
+    "SO-SO" => "Sool",
+    "SO-TO" => "Togdheer",
+    "SO-WO" => "Woqooyi Galbeed",
+    "ZA-EC" => "Prowincja Przylądkowa Wschodnia",
+    "ZA-FS" => "Wolne Państwo",
+    "ZA-GP" => "Gauteng",
+    "ZA-KZN" => "KwaZulu-Natal",
+    "ZA-LP" => "Limpopo",
+    "ZA-MP" => "Mpumalanga",
+    "ZA-NC" => "Prowincja Przylądkowa Północna",
+    "ZA-NW" => "Północno-Zachodni",
+    "ZA-WC" => "Prowincja Przylądkowa Zachodnia",
+    "SS-BN" => "Bahr el Ghazal Północny",
+    "SS-BW" => "Bahr el Ghazal Zachodni",
+    "SS-EC" => "Ekwatoria Środkowa",
+    "SS-EE" => "Ekwatoria Wschodnia",
+    "SS-EW" => "Ekwatoria Zachodnia",
+    "SS-JG" => "Jonglei",
+    "SS-LK" => "Lakes",
+    "SS-NU" => "Nil Górny",
+    "SS-UY" => "Unity",
+    "SS-WR" => "Warrap",
+    "ES-A" => "Alicante",
+    "ES-AB" => "Albacete",
+    "ES-AL" => "Almería",
+    "ES-AN" => "Andaluzja",
+    "ES-AR" => "Aragonia",
+    "ES-AS" => "Asturia (księstwo)",
+    "ES-AV" => "Ávila",
+    "ES-B" => "Barcelona",
+    "ES-BA" => "Badajoz",
+    "ES-BI" => "Vizcaya",
+    "ES-BU" => "Burgos",
+    "ES-C" => "A Coruña",
+    "ES-CA" => "Kadyks",
+    "ES-CB" => "Kantabria",
+    "ES-CC" => "Cáceres",
+    "ES-CE" => "Ceuta",
+    "ES-CL" => "Kastylia-León",
+    "ES-CM" => "Kastylia-La Mancha",
+    "ES-CN" => "Wyspy Kanaryjskie",
+    "ES-CO" => "Córdoba",
+    "ES-CR" => "Ciudad Real",
+    "ES-CS" => "Castellón",
+    "ES-CT" => "Katalonia",
+    "ES-CU" => "Cuenca",
+    "ES-EX" => "Ekstremadura",
+    "ES-GA" => "Galicja",
+    "ES-GC" => "Las Palmas",
+    "ES-GI" => "Girona",
+    "ES-GR" => "Grenada / Granada",
+    "ES-GU" => "Guadalajara",
+    "ES-H" => "Huelva",
+    "ES-HU" => "Huesca",
+    "ES-IB" => "Baleary",
+    "ES-J" => "Jaén",
+    "ES-L" => "Lleida",
+    "ES-LE" => "León",
+    "ES-LO" => "La Rioja",
+    "ES-LU" => "Lugo",
+    "ES-M" => "Madryt",
+    "ES-MA" => "Málaga",
+    "ES-MC" => "Murcja (region)",
+    "ES-MD" => "Madryt (wspólnota)",
+    "ES-ML" => "Melilla",
+    "ES-MU" => "Murcja",
+    "ES-NA" => "Nawarra",
+    "ES-NC" => "Nawarra (wspólnota autonomiczna)",
+    "ES-O" => "Asturia",
+    "ES-OR" => "Ourense",
+    "ES-P" => "Palencia",
+    "ES-PM" => "Baleary",
+    "ES-PO" => "Pontevedra",
+    "ES-PV" => "Kraj Basków",
+    "ES-RI" => "La Rioja",
+    "ES-S" => "Kantabria",
+    "ES-SA" => "Salamanka",
+    "ES-SE" => "Sewilla",
+    "ES-SG" => "Segovia",
+    "ES-SO" => "Soria",
+    "ES-SS" => "Gipuzkoa",
+    "ES-T" => "Tarragona",
+    "ES-TE" => "Teruel",
+    "ES-TF" => "Santa Cruz de Tenerife",
+    "ES-TO" => "Toledo",
+    "ES-V" => "Walencja",
+    "ES-VA" => "Valladolid",
+    "ES-VC" => "Walencja (wspólnota)",
+    "ES-VI" => "Araba",
+    "ES-Z" => "Saragossa",
+    "ES-ZA" => "Zamora",
+    "LK-12" => "Gampaha",
+    "LK-81" => "Badulla",
+    "LK-91" => "Ratnapura",
+    "SD-NO" => "Dystrykt Północny / Region Północny / Prowincja Północna",
+    "SR-BR" => "Brokopondo",
+    "SR-CM" => "Commewijne",
+    "SR-CR" => "Coronie",
+    "SR-MA" => "Marowijne",
+    "SR-NI" => "Nickerie",
+    "SR-PM" => "Paramaribo",
+    "SR-PR" => "Para",
+    "SR-SA" => "Saramacca",
+    "SR-SI" => "Sipaliwini",
+    "SR-WA" => "Wanica",
+    "CH-AG" => "Argowia",
+    "CH-AI" => "Appenzell Innerrhoden",
+    "CH-AR" => "Appenzell Ausserrhoden",
+    "CH-BE" => "Berno",
+    "CH-BL" => "Bazylea-Okręg",
+    "CH-BS" => "Bazylea-Miasto",
+    "CH-FR" => "Fryburg",
+    "CH-GE" => "Genewa",
+    "CH-GL" => "Glarus",
+    "CH-GR" => "Gryzonia",
+    "CH-JU" => "Jura",
+    "CH-LU" => "Lucerna",
+    "CH-NE" => "Neuchâtel",
+    "CH-NW" => "Nidwalden",
+    "CH-OW" => "Obwalden",
+    "CH-SG" => "Sankt Gallen",
+    "CH-SH" => "Szafuza",
+    "CH-SO" => "Solura",
+    "CH-SZ" => "Schwyz",
+    "CH-TG" => "Turgowia",
+    "CH-TI" => "Ticino",
+    "CH-UR" => "Uri",
+    "CH-VD" => "Vaud",
+    "CH-VS" => "Valais",
+    "CH-ZG" => "Zug",
+    "CH-ZH" => "Zurych",
+    "SY-DI" => "Damaszek",
+    "SY-DY" => "Dajr az-Zaur",
+    "SY-ID" => "Idlib",
+    "SY-RA" => "Ar-Rakka",
+    "TW-CHA" => "Changhua",
+    "TW-CYI" => "Chiayi",
+    "TW-CYQ" => "Chiayi",
+    "TW-HSQ" => "Hsinchu",
+    "TW-HSZ" => "Hsinchu",
+    "TW-HUA" => "Hualien",
+    "TW-KHH" => "Kaohsiung",
+    "TW-MIA" => "Miaoli",
+    "TW-NAN" => "Nantou",
+    "TW-PEN" => "Penghu",
+    "TW-PIF" => "Pingtung",
+    "TW-TAO" => "Taoyuan",
+    "TW-TNN" => "Tainan",
+    "TW-TPE" => "Tajpej",
+    "TW-TTT" => "Taitung",
+    "TW-TXG" => "Taichung",
+    "TW-YUN" => "Yunlin",
+    "TJ-KT" => "wilajet chatloński",
+    "TJ-SU" => "wilajet sogdyjski",
+    "TZ-01" => "Arusza",
+    "TZ-03" => "Dodoma",
+    "TZ-04" => "Iringa",
+    "TZ-05" => "Kagera",
+    "TZ-08" => "Kigona",
+    "TZ-09" => "Kilimandżaro",
+    "TZ-12" => "Lindi",
+    "TZ-13" => "Mara",
+    "TZ-14" => "Mbeya",
+    "TZ-16" => "Morogoro",
+    "TZ-17" => "Mtwara",
+    "TZ-18" => "Mwanza",
+    "TZ-20" => "Rukwa",
+    "TZ-21" => "Ruvumo",
+    "TZ-22" => "Shinyanga",
+    "TZ-23" => "Singida",
+    "TZ-24" => "Tabora",
+    "TZ-25" => "Tanga",
+    "TZ-26" => "Manyara",
+    "TH-11" => "Samut Prakan",
+    "TH-12" => "Nothaburi",
+    "TH-13" => "Pathum Thani",
+    "TH-14" => "Ayutthaya",
+    "TH-15" => "Ang Thong",
+    "TH-16" => "Lopburi",
+    "TH-17" => "Sing Buri",
+    "TH-18" => "Chainat",
+    "TH-19" => "Saraburi",
+    "TH-20" => "Chonburi",
+    "TH-21" => "Rayong",
+    "TH-22" => "Chanthaburi",
+    "TH-23" => "Trat",
+    "TH-24" => "Chachoengsao",
+    "TH-25" => "Prachinburi",
+    "TH-26" => "Nakhon Nayok",
+    "TH-27" => "Sa Kaeo",
+    "TH-30" => "Nakhon Ratchasima",
+    "TH-31" => "Buri Ram",
+    "TH-32" => "Surin",
+    "TH-33" => "Sisaket",
+    "TH-34" => "Ubon Ratchathani",
+    "TH-35" => "Yasothon",
+    "TH-36" => "Chaiyaphum",
+    "TH-37" => "Amnat Charoen",
+    "TH-39" => "Nongbua Lamphu",
+    "TH-40" => "Khon Kaen",
+    "TH-41" => "Udon Thani",
+    "TH-42" => "Loei",
+    "TH-43" => "Nong Khai",
+    "TH-44" => "Maha Sarakham",
+    "TH-45" => "Roi Et",
+    "TH-46" => "Kalasin",
+    "TH-47" => "Sakon Nakhon",
+    "TH-48" => "Nakhon Phanom",
+    "TH-49" => "Mukdahan",
+    "TH-50" => "Chiang Mai",
+    "TH-51" => "Lamphun",
+    "TH-52" => "Lampang",
+    "TH-53" => "Uttaradit",
+    "TH-54" => "Phrae",
+    "TH-55" => "Nan",
+    "TH-56" => "Phayao",
+    "TH-57" => "Chiang Rai",
+    "TH-58" => "Mae Hong Son",
+    "TH-60" => "Nakhon Sawan",
+    "TH-61" => "Uthai Thani",
+    "TH-62" => "Kamphaeng Phet",
+    "TH-63" => "Tak",
+    "TH-64" => "Sukhothai",
+    "TH-65" => "Phitsanulok",
+    "TH-66" => "Phichit",
+    "TH-67" => "Phetchabun",
+    "TH-70" => "Ratchaburi",
+    "TH-71" => "Kanchanaburi",
+    "TH-72" => "Suphanburi",
+    "TH-73" => "Nakhon Pathom",
+    "TH-74" => "Samut Sakhon",
+    "TH-75" => "Samut Songkhram",
+    "TH-76" => "Phetchaburi",
+    "TH-77" => "Prachuap Khiri Khan",
+    "TH-80" => "Nakhon Si Thammarat",
+    "TH-81" => "Krabi",
+    "TH-82" => "Phang Nga",
+    "TH-83" => "Phuket",
+    "TH-84" => "Surat Thani",
+    "TH-85" => "Ranong",
+    "TH-86" => "Chumphon",
+    "TH-90" => "Songkhla",
+    "TH-91" => "Satun",
+    "TH-92" => "Trang",
+    "TH-93" => "Phatthalung",
+    "TH-94" => "Pattani",
+    "TH-95" => "Yala",
+    "TH-96" => "Narathiwat",
+    "TH-S" => "Pattaya",
+    "TL-AL" => "Aileu",
+    "TL-AN" => "Ainaro",
+    "TL-BA" => "Baucau",
+    "TL-BO" => "Bobonaro",
+    "TL-CO" => "Cova-Lima",
+    "TL-DI" => "Dili",
+    "TL-ER" => "Ermera",
+    "TL-MF" => "Manufahi",
+    "TL-MT" => "Manatuto",
+    "TG-P" => "Plateaux",
+    "TG-S" => "Savanes",
+    "TO-01" => "'Eua",
+    "TO-02" => "Ha'apai",
+    "TO-03" => "Ongo Niua",
+    "TO-04" => "Tongatapu",
+    "TO-05" => "Vava'u",
+    "TT-ARI" => "Arima",
+    "TT-CHA" => "Chaguanas",
+    "TT-CTT" => "Couva-Tabaquite-Talparo",
+    "TT-DMN" => "Diego Martin",
+    "TT-PED" => "Penal-Debe",
+    "TT-POS" => "Port-of-Spain",
+    "TT-PRT" => "Princes Town",
+    "TT-PTF" => "Point Fortin",
+    "TT-SFO" => "San Fernando",
+    "TT-SGE" => "Sangre Grande",
+    "TT-SIP" => "Siparia",
+    "TT-SJL" => "San Juan-Laventille",
+    "TT-TUP" => "Tunapuna-Piarco",
+    "TN-11" => "Tunis",
+    "TN-13" => "Bin Arus",
+    "TN-14" => "Manuba",
+    "TN-21" => "Nabul",
+    "TN-22" => "Zaghwan",
+    "TN-23" => "Bizerta",
+    "TN-31" => "Badża",
+    "TN-32" => "Dżunduba",
+    "TN-33" => "Kaf",
+    "TN-34" => "Siljana",
+    "TN-41" => "Kairuan",
+    "TN-42" => "Kasrajn",
+    "TN-43" => "Sidi Bu Said",
+    "TN-51" => "Susa",
+    "TN-52" => "Monastyr",
+    "TN-53" => "Mahdija",
+    "TN-61" => "Safakis",
+    "TN-71" => "Kafsa",
+    "TN-72" => "Tauzar",
+    "TN-81" => "Kabis",
+    "TN-83" => "Tatawin",
+    "TR-01" => "Adana",
+    "TR-02" => "Adıyaman",
+    "TR-03" => "Afyonkarahisar",
+    "TR-04" => "Ağrı",
+    "TR-05" => "Amasya",
+    "TR-06" => "Ankara",
+    "TR-07" => "Antalya",
+    "TR-08" => "Artvin",
+    "TR-09" => "Aydın",
+    "TR-10" => "Balıkesir",
+    "TR-11" => "Bilecik",
+    "TR-12" => "Bingöl",
+    "TR-13" => "Bitlis",
+    "TR-14" => "Bolu",
+    "TR-15" => "Burdur",
+    "TR-16" => "Bursa",
+    "TR-17" => "Çanakkale",
+    "TR-18" => "Çankırı",
+    "TR-19" => "Çorum",
+    "TR-20" => "Denizli",
+    "TR-21" => "Diyarbakır",
+    "TR-22" => "Edirne",
+    "TR-23" => "Elazığ",
+    "TR-24" => "Erzincan",
+    "TR-25" => "Erzurum",
+    "TR-26" => "Eskişehir",
+    "TR-27" => "Gaziantep",
+    "TR-28" => "Giresun",
+    "TR-29" => "Gümüşhane",
+    "TR-30" => "Hakkari",
+    "TR-31" => "Hatay",
+    "TR-32" => "Isparta",
+    "TR-33" => "Mersin",
+    "TR-34" => "İstanbul",
+    "TR-35" => "İzmir",
+    "TR-36" => "Kars",
+    "TR-37" => "Kastamonu",
+    "TR-38" => "Kayseri",
+    "TR-39" => "Kırklareli",
+    "TR-40" => "Kırşehir",
+    "TR-41" => "Kocaeli",
+    "TR-42" => "Konya",
+    "TR-43" => "Kütahya",
+    "TR-44" => "Malatya",
+    "TR-45" => "Manisa",
+    "TR-46" => "Kahramanmaraş",
+    "TR-47" => "Mardin",
+    "TR-48" => "Muğla",
+    "TR-49" => "Muş",
+    "TR-50" => "Nevşehir",
+    "TR-51" => "Niğde",
+    "TR-52" => "Ordu",
+    "TR-53" => "Rize",
+    "TR-54" => "Sakarya",
+    "TR-55" => "Samsun",
+    "TR-56" => "Siirt",
+    "TR-57" => "Sinop",
+    "TR-58" => "Sivas",
+    "TR-59" => "Tekirdağ",
+    "TR-60" => "Tokat",
+    "TR-61" => "Trabzon",
+    "TR-62" => "Tunceli",
+    "TR-63" => "Şanlıurfa",
+    "TR-64" => "Uşak",
+    "TR-65" => "Wan",
+    "TR-66" => "Yozgat",
+    "TR-67" => "Zonguldak",
+    "TR-68" => "Aksaray",
+    "TR-69" => "Bayburt",
+    "TR-70" => "Karaman",
+    "TR-71" => "Kırıkkale",
+    "TR-72" => "Batman",
+    "TR-73" => "Şırnak",
+    "TR-74" => "Bartın",
+    "TR-75" => "Ardahan",
+    "TR-76" => "Iğdır",
+    "TR-77" => "Yalova",
+    "TR-78" => "Karabük",
+    "TR-79" => "Kilis",
+    "TR-80" => "Osmaniye",
+    "TR-81" => "Düzce",
+    "TM-A" => "wilajet achalski",
+    "TM-B" => "wilajet bałkański",
+    "TM-D" => "wilajet daszoguski",
+    "TM-L" => "wilajet lebapski",
+    "TM-M" => "wilajet maryjski",
+    "TM-S" => "Aszchabat",
+    "TV-FUN" => "Funafuti",
+    "TV-NIT" => "Niutao",
+    "TV-NKF" => "Nukufetau",
+    "TV-NKL" => "Nukulaelae",
+    "TV-NMA" => "Nanumea",
+    "TV-NUI" => "Nui",
+    "TV-VAI" => "Vaitupu",
+    "UG-101" => "Kalangala",
+    "UG-102" => "Kampala",
+    "UG-103" => "Kiboga",
+    "UG-104" => "Luwero",
+    "UG-105" => "Masaka",
+    "UG-106" => "Mpigi",
+    "UG-107" => "Mubende",
+    "UG-108" => "Mukono",
+    "UG-109" => "Nakasongola",
+    "UG-110" => "Rakai",
+    "UG-111" => "Sembabule",
+    "UG-112" => "Kayunga",
+    "UG-113" => "Wakiso",
+    "UG-114" => "Lyantonde",
+    "UG-115" => "Mityana",
+    "UG-116" => "Nakaseke",
+    "UG-201" => "Bugiri",
+    "UG-202" => "Busia",
+    "UG-203" => "Iganga",
+    "UG-204" => "Jinja",
+    "UG-205" => "Kamuli",
+    "UG-206" => "Kapchorwa",
+    "UG-207" => "Katakwi",
+    "UG-208" => "Kumi",
+    "UG-209" => "Mbale",
+    "UG-210" => "Pallisa",
+    "UG-211" => "Soroti",
+    "UG-212" => "Tororo",
+    "UG-213" => "Kaberamaido",
+    "UG-214" => "Mayuge",
+    "UG-215" => "Sironko",
+    "UG-216" => "Amuria",
+    "UG-217" => "Budaka",
+    "UG-218" => "Bududa",
+    "UG-219" => "Bukedea",
+    "UG-221" => "Butaleja",
+    "UG-222" => "Kaliro",
+    "UG-223" => "Manafwa",
+    "UG-224" => "Namutumba",
+    "UG-301" => "Adjumani",
+    "UG-302" => "Apac",
+    "UG-303" => "Arua",
+    "UG-304" => "Gulu",
+    "UG-305" => "Kitgum",
+    "UG-306" => "Kotido",
+    "UG-307" => "Lira",
+    "UG-308" => "Moroto",
+    "UG-309" => "Moyo",
+    "UG-310" => "Nebbi",
+    "UG-311" => "Nakapiripirit",
+    "UG-312" => "Pader",
+    "UG-313" => "Yumbe",
+    "UG-314" => "Abim",
+    "UG-315" => "Amolatar",
+    "UG-316" => "Amuru",
+    "UG-317" => "Dokolo",
+    "UG-318" => "Kaabong",
+    "UG-319" => "Koboko",
+    "UG-320" => "Maracha",
+    "UG-321" => "Oyam",
+    "UG-401" => "Bundibugyo",
+    "UG-402" => "Bushenyi",
+    "UG-403" => "Hoima",
+    "UG-404" => "Kabale",
+    "UG-405" => "Kabarole",
+    "UG-406" => "Kasese",
+    "UG-407" => "Kibale",
+    "UG-408" => "Kisoro",
+    "UG-409" => "Masindi",
+    "UG-410" => "Mbarara",
+    "UG-411" => "Ntungamo",
+    "UG-412" => "Rukungiri",
+    "UG-413" => "Kamwenge",
+    "UG-414" => "Kanungu",
+    "UG-415" => "Kyenjojo",
+    "UG-416" => "Bulisa",
+    "UG-417" => "Ibanda",
+    "UG-418" => "Isingiro",
+    "UG-419" => "Kiruhura",
+    "UG-C" => "Dystrykt Centralny / Region Centralny / Prowincja Centralna / Departament Centralny",
+    "UG-E" => "Dystrykt Wschodni / Region Wschodni / Prowincja Wschodnia",
+    "UG-N" => "Dystrykt Północny / Region Północny / Prowincja Północna",
+    "UG-W" => "Dystrykt Zachodni / Region Zachodni / Western Division / Prowincja Zachodnia",
+    "UA-40" => "Sewastopol",
+    "AE-AJ" => "Adżman",
+    "AE-DU" => "Dubaj",
+    "AE-FU" => "Fudżajra",
+    "AE-RK" => "Ras al-Chajma",
+    "AE-SH" => "Szardża",
+    "AE-UQ" => "Umm al-Kajwajn",
+    "GB-ABC" => "Armagh City, Banbridge and Craigavon",
+    "GB-ABD" => "Aberdeenshire",
+    "GB-ABE" => "Aberdeen",
+    "GB-AGB" => "Argyll and Bute",
+    "GB-AGY" => "Anglesey",
+    "GB-AND" => "Ards and North Down",
+    "GB-ANN" => "Antrim and Newtownabbey",
+    "GB-ANS" => "Angus",
+    "GB-BAS" => "Bath and North East Somerset",
+    "GB-BBD" => "Blackburn with Darwen",
+    "GB-BCP" => "Bournemouth, Christchurch and Poole",
+    "GB-BDF" => "Bedford",
+    "GB-BDG" => "Barking and Dagenham",
+    "GB-BEN" => "Brent",
+    "GB-BEX" => "Bexley",
+    "GB-BFS" => "Belfast",
+    "GB-BGE" => "Bridgend",
+    "GB-BGW" => "Blaenau Gwent",
+    "GB-BIR" => "Birmingham",
+    "GB-BKM" => "Buckinghamshire",
+    "GB-BNE" => "Barnet",
+    "GB-BNH" => "Brighton and Hove",
+    "GB-BNS" => "Barnsley",
+    "GB-BOL" => "Bolton",
+    "GB-BPL" => "Blackpool",
+    "GB-BRC" => "Bracknell Forest",
+    "GB-BRD" => "Bradford",
+    "GB-BRY" => "Bromley",
+    "GB-BST" => "Bristol",
+    "GB-BUR" => "Bury",
+    "GB-CAM" => "Cambridgeshire",
+    "GB-CAY" => "Caerphilly",
+    "GB-CBF" => "Central Bedfordshire",
+    "GB-CCG" => "Causeway Coast and Glens",
+    "GB-CGN" => "Ceredigion",
+    "GB-CHE" => "Cheshire East",
+    "GB-CHW" => "Cheshire West and Chester",
+    "GB-CLD" => "Calderdale",
+    "GB-CLK" => "Clackmannanshire",
+    "GB-CMA" => "Kumbria",
+    "GB-CMD" => "Camden",
+    "GB-CMN" => "Carmarthenshire",
+    "GB-CON" => "Kornwalia",
+    "GB-COV" => "Coventry",
+    "GB-CRF" => "Cardiff",
+    "GB-CRY" => "Croydon",
+    "GB-CWY" => "Conwy",
+    "GB-DAL" => "Darlington",
+    "GB-DBY" => "Derbyshire",
+    "GB-DEN" => "Denbighshire",
+    "GB-DER" => "Derby",
+    "GB-DEV" => "Devon",
+    "GB-DGY" => "Dumfries and Galloway",
+    "GB-DNC" => "Doncaster",
+    "GB-DND" => "Dundee",
+    "GB-DOR" => "Dorset",
+    "GB-DRS" => "Derry and Strabane",
+    "GB-DUD" => "Dudley",
+    "GB-DUR" => "County Durham",
+    "GB-EAL" => "Ealing",
+    "GB-EAY" => "East Ayrshire",
+    "GB-EDH" => "Edynburg",
+    "GB-EDU" => "East Dunbartonshire",
+    "GB-ELN" => "East Lothian",
+    "GB-ELS" => "Hebrydy Zewnętrzne",
+    "GB-ENF" => "Enfield",
+    "GB-ENG" => "Anglia",
+    "GB-ERW" => "East Renfrewshire",
+    "GB-ERY" => "East Riding of Yorkshire",
+    "GB-ESS" => "Essex",
+    "GB-ESX" => "East Sussex",
+    "GB-FAL" => "Falkirk",
+    "GB-FIF" => "Fife",
+    "GB-FLN" => "Flintshire",
+    "GB-FMO" => "Fermanagh and Omagh",
+    "GB-GAT" => "Gateshead",
+    "GB-GLG" => "Glasgow",
+    "GB-GLS" => "Gloucestershire",
+    "GB-GRE" => "Greenwich",
+    "GB-GWN" => "Gwynedd",
+    "GB-HAL" => "Halton",
+    "GB-HAM" => "Hampshire",
+    "GB-HAV" => "Havering",
+    "GB-HCK" => "Hackney",
+    "GB-HEF" => "Herefordshire",
+    "GB-HIL" => "Hilingdon",
+    "GB-HLD" => "Highland",
+    "GB-HMF" => "Hammersmith and Fulham",
+    "GB-HNS" => "Hounslow",
+    "GB-HPL" => "Hartlepool",
+    "GB-HRT" => "Hertfordshire",
+    "GB-HRW" => "Harrow",
+    "GB-HRY" => "Haringey",
+    "GB-IOS" => "Scilly",
+    "GB-IOW" => "Isle of Wight",
+    "GB-ISL" => "Islington",
+    "GB-IVC" => "Inverclyde",
+    "GB-KEC" => "Kensington and Chelsea",
+    "GB-KEN" => "Kent",
+    "GB-KHL" => "Kingston upon Hull",
+    "GB-KIR" => "Kirklees",
+    "GB-KTT" => "Kingston upon Thames",
+    "GB-KWL" => "Knowsley",
+    "GB-LAN" => "Lancashire",
+    "GB-LBC" => "Lisburn and Castlereagh",
+    "GB-LBH" => "Lambeth",
+    "GB-LCE" => "Leicester",
+    "GB-LDS" => "Leeds",
+    "GB-LEC" => "Leicestershire",
+    "GB-LEW" => "Lewisham",
+    "GB-LIN" => "Lincolnshire",
+    "GB-LIV" => "Liverpool",
+    "GB-LND" => "Londyn",
+    "GB-LUT" => "Luton",
+    "GB-MAN" => "Manchester",
+    "GB-MDB" => "Middlesbrough",
+    "GB-MDW" => "Medway",
+    "GB-MEA" => "Mid and East Antrim",
+    "GB-MIK" => "Milton Keynes",
+    "GB-MLN" => "Midlothian",
+    "GB-MON" => "Monmouthshire",
+    "GB-MRT" => "Merton",
+    "GB-MRY" => "Moray",
+    "GB-MTY" => "Merthyr Tydfil",
+    "GB-MUL" => "Mid Ulster",
+    "GB-NAY" => "North Ayrshire",
+    "GB-NBL" => "Northumberland",
+    "GB-NEL" => "North East Lincolnshire",
+    "GB-NET" => "Newcastle upon Tyne",
+    "GB-NFK" => "Norfolk",
+    "GB-NGM" => "Nottingham",
+    "GB-NIR" => "Irlandia Północna",
+    "GB-NLK" => "North Lanarkshire",
+    "GB-NLN" => "North Lincolnshire",
+    "GB-NMD" => "Newry, Mourne and Down",
+    "GB-NSM" => "North Somerset",
+    "GB-NTL" => "Neath Port Talbot",
+    "GB-NTT" => "Nottinghamshire",
+    "GB-NTY" => "North Tyneside",
+    "GB-NWM" => "Newham",
+    "GB-NWP" => "Newport",
+    "GB-NYK" => "North Yorkshire",
+    "GB-OLD" => "Oldham",
+    "GB-ORK" => "Orkady",
+    "GB-OXF" => "Oxfordshire",
+    "GB-PEM" => "Pembrokeshire",
+    "GB-PKN" => "Perth and Kinross",
+    "GB-PLY" => "Plymouth",
+    "GB-POR" => "Portsmouth",
+    "GB-POW" => "Powys",
+    "GB-PTE" => "Peterborough",
+    "GB-RCC" => "Redcar and Cleveland",
+    "GB-RCH" => "Rochdale",
+    "GB-RCT" => "Rhondda Cynon Taff",
+    "GB-RDB" => "Redbridge",
+    "GB-RDG" => "Reading",
+    "GB-RFW" => "Renfrewshire",
+    "GB-RIC" => "Richmond upon Thames",
+    "GB-ROT" => "Rotherham",
+    "GB-RUT" => "Rutland",
+    "GB-SAW" => "Sandwell",
+    "GB-SAY" => "South Ayrshire",
+    "GB-SCB" => "Scottish Borders",
+    "GB-SCT" => "Szkocja",
+    "GB-SFK" => "Suffolk",
+    "GB-SFT" => "Sefton",
+    "GB-SGC" => "South Gloucestershire",
+    "GB-SHF" => "Sheffield",
+    "GB-SHN" => "St. Helens",
+    "GB-SHR" => "Shropshire",
+    "GB-SKP" => "Stockport",
+    "GB-SLF" => "Salford",
+    "GB-SLG" => "Slough",
+    "GB-SLK" => "South Lanarkshire",
+    "GB-SND" => "Sunderland",
+    "GB-SOL" => "Solihull",
+    "GB-SOM" => "Somerset",
+    "GB-SOS" => "Southend-on-Sea",
+    "GB-SRY" => "Surrey",
+    "GB-STE" => "Stoke-on-Trent",
+    "GB-STG" => "Stirling",
+    "GB-STH" => "Southampton",
+    "GB-STN" => "Sutton",
+    "GB-STS" => "Staffordshire",
+    "GB-STT" => "Stockton-on-Tees",
+    "GB-STY" => "South Tyneside",
+    "GB-SWA" => "Swansea",
+    "GB-SWD" => "Swindon",
+    "GB-SWK" => "Southwark",
+    "GB-TAM" => "Tameside",
+    "GB-TFW" => "Telford and Wrekin",
+    "GB-THR" => "Thurrock",
+    "GB-TOB" => "Torbay",
+    "GB-TOF" => "Torfaen",
+    "GB-TRF" => "Trafford",
+    "GB-TWH" => "Tower Hamlets",
+    "GB-VGL" => "Vale of Glamorgan",
+    "GB-WAR" => "Warwickshire",
+    "GB-WBK" => "West Berkshire",
+    "GB-WDU" => "West Dunbartonshire",
+    "GB-WFT" => "Waltham Forest",
+    "GB-WGN" => "Wigan",
+    "GB-WIL" => "Wiltshire",
+    "GB-WKF" => "Wakefield",
+    "GB-WLL" => "Walsall",
+    "GB-WLN" => "West Lothian",
+    "GB-WLS" => "Walia",
+    "GB-WLV" => "Wolverhampton",
+    "GB-WND" => "Wandsworth",
+    "GB-WNM" => "Windsor and Maidenhead",
+    "GB-WOK" => "Wokingham",
+    "GB-WOR" => "Worcestershire",
+    "GB-WRL" => "Wirral",
+    "GB-WRT" => "Warrington",
+    "GB-WRX" => "Wrexham",
+    "GB-WSM" => "Westminster",
+    "GB-WSX" => "West Sussex",
+    "GB-YOR" => "York",
+    "GB-ZET" => "Szetlandy",
+    "US-AK" => "Alaska",
+    "US-AL" => "Alabama",
+    "US-AR" => "Arkansas",
+    "US-AS" => "Samoa Amerykańskie",
+    "US-AZ" => "Arizona",
+    "US-CA" => "Kalifornia",
+    "US-CO" => "Kolorado",
+    "US-CT" => "Connecticut",
+    "US-DC" => "Dystrykt Kolumbii",
+    "US-DE" => "Delaware",
+    "US-FL" => "Floryda",
+    "US-GA" => "Georgia",
+    "US-GU" => "Guam",
+    "US-HI" => "Hawaje",
+    "US-IA" => "Iowa",
+    "US-ID" => "Idaho",
+    "US-IL" => "Illinois",
+    "US-IN" => "Indiana",
+    "US-KS" => "Kansas",
+    "US-KY" => "Kentucky",
+    "US-LA" => "Luizjana",
+    "US-MA" => "Massachusetts",
+    "US-MD" => "Maryland",
+    "US-ME" => "Maine",
+    "US-MI" => "Michigan",
+    "US-MN" => "Minnesota",
+    "US-MO" => "Missouri",
+    "US-MP" => "Mariany Północne",
+    "US-MS" => "Missisipi",
+    "US-MT" => "Montana",
+    "US-NC" => "Karolina Północna",
+    "US-ND" => "Dakota Północna",
+    "US-NE" => "Nebraska",
+    "US-NH" => "New Hampshire",
+    "US-NJ" => "New Jersey",
+    "US-NM" => "Nowy Meksyk",
+    "US-NV" => "Nevada",
+    "US-NY" => "Nowy Jork",
+    "US-OH" => "Ohio",
+    "US-OK" => "Oklahoma",
+    "US-OR" => "Oregon",
+    "US-PA" => "Pensylwania",
+    "US-PR" => "Portoryko",
+    "US-RI" => "Rhode Island",
+    "US-SC" => "Karolina Południowa",
+    "US-SD" => "Dakota Południowa",
+    "US-TN" => "Tennessee",
+    "US-TX" => "Teksas",
+    "US-UM" => "Dalekie Wyspy Mniejsze Stanów Zjednoczonych",
+    "US-UT" => "Utah",
+    "US-VA" => "Wirginia",
+    "US-VI" => "Wyspy Dziewicze Stanów Zjednoczonych",
+    "US-VT" => "Vermont",
+    "US-WA" => "Waszyngton",
+    "US-WI" => "Wisconsin",
+    "US-WV" => "Wirginia Zachodnia",
+    "US-WY" => "Wyoming",
+    "UM-67" => "Johnston",
+    "UM-71" => "Midway",
+    "UM-76" => "Navassa",
+    "UM-79" => "Wake",
+    "UM-81" => "Baker",
+    "UM-84" => "Howland",
+    "UM-86" => "Jarvis",
+    "UM-89" => "Kingman",
+    "UM-95" => "Palmyra",
+    "UY-AR" => "Artigas",
+    "UY-CA" => "Canelones",
+    "UY-CL" => "Cerro Largo",
+    "UY-CO" => "Colonia",
+    "UY-DU" => "Durazno",
+    "UY-FD" => "Floryda",
+    "UY-FS" => "Flores",
+    "UY-LA" => "Lavalleja",
+    "UY-MA" => "Maldonado",
+    "UY-MO" => "Montevideo",
+    "UY-PA" => "Paysandú",
+    "UY-RN" => "Río Negro",
+    "UY-RO" => "Rocha",
+    "UY-RV" => "Rivera",
+    "UY-SA" => "Salto",
+    "UY-SJ" => "San José",
+    "UY-SO" => "Soriano",
+    "UY-TA" => "Tacuarembó",
+    "UY-TT" => "Treinta y Tres",
+    "UZ-AN" => "wilajet andiżański",
+    "UZ-BU" => "wilajet bucharski",
+    "UZ-JI" => "wilajet dżyzacki",
+    "UZ-NG" => "wilajet namangański",
+    "UZ-NW" => "wilajet nawojski",
+    "UZ-QA" => "wilajet kaszkadaryjski",
+    "UZ-SA" => "wilajet samarkandzki",
+    "UZ-SI" => "wilajet syrdaryjski",
+    "UZ-SU" => "wilajet surchondaryjski",
+    "UZ-TK" => "Taszkent",
+    "UZ-TO" => "Taszkent",
+    "UZ-XO" => "wilajet chorezmijski",
+    "VU-MAP" => "Malampa",
+    "VU-PAM" => "Penama",
+    "VU-SAM" => "Sanma",
+    "VU-SEE" => "Shefa",
+    "VU-TAE" => "Tafea",
+    "VU-TOB" => "Torba",
+    "VE-B" => "Anzoátegui",
+    "VE-C" => "Apure",
+    "VE-D" => "Aragua",
+    "VE-E" => "Barinas",
+    "VE-F" => "Bolívar",
+    "VE-G" => "Carabobo",
+    "VE-H" => "Cojedes",
+    "VE-I" => "Falcón",
+    "VE-J" => "Guárico",
+    "VE-K" => "Lara",
+    "VE-L" => "Mérida",
+    "VE-M" => "Miranda",
+    "VE-N" => "Monagas",
+    "VE-O" => "Nueva Esparta",
+    "VE-P" => "Portuguesa",
+    "VE-R" => "Sucre",
+    "VE-S" => "Táchira",
+    "VE-T" => "Trujillo",
+    "VE-U" => "Yaracuy",
+    "VE-V" => "Zulia",
+    "VE-W" => "Dependencias Federales",
+    "VE-Y" => "Delta Amacuro",
+    "VE-Z" => "Amazonas",
+    "VN-01" => "Lai Châu",
+    "VN-02" => "Lào Cai",
+    "VN-03" => "Hà Giang",
+    "VN-04" => "Cao Bằng",
+    "VN-05" => "Sơn La",
+    "VN-06" => "Yên Bái",
+    "VN-07" => "Tuyên Quang",
+    "VN-09" => "Lạng Sơn",
+    "VN-13" => "Quảng Ninh",
+    "VN-18" => "Ninh Bình",
+    "VN-20" => "Thái Bình",
+    "VN-21" => "Thanh Hóa",
+    "VN-22" => "Nghệ An",
+    "VN-24" => "Quảng Bình",
+    "VN-25" => "Quảng Trị",
+    "VN-26" => "Thừa Thiên-Huế",
+    "VN-27" => "Quảng Nam",
+    "VN-28" => "Kon Tum",
+    "VN-29" => "Quảng Ngãi",
+    "VN-30" => "Gia Lai",
+    "VN-31" => "Bình Định",
+    "VN-32" => "Phú Yên",
+    "VN-34" => "Khánh Hòa",
+    "VN-35" => "Lâm Đồng",
+    "VN-36" => "Ninh Thuận",
+    "VN-37" => "Tây Ninh",
+    "VN-39" => "Đồng Nai",
+    "VN-40" => "Bình Thuận",
+    "VN-41" => "Long An",
+    "VN-44" => "An Giang",
+    "VN-45" => "Đồng Tháp",
+    "VN-46" => "Tiền Giang",
+    "VN-49" => "Vĩnh Long",
+    "VN-50" => "Bến Tre",
+    "VN-51" => "Trà Vinh",
+    "VN-52" => "Sóc Trăng",
+    "VN-53" => "Bắc Kạn",
+    "VN-54" => "Bắc Giang",
+    "VN-55" => "Bạc Liêu",
+    "VN-56" => "Bắc Ninh",
+    "VN-57" => "Bình Dương",
+    "VN-58" => "Bình Phước",
+    "VN-59" => "Cà Mau",
+    "VN-63" => "Hà Nam",
+    "VN-66" => "Hưng Yên",
+    "VN-67" => "Nam Định",
+    "VN-68" => "Phú Thọ",
+    "VN-69" => "Thái Nguyên",
+    "VN-70" => "Vĩnh Phúc",
+    "VN-71" => "Điện Biên",
+    "VN-72" => "Đắk Nông",
+    "VN-73" => "Hậu Giang",
+    "VN-CT" => "Cần Thơ",
+    "VN-DN" => "Đà Nẵng",
+    "VN-HN" => "Hà Nội",
+    "VN-HP" => "Hải Phòng",
+    "YE-DH" => "Zamar",
+    "YE-HD" => "Hadramaut",
+    "YE-HJ" => "Hadżdża",
+    "YE-HU" => "Al-Hudajda",
+    "YE-IB" => "Ibb",
+    "YE-JA" => "Al-Dżauf",
+    "YE-LA" => "Lahidż",
+    "YE-MR" => "Al-Mahra",
+    "YE-MW" => "Al-Mahwit",
+    "YE-RA" => "Rajma",
+    "YE-SH" => "Szabwa",
+    "ZM-01" => "Dystrykt Zachodni / Region Zachodni / Western Division / Prowincja Zachodnia",
+    "ZM-02" => "Dystrykt Centralny / Region Centralny / Prowincja Centralna / Departament Centralny",
+    "ZM-03" => "Dystrykt Wschodni / Region Wschodni / Prowincja Wschodnia",
+    "ZM-04" => "Prowincja Luapula",
+    "ZM-05" => "Dystrykt Północny / Region Północny / Prowincja Północna",
+    "ZM-06" => "Prowincja Północno-Zachodnia",
+    "ZM-07" => "Południowy",
+    "ZM-08" => "Prowincja Copperbelt",
+    "ZM-09" => "Prowincja Lusaka",
+    "ZW-BU" => "Bulawayo",
+    "ZW-HA" => "Harare",
+    "ZW-MA" => "Manicaland",
+    "ZW-MC" => "Mashonaland Środkowy",
+    "ZW-ME" => "Mashonaland Wschodni",
+    "ZW-MI" => "Midlands",
+    "ZW-MN" => "Matabeleland Północny",
+    "ZW-MS" => "Matabeleland Południowy",
+    "ZW-MV" => "Masvingo",
+    "ZW-MW" => "Mashonaland Zachodni",
+};
+
+///Polish former country names by alpha-4 code
+pub const FORMER_NAME_MAP: Map<&str, &str> = phf_map! {
+    "BQAQ" => "Brytyjskie Terytorium Antarktyczne",
+    "BUMM" => "Birma, Socjalistyczna Republika Związku Birmańskiego",
+    "BYAA" => "Białoruska SRR (Socjalistyczna Republika Radziecka)",
+    "CTKI" => "Canton i Enderbury",
+    "CSHH" => "Czechosłowacja, Czechosłowacka Republika Socjalistyczna",
+    "DYBJ" => "Dahomej",
+    "NQAQ" => "Ziemia Królowej Maud",
+    "TPTL" => "Timor Wschodni",
+    "FXFR" => "Francja metropolitalna",
+    "AIDJ" => "Francuskie Terytorium Afarów i Issów",
+    "FQHH" => "Francuskie Terytoria Południowe i Antarktyczne",
+    "DDDE" => "Niemiecka Republika Demokratyczna",
+    "GEHH" => "Wyspy Gilberta i Ellice",
+    "JTUM" => "Johnston",
+    "MIUM" => "Midway",
+    "ANHH" => "Antyle Holenderskie",
+    "NTHH" => "Strefa Neutralna",
+    "NHVU" => "Nowe Hebrydy",
+    "PCHH" => "Powiernicze Wyspy Pacyfiku",
+    "PZPA" => "Strefa Kanału Panamskiego",
+    "CSXX" => "Serbia i Czarnogóra",
+    "SKIN" => "Sikkim",
+    "RHZW" => "Rodezja Południowa",
+    "PUUM" => "Wyspy Stanów Zjednoczonych na Pacyfiku",
+    "HVBF" => "Górna Wolta, Republika Górnej Wolty",
+    "SUHH" => "ZSSR, Związek Socjalistycznych Republik Radzieckich",
+    "VDVN" => "Wietnam Północny, Demokratyczna Republika Wietnamu",
+    "WKUM" => "Wake",
+    "YDYE" => "Jemen Południowy, Jemen Demokratyczny, Ludowo-Demokratyczna Republika Jemenu",
+    "YUCS" => "Jugosławia, (Socjalistyczna) Federalna Republika Jugosławii",
+    "ZRCD" => "Zair, Republika Zairu",
+};
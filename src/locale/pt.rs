@@ -0,0 +1,305 @@
+use phf::phf_map;
+use phf::Map;
+
+///Portuguese country names by alpha-2 code
+pub const COUNTRY_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF" => "Afeganistão",
+    "AX" => "Ilhas Alanda",
+    "AL" => "Albânia",
+    "DZ" => "Argélia",
+    "AS" => "Samoa Americana",
+    "AD" => "Andorra",
+    "AO" => "Angola",
+    "AI" => "Anguilla",
+    "AQ" => "Antártida",
+    "AG" => "Antígua e Barbuda",
+    "AR" => "Argentina",
+    "AM" => "Arménia",
+    "AW" => "Aruba",
+    "AU" => "Austrália",
+    "AT" => "Áustria",
+    "AZ" => "Azerbaijão",
+    "BS" => "Bahamas",
+    "BH" => "Barém",
+    "BD" => "Bangladeche",
+    "BB" => "Barbados",
+    "BY" => "Bielorússia",
+    "BE" => "Bélgica",
+    "BZ" => "Belize",
+    "BJ" => "Benim",
+    "BM" => "Bermudas",
+    "BT" => "Butão",
+    "BO" => "Bolívia, Estado Plurinacional da",
+    "BQ" => "Bonaire, Santo Eustáquio e Saba",
+    "BA" => "Bósnia e Herzegovina",
+    "BW" => "Botsuana",
+    "BV" => "Ilha Bouvet",
+    "BR" => "Brasil",
+    "IO" => "Território Britânico do Oceano Índico",
+    "BN" => "Brunei",
+    "BG" => "Bulgária",
+    "BF" => "Burkina Faso",
+    "BI" => "Burundi",
+    "CV" => "Cabo Verde",
+    "KH" => "Camboja",
+    "CM" => "Camarões",
+    "CA" => "Canadá",
+    "KY" => "Ilhas Caimão",
+    "CF" => "República Centro-Africana",
+    "TD" => "Chade",
+    "CL" => "Chile",
+    "CN" => "China",
+    "CX" => "Ilha Natal",
+    "CC" => "Ilhas Cocos",
+    "CO" => "Colômbia",
+    "KM" => "Comores",
+    "CG" => "Congo",
+    "CD" => "Congo, República Democrática do",
+    "CK" => "Ilhas Cook",
+    "CR" => "Costa Rica",
+    "CI" => "Costa do Marfim",
+    "HR" => "Croácia",
+    "CU" => "Cuba",
+    "CW" => "Curação",
+    "CY" => "Chipre",
+    "CZ" => "Chéquia",
+    "DK" => "Dinamarca",
+    "DJ" => "Djibouti",
+    "DM" => "Dominica",
+    "DO" => "República Dominicana",
+    "EC" => "Equador",
+    "EG" => "Egito",
+    "SV" => "El Salvador",
+    "GQ" => "Guiné Equatorial",
+    "ER" => "Eritreia",
+    "EE" => "Estónia",
+    "SZ" => "Suazilândia",
+    "ET" => "Etiópia",
+    "FK" => "Ilhas Falkland (Malvinas)",
+    "FO" => "Ilhas Faroé",
+    "FJ" => "Fiji",
+    "FI" => "Finlândia",
+    "FR" => "França",
+    "GF" => "Guiana Francesa",
+    "PF" => "Polinésia Francesa",
+    "TF" => "Territórios Franceses do Sul",
+    "GA" => "Gabão",
+    "GM" => "Gâmbia",
+    "GE" => "Geórgia",
+    "DE" => "Alemanha",
+    "GH" => "Gana",
+    "GI" => "Gibraltar",
+    "GR" => "Grécia",
+    "GL" => "Gronelândia",
+    "GD" => "Granada",
+    "GP" => "Guadalupe",
+    "GU" => "Guam",
+    "GT" => "Guatemala",
+    "GG" => "Guernsey",
+    "GN" => "Guiné",
+    "GW" => "Guiné-Bissáu",
+    "GY" => "Guiana",
+    "HT" => "Haiti",
+    "HM" => "Ilha Heard e Ilhas McDonald",
+    "VA" => "Santa Sé (Estado da Cidade do Vaticano)",
+    "HN" => "Honduras",
+    "HK" => "Hong Kong",
+    "HU" => "Hungria",
+    "IS" => "Islândia",
+    "IN" => "Índia",
+    "ID" => "Indonésia",
+    "IR" => "Irão, República Islâmica do",
+    "IQ" => "Iraque",
+    "IE" => "Irlanda",
+    "IM" => "Ilha de Man",
+    "IL" => "Israel",
+    "IT" => "Itália",
+    "JM" => "Jamaica",
+    "JP" => "Japão",
+    "JE" => "Jersey",
+    "JO" => "Jordânia",
+    "KZ" => "Cazaquistão",
+    "KE" => "Quénia",
+    "KI" => "Kiribati",
+    "KP" => "Coreia, República Popular Democrática da",
+    "KR" => "Coreia, República da",
+    "KW" => "Kuwait",
+    "KG" => "Quirguistão",
+    "LA" => "República Democrática Popular do Laos",
+    "LV" => "Letónia",
+    "LB" => "Líbano",
+    "LS" => "Lesoto",
+    "LR" => "Libéria",
+    "LY" => "Líbia",
+    "LI" => "Liechtenstein",
+    "LT" => "Lituânia",
+    "LU" => "Luxemburgo",
+    "MO" => "Macau",
+    "MG" => "Madagáscar",
+    "MW" => "Malawi",
+    "MY" => "Malásia",
+    "MV" => "Maldivas",
+    "ML" => "Mali",
+    "MT" => "Malta",
+    "MH" => "Ilhas Marshall",
+    "MQ" => "Martinica",
+    "MR" => "Mauritânia",
+    "MU" => "Maurícia",
+    "YT" => "Mayotte",
+    "MX" => "México",
+    "FM" => "Micronésia, Estados Federados da",
+    "MD" => "Moldávia, República da",
+    "MC" => "Mónaco",
+    "MN" => "Mongólia",
+    "ME" => "Montenegro",
+    "MS" => "Monserrate",
+    "MA" => "Marrocos",
+    "MZ" => "Moçambique",
+    "MM" => "Birmânia",
+    "NA" => "Namíbia",
+    "NR" => "Nauru",
+    "NP" => "Nepal",
+    "NL" => "Países Baixos",
+    "NC" => "Nova Caledónia",
+    "NZ" => "Nova Zelândia",
+    "NI" => "Nicarágua",
+    "NE" => "Níger",
+    "NG" => "Nigéria",
+    "NU" => "Niue",
+    "NF" => "Ilha Norfolk",
+    "MK" => "Macedónia do Norte",
+    "MP" => "Ilhas Marianas do Norte",
+    "NO" => "Noruega",
+    "OM" => "Omã",
+    "PK" => "Paquistão",
+    "PW" => "Palau",
+    "PS" => "Palestina, Estado da",
+    "PA" => "Panamá",
+    "PG" => "Papua Nova Guiné",
+    "PY" => "Paraguai",
+    "PE" => "Peru",
+    "PH" => "Filipinas",
+    "PN" => "Pitcairn",
+    "PL" => "Polónia",
+    "PT" => "Portugal",
+    "PR" => "Porto Rico",
+    "QA" => "Catar",
+    "RE" => "Ilha Reunião",
+    "RO" => "Roménia",
+    "RU" => "Federação Russa",
+    "RW" => "Ruanda",
+    "BL" => "Saint Barthélemy",
+    "SH" => "Santa Helena, Ascensão e Tristão da Cunha",
+    "KN" => "São Cristóvão e Nevis",
+    "LC" => "Santa Lúcia",
+    "MF" => "São Martin (Território Francês)",
+    "PM" => "Saint Pierre e Miquelon",
+    "VC" => "São Vicente e Granadinas",
+    "WS" => "Samoa",
+    "SM" => "San Marino",
+    "ST" => "São Tomé e Príncipe",
+    "SA" => "Arábia Saudita",
+    "SN" => "Senegal",
+    "RS" => "Sérvia",
+    "SC" => "Seychelles",
+    "SL" => "Serra Leoa",
+    "SG" => "Singapura",
+    "SX" => "São Martinho (Países Baixos)",
+    "SK" => "Eslováquia",
+    "SI" => "Eslovénia",
+    "SB" => "Ilhas Salomão",
+    "SO" => "Somália",
+    "ZA" => "África do Sul",
+    "GS" => "Ilhas Geórgia do Sul e Sandwich do Sul",
+    "SS" => "Sudão do Sul",
+    "ES" => "Espanha",
+    "LK" => "Sri Lanka",
+    "SD" => "Sudão",
+    "SR" => "Suriname",
+    "SJ" => "Svalbard e Jan Mayen",
+    "SE" => "Suécia",
+    "CH" => "Suíça",
+    "SY" => "República Árabe Síria",
+    "TW" => "Taiwan, Província da China",
+    "TJ" => "Tajiquistão",
+    "TZ" => "Tanzânia, República Unida da",
+    "TH" => "Tailândia",
+    "TL" => "Timor-Leste",
+    "TG" => "Togo",
+    "TK" => "Tokelau",
+    "TO" => "Tonga",
+    "TT" => "Trindade e Tobago",
+    "TN" => "Tunísia",
+    "TR" => "Turquia",
+    "TM" => "Turquemenistão",
+    "TC" => "Ilhas Turcas e Caicos",
+    "TV" => "Tuvalu",
+    "UG" => "Uganda",
+    "UA" => "Ucrânia",
+    "AE" => "Emirados Árabes Unidos",
+    "GB" => "Reino Unido",
+    "US" => "Estados Unidos",
+    "UM" => "Ilhas Menores Distantes dos Estados Unidos",
+    "UY" => "Uruguai",
+    "UZ" => "Uzbequistão",
+    "VU" => "Vanuatu",
+    "VE" => "Venezuela, República Bolivariana da",
+    "VN" => "Vietname",
+    "VG" => "Ilhas Virgens, Britânicas",
+    "VI" => "Ilhas Virgens, Estados Unidos",
+    "WF" => "Wallis e Futuna",
+    "EH" => "Saara Ocidental",
+    "YE" => "Iémen",
+    "ZM" => "Zâmbia",
+    "ZW" => "Zimbábue",
+};
+
+///Portuguese subdivision names by ISO 3166-2 code, 12 of 5046
+pub const SUBDIVISION_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AL-03" => "Elbasan",
+    "BE-WLX" => "Luxemburgo",
+    "CO-BOL" => "Bolívar",
+    "CO-SUC" => "Sucre",
+    "EC-B" => "Bolívar",
+    "HN-LE" => "Lempira",
+    "IN-SK" => "Siquim",
+    "LU-LU" => "Luxemburgo",
+    "UM-71" => "Ilhas Midway",
+    "UM-79" => "Ilha Wake",
+    "VE-F" => "Bolívar",
+    "VE-R" => "Sucre",
+};
+
+///Portuguese former country names by alpha-4 code
+pub const FORMER_NAME_MAP: Map<&str, &str> = phf_map! {
+    "BQAQ" => "Território Antárctico Britânico",
+    "BUMM" => "Burma, República Socialista da União de",
+    "BYAA" => "Repúblca Socialista Soviética da Bielorússia",
+    "CTKI" => "Ilhas Canton e Enderbury",
+    "CSHH" => "Checoslováquia, República Socialista Checoslovaca",
+    "DYBJ" => "Daomé",
+    "NQAQ" => "Terra da Rainha Maud",
+    "TPTL" => "Timor Leste",
+    "FXFR" => "França Metropolitana",
+    "AIDJ" => "Territórios dos Afars e Issas",
+    "FQHH" => "Terras Austrais e Antárticas Francesas",
+    "DDDE" => "República Democrática da Alemanha",
+    "GEHH" => "Ilhas Gilbert e Ellice",
+    "JTUM" => "Ilha Johnston",
+    "MIUM" => "Ilhas de Midway",
+    "ANHH" => "Antilhas Holandesas",
+    "NTHH" => "Zona Neutra",
+    "NHVU" => "Novas Hébridas",
+    "PCHH" => "Protectorado das Ilhas do Pacífico das Nações Unidas",
+    "PZPA" => "Zona do Canal do Canadá",
+    "CSXX" => "Sérvia e Montenegro",
+    "SKIN" => "Sikkim",
+    "RHZW" => "Rodésia do Sul",
+    "PUUM" => "Miscelânea de Ilhas Americanas do Pacífico",
+    "HVBF" => "Alto Volta, República do",
+    "SUHH" => "URSS, União das Repúblicas Socialistas Soviéticas",
+    "VDVN" => "Vietnam, República Democrática do",
+    "WKUM" => "Ilha Wake",
+    "YDYE" => "Iémen, Democrático, República Popular Democrática do",
+    "ZRCD" => "Zaire, República do",
+};
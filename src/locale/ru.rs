@@ -0,0 +1,2286 @@
+use phf::phf_map;
+use phf::Map;
+
+///Russian country names by alpha-2 code
+pub const COUNTRY_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF" => "Афганистан",
+    "AX" => "Аландские острова",
+    "AL" => "Албания",
+    "DZ" => "Алжир",
+    "AS" => "Американские Самоа",
+    "AD" => "Андорра",
+    "AO" => "Ангола",
+    "AI" => "Ангвилла",
+    "AQ" => "Антарктика",
+    "AG" => "Антигуа и Барбуда",
+    "AR" => "Аргентина",
+    "AM" => "Армения",
+    "AW" => "Аруба",
+    "AU" => "Австралия",
+    "AT" => "Австрия",
+    "AZ" => "Азербайджан",
+    "BS" => "Багамы",
+    "BH" => "Бахрейн",
+    "BD" => "Бангладеш",
+    "BB" => "Барбадос",
+    "BY" => "Беларусь",
+    "BE" => "Бельгия",
+    "BZ" => "Белиз",
+    "BJ" => "Бенин",
+    "BM" => "Бермуды",
+    "BT" => "Бутан",
+    "BO" => "Боливия",
+    "BQ" => "Бонайре, Синт-Эстатиус и Саба",
+    "BA" => "Босния и Герцеговина",
+    "BW" => "Ботсвана",
+    "BV" => "Остров Буве",
+    "BR" => "Бразилия",
+    "IO" => "Британская территория Индийского океана",
+    "BN" => "Бруней Даруссалам",
+    "BG" => "Болгария",
+    "BF" => "Буркина-Фасо",
+    "BI" => "Бурунди",
+    "CV" => "Кабо-Верде",
+    "KH" => "Камбоджа",
+    "CM" => "Камерун",
+    "CA" => "Канада",
+    "KY" => "Каймановы острова",
+    "CF" => "Центрально-африканская республика",
+    "TD" => "Чад",
+    "CL" => "Чили",
+    "CN" => "Китай",
+    "CX" => "Остров Рождества",
+    "CC" => "Кокосовые острова",
+    "CO" => "Колумбия",
+    "KM" => "Коморы",
+    "CG" => "Конго",
+    "CD" => "Демократическая Республика Конго",
+    "CK" => "Острова Кука",
+    "CR" => "Коста-Рика",
+    "CI" => "Кот-д'Ивуар",
+    "HR" => "Хорватия",
+    "CU" => "Куба",
+    "CW" => "Кюрасао",
+    "CY" => "Кипр",
+    "CZ" => "Чехия",
+    "DK" => "Дания",
+    "DJ" => "Джибути",
+    "DM" => "Доминика",
+    "DO" => "Доминиканская республика",
+    "EC" => "Эквадор",
+    "EG" => "Египет",
+    "SV" => "Сальвадор",
+    "GQ" => "Экваториальная Гвинея",
+    "ER" => "Эритрея",
+    "EE" => "Эстония",
+    "SZ" => "Эсватини",
+    "ET" => "Эфиопия",
+    "FK" => "Фолклендские (Мальвинские) острова",
+    "FO" => "Фарерские острова",
+    "FJ" => "Фиджи",
+    "FI" => "Финляндия",
+    "FR" => "Франция",
+    "GF" => "Французская Гвиана",
+    "PF" => "Французская Полинезия",
+    "TF" => "Французские южные территории",
+    "GA" => "Габон",
+    "GM" => "Гамбия",
+    "GE" => "Грузия",
+    "DE" => "Германия",
+    "GH" => "Гана",
+    "GI" => "Гибралтар",
+    "GR" => "Греция",
+    "GL" => "Гренландия",
+    "GD" => "Гренада",
+    "GP" => "Гваделупа",
+    "GU" => "Гуам",
+    "GT" => "Гватемала",
+    "GG" => "Гернси",
+    "GN" => "Гвинея",
+    "GW" => "Гвинея-Бисау",
+    "GY" => "Гайана",
+    "HT" => "Гаити",
+    "HM" => "Остров Херд и острова МакДональд",
+    "VA" => "Государство-город Ватикан",
+    "HN" => "Гондурас",
+    "HK" => "Гонконг",
+    "HU" => "Венгрия",
+    "IS" => "Исландия",
+    "IN" => "Индия",
+    "ID" => "Индонезия",
+    "IR" => "Иран",
+    "IQ" => "Ирак",
+    "IE" => "Ирландия",
+    "IM" => "Остров Мэн",
+    "IL" => "Израиль",
+    "IT" => "Италия",
+    "JM" => "Ямайка",
+    "JP" => "Япония",
+    "JE" => "Джерси",
+    "JO" => "Иордания",
+    "KZ" => "Казахстан",
+    "KE" => "Кения",
+    "KI" => "Кирибати",
+    "KP" => "Корейская Народно-Демократическая Республика",
+    "KR" => "Республика Корея",
+    "KW" => "Кувейт",
+    "KG" => "Киргизия",
+    "LA" => "Лаосская Народно-Демократическая Республика",
+    "LV" => "Латвия",
+    "LB" => "Ливан",
+    "LS" => "Лесото",
+    "LR" => "Либерия",
+    "LY" => "Ливия",
+    "LI" => "Лихтенштейн",
+    "LT" => "Литва",
+    "LU" => "Люксембург",
+    "MO" => "Макао",
+    "MG" => "Мадагаскар",
+    "MW" => "Малави",
+    "MY" => "Малайзия",
+    "MV" => "Мальдивы",
+    "ML" => "Мали",
+    "MT" => "Мальта",
+    "MH" => "Маршалловы острова",
+    "MQ" => "Мартиника",
+    "MR" => "Мавритания",
+    "MU" => "Маврикий",
+    "YT" => "Майот",
+    "MX" => "Мексика",
+    "FM" => "Федеративные Штаты Микронезии",
+    "MD" => "Республика Молдова",
+    "MC" => "Монако",
+    "MN" => "Монголия",
+    "ME" => "Черногория",
+    "MS" => "Монтсеррат",
+    "MA" => "Марокко",
+    "MZ" => "Мозамбик",
+    "MM" => "Мьянма",
+    "NA" => "Намибия",
+    "NR" => "Науру",
+    "NP" => "Непал",
+    "NL" => "Нидерланды",
+    "NC" => "Новая Каледония",
+    "NZ" => "Новая Зеландия",
+    "NI" => "Никарагуа",
+    "NE" => "Нигер",
+    "NG" => "Нигерия",
+    "NU" => "Ниуэ",
+    "NF" => "Остров Норфолк",
+    "MK" => "Северная Македония",
+    "MP" => "Острова северной Марианы",
+    "NO" => "Норвегия",
+    "OM" => "Оман",
+    "PK" => "Пакистан",
+    "PW" => "Палау",
+    "PS" => "Палестина",
+    "PA" => "Панама",
+    "PG" => "Папуа — Новая Гвинея",
+    "PY" => "Парагвай",
+    "PE" => "Перу",
+    "PH" => "Филиппины",
+    "PN" => "Питкэрн",
+    "PL" => "Польша",
+    "PT" => "Португалия",
+    "PR" => "Пуэрто-Рико",
+    "QA" => "Катар",
+    "RE" => "Реюньон",
+    "RO" => "Румыния",
+    "RU" => "Российская Федерация",
+    "RW" => "Руанда",
+    "BL" => "Сен-Бартельми",
+    "SH" => "Остров Святой Елены, Остров Вознесения и Тристан-да-Кунья",
+    "KN" => "Сент-Китс и Невис",
+    "LC" => "Сент-Люсия",
+    "MF" => "Сен-Мартен (Франция)",
+    "PM" => "Сен-Пьер и Микелон",
+    "VC" => "Сент-Винсент и Гренадины",
+    "WS" => "Самоа",
+    "SM" => "Сан-Марино",
+    "ST" => "Сан-Томе и Принсипи",
+    "SA" => "Саудовская Аравия",
+    "SN" => "Сенегал",
+    "RS" => "Сербия",
+    "SC" => "Сейшелы",
+    "SL" => "Сьерра-Леоне",
+    "SG" => "Сингапур",
+    "SX" => "Синт-Мартен (голландская часть)",
+    "SK" => "Словакия",
+    "SI" => "Словения",
+    "SB" => "Соломоновы Острова",
+    "SO" => "Сомали",
+    "ZA" => "Южная Африка",
+    "GS" => "Южная Джорджия и Южные Сандвичевы острова",
+    "SS" => "Южный Судан",
+    "ES" => "Испания",
+    "LK" => "Шри-Ланка",
+    "SD" => "Судан",
+    "SR" => "Суринам",
+    "SJ" => "Шпицберген и Ян-Майен",
+    "SE" => "Швеция",
+    "CH" => "Швейцария",
+    "SY" => "Сирийская Арабская Республика",
+    "TW" => "Китайская провинция Тайвань",
+    "TJ" => "Таджикистан",
+    "TZ" => "Танзания",
+    "TH" => "Таиланд",
+    "TL" => "Восточный Тимор",
+    "TG" => "Того",
+    "TK" => "Токелау",
+    "TO" => "Тонга",
+    "TT" => "Тринидад и Тобаго",
+    "TN" => "Тунис",
+    "TM" => "Туркменистан",
+    "TC" => "Острова Туркс и Каикос",
+    "TV" => "Тувалу",
+    "UG" => "Уганда",
+    "UA" => "Украина",
+    "AE" => "Объединённые Арабские Эмираты",
+    "GB" => "Соединённое Королевство",
+    "US" => "Соединённые штаты",
+    "UM" => "Соединенные штаты Малых Удаленных островов",
+    "UY" => "Уругвай",
+    "UZ" => "Узбекистан",
+    "VU" => "Вануату",
+    "VE" => "Боливарианская Республика Венесуэла",
+    "VN" => "Вьетнам",
+    "VG" => "Виргинские острова (Британия)",
+    "VI" => "Виргинские острова (США)",
+    "WF" => "Уоллес и Футана",
+    "EH" => "Западная Сахара",
+    "YE" => "Йемен",
+    "ZM" => "Замбия",
+    "ZW" => "Зимбабве",
+};
+
+///Russian subdivision names by ISO 3166-2 code, 1994 of 5046
+pub const SUBDIVISION_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF-BAL" => "Балх",
+    "AF-BAM" => "Бамиан",
+    "AF-BDG" => "Бадгис",
+    "AF-BDS" => "Бадахшан",
+    "AF-BGL" => "Баглан",
+    "AF-DAY" => "Дайкунди",
+    "AF-FRA" => "Фарах",
+    "AF-FYB" => "Фарьяб",
+    "AF-GHA" => "Газни",
+    "AF-GHO" => "Гор",
+    "AF-HEL" => "Гильменд",
+    "AF-HER" => "Герат",
+    "AF-JOW" => "Джаузджан",
+    "AF-KAB" => "Кабул",
+    "AF-KAN" => "Кандагар",
+    "AF-KAP" => "Каписа",
+    "AF-KDZ" => "Кундуз",
+    "AF-KHO" => "Хост",
+    "AF-KNR" => "Кунар",
+    "AF-LAG" => "Лагман",
+    "AF-LOG" => "Логар",
+    "AF-NAN" => "Нангархар",
+    "AF-NIM" => "Нимроз",
+    "AF-NUR" => "Нуристан",
+    "AF-PAN" => "Панджшер",
+    "AF-PAR" => "Парван",
+    "AF-PIA" => "Пактия",
+    "AF-PKA" => "Пактика",
+    "AF-SAM" => "Саманган",
+    "AF-SAR" => "Сари-Пуль",
+    "AF-TAK" => "Тахар",
+    "AF-URU" => "Урузган",
+    "AF-WAR" => "Вардак",
+    "AF-ZAB" => "Забуль",
+    "AL-01" => "Берат",
+    "AL-02" => "Дуррес",
+    "AL-03" => "Эльбасан",
+    "AL-04" => "Фиери",
+    "AL-05" => "Гирокастра",
+    "AL-06" => "Корча",
+    "AL-07" => "Кукес",
+    "AL-08" => "Лежа",
+    "AL-09" => "Дибра",
+    "AL-10" => "Шкодер",
+    "AL-11" => "Тирана",
+    "AL-12" => "Влёра",
+    "DZ-01" => "Адрар",
+    "DZ-02" => "Эш-Шелифф",
+    "DZ-03" => "Лагуат",
+    "DZ-04" => "Умм-эль-Буаги",
+    "DZ-05" => "Батна",
+    "DZ-06" => "Беджая",
+    "DZ-07" => "Бискра",
+    "DZ-08" => "Бешар",
+    "DZ-09" => "Блида",
+    "DZ-10" => "Буира",
+    "DZ-11" => "Таманрассет",
+    "DZ-12" => "Тебесса",
+    "DZ-13" => "Тлемсен",
+    "DZ-14" => "Тиарет",
+    "DZ-15" => "Тизи-Узу",
+    "DZ-16" => "Алджер",
+    "DZ-17" => "Джельфа",
+    "DZ-18" => "Джиджель",
+    "DZ-19" => "Сетиф",
+    "DZ-20" => "Саида",
+    "DZ-21" => "Скикда",
+    "DZ-22" => "Сиди-Бель-Аббес",
+    "DZ-23" => "Аннаба",
+    "DZ-24" => "Гельма",
+    "DZ-26" => "Медеа",
+    "DZ-30" => "Уаргла",
+    "DZ-31" => "Оран",
+    "DZ-32" => "Эль-Баяд",
+    "DZ-33" => "Иллизи",
+    "DZ-34" => "Бордж-Бу-Арреридж",
+    "DZ-35" => "Бумердес",
+    "DZ-36" => "Эль-Тарф",
+    "DZ-37" => "Тиндуф",
+    "DZ-38" => "Тисемсильт",
+    "DZ-39" => "Эль-Уэд",
+    "DZ-40" => "Хеншела",
+    "DZ-41" => "Сук-Ахрас",
+    "DZ-42" => "Типаза",
+    "DZ-43" => "Мила",
+    "DZ-44" => "Айн-Дефла",
+    "DZ-46" => "Айн-Темушент",
+    "DZ-47" => "Гардая",
+    "DZ-48" => "Гализан",
+    "AD-02" => "Канильо",
+    "AD-03" => "Энкамп",
+    "AD-04" => "Ла-Массана",
+    "AD-05" => "Ордино",
+    "AD-06" => "Сан-Жулиа-де-Лория",
+    "AD-07" => "Андорра-ла-Велья",
+    "AD-08" => "Эскальдес-Энгордань",
+    "AO-BGO" => "Бенго",
+    "AO-BGU" => "Бенгела",
+    "AO-BIE" => "Бие",
+    "AO-CAB" => "Кабинда",
+    "AO-CCU" => "Квандо-Кубанго",
+    "AO-CNN" => "Кунене",
+    "AO-CNO" => "Северная Кванза",
+    "AO-CUS" => "Южная Кванза",
+    "AO-HUA" => "Уамбо",
+    "AO-HUI" => "Уила",
+    "AO-LNO" => "Северная Лунда",
+    "AO-LSU" => "Южная Лунда",
+    "AO-LUA" => "Луанда",
+    "AO-MAL" => "Маланже",
+    "AO-MOX" => "Мошико",
+    "AO-NAM" => "Намибе",
+    "AO-UIG" => "Уиже",
+    "AO-ZAI" => "Заире",
+    "AG-03" => "Сент-Джордж",
+    "AG-04" => "Сент-Джон",
+    "AG-05" => "Сент-Мэри",
+    "AG-06" => "Сент-Пол",
+    "AG-07" => "Сент-Питер",
+    "AG-08" => "Сент-Филип",
+    "AG-10" => "Барбуда",
+    "AG-11" => "Редонда",
+    "AR-A" => "Сальта",
+    "AR-B" => "Буэнос-Айрес",
+    "AR-C" => "Автономный город Буэнос-Айрес",
+    "AR-D" => "Сан-Луис",
+    "AR-E" => "Энтре-Риос",
+    "AR-F" => "Риоха",
+    "AR-G" => "Сантьяго-дель-Эстеро",
+    "AR-H" => "Чако",
+    "AR-J" => "Сан-Хуан",
+    "AR-K" => "Катамарка",
+    "AR-L" => "Ла-Пампа",
+    "AR-M" => "Мендоса",
+    "AR-N" => "Мисьонес",
+    "AR-P" => "Формоса",
+    "AR-Q" => "Неукен",
+    "AR-R" => "Рио-Негро",
+    "AR-S" => "Санта-Фе",
+    "AR-T" => "Тукуман",
+    "AR-U" => "Чубут",
+    "AR-V" => "Огненная Земля",
+    "AR-W" => "Корриентес",
+    "AR-X" => "Кордова",
+    "AR-Y" => "Жужуй",
+    "AR-Z" => "Санта-Крус",
+    "AM-AG" => "Арагацотнская область",
+    "AM-AR" => "Араратская область",
+    "AM-AV" => "Армавирская область",
+    "AM-ER" => "Ереван",
+    "AM-GR" => "Гехаркуникская область",
+    "AM-KT" => "Котайкская область",
+    "AM-SH" => "Ширакская область",
+    "AM-SU" => "Сюникская область",
+    "AM-TV" => "Тавушская область",
+    "AM-VD" => "Вайоцдзорская область",
+    "AU-ACT" => "Австралийская столичная территория",
+    "AU-NSW" => "Новый Южный Уэльс",
+    "AU-NT" => "Северная территория",
+    "AU-QLD" => "Квинсленд",
+    "AU-SA" => "Южная Австралия",
+    "AU-TAS" => "Тасмания",
+    "AU-VIC" => "Виктория",
+    "AU-WA" => "Западная Австралия",
+    "AT-1" => "Бургенланд",
+    "AT-2" => "Каринтия",
+    "AT-3" => "Нижняя Австрия",
+    "AT-4" => "Верхняя Австрия",
+    "AT-5" => "Зальцбург",
+    "AT-6" => "Штирия",
+    "AT-7" => "Тироль",
+    "AT-8" => "Форарльберг",
+    "AT-9" => "Вена",
+    "AZ-ABS" => "Абшеронский район",
+    "AZ-AGA" => "Акстафинский район",
+    "AZ-AGC" => "Агджабединский район",
+    "AZ-AGM" => "Агдамский район",
+    "AZ-AGS" => "Агдашаский район",
+    "AZ-AGU" => "Ахсуйский район",
+    "AZ-AST" => "Астаринский район",
+    "AZ-BA" => "Баку",
+    "AZ-BAB" => "Бабекский район",
+    "AZ-BAL" => "Белоканский район",
+    "AZ-BAR" => "Бардинский район",
+    "AZ-BEY" => "Бейлаганский район",
+    "AZ-BIL" => "Билясуварский район",
+    "AZ-CAB" => "Джебраильский район",
+    "AZ-CAL" => "Джалилабадский район",
+    "AZ-CUL" => "Джульфинский район",
+    "AZ-DAS" => "Дашкесанский район",
+    "AZ-FUZ" => "Фузулинский район",
+    "AZ-GA" => "Гянджа",
+    "AZ-GAD" => "Кедабекский район",
+    "AZ-GOR" => "Геранбойский район",
+    "AZ-GOY" => "Геокчайский район",
+    "AZ-GYG" => "Гёйгёльский район",
+    "AZ-HAC" => "Аджикабульский район",
+    "AZ-IMI" => "Имишлинский район",
+    "AZ-ISM" => "Исмаиллинский район",
+    "AZ-KAL" => "Кельбаджарский район",
+    "AZ-KAN" => "Кенгерлинский район",
+    "AZ-KUR" => "Кюрдамирский район",
+    "AZ-LA" => "Ленкорань",
+    "AZ-LAC" => "Лачинский район",
+    "AZ-LAN" => "Ленкорань",
+    "AZ-LER" => "Лерикский район",
+    "AZ-MAS" => "Масаллинский район",
+    "AZ-MI" => "Мингечаур",
+    "AZ-NA" => "Нафталан",
+    "AZ-NEF" => "Нефтечалинский район",
+    "AZ-NV" => "Нахичевань",
+    "AZ-NX" => "Нахичевань",
+    "AZ-OGU" => "Огузский район",
+    "AZ-ORD" => "Ордубадский район",
+    "AZ-QAB" => "Габалинский район",
+    "AZ-QAX" => "Кахский район",
+    "AZ-QAZ" => "Казахский район",
+    "AZ-QBA" => "Губинский район",
+    "AZ-QBI" => "Кубатлинский район",
+    "AZ-QOB" => "Гобустанский район",
+    "AZ-QUS" => "Кусарский район",
+    "AZ-SA" => "Шекинский район",
+    "AZ-SAB" => "Сабирабадский район",
+    "AZ-SAD" => "Садаракский район",
+    "AZ-SAH" => "Шахбузский район",
+    "AZ-SAK" => "Шекинский район",
+    "AZ-SAL" => "Сальянский район",
+    "AZ-SAR" => "Шарурский район",
+    "AZ-SAT" => "Саатлинский район",
+    "AZ-SBN" => "Шабранский район",
+    "AZ-SIY" => "Сиазанский район",
+    "AZ-SKR" => "Шамкирский район",
+    "AZ-SM" => "Сумгаит",
+    "AZ-SMI" => "Шемахинский район",
+    "AZ-SMX" => "Самухский район",
+    "AZ-SR" => "Ширван",
+    "AZ-SUS" => "Шушинский район",
+    "AZ-TAR" => "Тертерский район",
+    "AZ-TOV" => "Товузский район",
+    "AZ-UCA" => "Уджарский район",
+    "AZ-XA" => "Ханкенди",
+    "AZ-XAC" => "Хачмазский район",
+    "AZ-XCI" => "Ходжалинский район",
+    "AZ-XIZ" => "Хызынский район",
+    "AZ-XVD" => "Ходжаведский район",
+    "AZ-YAR" => "Ярдымлинский район",
+    "AZ-YE" => "Евлахский район",
+    "AZ-YEV" => "Евлахский район",
+    "AZ-ZAN" => "Зангиланский район",
+    "AZ-ZAQ" => "Закаталинский район",
+    "AZ-ZAR" => "Зардабский район",
+    "BS-AK" => "Аклинс",
+    "BS-BI" => "Бимини",
+    "BS-IN" => "Инагуа",
+    "BS-LI" => "Лонг-Айленд",
+    "BS-MC" => "Мангров-Ки",
+    "BS-MG" => "Маягуана",
+    "BS-NP" => "Нью-Провиденс",
+    "BS-RC" => "Рам-Ки",
+    "BS-SO" => "Южный Абако",
+    "BS-SS" => "Сан-Сальвадор",
+    "BS-SW" => "Спэниш-Уэллс",
+    "BD-01" => "Бандарбан",
+    "BD-02" => "Баргуна",
+    "BD-03" => "Богра",
+    "BD-04" => "Брахманбария",
+    "BD-05" => "Багерхат",
+    "BD-06" => "Барисал",
+    "BD-07" => "Бхола",
+    "BD-08" => "Комилла",
+    "BD-09" => "Чандпур",
+    "BD-10" => "Читтагонг",
+    "BD-11" => "Кокс-Базар",
+    "BD-12" => "Чуаданга",
+    "BD-13" => "Дакка",
+    "BD-14" => "Динаджпур",
+    "BD-15" => "Фаридпур",
+    "BD-16" => "Фени",
+    "BD-17" => "Гопалгандж",
+    "BD-18" => "Газипур",
+    "BD-19" => "Гайбандха",
+    "BD-20" => "Хабигандж",
+    "BD-21" => "Джамалпур",
+    "BD-22" => "Джессор",
+    "BD-23" => "Дженайда",
+    "BD-24" => "Джайпурхат",
+    "BD-25" => "Джалокати",
+    "BD-26" => "Кишоргандж",
+    "BD-27" => "Кхулна",
+    "BD-28" => "Куриграм",
+    "BD-29" => "Кхаграчхари",
+    "BD-30" => "Куштия",
+    "BD-31" => "Лакшмипур",
+    "BD-32" => "Лалмонирхат",
+    "BD-33" => "Маникгандж",
+    "BD-34" => "Маймансингх",
+    "BD-35" => "Муншигандж",
+    "BD-36" => "Мадарипур",
+    "BD-37" => "Магура",
+    "BD-38" => "Маулвибазар",
+    "BD-39" => "Мехерпур",
+    "BD-40" => "Нараянгандж",
+    "BD-41" => "Нетрокона",
+    "BD-42" => "Нарсингди",
+    "BD-43" => "Нарайл",
+    "BD-44" => "Натор",
+    "BD-45" => "Навабгандж",
+    "BD-46" => "Нилпхамари",
+    "BD-47" => "Ноакхали",
+    "BD-48" => "Наогаон",
+    "BD-49" => "Пабна",
+    "BD-50" => "Пироджпур",
+    "BD-51" => "Патуакхали",
+    "BD-52" => "Панчагарх",
+    "BD-53" => "Раджбари",
+    "BD-54" => "Раджшахи",
+    "BD-55" => "Рангпур",
+    "BD-56" => "Рангамати",
+    "BD-57" => "Шерпур",
+    "BD-58" => "Саткхира",
+    "BD-59" => "Сираджгандж",
+    "BD-60" => "Силхет",
+    "BD-61" => "Сунамгандж",
+    "BD-62" => "Шариатпур",
+    "BD-63" => "Тангайл",
+    "BD-64" => "Тхакургаон",
+    "BD-A" => "Барисал",
+    "BD-B" => "Читтагонг",
+    "BD-C" => "Дакка",
+    "BD-D" => "Кхулна",
+    "BD-E" => "Раджшахи",
+    "BD-F" => "Рангпур",
+    "BD-G" => "Силхет",
+    "BD-H" => "Маймансингх",
+    "BB-01" => "Крайст Чёрч",
+    "BB-02" => "Сент Эндрю",
+    "BB-03" => "Сент-Джордж",
+    "BB-04" => "Сент Джеймс",
+    "BB-05" => "Сент-Джон",
+    "BB-06" => "Сент Джозеф",
+    "BB-07" => "Сент Лючи",
+    "BB-08" => "Сент Мишель",
+    "BB-09" => "Сент-Питер",
+    "BB-10" => "Сент-Филип",
+    "BB-11" => "Сент Томас",
+    "BY-HM" => "Минск",
+    "BY-HO" => "Гомельская область",
+    "BY-HR" => "Гродненская область",
+    "BY-MI" => "Минская область",
+    "BE-BRU" => "Брюссельский столичный регион",
+    "BE-VAN" => "Антверпен",
+    "BE-VBR" => "Фламандский Брабант",
+    "BE-VLG" => "Фламандский регион",
+    "BE-VLI" => "Лимбург",
+    "BE-VOV" => "Восточная Фландрия",
+    "BE-VWV" => "Западная Фландрия",
+    "BE-WAL" => "Валлония",
+    "BE-WBR" => "Валлонский Брабант",
+    "BE-WHT" => "Эно",
+    "BE-WLG" => "Льеж",
+    "BE-WLX" => "Люксембург",
+    "BE-WNA" => "Намюр",
+    "BZ-BZ" => "Белиз",
+    "BZ-TOL" => "Толедо",
+    "BJ-LI" => "Литораль",
+    "BJ-MO" => "Моно",
+    "BJ-PL" => "Плато",
+    "BT-11" => "Паро",
+    "BT-12" => "Мебиса",
+    "BT-13" => "Хас",
+    "BT-14" => "Самце",
+    "BT-15" => "Тхимпху",
+    "BT-23" => "Пунакха",
+    "BT-24" => "Вангди-Пходранг",
+    "BT-31" => "Сарпанг",
+    "BT-32" => "Тронгса",
+    "BT-34" => "Жемганг",
+    "BT-41" => "Трашиганг",
+    "BT-42" => "Монгар",
+    "BT-44" => "Лхунце",
+    "BT-45" => "Самдруп-Джонгхар",
+    "BT-TY" => "Трашиянгце",
+    "BO-C" => "Кочабамба",
+    "BO-H" => "Чукисака",
+    "BO-L" => "Ла-Пас",
+    "BO-N" => "Пандо",
+    "BO-P" => "Потоси",
+    "BO-S" => "Санта-Крус",
+    "BO-T" => "Тариха",
+    "BQ-BO" => "Бонайре",
+    "BQ-SA" => "Саба",
+    "BQ-SE" => "Синт-Эстатиус",
+    "BA-BIH" => "Федерация Боснии и Герцеговины",
+    "BA-BRC" => "Округ Брчко",
+    "BA-SRP" => "Республика Сербская",
+    "BW-CH" => "Чобе",
+    "BW-FR" => "Франсистаун",
+    "BW-GA" => "Габороне",
+    "BW-JW" => "Джваненг",
+    "BW-KL" => "Кгатленг",
+    "BW-LO" => "Лобаце",
+    "BR-AC" => "Акр",
+    "BR-AL" => "Алагоас",
+    "BR-AP" => "Амапа",
+    "BR-BA" => "Баия",
+    "BR-CE" => "Сеара",
+    "BR-ES" => "Эспириту-Санту",
+    "BR-GO" => "Гояс",
+    "BR-MA" => "Мараньян",
+    "BR-MG" => "Минас-Жерайс",
+    "BR-MS" => "Мату-Гросу-ду-Сул",
+    "BR-MT" => "Мату-Гросу",
+    "BR-PA" => "Пара",
+    "BR-PB" => "Параиба",
+    "BR-PE" => "Пернамбуку",
+    "BR-PI" => "Пиауи",
+    "BR-PR" => "Парана",
+    "BR-RJ" => "Рио-де-Жанейро",
+    "BR-RN" => "Риу-Гранди-ду-Норти",
+    "BR-RO" => "Рондония",
+    "BR-RR" => "Рорайма",
+    "BR-RS" => "Риу-Гранди-ду-Сул",
+    "BR-SC" => "Санта Катарина",
+    "BR-SE" => "Сержипи",
+    "BR-SP" => "Сан-Паулу",
+    "BR-TO" => "Токантинс",
+    "BN-BM" => "Бруней-Муара",
+    "BN-TE" => "Тембуронг",
+    "BG-01" => "Благоевград",
+    "BG-02" => "Бургас",
+    "BG-03" => "Варна",
+    "BG-04" => "Велико-Тырново",
+    "BG-05" => "Видин",
+    "BG-06" => "Враца",
+    "BG-07" => "Габрово",
+    "BG-08" => "Добрич",
+    "BG-09" => "Кырджали",
+    "BG-10" => "Кюстендил",
+    "BG-11" => "Ловеч",
+    "BG-12" => "Монтана",
+    "BG-13" => "Пазарджик",
+    "BG-14" => "Перник",
+    "BG-15" => "Плевен",
+    "BG-16" => "Пловдив",
+    "BG-17" => "Разград",
+    "BG-19" => "Силистра",
+    "BG-20" => "Сливен",
+    "BG-21" => "Смолян",
+    "BG-23" => "София",
+    "BG-24" => "Стара-Загора",
+    "BG-25" => "Тырговиште",
+    "BG-26" => "Хасково",
+    "BG-27" => "Шумен",
+    "BG-28" => "Ямбол",
+    "BF-01" => "Букле-ду-Мухун",
+    "BF-02" => "Каскады",
+    "BF-03" => "Центральная",
+    "BF-BAL" => "Бале",
+    "BF-BAM" => "Бам",
+    "BF-BAN" => "Банва",
+    "BF-BAZ" => "Базега",
+    "BF-BGR" => "Бугуриба",
+    "BF-BLG" => "Бульгу",
+    "BF-BLK" => "Булькиемде",
+    "BF-COM" => "Комоэ",
+    "BF-GAN" => "Ганзургу",
+    "BF-GNA" => "Ньянья",
+    "BF-GOU" => "Гурма",
+    "BF-HOU" => "Уэ",
+    "BF-IOB" => "Иоба",
+    "BF-KAD" => "Кадиого",
+    "BF-KEN" => "Кенедугу",
+    "BF-KMD" => "Комонджари",
+    "BF-KMP" => "Компьенга",
+    "BF-KOP" => "Кульпелого",
+    "BF-KOS" => "Коси",
+    "BF-KOT" => "Куритенга",
+    "BF-KOW" => "Курвеого",
+    "BF-LER" => "Лераба",
+    "BF-LOR" => "Лорум",
+    "BF-MOU" => "Мухун",
+    "BF-NAM" => "Наментенга",
+    "BF-NAO" => "Нахури",
+    "BF-NAY" => "Наяла",
+    "BF-NOU" => "Нумбиель",
+    "BF-OUB" => "Убритенга",
+    "BF-OUD" => "Удалан",
+    "BF-PAS" => "Пасоре",
+    "BF-PON" => "Пони",
+    "BF-SEN" => "Сено",
+    "BF-SIS" => "Сисили",
+    "BF-SMT" => "Санматенга",
+    "BF-SNG" => "Сангие",
+    "BF-SOM" => "Сум",
+    "BF-SOR" => "Суру",
+    "BF-TAP" => "Тапоа",
+    "BF-TUI" => "Тюи",
+    "BF-YAG" => "Яга",
+    "BF-YAT" => "Ятенга",
+    "BF-ZIR" => "Зиро",
+    "BF-ZON" => "Зондома",
+    "BF-ZOU" => "Зундвеого",
+    "BI-BL" => "Бужумбура-Рураль",
+    "BI-BM" => "Бужумбура-Мери",
+    "BI-BR" => "Бурури",
+    "BI-CA" => "Чанкузо",
+    "BI-CI" => "Чибитоке",
+    "BI-GI" => "Гитега",
+    "BI-KI" => "Кирундо",
+    "BI-KR" => "Карузи",
+    "BI-KY" => "Каянза",
+    "BI-MU" => "Мурамвья",
+    "BI-MW" => "Мваро",
+    "BI-MY" => "Муйинга",
+    "BI-RM" => "Румонге",
+    "BI-RT" => "Рутана",
+    "BI-RY" => "Руйиги",
+    "CV-BV" => "Боа-Виста",
+    "CV-CA" => "Санта Катарина",
+    "CV-CR" => "Санта-Крус",
+    "CV-MO" => "Моштейруш",
+    "CV-PN" => "Порто-Ново",
+    "CV-PR" => "Прая",
+    "CV-RB" => "Рибейра-Брава",
+    "CV-SD" => "Сан-Домингус",
+    "CV-SV" => "Сан-Висенти",
+    "CV-TA" => "Таррафал",
+    "CV-TS" => "Таррафал-де-Сан-Николау",
+    "CM-CE" => "Центральная",
+    "CM-LT" => "Литораль",
+    "CA-AB" => "Альберта",
+    "CA-BC" => "Британская Колумбия",
+    "CA-MB" => "Манитоба",
+    "CA-NB" => "Нью-Брансуик",
+    "CA-NL" => "Ньюфаундленд и Лабрадор",
+    "CA-NS" => "Новая Шотландия",
+    "CA-NT" => "Северо-Западные территории",
+    "CA-NU" => "Нунавут",
+    "CA-ON" => "Онтарио",
+    "CA-PE" => "Остров Принца Эдуарда",
+    "CA-QC" => "Квебек",
+    "CA-SK" => "Саскачеван",
+    "CA-YT" => "Юкон",
+    "CF-AC" => "Уам",
+    "CF-BB" => "Баминги-Бангоран",
+    "CF-BGF" => "Банги",
+    "CF-BK" => "Нижнее Котто",
+    "CF-HK" => "Верхнее Котто",
+    "CF-HM" => "Верхнее Мбому",
+    "CF-KB" => "Нана-Гребизи",
+    "CF-KG" => "Кемо",
+    "CF-LB" => "Лобае",
+    "CF-MP" => "Омбелла-Мпоко",
+    "CF-NM" => "Нана-Мамбере",
+    "CF-OP" => "Уам-Пенде",
+    "CF-SE" => "Сангха",
+    "CF-VK" => "Вакага",
+    "TD-LC" => "Эль-Фуджайра",
+    "CL-AI" => "Айсен-дель-Хенераль-Карлос-Ибаньес-дель-Кампо",
+    "CL-AN" => "Антофагаста",
+    "CL-AP" => "Арика-и-Паринакота",
+    "CL-AR" => "Арауко",
+    "CL-AT" => "Атакама",
+    "CL-BI" => "Био-Био",
+    "CL-CO" => "Кокимбо",
+    "CL-LI" => "Либертадор-Хенераль-Бернардо-О’Хиггинс",
+    "CL-LL" => "Лос-Лагос",
+    "CL-MA" => "Магальянес",
+    "CL-ML" => "Мауле",
+    "CN-BJ" => "Пекин",
+    "CN-CQ" => "Чунцин",
+    "CN-GD" => "Гуандун",
+    "CN-HI" => "Хайнань",
+    "CN-HK" => "Гонконг",
+    "CN-HN" => "Хунань",
+    "CN-JL" => "Гирин",
+    "CN-MO" => "Макао",
+    "CN-NM" => "Внутренняя Монголия",
+    "CN-QH" => "Цинхай",
+    "CN-SC" => "Сычуань",
+    "CN-SH" => "Шанхай",
+    "CN-YN" => "Юньнань",
+    "CN-ZJ" => "Чжэцзян",
+    "CO-ARA" => "Араука",
+    "CO-COR" => "Кордова",
+    "CO-NAR" => "Нариньо",
+    "CO-NSA" => "Северный Сантандер",
+    "CO-QUI" => "Киндио",
+    "CO-SAP" => "Сан-Андрес-и-Провиденсия",
+    "CG-11" => "Буэнза",
+    "CG-13" => "Сангха",
+    "CG-2" => "Лекуму",
+    "CG-BZV" => "Браззавиль",
+    "CD-BC" => "Центральное Конго",
+    "CD-BU" => "Нижнее Уэле",
+    "CD-EQ" => "Эквадор",
+    "CD-NK" => "Северное Киву",
+    "CD-NU" => "Северное Убанги",
+    "CD-SK" => "Южное Киву",
+    "CD-SU" => "Южное Убанги",
+    "CD-TA" => "Тангайл",
+    "CD-TO" => "Чопо",
+    "CD-TU" => "Чуапа",
+    "CR-C" => "Картаго",
+    "CR-G" => "Гуанакасте",
+    "CR-H" => "Эредия",
+    "CR-L" => "Лимон",
+    "CI-AB" => "Абиджан",
+    "CI-BS" => "Ла-Массана",
+    "CI-CM" => "Комоэ",
+    "HR-01" => "Загребачка",
+    "HR-02" => "Крапинско-Загорска",
+    "HR-03" => "Сисачко-Мославачка",
+    "HR-04" => "Карловачка",
+    "HR-05" => "Вараждинска",
+    "HR-06" => "Копривничко-Крижевачка",
+    "HR-07" => "Бьеловарско-Билогорска",
+    "HR-08" => "Приморско-Горанска",
+    "HR-09" => "Личко-Сеньска",
+    "HR-10" => "Вировитичко-Подравска",
+    "HR-11" => "Пожешко-Славонска",
+    "HR-12" => "Бродско-Посавска",
+    "HR-13" => "Задарска",
+    "HR-14" => "Осьечко-Бараньска",
+    "HR-15" => "Шибенско-Книнска",
+    "HR-16" => "Вуковарско-Сриемска",
+    "HR-17" => "Сплитско-Далматинска",
+    "HR-18" => "Истарска",
+    "HR-19" => "Дубровачко-Неретванска",
+    "HR-20" => "Меджимурска",
+    "HR-21" => "Загреб",
+    "CU-01" => "Пинар-дель-Рио",
+    "CU-03" => "Гавана",
+    "CU-04" => "Матансас",
+    "CU-06" => "Сьенфуэгос",
+    "CU-07" => "Санкти-Спиритус",
+    "CU-08" => "Сьего-де-Авила",
+    "CU-09" => "Камагуэй",
+    "CU-11" => "Ольгин",
+    "CU-12" => "Гранма",
+    "CU-13" => "Сантьяго-де-Куба",
+    "CU-14" => "Гуантанамо",
+    "CU-15" => "Артемиса",
+    "CY-02" => "Лимасол",
+    "CY-03" => "Ларнака",
+    "CZ-20" => "Среднечешский край",
+    "CZ-201" => "Бенешов",
+    "CZ-202" => "Бероун",
+    "CZ-203" => "Кладно",
+    "CZ-204" => "Колин",
+    "CZ-205" => "Кутна-Гора",
+    "CZ-206" => "Мельник",
+    "CZ-207" => "Млада-Болеслав",
+    "CZ-208" => "Нимбурк",
+    "CZ-209" => "Прага-восток",
+    "CZ-20A" => "Прага-запад",
+    "CZ-20B" => "Пршибрам",
+    "CZ-20C" => "Раковник",
+    "CZ-31" => "Южно-Чешский край",
+    "CZ-311" => "Ческе-Будеёвице",
+    "CZ-312" => "Чески-Крумлов",
+    "CZ-313" => "Йиндржихув-Градец",
+    "CZ-314" => "Писек",
+    "CZ-315" => "Прахатице",
+    "CZ-316" => "Страконице",
+    "CZ-317" => "Табор",
+    "CZ-32" => "Пльзеньский край",
+    "CZ-321" => "Домажлице",
+    "CZ-322" => "Клатови",
+    "CZ-323" => "Пльзень-город",
+    "CZ-324" => "Пльзень-юг",
+    "CZ-325" => "Пльзень-север",
+    "CZ-326" => "Рокицани",
+    "CZ-327" => "Тахов",
+    "CZ-41" => "Карловарский край",
+    "CZ-411" => "Хеб",
+    "CZ-412" => "Карловы Вары",
+    "CZ-413" => "Соколов",
+    "CZ-42" => "Устецкий край",
+    "CZ-421" => "Дечин",
+    "CZ-422" => "Хомутов",
+    "CZ-423" => "Литомержице",
+    "CZ-424" => "Лоуни",
+    "CZ-426" => "Теплице",
+    "CZ-427" => "Усти-над-Лабем",
+    "CZ-51" => "Либерецкий край",
+    "CZ-511" => "Ческа-Липа",
+    "CZ-512" => "Яблонец-над-Нисоу",
+    "CZ-513" => "Либерец",
+    "CZ-514" => "Семили",
+    "CZ-52" => "Краловеградецкий край",
+    "CZ-521" => "Градец-Кралове",
+    "CZ-522" => "Йичин",
+    "CZ-523" => "Наход",
+    "CZ-524" => "Рихнов-над-Кнежноу",
+    "CZ-525" => "Трутнов",
+    "CZ-53" => "Пардубицкий край",
+    "CZ-531" => "Хрудим",
+    "CZ-532" => "Пардубице",
+    "CZ-533" => "Свитави",
+    "CZ-534" => "Усти-над-Орлици",
+    "CZ-631" => "Гавличкув-Брод",
+    "CZ-632" => "Йиглава",
+    "CZ-633" => "Пельгржимов",
+    "CZ-634" => "Тршебич",
+    "CZ-635" => "Ждяр-над-Сазавоу",
+    "CZ-64" => "Южно-Моравский край",
+    "CZ-641" => "Бланско",
+    "CZ-642" => "Брно-город",
+    "CZ-643" => "Брно-пригород",
+    "CZ-644" => "Бржецлав",
+    "CZ-645" => "Годонин",
+    "CZ-646" => "Вишков",
+    "CZ-647" => "Зноймо",
+    "CZ-71" => "Оломоуцкий край",
+    "CZ-711" => "Есеник",
+    "CZ-712" => "Оломоуц",
+    "CZ-713" => "Простеёв",
+    "CZ-714" => "Пршеров",
+    "CZ-715" => "Шумперк",
+    "CZ-72" => "Злинский край",
+    "CZ-721" => "Кромержиж",
+    "CZ-722" => "Угерске-Градиште",
+    "CZ-723" => "Всетин",
+    "CZ-724" => "Злин",
+    "CZ-80" => "Моравско-Силезский край",
+    "CZ-802" => "Фридек-Мистек",
+    "CZ-803" => "Карвина",
+    "CZ-804" => "Нови-Йичин",
+    "CZ-805" => "Опава",
+    "CZ-806" => "Острава-город",
+    "DK-83" => "Южная Дания",
+    "DK-84" => "Ховедстаден",
+    "DK-85" => "Зеландия",
+    "DJ-AR" => "Арта",
+    "DJ-AS" => "Али-Сабих",
+    "DJ-DI" => "Дикиль",
+    "DJ-DJ" => "Джибути",
+    "DM-02" => "Сент Эндрю",
+    "DM-04" => "Сент-Джордж",
+    "DM-05" => "Сент-Джон",
+    "DM-06" => "Сент Джозеф",
+    "DM-10" => "Сент-Пол",
+    "DM-11" => "Сент-Питер",
+    "DO-21" => "Сан-Кристобаль",
+    "DO-22" => "Сан-Хуан",
+    "DO-23" => "Сан-Педро-де-Макорис",
+    "DO-24" => "Санчес-Рамирес",
+    "DO-25" => "Сантьяго",
+    "DO-27" => "Валверде",
+    "DO-32" => "Санто-Доминго",
+    "EC-A" => "Асуай",
+    "EC-C" => "Карчи",
+    "EC-D" => "Орельяна",
+    "EC-G" => "Гуаяс",
+    "EC-H" => "Чимборасо",
+    "EC-P" => "Пичинча",
+    "EC-S" => "Морона-Сантьяго",
+    "EC-SD" => "Санто-Доминго-де-лос-Тсачилас",
+    "EC-SE" => "Санта-Элена",
+    "EC-T" => "Тунгурауа",
+    "EC-U" => "Сукумбиос",
+    "EC-W" => "Галапагос",
+    "EC-Z" => "Самора-Чинчипе",
+    "EG-ASN" => "Асуан",
+    "EG-AST" => "Асьют",
+    "EG-BH" => "Эль-Фуджайра",
+    "EG-BNS" => "Бени-Суэйф",
+    "EG-DK" => "Дакахлия",
+    "EG-FYM" => "Эль-Файюм",
+    "EG-GH" => "Эль-Гарбия",
+    "EG-GZ" => "Эль-Гиза",
+    "EG-KFS" => "Кафр-эш-Шейх",
+    "EG-LX" => "Луксор",
+    "EG-PTS" => "Порт-Саид",
+    "EG-SHG" => "Сохаг",
+    "EG-SHR" => "Эш-Шаркия",
+    "EG-WAD" => "Вади-эль-Гедид",
+    "SV-LI" => "Ла-Либертад",
+    "SV-PA" => "Ла-Пас",
+    "SV-SS" => "Сан-Сальвадор",
+    "GQ-KN" => "Ке-Нтем",
+    "GQ-LI" => "Литораль",
+    "GQ-WN" => "Веле-Нзас",
+    "ER-GB" => "Гаш-Барка",
+    "EE-130" => "Ида-Вирумаа",
+    "EE-142" => "Антсла",
+    "EE-171" => "Елва",
+    "EE-184" => "Хаапсалу",
+    "EE-205" => "Хийумаа",
+    "EE-214" => "Хяэдемеэсте",
+    "EE-247" => "Йыгева",
+    "EE-251" => "Йыхви",
+    "EE-255" => "Ярвамаа",
+    "EE-272" => "Кадрина",
+    "EE-296" => "Кейла",
+    "EE-303" => "Кихну",
+    "EE-305" => "Кийли",
+    "EE-321" => "Кохтла-Ярве",
+    "EE-338" => "Козе",
+    "EE-37" => "Харьюмаа",
+    "EE-39" => "Хийумаа",
+    "EE-424" => "Локса",
+    "EE-442" => "Люганузе",
+    "EE-446" => "Маарду",
+    "EE-45" => "Ида-Вирумаа",
+    "EE-486" => "Муствеэ",
+    "EE-50" => "Йыгевамаа",
+    "EE-503" => "Мярьямаа",
+    "EE-511" => "Нарва",
+    "EE-514" => "Нарва-Йыэсуу",
+    "EE-52" => "Ярвамаа",
+    "EE-557" => "Отепя",
+    "EE-56" => "Ляэнемаа",
+    "EE-567" => "Пайде",
+    "EE-60" => "Ляэне-Вирумаа",
+    "EE-618" => "Пылтсамаа",
+    "EE-622" => "Пылва",
+    "EE-624" => "Пярну",
+    "EE-64" => "Пылвамаа",
+    "EE-651" => "Раазику",
+    "EE-661" => "Раквере",
+    "EE-663" => "Раквере",
+    "EE-668" => "Рапла",
+    "EE-68" => "Пярнумаа",
+    "EE-689" => "Рухну",
+    "EE-708" => "Ряпина",
+    "EE-71" => "Рапламаа",
+    "EE-714" => "Сааремаа",
+    "EE-719" => "Саку",
+    "EE-726" => "Сауэ",
+    "EE-732" => "Сетумаа",
+    "EE-735" => "Силламяэ",
+    "EE-74" => "Сааремаа",
+    "EE-784" => "Таллин",
+    "EE-79" => "Тартумаа",
+    "EE-792" => "Тапа",
+    "EE-793" => "Тарту",
+    "EE-796" => "Тарту",
+    "EE-803" => "Тойла",
+    "EE-81" => "Валгамаа",
+    "EE-824" => "Тырва",
+    "EE-84" => "Вильяндимаа",
+    "EE-87" => "Вырумаа",
+    "EE-890" => "Виймси",
+    "EE-897" => "Вильянди",
+    "EE-899" => "Вильянди",
+    "EE-903" => "Виру-Нигула",
+    "EE-907" => "Вормси",
+    "EE-917" => "Выру",
+    "EE-919" => "Выру",
+    "ET-AA" => "Аддис-Абеба",
+    "ET-AM" => "Амара",
+    "ET-BE" => "Бенишангуль-Гумуз",
+    "ET-DD" => "Дыре-Дауа",
+    "ET-OR" => "Оромия",
+    "FI-01" => "Аландские острова",
+    "FI-02" => "Южная Карелия",
+    "FI-03" => "Южная Остроботния",
+    "FI-04" => "Южное Саво",
+    "FI-05" => "Кайнуу",
+    "FI-09" => "Кюменлааксо",
+    "FI-10" => "Лаппи",
+    "FI-11" => "Бургенланд",
+    "FI-17" => "Сатакунта",
+    "FI-18" => "Ниланд",
+    "FR-01" => "Эн",
+    "FR-02" => "Эна",
+    "FR-03" => "Алье",
+    "FR-04" => "Альпы Верхнего Прованса",
+    "FR-05" => "Верхние Альпы",
+    "FR-06" => "Приморские Альпы",
+    "FR-07" => "Ардеш",
+    "FR-08" => "Арденны",
+    "FR-09" => "Арьеж",
+    "FR-10" => "Об",
+    "FR-11" => "Од",
+    "FR-12" => "Аверон",
+    "FR-13" => "Буш-дю-Рон",
+    "FR-14" => "Кальвадос",
+    "FR-15" => "Канталь",
+    "FR-16" => "Шаранта",
+    "FR-17" => "Приморская Шаранта",
+    "FR-18" => "Шер",
+    "FR-19" => "Коррез",
+    "FR-20R" => "Корсика",
+    "FR-21" => "Кот-д’Ор",
+    "FR-22" => "Кот-д’Армор",
+    "FR-23" => "Крёз",
+    "FR-24" => "Дордонь",
+    "FR-25" => "Ду",
+    "FR-26" => "Дром",
+    "FR-27" => "Эр",
+    "FR-28" => "Эр и Луар",
+    "FR-29" => "Финистер",
+    "FR-2A" => "Южная Корсика",
+    "FR-2B" => "Верхняя Корсика",
+    "FR-30" => "Гар",
+    "FR-31" => "Верхняя Гаронна",
+    "FR-32" => "Жер",
+    "FR-33" => "Жиронда",
+    "FR-34" => "Эро",
+    "FR-35" => "Иль и Вилен",
+    "FR-36" => "Эндр",
+    "FR-37" => "Эндр и Луара",
+    "FR-38" => "Изер",
+    "FR-39" => "Юра",
+    "FR-40" => "Ланды",
+    "FR-41" => "Луар и Шер",
+    "FR-42" => "Луара",
+    "FR-43" => "Верхняя Луара",
+    "FR-44" => "Атлантическая Луара",
+    "FR-45" => "Луаре",
+    "FR-46" => "Лот",
+    "FR-47" => "Ло и Гаронна",
+    "FR-48" => "Лозер",
+    "FR-49" => "Мен и Луара",
+    "FR-50" => "Манш",
+    "FR-51" => "Марна",
+    "FR-52" => "Верхняя Марна",
+    "FR-53" => "Майен",
+    "FR-54" => "Мёрт и Мозель",
+    "FR-55" => "Маас",
+    "FR-56" => "Морбиан",
+    "FR-57" => "Мозель",
+    "FR-58" => "Ньевр",
+    "FR-60" => "Уаза",
+    "FR-61" => "Орн",
+    "FR-62" => "Па-де-Кале",
+    "FR-63" => "Пюи-де-Дом",
+    "FR-64" => "Атлантические Пиренеи",
+    "FR-65" => "Верхние Пиренеи",
+    "FR-66" => "Восточные Пиренеи",
+    "FR-67" => "Нижний Рейн",
+    "FR-68" => "Верхний Рейн",
+    "FR-69" => "Рона",
+    "FR-70" => "Верхняя Сона",
+    "FR-71" => "Сона и Луара",
+    "FR-72" => "Сарта",
+    "FR-73" => "Савойя",
+    "FR-74" => "Верхняя Савойя",
+    "FR-76" => "Приморская Сена",
+    "FR-77" => "Сена и Марна",
+    "FR-78" => "Ивелин",
+    "FR-79" => "Дё-Севр",
+    "FR-80" => "Сомма",
+    "FR-81" => "Тарн",
+    "FR-82" => "Тарн и Гаронна",
+    "FR-84" => "Воклюз",
+    "FR-85" => "Вандея",
+    "FR-86" => "Вьенна",
+    "FR-87" => "Верхняя Вьенна",
+    "FR-88" => "Вогезы",
+    "FR-89" => "Йонна",
+    "FR-90" => "Территория Бельфор",
+    "FR-91" => "Эсон",
+    "FR-92" => "О-де-Сен",
+    "FR-93" => "Сен-Сен-Дени",
+    "FR-94" => "Валь-де-Марн",
+    "FR-95" => "Валь-д’Уаз",
+    "FR-971" => "Гваделупа",
+    "FR-972" => "Мартиника",
+    "FR-974" => "Реюньон",
+    "FR-976" => "Майотта",
+    "FR-ARA" => "Овернь — Рона — Альпы",
+    "FR-BFC" => "Бургундия — Франш-Конте",
+    "FR-BRE" => "Бретань",
+    "FR-CVL" => "Центр — Долина Луары",
+    "FR-GES" => "Гранд-Эст",
+    "FR-HDF" => "О-де-Франс",
+    "FR-IDF" => "Иль-де-Франс",
+    "FR-NAQ" => "Новая Аквитания",
+    "FR-NC" => "Новая Каледония",
+    "FR-OCC" => "Окситания",
+    "FR-PDL" => "Пеи-де-ла-Луар",
+    "FR-PF" => "Французская Полинезия",
+    "FR-PM" => "Сен-Пьер и Микелон",
+    "FR-TF" => "Французские Южные и Антарктические территории",
+    "FR-WF" => "Уоллис и Футуна",
+    "GA-2" => "Верхнее Огове",
+    "GA-3" => "Среднее Огове",
+    "GA-6" => "Огове-Ивиндо",
+    "GA-7" => "Огове-Лоло",
+    "GA-9" => "Волё-Нтем",
+    "GE-AB" => "Абхазия",
+    "GE-AJ" => "Аджария",
+    "GE-GU" => "Гурия",
+    "GE-IM" => "Имеретия",
+    "GE-KK" => "Квемо-Картли",
+    "GE-MM" => "Мцхета-Мтианети",
+    "GE-SJ" => "Самцхе-Джавахети",
+    "GE-SK" => "Шида-Картли",
+    "GE-SZ" => "Самегрело-Верхняя Сванетия",
+    "GE-TB" => "Тбилиси",
+    "DE-BB" => "Бранденбург",
+    "DE-BE" => "Берлин",
+    "DE-BW" => "Баден-Вюртемберг",
+    "DE-BY" => "Бавария",
+    "DE-HB" => "Бремен",
+    "DE-HE" => "Гессен",
+    "DE-HH" => "Гамбург",
+    "DE-MV" => "Мекленбург-Передняя Померания",
+    "DE-NI" => "Нижняя Саксония",
+    "DE-NW" => "Северный Рейн-Вестфалия",
+    "DE-RP" => "Рейнланд-Пфальц",
+    "DE-SH" => "Шлезвиг-Гольштейн",
+    "DE-SL" => "Саар",
+    "DE-ST" => "Саксония-Анхальт",
+    "DE-TH" => "Тюрингия",
+    "GH-AA" => "Большая Аккра",
+    "GH-AH" => "Ашанти",
+    "GH-SV" => "Саванна",
+    "GR-I" => "Аттика",
+    "GR-J" => "Пелопоннес",
+    "GR-M" => "Крит",
+    "GD-01" => "Сент Эндрю",
+    "GD-03" => "Сент-Джордж",
+    "GD-04" => "Сент-Джон",
+    "GD-10" => "Карриаку и Малый Мартиник",
+    "GN-C" => "Конакри",
+    "GN-D" => "Киндиа",
+    "GN-DB" => "Дабола",
+    "GN-FR" => "Фрия",
+    "GN-KD" => "Киндиа",
+    "GN-N" => "Нзерекоре",
+    "GN-NZ" => "Нзерекоре",
+    "GN-PI" => "Пита",
+    "GW-BA" => "Бафата",
+    "GY-BA" => "Барима-Уайни",
+    "GY-CU" => "Куюни-Мазаруни",
+    "GY-DE" => "Демерара-Махайка",
+    "GY-EB" => "Ист-Бербис-Корентайн",
+    "GY-ES" => "Эссекибо-Айлендс-Уэст-Демерара",
+    "GY-MA" => "Махайка-Бербис",
+    "GY-PM" => "Померун-Супенаам",
+    "GY-PT" => "Потаро-Сипаруни",
+    "GY-UD" => "Аппер-Демерара-Бербис",
+    "GY-UT" => "Аппер-Такуту-Аппер-Эссекибо",
+    "HT-CE" => "Центральная",
+    "HN-CM" => "Комаягуа",
+    "HN-GD" => "Грасьяс-а-Дьос",
+    "HN-IB" => "Ислас-де-ла-Баия",
+    "HN-IN" => "Интибука",
+    "HN-LP" => "Ла-Пас",
+    "HN-OL" => "Оланчо",
+    "HN-SB" => "Санта-Барбара",
+    "HN-VA" => "Валье",
+    "HU-BC" => "Бекешчаба",
+    "HU-BK" => "Бач-Кишкун",
+    "HU-BU" => "Будапешт",
+    "HU-BZ" => "Боршод-Абауй-Земплен",
+    "HU-CS" => "Чонград",
+    "HU-DE" => "Дебрецен",
+    "HU-DU" => "Дунауйварош",
+    "HU-EG" => "Эгер",
+    "HU-ER" => "Эрд",
+    "HU-GS" => "Дьёр-Мошон-Шопрон",
+    "HU-HB" => "Хайду-Бихар",
+    "HU-HV" => "Ходмезёвашархей",
+    "HU-KE" => "Комаром-Эстергом",
+    "HU-SN" => "Шопрон",
+    "HU-SO" => "Шомодь",
+    "HU-SS" => "Сексард",
+    "HU-ST" => "Шальготарьян",
+    "HU-SZ" => "Сабольч-Сатмар-Берег",
+    "HU-TB" => "Татабанья",
+    "HU-ZE" => "Залаэгерсег",
+    "IS-7" => "Эйстюрланд",
+    "IS-8" => "Сюдюрланд",
+    "IS-AKU" => "Акюрейри",
+    "IS-ARN" => "Аурнесхреппюр",
+    "IS-ASA" => "Аусахреппюр",
+    "IS-BLA" => "Блаускоугабиггд",
+    "IS-BOG" => "Боргарбиггд",
+    "IS-BOL" => "Болунгарвик",
+    "IS-DAB" => "Далабиггд",
+    "IS-EOM" => "Эйя-ог-Миклахольтсхреппюр",
+    "IS-EYF" => "Эйяфьярдарсвейт",
+    "IS-FJD" => "Фьярдабиггд",
+    "IS-FLR" => "Фльоутсдальсхреппюр",
+    "IS-GAR" => "Гардабайр",
+    "IS-GRN" => "Гриндавик",
+    "IS-GRU" => "Грюндарфьордюр",
+    "IS-HAF" => "Хабнарфьордюр",
+    "IS-HVA" => "Хвальфьярдарсвейт",
+    "IS-HVE" => "Хверагерди",
+    "IS-ISA" => "Исафьярдарбайр",
+    "IS-KAL" => "Кальдрананесхреппюр",
+    "IS-KOP" => "Коупавогюр",
+    "IS-LAN" => "Ланганесбигд",
+    "IS-MOS" => "Мосфедльсбайр",
+    "IS-NOR" => "Нордюртинг",
+    "IS-RHH" => "Рейкхоулахреппюр",
+    "IS-RKN" => "Рейкьянесбайр",
+    "IS-RKV" => "Рейкьявик",
+    "IS-SDN" => "Сюдюрнесьябайр",
+    "IS-SEL" => "Сельтьяднарнес",
+    "IS-SKO" => "Скоррадальсхреппюр",
+    "IS-SNF" => "Снайфедльсбайр",
+    "IS-STY" => "Стиккисхоульмюр",
+    "IS-TAL" => "Таулькнафьярдархреппюр",
+    "IS-VER" => "Вестюрбиггд",
+    "IS-VOP" => "Вопнафьордюр",
+    "IN-AN" => "Андаманские и Никобарские острова",
+    "IN-AP" => "Андхра-Прадеш",
+    "IN-AR" => "Аруначал-Прадеш",
+    "IN-AS" => "Ассам",
+    "IN-BR" => "Бихар",
+    "IN-CH" => "Чандпур",
+    "IN-DL" => "Дели",
+    "IN-GA" => "Гоа",
+    "IN-GJ" => "Гуджарат",
+    "IN-HP" => "Химачал-Прадеш",
+    "IN-HR" => "Харьяна",
+    "IN-JK" => "Джамму и Кашмир",
+    "IN-KA" => "Карнатака",
+    "IN-KL" => "Керала",
+    "IN-LD" => "Лакшадвип",
+    "IN-MH" => "Махараштра",
+    "IN-ML" => "Мегхалая",
+    "IN-MN" => "Манипур",
+    "IN-MP" => "Мадхья-Прадеш",
+    "IN-MZ" => "Мизорам",
+    "IN-NL" => "Нагаленд",
+    "IN-PB" => "Пенджаб",
+    "IN-RJ" => "Раджастхан",
+    "IN-SK" => "Сикким",
+    "IN-TN" => "Тамилнад",
+    "IN-TR" => "Трипура",
+    "IN-UP" => "Уттар-Прадеш",
+    "IN-WB" => "Западная Бенгалия",
+    "ID-BB" => "Банка-Белитунг",
+    "ID-BT" => "Бантен",
+    "ID-GO" => "Горонтало",
+    "ID-LA" => "Лампунг",
+    "ID-PA" => "Папуа",
+    "ID-PP" => "Папуа",
+    "ID-RI" => "Риау",
+    "ID-SL" => "Сулавеси",
+    "ID-SM" => "Суматра",
+    "ID-YO" => "Джокьякарта",
+    "IE-C" => "Коннахт",
+    "IE-CN" => "Каван",
+    "IE-CO" => "Корк",
+    "IE-CW" => "Карлоу",
+    "IE-D" => "Дублин",
+    "IE-DL" => "Донегол",
+    "IE-G" => "Голуэй",
+    "IE-KE" => "Килдэр",
+    "IE-KK" => "Килкенни",
+    "IE-L" => "Ленстер",
+    "IE-LD" => "Лонгфорд",
+    "IE-LK" => "Лимерик",
+    "IE-LS" => "Лиишь",
+    "IE-M" => "Манстер",
+    "IE-MN" => "Монахан",
+    "IE-MO" => "Майо",
+    "IE-OY" => "Оффали",
+    "IE-RN" => "Роскоммон",
+    "IE-SO" => "Слайго",
+    "IE-TA" => "Типперери",
+    "IE-U" => "Ольстер",
+    "IE-WD" => "Уотерфорд",
+    "IE-WH" => "Уэстмит",
+    "IE-WW" => "Уиклоу",
+    "IE-WX" => "Уэксфорд",
+    "IL-HA" => "Хайфа",
+    "IL-JM" => "Иерусалим",
+    "IL-Z" => "Шарджа",
+    "IT-21" => "Пьемонт",
+    "IT-23" => "Валле-д’Аоста",
+    "IT-25" => "Ломбардия",
+    "IT-32" => "Трентино-Альто-Адидже",
+    "IT-36" => "Фриули-Венеция-Джулия",
+    "IT-42" => "Лигурия",
+    "IT-45" => "Эмилия-Романья",
+    "IT-52" => "Тоскана",
+    "IT-55" => "Умбрия",
+    "IT-57" => "Марке",
+    "IT-62" => "Лацио",
+    "IT-65" => "Абруцци",
+    "IT-67" => "Молизе",
+    "IT-72" => "Кампания",
+    "IT-75" => "Апулия",
+    "IT-77" => "Базиликата",
+    "IT-78" => "Калабрия",
+    "IT-82" => "Сицилия",
+    "IT-88" => "Сардиния",
+    "IT-AG" => "Агридженто",
+    "IT-AL" => "Алессандрия",
+    "IT-AN" => "Анкона",
+    "IT-AP" => "Асколи-Пичено",
+    "IT-AQ" => "Л’Акуила",
+    "IT-AR" => "Ареццо",
+    "IT-AT" => "Асти",
+    "IT-AV" => "Авеллино",
+    "IT-BA" => "Бари",
+    "IT-BG" => "Бергамо",
+    "IT-BI" => "Биелла",
+    "IT-BL" => "Беллуно",
+    "IT-BN" => "Беневенто",
+    "IT-BO" => "Болонья",
+    "IT-BR" => "Бриндизи",
+    "IT-BS" => "Брешиа",
+    "IT-BT" => "Барлетта-Андрия-Трани",
+    "IT-BZ" => "Больцано",
+    "IT-CA" => "Кальяри",
+    "IT-CB" => "Кампобассо",
+    "IT-CE" => "Казерта",
+    "IT-CH" => "Кьети",
+    "IT-CL" => "Кальтаниссетта",
+    "IT-CN" => "Кунео",
+    "IT-CO" => "Комо",
+    "IT-CR" => "Кремона",
+    "IT-CS" => "Козенца",
+    "IT-CT" => "Катания",
+    "IT-CZ" => "Катандзаро",
+    "IT-EN" => "Энна",
+    "IT-FC" => "Форли-Чезена",
+    "IT-FE" => "Феррара",
+    "IT-FG" => "Фоджа",
+    "IT-FI" => "Флоренция",
+    "IT-FM" => "Фермо",
+    "IT-FR" => "Фрозиноне",
+    "IT-GE" => "Генуя",
+    "IT-GO" => "Гориция",
+    "IT-GR" => "Гроссето",
+    "IT-IS" => "Изерния",
+    "IT-KR" => "Кротоне",
+    "IT-LC" => "Лекко",
+    "IT-LE" => "Лечче",
+    "IT-LI" => "Ливорно",
+    "IT-LU" => "Лукка",
+    "IT-MB" => "Монца-э-Брианца",
+    "IT-MC" => "Мачерата",
+    "IT-ME" => "Мессина",
+    "IT-MI" => "Милан",
+    "IT-MN" => "Мантуя",
+    "IT-MO" => "Модена",
+    "IT-MS" => "Масса-Каррара",
+    "IT-MT" => "Матера",
+    "IT-NA" => "Неаполь",
+    "IT-NO" => "Новара",
+    "IT-NU" => "Нуоро",
+    "IT-OR" => "Ористано",
+    "IT-PA" => "Палермо",
+    "IT-PC" => "Пьяченца",
+    "IT-PD" => "Падуя",
+    "IT-PE" => "Пескара",
+    "IT-PG" => "Перуджа",
+    "IT-PI" => "Пиза",
+    "IT-PN" => "Порденоне",
+    "IT-PO" => "Прато",
+    "IT-PR" => "Парма",
+    "IT-PT" => "Пистоя",
+    "IT-PU" => "Пезаро-э-Урбино",
+    "IT-PV" => "Павия",
+    "IT-PZ" => "Потенца",
+    "IT-RA" => "Равенна",
+    "IT-RC" => "Реджо-ди-Калабрия",
+    "IT-RE" => "Реджо-нель-Эмилия",
+    "IT-RG" => "Рагуза",
+    "IT-RI" => "Риети",
+    "IT-RN" => "Римини",
+    "IT-RO" => "Ровиго",
+    "IT-SA" => "Салерно",
+    "IT-SI" => "Сиена",
+    "IT-SO" => "Сондрио",
+    "IT-SS" => "Сассари",
+    "IT-SU" => "Южная Сардиния",
+    "IT-SV" => "Савона",
+    "IT-TA" => "Таранто",
+    "IT-TE" => "Терамо",
+    "IT-TN" => "Тренто",
+    "IT-TO" => "Турин",
+    "IT-TP" => "Трапани",
+    "IT-TR" => "Терни",
+    "IT-TS" => "Триест",
+    "IT-TV" => "Тревизо",
+    "IT-UD" => "Удине",
+    "IT-VA" => "Варесе",
+    "IT-VB" => "Вербано-Кузио-Оссола",
+    "IT-VC" => "Верчелли",
+    "IT-VE" => "Венеция",
+    "IT-VI" => "Виченца",
+    "IT-VR" => "Верона",
+    "IT-VT" => "Витербо",
+    "IT-VV" => "Вибо-Валентия",
+    "JM-01" => "Кингстон",
+    "JM-02" => "Сент Эндрю",
+    "JM-03" => "Сент Томас",
+    "JM-04" => "Портленд",
+    "JM-05" => "Сент-Мэри",
+    "JM-08" => "Сент Джеймс",
+    "JM-09" => "Ганновер",
+    "JM-10" => "Уэстморленд",
+    "JM-12" => "Манчестер",
+    "JM-13" => "Кларендон",
+    "JP-01" => "Хоккайдо",
+    "JP-02" => "Аомори",
+    "JP-03" => "Ивате",
+    "JP-04" => "Мияги",
+    "JP-05" => "Акита",
+    "JP-07" => "Фукусима",
+    "JP-09" => "Тотиги",
+    "JP-11" => "Сайтама",
+    "JP-12" => "Тиба",
+    "JP-13" => "Токио",
+    "JP-14" => "Канагава",
+    "JP-15" => "Ниигата",
+    "JP-16" => "Тояма",
+    "JP-18" => "Фукуи",
+    "JP-19" => "Яманаси",
+    "JP-22" => "Сидзуока",
+    "JP-25" => "Сига",
+    "JP-26" => "Киото",
+    "JP-27" => "Осака",
+    "JP-28" => "Хиого",
+    "JP-29" => "Нара",
+    "JP-30" => "Вакаяма",
+    "JP-31" => "Тоттори",
+    "JP-32" => "Симане",
+    "JP-33" => "Окаяма",
+    "JP-34" => "Хиросима",
+    "JP-35" => "Ямагути",
+    "JP-36" => "Токусима",
+    "JP-37" => "Кагава",
+    "JP-38" => "Эхиме",
+    "JP-40" => "Фукуока",
+    "JP-42" => "Нагасаки",
+    "JP-43" => "Кумамото",
+    "JP-44" => "Оита",
+    "JP-45" => "Миядзаки",
+    "JP-46" => "Кагосима",
+    "JP-47" => "Окинава",
+    "KP-06" => "Хванхэ-Пукто",
+    "KP-10" => "Янгандо",
+    "KP-13" => "Расон",
+    "KP-14" => "Нампхо",
+    "KR-11" => "Сеул",
+    "LA-BK" => "Бокэу",
+    "LA-BL" => "Боликхамсай",
+    "LV-002" => "Айзкраукльский край",
+    "LV-007" => "Алуксненский край",
+    "LV-011" => "Адажский край",
+    "LV-015" => "Балвский край",
+    "LV-016" => "Бауский край",
+    "LV-022" => "Цесисский край",
+    "LV-026" => "Добельский край",
+    "LV-033" => "Гулбенский край",
+    "LV-041" => "Елгавский край",
+    "LV-042" => "Екабпилсский край",
+    "LV-047" => "Краславский край",
+    "LV-050" => "Кулдигский край",
+    "LV-052" => "Кекавский край",
+    "LV-054" => "Лимбажский край",
+    "LV-056" => "Ливанский край",
+    "LV-058" => "Лудзенский край",
+    "LV-059" => "Мадонский край",
+    "LV-062" => "Марупский край",
+    "LV-067" => "Огрский край",
+    "LV-068" => "Олайнский край",
+    "LV-073" => "Прейльский край",
+    "LV-077" => "Резекненский край",
+    "LV-080" => "Ропажский край",
+    "LV-087" => "Саласпилсский край",
+    "LV-088" => "Салдусский край",
+    "LV-089" => "Саулкрастский край",
+    "LV-091" => "Сигулдский край",
+    "LV-094" => "Смилтенский край",
+    "LV-097" => "Талсинский край",
+    "LV-099" => "Тукумский край",
+    "LV-101" => "Валкский край",
+    "LV-102" => "Вараклянский край",
+    "LV-106" => "Вентспилсский край",
+    "LV-DGV" => "Даугавпилс",
+    "LV-JEL" => "Елгава",
+    "LV-JUR" => "Юрмала",
+    "LV-LPX" => "Лиепая",
+    "LV-REZ" => "Резекне",
+    "LV-RIX" => "Рига",
+    "LV-VEN" => "Вентспилс",
+    "LS-D" => "Берея",
+    "LS-E" => "Мафетенг",
+    "LS-F" => "Мохалес-Хук",
+    "LS-G" => "Цгутхинг",
+    "LS-H" => "Цгачас-Нек",
+    "LS-J" => "Мокхотлонг",
+    "LS-K" => "Тхаба-Цека",
+    "LR-CM" => "Гранд-Кейп-Маунт",
+    "LR-GB" => "Гранд-Басса",
+    "LR-GG" => "Гранд-Джиде",
+    "LR-GK" => "Гранд-Кру",
+    "LY-BA" => "Бенгази",
+    "LY-BU" => "Эль-Бутнан",
+    "LY-JA" => "Эль-Джебель-эль-Ахдар",
+    "LY-KF" => "Эль-Куфра",
+    "LY-MI" => "Мисурата",
+    "LY-MQ" => "Марзук",
+    "LY-NL" => "Налут",
+    "LY-TB" => "Триполи",
+    "LY-WA" => "Эль-Вахат",
+    "LY-WD" => "Вади-эль-Хаят",
+    "LT-01" => "Акмяне",
+    "LT-33" => "Паневежис",
+    "LT-34" => "Пасвалис",
+    "LT-35" => "Плунге",
+    "LT-36" => "Пренай",
+    "LT-37" => "Радвилишкис",
+    "LT-38" => "Расейняй",
+    "LT-40" => "Рокишкис",
+    "LT-41" => "Шакяй",
+    "LT-42" => "Шальчининкай",
+    "LT-44" => "Шяуляй",
+    "LT-45" => "Шилале",
+    "LT-46" => "Шилуте",
+    "LT-47" => "Ширвинтос",
+    "LT-48" => "Скуодас",
+    "LT-49" => "Швенчёнис",
+    "LT-50" => "Таураге",
+    "LT-51" => "Тельшяй",
+    "LT-52" => "Тракай",
+    "LT-53" => "Укмерге",
+    "LT-54" => "Утена",
+    "LT-55" => "Варена",
+    "LT-56" => "Вилкавишкис",
+    "LT-58" => "Вильнюс",
+    "LT-59" => "Висагинас",
+    "LT-60" => "Зарасай",
+    "LU-LU" => "Люксембург",
+    "MT-47" => "Сафи",
+    "MT-49" => "Сент-Джон",
+    "MR-07" => "Адрар",
+    "MC-MC" => "Монте-Карло",
+    "MC-MG" => "Монегетти",
+    "MC-MO" => "Монако",
+    "MC-MU" => "Мулен",
+    "MA-BRR" => "Беррешид",
+    "MA-CAS" => "Касабланка",
+    "MA-CHE" => "Шавен",
+    "MA-ERR" => "Эр-Рашидия",
+    "MA-ESI" => "Эс-Сувейра",
+    "MA-FES" => "Фес",
+    "MA-FQH" => "Фких-Бен-Салах",
+    "MA-GUE" => "Гулимин",
+    "MA-GUF" => "Герсиф",
+    "MA-HAO" => "Эль-Хауз",
+    "MA-HOC" => "Эль-Хосейма",
+    "MA-KEN" => "Кенитра",
+    "MA-KHE" => "Хемиссет",
+    "MA-KHN" => "Хенифра",
+    "MA-KHO" => "Хурибга",
+    "MA-LAR" => "Лараш",
+    "MA-MAR" => "Марракеш",
+    "MA-MEK" => "Мекнес",
+    "MA-MID" => "Мидельт",
+    "MA-NAD" => "Надор",
+    "MA-RAB" => "Рабат",
+    "MA-SAF" => "Сафи",
+    "MA-SAL" => "Сале",
+    "MA-SEF" => "Сефру",
+    "MA-SET" => "Сеттат",
+    "MA-SIB" => "Сиди-Беннур",
+    "MA-SIF" => "Сиди-Ифни",
+    "MA-SIK" => "Сиди-Касем",
+    "MA-TAR" => "Тарудант",
+    "MA-TAZ" => "Таза",
+    "MA-TET" => "Тетуан",
+    "MA-TIN" => "Тингир",
+    "MA-TIZ" => "Тизнит",
+    "NL-BQ1" => "Бонайре",
+    "NL-BQ2" => "Саба",
+    "NL-BQ3" => "Синт-Эстатиус",
+    "NL-LI" => "Лимбург",
+    "NZ-CAN" => "Кентербери",
+    "NI-GR" => "Гранада",
+    "NI-LE" => "Леон",
+    "NG-PL" => "Плато",
+    "OM-ZA" => "Эз-Захира",
+    "PK-PB" => "Пенджаб",
+    "PS-BTH" => "Вифлеем",
+    "PS-HBN" => "Хеврон",
+    "PS-JEM" => "Иерусалим",
+    "PS-JEN" => "Дженин",
+    "PS-RBH" => "Рамалла",
+    "PA-1" => "Бокас-дель-Торо",
+    "PA-4" => "Чирики",
+    "PA-5" => "Дарьен",
+    "PA-6" => "Эррера",
+    "PA-7" => "Лос-Сантос",
+    "PA-8" => "Панама",
+    "PA-9" => "Верагуас",
+    "PA-KY" => "Гуна-Яла",
+    "PA-NB" => "Нгобе-Бугле",
+    "PY-1" => "Консепсьон",
+    "PY-10" => "Альто-Парана",
+    "PY-12" => "Ньеэмбуку",
+    "PY-13" => "Амамбай",
+    "PY-14" => "Канендию",
+    "PY-15" => "Пресиденте-Аес",
+    "PY-16" => "Альто-Парагвай",
+    "PY-2" => "Сан-Педро",
+    "PY-7" => "Итапуа",
+    "PY-8" => "Мисьонес",
+    "PY-9" => "Парагуари",
+    "PY-ASU" => "Асунсьон",
+    "PE-ARE" => "Арекипа",
+    "PE-AYA" => "Аякучо",
+    "PE-CAJ" => "Кахамарка",
+    "PE-LAL" => "Ла-Либертад",
+    "PE-LAM" => "Ламбаеке",
+    "PE-LIM" => "Лима",
+    "PE-LOR" => "Лорето",
+    "PE-MDD" => "Мадре-де-Дьос",
+    "PE-PAS" => "Паско",
+    "PE-PIU" => "Пьюра",
+    "PE-PUN" => "Пуно",
+    "PE-TAC" => "Такна",
+    "PE-UCA" => "Укаяли",
+    "PL-02" => "Нижнесилезское воеводство",
+    "PL-04" => "Куявско-Поморское воеводство",
+    "PL-06" => "Люблинское воеводство",
+    "PL-08" => "Любушское воеводство",
+    "PL-10" => "Лодзинское воеводство",
+    "PL-12" => "Малопольское воеводство",
+    "PL-14" => "Мазовецкое воеводство",
+    "PL-16" => "Опольское воеводство",
+    "PL-18" => "Подкарпатское воеводство",
+    "PL-20" => "Подляское воеводство",
+    "PL-22" => "Поморское воеводство",
+    "PL-24" => "Силезское воеводство",
+    "PL-26" => "Свентокшиское воеводство",
+    "PL-28" => "Варминьско-Мазурское воеводство",
+    "PL-30" => "Великопольское воеводство",
+    "PL-32" => "Западно-Поморское воеводство",
+    "PT-11" => "Лиссабон",
+    "PT-13" => "Порту",
+    "PT-14" => "Сантарен",
+    "PT-15" => "Сетубал",
+    "PT-16" => "Виана-ду-Каштелу",
+    "PT-17" => "Вила-Реал",
+    "RU-AD" => "Республика Адыгея",
+    "RU-AL" => "Республика Алтай",
+    "RU-ALT" => "Алтайский край",
+    "RU-AMU" => "Амурская область",
+    "RU-ARK" => "Архангельская область",
+    "RU-AST" => "Астраханская область",
+    "RU-BA" => "Республика Башкортостан",
+    "RU-BEL" => "Белгородская область",
+    "RU-BRY" => "Брянская область",
+    "RU-BU" => "Республика Бурятия",
+    "RU-CE" => "Чеченская республика",
+    "RU-CHE" => "Челябинская область",
+    "RU-CHU" => "Чукотский автономный округ",
+    "RU-CU" => "Чувашская республика",
+    "RU-DA" => "Республика Дагестан",
+    "RU-IN" => "Республика Ингушетия",
+    "RU-IRK" => "Иркутская область",
+    "RU-IVA" => "Ивановская область",
+    "RU-KAM" => "Камчатский край",
+    "RU-KB" => "Кабардино-Балкарская республика",
+    "RU-KC" => "Карачаево-Черкесская республика",
+    "RU-KDA" => "Краснодарский край",
+    "RU-KEM" => "Кемеровская область",
+    "RU-KGD" => "Калининградская область",
+    "RU-KGN" => "Курганская область",
+    "RU-KHA" => "Хабаровский край",
+    "RU-KHM" => "Ханты-Мансийский автономный округ",
+    "RU-KIR" => "Кировская область",
+    "RU-KK" => "Республика Хакассия",
+    "RU-KL" => "Республика Калмыкия",
+    "RU-KLU" => "Калужская область",
+    "RU-KO" => "Республика Коми",
+    "RU-KOS" => "Костромская область",
+    "RU-KR" => "Республика Карелия",
+    "RU-KRS" => "Курская область",
+    "RU-KYA" => "Красноярский край",
+    "RU-LEN" => "Ленинградская область",
+    "RU-LIP" => "Липецкая область",
+    "RU-MAG" => "Магаданская область",
+    "RU-ME" => "Республика Марий Эл",
+    "RU-MO" => "Республика Мордовия",
+    "RU-MOS" => "Московская область",
+    "RU-MOW" => "Москва",
+    "RU-MUR" => "Мурманская область",
+    "RU-NEN" => "Ненецкий автономный округ",
+    "RU-NGR" => "Новгородская область",
+    "RU-NIZ" => "Нижегородская область",
+    "RU-NVS" => "Новосибирская область",
+    "RU-OMS" => "Омская область",
+    "RU-ORE" => "Оренбургская область",
+    "RU-ORL" => "Орловская область",
+    "RU-PER" => "Пермский край",
+    "RU-PNZ" => "Пензенская область",
+    "RU-PRI" => "Приморский край",
+    "RU-PSK" => "Псковская область",
+    "RU-ROS" => "Ростовская область",
+    "RU-RYA" => "Рязанская область",
+    "RU-SA" => "Республика Саха",
+    "RU-SAK" => "Сахалинская область",
+    "RU-SAM" => "Самарская область",
+    "RU-SAR" => "Саратовская область",
+    "RU-SE" => "Республика Северная Осетия",
+    "RU-SMO" => "Смоленская область",
+    "RU-SPE" => "Санкт-Петербург",
+    "RU-STA" => "Ставропольский край",
+    "RU-SVE" => "Свердловская область",
+    "RU-TA" => "Республика Татарстан",
+    "RU-TAM" => "Тамбовская область",
+    "RU-TOM" => "Томская область",
+    "RU-TUL" => "Тульская область",
+    "RU-TVE" => "Тверская область",
+    "RU-TY" => "Республика Тува",
+    "RU-TYU" => "Тюменская область",
+    "RU-UD" => "Республика Удмуртия",
+    "RU-ULY" => "Ульяновская область",
+    "RU-VGG" => "Волгоградская область",
+    "RU-VLA" => "Владимирская область",
+    "RU-VLG" => "Вологодская область",
+    "RU-VOR" => "Воронежская область",
+    "RU-YAN" => "Ямало-Ненецкий автономный округ",
+    "RU-YAR" => "Ярославская область",
+    "RU-YEV" => "Еврейская автономная область",
+    "RU-ZAB" => "Забайкальский край",
+    "KN-01" => "Крайст-Черч-Никола-Таун",
+    "KN-02" => "Сент-Энн-Сэнди-Пойнт",
+    "KN-03" => "Сент-Джордж-Бастер",
+    "KN-04" => "Сент-Джордж-Джинджерланд",
+    "KN-05" => "Сент-Джеймс-Уиндуорд",
+    "KN-06" => "Сент-Джон-Капистер",
+    "KN-07" => "Сент-Джон-Фигтри",
+    "KN-08" => "Сент-Мери-Кайон",
+    "KN-10" => "Сент-Пол-Чарлстаун",
+    "KN-11" => "Сент-Питер-Бастер",
+    "KN-12" => "Сент-Томас-Лоуленд",
+    "KN-13" => "Сент-Томас-Мидл-Айленд",
+    "KN-15" => "Тринити-Палметто-Пойнт",
+    "KN-K" => "Сент-Китс",
+    "KN-N" => "Невис",
+    "VC-02" => "Сент Эндрю",
+    "VC-04" => "Сент-Джордж",
+    "SA-01" => "Эр-Рияд",
+    "SA-04" => "Эш-Шаркия",
+    "SA-05" => "Эль-Касим",
+    "SA-08" => "Эль-Худуд-эш-Шамалия",
+    "SA-10" => "Наджран",
+    "RS-00" => "Белград",
+    "RS-01" => "Северно-Бачский округ",
+    "RS-02" => "Средне-Банатский округ",
+    "RS-03" => "Северно-Банатский округ",
+    "RS-04" => "Южно-Банатский округ",
+    "RS-05" => "Западно-Бачский округ",
+    "RS-06" => "Южно-Бачский округ",
+    "RS-07" => "Сремский округ",
+    "RS-08" => "Мачванский округ",
+    "RS-09" => "Колубарский округ",
+    "RS-10" => "Подунайский округ",
+    "RS-11" => "Браничевский округ",
+    "RS-12" => "Шумадийский округ",
+    "RS-13" => "Поморавский округ",
+    "RS-14" => "Борский округ",
+    "RS-15" => "Заечарский округ",
+    "RS-16" => "Златиборский округ",
+    "RS-17" => "Моравичский округ",
+    "RS-18" => "Рашский округ",
+    "RS-19" => "Расинский округ",
+    "RS-20" => "Нишавский округ",
+    "RS-21" => "Топличский округ",
+    "RS-22" => "Пиротский округ",
+    "RS-23" => "Ябланичский округ",
+    "RS-24" => "Пчиньский округ",
+    "RS-25" => "Косовский округ",
+    "RS-26" => "Печский округ",
+    "RS-27" => "Призренский округ",
+    "RS-VO" => "Воеводина",
+    "SB-GU" => "Гуадалканал",
+    "SB-IS" => "Изабелла",
+    "SB-MK" => "Макира-Улава",
+    "SB-RB" => "Реннелл и Беллона",
+    "SO-BR" => "Бари",
+    "SS-BN" => "Северный Бахр-эль-Газаль",
+    "SS-BW" => "Западный Бахр-эль-Газаль",
+    "ES-AB" => "Альбасете",
+    "ES-AL" => "Альмерия",
+    "ES-AN" => "Андалусия",
+    "ES-AR" => "Арагон",
+    "ES-BA" => "Бадахос",
+    "ES-BI" => "Бискайя",
+    "ES-BU" => "Бургос",
+    "ES-CA" => "Кадис",
+    "ES-CB" => "Кантабрия",
+    "ES-CC" => "Касерес",
+    "ES-CE" => "Сеута",
+    "ES-CL" => "Кастилия-Леон",
+    "ES-CM" => "Кастилия-Ла-Манча",
+    "ES-CN" => "Канариас",
+    "ES-CO" => "Кордова",
+    "ES-CR" => "Сьюдад-Реаль",
+    "ES-CU" => "Куэнка",
+    "ES-EX" => "Эстремадура",
+    "ES-GC" => "Лас-Пальмас-де-Гран-Канария",
+    "ES-GR" => "Гранада",
+    "ES-GU" => "Гвадалахара",
+    "ES-H" => "Уэльва",
+    "ES-HU" => "Уэска",
+    "ES-LE" => "Леон",
+    "ES-LO" => "Риоха",
+    "ES-M" => "Мадрид",
+    "ES-MA" => "Малага",
+    "ES-ML" => "Мелилья",
+    "ES-MU" => "Мурсия",
+    "ES-O" => "Астурия",
+    "ES-P" => "Паленсия",
+    "ES-PV" => "Баскские земли",
+    "ES-RI" => "Риоха",
+    "ES-S" => "Кантабрия",
+    "ES-SA" => "Саламанка",
+    "ES-SE" => "Севилья",
+    "ES-SG" => "Сеговия",
+    "ES-SO" => "Сория",
+    "ES-SS" => "Гипускоа",
+    "ES-TE" => "Теруэль",
+    "ES-TF" => "Санта-Крус-де-Тенерифе",
+    "ES-TO" => "Толедо",
+    "ES-V" => "Валенсия",
+    "ES-VA" => "Вальядолид",
+    "ES-Z" => "Сарагоса",
+    "ES-ZA" => "Самора",
+    "LK-11" => "Коломбо",
+    "CH-AG" => "Аргау",
+    "CH-AI" => "Аппенцелль-Иннерроден",
+    "CH-AR" => "Аппенцелль-Аусерроден",
+    "CH-BE" => "Берн",
+    "CH-BL" => "Базель-Ланд",
+    "CH-BS" => "Базель-Штадт",
+    "CH-FR" => "Лимбург",
+    "CH-GE" => "Женева",
+    "CH-GL" => "Гларус",
+    "CH-GR" => "Граубюнден",
+    "CH-JU" => "Юра",
+    "CH-LU" => "Люцерн",
+    "CH-NE" => "Невшатель",
+    "CH-NW" => "Нидвальден",
+    "CH-OW" => "Обвальден",
+    "CH-SH" => "Шаффхаузен",
+    "CH-SO" => "Золотурн",
+    "CH-TG" => "Тургау",
+    "CH-TI" => "Тичино",
+    "CH-ZG" => "Цуг",
+    "CH-ZH" => "Цюрих",
+    "TW-TNN" => "Тайнань",
+    "TW-TPE" => "Тайбэй",
+    "TW-TXG" => "Тайчжун",
+    "UA-05" => "Винницкая область",
+    "UA-07" => "Волынская область",
+    "UA-09" => "Луганская область",
+    "UA-12" => "Днепропетровская область",
+    "UA-14" => "Донецкая область",
+    "UA-18" => "Житомирская область",
+    "UA-21" => "Закарпатская область",
+    "UA-23" => "Запорожская область",
+    "UA-26" => "Ивано-Франковская область",
+    "UA-30" => "Киев",
+    "UA-32" => "Киевская область",
+    "UA-35" => "Кировоградская область",
+    "UA-40" => "Севастополь",
+    "UA-43" => "Автономная Республика Крым",
+    "UA-46" => "Львовская область",
+    "UA-51" => "Одесская область",
+    "UA-53" => "Полтавская область",
+    "UA-56" => "Ровненская область",
+    "UA-59" => "Сумская область",
+    "UA-61" => "Тернопольская область",
+    "UA-63" => "Харьковская область",
+    "UA-65" => "Херсонская область",
+    "UA-68" => "Хмельницкая область",
+    "UA-71" => "Черкасская область",
+    "UA-74" => "Черниговская область",
+    "UA-77" => "Черновицкая область",
+    "AE-AJ" => "Аджман",
+    "AE-AZ" => "Абу-Даби",
+    "AE-DU" => "Дубай",
+    "AE-FU" => "Эль-Фуджайра",
+    "AE-RK" => "Рас эль-Хайма",
+    "AE-SH" => "Шарджа",
+    "AE-UQ" => "Умм эль-Кайвайн",
+    "GB-ABD" => "Абердиншир",
+    "GB-AGB" => "Аргайл-энд-Бьют",
+    "GB-BAS" => "Бат и Северо-Восточный Сомерсет",
+    "GB-BBD" => "Блэкберн-уит-Даруэн",
+    "GB-BDF" => "Бедфорд",
+    "GB-BDG" => "Баркинг и Дагенем",
+    "GB-BEN" => "Брент",
+    "GB-BFS" => "Белфаст",
+    "GB-BGW" => "Блайнай-Гуэнт",
+    "GB-BIR" => "Бирмингем",
+    "GB-BKM" => "Бакингемшир",
+    "GB-BNE" => "Барнет",
+    "GB-BNH" => "Брайтон-энд-Хов",
+    "GB-BNS" => "Барнсли",
+    "GB-BOL" => "Болтон",
+    "GB-BPL" => "Блэкпул",
+    "GB-BRC" => "Бракнелл-Форест",
+    "GB-BRD" => "Брадфорд",
+    "GB-BRY" => "Бромли",
+    "GB-BUR" => "Бэри",
+    "GB-CAM" => "Кембриджшир",
+    "GB-CBF" => "Центральный Бедфордшир",
+    "GB-CHE" => "Восточный Чешир",
+    "GB-CHW" => "Западный Чешир и Честер",
+    "GB-CLD" => "Колдердейл",
+    "GB-CLK" => "Клакманнаншир",
+    "GB-CMD" => "Камден",
+    "GB-CON" => "Корнуолл",
+    "GB-COV" => "Ковентри",
+    "GB-DAL" => "Дарлингтон",
+    "GB-DBY" => "Дербишир",
+    "GB-DGY" => "Дамфрис-энд-Галловей",
+    "GB-DNC" => "Донкастер",
+    "GB-DOR" => "Дорсет",
+    "GB-EAY" => "Ист-Эршир",
+    "GB-EDU" => "Ист-Данбартоншир",
+    "GB-ELN" => "Ист-Лотиан",
+    "GB-ENF" => "Энфилд",
+    "GB-ERW" => "Ист-Ренфрушир",
+    "GB-ERY" => "Ист-Райдинг-оф-Йоркшир",
+    "GB-ESS" => "Эссекс",
+    "GB-ESX" => "Восточный Суссекс",
+    "GB-FAL" => "Фолкерк",
+    "GB-FIF" => "Файф",
+    "GB-GAT" => "Гейтсхед",
+    "GB-GLS" => "Глостершир",
+    "GB-GWN" => "Гуинет",
+    "GB-HAM" => "Гэмпшир",
+    "GB-HAV" => "Хаверинг",
+    "GB-HEF" => "Херефордшир",
+    "GB-HMF" => "Хаммерсмит и Фулем",
+    "GB-HPL" => "Хартлпул",
+    "GB-HRT" => "Хартфордшир",
+    "GB-HRW" => "Harrow",
+    "GB-HRY" => "Харинги",
+    "GB-IOS" => "Силли",
+    "GB-ISL" => "Ислингтон",
+    "GB-IVC" => "Инверклайд",
+    "GB-KEN" => "Кент",
+    "GB-KHL" => "Кингстон-апон-Халл",
+    "GB-KIR" => "Керклис",
+    "GB-KTT" => "Кингстон-апон-Темс",
+    "GB-LAN" => "Ланкашир",
+    "GB-LCE" => "Лестер",
+    "GB-LDS" => "Лидс",
+    "GB-LEC" => "Лестершир",
+    "GB-LIN" => "Линкольншир",
+    "GB-LIV" => "Ливерпуль",
+    "GB-LUT" => "Лутон",
+    "GB-MAN" => "Манчестер",
+    "GB-MDB" => "Мидлсбро",
+    "GB-MIK" => "Милтон-Кинс",
+    "GB-MLN" => "Мидлотиан",
+    "GB-MRT" => "Мертон",
+    "GB-NAY" => "Норт-Эршир",
+    "GB-NBL" => "Нортамберленд",
+    "GB-NEL" => "Северо-Восточный Линкольншир",
+    "GB-NET" => "Ньюкасл-апон-Тайн",
+    "GB-NFK" => "Норфолк",
+    "GB-NGM" => "Ноттингем",
+    "GB-NLK" => "Норт-Ланаркшир",
+    "GB-NLN" => "Северный Линкольншир",
+    "GB-NSM" => "Северный Сомерсет",
+    "GB-NTT" => "Ноттингемшир",
+    "GB-NTY" => "Норт-Тайнсайд",
+    "GB-NWM" => "Ньюэм",
+    "GB-NYK" => "Норт-Йоркшир",
+    "GB-OLD" => "Олдем",
+    "GB-ORK" => "Оркнейские острова",
+    "GB-OXF" => "Оксфордшир",
+    "GB-PKN" => "Перт-энд-Кинросс",
+    "GB-POR" => "Портсмут",
+    "GB-POW" => "Поуис",
+    "GB-RCC" => "Редкар-энд-Кливленд",
+    "GB-RCH" => "Рочдейл",
+    "GB-RFW" => "Ренфрушир",
+    "GB-RIC" => "Ричмонд-апон-Темс",
+    "GB-ROT" => "Ротерем",
+    "GB-SAY" => "Саут-Эршир",
+    "GB-SCB" => "Скоттиш-Бордерс",
+    "GB-SFK" => "Суффолк",
+    "GB-SGC" => "Южный Глостершир",
+    "GB-SHF" => "Шеффилд",
+    "GB-SHR" => "Шропшир",
+    "GB-SKP" => "Стокпорт",
+    "GB-SLF" => "Солфорд",
+    "GB-SLG" => "Слау",
+    "GB-SLK" => "Саут-Ланаркшир",
+    "GB-SND" => "Сандерленд",
+    "GB-SOL" => "Солихалл",
+    "GB-SOM" => "Сомерсет",
+    "GB-SOS" => "Саутенд-он-Си",
+    "GB-SRY" => "Суррей",
+    "GB-STE" => "Сток-он-Трент",
+    "GB-STH" => "Саутгемптон",
+    "GB-STN" => "Саттон",
+    "GB-STS" => "Стаффордшир",
+    "GB-STT" => "Стоктон-он-Тис",
+    "GB-STY" => "Саут-Тайнсайд",
+    "GB-SWD" => "Суиндон",
+    "GB-TAM" => "Теймсайд",
+    "GB-TFW" => "Телфорд-энд-Рикин",
+    "GB-THR" => "Таррок",
+    "GB-TOB" => "Торбей",
+    "GB-TRF" => "Траффорд",
+    "GB-TWH" => "Тауэр-Хамлетс",
+    "GB-WAR" => "Уорикшир",
+    "GB-WFT" => "Уолтем-Форест",
+    "GB-WGN" => "Уиган",
+    "GB-WIL" => "Уилтшир",
+    "GB-WLL" => "Уолсолл",
+    "GB-WLN" => "Уэст-Лотиан",
+    "GB-WLV" => "Вулвергемптон",
+    "GB-WND" => "Уондсуэрт",
+    "GB-WNM" => "Виндзор и Мейденхед",
+    "GB-WOR" => "Вустершир",
+    "GB-WRT" => "Уоррингтон",
+    "GB-WSM" => "Вестминстер",
+    "GB-WSX" => "Западный Суссекс",
+    "GB-YOR" => "Йорк",
+    "GB-ZET" => "Шетландские острова",
+    "US-AK" => "Аляска",
+    "US-AL" => "Алабама",
+    "US-AR" => "Арканзас",
+    "US-AS" => "Американское Самоа",
+    "US-AZ" => "Аризона",
+    "US-CA" => "Калифорния",
+    "US-CO" => "Колорадо",
+    "US-CT" => "Коннектикут",
+    "US-DC" => "Вашингтон",
+    "US-DE" => "Делавэр",
+    "US-FL" => "Флорида",
+    "US-GA" => "Джорджия",
+    "US-GU" => "Гуам",
+    "US-HI" => "Гавайи",
+    "US-IA" => "Айова",
+    "US-ID" => "Айдахо",
+    "US-IL" => "Иллинойс",
+    "US-IN" => "Индиана",
+    "US-KS" => "Канзас",
+    "US-KY" => "Кентукки",
+    "US-LA" => "Луизиана",
+    "US-MA" => "Массачусетс",
+    "US-ME" => "Мэн",
+    "US-MI" => "Мичиган",
+    "US-MN" => "Миннесота",
+    "US-MO" => "Миссури",
+    "US-MP" => "Северные Марианские Острова",
+    "US-MS" => "Миссисипи",
+    "US-MT" => "Монтана",
+    "US-NC" => "Северная Каролина",
+    "US-ND" => "Северная Дакота",
+    "US-NE" => "Небраска",
+    "US-NH" => "Нью-Гэмпшир",
+    "US-NJ" => "Нью-Джерси",
+    "US-NM" => "Нью-Мексико",
+    "US-NV" => "Невада",
+    "US-NY" => "Нью-Йорк",
+    "US-OH" => "Огайо",
+    "US-OK" => "Оклахома",
+    "US-OR" => "Орегон",
+    "US-PA" => "Пенсильвания",
+    "US-PR" => "Пуэрто-Рико",
+    "US-RI" => "Род-Айленд",
+    "US-SC" => "Южная Каролина",
+    "US-SD" => "Южная Дакота",
+    "US-TN" => "Теннесси",
+    "US-TX" => "Техас",
+    "US-UM" => "Внешние малые острова США",
+    "US-UT" => "Юта",
+    "US-VA" => "Вирджиния",
+    "US-VI" => "Виргинские Острова",
+    "US-VT" => "Вермонт",
+    "US-WA" => "Вашингтон",
+    "US-WI" => "Висконсин",
+    "US-WY" => "Вайоминг",
+    "UY-FD" => "Флорида",
+    "UY-RN" => "Рио-Негро",
+    "UZ-QR" => "Каракалпакстан",
+    "UZ-SA" => "Самарканд",
+    "UZ-TK" => "Ташкент",
+    "UZ-TO" => "Ташкент",
+    "UZ-XO" => "Хорезм",
+    "VN-DN" => "Дананг",
+    "VN-HN" => "Ханой",
+    "ZW-MS" => "Южный Матабелеленд",
+    "ZW-MV" => "Масвинго",
+    "ZW-MW" => "Западный Машоналенд",
+};
+
+///Russian former country names by alpha-4 code
+pub const FORMER_NAME_MAP: Map<&str, &str> = phf_map! {
+    "BQAQ" => "Британская Антарктическая Территория",
+    "BUMM" => "Социалистическая Республика Бирманский Союз",
+    "BYAA" => "Белорусская Советская Социалистическая Республика",
+    "CTKI" => "Острова Кантон и Эндербери",
+    "CSHH" => "Чехословацкая Социалистическая Республика",
+    "DYBJ" => "Дагомея",
+    "NQAQ" => "Земля Королевы Мод",
+    "TPTL" => "Восточный Тимор",
+    "FXFR" => "Метрополия Франции",
+    "AIDJ" => "Французская территория афаров и исса",
+    "FQHH" => "Французские Южные и Антарктические Территории",
+    "DDDE" => "Германская Демократическая Республика",
+    "GEHH" => "Острова Гилберта и Эллис",
+    "JTUM" => "Остров Джонстон",
+    "MIUM" => "Острова Мидуэй",
+    "ANHH" => "Нидерландские антильские острова",
+    "NTHH" => "Нейтральная зона",
+    "NHVU" => "Новые Гебриды",
+    "PCHH" => "Подопечная территория Тихоокеанские острова",
+    "PZPA" => "Зона Панамского канала",
+    "CSXX" => "Сербия и Черногория",
+    "SKIN" => "Сикким",
+    "RHZW" => "Южная Родезия",
+    "PUUM" => "Американские тихоокеанские острова",
+    "HVBF" => "Республика Верхняя Вольта",
+    "SUHH" => "Союз Советских Социалистических Республик",
+    "VDVN" => "Демократическая Республика Вьетнам",
+    "WKUM" => "Атолл Уэйк",
+    "YDYE" => "Народная Демократическая Республика Йемен",
+    "ZRCD" => "Республика Заир",
+};
@@ -0,0 +1,4159 @@
+use phf::phf_map;
+use phf::Map;
+
+///Swedish country names by alpha-2 code
+pub const COUNTRY_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF" => "Afghanistan",
+    "AX" => "Åland",
+    "AL" => "Albanien",
+    "DZ" => "Algeriet",
+    "AS" => "Amerikanska Samoa",
+    "AD" => "Andorra",
+    "AO" => "Angola",
+    "AI" => "Anguilla",
+    "AQ" => "Antarktis",
+    "AG" => "Antigua och Barbuda",
+    "AR" => "Argentina",
+    "AM" => "Armenien",
+    "AW" => "Aruba",
+    "AU" => "Australien",
+    "AT" => "Österrike",
+    "AZ" => "Azerbajdzjan",
+    "BS" => "Bahamas",
+    "BH" => "Bahrain",
+    "BD" => "Bangladesh",
+    "BB" => "Barbados",
+    "BY" => "Vitryssland",
+    "BE" => "Belgien",
+    "BZ" => "Belize",
+    "BJ" => "Benin",
+    "BM" => "Bermuda",
+    "BT" => "Bhutan",
+    "BO" => "Bolivia, Mångnationella staten",
+    "BQ" => "Bonaire, Sint Eustatius och Saba",
+    "BA" => "Bosnien-Hercegovina",
+    "BW" => "Botswana",
+    "BV" => "Bouvetön",
+    "BR" => "Brasilien",
+    "IO" => "Brittiskt territorium i Indiska Oceanen",
+    "BN" => "Brunei",
+    "BG" => "Bulgarien",
+    "BF" => "Burkina Faso",
+    "BI" => "Burundi",
+    "CV" => "Kap Verde",
+    "KH" => "Kambodja",
+    "CM" => "Kamerun",
+    "CA" => "Kanada",
+    "KY" => "Caymanöarna",
+    "CF" => "Centralafrikanska republiken",
+    "TD" => "Tchad",
+    "CL" => "Chile",
+    "CN" => "Kina",
+    "CX" => "Julön",
+    "CC" => "Kokosöarna",
+    "CO" => "Colombia",
+    "KM" => "Comorerna",
+    "CG" => "Kongo",
+    "CD" => "Kongo, demokratiska republiken",
+    "CK" => "Cooköarna",
+    "CR" => "Costa Rica",
+    "CI" => "Elfenbenskusten",
+    "HR" => "Kroatien",
+    "CU" => "Kuba",
+    "CW" => "Curaçao",
+    "CY" => "Cypern",
+    "CZ" => "Tjeckien",
+    "DK" => "Danmark",
+    "DJ" => "Djibouti",
+    "DM" => "Dominica",
+    "DO" => "Dominikanska republiken",
+    "EC" => "Ecuador",
+    "EG" => "Egypten",
+    "SV" => "El Salvador",
+    "GQ" => "Ekvatorialguinea",
+    "ER" => "Eritrea",
+    "EE" => "Estland",
+    "SZ" => "Swaziland",
+    "ET" => "Etiopien",
+    "FK" => "Falklandsöarna (Malvinas)",
+    "FO" => "Färöarna",
+    "FJ" => "Fiji",
+    "FI" => "Finland",
+    "FR" => "Frankrike",
+    "GF" => "Franska Guyana",
+    "PF" => "Franska Polynesien",
+    "TF" => "Franska sydterritorierna",
+    "GA" => "Gabon",
+    "GM" => "Gambia",
+    "GE" => "Georgien",
+    "DE" => "Tyskland",
+    "GH" => "Ghana",
+    "GI" => "Gibraltar",
+    "GR" => "Grekland",
+    "GL" => "Grönland",
+    "GD" => "Grenada",
+    "GP" => "Guadeloupe",
+    "GU" => "Guam",
+    "GT" => "Guatemala",
+    "GG" => "Guernsey",
+    "GN" => "Guinea",
+    "GW" => "Guinea-Bissau",
+    "GY" => "Guyana",
+    "HT" => "Haiti",
+    "HM" => "Heardön och McDonaldöarna",
+    "VA" => "Vatikanstaten",
+    "HN" => "Honduras",
+    "HK" => "Hongkong",
+    "HU" => "Ungern",
+    "IS" => "Island",
+    "IN" => "Indien",
+    "ID" => "Indonesien",
+    "IR" => "Iran, islamiska republiken",
+    "IQ" => "Irak",
+    "IE" => "Irland",
+    "IM" => "Isle of Man",
+    "IL" => "Israel",
+    "IT" => "Italien",
+    "JM" => "Jamaica",
+    "JP" => "Japan",
+    "JE" => "Jersey",
+    "JO" => "Jordanien",
+    "KZ" => "Kazakstan",
+    "KE" => "Kenya",
+    "KI" => "Kiribati",
+    "KP" => "Korea, demokratiska folkrepubliken",
+    "KR" => "Sydkorea",
+    "KW" => "Kuwait",
+    "KG" => "Kirgizistan",
+    "LA" => "Demokratiska folkrepubliken Lao",
+    "LV" => "Lettland",
+    "LB" => "Libanon",
+    "LS" => "Lesotho",
+    "LR" => "Liberia",
+    "LY" => "Libyen",
+    "LI" => "Liechtenstein",
+    "LT" => "Litauen",
+    "LU" => "Luxemburg",
+    "MO" => "Macao",
+    "MG" => "Madagaskar",
+    "MW" => "Malawi",
+    "MY" => "Malaysia",
+    "MV" => "Maldiverna",
+    "ML" => "Mali",
+    "MT" => "Malta",
+    "MH" => "Marshallöarna",
+    "MQ" => "Martinique",
+    "MR" => "Mauretanien",
+    "MU" => "Mauritius",
+    "YT" => "Mayotte",
+    "MX" => "Mexiko",
+    "FM" => "Mikronesien, federala staterna",
+    "MD" => "Moldavien, republiken",
+    "MC" => "Monaco",
+    "MN" => "Mongoliet",
+    "ME" => "Montenegro",
+    "MS" => "Montserrat",
+    "MA" => "Marocko",
+    "MZ" => "Moçambique",
+    "MM" => "Myanmar",
+    "NA" => "Namibia",
+    "NR" => "Nauru",
+    "NP" => "Nepal",
+    "NL" => "Nederländerna",
+    "NC" => "Nya Kaledonien",
+    "NZ" => "Nya Zeeland",
+    "NI" => "Nicaragua",
+    "NE" => "Niger",
+    "NG" => "Nigeria",
+    "NU" => "Niue",
+    "NF" => "Norfolköarna",
+    "MK" => "Nordmakedonien",
+    "MP" => "Nordmarianerna",
+    "NO" => "Norge",
+    "OM" => "Oman",
+    "PK" => "Pakistan",
+    "PW" => "Palau",
+    "PS" => "Staten Palestina",
+    "PA" => "Panama",
+    "PG" => "Papua Nya Guinea",
+    "PY" => "Paraguay",
+    "PE" => "Peru",
+    "PH" => "Filippinerna",
+    "PN" => "Pitcairn",
+    "PL" => "Polen",
+    "PT" => "Portugal",
+    "PR" => "Puerto Rico",
+    "QA" => "Qatar",
+    "RE" => "Réunion",
+    "RO" => "Rumänien",
+    "RU" => "Ryska federationen",
+    "RW" => "Rwanda",
+    "BL" => "Saint-Barthélemy",
+    "SH" => "Saint Helena, Ascension och Tristan da Cunha",
+    "KN" => "Sankt Kitts och Nevis",
+    "LC" => "Sankt Lucia",
+    "MF" => "Saint Martin (franska delen)",
+    "PM" => "Sankt Pierre och Miquelon",
+    "VC" => "Sankt Vincent och Grenadinerna",
+    "WS" => "Samoa",
+    "SM" => "San Marino",
+    "ST" => "São Tomé och Príncipe",
+    "SA" => "Saudiarabien",
+    "SN" => "Senegal",
+    "RS" => "Serbien",
+    "SC" => "Seychellerna",
+    "SL" => "Sierra Leone",
+    "SG" => "Singapore",
+    "SX" => "Sint Maarten (nederländska delen)",
+    "SK" => "Slovakien",
+    "SI" => "Slovenien",
+    "SB" => "Salomonöarna",
+    "SO" => "Somalia",
+    "ZA" => "Sydafrika",
+    "GS" => "Sydgeorgien och södra Sandwichöarna",
+    "SS" => "Sydsudan",
+    "ES" => "Spanien",
+    "LK" => "Sri Lanka",
+    "SD" => "Sudan",
+    "SR" => "Surinam",
+    "SJ" => "Svalbard och Jan Mayen",
+    "SE" => "Sverige",
+    "CH" => "Schweiz",
+    "SY" => "Syriska arabrepubliken",
+    "TW" => "Taiwan, provins i Kina",
+    "TJ" => "Tadzjikistan",
+    "TZ" => "Tanzania, förenade republiken",
+    "TH" => "Thailand",
+    "TL" => "Östtimor",
+    "TG" => "Togo",
+    "TK" => "Tokelau",
+    "TO" => "Tonga",
+    "TT" => "Trinidad och Tobago",
+    "TN" => "Tunisien",
+    "TR" => "Turkiet",
+    "TM" => "Turkmenistan",
+    "TC" => "Turks- och Caicosöarna",
+    "TV" => "Tuvalu",
+    "UG" => "Uganda",
+    "UA" => "Ukraina",
+    "AE" => "Förenade Arabemiraten",
+    "GB" => "Förenade kungariket",
+    "US" => "USA",
+    "UM" => "Förenta staternas mindre öar i Oceanien och Västindien",
+    "UY" => "Uruguay",
+    "UZ" => "Uzbekistan",
+    "VU" => "Vanuatu",
+    "VE" => "Venezuela, Bolivarianska republiken",
+    "VN" => "Vietnam",
+    "VG" => "Jungfruöarna, brittiska",
+    "VI" => "Jungfruöarna, amerikanska",
+    "WF" => "Wallis och Futuna",
+    "EH" => "Västsahara",
+    "YE" => "Yemen",
+    "ZM" => "Zambia",
+    "ZW" => "Zimbabwe",
+};
+
+///Swedish subdivision names by ISO 3166-2 code, 3865 of 5046
+pub const SUBDIVISION_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF-BAL" => "Balkh",
+    "AF-BAM" => "Bamyan",
+    "AF-BDG" => "Badghis",
+    "AF-BDS" => "Badakhshan",
+    "AF-BGL" => "Baghlan",
+    "AF-DAY" => "Daikondi",
+    "AF-FRA" => "Farah",
+    "AF-FYB" => "Faryab",
+    "AF-GHA" => "Ghazni",
+    "AF-GHO" => "Ghor",
+    "AF-HEL" => "Helmand",
+    "AF-HER" => "Herat",
+    "AF-JOW" => "Jowzjān",
+    "AF-KAB" => "Kabul",
+    "AF-KAN" => "Kandahar",
+    "AF-KAP" => "Kapisa",
+    "AF-KDZ" => "Kunduz",
+    "AF-KHO" => "Khost",
+    "AF-LAG" => "Laghman",
+    "AF-LOG" => "Logar",
+    "AF-NAN" => "Nangarhar",
+    "AF-NIM" => "Nimruz",
+    "AF-NUR" => "Nurestan",
+    "AF-PAN" => "Panjshir",
+    "AF-PAR" => "Parvan",
+    "AF-PIA" => "Paktia",
+    "AF-PKA" => "Paktika",
+    "AF-SAM" => "Samangan",
+    "AF-SAR" => "Sar-e Pol",
+    "AF-TAK" => "Takhar",
+    "AF-URU" => "Oruzgan",
+    "AF-WAR" => "Wardak",
+    "AF-ZAB" => "Zabul",
+    "AL-01" => "Berat",
+    "AL-02" => "Durrës",
+    "AL-03" => "Elbasan",
+    "AL-04" => "Fier",
+    "AL-05" => "Gjirokastër",
+    "AL-06" => "Korça",
+    "AL-07" => "Kukës",
+    "AL-08" => "Lezha",
+    "AL-09" => "Dibër",
+    "AL-10" => "Shkodër",
+    "AL-11" => "Tirana",
+    "AL-12" => "Vlorë",
+    "DZ-01" => "Adrar",
+    "DZ-02" => "Chlef",
+    "DZ-03" => "Laghouat",
+    "DZ-04" => "Oum el Bouaghi",
+    "DZ-05" => "Batna",
+    "DZ-06" => "Béjaïa",
+    "DZ-07" => "Biskra",
+    "DZ-08" => "Béchar",
+    "DZ-09" => "Blida",
+    "DZ-10" => "Bouira",
+    "DZ-12" => "Tébessa",
+    "DZ-13" => "Tlemcen",
+    "DZ-14" => "Tiaret",
+    "DZ-15" => "Tizi Ouzou",
+    "DZ-16" => "Alger",
+    "DZ-17" => "Djelfa",
+    "DZ-18" => "Jijel",
+    "DZ-19" => "Sétif",
+    "DZ-20" => "Saïda",
+    "DZ-21" => "Skikda",
+    "DZ-22" => "Sidi Bel Abbès",
+    "DZ-23" => "Annaba",
+    "DZ-24" => "Guelma",
+    "DZ-25" => "Constantine",
+    "DZ-26" => "Médéa",
+    "DZ-27" => "Mostaganem",
+    "DZ-29" => "Mascara",
+    "DZ-30" => "Ouargla",
+    "DZ-31" => "Oran",
+    "DZ-32" => "El Bayadh",
+    "DZ-33" => "Illizi",
+    "DZ-34" => "Bordj Bou Arréridj",
+    "DZ-35" => "Boumerdès",
+    "DZ-36" => "El Tarf",
+    "DZ-37" => "Tindouf",
+    "DZ-38" => "Tissemsilt",
+    "DZ-39" => "El Oued",
+    "DZ-40" => "Khenchela",
+    "DZ-41" => "Souk Ahras",
+    "DZ-42" => "Tipaza",
+    "DZ-43" => "Mila",
+    "DZ-44" => "Aïn Defla",
+    "DZ-45" => "Naama",
+    "DZ-46" => "Aïn Témouchent",
+    "DZ-47" => "Ghardaïa",
+    "DZ-48" => "Relizane",
+    "AD-02" => "Canillo",
+    "AD-03" => "Encamp",
+    "AD-04" => "La Massana",
+    "AD-05" => "Ordino",
+    "AD-06" => "Sant Julià de Lòria",
+    "AD-07" => "Andorra la Vella",
+    "AD-08" => "Escaldes-Engordany",
+    "AO-BGO" => "Bengo",
+    "AO-BGU" => "Benguela",
+    "AO-BIE" => "Bié",
+    "AO-CAB" => "Cabinda",
+    "AO-CNN" => "Cunene",
+    "AO-HUA" => "Huambo",
+    "AO-HUI" => "Huíla",
+    "AO-LUA" => "Luanda",
+    "AO-MAL" => "Malanje",
+    "AO-MOX" => "Moxico",
+    "AO-NAM" => "Namibe",
+    "AO-UIG" => "Uíge",
+    "AO-ZAI" => "Zaire",
+    "AG-03" => "Saint George",
+    "AG-04" => "Saint John",
+    "AG-05" => "Saint Mary",
+    "AG-06" => "Saint Paul",
+    "AG-07" => "Saint Peter",
+    "AG-08" => "Saint Philip",
+    "AG-10" => "Barbuda",
+    "AG-11" => "Redonda",
+    "AR-A" => "Salta",
+    "AR-B" => "Buenos Aires",
+    "AR-C" => "Ciudad Autónoma de Buenos Aires",
+    "AR-D" => "San Luis",
+    "AR-F" => "La Rioja",
+    "AR-G" => "Santiago del Estero",
+    "AR-H" => "Chaco",
+    "AR-J" => "San Juan",
+    "AR-K" => "Catamarca",
+    "AR-L" => "La Pampa",
+    "AR-M" => "Mendoza",
+    "AR-N" => "Misiones",
+    "AR-P" => "Formosa",
+    "AR-R" => "Río Negro",
+    "AR-S" => "Santa Fe",
+    "AR-U" => "Chubut",
+    "AR-V" => "Eldslandet",
+    "AR-W" => "Corrientes",
+    "AR-X" => "Córdoba",
+    "AR-Y" => "Jujuy",
+    "AR-Z" => "Santa Cruz",
+    "AM-AR" => "Ararat",
+    "AM-AV" => "Armavir",
+    "AM-ER" => "Erevan",
+    "AM-KT" => "Kotajk",
+    "AM-SU" => "Siunik",
+    "AU-ACT" => "Australian Capital Territory",
+    "AU-NSW" => "Nya Sydwales",
+    "AU-NT" => "Nordterritoriet",
+    "AU-QLD" => "Queensland",
+    "AU-SA" => "Sydaustralien",
+    "AU-TAS" => "Tasmanien",
+    "AU-VIC" => "Victoria",
+    "AU-WA" => "Västaustralien",
+    "AT-1" => "Burgenland",
+    "AT-2" => "Kärnten",
+    "AT-3" => "Niederösterreich",
+    "AT-4" => "Oberösterreich",
+    "AT-5" => "Salzburg",
+    "AT-6" => "Steiermark",
+    "AT-7" => "Tyrolen",
+    "AT-8" => "Vorarlberg",
+    "AT-9" => "Wien",
+    "AZ-ABS" => "Apsjeron",
+    "AZ-AGA" => "Agstafa",
+    "AZ-AGC" => "Agdzjabedi",
+    "AZ-AGM" => "Ağdam",
+    "AZ-AGS" => "Agdash",
+    "AZ-AGU" => "Agsu",
+    "AZ-AST" => "Astara",
+    "AZ-BA" => "Baku",
+    "AZ-BAB" => "Babək",
+    "AZ-BAL" => "Balakən",
+    "AZ-BAR" => "Barda",
+    "AZ-BEY" => "Beyləqan",
+    "AZ-BIL" => "Biləsuvar",
+    "AZ-CAB" => "Cəbrayıl",
+    "AZ-CUL" => "Culfa",
+    "AZ-DAS" => "Daşkəsən",
+    "AZ-FUZ" => "Füzuli",
+    "AZ-GA" => "Gjandzja",
+    "AZ-GAD" => "Gədəbəy",
+    "AZ-GOR" => "Goranboy",
+    "AZ-GOY" => "Göyçay",
+    "AZ-GYG" => "Göygöl",
+    "AZ-HAC" => "Hacıqabul",
+    "AZ-IMI" => "İmişli",
+    "AZ-ISM" => "İsmayıllı",
+    "AZ-KAL" => "Kalbajar",
+    "AZ-KAN" => "Kangǝrli",
+    "AZ-KUR" => "Kurdamir",
+    "AZ-LA" => "Lankaran",
+    "AZ-LAC" => "Laçın",
+    "AZ-LAN" => "Lankaran",
+    "AZ-LER" => "Lerik",
+    "AZ-MAS" => "Masallı",
+    "AZ-MI" => "Mingetjaur",
+    "AZ-NA" => "Naftalan",
+    "AZ-NEF" => "Neftçala",
+    "AZ-NV" => "Nachitjevan",
+    "AZ-NX" => "Nachitjevan",
+    "AZ-OGU" => "Oğuz",
+    "AZ-ORD" => "Ordubad",
+    "AZ-QAB" => "Qəbələ",
+    "AZ-QAX" => "Qax",
+    "AZ-QAZ" => "Qazax",
+    "AZ-QBA" => "Quba",
+    "AZ-QBI" => "Qubadli",
+    "AZ-QOB" => "Gobustan",
+    "AZ-QUS" => "Qusar",
+    "AZ-SA" => "Sjaki",
+    "AZ-SAB" => "Sabirabad",
+    "AZ-SAD" => "Sadarak",
+    "AZ-SAH" => "Şahbuz",
+    "AZ-SAK" => "Sjaki",
+    "AZ-SAL" => "Salyan",
+    "AZ-SAR" => "Sharur",
+    "AZ-SAT" => "Saatlı",
+    "AZ-SBN" => "Şabran",
+    "AZ-SIY" => "Siazan",
+    "AZ-SKR" => "Shamkir",
+    "AZ-SM" => "Sumqayıt",
+    "AZ-SMI" => "Shamakhi",
+    "AZ-SMX" => "Samux",
+    "AZ-SR" => "Sirvan",
+    "AZ-SUS" => "Sjusja",
+    "AZ-TAR" => "Tərtər",
+    "AZ-TOV" => "Tovuz",
+    "AZ-UCA" => "Ucar",
+    "AZ-XA" => "Stepanakert",
+    "AZ-XAC" => "Xaçmaz",
+    "AZ-XCI" => "Xocalı",
+    "AZ-XIZ" => "Xızı",
+    "AZ-XVD" => "Xocavənd",
+    "AZ-YAR" => "Yardımlı",
+    "AZ-YE" => "Jevlach",
+    "AZ-YEV" => "Jevlach",
+    "AZ-ZAN" => "Zəngilan",
+    "AZ-ZAQ" => "Zaqatala",
+    "AZ-ZAR" => "Zərdab",
+    "BS-AK" => "Acklins",
+    "BS-BI" => "Bimini",
+    "BS-BP" => "Black Point",
+    "BS-BY" => "Berry Islands",
+    "BS-CE" => "Central Eleuthera",
+    "BS-CI" => "Cat Island",
+    "BS-CK" => "Crooked Island and Long Cay",
+    "BS-CO" => "Central Abaco",
+    "BS-CS" => "Central Andros",
+    "BS-EG" => "East Grand Bahama",
+    "BS-EX" => "Exuma",
+    "BS-FP" => "City of Freeport",
+    "BS-GC" => "Grand Cay",
+    "BS-HI" => "Harbour Island",
+    "BS-HT" => "Hope Town",
+    "BS-IN" => "Inagua",
+    "BS-LI" => "Long Island",
+    "BS-MC" => "Mangrove Cay",
+    "BS-MG" => "Mayaguana",
+    "BS-MI" => "Moore's Island",
+    "BS-NE" => "North Eleuthera",
+    "BS-NO" => "North Abaco",
+    "BS-NS" => "North Andros",
+    "BS-RC" => "Rum Cay",
+    "BS-RI" => "Ragged Island",
+    "BS-SA" => "South Andros",
+    "BS-SE" => "South Eleuthera",
+    "BS-SO" => "South Abaco",
+    "BS-SS" => "San Salvador",
+    "BS-SW" => "Spanish Wells",
+    "BS-WG" => "West Grand Bahama",
+    "BH-14" => "Al Janūbīyah",
+    "BH-15" => "Al Muḩarraq",
+    "BH-17" => "Ash Shamālīyah",
+    "BD-01" => "Bandarban",
+    "BD-02" => "Barguna",
+    "BD-04" => "Brahmanbaria",
+    "BD-05" => "Bagerhat",
+    "BD-07" => "Bhola",
+    "BD-09" => "Chandpur",
+    "BD-11" => "Cox's Bazar",
+    "BD-12" => "Chuadanga",
+    "BD-13" => "Dhaka",
+    "BD-14" => "Dinajpur",
+    "BD-15" => "Faridpur",
+    "BD-16" => "Feni",
+    "BD-17" => "Gopalganj",
+    "BD-18" => "Gazipur",
+    "BD-19" => "Gaibandha",
+    "BD-20" => "Habiganj",
+    "BD-21" => "Jamalpur",
+    "BD-23" => "Jhenaidah",
+    "BD-27" => "Khulna",
+    "BD-28" => "Kurigram",
+    "BD-30" => "Kushtia",
+    "BD-31" => "Lakshmipur",
+    "BD-32" => "Lalmonirhat",
+    "BD-33" => "Manikganj",
+    "BD-34" => "Mymensingh",
+    "BD-35" => "Munshiganj",
+    "BD-36" => "Madaripur",
+    "BD-37" => "Magura",
+    "BD-38" => "Moulvibazar",
+    "BD-39" => "Meherpur",
+    "BD-40" => "Narayanganj",
+    "BD-41" => "Netrakona",
+    "BD-42" => "Narsingdi",
+    "BD-43" => "Narail",
+    "BD-44" => "Natore",
+    "BD-46" => "Nilphamari",
+    "BD-47" => "Noakhali",
+    "BD-48" => "Naogaon",
+    "BD-49" => "Pabna",
+    "BD-50" => "Pirojpur",
+    "BD-51" => "Patuakhali",
+    "BD-52" => "Panchagarh",
+    "BD-53" => "Rajbari",
+    "BD-54" => "Rajshahi",
+    "BD-55" => "Rangpur",
+    "BD-56" => "Rangamati",
+    "BD-57" => "Sherpur",
+    "BD-58" => "Satkhira",
+    "BD-59" => "Sirajganj",
+    "BD-60" => "Sylhet",
+    "BD-61" => "Sunamganj",
+    "BD-62" => "Shariatpur",
+    "BD-63" => "Tangali",
+    "BD-64" => "Thakurgaon",
+    "BD-C" => "Dhaka",
+    "BD-D" => "Khulna",
+    "BD-E" => "Rajshahi",
+    "BD-F" => "Rangpur",
+    "BD-G" => "Sylhet",
+    "BD-H" => "Mymensingh",
+    "BB-01" => "Christ Church",
+    "BB-02" => "Saint Andrew",
+    "BB-03" => "Saint George",
+    "BB-04" => "Saint James",
+    "BB-05" => "Saint John",
+    "BB-06" => "Saint Joseph",
+    "BB-07" => "Saint Lucy",
+    "BB-08" => "Saint Michael",
+    "BB-09" => "Saint Peter",
+    "BB-10" => "Saint Philip",
+    "BB-11" => "Saint Thomas",
+    "BY-BR" => "Brests voblasts",
+    "BY-MA" => "Mahiljoŭs voblasts",
+    "BY-VI" => "Vitsebsks voblasts",
+    "BE-VAN" => "Antwerpen",
+    "BE-VBR" => "Vlaams-Brabant",
+    "BE-VLG" => "Flandern",
+    "BE-VLI" => "Limburg",
+    "BE-VOV" => "Östflandern",
+    "BE-VWV" => "Västflandern",
+    "BE-WAL" => "Vallonien",
+    "BE-WBR" => "Vallonska Brabant",
+    "BE-WHT" => "Hainaut",
+    "BE-WLG" => "Liège",
+    "BE-WLX" => "Luxemburg",
+    "BE-WNA" => "Namur",
+    "BZ-BZ" => "Belize",
+    "BZ-CY" => "Cayo",
+    "BZ-CZL" => "Corozal",
+    "BZ-OW" => "Orange Walk",
+    "BZ-SC" => "Stann Creek",
+    "BZ-TOL" => "Toledo",
+    "BJ-AL" => "Alibori",
+    "BJ-AQ" => "Atlantique",
+    "BJ-BO" => "Borgou",
+    "BJ-CO" => "Collines",
+    "BJ-DO" => "Donga",
+    "BJ-LI" => "Littoral",
+    "BJ-MO" => "Mono",
+    "BJ-OU" => "Ouémé",
+    "BJ-PL" => "Plateau",
+    "BJ-ZO" => "Zou",
+    "BT-11" => "Paro",
+    "BT-12" => "Chukha",
+    "BT-15" => "Thimphu",
+    "BT-21" => "Tsirang",
+    "BT-22" => "Dagana",
+    "BT-23" => "Punakha",
+    "BT-24" => "Wangdue Phodrang",
+    "BT-31" => "Sarpang",
+    "BT-32" => "Trongsa",
+    "BT-33" => "Bumthang",
+    "BT-34" => "Zhemgang",
+    "BT-41" => "Trashigang",
+    "BT-42" => "Mongar",
+    "BT-44" => "Lhuntse",
+    "BT-GA" => "Gasa",
+    "BT-TY" => "Trashi Yangtse",
+    "BO-B" => "El Beni",
+    "BO-C" => "Cochabamba",
+    "BO-H" => "Chuquisaca",
+    "BO-L" => "La Paz",
+    "BO-N" => "Pando",
+    "BO-O" => "Oruro",
+    "BO-P" => "Potosí",
+    "BO-S" => "Santa Cruz",
+    "BO-T" => "Tarija",
+    "BQ-BO" => "Bonaire",
+    "BQ-SA" => "Saba",
+    "BQ-SE" => "Sint Eustatius",
+    "BA-BIH" => "Federationen Bosnien och Hercegovina",
+    "BA-BRC" => "Brčko distrikt",
+    "BA-SRP" => "Republika Srpska",
+    "BW-CE" => "Central",
+    "BW-GH" => "Ghanzi",
+    "BW-KG" => "Kgalagadi",
+    "BW-KL" => "Kgatleng",
+    "BW-KW" => "Kweneng",
+    "BW-NE" => "Nordöst",
+    "BW-NW" => "Nordväst",
+    "BW-SE" => "Sydöst",
+    "BW-SO" => "Southern",
+    "BR-AC" => "Acre",
+    "BR-AL" => "Alagoas",
+    "BR-AM" => "Amazonas",
+    "BR-AP" => "Amapá",
+    "BR-BA" => "Bahia",
+    "BR-CE" => "Ceará",
+    "BR-DF" => "Distrito Federal",
+    "BR-ES" => "Espírito Santo",
+    "BR-GO" => "Goiás",
+    "BR-MA" => "Maranhão",
+    "BR-MG" => "Minas Gerais",
+    "BR-MS" => "Mato Grosso do Sul",
+    "BR-MT" => "Mato Grosso",
+    "BR-PA" => "Pará",
+    "BR-PB" => "Paraíba",
+    "BR-PE" => "Pernambuco",
+    "BR-PI" => "Piauí",
+    "BR-PR" => "Paraná",
+    "BR-RJ" => "Rio de Janeiro",
+    "BR-RN" => "Rio Grande do Norte",
+    "BR-RO" => "Rondônia",
+    "BR-RR" => "Roraima",
+    "BR-RS" => "Rio Grande do Sul",
+    "BR-SC" => "Santa Catarina",
+    "BR-SE" => "Sergipe",
+    "BR-SP" => "São Paulo",
+    "BR-TO" => "Tocantins",
+    "BN-BE" => "Belait",
+    "BN-BM" => "Brunei-Muara",
+    "BN-TE" => "Temburong",
+    "BN-TU" => "Tutong",
+    "BG-01" => "Blagoevgrad",
+    "BG-02" => "Burgas",
+    "BG-03" => "Varna",
+    "BG-04" => "Veliko Tarnovo",
+    "BG-05" => "Vidin",
+    "BG-06" => "Vratsa",
+    "BG-07" => "Gabrovo",
+    "BG-08" => "Dobrich",
+    "BG-09" => "Kardzhali",
+    "BG-10" => "Kyustendil",
+    "BG-11" => "Lovech",
+    "BG-12" => "Montana",
+    "BG-13" => "Pazardzhik",
+    "BG-14" => "Pernik",
+    "BG-15" => "Pleven",
+    "BG-16" => "Plovdiv",
+    "BG-17" => "Razgrad",
+    "BG-18" => "Ruse",
+    "BG-19" => "Silistra",
+    "BG-20" => "Sliven",
+    "BG-21" => "Smolyan",
+    "BG-23" => "Sofia",
+    "BG-24" => "Stara Zagora",
+    "BG-25" => "Targovishte",
+    "BG-26" => "Haskovo",
+    "BG-27" => "Shumen",
+    "BG-28" => "Yambol",
+    "BF-01" => "Boucle du Mouhoun",
+    "BF-02" => "Cascades",
+    "BF-03" => "Centre",
+    "BF-04" => "Centre-Est",
+    "BF-05" => "Centre-Nord",
+    "BF-06" => "Centre-Ouest",
+    "BF-07" => "Centre-Sud",
+    "BF-08" => "Est",
+    "BF-09" => "Hauts-Bassins",
+    "BF-10" => "Nord",
+    "BF-11" => "Plateau-Central",
+    "BF-12" => "Sahel",
+    "BF-13" => "Sud-Ouest",
+    "BF-BAL" => "Balé",
+    "BF-BAM" => "Bam",
+    "BF-BAN" => "Banwa",
+    "BF-BAZ" => "Bazèga",
+    "BF-BGR" => "Bougouriba",
+    "BF-BLG" => "Boulgou",
+    "BF-BLK" => "Boulkiemdé",
+    "BF-COM" => "Comoé",
+    "BF-GAN" => "Ganzourgou",
+    "BF-GNA" => "Gnagna",
+    "BF-GOU" => "Gourma",
+    "BF-HOU" => "Houet",
+    "BF-IOB" => "Ioba",
+    "BF-KAD" => "Kadiogo",
+    "BF-KEN" => "Kénédougou",
+    "BF-KMD" => "Komondjari",
+    "BF-KMP" => "Kompienga",
+    "BF-KOP" => "Koulpélogo",
+    "BF-KOS" => "Kossi",
+    "BF-KOT" => "Kouritenga",
+    "BF-KOW" => "Kourwéogo",
+    "BF-LER" => "Léraba",
+    "BF-LOR" => "Loroum",
+    "BF-MOU" => "Mouhoun",
+    "BF-NAM" => "Namentenga",
+    "BF-NAY" => "Nayala",
+    "BF-NOU" => "Noumbiel",
+    "BF-OUB" => "Oubritenga",
+    "BF-OUD" => "Oudalan",
+    "BF-PAS" => "Passoré",
+    "BF-PON" => "Poni",
+    "BF-SEN" => "Séno",
+    "BF-SIS" => "Sissili",
+    "BF-SMT" => "Sanmatenga",
+    "BF-SNG" => "Sanguié",
+    "BF-SOM" => "Soum",
+    "BF-SOR" => "Sourou",
+    "BF-TAP" => "Tapoa",
+    "BF-YAG" => "Yagha",
+    "BF-YAT" => "Yatenga",
+    "BF-ZIR" => "Ziro",
+    "BF-ZON" => "Zondoma",
+    "BF-ZOU" => "Zoundwéogo",
+    "BI-BB" => "Bubanza",
+    "BI-BL" => "Bujumbura Rural",
+    "BI-BM" => "Bujumbura Mairie",
+    "BI-BR" => "Bururi",
+    "BI-CA" => "Cankuzo",
+    "BI-CI" => "Cibitoke",
+    "BI-GI" => "Gitega",
+    "BI-KI" => "Kirundo",
+    "BI-KR" => "Karuzi",
+    "BI-KY" => "Kayanza",
+    "BI-MA" => "Makamba",
+    "BI-MU" => "Muramvya",
+    "BI-MW" => "Mwaro",
+    "BI-NG" => "Ngozi",
+    "BI-RT" => "Rutana",
+    "BI-RY" => "Ruyigi",
+    "CV-B" => "Ilhas de Barlavento",
+    "CV-BR" => "Brava",
+    "CV-BV" => "Boa Vista",
+    "CV-CA" => "Santa Catarina",
+    "CV-CR" => "Santa Cruz",
+    "CV-MA" => "Maio",
+    "CV-MO" => "Mosteiros",
+    "CV-PA" => "Paul",
+    "CV-PN" => "Porto Novo",
+    "CV-PR" => "Praia",
+    "CV-RB" => "Ribeira Brava",
+    "CV-RG" => "Ribeira Grande",
+    "CV-RS" => "Ribeira Grande de Santiago",
+    "CV-S" => "Ilhas de Sotavento",
+    "CV-SD" => "São Domingos",
+    "CV-SF" => "São Filipe",
+    "CV-SL" => "Sal",
+    "CV-SM" => "São Miguel",
+    "CV-SO" => "São Lourenço dos Órgãos",
+    "CV-SS" => "São Salvador do Mundo",
+    "CV-SV" => "São Vicente",
+    "CV-TA" => "Tarrafal",
+    "CV-TS" => "Tarrafal de São Nicolau",
+    "KH-11" => "Mondol Kiri",
+    "KH-12" => "Phnom Penh",
+    "KH-13" => "Preah Vihear",
+    "KH-14" => "Prey Veaeng",
+    "KH-15" => "Pousaat",
+    "KH-16" => "Rotanak Kiri",
+    "KH-17" => "Siem Reab",
+    "KH-20" => "Svaay Rieng",
+    "KH-21" => "Taakaev",
+    "KH-22" => "Otdar Mean Chey",
+    "KH-4" => "Kampong Chhnang",
+    "KH-7" => "Kampot",
+    "CM-AD" => "Adamaoua",
+    "CM-CE" => "Centre",
+    "CM-EN" => "Nordligaste",
+    "CM-ES" => "Östra",
+    "CM-LT" => "Littoral",
+    "CM-NO" => "Norra",
+    "CM-NW" => "North-West",
+    "CM-OU" => "Västra",
+    "CM-SU" => "Södra",
+    "CM-SW" => "Sydvästra",
+    "CA-AB" => "Alberta",
+    "CA-BC" => "Brittiska columbia",
+    "CA-MB" => "Manitoba",
+    "CA-NB" => "New Brunswick",
+    "CA-NL" => "Newfoundland och Labrador",
+    "CA-NS" => "Nova Scotia",
+    "CA-NT" => "Nordvästra territorierna",
+    "CA-NU" => "Nunavut",
+    "CA-ON" => "Ontario",
+    "CA-PE" => "Prins Edwardön",
+    "CA-QC" => "Quebec",
+    "CA-SK" => "Saskatchewan",
+    "CF-AC" => "Ouham",
+    "CF-BB" => "Bamingui-Bangoran",
+    "CF-BGF" => "Bangui",
+    "CF-BK" => "Basse-Kotto",
+    "CF-HK" => "Haute-Kotto",
+    "CF-HM" => "Haut-Mbomou",
+    "CF-HS" => "Haute-Sangha / Mambéré-Kadéï",
+    "CF-KB" => "Gribingui",
+    "CF-LB" => "Lobaye",
+    "CF-MB" => "Mbomou",
+    "CF-NM" => "Nana-Mambéré",
+    "CF-OP" => "Ouham-Pendé",
+    "CF-SE" => "Sangha",
+    "CF-UK" => "Ouaka",
+    "CF-VK" => "Vakaga",
+    "TD-ND" => "N'Djamena",
+    "TD-TI" => "Tibastī",
+    "CL-AN" => "Antofagasta",
+    "CL-AP" => "Arica y Parinacota",
+    "CL-AT" => "Atacama",
+    "CL-CO" => "Coquimbo",
+    "CL-LI" => "Libertador General Bernardo O'Higgins",
+    "CL-LL" => "Los Lagos",
+    "CL-LR" => "Los Ríos",
+    "CL-ML" => "Maule",
+    "CL-RM" => "Región Metropolitana de Santiago",
+    "CL-TA" => "Tarapacá",
+    "CL-VS" => "Valparaíso",
+    "CN-AH" => "Anhui Sheng",
+    "CN-CQ" => "Chongqing Shi",
+    "CN-FJ" => "Fujian Sheng",
+    "CN-GD" => "Guangdong Sheng",
+    "CN-GS" => "Gansu Sheng",
+    "CN-GX" => "Guangxi",
+    "CN-GZ" => "Guizhou Sheng",
+    "CN-HA" => "Henan Sheng",
+    "CN-HB" => "Hubei Sheng",
+    "CN-HE" => "Hebei Sheng",
+    "CN-HI" => "Hainan Sheng",
+    "CN-HL" => "Heilongjiang Sheng",
+    "CN-HN" => "Hunan Sheng",
+    "CN-JL" => "Jilin Sheng",
+    "CN-JS" => "Jiangsu Sheng",
+    "CN-JX" => "Jiangxi Sheng",
+    "CN-LN" => "Liaoning Sheng",
+    "CN-NM" => "Nei Mongol Zizhiqu",
+    "CN-NX" => "Ningxia Huizi Zizhiqu",
+    "CN-QH" => "Qinghai Sheng",
+    "CN-SC" => "Sichuan Sheng",
+    "CN-SD" => "Shandong Sheng",
+    "CN-SH" => "Shanghai Shi",
+    "CN-SN" => "Shaanxi Sheng",
+    "CN-SX" => "Shanxi Sheng",
+    "CN-TJ" => "Tianjin Shi",
+    "CN-XJ" => "Xinjiang Uygur Zizhiqu",
+    "CN-XZ" => "Xizang Zizhiqu",
+    "CN-YN" => "Yunnan Sheng",
+    "CN-ZJ" => "Zhejiang Sheng",
+    "CO-AMA" => "Amazonas",
+    "CO-ANT" => "Antioquia",
+    "CO-ARA" => "Arauca",
+    "CO-ATL" => "Atlántico",
+    "CO-BOL" => "Bolívar",
+    "CO-BOY" => "Boyacá",
+    "CO-CAL" => "Caldas",
+    "CO-CAQ" => "Caquetá",
+    "CO-CAS" => "Casanare",
+    "CO-CAU" => "Cauca",
+    "CO-CES" => "Cesar",
+    "CO-CHO" => "Chocó",
+    "CO-COR" => "Córdoba",
+    "CO-CUN" => "Cundinamarca",
+    "CO-DC" => "Distrito Capital de Bogotá",
+    "CO-GUA" => "Guainía",
+    "CO-GUV" => "Guaviare",
+    "CO-HUI" => "Huila",
+    "CO-LAG" => "La Guajira",
+    "CO-MAG" => "Magdalenafloden",
+    "CO-MET" => "Meta",
+    "CO-NAR" => "Nariño",
+    "CO-NSA" => "Norte de Santander",
+    "CO-PUT" => "Putumayo",
+    "CO-QUI" => "Quindío",
+    "CO-RIS" => "Risaralda",
+    "CO-SAN" => "Santander",
+    "CO-SAP" => "San Andrés, Providencia y Santa Catalina",
+    "CO-SUC" => "Sucre",
+    "CO-TOL" => "Tolima",
+    "CO-VAC" => "Valle del Cauca",
+    "CO-VAU" => "Vaupés",
+    "CO-VID" => "Vichada",
+    "CG-11" => "Bouenza",
+    "CG-12" => "Pool",
+    "CG-13" => "Sangha",
+    "CG-14" => "Plateaux",
+    "CG-15" => "Cuvette-Ouest",
+    "CG-2" => "Lékoumou",
+    "CG-5" => "Kouilou",
+    "CG-7" => "Likouala",
+    "CG-8" => "Cuvette",
+    "CG-9" => "Niari",
+    "CG-BZV" => "Brazzaville",
+    "CD-EQ" => "Équateur",
+    "CD-KN" => "Kinshasa",
+    "CD-MA" => "Maniema",
+    "CD-NK" => "Norra Kivu",
+    "CD-SK" => "Södra Kivu",
+    "CR-A" => "Alajuela",
+    "CR-C" => "Cartago",
+    "CR-G" => "Guanacaste",
+    "CR-H" => "Heredia",
+    "CR-L" => "Limón",
+    "CR-P" => "Puntarenas",
+    "CR-SJ" => "San José",
+    "CI-CM" => "Comoé",
+    "HR-01" => "Zagrebs län",
+    "HR-02" => "Krapina-Zagorjes län",
+    "HR-03" => "Sisak-Moslavinas län",
+    "HR-04" => "Karlovacs län",
+    "HR-05" => "Varaždins län",
+    "HR-06" => "Koprivnica-Križevcis län",
+    "HR-07" => "Bjelovarsko-bilogorska län",
+    "HR-08" => "Primorje-Gorski kotars län",
+    "HR-09" => "Lika-Senjs län",
+    "HR-10" => "Virovitica-Podravinas län",
+    "HR-11" => "Požega-Slavoniens län",
+    "HR-12" => "Brod-Posavinas län",
+    "HR-13" => "Zadars län",
+    "HR-14" => "Osijek-Baranjas län",
+    "HR-15" => "Šibenik-Knins län",
+    "HR-16" => "Vukovar-Srijems län",
+    "HR-17" => "Split-Dalmatiens län",
+    "HR-18" => "Istriens län",
+    "HR-19" => "Dubrovnik-Neretva län",
+    "HR-20" => "Međimurjes län",
+    "HR-21" => "Zagreb",
+    "CU-03" => "Havanna",
+    "CU-04" => "Matanzas",
+    "CU-05" => "Villa Clara",
+    "CU-06" => "Cienfuegos",
+    "CU-07" => "Sancti Spíritus",
+    "CU-08" => "Ciego de Ávila",
+    "CU-09" => "Camagüey",
+    "CU-10" => "Las Tunas",
+    "CU-11" => "Holguín",
+    "CU-12" => "Granma",
+    "CU-13" => "Santiago de Cuba",
+    "CU-14" => "Guantánamo",
+    "CU-99" => "Isla de la Juventud",
+    "CZ-20" => "Mellersta Böhmen",
+    "CZ-201" => "Benešov",
+    "CZ-202" => "Beroun",
+    "CZ-203" => "Kladno",
+    "CZ-204" => "Kolín",
+    "CZ-205" => "Kutná Hora",
+    "CZ-206" => "Mělník",
+    "CZ-207" => "Mladá Boleslav",
+    "CZ-208" => "Nymburk",
+    "CZ-209" => "Prag-východ",
+    "CZ-20A" => "Prag-západ",
+    "CZ-20B" => "Příbram",
+    "CZ-20C" => "Rakovník",
+    "CZ-31" => "Södra Böhmen",
+    "CZ-311" => "České Budějovice",
+    "CZ-312" => "Český Krumlov",
+    "CZ-313" => "Jindřichův Hradec",
+    "CZ-314" => "Písek",
+    "CZ-315" => "Prachatice",
+    "CZ-316" => "Strakonice",
+    "CZ-317" => "Tábor",
+    "CZ-32" => "Plzeň",
+    "CZ-321" => "Domažlice",
+    "CZ-322" => "Klatovy",
+    "CZ-323" => "Plzeň-město",
+    "CZ-324" => "Plzeň-jih",
+    "CZ-325" => "Plzeň-sever",
+    "CZ-326" => "Rokycany",
+    "CZ-327" => "Tachov",
+    "CZ-41" => "Karlovy Vary",
+    "CZ-411" => "Cheb",
+    "CZ-412" => "Karlovy Vary",
+    "CZ-413" => "Sokolov",
+    "CZ-42" => "Ústí nad Labem",
+    "CZ-421" => "Děčín",
+    "CZ-422" => "Chomutov",
+    "CZ-423" => "Litoměřice",
+    "CZ-424" => "Louny",
+    "CZ-425" => "Most",
+    "CZ-426" => "Teplice",
+    "CZ-427" => "Ústí nad Labem",
+    "CZ-51" => "Liberec",
+    "CZ-511" => "Česká Lípa",
+    "CZ-512" => "Jablonec nad Nisou",
+    "CZ-513" => "Liberec",
+    "CZ-514" => "Semily",
+    "CZ-52" => "Hradec Králové",
+    "CZ-521" => "Hradec Králové",
+    "CZ-522" => "Jičín",
+    "CZ-523" => "Náchod",
+    "CZ-524" => "Rychnov nad Kněžnou",
+    "CZ-525" => "Trutnov",
+    "CZ-53" => "Pardubice",
+    "CZ-531" => "Chrudim",
+    "CZ-532" => "Pardubice",
+    "CZ-533" => "Svitavy",
+    "CZ-534" => "Ústí nad Orlicí",
+    "CZ-63" => "Kraj Vysočina",
+    "CZ-631" => "Havlíčkův Brod",
+    "CZ-632" => "Jihlava",
+    "CZ-633" => "Pelhřimov",
+    "CZ-634" => "Třebíč",
+    "CZ-635" => "Žďár nad Sázavou",
+    "CZ-64" => "Södra Mähren",
+    "CZ-641" => "Blansko",
+    "CZ-642" => "Brno-město",
+    "CZ-643" => "Brno-venkov",
+    "CZ-644" => "Břeclav",
+    "CZ-645" => "Hodonín",
+    "CZ-646" => "Vyškov",
+    "CZ-647" => "Znojmo",
+    "CZ-71" => "Olomouc",
+    "CZ-711" => "Jeseník",
+    "CZ-712" => "Olomouc",
+    "CZ-713" => "Prostějov",
+    "CZ-714" => "Přerov",
+    "CZ-715" => "Šumperk",
+    "CZ-72" => "Zlín",
+    "CZ-721" => "Kroměříž",
+    "CZ-722" => "Uherské Hradiště",
+    "CZ-723" => "Vsetín",
+    "CZ-724" => "Zlín",
+    "CZ-80" => "Mähren-Schlesien",
+    "CZ-801" => "Bruntál",
+    "CZ-803" => "Karviná",
+    "CZ-804" => "Nový Jičín",
+    "CZ-805" => "Opava",
+    "CZ-806" => "Ostrava-město",
+    "DK-81" => "Nordjylland",
+    "DK-82" => "Midtjylland",
+    "DK-83" => "Syddanmark",
+    "DK-84" => "Hovedstaden",
+    "DK-85" => "Själland",
+    "DJ-AR" => "Arta",
+    "DJ-AS" => "Ali Sabieh",
+    "DJ-DI" => "Dikhil",
+    "DJ-DJ" => "Djibouti",
+    "DJ-TA" => "Tadjourah",
+    "DM-02" => "Saint Andrew",
+    "DM-03" => "Saint David",
+    "DM-04" => "Saint George",
+    "DM-05" => "Saint John",
+    "DM-06" => "Saint Joseph",
+    "DM-07" => "Saint Luke",
+    "DM-08" => "Saint Mark",
+    "DM-09" => "Saint Patrick",
+    "DM-10" => "Saint Paul",
+    "DM-11" => "Saint Peter",
+    "DO-01" => "Distrito Nacional (Santo Domingo)",
+    "DO-02" => "Azua",
+    "DO-04" => "Barahona",
+    "DO-05" => "Dajabón",
+    "DO-06" => "Duarte",
+    "DO-09" => "Espaillat",
+    "DO-10" => "Independencia",
+    "DO-11" => "La Altagracia",
+    "DO-12" => "La Romana",
+    "DO-13" => "La Vega",
+    "DO-14" => "María Trinidad Sánchez",
+    "DO-15" => "Monte Cristi",
+    "DO-16" => "Pedernales",
+    "DO-17" => "Peravia",
+    "DO-18" => "Puerto Plata",
+    "DO-20" => "Samaná",
+    "DO-21" => "San Cristóbal",
+    "DO-22" => "San Juan",
+    "DO-23" => "San Pedro de Macorís",
+    "DO-24" => "Sánchez Ramírez",
+    "DO-25" => "Santiago",
+    "DO-26" => "Santiago Rodríguez",
+    "DO-27" => "Valverde",
+    "DO-28" => "Monseñor Nouel",
+    "DO-29" => "Monte Plata",
+    "DO-30" => "Hato Mayor",
+    "EC-A" => "Azuay",
+    "EC-B" => "Bolívar",
+    "EC-C" => "Carchi",
+    "EC-D" => "Orellana",
+    "EC-E" => "Esmeraldas",
+    "EC-F" => "Cañar",
+    "EC-G" => "Guayas",
+    "EC-H" => "Chimborazo",
+    "EC-I" => "Imbabura",
+    "EC-L" => "Loja",
+    "EC-M" => "Manabí",
+    "EC-N" => "Napo",
+    "EC-O" => "El Oro",
+    "EC-P" => "Pichincha",
+    "EC-R" => "Los Ríos",
+    "EC-SD" => "Santo Domingo de los Tsáchila",
+    "EC-SE" => "Santa Elena",
+    "EC-T" => "Tungurahua",
+    "EC-U" => "Sucumbíos",
+    "EC-W" => "Galápagos",
+    "EC-X" => "Cotopaxi",
+    "EC-Y" => "Pastaza",
+    "EG-ALX" => "Al Iskandarīyah",
+    "EG-ASN" => "Aswān",
+    "EG-BA" => "Al-Bahr al-Ahmar",
+    "EG-BNS" => "Banī Suwayf",
+    "EG-C" => "Al Qāhirah",
+    "EG-DK" => "Ad Daqahlīyah",
+    "EG-FYM" => "Al Fayyūm",
+    "EG-GH" => "Al Gharbīyah",
+    "EG-GZ" => "Al Jīzah",
+    "EG-JS" => "Janūb Sīnā'",
+    "EG-KB" => "Al Qalyūbīyah",
+    "EG-KFS" => "Kafr ash Shaykh",
+    "EG-KN" => "Qinā",
+    "EG-MN" => "Al Minyā",
+    "EG-MNF" => "Al Minūfīyah",
+    "EG-SHG" => "Sūhāj",
+    "EG-SHR" => "Ash Sharqīyah",
+    "EG-SUZ" => "As Suways",
+    "EG-WAD" => "Al Wādī al Jadīd",
+    "SV-AH" => "Ahuachapán",
+    "SV-CA" => "Cabañas",
+    "SV-CH" => "Chalatenango",
+    "SV-CU" => "Cuscatlán",
+    "SV-LI" => "La Libertad",
+    "SV-MO" => "Morazán",
+    "SV-PA" => "La Paz",
+    "SV-SA" => "Santa Ana",
+    "SV-SM" => "San Miguel",
+    "SV-SO" => "Sonsonate",
+    "SV-SS" => "San Salvador",
+    "SV-SV" => "San Vicente",
+    "SV-UN" => "La Unión",
+    "SV-US" => "Usulután",
+    "GQ-KN" => "Kié-Ntem",
+    "GQ-LI" => "Litoral",
+    "GQ-WN" => "Provincia de Wele-Nzas",
+    "ER-AN" => "Ansabā",
+    "ER-DU" => "Debubregionen",
+    "ER-MA" => "Al Awsaţ",
+    "EE-205" => "Hiiumaa",
+    "EE-37" => "Harjumaa",
+    "EE-39" => "Hiiumaa",
+    "EE-45" => "Ida-Virumaa",
+    "EE-50" => "Jõgevamaa",
+    "EE-52" => "Järvamaa",
+    "EE-56" => "Läänemaa",
+    "EE-60" => "Lääne-Virumaa",
+    "EE-64" => "Põlvamaa",
+    "EE-68" => "Pärnumaa",
+    "EE-71" => "Raplamaa",
+    "EE-714" => "Saaremaa",
+    "EE-74" => "Saaremaa",
+    "EE-79" => "Tartumaa",
+    "EE-81" => "Valgamaa",
+    "EE-84" => "Viljandimaa",
+    "EE-87" => "Võrumaa",
+    "SZ-HH" => "Hhohho",
+    "SZ-LU" => "Lubombo",
+    "SZ-MA" => "Manzini",
+    "SZ-SH" => "Shiselweni",
+    "FJ-C" => "Central",
+    "FJ-E" => "Östra",
+    "FJ-N" => "Norra",
+    "FJ-R" => "Rotuma",
+    "FJ-W" => "Västra",
+    "FI-01" => "Åland",
+    "FI-02" => "Södra Karelen",
+    "FI-03" => "Södra Österbotten",
+    "FI-04" => "Södra Savolax",
+    "FI-05" => "Kajanaland",
+    "FI-06" => "Egentliga Tavastland",
+    "FI-07" => "Mellersta Österbotten",
+    "FI-08" => "Mellersta Finland",
+    "FI-09" => "Kymmenedalen",
+    "FI-10" => "Lappland",
+    "FI-11" => "Birkaland",
+    "FI-12" => "Österbotten",
+    "FI-13" => "Norra Karelen",
+    "FI-14" => "Norra Österbotten",
+    "FI-15" => "Norra Savolax",
+    "FI-16" => "Päijänne-Tavastland",
+    "FI-17" => "Satakunta",
+    "FI-18" => "Nyland",
+    "FI-19" => "Egentliga Finland",
+    "FR-01" => "Ain",
+    "FR-02" => "Aisne",
+    "FR-03" => "Allier",
+    "FR-04" => "Alpes-de-Haute-Provence",
+    "FR-05" => "Hautes-Alpes",
+    "FR-06" => "Alpes-Maritimes",
+    "FR-07" => "Ardèche",
+    "FR-08" => "Ardennerna",
+    "FR-09" => "Ariège",
+    "FR-10" => "Aube",
+    "FR-11" => "Aude",
+    "FR-12" => "Aveyron",
+    "FR-13" => "Bouches-du-Rhône",
+    "FR-14" => "Calvados",
+    "FR-15" => "Cantal",
+    "FR-16" => "Charente",
+    "FR-17" => "Charente-Maritime",
+    "FR-18" => "Cher",
+    "FR-19" => "Corrèze",
+    "FR-20R" => "Korsika",
+    "FR-21" => "Côte-d'Or",
+    "FR-22" => "Côtes-d'Armor",
+    "FR-23" => "Creuse",
+    "FR-24" => "Dordogne",
+    "FR-25" => "Doubs",
+    "FR-26" => "Drôme",
+    "FR-27" => "Eure",
+    "FR-28" => "Eure-et-Loir",
+    "FR-29" => "Finistère",
+    "FR-2A" => "Corse-du-Sud",
+    "FR-2B" => "Haute-Corse",
+    "FR-30" => "Gard",
+    "FR-31" => "Haute-Garonne",
+    "FR-32" => "Gers",
+    "FR-33" => "Gironde",
+    "FR-34" => "Hérault",
+    "FR-35" => "Ille-et-Vilaine",
+    "FR-36" => "Indre",
+    "FR-37" => "Indre-et-Loire",
+    "FR-38" => "Isère",
+    "FR-39" => "Jura",
+    "FR-40" => "Landes",
+    "FR-41" => "Loir-et-Cher",
+    "FR-42" => "Loire",
+    "FR-43" => "Haute-Loire",
+    "FR-44" => "Loire-Atlantique",
+    "FR-45" => "Loiret",
+    "FR-46" => "Lot",
+    "FR-47" => "Lot-et-Garonne",
+    "FR-48" => "Lozère",
+    "FR-49" => "Maine-et-Loire",
+    "FR-50" => "Manche",
+    "FR-51" => "Marne",
+    "FR-52" => "Haute-Marne",
+    "FR-53" => "Mayenne",
+    "FR-54" => "Meurthe-et-Moselle",
+    "FR-55" => "Meuse",
+    "FR-56" => "Morbihan",
+    "FR-57" => "Moselle",
+    "FR-58" => "Nièvre",
+    "FR-59" => "Nord",
+    "FR-60" => "Oise",
+    "FR-61" => "Orne",
+    "FR-62" => "Pas-de-Calais",
+    "FR-63" => "Puy-de-Dôme",
+    "FR-64" => "Pyrénées-Atlantiques",
+    "FR-65" => "Hautes-Pyrénées",
+    "FR-66" => "Pyrénées-Orientales",
+    "FR-67" => "Bas-Rhin",
+    "FR-68" => "Haut-Rhin",
+    "FR-69" => "Rhône",
+    "FR-70" => "Haute-Saône",
+    "FR-71" => "Saône-et-Loire",
+    "FR-72" => "Sarthe",
+    "FR-73" => "Savoie",
+    "FR-74" => "Haute-Savoie",
+    "FR-76" => "Seine-Maritime",
+    "FR-77" => "Seine-et-Marne",
+    "FR-78" => "Yvelines",
+    "FR-79" => "Deux-Sèvres",
+    "FR-80" => "Somme",
+    "FR-81" => "Tarn",
+    "FR-82" => "Tarn-et-Garonne",
+    "FR-83" => "Var",
+    "FR-84" => "Vaucluse",
+    "FR-85" => "Vendée",
+    "FR-86" => "Vienne",
+    "FR-87" => "Haute-Vienne",
+    "FR-88" => "Vosges",
+    "FR-89" => "Yonne",
+    "FR-90" => "Territoire de Belfort",
+    "FR-91" => "Essonne",
+    "FR-92" => "Hauts-de-Seine",
+    "FR-93" => "Seine-Saint-Denis",
+    "FR-94" => "Val-de-Marne",
+    "FR-95" => "Val-d'Oise",
+    "FR-971" => "Guadeloupe",
+    "FR-972" => "Martinique",
+    "FR-973" => "Franska Guyana",
+    "FR-974" => "Réunion",
+    "FR-976" => "Mayotte",
+    "FR-ARA" => "Auvergne-Rhône-Alpes",
+    "FR-BFC" => "Bourgogne-Franche-Comté",
+    "FR-BL" => "Saint-Barthélemy",
+    "FR-BRE" => "Bretagne",
+    "FR-CP" => "Clipperton",
+    "FR-CVL" => "Centre-Val de Loire",
+    "FR-GES" => "Grand-Est",
+    "FR-HDF" => "Hauts-de-France",
+    "FR-IDF" => "Île-de-France",
+    "FR-MF" => "Saint-Martin",
+    "FR-NAQ" => "Nouvelle-Aquitaine",
+    "FR-NC" => "Nouvelle-Calédonie",
+    "FR-NOR" => "Normandie",
+    "FR-OCC" => "Occitanien",
+    "FR-PAC" => "Provence-Alpes-Côte-d’Azur",
+    "FR-PDL" => "Pays-de-la-Loire",
+    "FR-PF" => "Franska polynesien",
+    "FR-PM" => "Saint-Pierre-et-Miquelon",
+    "FR-TF" => "Franska sydterritorierna",
+    "FR-WF" => "Wallis- och Futunaöarna",
+    "GA-1" => "Estuaire",
+    "GA-2" => "Haut-Ogooué",
+    "GA-3" => "Moyen-Ogooué",
+    "GA-4" => "Ngounié",
+    "GA-5" => "Nyanga",
+    "GA-6" => "Ogooué-Ivindo",
+    "GA-7" => "Ogooué-Lolo",
+    "GA-8" => "Ogooué-Maritime",
+    "GA-9" => "Woleu-Ntem",
+    "GM-B" => "Banjul",
+    "GM-L" => "Lower River",
+    "GM-M" => "Central River",
+    "GM-N" => "North Bank",
+    "GM-U" => "Upper River",
+    "GM-W" => "Västra",
+    "GE-AB" => "Abchazien",
+    "GE-AJ" => "Adzjarien",
+    "GE-GU" => "Gurien",
+    "DE-BB" => "Brandenburg",
+    "DE-BE" => "Berlin",
+    "DE-BW" => "Baden-Württemberg",
+    "DE-BY" => "Bayern",
+    "DE-HB" => "Bremen",
+    "DE-HE" => "Hessen",
+    "DE-HH" => "Hamburg",
+    "DE-MV" => "Mecklenburg-Vorpommern",
+    "DE-NI" => "Niedersachsen",
+    "DE-NW" => "Nordrhein-Westfalen",
+    "DE-RP" => "Rheinland-Pfalz",
+    "DE-SH" => "Schleswig-Holstein",
+    "DE-SL" => "Saarland",
+    "DE-SN" => "Sachsen",
+    "DE-ST" => "Sachsen-Anhalt",
+    "DE-TH" => "Thüringen",
+    "GH-AA" => "Storaccraregionen",
+    "GH-AH" => "Ashantiregionen",
+    "GH-CP" => "Central",
+    "GH-EP" => "Östra",
+    "GH-NE" => "Nordöst",
+    "GH-NP" => "Norra",
+    "GH-TV" => "Voltaregionen",
+    "GH-UE" => "Övre östregionen",
+    "GH-UW" => "Övre västregionen",
+    "GH-WP" => "Västra",
+    "GL-KU" => "Kujalleq",
+    "GL-QE" => "Qeqqata",
+    "GL-SM" => "Sermersooq",
+    "GD-01" => "Saint Andrew",
+    "GD-02" => "Saint David",
+    "GD-03" => "Saint George",
+    "GD-04" => "Saint John",
+    "GD-05" => "Saint Mark",
+    "GD-06" => "Saint Patrick",
+    "GD-10" => "Södra Grenadinöarna",
+    "GN-B" => "Boké",
+    "GN-BE" => "Beyla",
+    "GN-BF" => "Boffa",
+    "GN-BK" => "Boké",
+    "GN-C" => "Conakry",
+    "GN-CO" => "Coyah",
+    "GN-D" => "Kindia",
+    "GN-DB" => "Dabola",
+    "GN-DI" => "Dinguiraye",
+    "GN-DL" => "Dalaba",
+    "GN-DU" => "Dubréka",
+    "GN-F" => "Faranah",
+    "GN-FA" => "Faranah",
+    "GN-FO" => "Forécariah",
+    "GN-FR" => "Fria",
+    "GN-GA" => "Gaoual",
+    "GN-GU" => "Guékédou",
+    "GN-K" => "Kankan",
+    "GN-KA" => "Kankan",
+    "GN-KB" => "Koubia",
+    "GN-KD" => "Kindia",
+    "GN-KE" => "Kérouané",
+    "GN-KN" => "Koundara",
+    "GN-KO" => "Kouroussa",
+    "GN-KS" => "Kissidougou",
+    "GN-L" => "Labé",
+    "GN-LA" => "Labé",
+    "GN-LE" => "Lélouma",
+    "GN-LO" => "Lola",
+    "GN-M" => "Mamou",
+    "GN-MC" => "Macenta",
+    "GN-MD" => "Mandiana",
+    "GN-ML" => "Mali",
+    "GN-MM" => "Mamou",
+    "GN-N" => "Nzérékoré",
+    "GN-NZ" => "Nzérékoré",
+    "GN-PI" => "Pita",
+    "GN-SI" => "Siguiri",
+    "GN-TE" => "Télimélé",
+    "GN-TO" => "Tougué",
+    "GN-YO" => "Yomou",
+    "GW-BA" => "Bafatá",
+    "GW-BM" => "Biombo",
+    "GW-BS" => "Bissau",
+    "GW-CA" => "Cacheu",
+    "GW-GA" => "Gabú",
+    "GW-L" => "Leste",
+    "GW-N" => "Norte",
+    "GW-OI" => "Oio",
+    "GW-QU" => "Quinara",
+    "GW-S" => "Sul",
+    "GW-TO" => "Tombali",
+    "GY-BA" => "Barima-Waini",
+    "GY-CU" => "Cuyuni-Mazaruni",
+    "GY-DE" => "Demerara-Mahaica",
+    "GY-EB" => "East Berbice-Corentyne",
+    "GY-ES" => "Essequiboöarna - Västra Demerara",
+    "GY-MA" => "Mahaica-Berbice",
+    "GY-PM" => "Pomeroon-Supenaam",
+    "GY-PT" => "Potaro-Siparuni",
+    "GY-UD" => "Övre Demerara-Berbice",
+    "GY-UT" => "Övre Takutu - Övre Essequibo",
+    "HT-AR" => "Artibonite",
+    "HT-CE" => "Centre",
+    "HT-ND" => "Nord",
+    "HT-NE" => "Nord-Est",
+    "HT-NO" => "Nord-Ouest",
+    "HN-AT" => "Atlántida",
+    "HN-CH" => "Choluteca",
+    "HN-CL" => "Colón",
+    "HN-CM" => "Comayagua",
+    "HN-CP" => "Copán",
+    "HN-CR" => "Cortés",
+    "HN-EP" => "El Paraíso",
+    "HN-FM" => "Francisco Morazán",
+    "HN-GD" => "Gracias a Dios",
+    "HN-IB" => "Bahíaöarna",
+    "HN-IN" => "Intibucá",
+    "HN-LE" => "Lempira",
+    "HN-LP" => "La Paz",
+    "HN-OC" => "Ocotepeque",
+    "HN-OL" => "Olancho",
+    "HN-SB" => "Santa Bárbara",
+    "HN-VA" => "Valle",
+    "HN-YO" => "Yoro",
+    "HU-BA" => "Baranya",
+    "HU-BC" => "Békéscsaba",
+    "HU-BE" => "Békés",
+    "HU-BK" => "Bács-Kiskun",
+    "HU-BU" => "Budapest",
+    "HU-BZ" => "Borsod-Abaúj-Zemplén",
+    "HU-CS" => "Csongrád",
+    "HU-DE" => "Debrecen",
+    "HU-DU" => "Dunaújváros",
+    "HU-EG" => "Eger",
+    "HU-ER" => "Érd",
+    "HU-FE" => "Fejér",
+    "HU-GS" => "Győr-Moson-Sopron",
+    "HU-GY" => "Győr",
+    "HU-HB" => "Hajdú-Bihar",
+    "HU-HE" => "Heves",
+    "HU-HV" => "Hódmezővásárhely",
+    "HU-JN" => "Jász-Nagykun-Szolnok",
+    "HU-KE" => "Komárom-Esztergom",
+    "HU-KM" => "Kecskemet",
+    "HU-KV" => "Kaposvár",
+    "HU-MI" => "Miskolc",
+    "HU-NK" => "Nagykanizsa",
+    "HU-NO" => "Nógrád",
+    "HU-NY" => "Nyíregyháza",
+    "HU-PE" => "Pest",
+    "HU-PS" => "Pécs",
+    "HU-SD" => "Szeged",
+    "HU-SF" => "Székesfehérvár",
+    "HU-SH" => "Szombathely",
+    "HU-SK" => "Szolnok",
+    "HU-SN" => "Sopron",
+    "HU-SO" => "Somogy",
+    "HU-SS" => "Szekszárd",
+    "HU-ST" => "Salgótarján",
+    "HU-SZ" => "Szabolcs-Szatmár-Bereg",
+    "HU-TB" => "Tatabánya",
+    "HU-TO" => "Tolna",
+    "HU-VA" => "Vas",
+    "HU-VE" => "Veszprém",
+    "HU-VM" => "Veszprém",
+    "HU-ZA" => "Zala",
+    "HU-ZE" => "Zalaegerszeg",
+    "IS-2" => "Suðurnes",
+    "IS-3" => "Västlandet",
+    "IS-4" => "Västfjordarna",
+    "IS-5" => "Norðurland vestra",
+    "IS-6" => "Norðurland eystra",
+    "IS-7" => "Austurland",
+    "IS-8" => "Suðurland",
+    "IN-AN" => "Andamanerna och Nikobarerna",
+    "IN-AP" => "Andhra Pradesh",
+    "IN-AS" => "Assam",
+    "IN-DL" => "Delhi",
+    "IN-GA" => "Goa",
+    "IN-KL" => "Kerala",
+    "IN-LD" => "Lakshadweep",
+    "IN-MN" => "Manipur",
+    "IN-MP" => "Madhya Pradesh",
+    "IN-MZ" => "Mizoram",
+    "IN-PB" => "Punjab",
+    "IN-PY" => "Puducherry",
+    "IN-SK" => "Sikkim",
+    "IN-TR" => "Tripura",
+    "IN-UP" => "Uttar Pradesh",
+    "IN-WB" => "Västbengalen",
+    "ID-AC" => "Aceh",
+    "ID-BA" => "Bali",
+    "ID-BE" => "Bengkulu",
+    "ID-BT" => "Banten",
+    "ID-GO" => "Gorontalo",
+    "ID-JA" => "Jambi",
+    "ID-JB" => "Jawa Barat",
+    "ID-JI" => "Jawa Timur",
+    "ID-JK" => "Jakarta Raya",
+    "ID-JT" => "Jawa Tengah",
+    "ID-JW" => "Jawa",
+    "ID-KA" => "Kalimantan",
+    "ID-KB" => "Kalimantan Barat",
+    "ID-KI" => "Kalimantan Timur",
+    "ID-KR" => "Kepulauan Riau",
+    "ID-KS" => "Kalimantan Selatan",
+    "ID-KT" => "Kalimantan Tengah",
+    "ID-LA" => "Lampung",
+    "ID-MA" => "Maluku",
+    "ID-ML" => "Maluku",
+    "ID-MU" => "Maluku Utara",
+    "ID-NB" => "Nusa Tenggara Barat",
+    "ID-NT" => "Nusa Tenggara Timur",
+    "ID-NU" => "Nusa Tenggara",
+    "ID-PA" => "Papua",
+    "ID-PB" => "Papua Barat",
+    "ID-PP" => "Papua",
+    "ID-RI" => "Riau",
+    "ID-SA" => "Sulawesi Utara",
+    "ID-SG" => "Sulawesi Tenggara",
+    "ID-SL" => "Sulawesi",
+    "ID-SM" => "Sumatera",
+    "ID-SN" => "Sulawesi Selatan",
+    "ID-SR" => "Sulawesi Barat",
+    "ID-ST" => "Sulawesi Tengah",
+    "ID-SU" => "Sumatera Utara",
+    "ID-YO" => "Yogyakarta",
+    "IR-00" => "Markazī",
+    "IR-01" => "Gilan",
+    "IR-02" => "Mazandaran",
+    "IR-05" => "Kermanshah",
+    "IR-06" => "Khuzestan",
+    "IR-07" => "Fars",
+    "IR-08" => "Kerman",
+    "IR-10" => "Esfahan",
+    "IR-11" => "Sistan och Baluchistan",
+    "IR-12" => "Kurdistan",
+    "IR-13" => "Hamadan",
+    "IR-15" => "Lorestan",
+    "IR-16" => "Īlam",
+    "IR-18" => "Bushehr",
+    "IR-19" => "Zanjan",
+    "IR-20" => "Semnan",
+    "IR-21" => "Yazd",
+    "IR-22" => "Hormozgan",
+    "IR-23" => "Tehran",
+    "IR-24" => "Ardabīl",
+    "IR-25" => "Qom",
+    "IR-26" => "Qazvīn",
+    "IR-27" => "Golestan",
+    "IE-CE" => "Clare",
+    "IE-CN" => "Cavan",
+    "IE-CO" => "Cork",
+    "IE-CW" => "Carlow",
+    "IE-D" => "Dublin",
+    "IE-DL" => "Donegal",
+    "IE-G" => "Galway",
+    "IE-KE" => "Kildare",
+    "IE-KK" => "Kilkenny",
+    "IE-KY" => "Kerry",
+    "IE-L" => "Leinster",
+    "IE-LD" => "Longford",
+    "IE-LH" => "Louth",
+    "IE-LK" => "Limerick",
+    "IE-LM" => "Leitrim",
+    "IE-LS" => "Laois",
+    "IE-M" => "Munster",
+    "IE-MH" => "Meath",
+    "IE-MN" => "Monaghan",
+    "IE-MO" => "Mayo",
+    "IE-OY" => "Offaly",
+    "IE-RN" => "Roscommon",
+    "IE-SO" => "Sligo",
+    "IE-TA" => "Tipperary",
+    "IE-U" => "Ulster",
+    "IE-WD" => "Waterford",
+    "IE-WH" => "Westmeath",
+    "IE-WW" => "Wicklow",
+    "IE-WX" => "Wexford",
+    "IL-D" => "Debubregionen",
+    "IL-M" => "Al Awsaţ",
+    "IT-21" => "Piemonte",
+    "IT-25" => "Lombardiet",
+    "IT-32" => "Trentino-Alto Adige",
+    "IT-34" => "Veneto",
+    "IT-42" => "Ligurien",
+    "IT-45" => "Emilia-Romagna",
+    "IT-52" => "Toscana",
+    "IT-55" => "Umbrien",
+    "IT-57" => "Marche",
+    "IT-62" => "Lazio",
+    "IT-65" => "Abruzzo",
+    "IT-67" => "Molise",
+    "IT-72" => "Kampanien",
+    "IT-75" => "Puglia",
+    "IT-77" => "Basilicata",
+    "IT-78" => "Kalabrien",
+    "IT-82" => "Sicilien",
+    "IT-88" => "Sardinien",
+    "IT-AG" => "Agrigento",
+    "IT-AL" => "Alessandria",
+    "IT-AN" => "Ancona",
+    "IT-AP" => "Ascoli Piceno",
+    "IT-AQ" => "L'Aquila",
+    "IT-AR" => "Arezzo",
+    "IT-AT" => "Asti",
+    "IT-AV" => "Avellino",
+    "IT-BA" => "Bari",
+    "IT-BG" => "Bergamo",
+    "IT-BI" => "Biella",
+    "IT-BL" => "Belluno",
+    "IT-BN" => "Benevento",
+    "IT-BO" => "Bologna",
+    "IT-BR" => "Brindisi",
+    "IT-BS" => "Brescia",
+    "IT-BT" => "Barletta-Andria-Trani",
+    "IT-BZ" => "Bolzano",
+    "IT-CA" => "Cagliari",
+    "IT-CB" => "Campobasso",
+    "IT-CE" => "Caserta",
+    "IT-CH" => "Chieti",
+    "IT-CL" => "Caltanissetta",
+    "IT-CN" => "Cuneo",
+    "IT-CO" => "Como",
+    "IT-CR" => "Cremona",
+    "IT-CS" => "Cosenza",
+    "IT-CT" => "Catania",
+    "IT-CZ" => "Catanzaro",
+    "IT-EN" => "Enna",
+    "IT-FC" => "Forli-Cesenca",
+    "IT-FE" => "Ferrara",
+    "IT-FG" => "Foggia",
+    "IT-FI" => "Florens",
+    "IT-FM" => "Fermo",
+    "IT-FR" => "Frosinone",
+    "IT-GE" => "Genova",
+    "IT-GO" => "Gorizia",
+    "IT-GR" => "Grosseto",
+    "IT-IM" => "Imperia",
+    "IT-IS" => "Isernia",
+    "IT-KR" => "Crotone",
+    "IT-LC" => "Lecco",
+    "IT-LE" => "Lecce",
+    "IT-LI" => "Livorno",
+    "IT-LO" => "Lodi",
+    "IT-LT" => "Latina",
+    "IT-LU" => "Lucca",
+    "IT-MB" => "Monza e Brianza",
+    "IT-MC" => "Macerata",
+    "IT-ME" => "Messina",
+    "IT-MI" => "Milano",
+    "IT-MN" => "Mantova",
+    "IT-MO" => "Modena",
+    "IT-MS" => "Massa-Carrara",
+    "IT-MT" => "Matera",
+    "IT-NA" => "Napoli",
+    "IT-NO" => "Novara",
+    "IT-NU" => "Nuoro",
+    "IT-OR" => "Oristano",
+    "IT-PA" => "Palermo",
+    "IT-PC" => "Piacenza",
+    "IT-PD" => "Padova",
+    "IT-PE" => "Pescara",
+    "IT-PG" => "Perugia",
+    "IT-PI" => "Pisa",
+    "IT-PN" => "Pordenone",
+    "IT-PO" => "Prato",
+    "IT-PR" => "Parma",
+    "IT-PT" => "Pistoia",
+    "IT-PU" => "Pesaro e Urbino",
+    "IT-PV" => "Pavia",
+    "IT-PZ" => "Potenza",
+    "IT-RA" => "Ravenna",
+    "IT-RC" => "Reggio Calabria",
+    "IT-RE" => "Reggio Emilia",
+    "IT-RG" => "Ragusa",
+    "IT-RI" => "Rieti",
+    "IT-RM" => "Rom",
+    "IT-RN" => "Rimini",
+    "IT-RO" => "Rovigo",
+    "IT-SA" => "Salerno",
+    "IT-SI" => "Siena",
+    "IT-SO" => "Sondrio",
+    "IT-SP" => "La Spezia",
+    "IT-SR" => "Syrakusa",
+    "IT-SS" => "Sassari",
+    "IT-SV" => "Savona",
+    "IT-TA" => "Taranto",
+    "IT-TE" => "Teramo",
+    "IT-TN" => "Trento",
+    "IT-TO" => "Turin",
+    "IT-TP" => "Trapani",
+    "IT-TR" => "Terni",
+    "IT-TS" => "Trieste",
+    "IT-TV" => "Treviso",
+    "IT-UD" => "Udine",
+    "IT-VA" => "Varese",
+    "IT-VB" => "Verbano-Cusio-Ossola",
+    "IT-VC" => "Vercelli",
+    "IT-VE" => "Venedig",
+    "IT-VI" => "Vicenza",
+    "IT-VR" => "Verona",
+    "IT-VT" => "Viterbo",
+    "IT-VV" => "Vibo Valentia",
+    "JM-01" => "Kingston",
+    "JM-02" => "Saint Andrew",
+    "JM-03" => "Saint Thomas",
+    "JM-04" => "Portland",
+    "JM-05" => "Saint Mary",
+    "JM-06" => "Saint Ann",
+    "JM-07" => "Trelawny",
+    "JM-08" => "Saint James",
+    "JM-09" => "Hanover",
+    "JM-10" => "Westmoreland",
+    "JM-11" => "Saint Elizabeth",
+    "JM-12" => "Manchester",
+    "JM-13" => "Clarendon",
+    "JM-14" => "Saint Catherine",
+    "JP-01" => "Hokkaido",
+    "JP-02" => "Aomori",
+    "JP-03" => "Iwate",
+    "JP-04" => "Miyagi",
+    "JP-05" => "Akita",
+    "JP-06" => "Yamagata",
+    "JP-07" => "Fukushima",
+    "JP-08" => "Ibaraki",
+    "JP-09" => "Tochigi",
+    "JP-10" => "Gunma",
+    "JP-11" => "Saitama",
+    "JP-12" => "Chiba",
+    "JP-13" => "Tokyo",
+    "JP-14" => "Kanagawa",
+    "JP-15" => "Niigata",
+    "JP-16" => "Toyama",
+    "JP-17" => "Ishikawa",
+    "JP-18" => "Fukui",
+    "JP-19" => "Yamanashi",
+    "JP-20" => "Nagano",
+    "JP-21" => "Gifu",
+    "JP-22" => "Shizuoka",
+    "JP-23" => "Aichi",
+    "JP-24" => "Mie",
+    "JP-25" => "Shiga",
+    "JP-26" => "Kyoto",
+    "JP-27" => "Osaka",
+    "JP-28" => "Hyogo",
+    "JP-29" => "Nara",
+    "JP-30" => "Wakayama",
+    "JP-31" => "Tottori",
+    "JP-32" => "Shimane",
+    "JP-33" => "Okayama",
+    "JP-34" => "Hiroshima",
+    "JP-35" => "Yamaguchi",
+    "JP-36" => "Tokushima",
+    "JP-37" => "Kagawa",
+    "JP-38" => "Ehime",
+    "JP-39" => "Kochi",
+    "JP-40" => "Fukuoka",
+    "JP-41" => "Saga",
+    "JP-42" => "Nagasaki",
+    "JP-43" => "Kumamoto",
+    "JP-44" => "Oita",
+    "JP-45" => "Miyazaki",
+    "JP-46" => "Kagoshima",
+    "JP-47" => "Okinawa",
+    "JO-AJ" => "Ajlun",
+    "JO-AQ" => "Akaba",
+    "JO-AT" => "Ţafilah",
+    "JO-IR" => "Irbid",
+    "JO-JA" => "Jerash",
+    "JO-KA" => "Karak",
+    "JO-MA" => "Mafraq",
+    "JO-MD" => "Madaba",
+    "JO-MN" => "Ma‘an",
+    "KE-01" => "Baringo",
+    "KE-02" => "Bomet",
+    "KE-03" => "Bungoma",
+    "KE-04" => "Busia",
+    "KE-05" => "Elegeyo/Marakwet",
+    "KE-06" => "Embu",
+    "KE-07" => "Garissa",
+    "KE-08" => "Homa Bay",
+    "KE-09" => "Isiolo",
+    "KE-10" => "Kajiado",
+    "KE-11" => "Kakamega",
+    "KE-12" => "Kericho",
+    "KE-13" => "Kiambu",
+    "KE-14" => "Kilifi",
+    "KE-15" => "Kirinyaga",
+    "KE-16" => "Kisii",
+    "KE-17" => "Kisumu",
+    "KE-18" => "Kitui",
+    "KE-19" => "Kwale",
+    "KE-20" => "Laikipia",
+    "KE-21" => "Lamu",
+    "KE-22" => "Machakos",
+    "KE-23" => "Makueni",
+    "KE-24" => "Mandera",
+    "KE-25" => "Marsabit",
+    "KE-26" => "Meru",
+    "KE-27" => "Migori",
+    "KE-28" => "Mombasa",
+    "KE-29" => "Muranga",
+    "KE-31" => "Nakuru",
+    "KE-32" => "Nandi",
+    "KE-33" => "Narok",
+    "KE-34" => "Nyamira",
+    "KE-35" => "Nyandarua",
+    "KE-36" => "Nyeri",
+    "KE-37" => "Samburu",
+    "KE-38" => "Siaya",
+    "KE-39" => "Taita/Taveta",
+    "KE-40" => "Tana River",
+    "KE-41" => "Tharaka-Nithi",
+    "KE-42" => "Trans-Nzoia",
+    "KE-43" => "Turkana",
+    "KE-44" => "Uasin Gishu",
+    "KE-45" => "Vihiga",
+    "KE-46" => "Wajir",
+    "KE-47" => "Västra Pokot",
+    "KI-G" => "Gilbertöarna",
+    "KI-L" => "Line Islands",
+    "KI-P" => "Phoenixöarna",
+    "KP-04" => "Chagang",
+    "KP-05" => "Södra Hwanghae",
+    "KP-06" => "Norra Hwanghae",
+    "KW-FA" => "Al Farwaniyah",
+    "KW-MU" => "Mubārak al Kabīr",
+    "KG-B" => "Batken",
+    "KG-N" => "Naryn",
+    "KG-O" => "Osj",
+    "KG-T" => "Talas",
+    "LA-AT" => "Attapeu",
+    "LA-BK" => "Bokèo",
+    "LA-BL" => "Bolikhamsai",
+    "LA-CH" => "Champassak",
+    "LA-HO" => "Hua Phan",
+    "LA-KH" => "Khammuan",
+    "LA-LM" => "Louang Nam Tha",
+    "LA-LP" => "Louang Prabang",
+    "LA-OU" => "Udomxai",
+    "LA-PH" => "Phongsali",
+    "LA-SL" => "Saravane",
+    "LA-SV" => "Savannakhet",
+    "LA-XA" => "Sainyabuli",
+    "LA-XE" => "Sekong",
+    "LA-XI" => "Xieng Khouang",
+    "LA-XS" => "Xaisômboun",
+    "LV-002" => "Aizkraukle kommun",
+    "LV-007" => "Alūksne kommun",
+    "LV-011" => "Ādaži kommun",
+    "LV-015" => "Balvi kommun",
+    "LV-016" => "Bauska kommun",
+    "LV-022" => "Cēsis kommun",
+    "LV-026" => "Dobele kommun",
+    "LV-033" => "Gulbene kommun",
+    "LV-041" => "Jelgava kommun",
+    "LV-042" => "Jēkabpil kommun",
+    "LV-047" => "Krāslava kommun",
+    "LV-050" => "Kuldīga kommun",
+    "LV-052" => "Ķekava kommun",
+    "LV-054" => "Limbaži kommun",
+    "LV-056" => "Līvāni kommun",
+    "LV-058" => "Ludza kommun",
+    "LV-059" => "Madona kommun",
+    "LV-062" => "Mārupe kommun",
+    "LV-067" => "Ogre kommun",
+    "LV-068" => "Olaine kommun",
+    "LV-073" => "Preiļi kommun",
+    "LV-077" => "Rēzekne kommun",
+    "LV-080" => "Ropaži kommun",
+    "LV-087" => "Salaspils kommun",
+    "LV-088" => "Saldus kommun",
+    "LV-089" => "Saulkrasti kommun",
+    "LV-091" => "Sigulda kommun",
+    "LV-094" => "Smiltene kommun",
+    "LV-097" => "Talsi kommun",
+    "LV-099" => "Tukums kommun",
+    "LV-101" => "Valka kommun",
+    "LV-102" => "Varakļāni kommun",
+    "LV-106" => "Ventspils kommun",
+    "LV-DGV" => "Daugavpils",
+    "LV-JEL" => "Jelgava",
+    "LV-JUR" => "Jūrmala",
+    "LV-LPX" => "Liepāja",
+    "LV-REZ" => "Rēzekne",
+    "LV-RIX" => "Rīga",
+    "LV-VEN" => "Ventspils",
+    "LB-AK" => "Aakkâr",
+    "LB-BH" => "Baalbek-Hermel",
+    "LS-A" => "Maseru",
+    "LS-C" => "Leribe",
+    "LS-D" => "Berea",
+    "LS-E" => "Mafeteng",
+    "LS-F" => "Mohale's Hoek",
+    "LS-G" => "Quthing",
+    "LS-H" => "Qacha's Nek",
+    "LS-J" => "Mokhotlong",
+    "LS-K" => "Thaba-Tseka",
+    "LR-BG" => "Bong",
+    "LR-BM" => "Bomi",
+    "LR-CM" => "Grand Cape Mount",
+    "LR-GB" => "Grand Bassa",
+    "LR-GG" => "Grand Gedeh",
+    "LR-GK" => "Grand Kru",
+    "LR-LO" => "Lofa",
+    "LR-MG" => "Margibi",
+    "LR-MO" => "Montserrado",
+    "LR-MY" => "Maryland",
+    "LR-NI" => "Nimba",
+    "LR-SI" => "Sinoe",
+    "LY-BA" => "Benghazī",
+    "LY-BU" => "Al Buţnan",
+    "LY-DR" => "Darnah",
+    "LY-GT" => "Ghat",
+    "LY-JA" => "Al Jabal al Akhdar",
+    "LY-JG" => "Al Jabal al Gharbi",
+    "LY-JU" => "Al Jufrah",
+    "LY-KF" => "Al Kufrah",
+    "LY-MB" => "Al Murgub",
+    "LY-MI" => "Misratah",
+    "LY-MJ" => "Al Marj",
+    "LY-MQ" => "Murzuq",
+    "LY-NL" => "Nalut",
+    "LY-SB" => "Sabha",
+    "LY-SR" => "Surt",
+    "LY-TB" => "Tarabulus",
+    "LY-WD" => "Wadi al Hayaa",
+    "LY-ZA" => "Az Zāwiyah",
+    "LI-01" => "Balzers",
+    "LI-02" => "Eschen",
+    "LI-03" => "Gamprin",
+    "LI-04" => "Mauren",
+    "LI-05" => "Planken",
+    "LI-06" => "Ruggell",
+    "LI-07" => "Schaan",
+    "LI-08" => "Schellenberg",
+    "LI-09" => "Triesen",
+    "LI-10" => "Triesenberg",
+    "LI-11" => "Vaduz",
+    "LU-DI" => "Diekirch",
+    "LU-GR" => "Grevenmacher",
+    "LU-LU" => "Luxemburg",
+    "MG-A" => "Toamasina",
+    "MG-D" => "Antsiranana",
+    "MG-F" => "Fianarantsoa",
+    "MG-M" => "Mahajanga",
+    "MG-T" => "Antananarivo",
+    "MG-U" => "Toliara",
+    "MW-BA" => "Balaka",
+    "MW-BL" => "Blantyre",
+    "MW-C" => "Centrala regionen",
+    "MW-CK" => "Chikwawa",
+    "MW-CR" => "Chiradzulu",
+    "MW-CT" => "Chitipa",
+    "MW-DE" => "Dedza",
+    "MW-DO" => "Dowa",
+    "MW-KR" => "Karonga",
+    "MW-KS" => "Kasungu",
+    "MW-LI" => "Lilongwe",
+    "MW-LK" => "Likoma",
+    "MW-MC" => "Mchinji",
+    "MW-MG" => "Mangochi",
+    "MW-MH" => "Machinga",
+    "MW-MU" => "Mulanje",
+    "MW-MW" => "Mwanza",
+    "MW-MZ" => "Mzimba",
+    "MW-N" => "Norra regionen",
+    "MW-NB" => "Nkhata Bay",
+    "MW-NE" => "Neno",
+    "MW-NI" => "Ntchisi",
+    "MW-NK" => "Nkhotakota",
+    "MW-NS" => "Nsanje",
+    "MW-NU" => "Ntcheu",
+    "MW-PH" => "Phalombe",
+    "MW-RU" => "Rumphi",
+    "MW-S" => "Södra regionen",
+    "MW-SA" => "Salima",
+    "MW-TH" => "Thyolo",
+    "MW-ZO" => "Zomba",
+    "MY-01" => "Johor",
+    "MY-02" => "Kedah",
+    "MY-03" => "Kelantan",
+    "MY-04" => "Melaka",
+    "MY-05" => "Negeri Sembilan",
+    "MY-06" => "Pahang",
+    "MY-07" => "Pulau Pinang",
+    "MY-08" => "Perak",
+    "MY-09" => "Perlis",
+    "MY-10" => "Selangor",
+    "MY-11" => "Terengganu",
+    "MY-12" => "Sabah",
+    "MY-13" => "Sarawak",
+    "MY-14" => "Kuala Lumpur (territorium)",
+    "MY-15" => "Labuan (territorium)",
+    "MY-16" => "Putrajaya (territorium)",
+    "MV-MLE" => "Male",
+    "ML-1" => "Kayes",
+    "ML-2" => "Koulikoro",
+    "ML-3" => "Sikasso",
+    "ML-4" => "Ségou",
+    "ML-5" => "Mopti",
+    "ML-6" => "Tombouctou",
+    "ML-7" => "Gao",
+    "ML-8" => "Kidal",
+    "ML-BKO" => "Bamako",
+    "MT-01" => "Attard",
+    "MT-02" => "Balzan",
+    "MT-03" => "Birgu",
+    "MT-04" => "Birkirkara",
+    "MT-05" => "Birżebbuġa",
+    "MT-06" => "Bormla",
+    "MT-07" => "Dingli",
+    "MT-08" => "Fgura",
+    "MT-09" => "Floriana",
+    "MT-10" => "Fontana",
+    "MT-11" => "Gudja",
+    "MT-12" => "Gżira",
+    "MT-13" => "Għajnsielem",
+    "MT-14" => "Għarb",
+    "MT-15" => "Għargħur",
+    "MT-16" => "Għasri",
+    "MT-17" => "Għaxaq",
+    "MT-18" => "Ħamrun",
+    "MT-19" => "Iklin",
+    "MT-20" => "Isla",
+    "MT-21" => "Kalkara",
+    "MT-22" => "Kerċem",
+    "MT-23" => "Kirkop",
+    "MT-24" => "Lija",
+    "MT-25" => "Luqa",
+    "MT-26" => "Marsa",
+    "MT-27" => "Marsaskala",
+    "MT-28" => "Marsaxlokk",
+    "MT-29" => "Mdina",
+    "MT-30" => "Mellieħa",
+    "MT-31" => "Mġarr",
+    "MT-32" => "Mosta",
+    "MT-33" => "Mqabba",
+    "MT-34" => "Msida",
+    "MT-35" => "Mtarfa",
+    "MT-36" => "Munxar",
+    "MT-37" => "Nadur",
+    "MT-38" => "Naxxar",
+    "MT-39" => "Paola",
+    "MT-40" => "Pembroke",
+    "MT-41" => "Pietà",
+    "MT-42" => "Qala",
+    "MT-43" => "Qormi",
+    "MT-44" => "Qrendi",
+    "MT-46" => "Rabat Malta",
+    "MT-47" => "Safi",
+    "MT-49" => "Saint John",
+    "MT-52" => "Sannat",
+    "MT-54" => "Santa Venera",
+    "MT-55" => "Siġġiewi",
+    "MT-56" => "Sliema",
+    "MT-57" => "Swieqi",
+    "MT-59" => "Tarxien",
+    "MT-60" => "Valletta",
+    "MT-61" => "Xagħra",
+    "MT-62" => "Xewkija",
+    "MT-63" => "Xgħajra",
+    "MT-64" => "Żabbar",
+    "MT-66" => "Żebbuġ Malta",
+    "MT-67" => "Żejtun",
+    "MT-68" => "Żurrieq",
+    "MH-ALK" => "Ailuk",
+    "MH-ALL" => "Ailinglaplap",
+    "MH-ARN" => "Arno",
+    "MH-AUR" => "Aur",
+    "MH-EBO" => "Ebon",
+    "MH-JAB" => "Jabat",
+    "MH-JAL" => "Jaluit",
+    "MH-KWA" => "Kwajalein",
+    "MH-L" => "Ralik chain",
+    "MH-LAE" => "Lae",
+    "MH-LIB" => "Lib",
+    "MH-LIK" => "Likiep",
+    "MH-MAJ" => "Majuro",
+    "MH-MAL" => "Maloelap",
+    "MH-MEJ" => "Mejit",
+    "MH-MIL" => "Mili",
+    "MH-NMK" => "Namdrik",
+    "MH-NMU" => "Namu",
+    "MH-RON" => "Rongelap",
+    "MH-T" => "Ratak chain",
+    "MH-UJA" => "Ujae",
+    "MH-WTH" => "Wotho",
+    "MH-WTJ" => "Wotje",
+    "MR-01" => "Hodh ech Chargui",
+    "MR-03" => "Assaba",
+    "MR-04" => "Gorgol",
+    "MR-05" => "Brakna",
+    "MR-06" => "Trarza",
+    "MR-07" => "Adrar",
+    "MR-09" => "Tagant",
+    "MR-10" => "Guidimaka",
+    "MR-11" => "Tiris Zemmour",
+    "MR-12" => "Inchiri",
+    "MU-AG" => "Agalegaöarna",
+    "MU-BL" => "Black River",
+    "MU-CC" => "Cargados Carajos Shoals",
+    "MU-FL" => "Flacq",
+    "MU-GP" => "Grand Port",
+    "MU-MO" => "Moka",
+    "MU-PA" => "Pamplemousses",
+    "MU-PL" => "Port Louis",
+    "MU-PW" => "Plaines Wilhems",
+    "MU-RO" => "Rodriguesön",
+    "MU-RR" => "Rivière du Rempart",
+    "MU-SA" => "Savanne",
+    "MX-AGU" => "Aguascalientes",
+    "MX-BCN" => "Baja California",
+    "MX-BCS" => "Baja California Sur",
+    "MX-CAM" => "Campeche",
+    "MX-CHH" => "Chihuahua",
+    "MX-CHP" => "Chiapas",
+    "MX-CMX" => "Mexico City",
+    "MX-COA" => "Coahuila",
+    "MX-COL" => "Colima",
+    "MX-DUR" => "Durango",
+    "MX-GRO" => "Guerrero",
+    "MX-GUA" => "Guanajuato",
+    "MX-HID" => "Hidalgo",
+    "MX-JAL" => "Jalisco",
+    "MX-MEX" => "Mexiko",
+    "MX-MIC" => "Michoacán",
+    "MX-MOR" => "Morelos",
+    "MX-NAY" => "Nayarit",
+    "MX-NLE" => "Nuevo León",
+    "MX-OAX" => "Oaxaca",
+    "MX-PUE" => "Puebla",
+    "MX-QUE" => "Querétaro",
+    "MX-ROO" => "Quintana Roo",
+    "MX-SIN" => "Sinaloa",
+    "MX-SLP" => "San Luis Potosí",
+    "MX-SON" => "Sonora",
+    "MX-TAB" => "Tabasco",
+    "MX-TAM" => "Tamaulipas",
+    "MX-TLA" => "Tlaxcala",
+    "MX-VER" => "Veracruz",
+    "MX-YUC" => "Yucatán",
+    "MX-ZAC" => "Zacatecas",
+    "FM-KSA" => "Kosrae",
+    "FM-PNI" => "Pohnpei",
+    "FM-TRK" => "Chuuk",
+    "FM-YAP" => "Yap",
+    "MD-AN" => "Anenii Noi",
+    "MD-BA" => "Bălți",
+    "MD-BR" => "Briceni",
+    "MD-BS" => "Basarabeasca",
+    "MD-CA" => "Cahul",
+    "MD-CL" => "Călărași",
+    "MD-CM" => "Cimișlia",
+    "MD-CR" => "Criuleni",
+    "MD-CS" => "Căușeni",
+    "MD-CT" => "Cantemir",
+    "MD-CU" => "Chișinău",
+    "MD-DO" => "Dondușeni",
+    "MD-DR" => "Drochia",
+    "MD-DU" => "Dubăsari",
+    "MD-ED" => "Edineț",
+    "MD-FA" => "Fălești",
+    "MD-FL" => "Florești",
+    "MD-GL" => "Glodeni",
+    "MD-HI" => "Hîncești",
+    "MD-IA" => "Ialoveni",
+    "MD-LE" => "Leova",
+    "MD-NI" => "Nisporeni",
+    "MD-OC" => "Ocnița",
+    "MD-OR" => "Orhei",
+    "MD-RE" => "Rezina",
+    "MD-RI" => "Rîșcani",
+    "MD-SD" => "Șoldănești",
+    "MD-SI" => "Sîngerei",
+    "MD-SN" => "Transnistrien",
+    "MD-SO" => "Soroca",
+    "MD-ST" => "Strășeni",
+    "MD-SV" => "Ștefan Vodă",
+    "MD-TA" => "Taraclia",
+    "MD-TE" => "Telenești",
+    "MD-UN" => "Ungheni",
+    "MC-CL" => "La Colle",
+    "MC-CO" => "La Condamine",
+    "MC-FO" => "Fontvieille",
+    "MC-GA" => "La Gare",
+    "MC-JE" => "Jardin Exotique",
+    "MC-LA" => "Larvotto",
+    "MC-MA" => "Malbousquet",
+    "MC-MC" => "Monte-Carlo",
+    "MC-MG" => "Moneghetti",
+    "MC-MO" => "Monaco-Ville",
+    "MC-MU" => "Moulins",
+    "MC-PH" => "Port-Hercule",
+    "MC-SD" => "Sainte-Dévote",
+    "MC-SO" => "La Source",
+    "MC-SP" => "Spélugues",
+    "MC-SR" => "Saint-Roman",
+    "MC-VR" => "Vallon de la Rousse",
+    "MN-035" => "Orhon",
+    "MN-037" => "Darhan uul",
+    "MN-039" => "Chentij",
+    "MN-041" => "Chövsgöl",
+    "MN-043" => "Chovd",
+    "MN-046" => "Uvs",
+    "MN-047" => "Töv",
+    "MN-049" => "Selenge",
+    "MN-051" => "Sühbaatar",
+    "MN-055" => "Övörhangay",
+    "MN-057" => "Dzavhan",
+    "MN-061" => "Dornod",
+    "MN-067" => "Bulgan",
+    "MN-069" => "Bajanchongor",
+    "MN-071" => "Bajan-Ölgij",
+    "MN-073" => "Arhangay",
+    "ME-01" => "Andrijevica",
+    "ME-02" => "Bar",
+    "ME-03" => "Berane",
+    "ME-04" => "Bijelo Polje",
+    "ME-05" => "Budva",
+    "ME-06" => "Cetinje",
+    "ME-07" => "Danilovgrad",
+    "ME-08" => "Herceg-Novi",
+    "ME-09" => "Kolašin",
+    "ME-10" => "Kotor",
+    "ME-11" => "Mojkovac",
+    "ME-12" => "Nikšić",
+    "ME-13" => "Plav",
+    "ME-14" => "Pljevlja",
+    "ME-15" => "Plužine",
+    "ME-16" => "Podgorica",
+    "ME-17" => "Rožaje",
+    "ME-18" => "Šavnik",
+    "ME-19" => "Tivat",
+    "ME-20" => "Ulcinj",
+    "ME-21" => "Žabljak",
+    "MA-01" => "Tanger-Tétouan-Al Hoceïma",
+    "MA-02" => "L'Oriental",
+    "MA-03" => "Fès-Meknès",
+    "MA-04" => "Rabat-Salé-Kénitra",
+    "MA-05" => "Béni Mellal-Khénifra",
+    "MA-06" => "Casablanca-Settat",
+    "MA-07" => "Marrakech-Safi",
+    "MA-08" => "Drâa-Tafilalet",
+    "MA-09" => "Souss-Massa",
+    "MA-AGD" => "Agadir-Ida-Ou-Tanane",
+    "MA-AZI" => "Azilal",
+    "MA-BEM" => "Béni Mellal",
+    "MA-BER" => "Berkane",
+    "MA-BES" => "Ben Slimane",
+    "MA-BOD" => "Boujdour (EH)",
+    "MA-BOM" => "Boulemane",
+    "MA-BRR" => "Berrechid",
+    "MA-CAS" => "Casablanca",
+    "MA-CHE" => "Chefchaouen",
+    "MA-CHI" => "Chichaoua",
+    "MA-CHT" => "Chtouka-Ait Baha",
+    "MA-DRI" => "Driouch",
+    "MA-ERR" => "Errachidia",
+    "MA-ESI" => "Essaouira",
+    "MA-FAH" => "Fahs-Anjra",
+    "MA-FES" => "Fès",
+    "MA-FIG" => "Figuig",
+    "MA-FQH" => "Fquih Ben Salah",
+    "MA-GUE" => "Guelmim",
+    "MA-GUF" => "Guercif",
+    "MA-HAJ" => "El Hajeb",
+    "MA-HAO" => "Al Haouz",
+    "MA-HOC" => "Al Hoceïma",
+    "MA-IFR" => "Ifrane",
+    "MA-INE" => "Inezgane-Ait Melloul",
+    "MA-JDI" => "El Jadida",
+    "MA-JRA" => "Jerada",
+    "MA-KEN" => "Kénitra",
+    "MA-KES" => "El Kelaâ des Sraghna",
+    "MA-KHO" => "Khouribga",
+    "MA-LAA" => "Laâyoune (EH)",
+    "MA-LAR" => "Larache",
+    "MA-MAR" => "Marrakech",
+    "MA-MED" => "Médiouna",
+    "MA-MEK" => "Meknès",
+    "MA-MID" => "Midelt",
+    "MA-MOH" => "Mohammadia",
+    "MA-MOU" => "Moulay Yacoub",
+    "MA-NAD" => "Nador",
+    "MA-NOU" => "Nouaceur",
+    "MA-OUA" => "Ouarzazate",
+    "MA-OUJ" => "Oujda-Angad",
+    "MA-OUZ" => "Ouezzane",
+    "MA-RAB" => "Rabat",
+    "MA-REH" => "Rehimnat",
+    "MA-SAF" => "Safi",
+    "MA-SAL" => "Salé",
+    "MA-SEF" => "Sefrou",
+    "MA-SET" => "Settat",
+    "MA-SIB" => "Sidi Bennour",
+    "MA-SIF" => "Sidi Ifni",
+    "MA-SIK" => "Sidi Kacem",
+    "MA-SIL" => "Sidi Slimane",
+    "MA-SKH" => "Skhirate-Témara",
+    "MA-TAI" => "Taourirt",
+    "MA-TAO" => "Taounate",
+    "MA-TAT" => "Tata",
+    "MA-TAZ" => "Taza",
+    "MA-TET" => "Tétouan",
+    "MA-TIN" => "Tinghir",
+    "MA-TIZ" => "Tiznit",
+    "MA-TNG" => "Tanger-Assilah",
+    "MA-YUS" => "Youssoufia",
+    "MA-ZAG" => "Zagora",
+    "MZ-A" => "Niassa",
+    "MZ-B" => "Manica",
+    "MZ-G" => "Gaza",
+    "MZ-I" => "Inhambane",
+    "MZ-L" => "Maputo",
+    "MZ-MPM" => "Maputo",
+    "MZ-P" => "Cabo Delgado",
+    "MZ-S" => "Sofala",
+    "MZ-T" => "Tete",
+    "MM-01" => "Sagaing",
+    "MM-02" => "Bago",
+    "MM-03" => "Magway",
+    "MM-04" => "Mandalay",
+    "MM-05" => "Tanintharyi",
+    "MM-06" => "Yangon",
+    "MM-07" => "Ayeyarwady",
+    "MM-11" => "Kachin",
+    "MM-12" => "Kayah",
+    "MM-13" => "Kayin",
+    "MM-14" => "Chin",
+    "MM-15" => "Mon",
+    "MM-16" => "Rakhine",
+    "MM-17" => "Shan",
+    "NA-ER" => "Erongo",
+    "NA-HA" => "Hardap",
+    "NA-KH" => "Khomas",
+    "NA-KU" => "Kunene",
+    "NA-OD" => "Otjozondjupa",
+    "NA-OH" => "Omaheke",
+    "NA-ON" => "Oshana",
+    "NA-OS" => "Omusati",
+    "NA-OT" => "Oshikoto",
+    "NA-OW" => "Ohangwena",
+    "NR-01" => "Aiwo",
+    "NR-02" => "Anabar",
+    "NR-03" => "Anetan",
+    "NR-04" => "Anibare",
+    "NR-06" => "Boe",
+    "NR-07" => "Buada",
+    "NR-08" => "Denigomodu",
+    "NR-09" => "Ewa",
+    "NR-10" => "Ijuw",
+    "NR-11" => "Meneng",
+    "NR-12" => "Nibok",
+    "NR-13" => "Uaboe",
+    "NR-14" => "Yaren",
+    "NP-P4" => "Gandaki",
+    "NP-P6" => "Karnali",
+    "NL-AW" => "Aruba",
+    "NL-BQ1" => "Bonaire",
+    "NL-BQ2" => "Saba",
+    "NL-BQ3" => "Sint Eustatius",
+    "NL-CW" => "Curaçao",
+    "NL-DR" => "Drenthe",
+    "NL-FL" => "Flevoland",
+    "NL-GE" => "Gelderland",
+    "NL-GR" => "Groningen",
+    "NL-LI" => "Limburg",
+    "NL-NB" => "Noord-Brabant",
+    "NL-NH" => "Noord-Holland",
+    "NL-OV" => "Overijssel",
+    "NL-SX" => "Sint Maarten",
+    "NL-UT" => "Utrecht",
+    "NL-ZE" => "Zeeland",
+    "NL-ZH" => "Zuid-Holland",
+    "NZ-AUK" => "Auckland",
+    "NZ-BOP" => "Bay of Plenty",
+    "NZ-CAN" => "Canterbury",
+    "NZ-CIT" => "Chatham Islands (territorium)",
+    "NZ-HKB" => "Hawke's Bay",
+    "NZ-MWT" => "Manawatu-Wanganui",
+    "NZ-NTL" => "Northland",
+    "NZ-OTA" => "Otago",
+    "NZ-STL" => "Southland",
+    "NZ-TKI" => "Taranaki",
+    "NZ-WGN" => "Wellington",
+    "NZ-WKO" => "Waikato",
+    "NZ-WTC" => "West Coast",
+    "NI-BO" => "Boaco",
+    "NI-CA" => "Carazo",
+    "NI-CI" => "Chinandega",
+    "NI-CO" => "Chontales",
+    "NI-ES" => "Estelí",
+    "NI-GR" => "Granada",
+    "NI-JI" => "Jinotega",
+    "NI-LE" => "León",
+    "NI-MD" => "Madriz",
+    "NI-MN" => "Managua",
+    "NI-MS" => "Masaya",
+    "NI-MT" => "Matagalpa",
+    "NI-NS" => "Nueva Segovia",
+    "NI-RI" => "Rivas",
+    "NI-SJ" => "Río San Juan",
+    "NE-1" => "Agadez",
+    "NE-2" => "Diffa",
+    "NE-3" => "Dosso",
+    "NE-4" => "Maradi",
+    "NE-5" => "Tahoua",
+    "NE-6" => "Tillabéri",
+    "NE-7" => "Zinder",
+    "NE-8" => "Niamey",
+    "NG-AB" => "Abia",
+    "NG-AD" => "Adamawa",
+    "NG-AK" => "Akwa Ibom",
+    "NG-AN" => "Anambra",
+    "NG-BA" => "Bauchi",
+    "NG-BE" => "Benue",
+    "NG-BO" => "Borno",
+    "NG-BY" => "Bayelsa",
+    "NG-CR" => "Cross River",
+    "NG-DE" => "Delta",
+    "NG-EB" => "Ebonyi",
+    "NG-ED" => "Edo",
+    "NG-EK" => "Ekiti",
+    "NG-EN" => "Enugu",
+    "NG-GO" => "Gombe",
+    "NG-IM" => "Imo",
+    "NG-JI" => "Jigawa",
+    "NG-KD" => "Kaduna",
+    "NG-KE" => "Kebbi",
+    "NG-KN" => "Kano",
+    "NG-KO" => "Kogi",
+    "NG-KT" => "Katsina",
+    "NG-KW" => "Kwara",
+    "NG-LA" => "Lagos",
+    "NG-NI" => "Niger",
+    "NG-OG" => "Ogun",
+    "NG-ON" => "Ondo",
+    "NG-OS" => "Osun",
+    "NG-OY" => "Oyo",
+    "NG-PL" => "Plateau",
+    "NG-RI" => "Rivers",
+    "NG-SO" => "Sokoto",
+    "NG-TA" => "Taraba",
+    "NG-YO" => "Yobe",
+    "NG-ZA" => "Zamfara",
+    "MK-101" => "Veles",
+    "MK-102" => "Gradsko",
+    "MK-103" => "Demir Kapija",
+    "MK-104" => "Kavadarci",
+    "MK-105" => "Lozovo",
+    "MK-106" => "Negotino",
+    "MK-107" => "Rosoman",
+    "MK-108" => "Sveti Nikole",
+    "MK-109" => "Čaška",
+    "MK-201" => "Berovo",
+    "MK-202" => "Vinica",
+    "MK-203" => "Delčevo",
+    "MK-204" => "Zrnovci",
+    "MK-205" => "Karbinci",
+    "MK-206" => "Kočani",
+    "MK-207" => "Makedonska Kamenica",
+    "MK-208" => "Pehčevo",
+    "MK-209" => "Probištip",
+    "MK-210" => "Češinovo-Obleševo",
+    "MK-211" => "Štip",
+    "MK-301" => "Vevčani",
+    "MK-303" => "Debar",
+    "MK-307" => "Kičevo",
+    "MK-308" => "Makedonski Brod",
+    "MK-310" => "Ohrid",
+    "MK-311" => "Plasnica",
+    "MK-312" => "Struga",
+    "MK-313" => "Centar Župa",
+    "MK-401" => "Bogdanci",
+    "MK-402" => "Bosilovo",
+    "MK-403" => "Valandovo",
+    "MK-404" => "Vasilevo",
+    "MK-405" => "Gevgelija",
+    "MK-406" => "Dojran",
+    "MK-407" => "Konče",
+    "MK-408" => "Novo Selo",
+    "MK-409" => "Radoviš",
+    "MK-410" => "Strumica",
+    "MK-501" => "Bitola",
+    "MK-502" => "Demir Hisar",
+    "MK-503" => "Dolneni",
+    "MK-504" => "Krivogaštani",
+    "MK-505" => "Kruševo",
+    "MK-506" => "Mogila",
+    "MK-507" => "Novaci",
+    "MK-508" => "Prilep",
+    "MK-509" => "Resen",
+    "MK-601" => "Bogovinje",
+    "MK-602" => "Brvenica",
+    "MK-603" => "Vrapčište",
+    "MK-604" => "Gostivar",
+    "MK-605" => "Želino",
+    "MK-606" => "Jegunovce",
+    "MK-608" => "Tearce",
+    "MK-609" => "Tetovo",
+    "MK-701" => "Kratovo",
+    "MK-702" => "Kriva Palanka",
+    "MK-703" => "Kumanovo",
+    "MK-704" => "Lipkovo",
+    "MK-705" => "Rankovce",
+    "MK-706" => "Staro Nagoričane",
+    "MK-802" => "Aračinovo",
+    "MK-806" => "Zelenikovo",
+    "MK-807" => "Ilinden",
+    "MK-810" => "Petrovec",
+    "MK-812" => "Sopište",
+    "MK-813" => "Studeničani",
+    "NO-03" => "Oslo",
+    "NO-11" => "Rogaland",
+    "NO-15" => "Møre og Romsdal",
+    "NO-18" => "Nordland",
+    "NO-21" => "Svalbard (Arktiska regionen)",
+    "NO-22" => "Jan Mayen (Arktiska regionen)",
+    "OM-BU" => "Al Buraymi",
+    "OM-MA" => "Muscat",
+    "OM-MU" => "Musandam",
+    "OM-WU" => "Al Wusţá",
+    "OM-ZA" => "Az̧ Z̧āhirah",
+    "OM-ZU" => "Z̧ufār",
+    "PK-BA" => "Balochistan",
+    "PK-GB" => "Gilgit-Baltistan",
+    "PK-IS" => "Islamabad",
+    "PK-KP" => "Khyber Pakhtunkhwa",
+    "PK-PB" => "Punjab",
+    "PK-SD" => "Sindh",
+    "PW-002" => "Aimeliik",
+    "PW-004" => "Airai",
+    "PW-010" => "Angaur",
+    "PW-100" => "Kayangel",
+    "PW-150" => "Koror",
+    "PW-212" => "Melekeok",
+    "PW-214" => "Ngaraard",
+    "PW-218" => "Ngarchelong",
+    "PW-222" => "Ngardmau",
+    "PW-224" => "Ngatpang",
+    "PW-226" => "Ngchesar",
+    "PW-227" => "Ngeremlengui",
+    "PW-228" => "Ngiwal",
+    "PW-350" => "Peleliu",
+    "PW-370" => "Sonsorol",
+    "PS-BTH" => "Betlehem",
+    "PS-DEB" => "Deir El Balah",
+    "PS-GZA" => "Gaza",
+    "PS-HBN" => "Hebron",
+    "PS-JEM" => "Jerusalems provins",
+    "PS-JEN" => "Jenin",
+    "PS-KYS" => "Khan Yunis provins",
+    "PS-NBS" => "Nablus provins",
+    "PS-NGZ" => "Norra Gaza",
+    "PS-QQA" => "Qalqilya provins",
+    "PS-RBH" => "Ramallah och Al-Birahs provins",
+    "PS-RFH" => "Rafahs provins",
+    "PS-SLT" => "Salfit",
+    "PS-TBS" => "Tubas provins",
+    "PS-TKM" => "Tulkarm provins",
+    "PA-1" => "Bocas del Toro",
+    "PA-2" => "Coclé",
+    "PA-3" => "Colón",
+    "PA-4" => "Chiriquí",
+    "PA-5" => "Darién",
+    "PA-6" => "Herrera",
+    "PA-7" => "Los Santos",
+    "PA-8" => "Panamá",
+    "PA-9" => "Veraguas",
+    "PA-EM" => "Emberá",
+    "PA-NB" => "Ngöbe-Buglé",
+    "PG-CPK" => "Chimbu",
+    "PG-CPM" => "Central",
+    "PG-EBR" => "East New Britain",
+    "PG-EHG" => "Eastern Highlands",
+    "PG-EPW" => "Enga",
+    "PG-ESW" => "East Sepik",
+    "PG-GPK" => "Gulf",
+    "PG-MBA" => "Milne Bay",
+    "PG-MPL" => "Morobe",
+    "PG-MPM" => "Madang",
+    "PG-MRL" => "Manus",
+    "PG-NCD" => "National Capital District (Port Moresby)",
+    "PG-NIK" => "New Ireland",
+    "PG-NPP" => "Norra",
+    "PG-NSB" => "Bougainville",
+    "PG-SHM" => "Southern Highlands",
+    "PG-WBK" => "West New Britain",
+    "PG-WHM" => "Western Highlands",
+    "PG-WPD" => "Västra",
+    "PY-1" => "Concepción",
+    "PY-10" => "Alto Paraná",
+    "PY-11" => "Central",
+    "PY-12" => "Ñeembucú",
+    "PY-13" => "Amambay",
+    "PY-14" => "Canindeyú",
+    "PY-15" => "Presidente Hayes",
+    "PY-16" => "Alto Paraguay",
+    "PY-19" => "Boquerón",
+    "PY-2" => "San Pedro",
+    "PY-3" => "Cordillera",
+    "PY-4" => "Guairá",
+    "PY-5" => "Caaguazú",
+    "PY-6" => "Caazapá",
+    "PY-7" => "Itapúa",
+    "PY-8" => "Misiones",
+    "PY-9" => "Paraguarí",
+    "PY-ASU" => "Asunción",
+    "PE-ANC" => "Ancash",
+    "PE-ARE" => "Arequipa",
+    "PE-AYA" => "Ayacucho",
+    "PE-CAJ" => "Cajamarca",
+    "PE-CAL" => "El Callao",
+    "PE-HUC" => "Huánuco",
+    "PE-HUV" => "Huancavelica",
+    "PE-ICA" => "Ica",
+    "PE-LAL" => "La Libertad",
+    "PE-LAM" => "Lambayeque",
+    "PE-LIM" => "Lima",
+    "PE-LOR" => "Loreto",
+    "PE-MDD" => "Madre de Dios",
+    "PE-MOQ" => "Moquegua",
+    "PE-PAS" => "Pasco",
+    "PE-PIU" => "Piura",
+    "PE-PUN" => "Puno",
+    "PE-TAC" => "Tacna",
+    "PE-TUM" => "Tumbes",
+    "PE-UCA" => "Ucayali",
+    "PH-00" => "Metro Manila",
+    "PH-01" => "Ilcos (region I)",
+    "PH-02" => "Cagayandalen (region II)",
+    "PH-03" => "Centrala Luzon (region III)",
+    "PH-05" => "Bikol (region V)",
+    "PH-06" => "Västra Visayas (region VI)",
+    "PH-07" => "Centrala Visayas (region VII)",
+    "PH-08" => "Östra Visayas (region VIII)",
+    "PH-09" => "Zamboangahalvön (region IX)",
+    "PH-10" => "Norra Mindanao (region X)",
+    "PH-11" => "Davao (region XI)",
+    "PH-12" => "SOCCSKSARGEN (region XII)",
+    "PH-13" => "Caraga (region XIII",
+    "PH-14" => "Muslimska Mindanao (ARMM)",
+    "PH-15" => "Kordiljärernas administrativa region (CAR)",
+    "PH-ABR" => "Abra",
+    "PH-AGN" => "Agusan del Norte",
+    "PH-AGS" => "Agusan del Sur",
+    "PH-AKL" => "Aklan",
+    "PH-ALB" => "Albay",
+    "PH-ANT" => "Antique",
+    "PH-APA" => "Apayao",
+    "PH-AUR" => "Aurora",
+    "PH-BAS" => "Basilan",
+    "PH-BEN" => "Benguet",
+    "PH-BIL" => "Biliran",
+    "PH-BOH" => "Bohol",
+    "PH-BTG" => "Batangas",
+    "PH-BTN" => "Batanes",
+    "PH-BUK" => "Bukidnon",
+    "PH-BUL" => "Bulacan",
+    "PH-CAG" => "Cagayan",
+    "PH-CAM" => "Camiguin",
+    "PH-CAN" => "Camarines Norte",
+    "PH-CAP" => "Capiz",
+    "PH-CAS" => "Camarines Sur",
+    "PH-CAT" => "Catanduanes",
+    "PH-CAV" => "Cavite",
+    "PH-CEB" => "Cebu",
+    "PH-DAO" => "Davao Oriental",
+    "PH-DAS" => "Davao del Sur",
+    "PH-DAV" => "Davao del Norte",
+    "PH-DIN" => "Dinagatöarna",
+    "PH-EAS" => "Östra Samar",
+    "PH-GUI" => "Guimaras",
+    "PH-IFU" => "Ifugao",
+    "PH-ILI" => "Iloilo",
+    "PH-ILN" => "Norra Ilocos",
+    "PH-ILS" => "Södra Ilocos",
+    "PH-ISA" => "Isabela",
+    "PH-LAG" => "Laguna",
+    "PH-LAN" => "Lanao del Norte",
+    "PH-LAS" => "Lanao del Sur",
+    "PH-LEY" => "Leyte",
+    "PH-LUN" => "La Union",
+    "PH-MAD" => "Marinduque",
+    "PH-MAS" => "Masbate",
+    "PH-MDC" => "Mindoro Occidental",
+    "PH-MDR" => "Mindoro Oriental",
+    "PH-MOU" => "Mountain Province",
+    "PH-MSC" => "Misamis Occidental",
+    "PH-MSR" => "Misamis Oriental",
+    "PH-NEC" => "Negros Occidental",
+    "PH-NER" => "Negros Oriental",
+    "PH-NSA" => "Northern Samar",
+    "PH-NUE" => "Nueva Ecija",
+    "PH-NUV" => "Nueva Vizcaya",
+    "PH-PAM" => "Pampanga",
+    "PH-PAN" => "Pangasinan",
+    "PH-PLW" => "Palawan",
+    "PH-QUE" => "Quezon",
+    "PH-QUI" => "Quirino",
+    "PH-RIZ" => "Rizal",
+    "PH-ROM" => "Romblon",
+    "PH-SAR" => "Sarangani",
+    "PH-SCO" => "Södra Cotabato",
+    "PH-SIG" => "Siquijor",
+    "PH-SLE" => "Södra Leyte",
+    "PH-SLU" => "Sulu",
+    "PH-SOR" => "Sorsogon",
+    "PH-SUK" => "Sultan Kudarat",
+    "PH-SUN" => "Surigao del Norte",
+    "PH-SUR" => "Surigao del Sur",
+    "PH-TAR" => "Tarlac",
+    "PH-TAW" => "Tawi-Tawi",
+    "PH-ZAN" => "Zamboanga del Norte",
+    "PH-ZAS" => "Zamboanga del Sur",
+    "PH-ZMB" => "Zambales",
+    "PH-ZSI" => "Zamboanga Sibugay",
+    "PL-02" => "Nedre Schlesien",
+    "PL-04" => "Kujavien-Pommern",
+    "PL-06" => "Lublin",
+    "PL-08" => "Lubusz",
+    "PL-10" => "Łódz",
+    "PL-12" => "Lillpolen",
+    "PL-14" => "Masovien",
+    "PL-16" => "Opole",
+    "PL-18" => "Nedre Karpaterna",
+    "PL-20" => "Podlasien",
+    "PL-22" => "Pommern",
+    "PL-24" => "Schlesien",
+    "PL-26" => "Święty Krzyż",
+    "PL-28" => "Ermland-Masurien",
+    "PL-30" => "Storpolen",
+    "PL-32" => "Västpommern",
+    "PT-01" => "Aveiro",
+    "PT-02" => "Beyja",
+    "PT-03" => "Braga",
+    "PT-04" => "Bragança",
+    "PT-05" => "Castelo Branco",
+    "PT-06" => "Coimbra",
+    "PT-07" => "Évora",
+    "PT-08" => "Faro",
+    "PT-09" => "Guarda",
+    "PT-10" => "Leiria",
+    "PT-11" => "Lissabon",
+    "PT-12" => "Portalegre",
+    "PT-13" => "Porto",
+    "PT-14" => "Santarém",
+    "PT-15" => "Setúbal",
+    "PT-16" => "Viana do Castelo",
+    "PT-17" => "Vila Real",
+    "PT-18" => "Viseu",
+    "PT-20" => "Azorerna (region)",
+    "PT-30" => "Madeira (region)",
+    "QA-KH" => "Al Khawr wa adh Dhakhīrah",
+    "QA-WA" => "Al Wakrah",
+    "QA-ZA" => "Az̧ Z̧a‘āyin",
+    "RO-AB" => "Alba",
+    "RO-AG" => "Argeș",
+    "RO-AR" => "Arad",
+    "RO-B" => "București",
+    "RO-BC" => "Bacău",
+    "RO-BH" => "Bihor",
+    "RO-BN" => "Bistrița-Năsăud",
+    "RO-BR" => "Brăila",
+    "RO-BT" => "Botoșani",
+    "RO-BV" => "Brașov",
+    "RO-BZ" => "Buzău",
+    "RO-CJ" => "Cluj",
+    "RO-CL" => "Călărași",
+    "RO-CS" => "Caraș-Severin",
+    "RO-CT" => "Constanța",
+    "RO-CV" => "Covasna",
+    "RO-DB" => "Dâmbovița",
+    "RO-DJ" => "Dolj",
+    "RO-GJ" => "Gorj",
+    "RO-GL" => "Galați",
+    "RO-GR" => "Giurgiu",
+    "RO-HD" => "Hunedoara",
+    "RO-HR" => "Harghita",
+    "RO-IF" => "Ilfov",
+    "RO-IL" => "Ialomița",
+    "RO-IS" => "Iași",
+    "RO-MH" => "Mehedinți",
+    "RO-MM" => "Maramureș",
+    "RO-MS" => "Mureș",
+    "RO-NT" => "Neamț",
+    "RO-OT" => "Olt",
+    "RO-PH" => "Prahova",
+    "RO-SB" => "Sibiu",
+    "RO-SJ" => "Sălaj",
+    "RO-SM" => "Satu Mare",
+    "RO-SV" => "Suceava",
+    "RO-TL" => "Tulcea",
+    "RO-TM" => "Timiș",
+    "RO-TR" => "Teleorman",
+    "RO-VL" => "Vâlcea",
+    "RO-VN" => "Vrancea",
+    "RO-VS" => "Vaslui",
+    "RU-BA" => "Basjkirien",
+    "RU-CE" => "Tjetjenien",
+    "RU-CHE" => "Tjeljabinsk oblast'",
+    "RU-CHU" => "Tjuktjien okrug",
+    "RU-CU" => "Tjuvasjien",
+    "RU-DA" => "Dagestan",
+    "RU-KAM" => "Kamtjaka kraj",
+    "RU-KC" => "Karatjajen-Tjerkessien",
+    "RU-KLU" => "Kaluga oblast'",
+    "RU-KO" => "Komi",
+    "RU-MOW" => "Moskva",
+    "RU-NIZ" => "Niznij Novgorod oblast'",
+    "RU-SPE" => "Sankt Petersburg",
+    "RU-TA" => "Tatarstan",
+    "RU-VOR" => "Voronezj oblast'",
+    "RU-ZAB" => "Zabajkalskij kraj",
+    "RW-02" => "Östra",
+    "RW-03" => "Norra",
+    "RW-04" => "Västra",
+    "RW-05" => "Southern",
+    "SH-AC" => "Ascension",
+    "SH-HL" => "Saint Helena",
+    "SH-TA" => "Tristan da Cunha",
+    "KN-01" => "Christ Church Nichola Town",
+    "KN-02" => "Saint Anne Sandy Point",
+    "KN-03" => "Saint George Basseterre",
+    "KN-04" => "Saint George Gingerland",
+    "KN-05" => "Saint James Windward",
+    "KN-06" => "Saint John Capisterre",
+    "KN-07" => "Saint John Figtree",
+    "KN-08" => "Saint Mary Cayon",
+    "KN-09" => "Saint Paul Capisterre",
+    "KN-10" => "Saint Paul Charlestown",
+    "KN-11" => "Saint Peter Basseterre",
+    "KN-12" => "Saint Thomas Lowland",
+    "KN-13" => "Saint Thomas Middle Island",
+    "KN-15" => "Trinity Palmetto Point",
+    "KN-K" => "Saint Kitts",
+    "KN-N" => "Nevis",
+    "LC-03" => "Choiseul",
+    "VC-01" => "Charlotte",
+    "VC-02" => "Saint Andrew",
+    "VC-03" => "Saint David",
+    "VC-04" => "Saint George",
+    "VC-05" => "Saint Patrick",
+    "VC-06" => "Grenadinerna",
+    "WS-AA" => "A'ana",
+    "WS-AL" => "Aiga-i-le-Tai",
+    "WS-AT" => "Atua",
+    "WS-FA" => "Fa'asaleleaga",
+    "WS-GE" => "Gaga'emauga",
+    "WS-GI" => "Gagaifomauga",
+    "WS-PA" => "Palauli",
+    "WS-SA" => "Satupa'itea",
+    "WS-TU" => "Tuamasaga",
+    "WS-VF" => "Va'a-o-Fonoti",
+    "WS-VS" => "Vaisigano",
+    "SM-01" => "Acquaviva",
+    "SM-02" => "Chiesanuova",
+    "SM-03" => "Domagnano",
+    "SM-04" => "Faetano",
+    "SM-05" => "Fiorentino",
+    "SM-06" => "Borgo Maggiore",
+    "SM-08" => "Montegiardino",
+    "SM-09" => "Serravalle",
+    "ST-P" => "Príncipe",
+    "SA-04" => "Ash Sharqīyah",
+    "SA-05" => "Al Qaim",
+    "SA-07" => "Tabuk",
+    "SA-10" => "Najrsn",
+    "SA-12" => "Al Jawf",
+    "SN-DB" => "Diourbel",
+    "SN-DK" => "Dakar",
+    "SN-FK" => "Fatick",
+    "SN-KA" => "Kaffrine",
+    "SN-KD" => "Kolda",
+    "SN-KE" => "Kédougou",
+    "SN-KL" => "Kaolack",
+    "SN-LG" => "Louga",
+    "SN-MT" => "Matam",
+    "SN-SE" => "Sédhiou",
+    "SN-SL" => "Saint-Louis",
+    "SN-TC" => "Tambacounda",
+    "SN-TH" => "Thiès",
+    "SN-ZG" => "Ziguinchor",
+    "RS-00" => "Belgrad",
+    "RS-01" => "Severnobački okrug",
+    "RS-02" => "Srednjebanatski okrug",
+    "RS-03" => "Severnobanatski okrug",
+    "RS-04" => "Južnobanatski okrug",
+    "RS-05" => "Zapadnobački okrug",
+    "RS-06" => "Južnobački okrug",
+    "RS-07" => "Sremski okrug",
+    "RS-08" => "Mačvanski okrug",
+    "RS-09" => "Kolubarski okrug",
+    "RS-10" => "Podunavski okrug",
+    "RS-11" => "Braničevski okrug",
+    "RS-12" => "Šumadijski okrug",
+    "RS-13" => "Pomoravski okrug",
+    "RS-14" => "Borski okrug",
+    "RS-15" => "Zaječarski okrug",
+    "RS-16" => "Zlatiborski okrug",
+    "RS-17" => "Moravički okrug",
+    "RS-18" => "Raški okrug",
+    "RS-19" => "Rasinski okrug",
+    "RS-20" => "Nišavski okrug",
+    "RS-21" => "Toplički okrug",
+    "RS-22" => "Pirotski okrug",
+    "RS-23" => "Jablanički okrug",
+    "RS-24" => "Pčinjski okrug",
+    "RS-25" => "Kosovski okrug",
+    "RS-26" => "Pećki okrug",
+    "RS-27" => "Prizrenski okrug",
+    "RS-28" => "Kosovsko-Mitrovački okrug",
+    "RS-29" => "Kosovsko-Pomoravski okrug",
+    "RS-KM" => "Kosovo-Metohija",
+    "RS-VO" => "Vojvodina",
+    "SC-01" => "Anse aux Pins",
+    "SC-02" => "Anse Boileau",
+    "SC-03" => "Anse Etoile",
+    "SC-05" => "Anse Royale",
+    "SC-06" => "Baie Lazare",
+    "SC-07" => "Baie Sainte Anne",
+    "SC-08" => "Beau Vallon",
+    "SC-09" => "Bel Air",
+    "SC-10" => "Bel Ombre",
+    "SC-11" => "Cascade",
+    "SC-12" => "Glacis",
+    "SC-13" => "Grand Anse Mahe",
+    "SC-14" => "Grand Anse Praslin",
+    "SC-15" => "La Digue",
+    "SC-16" => "English River",
+    "SC-17" => "Mont Buxton",
+    "SC-18" => "Mont Fleuri",
+    "SC-19" => "Plaisance",
+    "SC-20" => "Pointe Larue",
+    "SC-21" => "Port Glaud",
+    "SC-22" => "Saint Louis",
+    "SC-23" => "Takamaka",
+    "SC-24" => "Les Mamelles",
+    "SC-25" => "Roche Caiman",
+    "SL-E" => "Östra",
+    "SL-N" => "Norra",
+    "SL-S" => "Southern",
+    "SL-W" => "Western Area (Freetown)",
+    "SG-01" => "Centrala Singapore",
+    "SG-02" => "Nordöst",
+    "SG-03" => "Nordväst",
+    "SG-04" => "Sydöst",
+    "SG-05" => "Sydväst",
+    "SK-BC" => "Banská Bystrica (region)",
+    "SK-BL" => "Bratislava (region)",
+    "SK-KI" => "Košice (region)",
+    "SK-NI" => "Nitra (region)",
+    "SK-PV" => "Prešov (region)",
+    "SK-TA" => "Trnava (region)",
+    "SK-TC" => "Trenčín (region)",
+    "SK-ZI" => "Žilina (region)",
+    "SI-001" => "Ajdovščina",
+    "SI-002" => "Beltinci",
+    "SI-003" => "Bled",
+    "SI-004" => "Bohinj",
+    "SI-005" => "Borovnica",
+    "SI-006" => "Bovec",
+    "SI-007" => "Brda",
+    "SI-008" => "Brezovica",
+    "SI-009" => "Brežice",
+    "SI-010" => "Tišina",
+    "SI-011" => "Celje",
+    "SI-012" => "Cerklje na Gorenjskem",
+    "SI-013" => "Cerknica",
+    "SI-014" => "Cerkno",
+    "SI-015" => "Črenšovci",
+    "SI-016" => "Črna na Koroškem",
+    "SI-017" => "Črnomelj",
+    "SI-018" => "Destrnik",
+    "SI-019" => "Divača",
+    "SI-020" => "Dobrepolje",
+    "SI-021" => "Dobrova-Polhov Gradec",
+    "SI-022" => "Dol pri Ljubljani",
+    "SI-023" => "Domžale",
+    "SI-024" => "Dornava",
+    "SI-025" => "Dravograd",
+    "SI-026" => "Duplek",
+    "SI-027" => "Gorenja vas-Poljane",
+    "SI-028" => "Gorišnica",
+    "SI-029" => "Gornja Radgona",
+    "SI-030" => "Gornji Grad",
+    "SI-031" => "Gornji Petrovci",
+    "SI-032" => "Grosuplje",
+    "SI-033" => "Šalovci",
+    "SI-034" => "Hrastnik",
+    "SI-035" => "Hrpelje-Kozina",
+    "SI-036" => "Idrija",
+    "SI-037" => "Ig",
+    "SI-038" => "Ilirska Bistrica",
+    "SI-039" => "Ivančna Gorica",
+    "SI-041" => "Jesenice",
+    "SI-042" => "Juršinci",
+    "SI-043" => "Kamnik",
+    "SI-044" => "Kanal",
+    "SI-045" => "Kidričevo",
+    "SI-046" => "Kobarid",
+    "SI-047" => "Kobilje",
+    "SI-048" => "Kočevje",
+    "SI-049" => "Komen",
+    "SI-051" => "Kozje",
+    "SI-052" => "Kranj",
+    "SI-053" => "Kranjska Gora",
+    "SI-054" => "Krško",
+    "SI-055" => "Kungota",
+    "SI-056" => "Kuzma",
+    "SI-057" => "Laško",
+    "SI-058" => "Lenart",
+    "SI-060" => "Litija",
+    "SI-061" => "Ljubljana",
+    "SI-062" => "Ljubno",
+    "SI-063" => "Ljutomer",
+    "SI-064" => "Logatec",
+    "SI-065" => "Loška dolina",
+    "SI-066" => "Loški Potok",
+    "SI-067" => "Luče",
+    "SI-068" => "Lukovica",
+    "SI-069" => "Majšperk",
+    "SI-070" => "Maribor",
+    "SI-071" => "Medvode",
+    "SI-072" => "Mengeš",
+    "SI-073" => "Metlika",
+    "SI-074" => "Mežica",
+    "SI-075" => "Miren-Kostanjevica",
+    "SI-076" => "Mislinja",
+    "SI-077" => "Moravče",
+    "SI-078" => "Moravske Toplice",
+    "SI-079" => "Mozirje",
+    "SI-080" => "Murska Sobota",
+    "SI-081" => "Muta",
+    "SI-082" => "Naklo",
+    "SI-083" => "Nazarje",
+    "SI-084" => "Nova Gorica",
+    "SI-086" => "Odranci",
+    "SI-087" => "Ormož",
+    "SI-088" => "Osilnica",
+    "SI-089" => "Pesnica",
+    "SI-091" => "Pivka",
+    "SI-092" => "Podčetrtek",
+    "SI-093" => "Podvelka",
+    "SI-094" => "Postojna",
+    "SI-095" => "Preddvor",
+    "SI-096" => "Ptuj",
+    "SI-097" => "Puconci",
+    "SI-098" => "Rače-Fram",
+    "SI-099" => "Radeče",
+    "SI-100" => "Radenci",
+    "SI-101" => "Radlje ob Dravi",
+    "SI-102" => "Radovljica",
+    "SI-103" => "Ravne na Koroškem",
+    "SI-104" => "Ribnica",
+    "SI-105" => "Rogašovci",
+    "SI-106" => "Rogaška Slatina",
+    "SI-107" => "Rogatec",
+    "SI-108" => "Ruše",
+    "SI-109" => "Semič",
+    "SI-110" => "Sevnica",
+    "SI-111" => "Sežana",
+    "SI-112" => "Slovenj Gradec",
+    "SI-113" => "Slovenska Bistrica",
+    "SI-114" => "Slovenske Konjice",
+    "SI-117" => "Šenčur",
+    "SI-118" => "Šentilj",
+    "SI-119" => "Šentjernej",
+    "SI-120" => "Šentjur",
+    "SI-121" => "Škocjan",
+    "SI-122" => "Škofja Loka",
+    "SI-123" => "Škofljica",
+    "SI-124" => "Šmarje pri Jelšah",
+    "SI-125" => "Šmartno ob Paki",
+    "SI-126" => "Šoštanj",
+    "SI-127" => "Štore",
+    "SI-128" => "Tolmin",
+    "SI-129" => "Trbovlje",
+    "SI-130" => "Trebnje",
+    "SI-131" => "Tržič",
+    "SI-132" => "Turnišče",
+    "SI-133" => "Velenje",
+    "SI-134" => "Velike Lašče",
+    "SI-135" => "Videm",
+    "SI-136" => "Vipava",
+    "SI-137" => "Vitanje",
+    "SI-138" => "Vodice",
+    "SI-139" => "Vojnik",
+    "SI-140" => "Vrhnika",
+    "SI-141" => "Vuzenica",
+    "SI-142" => "Zagorje ob Savi",
+    "SI-143" => "Zavrč",
+    "SI-144" => "Zreče",
+    "SI-146" => "Železniki",
+    "SI-147" => "Žiri",
+    "SI-148" => "Benedikt",
+    "SI-149" => "Bistrica ob Sotli",
+    "SI-150" => "Bloke",
+    "SI-151" => "Braslovče",
+    "SI-152" => "Cankova",
+    "SI-153" => "Cerkvenjak",
+    "SI-154" => "Dobje",
+    "SI-155" => "Dobrna",
+    "SI-157" => "Dolenjske Toplice",
+    "SI-158" => "Grad",
+    "SI-159" => "Hajdina",
+    "SI-160" => "Hoče-Slivnica",
+    "SI-162" => "Horjul",
+    "SI-163" => "Jezersko",
+    "SI-164" => "Komenda",
+    "SI-165" => "Kostel",
+    "SI-166" => "Križevci",
+    "SI-167" => "Lovrenc na Pohorju",
+    "SI-168" => "Markovci",
+    "SI-169" => "Miklavž na Dravskem polju",
+    "SI-170" => "Mirna Peč",
+    "SI-171" => "Oplotnica",
+    "SI-172" => "Podlehnik",
+    "SI-173" => "Polzela",
+    "SI-174" => "Prebold",
+    "SI-175" => "Prevalje",
+    "SI-176" => "Razkrižje",
+    "SI-177" => "Ribnica na Pohorju",
+    "SI-178" => "Selnica ob Dravi",
+    "SI-179" => "Sodražica",
+    "SI-180" => "Solčava",
+    "SI-181" => "Sveta Ana",
+    "SI-183" => "Šempeter-Vrtojba",
+    "SI-184" => "Tabor",
+    "SI-186" => "Trzin",
+    "SI-187" => "Velika Polana",
+    "SI-188" => "Veržej",
+    "SI-189" => "Vransko",
+    "SI-190" => "Žalec",
+    "SI-191" => "Žetale",
+    "SI-192" => "Žirovnica",
+    "SI-193" => "Žužemberk",
+    "SI-194" => "Šmartno pri Litiji",
+    "SI-195" => "Apače",
+    "SI-196" => "Cirkulane",
+    "SI-197" => "Kosanjevica na Krki",
+    "SI-198" => "Makole",
+    "SI-199" => "Mokronog-Trebelno",
+    "SI-200" => "Poljčane",
+    "SI-201" => "Renče-Vogrsko",
+    "SI-202" => "Središče ob Dravi",
+    "SI-203" => "Straža",
+    "SI-205" => "Sveti Tomaž",
+    "SI-207" => "Gorje",
+    "SI-208" => "Log-Dragomer",
+    "SI-209" => "Rečica ob Savinji",
+    "SI-211" => "Šentrupert",
+    "SB-CE" => "Central",
+    "SB-CH" => "Choiseul",
+    "SB-CT" => "Honiara huvudstadsterritorium",
+    "SB-GU" => "Guadalcanal",
+    "SB-IS" => "Isabel",
+    "SB-ML" => "Malaita",
+    "SB-RB" => "Rennell och Bellona",
+    "SB-TE" => "Temotu",
+    "SB-WE" => "Västra",
+    "SO-AW" => "Awdal",
+    "SO-BK" => "Bakool",
+    "SO-BN" => "Banaadir",
+    "SO-BR" => "Bari",
+    "SO-BY" => "Bay",
+    "SO-GA" => "Galguduud",
+    "SO-GE" => "Gedo",
+    "SO-JD" => "Jubbada Dhexe",
+    "SO-JH" => "Jubbada Hoose",
+    "SO-MU" => "Mudug",
+    "SO-NU" => "Nugaal",
+    "SO-SD" => "Shabeellaha Dhexe",
+    "SO-SH" => "Shabeellaha Hoose",
+    "SO-SO" => "Sool",
+    "SO-TO" => "Togdheer",
+    "SO-WO" => "Woqooyi Galbeed",
+    "ZA-EC" => "Östra Kapprovinsen",
+    "ZA-FS" => "Fristatsprovinsen",
+    "ZA-GP" => "Gauteng",
+    "ZA-KZN" => "Kwazulu-Natal",
+    "ZA-LP" => "Limpopo",
+    "ZA-MP" => "Mpumalanga",
+    "ZA-NC" => "Norra Kapprovinsen",
+    "ZA-NW" => "North-West",
+    "ZA-WC" => "Västra Kapprovinsen",
+    "SS-BN" => "Norra Bahr el Ghazal",
+    "SS-BW" => "Västra Bahr el-Ghazal",
+    "SS-EC" => "Central Equatoria",
+    "SS-EE" => "Eastern Equatoria",
+    "SS-EW" => "Western Equatoria",
+    "SS-JG" => "Jonglei",
+    "SS-LK" => "Lakes",
+    "SS-NU" => "Övre Nilen",
+    "SS-UY" => "Unity",
+    "SS-WR" => "Warrap",
+    "ES-AB" => "Albacete",
+    "ES-AL" => "Almería",
+    "ES-AN" => "Andalusien",
+    "ES-AR" => "Aragonien",
+    "ES-AS" => "Asturien",
+    "ES-AV" => "Ávila",
+    "ES-BA" => "Badajoz",
+    "ES-BI" => "Biscaya",
+    "ES-BU" => "Burgos",
+    "ES-CA" => "Cádiz",
+    "ES-CB" => "Kantabrien",
+    "ES-CC" => "Cáceres",
+    "ES-CE" => "Ceuta",
+    "ES-CL" => "Castilla y León",
+    "ES-CM" => "Castilla-La Mancha",
+    "ES-CN" => "Kanarieöarna",
+    "ES-CO" => "Córdoba",
+    "ES-CR" => "Ciudad Real",
+    "ES-CU" => "Cuenca",
+    "ES-EX" => "Extremadura",
+    "ES-GC" => "Las Palmas",
+    "ES-GR" => "Granada",
+    "ES-GU" => "Guadalajara",
+    "ES-H" => "Huelva",
+    "ES-HU" => "Huesca",
+    "ES-J" => "Jaén",
+    "ES-LE" => "León",
+    "ES-LO" => "La Rioja",
+    "ES-M" => "Madrid",
+    "ES-MA" => "Málaga",
+    "ES-MC" => "Murcia, Región de",
+    "ES-MD" => "Madrid, Comunidad de",
+    "ES-ML" => "Melilla",
+    "ES-MU" => "Murcia",
+    "ES-O" => "Asturias",
+    "ES-P" => "Palencia",
+    "ES-RI" => "La Rioja",
+    "ES-S" => "Kantabrien",
+    "ES-SA" => "Salamanca",
+    "ES-SE" => "Sevilla",
+    "ES-SG" => "Segovia",
+    "ES-SO" => "Soria",
+    "ES-SS" => "Gipuzkoa",
+    "ES-TE" => "Teruel",
+    "ES-TF" => "Santa Cruz, Teneriffa",
+    "ES-TO" => "Toledo",
+    "ES-VA" => "Valladolid",
+    "ES-Z" => "Zaragoza",
+    "ES-ZA" => "Zamora",
+    "LK-12" => "Gampaha",
+    "LK-81" => "Badulla",
+    "LK-91" => "Ratnapura",
+    "SD-NO" => "Norra",
+    "SR-BR" => "Brokopondo",
+    "SR-CM" => "Commewijne",
+    "SR-CR" => "Coronie",
+    "SR-MA" => "Marowijne",
+    "SR-NI" => "Nickerie",
+    "SR-PM" => "Paramaribo",
+    "SR-PR" => "Para",
+    "SR-SA" => "Saramacca",
+    "SR-SI" => "Sipaliwini",
+    "SR-WA" => "Wanica",
+    "CH-AG" => "Aargau",
+    "CH-AI" => "Appenzell Innerrhoden",
+    "CH-AR" => "Appenzell Ausserrhoden",
+    "CH-BE" => "Bern",
+    "CH-BL" => "Basel-Landschaft",
+    "CH-BS" => "Basel-Stadt",
+    "CH-GE" => "Genève",
+    "CH-GL" => "Glarus",
+    "CH-GR" => "Graubünden",
+    "CH-JU" => "Jura",
+    "CH-LU" => "Luzern",
+    "CH-NE" => "Neuchâtel",
+    "CH-NW" => "Nidwalden",
+    "CH-OW" => "Obwalden",
+    "CH-SG" => "Sankt Gallen",
+    "CH-SH" => "Schaffhausen",
+    "CH-SO" => "Solothurn",
+    "CH-SZ" => "Schwyz",
+    "CH-TG" => "Thurgau",
+    "CH-TI" => "Ticino",
+    "CH-UR" => "Uri",
+    "CH-VD" => "Vaud",
+    "CH-VS" => "Valais",
+    "CH-ZG" => "Zug",
+    "CH-ZH" => "Zürich",
+    "SY-DI" => "Dimashq",
+    "SY-DY" => "Dayr az Zawr",
+    "SY-ID" => "Idlib",
+    "SY-RA" => "Ar Raqqah",
+    "TW-CHA" => "Changhua",
+    "TW-CYI" => "Chiayi",
+    "TW-CYQ" => "Chiayi",
+    "TW-HSQ" => "Hsinchu",
+    "TW-HSZ" => "Hsinchu",
+    "TW-HUA" => "Hualien",
+    "TW-KHH" => "Kaohsiung",
+    "TW-MIA" => "Miaoli",
+    "TW-NAN" => "Nantou",
+    "TW-PEN" => "Penghu",
+    "TW-PIF" => "Pingtung",
+    "TW-TAO" => "Taoyuan",
+    "TW-TNN" => "Tainan",
+    "TW-TPE" => "Taipei",
+    "TW-TTT" => "Taitung",
+    "TW-TXG" => "Taichung",
+    "TW-YUN" => "Yunlin",
+    "TJ-KT" => "Chatlon",
+    "TJ-SU" => "Sughd",
+    "TZ-01" => "Arusha",
+    "TZ-03" => "Dodoma",
+    "TZ-04" => "Iringa",
+    "TZ-05" => "Kagera",
+    "TZ-08" => "Kigoma",
+    "TZ-09" => "Kilimanjaro",
+    "TZ-12" => "Lindi",
+    "TZ-13" => "Mara",
+    "TZ-14" => "Mbeya",
+    "TZ-16" => "Morogoro",
+    "TZ-17" => "Mtwara",
+    "TZ-18" => "Mwanza",
+    "TZ-20" => "Rukwa",
+    "TZ-21" => "Ruvuma",
+    "TZ-22" => "Shinyanga",
+    "TZ-23" => "Singida",
+    "TZ-24" => "Tabora",
+    "TZ-25" => "Tanga",
+    "TZ-26" => "Manyara",
+    "TH-11" => "Samut Prakan",
+    "TH-12" => "Nonthaburi",
+    "TH-13" => "Pathum Thani",
+    "TH-14" => "Phra Nakhon Si Ayutthaya",
+    "TH-15" => "Ang Thong",
+    "TH-16" => "Lop Buri",
+    "TH-17" => "Sing Buri",
+    "TH-18" => "Chai Nat",
+    "TH-19" => "Saraburi",
+    "TH-20" => "Chon Buri",
+    "TH-21" => "Rayong",
+    "TH-22" => "Chanthaburi",
+    "TH-23" => "Trat",
+    "TH-24" => "Chachoengsao",
+    "TH-25" => "Prachin Buri",
+    "TH-26" => "Nakhon Nayok",
+    "TH-27" => "Sa Kaeo",
+    "TH-30" => "Nakhon Ratchasima",
+    "TH-31" => "Buri Ram",
+    "TH-32" => "Surin",
+    "TH-33" => "Si Sa Ket",
+    "TH-34" => "Ubon Ratchathani",
+    "TH-35" => "Yasothon",
+    "TH-36" => "Chaiyaphum",
+    "TH-37" => "Amnat Charoen",
+    "TH-39" => "Nong Bua Lam Phu",
+    "TH-40" => "Khon Kaen",
+    "TH-41" => "Udon Thani",
+    "TH-42" => "Loei",
+    "TH-43" => "Nong Khai",
+    "TH-44" => "Maha Sarakham",
+    "TH-45" => "Roi Et",
+    "TH-46" => "Kalasin",
+    "TH-47" => "Sakon Nakhon",
+    "TH-48" => "Nakhon Phanom",
+    "TH-49" => "Mukdahan",
+    "TH-50" => "Chiang Mai",
+    "TH-51" => "Lamphun",
+    "TH-52" => "Lampang",
+    "TH-53" => "Uttaradit",
+    "TH-54" => "Phrae",
+    "TH-55" => "Nan",
+    "TH-56" => "Phayao",
+    "TH-57" => "Chiang Rai",
+    "TH-58" => "Mae Hong Son",
+    "TH-60" => "Nakhon Sawan",
+    "TH-61" => "Uthai Thani",
+    "TH-62" => "Kamphaeng Phet",
+    "TH-63" => "Tak",
+    "TH-64" => "Sukhothai",
+    "TH-65" => "Phitsanulok",
+    "TH-66" => "Phichit",
+    "TH-67" => "Phetchabun",
+    "TH-70" => "Ratchaburi",
+    "TH-71" => "Kanchanaburi",
+    "TH-72" => "Suphan Buri",
+    "TH-73" => "Nakhon Pathom",
+    "TH-74" => "Samut Sakhon",
+    "TH-75" => "Samut Songkhram",
+    "TH-76" => "Phetchaburi",
+    "TH-77" => "Prachuap Khiri Khan",
+    "TH-80" => "Nakhon Si Thammarat",
+    "TH-81" => "Krabi",
+    "TH-82" => "Phangnga",
+    "TH-83" => "Phuket",
+    "TH-84" => "Surat Thani",
+    "TH-85" => "Ranong",
+    "TH-86" => "Chumphon",
+    "TH-90" => "Songkhla",
+    "TH-91" => "Satun",
+    "TH-92" => "Trang",
+    "TH-93" => "Phatthalung",
+    "TH-94" => "Pattani",
+    "TH-95" => "Yala",
+    "TH-96" => "Narathiwat",
+    "TH-S" => "Phatthaya",
+    "TL-AL" => "Aileu",
+    "TL-AN" => "Ainaro",
+    "TL-BA" => "Baucau",
+    "TL-BO" => "Bobonaro",
+    "TL-CO" => "Cova Lima",
+    "TL-DI" => "Dili",
+    "TL-ER" => "Ermera",
+    "TL-MF" => "Manufahi",
+    "TL-MT" => "Manatuto",
+    "TG-P" => "Plateaux",
+    "TO-01" => "'Eua",
+    "TO-02" => "Ha'apai",
+    "TO-03" => "Niuas",
+    "TO-04" => "Tongatapu",
+    "TO-05" => "Vava'u",
+    "TT-ARI" => "Arima",
+    "TT-CHA" => "Chaguanas",
+    "TT-CTT" => "Couva-Tabaquite-Talparo",
+    "TT-DMN" => "Diego Martin",
+    "TT-PED" => "Penal-Debe",
+    "TT-POS" => "Port of Spain",
+    "TT-PRT" => "Princes Town",
+    "TT-PTF" => "Point Fortin",
+    "TT-SFO" => "San Fernando",
+    "TT-SGE" => "Sangre Grande",
+    "TT-SIP" => "Siparia",
+    "TT-SJL" => "San Juan-Laventille",
+    "TT-TUP" => "Tunapuna-Piarco",
+    "TN-11" => "Tunis",
+    "TN-13" => "Ben Arous",
+    "TN-14" => "La Manouba",
+    "TN-21" => "Nabeul",
+    "TN-22" => "Zaghouan",
+    "TN-23" => "Bizerte",
+    "TN-31" => "Béja",
+    "TN-32" => "Jendouba",
+    "TN-33" => "Kef",
+    "TN-34" => "Siliana",
+    "TN-41" => "Kairouan",
+    "TN-42" => "Kasserine",
+    "TN-43" => "Sidi Bouzid",
+    "TN-51" => "Sousse",
+    "TN-52" => "Monastir",
+    "TN-53" => "Mahdia",
+    "TN-61" => "Sfax",
+    "TN-71" => "Gafsa",
+    "TN-72" => "Tozeur",
+    "TN-81" => "Gabès",
+    "TN-83" => "Tataouine",
+    "TR-01" => "Adana",
+    "TR-02" => "Adıyaman",
+    "TR-03" => "Afyonkarahisar",
+    "TR-04" => "Ağrı",
+    "TR-05" => "Amasya",
+    "TR-06" => "Ankara",
+    "TR-07" => "Antalya",
+    "TR-08" => "Artvin",
+    "TR-09" => "Aydın",
+    "TR-10" => "Balıkesir",
+    "TR-11" => "Bilecik",
+    "TR-12" => "Bingöl",
+    "TR-13" => "Bitlis",
+    "TR-14" => "Bolu",
+    "TR-15" => "Burdur",
+    "TR-16" => "Bursa",
+    "TR-17" => "Çanakkale",
+    "TR-18" => "Çankırı",
+    "TR-19" => "Çorum",
+    "TR-20" => "Denizli",
+    "TR-21" => "Diyarbakır",
+    "TR-22" => "Edirne",
+    "TR-23" => "Elazığ",
+    "TR-24" => "Erzincan",
+    "TR-25" => "Erzurum",
+    "TR-26" => "Eskişehir",
+    "TR-27" => "Gaziantep",
+    "TR-28" => "Giresun",
+    "TR-29" => "Gümüşhane",
+    "TR-30" => "Hakkâri",
+    "TR-31" => "Hatay",
+    "TR-32" => "Isparta",
+    "TR-33" => "Mersin",
+    "TR-34" => "Istanbul",
+    "TR-35" => "İzmir",
+    "TR-36" => "Kars",
+    "TR-37" => "Kastamonu",
+    "TR-38" => "Kayseri",
+    "TR-39" => "Kırklareli",
+    "TR-40" => "Kırşehir",
+    "TR-41" => "Kocaeli",
+    "TR-42" => "Konya",
+    "TR-43" => "Kütahya",
+    "TR-44" => "Malatya",
+    "TR-45" => "Manisa",
+    "TR-46" => "Kahramanmaraş",
+    "TR-47" => "Mardin",
+    "TR-48" => "Muğla",
+    "TR-49" => "Muş",
+    "TR-50" => "Nevşehir",
+    "TR-51" => "Niğde",
+    "TR-52" => "Ordu",
+    "TR-53" => "Rize",
+    "TR-54" => "Sakarya",
+    "TR-55" => "Samsun",
+    "TR-56" => "Siirt",
+    "TR-57" => "Sinop",
+    "TR-58" => "Sivas",
+    "TR-59" => "Tekirdağ",
+    "TR-60" => "Tokat",
+    "TR-61" => "Trabzon",
+    "TR-62" => "Tunceli",
+    "TR-63" => "Şanlıurfa",
+    "TR-64" => "Uşak",
+    "TR-65" => "Van",
+    "TR-66" => "Yozgat",
+    "TR-67" => "Zonguldak",
+    "TR-68" => "Aksaray",
+    "TR-69" => "Bayburt",
+    "TR-70" => "Karaman",
+    "TR-71" => "Kırıkkale",
+    "TR-72" => "Batman",
+    "TR-73" => "Şırnak",
+    "TR-74" => "Bartın",
+    "TR-75" => "Ardahan",
+    "TR-76" => "Iğdır",
+    "TR-77" => "Yalova",
+    "TR-78" => "Karabük",
+    "TR-79" => "Kilis",
+    "TR-80" => "Osmaniye",
+    "TR-81" => "Düzce",
+    "TM-A" => "Achal",
+    "TM-B" => "Balkan",
+    "TM-D" => "Daşoguz",
+    "TM-L" => "Lebap",
+    "TM-M" => "Mary",
+    "TM-S" => "Aşgabat",
+    "TV-FUN" => "Funafuti",
+    "TV-NIT" => "Niutao",
+    "TV-NKF" => "Nukufetau",
+    "TV-NKL" => "Nukulaelae",
+    "TV-NMA" => "Nanumea",
+    "TV-NUI" => "Nui",
+    "TV-VAI" => "Vaitupu",
+    "UG-101" => "Kalangala",
+    "UG-102" => "Kampala",
+    "UG-103" => "Kiboga",
+    "UG-104" => "Luwero",
+    "UG-105" => "Masaka",
+    "UG-106" => "Mpigi",
+    "UG-107" => "Mubende",
+    "UG-108" => "Mukono",
+    "UG-109" => "Nakasongola",
+    "UG-110" => "Rakai",
+    "UG-111" => "Sembabule",
+    "UG-112" => "Kayunga",
+    "UG-113" => "Wakiso",
+    "UG-114" => "Lyantonde",
+    "UG-115" => "Mityana",
+    "UG-116" => "Nakaseke",
+    "UG-201" => "Bugiri",
+    "UG-202" => "Busia",
+    "UG-203" => "Iganga",
+    "UG-204" => "Jinja",
+    "UG-205" => "Kamuli",
+    "UG-206" => "Kapchorwa",
+    "UG-207" => "Katakwi",
+    "UG-208" => "Kumi",
+    "UG-209" => "Mbale",
+    "UG-210" => "Pallisa",
+    "UG-211" => "Soroti",
+    "UG-212" => "Tororo",
+    "UG-213" => "Kaberamaido",
+    "UG-214" => "Mayuge",
+    "UG-215" => "Sironko",
+    "UG-216" => "Amuria",
+    "UG-217" => "Budaka",
+    "UG-218" => "Bududa",
+    "UG-219" => "Bukedea",
+    "UG-221" => "Butaleja",
+    "UG-222" => "Kaliro",
+    "UG-223" => "Manafwa",
+    "UG-224" => "Namutumba",
+    "UG-301" => "Adjumani",
+    "UG-302" => "Apac",
+    "UG-303" => "Arua",
+    "UG-304" => "Gulu",
+    "UG-305" => "Kitgum",
+    "UG-306" => "Kotido",
+    "UG-307" => "Lira",
+    "UG-308" => "Moroto",
+    "UG-309" => "Moyo",
+    "UG-310" => "Nebbi",
+    "UG-311" => "Nakapiripirit",
+    "UG-312" => "Pader",
+    "UG-313" => "Yumbe",
+    "UG-314" => "Abim",
+    "UG-315" => "Amolatar",
+    "UG-316" => "Amuru",
+    "UG-317" => "Dokolo",
+    "UG-318" => "Kaabong",
+    "UG-319" => "Koboko",
+    "UG-320" => "Maracha",
+    "UG-321" => "Oyam",
+    "UG-401" => "Bundibugyo",
+    "UG-402" => "Bushenyi",
+    "UG-403" => "Hoima",
+    "UG-404" => "Kabale",
+    "UG-405" => "Kabarole",
+    "UG-406" => "Kasese",
+    "UG-407" => "Kibaale",
+    "UG-408" => "Kisoro",
+    "UG-409" => "Masindi",
+    "UG-410" => "Mbarara",
+    "UG-411" => "Ntungamo",
+    "UG-412" => "Rukungiri",
+    "UG-413" => "Kamwenge",
+    "UG-414" => "Kanungu",
+    "UG-415" => "Kyenjojo",
+    "UG-416" => "Buliisa",
+    "UG-417" => "Ibanda",
+    "UG-418" => "Isingiro",
+    "UG-419" => "Kiruhura",
+    "UG-C" => "Central",
+    "UG-E" => "Östra",
+    "UG-N" => "Norra",
+    "UG-W" => "Västra",
+    "UA-40" => "Sevastopol",
+    "AE-DU" => "Dubai",
+    "AE-FU" => "Fujairah",
+    "AE-RK" => "Ras al-Khaimah",
+    "AE-SH" => "Sharjah",
+    "AE-UQ" => "Umm al-Qaywayn",
+    "GB-ABD" => "Aberdeenshire",
+    "GB-ABE" => "Aberdeen City",
+    "GB-AGB" => "Argyll and Bute",
+    "GB-AND" => "Ards och North Down",
+    "GB-ANN" => "Antrim och Newtownabbey",
+    "GB-ANS" => "Angus",
+    "GB-BAS" => "Bath and North East Somerset",
+    "GB-BBD" => "Blackburn with Darwen",
+    "GB-BDF" => "Bedford",
+    "GB-BDG" => "Barking och Dagenham",
+    "GB-BEN" => "Brent",
+    "GB-BEX" => "Bexley",
+    "GB-BGW" => "Blaenau Gwent",
+    "GB-BIR" => "Birmingham",
+    "GB-BKM" => "Buckinghamshire",
+    "GB-BNE" => "Barnet",
+    "GB-BNH" => "Brighton and Hove",
+    "GB-BNS" => "Barnsley",
+    "GB-BOL" => "Bolton",
+    "GB-BPL" => "Blackpool",
+    "GB-BRC" => "Bracknell Forest",
+    "GB-BRD" => "Bradford",
+    "GB-BRY" => "Bromley",
+    "GB-BST" => "Bristol, City of",
+    "GB-BUR" => "Bury",
+    "GB-CAM" => "Cambridgeshire",
+    "GB-CBF" => "Central Bedfordshire",
+    "GB-CCG" => "Giant’s Causeway och Glens",
+    "GB-CHE" => "Cheshire East",
+    "GB-CHW" => "Cheshire West and Chester",
+    "GB-CLD" => "Calderdale",
+    "GB-CLK" => "Clackmannanshire",
+    "GB-CMA" => "Cumbria",
+    "GB-CMD" => "Camden",
+    "GB-CON" => "Cornwall",
+    "GB-COV" => "Coventry",
+    "GB-CRY" => "Croydon",
+    "GB-CWY" => "Conwy",
+    "GB-DAL" => "Darlington",
+    "GB-DBY" => "Derbyshire",
+    "GB-DER" => "Derby",
+    "GB-DEV" => "Devon",
+    "GB-DGY" => "Dumfries och Galloway",
+    "GB-DNC" => "Doncaster",
+    "GB-DND" => "Dundee City",
+    "GB-DOR" => "Dorset",
+    "GB-DRS" => "Derry och Strabane",
+    "GB-DUD" => "Dudley",
+    "GB-EAL" => "Ealing",
+    "GB-EAY" => "East Ayrshire",
+    "GB-EDH" => "Edinburgh, City of",
+    "GB-EDU" => "East Dunbartonshire",
+    "GB-ELN" => "East Lothian",
+    "GB-ELS" => "Eilean Siar",
+    "GB-ENF" => "Enfield",
+    "GB-ERW" => "East Renfrewshire",
+    "GB-ERY" => "East Riding of Yorkshire",
+    "GB-ESS" => "Essex",
+    "GB-ESX" => "East Sussex",
+    "GB-FAL" => "Falkirk",
+    "GB-FIF" => "Fife",
+    "GB-FMO" => "Fermanagh och Omagh",
+    "GB-GAT" => "Gateshead",
+    "GB-GLG" => "Glasgow City",
+    "GB-GLS" => "Gloucestershire",
+    "GB-GRE" => "Greenwich",
+    "GB-GWN" => "Gwynedd",
+    "GB-HAL" => "Halton",
+    "GB-HAM" => "Hampshire",
+    "GB-HAV" => "Havering",
+    "GB-HCK" => "Hackney",
+    "GB-HEF" => "Herefordshire",
+    "GB-HIL" => "Hillingdon",
+    "GB-HLD" => "Highland",
+    "GB-HMF" => "Hammersmith and Fulham",
+    "GB-HNS" => "Hounslow",
+    "GB-HPL" => "Hartlepool",
+    "GB-HRT" => "Hertfordshire",
+    "GB-HRW" => "Harrow",
+    "GB-HRY" => "Haringey",
+    "GB-IOS" => "Scillyöarna",
+    "GB-IOW" => "Isle of Wight",
+    "GB-ISL" => "Islington",
+    "GB-IVC" => "Inverclyde",
+    "GB-KEC" => "Kensington and Chelsea",
+    "GB-KEN" => "Kent",
+    "GB-KHL" => "Kingston upon Hull",
+    "GB-KIR" => "Kirklees",
+    "GB-KTT" => "Kingston upon Thames",
+    "GB-KWL" => "Knowsley",
+    "GB-LAN" => "Lancashire",
+    "GB-LBC" => "Lisburn och Castlereagh",
+    "GB-LBH" => "Lambeth",
+    "GB-LCE" => "Leicester",
+    "GB-LDS" => "Leeds",
+    "GB-LEC" => "Leicestershire",
+    "GB-LEW" => "Lewisham",
+    "GB-LIN" => "Lincolnshire",
+    "GB-LIV" => "Liverpool",
+    "GB-LND" => "London, City of",
+    "GB-LUT" => "Luton",
+    "GB-MAN" => "Manchester",
+    "GB-MDB" => "Middlesbrough",
+    "GB-MDW" => "Medway",
+    "GB-MEA" => "Medel- och Östantrim",
+    "GB-MIK" => "Milton Keynes",
+    "GB-MLN" => "Midlothian",
+    "GB-MRT" => "Merton",
+    "GB-MRY" => "Moray",
+    "GB-NAY" => "North Ayrshire",
+    "GB-NBL" => "Northumberland",
+    "GB-NEL" => "North East Lincolnshire",
+    "GB-NET" => "Newcastle upon Tyne",
+    "GB-NFK" => "Norfolk",
+    "GB-NGM" => "Nottingham",
+    "GB-NLK" => "North Lanarkshire",
+    "GB-NLN" => "North Lincolnshire",
+    "GB-NMD" => "Newry, Mourne och Down",
+    "GB-NSM" => "North Somerset",
+    "GB-NTT" => "Nottinghamshire",
+    "GB-NTY" => "North Tyneside",
+    "GB-NWM" => "Newham",
+    "GB-NYK" => "North Yorkshire",
+    "GB-OLD" => "Oldham",
+    "GB-ORK" => "Orkneyöarna",
+    "GB-OXF" => "Oxfordshire",
+    "GB-PKN" => "Perth och Kinross",
+    "GB-PLY" => "Plymouth",
+    "GB-POR" => "Portsmouth",
+    "GB-POW" => "Powys",
+    "GB-PTE" => "Peterborough",
+    "GB-RCC" => "Redcar and Cleveland",
+    "GB-RCH" => "Rochdale",
+    "GB-RDB" => "Redbridge",
+    "GB-RDG" => "Reading",
+    "GB-RFW" => "Renfrewshire",
+    "GB-RIC" => "Richmond upon Thames",
+    "GB-ROT" => "Rotherham",
+    "GB-RUT" => "Rutland",
+    "GB-SAW" => "Sandwell",
+    "GB-SAY" => "South Ayrshire",
+    "GB-SFK" => "Suffolk",
+    "GB-SFT" => "Sefton",
+    "GB-SGC" => "South Gloucestershire",
+    "GB-SHF" => "Sheffield",
+    "GB-SHN" => "St. Helens",
+    "GB-SHR" => "Shropshire",
+    "GB-SKP" => "Stockport",
+    "GB-SLF" => "Salford",
+    "GB-SLG" => "Slough",
+    "GB-SLK" => "South Lanarkshire",
+    "GB-SND" => "Sunderland",
+    "GB-SOL" => "Solihull",
+    "GB-SOM" => "Somerset",
+    "GB-SOS" => "Southend-on-Sea",
+    "GB-SRY" => "Surrey",
+    "GB-STE" => "Stoke-on-Trent",
+    "GB-STG" => "Stirling",
+    "GB-STH" => "Southampton",
+    "GB-STN" => "Sutton",
+    "GB-STS" => "Staffordshire",
+    "GB-STT" => "Stockton-on-Tees",
+    "GB-STY" => "South Tyneside",
+    "GB-SWD" => "Swindon",
+    "GB-SWK" => "Southwark",
+    "GB-TAM" => "Tameside",
+    "GB-TFW" => "Telford and Wrekin",
+    "GB-THR" => "Thurrock",
+    "GB-TOB" => "Torbay",
+    "GB-TRF" => "Trafford",
+    "GB-TWH" => "Tower Hamlets",
+    "GB-WAR" => "Warwickshire",
+    "GB-WBK" => "West Berkshire",
+    "GB-WDU" => "West Dunbartonshire",
+    "GB-WFT" => "Waltham Forest",
+    "GB-WGN" => "Wigan",
+    "GB-WIL" => "Wiltshire",
+    "GB-WKF" => "Wakefield",
+    "GB-WLL" => "Walsall",
+    "GB-WLN" => "West Lothian",
+    "GB-WLV" => "Wolverhampton",
+    "GB-WND" => "Wandsworth",
+    "GB-WNM" => "Windsor and Maidenhead",
+    "GB-WOK" => "Wokingham",
+    "GB-WOR" => "Worcestershire",
+    "GB-WRL" => "Wirral",
+    "GB-WRT" => "Warrington",
+    "GB-WSM" => "Westminster",
+    "GB-WSX" => "West Sussex",
+    "GB-YOR" => "York",
+    "GB-ZET" => "Shetlandsöarna",
+    "US-AK" => "Alaska",
+    "US-AL" => "Alabama",
+    "US-AR" => "Arkansas",
+    "US-AS" => "Amerikanska Samoa",
+    "US-AZ" => "Arizona",
+    "US-CA" => "Kalifornien",
+    "US-CO" => "Colorado",
+    "US-CT" => "Connecticut",
+    "US-DC" => "District of Columbia",
+    "US-DE" => "Delaware",
+    "US-FL" => "Florida",
+    "US-GA" => "Georgien",
+    "US-GU" => "Guam",
+    "US-HI" => "Hawaii",
+    "US-IA" => "Iowa",
+    "US-ID" => "Idaho",
+    "US-IL" => "Illinois",
+    "US-IN" => "Indiana",
+    "US-KS" => "Kansas",
+    "US-KY" => "Kentucky",
+    "US-LA" => "Louisiana",
+    "US-MA" => "Massachusetts",
+    "US-MD" => "Maryland",
+    "US-ME" => "Maine",
+    "US-MI" => "Michigan",
+    "US-MN" => "Minnesota",
+    "US-MO" => "Missouri",
+    "US-MP" => "Nordmarianerna",
+    "US-MS" => "Mississippi",
+    "US-MT" => "Montana",
+    "US-NC" => "North Carolina",
+    "US-ND" => "North Dakota",
+    "US-NE" => "Nebraska",
+    "US-NH" => "New Hampshire",
+    "US-NJ" => "New Jersey",
+    "US-NM" => "New Mexico",
+    "US-NV" => "Nevada",
+    "US-NY" => "New York",
+    "US-OH" => "Ohio",
+    "US-OK" => "Oklahoma",
+    "US-OR" => "Oregon",
+    "US-PA" => "Pennsylvania",
+    "US-PR" => "Puerto Rico",
+    "US-RI" => "Rhode Island",
+    "US-SC" => "South Carolina",
+    "US-SD" => "South Dakota",
+    "US-TN" => "Tennessee",
+    "US-TX" => "Texas",
+    "US-UM" => "Förenta staternas mindre öar i Oceanien och Västindien",
+    "US-UT" => "Utah",
+    "US-VA" => "Virginia",
+    "US-VT" => "Vermont",
+    "US-WA" => "Washington",
+    "US-WI" => "Wisconsin",
+    "US-WV" => "West Virginia",
+    "US-WY" => "Wyoming",
+    "UM-67" => "Johnstonatollen",
+    "UM-71" => "Midwayöarna",
+    "UM-76" => "Navassaön",
+    "UM-79" => "Wakeöarna",
+    "UM-81" => "Bakerön",
+    "UM-84" => "Howlandön",
+    "UM-86" => "Jarvisön",
+    "UM-89" => "Kingmanrevet",
+    "UM-95" => "Palmyraatollen",
+    "UY-AR" => "Artigas",
+    "UY-CA" => "Canelones",
+    "UY-CL" => "Cerro Largo",
+    "UY-CO" => "Colonia",
+    "UY-DU" => "Durazno",
+    "UY-FD" => "Florida",
+    "UY-FS" => "Flores",
+    "UY-LA" => "Lavalleja",
+    "UY-MA" => "Maldonado",
+    "UY-MO" => "Montevideo",
+    "UY-PA" => "Paysandú",
+    "UY-RN" => "Río Negro",
+    "UY-RO" => "Rocha",
+    "UY-RV" => "Rivera",
+    "UY-SA" => "Salto",
+    "UY-SJ" => "San José",
+    "UY-SO" => "Soriano",
+    "UY-TA" => "Tacuarembó",
+    "UY-TT" => "Treinta y Tres",
+    "UZ-AN" => "Andizjan",
+    "UZ-BU" => "Buchara",
+    "UZ-JI" => "Dzjizzach",
+    "UZ-NG" => "Namangan",
+    "UZ-NW" => "Navoi",
+    "UZ-QA" => "Qasjqadarja",
+    "UZ-SA" => "Samarkand",
+    "UZ-SI" => "Syr-Darja",
+    "UZ-SU" => "Surchondarja",
+    "UZ-TK" => "Toshkent",
+    "UZ-TO" => "Toshkent",
+    "UZ-XO" => "Khwarezm",
+    "VU-MAP" => "Malampa",
+    "VU-PAM" => "Pénama",
+    "VU-SAM" => "Sanma",
+    "VU-SEE" => "Shéfa",
+    "VU-TAE" => "Taféa",
+    "VU-TOB" => "Torba",
+    "VE-B" => "Anzoátegui",
+    "VE-C" => "Apure",
+    "VE-D" => "Aragua",
+    "VE-E" => "Barinas",
+    "VE-F" => "Bolívar",
+    "VE-G" => "Carabobo",
+    "VE-H" => "Cojedes",
+    "VE-I" => "Falcón",
+    "VE-J" => "Guárico",
+    "VE-K" => "Lara",
+    "VE-L" => "Mérida",
+    "VE-M" => "Miranda",
+    "VE-N" => "Monagas",
+    "VE-O" => "Nueva Esparta",
+    "VE-P" => "Portuguesa",
+    "VE-R" => "Sucre",
+    "VE-S" => "Táchira",
+    "VE-T" => "Trujillo",
+    "VE-U" => "Yaracuy",
+    "VE-V" => "Zulia",
+    "VE-W" => "Dependencias Federales",
+    "VE-Y" => "Delta Amacuro",
+    "VE-Z" => "Amazonas",
+    "VN-01" => "Lai Châu",
+    "VN-02" => "Lào Cai",
+    "VN-03" => "Hà Giang",
+    "VN-04" => "Cao Bằng",
+    "VN-05" => "Sơn La",
+    "VN-06" => "Yên Bái",
+    "VN-07" => "Tuyên Quang",
+    "VN-09" => "Lạng Sơn",
+    "VN-13" => "Quảng Ninh",
+    "VN-18" => "Ninh Bình",
+    "VN-20" => "Thái Bình",
+    "VN-21" => "Thanh Hóa",
+    "VN-22" => "Nghệ An",
+    "VN-24" => "Quảng Bình",
+    "VN-25" => "Quảng Trị",
+    "VN-26" => "Thừa Thiên-Huế",
+    "VN-27" => "Quảng Nam",
+    "VN-28" => "Kon Tum",
+    "VN-29" => "Quảng Ngãi",
+    "VN-30" => "Gia Lai",
+    "VN-31" => "Bình Định",
+    "VN-32" => "Phú Yên",
+    "VN-34" => "Khánh Hòa",
+    "VN-35" => "Lâm Đồng",
+    "VN-36" => "Ninh Thuận",
+    "VN-37" => "Tây Ninh",
+    "VN-39" => "Đồng Nai",
+    "VN-40" => "Bình Thuận",
+    "VN-41" => "Long An",
+    "VN-44" => "An Giang",
+    "VN-45" => "Đồng Tháp",
+    "VN-46" => "Tiền Giang",
+    "VN-49" => "Vĩnh Long",
+    "VN-50" => "Bến Tre",
+    "VN-51" => "Trà Vinh",
+    "VN-52" => "Sóc Trăng",
+    "VN-53" => "Bắc Kạn",
+    "VN-54" => "Bắc Giang",
+    "VN-55" => "Bạc Liêu",
+    "VN-56" => "Bắc Ninh",
+    "VN-57" => "Bình Dương",
+    "VN-58" => "Bình Phước",
+    "VN-59" => "Cà Mau",
+    "VN-63" => "Hà Nam",
+    "VN-66" => "Hưng Yên",
+    "VN-67" => "Nam Định",
+    "VN-68" => "Phú Thọ",
+    "VN-69" => "Thái Nguyên",
+    "VN-70" => "Vĩnh Phúc",
+    "VN-71" => "Điện Biên",
+    "VN-72" => "Đắk Nông",
+    "VN-73" => "Hậu Giang",
+    "VN-CT" => "Cần Thơ",
+    "VN-DN" => "Đà Nẵng",
+    "VN-HN" => "Hà Nội",
+    "VN-HP" => "Hải Phòng",
+    "YE-DH" => "Dhamar",
+    "YE-HD" => "Hadhramaut",
+    "YE-HJ" => "Hajjah",
+    "YE-HU" => "Al Hudaydah",
+    "YE-IB" => "Ibb",
+    "YE-JA" => "Al Jawf",
+    "YE-LA" => "Lahij",
+    "YE-MR" => "Al Mahrah",
+    "YE-MW" => "Al Mahwit",
+    "YE-RA" => "Raymah",
+    "YE-SH" => "Shabwah",
+    "ZM-01" => "Västra",
+    "ZM-02" => "Central",
+    "ZM-03" => "Östra",
+    "ZM-04" => "Luapula",
+    "ZM-05" => "Norra",
+    "ZM-06" => "Nordvästra Zambia",
+    "ZM-07" => "Southern",
+    "ZM-08" => "Copperbelt",
+    "ZM-09" => "Lusaka",
+    "ZW-BU" => "Bulawayo",
+    "ZW-HA" => "Harare",
+    "ZW-MA" => "Manicaland",
+    "ZW-MC" => "Mashonaland Central",
+    "ZW-ME" => "Mashonaland East",
+    "ZW-MI" => "Midlands",
+    "ZW-MN" => "Matabeleland North",
+    "ZW-MS" => "Matabeleland South",
+    "ZW-MV" => "Masvingo",
+    "ZW-MW" => "Mashonaland West",
+};
+
+///Swedish former country names by alpha-4 code
+pub const FORMER_NAME_MAP: Map<&str, &str> = phf_map! {
+    "BQAQ" => "Brittiska antarktiska territoriet",
+    "BUMM" => "Burman, socialistrepubliken",
+    "BYAA" => "Vitryska SSR",
+    "CTKI" => "Canton och Enderburyöarna",
+    "CSHH" => "Tjeckoslovakien, tjeckoslovakiska socialistrepubliken",
+    "DYBJ" => "Dahomey",
+    "NQAQ" => "Drottning Mauds land",
+    "TPTL" => "Östtimor",
+    "FXFR" => "Frankrike, Metropolitan",
+    "AIDJ" => "Franska Afars och Issas",
+    "FQHH" => "Franska antarktiska och sydterritorierna",
+    "DDDE" => "Tyska demokratiska republiken",
+    "GEHH" => "Gilbert och Elliceöarna",
+    "JTUM" => "Johnstonön",
+    "MIUM" => "Midwayöarna",
+    "ANHH" => "Nederländska Antillerna",
+    "NTHH" => "Neutral zon",
+    "NHVU" => "Nya Hebriderna",
+    "PCHH" => "Stillahavsöar (trust territory)",
+    "PZPA" => "Panamas kanalzon",
+    "CSXX" => "Serbien och Montenegro",
+    "SKIN" => "Sikkim",
+    "RHZW" => "Södra Rhodesia",
+    "PUUM" => "Diverse amerikanska stillahavsöar",
+    "HVBF" => "Övre Volta, republiken",
+    "SUHH" => "Sovjetunionen",
+    "VDVN" => "Vietnam, demokratiska republiken",
+    "WKUM" => "Wakeöarna",
+    "YDYE" => "Jemen, demokratiska, demokratiska folkrepubliken",
+    "YUCS" => "Jugoslavien, socialistiska federativa republiken",
+    "ZRCD" => "Zaire, republiken",
+};
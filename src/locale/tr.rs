@@ -0,0 +1,2318 @@
+use phf::phf_map;
+use phf::Map;
+
+///Turkish country names by alpha-2 code
+pub const COUNTRY_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF" => "Afganistan",
+    "AX" => "Åland Adaları",
+    "AL" => "Arnavutluk",
+    "DZ" => "Cezayir",
+    "AS" => "Amerikan Samoası",
+    "AD" => "Andorra",
+    "AO" => "Angola",
+    "AI" => "Anguilla",
+    "AQ" => "Antarktika",
+    "AG" => "Antigua ve Barbuda",
+    "AR" => "Arjantin",
+    "AM" => "Ermenistan",
+    "AW" => "Aruba",
+    "AU" => "Avustralya",
+    "AT" => "Avusturya",
+    "AZ" => "Azerbaycan",
+    "BS" => "Bahamalar",
+    "BH" => "Bahreyn",
+    "BD" => "Bangladeş",
+    "BB" => "Barbados",
+    "BY" => "Belarus",
+    "BE" => "Belçika",
+    "BZ" => "Belize",
+    "BJ" => "Benin",
+    "BM" => "Bermuda",
+    "BT" => "Bhutan",
+    "BO" => "Bolivya Çokuluslu Devleti",
+    "BQ" => "Bonaire, Sint Eustatius ve Saba",
+    "BA" => "Bosna-Hersek",
+    "BW" => "Botsvana",
+    "BV" => "Bouvet Adası",
+    "BR" => "Brezilya",
+    "IO" => "Britanya Hint Okyanusu Toprakları",
+    "BN" => "Brunei Krallığı",
+    "BG" => "Bulgaristan",
+    "BF" => "Burkina Faso",
+    "BI" => "Burundi",
+    "CV" => "Yeşil Burun Adaları",
+    "KH" => "Kamboçya",
+    "CM" => "Kamerun",
+    "CA" => "Kanada",
+    "KY" => "Cayman Adaları",
+    "CF" => "Orta Afrika Cumhuriyeti",
+    "TD" => "Çad",
+    "CL" => "Şili",
+    "CN" => "Çin",
+    "CX" => "Christmas Adası",
+    "CC" => "Cocos (Keeling) Adaları",
+    "CO" => "Kolombiya",
+    "KM" => "Komorlar",
+    "CG" => "Kongo",
+    "CD" => "Kongo Demokratik Cumhuriyeti",
+    "CK" => "Cook Adaları",
+    "CR" => "Kosta Rika",
+    "CI" => "Fildişi Sahili",
+    "HR" => "Hırvatistan",
+    "CU" => "Küba",
+    "CW" => "Curaçao",
+    "CY" => "Kıbrıs",
+    "CZ" => "Çekya",
+    "DK" => "Danimarka",
+    "DJ" => "Cibuti",
+    "DM" => "Dominika",
+    "DO" => "Dominik Cumhuriyeti",
+    "EC" => "Ekvador",
+    "EG" => "Mısır",
+    "SV" => "El Salvador",
+    "GQ" => "Ekvator Ginesi",
+    "ER" => "Eritre",
+    "EE" => "Estonya",
+    "SZ" => "Eswatini",
+    "ET" => "Etiyopya",
+    "FK" => "Falkland Adaları (Malvinas)",
+    "FO" => "Faroe Adaları",
+    "FJ" => "Fiji",
+    "FI" => "Finlandiya",
+    "FR" => "Fransa",
+    "GF" => "Fransız Guyanası",
+    "PF" => "Fransız Polinezyası",
+    "TF" => "Fransız Güney Bölgeleri",
+    "GA" => "Gabon",
+    "GM" => "Gambiya",
+    "GE" => "Gürcistan",
+    "DE" => "Almanya",
+    "GH" => "Gana",
+    "GI" => "Cebelitarık",
+    "GR" => "Yunanistan",
+    "GL" => "Grönland",
+    "GD" => "Grenada",
+    "GP" => "Guadeloupe",
+    "GU" => "Guam",
+    "GT" => "Guatemala",
+    "GG" => "Guernsey",
+    "GN" => "Gine",
+    "GW" => "Gine-Bissau",
+    "GY" => "Guyana",
+    "HT" => "Haiti",
+    "HM" => "Heard Adası ve McDonald Adaları",
+    "VA" => "Holy See (Vatikan Şehir Devleti)",
+    "HN" => "Honduras",
+    "HK" => "Hong Kong",
+    "HU" => "Macaristan",
+    "IS" => "İzlanda",
+    "IN" => "Hindistan",
+    "ID" => "Endonezya",
+    "IR" => "İran İslâm Cumhuriyeti",
+    "IQ" => "Irak",
+    "IE" => "İrlanda",
+    "IM" => "Man Adası",
+    "IL" => "İsrail",
+    "IT" => "İtalya",
+    "JM" => "Jamaika",
+    "JP" => "Japonya",
+    "JE" => "Jersey",
+    "JO" => "Ürdün",
+    "KZ" => "Kazakistan",
+    "KE" => "Kenya",
+    "KI" => "Kiribati",
+    "KP" => "Kore Demokratik Halk Cumhuriyeti",
+    "KR" => "Kore Cumhuriyeti",
+    "KW" => "Kuveyt",
+    "KG" => "Kırgızistan",
+    "LA" => "Lao Demokratik Halk Cumhuriyeti",
+    "LV" => "Letonya",
+    "LB" => "Lübnan",
+    "LS" => "Lesoto",
+    "LR" => "Liberya",
+    "LY" => "Libya",
+    "LI" => "Lihtenştayn",
+    "LT" => "Litvanya",
+    "LU" => "Lüksemburg",
+    "MO" => "Makao",
+    "MG" => "Madagaskar",
+    "MW" => "Malavi",
+    "MY" => "Malezya",
+    "MV" => "Maldivler",
+    "ML" => "Mali",
+    "MT" => "Malta",
+    "MH" => "Marşal Adaları",
+    "MQ" => "Martinique",
+    "MR" => "Moritanya",
+    "MU" => "Mauritius",
+    "YT" => "Mayotte",
+    "MX" => "Meksika",
+    "FM" => "Mikronezya Federe Devletleri",
+    "MD" => "Moldova Cumhuriyeti",
+    "MC" => "Monako",
+    "MN" => "Moğolistan",
+    "ME" => "Karadağ",
+    "MS" => "Montserrat",
+    "MA" => "Fas",
+    "MZ" => "Mozambik",
+    "MM" => "Myanmar",
+    "NA" => "Namibya",
+    "NR" => "Nauru",
+    "NP" => "Nepal",
+    "NL" => "Hollanda",
+    "NC" => "Yeni Kaledonya",
+    "NZ" => "Yeni Zelanda",
+    "NI" => "Nikaragua",
+    "NE" => "Nijer",
+    "NG" => "Nijerya",
+    "NU" => "Niue",
+    "NF" => "Norfolk Adası",
+    "MK" => "Kuzey Makedonya",
+    "MP" => "Kuzey Mariana Adaları",
+    "NO" => "Norveç",
+    "OM" => "Umman",
+    "PK" => "Pakistan",
+    "PW" => "Palau",
+    "PS" => "Filistin Devleti",
+    "PA" => "Panama",
+    "PG" => "Papua Yeni Gine",
+    "PY" => "Paraguay",
+    "PE" => "Peru",
+    "PH" => "Filipinler",
+    "PN" => "Pitcairn",
+    "PL" => "Polonya",
+    "PT" => "Portekiz",
+    "PR" => "Porto Riko",
+    "QA" => "Katar",
+    "RE" => "Réunion",
+    "RO" => "Romanya",
+    "RU" => "Rusya Federasyonu",
+    "RW" => "Ruanda",
+    "BL" => "Saint Barthélemy",
+    "SH" => "Saint Helena, Ascension ve Tristan da Cunha",
+    "KN" => "Saint Kitts ve Nevis",
+    "LC" => "Saint Lucia",
+    "MF" => "Saint Martin (Fransız kısmı)",
+    "PM" => "Saint Pierre ve Miquelon",
+    "VC" => "Saint Vincent ve Grenadinler",
+    "WS" => "Samoa",
+    "SM" => "San Marino",
+    "ST" => "Sao Tome ve Principe",
+    "SA" => "Suudi Arabistan",
+    "SN" => "Senegal",
+    "RS" => "Sırbistan",
+    "SC" => "Seyşeller",
+    "SL" => "Sierra Leone",
+    "SG" => "Singapur",
+    "SX" => "Sint Maarten (Hollanda kısmı)",
+    "SK" => "Slovakya",
+    "SI" => "Slovenya",
+    "SB" => "Solomon Adaları",
+    "SO" => "Somali",
+    "ZA" => "Güney Afrika",
+    "GS" => "Güney Georgia ve Güney Sandwich Adaları",
+    "SS" => "Güney Sudan",
+    "ES" => "İspanya",
+    "LK" => "Sri Lanka",
+    "SD" => "Sudan",
+    "SR" => "Surinam",
+    "SJ" => "Svalbard ve Jan Mayen",
+    "SE" => "İsveç",
+    "CH" => "İsviçre",
+    "SY" => "Suriye Arap Cumhuriyeti",
+    "TW" => "Tayvan, Çin Eyaleti",
+    "TJ" => "Tacikistan",
+    "TZ" => "Tanzanya Birleşik Cumhuriyeti",
+    "TH" => "Tayland",
+    "TL" => "Timor-Leste",
+    "TG" => "Togo",
+    "TK" => "Tokelau",
+    "TO" => "Tonga",
+    "TT" => "Trinidad ve Tobago",
+    "TN" => "Tunus",
+    "TR" => "Türkiye",
+    "TM" => "Türkmenistan",
+    "TC" => "Turks ve Caicos Adaları",
+    "TV" => "Tuvalu",
+    "UG" => "Uganda",
+    "UA" => "Ukrayna",
+    "AE" => "Birleşik Arap Emirlikleri",
+    "GB" => "Birleşik Krallık",
+    "US" => "Amerika Birleşik Devletleri",
+    "UM" => "Amerika Birleşik Devletleri Küçük Dış Adaları",
+    "UY" => "Uruguay",
+    "UZ" => "Özbekistan",
+    "VU" => "Vanuatu",
+    "VE" => "Venezuela Bolivar Cumhuriyeti",
+    "VN" => "Vietnam",
+    "VG" => "İngiliz Virgin Adaları",
+    "VI" => "Virgin Adaları, A.B.D.",
+    "WF" => "Wallis ve Futuna Adaları",
+    "EH" => "Batı Sahra",
+    "YE" => "Yemen",
+    "ZM" => "Zambiya",
+    "ZW" => "Zimbabve",
+};
+
+///Turkish subdivision names by ISO 3166-2 code, 2024 of 5046
+pub const SUBDIVISION_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF-BAL" => "Balkh",
+    "AF-BAM" => "Bāmyān",
+    "AF-BDG" => "Bādghīs",
+    "AF-BDS" => "Badakhshān",
+    "AF-BGL" => "Baghlān",
+    "AF-DAY" => "Dāykundī",
+    "AF-FRA" => "Farāh",
+    "AF-FYB" => "Fāryāb",
+    "AF-GHA" => "Ghaznī",
+    "AF-GHO" => "Ghōr",
+    "AF-HEL" => "Helmand",
+    "AF-HER" => "Herāt",
+    "AF-JOW" => "Jowzjān",
+    "AF-KAB" => "Kabil",
+    "AF-KAN" => "Kandahār",
+    "AF-KAP" => "Kāpīsā",
+    "AF-KDZ" => "Kunduz",
+    "AF-KHO" => "Khōst",
+    "AF-LAG" => "Laghmān",
+    "AF-LOG" => "Lōgar",
+    "AF-NAN" => "Nangarhār",
+    "AF-NIM" => "Nīmrōz",
+    "AF-NUR" => "Nūristān",
+    "AF-PAN" => "Panjshayr",
+    "AF-PAR" => "Parwān",
+    "AF-PIA" => "Paktiyā",
+    "AF-PKA" => "Paktīkā",
+    "AF-SAM" => "Samangān",
+    "AF-SAR" => "Sar-e Pul",
+    "AF-TAK" => "Takhār",
+    "AF-URU" => "Uruzgān",
+    "AF-WAR" => "Wardak",
+    "AF-ZAB" => "Zābul",
+    "AL-01" => "Berat",
+    "AL-02" => "Durrës",
+    "AL-03" => "Elbasan",
+    "AL-04" => "Fier",
+    "AL-05" => "Gjirokastër",
+    "AL-06" => "Korçë",
+    "AL-07" => "Kukës",
+    "AL-08" => "Lezhë",
+    "AL-09" => "Dibër",
+    "AL-10" => "Shkodër",
+    "AL-11" => "Tiran",
+    "AL-12" => "Vlorë",
+    "DZ-01" => "Adrar",
+    "DZ-02" => "Chlef",
+    "DZ-05" => "Batna",
+    "DZ-08" => "Bechar",
+    "DZ-09" => "Blida",
+    "DZ-10" => "Bouira",
+    "DZ-12" => "Tebessa",
+    "DZ-13" => "Tlemcen",
+    "DZ-16" => "Cezayir",
+    "DZ-20" => "Saida",
+    "DZ-23" => "Annaba",
+    "DZ-24" => "Guelma",
+    "DZ-26" => "Medea",
+    "DZ-29" => "Mascara",
+    "DZ-31" => "Oran",
+    "DZ-32" => "El Bayadh",
+    "DZ-33" => "Illizi",
+    "DZ-41" => "Souk Ahras",
+    "DZ-43" => "Mila",
+    "DZ-45" => "Naama",
+    "DZ-47" => "Ghardaia",
+    "DZ-48" => "Relizane",
+    "AD-02" => "Canillo",
+    "AD-03" => "Encamp",
+    "AD-04" => "La Massana",
+    "AD-05" => "Ordino",
+    "AD-06" => "Sant Julià de Lòria",
+    "AD-07" => "Andorra la Vella",
+    "AD-08" => "Escaldes-Engordany",
+    "AO-BGO" => "Bengo",
+    "AO-BGU" => "Benguela",
+    "AO-BIE" => "Bie",
+    "AO-CAB" => "Cabinda",
+    "AO-CNN" => "Cunene",
+    "AO-HUA" => "Huambo",
+    "AO-HUI" => "Huila",
+    "AO-LUA" => "Luanda",
+    "AO-MAL" => "Malange",
+    "AO-MOX" => "Moxico",
+    "AO-NAM" => "Namibe",
+    "AO-UIG" => "Uíge",
+    "AO-ZAI" => "Zaire",
+    "AG-03" => "Saint George",
+    "AG-04" => "Saint John",
+    "AG-05" => "Saint Mary",
+    "AG-06" => "Saint Paul",
+    "AG-07" => "Saint Peter",
+    "AG-08" => "Saint Philip",
+    "AG-10" => "Barbuda",
+    "AG-11" => "Redonda",
+    "AR-A" => "Salta",
+    "AR-B" => "Buenos Aires",
+    "AR-C" => "Buenos Aires Özerk Şehri",
+    "AR-D" => "San Luis",
+    "AR-G" => "Santiago del Estero",
+    "AR-H" => "Chaco",
+    "AR-J" => "San Juan",
+    "AR-K" => "Catamarca",
+    "AR-L" => "La Pampa",
+    "AR-M" => "Mendoza",
+    "AR-N" => "Misiones",
+    "AR-P" => "Formosa",
+    "AR-S" => "Santa Fe",
+    "AR-U" => "Chubut",
+    "AR-V" => "Tierra del Fuego",
+    "AR-W" => "Corrientes",
+    "AR-X" => "Córdoba",
+    "AR-Y" => "Jujuy",
+    "AR-Z" => "Santa Cruz",
+    "AM-AR" => "Ararat",
+    "AM-AV" => "Armavir",
+    "AM-ER" => "Erivan",
+    "AM-KT" => "Kotayk",
+    "AM-SU" => "Syunik",
+    "AU-ACT" => "Avustralya Başkent Bölgesi",
+    "AU-NSW" => "Yeni Güney Galler",
+    "AU-NT" => "Kuzey Bölgesi",
+    "AU-QLD" => "Queensland",
+    "AU-SA" => "Güney Avustralya",
+    "AU-TAS" => "Tazmanya",
+    "AU-VIC" => "Victoria",
+    "AU-WA" => "Batı Avustralya",
+    "AT-1" => "Burgenland",
+    "AT-2" => "Karnten",
+    "AT-3" => "Niederösterreich",
+    "AT-4" => "Oberösterreich",
+    "AT-5" => "Salzburg",
+    "AT-6" => "Steiermark",
+    "AT-7" => "Tirol",
+    "AT-8" => "Vorarlberg",
+    "AT-9" => "Wien",
+    "AZ-ABS" => "Abşeron",
+    "AZ-AGA" => "Ağstafa",
+    "AZ-AGC" => "Ağcabedi",
+    "AZ-AGM" => "Ağdam",
+    "AZ-AGS" => "Ağdaş",
+    "AZ-AGU" => "Ağsu",
+    "AZ-AST" => "Astara",
+    "AZ-BA" => "Bakı",
+    "AZ-BAB" => "Babək",
+    "AZ-BAL" => "Balakən",
+    "AZ-BEY" => "Beyləqan",
+    "AZ-BIL" => "Biləsuvar",
+    "AZ-CUL" => "Culfa",
+    "AZ-GOR" => "Goranboy",
+    "AZ-LER" => "Lerik",
+    "AZ-MAS" => "Masallı",
+    "AZ-NEF" => "Neftçala",
+    "AZ-ORD" => "Ordubad",
+    "AZ-QAZ" => "Qazax",
+    "AZ-QBA" => "Quba",
+    "AZ-QBI" => "Qubadlı",
+    "AZ-QUS" => "Qusar",
+    "AZ-SAB" => "Sabirabad",
+    "AZ-SAL" => "Salyan",
+    "AZ-SAT" => "Saatlı",
+    "AZ-SMX" => "Samux",
+    "AZ-XCI" => "Xocalı",
+    "AZ-ZAN" => "Zəngilan",
+    "BS-AK" => "Acklins",
+    "BS-BI" => "Bimini",
+    "BS-BY" => "Berry Adaları",
+    "BS-CE" => "Merkez Eleuthera",
+    "BS-CI" => "Cat Adası",
+    "BS-CO" => "Merkez Abaco",
+    "BS-CS" => "Merkez Andros",
+    "BS-EG" => "Doğu Grand Bahama",
+    "BS-GC" => "Grand Cay",
+    "BS-HI" => "Harbour Adası",
+    "BS-IN" => "Inagua",
+    "BS-LI" => "Long Island",
+    "BS-MG" => "Mayaguana",
+    "BS-MI" => "Moore Adası",
+    "BS-NE" => "Kuzey Eleuthera",
+    "BS-NO" => "Kuzey Abaco",
+    "BS-NS" => "Kuzey Andros",
+    "BS-RI" => "Ragged Adası",
+    "BS-SA" => "Güney Andros",
+    "BS-SE" => "Güney Eleuthera",
+    "BS-SO" => "Güney Abaco",
+    "BS-SS" => "San Salvador",
+    "BS-WG" => "Batı Grand Bahama",
+    "BH-14" => "Al Janūbīyah",
+    "BH-15" => "Al Muḩarraq",
+    "BD-01" => "Bandarban",
+    "BD-02" => "Barguna",
+    "BD-04" => "Brahmanbaria",
+    "BD-05" => "Bagerhat",
+    "BD-07" => "Bhola",
+    "BD-09" => "Chandpur",
+    "BD-12" => "Chuadanga",
+    "BD-13" => "Dakka",
+    "BD-19" => "Gaibandha",
+    "BD-20" => "Habiganj",
+    "BD-21" => "Jamalpur",
+    "BD-33" => "Manikganj",
+    "BD-35" => "Munshiganj",
+    "BD-37" => "Magura",
+    "BD-40" => "Narayanganj",
+    "BD-41" => "Netrakona",
+    "BD-43" => "Narail",
+    "BD-44" => "Natore",
+    "BD-49" => "Pabna",
+    "BD-51" => "Patuakhali",
+    "BD-52" => "Panchagarh",
+    "BD-55" => "Rangpur",
+    "BD-56" => "Rangamati",
+    "BD-63" => "Tangail",
+    "BD-64" => "Thakurgaon",
+    "BD-C" => "Dakka",
+    "BD-F" => "Rangpur",
+    "BB-03" => "Saint George",
+    "BB-04" => "Saint James",
+    "BB-05" => "Saint John",
+    "BB-06" => "Saint Joseph",
+    "BB-07" => "Saint Lucy",
+    "BB-08" => "Saint Michael",
+    "BB-09" => "Saint Peter",
+    "BB-10" => "Saint Philip",
+    "BB-11" => "Saint Thomas",
+    "BE-VLI" => "Limburg",
+    "BE-WHT" => "Hainaut",
+    "BE-WLG" => "Liège",
+    "BE-WLX" => "Lüksemburg",
+    "BE-WNA" => "Namur",
+    "BZ-BZ" => "Belize",
+    "BZ-TOL" => "Toledo",
+    "BJ-AL" => "Alibori",
+    "BJ-AQ" => "Atlantique",
+    "BJ-BO" => "Borgou",
+    "BJ-CO" => "Collines",
+    "BJ-DO" => "Donga",
+    "BJ-LI" => "Littoral",
+    "BJ-MO" => "Mono",
+    "BJ-PL" => "Plato",
+    "BJ-ZO" => "Zou",
+    "BT-22" => "Dagana",
+    "BT-23" => "Punakha",
+    "BT-31" => "Sarpang",
+    "BT-32" => "Trongsa",
+    "BT-33" => "Bumthang",
+    "BT-41" => "Trashigang",
+    "BT-42" => "Monggar",
+    "BT-GA" => "Gasa",
+    "BO-B" => "El Beni",
+    "BO-L" => "La Paz",
+    "BO-N" => "Pando",
+    "BO-P" => "Potosí",
+    "BO-S" => "Santa Cruz",
+    "BQ-BO" => "Bonaire",
+    "BQ-SA" => "Saba",
+    "BQ-SE" => "Sint Eustatius",
+    "BA-BIH" => "Bosna Hersek Federasyonu",
+    "BW-CE" => "Merkezi",
+    "BW-GH" => "Ghanzi",
+    "BW-KG" => "Kgalagadi",
+    "BW-KW" => "Kweneng",
+    "BW-NE" => "Kuzey Doğu",
+    "BW-NW" => "Kuzey Batı",
+    "BW-SE" => "Güney Doğu",
+    "BW-SO" => "Güney",
+    "BR-AL" => "Alagoas",
+    "BR-AM" => "Amazonas",
+    "BR-AP" => "Amapá",
+    "BR-BA" => "Bahia",
+    "BR-CE" => "Ceará",
+    "BR-GO" => "Goiás",
+    "BR-MA" => "Maranhão",
+    "BR-PA" => "Pará",
+    "BR-PB" => "Paraíba",
+    "BR-PI" => "Piauí",
+    "BR-PR" => "Paraná",
+    "BR-RO" => "Rondônia",
+    "BR-RR" => "Roraima",
+    "BR-SC" => "Santa Catarina",
+    "BR-SP" => "São Paulo",
+    "BR-TO" => "Tocantins",
+    "BN-BE" => "Belait",
+    "BN-BM" => "Brunei-Muara",
+    "BN-TE" => "Temburong",
+    "BN-TU" => "Tutong",
+    "BG-03" => "Varna",
+    "BG-05" => "Vidin",
+    "BG-06" => "Vratsa",
+    "BG-07" => "Gabrovo",
+    "BG-08" => "Dobriç",
+    "BG-09" => "Kırcaali",
+    "BG-10" => "Köstendil",
+    "BG-11" => "Lofça",
+    "BG-12" => "Montana",
+    "BG-14" => "Pernik",
+    "BG-15" => "Plevne",
+    "BG-17" => "Razgrad",
+    "BG-19" => "Silistre",
+    "BG-20" => "Sliven",
+    "BG-21" => "Smolyan",
+    "BG-23" => "Sofya",
+    "BG-24" => "Stara Zagora",
+    "BG-27" => "Şumnu",
+    "BG-28" => "Yambol",
+    "BF-02" => "Cascades",
+    "BF-03" => "Merkez",
+    "BF-04" => "Centre-Est",
+    "BF-05" => "Centre-Nord",
+    "BF-06" => "Centre-Ouest",
+    "BF-07" => "Centre-Sud",
+    "BF-08" => "Est",
+    "BF-10" => "Nord",
+    "BF-11" => "Plato-Merkez",
+    "BF-BAL" => "Balé",
+    "BF-BAM" => "Bam",
+    "BF-BAN" => "Banwa",
+    "BF-BAZ" => "Bazèga",
+    "BF-BGR" => "Bougouriba",
+    "BF-BLK" => "Boulkiemdé",
+    "BF-COM" => "Comoé",
+    "BF-GNA" => "Gnagna",
+    "BF-GOU" => "Gourma",
+    "BF-IOB" => "Ioba",
+    "BF-KMP" => "Kompienga",
+    "BF-KOP" => "Koulpélogo",
+    "BF-KOT" => "Kouritenga",
+    "BF-LER" => "Léraba",
+    "BF-OUB" => "Oubritenga",
+    "BF-OUD" => "Oudalan",
+    "BF-PON" => "Poni",
+    "BF-SEN" => "Séno",
+    "BF-SIS" => "Sissili",
+    "BF-SMT" => "Sanmatenga",
+    "BF-SNG" => "Sanguié",
+    "BF-TAP" => "Tapoa",
+    "BF-ZIR" => "Ziro",
+    "BI-BB" => "Bubanza",
+    "BI-BL" => "Kırsal Bujumbura",
+    "BI-BM" => "Bujumbura Mairie",
+    "BI-BR" => "Bururi",
+    "BI-GI" => "Gitega",
+    "BI-KI" => "Kirundo",
+    "BI-KR" => "Karuzi",
+    "BI-KY" => "Kayanza",
+    "BI-MA" => "Makamba",
+    "BI-MU" => "Muramvya",
+    "BI-MW" => "Mwaro",
+    "BI-RT" => "Rutana",
+    "CV-B" => "Ilhas de Barlavento",
+    "CV-CA" => "Santa Catarina",
+    "CV-CR" => "Santa Cruz",
+    "CV-MA" => "Maio",
+    "CV-MO" => "Mosteiros",
+    "CV-PR" => "Praia",
+    "CV-SL" => "Sal",
+    "CV-SM" => "São Miguel",
+    "KH-11" => "Mondol Kiri",
+    "KH-15" => "Pousaat",
+    "KH-20" => "Svaay Rieng",
+    "KH-7" => "Kampot",
+    "CM-AD" => "Adamaoua",
+    "CM-CE" => "Merkez",
+    "CM-LT" => "Littoral",
+    "CM-NW" => "Kuzey-Batı",
+    "CM-SW" => "Güney-Batı",
+    "CA-AB" => "Alberta",
+    "CA-BC" => "British Columbia",
+    "CA-MB" => "Manitoba",
+    "CA-NB" => "Yeni Brunswick",
+    "CA-NL" => "Newfoundland ve Labrador",
+    "CA-NS" => "Nova Scotia",
+    "CA-NT" => "Kuzeybatı Toprakları",
+    "CA-ON" => "Ontario",
+    "CA-PE" => "Prens Edward Adası",
+    "CA-QC" => "Quebec",
+    "CA-SK" => "Saskatchewan",
+    "CF-AC" => "Ouham",
+    "CF-BK" => "Basse-Kotto",
+    "CF-SE" => "Sangha",
+    "CL-AN" => "Antofagasta",
+    "CL-AT" => "Atacama",
+    "CL-ML" => "Maule",
+    "CL-TA" => "Tarapacá",
+    "CN-AH" => "Anhui Sheng",
+    "CN-BJ" => "Pekin Shi",
+    "CN-FJ" => "Fujian Sheng",
+    "CN-GS" => "Gansu Sheng",
+    "CN-HA" => "Henan Sheng",
+    "CN-HB" => "Hubei Sheng",
+    "CN-HE" => "Hebei Sheng",
+    "CN-HI" => "Hainan Sheng",
+    "CN-HN" => "Hunan Sheng",
+    "CN-SC" => "Sichuan Sheng",
+    "CN-SH" => "Şangay Shi",
+    "CO-AMA" => "Amazonas",
+    "CO-ARA" => "Arauca",
+    "CO-ATL" => "Atlántico",
+    "CO-BOL" => "Bolívar",
+    "CO-BOY" => "Boyacá",
+    "CO-CAL" => "Caldas",
+    "CO-CAU" => "Cauca",
+    "CO-CHO" => "Chocó",
+    "CO-COR" => "Córdoba",
+    "CO-GUA" => "Guainía",
+    "CO-GUV" => "Guaviare",
+    "CO-HUI" => "Huila",
+    "CO-MET" => "Meta",
+    "CO-NAR" => "Nariño",
+    "CO-QUI" => "Quindío",
+    "CO-SUC" => "Sucre",
+    "CO-TOL" => "Tolima",
+    "CG-12" => "Pool",
+    "CG-13" => "Sangha",
+    "CG-14" => "Plateaux",
+    "CG-2" => "Lékoumou",
+    "CG-5" => "Kouilou",
+    "CG-7" => "Likouala",
+    "CG-9" => "Niari",
+    "CG-BZV" => "Brazzaville",
+    "CD-KN" => "Kinshasa",
+    "CD-MA" => "Maniema",
+    "CR-G" => "Guanacaste",
+    "CR-L" => "Limón",
+    "CR-SJ" => "San José",
+    "CI-CM" => "Comoé",
+    "HR-21" => "Grad Zagreb",
+    "CU-04" => "Matanzas",
+    "CU-09" => "Camagüey",
+    "CU-11" => "Holguín",
+    "CU-12" => "Granma",
+    "CU-14" => "Guantánamo",
+    "CZ-201" => "Benešov",
+    "CZ-202" => "Beroun",
+    "CZ-203" => "Kladno",
+    "CZ-204" => "Kolín",
+    "CZ-208" => "Nymburk",
+    "CZ-209" => "Prag-doğu",
+    "CZ-20A" => "Prag-batı",
+    "CZ-317" => "Tábor",
+    "CZ-321" => "Domažlice",
+    "CZ-322" => "Klatovy",
+    "CZ-327" => "Tachov",
+    "CZ-41" => "Karlovy Vary bölgesi",
+    "CZ-411" => "Cheb",
+    "CZ-412" => "Karlovy Vary",
+    "CZ-422" => "Chomutov",
+    "CZ-424" => "Louny",
+    "CZ-425" => "Most",
+    "CZ-426" => "Teplice",
+    "CZ-513" => "Liberec",
+    "CZ-514" => "Semily",
+    "CZ-52" => "Hradec Králové bölgesi",
+    "CZ-522" => "Jičín",
+    "CZ-525" => "Trutnov",
+    "CZ-532" => "Pardubice",
+    "CZ-63" => "Vysočina Bölgesi",
+    "CZ-632" => "Jihlava",
+    "CZ-641" => "Blansko",
+    "CZ-644" => "Břeclav",
+    "CZ-645" => "Hodonín",
+    "CZ-646" => "Vyškov",
+    "CZ-647" => "Znojmo",
+    "CZ-711" => "Jeseník",
+    "CZ-713" => "Prostějov",
+    "CZ-714" => "Přerov",
+    "CZ-715" => "Šumperk",
+    "CZ-72" => "Zlín bölgesi",
+    "CZ-723" => "Vsetín",
+    "CZ-724" => "Zlín",
+    "CZ-801" => "Bruntál",
+    "CZ-803" => "Karviná",
+    "CZ-805" => "Opava",
+    "DK-81" => "Nordjylland",
+    "DK-82" => "Midtjylland",
+    "DK-83" => "Syddanmark",
+    "DK-85" => "Sjælland",
+    "DJ-AR" => "Arta",
+    "DJ-DJ" => "Cibuti",
+    "DM-03" => "Saint David",
+    "DM-04" => "Saint George",
+    "DM-05" => "Saint John",
+    "DM-06" => "Saint Joseph",
+    "DM-07" => "Saint Luke",
+    "DM-08" => "Saint Mark",
+    "DM-09" => "Saint Patrick",
+    "DM-10" => "Saint Paul",
+    "DM-11" => "Saint Peter",
+    "DO-02" => "Azua",
+    "DO-04" => "Barahona",
+    "DO-05" => "Dajabón",
+    "DO-10" => "Independencia",
+    "DO-11" => "La Altagracia",
+    "DO-12" => "La Romana",
+    "DO-15" => "Monte Cristi",
+    "DO-17" => "Peravia",
+    "DO-18" => "Puerto Plata",
+    "DO-20" => "Samana",
+    "DO-21" => "San Cristobal",
+    "DO-22" => "San Juan",
+    "DO-23" => "San Pedro de Macoris",
+    "DO-25" => "Santiago",
+    "DO-29" => "Monte Plata",
+    "EC-A" => "Azuay",
+    "EC-B" => "Bolívar",
+    "EC-D" => "Orellana",
+    "EC-F" => "Canar",
+    "EC-G" => "Guayas",
+    "EC-M" => "Manabi",
+    "EC-SE" => "Santa Elena",
+    "EC-Y" => "Pastaza",
+    "EG-ALX" => "İskenderiye",
+    "EG-ASN" => "Aswan",
+    "EG-BA" => "Al Bahr al Ahmar",
+    "EG-C" => "Kahire",
+    "EG-GH" => "Al Gharbia",
+    "EG-GZ" => "Al Jizah",
+    "EG-KB" => "Al Kalyubiye",
+    "EG-KN" => "Qina",
+    "EG-MN" => "Al Minya",
+    "EG-MNF" => "Al Minufiyah",
+    "EG-SHG" => "Suhaj",
+    "SV-CA" => "Cabanas",
+    "SV-LI" => "La Libertad",
+    "SV-MO" => "Morazan",
+    "SV-PA" => "La Paz",
+    "SV-SA" => "Santa Ana",
+    "SV-SM" => "San Miguel",
+    "SV-SS" => "San Salvador",
+    "SV-UN" => "La Union",
+    "ER-DU" => "Al Janubi",
+    "EE-205" => "Hiiumaa",
+    "EE-37" => "Harjumaa",
+    "EE-39" => "Hiiumaa",
+    "EE-52" => "Jarvamaa",
+    "EE-68" => "Parnumaa",
+    "EE-71" => "Raplamaa",
+    "EE-714" => "Saaremaa",
+    "EE-74" => "Saaremaa",
+    "EE-79" => "Tartumaa",
+    "EE-84" => "Viljandimaa",
+    "EE-87" => "Vorumaa",
+    "SZ-MA" => "Manzini",
+    "FJ-C" => "Merkezi",
+    "FJ-E" => "Doğu",
+    "FJ-N" => "Kuzey",
+    "FJ-R" => "Rotuma",
+    "FJ-W" => "Batı",
+    "FR-01" => "Ain",
+    "FR-02" => "Aisne",
+    "FR-03" => "Allier",
+    "FR-07" => "Ardeche",
+    "FR-09" => "Ariege",
+    "FR-10" => "Aube",
+    "FR-14" => "Calvados",
+    "FR-15" => "Cantal",
+    "FR-18" => "Cher",
+    "FR-20R" => "Corse",
+    "FR-21" => "Cote-d'Or",
+    "FR-22" => "Cotes-d'Armor",
+    "FR-23" => "Creuse",
+    "FR-27" => "Eure",
+    "FR-33" => "Gironde",
+    "FR-34" => "Herault",
+    "FR-36" => "Indre",
+    "FR-38" => "Isere",
+    "FR-39" => "Jura",
+    "FR-40" => "Landes",
+    "FR-42" => "Loire",
+    "FR-46" => "Lot",
+    "FR-48" => "Lozere",
+    "FR-50" => "Manche",
+    "FR-51" => "Marne",
+    "FR-52" => "Haute-Marne",
+    "FR-53" => "Mayenne",
+    "FR-56" => "Morbihan",
+    "FR-58" => "Nievre",
+    "FR-59" => "Nord",
+    "FR-61" => "Orne",
+    "FR-64" => "Pyrenees-Atlantiques",
+    "FR-66" => "Pyrenees-Orientales",
+    "FR-67" => "Bas-Rhin",
+    "FR-69" => "Rhone",
+    "FR-71" => "Saone-et-Loire",
+    "FR-85" => "Vendee",
+    "FR-89" => "Yonne",
+    "FR-90" => "Territoire de Belfort",
+    "FR-971" => "Guadeloupe",
+    "FR-972" => "Martinique",
+    "FR-974" => "La Reunion",
+    "FR-976" => "Mayotte",
+    "FR-ARA" => "Auvergne-Rhone-Alpes",
+    "FR-BL" => "Saint-Barthelemy",
+    "FR-BRE" => "Bretagne",
+    "FR-CP" => "Clipperton",
+    "FR-CVL" => "Centre-Val de Loire",
+    "FR-GES" => "Grand-Est",
+    "FR-HDF" => "Hauts-de-France",
+    "FR-MF" => "Saint-Martin",
+    "FR-NAQ" => "Nouvelle-Aquitaine",
+    "FR-OCC" => "Occitanie",
+    "GA-1" => "Estuaire",
+    "GA-2" => "Haut-Ogooue",
+    "GA-4" => "Ngounie",
+    "GA-5" => "Nyanga",
+    "GM-M" => "Central River",
+    "GM-N" => "Kuzey Kıyısı",
+    "GM-W" => "Batı",
+    "GE-AB" => "Abhazya",
+    "GE-AJ" => "Ajaria",
+    "GE-GU" => "Guria",
+    "DE-BE" => "Berlin",
+    "DE-BY" => "Bayern",
+    "DE-HB" => "Bremen",
+    "DE-SL" => "Saarland",
+    "DE-SN" => "Sachsen",
+    "GH-CP" => "Merkezi",
+    "GH-EP" => "Doğu",
+    "GH-NE" => "Kuzey Doğu",
+    "GH-NP" => "Kuzey",
+    "GH-TV" => "Volta",
+    "GH-WP" => "Batı",
+    "GD-02" => "Saint David",
+    "GD-03" => "Saint George",
+    "GD-04" => "Saint John",
+    "GD-05" => "Saint Mark",
+    "GD-06" => "Saint Patrick",
+    "GD-10" => "Güney Grenadine Adaları",
+    "GN-B" => "Boke",
+    "GN-BE" => "Beyla",
+    "GN-BK" => "Boke",
+    "GN-C" => "Conakry",
+    "GN-D" => "Kindia",
+    "GN-DL" => "Dalaba",
+    "GN-F" => "Faranah",
+    "GN-FA" => "Faranah",
+    "GN-FR" => "Fria",
+    "GN-GA" => "Gaoual",
+    "GN-K" => "Kankan",
+    "GN-KA" => "Kankan",
+    "GN-KB" => "Koubia",
+    "GN-KD" => "Kindia",
+    "GN-KN" => "Koundara",
+    "GN-L" => "Labe",
+    "GN-LA" => "Labe",
+    "GN-LE" => "Lelouma",
+    "GN-LO" => "Lola",
+    "GN-M" => "Mamou",
+    "GN-MC" => "Macenta",
+    "GN-MD" => "Mandiana",
+    "GN-ML" => "Mali",
+    "GN-MM" => "Mamou",
+    "GN-PI" => "Pita",
+    "GN-TO" => "Tougue",
+    "GW-BA" => "Bafata",
+    "GW-BS" => "Bissau",
+    "GW-CA" => "Cacheu",
+    "GW-GA" => "Gabu",
+    "GW-L" => "Leste",
+    "GW-N" => "Norte",
+    "GW-OI" => "Oio",
+    "GW-QU" => "Quinara",
+    "GW-S" => "Sul",
+    "GW-TO" => "Tombali",
+    "GY-BA" => "Barima-Waini",
+    "HT-AR" => "Artibonite",
+    "HT-CE" => "Merkez",
+    "HT-ND" => "Nord",
+    "HT-NE" => "Nord-Est",
+    "HN-AT" => "Atlantida",
+    "HN-CL" => "Colon",
+    "HN-CP" => "Copan",
+    "HN-CR" => "Cortes",
+    "HN-EP" => "El Paraiso",
+    "HN-IN" => "İntibuca",
+    "HN-LE" => "Lempira",
+    "HN-LP" => "La Paz",
+    "HN-OL" => "Olancho",
+    "HN-SB" => "Santa Barbara",
+    "HU-BA" => "Baranya",
+    "HU-CS" => "Csongrad",
+    "HU-EG" => "Eger",
+    "HU-ER" => "Erd",
+    "HU-HB" => "Hajdu-Bihar",
+    "HU-NK" => "Nagykanizsa",
+    "HU-NO" => "Nograd",
+    "HU-SD" => "Szeged",
+    "HU-TO" => "Tolna",
+    "HU-ZA" => "Zala",
+    "IS-2" => "Suðurnes",
+    "IS-3" => "Vesturland",
+    "IS-7" => "Austurland",
+    "IS-8" => "Suðurland",
+    "IN-AN" => "Andaman ve Nicobar Adaları",
+    "IN-DL" => "Delhi",
+    "IN-GA" => "Goa",
+    "IN-PB" => "Pencap",
+    "IN-SK" => "Sikkim",
+    "IN-WB" => "Batı Bengal",
+    "ID-BA" => "Bali",
+    "ID-BT" => "Banten",
+    "ID-GO" => "Gorontalo",
+    "ID-JA" => "Jambi",
+    "ID-JI" => "Jawa Timur",
+    "ID-JW" => "Jawa",
+    "ID-KA" => "Kalimantan",
+    "ID-KT" => "Kalimantan Tengah",
+    "ID-MA" => "Maluku",
+    "ID-ML" => "Maluku",
+    "ID-MU" => "Maluku Utara",
+    "ID-PA" => "Papua",
+    "ID-PB" => "Papua Barat",
+    "ID-PP" => "Papua",
+    "ID-SL" => "Sulawesi",
+    "ID-SM" => "Sumatera",
+    "ID-SR" => "Sulawesi Barat",
+    "IR-00" => "Merkezi",
+    "IR-02" => "Mazandaran",
+    "IR-05" => "Kirmanşah",
+    "IR-06" => "Huzistan",
+    "IR-07" => "Fars",
+    "IR-08" => "Kirman",
+    "IR-10" => "İsfahan",
+    "IR-12" => "Kürdistan",
+    "IR-13" => "Hamedan",
+    "IR-15" => "Luristan",
+    "IR-18" => "Buşehr",
+    "IR-19" => "Zencan",
+    "IR-20" => "Semnan",
+    "IR-22" => "Hürmüzgan",
+    "IR-23" => "Tahran",
+    "IR-24" => "Erdebil",
+    "IR-26" => "Kazvin",
+    "IR-27" => "Gülistan",
+    "IE-CE" => "Clare",
+    "IE-CN" => "Cavan",
+    "IE-DL" => "Donegal",
+    "IE-KK" => "Kilkenny",
+    "IE-KY" => "Kerry",
+    "IE-L" => "Leinster",
+    "IE-LD" => "Longford",
+    "IE-LH" => "Louth",
+    "IE-LK" => "Limerick",
+    "IE-M" => "Munster",
+    "IE-MH" => "Meath",
+    "IE-MN" => "Monaghan",
+    "IE-MO" => "Mayo",
+    "IE-U" => "Ulster",
+    "IE-WH" => "Westmeath",
+    "IL-D" => "Al Janubi",
+    "IT-21" => "Piemonte",
+    "IT-25" => "Lombardia",
+    "IT-34" => "Veneto",
+    "IT-42" => "Liguria",
+    "IT-45" => "Emilia-Romagna",
+    "IT-52" => "Toskana",
+    "IT-55" => "Umbria",
+    "IT-57" => "Marche",
+    "IT-62" => "Lazio",
+    "IT-65" => "Abruzzo",
+    "IT-67" => "Molise",
+    "IT-72" => "Campania",
+    "IT-75" => "Puglia",
+    "IT-77" => "Basilicata",
+    "IT-78" => "Calabria",
+    "IT-82" => "Sicilya",
+    "IT-88" => "Sardinya",
+    "IT-AG" => "Agrigento",
+    "IT-AL" => "Alessandria",
+    "IT-AN" => "Ancona",
+    "IT-AQ" => "L'Aquila",
+    "IT-AR" => "Arezzo",
+    "IT-AT" => "Asti",
+    "IT-AV" => "Avellino",
+    "IT-BA" => "Bari",
+    "IT-BI" => "Biella",
+    "IT-BR" => "Brindisi",
+    "IT-BZ" => "Bolzano",
+    "IT-CA" => "Cagliari",
+    "IT-CH" => "Chieti",
+    "IT-CN" => "Cuneo",
+    "IT-CO" => "Como",
+    "IT-CR" => "Cremona",
+    "IT-CS" => "Cosenza",
+    "IT-CT" => "Catania",
+    "IT-FC" => "Forli-Cesena",
+    "IT-FG" => "Foggia",
+    "IT-FI" => "Firenze",
+    "IT-FM" => "Fermo",
+    "IT-GE" => "Genova",
+    "IT-GO" => "Gorizia",
+    "IT-IS" => "İsernia",
+    "IT-KR" => "Crotone",
+    "IT-LO" => "Lodi",
+    "IT-LT" => "Latina",
+    "IT-MC" => "Macerata",
+    "IT-MI" => "Milano",
+    "IT-MN" => "Mantova",
+    "IT-MO" => "Modena",
+    "IT-MT" => "Matera",
+    "IT-NA" => "Napoli",
+    "IT-NO" => "Novara",
+    "IT-NU" => "Nuoro",
+    "IT-OR" => "Oristano",
+    "IT-PD" => "Padova",
+    "IT-PG" => "Perugia",
+    "IT-PI" => "Pisa",
+    "IT-PR" => "Parma",
+    "IT-PT" => "Pistoia",
+    "IT-PV" => "Pavia",
+    "IT-RA" => "Ravenna",
+    "IT-RI" => "Rieti",
+    "IT-RM" => "Roma",
+    "IT-RN" => "Rimini",
+    "IT-RO" => "Rovigo",
+    "IT-SI" => "Siena",
+    "IT-SV" => "Savona",
+    "IT-TE" => "Teramo",
+    "IT-TO" => "Torino",
+    "IT-TP" => "Trapani",
+    "IT-TR" => "Terni",
+    "IT-UD" => "Udine",
+    "IT-VE" => "Venedik",
+    "IT-VI" => "Vicenza",
+    "IT-VR" => "Verona",
+    "JM-01" => "Kingston",
+    "JM-03" => "Saint Thomas",
+    "JM-04" => "Portland",
+    "JM-05" => "Saint Mary",
+    "JM-06" => "Saint Ann",
+    "JM-07" => "Trelawny",
+    "JM-08" => "Saint James",
+    "JM-10" => "Westmoreland",
+    "JM-11" => "Saint Elizabeth",
+    "JM-12" => "Manchester",
+    "JM-14" => "Saint Catherine",
+    "JP-02" => "Aomori",
+    "JP-03" => "Iwate",
+    "JP-04" => "Miyagi",
+    "JP-05" => "Akita",
+    "JP-07" => "Fukuşima",
+    "JP-10" => "Gunma",
+    "JP-11" => "Saitama",
+    "JP-12" => "Chiba",
+    "JP-13" => "Tokyo",
+    "JP-16" => "Toyama",
+    "JP-23" => "Aichi",
+    "JP-24" => "Mie",
+    "JP-25" => "Shiga",
+    "JP-28" => "Hyogo",
+    "JP-29" => "Nara",
+    "JP-33" => "Okayama",
+    "JP-34" => "Hiroşima",
+    "JP-36" => "Tokushima",
+    "JP-38" => "Ehime",
+    "JP-41" => "Saga",
+    "JO-AQ" => "Akabe",
+    "JO-MD" => "Madaba",
+    "KE-01" => "Baringo",
+    "KE-03" => "Bungoma",
+    "KE-04" => "Busia",
+    "KE-06" => "Embu",
+    "KE-07" => "Garissa",
+    "KE-11" => "Kakamega",
+    "KE-14" => "Kilifi",
+    "KE-21" => "Lamu",
+    "KE-22" => "Machakos",
+    "KE-24" => "Mandera",
+    "KE-25" => "Marsabit",
+    "KE-26" => "Meru",
+    "KE-28" => "Mombasa",
+    "KE-29" => "Murang'a",
+    "KE-30" => "Nairobi",
+    "KE-32" => "Nandi",
+    "KE-35" => "Nyandarua",
+    "KE-37" => "Samburu",
+    "KE-40" => "Tana River",
+    "KE-43" => "Turkana",
+    "KE-45" => "Vihiga",
+    "KI-G" => "Gilbert Adaları",
+    "KI-L" => "Line Adaları",
+    "KI-P" => "Phoenix Adaları",
+    "KW-FA" => "Al Farwaniyah",
+    "KG-B" => "Batken",
+    "KG-N" => "Narın",
+    "KG-T" => "Talas",
+    "LA-AT" => "Attapu",
+    "LA-BL" => "Bolikhamxai",
+    "LA-CH" => "Champasak",
+    "LA-HO" => "Houaphan",
+    "LA-LP" => "Louangphabang",
+    "LA-OU" => "Oudomxai",
+    "LA-PH" => "Phongsali",
+    "LA-SL" => "Salavan",
+    "LA-XE" => "Xekong",
+    "LV-059" => "Madonas novads",
+    "LV-JUR" => "Jurmala",
+    "LB-AK" => "Aakkar",
+    "LS-A" => "Maseru",
+    "LS-C" => "Leribe",
+    "LS-D" => "Berea",
+    "LR-BG" => "Bong",
+    "LR-GB" => "Grand Bassa",
+    "LR-MG" => "Margibi",
+    "LR-MO" => "Montserrado",
+    "LR-MY" => "Maryland",
+    "LR-NI" => "Nimba",
+    "LR-SI" => "Sinoe",
+    "LY-BA" => "Bingazi",
+    "LY-BU" => "Al Butnan",
+    "LY-DR" => "Darnah",
+    "LY-JA" => "Al Jabal al Akhdar",
+    "LY-JG" => "Al Jabal al Gharbi",
+    "LY-KF" => "Al Kufrah",
+    "LY-MB" => "Al Marqab",
+    "LY-MI" => "Mişratah",
+    "LY-MJ" => "Al Marj",
+    "LY-SB" => "Sabha",
+    "LY-SR" => "Surt",
+    "LI-01" => "Balzers",
+    "LI-02" => "Eschen",
+    "LI-03" => "Gamprin",
+    "LI-04" => "Mauren",
+    "LI-05" => "Planken",
+    "LI-07" => "Schaan",
+    "LI-08" => "Schellenberg",
+    "LI-09" => "Triesen",
+    "LI-11" => "Vaduz",
+    "LU-GR" => "Grevenmacher",
+    "LU-LU" => "Lüksemburg",
+    "MG-A" => "Toamasina",
+    "MG-D" => "Antsiranana",
+    "MG-T" => "Antananarivo",
+    "MW-BA" => "Balaka",
+    "MW-C" => "Merkez Bölge",
+    "MW-CK" => "Chikwawa",
+    "MW-CT" => "Chitipa",
+    "MW-DO" => "Dowa",
+    "MW-KR" => "Karonga",
+    "MW-LK" => "Likoma",
+    "MW-MC" => "Mchinji",
+    "MW-MG" => "Mangochi",
+    "MW-MH" => "Machinga",
+    "MW-MU" => "Mulanje",
+    "MW-MW" => "Mwanza",
+    "MW-N" => "Kuzey Bölgesi",
+    "MW-NK" => "Nkhotakota",
+    "MW-NS" => "Nsanje",
+    "MW-PH" => "Phalombe",
+    "MW-S" => "Güney Bölgesi",
+    "MW-SA" => "Salima",
+    "MW-TH" => "Thyolo",
+    "MW-ZO" => "Zomba",
+    "MY-02" => "Kedah",
+    "MY-03" => "Kelantan",
+    "MY-04" => "Melaka",
+    "MY-06" => "Pahang",
+    "MY-08" => "Perak",
+    "MY-09" => "Perlis",
+    "MY-12" => "Sabah",
+    "MV-MLE" => "Male",
+    "ML-2" => "Koulikoro",
+    "ML-7" => "Gao",
+    "ML-8" => "Kidal",
+    "ML-BKO" => "Bamako",
+    "MT-01" => "Attard",
+    "MT-02" => "Balzan",
+    "MT-03" => "Birgu",
+    "MT-06" => "Bormla",
+    "MT-07" => "Dingli",
+    "MT-08" => "Fgura",
+    "MT-09" => "Floriana",
+    "MT-10" => "Fontana",
+    "MT-11" => "Gudja",
+    "MT-12" => "Gzira",
+    "MT-14" => "Gharb",
+    "MT-19" => "İklin",
+    "MT-20" => "Isla",
+    "MT-21" => "Kalkara",
+    "MT-23" => "Kirkop",
+    "MT-24" => "Lija",
+    "MT-25" => "Luqa",
+    "MT-26" => "Marsa",
+    "MT-27" => "Marsaskala",
+    "MT-28" => "Marsaxlokk",
+    "MT-29" => "Mdina",
+    "MT-30" => "Mellieha",
+    "MT-31" => "Mgarr",
+    "MT-32" => "Mosta",
+    "MT-33" => "Mqabba",
+    "MT-34" => "Msida",
+    "MT-35" => "Mtarfa",
+    "MT-36" => "Munxar",
+    "MT-37" => "Nadur",
+    "MT-38" => "Naxxar",
+    "MT-39" => "Paola",
+    "MT-40" => "Pembroke",
+    "MT-41" => "Pieta",
+    "MT-42" => "Qala",
+    "MT-43" => "Qormi",
+    "MT-47" => "Safi",
+    "MT-49" => "Saint John",
+    "MT-52" => "Sannat",
+    "MT-54" => "Santa Venera",
+    "MT-56" => "Sliema",
+    "MT-59" => "Tarxien",
+    "MT-60" => "Valletta",
+    "MT-61" => "Xaghra",
+    "MT-64" => "Zabbar",
+    "MH-ARN" => "Arno",
+    "MH-AUR" => "Aur",
+    "MH-EBO" => "Ebon",
+    "MH-JAB" => "Jabat",
+    "MH-JAL" => "Jaluit",
+    "MH-L" => "Ralik chain",
+    "MH-LIB" => "Lib",
+    "MH-MAL" => "Maloelap",
+    "MH-MIL" => "Mili",
+    "MH-NMK" => "Namdrik",
+    "MH-NMU" => "Namu",
+    "MH-RON" => "Rongelap",
+    "MH-T" => "Ratak chain",
+    "MH-WTH" => "Wotho",
+    "MR-03" => "Assaba",
+    "MR-04" => "Gorgol",
+    "MR-05" => "Brakna",
+    "MR-07" => "Adrar",
+    "MU-AG" => "Agalega Adaları",
+    "MU-MO" => "Moka",
+    "MU-RO" => "Rodrigues Adası",
+    "MX-BCN" => "Baja California",
+    "MX-BCS" => "Baja California Sur",
+    "MX-CHH" => "Chihuahua",
+    "MX-CHP" => "Chiapas",
+    "MX-COL" => "Colima",
+    "MX-GUA" => "Guanajuato",
+    "MX-MEX" => "Mexico",
+    "MX-MIC" => "Michoacan de Ocampo",
+    "MX-MOR" => "Morelos",
+    "MX-NLE" => "Nuevo Leon",
+    "MX-OAX" => "Oaxaca",
+    "MX-PUE" => "Puebla",
+    "MX-SIN" => "Sinaloa",
+    "MX-SLP" => "San Luis Potosi",
+    "MX-SON" => "Sonora",
+    "MX-YUC" => "Yucatan",
+    "FM-KSA" => "Kosrae",
+    "FM-PNI" => "Pohnpei",
+    "MD-CA" => "Cahul",
+    "MD-CM" => "Cimișlia",
+    "MD-CU" => "Kişinev",
+    "MD-ED" => "Edinet",
+    "MD-FL" => "Florești",
+    "MD-GL" => "Glodeni",
+    "MD-HI" => "Hincești",
+    "MD-IA" => "Ialoveni",
+    "MD-NI" => "Nisporeni",
+    "MD-OR" => "Orhei",
+    "MD-SO" => "Soroca",
+    "MC-CL" => "La Colle",
+    "MC-FO" => "Fontvieille",
+    "MC-JE" => "Jardin Exotique",
+    "MC-MC" => "Monte-Carlo",
+    "MC-PH" => "Port-Hercule",
+    "MC-SD" => "Sainte-Devote",
+    "MC-SR" => "Saint-Roman",
+    "MN-035" => "Orhun",
+    "MN-049" => "Selenge",
+    "MN-051" => "Sühbaatar",
+    "MN-055" => "Övörhangay",
+    "MN-067" => "Bulgan",
+    "MN-073" => "Arhangay",
+    "ME-01" => "Andrijevica",
+    "ME-02" => "Bar",
+    "ME-03" => "Berane",
+    "ME-05" => "Budva",
+    "ME-06" => "Cetinje",
+    "ME-07" => "Danilovgrad",
+    "ME-09" => "Kolaşin",
+    "ME-10" => "Kotor",
+    "ME-11" => "Mojkovac",
+    "ME-13" => "Plav",
+    "ME-14" => "Taşlıca",
+    "ME-16" => "Podgorica",
+    "ME-18" => "Savnik",
+    "ME-19" => "Tivat",
+    "ME-20" => "Ulcinj",
+    "ME-21" => "Zabljak",
+    "MA-02" => "L'Oriental",
+    "MA-03" => "Fes-Meknes",
+    "MA-07" => "Marakeş-Safi",
+    "MA-AOU" => "Aousserd (EH)",
+    "MA-BEM" => "Beni Mellal",
+    "MA-BER" => "Berkane",
+    "MA-BES" => "Benslimane",
+    "MA-BOD" => "Boujdour (EH)",
+    "MA-CAS" => "Kazablanka",
+    "MA-ESI" => "Essaouira",
+    "MA-ESM" => "Es-Semara (EH-kısmi)",
+    "MA-FES" => "Fes",
+    "MA-FIG" => "Figuig",
+    "MA-IFR" => "İfrane",
+    "MA-JRA" => "Jerada",
+    "MA-KEN" => "Kenitra",
+    "MA-LAR" => "Larache",
+    "MA-MAR" => "Marakeş",
+    "MA-MED" => "Mediouna",
+    "MA-MEK" => "Meknes",
+    "MA-MOH" => "Muhammediye",
+    "MA-NAD" => "Nador",
+    "MA-NOU" => "Nouaceur",
+    "MA-OUJ" => "Oujda-Angad",
+    "MA-OUZ" => "Ouezzane",
+    "MA-RAB" => "Rabat",
+    "MA-SAF" => "Safi",
+    "MA-SAL" => "Sale",
+    "MA-SEF" => "Sefrou",
+    "MA-SIL" => "Sidi Slimane",
+    "MA-TAI" => "Taourirt",
+    "MA-TAO" => "Taounate",
+    "MA-TAT" => "Tata",
+    "MA-ZAG" => "Zagora",
+    "MZ-A" => "Niassa",
+    "MZ-B" => "Manica",
+    "MZ-G" => "Gazze",
+    "MZ-P" => "Cabo Delgado",
+    "MZ-S" => "Sofala",
+    "MZ-T" => "Tete",
+    "MM-01" => "Sagaing",
+    "MM-04" => "Mandalay",
+    "MM-06" => "Yangon",
+    "MM-11" => "Kachin",
+    "MM-12" => "Kayah",
+    "MM-13" => "Kayin",
+    "MM-14" => "Chin",
+    "MM-15" => "Mon",
+    "MM-16" => "Rakhine",
+    "MM-17" => "Shan",
+    "NA-ER" => "Erongo",
+    "NA-KH" => "Khomas",
+    "NA-ON" => "Oshana",
+    "NA-OT" => "Oshikoto",
+    "NR-01" => "Aiwo",
+    "NR-02" => "Anabar",
+    "NR-03" => "Anetan",
+    "NR-04" => "Anibare",
+    "NR-06" => "Boe",
+    "NR-07" => "Buada",
+    "NR-11" => "Meneng",
+    "NR-12" => "Nibok",
+    "NR-13" => "Uaboe",
+    "NR-14" => "Yaren",
+    "NP-P4" => "Gandaki",
+    "NP-P6" => "Karnali",
+    "NL-AW" => "Aruba",
+    "NL-BQ1" => "Bonaire",
+    "NL-BQ2" => "Saba",
+    "NL-BQ3" => "Sint Eustatius",
+    "NL-CW" => "Curaçao",
+    "NL-DR" => "Drenthe",
+    "NL-FL" => "Flevoland",
+    "NL-GE" => "Gelderland",
+    "NL-LI" => "Limburg",
+    "NL-NH" => "Noord-Holland",
+    "NL-SX" => "Sint Maarten",
+    "NL-ZE" => "Zeeland",
+    "NZ-AUK" => "Auckland",
+    "NZ-CIT" => "Chatham Adaları Bölgesi",
+    "NZ-NTL" => "Northland",
+    "NZ-OTA" => "Otago",
+    "NZ-STL" => "Southland",
+    "NZ-WGN" => "Wellington",
+    "NZ-WKO" => "Waikato",
+    "NI-BO" => "Boaco",
+    "NI-CI" => "Chinandega",
+    "NI-ES" => "Esteli",
+    "NI-GR" => "Granada",
+    "NI-LE" => "Leon",
+    "NI-MD" => "Madriz",
+    "NI-MN" => "Managua",
+    "NI-MS" => "Masaya",
+    "NI-MT" => "Matagalpa",
+    "NI-NS" => "Nueva Segovia",
+    "NE-4" => "Maradi",
+    "NE-6" => "Tillaberi",
+    "NE-8" => "Niamey",
+    "NG-AB" => "Abia",
+    "NG-AN" => "Anambra",
+    "NG-BA" => "Bauchi",
+    "NG-BE" => "Benue",
+    "NG-BO" => "Borno",
+    "NG-DE" => "Delta",
+    "NG-EB" => "Ebonyi",
+    "NG-ED" => "Edo",
+    "NG-EK" => "Ekiti",
+    "NG-KD" => "Kaduna",
+    "NG-KN" => "Kano",
+    "NG-KO" => "Kogi",
+    "NG-KT" => "Katsina",
+    "NG-NI" => "Nijer",
+    "NG-OG" => "Ogun",
+    "NG-PL" => "Plato",
+    "NG-ZA" => "Zamfara",
+    "MK-101" => "Köprülü",
+    "MK-102" => "Gradsko",
+    "MK-107" => "Rosoman",
+    "MK-108" => "Sveti Nikole",
+    "MK-201" => "Berovo",
+    "MK-202" => "Vinica",
+    "MK-204" => "Zrnovci",
+    "MK-205" => "Karbinci",
+    "MK-206" => "Kocani",
+    "MK-303" => "Debre",
+    "MK-310" => "Ohrid",
+    "MK-311" => "Plasnica",
+    "MK-312" => "Struga",
+    "MK-401" => "Bogdanci",
+    "MK-405" => "Gevgeli",
+    "MK-406" => "Doyran",
+    "MK-408" => "Novo Selo",
+    "MK-410" => "Usturumca",
+    "MK-501" => "Bitola",
+    "MK-506" => "Mogila",
+    "MK-507" => "Novaci",
+    "MK-509" => "Resen",
+    "MK-601" => "Bogovinje",
+    "MK-602" => "Brvenica",
+    "MK-604" => "Gostivar",
+    "MK-605" => "Zelino",
+    "MK-608" => "Tearce",
+    "MK-609" => "Kalkandelen",
+    "MK-703" => "Kumanovo",
+    "MK-705" => "Rankovce",
+    "MK-706" => "Staro Nagoricane",
+    "MK-807" => "Ilinden",
+    "MK-810" => "Petrovec",
+    "NO-11" => "Rogaland",
+    "NO-18" => "Nordland",
+    "OM-BU" => "Al Buraymi",
+    "OM-WU" => "Al Wusţá",
+    "OM-ZA" => "Az Zahirah",
+    "PK-BA" => "Belucistan",
+    "PK-PB" => "Pencap",
+    "PK-SD" => "Sindh",
+    "PW-002" => "Aimeliik",
+    "PW-004" => "Airai",
+    "PW-010" => "Angaur",
+    "PW-100" => "Kayangel",
+    "PW-150" => "Koror",
+    "PW-214" => "Ngaraard",
+    "PW-218" => "Ngarchelong",
+    "PW-222" => "Ngardmau",
+    "PW-224" => "Ngatpang",
+    "PW-226" => "Ngchesar",
+    "PW-228" => "Ngiwal",
+    "PW-350" => "Peleliu",
+    "PW-370" => "Sonsorol",
+    "PS-DEB" => "Deir El Balah",
+    "PS-GZA" => "Gazze",
+    "PS-JEN" => "Cenin",
+    "PS-NGZ" => "Kuzey Gazze",
+    "PS-SLT" => "Salfit",
+    "PA-2" => "Cocle",
+    "PA-3" => "Colon",
+    "PA-5" => "Darien",
+    "PA-8" => "Panama",
+    "PA-9" => "Veraguas",
+    "PG-CPM" => "Merkezi",
+    "PG-EPW" => "Enga",
+    "PG-ESW" => "East Sepik",
+    "PG-MPL" => "Morobe",
+    "PG-MPM" => "Madang",
+    "PG-MRL" => "Manus",
+    "PG-NIK" => "New Ireland",
+    "PG-NPP" => "Kuzey",
+    "PG-NSB" => "Bougainville",
+    "PG-SHM" => "Southern Highlands",
+    "PG-WHM" => "Western Highlands",
+    "PG-WPD" => "Batı",
+    "PY-10" => "Alto Parana",
+    "PY-11" => "Merkezi",
+    "PY-13" => "Amambay",
+    "PY-16" => "Alto Paraguay",
+    "PY-19" => "Boqueron",
+    "PY-2" => "San Pedro",
+    "PY-4" => "Guaira",
+    "PY-5" => "Caaguazu",
+    "PY-6" => "Caazapa",
+    "PY-8" => "Misiones",
+    "PY-9" => "Paraguari",
+    "PY-ASU" => "Asuncion",
+    "PE-ARE" => "Arequipa",
+    "PE-CAJ" => "Cajamarca",
+    "PE-CAL" => "El Callao",
+    "PE-ICA" => "Ica",
+    "PE-LAL" => "La Libertad",
+    "PE-LAM" => "Lambayeque",
+    "PE-LIM" => "Lima",
+    "PE-LOR" => "Loreto",
+    "PE-TAC" => "Tacna",
+    "PE-TUM" => "Tumbes",
+    "PH-02" => "Cagayan Valley (Region II)",
+    "PH-03" => "Central Luzon (Region III)",
+    "PH-06" => "Western Visayas (Region VI)",
+    "PH-07" => "Central Visayas (Region VII)",
+    "PH-08" => "Eastern Visayas (Region VIII)",
+    "PH-10" => "Northern Mindanao (Region X)",
+    "PH-11" => "Davao (Region XI)",
+    "PH-13" => "Caraga (Region XIII)",
+    "PH-ABR" => "Abra",
+    "PH-AKL" => "Aklan",
+    "PH-ALB" => "Albay",
+    "PH-APA" => "Apayao",
+    "PH-AUR" => "Aurora",
+    "PH-BAS" => "Basilan",
+    "PH-BIL" => "Biliran",
+    "PH-BTG" => "Batangas",
+    "PH-BTN" => "Batanes",
+    "PH-BUK" => "Bukidnon",
+    "PH-BUL" => "Bulacan",
+    "PH-CAG" => "Cagayan",
+    "PH-DIN" => "Dinagat Islands",
+    "PH-EAS" => "Eastern Samar",
+    "PH-GUI" => "Guimaras",
+    "PH-ISA" => "Isabela",
+    "PH-LUN" => "La Union",
+    "PH-MAD" => "Marinduque",
+    "PH-MOU" => "Mountain Province",
+    "PH-MSC" => "Misamis Occidental",
+    "PH-NEC" => "Negros Occidental",
+    "PH-NSA" => "Northern Samar",
+    "PH-NUE" => "Nueva Ecija",
+    "PH-PAM" => "Pampanga",
+    "PH-PAN" => "Pangasinan",
+    "PH-PLW" => "Palawan",
+    "PH-SAR" => "Sarangani",
+    "PH-SCO" => "South Cotabato",
+    "PH-SLU" => "Sulu",
+    "PH-ZMB" => "Zambales",
+    "PL-02" => "Dolnoslaskie",
+    "PL-10" => "Lodzkie",
+    "PL-14" => "Mazowieckie",
+    "PL-22" => "Pomorskie",
+    "PL-24" => "Slaskie",
+    "PT-02" => "Beja",
+    "PT-03" => "Braga",
+    "PT-04" => "Bragança",
+    "PT-06" => "Coimbra",
+    "PT-07" => "Evora",
+    "PT-09" => "Guarda",
+    "PT-10" => "Leiria",
+    "PT-11" => "Lizbon",
+    "PT-12" => "Portalegre",
+    "PT-13" => "Porto",
+    "PT-14" => "Santarem",
+    "QA-WA" => "Al Wakrah",
+    "QA-ZA" => "Az Za'ayin",
+    "RO-AB" => "Alba",
+    "RO-AG" => "Argeş",
+    "RO-AR" => "Arad",
+    "RO-B" => "București",
+    "RO-BC" => "Bacau",
+    "RO-BR" => "Braila",
+    "RO-BT" => "Botoșani",
+    "RO-BV" => "Brașov",
+    "RO-CT" => "Köstence",
+    "RO-CV" => "Covasna",
+    "RO-DB" => "Dambovita",
+    "RO-GL" => "Galati",
+    "RO-HD" => "Hunedoara",
+    "RO-HR" => "Harghita",
+    "RO-IF" => "Ilfov",
+    "RO-MM" => "Maramureș",
+    "RO-MS" => "Mureș",
+    "RO-NT" => "Neamt",
+    "RO-SJ" => "Salaj",
+    "RO-SM" => "Satu Mare",
+    "RO-TR" => "Teleorman",
+    "RO-VN" => "Vrancea",
+    "RO-VS" => "Vaslui",
+    "RU-BA" => "Başkurdistan Cumhuriyeti",
+    "RU-CE" => "Çeçenistan Cumhuriyeti",
+    "RU-CU" => "Çuvaşistan Cumhuriyeti",
+    "RU-DA" => "Dağıstan Cumhuriyeti",
+    "RU-KAM" => "Kamçatka bölgesi",
+    "RU-KC" => "Karaçay-Çerkez Cumhuriyeti",
+    "RU-KO" => "Komi Cumhuriyeti",
+    "RU-MOW" => "Moskova",
+    "RU-TA" => "Tataristan Cumhuriyeti",
+    "RW-02" => "Doğu",
+    "RW-03" => "Kuzey",
+    "RW-04" => "Batı",
+    "RW-05" => "Güney",
+    "SH-HL" => "Saint Helena",
+    "KN-05" => "Saint James Windward",
+    "KN-06" => "Saint John Capisterre",
+    "KN-08" => "Saint Mary Cayon",
+    "KN-09" => "Saint Paul Capisterre",
+    "KN-12" => "Saint Thomas Lowland",
+    "KN-13" => "Saint Thomas Middle Island",
+    "KN-K" => "Saint Kitts",
+    "LC-03" => "Choiseul",
+    "VC-01" => "Charlotte",
+    "VC-03" => "Saint David",
+    "VC-04" => "Saint George",
+    "VC-05" => "Saint Patrick",
+    "WS-AA" => "A'ana",
+    "WS-AT" => "Atua",
+    "WS-GE" => "Gaga'emauga",
+    "WS-PA" => "Palauli",
+    "SM-02" => "Chiesanuova",
+    "SM-03" => "Domagnano",
+    "SM-04" => "Faetano",
+    "SM-05" => "Fiorentino",
+    "SM-08" => "Montegiardino",
+    "SA-10" => "Najran",
+    "SN-DK" => "Dakar",
+    "SN-KD" => "Kolda",
+    "SN-LG" => "Louga",
+    "SN-MT" => "Matam",
+    "SN-SL" => "Saint-Louis",
+    "SN-TC" => "Tambacounda",
+    "SN-TH" => "Thies",
+    "RS-00" => "Belgrad",
+    "RS-05" => "Zapadnobacki okrug",
+    "RS-07" => "Sremski okrug",
+    "RS-09" => "Kolubarski okrug",
+    "RS-11" => "Branicevski okrug",
+    "RS-13" => "Pomoravski okrug",
+    "RS-16" => "Zlatiborski okrug",
+    "RS-22" => "Pirotski okrug",
+    "RS-27" => "Prizrenski okrug",
+    "SC-03" => "Anse Etoile",
+    "SC-06" => "Baie Lazare",
+    "SC-07" => "Baie Sainte Anne",
+    "SC-09" => "Bel Air",
+    "SC-11" => "Cascade",
+    "SC-12" => "Glacis",
+    "SC-13" => "Grand Anse Mahe",
+    "SC-14" => "Grand Anse Praslin",
+    "SC-15" => "La Digue",
+    "SC-16" => "English River",
+    "SC-17" => "Mont Buxton",
+    "SC-18" => "Mont Fleuri",
+    "SC-19" => "Plaisance",
+    "SC-20" => "Pointe Larue",
+    "SC-21" => "Port Glaud",
+    "SC-22" => "Saint Louis",
+    "SC-23" => "Takamaka",
+    "SC-25" => "Roche Caiman",
+    "SL-E" => "Doğu",
+    "SL-N" => "Kuzey",
+    "SL-S" => "Güney",
+    "SG-01" => "Merkez Singapur",
+    "SG-02" => "Kuzey Doğu",
+    "SG-03" => "Kuzey Batı",
+    "SG-04" => "Güney Doğu",
+    "SG-05" => "Güney Batı",
+    "SK-BL" => "Bratislavsky kraj",
+    "SK-KI" => "Kosicky kraj",
+    "SK-NI" => "Nitriansky kraj",
+    "SK-PV" => "Presovsky kraj",
+    "SK-TA" => "Trnavsky kraj",
+    "SK-ZI" => "Zilinsky kraj",
+    "SI-002" => "Beltinci",
+    "SI-003" => "Bled",
+    "SI-004" => "Bohinj",
+    "SI-005" => "Borovnica",
+    "SI-006" => "Bovec",
+    "SI-007" => "Brda",
+    "SI-008" => "Brezovica",
+    "SI-009" => "Brezice",
+    "SI-010" => "Tisina",
+    "SI-011" => "Celje",
+    "SI-013" => "Cerknica",
+    "SI-014" => "Cerkno",
+    "SI-015" => "Crensovci",
+    "SI-017" => "Crnomelj",
+    "SI-018" => "Destrnik",
+    "SI-019" => "Divaca",
+    "SI-023" => "Domzale",
+    "SI-024" => "Dornava",
+    "SI-025" => "Dravograd",
+    "SI-028" => "Gorisnica",
+    "SI-029" => "Gornja Radgona",
+    "SI-030" => "Gornji Grad",
+    "SI-033" => "Salovci",
+    "SI-034" => "Hrastnik",
+    "SI-036" => "Idrija",
+    "SI-039" => "Ivancna Gorica",
+    "SI-041" => "Jesenice",
+    "SI-042" => "Jursinci",
+    "SI-043" => "Kamnik",
+    "SI-044" => "Kanal",
+    "SI-046" => "Kobarid",
+    "SI-047" => "Kobilje",
+    "SI-048" => "Kocevje",
+    "SI-049" => "Komen",
+    "SI-051" => "Kozje",
+    "SI-052" => "Kranj",
+    "SI-054" => "Krsko",
+    "SI-055" => "Kungota",
+    "SI-056" => "Kuzma",
+    "SI-057" => "Lasko",
+    "SI-058" => "Lenart",
+    "SI-060" => "Litija",
+    "SI-061" => "Ljubljana",
+    "SI-062" => "Ljubno",
+    "SI-064" => "Logatec",
+    "SI-067" => "Luce",
+    "SI-068" => "Lukovica",
+    "SI-069" => "Majsperk",
+    "SI-070" => "Maribor",
+    "SI-071" => "Medvode",
+    "SI-072" => "Menges",
+    "SI-073" => "Metlika",
+    "SI-074" => "Mezica",
+    "SI-076" => "Mislinja",
+    "SI-077" => "Moravce",
+    "SI-079" => "Mozirje",
+    "SI-081" => "Muta",
+    "SI-082" => "Naklo",
+    "SI-083" => "Nazarje",
+    "SI-084" => "Nova Gorica",
+    "SI-086" => "Odranci",
+    "SI-088" => "Osilnica",
+    "SI-089" => "Pesnica",
+    "SI-091" => "Pivka",
+    "SI-093" => "Podvelka",
+    "SI-094" => "Postojna",
+    "SI-097" => "Puconci",
+    "SI-100" => "Radenci",
+    "SI-102" => "Radovljica",
+    "SI-104" => "Ribnica",
+    "SI-107" => "Rogatec",
+    "SI-109" => "Semic",
+    "SI-110" => "Sevnica",
+    "SI-111" => "Sezana",
+    "SI-112" => "Slovenj Gradec",
+    "SI-121" => "Skocjan",
+    "SI-123" => "Skofljica",
+    "SI-126" => "Sostanj",
+    "SI-127" => "Store",
+    "SI-128" => "Tolmin",
+    "SI-129" => "Trbovlje",
+    "SI-130" => "Trebnje",
+    "SI-132" => "Turnisce",
+    "SI-133" => "Velenje",
+    "SI-135" => "Videm",
+    "SI-136" => "Vipava",
+    "SI-137" => "Vitanje",
+    "SI-138" => "Vodice",
+    "SI-139" => "Vojnik",
+    "SI-140" => "Vrhnika",
+    "SI-141" => "Vuzenica",
+    "SI-143" => "Zavrc",
+    "SI-144" => "Zrece",
+    "SI-147" => "Ziri",
+    "SI-148" => "Benedikt",
+    "SI-149" => "Bistrica ob Sotli",
+    "SI-150" => "Bloke",
+    "SI-151" => "Braslovce",
+    "SI-152" => "Cankova",
+    "SI-154" => "Dobje",
+    "SI-155" => "Dobrna",
+    "SI-158" => "Grad",
+    "SI-159" => "Hajdina",
+    "SI-160" => "Hoce-Slivnica",
+    "SI-164" => "Komenda",
+    "SI-165" => "Kostel",
+    "SI-168" => "Markovci",
+    "SI-170" => "Mirna Pec",
+    "SI-171" => "Oplotnica",
+    "SI-172" => "Podlehnik",
+    "SI-173" => "Polzela",
+    "SI-174" => "Prebold",
+    "SI-175" => "Prevalje",
+    "SI-179" => "Sodrazica",
+    "SI-180" => "Solcava",
+    "SI-181" => "Sveta Ana",
+    "SI-184" => "Tabor",
+    "SI-186" => "Trzin",
+    "SI-189" => "Vransko",
+    "SI-190" => "Zalec",
+    "SI-191" => "Zetale",
+    "SI-192" => "Zirovnica",
+    "SI-196" => "Cirkulane",
+    "SB-CE" => "Merkezi",
+    "SB-CH" => "Choiseul",
+    "SB-GU" => "Guadalcanal",
+    "SB-IS" => "Isabel",
+    "SB-ML" => "Malaita",
+    "SB-TE" => "Temotu",
+    "SB-WE" => "Batı",
+    "SO-BR" => "Bari",
+    "ZA-FS" => "Free State",
+    "ZA-NC" => "Northern Cape",
+    "ZA-NW" => "Kuzey-Batı",
+    "ZA-WC" => "Western Cape",
+    "SS-BN" => "Northern Bahr el Ghazal",
+    "SS-BW" => "Western Bahr el Ghazal",
+    "SS-EC" => "Central Equatoria",
+    "SS-EE" => "Eastern Equatoria",
+    "SS-EW" => "Western Equatoria",
+    "ES-AB" => "Albacete",
+    "ES-AL" => "Almeria",
+    "ES-AN" => "Andalucia",
+    "ES-AR" => "Aragon",
+    "ES-AV" => "Avila",
+    "ES-CA" => "Cadiz",
+    "ES-CB" => "Cantabria",
+    "ES-CC" => "Caceres",
+    "ES-CE" => "Ceuta",
+    "ES-CN" => "Canarias",
+    "ES-CO" => "Córdoba",
+    "ES-CU" => "Cuenca",
+    "ES-GC" => "Las Palmas",
+    "ES-GR" => "Granada",
+    "ES-GU" => "Guadalajara",
+    "ES-J" => "Jaen",
+    "ES-LE" => "Leon",
+    "ES-M" => "Madrid",
+    "ES-MA" => "Malaga",
+    "ES-MU" => "Murcia",
+    "ES-O" => "Asturias",
+    "ES-P" => "Palencia",
+    "ES-S" => "Cantabria",
+    "ES-SA" => "Salamanca",
+    "ES-SG" => "Segovia",
+    "ES-SO" => "Soria",
+    "ES-TE" => "Teruel",
+    "ES-TO" => "Toledo",
+    "ES-Z" => "Zaragoza",
+    "ES-ZA" => "Zamora",
+    "LK-12" => "Gampaha",
+    "SD-NO" => "Kuzey",
+    "SR-MA" => "Marowijne",
+    "SR-NI" => "Nickerie",
+    "SR-PM" => "Paramaribo",
+    "SR-PR" => "Para",
+    "SR-SA" => "Saramacca",
+    "SR-SI" => "Sipaliwini",
+    "SR-WA" => "Wanica",
+    "CH-BE" => "Bern",
+    "CH-GE" => "Cenevre",
+    "CH-GL" => "Glarus",
+    "CH-JU" => "Jura",
+    "CH-SG" => "Sankt Gallen",
+    "CH-TG" => "Thurgau",
+    "CH-UR" => "Uri",
+    "CH-VS" => "Valais",
+    "SY-ID" => "İdlib",
+    "TW-CHA" => "Changhua",
+    "TW-CYI" => "Chiayi",
+    "TW-CYQ" => "Chiayi",
+    "TW-MIA" => "Miaoli",
+    "TW-NAN" => "Nantou",
+    "TW-PEN" => "Penghu",
+    "TW-TNN" => "Tainan",
+    "TW-YUN" => "Yunlin",
+    "TJ-SU" => "Sughd",
+    "TZ-01" => "Arusha",
+    "TZ-03" => "Dodoma",
+    "TZ-04" => "Iringa",
+    "TZ-05" => "Kagera",
+    "TZ-08" => "Kigoma",
+    "TZ-12" => "Lindi",
+    "TZ-13" => "Mara",
+    "TZ-14" => "Mbeya",
+    "TZ-16" => "Morogoro",
+    "TZ-17" => "Mtwara",
+    "TZ-18" => "Mwanza",
+    "TZ-23" => "Singida",
+    "TZ-25" => "Tanga",
+    "TZ-26" => "Manyara",
+    "TH-15" => "Ang Thong",
+    "TH-17" => "Sing Buri",
+    "TH-18" => "Chai Nat",
+    "TH-19" => "Saraburi",
+    "TH-21" => "Rayong",
+    "TH-31" => "Buri Ram",
+    "TH-32" => "Surin",
+    "TH-35" => "Yasothon",
+    "TH-42" => "Loei",
+    "TH-46" => "Kalasin",
+    "TH-49" => "Mukdahan",
+    "TH-50" => "Chiang Mai",
+    "TH-52" => "Lampang",
+    "TH-57" => "Chiang Rai",
+    "TH-58" => "Mae Hong Son",
+    "TH-81" => "Krabi",
+    "TH-82" => "Phangnga",
+    "TH-85" => "Ranong",
+    "TH-90" => "Songkhla",
+    "TH-91" => "Satun",
+    "TH-92" => "Trang",
+    "TH-94" => "Pattani",
+    "TH-95" => "Yala",
+    "TL-AL" => "Aileu",
+    "TL-AN" => "Ainaro",
+    "TL-BA" => "Baucau",
+    "TL-BO" => "Bobonaro",
+    "TL-CO" => "Cova Lima",
+    "TL-ER" => "Ermera",
+    "TL-MF" => "Manufahi",
+    "TL-MT" => "Manatuto",
+    "TG-P" => "Plateaux",
+    "TO-02" => "Ha'apai",
+    "TO-03" => "Niuas",
+    "TO-04" => "Tongatapu",
+    "TO-05" => "Vava'u",
+    "TT-ARI" => "Arima",
+    "TT-SFO" => "San Fernando",
+    "TT-SIP" => "Siparia",
+    "TN-11" => "Tunis",
+    "TN-13" => "Ben Arous",
+    "TN-14" => "La Manouba",
+    "TN-23" => "Bizerte",
+    "TN-34" => "Siliana",
+    "TN-53" => "Mahdia",
+    "TN-71" => "Gafsa",
+    "TN-72" => "Tozeur",
+    "TN-81" => "Gabes",
+    "TR-01" => "Adana",
+    "TR-02" => "Adıyaman",
+    "TR-03" => "Afyonkarahisar",
+    "TR-05" => "Amasya",
+    "TR-06" => "Ankara",
+    "TR-07" => "Antalya",
+    "TR-08" => "Artvin",
+    "TR-09" => "Aydın",
+    "TR-12" => "Bingöl",
+    "TR-15" => "Burdur",
+    "TR-16" => "Bursa",
+    "TR-17" => "Çanakkale",
+    "TR-22" => "Edirne",
+    "TR-29" => "Gümüşhane",
+    "TR-30" => "Hakkâri",
+    "TR-31" => "Hatay",
+    "TR-32" => "Isparta",
+    "TR-34" => "İstanbul",
+    "TR-36" => "Kars",
+    "TR-37" => "Kastamonu",
+    "TR-42" => "Konya",
+    "TR-43" => "Kütahya",
+    "TR-44" => "Malatya",
+    "TR-45" => "Manisa",
+    "TR-46" => "Kahramanmaraş",
+    "TR-47" => "Mardin",
+    "TR-48" => "Muğla",
+    "TR-51" => "Niğde",
+    "TR-53" => "Rize",
+    "TR-57" => "Sinop",
+    "TR-60" => "Tokat",
+    "TR-61" => "Trabzon",
+    "TR-62" => "Tunceli",
+    "TR-63" => "Şanlıurfa",
+    "TR-65" => "Van",
+    "TR-70" => "Karaman",
+    "TR-72" => "Batman",
+    "TR-74" => "Bartın",
+    "TR-75" => "Ardahan",
+    "TR-77" => "Yalova",
+    "TR-78" => "Karabük",
+    "TR-79" => "Kilis",
+    "TR-80" => "Osmaniye",
+    "TR-81" => "Düzce",
+    "TM-B" => "Balkan",
+    "TM-L" => "Lebap",
+    "TM-M" => "Mary",
+    "TM-S" => "Aşgabat",
+    "TV-FUN" => "Funafuti",
+    "TV-NIT" => "Niutao",
+    "TV-NMA" => "Nanumea",
+    "TV-NUI" => "Nui",
+    "UG-102" => "Kampala",
+    "UG-105" => "Masaka",
+    "UG-108" => "Mukono",
+    "UG-109" => "Nakasongola",
+    "UG-112" => "Kayunga",
+    "UG-113" => "Wakiso",
+    "UG-114" => "Lyantonde",
+    "UG-115" => "Mityana",
+    "UG-201" => "Bugiri",
+    "UG-202" => "Busia",
+    "UG-203" => "Iganga",
+    "UG-204" => "Jinja",
+    "UG-205" => "Kamuli",
+    "UG-207" => "Katakwi",
+    "UG-208" => "Kumi",
+    "UG-209" => "Mbale",
+    "UG-210" => "Pallisa",
+    "UG-211" => "Soroti",
+    "UG-212" => "Tororo",
+    "UG-213" => "Kaberamaido",
+    "UG-214" => "Mayuge",
+    "UG-215" => "Sironko",
+    "UG-216" => "Amuria",
+    "UG-217" => "Budaka",
+    "UG-218" => "Bududa",
+    "UG-219" => "Bukedea",
+    "UG-221" => "Butaleja",
+    "UG-222" => "Kaliro",
+    "UG-223" => "Manafwa",
+    "UG-224" => "Namutumba",
+    "UG-301" => "Adjumani",
+    "UG-303" => "Arua",
+    "UG-304" => "Gulu",
+    "UG-307" => "Lira",
+    "UG-308" => "Moroto",
+    "UG-309" => "Moyo",
+    "UG-312" => "Pader",
+    "UG-313" => "Yumbe",
+    "UG-314" => "Abim",
+    "UG-315" => "Amolatar",
+    "UG-316" => "Amuru",
+    "UG-317" => "Dokolo",
+    "UG-318" => "Kaabong",
+    "UG-320" => "Maracha",
+    "UG-321" => "Oyam",
+    "UG-402" => "Bushenyi",
+    "UG-406" => "Kasese",
+    "UG-407" => "Kibaale",
+    "UG-409" => "Masindi",
+    "UG-413" => "Kamwenge",
+    "UG-414" => "Kanungu",
+    "UG-416" => "Buliisa",
+    "UG-417" => "Ibanda",
+    "UG-418" => "Isingiro",
+    "UG-C" => "Merkezi",
+    "UG-E" => "Doğu",
+    "UG-N" => "Kuzey",
+    "UG-W" => "Batı",
+    "AE-DU" => "Dubayy",
+    "AE-FU" => "Al Fujayrah",
+    "AE-RK" => "Ra’s al Khaymah",
+    "AE-SH" => "Ash Shāriqah",
+    "AE-UQ" => "Umm al Qaywayn",
+    "GB-AND" => "Ards ve North Down",
+    "GB-ANS" => "Angus",
+    "GB-BDF" => "Bedford",
+    "GB-BEN" => "Brent",
+    "GB-BEX" => "Bexley",
+    "GB-BIR" => "Birmingham",
+    "GB-BNE" => "Barnet",
+    "GB-BRD" => "Bradford",
+    "GB-BUR" => "Bury",
+    "GB-CBF" => "Merkez Bedfordshire",
+    "GB-CHE" => "Cheshire Doğu",
+    "GB-CLD" => "Calderdale",
+    "GB-CMA" => "Cumbria",
+    "GB-CMD" => "Camden",
+    "GB-COV" => "Coventry",
+    "GB-CRY" => "Croydon",
+    "GB-CWY" => "Conwy",
+    "GB-DAL" => "Darlington",
+    "GB-DER" => "Derby",
+    "GB-EAL" => "Ealing",
+    "GB-EAY" => "Doğu Ayrshire",
+    "GB-ELN" => "Doğu Lothian",
+    "GB-FMO" => "Fermanagh ve Omagh",
+    "GB-GRE" => "Greenwich",
+    "GB-HAM" => "Hampshire",
+    "GB-HEF" => "Herefordshire",
+    "GB-HLD" => "Highland",
+    "GB-HRY" => "Haringey",
+    "GB-ISL" => "Islington",
+    "GB-KEN" => "Kent",
+    "GB-KHL" => "Kingston upon Hull",
+    "GB-LCE" => "Leicester",
+    "GB-LDS" => "Leeds",
+    "GB-LND" => "Londra Şehri",
+    "GB-MAN" => "Manchester",
+    "GB-MLN" => "Midlothian",
+    "GB-MRT" => "Merton",
+    "GB-MRY" => "Moray",
+    "GB-NAY" => "Kuzey Ayrshire",
+    "GB-NBL" => "Northumberland",
+    "GB-NFK" => "Norfolk",
+    "GB-NGM" => "Nottingham",
+    "GB-NSM" => "Kuzey Somerset",
+    "GB-ORK" => "Orkney Adaları",
+    "GB-PLY" => "Plymouth",
+    "GB-POR" => "Portsmouth",
+    "GB-RFW" => "Renfrewshire",
+    "GB-RUT" => "Rutland",
+    "GB-SAY" => "Güney Ayrshire",
+    "GB-SHN" => "St. Helens",
+    "GB-SLF" => "Salford",
+    "GB-SND" => "Sunderland",
+    "GB-SOM" => "Somerset",
+    "GB-SRY" => "Surrey",
+    "GB-STH" => "Southampton",
+    "GB-SWD" => "Swindon",
+    "GB-SWK" => "Southwark",
+    "GB-TOB" => "Torbay",
+    "GB-WGN" => "Wigan",
+    "GB-WKF" => "Wakefield",
+    "GB-WOK" => "Wokingham",
+    "GB-WRT" => "Warrington",
+    "GB-WSM" => "Westminster",
+    "GB-YOR" => "York",
+    "GB-ZET" => "Shetland Adaları",
+    "US-AK" => "Alaska",
+    "US-AL" => "Alabama",
+    "US-AR" => "Arkansas",
+    "US-AS" => "Amerikan Samoası",
+    "US-AZ" => "Arizona",
+    "US-CA" => "Kaliforniya",
+    "US-CO" => "Kolorado",
+    "US-CT" => "Connecticut",
+    "US-DC" => "District of Columbia",
+    "US-DE" => "Delaware",
+    "US-FL" => "Florida",
+    "US-GA" => "Gürcistan",
+    "US-GU" => "Guam",
+    "US-HI" => "Hawaii",
+    "US-IA" => "Iowa",
+    "US-ID" => "Idaho",
+    "US-IL" => "Illinois",
+    "US-IN" => "Indiana",
+    "US-KS" => "Kansas",
+    "US-KY" => "Kentucky",
+    "US-LA" => "Louisiana",
+    "US-MA" => "Massachusetts",
+    "US-MD" => "Maryland",
+    "US-ME" => "Maine",
+    "US-MI" => "Michigan",
+    "US-MN" => "Minnesota",
+    "US-MO" => "Missouri",
+    "US-MP" => "Kuzey Mariana Adaları",
+    "US-MS" => "Mississippi",
+    "US-MT" => "Montana",
+    "US-NC" => "Kuzey Carolina",
+    "US-ND" => "Kuzey Dakota",
+    "US-NE" => "Nebraska",
+    "US-NH" => "New Hampshire",
+    "US-NJ" => "New Jersey",
+    "US-NM" => "New Mexico",
+    "US-NV" => "Nevada",
+    "US-NY" => "New York",
+    "US-OH" => "Ohio",
+    "US-OK" => "Oklahoma",
+    "US-OR" => "Oregon",
+    "US-PA" => "Pennsylvania",
+    "US-PR" => "Porto Riko",
+    "US-RI" => "Rhode Island",
+    "US-SC" => "Güney Carolina",
+    "US-SD" => "Güney Dakota",
+    "US-TN" => "Tennessee",
+    "US-TX" => "Texas",
+    "US-UM" => "Amerika Birleşik Devletleri Küçük Dış Adaları",
+    "US-UT" => "Utah",
+    "US-VA" => "Virginia",
+    "US-VT" => "Vermont",
+    "US-WA" => "Washington",
+    "US-WI" => "Wisconsin",
+    "US-WV" => "Batı Virginia",
+    "US-WY" => "Wyoming",
+    "UM-71" => "Midway Adaları",
+    "UM-76" => "Navassa Adası",
+    "UM-79" => "Wake Adası",
+    "UM-81" => "Baker Adası",
+    "UM-84" => "Howland Adası",
+    "UM-86" => "Jarvis Adası",
+    "UY-AR" => "Artigas",
+    "UY-CA" => "Canelones",
+    "UY-CO" => "Colonia",
+    "UY-FD" => "Florida",
+    "UY-FS" => "Flores",
+    "UY-MA" => "Maldonado",
+    "UY-PA" => "Paysandu",
+    "UY-RV" => "Rivera",
+    "UY-SA" => "Salto",
+    "UY-SJ" => "San José",
+    "UY-SO" => "Soriano",
+    "UZ-BU" => "Buhara",
+    "UZ-NW" => "Navii",
+    "UZ-XO" => "Harezm",
+    "VU-MAP" => "Malampa",
+    "VU-PAM" => "Penama",
+    "VU-SAM" => "Sanma",
+    "VU-SEE" => "Shefa",
+    "VU-TAE" => "Tafea",
+    "VU-TOB" => "Torba",
+    "VE-D" => "Aragua",
+    "VE-E" => "Barinas",
+    "VE-F" => "Bolívar",
+    "VE-G" => "Carabobo",
+    "VE-K" => "Lara",
+    "VE-L" => "Merida",
+    "VE-M" => "Miranda",
+    "VE-N" => "Monagas",
+    "VE-P" => "Portuguesa",
+    "VE-R" => "Sucre",
+    "VE-U" => "Yaracuy",
+    "VE-V" => "Zulia",
+    "VE-Z" => "Amazonas",
+    "VN-03" => "Ha Giang",
+    "VN-04" => "Cao Bang",
+    "VN-09" => "Lang Son",
+    "VN-36" => "Ninh Thuan",
+    "VN-39" => "Dong Nai",
+    "VN-40" => "Binh Thuan",
+    "VN-41" => "Long An",
+    "VN-46" => "Tien Giang",
+    "VN-54" => "Bac Giang",
+    "VN-63" => "Ha Nam",
+    "VN-73" => "Hau Giang",
+    "VN-HN" => "Ha Noi",
+    "YE-DH" => "Dhamar",
+    "ZM-01" => "Batı",
+    "ZM-02" => "Merkezi",
+    "ZM-03" => "Doğu",
+    "ZM-05" => "Kuzey",
+    "ZM-06" => "North-Western",
+    "ZM-07" => "Güney",
+    "ZM-09" => "Lusaka",
+    "ZW-BU" => "Bulawayo",
+    "ZW-MA" => "Manicaland",
+    "ZW-ME" => "Mashonaland East",
+    "ZW-MI" => "Midlands",
+    "ZW-MV" => "Masvingo",
+    "ZW-MW" => "Mashonaland West",
+};
+
+///Turkish former country names by alpha-4 code
+pub const FORMER_NAME_MAP: Map<&str, &str> = phf_map! {
+    "BQAQ" => "İngiliz Antarktika Bölgesi",
+    "BUMM" => "Burma Birliği Sosyalist Cumhuriyeti",
+    "BYAA" => "Beyaz Rusya SSC Sovyet Sosyalist Cumhuriyeti",
+    "CTKI" => "Canton ve Enderbury Adaları",
+    "CSHH" => "Çekoslovakya, Çekoslovak Sosyalist Cumhuriyeti",
+    "DYBJ" => "Dahomey",
+    "NQAQ" => "Kraliçe Maud Arazisi",
+    "TPTL" => "Doğu Timor",
+    "FXFR" => "Fransa, Metropolitan",
+    "AIDJ" => "Fransız Somalilandı",
+    "FQHH" => "Fransız Güney ve Antarktik Bölgeleri",
+    "DDDE" => "Alman Demokratik Cumhuriyeti",
+    "GEHH" => "Gilbert ve Ellice Adaları",
+    "JTUM" => "Johnston Adası",
+    "MIUM" => "Midway Adaları",
+    "ANHH" => "Hollanda Antilleri",
+    "NTHH" => "Tarafsız Bölge",
+    "NHVU" => "Yeni Hebridler",
+    "PCHH" => "Pasifik Adaları (vesayet ülkeleri)",
+    "PZPA" => "Panama Kanalı Bölgesi",
+    "CSXX" => "Sırbistan-Karadağ",
+    "SKIN" => "Sikkim",
+    "RHZW" => "Güney Rodezya",
+    "PUUM" => "ABD Çeşitli Pasifik Adaları",
+    "HVBF" => "Yukarı Volta Cumhuriyeti",
+    "SUHH" => "SSCB, Sovyet Sosyalist Cumhuriyetler Birliği",
+    "VDVN" => "Vietnam Demokratik Cumhuriyeti",
+    "WKUM" => "Wake Adası",
+    "YDYE" => "Yemen Demokratik Halk Cumhuriyeti",
+    "YUCS" => "Yugoslavya (Sosyalist) Federal Cumhuriyeti",
+    "ZRCD" => "Zaire Cumhuriyeti",
+};
@@ -0,0 +1,2990 @@
+use phf::phf_map;
+use phf::Map;
+
+///Simplified Chinese country names by alpha-2 code
+pub const COUNTRY_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF" => "阿富汗",
+    "AX" => "奥兰群岛",
+    "AL" => "阿尔巴尼亚",
+    "DZ" => "阿尔及利亚",
+    "AS" => "美属萨摩亚",
+    "AD" => "安道尔",
+    "AO" => "安哥拉",
+    "AI" => "安圭拉",
+    "AQ" => "南极洲",
+    "AG" => "安提瓜和巴布达",
+    "AR" => "阿根廷",
+    "AM" => "亚美尼亚",
+    "AW" => "阿鲁巴",
+    "AU" => "澳大利亚",
+    "AT" => "奥地利",
+    "AZ" => "阿塞拜疆",
+    "BS" => "巴哈马",
+    "BH" => "巴林",
+    "BD" => "孟加拉",
+    "BB" => "巴巴多斯",
+    "BY" => "白俄罗斯",
+    "BE" => "比利时",
+    "BZ" => "伯利兹",
+    "BJ" => "贝宁",
+    "BM" => "百慕大",
+    "BT" => "不丹",
+    "BO" => "玻利维亚共和国",
+    "BQ" => "博奈尔、圣尤斯特歇斯岛和萨巴",
+    "BA" => "波斯尼亚和黑塞哥维那",
+    "BW" => "博兹瓦那",
+    "BV" => "布维群岛",
+    "BR" => "巴西",
+    "IO" => "英属印度洋领地",
+    "BN" => "文莱",
+    "BG" => "保加利亚",
+    "BF" => "布基纳法索",
+    "BI" => "布隆迪",
+    "CV" => "佛得角",
+    "KH" => "柬埔塞",
+    "CM" => "喀麦隆",
+    "CA" => "加拿大",
+    "KY" => "开曼群岛",
+    "CF" => "中非",
+    "TD" => "乍得",
+    "CL" => "智利",
+    "CN" => "中国",
+    "CX" => "圣诞岛",
+    "CC" => "科科斯群岛",
+    "CO" => "哥伦比亚",
+    "KM" => "科摩罗",
+    "CG" => "刚果",
+    "CD" => "刚果民主共和国",
+    "CK" => "库克群岛",
+    "CR" => "哥斯达黎加",
+    "CI" => "科特迪瓦",
+    "HR" => "克罗地亚",
+    "CU" => "古巴",
+    "CW" => "库拉索",
+    "CY" => "塞浦路斯",
+    "CZ" => "捷克",
+    "DK" => "丹麦",
+    "DJ" => "吉布提",
+    "DM" => "多米尼克",
+    "DO" => "多米尼加共和国",
+    "EC" => "厄瓜多尔",
+    "EG" => "埃及",
+    "SV" => "萨尔瓦多",
+    "GQ" => "赤道几内亚",
+    "ER" => "厄立特里亚",
+    "EE" => "爱沙尼亚",
+    "SZ" => "斯威士兰",
+    "ET" => "埃塞俄比亚",
+    "FK" => "福克兰群岛(马尔维纳斯)",
+    "FO" => "法罗群岛",
+    "FJ" => "斐济",
+    "FI" => "芬兰",
+    "FR" => "法国",
+    "GF" => "法属圭亚那",
+    "PF" => "法属玻利尼西亚",
+    "TF" => "法属南半球领地",
+    "GA" => "加蓬",
+    "GM" => "冈比亚",
+    "GE" => "格鲁吉亚",
+    "DE" => "德国",
+    "GH" => "加纳",
+    "GI" => "直布罗陀",
+    "GR" => "希腊",
+    "GL" => "格陵兰",
+    "GD" => "格林纳达",
+    "GP" => "瓜德罗普",
+    "GU" => "关岛",
+    "GT" => "瓜地马拉",
+    "GG" => "根西岛",
+    "GN" => "几内亚",
+    "GW" => "几内亚比绍",
+    "GY" => "圭亚那",
+    "HT" => "海地",
+    "HM" => "赫德岛与麦克唐纳群岛",
+    "VA" => "梵地冈",
+    "HN" => "洪都拉斯",
+    "HK" => "香港",
+    "HU" => "匈牙利",
+    "IS" => "冰岛",
+    "IN" => "印度",
+    "ID" => "印度尼西亚",
+    "IR" => "伊朗伊斯兰共和国",
+    "IQ" => "伊拉克",
+    "IE" => "爱尔兰",
+    "IM" => "曼岛",
+    "IL" => "以色列",
+    "IT" => "意大利",
+    "JM" => "牙买加",
+    "JP" => "日本",
+    "JE" => "泽西岛",
+    "JO" => "约旦",
+    "KZ" => "哈萨克斯坦",
+    "KE" => "肯尼亚",
+    "KI" => "基里巴斯",
+    "KP" => "朝鲜民主主义人民共和国",
+    "KR" => "大韩民国",
+    "KW" => "科威特",
+    "KG" => "吉尔吉斯坦",
+    "LA" => "老挝人民民主共和国",
+    "LV" => "拉脱维亚",
+    "LB" => "黎巴嫩",
+    "LS" => "莱索托",
+    "LR" => "利比里亚",
+    "LY" => "利比亚",
+    "LI" => "列支敦士登",
+    "LT" => "立陶宛",
+    "LU" => "卢森堡",
+    "MO" => "澳门",
+    "MG" => "马达加斯加",
+    "MW" => "马拉维",
+    "MY" => "马来西亚",
+    "MV" => "马尔代夫",
+    "ML" => "马里",
+    "MT" => "马尔他",
+    "MH" => "马绍尔群岛",
+    "MQ" => "马提尼克",
+    "MR" => "毛里塔尼亚",
+    "MU" => "毛里求斯",
+    "YT" => "马约特",
+    "MX" => "墨西哥",
+    "FM" => "密克罗尼西亚",
+    "MD" => "摩尔多瓦共和国",
+    "MC" => "摩纳哥",
+    "MN" => "蒙古",
+    "ME" => "黑山",
+    "MS" => "蒙塞拉特岛",
+    "MA" => "摩洛哥",
+    "MZ" => "莫桑比克",
+    "MM" => "缅甸",
+    "NA" => "纳米比亚",
+    "NR" => "瑙鲁",
+    "NP" => "尼泊尔",
+    "NL" => "荷兰",
+    "NC" => "新喀里多尼亚",
+    "NZ" => "新西兰",
+    "NI" => "尼加拉瓜",
+    "NE" => "尼日尔",
+    "NG" => "尼日利亚",
+    "NU" => "纽埃",
+    "NF" => "诺福克岛",
+    "MK" => "北马其顿",
+    "MP" => "北马里亚纳群岛",
+    "NO" => "挪威",
+    "OM" => "阿曼",
+    "PK" => "巴基斯坦",
+    "PW" => "帕劳",
+    "PS" => "巴勒斯坦",
+    "PA" => "巴拿马",
+    "PG" => "巴布亚新几内亚",
+    "PY" => "巴拉圭",
+    "PE" => "秘鲁",
+    "PH" => "菲律宾",
+    "PN" => "皮特克恩",
+    "PL" => "波兰",
+    "PT" => "葡萄牙",
+    "PR" => "波多黎各",
+    "QA" => "卡塔尔",
+    "RE" => "留尼汪",
+    "RO" => "罗马尼亚",
+    "RU" => "俄罗斯",
+    "RW" => "卢旺达",
+    "BL" => "圣巴泰勒米岛",
+    "SH" => "圣赫勒拿-阿森松-特里斯坦达库尼亚",
+    "KN" => "圣基茨和尼维斯",
+    "LC" => "圣路西亚",
+    "MF" => "法属圣马丁",
+    "PM" => "圣皮埃尔和密克隆",
+    "VC" => "圣文森特和格林纳丁斯",
+    "WS" => "萨摩亚",
+    "SM" => "圣马力诺市",
+    "ST" => "圣多美和普林西比",
+    "SA" => "沙特阿拉伯",
+    "SN" => "塞内加尔",
+    "RS" => "塞尔维亚",
+    "SC" => "塞舌尔",
+    "SL" => "塞拉利昂",
+    "SG" => "新加坡",
+    "SX" => "荷属圣马丁",
+    "SK" => "斯洛伐克",
+    "SI" => "斯洛文尼亚",
+    "SB" => "所罗门群岛",
+    "SO" => "索马里",
+    "ZA" => "南非",
+    "GS" => "南乔治亚岛和南桑德韦奇岛",
+    "SS" => "南苏丹",
+    "ES" => "西班牙",
+    "LK" => "斯里兰卡",
+    "SD" => "苏丹",
+    "SR" => "苏里南",
+    "SJ" => "斯瓦尔巴特和扬马延岛",
+    "SE" => "瑞典",
+    "CH" => "瑞士",
+    "SY" => "阿拉伯叙利亚共和国",
+    "TW" => "中国台湾省",
+    "TJ" => "塔吉克斯坦",
+    "TZ" => "坦桑尼亚",
+    "TH" => "泰国",
+    "TL" => "东帝汶",
+    "TG" => "多哥",
+    "TK" => "托克劳",
+    "TO" => "汤加",
+    "TT" => "特里尼达和多巴哥",
+    "TN" => "突尼斯",
+    "TR" => "土耳其",
+    "TM" => "土库曼斯坦",
+    "TC" => "特克斯和凯科斯群岛",
+    "TV" => "图瓦卢",
+    "UG" => "乌干达",
+    "UA" => "乌克兰",
+    "AE" => "阿联酋",
+    "GB" => "英国",
+    "US" => "美国",
+    "UM" => "美国本土外小岛屿",
+    "UY" => "乌拉圭",
+    "UZ" => "乌兹别克斯坦",
+    "VU" => "瓦努阿图",
+    "VE" => "委内瑞拉玻利瓦尔共和国",
+    "VN" => "越南",
+    "VG" => "英属维尔京群岛",
+    "VI" => "美属维尔京群岛",
+    "WF" => "瓦利斯和富图纳",
+    "EH" => "西撒哈拉",
+    "YE" => "也门",
+    "ZM" => "赞比亚",
+    "ZW" => "津巴布韦",
+};
+
+///Simplified Chinese subdivision names by ISO 3166-2 code, 2696 of 5046
+pub const SUBDIVISION_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF-BAL" => "巴尔赫省",
+    "AF-BAM" => "巴米扬省",
+    "AF-BDG" => "巴德吉斯省",
+    "AF-BDS" => "巴达赫尚省",
+    "AF-BGL" => "巴格兰省",
+    "AF-DAY" => "代孔迪省",
+    "AF-FRA" => "法拉省",
+    "AF-FYB" => "法利亚布省",
+    "AF-GHA" => "加兹尼省",
+    "AF-GHO" => "古尔省",
+    "AF-HEL" => "赫尔曼德省",
+    "AF-HER" => "赫拉特省",
+    "AF-JOW" => "朱兹詹省",
+    "AF-KAB" => "喀布尔省",
+    "AF-KAN" => "坎大哈省",
+    "AF-KAP" => "卡比萨省",
+    "AF-KDZ" => "昆都士省",
+    "AF-KHO" => "霍斯特省",
+    "AF-KNR" => "库纳尔",
+    "AF-LAG" => "拉格曼省",
+    "AF-LOG" => "洛加尔省",
+    "AF-NAN" => "楠格哈尔省",
+    "AF-NIM" => "尼姆鲁兹省",
+    "AF-NUR" => "努尔斯坦省",
+    "AF-PAN" => "潘杰希尔省",
+    "AF-PAR" => "帕尔旺省",
+    "AF-PIA" => "帕克蒂亚省",
+    "AF-PKA" => "帕克蒂卡省",
+    "AF-SAM" => "萨曼甘省",
+    "AF-SAR" => "萨尔普勒省",
+    "AF-TAK" => "塔哈尔省",
+    "AF-URU" => "乌鲁兹甘省",
+    "AF-WAR" => "瓦尔达克省",
+    "AF-ZAB" => "扎布尔省",
+    "AL-01" => "培拉特区",
+    "AL-02" => "都拉斯区",
+    "AL-03" => "爱尔巴桑区",
+    "AL-04" => "非夏尔区",
+    "AL-05" => "吉罗卡斯特区",
+    "AL-07" => "库克斯区",
+    "AL-08" => "莱什区",
+    "AL-09" => "迪勃拉区",
+    "AL-10" => "斯库台区",
+    "AL-11" => "地拉那区",
+    "AL-12" => "发罗拉区",
+    "DZ-02" => "谢里夫",
+    "DZ-03" => "拉格瓦特",
+    "DZ-04" => "乌姆布阿基",
+    "DZ-05" => "巴特纳",
+    "DZ-07" => "比斯克拉",
+    "DZ-09" => "布利达",
+    "DZ-10" => "布依拉",
+    "DZ-13" => "特莱姆森",
+    "DZ-14" => "提亚雷特",
+    "DZ-15" => "提济乌祖",
+    "DZ-16" => "阿尔及尔",
+    "DZ-17" => "杰勒法",
+    "DZ-18" => "吉杰尔",
+    "DZ-21" => "斯基克达",
+    "DZ-23" => "安纳巴",
+    "DZ-24" => "盖尔马",
+    "DZ-25" => "君士坦丁",
+    "DZ-27" => "莫斯塔加纳姆",
+    "DZ-29" => "马斯卡拉",
+    "DZ-30" => "瓦尔格拉",
+    "DZ-31" => "奥兰",
+    "DZ-32" => "贝伊德",
+    "DZ-33" => "伊利齐",
+    "DZ-36" => "塔里夫",
+    "DZ-37" => "廷杜夫",
+    "DZ-38" => "蒂斯姆西勒特",
+    "DZ-39" => "瓦德",
+    "DZ-40" => "罕西拉",
+    "DZ-41" => "苏克·阿赫拉斯",
+    "DZ-42" => "蒂巴扎",
+    "DZ-43" => "密拉",
+    "DZ-45" => "纳阿马",
+    "DZ-48" => "赫利赞",
+    "AD-02" => "卡尼略",
+    "AD-03" => "恩坎普",
+    "AD-04" => "马萨纳",
+    "AD-05" => "奥尔迪诺",
+    "AD-06" => "圣胡利娅-德洛里亚",
+    "AD-07" => "安道尔城",
+    "AD-08" => "莱塞斯卡尔德-恩戈尔达",
+    "AO-BGO" => "本哥省",
+    "AO-BGU" => "本吉拉省",
+    "AO-BIE" => "比耶省",
+    "AO-CAB" => "喀丙达省",
+    "AO-CNN" => "库内纳省",
+    "AO-HUA" => "世博省",
+    "AO-HUI" => "威拉省",
+    "AO-LUA" => "罗安达省",
+    "AO-MAL" => "马兰哲省",
+    "AO-MOX" => "莫希科省",
+    "AO-NAM" => "纳米贝省",
+    "AO-UIG" => "威热省",
+    "AO-ZAI" => "扎伊尔省",
+    "AG-03" => "圣乔治",
+    "AG-04" => "圣约翰",
+    "AG-05" => "圣玛丽",
+    "AG-06" => "圣保罗",
+    "AG-07" => "圣彼得",
+    "AG-08" => "圣飞利浦",
+    "AG-10" => "巴布达",
+    "AG-11" => "雷东达岛",
+    "AR-A" => "萨尔塔省",
+    "AR-B" => "布宜诺斯艾利斯省",
+    "AR-D" => "圣路易斯省",
+    "AR-F" => "拉里奥哈",
+    "AR-G" => "圣地亚哥-德尔埃斯特罗省",
+    "AR-H" => "查科省",
+    "AR-J" => "圣胡安省",
+    "AR-K" => "卡塔马卡省",
+    "AR-L" => "拉潘帕省",
+    "AR-M" => "门多萨省",
+    "AR-N" => "米西奥内斯省",
+    "AR-P" => "福尔摩沙省",
+    "AR-S" => "圣菲省",
+    "AR-U" => "丘布特省",
+    "AR-V" => "火地岛省",
+    "AR-W" => "科连特斯省",
+    "AR-X" => "科尔多瓦省",
+    "AR-Y" => "胡胡伊省",
+    "AR-Z" => "圣克鲁斯省",
+    "AM-AR" => "阿拉拉特省",
+    "AM-AV" => "阿尔马维尔省",
+    "AM-ER" => "埃里温",
+    "AM-KT" => "科泰克省",
+    "AM-LO" => "洛里省",
+    "AM-SU" => "休尼克省",
+    "AU-ACT" => "澳大利亚首都特区",
+    "AU-NSW" => "新南威尔士",
+    "AU-NT" => "北领地",
+    "AU-QLD" => "昆士兰州",
+    "AU-SA" => "南澳大利亚州",
+    "AU-TAS" => "塔斯曼尼亚",
+    "AU-VIC" => "维多利亚州",
+    "AU-WA" => "西澳大利亚州",
+    "AT-1" => "布尔根兰州",
+    "AT-2" => "凯恩藤州",
+    "AT-3" => "下奥地利州",
+    "AT-4" => "上奥地利州",
+    "AT-5" => "萨尔茨堡州",
+    "AT-6" => "施泰尔马克州",
+    "AT-7" => "蒂罗尔州",
+    "AT-8" => "福拉尔贝格州",
+    "AT-9" => "维也纳州",
+    "AZ-ABS" => "阿布歇隆区",
+    "AZ-AGA" => "阿克斯塔法区",
+    "AZ-AGC" => "阿格贾贝迪区",
+    "AZ-AGM" => "阿格达姆区",
+    "AZ-AGS" => "阿格达什区",
+    "AZ-AGU" => "阿赫苏区",
+    "AZ-AST" => "阿斯塔拉区",
+    "AZ-BA" => "巴库",
+    "AZ-BAB" => "巴贝克区",
+    "AZ-BAL" => "巴拉肯区",
+    "AZ-NV" => "那克赤凡",
+    "AZ-NX" => "那克赤凡",
+    "AZ-QAZ" => "卡扎赫",
+    "AZ-SAL" => "萨利亚内",
+    "AZ-SM" => "苏姆盖特",
+    "BS-CI" => "卡特岛",
+    "BS-EX" => "埃克苏马",
+    "BS-HI" => "哈伯岛",
+    "BS-IN" => "伊纳瓜",
+    "BS-LI" => "长屿",
+    "BS-MG" => "马亚瓜纳",
+    "BS-RI" => "拉吉德岛",
+    "BS-SS" => "圣萨尔瓦多省",
+    "BH-14" => "南方省",
+    "BH-15" => "穆哈拉克省",
+    "BH-17" => "北方省",
+    "BB-02" => "圣安德鲁斯",
+    "BB-03" => "圣乔治",
+    "BB-04" => "圣詹姆斯",
+    "BB-05" => "圣约翰",
+    "BB-06" => "圣约瑟",
+    "BB-07" => "圣露西",
+    "BB-08" => "圣麦可",
+    "BB-09" => "圣彼得",
+    "BB-10" => "圣飞利浦",
+    "BB-11" => "圣托马斯",
+    "BE-VAN" => "安特卫普省",
+    "BE-VBR" => "佛兰芒布拉班特省",
+    "BE-VOV" => "东佛兰德省",
+    "BE-VWV" => "西佛兰德省",
+    "BE-WAL" => "瓦隆大区",
+    "BE-WHT" => "埃诺省",
+    "BE-WLX" => "卢森堡",
+    "BE-WNA" => "那慕尔省",
+    "BZ-BZ" => "伯利兹",
+    "BZ-CY" => "卡约",
+    "BZ-CZL" => "科罗萨尔",
+    "BZ-OW" => "橘园",
+    "BZ-SC" => "斯坦港",
+    "BJ-AL" => "阿黎博里省",
+    "BJ-AQ" => "大西洋省",
+    "BJ-BO" => "博尔古省",
+    "BJ-CO" => "丘陵省",
+    "BJ-DO" => "峡谷省",
+    "BJ-LI" => "滨海省",
+    "BJ-MO" => "莫诺省",
+    "BJ-OU" => "韦梅省",
+    "BJ-PL" => "高原省",
+    "BJ-ZO" => "祖省",
+    "BT-11" => "帕罗宗",
+    "BT-12" => "楚卡宗",
+    "BT-15" => "廷布宗",
+    "BT-21" => "奇朗宗",
+    "BT-22" => "达加纳宗",
+    "BT-23" => "普那卡宗",
+    "BT-24" => "旺杜波德朗宗",
+    "BT-31" => "盖莱普宗",
+    "BT-32" => "通萨宗",
+    "BT-33" => "布姆唐宗",
+    "BT-34" => "谢姆冈宗",
+    "BT-41" => "塔希冈宗",
+    "BT-42" => "蒙加尔宗",
+    "BT-44" => "伦奇宗",
+    "BT-GA" => "加萨宗",
+    "BT-TY" => "塔希央奇宗",
+    "BO-B" => "贝尼省",
+    "BO-C" => "科恰班巴省",
+    "BO-H" => "丘基萨卡省",
+    "BO-L" => "拉巴斯省",
+    "BO-N" => "潘多省",
+    "BO-O" => "奥鲁罗省",
+    "BO-P" => "波托西省",
+    "BO-S" => "圣克鲁斯省",
+    "BO-T" => "塔里哈省",
+    "BA-SRP" => "塞族共和国",
+    "BW-CE" => "中部",
+    "BW-GH" => "杭济",
+    "BW-KG" => "卡拉哈迪",
+    "BW-KL" => "卡特伦",
+    "BW-KW" => "奎嫩",
+    "BR-AC" => "阿克里",
+    "BR-AL" => "阿拉戈阿斯",
+    "BR-AM" => "亚马孙州",
+    "BR-BA" => "巴伊亚",
+    "BR-DF" => "首都特区",
+    "BR-MG" => "米纳斯吉拉斯",
+    "BR-MS" => "南马托格罗索",
+    "BR-MT" => "马托格罗索",
+    "BR-PE" => "伯南布哥",
+    "BR-RJ" => "里约热内卢",
+    "BR-RN" => "北里约格朗德",
+    "BR-RR" => "罗赖马",
+    "BR-RS" => "南里约格朗德",
+    "BR-SE" => "塞尔希培",
+    "BN-BE" => "马来奕",
+    "BN-BM" => "穆阿拉",
+    "BN-TE" => "腾布荣",
+    "BN-TU" => "都东",
+    "BG-01" => "布拉格耶夫格勒",
+    "BG-02" => "布尔加斯",
+    "BG-03" => "瓦尔纳",
+    "BG-04" => "大特尔诺沃",
+    "BG-05" => "维丁",
+    "BG-06" => "弗拉察",
+    "BG-07" => "加布罗沃",
+    "BG-08" => "多布里奇",
+    "BG-09" => "克尔贾利州",
+    "BG-10" => "丘斯滕迪尔",
+    "BG-11" => "洛维奇",
+    "BG-12" => "蒙塔纳",
+    "BG-13" => "帕扎尔吉克",
+    "BG-14" => "贝尔尼克",
+    "BG-15" => "普列文",
+    "BG-16" => "普罗夫迪夫",
+    "BG-17" => "拉兹格勒",
+    "BG-18" => "鲁塞",
+    "BG-19" => "锡利斯特拉",
+    "BG-20" => "斯利文",
+    "BG-21" => "斯莫梁",
+    "BG-23" => "索菲亚州",
+    "BG-24" => "旧扎戈拉",
+    "BG-25" => "特尔戈维什特",
+    "BG-26" => "哈斯科沃",
+    "BG-27" => "舒门",
+    "BG-28" => "扬博尔",
+    "BF-03" => "中部",
+    "BF-08" => "东部省",
+    "BF-10" => "北部省",
+    "BF-BAL" => "巴雷",
+    "BF-BAM" => "巴姆",
+    "BF-BAN" => "巴瓦",
+    "BF-BAZ" => "巴泽加",
+    "BF-BGR" => "布古里巴",
+    "BF-BLG" => "布尔古",
+    "BF-BLK" => "布尔基恩德",
+    "BF-COM" => "科莫埃",
+    "BF-GAN" => "冈祖尔古",
+    "BF-GNA" => "尼亚尼亚",
+    "BF-GOU" => "古尔马",
+    "BF-HOU" => "乌埃",
+    "BF-IOB" => "伊奥巴",
+    "BF-KAD" => "卡焦戈",
+    "BF-KMD" => "科蒙加里",
+    "BF-KMP" => "孔皮恩加",
+    "BF-KOS" => "孔西",
+    "BF-KOT" => "库里滕加",
+    "BF-LOR" => "罗卢姆",
+    "BF-MOU" => "穆翁",
+    "BF-NAM" => "纳门滕加",
+    "BF-NAY" => "纳亚拉",
+    "BF-NOU" => "努姆比埃尔",
+    "BF-OUB" => "乌布里滕加",
+    "BF-OUD" => "乌达兰",
+    "BF-PON" => "波尼",
+    "BF-SMT" => "桑马滕加",
+    "BF-SOM" => "苏姆",
+    "BF-SOR" => "苏鲁",
+    "BF-TAP" => "塔波阿",
+    "BF-YAG" => "亚加",
+    "BF-YAT" => "亚滕加",
+    "BF-ZIR" => "济罗",
+    "BF-ZON" => "宗多马",
+    "BI-BB" => "布班扎",
+    "BI-BR" => "布鲁里",
+    "BI-CA" => "坎库佐",
+    "BI-CI" => "锡比托凯",
+    "BI-GI" => "基特加",
+    "BI-KR" => "卡鲁济",
+    "BI-KY" => "卡扬扎",
+    "BI-MA" => "马坎巴",
+    "BI-MU" => "穆拉姆维亚",
+    "BI-MW" => "穆瓦洛",
+    "BI-NG" => "恩戈齐",
+    "BI-RT" => "鲁塔纳",
+    "BI-RY" => "鲁伊吉",
+    "CV-B" => "迎风群岛",
+    "CV-BR" => "布拉瓦岛",
+    "CV-BV" => "博阿维斯塔岛",
+    "CV-CR" => "圣克鲁斯省",
+    "CV-MA" => "马尤岛",
+    "CV-MO" => "莫斯特罗",
+    "CV-PN" => "波多诺伏",
+    "CV-PR" => "普拉亚",
+    "CV-RG" => "大里贝拉",
+    "CV-S" => "背风群岛",
+    "CV-SD" => "圣多明戈",
+    "CV-SF" => "圣菲利普",
+    "CV-SL" => "萨尔岛",
+    "CV-SV" => "圣维森特岛",
+    "CV-TA" => "塔拉法尔",
+    "KH-11" => "蒙多基里省",
+    "KH-12" => "金边市",
+    "KH-13" => "柏威夏省",
+    "KH-14" => "波罗勉省",
+    "KH-15" => "菩萨省",
+    "KH-16" => "拉达那基里省",
+    "KH-17" => "暹粒省",
+    "KH-20" => "柴桢省",
+    "KH-21" => "茶胶省",
+    "KH-22" => "奥多棉芷省",
+    "KH-4" => "磅清扬省",
+    "KH-7" => "贡布省",
+    "CM-AD" => "阿达马瓦省",
+    "CM-CE" => "中部",
+    "CM-EN" => "极北省",
+    "CM-ES" => "东方省",
+    "CM-LT" => "滨海省",
+    "CM-NO" => "北方省",
+    "CM-OU" => "西方省",
+    "CM-SU" => "南方省",
+    "CM-SW" => "西南省",
+    "CA-AB" => "艾伯塔",
+    "CA-BC" => "不列颠哥伦比亚",
+    "CA-MB" => "马尼托巴",
+    "CA-NB" => "新不伦瑞克",
+    "CA-NL" => "纽芬兰与拉布拉多",
+    "CA-NS" => "新斯科舍",
+    "CA-NT" => "西北地区",
+    "CA-NU" => "努纳武特",
+    "CA-ON" => "安大略省",
+    "CA-PE" => "爱德华王子岛省",
+    "CA-QC" => "魁北克省",
+    "CA-SK" => "萨斯喀彻温",
+    "CF-AC" => "瓦姆",
+    "CF-BB" => "巴明吉-班戈兰",
+    "CF-BGF" => "班吉直辖市",
+    "CF-BK" => "下科托",
+    "CF-HK" => "上科托",
+    "CF-HM" => "上姆博穆",
+    "CF-LB" => "洛巴伊",
+    "CF-MB" => "姆博穆",
+    "CF-SE" => "尚加",
+    "CF-UK" => "瓦卡",
+    "CF-VK" => "瓦卡加",
+    "CL-AN" => "安托法加斯塔大区",
+    "CL-AT" => "阿塔卡马大区",
+    "CL-CO" => "科金博大区",
+    "CL-LI" => "奥伊金斯将军解放者大区",
+    "CL-LL" => "湖大区",
+    "CL-LR" => "洛斯里奥斯省",
+    "CL-ML" => "马乌莱大区",
+    "CN-AH" => "安徽省",
+    "CN-BJ" => "北京市",
+    "CN-CQ" => "重庆市",
+    "CN-FJ" => "福建省",
+    "CN-GD" => "广东省",
+    "CN-GS" => "甘肃省",
+    "CN-GX" => "广西壮族自治区",
+    "CN-GZ" => "贵州省",
+    "CN-HA" => "河南省",
+    "CN-HB" => "湖北省",
+    "CN-HE" => "河北省",
+    "CN-HI" => "海南省",
+    "CN-HK" => "香港特别行政区",
+    "CN-HL" => "黑龙江省",
+    "CN-HN" => "湖南省",
+    "CN-JL" => "吉林省",
+    "CN-JS" => "江苏省",
+    "CN-JX" => "江西省",
+    "CN-LN" => "辽宁省",
+    "CN-MO" => "澳门特别行政区",
+    "CN-NM" => "内蒙古自治区",
+    "CN-NX" => "宁夏回族自治区",
+    "CN-QH" => "青海省",
+    "CN-SC" => "四川省",
+    "CN-SD" => "山东省",
+    "CN-SH" => "上海市",
+    "CN-SN" => "陕西省",
+    "CN-SX" => "山西省",
+    "CN-TJ" => "天津市",
+    "CN-TW" => "台湾省",
+    "CN-XJ" => "新疆维吾尔自治区",
+    "CN-XZ" => "西藏自治区",
+    "CN-YN" => "云南省",
+    "CN-ZJ" => "浙江省",
+    "CO-AMA" => "亚马孙州",
+    "CO-ANT" => "安提奥基亚",
+    "CO-ARA" => "阿劳卡",
+    "CO-ATL" => "大西洋",
+    "CO-BOL" => "玻利瓦尔省",
+    "CO-BOY" => "博亚卡",
+    "CO-CAL" => "卡尔达斯",
+    "CO-CAQ" => "卡克塔",
+    "CO-CAS" => "卡萨纳雷",
+    "CO-CAU" => "考卡",
+    "CO-CES" => "塞萨尔",
+    "CO-CHO" => "乔科",
+    "CO-COR" => "科尔多瓦省",
+    "CO-CUN" => "昆迪纳马卡",
+    "CO-DC" => "波哥大首都区",
+    "CO-GUA" => "瓜伊尼亚",
+    "CO-GUV" => "瓜维亚雷",
+    "CO-HUI" => "乌伊拉",
+    "CO-LAG" => "瓜希拉",
+    "CO-MAG" => "马格达雷那",
+    "CO-MET" => "梅塔",
+    "CO-NAR" => "纳里尼奥",
+    "CO-NSA" => "北桑坦德",
+    "CO-PUT" => "普图马约",
+    "CO-RIS" => "利萨拉尔达",
+    "CO-SAN" => "桑坦德",
+    "CO-SAP" => "圣安德烈斯-普罗维登西亚",
+    "CO-SUC" => "苏克雷",
+    "CO-TOL" => "托利马",
+    "CO-VAC" => "考卡山谷",
+    "CO-VAU" => "沃佩斯",
+    "CO-VID" => "维查达",
+    "CG-11" => "布恩扎",
+    "CG-12" => "普尔",
+    "CG-13" => "尚加",
+    "CG-15" => "西盆地省",
+    "CG-5" => "奎卢",
+    "CG-7" => "利夸拉",
+    "CG-8" => "盆地省",
+    "CG-9" => "尼阿利",
+    "CG-BZV" => "布拉柴维尔",
+    "CD-EQ" => "赤道",
+    "CD-KN" => "金沙萨直辖市",
+    "CD-MA" => "马尼埃马",
+    "CD-NK" => "北基伍",
+    "CD-SK" => "南基伍",
+    "CR-A" => "阿拉辉拉",
+    "CR-C" => "卡塔戈",
+    "CR-G" => "瓜纳卡斯特",
+    "CR-H" => "埃雷迪亚",
+    "CR-P" => "彭塔雷纳斯",
+    "CI-CM" => "科莫埃",
+    "HR-01" => "萨格勒布市",
+    "HR-02" => "克拉皮纳-扎戈列",
+    "HR-03" => "锡萨克-莫斯拉维纳",
+    "HR-04" => "卡尔洛瓦茨",
+    "HR-05" => "瓦拉日丁",
+    "HR-06" => "科普里夫尼察-克里热夫齐",
+    "HR-07" => "别洛瓦尔-比洛戈拉",
+    "HR-08" => "滨海和山区",
+    "HR-09" => "利卡-塞尼",
+    "HR-10" => "维罗维蒂察-波德拉维纳",
+    "HR-11" => "波热加-斯拉沃尼亚",
+    "HR-12" => "布罗德-波萨维纳",
+    "HR-13" => "扎达尔",
+    "HR-14" => "奥西耶克-巴拉尼亚",
+    "HR-15" => "希贝尼克-克宁",
+    "HR-16" => "武科瓦尔-斯里耶姆",
+    "HR-17" => "斯普利特-达尔马提亚",
+    "HR-18" => "伊斯特拉",
+    "HR-19" => "杜布罗夫斯克-内雷特瓦",
+    "HR-20" => "梅吉穆列",
+    "HR-21" => "札格雷布直辖市",
+    "CU-03" => "哈瓦那省",
+    "CU-04" => "马坦萨斯省",
+    "CU-05" => "比亚克拉拉省",
+    "CU-06" => "西恩富戈斯省",
+    "CU-07" => "圣斯皮里图斯",
+    "CU-08" => "谢戈德阿维拉省",
+    "CU-09" => "卡马圭省",
+    "CU-10" => "拉斯图纳斯省",
+    "CU-11" => "奥尔金",
+    "CU-12" => "格拉玛省",
+    "CU-13" => "圣地亚哥省",
+    "CU-14" => "关塔那摩省",
+    "CU-99" => "青年岛特区",
+    "CZ-20" => "中捷克",
+    "CZ-31" => "南捷克",
+    "CZ-32" => "比尔森",
+    "CZ-41" => "卡罗维发利",
+    "CZ-42" => "乌斯季",
+    "CZ-51" => "利贝雷克",
+    "CZ-52" => "赫拉德茨-克拉洛韦",
+    "CZ-53" => "帕尔杜比采",
+    "CZ-64" => "南摩拉维亚",
+    "CZ-71" => "奥洛穆茨",
+    "CZ-72" => "兹林",
+    "CZ-80" => "摩拉维亚-西里西亚",
+    "DK-81" => "北日德兰大区",
+    "DK-82" => "中日德兰大区",
+    "DK-83" => "南丹麦大区",
+    "DK-84" => "京畿大区",
+    "DK-85" => "西兰大区",
+    "DJ-AR" => "阿尔塔州",
+    "DJ-AS" => "阿里·萨比州",
+    "DJ-DI" => "迪奇尔州",
+    "DJ-DJ" => "吉布提市",
+    "DJ-TA" => "塔朱拉州",
+    "DM-02" => "圣安德鲁斯",
+    "DM-03" => "圣大卫",
+    "DM-04" => "圣乔治",
+    "DM-05" => "圣约翰",
+    "DM-06" => "圣约瑟",
+    "DM-07" => "圣路加",
+    "DM-08" => "圣马可",
+    "DM-09" => "圣派屈克",
+    "DM-10" => "圣保罗",
+    "DM-11" => "圣彼得",
+    "DO-01" => "国家区",
+    "DO-02" => "阿苏阿",
+    "DO-04" => "巴拉奥纳",
+    "DO-05" => "达哈翁",
+    "DO-21" => "圣克里斯托瓦尔",
+    "DO-22" => "圣胡安省",
+    "DO-25" => "圣地亚哥",
+    "EC-A" => "阿苏艾省",
+    "EC-B" => "玻利瓦尔省",
+    "EC-C" => "卡尔奇省",
+    "EC-D" => "奥雷利亚纳省",
+    "EC-E" => "埃斯梅拉尔达斯省",
+    "EC-F" => "卡尼亚尔省",
+    "EC-G" => "瓜亚斯省",
+    "EC-H" => "钦博拉索省",
+    "EC-I" => "因巴布拉省",
+    "EC-L" => "洛哈省",
+    "EC-M" => "马纳比省",
+    "EC-N" => "纳波省",
+    "EC-O" => "埃尔奥罗省",
+    "EC-P" => "皮钦查省",
+    "EC-R" => "洛斯里奥斯省",
+    "EC-T" => "通古拉瓦省",
+    "EC-U" => "苏昆毕奥斯省",
+    "EC-W" => "加拉帕戈斯省",
+    "EC-X" => "科托帕希省",
+    "EC-Y" => "帕斯塔萨省",
+    "EG-KFS" => "谢赫村",
+    "EG-SUZ" => "苏伊士",
+    "SV-CH" => "查拉特南戈省",
+    "SV-PA" => "拉巴斯省",
+    "SV-SA" => "圣安娜省",
+    "SV-SM" => "圣米格尔省",
+    "SV-SO" => "松索纳特省",
+    "SV-SS" => "圣萨尔瓦多省",
+    "SV-SV" => "圣维森特省",
+    "GQ-LI" => "滨海省",
+    "EE-81" => "瓦尔加省",
+    "SZ-HH" => "霍霍",
+    "SZ-LU" => "卢邦博",
+    "SZ-MA" => "曼齐尼区",
+    "SZ-SH" => "希塞卢韦尼",
+    "FJ-C" => "中部",
+    "FJ-E" => "东部区",
+    "FJ-N" => "北部区",
+    "FJ-R" => "罗图马岛",
+    "FJ-W" => "西部区",
+    "FR-01" => "安省",
+    "FR-02" => "埃纳省",
+    "FR-03" => "阿列省",
+    "FR-04" => "上普罗旺斯阿尔卑斯省",
+    "FR-05" => "上阿尔卑斯省",
+    "FR-06" => "滨海阿尔卑斯省",
+    "FR-07" => "阿尔代什省",
+    "FR-08" => "阿登省",
+    "FR-09" => "阿列日省",
+    "FR-10" => "奥布省",
+    "FR-11" => "奥德省",
+    "FR-12" => "阿韦龙省",
+    "FR-13" => "罗讷河口省",
+    "FR-14" => "卡尔瓦多斯省",
+    "FR-15" => "康塔尔省",
+    "FR-16" => "夏朗德省",
+    "FR-17" => "滨海夏朗德省",
+    "FR-18" => "谢尔省",
+    "FR-19" => "科雷兹省",
+    "FR-20R" => "科西嘉",
+    "FR-21" => "科多尔省",
+    "FR-22" => "阿摩尔滨海省",
+    "FR-23" => "克勒兹省",
+    "FR-24" => "多尔多涅省",
+    "FR-25" => "杜省",
+    "FR-26" => "德龙省",
+    "FR-27" => "厄尔省",
+    "FR-28" => "厄尔-卢瓦尔省",
+    "FR-29" => "菲尼斯泰尔省",
+    "FR-2A" => "南科西嘉",
+    "FR-2B" => "上科西嘉",
+    "FR-30" => "加尔省",
+    "FR-31" => "上加龙省",
+    "FR-32" => "热尔省",
+    "FR-33" => "吉伦特省",
+    "FR-34" => "埃罗省",
+    "FR-35" => "伊勒-维莱讷省",
+    "FR-36" => "安德尔省",
+    "FR-37" => "安德尔-卢瓦尔省",
+    "FR-38" => "伊泽尔省",
+    "FR-39" => "汝拉",
+    "FR-40" => "朗德省",
+    "FR-41" => "卢瓦尔-谢尔省",
+    "FR-42" => "卢瓦尔省",
+    "FR-43" => "上卢瓦尔省",
+    "FR-44" => "大西洋卢瓦尔省",
+    "FR-45" => "卢瓦雷省",
+    "FR-46" => "洛特省",
+    "FR-47" => "洛特-加龙省",
+    "FR-48" => "洛泽尔省",
+    "FR-49" => "曼恩-卢瓦尔省",
+    "FR-50" => "芒什省",
+    "FR-51" => "马恩省",
+    "FR-53" => "马耶讷省",
+    "FR-54" => "默尔特-摩泽尔省",
+    "FR-55" => "默兹省",
+    "FR-56" => "莫尔比昂省",
+    "FR-57" => "摩泽尔省",
+    "FR-58" => "涅夫勒省",
+    "FR-59" => "北部省",
+    "FR-60" => "瓦兹省",
+    "FR-61" => "奥恩省",
+    "FR-62" => "加来海峡省",
+    "FR-63" => "多姆山省",
+    "FR-64" => "比利牛斯-大西洋省",
+    "FR-65" => "上比利尼斯省",
+    "FR-66" => "东比利尼斯省",
+    "FR-67" => "下莱茵省",
+    "FR-68" => "上莱茵省",
+    "FR-69" => "罗讷省",
+    "FR-70" => "上索恩省",
+    "FR-71" => "索恩-卢瓦尔省",
+    "FR-72" => "萨尔特省",
+    "FR-73" => "萨瓦省",
+    "FR-74" => "上萨瓦省",
+    "FR-76" => "滨海塞纳省",
+    "FR-77" => "塞纳-马恩省",
+    "FR-78" => "伊夫林省",
+    "FR-79" => "德塞夫勒省",
+    "FR-80" => "索姆省",
+    "FR-81" => "塔恩省",
+    "FR-82" => "塔恩-加龙省",
+    "FR-83" => "瓦尔省",
+    "FR-84" => "沃克吕兹省",
+    "FR-85" => "旺代省",
+    "FR-86" => "维埃纳省",
+    "FR-87" => "上维埃纳省",
+    "FR-88" => "孚日省",
+    "FR-89" => "约讷省",
+    "FR-90" => "贝尔福地区",
+    "FR-91" => "埃松省",
+    "FR-92" => "上塞纳省",
+    "FR-93" => "塞纳-圣但尼省",
+    "FR-94" => "瓦勒德马恩省",
+    "FR-971" => "瓜德罗普",
+    "FR-972" => "马提尼克",
+    "FR-976" => "马约特",
+    "FR-BRE" => "布列塔尼",
+    "FR-IDF" => "法兰西岛",
+    "FR-NC" => "新喀里多尼亚",
+    "FR-PF" => "法属玻里尼西亚",
+    "FR-PM" => "圣皮埃尔和密克隆群岛",
+    "FR-TF" => "法属南部领地",
+    "GA-1" => "河口省",
+    "GA-2" => "上奥果韦省",
+    "GA-3" => "中奥果韦省",
+    "GA-4" => "恩古涅省",
+    "GA-5" => "尼扬加省",
+    "GA-6" => "奥果韦－伊温多省",
+    "GA-7" => "奥果韦－洛洛省",
+    "GA-8" => "滨海奥果韦省",
+    "GA-9" => "沃勒－恩特姆省",
+    "GM-B" => "班珠尔市",
+    "GM-L" => "下河区",
+    "GM-M" => "麦卡锡岛区",
+    "GM-N" => "北岸区",
+    "GM-U" => "上河区",
+    "GM-W" => "西部区",
+    "GE-AB" => "阿布哈兹",
+    "GE-AJ" => "阿扎尔",
+    "GE-GU" => "古利亚",
+    "DE-BB" => "勃兰登堡",
+    "DE-BE" => "柏林",
+    "DE-BY" => "巴伐利亚",
+    "DE-HB" => "不来梅",
+    "DE-HE" => "黑森",
+    "DE-HH" => "汉堡",
+    "DE-MV" => "梅克伦堡-前波美拉尼亚",
+    "DE-NI" => "下萨克森",
+    "DE-NW" => "北莱茵-威斯特法伦",
+    "DE-RP" => "莱茵兰-普法尔茨",
+    "DE-SH" => "石勒苏益格-荷尔斯泰因",
+    "DE-SL" => "萨尔",
+    "DE-SN" => "萨克森",
+    "DE-ST" => "萨克森-安哈尔特",
+    "DE-TH" => "图林根",
+    "GH-AA" => "大阿克拉地区",
+    "GH-AH" => "阿散蒂地区",
+    "GH-CP" => "中部",
+    "GH-EP" => "东部区",
+    "GH-NP" => "北部区",
+    "GH-TV" => "沃尔特地区",
+    "GH-UE" => "东北地区",
+    "GH-UW" => "西北地区",
+    "GH-WP" => "西部区",
+    "GD-01" => "圣安德鲁斯",
+    "GD-02" => "圣大卫",
+    "GD-03" => "圣乔治",
+    "GD-04" => "圣约翰",
+    "GD-05" => "圣马可",
+    "GD-06" => "圣派屈克",
+    "GD-10" => "南格林纳达群岛",
+    "GN-B" => "博凯",
+    "GN-BE" => "贝拉",
+    "GN-BF" => "博法",
+    "GN-BK" => "博凯",
+    "GN-CO" => "科亚",
+    "GN-D" => "金迪亚",
+    "GN-DB" => "达波拉",
+    "GN-DI" => "丁基拉耶",
+    "GN-DL" => "达拉巴",
+    "GN-DU" => "杜布雷卡",
+    "GN-F" => "法拉纳",
+    "GN-FA" => "法拉纳",
+    "GN-FO" => "弗雷卡里亚",
+    "GN-FR" => "弗里亚",
+    "GN-GA" => "高瓦尔",
+    "GN-GU" => "盖凯杜古",
+    "GN-K" => "康康",
+    "GN-KA" => "康康",
+    "GN-KB" => "库比亚",
+    "GN-KD" => "金迪亚",
+    "GN-KE" => "凯鲁阿内",
+    "GN-KN" => "孔达拉",
+    "GN-KO" => "库鲁萨",
+    "GN-KS" => "吉西杜古",
+    "GN-L" => "拉贝",
+    "GN-LA" => "拉贝",
+    "GN-LE" => "莱鲁马",
+    "GN-LO" => "洛拉",
+    "GN-M" => "玛木",
+    "GN-MC" => "马桑达",
+    "GN-MD" => "芒加纳",
+    "GN-ML" => "马里省",
+    "GN-MM" => "玛木",
+    "GN-N" => "恩泽雷科雷",
+    "GN-NZ" => "恩泽雷科雷",
+    "GN-PI" => "皮塔",
+    "GN-SI" => "西基里",
+    "GN-TE" => "特里梅莱",
+    "GN-TO" => "杜盖",
+    "GN-YO" => "尤木",
+    "GW-BM" => "比翁博",
+    "GW-BS" => "比绍",
+    "GW-CA" => "卡谢乌",
+    "GW-OI" => "奥约",
+    "GW-QU" => "基纳拉",
+    "GW-TO" => "通巴利",
+    "GY-BA" => "巴里马-瓦伊尼",
+    "GY-CU" => "库尤尼-马扎鲁尼",
+    "GY-DE" => "德梅拉拉-马海卡",
+    "GY-EB" => "东伯比斯-科兰太因",
+    "GY-ES" => "埃塞奎博群岛-西德梅拉拉",
+    "GY-MA" => "马海卡-伯比斯",
+    "GY-PM" => "波默伦-苏佩纳姆",
+    "GY-PT" => "波塔罗-锡帕鲁尼",
+    "GY-UD" => "上德梅拉拉-伯比斯",
+    "GY-UT" => "上塔库图-上埃塞奎博",
+    "HT-CE" => "中部",
+    "HT-ND" => "北部省",
+    "HT-NE" => "东北区",
+    "HT-NO" => "西北区",
+    "HN-CH" => "乔卢特卡",
+    "HN-CL" => "科隆省",
+    "HN-CM" => "科马亚瓜",
+    "HN-GD" => "格拉西亚斯-阿迪奥斯",
+    "HN-LE" => "伦皮拉",
+    "HN-LP" => "拉巴斯省",
+    "HN-OC" => "奥科特佩克",
+    "HN-OL" => "奥兰乔",
+    "HN-VA" => "山谷",
+    "HN-YO" => "约罗",
+    "HU-BA" => "巴兰尼亚州",
+    "HU-BC" => "贝凯什乔包",
+    "HU-BE" => "贝凯什州",
+    "HU-BK" => "巴奇-基什孔州",
+    "HU-BU" => "布达佩斯",
+    "HU-BZ" => "包尔绍德-奥包乌伊-曾普伦州",
+    "HU-CS" => "琼格拉德州",
+    "HU-DE" => "德布勒森",
+    "HU-DU" => "多瑙新城",
+    "HU-EG" => "埃格尔",
+    "HU-FE" => "费耶尔州",
+    "HU-GS" => "杰尔-莫松-肖普朗州",
+    "HU-GY" => "杰尔",
+    "HU-HE" => "赫维什州",
+    "HU-HV" => "霍德梅泽瓦",
+    "HU-JN" => "加兹-纳杰孔-索尔诺克州",
+    "HU-KE" => "科马罗姆-埃斯泰尔戈姆州",
+    "HU-KM" => "凯奇凯梅特",
+    "HU-KV" => "考波什堡",
+    "HU-MI" => "米什科尔茨",
+    "HU-NK" => "瑙吉考尼饶",
+    "HU-PE" => "佩斯州",
+    "HU-PS" => "佩奇",
+    "HU-SD" => "塞格德",
+    "HU-SF" => "塞克什白堡",
+    "HU-SH" => "松博特海伊",
+    "HU-SK" => "索尔诺克",
+    "HU-SN" => "肖普朗",
+    "HU-SO" => "绍莫吉州",
+    "HU-SS" => "塞克萨德",
+    "HU-ST" => "绍尔戈陶尔扬",
+    "HU-SZ" => "索博尔奇-索特马尔-贝拉格州",
+    "HU-TB" => "陶陶巴尼奥",
+    "HU-TO" => "托尔瑙州",
+    "HU-VA" => "沃什州",
+    "HU-VE" => "维斯普雷姆州",
+    "HU-VM" => "维斯普雷姆州",
+    "HU-ZA" => "佐洛州",
+    "HU-ZE" => "佐洛埃格塞格",
+    "IS-2" => "西南区",
+    "IS-3" => "西部区",
+    "IS-4" => "西峡湾区",
+    "IS-5" => "西北区",
+    "IS-6" => "东北区",
+    "IS-7" => "东部区",
+    "IS-8" => "南部区",
+    "IN-AN" => "安达曼-尼科巴群岛",
+    "IN-AP" => "安得拉邦",
+    "IN-AS" => "阿萨姆邦",
+    "IN-DL" => "德里",
+    "IN-GA" => "果阿邦",
+    "IN-KL" => "喀拉拉邦",
+    "IN-LD" => "拉克沙群岛",
+    "IN-MN" => "曼尼普尔邦",
+    "IN-MP" => "中央邦",
+    "IN-MZ" => "米佐拉姆邦",
+    "IN-PB" => "旁遮普邦",
+    "IN-SK" => "泰米尔纳德邦",
+    "IN-TR" => "特里普拉邦",
+    "IN-UP" => "北方邦",
+    "IN-WB" => "西孟加拉邦",
+    "ID-AC" => "亚齐",
+    "ID-BA" => "巴厘",
+    "ID-BE" => "明古鲁",
+    "ID-BT" => "万丹",
+    "ID-GO" => "哥伦打洛",
+    "ID-JA" => "占碑",
+    "ID-JB" => "西爪哇",
+    "ID-JI" => "东爪哇",
+    "ID-JK" => "雅加达",
+    "ID-JT" => "中爪哇",
+    "ID-JW" => "爪哇",
+    "ID-KA" => "加里曼丹",
+    "ID-KB" => "西加里曼丹",
+    "ID-KI" => "东加里曼丹",
+    "ID-KR" => "廖内群岛",
+    "ID-KS" => "南加里曼丹",
+    "ID-KT" => "中加里曼丹",
+    "ID-LA" => "楠榜",
+    "ID-MA" => "马鲁古",
+    "ID-ML" => "马鲁古",
+    "ID-MU" => "北马鲁古",
+    "ID-NB" => "西努沙登加拉",
+    "ID-NT" => "东努沙登加拉",
+    "ID-NU" => "努沙登加拉",
+    "ID-PA" => "巴布亚",
+    "ID-PP" => "巴布亚",
+    "ID-RI" => "廖内",
+    "ID-SA" => "北苏拉威西",
+    "ID-SG" => "东南苏拉威西",
+    "ID-SL" => "苏拉威西",
+    "ID-SM" => "苏门答腊",
+    "ID-SN" => "南苏拉威西",
+    "ID-SR" => "西苏拉威西",
+    "ID-ST" => "中苏拉威西",
+    "ID-SU" => "北苏门答腊",
+    "ID-YO" => "日惹特区",
+    "IR-00" => "中央",
+    "IR-01" => "吉兰省",
+    "IR-02" => "马赞德兰",
+    "IR-05" => "克尔曼沙汗",
+    "IR-06" => "胡齐斯坦",
+    "IR-07" => "法尔斯",
+    "IR-08" => "克尔曼",
+    "IR-10" => "伊斯法罕",
+    "IR-11" => "锡斯坦-俾路支斯坦",
+    "IR-12" => "库尔德斯坦",
+    "IR-13" => "哈马丹",
+    "IR-15" => "洛雷斯坦",
+    "IR-16" => "伊拉姆省",
+    "IR-18" => "布什尔",
+    "IR-19" => "赞詹",
+    "IR-20" => "塞姆南",
+    "IR-21" => "亚兹德",
+    "IR-22" => "霍尔木兹甘",
+    "IR-23" => "德黑兰",
+    "IR-24" => "阿尔达比勒",
+    "IR-25" => "库姆",
+    "IR-26" => "加兹温",
+    "IR-27" => "戈勒斯坦",
+    "IE-CE" => "克莱尔郡",
+    "IE-CN" => "卡文郡",
+    "IE-CO" => "科克郡",
+    "IE-CW" => "卡娄郡",
+    "IE-D" => "都柏林郡",
+    "IE-DL" => "当尼戈尔郡",
+    "IE-G" => "高维郡",
+    "IE-KE" => "基尔代尔郡",
+    "IE-KK" => "基尔肯尼郡",
+    "IE-KY" => "凯瑞郡",
+    "IE-L" => "伦斯特",
+    "IE-LD" => "朗福德郡",
+    "IE-LH" => "劳斯郡",
+    "IE-LK" => "利默里克郡",
+    "IE-LM" => "利特里姆郡",
+    "IE-LS" => "利施郡",
+    "IE-M" => "蒙斯特",
+    "IE-MH" => "米斯郡",
+    "IE-MN" => "莫纳亨郡",
+    "IE-MO" => "梅欧郡",
+    "IE-OY" => "奥法利郡",
+    "IE-RN" => "罗斯康门郡",
+    "IE-SO" => "斯莱戈郡",
+    "IE-TA" => "蒂珀雷里郡",
+    "IE-U" => "阿尔斯特",
+    "IE-WD" => "沃特福德郡",
+    "IE-WH" => "西米斯郡",
+    "IE-WW" => "威克娄郡",
+    "IE-WX" => "韦克斯福德郡",
+    "IT-21" => "皮埃蒙特",
+    "IT-25" => "伦巴第",
+    "IT-32" => "特伦蒂诺-上阿迪杰",
+    "IT-34" => "威尼托",
+    "IT-42" => "利古里亚",
+    "IT-45" => "艾米利亚-罗马涅",
+    "IT-52" => "托斯卡纳",
+    "IT-55" => "翁布里亚",
+    "IT-57" => "马尔凯",
+    "IT-62" => "拉齐奥",
+    "IT-65" => "阿布鲁佐",
+    "IT-67" => "莫利塞",
+    "IT-72" => "坎帕尼亚",
+    "IT-75" => "普利亚",
+    "IT-77" => "巴斯利卡塔",
+    "IT-78" => "卡拉布里亚",
+    "IT-82" => "西西里",
+    "IT-88" => "萨丁",
+    "IT-AG" => "阿格里根托",
+    "IT-AL" => "亚历山德里亚",
+    "IT-AN" => "安科纳",
+    "IT-AP" => "阿斯科利皮切诺",
+    "IT-AQ" => "拉奎拉",
+    "IT-AR" => "阿雷佐",
+    "IT-AT" => "阿斯蒂",
+    "IT-AV" => "阿韦利诺",
+    "IT-BG" => "贝加莫",
+    "IT-BI" => "比耶拉",
+    "IT-BL" => "贝卢诺",
+    "IT-BN" => "贝内文托",
+    "IT-BO" => "博洛尼亚",
+    "IT-BR" => "布林迪西",
+    "IT-BS" => "布雷西亚",
+    "IT-BZ" => "博尔扎诺",
+    "IT-CA" => "卡利亚里",
+    "IT-CB" => "坎波巴索",
+    "IT-CE" => "卡塞尔塔",
+    "IT-CH" => "奇耶蒂",
+    "IT-CL" => "卡尔塔尼塞塔",
+    "IT-CN" => "库内奥",
+    "IT-CO" => "科莫",
+    "IT-CR" => "克雷莫纳",
+    "IT-CS" => "科森扎",
+    "IT-CT" => "卡塔尼亚",
+    "IT-CZ" => "卡坦扎罗",
+    "IT-EN" => "恩纳",
+    "IT-FE" => "费拉拉",
+    "IT-FG" => "福贾",
+    "IT-FI" => "佛罗伦萨",
+    "IT-FR" => "弗洛西诺内",
+    "IT-GE" => "热那亚",
+    "IT-GO" => "戈里奇亚",
+    "IT-GR" => "格罗塞托",
+    "IT-IM" => "因佩里亚",
+    "IT-IS" => "伊塞尔尼亚",
+    "IT-KR" => "克罗托内",
+    "IT-LC" => "雷科",
+    "IT-LE" => "莱切",
+    "IT-LI" => "里窝那",
+    "IT-LO" => "洛迪",
+    "IT-LT" => "拉蒂纳",
+    "IT-LU" => "卢卡",
+    "IT-MC" => "马塞拉塔",
+    "IT-ME" => "墨西拿",
+    "IT-MI" => "米兰",
+    "IT-MN" => "蒙托瓦",
+    "IT-MO" => "摩德纳",
+    "IT-MS" => "马萨-卡拉拉",
+    "IT-MT" => "梅特拉",
+    "IT-NA" => "那波利",
+    "IT-NO" => "诺瓦拉",
+    "IT-NU" => "努奥罗",
+    "IT-OR" => "奥里斯塔诺",
+    "IT-PA" => "巴勒莫",
+    "IT-PC" => "皮亚琴察",
+    "IT-PD" => "帕多瓦",
+    "IT-PE" => "佩斯卡拉",
+    "IT-PG" => "佩鲁贾",
+    "IT-PI" => "比萨",
+    "IT-PN" => "波代诺内",
+    "IT-PO" => "普拉托",
+    "IT-PR" => "帕尔马",
+    "IT-PT" => "比斯托利亚",
+    "IT-PU" => "佩萨洛乌尔毕诺",
+    "IT-PV" => "帕维亚",
+    "IT-PZ" => "波坦察",
+    "IT-RA" => "拉韦纳",
+    "IT-RC" => "雷焦卡拉布里亚",
+    "IT-RE" => "雷焦艾米利亚",
+    "IT-RG" => "拉古萨",
+    "IT-RI" => "列蒂",
+    "IT-RM" => "罗马",
+    "IT-RN" => "里米尼",
+    "IT-RO" => "罗维戈",
+    "IT-SA" => "萨莱诺",
+    "IT-SI" => "锡耶纳",
+    "IT-SO" => "松德里奥",
+    "IT-SP" => "拉斯佩奇亚",
+    "IT-SR" => "锡拉库萨",
+    "IT-SS" => "萨萨里",
+    "IT-SV" => "萨窝那",
+    "IT-TA" => "塔兰托",
+    "IT-TE" => "泰拉莫",
+    "IT-TN" => "特伦托",
+    "IT-TO" => "都灵",
+    "IT-TP" => "特拉帕尼",
+    "IT-TR" => "特尔尼",
+    "IT-TS" => "的里雅斯特",
+    "IT-TV" => "特雷维索",
+    "IT-UD" => "乌迪内",
+    "IT-VA" => "瓦雷泽",
+    "IT-VB" => "韦巴诺-库西奥-奥索拉",
+    "IT-VC" => "韦塞利",
+    "IT-VE" => "威尼斯",
+    "IT-VI" => "维琴察",
+    "IT-VR" => "维罗那",
+    "IT-VT" => "维特尔博",
+    "IT-VV" => "维伯瓦伦蒂亚",
+    "JM-01" => "金斯敦",
+    "JM-02" => "圣安德鲁斯",
+    "JM-03" => "圣托马斯",
+    "JM-04" => "波特兰",
+    "JM-05" => "圣玛丽",
+    "JM-06" => "圣安娜",
+    "JM-07" => "特里洛尼",
+    "JM-08" => "圣詹姆斯",
+    "JM-09" => "汉诺威",
+    "JM-10" => "西摩兰",
+    "JM-11" => "圣伊丽莎白",
+    "JM-13" => "克拉伦登",
+    "JM-14" => "圣凯瑟琳",
+    "JP-01" => "北海道",
+    "JP-02" => "青森县",
+    "JP-03" => "岩手县",
+    "JP-04" => "宫城县",
+    "JP-05" => "秋田县",
+    "JP-06" => "山形县",
+    "JP-07" => "福岛县",
+    "JP-08" => "茨城县",
+    "JP-09" => "枥木县",
+    "JP-10" => "群马县",
+    "JP-11" => "琦玉县",
+    "JP-12" => "千叶县",
+    "JP-13" => "东京都",
+    "JP-14" => "神奈川县",
+    "JP-15" => "新泻县",
+    "JP-16" => "富山县",
+    "JP-17" => "石川县",
+    "JP-18" => "福井县",
+    "JP-19" => "山梨县",
+    "JP-20" => "长野县",
+    "JP-21" => "岐阜县",
+    "JP-22" => "静冈县",
+    "JP-23" => "爱知县",
+    "JP-24" => "三重县",
+    "JP-25" => "滋贺县",
+    "JP-26" => "京都府",
+    "JP-27" => "大阪府",
+    "JP-28" => "兵库县",
+    "JP-29" => "奈良县",
+    "JP-30" => "和歌山县",
+    "JP-31" => "鸟取县",
+    "JP-32" => "岛根县",
+    "JP-33" => "冈山县",
+    "JP-34" => "广岛县",
+    "JP-35" => "山口县",
+    "JP-36" => "德岛县",
+    "JP-37" => "香川县",
+    "JP-38" => "爱媛县",
+    "JP-39" => "高知县",
+    "JP-40" => "福冈县",
+    "JP-41" => "佐贺县",
+    "JP-42" => "长崎县",
+    "JP-43" => "熊本县",
+    "JP-44" => "大分县",
+    "JP-45" => "宫崎县",
+    "JP-46" => "鹿儿岛县",
+    "JP-47" => "冲绳县",
+    "JO-IR" => "伊尔比德",
+    "JO-JA" => "杰拉什",
+    "JO-KA" => "卡拉克",
+    "JO-MA" => "马夫拉克",
+    "KE-04" => "布西亚",
+    "KI-G" => "吉尔伯特群岛",
+    "KI-L" => "莱恩群岛",
+    "KI-P" => "菲尼克斯群岛",
+    "KP-04" => "慈江道",
+    "KG-B" => "巴特肯州",
+    "KG-N" => "纳伦州",
+    "KG-O" => "奥什州",
+    "KG-T" => "塔拉斯州",
+    "LA-AT" => "阿速坡",
+    "LA-BK" => "博乔",
+    "LA-BL" => "波里坎赛",
+    "LA-CH" => "占巴塞",
+    "LA-HO" => "华潘",
+    "LA-KH" => "甘蒙",
+    "LA-LM" => "琅南塔",
+    "LA-LP" => "琅勃拉邦",
+    "LA-OU" => "乌多姆赛",
+    "LA-PH" => "丰沙里",
+    "LA-SL" => "沙拉湾",
+    "LA-SV" => "沙湾拿吉",
+    "LA-XA" => "沙耶武里",
+    "LV-DGV" => "陶格夫皮尔斯",
+    "LV-JEL" => "叶尔加瓦",
+    "LV-VEN" => "文茨皮尔斯",
+    "LS-A" => "马塞卢",
+    "LS-C" => "莱里贝",
+    "LS-D" => "伯里亚",
+    "LS-E" => "马费滕",
+    "LS-F" => "莫哈莱斯胡克",
+    "LS-G" => "古廷",
+    "LS-H" => "加查斯内克",
+    "LS-J" => "莫霍特隆",
+    "LS-K" => "塔巴采卡",
+    "LR-BG" => "邦县",
+    "LR-BM" => "伯米县",
+    "LR-CM" => "大角山县",
+    "LR-GG" => "大各德县",
+    "LR-GK" => "大克鲁县",
+    "LR-LO" => "罗发县",
+    "LR-MG" => "马及比县",
+    "LR-MO" => "孟色罗拉多县",
+    "LR-NI" => "年巴县",
+    "LR-SI" => "习诺县",
+    "LY-BA" => "班加西",
+    "LY-BU" => "巴特曼",
+    "LY-DR" => "德尔纳",
+    "LY-JA" => "绿山",
+    "LY-JU" => "胡夫拉",
+    "LY-KF" => "库夫拉",
+    "LY-MI" => "米苏拉塔",
+    "LY-MJ" => "迈尔季",
+    "LY-MQ" => "迈尔祖格",
+    "LY-SB" => "塞卜哈",
+    "LY-SR" => "苏尔特",
+    "LY-TB" => "的黎波里",
+    "LI-01" => "巴札尔",
+    "LI-02" => "埃申",
+    "LI-03" => "甘普林",
+    "LI-04" => "毛伦",
+    "LI-05" => "伯朗肯",
+    "LI-06" => "儒格尔",
+    "LI-07" => "沙恩",
+    "LI-08" => "许内勒贝格",
+    "LI-09" => "特里森",
+    "LI-10" => "特里森贝格",
+    "LI-11" => "瓦杜茨",
+    "LU-DI" => "迪基希",
+    "LU-GR" => "格雷文马赫",
+    "LU-LU" => "卢森堡",
+    "MG-A" => "图阿马西拉省",
+    "MG-D" => "安齐拉纳纳省",
+    "MG-F" => "菲亚纳兰楚阿省",
+    "MG-M" => "马哈赞加省",
+    "MG-T" => "塔那那利佛省",
+    "MG-U" => "图利亚拉省",
+    "MW-BA" => "巴拉卡县",
+    "MW-BL" => "布兰太尔县",
+    "MW-CK" => "奇克瓦瓦县",
+    "MW-CR" => "奇拉朱卢县",
+    "MW-CT" => "奇蒂帕县",
+    "MW-DE" => "代扎县",
+    "MW-DO" => "多瓦县",
+    "MW-KR" => "卡龙加县",
+    "MW-KS" => "卡松古县",
+    "MW-LI" => "利隆圭县",
+    "MW-MC" => "姆钦吉县",
+    "MW-MG" => "曼戈切县",
+    "MW-MH" => "马钦加县",
+    "MW-MU" => "姆兰杰县",
+    "MW-MW" => "姆万扎区",
+    "MW-MZ" => "姆津巴县",
+    "MW-NB" => "恩卡塔湾县",
+    "MW-NI" => "恩奇斯县",
+    "MW-NK" => "恩科塔科塔县",
+    "MW-NS" => "恩桑杰县",
+    "MW-NU" => "恩彻乌县",
+    "MW-RU" => "伦比县",
+    "MW-SA" => "萨利马县",
+    "MW-TH" => "蒂约罗县",
+    "MW-ZO" => "松巴县",
+    "MY-01" => "柔佛",
+    "MY-02" => "吉打",
+    "MY-03" => "吉兰丹",
+    "MY-04" => "马六甲",
+    "MY-05" => "森美兰",
+    "MY-06" => "彭亨",
+    "MY-07" => "槟城",
+    "MY-08" => "霹雳",
+    "MY-09" => "玻璃市",
+    "MY-10" => "雪兰莪",
+    "MY-11" => "登嘉楼",
+    "MY-12" => "沙巴",
+    "MY-13" => "砂拉越",
+    "MY-14" => "吉隆坡",
+    "MY-15" => "纳闽",
+    "MY-16" => "布特拉再也",
+    "MV-MLE" => "马累",
+    "ML-1" => "卡伊地区",
+    "ML-2" => "库里克罗地区",
+    "ML-3" => "锡加索地区",
+    "ML-4" => "塞古地区",
+    "ML-5" => "莫普提地区",
+    "ML-6" => "通布图地区",
+    "ML-7" => "加奥地区",
+    "ML-8" => "基达尔地区",
+    "ML-BKO" => "巴马科",
+    "MT-47" => "萨非",
+    "MT-49" => "圣约翰",
+    "MH-ALK" => "艾卢克",
+    "MH-ARN" => "阿尔诺",
+    "MH-AUR" => "奥尔",
+    "MH-EBO" => "埃崩",
+    "MH-JAL" => "贾卢伊特",
+    "MH-KWA" => "夸贾林",
+    "MH-LAE" => "拉埃",
+    "MH-LIB" => "里布",
+    "MH-LIK" => "利基埃普",
+    "MH-MAJ" => "马朱罗",
+    "MH-MAL" => "马洛埃拉普",
+    "MH-MEJ" => "梅吉特",
+    "MH-MIL" => "米利",
+    "MH-NMU" => "纳木",
+    "MH-RON" => "朗格拉普",
+    "MH-UJA" => "乌贾",
+    "MH-WTH" => "沃特",
+    "MH-WTJ" => "沃特杰",
+    "MR-01" => "西胡德",
+    "MR-04" => "戈尔戈勒",
+    "MR-05" => "卜拉克纳",
+    "MR-06" => "特拉扎",
+    "MR-09" => "塔甘特",
+    "MR-10" => "吉迪马卡",
+    "MR-11" => "提里斯-宰穆尔",
+    "MR-12" => "因希里",
+    "MU-AG" => "阿加莱加群岛",
+    "MU-BL" => "黑河",
+    "MU-FL" => "弗拉克",
+    "MU-GP" => "大港",
+    "MU-MO" => "莫卡",
+    "MU-PA" => "庞普勒穆斯",
+    "MU-PL" => "路易港",
+    "MU-PW" => "威廉平原",
+    "MU-RO" => "罗德里格斯",
+    "MU-SA" => "萨凡纳",
+    "MX-AGU" => "阿瓜斯卡连特斯州",
+    "MX-BCN" => "下加利福尼亚州",
+    "MX-BCS" => "南下加利福尼亚州",
+    "MX-CAM" => "坎佩切州",
+    "MX-CHH" => "奇瓦瓦州",
+    "MX-CHP" => "恰帕斯州",
+    "MX-COL" => "科利马州",
+    "MX-DUR" => "杜兰戈州",
+    "MX-GRO" => "格雷罗州",
+    "MX-GUA" => "瓜纳华托州",
+    "MX-HID" => "伊达尔戈州",
+    "MX-JAL" => "哈利斯科州",
+    "MX-MOR" => "莫雷洛斯州",
+    "MX-NAY" => "纳亚里特州",
+    "MX-OAX" => "瓦哈卡州",
+    "MX-PUE" => "普埃布拉州",
+    "MX-ROO" => "金塔纳罗奥州",
+    "MX-SIN" => "锡那罗亚州",
+    "MX-SON" => "索诺拉州",
+    "MX-TAB" => "塔巴斯科州",
+    "MX-TAM" => "塔毛利帕斯州",
+    "MX-TLA" => "特拉斯卡拉州",
+    "MX-ZAC" => "萨卡特卡斯州",
+    "FM-KSA" => "科斯雷",
+    "FM-PNI" => "波纳佩",
+    "FM-TRK" => "丘克",
+    "FM-YAP" => "雅浦",
+    "MD-CA" => "卡胡尔",
+    "MD-OR" => "奥尔海伊",
+    "MD-SN" => "德涅斯特河左岸地区",
+    "MD-SO" => "索罗卡",
+    "MD-TA" => "塔拉克利亚",
+    "MD-UN" => "温盖尼",
+    "MN-035" => "鄂尔浑省",
+    "MN-037" => "达尔汗乌勒省",
+    "MN-039" => "肯特省",
+    "MN-043" => "科布多省",
+    "MN-046" => "乌布苏省",
+    "MN-049" => "色楞格省",
+    "MN-057" => "扎布汗省",
+    "MN-061" => "东方省",
+    "MN-067" => "布尔干省",
+    "MN-069" => "巴彦洪戈尔省",
+    "MN-073" => "后杭爱省",
+    "ME-01" => "安德里耶维察",
+    "ME-02" => "巴尔",
+    "ME-03" => "贝拉内",
+    "ME-04" => "比耶洛波列",
+    "ME-05" => "布德瓦",
+    "ME-06" => "采蒂涅",
+    "ME-07" => "达尼洛夫格勒",
+    "ME-08" => "新海尔采格",
+    "ME-09" => "科拉辛",
+    "ME-10" => "科托尔",
+    "ME-11" => "莫伊科瓦茨",
+    "ME-12" => "尼克希奇",
+    "ME-13" => "普拉夫",
+    "ME-14" => "普卢日内",
+    "ME-15" => "普列夫利亚",
+    "ME-16" => "波德戈里察",
+    "ME-17" => "罗扎伊",
+    "ME-18" => "沙夫尼克",
+    "ME-19" => "蒂瓦特",
+    "ME-20" => "乌尔齐尼",
+    "ME-21" => "扎布良克",
+    "MA-AZI" => "艾济拉勒",
+    "MA-BER" => "贝尔坎",
+    "MA-BOM" => "布勒曼",
+    "MA-CHI" => "希沙瓦",
+    "MA-ERR" => "拉希迪耶",
+    "MA-ESI" => "索维拉",
+    "MA-FIG" => "菲吉格",
+    "MA-GUE" => "盖勒敏",
+    "MA-HAJ" => "哈杰布",
+    "MA-HAO" => "豪兹",
+    "MA-HOC" => "胡塞马",
+    "MA-IFR" => "伊夫兰",
+    "MA-JDI" => "杰迪代",
+    "MA-KEN" => "盖尼特拉",
+    "MA-KHO" => "胡里卜盖",
+    "MA-LAA" => "阿尤恩",
+    "MA-LAR" => "拉拉谢",
+    "MA-NAD" => "纳祖尔",
+    "MA-OUA" => "瓦尔扎扎特",
+    "MA-SAF" => "萨非",
+    "MA-SEF" => "塞夫劳",
+    "MA-SET" => "塞塔特",
+    "MA-TAO" => "陶纳特",
+    "MA-TAT" => "塔塔",
+    "MA-TAZ" => "塔扎",
+    "MA-TET" => "得土安",
+    "MA-TIZ" => "提兹尼特",
+    "MZ-A" => "尼亚萨省",
+    "MZ-B" => "马尼卡省",
+    "MZ-G" => "加扎省",
+    "MZ-I" => "伊尼扬巴内",
+    "MZ-L" => "马普托市",
+    "MZ-MPM" => "马普托市",
+    "MZ-P" => "德尔加杜角省",
+    "MZ-S" => "索法拉省",
+    "MZ-T" => "太特省",
+    "MM-01" => "实皆省",
+    "MM-02" => "勃固省",
+    "MM-03" => "马圭省",
+    "MM-04" => "曼德勒省",
+    "MM-05" => "德林达依省",
+    "MM-06" => "仰光省",
+    "MM-07" => "伊洛瓦底省",
+    "MM-11" => "克钦邦",
+    "MM-12" => "克耶邦",
+    "MM-13" => "克伦邦",
+    "MM-14" => "钦邦",
+    "MM-15" => "孟邦",
+    "MM-16" => "若开邦",
+    "MM-17" => "掸邦",
+    "NA-ER" => "埃龙戈",
+    "NA-HA" => "哈达普",
+    "NA-KH" => "霍马斯",
+    "NA-KU" => "库内内",
+    "NA-OD" => "奥乔宗蒂约巴",
+    "NA-OH" => "奥马赫科",
+    "NA-ON" => "奥沙纳",
+    "NA-OS" => "奥姆沙蒂",
+    "NA-OT" => "奥希科托",
+    "NA-OW" => "奥汉圭纳",
+    "NL-AW" => "阿鲁巴",
+    "NL-CW" => "库拉索",
+    "NL-DR" => "德伦特省",
+    "NL-FL" => "菲仕兰省",
+    "NL-GE" => "海尔德兰",
+    "NL-GR" => "格罗宁根",
+    "NL-NB" => "北部拉班特",
+    "NL-NH" => "北荷兰",
+    "NL-OV" => "上艾瑟尔",
+    "NL-UT" => "乌德勒支",
+    "NL-ZE" => "泽兰",
+    "NL-ZH" => "南荷兰",
+    "NZ-AUK" => "奥克兰",
+    "NZ-BOP" => "丰盛湾",
+    "NZ-CAN" => "坎特伯雷",
+    "NZ-MWT" => "马努瓦图-旺格纽伊",
+    "NZ-NTL" => "北部地区",
+    "NZ-OTA" => "奥塔哥地区",
+    "NZ-STL" => "南部地区",
+    "NZ-TKI" => "塔拉纳奇",
+    "NZ-WGN" => "惠灵顿",
+    "NZ-WKO" => "怀卡托",
+    "NZ-WTC" => "西岸",
+    "NI-BO" => "博阿科",
+    "NI-CA" => "卡拉索",
+    "NI-CI" => "奇南德加",
+    "NI-CO" => "琼塔莱斯",
+    "NI-ES" => "埃斯特利",
+    "NI-GR" => "格拉纳达",
+    "NI-JI" => "希诺特加",
+    "NI-LE" => "莱昂",
+    "NI-MD" => "马德里斯",
+    "NI-MN" => "马那瓜",
+    "NI-MS" => "马萨亚",
+    "NI-MT" => "马塔加尔帕",
+    "NI-NS" => "新塞哥维亚",
+    "NI-RI" => "里瓦斯",
+    "NI-SJ" => "圣胡安河",
+    "NE-1" => "阿加德兹",
+    "NE-2" => "迪法",
+    "NE-3" => "多索",
+    "NE-4" => "马拉迪",
+    "NE-5" => "塔瓦",
+    "NE-6" => "蒂拉贝里",
+    "NE-7" => "津德尔",
+    "NE-8" => "尼亚美市",
+    "NG-AB" => "阿比亚州",
+    "NG-AD" => "阿达马瓦州",
+    "NG-AK" => "阿夸伊博姆州",
+    "NG-AN" => "阿南布拉州",
+    "NG-BA" => "包奇州",
+    "NG-BE" => "贝努埃州",
+    "NG-BO" => "博尔诺州",
+    "NG-BY" => "巴耶尔萨州",
+    "NG-CR" => "克里斯河州",
+    "NG-DE" => "三角州",
+    "NG-EB" => "埃邦伊州",
+    "NG-ED" => "埃多州",
+    "NG-EK" => "埃基蒂州",
+    "NG-EN" => "埃努古州",
+    "NG-GO" => "贡贝州",
+    "NG-IM" => "伊莫州",
+    "NG-JI" => "吉加瓦州",
+    "NG-KD" => "卡杜纳州",
+    "NG-KE" => "凯比州",
+    "NG-KN" => "卡诺州",
+    "NG-KO" => "科吉州",
+    "NG-KT" => "卡齐纳州",
+    "NG-KW" => "夸拉州",
+    "NG-LA" => "拉哥斯州",
+    "NG-NA" => "纳萨拉瓦州",
+    "NG-NI" => "尼日尔州",
+    "NG-OG" => "奥贡州",
+    "NG-ON" => "翁多州",
+    "NG-OS" => "奥孙州",
+    "NG-OY" => "奥约州",
+    "NG-PL" => "高原省",
+    "NG-RI" => "河流州",
+    "NG-SO" => "索科托州",
+    "NG-TA" => "塔拉巴州",
+    "NG-YO" => "约贝州",
+    "NG-ZA" => "扎姆法拉州",
+    "NO-03" => "奥斯陆",
+    "NO-11" => "罗加兰",
+    "NO-18" => "诺尔兰",
+    "OM-MU" => "穆桑达姆省",
+    "OM-WU" => "中部省",
+    "PK-IS" => "伊斯兰堡",
+    "PK-PB" => "旁遮普邦",
+    "PW-002" => "艾梅利克",
+    "PW-004" => "艾拉伊",
+    "PW-010" => "安加尔",
+    "PW-100" => "卡扬埃尔",
+    "PW-150" => "科罗尔",
+    "PW-212" => "梅莱凯奥克",
+    "PW-214" => "雅拉尔德",
+    "PW-218" => "雅切隆",
+    "PW-222" => "雅德马乌",
+    "PW-224" => "雅庞",
+    "PW-226" => "恩切萨尔",
+    "PW-227" => "埃雷姆伦维",
+    "PW-228" => "宜瓦尔",
+    "PW-350" => "贝里琉",
+    "PW-370" => "松索罗尔",
+    "PS-GZA" => "加扎省",
+    "PA-1" => "博卡斯·德尔托罗省",
+    "PA-2" => "科克莱省",
+    "PA-3" => "科隆省",
+    "PA-4" => "奇里基省",
+    "PA-5" => "达连省",
+    "PA-6" => "埃雷拉省",
+    "PA-7" => "洛斯桑托斯省",
+    "PA-8" => "巴拿马省",
+    "PA-9" => "贝拉瓜斯省",
+    "PG-CPK" => "钦布省",
+    "PG-CPM" => "中部",
+    "PG-EBR" => "东新不列颠省",
+    "PG-EHG" => "东高地省",
+    "PG-EPW" => "恩加省",
+    "PG-ESW" => "东塞皮克省",
+    "PG-GPK" => "海湾省",
+    "PG-MBA" => "米尔恩湾省",
+    "PG-MPL" => "莫雷贝省",
+    "PG-MPM" => "马当省",
+    "PG-MRL" => "马努斯省",
+    "PG-NCD" => "国家首都区 (莫尔兹比港)",
+    "PG-NIK" => "新爱尔兰省",
+    "PG-NPP" => "北部区",
+    "PG-SHM" => "南高地省",
+    "PG-WBK" => "西新不列颠省",
+    "PG-WHM" => "西高地省",
+    "PG-WPD" => "西部区",
+    "PY-11" => "中部",
+    "PY-13" => "阿曼拜省",
+    "PY-15" => "阿耶斯总统省",
+    "PY-16" => "上巴拉圭省",
+    "PY-2" => "圣佩德罗省",
+    "PY-3" => "科迪勒拉省",
+    "PY-8" => "米西奥内斯省",
+    "PE-ANC" => "安卡什区域",
+    "PE-ARE" => "阿雷基帕区域",
+    "PE-AYA" => "阿亚库乔区域",
+    "PE-CAJ" => "卡哈马卡区域",
+    "PE-CAL" => "卡亚俄区",
+    "PE-HUV" => "万卡维利卡区域",
+    "PE-ICA" => "伊卡区域",
+    "PE-LAM" => "兰巴耶克区域",
+    "PE-LIM" => "利马区域",
+    "PE-LOR" => "洛雷托区域",
+    "PE-MDD" => "马德雷·德迪奥斯区域",
+    "PE-MOQ" => "莫克瓜区域",
+    "PE-PAS" => "帕斯科区域",
+    "PE-PIU" => "皮乌拉区域",
+    "PE-PUN" => "普诺区域",
+    "PE-TAC" => "塔克纳区域",
+    "PE-TUM" => "通贝斯区域",
+    "PE-UCA" => "乌卡亚利区域",
+    "PH-ABR" => "阿布拉",
+    "PH-AGN" => "北阿古桑",
+    "PH-AGS" => "南阿古桑",
+    "PH-AKL" => "阿克兰",
+    "PH-ALB" => "阿尔拜",
+    "PH-ANT" => "安蒂克",
+    "PH-APA" => "阿巴尧",
+    "PH-AUR" => "奥罗拉",
+    "PH-BAS" => "巴西兰",
+    "PH-BEN" => "本格特",
+    "PH-BIL" => "比利兰",
+    "PH-BOH" => "保和",
+    "PH-BTG" => "八打雁",
+    "PH-BTN" => "巴坦群岛",
+    "PH-BUK" => "布基农",
+    "PH-BUL" => "布拉干",
+    "PH-CAG" => "卡加延",
+    "PH-CAM" => "甘米银",
+    "PH-CAN" => "北甘马磷",
+    "PH-CAP" => "卡皮斯",
+    "PH-CAS" => "南甘马磷",
+    "PH-CAT" => "卡坦端内斯",
+    "PH-CAV" => "甲米地",
+    "PH-CEB" => "宿务",
+    "PH-DAO" => "东达沃",
+    "PH-DAS" => "南达沃",
+    "PH-EAS" => "东萨马",
+    "PH-GUI" => "吉马拉斯",
+    "PH-IFU" => "伊富高",
+    "PH-ILI" => "伊洛伊洛",
+    "PH-ILN" => "北伊罗戈",
+    "PH-ILS" => "南伊罗戈",
+    "PH-ISA" => "伊莎贝拉",
+    "PH-LAG" => "内湖",
+    "PH-LAN" => "北拉瑙",
+    "PH-LAS" => "南拉瑙",
+    "PH-LEY" => "莱特",
+    "PH-MAD" => "马林杜克",
+    "PH-MAS" => "马斯巴特",
+    "PH-MDC" => "西民都洛",
+    "PH-MDR" => "东民都洛",
+    "PH-MOU" => "高山省",
+    "PH-MSC" => "西米萨米斯",
+    "PH-MSR" => "东米萨米斯",
+    "PH-NER" => "东内格罗斯",
+    "PH-NSA" => "北萨马",
+    "PH-NUE" => "新怡诗夏",
+    "PH-NUV" => "新比斯开",
+    "PH-PAM" => "邦板牙",
+    "PH-PAN" => "班诗兰",
+    "PH-PLW" => "巴拉望",
+    "PH-QUE" => "奎松",
+    "PH-QUI" => "季里诺",
+    "PH-RIZ" => "黎刹",
+    "PH-ROM" => "朗布隆",
+    "PH-SAR" => "萨兰加尼",
+    "PH-SCO" => "南哥打巴托",
+    "PH-SIG" => "锡基霍尔",
+    "PH-SLE" => "南莱特",
+    "PH-SLU" => "苏禄",
+    "PH-SOR" => "索索贡",
+    "PH-SUK" => "苏丹库达拉",
+    "PH-SUN" => "北苏里高",
+    "PH-SUR" => "南苏里高",
+    "PH-TAR" => "打拉",
+    "PH-TAW" => "塔威塔威",
+    "PH-ZAN" => "北三宝颜",
+    "PH-ZAS" => "南三宝颜",
+    "PH-ZMB" => "三描礼士",
+    "PL-02" => "下西里西亚省",
+    "PL-04" => "库亚维-滨海省",
+    "PL-06" => "卢布林省",
+    "PL-08" => "卢布斯卡省",
+    "PL-10" => "罗兹省",
+    "PL-12" => "小波兰省",
+    "PL-14" => "马佐夫舍省",
+    "PL-16" => "奥波莱省",
+    "PL-18" => "喀尔巴阡山省",
+    "PL-20" => "波德拉谢省",
+    "PL-22" => "滨海省",
+    "PL-24" => "西里西亚省",
+    "PL-26" => "圣十字省",
+    "PL-28" => "瓦尔米亚-马祖里省",
+    "PL-30" => "大波兰省",
+    "PL-32" => "西滨海省",
+    "PT-01" => "亚威罗",
+    "PT-02" => "贝雅",
+    "PT-03" => "布拉加",
+    "PT-05" => "布朗库堡",
+    "PT-06" => "科英布拉",
+    "PT-08" => "法鲁",
+    "PT-09" => "瓜达",
+    "PT-10" => "莱里亚",
+    "PT-11" => "里斯本",
+    "PT-12" => "波塔莱格雷",
+    "PT-13" => "波尔图",
+    "PT-16" => "维亚纳堡",
+    "PT-17" => "雷阿尔城",
+    "PT-18" => "维塞乌",
+    "QA-WA" => "沃克拉",
+    "RO-AB" => "阿尔巴",
+    "RO-AR" => "阿拉德",
+    "RO-BH" => "比霍尔",
+    "RO-CJ" => "克鲁日",
+    "RO-CV" => "科瓦斯纳",
+    "RO-DJ" => "多尔日",
+    "RO-GJ" => "戈尔日",
+    "RO-GR" => "朱尔朱",
+    "RO-HD" => "洪尼多阿拉",
+    "RO-HR" => "哈尔吉塔",
+    "RO-IF" => "伊尔福夫",
+    "RO-OT" => "奥尔特",
+    "RO-PH" => "普拉霍瓦",
+    "RO-SB" => "锡比乌",
+    "RO-SM" => "萨图·马雷",
+    "RO-SV" => "苏恰瓦",
+    "RO-TL" => "图尔恰",
+    "RO-TR" => "特列奥尔曼",
+    "RO-VN" => "弗郎恰",
+    "RO-VS" => "瓦斯鲁伊",
+    "RU-CE" => "车臣共和国",
+    "RU-CHU" => "楚科奇自治区",
+    "RU-CU" => "楚瓦什共和国",
+    "RU-DA" => "达吉斯坦共和国",
+    "RU-KLU" => "卡卢加州",
+    "RU-KO" => "科米共和国",
+    "RU-MOW" => "莫斯科",
+    "RU-NIZ" => "下诺夫哥罗德州",
+    "RU-SPE" => "圣彼得堡",
+    "RU-TA" => "鞑靼斯坦共和国",
+    "RU-VOR" => "沃罗涅日州",
+    "RW-02" => "东部区",
+    "RW-03" => "北部区",
+    "RW-04" => "西部区",
+    "KN-K" => "圣科特司",
+    "KN-N" => "尼维斯",
+    "VC-01" => "沙洛",
+    "VC-02" => "圣安德鲁斯",
+    "VC-03" => "圣大卫",
+    "VC-04" => "圣乔治",
+    "VC-05" => "圣派屈克",
+    "VC-06" => "格瑞那丁",
+    "WS-AA" => "阿纳",
+    "WS-AL" => "艾加伊勒泰",
+    "WS-AT" => "阿图阿",
+    "WS-GE" => "加加埃毛加",
+    "WS-GI" => "加盖福毛加",
+    "WS-PA" => "帕劳利",
+    "WS-SA" => "萨图帕伊泰阿",
+    "WS-TU" => "图阿马萨加",
+    "WS-VF" => "瓦奥福诺蒂",
+    "WS-VS" => "韦西加诺",
+    "SM-06" => "博尔戈马焦雷",
+    "ST-P" => "普林西比",
+    "SN-DB" => "久尔贝勒",
+    "SN-DK" => "达喀尔",
+    "SN-FK" => "法蒂克",
+    "SN-KD" => "科尔达",
+    "SN-KL" => "考拉克",
+    "SN-LG" => "卢加",
+    "SN-MT" => "马塔姆",
+    "SN-SL" => "圣路易",
+    "SN-TC" => "坦巴昆达",
+    "SN-TH" => "捷斯",
+    "SN-ZG" => "济金绍尔",
+    "RS-00" => "贝尔格莱德",
+    "RS-KM" => "科索沃-梅托西亚",
+    "RS-VO" => "沃伊伏丁那",
+    "SL-E" => "东部区",
+    "SL-N" => "北部区",
+    "SL-W" => "弗里敦",
+    "SK-BC" => "班斯卡·比斯特理察州",
+    "SK-BL" => "布拉迪斯拉发州",
+    "SK-KI" => "科希策州",
+    "SK-NI" => "尼特拉州",
+    "SK-PV" => "普列索夫州",
+    "SK-TA" => "特尔纳瓦州",
+    "SK-TC" => "特伦钦州",
+    "SK-ZI" => "日利纳州",
+    "SI-001" => "阿伊多夫什契纳",
+    "SI-002" => "贝尔廷齐",
+    "SI-003" => "不莱德",
+    "SI-004" => "博希尼",
+    "SI-005" => "博罗夫尼察",
+    "SI-006" => "博韦茨",
+    "SI-007" => "布尔达",
+    "SI-008" => "布罗佐维察",
+    "SI-009" => "布雷日采",
+    "SI-010" => "蒂希纳",
+    "SI-011" => "采列",
+    "SI-012" => "戈雷尼斯卡地区采尔克列",
+    "SI-013" => "采尔克尼察",
+    "SI-014" => "采尔克诺",
+    "SI-015" => "奇伦绍夫奇",
+    "SI-016" => "科罗什卡地区奇尔纳",
+    "SI-017" => "奇尔诺梅利",
+    "SI-018" => "代斯特尔尼克-特尔诺夫斯克村",
+    "SI-019" => "迪瓦查",
+    "SI-020" => "多布雷波列",
+    "SI-021" => "多布罗瓦-霍尔尤尔-波尔霍夫格拉代茨",
+    "SI-022" => "卢布尔雅那附近多尔",
+    "SI-023" => "多姆扎莱",
+    "SI-024" => "多尔纳瓦",
+    "SI-025" => "德拉沃格勒",
+    "SI-026" => "杜普莱克",
+    "SI-027" => "戈雷尼亚村-波利亚内",
+    "SI-028" => "戈里什尼察",
+    "SI-029" => "上拉德戈纳",
+    "SI-030" => "戈尔尼格勒",
+    "SI-031" => "上彼得罗夫齐",
+    "SI-032" => "格罗苏普列",
+    "SI-033" => "沙洛夫齐",
+    "SI-034" => "赫拉斯特尼克",
+    "SI-035" => "赫尔佩列-科济纳",
+    "SI-036" => "伊德里亚",
+    "SI-037" => "伊格",
+    "SI-038" => "伊利尔斯卡比斯特里察",
+    "SI-039" => "伊万奇纳戈里察",
+    "SI-041" => "耶塞尼采",
+    "SI-042" => "尤尔欣齐",
+    "SI-043" => "卡姆尼克",
+    "SI-044" => "卡纳尔",
+    "SI-045" => "基德里切沃",
+    "SI-046" => "科巴里德",
+    "SI-047" => "科比列",
+    "SI-049" => "科门",
+    "SI-051" => "科济耶",
+    "SI-052" => "克拉尼",
+    "SI-053" => "克拉尼斯卡戈拉",
+    "SI-054" => "克尔什科",
+    "SI-055" => "昆戈塔",
+    "SI-056" => "库兹马",
+    "SI-057" => "拉什科",
+    "SI-058" => "来纳尔特",
+    "SI-060" => "利蒂亚",
+    "SI-061" => "卢布尔雅那",
+    "SI-062" => "柳布诺",
+    "SI-063" => "柳托梅尔",
+    "SI-064" => "洛加泰茨",
+    "SI-065" => "洛什卡多利纳",
+    "SI-066" => "洛什基波托克",
+    "SI-067" => "卢切",
+    "SI-068" => "卢科维察",
+    "SI-069" => "马伊什佩克",
+    "SI-070" => "马里博尔",
+    "SI-071" => "梅德沃代",
+    "SI-072" => "门盖什",
+    "SI-073" => "梅特利卡",
+    "SI-074" => "梅日察",
+    "SI-075" => "米伦-科斯塔涅维察",
+    "SI-076" => "米斯利尼亚",
+    "SI-077" => "摩拉夫切",
+    "SI-078" => "摩拉瓦-托普利采",
+    "SI-079" => "莫济列",
+    "SI-080" => "穆尔斯卡索博塔",
+    "SI-081" => "穆塔",
+    "SI-082" => "纳克洛",
+    "SI-083" => "纳扎列",
+    "SI-084" => "新戈里察",
+    "SI-108" => "鲁塞",
+    "SI-117" => "申丘尔",
+    "SI-118" => "申蒂利",
+    "SI-119" => "申特耶尔内伊",
+    "SI-121" => "什科茨杨",
+    "SI-122" => "什科菲亚洛卡",
+    "SI-123" => "什科夫利察",
+    "SI-124" => "耶尔沙赫附近什马列",
+    "SI-125" => "帕卡河畔什马尔特诺",
+    "SI-126" => "绍什塔尼",
+    "SI-127" => "什托雷",
+    "SI-128" => "托尔明",
+    "SI-129" => "特雷布涅",
+    "SI-130" => "特雷布涅",
+    "SI-131" => "特尔日奇",
+    "SI-132" => "图尔尼什切",
+    "SI-133" => "韦莱涅",
+    "SI-134" => "大拉什切",
+    "SI-135" => "维代姆",
+    "SI-136" => "维帕瓦",
+    "SI-137" => "维塔涅",
+    "SI-139" => "沃伊尼克",
+    "SI-140" => "弗尔赫尼卡",
+    "SI-141" => "武泽尼察",
+    "SI-142" => "萨瓦河畔扎列格",
+    "SI-143" => "扎夫尔奇",
+    "SI-144" => "兹雷切",
+    "SI-146" => "热莱兹尼基",
+    "SI-147" => "日里",
+    "SI-149" => "索特里河畔比什特里察",
+    "SI-150" => "不洛科",
+    "SI-151" => "布拉斯洛夫采",
+    "SI-152" => "灿科瓦-蒂希纳",
+    "SI-153" => "采尔克芬尼亚克",
+    "SI-154" => "多布耶",
+    "SI-155" => "多布尔纳",
+    "SI-157" => "多莱尼斯科托普利采",
+    "SI-158" => "格拉德",
+    "SI-159" => "哈伊蒂纳",
+    "SI-160" => "霍策-什里夫尼察",
+    "SI-163" => "耶则尔斯科",
+    "SI-164" => "科门达",
+    "SI-165" => "科斯特尔",
+    "SI-166" => "克里兹夫奇",
+    "SI-167" => "波霍里尤地区洛夫伦茨",
+    "SI-168" => "马里科夫奇",
+    "SI-169" => "德拉夫斯肯波尔尤地区米克拉夫兹",
+    "SI-170" => "米尔纳比茨",
+    "SI-183" => "赛姆比特-费尔托伊巴",
+    "SI-184" => "塔波尔",
+    "SI-186" => "特尔兹恩",
+    "SI-187" => "维里卡波兰纳",
+    "SI-188" => "维尔泽伊",
+    "SI-189" => "弗兰斯科",
+    "SI-190" => "扎列奇",
+    "SI-191" => "热塔莱",
+    "SI-192" => "兹罗夫尼察",
+    "SI-193" => "祖泽姆波克",
+    "SI-194" => "利蒂基附近什马尔特诺",
+    "SB-CE" => "中部",
+    "SB-CT" => "首都直辖区 (霍尼亚拉)",
+    "SB-GU" => "瓜达尔卡纳尔",
+    "SB-IS" => "伊萨贝尔",
+    "SB-ML" => "马莱塔",
+    "SB-RB" => "拉纳尔和贝罗纳",
+    "SB-TE" => "泰莫图",
+    "SB-WE" => "西部区",
+    "ZA-EC" => "东开普省",
+    "ZA-FS" => "奥兰治自由邦",
+    "ZA-GP" => "豪登省",
+    "ZA-KZN" => "夸祖鲁-纳塔尔省",
+    "ZA-LP" => "林波波省",
+    "ZA-MP" => "普马兰加省",
+    "ZA-NC" => "北开普省",
+    "ZA-WC" => "西开普省",
+    "ES-AB" => "阿尔瓦塞特",
+    "ES-AL" => "阿尔梅里亚",
+    "ES-AR" => "阿拉贡",
+    "ES-AS" => "阿斯图利亚斯省",
+    "ES-AV" => "阿维拉",
+    "ES-BA" => "巴达霍斯",
+    "ES-BU" => "布尔戈斯",
+    "ES-CA" => "加的斯",
+    "ES-CB" => "坎塔布利亚",
+    "ES-CC" => "卡塞雷斯",
+    "ES-CE" => "休达",
+    "ES-CM" => "卡斯蒂利亚－拉曼恰",
+    "ES-CN" => "加那利",
+    "ES-CO" => "科尔多瓦省",
+    "ES-CR" => "雷阿尔城",
+    "ES-CU" => "昆卡",
+    "ES-EX" => "埃斯特雷马杜拉",
+    "ES-GC" => "拉斯帕尔马斯",
+    "ES-GR" => "格拉纳达",
+    "ES-GU" => "瓜达拉哈拉",
+    "ES-H" => "韦尔瓦",
+    "ES-HU" => "韦斯卡",
+    "ES-J" => "哈恩",
+    "ES-LE" => "莱昂",
+    "ES-LO" => "拉里奥哈",
+    "ES-M" => "马德里",
+    "ES-MA" => "马拉加",
+    "ES-MD" => "马德里",
+    "ES-ML" => "梅利利亚",
+    "ES-MU" => "穆尔西亚",
+    "ES-O" => "阿斯图利亚斯",
+    "ES-P" => "帕伦西亚",
+    "ES-RI" => "拉里奥哈",
+    "ES-S" => "坎塔布利亚",
+    "ES-SA" => "萨拉曼卡",
+    "ES-SE" => "塞维利亚",
+    "ES-SG" => "塞哥维亚",
+    "ES-SO" => "索里亚",
+    "ES-TE" => "特鲁埃尔",
+    "ES-TF" => "圣克鲁斯-德特内里费",
+    "ES-VA" => "巴利亚多利德",
+    "ES-Z" => "萨拉戈萨",
+    "ES-ZA" => "萨莫拉",
+    "LK-12" => "加姆珀哈",
+    "LK-81" => "巴杜勒",
+    "LK-91" => "拉特纳普勒",
+    "SD-NO" => "北部区",
+    "SR-BR" => "布罗科蓬多",
+    "SR-CM" => "科默韦讷",
+    "SR-CR" => "科罗尼",
+    "SR-MA" => "马罗韦纳",
+    "SR-NI" => "尼克里",
+    "SR-PM" => "帕拉马里博区",
+    "SR-SA" => "萨拉马卡",
+    "SR-SI" => "西帕利维尼",
+    "SR-WA" => "瓦尼卡",
+    "CH-AG" => "阿尔高",
+    "CH-AI" => "内阿彭策尔",
+    "CH-AR" => "外阿彭策尔",
+    "CH-BE" => "伯尔尼",
+    "CH-BL" => "巴塞尔乡村",
+    "CH-BS" => "巴塞尔城市",
+    "CH-GL" => "格拉鲁斯",
+    "CH-JU" => "汝拉",
+    "CH-LU" => "卢塞恩",
+    "CH-NW" => "下瓦尔登",
+    "CH-OW" => "上瓦尔登",
+    "CH-SG" => "圣加仑",
+    "CH-SH" => "沙夫豪森",
+    "CH-SO" => "索洛图恩",
+    "CH-SZ" => "施维茨",
+    "CH-TG" => "图尔高",
+    "CH-TI" => "提契诺",
+    "CH-UR" => "乌里",
+    "CH-VD" => "沃",
+    "CH-VS" => "瓦莱",
+    "CH-ZG" => "楚格",
+    "SY-DI" => "大马士革市",
+    "SY-DY" => "代尔祖尔",
+    "SY-ID" => "伊德利卜",
+    "SY-RA" => "拉卡",
+    "TW-CHA" => "彰化县",
+    "TW-CYI" => "嘉义县",
+    "TW-CYQ" => "嘉义县",
+    "TW-HSQ" => "新竹县",
+    "TW-HSZ" => "新竹县",
+    "TW-HUA" => "花莲县",
+    "TW-KHH" => "高雄县",
+    "TW-MIA" => "苗栗县",
+    "TW-NAN" => "南投县",
+    "TW-PEN" => "澎湖县",
+    "TW-PIF" => "屏东县",
+    "TW-TAO" => "桃园县",
+    "TW-TNN" => "台南县",
+    "TW-TPE" => "台北县",
+    "TW-TTT" => "台东县",
+    "TW-TXG" => "台中县",
+    "TW-YUN" => "云林县",
+    "TJ-KT" => "哈特隆州",
+    "TJ-SU" => "索格特州",
+    "TZ-01" => "阿鲁沙",
+    "TZ-03" => "多多马",
+    "TZ-04" => "伊林加",
+    "TZ-05" => "卡盖拉",
+    "TZ-08" => "基戈马",
+    "TZ-09" => "乞力马扎罗",
+    "TZ-12" => "林迪",
+    "TZ-13" => "马腊",
+    "TZ-14" => "姆贝亚",
+    "TZ-16" => "莫罗戈罗",
+    "TZ-17" => "姆特瓦拉",
+    "TZ-18" => "姆万扎区",
+    "TZ-20" => "鲁夸",
+    "TZ-21" => "鲁伍马",
+    "TZ-22" => "希尼安加",
+    "TZ-23" => "辛吉达",
+    "TZ-24" => "塔波拉",
+    "TZ-25" => "坦噶",
+    "TZ-26" => "曼亚拉",
+    "TH-11" => "北榄府",
+    "TH-12" => "暖武里府",
+    "TH-13" => "巴吞他尼府",
+    "TH-14" => "大城府",
+    "TH-15" => "红统府",
+    "TH-16" => "华富里府",
+    "TH-17" => "信武里府",
+    "TH-18" => "猜纳府",
+    "TH-19" => "北标府",
+    "TH-20" => "春武里府",
+    "TH-21" => "罗勇府",
+    "TH-22" => "尖竹汶府",
+    "TH-23" => "桐艾府",
+    "TH-24" => "北柳府",
+    "TH-25" => "巴真府",
+    "TH-26" => "坤西育府",
+    "TH-27" => "沙缴府",
+    "TH-30" => "呵叻府",
+    "TH-31" => "武里喃府",
+    "TH-32" => "素辇府",
+    "TH-33" => "四色菊府",
+    "TH-34" => "乌汶府",
+    "TH-35" => "益梭通府",
+    "TH-36" => "猜也贲府",
+    "TH-37" => "安纳乍能府",
+    "TH-39" => "廊磨喃蒲府",
+    "TH-40" => "坤敬府",
+    "TH-41" => "莫肯府",
+    "TH-42" => "黎府",
+    "TH-43" => "廊开府",
+    "TH-44" => "吗哈沙拉堪府",
+    "TH-45" => "横逸府",
+    "TH-46" => "加拉信府",
+    "TH-47" => "色军府",
+    "TH-48" => "那空拍侬府",
+    "TH-49" => "莫拉限府",
+    "TH-50" => "清迈府",
+    "TH-51" => "南奔府",
+    "TH-52" => "南邦府",
+    "TH-53" => "程逸府",
+    "TH-54" => "帕府",
+    "TH-55" => "楠府",
+    "TH-56" => "拍天府",
+    "TH-57" => "清莱府",
+    "TH-58" => "湄宏顺府",
+    "TH-60" => "北榄坡府",
+    "TH-61" => "乌泰他尼府",
+    "TH-62" => "甘烹碧府",
+    "TH-63" => "来兴府",
+    "TH-64" => "素可泰",
+    "TH-65" => "彭世洛府",
+    "TH-66" => "披集府",
+    "TH-67" => "碧差汶府",
+    "TH-70" => "叻丕府",
+    "TH-71" => "北碧府",
+    "TH-72" => "素攀府",
+    "TH-73" => "佛统府",
+    "TH-74" => "龙仔厝府",
+    "TH-75" => "夜功府",
+    "TH-76" => "佛丕府",
+    "TH-77" => "班武里府",
+    "TH-80" => "洛坤府",
+    "TH-81" => "甲米府",
+    "TH-82" => "攀牙府",
+    "TH-83" => "普吉府",
+    "TH-84" => "素叻府",
+    "TH-85" => "拉廊府",
+    "TH-86" => "春蓬府",
+    "TH-90" => "宋卡府",
+    "TH-91" => "沙敦府",
+    "TH-92" => "董里府",
+    "TH-93" => "博他仑府",
+    "TH-94" => "北大年府",
+    "TH-95" => "惹拉府",
+    "TH-96" => "陶公府",
+    "TH-S" => "芭达亚",
+    "TL-AL" => "阿伊莱乌区",
+    "TL-AN" => "阿伊纳罗区",
+    "TL-BA" => "包考区",
+    "TL-BO" => "博博纳罗区",
+    "TL-CO" => "科瓦利马区",
+    "TL-ER" => "埃尔梅拉区",
+    "TL-MF" => "马努法伊区",
+    "TL-MT" => "马纳图托区",
+    "TO-01" => "埃瓦岛",
+    "TO-02" => "哈派群岛",
+    "TO-03" => "纽阿斯",
+    "TO-04" => "汤加塔布岛",
+    "TO-05" => "瓦瓦乌群岛",
+    "TT-ARI" => "阿里马",
+    "TT-CHA" => "查瓜纳斯",
+    "TT-CTT" => "库瓦-塔巴基特-塔尔帕罗",
+    "TT-DMN" => "迭哥马丁",
+    "TT-PED" => "皮纳尔-德贝",
+    "TT-POS" => "西班牙港市",
+    "TT-PRT" => "王子镇",
+    "TT-PTF" => "福廷岬",
+    "TT-SFO" => "圣费尔南多市",
+    "TT-SGE" => "大桑格雷",
+    "TT-SIP" => "锡帕里亚",
+    "TT-SJL" => "圣胡安-拉芬蒂勒",
+    "TT-TUP" => "图纳普纳-皮亚尔科",
+    "TN-11" => "突尼斯",
+    "TN-13" => "本阿鲁斯",
+    "TN-14" => "马努巴",
+    "TN-21" => "纳布勒",
+    "TN-22" => "宰格万",
+    "TN-23" => "比塞大",
+    "TN-31" => "巴杰",
+    "TN-32" => "坚杜拜",
+    "TN-33" => "卡夫",
+    "TN-34" => "锡勒亚奈",
+    "TN-41" => "凯鲁万",
+    "TN-42" => "卡塞林",
+    "TN-43" => "西迪布济德",
+    "TN-51" => "苏塞",
+    "TN-52" => "莫纳斯提尔",
+    "TN-53" => "马赫迪耶",
+    "TN-61" => "斯法克斯",
+    "TN-71" => "加夫萨",
+    "TN-72" => "托泽尔",
+    "TN-81" => "加贝斯",
+    "TN-83" => "泰塔温",
+    "TR-01" => "阿达纳",
+    "TR-02" => "阿德亚曼",
+    "TR-04" => "阿勒",
+    "TR-05" => "阿马西亚",
+    "TR-06" => "安卡拉",
+    "TR-07" => "安塔利亚",
+    "TR-08" => "阿尔特温",
+    "TR-09" => "艾登",
+    "TR-10" => "巴勒克埃西尔",
+    "TR-11" => "比莱吉克",
+    "TR-12" => "宾格尔",
+    "TR-13" => "比特利斯",
+    "TR-14" => "博卢",
+    "TR-15" => "布尔杜尔",
+    "TR-16" => "布尔萨",
+    "TR-17" => "恰纳卡莱",
+    "TR-18" => "昌克勒",
+    "TR-19" => "乔鲁姆",
+    "TR-20" => "代尼兹利",
+    "TR-21" => "迪亚巴克尔",
+    "TR-22" => "埃迪尔内",
+    "TR-23" => "埃拉泽省",
+    "TR-24" => "埃尔津詹",
+    "TR-25" => "埃尔祖鲁姆",
+    "TR-26" => "埃斯基谢希尔",
+    "TR-27" => "加济安泰普",
+    "TR-28" => "吉雷松",
+    "TR-29" => "居米什哈内",
+    "TR-30" => "哈卡里",
+    "TR-31" => "哈塔伊",
+    "TR-32" => "伊斯帕尔塔",
+    "TR-34" => "伊斯坦布尔",
+    "TR-35" => "伊兹密尔",
+    "TR-36" => "卡尔斯",
+    "TR-37" => "卡斯塔莫努",
+    "TR-38" => "开塞利",
+    "TR-39" => "柯克拉雷利",
+    "TR-40" => "克尔谢希尔",
+    "TR-41" => "科贾埃利",
+    "TR-42" => "科尼亚",
+    "TR-43" => "屈塔希亚",
+    "TR-44" => "马拉蒂亚",
+    "TR-45" => "马尼萨",
+    "TR-46" => "卡赫拉曼马拉什",
+    "TR-47" => "马尔丁",
+    "TR-48" => "穆拉",
+    "TR-49" => "穆什",
+    "TR-50" => "内夫谢希尔",
+    "TR-51" => "尼代",
+    "TR-52" => "奥尔杜",
+    "TR-53" => "里泽",
+    "TR-54" => "萨卡里亚",
+    "TR-55" => "萨姆松",
+    "TR-56" => "锡尔特",
+    "TR-57" => "锡诺普",
+    "TR-58" => "锡瓦斯",
+    "TR-59" => "泰基尔达",
+    "TR-60" => "托卡特",
+    "TR-61" => "特拉布宗",
+    "TR-62" => "通杰利",
+    "TR-63" => "尚利乌尔法",
+    "TR-64" => "乌萨克",
+    "TR-65" => "凡",
+    "TR-66" => "约兹加特",
+    "TR-67" => "宗古尔达克",
+    "TR-68" => "阿克萨赖",
+    "TR-69" => "巴伊布尔特",
+    "TR-70" => "卡拉曼",
+    "TR-71" => "克勒克卡莱",
+    "TR-72" => "巴特曼",
+    "TR-73" => "锡尔纳克",
+    "TR-74" => "巴尔腾",
+    "TR-75" => "阿尔达罕",
+    "TR-76" => "厄德尔",
+    "TR-77" => "亚罗法",
+    "TR-78" => "卡拉比克",
+    "TR-79" => "基利斯",
+    "TR-80" => "奥斯曼尼菲",
+    "TR-81" => "迪兹杰",
+    "TM-A" => "阿哈尔",
+    "TM-B" => "巴尔坎",
+    "TM-D" => "达沙古兹",
+    "TM-L" => "列巴普",
+    "TM-M" => "马雷",
+    "TV-FUN" => "富纳富提",
+    "TV-NIT" => "纽乌道乌",
+    "TV-NKF" => "努谷费陶",
+    "TV-NKL" => "努谷拉耶拉耶",
+    "TV-NMA" => "那努米亚",
+    "TV-NUI" => "努伊",
+    "TV-VAI" => "婓伊托波",
+    "UG-101" => "卡兰加拉",
+    "UG-102" => "坎帕拉",
+    "UG-103" => "基博加",
+    "UG-104" => "卢韦罗",
+    "UG-105" => "马萨卡",
+    "UG-106" => "姆皮吉",
+    "UG-107" => "穆本德",
+    "UG-108" => "穆科诺",
+    "UG-109" => "纳卡松戈拉",
+    "UG-110" => "拉卡伊",
+    "UG-111" => "森巴布莱",
+    "UG-112" => "卡永加",
+    "UG-113" => "瓦基索",
+    "UG-201" => "布吉里",
+    "UG-202" => "布西亚",
+    "UG-203" => "伊甘加",
+    "UG-204" => "金贾",
+    "UG-205" => "卡穆利",
+    "UG-206" => "卡普乔鲁瓦",
+    "UG-207" => "卡塔奎",
+    "UG-208" => "库米",
+    "UG-209" => "姆巴莱",
+    "UG-210" => "帕利萨",
+    "UG-211" => "索罗提",
+    "UG-212" => "托罗罗",
+    "UG-213" => "卡贝拉马伊多",
+    "UG-214" => "马尤盖",
+    "UG-215" => "锡龙科",
+    "UG-301" => "阿朱马尼",
+    "UG-302" => "阿帕克",
+    "UG-303" => "阿鲁阿",
+    "UG-304" => "古卢",
+    "UG-305" => "基特古姆",
+    "UG-306" => "科蒂多",
+    "UG-307" => "利拉",
+    "UG-308" => "莫罗托",
+    "UG-309" => "莫约",
+    "UG-310" => "内比",
+    "UG-311" => "纳卡皮里皮里特",
+    "UG-312" => "帕德尔",
+    "UG-313" => "永贝",
+    "UG-401" => "本迪布焦",
+    "UG-402" => "布谢尼",
+    "UG-403" => "霍伊马",
+    "UG-404" => "卡巴莱",
+    "UG-405" => "卡巴罗莱",
+    "UG-406" => "卡塞塞",
+    "UG-407" => "基巴莱",
+    "UG-408" => "基索罗",
+    "UG-409" => "马辛迪",
+    "UG-410" => "姆巴拉拉",
+    "UG-411" => "恩通加莫",
+    "UG-412" => "鲁昆吉里",
+    "UG-413" => "卡姆文盖",
+    "UG-414" => "卡农古",
+    "UG-415" => "基恩乔乔",
+    "UG-C" => "中部",
+    "UG-E" => "东部区",
+    "UG-N" => "北部区",
+    "UG-W" => "西部区",
+    "UA-40" => "塞瓦斯托波尔市",
+    "AE-AJ" => "阿吉曼",
+    "AE-AZ" => "阿布扎比",
+    "AE-DU" => "迪拜",
+    "AE-FU" => "富吉拉",
+    "AE-RK" => "拉斯海玛",
+    "AE-SH" => "夏尔迦",
+    "AE-UQ" => "欧姆古温",
+    "GB-ABD" => "阿伯丁郡",
+    "GB-ABE" => "阿伯丁市",
+    "GB-AGB" => "阿盖尔-比特",
+    "GB-ANS" => "安格斯",
+    "GB-BAS" => "巴思-东北萨默塞特",
+    "GB-BBD" => "布莱克本",
+    "GB-BDG" => "巴金-达根汉姆市",
+    "GB-BEN" => "布兰特市",
+    "GB-BEX" => "白克斯雷市",
+    "GB-BGW" => "布莱耐格温特",
+    "GB-BIR" => "伯明翰郡",
+    "GB-BKM" => "白金汉郡",
+    "GB-BNE" => "巴奈特市",
+    "GB-BNH" => "布赖顿-霍伍",
+    "GB-BNS" => "巴恩斯利郡",
+    "GB-BOL" => "博尔顿市",
+    "GB-BPL" => "布莱克浦",
+    "GB-BRC" => "布拉克内尔森林",
+    "GB-BRD" => "布拉德福德郡",
+    "GB-BRY" => "布劳姆雷市",
+    "GB-BST" => "布里斯托尔市",
+    "GB-BUR" => "贝里市",
+    "GB-CAM" => "剑桥郡",
+    "GB-CLD" => "卡里德戴尔郡",
+    "GB-CLK" => "克拉克曼南郡",
+    "GB-CMA" => "坎布里亚",
+    "GB-CMD" => "坎敦市",
+    "GB-CON" => "康沃尔",
+    "GB-COV" => "考文垂郡",
+    "GB-CRY" => "克劳顿市",
+    "GB-CWY" => "康威",
+    "GB-DAL" => "达灵顿",
+    "GB-DBY" => "德比郡",
+    "GB-DER" => "德比",
+    "GB-DEV" => "德文",
+    "GB-DGY" => "邓弗里斯盖洛韦",
+    "GB-DNC" => "唐克斯特郡",
+    "GB-DND" => "邓迪市",
+    "GB-DOR" => "多塞特",
+    "GB-DUD" => "达德利郡",
+    "GB-EAL" => "伊林市",
+    "GB-EAY" => "东艾尔郡",
+    "GB-EDH" => "爱丁堡市",
+    "GB-EDU" => "东邓巴顿郡",
+    "GB-ELN" => "东洛锡安",
+    "GB-ELS" => "埃利安-锡尔",
+    "GB-ENF" => "英菲尔德市",
+    "GB-ERW" => "东伦弗鲁郡",
+    "GB-ERY" => "约克郡东区",
+    "GB-ESS" => "埃塞克斯",
+    "GB-ESX" => "东萨塞克斯",
+    "GB-FAL" => "福尔柯克",
+    "GB-FIF" => "法夫",
+    "GB-GAT" => "盖茨黑德郡",
+    "GB-GLG" => "格拉斯哥市",
+    "GB-GLS" => "格洛斯特郡",
+    "GB-GRE" => "格林尼治市",
+    "GB-GWN" => "圭内斯",
+    "GB-HAL" => "哈尔顿",
+    "GB-HAM" => "汉普郡",
+    "GB-HAV" => "哈佛英市",
+    "GB-HCK" => "汉克尼市",
+    "GB-HIL" => "黑灵敦市",
+    "GB-HLD" => "苏格兰高地",
+    "GB-HMF" => "哈默斯密斯－佛尔汉姆市",
+    "GB-HNS" => "杭斯路市",
+    "GB-HPL" => "哈特尔浦",
+    "GB-HRT" => "赫里福德郡",
+    "GB-HRW" => "海伦市",
+    "GB-HRY" => "哈灵根市",
+    "GB-IOW" => "怀特岛",
+    "GB-ISL" => "艾斯灵顿市",
+    "GB-IVC" => "因弗克莱德",
+    "GB-KEC" => "坎星顿－切尔西市",
+    "GB-KEN" => "肯特",
+    "GB-KIR" => "柯克利斯郡",
+    "GB-KTT" => "泰晤士河畔金斯顿市",
+    "GB-KWL" => "诺斯雷市",
+    "GB-LAN" => "兰开夏",
+    "GB-LBH" => "兰贝斯市",
+    "GB-LCE" => "莱斯特",
+    "GB-LDS" => "利兹郡",
+    "GB-LEC" => "莱斯特郡",
+    "GB-LEW" => "路易斯汉市",
+    "GB-LIN" => "林肯郡",
+    "GB-LIV" => "利物浦",
+    "GB-LND" => "伦敦市",
+    "GB-LUT" => "卢顿",
+    "GB-MDB" => "米德尔斯堡",
+    "GB-MDW" => "梅德韦",
+    "GB-MIK" => "米尔顿凯恩斯",
+    "GB-MLN" => "中洛锡安",
+    "GB-MRT" => "莫顿市",
+    "GB-MRY" => "马里",
+    "GB-NEL" => "东北林肯郡",
+    "GB-NET" => "泰恩河畔纽卡斯尔郡",
+    "GB-NFK" => "诺福克",
+    "GB-NGM" => "诺丁汉",
+    "GB-NLK" => "北拉纳克郡",
+    "GB-NLN" => "北林肯郡",
+    "GB-NSM" => "北萨默塞特",
+    "GB-NTT" => "诺丁汉郡",
+    "GB-NTY" => "北泰恩河畔郡",
+    "GB-NWM" => "纽汉市",
+    "GB-NYK" => "北约克郡",
+    "GB-OLD" => "奥尔德姆市",
+    "GB-ORK" => "奥克尼群岛",
+    "GB-OXF" => "牛津郡",
+    "GB-PKN" => "珀斯-金罗斯",
+    "GB-PLY" => "普利茅斯",
+    "GB-POR" => "朴次茅斯",
+    "GB-POW" => "波伊斯",
+    "GB-PTE" => "彼得伯勒",
+    "GB-RCC" => "里德卡-克里夫兰",
+    "GB-RCH" => "罗奇代尔市",
+    "GB-RDB" => "红桥市",
+    "GB-RDG" => "雷丁",
+    "GB-RFW" => "伦弗鲁郡",
+    "GB-RIC" => "泰晤士河畔里士满市",
+    "GB-ROT" => "罗瑟勒姆郡",
+    "GB-RUT" => "拉特兰",
+    "GB-SAW" => "山德威尔郡",
+    "GB-SAY" => "南艾尔郡",
+    "GB-SFK" => "萨福克",
+    "GB-SGC" => "南格洛斯特郡",
+    "GB-SHF" => "设菲尔德",
+    "GB-SHN" => "圣海伦斯郡",
+    "GB-SHR" => "什罗普郡",
+    "GB-SKP" => "斯托克波特市",
+    "GB-SLF" => "塞尔福德市",
+    "GB-SLG" => "斯劳",
+    "GB-SLK" => "南拉纳克郡",
+    "GB-SND" => "森得兰郡",
+    "GB-SOL" => "索利哈尔郡",
+    "GB-SOM" => "萨默塞特",
+    "GB-SOS" => "绍森德",
+    "GB-SRY" => "萨里",
+    "GB-STE" => "特伦河畔斯托克",
+    "GB-STG" => "斯特灵",
+    "GB-STH" => "南安普敦",
+    "GB-STN" => "萨顿市",
+    "GB-STS" => "斯塔福德郡",
+    "GB-STT" => "蒂斯河畔斯托克",
+    "GB-STY" => "南泰恩河畔郡",
+    "GB-SWD" => "斯温顿",
+    "GB-SWK" => "南瓦可市",
+    "GB-TAM" => "泰姆赛德市",
+    "GB-TFW" => "特尔福德-里金",
+    "GB-THR" => "瑟罗克",
+    "GB-TOB" => "托贝郡",
+    "GB-TRF" => "特兰弗德市",
+    "GB-TWH" => "塔沃哈姆莱茨市",
+    "GB-WAR" => "沃里克郡",
+    "GB-WBK" => "西巴克夏",
+    "GB-WDU" => "西邓巴顿郡",
+    "GB-WFT" => "威尔詹福利斯特市",
+    "GB-WGN" => "威根市",
+    "GB-WKF" => "韦克菲尔德市",
+    "GB-WLL" => "沃尔索尔郡",
+    "GB-WLN" => "西洛锡安",
+    "GB-WLV" => "伍尔弗汉普顿郡",
+    "GB-WND" => "旺茨沃斯市",
+    "GB-WNM" => "温莎梅登黑德",
+    "GB-WOK" => "沃金厄姆",
+    "GB-WOR" => "伍斯特郡",
+    "GB-WRL" => "威瑞尔郡",
+    "GB-WRT" => "沃灵顿",
+    "GB-WSM" => "西明斯特市",
+    "GB-WSX" => "西萨塞克斯",
+    "GB-YOR" => "约克",
+    "GB-ZET" => "设得兰群岛",
+    "US-AK" => "阿拉斯加州",
+    "US-AL" => "亚拉巴马州",
+    "US-AR" => "阿肯色州",
+    "US-AS" => "美属萨摩亚",
+    "US-AZ" => "亚利桑那州",
+    "US-CA" => "加利福尼亚州",
+    "US-CO" => "科罗拉多州",
+    "US-CT" => "康涅狄格州",
+    "US-DC" => "华盛顿哥伦比亚特区",
+    "US-DE" => "特拉华州",
+    "US-FL" => "佛罗里达州",
+    "US-GA" => "佐治亚州",
+    "US-GU" => "关岛",
+    "US-HI" => "夏威夷州",
+    "US-IA" => "艾奥瓦州",
+    "US-ID" => "爱达荷州",
+    "US-IL" => "伊利诺伊州",
+    "US-IN" => "印第安纳州",
+    "US-KS" => "堪萨斯州",
+    "US-KY" => "肯塔基州",
+    "US-LA" => "路易斯安那州",
+    "US-MA" => "马萨诸塞州",
+    "US-ME" => "缅因州",
+    "US-MI" => "密歇根州",
+    "US-MN" => "明尼苏达州",
+    "US-MO" => "密苏里州",
+    "US-MP" => "北马里亚纳群岛",
+    "US-MS" => "密西西比州",
+    "US-MT" => "蒙塔纳",
+    "US-NC" => "北卡罗来纳州",
+    "US-ND" => "北达科他州",
+    "US-NE" => "内布拉斯加州",
+    "US-NH" => "新罕布什尔州",
+    "US-NJ" => "新泽西州",
+    "US-NM" => "新墨西哥州",
+    "US-NV" => "内华达州",
+    "US-NY" => "纽约州",
+    "US-OH" => "俄亥俄州",
+    "US-OK" => "俄克拉何马州",
+    "US-OR" => "俄勒冈州",
+    "US-PA" => "宾夕法尼亚州",
+    "US-PR" => "波多黎各",
+    "US-RI" => "罗德岛州",
+    "US-SC" => "南卡罗莱那州",
+    "US-SD" => "南达科他州",
+    "US-TN" => "田纳西州",
+    "US-TX" => "得克萨斯州",
+    "US-UM" => "美国本土外小岛屿",
+    "US-UT" => "犹他州",
+    "US-VA" => "弗吉尼亚州",
+    "US-VI" => "美属维尔京群岛",
+    "US-VT" => "佛蒙特州",
+    "US-WA" => "华盛顿州",
+    "US-WI" => "威斯康星州",
+    "US-WV" => "西维吉尼亚州",
+    "US-WY" => "怀俄明州",
+    "UM-67" => "约翰斯顿岛",
+    "UM-71" => "中途岛",
+    "UM-76" => "纳弗沙岛",
+    "UM-79" => "威克岛",
+    "UM-81" => "贝克岛",
+    "UM-84" => "豪兰岛",
+    "UM-86" => "贾维斯岛",
+    "UM-89" => "金曼礁",
+    "UM-95" => "巴尔米拉环礁",
+    "UY-AR" => "阿蒂加斯",
+    "UY-CA" => "卡内洛内斯",
+    "UY-CL" => "塞罗拉尔戈",
+    "UY-CO" => "科洛尼亚",
+    "UY-DU" => "杜拉斯诺",
+    "UY-FD" => "佛罗里达州",
+    "UY-FS" => "弗洛雷斯",
+    "UY-LA" => "拉瓦列哈",
+    "UY-MA" => "马尔多纳多",
+    "UY-MO" => "蒙特维多",
+    "UY-RO" => "罗恰",
+    "UY-RV" => "里维拉",
+    "UY-SA" => "萨尔托",
+    "UY-SO" => "索里亚诺",
+    "UY-TT" => "特雷因塔伊特雷斯",
+    "UZ-AN" => "安集延",
+    "UZ-BU" => "布哈拉",
+    "UZ-JI" => "吉扎克",
+    "UZ-NG" => "纳曼干",
+    "UZ-NW" => "纳沃伊",
+    "UZ-QA" => "卡什卡达里亚",
+    "UZ-SA" => "撒马尔罕",
+    "UZ-SI" => "锡尔河",
+    "UZ-SU" => "苏尔汉河",
+    "UZ-TK" => "塔什干",
+    "UZ-TO" => "塔什干",
+    "UZ-XO" => "花拉子模",
+    "VU-MAP" => "马朗帕",
+    "VU-SAM" => "桑马",
+    "VU-TOB" => "托尔巴",
+    "VE-B" => "安索阿特吉州",
+    "VE-C" => "阿普雷州",
+    "VE-D" => "阿拉瓜州",
+    "VE-E" => "巴里纳斯州",
+    "VE-F" => "玻利瓦尔省",
+    "VE-G" => "卡拉沃沃州",
+    "VE-H" => "科赫德斯州",
+    "VE-I" => "法尔孔州",
+    "VE-J" => "瓜里科州",
+    "VE-K" => "拉腊州",
+    "VE-L" => "梅里达州",
+    "VE-M" => "米兰达州",
+    "VE-N" => "莫纳加斯州",
+    "VE-O" => "新埃斯帕塔州",
+    "VE-P" => "波图格萨州",
+    "VE-R" => "苏克雷",
+    "VE-S" => "塔奇拉州",
+    "VE-T" => "特鲁希略州",
+    "VE-U" => "亚拉奎州",
+    "VE-V" => "苏利亚州",
+    "VE-W" => "联邦属地",
+    "VE-Y" => "阿马库罗三角洲",
+    "VE-Z" => "亚马孙州",
+    "VN-01" => "莱州省",
+    "VN-02" => "老街省",
+    "VN-03" => "河江省",
+    "VN-04" => "高平省",
+    "VN-05" => "山罗省",
+    "VN-06" => "安沛省",
+    "VN-07" => "宣光省",
+    "VN-09" => "谅山省",
+    "VN-13" => "广宁省",
+    "VN-18" => "宁平省",
+    "VN-20" => "太平省",
+    "VN-21" => "清化省",
+    "VN-22" => "义安省",
+    "VN-24" => "广平省",
+    "VN-25" => "广治省",
+    "VN-26" => "承天顺化省",
+    "VN-27" => "广南省",
+    "VN-28" => "昆嵩省",
+    "VN-29" => "广义省",
+    "VN-30" => "嘉莱省",
+    "VN-31" => "平定省",
+    "VN-32" => "富安省",
+    "VN-34" => "庆和省",
+    "VN-35" => "林同省",
+    "VN-36" => "宁顺省",
+    "VN-37" => "西宁省",
+    "VN-39" => "同奈省",
+    "VN-40" => "平顺省",
+    "VN-41" => "隆安省",
+    "VN-44" => "安江省",
+    "VN-45" => "同塔省",
+    "VN-46" => "前江省",
+    "VN-49" => "永隆省",
+    "VN-50" => "槟知省",
+    "VN-51" => "茶荣省",
+    "VN-52" => "朔庄省",
+    "VN-53" => "北干省",
+    "VN-54" => "北江省",
+    "VN-55" => "薄辽省",
+    "VN-56" => "北宁省",
+    "VN-57" => "平阳省",
+    "VN-58" => "平福省",
+    "VN-59" => "金瓯省",
+    "VN-63" => "河南省",
+    "VN-66" => "兴安省",
+    "VN-67" => "南定省",
+    "VN-68" => "富寿省",
+    "VN-69" => "太原省",
+    "VN-70" => "永富省",
+    "VN-71" => "奠边省",
+    "VN-72" => "得农省",
+    "VN-73" => "后江省",
+    "VN-CT" => "芹苴市",
+    "YE-IB" => "伊卜",
+    "YE-MR" => "马哈拉",
+    "YE-SH" => "夏卜瓦",
+    "ZM-01" => "西部区",
+    "ZM-02" => "中部",
+    "ZM-03" => "东部区",
+    "ZM-04" => "卢阿普拉省",
+    "ZM-05" => "北部区",
+    "ZM-06" => "西北省",
+    "ZM-08" => "铜带省",
+    "ZM-09" => "卢萨卡省",
+    "ZW-BU" => "布拉瓦约",
+    "ZW-HA" => "哈拉雷",
+    "ZW-MA" => "马尼卡兰",
+    "ZW-MC" => "中马绍纳兰",
+    "ZW-ME" => "东马绍纳兰",
+    "ZW-MI" => "中部",
+    "ZW-MN" => "北马塔贝莱兰",
+    "ZW-MS" => "南马塔贝莱兰",
+    "ZW-MV" => "马斯温戈",
+    "ZW-MW" => "西马绍纳兰",
+};
+
+///Simplified Chinese former country names by alpha-4 code
+pub const FORMER_NAME_MAP: Map<&str, &str> = phf_map! {
+    "BQAQ" => "英属南极洲领地",
+    "BUMM" => "缅甸联邦社会主义共和国",
+    "BYAA" => "白俄罗斯苏维埃社会主义共和国",
+    "CTKI" => "坎顿和恩德贝里岛",
+    "CSHH" => "捷克斯洛伐克社会主义共和国",
+    "DYBJ" => "达荷美",
+    "NQAQ" => "毛德皇后地",
+    "TPTL" => "东帝汶",
+    "FXFR" => "法国的欧洲部分",
+    "AIDJ" => "阿法斯和埃萨斯",
+    "FQHH" => "法属南半球及南极领地",
+    "DDDE" => "德意志民主共和国",
+    "GEHH" => "吉尔柏特及埃里斯群岛",
+    "JTUM" => "约翰斯顿岛",
+    "MIUM" => "中途岛",
+    "ANHH" => "荷属安德列斯岛",
+    "NTHH" => "中立区",
+    "NHVU" => "新赫布里底群岛",
+    "PCHH" => "太平洋诸岛 (托管地)",
+    "PZPA" => "巴拿马运河区",
+    "CSXX" => "塞尔维亚和黑山",
+    "SKIN" => "锡金",
+    "RHZW" => "南罗德西亚",
+    "PUUM" => "美属太平洋诸岛",
+    "HVBF" => "上沃尔特共和国",
+    "SUHH" => "苏维埃社会主义共和国联盟",
+    "VDVN" => "越南民主共和国",
+    "WKUM" => "威克岛",
+    "YDYE" => "也门民主人民共和国",
+    "YUCS" => "南斯拉夫社会主义联邦共和国",
+    "ZRCD" => "扎伊尔共和国",
+};
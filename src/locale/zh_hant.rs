@@ -0,0 +1,745 @@
+use phf::phf_map;
+use phf::Map;
+
+///Traditional Chinese country names by alpha-2 code
+pub const COUNTRY_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AF" => "阿富汗",
+    "AX" => "奧蘭群島",
+    "AL" => "阿爾巴尼亞",
+    "DZ" => "阿爾及利亞",
+    "AS" => "美屬薩摩亞",
+    "AD" => "安道爾",
+    "AO" => "安哥拉",
+    "AI" => "安圭拉",
+    "AQ" => "南極洲",
+    "AG" => "安地卡及巴布達",
+    "AR" => "阿根廷",
+    "AM" => "亞美尼亞",
+    "AW" => "阿路巴",
+    "AU" => "澳大利亞",
+    "AT" => "奧地利",
+    "AZ" => "亞塞拜然",
+    "BS" => "巴哈馬",
+    "BH" => "巴林",
+    "BD" => "孟加拉",
+    "BB" => "巴貝多",
+    "BY" => "白俄羅斯",
+    "BE" => "比利時",
+    "BZ" => "貝里斯",
+    "BJ" => "貝南",
+    "BM" => "百慕達",
+    "BT" => "不丹",
+    "BO" => "玻利維亞多民族國",
+    "BQ" => "波內赫、聖尤斯特歇斯及薩巴",
+    "BA" => "波士尼亞及赫塞哥維納",
+    "BW" => "波札那",
+    "BV" => "布威島",
+    "BR" => "巴西",
+    "IO" => "英屬印度洋領地",
+    "BN" => "汶萊",
+    "BG" => "保加利亞",
+    "BF" => "布吉納法索",
+    "BI" => "蒲隆地",
+    "CV" => "維德角",
+    "KH" => "柬埔寨",
+    "CM" => "喀麥隆",
+    "CA" => "加拿大",
+    "KY" => "開曼群島",
+    "CF" => "中非共和國",
+    "TD" => "查德",
+    "CL" => "智利",
+    "CN" => "中國",
+    "CX" => "聖誕島",
+    "CC" => "科科斯 (基林) 群島",
+    "CO" => "哥倫比亞",
+    "KM" => "葛摩",
+    "CG" => "剛果",
+    "CD" => "剛果民主共和國",
+    "CK" => "庫克群島",
+    "CR" => "哥斯大黎加",
+    "CI" => "象牙海岸",
+    "HR" => "克羅埃西亞",
+    "CU" => "古巴",
+    "CW" => "古拉索",
+    "CY" => "賽普勒斯",
+    "CZ" => "捷克",
+    "DK" => "丹麥",
+    "DJ" => "吉布地",
+    "DM" => "多米尼克",
+    "DO" => "多明尼加共和國",
+    "EC" => "厄瓜多",
+    "EG" => "埃及",
+    "SV" => "薩爾瓦多",
+    "GQ" => "赤道幾內亞",
+    "ER" => "厄利垂亞",
+    "EE" => "愛沙尼亞",
+    "SZ" => "史瓦帝尼",
+    "ET" => "衣索比亞",
+    "FK" => "福克蘭群島 (馬維娜斯)",
+    "FO" => "法羅群島",
+    "FJ" => "斐濟",
+    "FI" => "芬蘭",
+    "FR" => "法國",
+    "GF" => "法屬蓋亞那",
+    "PF" => "法屬玻里尼西亞",
+    "TF" => "法屬南部領地",
+    "GA" => "加彭",
+    "GM" => "甘比亞",
+    "GE" => "喬治亞",
+    "DE" => "德國",
+    "GH" => "迦納",
+    "GI" => "直布羅陀",
+    "GR" => "希臘",
+    "GL" => "格陵蘭",
+    "GD" => "格瑞那達",
+    "GP" => "瓜地洛普",
+    "GU" => "關島",
+    "GT" => "瓜地馬拉",
+    "GG" => "根息島",
+    "GN" => "幾內亞",
+    "GW" => "幾內亞比索",
+    "GY" => "蓋亞那",
+    "HT" => "海地",
+    "HM" => "赫德島及麥當勞群島",
+    "VA" => "教廷 (梵蒂岡城市國)",
+    "HN" => "宏都拉斯",
+    "HK" => "香港",
+    "HU" => "匈牙利",
+    "IS" => "冰島",
+    "IN" => "印度",
+    "ID" => "印度尼西亞",
+    "IR" => "伊朗伊斯蘭共和國",
+    "IQ" => "伊拉克",
+    "IE" => "愛爾蘭",
+    "IM" => "曼島",
+    "IL" => "以色列",
+    "IT" => "義大利",
+    "JM" => "牙買加",
+    "JP" => "日本",
+    "JE" => "澤西島",
+    "JO" => "約旦",
+    "KZ" => "哈薩克",
+    "KE" => "肯亞",
+    "KI" => "吉里巴斯",
+    "KP" => "朝鮮民主主義人民共和國",
+    "KR" => "大韓民國",
+    "KW" => "科威特",
+    "KG" => "吉爾吉斯",
+    "LA" => "寮人民民主共和國",
+    "LV" => "拉脫維亞",
+    "LB" => "黎巴嫩",
+    "LS" => "賴索托",
+    "LR" => "賴比瑞亞",
+    "LY" => "利比亞",
+    "LI" => "列支敦斯登",
+    "LT" => "立陶宛",
+    "LU" => "盧森堡",
+    "MO" => "澳門",
+    "MG" => "馬達加斯加",
+    "MW" => "馬拉威",
+    "MY" => "馬來西亞",
+    "MV" => "馬爾地夫",
+    "ML" => "馬利",
+    "MT" => "馬爾他",
+    "MH" => "馬紹爾群島",
+    "MQ" => "馬丁尼克",
+    "MR" => "茅利塔尼亞",
+    "MU" => "模里西斯",
+    "YT" => "馬約特",
+    "MX" => "墨西哥",
+    "FM" => "密克羅尼西亞聯邦",
+    "MD" => "摩爾多瓦共和國",
+    "MC" => "摩納哥",
+    "MN" => "蒙古",
+    "ME" => "蒙特內哥羅",
+    "MS" => "蒙塞拉特島",
+    "MA" => "摩洛哥",
+    "MZ" => "莫三比克",
+    "MM" => "緬甸",
+    "NA" => "納米比亞",
+    "NR" => "諾魯",
+    "NP" => "尼泊爾",
+    "NL" => "荷蘭",
+    "NC" => "新喀里多尼亞",
+    "NZ" => "紐西蘭",
+    "NI" => "尼加拉瓜",
+    "NE" => "尼日",
+    "NG" => "奈及利亞",
+    "NU" => "紐埃",
+    "NF" => "諾福克島",
+    "MK" => "北馬其頓",
+    "MP" => "北馬里亞納群島",
+    "NO" => "挪威",
+    "OM" => "阿曼",
+    "PK" => "巴基斯坦",
+    "PW" => "帛琉",
+    "PS" => "巴勒斯坦",
+    "PA" => "巴拿馬",
+    "PG" => "巴布亞紐幾內亞",
+    "PY" => "巴拉圭",
+    "PE" => "祕魯",
+    "PH" => "菲律賓",
+    "PN" => "皮特肯島",
+    "PL" => "波蘭",
+    "PT" => "葡萄牙",
+    "PR" => "波多黎各",
+    "QA" => "卡達",
+    "RE" => "留尼旺島",
+    "RO" => "羅馬尼亞",
+    "RU" => "俄羅斯聯邦",
+    "RW" => "盧安達",
+    "BL" => "聖巴瑟米",
+    "SH" => "聖赫倫那島、阿森松島及崔斯坦達庫尼亞群島",
+    "KN" => "聖克里斯多福及尼維斯",
+    "LC" => "聖露西亞",
+    "MF" => "聖馬丁 (法屬)",
+    "PM" => "聖皮耶及密克隆群島",
+    "VC" => "聖文森及格瑞納丁",
+    "WS" => "薩摩亞",
+    "SM" => "聖馬利諾",
+    "ST" => "聖多美及普林西比",
+    "SA" => "沙烏地阿拉伯",
+    "SN" => "塞內加爾",
+    "RS" => "塞爾維亞",
+    "SC" => "塞席爾",
+    "SL" => "獅子山",
+    "SG" => "新加坡",
+    "SX" => "聖馬丁 (荷屬)",
+    "SK" => "斯洛伐克",
+    "SI" => "斯洛維尼亞",
+    "SB" => "索羅門群島",
+    "SO" => "索馬利亞",
+    "ZA" => "南非",
+    "GS" => "南喬治亞及南三明治群島",
+    "SS" => "南蘇丹",
+    "ES" => "西班牙",
+    "LK" => "斯里蘭卡",
+    "SD" => "蘇丹",
+    "SR" => "蘇利南",
+    "SJ" => "冷岸群島及央棉",
+    "SE" => "瑞典",
+    "CH" => "瑞士",
+    "SY" => "敘利亞阿拉伯共和國",
+    "TW" => "中華民國",
+    "TJ" => "塔吉克",
+    "TZ" => "坦尚尼亞聯合共和國",
+    "TH" => "泰國",
+    "TL" => "東帝汶",
+    "TG" => "多哥",
+    "TK" => "托克勞",
+    "TO" => "東加",
+    "TT" => "千里達及托巴哥",
+    "TN" => "突尼西亞",
+    "TR" => "土耳其",
+    "TM" => "土庫曼",
+    "TC" => "土克凱可群島",
+    "TV" => "吐瓦魯",
+    "UG" => "烏干達",
+    "UA" => "烏克蘭",
+    "AE" => "阿拉伯聯合大公國",
+    "GB" => "英國",
+    "US" => "美國",
+    "UM" => "美屬邊疆群島",
+    "UY" => "烏拉圭",
+    "UZ" => "烏茲別克",
+    "VU" => "萬那杜",
+    "VE" => "委內瑞拉玻利瓦爾共和國",
+    "VN" => "越南",
+    "VG" => "英屬維京群島",
+    "VI" => "美屬維京群島",
+    "WF" => "沃里斯及伏塔那群島",
+    "EH" => "西撒哈拉",
+    "YE" => "葉門",
+    "ZM" => "尚比亞",
+    "ZW" => "辛巴威",
+};
+
+///Traditional Chinese subdivision names by ISO 3166-2 code, 451 of 5046
+pub const SUBDIVISION_NAME_MAP: Map<&str, &str> = phf_map! {
+    "AL-03" => "愛爾巴桑",
+    "DZ-01" => "阿德拉",
+    "DZ-02" => "謝萊夫",
+    "DZ-03" => "拉格瓦特",
+    "DZ-05" => "巴特納",
+    "DZ-07" => "比斯克拉",
+    "DZ-13" => "特蘭森",
+    "DZ-14" => "提亞",
+    "DZ-23" => "亞拿巴",
+    "DZ-25" => "君士坦丁",
+    "DZ-29" => "馬斯卡拉",
+    "DZ-30" => "瓦格拉",
+    "DZ-31" => "奧倫",
+    "DZ-33" => "伊利吉",
+    "DZ-37" => "廷杜夫",
+    "AD-02" => "卡尼略",
+    "AD-03" => "恩坎普",
+    "AD-04" => "馬薩納語",
+    "AD-05" => "奧爾迪諾",
+    "AD-06" => "聖胡利婭-德洛里亞",
+    "AD-07" => "安道爾城",
+    "AG-04" => "聖約翰",
+    "AG-06" => "聖保羅",
+    "AR-A" => "沙爾塔",
+    "AR-B" => "布宜諾賽利斯",
+    "AR-J" => "聖胡安",
+    "AR-M" => "門多薩",
+    "AR-P" => "福摩沙",
+    "AR-S" => "聖塔非",
+    "AR-W" => "科林特斯",
+    "AR-Z" => "聖克魯茲",
+    "AU-ACT" => "澳洲首都領地",
+    "AU-NSW" => "新南威爾士",
+    "AU-NT" => "北領土",
+    "AU-QLD" => "昆士蘭",
+    "AU-SA" => "南澳大利亞",
+    "AU-TAS" => "塔斯馬尼亞",
+    "AU-WA" => "西澳大利亞",
+    "AT-5" => "薩爾堡",
+    "BS-SS" => "聖薩爾瓦多",
+    "BB-05" => "聖約翰",
+    "BB-06" => "聖約瑟",
+    "BE-VLI" => "林堡",
+    "BE-WHT" => "海諾",
+    "BE-WLX" => "盧森堡",
+    "BE-WNA" => "南木爾",
+    "BZ-BZ" => "貝里斯",
+    "BZ-TOL" => "托利多",
+    "BO-C" => "科洽班巴",
+    "BO-O" => "奧魯洛",
+    "BO-P" => "波多西",
+    "BO-S" => "聖克魯茲",
+    "BO-T" => "塔利哈",
+    "BW-GH" => "干西",
+    "BR-AL" => "阿拉哥亞斯",
+    "BR-AM" => "亞馬孫納斯",
+    "BR-AP" => "阿馬帕",
+    "BR-CE" => "西阿拉",
+    "BR-ES" => "聖林城",
+    "BR-GO" => "哥亞斯",
+    "BR-MA" => "馬蘭約",
+    "BR-MG" => "美納斯",
+    "BR-PB" => "帕拉伊巴",
+    "BR-PI" => "皮奧伊",
+    "BR-PR" => "巴拉那",
+    "BR-RO" => "隆多尼亞",
+    "BR-SC" => "聖卡塔琳娜",
+    "BR-TO" => "托坎廷斯",
+    "BG-02" => "布爾加斯",
+    "BG-03" => "瓦納",
+    "BG-12" => "蒙大拿",
+    "BG-16" => "普洛第夫",
+    "BG-23" => "索非亞",
+    "CV-BV" => "保維斯塔",
+    "CV-CA" => "聖卡塔琳娜",
+    "CV-CR" => "聖克魯茲",
+    "KH-12" => "金邊",
+    "CA-AB" => "亞伯達",
+    "CA-BC" => "不列顛哥倫比亞",
+    "CA-MB" => "曼尼托巴",
+    "CA-NB" => "新布藍玆維",
+    "CA-NL" => "紐芬蘭與拉布拉多",
+    "CA-NS" => "新斯科細亞",
+    "CA-NT" => "西北領土",
+    "CA-NU" => "紐納武特",
+    "CA-PE" => "愛德華太子島",
+    "CA-SK" => "薩克其萬",
+    "CF-BGF" => "班基",
+    "CL-AN" => "安多法加斯大",
+    "CO-AMA" => "亞馬孫納斯",
+    "CO-BOL" => "玻利瓦",
+    "CO-MAG" => "馬達利納",
+    "CO-SAN" => "聖坦德",
+    "CO-SUC" => "蘇克雷",
+    "CG-BZV" => "布拉薩市",
+    "CD-KN" => "金夏沙",
+    "CR-A" => "阿拉惠拉",
+    "CR-SJ" => "聖約瑟",
+    "CU-04" => "馬坦薩斯",
+    "CU-06" => "西恩菲哥斯",
+    "CU-09" => "卡馬瓜",
+    "CU-11" => "歐爾根",
+    "CU-13" => "聖地牙哥古巴",
+    "CU-14" => "關塔那木",
+    "CZ-412" => "卡羅維瓦立",
+    "CZ-513" => "利柏雷治",
+    "DJ-DJ" => "吉布地",
+    "DM-05" => "聖約翰",
+    "DM-06" => "聖約瑟",
+    "DM-10" => "聖保羅",
+    "DO-04" => "巴拉奧納",
+    "DO-12" => "羅馬納",
+    "DO-18" => "普拉塔港",
+    "DO-22" => "聖胡安",
+    "EC-B" => "玻利瓦",
+    "SV-SA" => "聖安納",
+    "SV-SS" => "聖薩爾瓦多",
+    "SZ-MA" => "曼玆尼",
+    "FR-971" => "瓜地洛普",
+    "FR-972" => "馬丁尼克",
+    "FR-976" => "馬約特",
+    "GM-B" => "斑竹",
+    "DE-BB" => "勃蘭登堡",
+    "DE-BW" => "巴登坦柏",
+    "DE-SH" => "什列斯威荷斯坦",
+    "DE-SL" => "薩爾蘭",
+    "GD-04" => "聖約翰",
+    "GN-C" => "柯那克里",
+    "GN-ML" => "馬利",
+    "HN-CM" => "利馬雅瓜",
+    "HN-LE" => "倫皮拉",
+    "HN-SB" => "聖巴巴拉",
+    "HN-YO" => "約羅",
+    "HU-BU" => "布達佩斯",
+    "HU-DE" => "德布勒森",
+    "HU-KM" => "克赤克美",
+    "HU-PS" => "佩科斯",
+    "HU-SD" => "塞革德",
+    "HU-SK" => "索諾克",
+    "IN-DL" => "德里",
+    "IN-SK" => "錫金",
+    "IR-21" => "雅茲德",
+    "IE-CO" => "科克",
+    "IE-MO" => "梅歐",
+    "IT-BA" => "巴利",
+    "IT-BG" => "柏加摩",
+    "IT-BO" => "波隆納",
+    "IT-BR" => "布林底希",
+    "IT-BS" => "布雷沙",
+    "IT-BZ" => "波爾察諾",
+    "IT-CA" => "卡拉里",
+    "IT-CN" => "庫尼奧",
+    "IT-CT" => "卡塔尼亞",
+    "IT-FE" => "菲拉拉",
+    "IT-FR" => "夫羅西諾尼",
+    "IT-GR" => "格洛瑟托",
+    "IT-KR" => "克羅登",
+    "IT-LE" => "雷契",
+    "IT-LT" => "拉丁納",
+    "IT-ME" => "美西納",
+    "IT-PA" => "巴勒摩",
+    "IT-PC" => "皮辰札",
+    "IT-PE" => "佩斯卡拉",
+    "IT-PG" => "珀魯加",
+    "IT-PI" => "比薩",
+    "IT-PR" => "帕馬",
+    "IT-RI" => "里提",
+    "IT-RN" => "林米尼",
+    "IT-TP" => "特拉帕尼",
+    "IT-TS" => "的港",
+    "IT-TV" => "翠維索",
+    "IT-VR" => "維洛納",
+    "IT-VT" => "維特波",
+    "JP-02" => "青森",
+    "JP-05" => "秋田",
+    "JP-06" => "山形",
+    "JP-13" => "東京",
+    "JP-15" => "新潟",
+    "JP-16" => "富山",
+    "JP-21" => "岐阜",
+    "JP-27" => "大阪",
+    "JP-31" => "鳥取",
+    "JP-33" => "岡山",
+    "JP-34" => "廣島",
+    "JP-35" => "山口",
+    "JP-39" => "高知",
+    "JP-40" => "福岡",
+    "JP-41" => "佐賀",
+    "JP-42" => "長崎",
+    "JP-43" => "熊本",
+    "JP-44" => "大分",
+    "JP-45" => "宮崎",
+    "JP-46" => "鹿兒島",
+    "KI-G" => "吉伯特群島",
+    "KI-L" => "萊恩群島",
+    "KI-P" => "費尼克斯島",
+    "LV-LPX" => "利帕雅",
+    "LV-RIX" => "里加",
+    "LR-MY" => "馬利蘭",
+    "LI-11" => "瓦杜茲",
+    "LU-LU" => "盧森堡",
+    "MG-D" => "安齊拉納納",
+    "MG-M" => "馬哈贊加",
+    "MG-T" => "安塔那那利佛",
+    "MW-MW" => "木宛札",
+    "MY-04" => "麻六甲",
+    "MV-MLE" => "馬烈",
+    "MT-10" => "芳塔納",
+    "MT-25" => "魯卡",
+    "MT-49" => "聖約翰",
+    "MT-60" => "法勒他",
+    "MH-MAJ" => "馬久羅",
+    "MR-07" => "阿德拉",
+    "MU-PL" => "路易士港",
+    "MX-BCN" => "下加利福尼亞",
+    "MX-BCS" => "南下加利福尼亞",
+    "MX-CHP" => "嘉帕斯",
+    "MX-GRO" => "格瑞羅",
+    "MX-GUA" => "瓜納華托",
+    "MX-HID" => "希達哥",
+    "MX-JAL" => "哈利斯科",
+    "MX-MEX" => "墨西哥",
+    "MX-MOR" => "摩雷洛斯",
+    "MX-NAY" => "納雅里特",
+    "MX-NLE" => "新雷昂",
+    "MX-ROO" => "琴塔納羅",
+    "MX-SIN" => "西納洛亞",
+    "MX-TAB" => "塔巴斯科",
+    "MX-TAM" => "塔茅利帕",
+    "MX-TLA" => "特拉克斯卡拉",
+    "MX-YUC" => "猶加敦",
+    "ME-16" => "波多里察",
+    "ME-19" => "Tivat",
+    "MA-NAD" => "納多爾",
+    "MA-RAB" => "拉巴特",
+    "MZ-L" => "馬布多",
+    "MZ-MPM" => "馬布多",
+    "NL-CW" => "古拉索",
+    "NL-GR" => "格羅寧根",
+    "NL-LI" => "林堡",
+    "NZ-AUK" => "奧克蘭",
+    "NZ-WGN" => "威靈頓",
+    "NI-CI" => "奇南德加",
+    "NI-GR" => "格瑞那達",
+    "NI-JI" => "希諾提加",
+    "NI-MN" => "馬拿瓜",
+    "NI-RI" => "里瓦斯",
+    "NE-1" => "阿加岱",
+    "NE-7" => "津德爾",
+    "NE-8" => "尼阿美",
+    "NG-DE" => "德爾塔",
+    "NG-KD" => "卡杜納",
+    "NG-KN" => "卡諾",
+    "NG-NI" => "尼日",
+    "MK-310" => "奧克里德",
+    "NO-03" => "奧斯陸",
+    "PK-IS" => "伊斯蘭馬巴德",
+    "PW-150" => "科羅爾",
+    "PA-8" => "巴拿馬",
+    "PY-ASU" => "亞松森",
+    "PE-ARE" => "阿雷奇帕",
+    "PE-AYA" => "阿雅庫喬",
+    "PE-LOR" => "羅雷托",
+    "PE-PAS" => "帕斯科",
+    "PE-TAC" => "塔克納",
+    "PE-TUM" => "屯貝斯",
+    "PH-MAS" => "馬斯巴提",
+    "PT-02" => "貝惹",
+    "PT-05" => "加斯德盧布朗科",
+    "PT-08" => "法洛",
+    "PT-14" => "桑塔林",
+    "RO-AR" => "阿拉德",
+    "RO-BC" => "巴考",
+    "RO-SB" => "西比由",
+    "RO-SM" => "沙土馬勒",
+    "RO-SV" => "蘇恰瓦",
+    "RO-TL" => "土爾查",
+    "SN-DK" => "達卡",
+    "SN-SL" => "聖路易斯",
+    "SN-TC" => "坦巴孔達",
+    "SN-ZG" => "濟袞秀",
+    "SC-11" => "喀斯開",
+    "SC-19" => "普萊三斯",
+    "SI-061" => "盧布爾雅那",
+    "SI-070" => "馬里包",
+    "SO-BR" => "巴利",
+    "ES-AL" => "阿美里亞",
+    "ES-GR" => "格瑞那達",
+    "ES-GU" => "瓜達拉哈拉",
+    "ES-M" => "馬德里",
+    "ES-MA" => "馬拉加",
+    "ES-ML" => "美利拉",
+    "ES-SA" => "沙拉曼卡",
+    "ES-TO" => "托利多",
+    "ES-Z" => "札拉哥沙",
+    "SR-PM" => "巴拉馬利波",
+    "CH-BE" => "伯恩",
+    "CH-NE" => "納沙泰爾",
+    "CH-ZH" => "蘇黎世",
+    "SY-DY" => "德利索爾",
+    "TW-CHA" => "彰化",
+    "TW-CYI" => "嘉義",
+    "TW-CYQ" => "嘉義",
+    "TW-HSQ" => "新竹",
+    "TW-HSZ" => "新竹",
+    "TW-HUA" => "花蓮",
+    "TW-KHH" => "高雄",
+    "TW-MIA" => "苗栗",
+    "TW-NAN" => "南投",
+    "TW-PEN" => "澎湖",
+    "TW-PIF" => "屏東",
+    "TW-TAO" => "桃園",
+    "TW-TNN" => "臺南",
+    "TW-TPE" => "臺北",
+    "TW-TTT" => "臺東",
+    "TW-TXG" => "臺中",
+    "TW-YUN" => "雲林",
+    "TZ-01" => "阿魯憂",
+    "TZ-03" => "杜篤瑪",
+    "TZ-04" => "伊林加",
+    "TZ-08" => "基哥馬",
+    "TZ-14" => "木貝雅",
+    "TZ-16" => "摩洛哥羅",
+    "TZ-17" => "木特瓦拉",
+    "TZ-18" => "木宛札",
+    "TZ-24" => "塔波拉",
+    "TH-20" => "瓊布里",
+    "TH-21" => "拉勇",
+    "TH-34" => "烏汶",
+    "TH-40" => "孔敬",
+    "TH-41" => "烏隆",
+    "TH-50" => "清邁",
+    "TH-52" => "南邦",
+    "TH-54" => "非拉",
+    "TH-55" => "難鎮",
+    "TH-58" => "湄宏順",
+    "TH-83" => "普吉島",
+    "TH-84" => "素叻他尼",
+    "TH-85" => "拉廊",
+    "TH-92" => "特朗",
+    "TN-11" => "突尼斯",
+    "TN-23" => "比塞大",
+    "TN-32" => "堅杜拜",
+    "TN-41" => "凱魯萬",
+    "TN-52" => "莫納斯提",
+    "TN-61" => "斯法克斯",
+    "TN-71" => "加夫沙",
+    "TN-72" => "托蘇爾",
+    "TR-01" => "亞達納",
+    "TR-06" => "安卡拉",
+    "TR-07" => "安塔利亞",
+    "TR-15" => "布爾都",
+    "TR-16" => "柏沙",
+    "TR-25" => "艾斯倫",
+    "TR-27" => "加吉安特",
+    "TR-36" => "卡斯",
+    "TR-38" => "開瑟里",
+    "TR-42" => "康雅",
+    "TR-44" => "馬拉特雅",
+    "TR-55" => "桑孫",
+    "TR-61" => "特拉比松",
+    "TR-65" => "凡城",
+    "TV-FUN" => "富納富提",
+    "UG-102" => "坎帕拉",
+    "UG-303" => "阿魯亞",
+    "UG-404" => "卡巴雷",
+    "AE-FU" => "富吉拉",
+    "GB-BPL" => "黑潭",
+    "GB-BRD" => "布拉福",
+    "GB-CMD" => "康登",
+    "GB-COV" => "科芬特里",
+    "GB-DAL" => "大令頓",
+    "GB-LDS" => "里玆",
+    "GB-LUT" => "盧頓",
+    "GB-MLN" => "密德羅申",
+    "GB-PTE" => "彼得波羅",
+    "GB-RDG" => "里丁",
+    "GB-RUT" => "魯特蘭",
+    "GB-SFK" => "蘇佛克",
+    "GB-SOM" => "索美列斯特",
+    "GB-STH" => "南安普敦",
+    "GB-WKF" => "威克菲",
+    "GB-WSM" => "威敏斯特",
+    "US-AK" => "阿拉斯加",
+    "US-AL" => "阿拉巴馬",
+    "US-AR" => "阿肯色",
+    "US-AS" => "美屬薩摩亞",
+    "US-AZ" => "亞利桑那",
+    "US-CA" => "加利福尼亞",
+    "US-CO" => "科羅拉多",
+    "US-CT" => "康乃狄格",
+    "US-DC" => "哥倫比亞特區",
+    "US-DE" => "德拉瓦語",
+    "US-FL" => "弗羅里達",
+    "US-GA" => "喬治亞",
+    "US-GU" => "關島",
+    "US-HI" => "夏威夷",
+    "US-IA" => "愛阿華",
+    "US-ID" => "愛達荷",
+    "US-IL" => "伊利諾",
+    "US-KS" => "堪薩斯",
+    "US-KY" => "肯塔基",
+    "US-LA" => "路易斯安那",
+    "US-MA" => "麻薩諸塞",
+    "US-MD" => "馬利蘭",
+    "US-ME" => "緬因",
+    "US-MI" => "密西根",
+    "US-MN" => "明尼蘇達",
+    "US-MO" => "密蘇里",
+    "US-MP" => "北馬里亞納群島",
+    "US-MS" => "密士失必",
+    "US-MT" => "蒙大拿",
+    "US-NC" => "北卡羅來納",
+    "US-ND" => "北達科他",
+    "US-NE" => "內布拉斯加",
+    "US-NH" => "新罕布夏",
+    "US-NJ" => "新澤西",
+    "US-NM" => "新墨西哥",
+    "US-NV" => "內華達",
+    "US-OH" => "俄亥俄",
+    "US-OK" => "俄克拉荷馬",
+    "US-OR" => "俄勒岡",
+    "US-PA" => "賓夕法尼亞",
+    "US-PR" => "波多黎各",
+    "US-RI" => "羅得島",
+    "US-SC" => "南卡羅來納",
+    "US-SD" => "南達科他",
+    "US-TN" => "田納西",
+    "US-TX" => "德克薩斯",
+    "US-UM" => "美屬邊疆群島",
+    "US-UT" => "猶他",
+    "US-VA" => "維吉尼亞",
+    "US-VT" => "佛蒙特",
+    "US-WI" => "威斯康辛",
+    "US-WV" => "西維吉尼亞",
+    "UM-71" => "中途群島",
+    "UM-79" => "威克島",
+    "UY-CO" => "科隆尼亞",
+    "UY-DU" => "杜拉茲諾",
+    "UY-FD" => "弗羅里達",
+    "UY-MA" => "馬多納多",
+    "UY-SJ" => "聖約瑟",
+    "UZ-SA" => "撒馬爾罕",
+    "VE-E" => "巴里納斯",
+    "VE-F" => "玻利瓦",
+    "VE-K" => "拉阿",
+    "VE-R" => "蘇克雷",
+    "VE-T" => "楚吉約",
+    "VE-Z" => "亞馬孫納斯",
+    "ZM-09" => "路沙卡",
+    "ZW-MI" => "密德蘭斯",
+};
+
+///Traditional Chinese former country names by alpha-4 code
+pub const FORMER_NAME_MAP: Map<&str, &str> = phf_map! {
+    "BQAQ" => "英屬南極洲領地",
+    "BUMM" => "緬甸聯邦社會主義共和國",
+    "BYAA" => "白俄羅斯蘇維埃社會主義共和國",
+    "CTKI" => "坎呑及恩得伯利群島",
+    "CSHH" => "捷克斯洛伐克社會主義共和國",
+    "DYBJ" => "達荷美",
+    "NQAQ" => "莫德皇后地",
+    "TPTL" => "東帝汶",
+    "FXFR" => "法國本土",
+    "AIDJ" => "法屬阿法爾及伊薩",
+    "FQHH" => "法屬南方及南極領地",
+    "DDDE" => "德意志民主共和國",
+    "GEHH" => "吉爾柏特及埃里斯群島",
+    "JTUM" => "約翰斯頓島",
+    "MIUM" => "中途群島",
+    "ANHH" => "荷屬安地列斯",
+    "NTHH" => "中立區",
+    "NHVU" => "新赫布里",
+    "PCHH" => "太平洋島嶼託管地",
+    "PZPA" => "巴拿馬運河區",
+    "CSXX" => "塞爾維亞及蒙特內哥羅",
+    "SKIN" => "錫金",
+    "RHZW" => "南羅得西亞",
+    "PUUM" => "美屬太平洋諸島",
+    "HVBF" => "上伏塔共和國",
+    "SUHH" => "蘇維埃社會主義共和國聯邦",
+    "VDVN" => "越南民主共和國",
+    "WKUM" => "威克島",
+    "YDYE" => "葉門民主人民共和國",
+    "YUCS" => "南斯拉夫社會主義聯邦共和國",
+    "ZRCD" => "薩伊共和國",
+};
@@ -0,0 +1,116 @@
+use crate::iso3166_2::{self, Subdivision};
+use crate::iso3166_3::{self, CountryCode3};
+use crate::{from_alpha2_ignore_case, from_alpha3_ignore_case, CountryCode};
+
+/// Any code defined by ISO 3166, as returned by `lookup`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Code {
+    ///ISO 3166-1 country
+    Country(CountryCode),
+    ///ISO 3166-2 subdivision
+    Subdivision(Subdivision),
+    ///ISO 3166-3 former country
+    Former(CountryCode3),
+}
+
+impl Code {
+    ///Return the canonical code: alpha-2, subdivision code or alpha-4
+    pub fn code(&self) -> &'static str {
+        match self {
+            Code::Country(c) => c.alpha2,
+            Code::Subdivision(s) => s.code,
+            Code::Former(f) => f.code,
+        }
+    }
+
+    ///Return the English name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Code::Country(c) => c.name,
+            Code::Subdivision(s) => s.name,
+            Code::Former(f) => f.name,
+        }
+    }
+}
+
+impl From<CountryCode> for Code {
+    fn from(country: CountryCode) -> Self {
+        Code::Country(country)
+    }
+}
+
+impl From<Subdivision> for Code {
+    fn from(subdivision: Subdivision) -> Self {
+        Code::Subdivision(subdivision)
+    }
+}
+
+impl From<CountryCode3> for Code {
+    fn from(former: CountryCode3) -> Self {
+        Code::Former(former)
+    }
+}
+
+/// Returns the best match for any ISO 3166 identifier, working out whether it
+/// is an alpha-2, alpha-3 or numeric country code, a subdivision code or a
+/// former country's alpha-4 code.
+///
+/// Lookups ignore case and surrounding whitespace like the `*_ignore_case`
+/// functions. When an input has several readings the first of `lookup_all`
+/// wins.
+/// #Sample
+/// ```
+/// use rust_iso3166::{lookup, Code};
+/// assert_eq!(Some(Code::Country(rust_iso3166::AU)), lookup("036"));
+/// assert_eq!("GB-EDH", lookup("gb-edh").unwrap().code());
+/// assert_eq!("Panama Canal Zone", lookup("PZPA").unwrap().name());
+/// ```
+pub fn lookup(code: &str) -> Option<Code> {
+    lookup_all(code).into_iter().next()
+}
+
+/// Returns every reading of an ISO 3166 identifier, best first.
+///
+/// The precedence is:
+/// 1. numeric country code (1 to 3 digits, `36` and `036` alike)
+/// 2. alpha-2 or alpha-3 country code
+/// 3. ISO 3166-3 alpha-4 code
+/// 4. ISO 3166-2 code, with `-`, `_` or a space as separator
+/// 5. ISO 3166-2 code with the separator left out, such as `GBENG`
+///
+/// So `EST` is Estonia first and the Spanish province `ES-T` second.
+/// #Sample
+/// ```
+/// let all = rust_iso3166::lookup_all("EST");
+/// assert_eq!(vec!["EE", "ES-T"], all.iter().map(|c| c.code()).collect::<Vec<_>>());
+/// ```
+pub fn lookup_all(code: &str) -> Vec<Code> {
+    let code = code.trim();
+    let mut found = Vec::new();
+    if code.is_empty() || !code.is_ascii() {
+        return found;
+    }
+    if code.len() <= 3 && code.bytes().all(|b| b.is_ascii_digit()) {
+        let country = code
+            .parse::<u16>()
+            .ok()
+            .and_then(|numeric| CountryCode::try_from(numeric).ok());
+        found.extend(country.map(Code::Country));
+        return found;
+    }
+    match code.len() {
+        2 => found.extend(from_alpha2_ignore_case(code).map(Code::Country)),
+        3 => found.extend(from_alpha3_ignore_case(code).map(Code::Country)),
+        4 => found.extend(iso3166_3::from_code_ignore_case(code).map(Code::Former)),
+        _ => {}
+    }
+    found.extend(iso3166_2::from_code_ignore_case(code).map(Code::Subdivision));
+    if (3..=5).contains(&code.len()) && code.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        let mut buf = [b'-'; 6];
+        buf[..2].copy_from_slice(&code.as_bytes()[..2]);
+        buf[3..code.len() + 1].copy_from_slice(&code.as_bytes()[2..]);
+        let hyphenated = std::str::from_utf8(&buf[..code.len() + 1]).expect("ASCII input");
+        found.extend(iso3166_2::from_code_ignore_case(hyphenated).map(Code::Subdivision));
+    }
+    found
+}
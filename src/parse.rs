@@ -0,0 +1,288 @@
+use crate::iso3166_2::{Subdivision, SUBDIVISION_MAP};
+use crate::iso3166_3::{self, CountryCode3, ALPHA4_MAP};
+use crate::{Country, CountryCode, ALPHA2_MAP, ALPHA3_MAP, NUMERIC_MAP};
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Reason a value could not be parsed into a `CountryCode`, `Subdivision` or
+/// `CountryCode3`.
+///
+/// Parsing checks the shape of the input first and only then looks it up, so
+/// `Unassigned` means the input is a well-formed code that ISO 3166 does not
+/// currently assign.
+/// #Sample
+/// ```
+/// use rust_iso3166::{CountryCode, ParseError};
+/// assert_eq!(Err(ParseError::Empty), "".parse::<CountryCode>());
+/// assert_eq!(Err(ParseError::InvalidLength(4)), "AUST".parse::<CountryCode>());
+/// assert_eq!(
+///     Err(ParseError::InvalidCharacter { index: 1, character: '@' }),
+///     "A@".parse::<CountryCode>()
+/// );
+/// assert_eq!(Err(ParseError::Unassigned), "XX".parse::<CountryCode>());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseError {
+    ///The input is empty
+    Empty,
+    ///The input has a length (in characters) no code of this kind can have
+    InvalidLength(usize),
+    ///The input contains a character that is not allowed at this position
+    InvalidCharacter {
+        ///Position of the character, counted in characters
+        index: usize,
+        ///The offending character
+        character: char,
+    },
+    ///The input is well-formed but not assigned
+    Unassigned,
+    ///The country prefix of an ISO 3166-2 code is not an assigned alpha-2 code
+    UnknownCountry,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "empty code"),
+            ParseError::InvalidLength(len) => write!(f, "invalid code length: {}", len),
+            ParseError::InvalidCharacter { index, character } => {
+                write!(f, "invalid character {:?} at index {}", character, index)
+            }
+            ParseError::Unassigned => write!(f, "code is not assigned"),
+            ParseError::UnknownCountry => write!(f, "unknown country code prefix"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Copies `s` into an ASCII upper-cased buffer, checking that its length is
+/// within `lengths` and every character passes `allowed`. Returns the buffer
+/// and the number of bytes used.
+pub(crate) fn to_upper<const N: usize>(
+    s: &str,
+    lengths: RangeInclusive<usize>,
+    allowed: impl Fn(usize, char) -> bool,
+) -> Result<([u8; N], usize), ParseError> {
+    let len = s.chars().count();
+    if len == 0 {
+        return Err(ParseError::Empty);
+    }
+    if !lengths.contains(&len) || len > N {
+        return Err(ParseError::InvalidLength(len));
+    }
+    let mut buf = [0u8; N];
+    for (index, character) in s.chars().enumerate() {
+        if !character.is_ascii() || !allowed(index, character) {
+            return Err(ParseError::InvalidCharacter { index, character });
+        }
+        buf[index] = character.to_ascii_uppercase() as u8;
+    }
+    Ok((buf, len))
+}
+
+/// Views the part of a buffer filled by `to_upper` as a `&str`.
+pub(crate) fn as_str(buf: &[u8]) -> &str {
+    std::str::from_utf8(buf).expect("buffer only holds ASCII")
+}
+
+/// Upper-cases ASCII `s` into a stack buffer of at most `N` bytes and passes
+/// it to `lookup`. Returns `None` without calling `lookup` if `s` is longer
+/// than `N` or not ASCII.
+#[cfg(feature = "serde")]
+pub(crate) fn with_upper<const N: usize, T>(
+    s: &str,
+    lookup: impl FnOnce(&str) -> Option<T>,
+) -> Option<T> {
+    fold::<N, T>(s, |b| b.to_ascii_uppercase(), lookup)
+}
+
+/// Like `with_upper`, but also trims surrounding whitespace and accepts `_`
+/// or a space in place of the ISO 3166-2 hyphen.
+pub(crate) fn with_lenient<const N: usize, T>(
+    s: &str,
+    lookup: impl FnOnce(&str) -> Option<T>,
+) -> Option<T> {
+    let map = |b: u8| match b {
+        b'_' | b' ' => b'-',
+        _ => b.to_ascii_uppercase(),
+    };
+    fold::<N, T>(s.trim(), map, lookup)
+}
+
+fn fold<const N: usize, T>(
+    s: &str,
+    map: impl Fn(u8) -> u8,
+    lookup: impl FnOnce(&str) -> Option<T>,
+) -> Option<T> {
+    if s.len() > N || !s.is_ascii() {
+        return None;
+    }
+    let mut buf = [0u8; N];
+    for (folded, b) in buf.iter_mut().zip(s.bytes()) {
+        *folded = map(b);
+    }
+    lookup(as_str(&buf[..s.len()]))
+}
+
+fn parse_alpha2(s: &str) -> Result<CountryCode, ParseError> {
+    let (buf, len) = to_upper::<2>(s, 2..=2, |_, c| c.is_ascii_alphabetic())?;
+    ALPHA2_MAP
+        .get(as_str(&buf[..len]))
+        .cloned()
+        .ok_or(ParseError::Unassigned)
+}
+
+fn parse_alpha3_or_numeric(s: &str) -> Result<CountryCode, ParseError> {
+    if s.starts_with(|c: char| c.is_ascii_digit()) {
+        let (buf, len) = to_upper::<3>(s, 3..=3, |_, c| c.is_ascii_digit())?;
+        NUMERIC_MAP
+            .get(as_str(&buf[..len]))
+            .cloned()
+            .ok_or(ParseError::Unassigned)
+    } else {
+        let (buf, len) = to_upper::<3>(s, 3..=3, |_, c| c.is_ascii_alphabetic())?;
+        ALPHA3_MAP
+            .get(as_str(&buf[..len]))
+            .cloned()
+            .ok_or(ParseError::Unassigned)
+    }
+}
+
+/// Parses an alpha-2, alpha-3 or 3 digit numeric code, ignoring ASCII case.
+/// #Sample
+/// ```
+/// use rust_iso3166::CountryCode;
+/// let au: CountryCode = "AU".parse().unwrap();
+/// assert_eq!(au, "aus".parse().unwrap());
+/// assert_eq!(au, "036".parse().unwrap());
+/// ```
+impl FromStr for CountryCode {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.chars().count() {
+            0 => Err(ParseError::Empty),
+            2 => parse_alpha2(s),
+            3 => parse_alpha3_or_numeric(s),
+            len => Err(ParseError::InvalidLength(len)),
+        }
+    }
+}
+
+impl TryFrom<&str> for CountryCode {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Looks up a numeric code, `036` and `36` being the same code.
+impl TryFrom<u16> for CountryCode {
+    type Error = ParseError;
+
+    fn try_from(numeric: u16) -> Result<Self, Self::Error> {
+        if numeric > 999 {
+            return Err(ParseError::InvalidLength(numeric.to_string().len()));
+        }
+        Country::from_numeric(numeric)
+            .map(Country::country_code)
+            .ok_or(ParseError::Unassigned)
+    }
+}
+
+/// Parses an ISO 3166-2 code such as `GB-EDH`, ignoring ASCII case.
+/// #Sample
+/// ```
+/// use rust_iso3166::ParseError;
+/// use rust_iso3166::iso3166_2::Subdivision;
+/// let sub: Subdivision = "se-o".parse().unwrap();
+/// assert_eq!("SE-O", sub.code);
+/// assert_eq!(Err(ParseError::UnknownCountry), "XX-O".parse::<Subdivision>());
+/// assert_eq!(Err(ParseError::Unassigned), "SE-XX".parse::<Subdivision>());
+/// ```
+///
+/// ISO 3166-2 has no numeric codes, so unlike `CountryCode` and
+/// `CountryCode3` there is no `TryFrom<u16>`.
+impl FromStr for Subdivision {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (buf, len) = to_upper::<6>(s, 4..=6, |index, c| match index {
+            0 | 1 => c.is_ascii_alphabetic(),
+            2 => c == '-',
+            _ => c.is_ascii_alphanumeric(),
+        })?;
+        let code = as_str(&buf[..len]);
+        if !ALPHA2_MAP.contains_key(&code[..2]) {
+            return Err(ParseError::UnknownCountry);
+        }
+        SUBDIVISION_MAP
+            .get(code)
+            .cloned()
+            .ok_or(ParseError::Unassigned)
+    }
+}
+
+impl TryFrom<&str> for Subdivision {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Parses an ISO 3166-3 alpha-4 code such as `PZPA`, ignoring ASCII case.
+/// #Sample
+/// ```
+/// use rust_iso3166::iso3166_3::CountryCode3;
+/// let pz: CountryCode3 = "pzpa".parse().unwrap();
+/// assert_eq!("Panama Canal Zone", pz.name);
+/// ```
+impl FromStr for CountryCode3 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (buf, len) = to_upper::<4>(s, 4..=4, |_, c| c.is_ascii_alphabetic())?;
+        ALPHA4_MAP
+            .get(as_str(&buf[..len]))
+            .cloned()
+            .ok_or(ParseError::Unassigned)
+    }
+}
+
+impl TryFrom<&str> for CountryCode3 {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Looks up the former numeric code of a deleted country. A code that passed
+/// from one entry to the next, such as `891` from Yugoslavia to Serbia and
+/// Montenegro, gives the entry withdrawn last.
+/// #Sample
+/// ```
+/// use rust_iso3166::iso3166_3::CountryCode3;
+/// assert_eq!("DDDE", CountryCode3::try_from(278).unwrap().code);
+/// assert_eq!("CSXX", CountryCode3::try_from(891).unwrap().code);
+/// ```
+impl TryFrom<u16> for CountryCode3 {
+    type Error = ParseError;
+
+    fn try_from(numeric: u16) -> Result<Self, Self::Error> {
+        if numeric > 999 {
+            return Err(ParseError::InvalidLength(numeric.to_string().len()));
+        }
+        iso3166_3::ALL
+            .iter()
+            .filter(|c| numeric != 0 && c.former.numeric == numeric)
+            .max_by_key(|c| c.validity[1])
+            .copied()
+            .ok_or(ParseError::Unassigned)
+    }
+}
@@ -0,0 +1,346 @@
+use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+use std::sync::OnceLock;
+
+impl CountryCode {
+    ///Return the E.164 international calling codes. Countries sharing a code
+    ///are told apart by the leading digits after it, written as `+1-684`.
+    pub fn calling_codes(&self) -> &'static [&'static str] {
+        CALLING_CODE_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+}
+
+/// Returns the candidate countries for a phone number or the start of one,
+/// most specific first.
+///
+/// The longest calling code the number starts with wins, so `+1 684` is
+/// American Samoa alone while `+1 212` could be any country using the bare
+/// `+1`. If the input is too short to tell, every country whose code starts
+/// with it is a candidate, so `+1` returns the whole North American
+/// Numbering Plan. Spaces, dashes, dots and parentheses are ignored and the
+/// number may start with `+` or `00`.
+/// #Sample
+/// ```
+/// let found = rust_iso3166::from_phone_prefix("+44 7911 123456");
+/// assert_eq!(vec!["GB"], found.iter().map(|c| c.alpha2).collect::<Vec<_>>());
+/// let found = rust_iso3166::from_phone_prefix("+7 495 123-45-67");
+/// assert_eq!(vec!["RU"], found.iter().map(|c| c.alpha2).collect::<Vec<_>>());
+/// let found = rust_iso3166::from_phone_prefix("+7 727 123-45-67");
+/// assert_eq!(vec!["KZ"], found.iter().map(|c| c.alpha2).collect::<Vec<_>>());
+/// let found = rust_iso3166::from_phone_prefix("+1 (684) 633-1234");
+/// assert_eq!(vec!["AS"], found.iter().map(|c| c.alpha2).collect::<Vec<_>>());
+/// ```
+pub fn from_phone_prefix(number: &str) -> Vec<CountryCode> {
+    let number = number.trim();
+    let number = number
+        .strip_prefix('+')
+        .or_else(|| number.strip_prefix("00"))
+        .unwrap_or(number);
+    let mut digits = String::with_capacity(number.len());
+    for c in number.chars() {
+        match c {
+            '0'..='9' => digits.push(c),
+            ' ' | '-' | '.' | '(' | ')' => {}
+            _ => return Vec::new(),
+        }
+    }
+    if digits.is_empty() {
+        return Vec::new();
+    }
+
+    let index = index();
+    let longest = index
+        .iter()
+        .map(|(prefix, _)| prefix)
+        .filter(|prefix| digits.starts_with(prefix.as_str()))
+        .max_by_key(|prefix| prefix.len());
+    let mut found: Vec<CountryCode> = Vec::new();
+    let mut add = |country: CountryCode| {
+        if !found.contains(&country) {
+            found.push(country);
+        }
+    };
+    if let Some(longest) = longest {
+        for (prefix, country) in index {
+            if prefix == longest {
+                add(*country);
+            }
+        }
+    }
+    for (prefix, country) in index {
+        if prefix.len() > digits.len() && prefix.starts_with(&digits) {
+            add(*country);
+        }
+    }
+    found
+}
+
+/// Calling codes as plain digits, in `ALL` order.
+fn index() -> &'static [(String, CountryCode)] {
+    static INDEX: OnceLock<Vec<(String, CountryCode)>> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        crate::ALL
+            .iter()
+            .flat_map(|country| {
+                country.calling_codes().iter().map(|code| {
+                    let digits = code.chars().filter(char::is_ascii_digit).collect();
+                    (digits, *country)
+                })
+            })
+            .collect()
+    })
+}
+
+/// E.164 calling codes by alpha-2 code.
+pub const CALLING_CODE_MAP: Map<&str, &[&str]> = phf_map! {
+    "AD" => &["+376"],
+    "AE" => &["+971"],
+    "AF" => &["+93"],
+    "AG" => &["+1-268"],
+    "AI" => &["+1-264"],
+    "AL" => &["+355"],
+    "AM" => &["+374"],
+    "AO" => &["+244"],
+    "AQ" => &["+672-1"],
+    "AR" => &["+54"],
+    "AS" => &["+1-684"],
+    "AT" => &["+43"],
+    "AU" => &["+61"],
+    "AW" => &["+297"],
+    "AX" => &["+358-18"],
+    "AZ" => &["+994"],
+    "BA" => &["+387"],
+    "BB" => &["+1-246"],
+    "BD" => &["+880"],
+    "BE" => &["+32"],
+    "BF" => &["+226"],
+    "BG" => &["+359"],
+    "BH" => &["+973"],
+    "BI" => &["+257"],
+    "BJ" => &["+229"],
+    "BL" => &["+590"],
+    "BM" => &["+1-441"],
+    "BN" => &["+673"],
+    "BO" => &["+591"],
+    "BQ" => &["+599-3", "+599-4", "+599-7"],
+    "BR" => &["+55"],
+    "BS" => &["+1-242"],
+    "BT" => &["+975"],
+    "BV" => &[],
+    "BW" => &["+267"],
+    "BY" => &["+375"],
+    "BZ" => &["+501"],
+    "CA" => &["+1"],
+    "CC" => &["+61-8-9162"],
+    "CD" => &["+243"],
+    "CF" => &["+236"],
+    "CG" => &["+242"],
+    "CH" => &["+41"],
+    "CI" => &["+225"],
+    "CK" => &["+682"],
+    "CL" => &["+56"],
+    "CM" => &["+237"],
+    "CN" => &["+86"],
+    "CO" => &["+57"],
+    "CR" => &["+506"],
+    "CU" => &["+53"],
+    "CV" => &["+238"],
+    "CW" => &["+599-9"],
+    "CX" => &["+61-8-9164"],
+    "CY" => &["+357"],
+    "CZ" => &["+420"],
+    "DE" => &["+49"],
+    "DJ" => &["+253"],
+    "DK" => &["+45"],
+    "DM" => &["+1-767"],
+    "DO" => &["+1-809", "+1-829", "+1-849"],
+    "DZ" => &["+213"],
+    "EC" => &["+593"],
+    "EE" => &["+372"],
+    "EG" => &["+20"],
+    "EH" => &["+212-5288", "+212-5289"],
+    "ER" => &["+291"],
+    "ES" => &["+34"],
+    "ET" => &["+251"],
+    "FI" => &["+358"],
+    "FJ" => &["+679"],
+    "FK" => &["+500"],
+    "FM" => &["+691"],
+    "FO" => &["+298"],
+    "FR" => &["+33"],
+    "GA" => &["+241"],
+    "GB" => &["+44"],
+    "GD" => &["+1-473"],
+    "GE" => &["+995"],
+    "GF" => &["+594"],
+    "GG" => &["+44-1481"],
+    "GH" => &["+233"],
+    "GI" => &["+350"],
+    "GL" => &["+299"],
+    "GM" => &["+220"],
+    "GN" => &["+224"],
+    "GP" => &["+590"],
+    "GQ" => &["+240"],
+    "GR" => &["+30"],
+    "GS" => &["+500"],
+    "GT" => &["+502"],
+    "GU" => &["+1-671"],
+    "GW" => &["+245"],
+    "GY" => &["+592"],
+    "HK" => &["+852"],
+    "HM" => &[],
+    "HN" => &["+504"],
+    "HR" => &["+385"],
+    "HT" => &["+509"],
+    "HU" => &["+36"],
+    "ID" => &["+62"],
+    "IE" => &["+353"],
+    "IL" => &["+972"],
+    "IM" => &["+44-1624"],
+    "IN" => &["+91"],
+    "IO" => &["+246"],
+    "IQ" => &["+964"],
+    "IR" => &["+98"],
+    "IS" => &["+354"],
+    "IT" => &["+39"],
+    "JE" => &["+44-1534"],
+    "JM" => &["+1-876", "+1-658"],
+    "JO" => &["+962"],
+    "JP" => &["+81"],
+    "KE" => &["+254"],
+    "KG" => &["+996"],
+    "KH" => &["+855"],
+    "KI" => &["+686"],
+    "KM" => &["+269"],
+    "KN" => &["+1-869"],
+    "KP" => &["+850"],
+    "KR" => &["+82"],
+    "KW" => &["+965"],
+    "KY" => &["+1-345"],
+    "KZ" => &["+7-6", "+7-7"],
+    "LA" => &["+856"],
+    "LB" => &["+961"],
+    "LC" => &["+1-758"],
+    "LI" => &["+423"],
+    "LK" => &["+94"],
+    "LR" => &["+231"],
+    "LS" => &["+266"],
+    "LT" => &["+370"],
+    "LU" => &["+352"],
+    "LV" => &["+371"],
+    "LY" => &["+218"],
+    "MA" => &["+212"],
+    "MC" => &["+377"],
+    "MD" => &["+373"],
+    "ME" => &["+382"],
+    "MF" => &["+590"],
+    "MG" => &["+261"],
+    "MH" => &["+692"],
+    "MK" => &["+389"],
+    "ML" => &["+223"],
+    "MM" => &["+95"],
+    "MN" => &["+976"],
+    "MO" => &["+853"],
+    "MP" => &["+1-670"],
+    "MQ" => &["+596"],
+    "MR" => &["+222"],
+    "MS" => &["+1-664"],
+    "MT" => &["+356"],
+    "MU" => &["+230"],
+    "MV" => &["+960"],
+    "MW" => &["+265"],
+    "MX" => &["+52"],
+    "MY" => &["+60"],
+    "MZ" => &["+258"],
+    "NA" => &["+264"],
+    "NC" => &["+687"],
+    "NE" => &["+227"],
+    "NF" => &["+672-3"],
+    "NG" => &["+234"],
+    "NI" => &["+505"],
+    "NL" => &["+31"],
+    "NO" => &["+47"],
+    "NP" => &["+977"],
+    "NR" => &["+674"],
+    "NU" => &["+683"],
+    "NZ" => &["+64"],
+    "OM" => &["+968"],
+    "PA" => &["+507"],
+    "PE" => &["+51"],
+    "PF" => &["+689"],
+    "PG" => &["+675"],
+    "PH" => &["+63"],
+    "PK" => &["+92"],
+    "PL" => &["+48"],
+    "PM" => &["+508"],
+    "PN" => &["+64"],
+    "PR" => &["+1-787", "+1-939"],
+    "PS" => &["+970"],
+    "PT" => &["+351"],
+    "PW" => &["+680"],
+    "PY" => &["+595"],
+    "QA" => &["+974"],
+    "RE" => &["+262"],
+    "RO" => &["+40"],
+    "RS" => &["+381"],
+    "RU" => &["+7"],
+    "RW" => &["+250"],
+    "SA" => &["+966"],
+    "SB" => &["+677"],
+    "SC" => &["+248"],
+    "SD" => &["+249"],
+    "SE" => &["+46"],
+    "SG" => &["+65"],
+    "SH" => &["+290", "+247"],
+    "SI" => &["+386"],
+    "SJ" => &["+47-79"],
+    "SK" => &["+421"],
+    "SL" => &["+232"],
+    "SM" => &["+378"],
+    "SN" => &["+221"],
+    "SO" => &["+252"],
+    "SR" => &["+597"],
+    "SS" => &["+211"],
+    "ST" => &["+239"],
+    "SV" => &["+503"],
+    "SX" => &["+1-721"],
+    "SY" => &["+963"],
+    "SZ" => &["+268"],
+    "TC" => &["+1-649"],
+    "TD" => &["+235"],
+    "TF" => &["+262"],
+    "TG" => &["+228"],
+    "TH" => &["+66"],
+    "TJ" => &["+992"],
+    "TK" => &["+690"],
+    "TL" => &["+670"],
+    "TM" => &["+993"],
+    "TN" => &["+216"],
+    "TO" => &["+676"],
+    "TR" => &["+90"],
+    "TT" => &["+1-868"],
+    "TV" => &["+688"],
+    "TW" => &["+886"],
+    "TZ" => &["+255"],
+    "UA" => &["+380"],
+    "UG" => &["+256"],
+    "UM" => &["+1"],
+    "US" => &["+1"],
+    "UY" => &["+598"],
+    "UZ" => &["+998"],
+    "VA" => &["+39-06-698"],
+    "VC" => &["+1-784"],
+    "VE" => &["+58"],
+    "VG" => &["+1-284"],
+    "VI" => &["+1-340"],
+    "VN" => &["+84"],
+    "VU" => &["+678"],
+    "WF" => &["+681"],
+    "WS" => &["+685"],
+    "YE" => &["+967"],
+    "YT" => &["+262-269", "+262-639"],
+    "ZA" => &["+27"],
+    "ZM" => &["+260"],
+    "ZW" => &["+263"],
+};
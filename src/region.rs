@@ -0,0 +1,427 @@
+use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+
+/// Level of a region in the UN M49 hierarchy.
+#[derive(Debug, Ord, PartialOrd, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RegionLevel {
+    ///The world, `001`
+    World,
+    ///A continent such as Africa (`002`), called "region" by M49
+    Region,
+    ///A sub-region such as Northern Africa (`015`)
+    SubRegion,
+    ///An intermediate region such as Eastern Africa (`014`)
+    IntermediateRegion,
+}
+
+/// A UN M49 geographic region.
+///
+/// The UN Statistics Division groups countries into regions, sub-regions and
+/// (for parts of Africa, the Americas and Europe) intermediate regions. The
+/// ISO 3166-1 numeric codes come from the same M49 scheme.
+/// #Sample
+/// ```
+/// use rust_iso3166::Region;
+/// let europe = Region::from_code(150).unwrap();
+/// assert_eq!("Europe", europe.name);
+/// assert!(europe.countries().contains(&rust_iso3166::FR));
+///
+/// let brazil = rust_iso3166::from_alpha2("BR").unwrap();
+/// assert_eq!("Americas", brazil.region().unwrap().name);
+/// assert_eq!("Latin America and the Caribbean", brazil.sub_region().unwrap().name);
+/// assert_eq!("South America", brazil.intermediate_region().unwrap().name);
+/// ```
+#[derive(Debug, Ord, PartialOrd, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Region {
+    ///M49 numeric code
+    pub code: u16,
+    ///English name
+    pub name: &'static str,
+    ///Level in the hierarchy
+    pub level: RegionLevel,
+    ///M49 code of the enclosing region, `None` for the world
+    pub parent: Option<u16>,
+}
+
+impl Region {
+    /// Returns the Region with the given M49 code, if exists.
+    pub fn from_code(code: u16) -> Option<Region> {
+        REGION_MAP.get(&code).copied()
+    }
+
+    ///Return len 3 String for the M49 code
+    pub fn code_str(&self) -> String {
+        format!("{:03}", self.code)
+    }
+
+    ///Return the enclosing region
+    pub fn parent(&self) -> Option<Region> {
+        self.parent.and_then(Region::from_code)
+    }
+
+    ///Return the regions directly within this one
+    pub fn children(&self) -> Vec<Region> {
+        let mut found: Vec<Region> = REGION_MAP
+            .values()
+            .filter(|r| r.parent == Some(self.code))
+            .copied()
+            .collect();
+        found.sort_by_key(|r| r.code);
+        found
+    }
+
+    ///Return true if `other` is this region or lies within it
+    pub fn contains(&self, other: &Region) -> bool {
+        let mut region = Some(*other);
+        while let Some(r) = region {
+            if r.code == self.code {
+                return true;
+            }
+            region = r.parent();
+        }
+        false
+    }
+
+    ///Return the countries within this region, at any depth, in `ALL` order
+    pub fn countries(&self) -> Vec<CountryCode> {
+        crate::ALL
+            .iter()
+            .filter(|c| c.m49_region().is_some_and(|r| self.contains(&r)))
+            .copied()
+            .collect()
+    }
+}
+
+impl CountryCode {
+    /// The most specific M49 region of this country.
+    fn m49_region(&self) -> Option<Region> {
+        COUNTRY_REGION_MAP
+            .get(self.alpha2)
+            .and_then(|code| Region::from_code(*code))
+    }
+
+    fn region_at(&self, level: RegionLevel) -> Option<Region> {
+        let mut region = self.m49_region();
+        while let Some(r) = region {
+            if r.level == level {
+                return Some(r);
+            }
+            region = r.parent();
+        }
+        None
+    }
+
+    ///Return the M49 region (continent), such as Africa. Antarctica is in
+    ///none; Taiwan, which M49 does not list, is placed in Eastern Asia.
+    pub fn region(&self) -> Option<Region> {
+        self.region_at(RegionLevel::Region)
+    }
+
+    ///Return the M49 sub-region, such as Sub-Saharan Africa
+    pub fn sub_region(&self) -> Option<Region> {
+        self.region_at(RegionLevel::SubRegion)
+    }
+
+    ///Return the M49 intermediate region, such as Eastern Africa, if the
+    ///country's sub-region is divided further
+    pub fn intermediate_region(&self) -> Option<Region> {
+        self.region_at(RegionLevel::IntermediateRegion)
+    }
+}
+
+const fn region(code: u16, name: &'static str, level: RegionLevel, parent: u16) -> Region {
+    Region {
+        code,
+        name,
+        level,
+        parent: Some(parent),
+    }
+}
+
+/// M49 regions by numeric code.
+pub const REGION_MAP: Map<u16, Region> = phf_map! {
+    1u16 => Region { code: 1, name: "World", level: RegionLevel::World, parent: None },
+    2u16 => region(2, "Africa", RegionLevel::Region, 1),
+    15u16 => region(15, "Northern Africa", RegionLevel::SubRegion, 2),
+    202u16 => region(202, "Sub-Saharan Africa", RegionLevel::SubRegion, 2),
+    14u16 => region(14, "Eastern Africa", RegionLevel::IntermediateRegion, 202),
+    17u16 => region(17, "Middle Africa", RegionLevel::IntermediateRegion, 202),
+    18u16 => region(18, "Southern Africa", RegionLevel::IntermediateRegion, 202),
+    11u16 => region(11, "Western Africa", RegionLevel::IntermediateRegion, 202),
+    19u16 => region(19, "Americas", RegionLevel::Region, 1),
+    419u16 => region(419, "Latin America and the Caribbean", RegionLevel::SubRegion, 19),
+    29u16 => region(29, "Caribbean", RegionLevel::IntermediateRegion, 419),
+    13u16 => region(13, "Central America", RegionLevel::IntermediateRegion, 419),
+    5u16 => region(5, "South America", RegionLevel::IntermediateRegion, 419),
+    21u16 => region(21, "Northern America", RegionLevel::SubRegion, 19),
+    142u16 => region(142, "Asia", RegionLevel::Region, 1),
+    143u16 => region(143, "Central Asia", RegionLevel::SubRegion, 142),
+    30u16 => region(30, "Eastern Asia", RegionLevel::SubRegion, 142),
+    35u16 => region(35, "South-eastern Asia", RegionLevel::SubRegion, 142),
+    34u16 => region(34, "Southern Asia", RegionLevel::SubRegion, 142),
+    145u16 => region(145, "Western Asia", RegionLevel::SubRegion, 142),
+    150u16 => region(150, "Europe", RegionLevel::Region, 1),
+    151u16 => region(151, "Eastern Europe", RegionLevel::SubRegion, 150),
+    154u16 => region(154, "Northern Europe", RegionLevel::SubRegion, 150),
+    830u16 => region(830, "Channel Islands", RegionLevel::IntermediateRegion, 154),
+    39u16 => region(39, "Southern Europe", RegionLevel::SubRegion, 150),
+    155u16 => region(155, "Western Europe", RegionLevel::SubRegion, 150),
+    9u16 => region(9, "Oceania", RegionLevel::Region, 1),
+    53u16 => region(53, "Australia and New Zealand", RegionLevel::SubRegion, 9),
+    54u16 => region(54, "Melanesia", RegionLevel::SubRegion, 9),
+    57u16 => region(57, "Micronesia", RegionLevel::SubRegion, 9),
+    61u16 => region(61, "Polynesia", RegionLevel::SubRegion, 9),
+};
+
+/// The most specific M49 region of each country, by alpha-2 code.
+pub const COUNTRY_REGION_MAP: Map<&str, u16> = phf_map! {
+    "AD" => 39,
+    "AE" => 145,
+    "AF" => 34,
+    "AG" => 29,
+    "AI" => 29,
+    "AL" => 39,
+    "AM" => 145,
+    "AO" => 17,
+    "AR" => 5,
+    "AS" => 61,
+    "AT" => 155,
+    "AU" => 53,
+    "AW" => 29,
+    "AX" => 154,
+    "AZ" => 145,
+    "BA" => 39,
+    "BB" => 29,
+    "BD" => 34,
+    "BE" => 155,
+    "BF" => 11,
+    "BG" => 151,
+    "BH" => 145,
+    "BI" => 14,
+    "BJ" => 11,
+    "BL" => 29,
+    "BM" => 21,
+    "BN" => 35,
+    "BO" => 5,
+    "BQ" => 29,
+    "BR" => 5,
+    "BS" => 29,
+    "BT" => 34,
+    "BV" => 5,
+    "BW" => 18,
+    "BY" => 151,
+    "BZ" => 13,
+    "CA" => 21,
+    "CC" => 53,
+    "CD" => 17,
+    "CF" => 17,
+    "CG" => 17,
+    "CH" => 155,
+    "CI" => 11,
+    "CK" => 61,
+    "CL" => 5,
+    "CM" => 17,
+    "CN" => 30,
+    "CO" => 5,
+    "CR" => 13,
+    "CU" => 29,
+    "CV" => 11,
+    "CW" => 29,
+    "CX" => 53,
+    "CY" => 145,
+    "CZ" => 151,
+    "DE" => 155,
+    "DJ" => 14,
+    "DK" => 154,
+    "DM" => 29,
+    "DO" => 29,
+    "DZ" => 15,
+    "EC" => 5,
+    "EE" => 154,
+    "EG" => 15,
+    "EH" => 15,
+    "ER" => 14,
+    "ES" => 39,
+    "ET" => 14,
+    "FI" => 154,
+    "FJ" => 54,
+    "FK" => 5,
+    "FM" => 57,
+    "FO" => 154,
+    "FR" => 155,
+    "GA" => 17,
+    "GB" => 154,
+    "GD" => 29,
+    "GE" => 145,
+    "GF" => 5,
+    "GG" => 830,
+    "GH" => 11,
+    "GI" => 39,
+    "GL" => 21,
+    "GM" => 11,
+    "GN" => 11,
+    "GP" => 29,
+    "GQ" => 17,
+    "GR" => 39,
+    "GS" => 5,
+    "GT" => 13,
+    "GU" => 57,
+    "GW" => 11,
+    "GY" => 5,
+    "HK" => 30,
+    "HM" => 53,
+    "HN" => 13,
+    "HR" => 39,
+    "HT" => 29,
+    "HU" => 151,
+    "ID" => 35,
+    "IE" => 154,
+    "IL" => 145,
+    "IM" => 154,
+    "IN" => 34,
+    "IO" => 14,
+    "IQ" => 145,
+    "IR" => 34,
+    "IS" => 154,
+    "IT" => 39,
+    "JE" => 830,
+    "JM" => 29,
+    "JO" => 145,
+    "JP" => 30,
+    "KE" => 14,
+    "KG" => 143,
+    "KH" => 35,
+    "KI" => 57,
+    "KM" => 14,
+    "KN" => 29,
+    "KP" => 30,
+    "KR" => 30,
+    "KW" => 145,
+    "KY" => 29,
+    "KZ" => 143,
+    "LA" => 35,
+    "LB" => 145,
+    "LC" => 29,
+    "LI" => 155,
+    "LK" => 34,
+    "LR" => 11,
+    "LS" => 18,
+    "LT" => 154,
+    "LU" => 155,
+    "LV" => 154,
+    "LY" => 15,
+    "MA" => 15,
+    "MC" => 155,
+    "MD" => 151,
+    "ME" => 39,
+    "MF" => 29,
+    "MG" => 14,
+    "MH" => 57,
+    "MK" => 39,
+    "ML" => 11,
+    "MM" => 35,
+    "MN" => 30,
+    "MO" => 30,
+    "MP" => 57,
+    "MQ" => 29,
+    "MR" => 11,
+    "MS" => 29,
+    "MT" => 39,
+    "MU" => 14,
+    "MV" => 34,
+    "MW" => 14,
+    "MX" => 13,
+    "MY" => 35,
+    "MZ" => 14,
+    "NA" => 18,
+    "NC" => 54,
+    "NE" => 11,
+    "NF" => 53,
+    "NG" => 11,
+    "NI" => 13,
+    "NL" => 155,
+    "NO" => 154,
+    "NP" => 34,
+    "NR" => 57,
+    "NU" => 61,
+    "NZ" => 53,
+    "OM" => 145,
+    "PA" => 13,
+    "PE" => 5,
+    "PF" => 61,
+    "PG" => 54,
+    "PH" => 35,
+    "PK" => 34,
+    "PL" => 151,
+    "PM" => 21,
+    "PN" => 61,
+    "PR" => 29,
+    "PS" => 145,
+    "PT" => 39,
+    "PW" => 57,
+    "PY" => 5,
+    "QA" => 145,
+    "RE" => 14,
+    "RO" => 151,
+    "RS" => 39,
+    "RU" => 151,
+    "RW" => 14,
+    "SA" => 145,
+    "SB" => 54,
+    "SC" => 14,
+    "SD" => 15,
+    "SE" => 154,
+    "SG" => 35,
+    "SH" => 11,
+    "SI" => 39,
+    "SJ" => 154,
+    "SK" => 151,
+    "SL" => 11,
+    "SM" => 39,
+    "SN" => 11,
+    "SO" => 14,
+    "SR" => 5,
+    "SS" => 14,
+    "ST" => 17,
+    "SV" => 13,
+    "SX" => 29,
+    "SY" => 145,
+    "SZ" => 18,
+    "TC" => 29,
+    "TD" => 17,
+    "TF" => 14,
+    "TG" => 11,
+    "TH" => 35,
+    "TJ" => 143,
+    "TK" => 61,
+    "TL" => 35,
+    "TM" => 143,
+    "TN" => 15,
+    "TO" => 61,
+    "TR" => 145,
+    "TT" => 29,
+    "TV" => 61,
+    "TW" => 30,
+    "TZ" => 14,
+    "UA" => 151,
+    "UG" => 14,
+    "UM" => 57,
+    "US" => 21,
+    "UY" => 5,
+    "UZ" => 143,
+    "VA" => 39,
+    "VC" => 29,
+    "VE" => 5,
+    "VG" => 29,
+    "VI" => 29,
+    "VN" => 35,
+    "VU" => 54,
+    "WF" => 61,
+    "WS" => 61,
+    "YE" => 145,
+    "YT" => 14,
+    "ZA" => 18,
+    "ZM" => 14,
+    "ZW" => 14,
+};
@@ -0,0 +1,238 @@
+//! Fuzzy, ranked search over the English names of countries, subdivisions and
+//! former countries.
+//!
+//! Country aliases such as `"South Korea"` are searched too. Names and
+//! queries are compared case-insensitively with Latin diacritics
+//! stripped, so `"Vastra Gotaland"` finds `"Västra Götalands län"`. Each
+//! candidate gets a score between `0.0` and `1.0` from the best of:
+//!
+//! * an exact match (`1.0`),
+//! * the query being a prefix of the name,
+//! * matching the query word by word against the words of the name, allowing
+//!   typos in each word,
+//! * the edit distance between the whole query and the whole name.
+//!
+//! # Sample code
+//! ```
+//! use rust_iso3166::search::{search, SearchOptions};
+//! let found = search("Untied Kingdom", &SearchOptions::default());
+//! assert_eq!("GB", found[0].code.code());
+//!
+//! let options = SearchOptions {
+//!     countries: false,
+//!     former: false,
+//!     country: rust_iso3166::from_alpha2("SE"),
+//!     ..SearchOptions::default()
+//! };
+//! let found = search("Vastra Gotaland", &options);
+//! assert_eq!("SE-O", found[0].code.code());
+//! ```
+
+use crate::normalize::normalize;
+use crate::{iso3166_2, iso3166_3, Code, CountryCode};
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+/// Limits and filters for `search`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SearchOptions {
+    ///Maximum number of matches returned
+    pub limit: usize,
+    ///Matches scoring below this are dropped
+    pub min_score: f64,
+    ///Search ISO 3166-1 country names
+    pub countries: bool,
+    ///Search ISO 3166-2 subdivision names
+    pub subdivisions: bool,
+    ///Search ISO 3166-3 former country names
+    pub former: bool,
+    ///Only search the subdivisions of this country
+    pub country: Option<CountryCode>,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        SearchOptions {
+            limit: 10,
+            min_score: 0.6,
+            countries: true,
+            subdivisions: true,
+            former: true,
+            country: None,
+        }
+    }
+}
+
+/// A ranked result of `search`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Match {
+    ///The country, subdivision or former country found
+    pub code: Code,
+    ///The name or alias that matched
+    pub name: &'static str,
+    ///Between `0.0` and `1.0`, higher is better
+    pub score: f64,
+}
+
+/// Returns the best matches for `query`, best first.
+/// #Sample
+/// ```
+/// use rust_iso3166::search::{search, SearchOptions};
+/// let options = SearchOptions { subdivisions: false, limit: 1, ..SearchOptions::default() };
+/// let found = search("austrlia", &options);
+/// assert_eq!("AU", found[0].code.code());
+/// ```
+pub fn search(query: &str, options: &SearchOptions) -> Vec<Match> {
+    let query = normalize(query);
+    if query.is_empty() || options.limit == 0 {
+        return Vec::new();
+    }
+    let query_tokens: Vec<Vec<char>> = query.split(' ').map(|t| t.chars().collect()).collect();
+    let query_chars: Vec<char> = query.chars().collect();
+    let mut found: Vec<Match> = index()
+        .iter()
+        .filter(|entry| wanted(&entry.code, options))
+        .filter_map(|entry| {
+            let score = score(&query, &query_chars, &query_tokens, &entry.normalized);
+            (score >= options.min_score).then_some(Match {
+                code: entry.code,
+                name: entry.name,
+                score,
+            })
+        })
+        .collect();
+    found.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| rank(&a.code).cmp(&rank(&b.code)))
+            .then_with(|| a.name.cmp(b.name))
+    });
+    // A country can match by its name and several aliases; keep the best.
+    let mut seen = HashSet::new();
+    found.retain(|m| seen.insert(m.code));
+    found.truncate(options.limit);
+    found
+}
+
+struct Entry {
+    code: Code,
+    name: &'static str,
+    normalized: String,
+}
+
+/// Every searchable name, normalized once on first use.
+fn index() -> &'static [Entry] {
+    static INDEX: OnceLock<Vec<Entry>> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let countries = crate::ALL.iter().map(|c| (Code::Country(*c), c.name));
+        let aliases = crate::ALL
+            .iter()
+            .flat_map(|c| c.aliases().iter().map(|alias| (Code::Country(*c), *alias)));
+        let subdivisions = iso3166_2::SUBDIVISION_MAP
+            .values()
+            .map(|s| (Code::Subdivision(*s), s.name));
+        let former = iso3166_3::ALL.iter().map(|f| (Code::Former(*f), f.name));
+        countries
+            .chain(aliases)
+            .chain(subdivisions)
+            .chain(former)
+            .map(|(code, name)| Entry {
+                code,
+                name,
+                normalized: normalize(name),
+            })
+            .collect()
+    })
+}
+
+fn wanted(code: &Code, options: &SearchOptions) -> bool {
+    match code {
+        Code::Country(_) => options.countries,
+        Code::Subdivision(s) => {
+            options.subdivisions && options.country.is_none_or(|c| c.alpha2 == s.country_code)
+        }
+        Code::Former(_) => options.former,
+    }
+}
+
+/// Countries rank before subdivisions and former countries on equal scores.
+fn rank(code: &Code) -> u8 {
+    match code {
+        Code::Country(_) => 0,
+        Code::Subdivision(_) => 1,
+        Code::Former(_) => 2,
+    }
+}
+
+fn score(query: &str, query_chars: &[char], query_tokens: &[Vec<char>], name: &str) -> f64 {
+    if query == name {
+        return 1.0;
+    }
+    let name_chars: Vec<char> = name.chars().collect();
+    let mut best = 0.0f64;
+    if name.starts_with(query) {
+        best = 0.9 + 0.09 * query_chars.len() as f64 / name_chars.len() as f64;
+    }
+
+    let name_tokens: Vec<Vec<char>> = name.split(' ').map(|t| t.chars().collect()).collect();
+    let matched: f64 = query_tokens
+        .iter()
+        .map(|q| {
+            name_tokens
+                .iter()
+                .map(|n| token_similarity(q, n))
+                .fold(0.0, f64::max)
+        })
+        .sum();
+    let coverage = (query_tokens.len() as f64 / name_tokens.len() as f64).min(1.0);
+    best = best.max(0.85 * matched / query_tokens.len() as f64 * (0.9 + 0.1 * coverage));
+
+    best.max(0.85 * similarity(query_chars, &name_chars))
+}
+
+fn token_similarity(query: &[char], name: &[char]) -> f64 {
+    if query == name {
+        1.0
+    } else if query.len() >= 3 && name.starts_with(query) {
+        0.9
+    } else {
+        similarity(query, name)
+    }
+}
+
+/// `1.0` minus the edit distance relative to the longer input.
+fn similarity(a: &[char], b: &[char]) -> f64 {
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    // Cheap bound: the distance is at least the difference in length.
+    if a.len().abs_diff(b.len()) * 2 > longest {
+        return 0.0;
+    }
+    1.0 - edit_distance(a, b) as f64 / longest as f64
+}
+
+/// Optimal string alignment distance: Levenshtein plus adjacent
+/// transpositions, so `untied` is one edit away from `united`.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let width = b.len() + 1;
+    let mut rows = vec![0usize; width * (a.len() + 1)];
+    for (j, cell) in rows.iter_mut().take(width).enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        rows[i * width] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut d = (rows[(i - 1) * width + j] + 1)
+                .min(rows[i * width + j - 1] + 1)
+                .min(rows[(i - 1) * width + j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d = d.min(rows[(i - 2) * width + j - 2] + 1);
+            }
+            rows[i * width + j] = d;
+        }
+    }
+    rows[a.len() * width + b.len()]
+}
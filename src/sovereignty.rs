@@ -0,0 +1,153 @@
+use crate::{CountryCode, AU, CN, DK, FI, FR, GB, NL, NO, NZ, US};
+use phf::phf_map;
+use phf::Map;
+
+/// How a territory relates to the state it belongs to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Relationship {
+    ///United States territory, such as Guam or Puerto Rico
+    UnincorporatedTerritory,
+    ///Self-governing possession of the British Crown: Guernsey, Jersey and
+    ///the Isle of Man
+    CrownDependency,
+    ///British Overseas Territory, such as Bermuda
+    BritishOverseasTerritory,
+    ///Special administrative region of China: Hong Kong and Macao
+    SpecialAdministrativeRegion,
+    ///French overseas department and region, such as Réunion
+    OverseasDepartment,
+    ///French overseas collectivity, such as French Polynesia
+    OverseasCollectivity,
+    ///New Caledonia's special status within France
+    SuiGenerisCollectivity,
+    ///Overseas territory without a permanent population, such as the French
+    ///Southern Territories
+    OverseasTerritory,
+    ///Constituent country of the Kingdom of the Netherlands
+    ConstituentCountry,
+    ///Special municipality of the Netherlands
+    SpecialMunicipality,
+    ///Autonomous territory, such as Greenland or Åland
+    AutonomousTerritory,
+    ///Self-governing state in free association with New Zealand
+    FreeAssociation,
+    ///Non-self-governing dependent territory, such as Tokelau
+    DependentTerritory,
+    ///External territory of Australia
+    ExternalTerritory,
+    ///Integral part of the state with a special status: Svalbard and Jan
+    ///Mayen
+    IntegralPart,
+}
+
+/// A territory's link to its sovereign state.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Dependency {
+    ///The dependent territory
+    pub territory: CountryCode,
+    ///The sovereign state the territory belongs to
+    pub sovereign: CountryCode,
+    ///What kind of territory it is
+    pub relationship: Relationship,
+}
+
+impl CountryCode {
+    ///Return how this territory belongs to its sovereign state, if it is
+    ///one. Antarctica, Palestine, Taiwan and Western Sahara are neither
+    ///independent nor attributed to a sovereign.
+    pub fn dependency(&self) -> Option<Dependency> {
+        DEPENDENCY_MAP.get(self.alpha2).copied()
+    }
+
+    ///Return the sovereign state of this territory, if it is one
+    pub fn sovereign(&self) -> Option<CountryCode> {
+        self.dependency().map(|d| d.sovereign)
+    }
+
+    ///Return the territories that belong to this state
+    pub fn dependencies(&self) -> Vec<Dependency> {
+        let mut found: Vec<Dependency> = DEPENDENCY_MAP
+            .values()
+            .filter(|d| d.sovereign == *self)
+            .copied()
+            .collect();
+        found.sort_by_key(|d| d.territory.alpha2);
+        found
+    }
+}
+
+const fn dependency(
+    territory: CountryCode,
+    sovereign: CountryCode,
+    relationship: Relationship,
+) -> Dependency {
+    Dependency {
+        territory,
+        sovereign,
+        relationship,
+    }
+}
+
+/// Dependent territories by alpha-2 code.
+pub const DEPENDENCY_MAP: Map<&str, Dependency> = phf_map! {
+    "AS" => dependency(crate::AS, US, Relationship::UnincorporatedTerritory),
+    "GU" => dependency(crate::GU, US, Relationship::UnincorporatedTerritory),
+    "MP" => dependency(crate::MP, US, Relationship::UnincorporatedTerritory),
+    "PR" => dependency(crate::PR, US, Relationship::UnincorporatedTerritory),
+    "UM" => dependency(crate::UM, US, Relationship::UnincorporatedTerritory),
+    "VI" => dependency(crate::VI, US, Relationship::UnincorporatedTerritory),
+
+    "GG" => dependency(crate::GG, GB, Relationship::CrownDependency),
+    "IM" => dependency(crate::IM, GB, Relationship::CrownDependency),
+    "JE" => dependency(crate::JE, GB, Relationship::CrownDependency),
+    "AI" => dependency(crate::AI, GB, Relationship::BritishOverseasTerritory),
+    "BM" => dependency(crate::BM, GB, Relationship::BritishOverseasTerritory),
+    "FK" => dependency(crate::FK, GB, Relationship::BritishOverseasTerritory),
+    "GI" => dependency(crate::GI, GB, Relationship::BritishOverseasTerritory),
+    "GS" => dependency(crate::GS, GB, Relationship::BritishOverseasTerritory),
+    "IO" => dependency(crate::IO, GB, Relationship::BritishOverseasTerritory),
+    "KY" => dependency(crate::KY, GB, Relationship::BritishOverseasTerritory),
+    "MS" => dependency(crate::MS, GB, Relationship::BritishOverseasTerritory),
+    "PN" => dependency(crate::PN, GB, Relationship::BritishOverseasTerritory),
+    "SH" => dependency(crate::SH, GB, Relationship::BritishOverseasTerritory),
+    "TC" => dependency(crate::TC, GB, Relationship::BritishOverseasTerritory),
+    "VG" => dependency(crate::VG, GB, Relationship::BritishOverseasTerritory),
+
+    "HK" => dependency(crate::HK, CN, Relationship::SpecialAdministrativeRegion),
+    "MO" => dependency(crate::MO, CN, Relationship::SpecialAdministrativeRegion),
+
+    "GF" => dependency(crate::GF, FR, Relationship::OverseasDepartment),
+    "GP" => dependency(crate::GP, FR, Relationship::OverseasDepartment),
+    "MQ" => dependency(crate::MQ, FR, Relationship::OverseasDepartment),
+    "RE" => dependency(crate::RE, FR, Relationship::OverseasDepartment),
+    "YT" => dependency(crate::YT, FR, Relationship::OverseasDepartment),
+    "BL" => dependency(crate::BL, FR, Relationship::OverseasCollectivity),
+    "MF" => dependency(crate::MF, FR, Relationship::OverseasCollectivity),
+    "PF" => dependency(crate::PF, FR, Relationship::OverseasCollectivity),
+    "PM" => dependency(crate::PM, FR, Relationship::OverseasCollectivity),
+    "WF" => dependency(crate::WF, FR, Relationship::OverseasCollectivity),
+    "NC" => dependency(crate::NC, FR, Relationship::SuiGenerisCollectivity),
+    "TF" => dependency(crate::TF, FR, Relationship::OverseasTerritory),
+
+    "AW" => dependency(crate::AW, NL, Relationship::ConstituentCountry),
+    "CW" => dependency(crate::CW, NL, Relationship::ConstituentCountry),
+    "SX" => dependency(crate::SX, NL, Relationship::ConstituentCountry),
+    "BQ" => dependency(crate::BQ, NL, Relationship::SpecialMunicipality),
+
+    "FO" => dependency(crate::FO, DK, Relationship::AutonomousTerritory),
+    "GL" => dependency(crate::GL, DK, Relationship::AutonomousTerritory),
+    "AX" => dependency(crate::AX, FI, Relationship::AutonomousTerritory),
+
+    "CK" => dependency(crate::CK, NZ, Relationship::FreeAssociation),
+    "NU" => dependency(crate::NU, NZ, Relationship::FreeAssociation),
+    "TK" => dependency(crate::TK, NZ, Relationship::DependentTerritory),
+
+    "CC" => dependency(crate::CC, AU, Relationship::ExternalTerritory),
+    "CX" => dependency(crate::CX, AU, Relationship::ExternalTerritory),
+    "HM" => dependency(crate::HM, AU, Relationship::ExternalTerritory),
+    "NF" => dependency(crate::NF, AU, Relationship::ExternalTerritory),
+
+    "BV" => dependency(crate::BV, NO, Relationship::DependentTerritory),
+    "SJ" => dependency(crate::SJ, NO, Relationship::IntegralPart),
+};
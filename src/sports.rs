@@ -0,0 +1,1849 @@
+//! Team codes of international sports federations: the IOC, FIFA, World
+//! Athletics and the International Paralympic Committee.
+//!
+//! Codes stand for a `Code`, which is usually a country. FIFA's England,
+//! Scotland, Wales and Northern Ireland teams are subdivisions of the United
+//! Kingdom, and codes of teams that no longer compete, such as the IOC's
+//! `URS`, point to the former country in ISO 3166-3.
+//!
+//! # Sample code
+//! ```
+//! use rust_iso3166::sports::{self, Federation};
+//! use rust_iso3166::Code;
+//! let de = rust_iso3166::from_alpha2("DE").unwrap();
+//! assert_eq!(Some("GER"), de.sports_code(Federation::IOC));
+//! assert_eq!(None, rust_iso3166::GB.sports_code(Federation::FIFA));
+//!
+//! let netherlands = sports::from_code(Federation::FIFA, "NED").unwrap();
+//! assert_eq!(Code::Country(rust_iso3166::NL), netherlands.team);
+//! assert_eq!("GB-SCT", sports::from_code(Federation::FIFA, "SCO").unwrap().team.code());
+//! let soviet_union = sports::from_code(Federation::IOC, "urs").unwrap();
+//! assert_eq!("SUHH", soviet_union.team.code());
+//! assert!(soviet_union.historical);
+//! ```
+
+use crate::parse::{as_str, to_upper};
+use crate::{iso3166_2, iso3166_3, Code, CountryCode};
+use phf::phf_map;
+use phf::Map;
+
+/// A sports federation with its own team codes.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Federation {
+    ///International Olympic Committee
+    IOC,
+    ///Fédération Internationale de Football Association
+    FIFA,
+    ///World Athletics, whose members outside the Olympic movement include
+    ///Anguilla, Gibraltar and Macao
+    WorldAthletics,
+    ///International Paralympic Committee, whose members outside the Olympic
+    ///movement include the Faroe Islands and Macao
+    IPC,
+}
+
+/// A federation's team code and the team it stands for.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SportsCode {
+    ///The federation's code, such as `GER`
+    pub code: &'static str,
+    ///The country, subdivision or former country of the team
+    pub team: Code,
+    ///True for codes of teams that no longer compete, such as `URS`
+    pub historical: bool,
+}
+
+impl Federation {
+    ///All federations
+    pub const ALL: &'static [Federation] = &[
+        Federation::IOC,
+        Federation::FIFA,
+        Federation::WorldAthletics,
+        Federation::IPC,
+    ];
+
+    ///Return the English name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Federation::IOC => "International Olympic Committee",
+            Federation::FIFA => "FIFA",
+            Federation::WorldAthletics => "World Athletics",
+            Federation::IPC => "International Paralympic Committee",
+        }
+    }
+
+    ///Return every code of this federation, current and historical
+    pub fn codes(&self) -> &'static Map<&'static str, SportsCode> {
+        match self {
+            Federation::IOC => &IOC_MAP,
+            Federation::FIFA => &FIFA_MAP,
+            Federation::WorldAthletics => &WORLD_ATHLETICS_MAP,
+            Federation::IPC => &IPC_MAP,
+        }
+    }
+
+    ///Return the current code of every team, by alpha-2 or ISO 3166-2 code
+    pub fn team_codes(&self) -> &'static Map<&'static str, &'static str> {
+        match self {
+            Federation::IOC => &IOC_TEAM_MAP,
+            Federation::FIFA => &FIFA_TEAM_MAP,
+            Federation::WorldAthletics => &WORLD_ATHLETICS_TEAM_MAP,
+            Federation::IPC => &IPC_TEAM_MAP,
+        }
+    }
+}
+
+impl Code {
+    ///Return the current team code in the given federation
+    pub fn sports_code(&self, federation: Federation) -> Option<&'static str> {
+        federation.team_codes().get(self.code()).copied()
+    }
+}
+
+impl CountryCode {
+    ///Return the current team code in the given federation. The United
+    ///Kingdom has no FIFA code, see `Code::sports_code` for its home nations.
+    pub fn sports_code(&self, federation: Federation) -> Option<&'static str> {
+        Code::Country(*self).sports_code(federation)
+    }
+}
+
+/// Returns the team with the given federation code, if exists. Historical
+/// codes are included and ASCII case is ignored.
+/// #Sample
+/// ```
+/// use rust_iso3166::sports::{self, Federation};
+/// let gdr = sports::from_code(Federation::IOC, "GDR").unwrap();
+/// assert_eq!("DDDE", gdr.team.code());
+/// let wales = sports::from_code(Federation::FIFA, "WAL").unwrap();
+/// assert_eq!("GB-WLS", wales.team.code());
+/// ```
+pub fn from_code(federation: Federation, code: &str) -> Option<SportsCode> {
+    let (buf, len) = to_upper::<3>(code.trim(), 3..=3, |_, c| c.is_ascii_alphabetic()).ok()?;
+    federation.codes().get(as_str(&buf[..len])).copied()
+}
+
+/// International Olympic Committee codes.
+pub const IOC_MAP: Map<&str, SportsCode> = phf_map! {
+    "AFG" => SportsCode { code: "AFG", team: Code::Country(crate::AF), historical: false },
+    "AHO" => SportsCode { code: "AHO", team: Code::Former(iso3166_3::ANHH), historical: true },
+    "ALB" => SportsCode { code: "ALB", team: Code::Country(crate::AL), historical: false },
+    "ALG" => SportsCode { code: "ALG", team: Code::Country(crate::DZ), historical: false },
+    "AND" => SportsCode { code: "AND", team: Code::Country(crate::AD), historical: false },
+    "ANG" => SportsCode { code: "ANG", team: Code::Country(crate::AO), historical: false },
+    "ANT" => SportsCode { code: "ANT", team: Code::Country(crate::AG), historical: false },
+    "ARG" => SportsCode { code: "ARG", team: Code::Country(crate::AR), historical: false },
+    "ARM" => SportsCode { code: "ARM", team: Code::Country(crate::AM), historical: false },
+    "ARU" => SportsCode { code: "ARU", team: Code::Country(crate::AW), historical: false },
+    "ASA" => SportsCode { code: "ASA", team: Code::Country(crate::AS), historical: false },
+    "AUS" => SportsCode { code: "AUS", team: Code::Country(crate::AU), historical: false },
+    "AUT" => SportsCode { code: "AUT", team: Code::Country(crate::AT), historical: false },
+    "AZE" => SportsCode { code: "AZE", team: Code::Country(crate::AZ), historical: false },
+    "BAH" => SportsCode { code: "BAH", team: Code::Country(crate::BS), historical: false },
+    "BAN" => SportsCode { code: "BAN", team: Code::Country(crate::BD), historical: false },
+    "BAR" => SportsCode { code: "BAR", team: Code::Country(crate::BB), historical: false },
+    "BDI" => SportsCode { code: "BDI", team: Code::Country(crate::BI), historical: false },
+    "BEL" => SportsCode { code: "BEL", team: Code::Country(crate::BE), historical: false },
+    "BEN" => SportsCode { code: "BEN", team: Code::Country(crate::BJ), historical: false },
+    "BER" => SportsCode { code: "BER", team: Code::Country(crate::BM), historical: false },
+    "BHU" => SportsCode { code: "BHU", team: Code::Country(crate::BT), historical: false },
+    "BIH" => SportsCode { code: "BIH", team: Code::Country(crate::BA), historical: false },
+    "BIR" => SportsCode { code: "BIR", team: Code::Former(iso3166_3::BUMM), historical: true },
+    "BIZ" => SportsCode { code: "BIZ", team: Code::Country(crate::BZ), historical: false },
+    "BLR" => SportsCode { code: "BLR", team: Code::Country(crate::BY), historical: false },
+    "BOL" => SportsCode { code: "BOL", team: Code::Country(crate::BO), historical: false },
+    "BOT" => SportsCode { code: "BOT", team: Code::Country(crate::BW), historical: false },
+    "BRA" => SportsCode { code: "BRA", team: Code::Country(crate::BR), historical: false },
+    "BRN" => SportsCode { code: "BRN", team: Code::Country(crate::BH), historical: false },
+    "BRU" => SportsCode { code: "BRU", team: Code::Country(crate::BN), historical: false },
+    "BUL" => SportsCode { code: "BUL", team: Code::Country(crate::BG), historical: false },
+    "BUR" => SportsCode { code: "BUR", team: Code::Country(crate::BF), historical: false },
+    "CAF" => SportsCode { code: "CAF", team: Code::Country(crate::CF), historical: false },
+    "CAM" => SportsCode { code: "CAM", team: Code::Country(crate::KH), historical: false },
+    "CAN" => SportsCode { code: "CAN", team: Code::Country(crate::CA), historical: false },
+    "CAY" => SportsCode { code: "CAY", team: Code::Country(crate::KY), historical: false },
+    "CGO" => SportsCode { code: "CGO", team: Code::Country(crate::CG), historical: false },
+    "CHA" => SportsCode { code: "CHA", team: Code::Country(crate::TD), historical: false },
+    "CHI" => SportsCode { code: "CHI", team: Code::Country(crate::CL), historical: false },
+    "CHN" => SportsCode { code: "CHN", team: Code::Country(crate::CN), historical: false },
+    "CIV" => SportsCode { code: "CIV", team: Code::Country(crate::CI), historical: false },
+    "CMR" => SportsCode { code: "CMR", team: Code::Country(crate::CM), historical: false },
+    "COD" => SportsCode { code: "COD", team: Code::Country(crate::CD), historical: false },
+    "COK" => SportsCode { code: "COK", team: Code::Country(crate::CK), historical: false },
+    "COL" => SportsCode { code: "COL", team: Code::Country(crate::CO), historical: false },
+    "COM" => SportsCode { code: "COM", team: Code::Country(crate::KM), historical: false },
+    "CPV" => SportsCode { code: "CPV", team: Code::Country(crate::CV), historical: false },
+    "CRC" => SportsCode { code: "CRC", team: Code::Country(crate::CR), historical: false },
+    "CRO" => SportsCode { code: "CRO", team: Code::Country(crate::HR), historical: false },
+    "CUB" => SportsCode { code: "CUB", team: Code::Country(crate::CU), historical: false },
+    "CYP" => SportsCode { code: "CYP", team: Code::Country(crate::CY), historical: false },
+    "CZE" => SportsCode { code: "CZE", team: Code::Country(crate::CZ), historical: false },
+    "DAH" => SportsCode { code: "DAH", team: Code::Former(iso3166_3::DYBJ), historical: true },
+    "DEN" => SportsCode { code: "DEN", team: Code::Country(crate::DK), historical: false },
+    "DJI" => SportsCode { code: "DJI", team: Code::Country(crate::DJ), historical: false },
+    "DMA" => SportsCode { code: "DMA", team: Code::Country(crate::DM), historical: false },
+    "DOM" => SportsCode { code: "DOM", team: Code::Country(crate::DO), historical: false },
+    "ECU" => SportsCode { code: "ECU", team: Code::Country(crate::EC), historical: false },
+    "EGY" => SportsCode { code: "EGY", team: Code::Country(crate::EG), historical: false },
+    "ERI" => SportsCode { code: "ERI", team: Code::Country(crate::ER), historical: false },
+    "ESA" => SportsCode { code: "ESA", team: Code::Country(crate::SV), historical: false },
+    "ESP" => SportsCode { code: "ESP", team: Code::Country(crate::ES), historical: false },
+    "EST" => SportsCode { code: "EST", team: Code::Country(crate::EE), historical: false },
+    "ETH" => SportsCode { code: "ETH", team: Code::Country(crate::ET), historical: false },
+    "EUN" => SportsCode { code: "EUN", team: Code::Former(iso3166_3::SUHH), historical: true },
+    "FIJ" => SportsCode { code: "FIJ", team: Code::Country(crate::FJ), historical: false },
+    "FIN" => SportsCode { code: "FIN", team: Code::Country(crate::FI), historical: false },
+    "FRA" => SportsCode { code: "FRA", team: Code::Country(crate::FR), historical: false },
+    "FRG" => SportsCode { code: "FRG", team: Code::Country(crate::DE), historical: true },
+    "FSM" => SportsCode { code: "FSM", team: Code::Country(crate::FM), historical: false },
+    "GAB" => SportsCode { code: "GAB", team: Code::Country(crate::GA), historical: false },
+    "GAM" => SportsCode { code: "GAM", team: Code::Country(crate::GM), historical: false },
+    "GBR" => SportsCode { code: "GBR", team: Code::Country(crate::GB), historical: false },
+    "GBS" => SportsCode { code: "GBS", team: Code::Country(crate::GW), historical: false },
+    "GDR" => SportsCode { code: "GDR", team: Code::Former(iso3166_3::DDDE), historical: true },
+    "GEO" => SportsCode { code: "GEO", team: Code::Country(crate::GE), historical: false },
+    "GEQ" => SportsCode { code: "GEQ", team: Code::Country(crate::GQ), historical: false },
+    "GER" => SportsCode { code: "GER", team: Code::Country(crate::DE), historical: false },
+    "GHA" => SportsCode { code: "GHA", team: Code::Country(crate::GH), historical: false },
+    "GRE" => SportsCode { code: "GRE", team: Code::Country(crate::GR), historical: false },
+    "GRN" => SportsCode { code: "GRN", team: Code::Country(crate::GD), historical: false },
+    "GUA" => SportsCode { code: "GUA", team: Code::Country(crate::GT), historical: false },
+    "GUI" => SportsCode { code: "GUI", team: Code::Country(crate::GN), historical: false },
+    "GUM" => SportsCode { code: "GUM", team: Code::Country(crate::GU), historical: false },
+    "GUY" => SportsCode { code: "GUY", team: Code::Country(crate::GY), historical: false },
+    "HAI" => SportsCode { code: "HAI", team: Code::Country(crate::HT), historical: false },
+    "HKG" => SportsCode { code: "HKG", team: Code::Country(crate::HK), historical: false },
+    "HON" => SportsCode { code: "HON", team: Code::Country(crate::HN), historical: false },
+    "HUN" => SportsCode { code: "HUN", team: Code::Country(crate::HU), historical: false },
+    "INA" => SportsCode { code: "INA", team: Code::Country(crate::ID), historical: false },
+    "IND" => SportsCode { code: "IND", team: Code::Country(crate::IN), historical: false },
+    "IRI" => SportsCode { code: "IRI", team: Code::Country(crate::IR), historical: false },
+    "IRL" => SportsCode { code: "IRL", team: Code::Country(crate::IE), historical: false },
+    "IRQ" => SportsCode { code: "IRQ", team: Code::Country(crate::IQ), historical: false },
+    "ISL" => SportsCode { code: "ISL", team: Code::Country(crate::IS), historical: false },
+    "ISR" => SportsCode { code: "ISR", team: Code::Country(crate::IL), historical: false },
+    "ISV" => SportsCode { code: "ISV", team: Code::Country(crate::VI), historical: false },
+    "ITA" => SportsCode { code: "ITA", team: Code::Country(crate::IT), historical: false },
+    "IVB" => SportsCode { code: "IVB", team: Code::Country(crate::VG), historical: false },
+    "JAM" => SportsCode { code: "JAM", team: Code::Country(crate::JM), historical: false },
+    "JOR" => SportsCode { code: "JOR", team: Code::Country(crate::JO), historical: false },
+    "JPN" => SportsCode { code: "JPN", team: Code::Country(crate::JP), historical: false },
+    "KAZ" => SportsCode { code: "KAZ", team: Code::Country(crate::KZ), historical: false },
+    "KEN" => SportsCode { code: "KEN", team: Code::Country(crate::KE), historical: false },
+    "KGZ" => SportsCode { code: "KGZ", team: Code::Country(crate::KG), historical: false },
+    "KIR" => SportsCode { code: "KIR", team: Code::Country(crate::KI), historical: false },
+    "KOR" => SportsCode { code: "KOR", team: Code::Country(crate::KR), historical: false },
+    "KSA" => SportsCode { code: "KSA", team: Code::Country(crate::SA), historical: false },
+    "KUW" => SportsCode { code: "KUW", team: Code::Country(crate::KW), historical: false },
+    "LAO" => SportsCode { code: "LAO", team: Code::Country(crate::LA), historical: false },
+    "LAT" => SportsCode { code: "LAT", team: Code::Country(crate::LV), historical: false },
+    "LBA" => SportsCode { code: "LBA", team: Code::Country(crate::LY), historical: false },
+    "LBN" => SportsCode { code: "LBN", team: Code::Country(crate::LB), historical: false },
+    "LBR" => SportsCode { code: "LBR", team: Code::Country(crate::LR), historical: false },
+    "LCA" => SportsCode { code: "LCA", team: Code::Country(crate::LC), historical: false },
+    "LES" => SportsCode { code: "LES", team: Code::Country(crate::LS), historical: false },
+    "LIE" => SportsCode { code: "LIE", team: Code::Country(crate::LI), historical: false },
+    "LTU" => SportsCode { code: "LTU", team: Code::Country(crate::LT), historical: false },
+    "LUX" => SportsCode { code: "LUX", team: Code::Country(crate::LU), historical: false },
+    "MAD" => SportsCode { code: "MAD", team: Code::Country(crate::MG), historical: false },
+    "MAR" => SportsCode { code: "MAR", team: Code::Country(crate::MA), historical: false },
+    "MAS" => SportsCode { code: "MAS", team: Code::Country(crate::MY), historical: false },
+    "MAW" => SportsCode { code: "MAW", team: Code::Country(crate::MW), historical: false },
+    "MDA" => SportsCode { code: "MDA", team: Code::Country(crate::MD), historical: false },
+    "MDV" => SportsCode { code: "MDV", team: Code::Country(crate::MV), historical: false },
+    "MEX" => SportsCode { code: "MEX", team: Code::Country(crate::MX), historical: false },
+    "MGL" => SportsCode { code: "MGL", team: Code::Country(crate::MN), historical: false },
+    "MHL" => SportsCode { code: "MHL", team: Code::Country(crate::MH), historical: false },
+    "MKD" => SportsCode { code: "MKD", team: Code::Country(crate::MK), historical: false },
+    "MLI" => SportsCode { code: "MLI", team: Code::Country(crate::ML), historical: false },
+    "MLT" => SportsCode { code: "MLT", team: Code::Country(crate::MT), historical: false },
+    "MNE" => SportsCode { code: "MNE", team: Code::Country(crate::ME), historical: false },
+    "MON" => SportsCode { code: "MON", team: Code::Country(crate::MC), historical: false },
+    "MOZ" => SportsCode { code: "MOZ", team: Code::Country(crate::MZ), historical: false },
+    "MRI" => SportsCode { code: "MRI", team: Code::Country(crate::MU), historical: false },
+    "MTN" => SportsCode { code: "MTN", team: Code::Country(crate::MR), historical: false },
+    "MYA" => SportsCode { code: "MYA", team: Code::Country(crate::MM), historical: false },
+    "NAM" => SportsCode { code: "NAM", team: Code::Country(crate::NA), historical: false },
+    "NCA" => SportsCode { code: "NCA", team: Code::Country(crate::NI), historical: false },
+    "NED" => SportsCode { code: "NED", team: Code::Country(crate::NL), historical: false },
+    "NEP" => SportsCode { code: "NEP", team: Code::Country(crate::NP), historical: false },
+    "NGR" => SportsCode { code: "NGR", team: Code::Country(crate::NG), historical: false },
+    "NIG" => SportsCode { code: "NIG", team: Code::Country(crate::NE), historical: false },
+    "NOR" => SportsCode { code: "NOR", team: Code::Country(crate::NO), historical: false },
+    "NRU" => SportsCode { code: "NRU", team: Code::Country(crate::NR), historical: false },
+    "NZL" => SportsCode { code: "NZL", team: Code::Country(crate::NZ), historical: false },
+    "OMA" => SportsCode { code: "OMA", team: Code::Country(crate::OM), historical: false },
+    "PAK" => SportsCode { code: "PAK", team: Code::Country(crate::PK), historical: false },
+    "PAN" => SportsCode { code: "PAN", team: Code::Country(crate::PA), historical: false },
+    "PAR" => SportsCode { code: "PAR", team: Code::Country(crate::PY), historical: false },
+    "PER" => SportsCode { code: "PER", team: Code::Country(crate::PE), historical: false },
+    "PHI" => SportsCode { code: "PHI", team: Code::Country(crate::PH), historical: false },
+    "PLE" => SportsCode { code: "PLE", team: Code::Country(crate::PS), historical: false },
+    "PLW" => SportsCode { code: "PLW", team: Code::Country(crate::PW), historical: false },
+    "PNG" => SportsCode { code: "PNG", team: Code::Country(crate::PG), historical: false },
+    "POL" => SportsCode { code: "POL", team: Code::Country(crate::PL), historical: false },
+    "POR" => SportsCode { code: "POR", team: Code::Country(crate::PT), historical: false },
+    "PRK" => SportsCode { code: "PRK", team: Code::Country(crate::KP), historical: false },
+    "PUR" => SportsCode { code: "PUR", team: Code::Country(crate::PR), historical: false },
+    "QAT" => SportsCode { code: "QAT", team: Code::Country(crate::QA), historical: false },
+    "RHO" => SportsCode { code: "RHO", team: Code::Former(iso3166_3::RHZW), historical: true },
+    "ROU" => SportsCode { code: "ROU", team: Code::Country(crate::RO), historical: false },
+    "RSA" => SportsCode { code: "RSA", team: Code::Country(crate::ZA), historical: false },
+    "RUS" => SportsCode { code: "RUS", team: Code::Country(crate::RU), historical: false },
+    "RWA" => SportsCode { code: "RWA", team: Code::Country(crate::RW), historical: false },
+    "SAM" => SportsCode { code: "SAM", team: Code::Country(crate::WS), historical: false },
+    "SCG" => SportsCode { code: "SCG", team: Code::Former(iso3166_3::CSXX), historical: true },
+    "SEN" => SportsCode { code: "SEN", team: Code::Country(crate::SN), historical: false },
+    "SEY" => SportsCode { code: "SEY", team: Code::Country(crate::SC), historical: false },
+    "SGP" => SportsCode { code: "SGP", team: Code::Country(crate::SG), historical: false },
+    "SKN" => SportsCode { code: "SKN", team: Code::Country(crate::KN), historical: false },
+    "SLE" => SportsCode { code: "SLE", team: Code::Country(crate::SL), historical: false },
+    "SLO" => SportsCode { code: "SLO", team: Code::Country(crate::SI), historical: false },
+    "SMR" => SportsCode { code: "SMR", team: Code::Country(crate::SM), historical: false },
+    "SOL" => SportsCode { code: "SOL", team: Code::Country(crate::SB), historical: false },
+    "SOM" => SportsCode { code: "SOM", team: Code::Country(crate::SO), historical: false },
+    "SRB" => SportsCode { code: "SRB", team: Code::Country(crate::RS), historical: false },
+    "SRI" => SportsCode { code: "SRI", team: Code::Country(crate::LK), historical: false },
+    "SSD" => SportsCode { code: "SSD", team: Code::Country(crate::SS), historical: false },
+    "STP" => SportsCode { code: "STP", team: Code::Country(crate::ST), historical: false },
+    "SUD" => SportsCode { code: "SUD", team: Code::Country(crate::SD), historical: false },
+    "SUI" => SportsCode { code: "SUI", team: Code::Country(crate::CH), historical: false },
+    "SUR" => SportsCode { code: "SUR", team: Code::Country(crate::SR), historical: false },
+    "SVK" => SportsCode { code: "SVK", team: Code::Country(crate::SK), historical: false },
+    "SWE" => SportsCode { code: "SWE", team: Code::Country(crate::SE), historical: false },
+    "SWZ" => SportsCode { code: "SWZ", team: Code::Country(crate::SZ), historical: false },
+    "SYR" => SportsCode { code: "SYR", team: Code::Country(crate::SY), historical: false },
+    "TAN" => SportsCode { code: "TAN", team: Code::Country(crate::TZ), historical: false },
+    "TCH" => SportsCode { code: "TCH", team: Code::Former(iso3166_3::CSHH), historical: true },
+    "TGA" => SportsCode { code: "TGA", team: Code::Country(crate::TO), historical: false },
+    "THA" => SportsCode { code: "THA", team: Code::Country(crate::TH), historical: false },
+    "TJK" => SportsCode { code: "TJK", team: Code::Country(crate::TJ), historical: false },
+    "TKM" => SportsCode { code: "TKM", team: Code::Country(crate::TM), historical: false },
+    "TLS" => SportsCode { code: "TLS", team: Code::Country(crate::TL), historical: false },
+    "TOG" => SportsCode { code: "TOG", team: Code::Country(crate::TG), historical: false },
+    "TPE" => SportsCode { code: "TPE", team: Code::Country(crate::TW), historical: false },
+    "TTO" => SportsCode { code: "TTO", team: Code::Country(crate::TT), historical: false },
+    "TUN" => SportsCode { code: "TUN", team: Code::Country(crate::TN), historical: false },
+    "TUR" => SportsCode { code: "TUR", team: Code::Country(crate::TR), historical: false },
+    "TUV" => SportsCode { code: "TUV", team: Code::Country(crate::TV), historical: false },
+    "UAE" => SportsCode { code: "UAE", team: Code::Country(crate::AE), historical: false },
+    "UGA" => SportsCode { code: "UGA", team: Code::Country(crate::UG), historical: false },
+    "UKR" => SportsCode { code: "UKR", team: Code::Country(crate::UA), historical: false },
+    "URS" => SportsCode { code: "URS", team: Code::Former(iso3166_3::SUHH), historical: true },
+    "URU" => SportsCode { code: "URU", team: Code::Country(crate::UY), historical: false },
+    "USA" => SportsCode { code: "USA", team: Code::Country(crate::US), historical: false },
+    "UZB" => SportsCode { code: "UZB", team: Code::Country(crate::UZ), historical: false },
+    "VAN" => SportsCode { code: "VAN", team: Code::Country(crate::VU), historical: false },
+    "VEN" => SportsCode { code: "VEN", team: Code::Country(crate::VE), historical: false },
+    "VIE" => SportsCode { code: "VIE", team: Code::Country(crate::VN), historical: false },
+    "VIN" => SportsCode { code: "VIN", team: Code::Country(crate::VC), historical: false },
+    "VOL" => SportsCode { code: "VOL", team: Code::Former(iso3166_3::HVBF), historical: true },
+    "YAR" => SportsCode { code: "YAR", team: Code::Country(crate::YE), historical: true },
+    "YEM" => SportsCode { code: "YEM", team: Code::Country(crate::YE), historical: false },
+    "YMD" => SportsCode { code: "YMD", team: Code::Former(iso3166_3::YDYE), historical: true },
+    "YUG" => SportsCode { code: "YUG", team: Code::Former(iso3166_3::YUCS), historical: true },
+    "ZAI" => SportsCode { code: "ZAI", team: Code::Former(iso3166_3::ZRCD), historical: true },
+    "ZAM" => SportsCode { code: "ZAM", team: Code::Country(crate::ZM), historical: false },
+    "ZIM" => SportsCode { code: "ZIM", team: Code::Country(crate::ZW), historical: false },
+};
+
+/// Current International Olympic Committee codes by alpha-2 or ISO 3166-2 code.
+pub const IOC_TEAM_MAP: Map<&str, &str> = phf_map! {
+    "AD" => "AND",
+    "AE" => "UAE",
+    "AF" => "AFG",
+    "AG" => "ANT",
+    "AL" => "ALB",
+    "AM" => "ARM",
+    "AO" => "ANG",
+    "AR" => "ARG",
+    "AS" => "ASA",
+    "AT" => "AUT",
+    "AU" => "AUS",
+    "AW" => "ARU",
+    "AZ" => "AZE",
+    "BA" => "BIH",
+    "BB" => "BAR",
+    "BD" => "BAN",
+    "BE" => "BEL",
+    "BF" => "BUR",
+    "BG" => "BUL",
+    "BH" => "BRN",
+    "BI" => "BDI",
+    "BJ" => "BEN",
+    "BM" => "BER",
+    "BN" => "BRU",
+    "BO" => "BOL",
+    "BR" => "BRA",
+    "BS" => "BAH",
+    "BT" => "BHU",
+    "BW" => "BOT",
+    "BY" => "BLR",
+    "BZ" => "BIZ",
+    "CA" => "CAN",
+    "CD" => "COD",
+    "CF" => "CAF",
+    "CG" => "CGO",
+    "CH" => "SUI",
+    "CI" => "CIV",
+    "CK" => "COK",
+    "CL" => "CHI",
+    "CM" => "CMR",
+    "CN" => "CHN",
+    "CO" => "COL",
+    "CR" => "CRC",
+    "CU" => "CUB",
+    "CV" => "CPV",
+    "CY" => "CYP",
+    "CZ" => "CZE",
+    "DE" => "GER",
+    "DJ" => "DJI",
+    "DK" => "DEN",
+    "DM" => "DMA",
+    "DO" => "DOM",
+    "DZ" => "ALG",
+    "EC" => "ECU",
+    "EE" => "EST",
+    "EG" => "EGY",
+    "ER" => "ERI",
+    "ES" => "ESP",
+    "ET" => "ETH",
+    "FI" => "FIN",
+    "FJ" => "FIJ",
+    "FM" => "FSM",
+    "FR" => "FRA",
+    "GA" => "GAB",
+    "GB" => "GBR",
+    "GD" => "GRN",
+    "GE" => "GEO",
+    "GH" => "GHA",
+    "GM" => "GAM",
+    "GN" => "GUI",
+    "GQ" => "GEQ",
+    "GR" => "GRE",
+    "GT" => "GUA",
+    "GU" => "GUM",
+    "GW" => "GBS",
+    "GY" => "GUY",
+    "HK" => "HKG",
+    "HN" => "HON",
+    "HR" => "CRO",
+    "HT" => "HAI",
+    "HU" => "HUN",
+    "ID" => "INA",
+    "IE" => "IRL",
+    "IL" => "ISR",
+    "IN" => "IND",
+    "IQ" => "IRQ",
+    "IR" => "IRI",
+    "IS" => "ISL",
+    "IT" => "ITA",
+    "JM" => "JAM",
+    "JO" => "JOR",
+    "JP" => "JPN",
+    "KE" => "KEN",
+    "KG" => "KGZ",
+    "KH" => "CAM",
+    "KI" => "KIR",
+    "KM" => "COM",
+    "KN" => "SKN",
+    "KP" => "PRK",
+    "KR" => "KOR",
+    "KW" => "KUW",
+    "KY" => "CAY",
+    "KZ" => "KAZ",
+    "LA" => "LAO",
+    "LB" => "LBN",
+    "LC" => "LCA",
+    "LI" => "LIE",
+    "LK" => "SRI",
+    "LR" => "LBR",
+    "LS" => "LES",
+    "LT" => "LTU",
+    "LU" => "LUX",
+    "LV" => "LAT",
+    "LY" => "LBA",
+    "MA" => "MAR",
+    "MC" => "MON",
+    "MD" => "MDA",
+    "ME" => "MNE",
+    "MG" => "MAD",
+    "MH" => "MHL",
+    "MK" => "MKD",
+    "ML" => "MLI",
+    "MM" => "MYA",
+    "MN" => "MGL",
+    "MR" => "MTN",
+    "MT" => "MLT",
+    "MU" => "MRI",
+    "MV" => "MDV",
+    "MW" => "MAW",
+    "MX" => "MEX",
+    "MY" => "MAS",
+    "MZ" => "MOZ",
+    "NA" => "NAM",
+    "NE" => "NIG",
+    "NG" => "NGR",
+    "NI" => "NCA",
+    "NL" => "NED",
+    "NO" => "NOR",
+    "NP" => "NEP",
+    "NR" => "NRU",
+    "NZ" => "NZL",
+    "OM" => "OMA",
+    "PA" => "PAN",
+    "PE" => "PER",
+    "PG" => "PNG",
+    "PH" => "PHI",
+    "PK" => "PAK",
+    "PL" => "POL",
+    "PR" => "PUR",
+    "PS" => "PLE",
+    "PT" => "POR",
+    "PW" => "PLW",
+    "PY" => "PAR",
+    "QA" => "QAT",
+    "RO" => "ROU",
+    "RS" => "SRB",
+    "RU" => "RUS",
+    "RW" => "RWA",
+    "SA" => "KSA",
+    "SB" => "SOL",
+    "SC" => "SEY",
+    "SD" => "SUD",
+    "SE" => "SWE",
+    "SG" => "SGP",
+    "SI" => "SLO",
+    "SK" => "SVK",
+    "SL" => "SLE",
+    "SM" => "SMR",
+    "SN" => "SEN",
+    "SO" => "SOM",
+    "SR" => "SUR",
+    "SS" => "SSD",
+    "ST" => "STP",
+    "SV" => "ESA",
+    "SY" => "SYR",
+    "SZ" => "SWZ",
+    "TD" => "CHA",
+    "TG" => "TOG",
+    "TH" => "THA",
+    "TJ" => "TJK",
+    "TL" => "TLS",
+    "TM" => "TKM",
+    "TN" => "TUN",
+    "TO" => "TGA",
+    "TR" => "TUR",
+    "TT" => "TTO",
+    "TV" => "TUV",
+    "TW" => "TPE",
+    "TZ" => "TAN",
+    "UA" => "UKR",
+    "UG" => "UGA",
+    "US" => "USA",
+    "UY" => "URU",
+    "UZ" => "UZB",
+    "VC" => "VIN",
+    "VE" => "VEN",
+    "VG" => "IVB",
+    "VI" => "ISV",
+    "VN" => "VIE",
+    "VU" => "VAN",
+    "WS" => "SAM",
+    "YE" => "YEM",
+    "ZA" => "RSA",
+    "ZM" => "ZAM",
+    "ZW" => "ZIM",
+};
+
+/// FIFA codes.
+pub const FIFA_MAP: Map<&str, SportsCode> = phf_map! {
+    "AFG" => SportsCode { code: "AFG", team: Code::Country(crate::AF), historical: false },
+    "AIA" => SportsCode { code: "AIA", team: Code::Country(crate::AI), historical: false },
+    "ALB" => SportsCode { code: "ALB", team: Code::Country(crate::AL), historical: false },
+    "ALG" => SportsCode { code: "ALG", team: Code::Country(crate::DZ), historical: false },
+    "AND" => SportsCode { code: "AND", team: Code::Country(crate::AD), historical: false },
+    "ANG" => SportsCode { code: "ANG", team: Code::Country(crate::AO), historical: false },
+    "ANT" => SportsCode { code: "ANT", team: Code::Former(iso3166_3::ANHH), historical: true },
+    "ARG" => SportsCode { code: "ARG", team: Code::Country(crate::AR), historical: false },
+    "ARM" => SportsCode { code: "ARM", team: Code::Country(crate::AM), historical: false },
+    "ARU" => SportsCode { code: "ARU", team: Code::Country(crate::AW), historical: false },
+    "ASA" => SportsCode { code: "ASA", team: Code::Country(crate::AS), historical: false },
+    "ATG" => SportsCode { code: "ATG", team: Code::Country(crate::AG), historical: false },
+    "AUS" => SportsCode { code: "AUS", team: Code::Country(crate::AU), historical: false },
+    "AUT" => SportsCode { code: "AUT", team: Code::Country(crate::AT), historical: false },
+    "AZE" => SportsCode { code: "AZE", team: Code::Country(crate::AZ), historical: false },
+    "BAH" => SportsCode { code: "BAH", team: Code::Country(crate::BS), historical: false },
+    "BAN" => SportsCode { code: "BAN", team: Code::Country(crate::BD), historical: false },
+    "BDI" => SportsCode { code: "BDI", team: Code::Country(crate::BI), historical: false },
+    "BEL" => SportsCode { code: "BEL", team: Code::Country(crate::BE), historical: false },
+    "BEN" => SportsCode { code: "BEN", team: Code::Country(crate::BJ), historical: false },
+    "BER" => SportsCode { code: "BER", team: Code::Country(crate::BM), historical: false },
+    "BFA" => SportsCode { code: "BFA", team: Code::Country(crate::BF), historical: false },
+    "BHR" => SportsCode { code: "BHR", team: Code::Country(crate::BH), historical: false },
+    "BHU" => SportsCode { code: "BHU", team: Code::Country(crate::BT), historical: false },
+    "BIH" => SportsCode { code: "BIH", team: Code::Country(crate::BA), historical: false },
+    "BLR" => SportsCode { code: "BLR", team: Code::Country(crate::BY), historical: false },
+    "BLZ" => SportsCode { code: "BLZ", team: Code::Country(crate::BZ), historical: false },
+    "BOL" => SportsCode { code: "BOL", team: Code::Country(crate::BO), historical: false },
+    "BOT" => SportsCode { code: "BOT", team: Code::Country(crate::BW), historical: false },
+    "BRA" => SportsCode { code: "BRA", team: Code::Country(crate::BR), historical: false },
+    "BRB" => SportsCode { code: "BRB", team: Code::Country(crate::BB), historical: false },
+    "BRU" => SportsCode { code: "BRU", team: Code::Country(crate::BN), historical: false },
+    "BUL" => SportsCode { code: "BUL", team: Code::Country(crate::BG), historical: false },
+    "CAM" => SportsCode { code: "CAM", team: Code::Country(crate::KH), historical: false },
+    "CAN" => SportsCode { code: "CAN", team: Code::Country(crate::CA), historical: false },
+    "CAY" => SportsCode { code: "CAY", team: Code::Country(crate::KY), historical: false },
+    "CGO" => SportsCode { code: "CGO", team: Code::Country(crate::CG), historical: false },
+    "CHA" => SportsCode { code: "CHA", team: Code::Country(crate::TD), historical: false },
+    "CHI" => SportsCode { code: "CHI", team: Code::Country(crate::CL), historical: false },
+    "CHN" => SportsCode { code: "CHN", team: Code::Country(crate::CN), historical: false },
+    "CIV" => SportsCode { code: "CIV", team: Code::Country(crate::CI), historical: false },
+    "CMR" => SportsCode { code: "CMR", team: Code::Country(crate::CM), historical: false },
+    "COD" => SportsCode { code: "COD", team: Code::Country(crate::CD), historical: false },
+    "COK" => SportsCode { code: "COK", team: Code::Country(crate::CK), historical: false },
+    "COL" => SportsCode { code: "COL", team: Code::Country(crate::CO), historical: false },
+    "COM" => SportsCode { code: "COM", team: Code::Country(crate::KM), historical: false },
+    "CPV" => SportsCode { code: "CPV", team: Code::Country(crate::CV), historical: false },
+    "CRC" => SportsCode { code: "CRC", team: Code::Country(crate::CR), historical: false },
+    "CRO" => SportsCode { code: "CRO", team: Code::Country(crate::HR), historical: false },
+    "CTA" => SportsCode { code: "CTA", team: Code::Country(crate::CF), historical: false },
+    "CUB" => SportsCode { code: "CUB", team: Code::Country(crate::CU), historical: false },
+    "CUW" => SportsCode { code: "CUW", team: Code::Country(crate::CW), historical: false },
+    "CYP" => SportsCode { code: "CYP", team: Code::Country(crate::CY), historical: false },
+    "CZE" => SportsCode { code: "CZE", team: Code::Country(crate::CZ), historical: false },
+    "DEN" => SportsCode { code: "DEN", team: Code::Country(crate::DK), historical: false },
+    "DJI" => SportsCode { code: "DJI", team: Code::Country(crate::DJ), historical: false },
+    "DMA" => SportsCode { code: "DMA", team: Code::Country(crate::DM), historical: false },
+    "DOM" => SportsCode { code: "DOM", team: Code::Country(crate::DO), historical: false },
+    "ECU" => SportsCode { code: "ECU", team: Code::Country(crate::EC), historical: false },
+    "EGY" => SportsCode { code: "EGY", team: Code::Country(crate::EG), historical: false },
+    "ENG" => SportsCode { code: "ENG", team: Code::Subdivision(iso3166_2::GB_ENG), historical: false },
+    "EQG" => SportsCode { code: "EQG", team: Code::Country(crate::GQ), historical: false },
+    "ERI" => SportsCode { code: "ERI", team: Code::Country(crate::ER), historical: false },
+    "ESP" => SportsCode { code: "ESP", team: Code::Country(crate::ES), historical: false },
+    "EST" => SportsCode { code: "EST", team: Code::Country(crate::EE), historical: false },
+    "ETH" => SportsCode { code: "ETH", team: Code::Country(crate::ET), historical: false },
+    "FIJ" => SportsCode { code: "FIJ", team: Code::Country(crate::FJ), historical: false },
+    "FIN" => SportsCode { code: "FIN", team: Code::Country(crate::FI), historical: false },
+    "FRA" => SportsCode { code: "FRA", team: Code::Country(crate::FR), historical: false },
+    "FRG" => SportsCode { code: "FRG", team: Code::Country(crate::DE), historical: true },
+    "FRO" => SportsCode { code: "FRO", team: Code::Country(crate::FO), historical: false },
+    "GAB" => SportsCode { code: "GAB", team: Code::Country(crate::GA), historical: false },
+    "GAM" => SportsCode { code: "GAM", team: Code::Country(crate::GM), historical: false },
+    "GDR" => SportsCode { code: "GDR", team: Code::Former(iso3166_3::DDDE), historical: true },
+    "GEO" => SportsCode { code: "GEO", team: Code::Country(crate::GE), historical: false },
+    "GER" => SportsCode { code: "GER", team: Code::Country(crate::DE), historical: false },
+    "GHA" => SportsCode { code: "GHA", team: Code::Country(crate::GH), historical: false },
+    "GIB" => SportsCode { code: "GIB", team: Code::Country(crate::GI), historical: false },
+    "GNB" => SportsCode { code: "GNB", team: Code::Country(crate::GW), historical: false },
+    "GRE" => SportsCode { code: "GRE", team: Code::Country(crate::GR), historical: false },
+    "GRN" => SportsCode { code: "GRN", team: Code::Country(crate::GD), historical: false },
+    "GUA" => SportsCode { code: "GUA", team: Code::Country(crate::GT), historical: false },
+    "GUI" => SportsCode { code: "GUI", team: Code::Country(crate::GN), historical: false },
+    "GUM" => SportsCode { code: "GUM", team: Code::Country(crate::GU), historical: false },
+    "GUY" => SportsCode { code: "GUY", team: Code::Country(crate::GY), historical: false },
+    "HAI" => SportsCode { code: "HAI", team: Code::Country(crate::HT), historical: false },
+    "HKG" => SportsCode { code: "HKG", team: Code::Country(crate::HK), historical: false },
+    "HON" => SportsCode { code: "HON", team: Code::Country(crate::HN), historical: false },
+    "HUN" => SportsCode { code: "HUN", team: Code::Country(crate::HU), historical: false },
+    "IDN" => SportsCode { code: "IDN", team: Code::Country(crate::ID), historical: false },
+    "IND" => SportsCode { code: "IND", team: Code::Country(crate::IN), historical: false },
+    "IRL" => SportsCode { code: "IRL", team: Code::Country(crate::IE), historical: false },
+    "IRN" => SportsCode { code: "IRN", team: Code::Country(crate::IR), historical: false },
+    "IRQ" => SportsCode { code: "IRQ", team: Code::Country(crate::IQ), historical: false },
+    "ISL" => SportsCode { code: "ISL", team: Code::Country(crate::IS), historical: false },
+    "ISR" => SportsCode { code: "ISR", team: Code::Country(crate::IL), historical: false },
+    "ITA" => SportsCode { code: "ITA", team: Code::Country(crate::IT), historical: false },
+    "JAM" => SportsCode { code: "JAM", team: Code::Country(crate::JM), historical: false },
+    "JOR" => SportsCode { code: "JOR", team: Code::Country(crate::JO), historical: false },
+    "JPN" => SportsCode { code: "JPN", team: Code::Country(crate::JP), historical: false },
+    "KAZ" => SportsCode { code: "KAZ", team: Code::Country(crate::KZ), historical: false },
+    "KEN" => SportsCode { code: "KEN", team: Code::Country(crate::KE), historical: false },
+    "KGZ" => SportsCode { code: "KGZ", team: Code::Country(crate::KG), historical: false },
+    "KOR" => SportsCode { code: "KOR", team: Code::Country(crate::KR), historical: false },
+    "KSA" => SportsCode { code: "KSA", team: Code::Country(crate::SA), historical: false },
+    "KUW" => SportsCode { code: "KUW", team: Code::Country(crate::KW), historical: false },
+    "LAO" => SportsCode { code: "LAO", team: Code::Country(crate::LA), historical: false },
+    "LBN" => SportsCode { code: "LBN", team: Code::Country(crate::LB), historical: false },
+    "LBR" => SportsCode { code: "LBR", team: Code::Country(crate::LR), historical: false },
+    "LBY" => SportsCode { code: "LBY", team: Code::Country(crate::LY), historical: false },
+    "LCA" => SportsCode { code: "LCA", team: Code::Country(crate::LC), historical: false },
+    "LES" => SportsCode { code: "LES", team: Code::Country(crate::LS), historical: false },
+    "LIE" => SportsCode { code: "LIE", team: Code::Country(crate::LI), historical: false },
+    "LTU" => SportsCode { code: "LTU", team: Code::Country(crate::LT), historical: false },
+    "LUX" => SportsCode { code: "LUX", team: Code::Country(crate::LU), historical: false },
+    "LVA" => SportsCode { code: "LVA", team: Code::Country(crate::LV), historical: false },
+    "MAC" => SportsCode { code: "MAC", team: Code::Country(crate::MO), historical: false },
+    "MAD" => SportsCode { code: "MAD", team: Code::Country(crate::MG), historical: false },
+    "MAR" => SportsCode { code: "MAR", team: Code::Country(crate::MA), historical: false },
+    "MAS" => SportsCode { code: "MAS", team: Code::Country(crate::MY), historical: false },
+    "MDA" => SportsCode { code: "MDA", team: Code::Country(crate::MD), historical: false },
+    "MDV" => SportsCode { code: "MDV", team: Code::Country(crate::MV), historical: false },
+    "MEX" => SportsCode { code: "MEX", team: Code::Country(crate::MX), historical: false },
+    "MKD" => SportsCode { code: "MKD", team: Code::Country(crate::MK), historical: false },
+    "MLI" => SportsCode { code: "MLI", team: Code::Country(crate::ML), historical: false },
+    "MLT" => SportsCode { code: "MLT", team: Code::Country(crate::MT), historical: false },
+    "MNE" => SportsCode { code: "MNE", team: Code::Country(crate::ME), historical: false },
+    "MNG" => SportsCode { code: "MNG", team: Code::Country(crate::MN), historical: false },
+    "MOZ" => SportsCode { code: "MOZ", team: Code::Country(crate::MZ), historical: false },
+    "MRI" => SportsCode { code: "MRI", team: Code::Country(crate::MU), historical: false },
+    "MSR" => SportsCode { code: "MSR", team: Code::Country(crate::MS), historical: false },
+    "MTN" => SportsCode { code: "MTN", team: Code::Country(crate::MR), historical: false },
+    "MWI" => SportsCode { code: "MWI", team: Code::Country(crate::MW), historical: false },
+    "MYA" => SportsCode { code: "MYA", team: Code::Country(crate::MM), historical: false },
+    "NAM" => SportsCode { code: "NAM", team: Code::Country(crate::NA), historical: false },
+    "NCA" => SportsCode { code: "NCA", team: Code::Country(crate::NI), historical: false },
+    "NCL" => SportsCode { code: "NCL", team: Code::Country(crate::NC), historical: false },
+    "NED" => SportsCode { code: "NED", team: Code::Country(crate::NL), historical: false },
+    "NEP" => SportsCode { code: "NEP", team: Code::Country(crate::NP), historical: false },
+    "NGA" => SportsCode { code: "NGA", team: Code::Country(crate::NG), historical: false },
+    "NIG" => SportsCode { code: "NIG", team: Code::Country(crate::NE), historical: false },
+    "NIR" => SportsCode { code: "NIR", team: Code::Subdivision(iso3166_2::GB_NIR), historical: false },
+    "NOR" => SportsCode { code: "NOR", team: Code::Country(crate::NO), historical: false },
+    "NZL" => SportsCode { code: "NZL", team: Code::Country(crate::NZ), historical: false },
+    "OMA" => SportsCode { code: "OMA", team: Code::Country(crate::OM), historical: false },
+    "PAK" => SportsCode { code: "PAK", team: Code::Country(crate::PK), historical: false },
+    "PAN" => SportsCode { code: "PAN", team: Code::Country(crate::PA), historical: false },
+    "PAR" => SportsCode { code: "PAR", team: Code::Country(crate::PY), historical: false },
+    "PER" => SportsCode { code: "PER", team: Code::Country(crate::PE), historical: false },
+    "PHI" => SportsCode { code: "PHI", team: Code::Country(crate::PH), historical: false },
+    "PLE" => SportsCode { code: "PLE", team: Code::Country(crate::PS), historical: false },
+    "PNG" => SportsCode { code: "PNG", team: Code::Country(crate::PG), historical: false },
+    "POL" => SportsCode { code: "POL", team: Code::Country(crate::PL), historical: false },
+    "POR" => SportsCode { code: "POR", team: Code::Country(crate::PT), historical: false },
+    "PRK" => SportsCode { code: "PRK", team: Code::Country(crate::KP), historical: false },
+    "PUR" => SportsCode { code: "PUR", team: Code::Country(crate::PR), historical: false },
+    "QAT" => SportsCode { code: "QAT", team: Code::Country(crate::QA), historical: false },
+    "ROU" => SportsCode { code: "ROU", team: Code::Country(crate::RO), historical: false },
+    "RSA" => SportsCode { code: "RSA", team: Code::Country(crate::ZA), historical: false },
+    "RUS" => SportsCode { code: "RUS", team: Code::Country(crate::RU), historical: false },
+    "RWA" => SportsCode { code: "RWA", team: Code::Country(crate::RW), historical: false },
+    "SAM" => SportsCode { code: "SAM", team: Code::Country(crate::WS), historical: false },
+    "SCG" => SportsCode { code: "SCG", team: Code::Former(iso3166_3::CSXX), historical: true },
+    "SCO" => SportsCode { code: "SCO", team: Code::Subdivision(iso3166_2::GB_SCT), historical: false },
+    "SDN" => SportsCode { code: "SDN", team: Code::Country(crate::SD), historical: false },
+    "SEN" => SportsCode { code: "SEN", team: Code::Country(crate::SN), historical: false },
+    "SEY" => SportsCode { code: "SEY", team: Code::Country(crate::SC), historical: false },
+    "SIN" => SportsCode { code: "SIN", team: Code::Country(crate::SG), historical: false },
+    "SKN" => SportsCode { code: "SKN", team: Code::Country(crate::KN), historical: false },
+    "SLE" => SportsCode { code: "SLE", team: Code::Country(crate::SL), historical: false },
+    "SLV" => SportsCode { code: "SLV", team: Code::Country(crate::SV), historical: false },
+    "SMR" => SportsCode { code: "SMR", team: Code::Country(crate::SM), historical: false },
+    "SOL" => SportsCode { code: "SOL", team: Code::Country(crate::SB), historical: false },
+    "SOM" => SportsCode { code: "SOM", team: Code::Country(crate::SO), historical: false },
+    "SRB" => SportsCode { code: "SRB", team: Code::Country(crate::RS), historical: false },
+    "SRI" => SportsCode { code: "SRI", team: Code::Country(crate::LK), historical: false },
+    "SSD" => SportsCode { code: "SSD", team: Code::Country(crate::SS), historical: false },
+    "STP" => SportsCode { code: "STP", team: Code::Country(crate::ST), historical: false },
+    "SUI" => SportsCode { code: "SUI", team: Code::Country(crate::CH), historical: false },
+    "SUR" => SportsCode { code: "SUR", team: Code::Country(crate::SR), historical: false },
+    "SVK" => SportsCode { code: "SVK", team: Code::Country(crate::SK), historical: false },
+    "SVN" => SportsCode { code: "SVN", team: Code::Country(crate::SI), historical: false },
+    "SWE" => SportsCode { code: "SWE", team: Code::Country(crate::SE), historical: false },
+    "SWZ" => SportsCode { code: "SWZ", team: Code::Country(crate::SZ), historical: false },
+    "SYR" => SportsCode { code: "SYR", team: Code::Country(crate::SY), historical: false },
+    "TAH" => SportsCode { code: "TAH", team: Code::Country(crate::PF), historical: false },
+    "TAN" => SportsCode { code: "TAN", team: Code::Country(crate::TZ), historical: false },
+    "TCA" => SportsCode { code: "TCA", team: Code::Country(crate::TC), historical: false },
+    "TCH" => SportsCode { code: "TCH", team: Code::Former(iso3166_3::CSHH), historical: true },
+    "TGA" => SportsCode { code: "TGA", team: Code::Country(crate::TO), historical: false },
+    "THA" => SportsCode { code: "THA", team: Code::Country(crate::TH), historical: false },
+    "TJK" => SportsCode { code: "TJK", team: Code::Country(crate::TJ), historical: false },
+    "TKM" => SportsCode { code: "TKM", team: Code::Country(crate::TM), historical: false },
+    "TLS" => SportsCode { code: "TLS", team: Code::Country(crate::TL), historical: false },
+    "TOG" => SportsCode { code: "TOG", team: Code::Country(crate::TG), historical: false },
+    "TPE" => SportsCode { code: "TPE", team: Code::Country(crate::TW), historical: false },
+    "TRI" => SportsCode { code: "TRI", team: Code::Country(crate::TT), historical: false },
+    "TUN" => SportsCode { code: "TUN", team: Code::Country(crate::TN), historical: false },
+    "TUR" => SportsCode { code: "TUR", team: Code::Country(crate::TR), historical: false },
+    "UAE" => SportsCode { code: "UAE", team: Code::Country(crate::AE), historical: false },
+    "UGA" => SportsCode { code: "UGA", team: Code::Country(crate::UG), historical: false },
+    "UKR" => SportsCode { code: "UKR", team: Code::Country(crate::UA), historical: false },
+    "URS" => SportsCode { code: "URS", team: Code::Former(iso3166_3::SUHH), historical: true },
+    "URU" => SportsCode { code: "URU", team: Code::Country(crate::UY), historical: false },
+    "USA" => SportsCode { code: "USA", team: Code::Country(crate::US), historical: false },
+    "UZB" => SportsCode { code: "UZB", team: Code::Country(crate::UZ), historical: false },
+    "VAN" => SportsCode { code: "VAN", team: Code::Country(crate::VU), historical: false },
+    "VEN" => SportsCode { code: "VEN", team: Code::Country(crate::VE), historical: false },
+    "VGB" => SportsCode { code: "VGB", team: Code::Country(crate::VG), historical: false },
+    "VIE" => SportsCode { code: "VIE", team: Code::Country(crate::VN), historical: false },
+    "VIN" => SportsCode { code: "VIN", team: Code::Country(crate::VC), historical: false },
+    "VIR" => SportsCode { code: "VIR", team: Code::Country(crate::VI), historical: false },
+    "WAL" => SportsCode { code: "WAL", team: Code::Subdivision(iso3166_2::GB_WLS), historical: false },
+    "YEM" => SportsCode { code: "YEM", team: Code::Country(crate::YE), historical: false },
+    "YUG" => SportsCode { code: "YUG", team: Code::Former(iso3166_3::YUCS), historical: true },
+    "ZAI" => SportsCode { code: "ZAI", team: Code::Former(iso3166_3::ZRCD), historical: true },
+    "ZAM" => SportsCode { code: "ZAM", team: Code::Country(crate::ZM), historical: false },
+    "ZIM" => SportsCode { code: "ZIM", team: Code::Country(crate::ZW), historical: false },
+};
+
+/// Current FIFA codes by alpha-2 or ISO 3166-2 code.
+pub const FIFA_TEAM_MAP: Map<&str, &str> = phf_map! {
+    "AD" => "AND",
+    "AE" => "UAE",
+    "AF" => "AFG",
+    "AG" => "ATG",
+    "AI" => "AIA",
+    "AL" => "ALB",
+    "AM" => "ARM",
+    "AO" => "ANG",
+    "AR" => "ARG",
+    "AS" => "ASA",
+    "AT" => "AUT",
+    "AU" => "AUS",
+    "AW" => "ARU",
+    "AZ" => "AZE",
+    "BA" => "BIH",
+    "BB" => "BRB",
+    "BD" => "BAN",
+    "BE" => "BEL",
+    "BF" => "BFA",
+    "BG" => "BUL",
+    "BH" => "BHR",
+    "BI" => "BDI",
+    "BJ" => "BEN",
+    "BM" => "BER",
+    "BN" => "BRU",
+    "BO" => "BOL",
+    "BR" => "BRA",
+    "BS" => "BAH",
+    "BT" => "BHU",
+    "BW" => "BOT",
+    "BY" => "BLR",
+    "BZ" => "BLZ",
+    "CA" => "CAN",
+    "CD" => "COD",
+    "CF" => "CTA",
+    "CG" => "CGO",
+    "CH" => "SUI",
+    "CI" => "CIV",
+    "CK" => "COK",
+    "CL" => "CHI",
+    "CM" => "CMR",
+    "CN" => "CHN",
+    "CO" => "COL",
+    "CR" => "CRC",
+    "CU" => "CUB",
+    "CV" => "CPV",
+    "CW" => "CUW",
+    "CY" => "CYP",
+    "CZ" => "CZE",
+    "DE" => "GER",
+    "DJ" => "DJI",
+    "DK" => "DEN",
+    "DM" => "DMA",
+    "DO" => "DOM",
+    "DZ" => "ALG",
+    "EC" => "ECU",
+    "EE" => "EST",
+    "EG" => "EGY",
+    "ER" => "ERI",
+    "ES" => "ESP",
+    "ET" => "ETH",
+    "FI" => "FIN",
+    "FJ" => "FIJ",
+    "FO" => "FRO",
+    "FR" => "FRA",
+    "GA" => "GAB",
+    "GD" => "GRN",
+    "GE" => "GEO",
+    "GH" => "GHA",
+    "GI" => "GIB",
+    "GM" => "GAM",
+    "GN" => "GUI",
+    "GQ" => "EQG",
+    "GR" => "GRE",
+    "GT" => "GUA",
+    "GU" => "GUM",
+    "GW" => "GNB",
+    "GY" => "GUY",
+    "HK" => "HKG",
+    "HN" => "HON",
+    "HR" => "CRO",
+    "HT" => "HAI",
+    "HU" => "HUN",
+    "ID" => "IDN",
+    "IE" => "IRL",
+    "IL" => "ISR",
+    "IN" => "IND",
+    "IQ" => "IRQ",
+    "IR" => "IRN",
+    "IS" => "ISL",
+    "IT" => "ITA",
+    "JM" => "JAM",
+    "JO" => "JOR",
+    "JP" => "JPN",
+    "KE" => "KEN",
+    "KG" => "KGZ",
+    "KH" => "CAM",
+    "KM" => "COM",
+    "KN" => "SKN",
+    "KP" => "PRK",
+    "KR" => "KOR",
+    "KW" => "KUW",
+    "KY" => "CAY",
+    "KZ" => "KAZ",
+    "LA" => "LAO",
+    "LB" => "LBN",
+    "LC" => "LCA",
+    "LI" => "LIE",
+    "LK" => "SRI",
+    "LR" => "LBR",
+    "LS" => "LES",
+    "LT" => "LTU",
+    "LU" => "LUX",
+    "LV" => "LVA",
+    "LY" => "LBY",
+    "MA" => "MAR",
+    "MD" => "MDA",
+    "ME" => "MNE",
+    "MG" => "MAD",
+    "MK" => "MKD",
+    "ML" => "MLI",
+    "MM" => "MYA",
+    "MN" => "MNG",
+    "MO" => "MAC",
+    "MR" => "MTN",
+    "MS" => "MSR",
+    "MT" => "MLT",
+    "MU" => "MRI",
+    "MV" => "MDV",
+    "MW" => "MWI",
+    "MX" => "MEX",
+    "MY" => "MAS",
+    "MZ" => "MOZ",
+    "NA" => "NAM",
+    "NC" => "NCL",
+    "NE" => "NIG",
+    "NG" => "NGA",
+    "NI" => "NCA",
+    "NL" => "NED",
+    "NO" => "NOR",
+    "NP" => "NEP",
+    "NZ" => "NZL",
+    "OM" => "OMA",
+    "PA" => "PAN",
+    "PE" => "PER",
+    "PF" => "TAH",
+    "PG" => "PNG",
+    "PH" => "PHI",
+    "PK" => "PAK",
+    "PL" => "POL",
+    "PR" => "PUR",
+    "PS" => "PLE",
+    "PT" => "POR",
+    "PY" => "PAR",
+    "QA" => "QAT",
+    "RO" => "ROU",
+    "RS" => "SRB",
+    "RU" => "RUS",
+    "RW" => "RWA",
+    "SA" => "KSA",
+    "SB" => "SOL",
+    "SC" => "SEY",
+    "SD" => "SDN",
+    "SE" => "SWE",
+    "SG" => "SIN",
+    "SI" => "SVN",
+    "SK" => "SVK",
+    "SL" => "SLE",
+    "SM" => "SMR",
+    "SN" => "SEN",
+    "SO" => "SOM",
+    "SR" => "SUR",
+    "SS" => "SSD",
+    "ST" => "STP",
+    "SV" => "SLV",
+    "SY" => "SYR",
+    "SZ" => "SWZ",
+    "TC" => "TCA",
+    "TD" => "CHA",
+    "TG" => "TOG",
+    "TH" => "THA",
+    "TJ" => "TJK",
+    "TL" => "TLS",
+    "TM" => "TKM",
+    "TN" => "TUN",
+    "TO" => "TGA",
+    "TR" => "TUR",
+    "TT" => "TRI",
+    "TW" => "TPE",
+    "TZ" => "TAN",
+    "UA" => "UKR",
+    "UG" => "UGA",
+    "US" => "USA",
+    "UY" => "URU",
+    "UZ" => "UZB",
+    "VC" => "VIN",
+    "VE" => "VEN",
+    "VG" => "VGB",
+    "VI" => "VIR",
+    "VN" => "VIE",
+    "VU" => "VAN",
+    "WS" => "SAM",
+    "YE" => "YEM",
+    "ZA" => "RSA",
+    "ZM" => "ZAM",
+    "ZW" => "ZIM",
+    "GB-ENG" => "ENG",
+    "GB-NIR" => "NIR",
+    "GB-SCT" => "SCO",
+    "GB-WLS" => "WAL",
+};
+
+/// World Athletics codes.
+pub const WORLD_ATHLETICS_MAP: Map<&str, SportsCode> = phf_map! {
+    "AFG" => SportsCode { code: "AFG", team: Code::Country(crate::AF), historical: false },
+    "AIA" => SportsCode { code: "AIA", team: Code::Country(crate::AI), historical: false },
+    "ALB" => SportsCode { code: "ALB", team: Code::Country(crate::AL), historical: false },
+    "ALG" => SportsCode { code: "ALG", team: Code::Country(crate::DZ), historical: false },
+    "AND" => SportsCode { code: "AND", team: Code::Country(crate::AD), historical: false },
+    "ANG" => SportsCode { code: "ANG", team: Code::Country(crate::AO), historical: false },
+    "ANT" => SportsCode { code: "ANT", team: Code::Country(crate::AG), historical: false },
+    "ARG" => SportsCode { code: "ARG", team: Code::Country(crate::AR), historical: false },
+    "ARM" => SportsCode { code: "ARM", team: Code::Country(crate::AM), historical: false },
+    "ARU" => SportsCode { code: "ARU", team: Code::Country(crate::AW), historical: false },
+    "ASA" => SportsCode { code: "ASA", team: Code::Country(crate::AS), historical: false },
+    "AUS" => SportsCode { code: "AUS", team: Code::Country(crate::AU), historical: false },
+    "AUT" => SportsCode { code: "AUT", team: Code::Country(crate::AT), historical: false },
+    "AZE" => SportsCode { code: "AZE", team: Code::Country(crate::AZ), historical: false },
+    "BAH" => SportsCode { code: "BAH", team: Code::Country(crate::BS), historical: false },
+    "BAN" => SportsCode { code: "BAN", team: Code::Country(crate::BD), historical: false },
+    "BAR" => SportsCode { code: "BAR", team: Code::Country(crate::BB), historical: false },
+    "BDI" => SportsCode { code: "BDI", team: Code::Country(crate::BI), historical: false },
+    "BEL" => SportsCode { code: "BEL", team: Code::Country(crate::BE), historical: false },
+    "BEN" => SportsCode { code: "BEN", team: Code::Country(crate::BJ), historical: false },
+    "BER" => SportsCode { code: "BER", team: Code::Country(crate::BM), historical: false },
+    "BHU" => SportsCode { code: "BHU", team: Code::Country(crate::BT), historical: false },
+    "BIH" => SportsCode { code: "BIH", team: Code::Country(crate::BA), historical: false },
+    "BIZ" => SportsCode { code: "BIZ", team: Code::Country(crate::BZ), historical: false },
+    "BLR" => SportsCode { code: "BLR", team: Code::Country(crate::BY), historical: false },
+    "BOL" => SportsCode { code: "BOL", team: Code::Country(crate::BO), historical: false },
+    "BOT" => SportsCode { code: "BOT", team: Code::Country(crate::BW), historical: false },
+    "BRA" => SportsCode { code: "BRA", team: Code::Country(crate::BR), historical: false },
+    "BRN" => SportsCode { code: "BRN", team: Code::Country(crate::BH), historical: false },
+    "BRU" => SportsCode { code: "BRU", team: Code::Country(crate::BN), historical: false },
+    "BUL" => SportsCode { code: "BUL", team: Code::Country(crate::BG), historical: false },
+    "BUR" => SportsCode { code: "BUR", team: Code::Country(crate::BF), historical: false },
+    "CAF" => SportsCode { code: "CAF", team: Code::Country(crate::CF), historical: false },
+    "CAM" => SportsCode { code: "CAM", team: Code::Country(crate::KH), historical: false },
+    "CAN" => SportsCode { code: "CAN", team: Code::Country(crate::CA), historical: false },
+    "CAY" => SportsCode { code: "CAY", team: Code::Country(crate::KY), historical: false },
+    "CGO" => SportsCode { code: "CGO", team: Code::Country(crate::CG), historical: false },
+    "CHA" => SportsCode { code: "CHA", team: Code::Country(crate::TD), historical: false },
+    "CHI" => SportsCode { code: "CHI", team: Code::Country(crate::CL), historical: false },
+    "CHN" => SportsCode { code: "CHN", team: Code::Country(crate::CN), historical: false },
+    "CIV" => SportsCode { code: "CIV", team: Code::Country(crate::CI), historical: false },
+    "CMR" => SportsCode { code: "CMR", team: Code::Country(crate::CM), historical: false },
+    "COD" => SportsCode { code: "COD", team: Code::Country(crate::CD), historical: false },
+    "COK" => SportsCode { code: "COK", team: Code::Country(crate::CK), historical: false },
+    "COL" => SportsCode { code: "COL", team: Code::Country(crate::CO), historical: false },
+    "COM" => SportsCode { code: "COM", team: Code::Country(crate::KM), historical: false },
+    "CPV" => SportsCode { code: "CPV", team: Code::Country(crate::CV), historical: false },
+    "CRC" => SportsCode { code: "CRC", team: Code::Country(crate::CR), historical: false },
+    "CRO" => SportsCode { code: "CRO", team: Code::Country(crate::HR), historical: false },
+    "CUB" => SportsCode { code: "CUB", team: Code::Country(crate::CU), historical: false },
+    "CYP" => SportsCode { code: "CYP", team: Code::Country(crate::CY), historical: false },
+    "CZE" => SportsCode { code: "CZE", team: Code::Country(crate::CZ), historical: false },
+    "DEN" => SportsCode { code: "DEN", team: Code::Country(crate::DK), historical: false },
+    "DJI" => SportsCode { code: "DJI", team: Code::Country(crate::DJ), historical: false },
+    "DMA" => SportsCode { code: "DMA", team: Code::Country(crate::DM), historical: false },
+    "DOM" => SportsCode { code: "DOM", team: Code::Country(crate::DO), historical: false },
+    "ECU" => SportsCode { code: "ECU", team: Code::Country(crate::EC), historical: false },
+    "EGY" => SportsCode { code: "EGY", team: Code::Country(crate::EG), historical: false },
+    "ERI" => SportsCode { code: "ERI", team: Code::Country(crate::ER), historical: false },
+    "ESA" => SportsCode { code: "ESA", team: Code::Country(crate::SV), historical: false },
+    "ESP" => SportsCode { code: "ESP", team: Code::Country(crate::ES), historical: false },
+    "EST" => SportsCode { code: "EST", team: Code::Country(crate::EE), historical: false },
+    "ETH" => SportsCode { code: "ETH", team: Code::Country(crate::ET), historical: false },
+    "FIJ" => SportsCode { code: "FIJ", team: Code::Country(crate::FJ), historical: false },
+    "FIN" => SportsCode { code: "FIN", team: Code::Country(crate::FI), historical: false },
+    "FRA" => SportsCode { code: "FRA", team: Code::Country(crate::FR), historical: false },
+    "FSM" => SportsCode { code: "FSM", team: Code::Country(crate::FM), historical: false },
+    "GAB" => SportsCode { code: "GAB", team: Code::Country(crate::GA), historical: false },
+    "GAM" => SportsCode { code: "GAM", team: Code::Country(crate::GM), historical: false },
+    "GBR" => SportsCode { code: "GBR", team: Code::Country(crate::GB), historical: false },
+    "GBS" => SportsCode { code: "GBS", team: Code::Country(crate::GW), historical: false },
+    "GEO" => SportsCode { code: "GEO", team: Code::Country(crate::GE), historical: false },
+    "GEQ" => SportsCode { code: "GEQ", team: Code::Country(crate::GQ), historical: false },
+    "GER" => SportsCode { code: "GER", team: Code::Country(crate::DE), historical: false },
+    "GHA" => SportsCode { code: "GHA", team: Code::Country(crate::GH), historical: false },
+    "GIB" => SportsCode { code: "GIB", team: Code::Country(crate::GI), historical: false },
+    "GRE" => SportsCode { code: "GRE", team: Code::Country(crate::GR), historical: false },
+    "GRN" => SportsCode { code: "GRN", team: Code::Country(crate::GD), historical: false },
+    "GUA" => SportsCode { code: "GUA", team: Code::Country(crate::GT), historical: false },
+    "GUI" => SportsCode { code: "GUI", team: Code::Country(crate::GN), historical: false },
+    "GUM" => SportsCode { code: "GUM", team: Code::Country(crate::GU), historical: false },
+    "GUY" => SportsCode { code: "GUY", team: Code::Country(crate::GY), historical: false },
+    "HAI" => SportsCode { code: "HAI", team: Code::Country(crate::HT), historical: false },
+    "HKG" => SportsCode { code: "HKG", team: Code::Country(crate::HK), historical: false },
+    "HON" => SportsCode { code: "HON", team: Code::Country(crate::HN), historical: false },
+    "HUN" => SportsCode { code: "HUN", team: Code::Country(crate::HU), historical: false },
+    "INA" => SportsCode { code: "INA", team: Code::Country(crate::ID), historical: false },
+    "IND" => SportsCode { code: "IND", team: Code::Country(crate::IN), historical: false },
+    "IRI" => SportsCode { code: "IRI", team: Code::Country(crate::IR), historical: false },
+    "IRL" => SportsCode { code: "IRL", team: Code::Country(crate::IE), historical: false },
+    "IRQ" => SportsCode { code: "IRQ", team: Code::Country(crate::IQ), historical: false },
+    "ISL" => SportsCode { code: "ISL", team: Code::Country(crate::IS), historical: false },
+    "ISR" => SportsCode { code: "ISR", team: Code::Country(crate::IL), historical: false },
+    "ISV" => SportsCode { code: "ISV", team: Code::Country(crate::VI), historical: false },
+    "ITA" => SportsCode { code: "ITA", team: Code::Country(crate::IT), historical: false },
+    "IVB" => SportsCode { code: "IVB", team: Code::Country(crate::VG), historical: false },
+    "JAM" => SportsCode { code: "JAM", team: Code::Country(crate::JM), historical: false },
+    "JOR" => SportsCode { code: "JOR", team: Code::Country(crate::JO), historical: false },
+    "JPN" => SportsCode { code: "JPN", team: Code::Country(crate::JP), historical: false },
+    "KAZ" => SportsCode { code: "KAZ", team: Code::Country(crate::KZ), historical: false },
+    "KEN" => SportsCode { code: "KEN", team: Code::Country(crate::KE), historical: false },
+    "KGZ" => SportsCode { code: "KGZ", team: Code::Country(crate::KG), historical: false },
+    "KIR" => SportsCode { code: "KIR", team: Code::Country(crate::KI), historical: false },
+    "KOR" => SportsCode { code: "KOR", team: Code::Country(crate::KR), historical: false },
+    "KSA" => SportsCode { code: "KSA", team: Code::Country(crate::SA), historical: false },
+    "KUW" => SportsCode { code: "KUW", team: Code::Country(crate::KW), historical: false },
+    "LAO" => SportsCode { code: "LAO", team: Code::Country(crate::LA), historical: false },
+    "LAT" => SportsCode { code: "LAT", team: Code::Country(crate::LV), historical: false },
+    "LBA" => SportsCode { code: "LBA", team: Code::Country(crate::LY), historical: false },
+    "LBN" => SportsCode { code: "LBN", team: Code::Country(crate::LB), historical: false },
+    "LBR" => SportsCode { code: "LBR", team: Code::Country(crate::LR), historical: false },
+    "LCA" => SportsCode { code: "LCA", team: Code::Country(crate::LC), historical: false },
+    "LES" => SportsCode { code: "LES", team: Code::Country(crate::LS), historical: false },
+    "LIE" => SportsCode { code: "LIE", team: Code::Country(crate::LI), historical: false },
+    "LTU" => SportsCode { code: "LTU", team: Code::Country(crate::LT), historical: false },
+    "LUX" => SportsCode { code: "LUX", team: Code::Country(crate::LU), historical: false },
+    "MAC" => SportsCode { code: "MAC", team: Code::Country(crate::MO), historical: false },
+    "MAD" => SportsCode { code: "MAD", team: Code::Country(crate::MG), historical: false },
+    "MAR" => SportsCode { code: "MAR", team: Code::Country(crate::MA), historical: false },
+    "MAS" => SportsCode { code: "MAS", team: Code::Country(crate::MY), historical: false },
+    "MAW" => SportsCode { code: "MAW", team: Code::Country(crate::MW), historical: false },
+    "MDA" => SportsCode { code: "MDA", team: Code::Country(crate::MD), historical: false },
+    "MDV" => SportsCode { code: "MDV", team: Code::Country(crate::MV), historical: false },
+    "MEX" => SportsCode { code: "MEX", team: Code::Country(crate::MX), historical: false },
+    "MGL" => SportsCode { code: "MGL", team: Code::Country(crate::MN), historical: false },
+    "MHL" => SportsCode { code: "MHL", team: Code::Country(crate::MH), historical: false },
+    "MKD" => SportsCode { code: "MKD", team: Code::Country(crate::MK), historical: false },
+    "MLI" => SportsCode { code: "MLI", team: Code::Country(crate::ML), historical: false },
+    "MLT" => SportsCode { code: "MLT", team: Code::Country(crate::MT), historical: false },
+    "MNE" => SportsCode { code: "MNE", team: Code::Country(crate::ME), historical: false },
+    "MON" => SportsCode { code: "MON", team: Code::Country(crate::MC), historical: false },
+    "MOZ" => SportsCode { code: "MOZ", team: Code::Country(crate::MZ), historical: false },
+    "MRI" => SportsCode { code: "MRI", team: Code::Country(crate::MU), historical: false },
+    "MSR" => SportsCode { code: "MSR", team: Code::Country(crate::MS), historical: false },
+    "MTN" => SportsCode { code: "MTN", team: Code::Country(crate::MR), historical: false },
+    "MYA" => SportsCode { code: "MYA", team: Code::Country(crate::MM), historical: false },
+    "NAM" => SportsCode { code: "NAM", team: Code::Country(crate::NA), historical: false },
+    "NCA" => SportsCode { code: "NCA", team: Code::Country(crate::NI), historical: false },
+    "NED" => SportsCode { code: "NED", team: Code::Country(crate::NL), historical: false },
+    "NEP" => SportsCode { code: "NEP", team: Code::Country(crate::NP), historical: false },
+    "NFI" => SportsCode { code: "NFI", team: Code::Country(crate::NF), historical: false },
+    "NGR" => SportsCode { code: "NGR", team: Code::Country(crate::NG), historical: false },
+    "NIG" => SportsCode { code: "NIG", team: Code::Country(crate::NE), historical: false },
+    "NMI" => SportsCode { code: "NMI", team: Code::Country(crate::MP), historical: false },
+    "NOR" => SportsCode { code: "NOR", team: Code::Country(crate::NO), historical: false },
+    "NRU" => SportsCode { code: "NRU", team: Code::Country(crate::NR), historical: false },
+    "NZL" => SportsCode { code: "NZL", team: Code::Country(crate::NZ), historical: false },
+    "OMA" => SportsCode { code: "OMA", team: Code::Country(crate::OM), historical: false },
+    "PAK" => SportsCode { code: "PAK", team: Code::Country(crate::PK), historical: false },
+    "PAN" => SportsCode { code: "PAN", team: Code::Country(crate::PA), historical: false },
+    "PAR" => SportsCode { code: "PAR", team: Code::Country(crate::PY), historical: false },
+    "PER" => SportsCode { code: "PER", team: Code::Country(crate::PE), historical: false },
+    "PHI" => SportsCode { code: "PHI", team: Code::Country(crate::PH), historical: false },
+    "PLE" => SportsCode { code: "PLE", team: Code::Country(crate::PS), historical: false },
+    "PLW" => SportsCode { code: "PLW", team: Code::Country(crate::PW), historical: false },
+    "PNG" => SportsCode { code: "PNG", team: Code::Country(crate::PG), historical: false },
+    "POL" => SportsCode { code: "POL", team: Code::Country(crate::PL), historical: false },
+    "POR" => SportsCode { code: "POR", team: Code::Country(crate::PT), historical: false },
+    "PRK" => SportsCode { code: "PRK", team: Code::Country(crate::KP), historical: false },
+    "PUR" => SportsCode { code: "PUR", team: Code::Country(crate::PR), historical: false },
+    "QAT" => SportsCode { code: "QAT", team: Code::Country(crate::QA), historical: false },
+    "ROU" => SportsCode { code: "ROU", team: Code::Country(crate::RO), historical: false },
+    "RSA" => SportsCode { code: "RSA", team: Code::Country(crate::ZA), historical: false },
+    "RUS" => SportsCode { code: "RUS", team: Code::Country(crate::RU), historical: false },
+    "RWA" => SportsCode { code: "RWA", team: Code::Country(crate::RW), historical: false },
+    "SAM" => SportsCode { code: "SAM", team: Code::Country(crate::WS), historical: false },
+    "SEN" => SportsCode { code: "SEN", team: Code::Country(crate::SN), historical: false },
+    "SEY" => SportsCode { code: "SEY", team: Code::Country(crate::SC), historical: false },
+    "SGP" => SportsCode { code: "SGP", team: Code::Country(crate::SG), historical: false },
+    "SKN" => SportsCode { code: "SKN", team: Code::Country(crate::KN), historical: false },
+    "SLE" => SportsCode { code: "SLE", team: Code::Country(crate::SL), historical: false },
+    "SLO" => SportsCode { code: "SLO", team: Code::Country(crate::SI), historical: false },
+    "SMR" => SportsCode { code: "SMR", team: Code::Country(crate::SM), historical: false },
+    "SOL" => SportsCode { code: "SOL", team: Code::Country(crate::SB), historical: false },
+    "SOM" => SportsCode { code: "SOM", team: Code::Country(crate::SO), historical: false },
+    "SRB" => SportsCode { code: "SRB", team: Code::Country(crate::RS), historical: false },
+    "SRI" => SportsCode { code: "SRI", team: Code::Country(crate::LK), historical: false },
+    "SSD" => SportsCode { code: "SSD", team: Code::Country(crate::SS), historical: false },
+    "STP" => SportsCode { code: "STP", team: Code::Country(crate::ST), historical: false },
+    "SUD" => SportsCode { code: "SUD", team: Code::Country(crate::SD), historical: false },
+    "SUI" => SportsCode { code: "SUI", team: Code::Country(crate::CH), historical: false },
+    "SUR" => SportsCode { code: "SUR", team: Code::Country(crate::SR), historical: false },
+    "SVK" => SportsCode { code: "SVK", team: Code::Country(crate::SK), historical: false },
+    "SWE" => SportsCode { code: "SWE", team: Code::Country(crate::SE), historical: false },
+    "SWZ" => SportsCode { code: "SWZ", team: Code::Country(crate::SZ), historical: false },
+    "SYR" => SportsCode { code: "SYR", team: Code::Country(crate::SY), historical: false },
+    "TAN" => SportsCode { code: "TAN", team: Code::Country(crate::TZ), historical: false },
+    "TGA" => SportsCode { code: "TGA", team: Code::Country(crate::TO), historical: false },
+    "THA" => SportsCode { code: "THA", team: Code::Country(crate::TH), historical: false },
+    "TJK" => SportsCode { code: "TJK", team: Code::Country(crate::TJ), historical: false },
+    "TKM" => SportsCode { code: "TKM", team: Code::Country(crate::TM), historical: false },
+    "TKS" => SportsCode { code: "TKS", team: Code::Country(crate::TC), historical: false },
+    "TLS" => SportsCode { code: "TLS", team: Code::Country(crate::TL), historical: false },
+    "TOG" => SportsCode { code: "TOG", team: Code::Country(crate::TG), historical: false },
+    "TPE" => SportsCode { code: "TPE", team: Code::Country(crate::TW), historical: false },
+    "TTO" => SportsCode { code: "TTO", team: Code::Country(crate::TT), historical: false },
+    "TUN" => SportsCode { code: "TUN", team: Code::Country(crate::TN), historical: false },
+    "TUR" => SportsCode { code: "TUR", team: Code::Country(crate::TR), historical: false },
+    "TUV" => SportsCode { code: "TUV", team: Code::Country(crate::TV), historical: false },
+    "UAE" => SportsCode { code: "UAE", team: Code::Country(crate::AE), historical: false },
+    "UGA" => SportsCode { code: "UGA", team: Code::Country(crate::UG), historical: false },
+    "UKR" => SportsCode { code: "UKR", team: Code::Country(crate::UA), historical: false },
+    "URU" => SportsCode { code: "URU", team: Code::Country(crate::UY), historical: false },
+    "USA" => SportsCode { code: "USA", team: Code::Country(crate::US), historical: false },
+    "UZB" => SportsCode { code: "UZB", team: Code::Country(crate::UZ), historical: false },
+    "VAN" => SportsCode { code: "VAN", team: Code::Country(crate::VU), historical: false },
+    "VEN" => SportsCode { code: "VEN", team: Code::Country(crate::VE), historical: false },
+    "VIE" => SportsCode { code: "VIE", team: Code::Country(crate::VN), historical: false },
+    "VIN" => SportsCode { code: "VIN", team: Code::Country(crate::VC), historical: false },
+    "YEM" => SportsCode { code: "YEM", team: Code::Country(crate::YE), historical: false },
+    "ZAM" => SportsCode { code: "ZAM", team: Code::Country(crate::ZM), historical: false },
+    "ZIM" => SportsCode { code: "ZIM", team: Code::Country(crate::ZW), historical: false },
+};
+
+/// Current World Athletics codes by alpha-2 or ISO 3166-2 code.
+pub const WORLD_ATHLETICS_TEAM_MAP: Map<&str, &str> = phf_map! {
+    "AD" => "AND",
+    "AE" => "UAE",
+    "AF" => "AFG",
+    "AG" => "ANT",
+    "AI" => "AIA",
+    "AL" => "ALB",
+    "AM" => "ARM",
+    "AO" => "ANG",
+    "AR" => "ARG",
+    "AS" => "ASA",
+    "AT" => "AUT",
+    "AU" => "AUS",
+    "AW" => "ARU",
+    "AZ" => "AZE",
+    "BA" => "BIH",
+    "BB" => "BAR",
+    "BD" => "BAN",
+    "BE" => "BEL",
+    "BF" => "BUR",
+    "BG" => "BUL",
+    "BH" => "BRN",
+    "BI" => "BDI",
+    "BJ" => "BEN",
+    "BM" => "BER",
+    "BN" => "BRU",
+    "BO" => "BOL",
+    "BR" => "BRA",
+    "BS" => "BAH",
+    "BT" => "BHU",
+    "BW" => "BOT",
+    "BY" => "BLR",
+    "BZ" => "BIZ",
+    "CA" => "CAN",
+    "CD" => "COD",
+    "CF" => "CAF",
+    "CG" => "CGO",
+    "CH" => "SUI",
+    "CI" => "CIV",
+    "CK" => "COK",
+    "CL" => "CHI",
+    "CM" => "CMR",
+    "CN" => "CHN",
+    "CO" => "COL",
+    "CR" => "CRC",
+    "CU" => "CUB",
+    "CV" => "CPV",
+    "CY" => "CYP",
+    "CZ" => "CZE",
+    "DE" => "GER",
+    "DJ" => "DJI",
+    "DK" => "DEN",
+    "DM" => "DMA",
+    "DO" => "DOM",
+    "DZ" => "ALG",
+    "EC" => "ECU",
+    "EE" => "EST",
+    "EG" => "EGY",
+    "ER" => "ERI",
+    "ES" => "ESP",
+    "ET" => "ETH",
+    "FI" => "FIN",
+    "FJ" => "FIJ",
+    "FM" => "FSM",
+    "FR" => "FRA",
+    "GA" => "GAB",
+    "GB" => "GBR",
+    "GD" => "GRN",
+    "GE" => "GEO",
+    "GH" => "GHA",
+    "GI" => "GIB",
+    "GM" => "GAM",
+    "GN" => "GUI",
+    "GQ" => "GEQ",
+    "GR" => "GRE",
+    "GT" => "GUA",
+    "GU" => "GUM",
+    "GW" => "GBS",
+    "GY" => "GUY",
+    "HK" => "HKG",
+    "HN" => "HON",
+    "HR" => "CRO",
+    "HT" => "HAI",
+    "HU" => "HUN",
+    "ID" => "INA",
+    "IE" => "IRL",
+    "IL" => "ISR",
+    "IN" => "IND",
+    "IQ" => "IRQ",
+    "IR" => "IRI",
+    "IS" => "ISL",
+    "IT" => "ITA",
+    "JM" => "JAM",
+    "JO" => "JOR",
+    "JP" => "JPN",
+    "KE" => "KEN",
+    "KG" => "KGZ",
+    "KH" => "CAM",
+    "KI" => "KIR",
+    "KM" => "COM",
+    "KN" => "SKN",
+    "KP" => "PRK",
+    "KR" => "KOR",
+    "KW" => "KUW",
+    "KY" => "CAY",
+    "KZ" => "KAZ",
+    "LA" => "LAO",
+    "LB" => "LBN",
+    "LC" => "LCA",
+    "LI" => "LIE",
+    "LK" => "SRI",
+    "LR" => "LBR",
+    "LS" => "LES",
+    "LT" => "LTU",
+    "LU" => "LUX",
+    "LV" => "LAT",
+    "LY" => "LBA",
+    "MA" => "MAR",
+    "MC" => "MON",
+    "MD" => "MDA",
+    "ME" => "MNE",
+    "MG" => "MAD",
+    "MH" => "MHL",
+    "MK" => "MKD",
+    "ML" => "MLI",
+    "MM" => "MYA",
+    "MN" => "MGL",
+    "MO" => "MAC",
+    "MP" => "NMI",
+    "MR" => "MTN",
+    "MS" => "MSR",
+    "MT" => "MLT",
+    "MU" => "MRI",
+    "MV" => "MDV",
+    "MW" => "MAW",
+    "MX" => "MEX",
+    "MY" => "MAS",
+    "MZ" => "MOZ",
+    "NA" => "NAM",
+    "NE" => "NIG",
+    "NF" => "NFI",
+    "NG" => "NGR",
+    "NI" => "NCA",
+    "NL" => "NED",
+    "NO" => "NOR",
+    "NP" => "NEP",
+    "NR" => "NRU",
+    "NZ" => "NZL",
+    "OM" => "OMA",
+    "PA" => "PAN",
+    "PE" => "PER",
+    "PG" => "PNG",
+    "PH" => "PHI",
+    "PK" => "PAK",
+    "PL" => "POL",
+    "PR" => "PUR",
+    "PS" => "PLE",
+    "PT" => "POR",
+    "PW" => "PLW",
+    "PY" => "PAR",
+    "QA" => "QAT",
+    "RO" => "ROU",
+    "RS" => "SRB",
+    "RU" => "RUS",
+    "RW" => "RWA",
+    "SA" => "KSA",
+    "SB" => "SOL",
+    "SC" => "SEY",
+    "SD" => "SUD",
+    "SE" => "SWE",
+    "SG" => "SGP",
+    "SI" => "SLO",
+    "SK" => "SVK",
+    "SL" => "SLE",
+    "SM" => "SMR",
+    "SN" => "SEN",
+    "SO" => "SOM",
+    "SR" => "SUR",
+    "SS" => "SSD",
+    "ST" => "STP",
+    "SV" => "ESA",
+    "SY" => "SYR",
+    "SZ" => "SWZ",
+    "TC" => "TKS",
+    "TD" => "CHA",
+    "TG" => "TOG",
+    "TH" => "THA",
+    "TJ" => "TJK",
+    "TL" => "TLS",
+    "TM" => "TKM",
+    "TN" => "TUN",
+    "TO" => "TGA",
+    "TR" => "TUR",
+    "TT" => "TTO",
+    "TV" => "TUV",
+    "TW" => "TPE",
+    "TZ" => "TAN",
+    "UA" => "UKR",
+    "UG" => "UGA",
+    "US" => "USA",
+    "UY" => "URU",
+    "UZ" => "UZB",
+    "VC" => "VIN",
+    "VE" => "VEN",
+    "VG" => "IVB",
+    "VI" => "ISV",
+    "VN" => "VIE",
+    "VU" => "VAN",
+    "WS" => "SAM",
+    "YE" => "YEM",
+    "ZA" => "RSA",
+    "ZM" => "ZAM",
+    "ZW" => "ZIM",
+};
+
+/// International Paralympic Committee codes.
+pub const IPC_MAP: Map<&str, SportsCode> = phf_map! {
+    "AFG" => SportsCode { code: "AFG", team: Code::Country(crate::AF), historical: false },
+    "ALB" => SportsCode { code: "ALB", team: Code::Country(crate::AL), historical: false },
+    "ALG" => SportsCode { code: "ALG", team: Code::Country(crate::DZ), historical: false },
+    "AND" => SportsCode { code: "AND", team: Code::Country(crate::AD), historical: false },
+    "ANG" => SportsCode { code: "ANG", team: Code::Country(crate::AO), historical: false },
+    "ANT" => SportsCode { code: "ANT", team: Code::Country(crate::AG), historical: false },
+    "ARG" => SportsCode { code: "ARG", team: Code::Country(crate::AR), historical: false },
+    "ARM" => SportsCode { code: "ARM", team: Code::Country(crate::AM), historical: false },
+    "ARU" => SportsCode { code: "ARU", team: Code::Country(crate::AW), historical: false },
+    "ASA" => SportsCode { code: "ASA", team: Code::Country(crate::AS), historical: false },
+    "AUS" => SportsCode { code: "AUS", team: Code::Country(crate::AU), historical: false },
+    "AUT" => SportsCode { code: "AUT", team: Code::Country(crate::AT), historical: false },
+    "AZE" => SportsCode { code: "AZE", team: Code::Country(crate::AZ), historical: false },
+    "BAH" => SportsCode { code: "BAH", team: Code::Country(crate::BS), historical: false },
+    "BAN" => SportsCode { code: "BAN", team: Code::Country(crate::BD), historical: false },
+    "BAR" => SportsCode { code: "BAR", team: Code::Country(crate::BB), historical: false },
+    "BDI" => SportsCode { code: "BDI", team: Code::Country(crate::BI), historical: false },
+    "BEL" => SportsCode { code: "BEL", team: Code::Country(crate::BE), historical: false },
+    "BEN" => SportsCode { code: "BEN", team: Code::Country(crate::BJ), historical: false },
+    "BER" => SportsCode { code: "BER", team: Code::Country(crate::BM), historical: false },
+    "BHU" => SportsCode { code: "BHU", team: Code::Country(crate::BT), historical: false },
+    "BIH" => SportsCode { code: "BIH", team: Code::Country(crate::BA), historical: false },
+    "BIZ" => SportsCode { code: "BIZ", team: Code::Country(crate::BZ), historical: false },
+    "BLR" => SportsCode { code: "BLR", team: Code::Country(crate::BY), historical: false },
+    "BOL" => SportsCode { code: "BOL", team: Code::Country(crate::BO), historical: false },
+    "BOT" => SportsCode { code: "BOT", team: Code::Country(crate::BW), historical: false },
+    "BRA" => SportsCode { code: "BRA", team: Code::Country(crate::BR), historical: false },
+    "BRN" => SportsCode { code: "BRN", team: Code::Country(crate::BH), historical: false },
+    "BRU" => SportsCode { code: "BRU", team: Code::Country(crate::BN), historical: false },
+    "BUL" => SportsCode { code: "BUL", team: Code::Country(crate::BG), historical: false },
+    "BUR" => SportsCode { code: "BUR", team: Code::Country(crate::BF), historical: false },
+    "CAF" => SportsCode { code: "CAF", team: Code::Country(crate::CF), historical: false },
+    "CAM" => SportsCode { code: "CAM", team: Code::Country(crate::KH), historical: false },
+    "CAN" => SportsCode { code: "CAN", team: Code::Country(crate::CA), historical: false },
+    "CAY" => SportsCode { code: "CAY", team: Code::Country(crate::KY), historical: false },
+    "CGO" => SportsCode { code: "CGO", team: Code::Country(crate::CG), historical: false },
+    "CHA" => SportsCode { code: "CHA", team: Code::Country(crate::TD), historical: false },
+    "CHI" => SportsCode { code: "CHI", team: Code::Country(crate::CL), historical: false },
+    "CHN" => SportsCode { code: "CHN", team: Code::Country(crate::CN), historical: false },
+    "CIV" => SportsCode { code: "CIV", team: Code::Country(crate::CI), historical: false },
+    "CMR" => SportsCode { code: "CMR", team: Code::Country(crate::CM), historical: false },
+    "COD" => SportsCode { code: "COD", team: Code::Country(crate::CD), historical: false },
+    "COK" => SportsCode { code: "COK", team: Code::Country(crate::CK), historical: false },
+    "COL" => SportsCode { code: "COL", team: Code::Country(crate::CO), historical: false },
+    "COM" => SportsCode { code: "COM", team: Code::Country(crate::KM), historical: false },
+    "CPV" => SportsCode { code: "CPV", team: Code::Country(crate::CV), historical: false },
+    "CRC" => SportsCode { code: "CRC", team: Code::Country(crate::CR), historical: false },
+    "CRO" => SportsCode { code: "CRO", team: Code::Country(crate::HR), historical: false },
+    "CUB" => SportsCode { code: "CUB", team: Code::Country(crate::CU), historical: false },
+    "CYP" => SportsCode { code: "CYP", team: Code::Country(crate::CY), historical: false },
+    "CZE" => SportsCode { code: "CZE", team: Code::Country(crate::CZ), historical: false },
+    "DEN" => SportsCode { code: "DEN", team: Code::Country(crate::DK), historical: false },
+    "DJI" => SportsCode { code: "DJI", team: Code::Country(crate::DJ), historical: false },
+    "DMA" => SportsCode { code: "DMA", team: Code::Country(crate::DM), historical: false },
+    "DOM" => SportsCode { code: "DOM", team: Code::Country(crate::DO), historical: false },
+    "ECU" => SportsCode { code: "ECU", team: Code::Country(crate::EC), historical: false },
+    "EGY" => SportsCode { code: "EGY", team: Code::Country(crate::EG), historical: false },
+    "ERI" => SportsCode { code: "ERI", team: Code::Country(crate::ER), historical: false },
+    "ESA" => SportsCode { code: "ESA", team: Code::Country(crate::SV), historical: false },
+    "ESP" => SportsCode { code: "ESP", team: Code::Country(crate::ES), historical: false },
+    "EST" => SportsCode { code: "EST", team: Code::Country(crate::EE), historical: false },
+    "ETH" => SportsCode { code: "ETH", team: Code::Country(crate::ET), historical: false },
+    "FIJ" => SportsCode { code: "FIJ", team: Code::Country(crate::FJ), historical: false },
+    "FIN" => SportsCode { code: "FIN", team: Code::Country(crate::FI), historical: false },
+    "FRA" => SportsCode { code: "FRA", team: Code::Country(crate::FR), historical: false },
+    "FRO" => SportsCode { code: "FRO", team: Code::Country(crate::FO), historical: false },
+    "FSM" => SportsCode { code: "FSM", team: Code::Country(crate::FM), historical: false },
+    "GAB" => SportsCode { code: "GAB", team: Code::Country(crate::GA), historical: false },
+    "GAM" => SportsCode { code: "GAM", team: Code::Country(crate::GM), historical: false },
+    "GBR" => SportsCode { code: "GBR", team: Code::Country(crate::GB), historical: false },
+    "GBS" => SportsCode { code: "GBS", team: Code::Country(crate::GW), historical: false },
+    "GEO" => SportsCode { code: "GEO", team: Code::Country(crate::GE), historical: false },
+    "GEQ" => SportsCode { code: "GEQ", team: Code::Country(crate::GQ), historical: false },
+    "GER" => SportsCode { code: "GER", team: Code::Country(crate::DE), historical: false },
+    "GHA" => SportsCode { code: "GHA", team: Code::Country(crate::GH), historical: false },
+    "GRE" => SportsCode { code: "GRE", team: Code::Country(crate::GR), historical: false },
+    "GRN" => SportsCode { code: "GRN", team: Code::Country(crate::GD), historical: false },
+    "GUA" => SportsCode { code: "GUA", team: Code::Country(crate::GT), historical: false },
+    "GUI" => SportsCode { code: "GUI", team: Code::Country(crate::GN), historical: false },
+    "GUM" => SportsCode { code: "GUM", team: Code::Country(crate::GU), historical: false },
+    "GUY" => SportsCode { code: "GUY", team: Code::Country(crate::GY), historical: false },
+    "HAI" => SportsCode { code: "HAI", team: Code::Country(crate::HT), historical: false },
+    "HKG" => SportsCode { code: "HKG", team: Code::Country(crate::HK), historical: false },
+    "HON" => SportsCode { code: "HON", team: Code::Country(crate::HN), historical: false },
+    "HUN" => SportsCode { code: "HUN", team: Code::Country(crate::HU), historical: false },
+    "INA" => SportsCode { code: "INA", team: Code::Country(crate::ID), historical: false },
+    "IND" => SportsCode { code: "IND", team: Code::Country(crate::IN), historical: false },
+    "IRI" => SportsCode { code: "IRI", team: Code::Country(crate::IR), historical: false },
+    "IRL" => SportsCode { code: "IRL", team: Code::Country(crate::IE), historical: false },
+    "IRQ" => SportsCode { code: "IRQ", team: Code::Country(crate::IQ), historical: false },
+    "ISL" => SportsCode { code: "ISL", team: Code::Country(crate::IS), historical: false },
+    "ISR" => SportsCode { code: "ISR", team: Code::Country(crate::IL), historical: false },
+    "ISV" => SportsCode { code: "ISV", team: Code::Country(crate::VI), historical: false },
+    "ITA" => SportsCode { code: "ITA", team: Code::Country(crate::IT), historical: false },
+    "IVB" => SportsCode { code: "IVB", team: Code::Country(crate::VG), historical: false },
+    "JAM" => SportsCode { code: "JAM", team: Code::Country(crate::JM), historical: false },
+    "JOR" => SportsCode { code: "JOR", team: Code::Country(crate::JO), historical: false },
+    "JPN" => SportsCode { code: "JPN", team: Code::Country(crate::JP), historical: false },
+    "KAZ" => SportsCode { code: "KAZ", team: Code::Country(crate::KZ), historical: false },
+    "KEN" => SportsCode { code: "KEN", team: Code::Country(crate::KE), historical: false },
+    "KGZ" => SportsCode { code: "KGZ", team: Code::Country(crate::KG), historical: false },
+    "KIR" => SportsCode { code: "KIR", team: Code::Country(crate::KI), historical: false },
+    "KOR" => SportsCode { code: "KOR", team: Code::Country(crate::KR), historical: false },
+    "KSA" => SportsCode { code: "KSA", team: Code::Country(crate::SA), historical: false },
+    "KUW" => SportsCode { code: "KUW", team: Code::Country(crate::KW), historical: false },
+    "LAO" => SportsCode { code: "LAO", team: Code::Country(crate::LA), historical: false },
+    "LAT" => SportsCode { code: "LAT", team: Code::Country(crate::LV), historical: false },
+    "LBA" => SportsCode { code: "LBA", team: Code::Country(crate::LY), historical: false },
+    "LBN" => SportsCode { code: "LBN", team: Code::Country(crate::LB), historical: false },
+    "LBR" => SportsCode { code: "LBR", team: Code::Country(crate::LR), historical: false },
+    "LCA" => SportsCode { code: "LCA", team: Code::Country(crate::LC), historical: false },
+    "LES" => SportsCode { code: "LES", team: Code::Country(crate::LS), historical: false },
+    "LIE" => SportsCode { code: "LIE", team: Code::Country(crate::LI), historical: false },
+    "LTU" => SportsCode { code: "LTU", team: Code::Country(crate::LT), historical: false },
+    "LUX" => SportsCode { code: "LUX", team: Code::Country(crate::LU), historical: false },
+    "MAC" => SportsCode { code: "MAC", team: Code::Country(crate::MO), historical: false },
+    "MAD" => SportsCode { code: "MAD", team: Code::Country(crate::MG), historical: false },
+    "MAR" => SportsCode { code: "MAR", team: Code::Country(crate::MA), historical: false },
+    "MAS" => SportsCode { code: "MAS", team: Code::Country(crate::MY), historical: false },
+    "MAW" => SportsCode { code: "MAW", team: Code::Country(crate::MW), historical: false },
+    "MDA" => SportsCode { code: "MDA", team: Code::Country(crate::MD), historical: false },
+    "MDV" => SportsCode { code: "MDV", team: Code::Country(crate::MV), historical: false },
+    "MEX" => SportsCode { code: "MEX", team: Code::Country(crate::MX), historical: false },
+    "MGL" => SportsCode { code: "MGL", team: Code::Country(crate::MN), historical: false },
+    "MHL" => SportsCode { code: "MHL", team: Code::Country(crate::MH), historical: false },
+    "MKD" => SportsCode { code: "MKD", team: Code::Country(crate::MK), historical: false },
+    "MLI" => SportsCode { code: "MLI", team: Code::Country(crate::ML), historical: false },
+    "MLT" => SportsCode { code: "MLT", team: Code::Country(crate::MT), historical: false },
+    "MNE" => SportsCode { code: "MNE", team: Code::Country(crate::ME), historical: false },
+    "MON" => SportsCode { code: "MON", team: Code::Country(crate::MC), historical: false },
+    "MOZ" => SportsCode { code: "MOZ", team: Code::Country(crate::MZ), historical: false },
+    "MRI" => SportsCode { code: "MRI", team: Code::Country(crate::MU), historical: false },
+    "MTN" => SportsCode { code: "MTN", team: Code::Country(crate::MR), historical: false },
+    "MYA" => SportsCode { code: "MYA", team: Code::Country(crate::MM), historical: false },
+    "NAM" => SportsCode { code: "NAM", team: Code::Country(crate::NA), historical: false },
+    "NCA" => SportsCode { code: "NCA", team: Code::Country(crate::NI), historical: false },
+    "NED" => SportsCode { code: "NED", team: Code::Country(crate::NL), historical: false },
+    "NEP" => SportsCode { code: "NEP", team: Code::Country(crate::NP), historical: false },
+    "NGR" => SportsCode { code: "NGR", team: Code::Country(crate::NG), historical: false },
+    "NIG" => SportsCode { code: "NIG", team: Code::Country(crate::NE), historical: false },
+    "NOR" => SportsCode { code: "NOR", team: Code::Country(crate::NO), historical: false },
+    "NRU" => SportsCode { code: "NRU", team: Code::Country(crate::NR), historical: false },
+    "NZL" => SportsCode { code: "NZL", team: Code::Country(crate::NZ), historical: false },
+    "OMA" => SportsCode { code: "OMA", team: Code::Country(crate::OM), historical: false },
+    "PAK" => SportsCode { code: "PAK", team: Code::Country(crate::PK), historical: false },
+    "PAN" => SportsCode { code: "PAN", team: Code::Country(crate::PA), historical: false },
+    "PAR" => SportsCode { code: "PAR", team: Code::Country(crate::PY), historical: false },
+    "PER" => SportsCode { code: "PER", team: Code::Country(crate::PE), historical: false },
+    "PHI" => SportsCode { code: "PHI", team: Code::Country(crate::PH), historical: false },
+    "PLE" => SportsCode { code: "PLE", team: Code::Country(crate::PS), historical: false },
+    "PLW" => SportsCode { code: "PLW", team: Code::Country(crate::PW), historical: false },
+    "PNG" => SportsCode { code: "PNG", team: Code::Country(crate::PG), historical: false },
+    "POL" => SportsCode { code: "POL", team: Code::Country(crate::PL), historical: false },
+    "POR" => SportsCode { code: "POR", team: Code::Country(crate::PT), historical: false },
+    "PRK" => SportsCode { code: "PRK", team: Code::Country(crate::KP), historical: false },
+    "PUR" => SportsCode { code: "PUR", team: Code::Country(crate::PR), historical: false },
+    "QAT" => SportsCode { code: "QAT", team: Code::Country(crate::QA), historical: false },
+    "ROU" => SportsCode { code: "ROU", team: Code::Country(crate::RO), historical: false },
+    "RSA" => SportsCode { code: "RSA", team: Code::Country(crate::ZA), historical: false },
+    "RUS" => SportsCode { code: "RUS", team: Code::Country(crate::RU), historical: false },
+    "RWA" => SportsCode { code: "RWA", team: Code::Country(crate::RW), historical: false },
+    "SAM" => SportsCode { code: "SAM", team: Code::Country(crate::WS), historical: false },
+    "SEN" => SportsCode { code: "SEN", team: Code::Country(crate::SN), historical: false },
+    "SEY" => SportsCode { code: "SEY", team: Code::Country(crate::SC), historical: false },
+    "SGP" => SportsCode { code: "SGP", team: Code::Country(crate::SG), historical: false },
+    "SKN" => SportsCode { code: "SKN", team: Code::Country(crate::KN), historical: false },
+    "SLE" => SportsCode { code: "SLE", team: Code::Country(crate::SL), historical: false },
+    "SLO" => SportsCode { code: "SLO", team: Code::Country(crate::SI), historical: false },
+    "SMR" => SportsCode { code: "SMR", team: Code::Country(crate::SM), historical: false },
+    "SOL" => SportsCode { code: "SOL", team: Code::Country(crate::SB), historical: false },
+    "SOM" => SportsCode { code: "SOM", team: Code::Country(crate::SO), historical: false },
+    "SRB" => SportsCode { code: "SRB", team: Code::Country(crate::RS), historical: false },
+    "SRI" => SportsCode { code: "SRI", team: Code::Country(crate::LK), historical: false },
+    "SSD" => SportsCode { code: "SSD", team: Code::Country(crate::SS), historical: false },
+    "STP" => SportsCode { code: "STP", team: Code::Country(crate::ST), historical: false },
+    "SUD" => SportsCode { code: "SUD", team: Code::Country(crate::SD), historical: false },
+    "SUI" => SportsCode { code: "SUI", team: Code::Country(crate::CH), historical: false },
+    "SUR" => SportsCode { code: "SUR", team: Code::Country(crate::SR), historical: false },
+    "SVK" => SportsCode { code: "SVK", team: Code::Country(crate::SK), historical: false },
+    "SWE" => SportsCode { code: "SWE", team: Code::Country(crate::SE), historical: false },
+    "SWZ" => SportsCode { code: "SWZ", team: Code::Country(crate::SZ), historical: false },
+    "SYR" => SportsCode { code: "SYR", team: Code::Country(crate::SY), historical: false },
+    "TAN" => SportsCode { code: "TAN", team: Code::Country(crate::TZ), historical: false },
+    "TGA" => SportsCode { code: "TGA", team: Code::Country(crate::TO), historical: false },
+    "THA" => SportsCode { code: "THA", team: Code::Country(crate::TH), historical: false },
+    "TJK" => SportsCode { code: "TJK", team: Code::Country(crate::TJ), historical: false },
+    "TKM" => SportsCode { code: "TKM", team: Code::Country(crate::TM), historical: false },
+    "TLS" => SportsCode { code: "TLS", team: Code::Country(crate::TL), historical: false },
+    "TOG" => SportsCode { code: "TOG", team: Code::Country(crate::TG), historical: false },
+    "TPE" => SportsCode { code: "TPE", team: Code::Country(crate::TW), historical: false },
+    "TTO" => SportsCode { code: "TTO", team: Code::Country(crate::TT), historical: false },
+    "TUN" => SportsCode { code: "TUN", team: Code::Country(crate::TN), historical: false },
+    "TUR" => SportsCode { code: "TUR", team: Code::Country(crate::TR), historical: false },
+    "TUV" => SportsCode { code: "TUV", team: Code::Country(crate::TV), historical: false },
+    "UAE" => SportsCode { code: "UAE", team: Code::Country(crate::AE), historical: false },
+    "UGA" => SportsCode { code: "UGA", team: Code::Country(crate::UG), historical: false },
+    "UKR" => SportsCode { code: "UKR", team: Code::Country(crate::UA), historical: false },
+    "URU" => SportsCode { code: "URU", team: Code::Country(crate::UY), historical: false },
+    "USA" => SportsCode { code: "USA", team: Code::Country(crate::US), historical: false },
+    "UZB" => SportsCode { code: "UZB", team: Code::Country(crate::UZ), historical: false },
+    "VAN" => SportsCode { code: "VAN", team: Code::Country(crate::VU), historical: false },
+    "VEN" => SportsCode { code: "VEN", team: Code::Country(crate::VE), historical: false },
+    "VIE" => SportsCode { code: "VIE", team: Code::Country(crate::VN), historical: false },
+    "VIN" => SportsCode { code: "VIN", team: Code::Country(crate::VC), historical: false },
+    "YEM" => SportsCode { code: "YEM", team: Code::Country(crate::YE), historical: false },
+    "ZAM" => SportsCode { code: "ZAM", team: Code::Country(crate::ZM), historical: false },
+    "ZIM" => SportsCode { code: "ZIM", team: Code::Country(crate::ZW), historical: false },
+};
+
+/// Current International Paralympic Committee codes by alpha-2 or ISO 3166-2 code.
+pub const IPC_TEAM_MAP: Map<&str, &str> = phf_map! {
+    "AD" => "AND",
+    "AE" => "UAE",
+    "AF" => "AFG",
+    "AG" => "ANT",
+    "AL" => "ALB",
+    "AM" => "ARM",
+    "AO" => "ANG",
+    "AR" => "ARG",
+    "AS" => "ASA",
+    "AT" => "AUT",
+    "AU" => "AUS",
+    "AW" => "ARU",
+    "AZ" => "AZE",
+    "BA" => "BIH",
+    "BB" => "BAR",
+    "BD" => "BAN",
+    "BE" => "BEL",
+    "BF" => "BUR",
+    "BG" => "BUL",
+    "BH" => "BRN",
+    "BI" => "BDI",
+    "BJ" => "BEN",
+    "BM" => "BER",
+    "BN" => "BRU",
+    "BO" => "BOL",
+    "BR" => "BRA",
+    "BS" => "BAH",
+    "BT" => "BHU",
+    "BW" => "BOT",
+    "BY" => "BLR",
+    "BZ" => "BIZ",
+    "CA" => "CAN",
+    "CD" => "COD",
+    "CF" => "CAF",
+    "CG" => "CGO",
+    "CH" => "SUI",
+    "CI" => "CIV",
+    "CK" => "COK",
+    "CL" => "CHI",
+    "CM" => "CMR",
+    "CN" => "CHN",
+    "CO" => "COL",
+    "CR" => "CRC",
+    "CU" => "CUB",
+    "CV" => "CPV",
+    "CY" => "CYP",
+    "CZ" => "CZE",
+    "DE" => "GER",
+    "DJ" => "DJI",
+    "DK" => "DEN",
+    "DM" => "DMA",
+    "DO" => "DOM",
+    "DZ" => "ALG",
+    "EC" => "ECU",
+    "EE" => "EST",
+    "EG" => "EGY",
+    "ER" => "ERI",
+    "ES" => "ESP",
+    "ET" => "ETH",
+    "FI" => "FIN",
+    "FJ" => "FIJ",
+    "FM" => "FSM",
+    "FO" => "FRO",
+    "FR" => "FRA",
+    "GA" => "GAB",
+    "GB" => "GBR",
+    "GD" => "GRN",
+    "GE" => "GEO",
+    "GH" => "GHA",
+    "GM" => "GAM",
+    "GN" => "GUI",
+    "GQ" => "GEQ",
+    "GR" => "GRE",
+    "GT" => "GUA",
+    "GU" => "GUM",
+    "GW" => "GBS",
+    "GY" => "GUY",
+    "HK" => "HKG",
+    "HN" => "HON",
+    "HR" => "CRO",
+    "HT" => "HAI",
+    "HU" => "HUN",
+    "ID" => "INA",
+    "IE" => "IRL",
+    "IL" => "ISR",
+    "IN" => "IND",
+    "IQ" => "IRQ",
+    "IR" => "IRI",
+    "IS" => "ISL",
+    "IT" => "ITA",
+    "JM" => "JAM",
+    "JO" => "JOR",
+    "JP" => "JPN",
+    "KE" => "KEN",
+    "KG" => "KGZ",
+    "KH" => "CAM",
+    "KI" => "KIR",
+    "KM" => "COM",
+    "KN" => "SKN",
+    "KP" => "PRK",
+    "KR" => "KOR",
+    "KW" => "KUW",
+    "KY" => "CAY",
+    "KZ" => "KAZ",
+    "LA" => "LAO",
+    "LB" => "LBN",
+    "LC" => "LCA",
+    "LI" => "LIE",
+    "LK" => "SRI",
+    "LR" => "LBR",
+    "LS" => "LES",
+    "LT" => "LTU",
+    "LU" => "LUX",
+    "LV" => "LAT",
+    "LY" => "LBA",
+    "MA" => "MAR",
+    "MC" => "MON",
+    "MD" => "MDA",
+    "ME" => "MNE",
+    "MG" => "MAD",
+    "MH" => "MHL",
+    "MK" => "MKD",
+    "ML" => "MLI",
+    "MM" => "MYA",
+    "MN" => "MGL",
+    "MO" => "MAC",
+    "MR" => "MTN",
+    "MT" => "MLT",
+    "MU" => "MRI",
+    "MV" => "MDV",
+    "MW" => "MAW",
+    "MX" => "MEX",
+    "MY" => "MAS",
+    "MZ" => "MOZ",
+    "NA" => "NAM",
+    "NE" => "NIG",
+    "NG" => "NGR",
+    "NI" => "NCA",
+    "NL" => "NED",
+    "NO" => "NOR",
+    "NP" => "NEP",
+    "NR" => "NRU",
+    "NZ" => "NZL",
+    "OM" => "OMA",
+    "PA" => "PAN",
+    "PE" => "PER",
+    "PG" => "PNG",
+    "PH" => "PHI",
+    "PK" => "PAK",
+    "PL" => "POL",
+    "PR" => "PUR",
+    "PS" => "PLE",
+    "PT" => "POR",
+    "PW" => "PLW",
+    "PY" => "PAR",
+    "QA" => "QAT",
+    "RO" => "ROU",
+    "RS" => "SRB",
+    "RU" => "RUS",
+    "RW" => "RWA",
+    "SA" => "KSA",
+    "SB" => "SOL",
+    "SC" => "SEY",
+    "SD" => "SUD",
+    "SE" => "SWE",
+    "SG" => "SGP",
+    "SI" => "SLO",
+    "SK" => "SVK",
+    "SL" => "SLE",
+    "SM" => "SMR",
+    "SN" => "SEN",
+    "SO" => "SOM",
+    "SR" => "SUR",
+    "SS" => "SSD",
+    "ST" => "STP",
+    "SV" => "ESA",
+    "SY" => "SYR",
+    "SZ" => "SWZ",
+    "TD" => "CHA",
+    "TG" => "TOG",
+    "TH" => "THA",
+    "TJ" => "TJK",
+    "TL" => "TLS",
+    "TM" => "TKM",
+    "TN" => "TUN",
+    "TO" => "TGA",
+    "TR" => "TUR",
+    "TT" => "TTO",
+    "TV" => "TUV",
+    "TW" => "TPE",
+    "TZ" => "TAN",
+    "UA" => "UKR",
+    "UG" => "UGA",
+    "US" => "USA",
+    "UY" => "URU",
+    "UZ" => "UZB",
+    "VC" => "VIN",
+    "VE" => "VEN",
+    "VG" => "IVB",
+    "VI" => "ISV",
+    "VN" => "VIE",
+    "VU" => "VAN",
+    "WS" => "SAM",
+    "YE" => "YEM",
+    "ZA" => "RSA",
+    "ZM" => "ZAM",
+    "ZW" => "ZIM",
+};
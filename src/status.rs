@@ -0,0 +1,215 @@
+use crate::iso3166_3::{self, CountryCode3};
+use crate::parse::{as_str, to_upper};
+use crate::{CountryCode, ParseError, ALPHA2_MAP, ALPHA3_MAP};
+use phf::phf_map;
+use phf::Map;
+
+/// How ISO 3166-1 treats an alpha-2 or alpha-3 code, as returned by
+/// `code_status`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CodeStatus {
+    ///Officially assigned to a country
+    Assigned(CountryCode),
+    ///Reserved on request of a national ISO member body, government or
+    ///international organization, such as `UK` or `EU`
+    ExceptionallyReserved(Reservation),
+    ///Deleted from ISO 3166-1 and kept from reuse for a period of time, such
+    ///as `BU` (Burma)
+    TransitionallyReserved(Reservation),
+    ///In use in another coding system, mostly international vehicle
+    ///registration and WIPO codes, such as `RA` (Argentina)
+    IndeterminatelyReserved(Reservation),
+    ///Free for users to assign: `AA`, `QM`–`QZ`, `XA`–`XZ` and `ZZ`, and the
+    ///alpha-3 codes starting with them. Commonly used for Kosovo (`XK`).
+    UserAssigned,
+    ///Not assigned, reserved or user-assigned
+    Unassigned,
+}
+
+/// Details of a reserved code element.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Reservation {
+    ///The reserved alpha-2 or alpha-3 code
+    pub code: &'static str,
+    ///What the code stands for
+    pub note: &'static str,
+    ///The deleted countries that used the code, if any
+    pub former: &'static [CountryCode3],
+}
+
+impl CodeStatus {
+    ///Return the reservation details of a reserved code
+    pub fn reservation(&self) -> Option<&Reservation> {
+        match self {
+            CodeStatus::ExceptionallyReserved(r)
+            | CodeStatus::TransitionallyReserved(r)
+            | CodeStatus::IndeterminatelyReserved(r) => Some(r),
+            _ => None,
+        }
+    }
+
+    ///Return true for exceptionally, transitionally and indeterminately
+    ///reserved codes
+    pub fn is_reserved(&self) -> bool {
+        self.reservation().is_some()
+    }
+}
+
+/// Returns how ISO 3166-1 treats an alpha-2 or alpha-3 code, ignoring ASCII
+/// case. Input that cannot be an alpha code at all is an error.
+///
+/// `EL`, which the European Union uses for Greece, is not reserved by ISO and
+/// comes out as `Unassigned`.
+/// #Sample
+/// ```
+/// use rust_iso3166::{code_status, CodeStatus};
+/// assert_eq!(Ok(CodeStatus::Assigned(rust_iso3166::GB)), code_status("GB"));
+/// let uk = code_status("uk").unwrap();
+/// assert_eq!("United Kingdom", uk.reservation().unwrap().note);
+/// let cs = code_status("CS").unwrap();
+/// assert_eq!("CSXX", cs.reservation().unwrap().former[0].code);
+/// assert_eq!(Ok(CodeStatus::UserAssigned), code_status("XK"));
+/// assert_eq!(Ok(CodeStatus::Unassigned), code_status("EL"));
+/// assert!(code_status("E1").is_err());
+/// ```
+pub fn code_status(code: &str) -> Result<CodeStatus, ParseError> {
+    let (buf, len) = to_upper::<3>(code, 2..=3, |_, c| c.is_ascii_alphabetic())?;
+    let code = as_str(&buf[..len]);
+    let assigned = match len {
+        2 => ALPHA2_MAP.get(code),
+        _ => ALPHA3_MAP.get(code),
+    };
+    if let Some(country) = assigned {
+        return Ok(CodeStatus::Assigned(*country));
+    }
+    if let Some(status) = RESERVED_MAP.get(code) {
+        return Ok(*status);
+    }
+    let user_assigned = match buf {
+        [b'A', b'A', _] | [b'X', _, _] | [b'Z', b'Z', _] => true,
+        [b'Q', second, _] => (b'M'..=b'Z').contains(&second),
+        _ => false,
+    };
+    Ok(if user_assigned {
+        CodeStatus::UserAssigned
+    } else {
+        CodeStatus::Unassigned
+    })
+}
+
+const fn exceptional(code: &'static str, note: &'static str) -> CodeStatus {
+    CodeStatus::ExceptionallyReserved(Reservation {
+        code,
+        note,
+        former: &[],
+    })
+}
+
+const fn transitional(
+    code: &'static str,
+    note: &'static str,
+    former: &'static [CountryCode3],
+) -> CodeStatus {
+    CodeStatus::TransitionallyReserved(Reservation { code, note, former })
+}
+
+const fn indeterminate(code: &'static str, note: &'static str) -> CodeStatus {
+    CodeStatus::IndeterminatelyReserved(Reservation {
+        code,
+        note,
+        former: &[],
+    })
+}
+
+/// Reserved alpha-2 and alpha-3 code elements.
+pub const RESERVED_MAP: Map<&str, CodeStatus> = phf_map! {
+    "AC" => exceptional("AC", "Ascension Island"),
+    "CP" => exceptional("CP", "Clipperton Island"),
+    "CQ" => exceptional("CQ", "Island of Sark"),
+    "DG" => exceptional("DG", "Diego Garcia"),
+    "EA" => exceptional("EA", "Ceuta, Melilla"),
+    "EU" => exceptional("EU", "European Union"),
+    "EZ" => exceptional("EZ", "Eurozone"),
+    "FX" => CodeStatus::ExceptionallyReserved(Reservation {
+        code: "FX",
+        note: "France, Metropolitan",
+        former: &[iso3166_3::FXFR],
+    }),
+    "IC" => exceptional("IC", "Canary Islands"),
+    "SU" => CodeStatus::ExceptionallyReserved(Reservation {
+        code: "SU",
+        note: "USSR",
+        former: &[iso3166_3::SUHH],
+    }),
+    "TA" => exceptional("TA", "Tristan da Cunha"),
+    "UK" => exceptional("UK", "United Kingdom"),
+    "UN" => exceptional("UN", "United Nations"),
+    "ASC" => exceptional("ASC", "Ascension Island"),
+    "CPT" => exceptional("CPT", "Clipperton Island"),
+    "DGA" => exceptional("DGA", "Diego Garcia"),
+    "FXX" => CodeStatus::ExceptionallyReserved(Reservation {
+        code: "FXX",
+        note: "France, Metropolitan",
+        former: &[iso3166_3::FXFR],
+    }),
+    "SUN" => CodeStatus::ExceptionallyReserved(Reservation {
+        code: "SUN",
+        note: "USSR",
+        former: &[iso3166_3::SUHH],
+    }),
+    "TAA" => exceptional("TAA", "Tristan da Cunha"),
+
+    "AN" => transitional("AN", "Netherlands Antilles, deleted 2010", &[iso3166_3::ANHH]),
+    "BU" => transitional("BU", "Burma, deleted 1989", &[iso3166_3::BUMM]),
+    "CS" => transitional(
+        "CS",
+        "Serbia and Montenegro, deleted 2006; Czechoslovakia, deleted 1993",
+        &[iso3166_3::CSXX, iso3166_3::CSHH],
+    ),
+    "NT" => transitional("NT", "Neutral Zone, deleted 1993", &[iso3166_3::NTHH]),
+    "SF" => transitional("SF", "Finland, international vehicle registration", &[]),
+    "TP" => transitional("TP", "East Timor, deleted 2002", &[iso3166_3::TPTL]),
+    "YU" => transitional("YU", "Yugoslavia, deleted 2003", &[iso3166_3::YUCS]),
+    "ZR" => transitional("ZR", "Zaire, deleted 1997", &[iso3166_3::ZRCD]),
+    "ANT" => transitional("ANT", "Netherlands Antilles, deleted 2010", &[iso3166_3::ANHH]),
+    "BUR" => transitional("BUR", "Burma, deleted 1989", &[iso3166_3::BUMM]),
+    "BYS" => transitional("BYS", "Byelorussian SSR, deleted 1992", &[iso3166_3::BYAA]),
+    "CSK" => transitional("CSK", "Czechoslovakia, deleted 1993", &[iso3166_3::CSHH]),
+    "NTZ" => transitional("NTZ", "Neutral Zone, deleted 1993", &[iso3166_3::NTHH]),
+    "ROM" => transitional("ROM", "Romania, changed to ROU in 2002", &[]),
+    "SCG" => transitional("SCG", "Serbia and Montenegro, deleted 2006", &[iso3166_3::CSXX]),
+    "TMP" => transitional("TMP", "East Timor, deleted 2002", &[iso3166_3::TPTL]),
+    "YUG" => transitional("YUG", "Yugoslavia, deleted 2003", &[iso3166_3::YUCS]),
+    "ZAR" => transitional("ZAR", "Zaire, deleted 1997", &[iso3166_3::ZRCD]),
+
+    "DY" => indeterminate("DY", "Benin, international vehicle registration"),
+    "EW" => indeterminate("EW", "Estonia, international vehicle registration"),
+    "FL" => indeterminate("FL", "Liechtenstein, international vehicle registration"),
+    "JA" => indeterminate("JA", "Jamaica, international vehicle registration"),
+    "LF" => indeterminate("LF", "Libya Fezzan, international vehicle registration"),
+    "PI" => indeterminate("PI", "Philippines, international vehicle registration"),
+    "RA" => indeterminate("RA", "Argentina, international vehicle registration"),
+    "RB" => indeterminate("RB", "Bolivia, Botswana, international vehicle registration"),
+    "RC" => indeterminate("RC", "China, international vehicle registration"),
+    "RH" => indeterminate("RH", "Haiti, international vehicle registration"),
+    "RI" => indeterminate("RI", "Indonesia, international vehicle registration"),
+    "RL" => indeterminate("RL", "Lebanon, international vehicle registration"),
+    "RM" => indeterminate("RM", "Madagascar, international vehicle registration"),
+    "RN" => indeterminate("RN", "Niger, international vehicle registration"),
+    "RP" => indeterminate("RP", "Philippines, international vehicle registration"),
+    "WG" => indeterminate("WG", "Grenada, international vehicle registration"),
+    "WL" => indeterminate("WL", "Saint Lucia, international vehicle registration"),
+    "WV" => indeterminate("WV", "Saint Vincent, international vehicle registration"),
+    "YV" => indeterminate("YV", "Venezuela, international vehicle registration"),
+    "AP" => indeterminate("AP", "African Regional Intellectual Property Organization, WIPO"),
+    "BX" => indeterminate("BX", "Benelux Office for Intellectual Property, WIPO"),
+    "EF" => indeterminate("EF", "Union of Countries under the European Community Patent Convention, WIPO"),
+    "EM" => indeterminate("EM", "European Union Intellectual Property Office, WIPO"),
+    "EP" => indeterminate("EP", "European Patent Organization, WIPO"),
+    "EV" => indeterminate("EV", "Eurasian Patent Organization, WIPO"),
+    "GC" => indeterminate("GC", "Patent Office of the Gulf Cooperation Council, WIPO"),
+    "IB" => indeterminate("IB", "International Bureau of WIPO"),
+    "OA" => indeterminate("OA", "African Intellectual Property Organization, WIPO"),
+    "WO" => indeterminate("WO", "World Intellectual Property Organization"),
+};
@@ -0,0 +1,935 @@
+use crate::iso3166_2::Subdivision;
+use crate::CountryCode;
+use phf::phf_map;
+use phf::Map;
+
+impl CountryCode {
+    ///Return the IANA time zones of the country, such as `Europe/Berlin` and
+    ///`Europe/Busingen` for Germany, from the tz database's zone.tab. The
+    ///zone of the capital or of most people comes first, so it makes a
+    ///sensible default. Empty for uninhabited places such as Bouvet Island.
+    pub fn time_zones(&self) -> &'static [&'static str] {
+        COUNTRY_TIME_ZONE_MAP.get(self.alpha2).copied().unwrap_or(&[])
+    }
+
+    ///Return `time_zones` as `chrono_tz::Tz` values
+    #[cfg(feature = "chrono-tz")]
+    pub fn time_zones_tz(&self) -> Vec<chrono_tz::Tz> {
+        self.time_zones().iter().filter_map(|z| z.parse().ok()).collect()
+    }
+}
+
+impl Subdivision {
+    ///Return the IANA time zones of the subdivision, main zone first, such
+    ///as `America/Los_Angeles` for `US-CA` or `Australia/Perth` and
+    ///`Australia/Eucla` for `AU-WA`. Only states, provinces and territories
+    ///of the United States, Canada and Australia are covered; elsewhere this
+    ///is empty and the country's `time_zones` apply.
+    pub fn time_zones(&self) -> &'static [&'static str] {
+        SUBDIVISION_TIME_ZONE_MAP.get(self.code).copied().unwrap_or(&[])
+    }
+
+    ///Return `time_zones` as `chrono_tz::Tz` values
+    #[cfg(feature = "chrono-tz")]
+    pub fn time_zones_tz(&self) -> Vec<chrono_tz::Tz> {
+        self.time_zones().iter().filter_map(|z| z.parse().ok()).collect()
+    }
+}
+
+/// Returns the countries whose clocks follow an IANA time zone, the country
+/// listing it in zone.tab first.
+///
+/// Countries that zone1970.tab groups under the zone follow, so
+/// `Europe/Zurich` also returns Germany (for Büsingen) and Liechtenstein.
+/// Legacy names linked to a zone, such as `US/Pacific` or `Asia/Calcutta`,
+/// are accepted. Names are case-sensitive, as in the tz database.
+/// #Sample
+/// ```
+/// use rust_iso3166::countries_for_zone;
+/// assert_eq!(&[rust_iso3166::CH, rust_iso3166::DE, rust_iso3166::LI], countries_for_zone("Europe/Zurich"));
+/// assert_eq!(&[rust_iso3166::IN], countries_for_zone("Asia/Calcutta"));
+/// assert!(countries_for_zone("Etc/UTC").is_empty());
+/// assert_eq!(&["America/Los_Angeles"], rust_iso3166::iso3166_2::from_code("US-CA").unwrap().time_zones());
+/// ```
+pub fn countries_for_zone(zone: &str) -> &'static [CountryCode] {
+    ZONE_COUNTRY_MAP.get(zone.trim()).copied().unwrap_or(&[])
+}
+
+/// IANA time zones by alpha-2 code.
+pub const COUNTRY_TIME_ZONE_MAP: Map<&str, &[&str]> = phf_map! {
+    "AD" => &["Europe/Andorra"],
+    "AE" => &["Asia/Dubai"],
+    "AF" => &["Asia/Kabul"],
+    "AG" => &["America/Antigua"],
+    "AI" => &["America/Anguilla"],
+    "AL" => &["Europe/Tirane"],
+    "AM" => &["Asia/Yerevan"],
+    "AO" => &["Africa/Luanda"],
+    "AQ" => &["Antarctica/McMurdo", "Antarctica/Casey", "Antarctica/Davis", "Antarctica/DumontDUrville", "Antarctica/Mawson", "Antarctica/Palmer", "Antarctica/Rothera", "Antarctica/Syowa", "Antarctica/Troll", "Antarctica/Vostok"],
+    "AR" => &["America/Argentina/Buenos_Aires", "America/Argentina/Cordoba", "America/Argentina/Salta", "America/Argentina/Jujuy", "America/Argentina/Tucuman", "America/Argentina/Catamarca", "America/Argentina/La_Rioja", "America/Argentina/San_Juan", "America/Argentina/Mendoza", "America/Argentina/San_Luis", "America/Argentina/Rio_Gallegos", "America/Argentina/Ushuaia"],
+    "AS" => &["Pacific/Pago_Pago"],
+    "AT" => &["Europe/Vienna"],
+    "AU" => &["Australia/Sydney", "Australia/Lord_Howe", "Antarctica/Macquarie", "Australia/Hobart", "Australia/Melbourne", "Australia/Broken_Hill", "Australia/Brisbane", "Australia/Lindeman", "Australia/Adelaide", "Australia/Darwin", "Australia/Perth", "Australia/Eucla"],
+    "AW" => &["America/Aruba"],
+    "AX" => &["Europe/Mariehamn"],
+    "AZ" => &["Asia/Baku"],
+    "BA" => &["Europe/Sarajevo"],
+    "BB" => &["America/Barbados"],
+    "BD" => &["Asia/Dhaka"],
+    "BE" => &["Europe/Brussels"],
+    "BF" => &["Africa/Ouagadougou"],
+    "BG" => &["Europe/Sofia"],
+    "BH" => &["Asia/Bahrain"],
+    "BI" => &["Africa/Bujumbura"],
+    "BJ" => &["Africa/Porto-Novo"],
+    "BL" => &["America/St_Barthelemy"],
+    "BM" => &["Atlantic/Bermuda"],
+    "BN" => &["Asia/Brunei"],
+    "BO" => &["America/La_Paz"],
+    "BQ" => &["America/Kralendijk"],
+    "BR" => &["America/Sao_Paulo", "America/Noronha", "America/Belem", "America/Fortaleza", "America/Recife", "America/Araguaina", "America/Maceio", "America/Bahia", "America/Campo_Grande", "America/Cuiaba", "America/Santarem", "America/Porto_Velho", "America/Boa_Vista", "America/Manaus", "America/Eirunepe", "America/Rio_Branco"],
+    "BS" => &["America/Nassau"],
+    "BT" => &["Asia/Thimphu"],
+    "BW" => &["Africa/Gaborone"],
+    "BY" => &["Europe/Minsk"],
+    "BZ" => &["America/Belize"],
+    "CA" => &["America/Toronto", "America/St_Johns", "America/Halifax", "America/Glace_Bay", "America/Moncton", "America/Goose_Bay", "America/Blanc-Sablon", "America/Iqaluit", "America/Atikokan", "America/Winnipeg", "America/Resolute", "America/Rankin_Inlet", "America/Regina", "America/Swift_Current", "America/Edmonton", "America/Cambridge_Bay", "America/Inuvik", "America/Creston", "America/Dawson_Creek", "America/Fort_Nelson", "America/Whitehorse", "America/Dawson", "America/Vancouver"],
+    "CC" => &["Indian/Cocos"],
+    "CD" => &["Africa/Kinshasa", "Africa/Lubumbashi"],
+    "CF" => &["Africa/Bangui"],
+    "CG" => &["Africa/Brazzaville"],
+    "CH" => &["Europe/Zurich"],
+    "CI" => &["Africa/Abidjan"],
+    "CK" => &["Pacific/Rarotonga"],
+    "CL" => &["America/Santiago", "America/Coyhaique", "America/Punta_Arenas", "Pacific/Easter"],
+    "CM" => &["Africa/Douala"],
+    "CN" => &["Asia/Shanghai", "Asia/Urumqi"],
+    "CO" => &["America/Bogota"],
+    "CR" => &["America/Costa_Rica"],
+    "CU" => &["America/Havana"],
+    "CV" => &["Atlantic/Cape_Verde"],
+    "CW" => &["America/Curacao"],
+    "CX" => &["Indian/Christmas"],
+    "CY" => &["Asia/Nicosia", "Asia/Famagusta"],
+    "CZ" => &["Europe/Prague"],
+    "DE" => &["Europe/Berlin", "Europe/Busingen"],
+    "DJ" => &["Africa/Djibouti"],
+    "DK" => &["Europe/Copenhagen"],
+    "DM" => &["America/Dominica"],
+    "DO" => &["America/Santo_Domingo"],
+    "DZ" => &["Africa/Algiers"],
+    "EC" => &["America/Guayaquil", "Pacific/Galapagos"],
+    "EE" => &["Europe/Tallinn"],
+    "EG" => &["Africa/Cairo"],
+    "EH" => &["Africa/El_Aaiun"],
+    "ER" => &["Africa/Asmara"],
+    "ES" => &["Europe/Madrid", "Africa/Ceuta", "Atlantic/Canary"],
+    "ET" => &["Africa/Addis_Ababa"],
+    "FI" => &["Europe/Helsinki"],
+    "FJ" => &["Pacific/Fiji"],
+    "FK" => &["Atlantic/Stanley"],
+    "FM" => &["Pacific/Pohnpei", "Pacific/Chuuk", "Pacific/Kosrae"],
+    "FO" => &["Atlantic/Faroe"],
+    "FR" => &["Europe/Paris"],
+    "GA" => &["Africa/Libreville"],
+    "GB" => &["Europe/London"],
+    "GD" => &["America/Grenada"],
+    "GE" => &["Asia/Tbilisi"],
+    "GF" => &["America/Cayenne"],
+    "GG" => &["Europe/Guernsey"],
+    "GH" => &["Africa/Accra"],
+    "GI" => &["Europe/Gibraltar"],
+    "GL" => &["America/Nuuk", "America/Danmarkshavn", "America/Scoresbysund", "America/Thule"],
+    "GM" => &["Africa/Banjul"],
+    "GN" => &["Africa/Conakry"],
+    "GP" => &["America/Guadeloupe"],
+    "GQ" => &["Africa/Malabo"],
+    "GR" => &["Europe/Athens"],
+    "GS" => &["Atlantic/South_Georgia"],
+    "GT" => &["America/Guatemala"],
+    "GU" => &["Pacific/Guam"],
+    "GW" => &["Africa/Bissau"],
+    "GY" => &["America/Guyana"],
+    "HK" => &["Asia/Hong_Kong"],
+    "HN" => &["America/Tegucigalpa"],
+    "HR" => &["Europe/Zagreb"],
+    "HT" => &["America/Port-au-Prince"],
+    "HU" => &["Europe/Budapest"],
+    "ID" => &["Asia/Jakarta", "Asia/Pontianak", "Asia/Makassar", "Asia/Jayapura"],
+    "IE" => &["Europe/Dublin"],
+    "IL" => &["Asia/Jerusalem"],
+    "IM" => &["Europe/Isle_of_Man"],
+    "IN" => &["Asia/Kolkata"],
+    "IO" => &["Indian/Chagos"],
+    "IQ" => &["Asia/Baghdad"],
+    "IR" => &["Asia/Tehran"],
+    "IS" => &["Atlantic/Reykjavik"],
+    "IT" => &["Europe/Rome"],
+    "JE" => &["Europe/Jersey"],
+    "JM" => &["America/Jamaica"],
+    "JO" => &["Asia/Amman"],
+    "JP" => &["Asia/Tokyo"],
+    "KE" => &["Africa/Nairobi"],
+    "KG" => &["Asia/Bishkek"],
+    "KH" => &["Asia/Phnom_Penh"],
+    "KI" => &["Pacific/Tarawa", "Pacific/Kanton", "Pacific/Kiritimati"],
+    "KM" => &["Indian/Comoro"],
+    "KN" => &["America/St_Kitts"],
+    "KP" => &["Asia/Pyongyang"],
+    "KR" => &["Asia/Seoul"],
+    "KW" => &["Asia/Kuwait"],
+    "KY" => &["America/Cayman"],
+    "KZ" => &["Asia/Almaty", "Asia/Qyzylorda", "Asia/Qostanay", "Asia/Aqtobe", "Asia/Aqtau", "Asia/Atyrau", "Asia/Oral"],
+    "LA" => &["Asia/Vientiane"],
+    "LB" => &["Asia/Beirut"],
+    "LC" => &["America/St_Lucia"],
+    "LI" => &["Europe/Vaduz"],
+    "LK" => &["Asia/Colombo"],
+    "LR" => &["Africa/Monrovia"],
+    "LS" => &["Africa/Maseru"],
+    "LT" => &["Europe/Vilnius"],
+    "LU" => &["Europe/Luxembourg"],
+    "LV" => &["Europe/Riga"],
+    "LY" => &["Africa/Tripoli"],
+    "MA" => &["Africa/Casablanca"],
+    "MC" => &["Europe/Monaco"],
+    "MD" => &["Europe/Chisinau"],
+    "ME" => &["Europe/Podgorica"],
+    "MF" => &["America/Marigot"],
+    "MG" => &["Indian/Antananarivo"],
+    "MH" => &["Pacific/Majuro", "Pacific/Kwajalein"],
+    "MK" => &["Europe/Skopje"],
+    "ML" => &["Africa/Bamako"],
+    "MM" => &["Asia/Yangon"],
+    "MN" => &["Asia/Ulaanbaatar", "Asia/Hovd"],
+    "MO" => &["Asia/Macau"],
+    "MP" => &["Pacific/Saipan"],
+    "MQ" => &["America/Martinique"],
+    "MR" => &["Africa/Nouakchott"],
+    "MS" => &["America/Montserrat"],
+    "MT" => &["Europe/Malta"],
+    "MU" => &["Indian/Mauritius"],
+    "MV" => &["Indian/Maldives"],
+    "MW" => &["Africa/Blantyre"],
+    "MX" => &["America/Mexico_City", "America/Cancun", "America/Merida", "America/Monterrey", "America/Matamoros", "America/Chihuahua", "America/Ciudad_Juarez", "America/Ojinaga", "America/Mazatlan", "America/Bahia_Banderas", "America/Hermosillo", "America/Tijuana"],
+    "MY" => &["Asia/Kuala_Lumpur", "Asia/Kuching"],
+    "MZ" => &["Africa/Maputo"],
+    "NA" => &["Africa/Windhoek"],
+    "NC" => &["Pacific/Noumea"],
+    "NE" => &["Africa/Niamey"],
+    "NF" => &["Pacific/Norfolk"],
+    "NG" => &["Africa/Lagos"],
+    "NI" => &["America/Managua"],
+    "NL" => &["Europe/Amsterdam"],
+    "NO" => &["Europe/Oslo"],
+    "NP" => &["Asia/Kathmandu"],
+    "NR" => &["Pacific/Nauru"],
+    "NU" => &["Pacific/Niue"],
+    "NZ" => &["Pacific/Auckland", "Pacific/Chatham"],
+    "OM" => &["Asia/Muscat"],
+    "PA" => &["America/Panama"],
+    "PE" => &["America/Lima"],
+    "PF" => &["Pacific/Tahiti", "Pacific/Marquesas", "Pacific/Gambier"],
+    "PG" => &["Pacific/Port_Moresby", "Pacific/Bougainville"],
+    "PH" => &["Asia/Manila"],
+    "PK" => &["Asia/Karachi"],
+    "PL" => &["Europe/Warsaw"],
+    "PM" => &["America/Miquelon"],
+    "PN" => &["Pacific/Pitcairn"],
+    "PR" => &["America/Puerto_Rico"],
+    "PS" => &["Asia/Hebron", "Asia/Gaza"],
+    "PT" => &["Europe/Lisbon", "Atlantic/Madeira", "Atlantic/Azores"],
+    "PW" => &["Pacific/Palau"],
+    "PY" => &["America/Asuncion"],
+    "QA" => &["Asia/Qatar"],
+    "RE" => &["Indian/Reunion"],
+    "RO" => &["Europe/Bucharest"],
+    "RS" => &["Europe/Belgrade"],
+    "RU" => &["Europe/Moscow", "Europe/Kaliningrad", "Europe/Kirov", "Europe/Volgograd", "Europe/Astrakhan", "Europe/Saratov", "Europe/Ulyanovsk", "Europe/Samara", "Asia/Yekaterinburg", "Asia/Omsk", "Asia/Novosibirsk", "Asia/Barnaul", "Asia/Tomsk", "Asia/Novokuznetsk", "Asia/Krasnoyarsk", "Asia/Irkutsk", "Asia/Chita", "Asia/Yakutsk", "Asia/Khandyga", "Asia/Vladivostok", "Asia/Ust-Nera", "Asia/Magadan", "Asia/Sakhalin", "Asia/Srednekolymsk", "Asia/Kamchatka", "Asia/Anadyr"],
+    "RW" => &["Africa/Kigali"],
+    "SA" => &["Asia/Riyadh"],
+    "SB" => &["Pacific/Guadalcanal"],
+    "SC" => &["Indian/Mahe"],
+    "SD" => &["Africa/Khartoum"],
+    "SE" => &["Europe/Stockholm"],
+    "SG" => &["Asia/Singapore"],
+    "SH" => &["Atlantic/St_Helena"],
+    "SI" => &["Europe/Ljubljana"],
+    "SJ" => &["Arctic/Longyearbyen"],
+    "SK" => &["Europe/Bratislava"],
+    "SL" => &["Africa/Freetown"],
+    "SM" => &["Europe/San_Marino"],
+    "SN" => &["Africa/Dakar"],
+    "SO" => &["Africa/Mogadishu"],
+    "SR" => &["America/Paramaribo"],
+    "SS" => &["Africa/Juba"],
+    "ST" => &["Africa/Sao_Tome"],
+    "SV" => &["America/El_Salvador"],
+    "SX" => &["America/Lower_Princes"],
+    "SY" => &["Asia/Damascus"],
+    "SZ" => &["Africa/Mbabane"],
+    "TC" => &["America/Grand_Turk"],
+    "TD" => &["Africa/Ndjamena"],
+    "TF" => &["Indian/Kerguelen"],
+    "TG" => &["Africa/Lome"],
+    "TH" => &["Asia/Bangkok"],
+    "TJ" => &["Asia/Dushanbe"],
+    "TK" => &["Pacific/Fakaofo"],
+    "TL" => &["Asia/Dili"],
+    "TM" => &["Asia/Ashgabat"],
+    "TN" => &["Africa/Tunis"],
+    "TO" => &["Pacific/Tongatapu"],
+    "TR" => &["Europe/Istanbul"],
+    "TT" => &["America/Port_of_Spain"],
+    "TV" => &["Pacific/Funafuti"],
+    "TW" => &["Asia/Taipei"],
+    "TZ" => &["Africa/Dar_es_Salaam"],
+    "UA" => &["Europe/Kyiv", "Europe/Simferopol"],
+    "UG" => &["Africa/Kampala"],
+    "UM" => &["Pacific/Midway", "Pacific/Wake"],
+    "US" => &["America/New_York", "America/Detroit", "America/Kentucky/Louisville", "America/Kentucky/Monticello", "America/Indiana/Indianapolis", "America/Indiana/Vincennes", "America/Indiana/Winamac", "America/Indiana/Marengo", "America/Indiana/Petersburg", "America/Indiana/Vevay", "America/Chicago", "America/Indiana/Tell_City", "America/Indiana/Knox", "America/Menominee", "America/North_Dakota/Center", "America/North_Dakota/New_Salem", "America/North_Dakota/Beulah", "America/Denver", "America/Boise", "America/Phoenix", "America/Los_Angeles", "America/Anchorage", "America/Juneau", "America/Sitka", "America/Metlakatla", "America/Yakutat", "America/Nome", "America/Adak", "Pacific/Honolulu"],
+    "UY" => &["America/Montevideo"],
+    "UZ" => &["Asia/Tashkent", "Asia/Samarkand"],
+    "VA" => &["Europe/Vatican"],
+    "VC" => &["America/St_Vincent"],
+    "VE" => &["America/Caracas"],
+    "VG" => &["America/Tortola"],
+    "VI" => &["America/St_Thomas"],
+    "VN" => &["Asia/Ho_Chi_Minh"],
+    "VU" => &["Pacific/Efate"],
+    "WF" => &["Pacific/Wallis"],
+    "WS" => &["Pacific/Apia"],
+    "YE" => &["Asia/Aden"],
+    "YT" => &["Indian/Mayotte"],
+    "ZA" => &["Africa/Johannesburg"],
+    "ZM" => &["Africa/Lusaka"],
+    "ZW" => &["Africa/Harare"],
+};
+
+/// IANA time zones by ISO 3166-2 code.
+pub const SUBDIVISION_TIME_ZONE_MAP: Map<&str, &[&str]> = phf_map! {
+    "AU-ACT" => &["Australia/Sydney"],
+    "AU-NSW" => &["Australia/Sydney", "Australia/Broken_Hill", "Australia/Lord_Howe"],
+    "AU-NT" => &["Australia/Darwin"],
+    "AU-QLD" => &["Australia/Brisbane", "Australia/Lindeman"],
+    "AU-SA" => &["Australia/Adelaide"],
+    "AU-TAS" => &["Australia/Hobart", "Antarctica/Macquarie"],
+    "AU-VIC" => &["Australia/Melbourne"],
+    "AU-WA" => &["Australia/Perth", "Australia/Eucla"],
+    "CA-AB" => &["America/Edmonton"],
+    "CA-BC" => &["America/Vancouver", "America/Edmonton", "America/Creston", "America/Dawson_Creek", "America/Fort_Nelson"],
+    "CA-MB" => &["America/Winnipeg"],
+    "CA-NB" => &["America/Moncton"],
+    "CA-NL" => &["America/St_Johns", "America/Goose_Bay"],
+    "CA-NS" => &["America/Halifax", "America/Glace_Bay"],
+    "CA-NT" => &["America/Edmonton", "America/Inuvik"],
+    "CA-NU" => &["America/Iqaluit", "America/Rankin_Inlet", "America/Cambridge_Bay", "America/Resolute", "America/Atikokan"],
+    "CA-ON" => &["America/Toronto", "America/Winnipeg", "America/Atikokan"],
+    "CA-PE" => &["America/Halifax"],
+    "CA-QC" => &["America/Toronto", "America/Blanc-Sablon"],
+    "CA-SK" => &["America/Regina", "America/Swift_Current", "America/Edmonton"],
+    "CA-YT" => &["America/Whitehorse", "America/Dawson"],
+    "US-AK" => &["America/Anchorage", "America/Juneau", "America/Sitka", "America/Metlakatla", "America/Yakutat", "America/Nome", "America/Adak"],
+    "US-AL" => &["America/Chicago"],
+    "US-AR" => &["America/Chicago"],
+    "US-AS" => &["Pacific/Pago_Pago"],
+    "US-AZ" => &["America/Phoenix", "America/Denver"],
+    "US-CA" => &["America/Los_Angeles"],
+    "US-CO" => &["America/Denver"],
+    "US-CT" => &["America/New_York"],
+    "US-DC" => &["America/New_York"],
+    "US-DE" => &["America/New_York"],
+    "US-FL" => &["America/New_York", "America/Chicago"],
+    "US-GA" => &["America/New_York"],
+    "US-GU" => &["Pacific/Guam"],
+    "US-HI" => &["Pacific/Honolulu"],
+    "US-IA" => &["America/Chicago"],
+    "US-ID" => &["America/Boise", "America/Los_Angeles"],
+    "US-IL" => &["America/Chicago"],
+    "US-IN" => &["America/Indiana/Indianapolis", "America/Indiana/Vincennes", "America/Indiana/Winamac", "America/Indiana/Marengo", "America/Indiana/Petersburg", "America/Indiana/Vevay", "America/Indiana/Tell_City", "America/Indiana/Knox", "America/Chicago"],
+    "US-KS" => &["America/Chicago", "America/Denver"],
+    "US-KY" => &["America/New_York", "America/Kentucky/Louisville", "America/Kentucky/Monticello", "America/Chicago"],
+    "US-LA" => &["America/Chicago"],
+    "US-MA" => &["America/New_York"],
+    "US-MD" => &["America/New_York"],
+    "US-ME" => &["America/New_York"],
+    "US-MI" => &["America/Detroit", "America/Menominee"],
+    "US-MN" => &["America/Chicago"],
+    "US-MO" => &["America/Chicago"],
+    "US-MP" => &["Pacific/Saipan"],
+    "US-MS" => &["America/Chicago"],
+    "US-MT" => &["America/Denver"],
+    "US-NC" => &["America/New_York"],
+    "US-ND" => &["America/Chicago", "America/North_Dakota/Center", "America/North_Dakota/New_Salem", "America/North_Dakota/Beulah", "America/Denver"],
+    "US-NE" => &["America/Chicago", "America/Denver"],
+    "US-NH" => &["America/New_York"],
+    "US-NJ" => &["America/New_York"],
+    "US-NM" => &["America/Denver"],
+    "US-NV" => &["America/Los_Angeles", "America/Denver"],
+    "US-NY" => &["America/New_York"],
+    "US-OH" => &["America/New_York"],
+    "US-OK" => &["America/Chicago"],
+    "US-OR" => &["America/Los_Angeles", "America/Boise"],
+    "US-PA" => &["America/New_York"],
+    "US-PR" => &["America/Puerto_Rico"],
+    "US-RI" => &["America/New_York"],
+    "US-SC" => &["America/New_York"],
+    "US-SD" => &["America/Chicago", "America/Denver"],
+    "US-TN" => &["America/Chicago", "America/New_York"],
+    "US-TX" => &["America/Chicago", "America/Denver"],
+    "US-UM" => &["Pacific/Midway", "Pacific/Wake"],
+    "US-UT" => &["America/Denver"],
+    "US-VA" => &["America/New_York"],
+    "US-VI" => &["America/St_Thomas"],
+    "US-VT" => &["America/New_York"],
+    "US-WA" => &["America/Los_Angeles"],
+    "US-WI" => &["America/Chicago"],
+    "US-WV" => &["America/New_York"],
+    "US-WY" => &["America/Denver"],
+};
+
+/// Countries by IANA time zone, including legacy linked names.
+pub const ZONE_COUNTRY_MAP: Map<&str, &[CountryCode]> = phf_map! {
+    "Africa/Abidjan" => &[crate::CI, crate::BF, crate::GH, crate::GM, crate::GN, crate::IS, crate::ML, crate::MR, crate::SH, crate::SL, crate::SN, crate::TG],
+    "Africa/Accra" => &[crate::GH],
+    "Africa/Addis_Ababa" => &[crate::ET],
+    "Africa/Algiers" => &[crate::DZ],
+    "Africa/Asmara" => &[crate::ER],
+    "Africa/Asmera" => &[crate::KE, crate::DJ, crate::ER, crate::ET, crate::KM, crate::MG, crate::SO, crate::TZ, crate::UG, crate::YT],
+    "Africa/Bamako" => &[crate::ML],
+    "Africa/Bangui" => &[crate::CF],
+    "Africa/Banjul" => &[crate::GM],
+    "Africa/Bissau" => &[crate::GW],
+    "Africa/Blantyre" => &[crate::MW],
+    "Africa/Brazzaville" => &[crate::CG],
+    "Africa/Bujumbura" => &[crate::BI],
+    "Africa/Cairo" => &[crate::EG],
+    "Africa/Casablanca" => &[crate::MA],
+    "Africa/Ceuta" => &[crate::ES],
+    "Africa/Conakry" => &[crate::GN],
+    "Africa/Dakar" => &[crate::SN],
+    "Africa/Dar_es_Salaam" => &[crate::TZ],
+    "Africa/Djibouti" => &[crate::DJ],
+    "Africa/Douala" => &[crate::CM],
+    "Africa/El_Aaiun" => &[crate::EH],
+    "Africa/Freetown" => &[crate::SL],
+    "Africa/Gaborone" => &[crate::BW],
+    "Africa/Harare" => &[crate::ZW],
+    "Africa/Johannesburg" => &[crate::ZA, crate::LS, crate::SZ],
+    "Africa/Juba" => &[crate::SS],
+    "Africa/Kampala" => &[crate::UG],
+    "Africa/Khartoum" => &[crate::SD],
+    "Africa/Kigali" => &[crate::RW],
+    "Africa/Kinshasa" => &[crate::CD],
+    "Africa/Lagos" => &[crate::NG, crate::AO, crate::BJ, crate::CD, crate::CF, crate::CG, crate::CM, crate::GA, crate::GQ, crate::NE],
+    "Africa/Libreville" => &[crate::GA],
+    "Africa/Lome" => &[crate::TG],
+    "Africa/Luanda" => &[crate::AO],
+    "Africa/Lubumbashi" => &[crate::CD],
+    "Africa/Lusaka" => &[crate::ZM],
+    "Africa/Malabo" => &[crate::GQ],
+    "Africa/Maputo" => &[crate::MZ, crate::BI, crate::BW, crate::CD, crate::MW, crate::RW, crate::ZM, crate::ZW],
+    "Africa/Maseru" => &[crate::LS],
+    "Africa/Mbabane" => &[crate::SZ],
+    "Africa/Mogadishu" => &[crate::SO],
+    "Africa/Monrovia" => &[crate::LR],
+    "Africa/Nairobi" => &[crate::KE, crate::DJ, crate::ER, crate::ET, crate::KM, crate::MG, crate::SO, crate::TZ, crate::UG, crate::YT],
+    "Africa/Ndjamena" => &[crate::TD],
+    "Africa/Niamey" => &[crate::NE],
+    "Africa/Nouakchott" => &[crate::MR],
+    "Africa/Ouagadougou" => &[crate::BF],
+    "Africa/Porto-Novo" => &[crate::BJ],
+    "Africa/Sao_Tome" => &[crate::ST],
+    "Africa/Timbuktu" => &[crate::CI, crate::BF, crate::GH, crate::GM, crate::GN, crate::IS, crate::ML, crate::MR, crate::SH, crate::SL, crate::SN, crate::TG],
+    "Africa/Tripoli" => &[crate::LY],
+    "Africa/Tunis" => &[crate::TN],
+    "Africa/Windhoek" => &[crate::NA],
+    "America/Adak" => &[crate::US],
+    "America/Anchorage" => &[crate::US],
+    "America/Anguilla" => &[crate::AI],
+    "America/Antigua" => &[crate::AG],
+    "America/Araguaina" => &[crate::BR],
+    "America/Argentina/Buenos_Aires" => &[crate::AR],
+    "America/Argentina/Catamarca" => &[crate::AR],
+    "America/Argentina/ComodRivadavia" => &[crate::AR],
+    "America/Argentina/Cordoba" => &[crate::AR],
+    "America/Argentina/Jujuy" => &[crate::AR],
+    "America/Argentina/La_Rioja" => &[crate::AR],
+    "America/Argentina/Mendoza" => &[crate::AR],
+    "America/Argentina/Rio_Gallegos" => &[crate::AR],
+    "America/Argentina/Salta" => &[crate::AR],
+    "America/Argentina/San_Juan" => &[crate::AR],
+    "America/Argentina/San_Luis" => &[crate::AR],
+    "America/Argentina/Tucuman" => &[crate::AR],
+    "America/Argentina/Ushuaia" => &[crate::AR],
+    "America/Aruba" => &[crate::AW],
+    "America/Asuncion" => &[crate::PY],
+    "America/Atikokan" => &[crate::CA],
+    "America/Atka" => &[crate::US],
+    "America/Bahia" => &[crate::BR],
+    "America/Bahia_Banderas" => &[crate::MX],
+    "America/Barbados" => &[crate::BB],
+    "America/Belem" => &[crate::BR],
+    "America/Belize" => &[crate::BZ],
+    "America/Blanc-Sablon" => &[crate::CA],
+    "America/Boa_Vista" => &[crate::BR],
+    "America/Bogota" => &[crate::CO],
+    "America/Boise" => &[crate::US],
+    "America/Buenos_Aires" => &[crate::AR],
+    "America/Cambridge_Bay" => &[crate::CA],
+    "America/Campo_Grande" => &[crate::BR],
+    "America/Cancun" => &[crate::MX],
+    "America/Caracas" => &[crate::VE],
+    "America/Catamarca" => &[crate::AR],
+    "America/Cayenne" => &[crate::GF],
+    "America/Cayman" => &[crate::KY],
+    "America/Chicago" => &[crate::US],
+    "America/Chihuahua" => &[crate::MX],
+    "America/Ciudad_Juarez" => &[crate::MX],
+    "America/Coral_Harbour" => &[crate::PA, crate::CA, crate::KY],
+    "America/Cordoba" => &[crate::AR],
+    "America/Costa_Rica" => &[crate::CR],
+    "America/Coyhaique" => &[crate::CL],
+    "America/Creston" => &[crate::CA],
+    "America/Cuiaba" => &[crate::BR],
+    "America/Curacao" => &[crate::CW],
+    "America/Danmarkshavn" => &[crate::GL],
+    "America/Dawson" => &[crate::CA],
+    "America/Dawson_Creek" => &[crate::CA],
+    "America/Denver" => &[crate::US],
+    "America/Detroit" => &[crate::US],
+    "America/Dominica" => &[crate::DM],
+    "America/Edmonton" => &[crate::CA],
+    "America/Eirunepe" => &[crate::BR],
+    "America/El_Salvador" => &[crate::SV],
+    "America/Ensenada" => &[crate::MX],
+    "America/Fort_Nelson" => &[crate::CA],
+    "America/Fort_Wayne" => &[crate::US],
+    "America/Fortaleza" => &[crate::BR],
+    "America/Glace_Bay" => &[crate::CA],
+    "America/Godthab" => &[crate::GL],
+    "America/Goose_Bay" => &[crate::CA],
+    "America/Grand_Turk" => &[crate::TC],
+    "America/Grenada" => &[crate::GD],
+    "America/Guadeloupe" => &[crate::GP],
+    "America/Guatemala" => &[crate::GT],
+    "America/Guayaquil" => &[crate::EC],
+    "America/Guyana" => &[crate::GY],
+    "America/Halifax" => &[crate::CA],
+    "America/Havana" => &[crate::CU],
+    "America/Hermosillo" => &[crate::MX],
+    "America/Indiana/Indianapolis" => &[crate::US],
+    "America/Indiana/Knox" => &[crate::US],
+    "America/Indiana/Marengo" => &[crate::US],
+    "America/Indiana/Petersburg" => &[crate::US],
+    "America/Indiana/Tell_City" => &[crate::US],
+    "America/Indiana/Vevay" => &[crate::US],
+    "America/Indiana/Vincennes" => &[crate::US],
+    "America/Indiana/Winamac" => &[crate::US],
+    "America/Indianapolis" => &[crate::US],
+    "America/Inuvik" => &[crate::CA],
+    "America/Iqaluit" => &[crate::CA],
+    "America/Jamaica" => &[crate::JM],
+    "America/Jujuy" => &[crate::AR],
+    "America/Juneau" => &[crate::US],
+    "America/Kentucky/Louisville" => &[crate::US],
+    "America/Kentucky/Monticello" => &[crate::US],
+    "America/Knox_IN" => &[crate::US],
+    "America/Kralendijk" => &[crate::BQ],
+    "America/La_Paz" => &[crate::BO],
+    "America/Lima" => &[crate::PE],
+    "America/Los_Angeles" => &[crate::US],
+    "America/Louisville" => &[crate::US],
+    "America/Lower_Princes" => &[crate::SX],
+    "America/Maceio" => &[crate::BR],
+    "America/Managua" => &[crate::NI],
+    "America/Manaus" => &[crate::BR],
+    "America/Marigot" => &[crate::MF],
+    "America/Martinique" => &[crate::MQ],
+    "America/Matamoros" => &[crate::MX],
+    "America/Mazatlan" => &[crate::MX],
+    "America/Mendoza" => &[crate::AR],
+    "America/Menominee" => &[crate::US],
+    "America/Merida" => &[crate::MX],
+    "America/Metlakatla" => &[crate::US],
+    "America/Mexico_City" => &[crate::MX],
+    "America/Miquelon" => &[crate::PM],
+    "America/Moncton" => &[crate::CA],
+    "America/Monterrey" => &[crate::MX],
+    "America/Montevideo" => &[crate::UY],
+    "America/Montreal" => &[crate::CA, crate::BS],
+    "America/Montserrat" => &[crate::MS],
+    "America/Nassau" => &[crate::BS],
+    "America/New_York" => &[crate::US],
+    "America/Nipigon" => &[crate::CA, crate::BS],
+    "America/Nome" => &[crate::US],
+    "America/Noronha" => &[crate::BR],
+    "America/North_Dakota/Beulah" => &[crate::US],
+    "America/North_Dakota/Center" => &[crate::US],
+    "America/North_Dakota/New_Salem" => &[crate::US],
+    "America/Nuuk" => &[crate::GL],
+    "America/Ojinaga" => &[crate::MX],
+    "America/Panama" => &[crate::PA, crate::CA, crate::KY],
+    "America/Pangnirtung" => &[crate::CA],
+    "America/Paramaribo" => &[crate::SR],
+    "America/Phoenix" => &[crate::US, crate::CA],
+    "America/Port-au-Prince" => &[crate::HT],
+    "America/Port_of_Spain" => &[crate::TT],
+    "America/Porto_Acre" => &[crate::BR],
+    "America/Porto_Velho" => &[crate::BR],
+    "America/Puerto_Rico" => &[crate::PR, crate::AG, crate::CA, crate::AI, crate::AW, crate::BL, crate::BQ, crate::CW, crate::DM, crate::GD, crate::GP, crate::KN, crate::LC, crate::MF, crate::MS, crate::SX, crate::TT, crate::VC, crate::VG, crate::VI],
+    "America/Punta_Arenas" => &[crate::CL],
+    "America/Rainy_River" => &[crate::CA],
+    "America/Rankin_Inlet" => &[crate::CA],
+    "America/Recife" => &[crate::BR],
+    "America/Regina" => &[crate::CA],
+    "America/Resolute" => &[crate::CA],
+    "America/Rio_Branco" => &[crate::BR],
+    "America/Rosario" => &[crate::AR],
+    "America/Santa_Isabel" => &[crate::MX],
+    "America/Santarem" => &[crate::BR],
+    "America/Santiago" => &[crate::CL],
+    "America/Santo_Domingo" => &[crate::DO],
+    "America/Sao_Paulo" => &[crate::BR],
+    "America/Scoresbysund" => &[crate::GL],
+    "America/Shiprock" => &[crate::US],
+    "America/Sitka" => &[crate::US],
+    "America/St_Barthelemy" => &[crate::BL],
+    "America/St_Johns" => &[crate::CA],
+    "America/St_Kitts" => &[crate::KN],
+    "America/St_Lucia" => &[crate::LC],
+    "America/St_Thomas" => &[crate::VI],
+    "America/St_Vincent" => &[crate::VC],
+    "America/Swift_Current" => &[crate::CA],
+    "America/Tegucigalpa" => &[crate::HN],
+    "America/Thule" => &[crate::GL],
+    "America/Thunder_Bay" => &[crate::CA, crate::BS],
+    "America/Tijuana" => &[crate::MX],
+    "America/Toronto" => &[crate::CA, crate::BS],
+    "America/Tortola" => &[crate::VG],
+    "America/Vancouver" => &[crate::CA],
+    "America/Virgin" => &[crate::PR, crate::AG, crate::CA, crate::AI, crate::AW, crate::BL, crate::BQ, crate::CW, crate::DM, crate::GD, crate::GP, crate::KN, crate::LC, crate::MF, crate::MS, crate::SX, crate::TT, crate::VC, crate::VG, crate::VI],
+    "America/Whitehorse" => &[crate::CA],
+    "America/Winnipeg" => &[crate::CA],
+    "America/Yakutat" => &[crate::US],
+    "America/Yellowknife" => &[crate::CA],
+    "Antarctica/Casey" => &[crate::AQ],
+    "Antarctica/Davis" => &[crate::AQ],
+    "Antarctica/DumontDUrville" => &[crate::AQ],
+    "Antarctica/Macquarie" => &[crate::AU],
+    "Antarctica/Mawson" => &[crate::AQ],
+    "Antarctica/McMurdo" => &[crate::AQ],
+    "Antarctica/Palmer" => &[crate::AQ],
+    "Antarctica/Rothera" => &[crate::AQ],
+    "Antarctica/South_Pole" => &[crate::NZ, crate::AQ],
+    "Antarctica/Syowa" => &[crate::AQ],
+    "Antarctica/Troll" => &[crate::AQ],
+    "Antarctica/Vostok" => &[crate::AQ],
+    "Arctic/Longyearbyen" => &[crate::SJ],
+    "Asia/Aden" => &[crate::YE],
+    "Asia/Almaty" => &[crate::KZ],
+    "Asia/Amman" => &[crate::JO],
+    "Asia/Anadyr" => &[crate::RU],
+    "Asia/Aqtau" => &[crate::KZ],
+    "Asia/Aqtobe" => &[crate::KZ],
+    "Asia/Ashgabat" => &[crate::TM],
+    "Asia/Ashkhabad" => &[crate::TM],
+    "Asia/Atyrau" => &[crate::KZ],
+    "Asia/Baghdad" => &[crate::IQ],
+    "Asia/Bahrain" => &[crate::BH],
+    "Asia/Baku" => &[crate::AZ],
+    "Asia/Bangkok" => &[crate::TH, crate::CX, crate::KH, crate::LA, crate::VN],
+    "Asia/Barnaul" => &[crate::RU],
+    "Asia/Beirut" => &[crate::LB],
+    "Asia/Bishkek" => &[crate::KG],
+    "Asia/Brunei" => &[crate::BN],
+    "Asia/Calcutta" => &[crate::IN],
+    "Asia/Chita" => &[crate::RU],
+    "Asia/Choibalsan" => &[crate::MN],
+    "Asia/Chongqing" => &[crate::CN],
+    "Asia/Chungking" => &[crate::CN],
+    "Asia/Colombo" => &[crate::LK],
+    "Asia/Dacca" => &[crate::BD],
+    "Asia/Damascus" => &[crate::SY],
+    "Asia/Dhaka" => &[crate::BD],
+    "Asia/Dili" => &[crate::TL],
+    "Asia/Dubai" => &[crate::AE, crate::OM, crate::RE, crate::SC, crate::TF],
+    "Asia/Dushanbe" => &[crate::TJ],
+    "Asia/Famagusta" => &[crate::CY],
+    "Asia/Gaza" => &[crate::PS],
+    "Asia/Harbin" => &[crate::CN],
+    "Asia/Hebron" => &[crate::PS],
+    "Asia/Ho_Chi_Minh" => &[crate::VN],
+    "Asia/Hong_Kong" => &[crate::HK],
+    "Asia/Hovd" => &[crate::MN],
+    "Asia/Irkutsk" => &[crate::RU],
+    "Asia/Istanbul" => &[crate::TR],
+    "Asia/Jakarta" => &[crate::ID],
+    "Asia/Jayapura" => &[crate::ID],
+    "Asia/Jerusalem" => &[crate::IL],
+    "Asia/Kabul" => &[crate::AF],
+    "Asia/Kamchatka" => &[crate::RU],
+    "Asia/Karachi" => &[crate::PK],
+    "Asia/Kashgar" => &[crate::CN],
+    "Asia/Kathmandu" => &[crate::NP],
+    "Asia/Katmandu" => &[crate::NP],
+    "Asia/Khandyga" => &[crate::RU],
+    "Asia/Kolkata" => &[crate::IN],
+    "Asia/Krasnoyarsk" => &[crate::RU],
+    "Asia/Kuala_Lumpur" => &[crate::MY],
+    "Asia/Kuching" => &[crate::MY, crate::BN],
+    "Asia/Kuwait" => &[crate::KW],
+    "Asia/Macao" => &[crate::MO],
+    "Asia/Macau" => &[crate::MO],
+    "Asia/Magadan" => &[crate::RU],
+    "Asia/Makassar" => &[crate::ID],
+    "Asia/Manila" => &[crate::PH],
+    "Asia/Muscat" => &[crate::OM],
+    "Asia/Nicosia" => &[crate::CY],
+    "Asia/Novokuznetsk" => &[crate::RU],
+    "Asia/Novosibirsk" => &[crate::RU],
+    "Asia/Omsk" => &[crate::RU],
+    "Asia/Oral" => &[crate::KZ],
+    "Asia/Phnom_Penh" => &[crate::KH],
+    "Asia/Pontianak" => &[crate::ID],
+    "Asia/Pyongyang" => &[crate::KP],
+    "Asia/Qatar" => &[crate::QA, crate::BH],
+    "Asia/Qostanay" => &[crate::KZ],
+    "Asia/Qyzylorda" => &[crate::KZ],
+    "Asia/Rangoon" => &[crate::MM, crate::CC],
+    "Asia/Riyadh" => &[crate::SA, crate::AQ, crate::KW, crate::YE],
+    "Asia/Saigon" => &[crate::VN],
+    "Asia/Sakhalin" => &[crate::RU],
+    "Asia/Samarkand" => &[crate::UZ],
+    "Asia/Seoul" => &[crate::KR],
+    "Asia/Shanghai" => &[crate::CN],
+    "Asia/Singapore" => &[crate::SG, crate::AQ, crate::MY],
+    "Asia/Srednekolymsk" => &[crate::RU],
+    "Asia/Taipei" => &[crate::TW],
+    "Asia/Tashkent" => &[crate::UZ],
+    "Asia/Tbilisi" => &[crate::GE],
+    "Asia/Tehran" => &[crate::IR],
+    "Asia/Tel_Aviv" => &[crate::IL],
+    "Asia/Thimbu" => &[crate::BT],
+    "Asia/Thimphu" => &[crate::BT],
+    "Asia/Tokyo" => &[crate::JP, crate::AU],
+    "Asia/Tomsk" => &[crate::RU],
+    "Asia/Ujung_Pandang" => &[crate::ID],
+    "Asia/Ulaanbaatar" => &[crate::MN],
+    "Asia/Ulan_Bator" => &[crate::MN],
+    "Asia/Urumqi" => &[crate::CN],
+    "Asia/Ust-Nera" => &[crate::RU],
+    "Asia/Vientiane" => &[crate::LA],
+    "Asia/Vladivostok" => &[crate::RU],
+    "Asia/Yakutsk" => &[crate::RU],
+    "Asia/Yangon" => &[crate::MM, crate::CC],
+    "Asia/Yekaterinburg" => &[crate::RU],
+    "Asia/Yerevan" => &[crate::AM],
+    "Atlantic/Azores" => &[crate::PT],
+    "Atlantic/Bermuda" => &[crate::BM],
+    "Atlantic/Canary" => &[crate::ES],
+    "Atlantic/Cape_Verde" => &[crate::CV],
+    "Atlantic/Faeroe" => &[crate::FO],
+    "Atlantic/Faroe" => &[crate::FO],
+    "Atlantic/Jan_Mayen" => &[crate::DE, crate::DK, crate::NO, crate::SE, crate::SJ],
+    "Atlantic/Madeira" => &[crate::PT],
+    "Atlantic/Reykjavik" => &[crate::IS],
+    "Atlantic/South_Georgia" => &[crate::GS],
+    "Atlantic/St_Helena" => &[crate::SH],
+    "Atlantic/Stanley" => &[crate::FK],
+    "Australia/ACT" => &[crate::AU],
+    "Australia/Adelaide" => &[crate::AU],
+    "Australia/Brisbane" => &[crate::AU],
+    "Australia/Broken_Hill" => &[crate::AU],
+    "Australia/Canberra" => &[crate::AU],
+    "Australia/Currie" => &[crate::AU],
+    "Australia/Darwin" => &[crate::AU],
+    "Australia/Eucla" => &[crate::AU],
+    "Australia/Hobart" => &[crate::AU],
+    "Australia/LHI" => &[crate::AU],
+    "Australia/Lindeman" => &[crate::AU],
+    "Australia/Lord_Howe" => &[crate::AU],
+    "Australia/Melbourne" => &[crate::AU],
+    "Australia/NSW" => &[crate::AU],
+    "Australia/North" => &[crate::AU],
+    "Australia/Perth" => &[crate::AU],
+    "Australia/Queensland" => &[crate::AU],
+    "Australia/South" => &[crate::AU],
+    "Australia/Sydney" => &[crate::AU],
+    "Australia/Tasmania" => &[crate::AU],
+    "Australia/Victoria" => &[crate::AU],
+    "Australia/West" => &[crate::AU],
+    "Australia/Yancowinna" => &[crate::AU],
+    "Brazil/Acre" => &[crate::BR],
+    "Brazil/DeNoronha" => &[crate::BR],
+    "Brazil/East" => &[crate::BR],
+    "Brazil/West" => &[crate::BR],
+    "Canada/Atlantic" => &[crate::CA],
+    "Canada/Central" => &[crate::CA],
+    "Canada/Eastern" => &[crate::CA, crate::BS],
+    "Canada/Mountain" => &[crate::CA],
+    "Canada/Newfoundland" => &[crate::CA],
+    "Canada/Pacific" => &[crate::CA],
+    "Canada/Saskatchewan" => &[crate::CA],
+    "Canada/Yukon" => &[crate::CA],
+    "Chile/Continental" => &[crate::CL],
+    "Chile/EasterIsland" => &[crate::CL],
+    "Cuba" => &[crate::CU],
+    "Egypt" => &[crate::EG],
+    "Eire" => &[crate::IE],
+    "Europe/Amsterdam" => &[crate::NL],
+    "Europe/Andorra" => &[crate::AD],
+    "Europe/Astrakhan" => &[crate::RU],
+    "Europe/Athens" => &[crate::GR],
+    "Europe/Belfast" => &[crate::GB, crate::GG, crate::IM, crate::JE],
+    "Europe/Belgrade" => &[crate::RS, crate::BA, crate::HR, crate::ME, crate::MK, crate::SI],
+    "Europe/Berlin" => &[crate::DE, crate::DK, crate::NO, crate::SE, crate::SJ],
+    "Europe/Bratislava" => &[crate::SK],
+    "Europe/Brussels" => &[crate::BE, crate::LU, crate::NL],
+    "Europe/Bucharest" => &[crate::RO],
+    "Europe/Budapest" => &[crate::HU],
+    "Europe/Busingen" => &[crate::DE],
+    "Europe/Chisinau" => &[crate::MD],
+    "Europe/Copenhagen" => &[crate::DK],
+    "Europe/Dublin" => &[crate::IE],
+    "Europe/Gibraltar" => &[crate::GI],
+    "Europe/Guernsey" => &[crate::GG],
+    "Europe/Helsinki" => &[crate::FI, crate::AX],
+    "Europe/Isle_of_Man" => &[crate::IM],
+    "Europe/Istanbul" => &[crate::TR],
+    "Europe/Jersey" => &[crate::JE],
+    "Europe/Kaliningrad" => &[crate::RU],
+    "Europe/Kiev" => &[crate::UA],
+    "Europe/Kirov" => &[crate::RU],
+    "Europe/Kyiv" => &[crate::UA],
+    "Europe/Lisbon" => &[crate::PT],
+    "Europe/Ljubljana" => &[crate::SI],
+    "Europe/London" => &[crate::GB, crate::GG, crate::IM, crate::JE],
+    "Europe/Luxembourg" => &[crate::LU],
+    "Europe/Madrid" => &[crate::ES],
+    "Europe/Malta" => &[crate::MT],
+    "Europe/Mariehamn" => &[crate::AX],
+    "Europe/Minsk" => &[crate::BY],
+    "Europe/Monaco" => &[crate::MC],
+    "Europe/Moscow" => &[crate::RU],
+    "Europe/Nicosia" => &[crate::CY],
+    "Europe/Oslo" => &[crate::NO],
+    "Europe/Paris" => &[crate::FR, crate::MC],
+    "Europe/Podgorica" => &[crate::ME],
+    "Europe/Prague" => &[crate::CZ, crate::SK],
+    "Europe/Riga" => &[crate::LV],
+    "Europe/Rome" => &[crate::IT, crate::SM, crate::VA],
+    "Europe/Samara" => &[crate::RU],
+    "Europe/San_Marino" => &[crate::SM],
+    "Europe/Sarajevo" => &[crate::BA],
+    "Europe/Saratov" => &[crate::RU],
+    "Europe/Simferopol" => &[crate::UA, crate::RU],
+    "Europe/Skopje" => &[crate::MK],
+    "Europe/Sofia" => &[crate::BG],
+    "Europe/Stockholm" => &[crate::SE],
+    "Europe/Tallinn" => &[crate::EE],
+    "Europe/Tirane" => &[crate::AL],
+    "Europe/Tiraspol" => &[crate::MD],
+    "Europe/Ulyanovsk" => &[crate::RU],
+    "Europe/Uzhgorod" => &[crate::UA],
+    "Europe/Vaduz" => &[crate::LI],
+    "Europe/Vatican" => &[crate::VA],
+    "Europe/Vienna" => &[crate::AT],
+    "Europe/Vilnius" => &[crate::LT],
+    "Europe/Volgograd" => &[crate::RU],
+    "Europe/Warsaw" => &[crate::PL],
+    "Europe/Zagreb" => &[crate::HR],
+    "Europe/Zaporozhye" => &[crate::UA],
+    "Europe/Zurich" => &[crate::CH, crate::DE, crate::LI],
+    "GB" => &[crate::GB, crate::GG, crate::IM, crate::JE],
+    "GB-Eire" => &[crate::GB, crate::GG, crate::IM, crate::JE],
+    "Hongkong" => &[crate::HK],
+    "Iceland" => &[crate::CI, crate::BF, crate::GH, crate::GM, crate::GN, crate::IS, crate::ML, crate::MR, crate::SH, crate::SL, crate::SN, crate::TG],
+    "Indian/Antananarivo" => &[crate::MG],
+    "Indian/Chagos" => &[crate::IO],
+    "Indian/Christmas" => &[crate::CX],
+    "Indian/Cocos" => &[crate::CC],
+    "Indian/Comoro" => &[crate::KM],
+    "Indian/Kerguelen" => &[crate::TF],
+    "Indian/Mahe" => &[crate::SC],
+    "Indian/Maldives" => &[crate::MV, crate::TF],
+    "Indian/Mauritius" => &[crate::MU],
+    "Indian/Mayotte" => &[crate::YT],
+    "Indian/Reunion" => &[crate::RE],
+    "Iran" => &[crate::IR],
+    "Israel" => &[crate::IL],
+    "Jamaica" => &[crate::JM],
+    "Japan" => &[crate::JP, crate::AU],
+    "Kwajalein" => &[crate::MH],
+    "Libya" => &[crate::LY],
+    "Mexico/BajaNorte" => &[crate::MX],
+    "Mexico/BajaSur" => &[crate::MX],
+    "Mexico/General" => &[crate::MX],
+    "NZ" => &[crate::NZ, crate::AQ],
+    "NZ-CHAT" => &[crate::NZ],
+    "Navajo" => &[crate::US],
+    "PRC" => &[crate::CN],
+    "Pacific/Apia" => &[crate::WS],
+    "Pacific/Auckland" => &[crate::NZ, crate::AQ],
+    "Pacific/Bougainville" => &[crate::PG],
+    "Pacific/Chatham" => &[crate::NZ],
+    "Pacific/Chuuk" => &[crate::FM],
+    "Pacific/Easter" => &[crate::CL],
+    "Pacific/Efate" => &[crate::VU],
+    "Pacific/Enderbury" => &[crate::KI],
+    "Pacific/Fakaofo" => &[crate::TK],
+    "Pacific/Fiji" => &[crate::FJ],
+    "Pacific/Funafuti" => &[crate::TV],
+    "Pacific/Galapagos" => &[crate::EC],
+    "Pacific/Gambier" => &[crate::PF],
+    "Pacific/Guadalcanal" => &[crate::SB, crate::FM],
+    "Pacific/Guam" => &[crate::GU, crate::MP],
+    "Pacific/Honolulu" => &[crate::US],
+    "Pacific/Johnston" => &[crate::US],
+    "Pacific/Kanton" => &[crate::KI],
+    "Pacific/Kiritimati" => &[crate::KI],
+    "Pacific/Kosrae" => &[crate::FM],
+    "Pacific/Kwajalein" => &[crate::MH],
+    "Pacific/Majuro" => &[crate::MH],
+    "Pacific/Marquesas" => &[crate::PF],
+    "Pacific/Midway" => &[crate::UM],
+    "Pacific/Nauru" => &[crate::NR],
+    "Pacific/Niue" => &[crate::NU],
+    "Pacific/Norfolk" => &[crate::NF],
+    "Pacific/Noumea" => &[crate::NC],
+    "Pacific/Pago_Pago" => &[crate::AS, crate::UM],
+    "Pacific/Palau" => &[crate::PW],
+    "Pacific/Pitcairn" => &[crate::PN],
+    "Pacific/Pohnpei" => &[crate::FM],
+    "Pacific/Ponape" => &[crate::SB, crate::FM],
+    "Pacific/Port_Moresby" => &[crate::PG, crate::AQ, crate::FM],
+    "Pacific/Rarotonga" => &[crate::CK],
+    "Pacific/Saipan" => &[crate::MP],
+    "Pacific/Samoa" => &[crate::AS, crate::UM],
+    "Pacific/Tahiti" => &[crate::PF],
+    "Pacific/Tarawa" => &[crate::KI, crate::MH, crate::TV, crate::UM, crate::WF],
+    "Pacific/Tongatapu" => &[crate::TO],
+    "Pacific/Truk" => &[crate::PG, crate::AQ, crate::FM],
+    "Pacific/Wake" => &[crate::UM],
+    "Pacific/Wallis" => &[crate::WF],
+    "Pacific/Yap" => &[crate::PG, crate::AQ, crate::FM],
+    "Poland" => &[crate::PL],
+    "Portugal" => &[crate::PT],
+    "ROC" => &[crate::TW],
+    "ROK" => &[crate::KR],
+    "Singapore" => &[crate::SG, crate::AQ, crate::MY],
+    "Turkey" => &[crate::TR],
+    "US/Alaska" => &[crate::US],
+    "US/Aleutian" => &[crate::US],
+    "US/Arizona" => &[crate::US, crate::CA],
+    "US/Central" => &[crate::US],
+    "US/East-Indiana" => &[crate::US],
+    "US/Eastern" => &[crate::US],
+    "US/Hawaii" => &[crate::US],
+    "US/Indiana-Starke" => &[crate::US],
+    "US/Michigan" => &[crate::US],
+    "US/Mountain" => &[crate::US],
+    "US/Pacific" => &[crate::US],
+    "US/Samoa" => &[crate::AS, crate::UM],
+    "W-SU" => &[crate::RU],
+};
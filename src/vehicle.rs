@@ -0,0 +1,465 @@
+use crate::iso3166_3;
+use crate::parse::{as_str, to_upper};
+use crate::{Code, CountryCode};
+use phf::phf_map;
+use phf::Map;
+
+/// An international vehicle registration distinguishing sign, as shown on
+/// the oval plate required by the 1949 and 1968 road traffic conventions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct VehicleSign {
+    ///The sign, such as `D` or `RSM`
+    pub sign: &'static str,
+    ///The country, or the former country for historical signs
+    pub code: Code,
+    ///True for signs no longer in use, such as `SU` or `DDR`
+    pub historical: bool,
+}
+
+impl CountryCode {
+    ///Return the international vehicle registration distinguishing sign,
+    ///such as `D` for Germany or `RSM` for San Marino. Guernsey is `GBG`;
+    ///Alderney's own `GBA` only resolves back through `from_vehicle_sign`.
+    pub fn vehicle_sign(&self) -> Option<&'static str> {
+        COUNTRY_VEHICLE_SIGN_MAP.get(self.alpha2).copied()
+    }
+}
+
+/// Returns the country or former country of an international vehicle
+/// registration distinguishing sign, if exists. ASCII case and surrounding
+/// whitespace are ignored.
+///
+/// Signs are not ISO codes: `SD` is Eswatini, not Sudan, and `RC` is Taiwan.
+/// Historical signs are included; those of dissolved countries point to
+/// their ISO 3166-3 entry.
+/// #Sample
+/// ```
+/// use rust_iso3166::{from_vehicle_sign, Code};
+/// assert_eq!(Code::Country(rust_iso3166::DE), from_vehicle_sign("D").unwrap().code);
+/// assert_eq!(Code::Country(rust_iso3166::SM), from_vehicle_sign("rsm").unwrap().code);
+/// assert_eq!(Code::Country(rust_iso3166::SZ), from_vehicle_sign("SD").unwrap().code);
+/// let gdr = from_vehicle_sign("DDR").unwrap();
+/// assert_eq!("DDDE", gdr.code.code());
+/// assert!(gdr.historical);
+/// ```
+pub fn from_vehicle_sign(sign: &str) -> Option<VehicleSign> {
+    let (buf, len) = to_upper::<3>(sign.trim(), 1..=3, |_, c| c.is_ascii_alphabetic()).ok()?;
+    VEHICLE_SIGN_MAP.get(as_str(&buf[..len])).copied()
+}
+
+const fn current(sign: &'static str, country: CountryCode) -> VehicleSign {
+    VehicleSign {
+        sign,
+        code: Code::Country(country),
+        historical: false,
+    }
+}
+
+const fn historical(sign: &'static str, code: Code) -> VehicleSign {
+    VehicleSign {
+        sign,
+        code,
+        historical: true,
+    }
+}
+
+/// Current distinguishing signs by alpha-2 code.
+pub const COUNTRY_VEHICLE_SIGN_MAP: Map<&str, &str> = phf_map! {
+    "AD" => "AND",
+    "AE" => "UAE",
+    "AF" => "AFG",
+    "AL" => "AL",
+    "AM" => "AM",
+    "AO" => "ANG",
+    "AR" => "RA",
+    "AT" => "A",
+    "AU" => "AUS",
+    "AX" => "AX",
+    "AZ" => "AZ",
+    "BA" => "BIH",
+    "BB" => "BDS",
+    "BD" => "BD",
+    "BE" => "B",
+    "BF" => "BF",
+    "BG" => "BG",
+    "BH" => "BRN",
+    "BI" => "RU",
+    "BJ" => "DY",
+    "BN" => "BRU",
+    "BO" => "BOL",
+    "BR" => "BR",
+    "BS" => "BS",
+    "BT" => "BHT",
+    "BW" => "RB",
+    "BY" => "BY",
+    "BZ" => "BH",
+    "CA" => "CDN",
+    "CD" => "CGO",
+    "CF" => "RCA",
+    "CG" => "RCB",
+    "CH" => "CH",
+    "CI" => "CI",
+    "CL" => "RCH",
+    "CM" => "CAM",
+    "CO" => "CO",
+    "CR" => "CR",
+    "CU" => "C",
+    "CV" => "CV",
+    "CY" => "CY",
+    "CZ" => "CZ",
+    "DE" => "D",
+    "DJ" => "DJI",
+    "DK" => "DK",
+    "DM" => "WD",
+    "DO" => "DOM",
+    "DZ" => "DZ",
+    "EC" => "EC",
+    "EE" => "EST",
+    "EG" => "ET",
+    "ER" => "ER",
+    "ES" => "E",
+    "ET" => "ETH",
+    "FI" => "FIN",
+    "FJ" => "FJI",
+    "FM" => "FSM",
+    "FO" => "FO",
+    "FR" => "F",
+    "GA" => "G",
+    "GB" => "GB",
+    "GD" => "WG",
+    "GE" => "GE",
+    "GG" => "GBG",
+    "GH" => "GH",
+    "GI" => "GBZ",
+    "GM" => "WAG",
+    "GN" => "RG",
+    "GQ" => "GQ",
+    "GR" => "GR",
+    "GT" => "GCA",
+    "GW" => "GW",
+    "GY" => "GUY",
+    "HK" => "HK",
+    "HN" => "HN",
+    "HR" => "HR",
+    "HT" => "RH",
+    "HU" => "H",
+    "ID" => "RI",
+    "IE" => "IRL",
+    "IL" => "IL",
+    "IM" => "GBM",
+    "IN" => "IND",
+    "IQ" => "IRQ",
+    "IR" => "IR",
+    "IS" => "IS",
+    "IT" => "I",
+    "JE" => "GBJ",
+    "JM" => "JA",
+    "JO" => "JOR",
+    "JP" => "J",
+    "KE" => "EAK",
+    "KG" => "KS",
+    "KH" => "K",
+    "KR" => "ROK",
+    "KW" => "KWT",
+    "KZ" => "KZ",
+    "LA" => "LAO",
+    "LB" => "RL",
+    "LC" => "WL",
+    "LI" => "FL",
+    "LK" => "CL",
+    "LR" => "LB",
+    "LS" => "LS",
+    "LT" => "LT",
+    "LU" => "L",
+    "LV" => "LV",
+    "LY" => "LAR",
+    "MA" => "MA",
+    "MC" => "MC",
+    "MD" => "MD",
+    "ME" => "MNE",
+    "MG" => "RM",
+    "MK" => "NMK",
+    "ML" => "RMM",
+    "MM" => "MYA",
+    "MN" => "MGL",
+    "MR" => "RIM",
+    "MT" => "M",
+    "MU" => "MS",
+    "MV" => "MV",
+    "MW" => "MW",
+    "MX" => "MEX",
+    "MY" => "MAL",
+    "MZ" => "MOC",
+    "NA" => "NAM",
+    "NE" => "RN",
+    "NG" => "WAN",
+    "NI" => "NIC",
+    "NL" => "NL",
+    "NO" => "N",
+    "NP" => "NEP",
+    "NR" => "NAU",
+    "NZ" => "NZ",
+    "OM" => "OM",
+    "PA" => "PA",
+    "PE" => "PE",
+    "PG" => "PNG",
+    "PH" => "RP",
+    "PK" => "PK",
+    "PL" => "PL",
+    "PT" => "P",
+    "PY" => "PY",
+    "QA" => "Q",
+    "RO" => "RO",
+    "RS" => "SRB",
+    "RU" => "RUS",
+    "RW" => "RWA",
+    "SA" => "KSA",
+    "SC" => "SY",
+    "SD" => "SUD",
+    "SE" => "S",
+    "SG" => "SGP",
+    "SI" => "SLO",
+    "SK" => "SK",
+    "SL" => "WAL",
+    "SM" => "RSM",
+    "SN" => "SN",
+    "SO" => "SO",
+    "SR" => "SME",
+    "ST" => "STP",
+    "SV" => "ES",
+    "SY" => "SYR",
+    "SZ" => "SD",
+    "TD" => "TCH",
+    "TG" => "TG",
+    "TH" => "T",
+    "TJ" => "TJ",
+    "TL" => "TL",
+    "TM" => "TM",
+    "TN" => "TN",
+    "TR" => "TR",
+    "TT" => "TT",
+    "TW" => "RC",
+    "TZ" => "EAT",
+    "UA" => "UA",
+    "UG" => "EAU",
+    "US" => "USA",
+    "UY" => "UY",
+    "UZ" => "UZ",
+    "VA" => "V",
+    "VC" => "WV",
+    "VE" => "YV",
+    "VN" => "VN",
+    "WS" => "WS",
+    "ZA" => "ZA",
+    "ZM" => "Z",
+    "ZW" => "ZW",
+};
+
+/// Current and historical distinguishing signs.
+pub const VEHICLE_SIGN_MAP: Map<&str, VehicleSign> = phf_map! {
+    "A" => current("A", crate::AT),
+    "AFG" => current("AFG", crate::AF),
+    "AL" => current("AL", crate::AL),
+    "AM" => current("AM", crate::AM),
+    "AND" => current("AND", crate::AD),
+    "ANG" => current("ANG", crate::AO),
+    "AUS" => current("AUS", crate::AU),
+    "AX" => current("AX", crate::AX),
+    "AZ" => current("AZ", crate::AZ),
+    "B" => current("B", crate::BE),
+    "BD" => current("BD", crate::BD),
+    "BDS" => current("BDS", crate::BB),
+    "BF" => current("BF", crate::BF),
+    "BG" => current("BG", crate::BG),
+    "BH" => current("BH", crate::BZ),
+    "BHT" => current("BHT", crate::BT),
+    "BIH" => current("BIH", crate::BA),
+    "BOL" => current("BOL", crate::BO),
+    "BR" => current("BR", crate::BR),
+    "BRN" => current("BRN", crate::BH),
+    "BRU" => current("BRU", crate::BN),
+    "BS" => current("BS", crate::BS),
+    "BY" => current("BY", crate::BY),
+    "C" => current("C", crate::CU),
+    "CAM" => current("CAM", crate::CM),
+    "CDN" => current("CDN", crate::CA),
+    "CGO" => current("CGO", crate::CD),
+    "CH" => current("CH", crate::CH),
+    "CI" => current("CI", crate::CI),
+    "CL" => current("CL", crate::LK),
+    "CO" => current("CO", crate::CO),
+    "CR" => current("CR", crate::CR),
+    "CV" => current("CV", crate::CV),
+    "CY" => current("CY", crate::CY),
+    "CZ" => current("CZ", crate::CZ),
+    "D" => current("D", crate::DE),
+    "DJI" => current("DJI", crate::DJ),
+    "DK" => current("DK", crate::DK),
+    "DOM" => current("DOM", crate::DO),
+    "DY" => current("DY", crate::BJ),
+    "DZ" => current("DZ", crate::DZ),
+    "E" => current("E", crate::ES),
+    "EAK" => current("EAK", crate::KE),
+    "EAT" => current("EAT", crate::TZ),
+    "EAU" => current("EAU", crate::UG),
+    "EC" => current("EC", crate::EC),
+    "ER" => current("ER", crate::ER),
+    "ES" => current("ES", crate::SV),
+    "EST" => current("EST", crate::EE),
+    "ET" => current("ET", crate::EG),
+    "ETH" => current("ETH", crate::ET),
+    "F" => current("F", crate::FR),
+    "FIN" => current("FIN", crate::FI),
+    "FJI" => current("FJI", crate::FJ),
+    "FL" => current("FL", crate::LI),
+    "FO" => current("FO", crate::FO),
+    "FSM" => current("FSM", crate::FM),
+    "G" => current("G", crate::GA),
+    "GB" => current("GB", crate::GB),
+    "GBA" => current("GBA", crate::GG),
+    "GBG" => current("GBG", crate::GG),
+    "GBJ" => current("GBJ", crate::JE),
+    "GBM" => current("GBM", crate::IM),
+    "GBZ" => current("GBZ", crate::GI),
+    "GCA" => current("GCA", crate::GT),
+    "GE" => current("GE", crate::GE),
+    "GH" => current("GH", crate::GH),
+    "GQ" => current("GQ", crate::GQ),
+    "GR" => current("GR", crate::GR),
+    "GUY" => current("GUY", crate::GY),
+    "GW" => current("GW", crate::GW),
+    "H" => current("H", crate::HU),
+    "HK" => current("HK", crate::HK),
+    "HN" => current("HN", crate::HN),
+    "HR" => current("HR", crate::HR),
+    "I" => current("I", crate::IT),
+    "IL" => current("IL", crate::IL),
+    "IND" => current("IND", crate::IN),
+    "IR" => current("IR", crate::IR),
+    "IRL" => current("IRL", crate::IE),
+    "IRQ" => current("IRQ", crate::IQ),
+    "IS" => current("IS", crate::IS),
+    "J" => current("J", crate::JP),
+    "JA" => current("JA", crate::JM),
+    "JOR" => current("JOR", crate::JO),
+    "K" => current("K", crate::KH),
+    "KS" => current("KS", crate::KG),
+    "KSA" => current("KSA", crate::SA),
+    "KWT" => current("KWT", crate::KW),
+    "KZ" => current("KZ", crate::KZ),
+    "L" => current("L", crate::LU),
+    "LAO" => current("LAO", crate::LA),
+    "LAR" => current("LAR", crate::LY),
+    "LB" => current("LB", crate::LR),
+    "LS" => current("LS", crate::LS),
+    "LT" => current("LT", crate::LT),
+    "LV" => current("LV", crate::LV),
+    "M" => current("M", crate::MT),
+    "MA" => current("MA", crate::MA),
+    "MAL" => current("MAL", crate::MY),
+    "MC" => current("MC", crate::MC),
+    "MD" => current("MD", crate::MD),
+    "MEX" => current("MEX", crate::MX),
+    "MGL" => current("MGL", crate::MN),
+    "MNE" => current("MNE", crate::ME),
+    "MOC" => current("MOC", crate::MZ),
+    "MS" => current("MS", crate::MU),
+    "MV" => current("MV", crate::MV),
+    "MW" => current("MW", crate::MW),
+    "MYA" => current("MYA", crate::MM),
+    "N" => current("N", crate::NO),
+    "NAM" => current("NAM", crate::NA),
+    "NAU" => current("NAU", crate::NR),
+    "NEP" => current("NEP", crate::NP),
+    "NIC" => current("NIC", crate::NI),
+    "NL" => current("NL", crate::NL),
+    "NMK" => current("NMK", crate::MK),
+    "NZ" => current("NZ", crate::NZ),
+    "OM" => current("OM", crate::OM),
+    "P" => current("P", crate::PT),
+    "PA" => current("PA", crate::PA),
+    "PE" => current("PE", crate::PE),
+    "PK" => current("PK", crate::PK),
+    "PL" => current("PL", crate::PL),
+    "PNG" => current("PNG", crate::PG),
+    "PY" => current("PY", crate::PY),
+    "Q" => current("Q", crate::QA),
+    "RA" => current("RA", crate::AR),
+    "RB" => current("RB", crate::BW),
+    "RC" => current("RC", crate::TW),
+    "RCA" => current("RCA", crate::CF),
+    "RCB" => current("RCB", crate::CG),
+    "RCH" => current("RCH", crate::CL),
+    "RG" => current("RG", crate::GN),
+    "RH" => current("RH", crate::HT),
+    "RI" => current("RI", crate::ID),
+    "RIM" => current("RIM", crate::MR),
+    "RL" => current("RL", crate::LB),
+    "RM" => current("RM", crate::MG),
+    "RMM" => current("RMM", crate::ML),
+    "RN" => current("RN", crate::NE),
+    "RO" => current("RO", crate::RO),
+    "ROK" => current("ROK", crate::KR),
+    "RP" => current("RP", crate::PH),
+    "RSM" => current("RSM", crate::SM),
+    "RU" => current("RU", crate::BI),
+    "RUS" => current("RUS", crate::RU),
+    "RWA" => current("RWA", crate::RW),
+    "S" => current("S", crate::SE),
+    "SD" => current("SD", crate::SZ),
+    "SGP" => current("SGP", crate::SG),
+    "SK" => current("SK", crate::SK),
+    "SLO" => current("SLO", crate::SI),
+    "SME" => current("SME", crate::SR),
+    "SN" => current("SN", crate::SN),
+    "SO" => current("SO", crate::SO),
+    "SRB" => current("SRB", crate::RS),
+    "STP" => current("STP", crate::ST),
+    "SUD" => current("SUD", crate::SD),
+    "SY" => current("SY", crate::SC),
+    "SYR" => current("SYR", crate::SY),
+    "T" => current("T", crate::TH),
+    "TCH" => current("TCH", crate::TD),
+    "TG" => current("TG", crate::TG),
+    "TJ" => current("TJ", crate::TJ),
+    "TL" => current("TL", crate::TL),
+    "TM" => current("TM", crate::TM),
+    "TN" => current("TN", crate::TN),
+    "TR" => current("TR", crate::TR),
+    "TT" => current("TT", crate::TT),
+    "UA" => current("UA", crate::UA),
+    "UAE" => current("UAE", crate::AE),
+    "USA" => current("USA", crate::US),
+    "UY" => current("UY", crate::UY),
+    "UZ" => current("UZ", crate::UZ),
+    "V" => current("V", crate::VA),
+    "VN" => current("VN", crate::VN),
+    "WAG" => current("WAG", crate::GM),
+    "WAL" => current("WAL", crate::SL),
+    "WAN" => current("WAN", crate::NG),
+    "WD" => current("WD", crate::DM),
+    "WG" => current("WG", crate::GD),
+    "WL" => current("WL", crate::LC),
+    "WS" => current("WS", crate::WS),
+    "WV" => current("WV", crate::VC),
+    "YV" => current("YV", crate::VE),
+    "Z" => current("Z", crate::ZM),
+    "ZA" => current("ZA", crate::ZA),
+    "ZW" => current("ZW", crate::ZW),
+
+    "ADN" => historical("ADN", Code::Former(iso3166_3::YDYE)),
+    "BUR" => historical("BUR", Code::Former(iso3166_3::BUMM)),
+    "CS" => historical("CS", Code::Former(iso3166_3::CSHH)),
+    "DDR" => historical("DDR", Code::Former(iso3166_3::DDDE)),
+    "EW" => historical("EW", Code::Country(crate::EE)),
+    "HV" => historical("HV", Code::Former(iso3166_3::HVBF)),
+    "LF" => historical("LF", Code::Country(crate::LY)),
+    "MK" => historical("MK", Code::Country(crate::MK)),
+    "NA" => historical("NA", Code::Former(iso3166_3::ANHH)),
+    "PI" => historical("PI", Code::Country(crate::PH)),
+    "RSR" => historical("RSR", Code::Former(iso3166_3::RHZW)),
+    "SCG" => historical("SCG", Code::Former(iso3166_3::CSXX)),
+    "SU" => historical("SU", Code::Former(iso3166_3::SUHH)),
+    "YU" => historical("YU", Code::Former(iso3166_3::YUCS)),
+    "ZRE" => historical("ZRE", Code::Former(iso3166_3::ZRCD)),
+};
@@ -0,0 +1,57 @@
+use rust_iso3166::{from_alias, from_name, ALIAS_MAP};
+use std::collections::HashSet;
+
+fn alpha2(country: Option<rust_iso3166::CountryCode>) -> Option<&'static str> {
+    country.map(|c| c.alpha2)
+}
+
+#[test]
+fn test_from_alias() {
+    assert_eq!(alpha2(from_alias("UK")), Some("GB"));
+    assert_eq!(alpha2(from_alias("great britain")), Some("GB"));
+    assert_eq!(alpha2(from_alias("South Korea")), Some("KR"));
+    assert_eq!(alpha2(from_alias("Turkey")), Some("TR"));
+    assert_eq!(alpha2(from_alias("RUSSIA")), Some("RU"));
+    assert_eq!(alpha2(from_alias("Holland")), Some("NL"));
+    assert_eq!(alpha2(from_alias("Ivory Coast")), Some("CI"));
+    assert_eq!(alpha2(from_alias("Korea, Republic of")), Some("KR"));
+    assert_eq!(from_alias("Australia"), None);
+    assert_eq!(from_alias("Atlantis"), None);
+}
+
+#[test]
+fn test_from_name() {
+    assert_eq!(alpha2(from_name("Australia")), Some("AU"));
+    assert_eq!(alpha2(from_name("korea (republic of)")), Some("KR"));
+    assert_eq!(alpha2(from_name("Cote d'Ivoire")), Some("CI"));
+    assert_eq!(alpha2(from_name("Ivory Coast")), Some("CI"));
+    assert_eq!(alpha2(from_name("  Türkiye ")), Some("TR"));
+    assert_eq!(from_name(""), None);
+}
+
+#[test]
+fn test_aliases_are_unambiguous() {
+    let mut seen = HashSet::new();
+    for (alpha2, aliases) in ALIAS_MAP.entries() {
+        let country = rust_iso3166::from_alpha2(alpha2).unwrap();
+        assert_eq!(country.aliases(), *aliases);
+        for alias in *aliases {
+            assert!(
+                seen.insert(alias.to_lowercase()),
+                "duplicate alias {}",
+                alias
+            );
+            assert_eq!(from_alias(alias), Some(country), "{}", alias);
+            let by_name = from_name(alias).unwrap();
+            assert_eq!(by_name, country, "alias {} shadowed by a name", alias);
+        }
+    }
+}
+
+#[test]
+fn test_search_uses_aliases() {
+    use rust_iso3166::search::{search, SearchOptions};
+    let found = search("Soth Korea", &SearchOptions::default());
+    assert_eq!(found[0].code.code(), "KR");
+    assert_eq!(found[0].name, "South Korea");
+}
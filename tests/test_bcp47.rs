@@ -0,0 +1,85 @@
+use rust_iso3166::{
+    from_accept_language, locale_region, CountryCode, LocaleRegion, Region, DEPRECATED_REGION_MAP,
+};
+
+fn country(tag: &str) -> Option<&'static str> {
+    CountryCode::from_locale(tag).map(|c| c.alpha2)
+}
+
+fn accepted(header: &str) -> Vec<&'static str> {
+    from_accept_language(header)
+        .iter()
+        .map(|c| c.alpha2)
+        .collect()
+}
+
+#[test]
+fn test_from_locale() {
+    assert_eq!(country("pt-BR"), Some("BR"));
+    assert_eq!(country("zh_Hant_TW"), Some("TW"));
+    assert_eq!(country("zh-yue-HK"), Some("HK"));
+    assert_eq!(country("en-gb"), Some("GB"));
+    assert_eq!(country("de_DE.UTF-8"), Some("DE"));
+    assert_eq!(country("ca_ES@valencia"), Some("ES"));
+    assert_eq!(country("sl-IT-nedis"), Some("IT"));
+    assert_eq!(country("es-840"), Some("US"));
+    assert_eq!(country(" fr-CA "), Some("CA"));
+    assert_eq!(country("en"), None);
+    assert_eq!(country("sr-Latn"), None);
+    assert_eq!(country("es-419"), None);
+    assert_eq!(country("en-UK"), None);
+    assert_eq!(country("de-u-rg-chzzzz"), None);
+    assert_eq!(country("x-private-US"), None);
+    assert_eq!(country(""), None);
+}
+
+#[test]
+fn test_macro_regions() {
+    let latin_america = Region::from_code(419).unwrap();
+    assert_eq!(
+        locale_region("es-419"),
+        Some(LocaleRegion::Region(latin_america))
+    );
+    let countries = locale_region("es-419").unwrap().countries();
+    assert!(countries.contains(&rust_iso3166::MX));
+    assert!(countries.contains(&rust_iso3166::AR));
+    assert!(!countries.contains(&rust_iso3166::ES));
+    assert_eq!(
+        locale_region("en-150").unwrap().countries(),
+        Region::from_code(150).unwrap().countries()
+    );
+    assert_eq!(locale_region("en-999"), None);
+}
+
+#[test]
+fn test_deprecated_regions() {
+    assert_eq!(country("my-BU"), Some("MM"));
+    assert_eq!(country("de-DD"), Some("DE"));
+    assert_eq!(country("fr-FX"), Some("FR"));
+    assert_eq!(country("pt-TP"), Some("TL"));
+    assert_eq!(country("ar-YD"), Some("YE"));
+    assert_eq!(country("fr-zr"), Some("CD"));
+    for code in DEPRECATED_REGION_MAP.keys() {
+        assert!(rust_iso3166::from_alpha2(code).is_none(), "{}", code);
+    }
+}
+
+#[test]
+fn test_accept_language() {
+    assert_eq!(
+        accepted("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5"),
+        vec!["CH"]
+    );
+    assert_eq!(
+        accepted("en-US;q=0.5, pt-BR, de-AT;q=0.7"),
+        vec!["BR", "AT", "US"]
+    );
+    assert_eq!(accepted("en-GB;q=0, en-US"), vec!["US"]);
+    assert_eq!(accepted("en-US,en-GB;q=0.9,en-US;q=0.8"), vec!["US", "GB"]);
+    assert_eq!(accepted("en-GB;q=2, de-DE;q=abc, it-IT"), vec!["IT"]);
+    assert_eq!(accepted("de-CH;Q=0.9 , fr-CH;q=1.0"), vec!["CH"]);
+    let found = accepted("es-MX, es-419;q=0.9");
+    assert_eq!(found[0], "MX");
+    assert!(found.contains(&"AR"));
+    assert!(accepted("").is_empty());
+}
@@ -0,0 +1,95 @@
+use rust_iso3166::{border_crossings, from_alpha2, landmasses, ALL, LANDLOCKED, NEIGHBOR_MAP};
+
+fn neighbors(alpha2: &str) -> Vec<&'static str> {
+    from_alpha2(alpha2)
+        .unwrap()
+        .neighbors()
+        .iter()
+        .map(|c| c.alpha2)
+        .collect()
+}
+
+fn crossings(from: &str, to: &str) -> Option<usize> {
+    border_crossings(from_alpha2(from).unwrap(), from_alpha2(to).unwrap())
+}
+
+#[test]
+fn test_neighbors() {
+    assert_eq!(
+        neighbors("DE"),
+        vec!["AT", "BE", "CH", "CZ", "DK", "FR", "LU", "NL", "PL"]
+    );
+    assert_eq!(neighbors("PT"), vec!["ES"]);
+    assert_eq!(neighbors("GF"), vec!["BR", "SR"]);
+    assert_eq!(neighbors("CN").len(), 16);
+    assert_eq!(neighbors("RU").len(), 14);
+    assert!(neighbors("SG").is_empty());
+    assert!(neighbors("AU").is_empty());
+    assert!(rust_iso3166::ES.is_neighbor(&rust_iso3166::MA));
+    assert!(!rust_iso3166::FR.is_neighbor(&rust_iso3166::GB));
+}
+
+#[test]
+fn test_symmetric() {
+    for (alpha2, list) in NEIGHBOR_MAP.entries() {
+        let country = from_alpha2(alpha2).unwrap();
+        for neighbor in list.iter() {
+            assert!(
+                neighbor.is_neighbor(&country),
+                "{} {}",
+                alpha2,
+                neighbor.alpha2
+            );
+            assert_ne!(*neighbor, country);
+        }
+    }
+}
+
+#[test]
+fn test_landlocked() {
+    assert_eq!(LANDLOCKED.len(), 43);
+    assert!(rust_iso3166::CH.is_landlocked());
+    assert!(rust_iso3166::KZ.is_landlocked());
+    assert!(!rust_iso3166::FR.is_landlocked());
+    let double: Vec<_> = ALL
+        .iter()
+        .filter(|c| c.is_double_landlocked())
+        .map(|c| c.alpha2)
+        .collect();
+    assert_eq!(double.len(), 2);
+    assert!(double.contains(&"LI"));
+    assert!(double.contains(&"UZ"));
+}
+
+#[test]
+fn test_border_crossings() {
+    assert_eq!(crossings("FR", "FR"), Some(0));
+    assert_eq!(crossings("FR", "ES"), Some(1));
+    assert_eq!(crossings("PT", "DE"), Some(3));
+    assert_eq!(
+        crossings("ES", "ZA"),
+        Some(crossings("MA", "ZA").unwrap() + 1)
+    );
+    assert_eq!(crossings("CA", "AR"), crossings("AR", "CA"));
+    assert_eq!(crossings("GB", "IE"), Some(1));
+    assert_eq!(crossings("GB", "FR"), None);
+    assert_eq!(crossings("JP", "JP"), Some(0));
+    assert_eq!(crossings("JP", "KR"), None);
+}
+
+#[test]
+fn test_landmasses() {
+    let landmasses = landmasses();
+    assert_eq!(landmasses.len(), 5);
+    assert!(landmasses.windows(2).all(|w| w[0].len() >= w[1].len()));
+    let americas = &landmasses[1];
+    assert_eq!(americas.len(), 23);
+    assert!(americas.contains(&rust_iso3166::CA));
+    assert!(americas.contains(&rust_iso3166::GF));
+    assert_eq!(
+        rust_iso3166::HT.landmass(),
+        vec![rust_iso3166::DO, rust_iso3166::HT]
+    );
+    assert_eq!(rust_iso3166::IS.landmass(), vec![rust_iso3166::IS]);
+    assert!(rust_iso3166::PG.landmass().contains(&rust_iso3166::FR));
+}
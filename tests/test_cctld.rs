@@ -0,0 +1,67 @@
+use rust_iso3166::{from_alpha2, from_hostname, CCTLD_MAP, COUNTRY_CCTLD_MAP, COUNTRY_IDN_MAP};
+
+fn host(hostname: &str) -> Option<&'static str> {
+    from_hostname(hostname).map(|c| c.alpha2)
+}
+
+#[test]
+fn test_cctld() {
+    assert_eq!(from_alpha2("GB").unwrap().cctld(), Some(".uk"));
+    assert_eq!(from_alpha2("SH").unwrap().cctld(), Some(".sh"));
+    assert_eq!(from_alpha2("DE").unwrap().cctld(), Some(".de"));
+    assert_eq!(from_alpha2("AX").unwrap().cctld(), Some(".ax"));
+    assert_eq!(from_alpha2("UM").unwrap().cctld(), None);
+    assert_eq!(from_alpha2("BL").unwrap().cctld(), None);
+    assert_eq!(COUNTRY_CCTLD_MAP.len(), rust_iso3166::ALL.len() - 5);
+}
+
+#[test]
+fn test_from_hostname() {
+    assert_eq!(host("shop.example.co.uk"), Some("GB"));
+    assert_eq!(host("example.gb"), Some("GB"));
+    assert_eq!(host("www.gov.ac"), Some("SH"));
+    assert_eq!(host("example.com.au"), Some("AU"));
+    assert_eq!(host("Jane.Doe@Example.CO.JP"), Some("JP"));
+    assert_eq!(host("example.de."), Some("DE"));
+    assert_eq!(host("localhost.tv"), Some("TV"));
+    assert_eq!(host("example.eu"), None);
+    assert_eq!(host("example.su"), None);
+    assert_eq!(host("example.uk.com"), None);
+    assert_eq!(host("example.com"), None);
+    assert_eq!(host("localhost"), None);
+    assert_eq!(host(""), None);
+}
+
+#[test]
+fn test_idn() {
+    assert_eq!(host("пример.рф"), Some("RU"));
+    assert_eq!(host("пример.РФ"), Some("RU"));
+    assert_eq!(host("xn--e1afmkfd.xn--p1ai"), Some("RU"));
+    assert_eq!(host("XN--P1AI"), Some("RU"));
+    assert_eq!(host("例子.中国"), Some("CN"));
+    assert_eq!(host("example.xn--fiqs8s"), Some("CN"));
+    assert_eq!(host("example.xn--fiqz9s"), Some("CN"));
+    assert_eq!(host("example.ею"), None);
+    let cn: Vec<_> = from_alpha2("CN")
+        .unwrap()
+        .idn_cctlds()
+        .iter()
+        .map(|t| (t.unicode, t.punycode))
+        .collect();
+    assert_eq!(cn, vec![("中国", "xn--fiqs8s"), ("中國", "xn--fiqz9s")]);
+    assert!(from_alpha2("DE").unwrap().idn_cctlds().is_empty());
+}
+
+#[test]
+fn test_data_is_consistent() {
+    for (alpha2, tld) in COUNTRY_CCTLD_MAP.entries() {
+        assert_eq!(CCTLD_MAP.get(&tld[1..]).map(|c| c.alpha2), Some(*alpha2));
+    }
+    for (alpha2, tlds) in COUNTRY_IDN_MAP.entries() {
+        for tld in *tlds {
+            assert_eq!(host(tld.unicode), Some(*alpha2));
+            assert_eq!(host(tld.punycode), Some(*alpha2));
+            assert!(tld.punycode.starts_with("xn--"));
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use rust_iso3166::{Country, CountryCode};
+
+#[test]
+fn test_country_round_trip() {
+    for code in rust_iso3166::ALL {
+        let country = Country::try_from(*code).unwrap();
+        assert_eq!(country as u16, code.numeric);
+        assert_eq!(CountryCode::from(country), *code);
+        assert_eq!(Country::from_numeric(code.numeric), Some(country));
+    }
+}
+
+#[test]
+fn test_country_discriminant() {
+    assert_eq!(Country::AU as u16, 36);
+    assert_eq!(Country::GB as u16, 826);
+    assert_eq!(Country::US.country_code().alpha2, "US");
+    assert_eq!(Country::from_numeric(0), None);
+    assert_eq!(Country::from_numeric(999), None);
+}
+
+#[test]
+fn test_country_try_from_former_code() {
+    let burma = rust_iso3166::iso3166_3::from_code("BUMM").unwrap();
+    assert_eq!(Country::try_from(burma.former), Err(burma.former));
+    assert_eq!(Country::try_from(burma.new_countries[0]), Ok(Country::MM));
+}
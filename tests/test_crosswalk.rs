@@ -0,0 +1,75 @@
+use rust_iso3166::crosswalk::{self, Scheme, CROSSWALK_MAP, FIPS_MAP};
+use rust_iso3166::from_alpha2;
+
+fn code(alpha2: &str, scheme: Scheme) -> Option<&'static str> {
+    from_alpha2(alpha2).unwrap().crosswalk_code(scheme)
+}
+
+fn country(scheme: Scheme, code: &str) -> Option<&'static str> {
+    crosswalk::from_code(scheme, code).map(|c| c.alpha2)
+}
+
+#[test]
+fn test_fips() {
+    assert_eq!(code("AU", Scheme::FIPS), Some("AS"));
+    assert_eq!(code("AT", Scheme::FIPS), Some("AU"));
+    assert_eq!(code("DE", Scheme::FIPS), Some("GM"));
+    assert_eq!(code("GB", Scheme::FIPS), Some("UK"));
+    assert_eq!(code("CZ", Scheme::FIPS), Some("EZ"));
+    assert_eq!(code("PS", Scheme::FIPS), None);
+    assert_eq!(country(Scheme::FIPS, "AU"), Some("AT"));
+    assert_eq!(country(Scheme::FIPS, "AS"), Some("AU"));
+    assert_eq!(country(Scheme::FIPS, "AQ"), Some("AS"));
+    assert_eq!(country(Scheme::FIPS, "UK"), Some("GB"));
+    assert_eq!(country(Scheme::FIPS, "GB"), Some("GA"));
+    assert_eq!(country(Scheme::FIPS, "WE"), Some("PS"));
+    assert_eq!(country(Scheme::FIPS, "GZ"), Some("PS"));
+    assert_eq!(country(Scheme::FIPS, "JN"), Some("SJ"));
+    assert_eq!(country(Scheme::FIPS, "EU"), Some("TF"));
+    assert_eq!(country(Scheme::FIPS, " uk "), Some("GB"));
+    assert_eq!(country(Scheme::FIPS, "XX"), None);
+}
+
+#[test]
+fn test_collisions() {
+    // FIPS codes that are a different country's ISO alpha-2 code
+    let collisions: Vec<_> = FIPS_MAP
+        .entries()
+        .filter(|(fips, country)| from_alpha2(fips).is_some_and(|iso| iso != **country))
+        .collect();
+    assert!(collisions.len() > 60);
+    for (fips, country) in collisions {
+        assert_ne!(crosswalk::from_code(Scheme::FIPS, fips), from_alpha2(fips));
+        assert_eq!(crosswalk::from_code(Scheme::FIPS, fips), Some(*country));
+    }
+}
+
+#[test]
+fn test_stanag_and_itu() {
+    assert_eq!(code("DE", Scheme::STANAG), Some("DEU"));
+    assert_eq!(country(Scheme::STANAG, "gbr"), Some("GB"));
+    assert_eq!(country(Scheme::STANAG, "GB"), None);
+    assert_eq!(code("GB", Scheme::ITU), Some("G"));
+    assert_eq!(code("NL", Scheme::ITU), Some("HOL"));
+    assert_eq!(code("SA", Scheme::ITU), Some("ARS"));
+    assert_eq!(code("ZA", Scheme::ITU), Some("AFS"));
+    assert_eq!(code("AQ", Scheme::ITU), None);
+    assert_eq!(country(Scheme::ITU, "F"), Some("FR"));
+    assert_eq!(country(Scheme::ITU, "D"), Some("DE"));
+    assert_eq!(country(Scheme::ITU, "GBR"), None);
+    assert_eq!(country(Scheme::ITU, ""), None);
+}
+
+#[test]
+fn test_data_is_consistent() {
+    assert_eq!(CROSSWALK_MAP.len(), rust_iso3166::ALL.len());
+    for country in rust_iso3166::ALL {
+        assert_eq!(country.crosswalk_code(Scheme::STANAG), Some(country.alpha3));
+        for scheme in Scheme::ALL {
+            if let Some(code) = country.crosswalk_code(*scheme) {
+                assert_eq!(crosswalk::from_code(*scheme, code), Some(*country));
+            }
+        }
+    }
+    assert_eq!(Scheme::FIPS.name(), "FIPS 10-4");
+}
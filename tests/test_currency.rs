@@ -0,0 +1,82 @@
+#![cfg(feature = "currency")]
+
+use rust_iso3166::currency::{self, countries_using, COUNTRY_CURRENCY_MAP, CURRENCY_MAP};
+use rust_iso3166::from_alpha2;
+
+fn codes(alpha2: &str) -> Vec<&'static str> {
+    from_alpha2(alpha2)
+        .unwrap()
+        .currencies()
+        .iter()
+        .map(|c| c.code)
+        .collect()
+}
+
+fn users(code: &str) -> Vec<&'static str> {
+    countries_using(code).iter().map(|c| c.alpha2).collect()
+}
+
+#[test]
+fn test_currencies() {
+    assert_eq!(codes("AU"), vec!["AUD"]);
+    assert_eq!(codes("PA"), vec!["PAB", "USD"]);
+    assert_eq!(codes("BT"), vec!["BTN", "INR"]);
+    assert_eq!(codes("LS"), vec!["LSL", "ZAR"]);
+    assert_eq!(codes("SV"), vec!["USD", "SVC"]);
+    assert_eq!(codes("ZW"), vec!["ZWG", "USD"]);
+    assert_eq!(codes("HR"), vec!["EUR"]);
+    assert_eq!(codes("BG"), vec!["EUR"]);
+    assert_eq!(codes("CH"), vec!["CHF"]);
+    assert!(codes("AQ").is_empty());
+}
+
+#[test]
+fn test_countries_using() {
+    let eur = users("EUR");
+    for alpha2 in ["DE", "FR", "HR", "ME", "VA", "AD", "RE"] {
+        assert!(eur.contains(&alpha2), "{}", alpha2);
+    }
+    assert!(!eur.contains(&"GB"));
+    assert!(users("XOF").contains(&"SN"));
+    assert!(users("XCD").contains(&"AG"));
+    assert!(users("AUD").contains(&"KI"));
+    assert!(users("USD").contains(&"EC"));
+    assert_eq!(users("XCG"), vec!["CW", "SX"]);
+    assert!(users("ANG").is_empty());
+    assert_eq!(users("SVC"), vec!["SV"]);
+    assert_eq!(users(" eur "), users("EUR"));
+    assert!(users("EU").is_empty());
+    assert_eq!(currency::from_code("JOD").unwrap().countries().len(), 2);
+}
+
+#[test]
+fn test_lookups() {
+    let jpy = currency::from_code("JPY").unwrap();
+    assert_eq!((jpy.numeric, jpy.minor_units), (392, 0));
+    assert_eq!(currency::from_code("KWD").unwrap().minor_units, 3);
+    assert_eq!(currency::from_numeric(36).unwrap().code, "AUD");
+    assert_eq!(currency::from_numeric(36).unwrap().numeric_str(), "036");
+    assert_eq!(currency::from_numeric(999), None);
+    assert_eq!(currency::from_code("XXX"), None);
+    assert_eq!(currency::from_code(" jpy "), Some(jpy));
+    assert_eq!(currency::from_code("EURO"), None);
+    assert_eq!(currency::from_code("EUR"), Some(currency::EUR));
+}
+
+#[test]
+fn test_data_is_consistent() {
+    assert_eq!(COUNTRY_CURRENCY_MAP.len(), rust_iso3166::ALL.len());
+    for (alpha2, currencies) in COUNTRY_CURRENCY_MAP.entries() {
+        assert!(from_alpha2(alpha2).is_some());
+        for c in *currencies {
+            assert_eq!(CURRENCY_MAP.get(c.code), Some(c), "{}", alpha2);
+        }
+    }
+    let mut numerics: Vec<_> = CURRENCY_MAP.values().map(|c| c.numeric).collect();
+    numerics.sort();
+    numerics.dedup();
+    assert_eq!(numerics.len(), CURRENCY_MAP.len());
+    for currency in CURRENCY_MAP.values() {
+        assert!(!currency.countries().is_empty(), "{}", currency.code);
+    }
+}
@@ -0,0 +1,129 @@
+use rust_iso3166::{find_flags, flag_status, CodeStatus, CountryCode};
+
+fn found(text: &str) -> Vec<&'static str> {
+    find_flags(text).iter().map(|c| c.alpha2).collect()
+}
+
+#[test]
+fn test_flag_emoji() {
+    assert_eq!(rust_iso3166::AU.flag_emoji(), "🇦🇺");
+    assert_eq!(rust_iso3166::GB.flag_emoji(), "🇬🇧");
+    assert_eq!(rust_iso3166::ZW.flag_emoji(), "\u{1F1FF}\u{1F1FC}");
+    for country in rust_iso3166::ALL {
+        let flag = country.flag_emoji();
+        assert_eq!(flag.chars().count(), 2);
+        assert_eq!(CountryCode::from_flag_emoji(&flag), Some(*country));
+    }
+}
+
+#[test]
+fn test_from_flag_emoji() {
+    assert_eq!(CountryCode::from_flag_emoji("🇦🇺"), Some(rust_iso3166::AU));
+    assert_eq!(CountryCode::from_flag_emoji(" 🇯🇵 "), Some(rust_iso3166::JP));
+    assert_eq!(CountryCode::from_flag_emoji("🇪🇺"), None);
+    assert_eq!(CountryCode::from_flag_emoji("🇺🇳"), None);
+    assert_eq!(CountryCode::from_flag_emoji("🇦"), None);
+    assert_eq!(CountryCode::from_flag_emoji("🇦🇺🇦🇺"), None);
+    assert_eq!(CountryCode::from_flag_emoji("AU"), None);
+    assert_eq!(CountryCode::from_flag_emoji(""), None);
+}
+
+#[test]
+fn test_flag_status() {
+    let reserved = |flag: &str| flag_status(flag).unwrap().reservation().unwrap().code;
+    assert_eq!(reserved("🇪🇺"), "EU");
+    assert_eq!(reserved("🇺🇳"), "UN");
+    assert_eq!(reserved("🇦🇨"), "AC");
+    assert_eq!(reserved("🇹🇦"), "TA");
+    assert!(matches!(
+        flag_status("🇪🇺"),
+        Some(CodeStatus::ExceptionallyReserved(_))
+    ));
+    assert_eq!(flag_status("🇽🇰"), Some(CodeStatus::UserAssigned));
+    assert_eq!(flag_status("🇪🇱"), Some(CodeStatus::Unassigned));
+    assert_eq!(flag_status("🏴"), None);
+}
+
+#[test]
+fn test_find_flags() {
+    assert_eq!(found("Go 🇧🇷!"), vec!["BR"]);
+    assert_eq!(found("🇩🇪🇫🇷🇮🇹"), vec!["DE", "FR", "IT"]);
+    assert_eq!(found("🇪🇺🇩🇪 🇺🇳"), vec!["DE"]);
+    assert_eq!(found("🇩🇪 vs 🇩🇪"), vec!["DE", "DE"]);
+    // A lone regional indicator does not pair across other text
+    assert_eq!(found("🇩 🇪🇸"), vec!["ES"]);
+    assert!(found("no flags here").is_empty());
+}
+
+#[test]
+fn test_subdivision_flags() {
+    use rust_iso3166::iso3166_2::{self, Subdivision};
+    let england = iso3166_2::from_code("GB-ENG").unwrap();
+    assert_eq!(
+        england.flag_tag_sequence(),
+        "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}"
+    );
+    assert_eq!(
+        iso3166_2::from_code("GB-WLS").unwrap().flag_tag_sequence(),
+        "\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}"
+    );
+    for code in ["GB-ENG", "GB-SCT", "GB-WLS"] {
+        let subdivision = iso3166_2::from_code(code).unwrap();
+        assert!(subdivision.is_rgi_flag(), "{}", code);
+        let sequence = subdivision.flag_tag_sequence();
+        assert_eq!(
+            Subdivision::from_flag_tag_sequence(&sequence),
+            Some(subdivision)
+        );
+    }
+    let texas = iso3166_2::from_code("US-TX").unwrap();
+    assert!(!texas.is_rgi_flag());
+    assert_eq!(
+        Subdivision::from_flag_tag_sequence(&texas.flag_tag_sequence()),
+        Some(texas)
+    );
+    let paris = iso3166_2::from_code("FR-75C").unwrap();
+    assert_eq!(
+        Subdivision::from_flag_tag_sequence(&paris.flag_tag_sequence()),
+        Some(paris)
+    );
+    for subdivision in iso3166_2::SUBDIVISION_MAP.values() {
+        let sequence = subdivision.flag_tag_sequence();
+        assert_eq!(
+            Subdivision::from_flag_tag_sequence(&sequence),
+            Some(*subdivision)
+        );
+    }
+}
+
+#[test]
+fn test_subdivision_flag_errors() {
+    use rust_iso3166::iso3166_2::Subdivision;
+    // Unknown subdivision "gbxxx"
+    assert_eq!(
+        Subdivision::from_flag_tag_sequence(
+            "\u{1F3F4}\u{E0067}\u{E0062}\u{E0078}\u{E0078}\u{E0078}\u{E007F}"
+        ),
+        None
+    );
+    // Missing CANCEL TAG
+    assert_eq!(
+        Subdivision::from_flag_tag_sequence(
+            "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}"
+        ),
+        None
+    );
+    // Too long
+    assert_eq!(
+        Subdivision::from_flag_tag_sequence(
+            "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E0067}\u{E0067}\u{E007F}"
+        ),
+        None
+    );
+    assert_eq!(
+        Subdivision::from_flag_tag_sequence("\u{1F3F4}\u{E007F}"),
+        None
+    );
+    assert_eq!(Subdivision::from_flag_tag_sequence("🇬🇧"), None);
+    assert_eq!(Subdivision::from_flag_tag_sequence(""), None);
+}
@@ -0,0 +1,83 @@
+use rust_iso3166::{
+    distance_km, from_alpha2, Coordinates, ALL, BOUNDING_BOX_MAP, CAPITAL_MAP, CENTROID_MAP,
+};
+
+fn capitals(alpha2: &str) -> Vec<&'static str> {
+    from_alpha2(alpha2)
+        .unwrap()
+        .capital()
+        .iter()
+        .map(|c| c.name)
+        .collect()
+}
+
+#[test]
+fn test_capital() {
+    assert_eq!(capitals("FR"), vec!["Paris"]);
+    assert_eq!(capitals("AU"), vec!["Canberra"]);
+    assert_eq!(
+        capitals("ZA"),
+        vec!["Pretoria", "Cape Town", "Bloemfontein"]
+    );
+    assert_eq!(capitals("BO"), vec!["Sucre", "La Paz"]);
+    assert_eq!(capitals("NL"), vec!["Amsterdam", "The Hague"]);
+    assert!(capitals("AQ").is_empty());
+    assert!(capitals("HK").is_empty());
+    let ottawa = rust_iso3166::CA.capital()[0].coordinates;
+    assert_eq!(ottawa.latitude, 45.42);
+    assert_eq!(ottawa.longitude, -75.70);
+}
+
+#[test]
+fn test_complete() {
+    assert_eq!(CENTROID_MAP.len(), ALL.len());
+    assert_eq!(BOUNDING_BOX_MAP.len(), ALL.len());
+    for country in ALL {
+        let centroid = country.centroid().unwrap();
+        let bounds = country.bounding_box().unwrap();
+        assert!(bounds.south < bounds.north, "{}", country.alpha2);
+        assert!(bounds.contains(&centroid), "{}", country.alpha2);
+        for capital in country.capital() {
+            assert!(bounds.contains(&capital.coordinates), "{}", capital.name);
+        }
+    }
+    assert!(CAPITAL_MAP.len() > 235);
+}
+
+#[test]
+fn test_antimeridian() {
+    let mut crossing: Vec<_> = ALL
+        .iter()
+        .filter(|c| c.bounding_box().unwrap().crosses_antimeridian())
+        .map(|c| c.alpha2)
+        .collect();
+    crossing.sort();
+    assert_eq!(crossing, vec!["FJ", "KI", "RU", "UM", "US"]);
+    let russia = rust_iso3166::RU.bounding_box().unwrap();
+    let chukotka = Coordinates {
+        latitude: 66.0,
+        longitude: -172.0,
+    };
+    assert!(russia.contains(&chukotka));
+    assert!(!russia.contains(&rust_iso3166::US.centroid().unwrap()));
+    assert!(!rust_iso3166::FR
+        .bounding_box()
+        .unwrap()
+        .crosses_antimeridian());
+}
+
+#[test]
+fn test_distance() {
+    let paris = rust_iso3166::FR.capital()[0].coordinates;
+    let london = rust_iso3166::GB.capital()[0].coordinates;
+    let km = paris.distance_km(&london);
+    assert!((340.0..350.0).contains(&km), "{}", km);
+    assert_eq!(km, london.distance_km(&paris));
+    let fr_de = distance_km(rust_iso3166::FR, rust_iso3166::DE).unwrap();
+    assert!((800.0..900.0).contains(&fr_de), "{}", fr_de);
+    let fj_ws = distance_km(rust_iso3166::FJ, rust_iso3166::WS).unwrap();
+    assert!(fj_ws < 1500.0, "{}", fj_ws);
+    let antipodes = distance_km(rust_iso3166::ES, rust_iso3166::NZ).unwrap();
+    assert!(antipodes > 19000.0 && antipodes < 20016.0, "{}", antipodes);
+    assert_eq!(distance_km(rust_iso3166::JP, rust_iso3166::JP), Some(0.0));
+}
@@ -0,0 +1,93 @@
+use rust_iso3166::from_alpha2;
+use rust_iso3166::groups::{Date, Group};
+
+fn alpha2s(countries: Vec<rust_iso3166::CountryCode>) -> Vec<&'static str> {
+    let mut codes: Vec<_> = countries.iter().map(|c| c.alpha2).collect();
+    codes.sort();
+    codes
+}
+
+#[test]
+fn test_brexit() {
+    let gb = from_alpha2("GB").unwrap();
+    assert!(!gb.is_member_of(Group::EU, Date::new(1972, 12, 31)));
+    assert!(gb.is_member_of(Group::EU, Date::new(1973, 1, 1)));
+    assert!(gb.is_member_of(Group::EU, Date::new(2020, 1, 31)));
+    assert!(!gb.is_member_of(Group::EU, Date::new(2020, 2, 1)));
+    // The transition period kept the UK in the single market until 2021
+    assert!(gb.is_member_of(Group::EEA, Date::new(2020, 12, 31)));
+    assert!(!gb.is_member_of(Group::EEA, Date::new(2021, 1, 1)));
+    assert_eq!(Group::EU.members_at(Date::new(2020, 1, 31)).len(), 28);
+    assert_eq!(Group::EU.members_at(Date::new(2020, 2, 1)).len(), 27);
+}
+
+#[test]
+fn test_eurozone() {
+    let hr = from_alpha2("HR").unwrap();
+    assert!(!hr.is_member_of(Group::Eurozone, Date::new(2022, 12, 31)));
+    assert!(hr.is_member_of(Group::Eurozone, Date::new(2023, 1, 1)));
+    assert_eq!(Group::Eurozone.members_at(Date::new(1999, 1, 1)).len(), 11);
+    assert_eq!(Group::Eurozone.members_at(Date::new(2026, 1, 1)).len(), 21);
+    assert!(Group::Eurozone
+        .members_at(Date::new(1998, 12, 31))
+        .is_empty());
+}
+
+#[test]
+fn test_members_at() {
+    assert_eq!(
+        alpha2s(Group::EU.members_at(Date::new(1958, 1, 1))),
+        vec!["BE", "DE", "FR", "IT", "LU", "NL"]
+    );
+    assert_eq!(
+        alpha2s(Group::ASEAN.members_at(Date::new(1990, 1, 1))),
+        vec!["BN", "ID", "MY", "PH", "SG", "TH"]
+    );
+    assert_eq!(Group::ASEAN.members_at(Date::new(2025, 10, 26)).len(), 11);
+    assert_eq!(Group::G20.members_at(Date::new(2024, 1, 1)).len(), 19);
+    assert_eq!(Group::OECD.members_at(Date::new(2024, 1, 1)).len(), 38);
+    assert_eq!(Group::Schengen.members_at(Date::new(2025, 1, 1)).len(), 29);
+}
+
+#[test]
+fn test_groups_at() {
+    let no = from_alpha2("NO").unwrap();
+    assert_eq!(
+        no.groups_at(Date::new(2024, 1, 1)),
+        vec![Group::EEA, Group::Schengen, Group::OECD]
+    );
+    assert!(from_alpha2("AQ")
+        .unwrap()
+        .groups_at(Date::new(2024, 1, 1))
+        .is_empty());
+}
+
+#[test]
+fn test_memberships_are_consistent() {
+    for group in Group::ALL {
+        let memberships = group.memberships();
+        for (i, m) in memberships.iter().enumerate() {
+            assert!(m.left.is_none_or(|left| m.joined < left));
+            assert!(
+                i == 0 || memberships[i - 1].joined <= m.joined,
+                "{:?} {}",
+                group,
+                m.country.alpha2
+            );
+            assert!(
+                !memberships[..i]
+                    .iter()
+                    .any(|other| other.country == m.country),
+                "{:?} {}",
+                group,
+                m.country.alpha2
+            );
+        }
+    }
+    // Euro area members are all EU members
+    let today = Date::new(2026, 1, 1);
+    for country in Group::Eurozone.members_at(today) {
+        assert!(country.is_member_of(Group::EU, today), "{}", country.alpha2);
+    }
+    assert_eq!(Date::new(2020, 2, 1).to_string(), "2020-02-01");
+}
@@ -0,0 +1,36 @@
+use rust_iso3166::iso3166_2::from_code_ignore_case as from_code_2;
+use rust_iso3166::iso3166_3::from_code_ignore_case as from_code_3;
+use rust_iso3166::{from_alpha2_ignore_case, from_alpha3_ignore_case};
+
+#[test]
+fn test_country_code_ignore_case() {
+    for country in rust_iso3166::ALL {
+        let lower = country.alpha2.to_lowercase();
+        assert_eq!(from_alpha2_ignore_case(&lower), Some(*country));
+        let padded = format!("\t{} ", country.alpha3.to_lowercase());
+        assert_eq!(from_alpha3_ignore_case(&padded), Some(*country));
+    }
+    assert_eq!(from_alpha2_ignore_case("gB").unwrap().alpha3, "GBR");
+    assert_eq!(from_alpha2_ignore_case("g b"), None);
+    assert_eq!(from_alpha2_ignore_case("GBR"), None);
+    assert_eq!(from_alpha3_ignore_case("ÅLA"), None);
+    assert_eq!(from_alpha2_ignore_case(""), None);
+}
+
+#[test]
+fn test_subdivision_ignore_case() {
+    for separator in ["-", "_", " "] {
+        let code = format!(" gb{}edh\n", separator);
+        assert_eq!(from_code_2(&code).unwrap().code, "GB-EDH");
+    }
+    assert_eq!(from_code_2("Se-O").unwrap().code, "SE-O");
+    assert_eq!(from_code_2("GB--EDH"), None);
+    assert_eq!(from_code_2("GBEDH"), None);
+    assert_eq!(from_code_2("GB-EDHX"), None);
+}
+
+#[test]
+fn test_country_code3_ignore_case() {
+    assert_eq!(from_code_3(" pzpa").unwrap().code, "PZPA");
+    assert_eq!(from_code_3("pzp"), None);
+}
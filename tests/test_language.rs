@@ -0,0 +1,81 @@
+use rust_iso3166::{countries_speaking, from_alpha2, LanguageStatus, COUNTRY_LANGUAGE_MAP};
+
+fn codes(alpha2: &str) -> Vec<&'static str> {
+    from_alpha2(alpha2)
+        .unwrap()
+        .languages()
+        .iter()
+        .map(|l| l.code)
+        .collect()
+}
+
+fn speakers(code: &str) -> Vec<&'static str> {
+    countries_speaking(code).iter().map(|c| c.alpha2).collect()
+}
+
+#[test]
+fn test_languages() {
+    assert_eq!(codes("AU"), vec!["en"]);
+    assert_eq!(codes("CH"), vec!["de", "gsw", "en", "fr", "it", "rm"]);
+    assert_eq!(codes("SG"), vec!["en", "zh", "ms", "ta"]);
+    assert_eq!(codes("TW"), vec!["zh"]);
+    assert_eq!(codes("IN")[..2], ["hi", "en"]);
+    assert!(codes("AQ").is_empty());
+
+    let us = from_alpha2("US").unwrap().languages();
+    assert_eq!(us[0].name, "English");
+    assert_eq!(us[0].alpha3, "eng");
+    assert!(us[0].speakers > 300_000_000);
+}
+
+#[test]
+fn test_status() {
+    let status = |alpha2: &str, code: &str| {
+        from_alpha2(alpha2)
+            .unwrap()
+            .languages()
+            .iter()
+            .find(|l| l.code == code)
+            .map(|l| l.status)
+    };
+    assert_eq!(status("FR", "fr"), Some(LanguageStatus::Official));
+    assert_eq!(status("US", "en"), Some(LanguageStatus::DeFactoOfficial));
+    assert_eq!(status("ES", "ca"), Some(LanguageStatus::OfficialRegional));
+    assert_eq!(status("CH", "en"), Some(LanguageStatus::Unofficial));
+    assert!(status("ES", "ca").unwrap().is_official());
+    assert!(!LanguageStatus::Unofficial.is_official());
+    // Serbian in Latin script is merged into Serbian
+    assert_eq!(status("RS", "sr"), Some(LanguageStatus::Official));
+}
+
+#[test]
+fn test_countries_speaking() {
+    let pt = speakers("pt");
+    assert_eq!(pt[..4], ["BR", "AO", "PT", "MZ"]);
+    assert!(pt.contains(&"TL"));
+    assert_eq!(speakers("POR"), pt);
+    assert_eq!(speakers("zho"), speakers("zh"));
+    assert_eq!(speakers("zh")[0], "CN");
+    assert_eq!(speakers("gsw"), vec!["CH", "LI"]);
+    assert!(speakers("xx").is_empty());
+    assert!(speakers("").is_empty());
+}
+
+#[test]
+fn test_data_is_consistent() {
+    assert_eq!(COUNTRY_LANGUAGE_MAP.len(), rust_iso3166::ALL.len());
+    for (alpha2, languages) in COUNTRY_LANGUAGE_MAP.entries() {
+        assert!(from_alpha2(alpha2).is_some());
+        for pair in languages.windows(2) {
+            assert!(pair[0].speakers >= pair[1].speakers, "{}", alpha2);
+            assert_ne!(pair[0].code, pair[1].code, "{}", alpha2);
+        }
+        for l in *languages {
+            assert!(matches!(l.code.len(), 2 | 3), "{}", alpha2);
+            assert_eq!(l.alpha3.len(), 3, "{}", alpha2);
+            assert!(countries_speaking(l.code)
+                .iter()
+                .any(|c| c.alpha2 == *alpha2));
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use rust_iso3166::from_alpha2;
+
+#[test]
+#[cfg(feature = "locale-de")]
+fn test_name_in() {
+    let germany = from_alpha2("DE").unwrap();
+    assert_eq!(germany.name_in("de"), Some("Deutschland"));
+    assert_eq!(germany.name_in("DE_at"), Some("Deutschland"));
+    assert_eq!(germany.name_in(" de-CH "), Some("Deutschland"));
+    assert_eq!(germany.name_in("de-DE-u-co-phonebk"), Some("Deutschland"));
+    let england = rust_iso3166::iso3166_2::from_code("GB-ENG").unwrap();
+    assert_eq!(england.name_in("de"), Some("England"));
+    let gdr = rust_iso3166::iso3166_3::from_code("DDDE").unwrap();
+    assert_eq!(gdr.name_in("de"), Some("Deutsche Demokratische Republik"));
+}
+
+#[test]
+#[cfg(all(feature = "locale-zh", feature = "locale-zh-hant"))]
+fn test_chinese_scripts() {
+    let germany = from_alpha2("DE").unwrap();
+    assert_eq!(germany.name_in("zh"), Some("德国"));
+    assert_eq!(germany.name_in("zh-CN"), Some("德国"));
+    assert_eq!(germany.name_in("zh-Hans"), Some("德国"));
+    assert_eq!(germany.name_in("zh-Hant"), Some("德國"));
+    assert_eq!(germany.name_in("zh_TW"), Some("德國"));
+    assert_eq!(germany.name_in("zh-Hant-HK"), Some("德國"));
+    assert_eq!(germany.name_in("zh-Hant-TW-u-ca-roc"), Some("德國"));
+    assert_eq!(germany.name_in("zh-CN-u-ca-chinese"), Some("德国"));
+}
+
+#[test]
+#[cfg(feature = "locale-de")]
+fn test_annotations_are_stripped() {
+    let gotaland = rust_iso3166::iso3166_2::from_code("SE-O").unwrap();
+    assert_eq!(gotaland.name_in("de"), Some("Västra Götalands län"));
+}
+
+#[test]
+fn test_unknown_locale() {
+    let germany = from_alpha2("DE").unwrap();
+    assert_eq!(germany.name_in("tlh"), None);
+    assert_eq!(germany.name_in(""), None);
+    assert_eq!(germany.name_in("dé"), None);
+    #[cfg(not(feature = "locale-de"))]
+    assert_eq!(germany.name_in("de"), None);
+}
+
+#[test]
+#[cfg(feature = "all-locales")]
+fn test_every_locale_names_most_countries() {
+    assert_eq!(rust_iso3166::locale::LOCALES.len(), 15);
+    for locale in rust_iso3166::locale::LOCALES {
+        // Catalogs can lag a renaming, e.g. "Türkiye" is untranslated in some.
+        let named = rust_iso3166::ALL
+            .iter()
+            .filter_map(|c| c.name_in(locale))
+            .inspect(|name| assert!(!name.is_empty()))
+            .count();
+        assert!(
+            named >= rust_iso3166::ALL.len() - 5,
+            "{} names {}",
+            locale,
+            named
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use rust_iso3166::{lookup, lookup_all, Code};
+
+fn codes(input: &str) -> Vec<&'static str> {
+    lookup_all(input).iter().map(|c| c.code()).collect()
+}
+
+#[test]
+fn test_lookup_countries() {
+    let au = Some(Code::Country(rust_iso3166::AU));
+    assert_eq!(lookup("AU"), au);
+    assert_eq!(lookup(" aus "), au);
+    assert_eq!(lookup("036"), au);
+    assert_eq!(lookup("36"), au);
+    assert_eq!(lookup("4").unwrap().code(), "AF");
+}
+
+#[test]
+fn test_lookup_subdivisions_and_former() {
+    assert_eq!(lookup("GB-EDH").unwrap().name(), "Edinburgh, City of");
+    assert_eq!(lookup("gb_edh").unwrap().code(), "GB-EDH");
+    assert_eq!(lookup("GBEDH").unwrap().code(), "GB-EDH");
+    assert_eq!(lookup("ca on").unwrap().code(), "CA-ON");
+    assert!(matches!(lookup("CSHH"), Some(Code::Former(f)) if f.code == "CSHH"));
+}
+
+#[test]
+fn test_lookup_precedence() {
+    assert_eq!(codes("EST"), vec!["EE", "ES-T"]);
+    assert_eq!(codes("ESA"), vec!["ES-A"]);
+    assert_eq!(codes("CAON"), vec!["CA-ON"]);
+}
+
+#[test]
+fn test_lookup_misses() {
+    assert_eq!(lookup(""), None);
+    assert_eq!(lookup("   "), None);
+    assert_eq!(lookup("000"), None);
+    assert_eq!(lookup("1000"), None);
+    assert_eq!(lookup("XX"), None);
+    assert_eq!(lookup("ÅX"), None);
+    assert_eq!(lookup("United Kingdom"), None);
+}
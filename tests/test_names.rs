@@ -0,0 +1,91 @@
+use rust_iso3166::{from_alpha2, iso3166_3};
+
+#[test]
+fn test_official_name() {
+    let gb = from_alpha2("GB").unwrap();
+    assert_eq!(
+        gb.official_name,
+        Some("United Kingdom of Great Britain and Northern Ireland")
+    );
+    assert_eq!(
+        from_alpha2("DE").unwrap().official_name,
+        Some("Federal Republic of Germany")
+    );
+    assert_eq!(
+        from_alpha2("TR").unwrap().official_name,
+        Some("Republic of Türkiye")
+    );
+    assert_eq!(
+        from_alpha2("AU").unwrap().official_name,
+        Some("Commonwealth of Australia")
+    );
+    assert_eq!(
+        from_alpha2("CD").unwrap().official_name,
+        Some("Democratic Republic of the Congo")
+    );
+    assert_eq!(
+        from_alpha2("KR").unwrap().official_name,
+        Some("Republic of Korea")
+    );
+    assert_eq!(
+        from_alpha2("ER").unwrap().official_name,
+        Some("State of Eritrea")
+    );
+    assert_eq!(from_alpha2("JP").unwrap().official_name, Some("Japan"));
+    assert_eq!(from_alpha2("AQ").unwrap().official_name, None);
+    assert_eq!(from_alpha2("GL").unwrap().official_name, None);
+    for country in rust_iso3166::ALL {
+        if let Some(name) = country.official_name {
+            assert!(!name.starts_with("the "), "{}", country.alpha2);
+        }
+    }
+}
+
+#[test]
+fn test_name_fr() {
+    assert_eq!(from_alpha2("DE").unwrap().name_fr, "Allemagne");
+    assert_eq!(
+        from_alpha2("GB").unwrap().name_fr,
+        "Royaume-Uni de Grande-Bretagne et d'Irlande du Nord"
+    );
+    assert_eq!(from_alpha2("CI").unwrap().name_fr, "Côte d'Ivoire");
+    assert_eq!(
+        from_alpha2("BO").unwrap().name_fr,
+        "Bolivie (État plurinational de)"
+    );
+    assert_eq!(from_alpha2("MM").unwrap().name_fr, "Myanmar");
+    assert_eq!(from_alpha2("TL").unwrap().name_fr, "Timor-Leste");
+    assert_eq!(from_alpha2("MO").unwrap().name_fr, "Macao");
+    assert_eq!(from_alpha2("CV").unwrap().name_fr, "Cabo Verde");
+    assert_eq!(from_alpha2("CG").unwrap().name_fr, "Congo");
+    assert_eq!(from_alpha2("GL").unwrap().name_fr, "Groenland");
+    assert_eq!(from_alpha2("KY").unwrap().name_fr, "Caïmans (les Îles)");
+    for country in rust_iso3166::ALL {
+        assert!(!country.name_fr.is_empty(), "{}", country.alpha2);
+        for article in ["(le)", "(la)", "(les)", "(l')"] {
+            assert!(!country.name_fr.ends_with(article), "{}", country.alpha2);
+        }
+    }
+}
+
+#[test]
+fn test_former_country_names() {
+    let gdr = iso3166_3::from_code("DDDE").unwrap();
+    assert_eq!(gdr.former.name_fr, "République démocratique allemande");
+    assert_eq!(gdr.former.official_name, Some("German Democratic Republic"));
+    assert_eq!(
+        gdr.new_countries[0].official_name,
+        Some("Federal Republic of Germany")
+    );
+    assert_eq!(gdr.new_countries[0].name_fr, "Allemagne");
+    let byelorussia = iso3166_3::from_code("BYAA").unwrap();
+    assert_eq!(
+        byelorussia.former.official_name,
+        Some("Byelorussian Soviet Socialist Republic")
+    );
+    let east_timor = iso3166_3::from_code("TPTL").unwrap();
+    assert_eq!(east_timor.new_countries[0].name_fr, "Timor-Leste");
+    let yugoslavia = iso3166_3::from_code("YUCS").unwrap();
+    assert_eq!(yugoslavia.new_countries[0].name_fr, "Serbie et Monténégro");
+    assert_eq!(yugoslavia.new_countries[0].official_name, None);
+}
@@ -0,0 +1,110 @@
+use rust_iso3166::iso3166_2::Subdivision;
+use rust_iso3166::iso3166_3::CountryCode3;
+use rust_iso3166::{CountryCode, ParseError};
+
+#[test]
+fn test_country_code_from_str() {
+    let au = rust_iso3166::from_alpha2("AU").unwrap();
+    assert_eq!("AU".parse::<CountryCode>(), Ok(au));
+    assert_eq!("aU".parse::<CountryCode>(), Ok(au));
+    assert_eq!("AUS".parse::<CountryCode>(), Ok(au));
+    assert_eq!("036".parse::<CountryCode>(), Ok(au));
+    assert_eq!(CountryCode::try_from("aus"), Ok(au));
+    assert_eq!(CountryCode::try_from(36u16), Ok(au));
+}
+
+#[test]
+fn test_country_code_errors() {
+    assert_eq!("".parse::<CountryCode>(), Err(ParseError::Empty));
+    assert_eq!(
+        "A".parse::<CountryCode>(),
+        Err(ParseError::InvalidLength(1))
+    );
+    assert_eq!(
+        "AUST".parse::<CountryCode>(),
+        Err(ParseError::InvalidLength(4))
+    );
+    assert_eq!(
+        "0A6".parse::<CountryCode>(),
+        Err(ParseError::InvalidCharacter {
+            index: 1,
+            character: 'A'
+        })
+    );
+    assert_eq!(
+        "ÅX".parse::<CountryCode>(),
+        Err(ParseError::InvalidCharacter {
+            index: 0,
+            character: 'Å'
+        })
+    );
+    assert_eq!("XX".parse::<CountryCode>(), Err(ParseError::Unassigned));
+    assert_eq!("XXX".parse::<CountryCode>(), Err(ParseError::Unassigned));
+    assert_eq!("000".parse::<CountryCode>(), Err(ParseError::Unassigned));
+    assert_eq!(CountryCode::try_from(0u16), Err(ParseError::Unassigned));
+    assert_eq!(
+        CountryCode::try_from(1000u16),
+        Err(ParseError::InvalidLength(4))
+    );
+}
+
+#[test]
+fn test_subdivision_from_str() {
+    let edh = rust_iso3166::iso3166_2::from_code("GB-EDH").unwrap();
+    assert_eq!("GB-EDH".parse::<Subdivision>(), Ok(edh));
+    assert_eq!(Subdivision::try_from("gb-edh"), Ok(edh));
+    assert_eq!("".parse::<Subdivision>(), Err(ParseError::Empty));
+    assert_eq!(
+        "GB-EDHX".parse::<Subdivision>(),
+        Err(ParseError::InvalidLength(7))
+    );
+    assert_eq!(
+        "GB_EDH".parse::<Subdivision>(),
+        Err(ParseError::InvalidCharacter {
+            index: 2,
+            character: '_'
+        })
+    );
+    assert_eq!(
+        "QQ-EDH".parse::<Subdivision>(),
+        Err(ParseError::UnknownCountry)
+    );
+    assert_eq!("GB-XXX".parse::<Subdivision>(), Err(ParseError::Unassigned));
+}
+
+#[test]
+fn test_country_code3_from_str() {
+    let pzpa = rust_iso3166::iso3166_3::from_code("PZPA").unwrap();
+    assert_eq!("PZPA".parse::<CountryCode3>(), Ok(pzpa));
+    assert_eq!(CountryCode3::try_from("pzPA"), Ok(pzpa));
+    assert_eq!(
+        "PZP".parse::<CountryCode3>(),
+        Err(ParseError::InvalidLength(3))
+    );
+    assert_eq!("XXXX".parse::<CountryCode3>(), Err(ParseError::Unassigned));
+}
+
+#[test]
+fn test_country_code3_from_numeric() {
+    let code = |numeric: u16| CountryCode3::try_from(numeric).map(|c| c.code);
+    assert_eq!(code(278), Ok("DDDE"));
+    assert_eq!(code(810), Ok("SUHH"));
+    assert_eq!(code(104), Ok("BUMM"));
+    assert_eq!(code(891), Ok("CSXX"));
+    assert_eq!(code(0), Err(ParseError::Unassigned));
+    assert_eq!(code(36), Err(ParseError::Unassigned));
+    assert_eq!(code(1000), Err(ParseError::InvalidLength(4)));
+}
+
+#[test]
+fn test_parse_error_display() {
+    assert_eq!(ParseError::Empty.to_string(), "empty code");
+    assert_eq!(
+        ParseError::InvalidCharacter {
+            index: 1,
+            character: '@'
+        }
+        .to_string(),
+        "invalid character '@' at index 1"
+    );
+}
@@ -0,0 +1,77 @@
+use rust_iso3166::{from_alpha2, from_phone_prefix, CALLING_CODE_MAP};
+
+fn alpha2s(number: &str) -> Vec<&'static str> {
+    let mut found: Vec<_> = from_phone_prefix(number).iter().map(|c| c.alpha2).collect();
+    found.sort();
+    found
+}
+
+#[test]
+fn test_calling_codes() {
+    assert_eq!(from_alpha2("US").unwrap().calling_codes(), &["+1"]);
+    assert_eq!(from_alpha2("AS").unwrap().calling_codes(), &["+1-684"]);
+    assert_eq!(from_alpha2("GB").unwrap().calling_codes(), &["+44"]);
+    assert_eq!(from_alpha2("RU").unwrap().calling_codes(), &["+7"]);
+    assert_eq!(
+        from_alpha2("KZ").unwrap().calling_codes(),
+        &["+7-6", "+7-7"]
+    );
+    assert_eq!(
+        from_alpha2("DO").unwrap().calling_codes(),
+        &["+1-809", "+1-829", "+1-849"]
+    );
+    assert!(from_alpha2("BV").unwrap().calling_codes().is_empty());
+}
+
+#[test]
+fn test_longest_prefix() {
+    assert_eq!(alpha2s("+447911123456"), vec!["GB"]);
+    assert_eq!(alpha2s("+44 1481 123456"), vec!["GG"]);
+    assert_eq!(alpha2s("0044 1534 123456"), vec!["JE"]);
+    assert_eq!(alpha2s("+1 684 633 1234"), vec!["AS"]);
+    assert_eq!(alpha2s("+1 (212) 555-0100"), vec!["CA", "UM", "US"]);
+    assert_eq!(alpha2s("+7 727 123 4567"), vec!["KZ"]);
+    assert_eq!(alpha2s("+7 6 12 34"), vec!["KZ"]);
+    assert_eq!(alpha2s("+7 495 123 4567"), vec!["RU"]);
+    assert_eq!(alpha2s("+358 18 12345"), vec!["AX"]);
+    assert_eq!(alpha2s("+358 9 12345"), vec!["FI"]);
+    assert_eq!(alpha2s("+39 06 698 12345"), vec!["VA"]);
+    assert_eq!(alpha2s("+39 06 1234 5678"), vec!["IT"]);
+}
+
+#[test]
+fn test_short_prefix() {
+    let nanp = alpha2s("+1");
+    assert!(nanp.len() > 20);
+    for alpha2 in ["US", "CA", "AS", "PR", "JM", "DO"] {
+        assert!(nanp.contains(&alpha2), "{}", alpha2);
+    }
+    assert!(!nanp.contains(&"RU"));
+    assert_eq!(alpha2s("+7"), vec!["KZ", "RU"]);
+    assert_eq!(from_phone_prefix("+7")[0].alpha2, "RU");
+    assert!(alpha2s("+35").contains(&"FI"));
+    // The most specific match comes first
+    assert_eq!(from_phone_prefix("+1684")[0].alpha2, "AS");
+}
+
+#[test]
+fn test_invalid() {
+    assert!(from_phone_prefix("").is_empty());
+    assert!(from_phone_prefix("+").is_empty());
+    assert!(from_phone_prefix("07911 123456").is_empty());
+    assert!(from_phone_prefix("+44 79x1").is_empty());
+}
+
+#[test]
+fn test_calling_codes_are_well_formed() {
+    for (alpha2, codes) in CALLING_CODE_MAP.entries() {
+        let country = from_alpha2(alpha2).unwrap();
+        for code in *codes {
+            assert!(code.starts_with('+'), "{}", code);
+            let digits = code.chars().filter(char::is_ascii_digit).count();
+            assert!((1..=9).contains(&digits), "{}", code);
+            assert!(from_phone_prefix(code).contains(&country), "{}", code);
+        }
+    }
+    assert_eq!(CALLING_CODE_MAP.len(), rust_iso3166::ALL.len());
+}
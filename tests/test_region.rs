@@ -0,0 +1,96 @@
+use rust_iso3166::{from_alpha2, Region, RegionLevel, COUNTRY_REGION_MAP, REGION_MAP};
+
+fn names(alpha2: &str) -> [Option<&'static str>; 3] {
+    let country = from_alpha2(alpha2).unwrap();
+    [
+        country.region().map(|r| r.name),
+        country.sub_region().map(|r| r.name),
+        country.intermediate_region().map(|r| r.name),
+    ]
+}
+
+#[test]
+fn test_country_regions() {
+    assert_eq!(
+        names("KE"),
+        [
+            Some("Africa"),
+            Some("Sub-Saharan Africa"),
+            Some("Eastern Africa")
+        ]
+    );
+    assert_eq!(names("EG"), [Some("Africa"), Some("Northern Africa"), None]);
+    assert_eq!(
+        names("MX"),
+        [
+            Some("Americas"),
+            Some("Latin America and the Caribbean"),
+            Some("Central America")
+        ]
+    );
+    assert_eq!(
+        names("CA"),
+        [Some("Americas"), Some("Northern America"), None]
+    );
+    assert_eq!(
+        names("JE"),
+        [
+            Some("Europe"),
+            Some("Northern Europe"),
+            Some("Channel Islands")
+        ]
+    );
+    assert_eq!(names("TR"), [Some("Asia"), Some("Western Asia"), None]);
+    assert_eq!(
+        names("NZ"),
+        [Some("Oceania"), Some("Australia and New Zealand"), None]
+    );
+    assert_eq!(names("AQ"), [None, None, None]);
+}
+
+#[test]
+fn test_from_code() {
+    assert_eq!(Region::from_code(2).unwrap().name, "Africa");
+    assert_eq!(Region::from_code(419).unwrap().code_str(), "419");
+    assert_eq!(Region::from_code(5).unwrap().code_str(), "005");
+    assert_eq!(Region::from_code(1).unwrap().level, RegionLevel::World);
+    assert_eq!(Region::from_code(1).unwrap().parent(), None);
+    assert_eq!(Region::from_code(36), None);
+}
+
+#[test]
+fn test_countries() {
+    let world = Region::from_code(1).unwrap();
+    assert_eq!(world.countries().len(), rust_iso3166::ALL.len() - 1);
+    let channel = Region::from_code(830).unwrap().countries();
+    assert_eq!(
+        channel.iter().map(|c| c.alpha2).collect::<Vec<_>>(),
+        vec!["GG", "JE"]
+    );
+    let continents: usize = world.children().iter().map(|r| r.countries().len()).sum();
+    assert_eq!(continents, world.countries().len());
+    assert!(Region::from_code(150)
+        .unwrap()
+        .countries()
+        .contains(&rust_iso3166::FR));
+    assert!(!Region::from_code(150)
+        .unwrap()
+        .countries()
+        .contains(&rust_iso3166::US));
+}
+
+#[test]
+fn test_hierarchy_is_consistent() {
+    for (code, region) in REGION_MAP.entries() {
+        assert_eq!(region.code, *code);
+        if let Some(parent) = region.parent() {
+            assert!(parent.level < region.level, "{}", region.name);
+            assert!(parent.children().contains(region));
+        }
+    }
+    for (alpha2, code) in COUNTRY_REGION_MAP.entries() {
+        let region = Region::from_code(*code).unwrap();
+        assert!(region.level >= RegionLevel::SubRegion, "{}", alpha2);
+        assert!(from_alpha2(alpha2).is_some());
+    }
+}
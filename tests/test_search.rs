@@ -0,0 +1,70 @@
+use rust_iso3166::search::{search, SearchOptions};
+use rust_iso3166::Code;
+
+fn top(query: &str, options: &SearchOptions) -> Option<&'static str> {
+    search(query, options).first().map(|m| m.code.code())
+}
+
+#[test]
+fn test_search_typos_and_diacritics() {
+    let options = SearchOptions::default();
+    assert_eq!(top("Untied Kingdom", &options), Some("GB"));
+    assert_eq!(top("germny", &options), Some("DE"));
+    assert_eq!(top("Vastra Gotaland", &options), Some("SE-O"));
+    assert_eq!(top("cote divoire", &options), Some("CI"));
+    assert_eq!(top("SÃO PAULO", &options), Some("BR-SP"));
+}
+
+#[test]
+fn test_search_ranking() {
+    let found = search("congo", &SearchOptions::default());
+    assert_eq!(found[0].code.code(), "CG");
+    assert_eq!(found[0].score, 1.0);
+    assert_eq!(found[1].code.code(), "CD");
+    assert!(found.windows(2).all(|w| w[0].score >= w[1].score));
+}
+
+#[test]
+fn test_search_filters() {
+    let countries = SearchOptions {
+        subdivisions: false,
+        former: false,
+        ..SearchOptions::default()
+    };
+    assert!(search("new", &countries)
+        .iter()
+        .all(|m| matches!(m.code, Code::Country(_))));
+
+    let gb = SearchOptions {
+        countries: false,
+        former: false,
+        country: rust_iso3166::from_alpha2("GB"),
+        ..SearchOptions::default()
+    };
+    let found = search("ireland", &gb);
+    assert_eq!(found[0].code.code(), "GB-NIR");
+    assert!(found.iter().all(|m| m.code.code().starts_with("GB-")));
+
+    let former = SearchOptions {
+        countries: false,
+        subdivisions: false,
+        ..SearchOptions::default()
+    };
+    assert_eq!(top("yugoslavia", &former), Some("YUCS"));
+}
+
+#[test]
+fn test_search_limits() {
+    let options = SearchOptions {
+        limit: 3,
+        ..SearchOptions::default()
+    };
+    assert_eq!(search("new", &options).len(), 3);
+    let options = SearchOptions {
+        min_score: 1.0,
+        ..SearchOptions::default()
+    };
+    assert!(search("germny", &options).is_empty());
+    assert!(search("", &SearchOptions::default()).is_empty());
+    assert!(search(" -- ", &SearchOptions::default()).is_empty());
+}
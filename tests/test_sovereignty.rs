@@ -0,0 +1,63 @@
+use rust_iso3166::{from_alpha2, Relationship, DEPENDENCY_MAP};
+
+#[test]
+fn test_is_independent() {
+    assert!(from_alpha2("US").unwrap().is_independent());
+    assert!(from_alpha2("DK").unwrap().is_independent());
+    assert!(!from_alpha2("PR").unwrap().is_independent());
+    assert!(!from_alpha2("HK").unwrap().is_independent());
+    assert!(!from_alpha2("AQ").unwrap().is_independent());
+}
+
+#[test]
+fn test_sovereign() {
+    let sovereign = |alpha2| from_alpha2(alpha2).unwrap().sovereign().map(|c| c.alpha2);
+    assert_eq!(sovereign("GU"), Some("US"));
+    assert_eq!(sovereign("PR"), Some("US"));
+    assert_eq!(sovereign("VI"), Some("US"));
+    assert_eq!(sovereign("GL"), Some("DK"));
+    assert_eq!(sovereign("FO"), Some("DK"));
+    assert_eq!(sovereign("HK"), Some("CN"));
+    assert_eq!(sovereign("JE"), Some("GB"));
+    assert_eq!(sovereign("US"), None);
+    assert_eq!(sovereign("AQ"), None);
+
+    let macao = from_alpha2("MO").unwrap().dependency().unwrap();
+    assert_eq!(
+        macao.relationship,
+        Relationship::SpecialAdministrativeRegion
+    );
+    let man = from_alpha2("IM").unwrap().dependency().unwrap();
+    assert_eq!(man.relationship, Relationship::CrownDependency);
+}
+
+#[test]
+fn test_dependencies() {
+    let denmark: Vec<_> = from_alpha2("DK")
+        .unwrap()
+        .dependencies()
+        .iter()
+        .map(|d| d.territory.alpha2)
+        .collect();
+    assert_eq!(denmark, vec!["FO", "GL"]);
+    assert_eq!(from_alpha2("US").unwrap().dependencies().len(), 6);
+    assert!(from_alpha2("DE").unwrap().dependencies().is_empty());
+}
+
+#[test]
+fn test_dependencies_are_consistent() {
+    for (alpha2, dependency) in DEPENDENCY_MAP.entries() {
+        assert_eq!(dependency.territory.alpha2, *alpha2);
+        assert!(!dependency.territory.is_independent(), "{}", alpha2);
+        assert!(dependency.sovereign.is_independent(), "{}", alpha2);
+    }
+    for country in rust_iso3166::ALL {
+        if !country.is_independent() && country.sovereign().is_none() {
+            assert!(
+                ["AQ", "EH", "PS", "TW"].contains(&country.alpha2),
+                "{}",
+                country.alpha2
+            );
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use rust_iso3166::iso3166_2;
+use rust_iso3166::sports::{self, Federation};
+use rust_iso3166::{from_alpha2, Code};
+
+fn code(alpha2: &str, federation: Federation) -> Option<&'static str> {
+    from_alpha2(alpha2).unwrap().sports_code(federation)
+}
+
+fn team(federation: Federation, code: &str) -> Option<&'static str> {
+    sports::from_code(federation, code).map(|c| c.team.code())
+}
+
+#[test]
+fn test_sports_code() {
+    assert_eq!(code("DE", Federation::IOC), Some("GER"));
+    assert_eq!(code("NL", Federation::IOC), Some("NED"));
+    assert_eq!(code("ID", Federation::IOC), Some("INA"));
+    assert_eq!(code("ID", Federation::FIFA), Some("IDN"));
+    assert_eq!(code("SG", Federation::IOC), Some("SGP"));
+    assert_eq!(code("SG", Federation::FIFA), Some("SIN"));
+    assert_eq!(code("TW", Federation::FIFA), Some("TPE"));
+    assert_eq!(code("PF", Federation::FIFA), Some("TAH"));
+    assert_eq!(code("GI", Federation::WorldAthletics), Some("GIB"));
+    assert_eq!(code("GI", Federation::IOC), None);
+    assert_eq!(code("FO", Federation::IPC), Some("FRO"));
+    assert_eq!(code("GB", Federation::IOC), Some("GBR"));
+    assert_eq!(code("GB", Federation::FIFA), None);
+    assert_eq!(code("AQ", Federation::IOC), None);
+}
+
+#[test]
+fn test_home_nations() {
+    for (fifa, subdivision) in [
+        ("ENG", "GB-ENG"),
+        ("SCO", "GB-SCT"),
+        ("WAL", "GB-WLS"),
+        ("NIR", "GB-NIR"),
+    ] {
+        assert_eq!(team(Federation::FIFA, fifa), Some(subdivision));
+        let home_nation = Code::Subdivision(iso3166_2::from_code(subdivision).unwrap());
+        assert_eq!(home_nation.sports_code(Federation::FIFA), Some(fifa));
+        assert_eq!(home_nation.sports_code(Federation::IOC), None);
+    }
+    assert_eq!(team(Federation::IOC, "ENG"), None);
+}
+
+#[test]
+fn test_historical() {
+    assert_eq!(team(Federation::IOC, "URS"), Some("SUHH"));
+    assert_eq!(team(Federation::IOC, "EUN"), Some("SUHH"));
+    assert_eq!(team(Federation::IOC, "GDR"), Some("DDDE"));
+    assert_eq!(team(Federation::IOC, "YUG"), Some("YUCS"));
+    assert_eq!(team(Federation::IOC, "TCH"), Some("CSHH"));
+    assert_eq!(team(Federation::IOC, "FRG"), Some("DE"));
+    assert_eq!(team(Federation::FIFA, "ANT"), Some("ANHH"));
+    assert_eq!(team(Federation::IOC, "ANT"), Some("AG"));
+    assert!(
+        sports::from_code(Federation::IOC, "URS")
+            .unwrap()
+            .historical
+    );
+    assert!(
+        !sports::from_code(Federation::IOC, "GER")
+            .unwrap()
+            .historical
+    );
+    // The current code wins over historical codes of the same country
+    assert_eq!(code("DE", Federation::FIFA), Some("GER"));
+}
+
+#[test]
+fn test_from_code() {
+    assert_eq!(team(Federation::IOC, "SUI"), Some("CH"));
+    assert_eq!(team(Federation::IOC, " sui "), Some("CH"));
+    assert_eq!(team(Federation::FIFA, "CTA"), Some("CF"));
+    assert_eq!(team(Federation::IOC, "CAF"), Some("CF"));
+    assert_eq!(team(Federation::IOC, "KOS"), None);
+    assert_eq!(team(Federation::IOC, "GE"), None);
+    assert_eq!(team(Federation::IOC, ""), None);
+    assert_eq!(Federation::WorldAthletics.name(), "World Athletics");
+}
+
+#[test]
+fn test_data_is_consistent() {
+    for federation in Federation::ALL {
+        for (key, value) in federation.codes().entries() {
+            assert_eq!(*key, value.code);
+            assert_eq!(value.code.len(), 3);
+            if !value.historical {
+                assert_eq!(value.team.sports_code(*federation), Some(value.code));
+            }
+        }
+        for (team, code) in federation.team_codes().entries() {
+            let found = sports::from_code(*federation, code).unwrap();
+            assert_eq!(found.team.code(), *team);
+            assert!(!found.historical);
+        }
+        let current = federation.codes().values().filter(|c| !c.historical);
+        assert_eq!(current.count(), federation.team_codes().len());
+    }
+    assert_eq!(
+        sports::IOC_MAP.values().filter(|c| !c.historical).count(),
+        205
+    );
+    assert_eq!(
+        sports::FIFA_MAP.values().filter(|c| !c.historical).count(),
+        210
+    );
+}
@@ -0,0 +1,102 @@
+use rust_iso3166::{code_status, CodeStatus, ParseError, RESERVED_MAP};
+
+#[test]
+fn test_assigned() {
+    assert_eq!(
+        code_status("AU"),
+        Ok(CodeStatus::Assigned(rust_iso3166::AU))
+    );
+    assert_eq!(
+        code_status("aus"),
+        Ok(CodeStatus::Assigned(rust_iso3166::AU))
+    );
+    for country in rust_iso3166::ALL {
+        assert_eq!(
+            code_status(country.alpha2),
+            Ok(CodeStatus::Assigned(*country))
+        );
+        assert_eq!(
+            code_status(country.alpha3),
+            Ok(CodeStatus::Assigned(*country))
+        );
+    }
+}
+
+#[test]
+fn test_reserved() {
+    let uk = code_status("UK").unwrap();
+    assert!(matches!(uk, CodeStatus::ExceptionallyReserved(_)));
+    assert!(uk.is_reserved());
+    assert!(matches!(
+        code_status("EU"),
+        Ok(CodeStatus::ExceptionallyReserved(_))
+    ));
+    assert!(matches!(
+        code_status("RA"),
+        Ok(CodeStatus::IndeterminatelyReserved(_))
+    ));
+
+    let cs = code_status("cs").unwrap();
+    assert!(matches!(cs, CodeStatus::TransitionallyReserved(_)));
+    let former: Vec<_> = cs
+        .reservation()
+        .unwrap()
+        .former
+        .iter()
+        .map(|f| f.code)
+        .collect();
+    assert_eq!(former, vec!["CSXX", "CSHH"]);
+    let bur = code_status("BUR").unwrap().reservation().unwrap().former;
+    assert_eq!(bur[0].new_countries[0].alpha2, "MM");
+
+    let sf = code_status("SF").unwrap();
+    assert!(matches!(sf, CodeStatus::TransitionallyReserved(_)));
+    let sf = sf.reservation().unwrap();
+    assert_eq!(sf.note, "Finland, international vehicle registration");
+    assert!(sf.former.is_empty());
+}
+
+#[test]
+fn test_reservations_are_consistent() {
+    for (code, status) in RESERVED_MAP.entries() {
+        let reservation = status.reservation().expect(code);
+        assert_eq!(reservation.code, *code);
+        assert!(!reservation.note.is_empty());
+        assert_eq!(code_status(code), Ok(*status));
+        for former in reservation.former {
+            assert!(
+                former.former.alpha2 == *code || former.former.alpha3 == *code,
+                "{} does not come from {}",
+                code,
+                former.code
+            );
+        }
+    }
+}
+
+#[test]
+fn test_user_assigned_and_unassigned() {
+    for code in [
+        "AA", "QM", "QZ", "XA", "XK", "XZ", "ZZ", "AAA", "QMA", "XKX", "ZZZ",
+    ] {
+        assert_eq!(code_status(code), Ok(CodeStatus::UserAssigned), "{}", code);
+    }
+    for code in ["EL", "QL", "AB", "ZY", "ABC"] {
+        assert_eq!(code_status(code), Ok(CodeStatus::Unassigned), "{}", code);
+    }
+    assert_eq!(code_status("XK").unwrap().reservation(), None);
+}
+
+#[test]
+fn test_invalid() {
+    assert_eq!(code_status(""), Err(ParseError::Empty));
+    assert_eq!(code_status("A"), Err(ParseError::InvalidLength(1)));
+    assert_eq!(code_status("ABCD"), Err(ParseError::InvalidLength(4)));
+    assert_eq!(
+        code_status("036"),
+        Err(ParseError::InvalidCharacter {
+            index: 0,
+            character: '0'
+        })
+    );
+}
@@ -0,0 +1,104 @@
+use rust_iso3166::iso3166_2;
+use rust_iso3166::{
+    countries_for_zone, from_alpha2, ALL, COUNTRY_TIME_ZONE_MAP, SUBDIVISION_TIME_ZONE_MAP,
+};
+
+fn zones(alpha2: &str) -> &'static [&'static str] {
+    from_alpha2(alpha2).unwrap().time_zones()
+}
+
+fn subdivision_zones(code: &str) -> &'static [&'static str] {
+    iso3166_2::from_code(code).unwrap().time_zones()
+}
+
+fn countries(zone: &str) -> Vec<&'static str> {
+    countries_for_zone(zone).iter().map(|c| c.alpha2).collect()
+}
+
+#[test]
+fn test_country_time_zones() {
+    assert_eq!(zones("FR"), &["Europe/Paris"]);
+    assert_eq!(zones("DE"), &["Europe/Berlin", "Europe/Busingen"]);
+    assert_eq!(zones("LI"), &["Europe/Vaduz"]);
+    assert_eq!(zones("US")[0], "America/New_York");
+    assert_eq!(zones("CA")[0], "America/Toronto");
+    assert_eq!(zones("AU")[0], "Australia/Sydney");
+    assert_eq!(zones("RU")[0], "Europe/Moscow");
+    assert!(zones("US").contains(&"Pacific/Honolulu"));
+    assert!(zones("BV").is_empty());
+    let missing: Vec<_> = ALL
+        .iter()
+        .filter(|c| c.time_zones().is_empty())
+        .map(|c| c.alpha2)
+        .collect();
+    assert_eq!(missing.len(), 2);
+    assert_eq!(COUNTRY_TIME_ZONE_MAP.len(), ALL.len() - 2);
+}
+
+#[test]
+fn test_subdivision_time_zones() {
+    assert_eq!(subdivision_zones("US-CA"), &["America/Los_Angeles"]);
+    assert_eq!(subdivision_zones("US-NY"), &["America/New_York"]);
+    assert_eq!(subdivision_zones("US-TX")[0], "America/Chicago");
+    assert_eq!(subdivision_zones("US-PR"), &["America/Puerto_Rico"]);
+    assert_eq!(subdivision_zones("AU-WA")[0], "Australia/Perth");
+    assert_eq!(subdivision_zones("AU-QLD")[0], "Australia/Brisbane");
+    assert_eq!(subdivision_zones("CA-BC")[0], "America/Vancouver");
+    assert_eq!(subdivision_zones("CA-QC")[0], "America/Toronto");
+    assert!(subdivision_zones("DE-BY").is_empty());
+}
+
+#[test]
+fn test_subdivisions_covered() {
+    for prefix in ["US-", "CA-", "AU-"] {
+        for subdivision in iso3166_2::SUBDIVISION_MAP
+            .values()
+            .filter(|s| s.code.starts_with(prefix))
+        {
+            assert!(!subdivision.time_zones().is_empty(), "{}", subdivision.code);
+        }
+    }
+    for (code, zones) in SUBDIVISION_TIME_ZONE_MAP.entries() {
+        assert!(iso3166_2::from_code(code).is_some(), "{}", code);
+        for zone in zones.iter() {
+            assert!(!countries_for_zone(zone).is_empty(), "{}", zone);
+        }
+    }
+}
+
+#[test]
+fn test_countries_for_zone() {
+    assert_eq!(countries("Europe/Zurich"), vec!["CH", "DE", "LI"]);
+    assert_eq!(countries("Europe/Paris"), vec!["FR", "MC"]);
+    assert_eq!(countries("America/Los_Angeles"), vec!["US"]);
+    assert_eq!(countries("US/Pacific"), vec!["US"]);
+    assert_eq!(countries("Asia/Calcutta"), vec!["IN"]);
+    assert_eq!(countries("Europe/Kiev"), vec!["UA"]);
+    assert_eq!(countries(" Asia/Tokyo ")[0], "JP");
+    assert!(countries("Etc/UTC").is_empty());
+    assert!(countries("europe/paris").is_empty());
+    for country in ALL {
+        for zone in country.time_zones() {
+            assert!(countries_for_zone(zone).contains(country), "{}", zone);
+        }
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn test_chrono_tz() {
+    for country in ALL {
+        assert_eq!(country.time_zones_tz().len(), country.time_zones().len());
+    }
+    for subdivision in iso3166_2::SUBDIVISION_MAP.values() {
+        assert_eq!(
+            subdivision.time_zones_tz().len(),
+            subdivision.time_zones().len()
+        );
+    }
+    let california = iso3166_2::from_code("US-CA").unwrap();
+    assert_eq!(
+        california.time_zones_tz(),
+        vec![chrono_tz::America::Los_Angeles]
+    );
+}
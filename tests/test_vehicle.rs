@@ -0,0 +1,76 @@
+use rust_iso3166::{
+    from_alpha2, from_vehicle_sign, Code, COUNTRY_VEHICLE_SIGN_MAP, VEHICLE_SIGN_MAP,
+};
+
+fn sign(alpha2: &str) -> Option<&'static str> {
+    from_alpha2(alpha2).unwrap().vehicle_sign()
+}
+
+fn code(sign: &str) -> Option<&'static str> {
+    from_vehicle_sign(sign).map(|s| s.code.code())
+}
+
+#[test]
+fn test_vehicle_sign() {
+    assert_eq!(sign("DE"), Some("D"));
+    assert_eq!(sign("GB"), Some("GB"));
+    assert_eq!(sign("CH"), Some("CH"));
+    assert_eq!(sign("SM"), Some("RSM"));
+    assert_eq!(sign("AT"), Some("A"));
+    assert_eq!(sign("GG"), Some("GBG"));
+    assert_eq!(sign("MK"), Some("NMK"));
+    assert_eq!(sign("AQ"), None);
+}
+
+#[test]
+fn test_from_vehicle_sign() {
+    assert_eq!(code("D"), Some("DE"));
+    assert_eq!(code("gb"), Some("GB"));
+    assert_eq!(code(" RSM "), Some("SM"));
+    assert_eq!(code("GBA"), Some("GG"));
+    assert_eq!(code("GBZ"), Some("GI"));
+    // Signs that are a different country's ISO code
+    assert_eq!(code("SD"), Some("SZ"));
+    assert_eq!(code("ES"), Some("SV"));
+    assert_eq!(code("RC"), Some("TW"));
+    assert_eq!(code("BH"), Some("BZ"));
+    assert_eq!(code("XX"), None);
+    assert_eq!(code("DEUT"), None);
+    assert_eq!(code(""), None);
+}
+
+#[test]
+fn test_historical() {
+    assert_eq!(code("SU"), Some("SUHH"));
+    assert_eq!(code("DDR"), Some("DDDE"));
+    assert_eq!(code("YU"), Some("YUCS"));
+    assert_eq!(code("CS"), Some("CSHH"));
+    assert_eq!(code("ZRE"), Some("ZRCD"));
+    assert_eq!(code("EW"), Some("EE"));
+    assert_eq!(code("MK"), Some("MK"));
+    let su = from_vehicle_sign("SU").unwrap();
+    assert!(su.historical);
+    assert!(matches!(su.code, Code::Former(_)));
+    assert!(!from_vehicle_sign("D").unwrap().historical);
+}
+
+#[test]
+fn test_data_is_consistent() {
+    for (alpha2, sign) in COUNTRY_VEHICLE_SIGN_MAP.entries() {
+        let country = from_alpha2(alpha2).unwrap();
+        let found = from_vehicle_sign(sign).unwrap();
+        assert_eq!(found.code, Code::Country(country));
+        assert!(!found.historical);
+    }
+    for (key, value) in VEHICLE_SIGN_MAP.entries() {
+        assert_eq!(*key, value.sign);
+        if !value.historical && value.sign != "GBA" {
+            assert_eq!(
+                COUNTRY_VEHICLE_SIGN_MAP.get(value.code.code()),
+                Some(key),
+                "{}",
+                key
+            );
+        }
+    }
+}